@@ -0,0 +1,205 @@
+//! Audit log of the operations writing to GitHub
+//!
+//! The MCP server and CLI run each operation within
+//! `github::audit::with_audit`. An operation that sent at least one write
+//! request is appended to the audit log as one JSON line, whether it
+//! succeeded or not. Read-only operations and dry runs are not recorded.
+//!
+//! The log is append-only: entries are never rewritten, and each is written
+//! with a single append so concurrent processes do not interleave lines.
+
+use crate::config::AuditConfig;
+use crate::github::GitHubClient;
+use crate::types::audit::AuditEntry;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+/// Text replacing redacted parameter values
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Maximum characters of a string parameter in the log; longer values, such
+/// as file contents, are cut
+pub const MAX_PARAMETER_CHARS: usize = 500;
+
+/// Parameters whose values are never logged
+const SECRET_PARAMETERS: [&str; 3] = ["token", "password", "github_token"];
+
+/// Tools and CLI commands whose `value` parameter is a secret
+const SECRET_VALUE_TOOLS: [&str; 2] = ["set_repo_secret", "secrets set"];
+
+/// Append-only JSON Lines file of audit entries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditLog {
+    path: PathBuf,
+}
+
+/// Filter of the entries read from the audit log
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditQuery {
+    /// Only entries of this tool or CLI command
+    pub tool: Option<String>,
+    /// Only entries of this actor
+    pub actor: Option<String>,
+    /// Only entries at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Only failed operations
+    pub failed_only: bool,
+    /// Only the newest entries, at most this many
+    pub limit: Option<usize>,
+}
+
+impl AuditLog {
+    /// Create an audit log writing to `path`
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Returns the audit log of the configuration, or `None` if auditing is
+    /// disabled
+    pub fn from_config(config: &AuditConfig) -> Result<Option<Self>> {
+        Ok(config.log_path()?.map(Self::new))
+    }
+
+    /// Returns the path of the log file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append an entry, creating the file and its directory if needed
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    pub fn append(&self, entry: &AuditEntry) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+        }
+        let mut line = serde_json::to_vec(entry)?;
+        line.push(b'\n');
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open audit log {}", self.path.display()))?;
+        file.write_all(&line)
+            .with_context(|| format!("Failed to write audit log {}", self.path.display()))
+    }
+
+    /// Read the entries matching `query`, oldest first
+    ///
+    /// A missing file has no entries. Lines that are not valid entries, such
+    /// as a line cut short by a crash, are skipped with a warning.
+    ///
+    /// # Errors
+    /// Returns an error if the file exists but cannot be read.
+    pub fn read(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let file = std::fs::File::open(&self.path)
+            .with_context(|| format!("Failed to open audit log {}", self.path.display()))?;
+
+        let mut entries = Vec::new();
+        for (index, line) in BufReader::new(file).lines().enumerate() {
+            let line =
+                line.with_context(|| format!("Failed to read audit log {}", self.path.display()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry: AuditEntry = match serde_json::from_str(&line) {
+                Ok(entry) => entry,
+                Err(e) => {
+                    tracing::warn!(
+                        "Skipping invalid line {} of audit log {}: {}",
+                        index + 1,
+                        self.path.display(),
+                        e
+                    );
+                    continue;
+                }
+            };
+            if query.matches(&entry) {
+                entries.push(entry);
+            }
+        }
+
+        if let Some(limit) = query.limit {
+            let skip = entries.len().saturating_sub(limit);
+            entries.drain(..skip);
+        }
+        Ok(entries)
+    }
+}
+
+impl AuditQuery {
+    /// Returns true if the entry passes the filter, ignoring the limit
+    pub fn matches(&self, entry: &AuditEntry) -> bool {
+        self.tool.as_ref().is_none_or(|tool| entry.tool == *tool)
+            && self
+                .actor
+                .as_ref()
+                .is_none_or(|actor| entry.actor.as_ref() == Some(actor))
+            && self.since.is_none_or(|since| entry.timestamp >= since)
+            && (!self.failed_only || !entry.success)
+    }
+}
+
+/// Prepare the parameters of `tool` for the log
+///
+/// Tokens, passwords and secret values are replaced with `REDACTED`, and
+/// strings longer than `MAX_PARAMETER_CHARS` are cut.
+pub fn redact_parameters(tool: &str, parameters: serde_json::Value) -> serde_json::Value {
+    let secret_value = SECRET_VALUE_TOOLS.contains(&tool);
+    redact_value(parameters, secret_value)
+}
+
+fn redact_value(value: serde_json::Value, secret_value: bool) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(object) => {
+            // Operations of execute_batch name their tool next to their params
+            let secret_value = secret_value
+                || object
+                    .get("tool")
+                    .and_then(|tool| tool.as_str())
+                    .is_some_and(|tool| SECRET_VALUE_TOOLS.contains(&tool));
+            object
+                .into_iter()
+                .map(|(key, value)| {
+                    let is_secret = SECRET_PARAMETERS.contains(&key.as_str())
+                        || (secret_value && key == "value");
+                    let value = if is_secret {
+                        serde_json::Value::String(REDACTED.to_string())
+                    } else {
+                        redact_value(value, secret_value)
+                    };
+                    (key, value)
+                })
+                .collect()
+        }
+        serde_json::Value::Array(values) => values
+            .into_iter()
+            .map(|value| redact_value(value, secret_value))
+            .collect(),
+        serde_json::Value::String(text) if text.chars().count() > MAX_PARAMETER_CHARS => {
+            let total = text.chars().count();
+            let head: String = text.chars().take(MAX_PARAMETER_CHARS).collect();
+            serde_json::Value::String(format!("{}... ({} characters)", head, total))
+        }
+        value => value,
+    }
+}
+
+/// Returns the login of the account the client's token acts as, the actor
+/// of its audit entries, or `None` if it cannot be read
+pub async fn authenticated_login(github_client: &GitHubClient) -> Option<String> {
+    match github_client.get_authenticated_user().await {
+        Ok(user) => Some(user.login),
+        Err(e) => {
+            tracing::warn!("Failed to read the actor of the audit log: {:#}", e);
+            None
+        }
+    }
+}
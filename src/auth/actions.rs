@@ -0,0 +1,33 @@
+//! Token of GitHub Actions workflows
+//!
+//! Workflows pass their token as `GITHUB_TOKEN`, e.g. with
+//! `env: GITHUB_TOKEN: ${{ github.token }}`. The variable is only read when
+//! running inside GitHub Actions, so a `GITHUB_TOKEN` exported in a local
+//! shell for other tools is never picked up.
+
+use super::credential_store::parse_secret;
+
+/// Environment variable holding the token of a GitHub Actions workflow
+pub const ACTIONS_TOKEN_ENV_VAR: &str = "GITHUB_TOKEN";
+
+/// Environment variable GitHub Actions sets to `true` on its runners
+pub const ACTIONS_ENV_VAR: &str = "GITHUB_ACTIONS";
+
+/// Returns true if running inside a GitHub Actions workflow
+pub fn is_github_actions() -> bool {
+    std::env::var(ACTIONS_ENV_VAR).is_ok_and(|value| value == "true")
+}
+
+/// Read the token of the GitHub Actions workflow
+///
+/// # Returns
+/// The token, or `None` outside GitHub Actions or if `GITHUB_TOKEN` is not
+/// passed to the step
+pub fn actions_token() -> Option<String> {
+    if !is_github_actions() {
+        return None;
+    }
+    std::env::var(ACTIONS_TOKEN_ENV_VAR)
+        .ok()
+        .and_then(|token| parse_secret(token.as_bytes()))
+}
@@ -0,0 +1,309 @@
+//! Credential stores holding the GitHub token
+//!
+//! The keyring store talks to the OS keyring through the tools shipped with
+//! each platform: `security` on macOS, `secret-tool` (libsecret) for the
+//! Secret Service on Linux and the WinRT `PasswordVault` through PowerShell
+//! on Windows, which backs the Windows Credential Manager.
+
+use anyhow::{Context, Result};
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+use crate::config::CredentialStoreKind;
+
+/// Environment variable holding the GitHub token
+pub const TOKEN_ENV_VAR: &str = "GITHUB_EDIT_GITHUB_TOKEN";
+
+/// Keyring service name the token is stored under
+pub const KEYRING_SERVICE: &str = "github-edit";
+
+/// Keyring account name of the token
+pub const KEYRING_DEFAULT_ACCOUNT: &str = "github-token";
+
+/// Exit code the keyring scripts use when no token is stored
+const NOT_FOUND_EXIT_CODE: i32 = 44;
+
+/// Environment variables passing the service and account to the PowerShell
+/// scripts, so that profile names are never parsed as script source
+const POWERSHELL_RESOURCE_ENV_VAR: &str = "GITHUB_EDIT_KEYRING_RESOURCE";
+const POWERSHELL_ACCOUNT_ENV_VAR: &str = "GITHUB_EDIT_KEYRING_ACCOUNT";
+
+/// A place the GitHub token can be read from and written to
+pub trait CredentialStore {
+    /// Human readable name of the store used in messages
+    fn name(&self) -> &'static str;
+
+    /// Read the token, returning `None` if none is stored
+    fn get_token(&self) -> Result<Option<String>>;
+
+    /// Store the token, replacing any stored token
+    fn set_token(&self, token: &str) -> Result<()>;
+
+    /// Delete the token, returning false if none was stored
+    fn delete_token(&self) -> Result<bool>;
+}
+
+/// Create the credential store of the given kind
+pub fn credential_store(kind: CredentialStoreKind) -> Box<dyn CredentialStore> {
+    match kind {
+        CredentialStoreKind::Env => Box::new(EnvCredentialStore),
+        CredentialStoreKind::Keyring => Box::new(KeyringCredentialStore::default()),
+    }
+}
+
+/// Reads the token from the `GITHUB_EDIT_GITHUB_TOKEN` environment variable
+///
+/// The variable cannot be changed for the calling shell, so writing is not
+/// supported.
+pub struct EnvCredentialStore;
+
+impl CredentialStore for EnvCredentialStore {
+    fn name(&self) -> &'static str {
+        "environment variable"
+    }
+
+    fn get_token(&self) -> Result<Option<String>> {
+        Ok(std::env::var(TOKEN_ENV_VAR)
+            .ok()
+            .and_then(|token| parse_secret(token.as_bytes())))
+    }
+
+    fn set_token(&self, _token: &str) -> Result<()> {
+        anyhow::bail!(
+            "The token cannot be stored in an environment variable. Export {} in your shell instead",
+            TOKEN_ENV_VAR
+        )
+    }
+
+    fn delete_token(&self) -> Result<bool> {
+        anyhow::bail!(
+            "The token cannot be removed from an environment variable. Unset {} in your shell instead",
+            TOKEN_ENV_VAR
+        )
+    }
+}
+
+/// Stores the token in the OS keyring
+pub struct KeyringCredentialStore {
+    service: String,
+    account: String,
+}
+
+impl Default for KeyringCredentialStore {
+    fn default() -> Self {
+        Self::new(KEYRING_DEFAULT_ACCOUNT)
+    }
+}
+
+impl KeyringCredentialStore {
+    /// Create a store for the token saved under `account` of the github-edit service
+    pub fn new(account: impl Into<String>) -> Self {
+        Self {
+            service: KEYRING_SERVICE.to_string(),
+            account: account.into(),
+        }
+    }
+
+    /// PowerShell command running `script` with `$vault`, `$resource` and
+    /// `$account` defined
+    fn powershell(&self, script: &str) -> Command {
+        let mut command = Command::new("powershell");
+        command
+            .args(["-NoProfile", "-NonInteractive", "-Command"])
+            .arg(format!(
+                "[void][Windows.Security.Credentials.PasswordVault,Windows.Security.Credentials,ContentType=WindowsRuntime]; \
+                 $vault = New-Object Windows.Security.Credentials.PasswordVault; \
+                 $resource = $env:{}; $account = $env:{}; {}",
+                POWERSHELL_RESOURCE_ENV_VAR, POWERSHELL_ACCOUNT_ENV_VAR, script
+            ))
+            .env(POWERSHELL_RESOURCE_ENV_VAR, &self.service)
+            .env(POWERSHELL_ACCOUNT_ENV_VAR, &self.account);
+        command
+    }
+}
+
+impl CredentialStore for KeyringCredentialStore {
+    fn name(&self) -> &'static str {
+        "OS keyring"
+    }
+
+    fn get_token(&self) -> Result<Option<String>> {
+        let mut command = if cfg!(target_os = "macos") {
+            let mut command = Command::new("security");
+            command.args([
+                "find-generic-password",
+                "-w",
+                "-s",
+                &self.service,
+                "-a",
+                &self.account,
+            ]);
+            command
+        } else if cfg!(windows) {
+            self.powershell(
+                "try { $credential = $vault.Retrieve($resource, $account) } catch { exit 44 }; \
+                 $credential.RetrievePassword(); $credential.Password",
+            )
+        } else {
+            let mut command = Command::new("secret-tool");
+            command.args(["lookup", "service", &self.service, "account", &self.account]);
+            command
+        };
+
+        let output = run_keyring_command(&mut command, None)?;
+        if output.status.success() {
+            Ok(parse_secret(&output.stdout))
+        } else if is_not_found(&output) {
+            Ok(None)
+        } else {
+            Err(keyring_error("read the token from", &output))
+        }
+    }
+
+    fn set_token(&self, token: &str) -> Result<()> {
+        let token = token.trim();
+        if token.is_empty() {
+            anyhow::bail!("Token must not be empty");
+        }
+
+        // The token is passed on stdin so it does not show up in the process
+        // list. `security` prompts for it when `-w` is the last option, and
+        // asks for it a second time to confirm it.
+        let (mut command, input) = if cfg!(target_os = "macos") {
+            let mut command = Command::new("security");
+            command.args([
+                "add-generic-password",
+                "-U",
+                "-s",
+                &self.service,
+                "-a",
+                &self.account,
+                "-w",
+            ]);
+            (command, format!("{}\n{}\n", token, token))
+        } else if cfg!(windows) {
+            (
+                self.powershell(
+                    "$token = [Console]::In.ReadToEnd().Trim(); \
+                     $vault.Add((New-Object Windows.Security.Credentials.PasswordCredential($resource, $account, $token)))",
+                ),
+                token.to_string(),
+            )
+        } else {
+            let mut command = Command::new("secret-tool");
+            command.args([
+                "store",
+                "--label=github-edit GitHub token",
+                "service",
+                &self.service,
+                "account",
+                &self.account,
+            ]);
+            (command, token.to_string())
+        };
+
+        let output = run_keyring_command(&mut command, Some(&input))?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(keyring_error("store the token in", &output))
+        }
+    }
+
+    fn delete_token(&self) -> Result<bool> {
+        // `secret-tool clear` succeeds whether or not a token was stored
+        if !cfg!(target_os = "macos") && !cfg!(windows) && self.get_token()?.is_none() {
+            return Ok(false);
+        }
+
+        let mut command = if cfg!(target_os = "macos") {
+            let mut command = Command::new("security");
+            command.args([
+                "delete-generic-password",
+                "-s",
+                &self.service,
+                "-a",
+                &self.account,
+            ]);
+            command
+        } else if cfg!(windows) {
+            self.powershell(
+                "try { $credential = $vault.Retrieve($resource, $account) } catch { exit 44 }; \
+                 $vault.Remove($credential)",
+            )
+        } else {
+            let mut command = Command::new("secret-tool");
+            command.args(["clear", "service", &self.service, "account", &self.account]);
+            command
+        };
+
+        let output = run_keyring_command(&mut command, None)?;
+        if output.status.success() {
+            Ok(true)
+        } else if is_not_found(&output) {
+            Ok(false)
+        } else {
+            Err(keyring_error("delete the token from", &output))
+        }
+    }
+}
+
+/// Extract a token from command output or a variable value
+///
+/// Surrounding whitespace is removed; blank values count as no token.
+pub fn parse_secret(output: &[u8]) -> Option<String> {
+    let secret = String::from_utf8_lossy(output).trim().to_string();
+    (!secret.is_empty()).then_some(secret)
+}
+
+fn run_keyring_command(command: &mut Command, input: Option<&str>) -> Result<Output> {
+    let program = command.get_program().to_string_lossy().to_string();
+    let mut child = command
+        .stdin(if input.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => anyhow::anyhow!(
+                "'{}' was not found. It is required to access the OS keyring{}",
+                program,
+                if program == "secret-tool" {
+                    " (install libsecret-tools or libsecret)"
+                } else {
+                    ""
+                }
+            ),
+            _ => anyhow::anyhow!("Failed to run '{}': {}", program, e),
+        })?;
+
+    if let (Some(input), Some(mut stdin)) = (input, child.stdin.take()) {
+        stdin
+            .write_all(input.as_bytes())
+            .with_context(|| format!("Failed to pass the token to '{}'", program))?;
+    }
+
+    child
+        .wait_with_output()
+        .with_context(|| format!("Failed to run '{}'", program))
+}
+
+fn is_not_found(output: &Output) -> bool {
+    // `secret-tool lookup` exits with 1 and prints nothing when no item matches
+    match output.status.code() {
+        Some(NOT_FOUND_EXIT_CODE) => true,
+        Some(1) => !cfg!(target_os = "macos") && !cfg!(windows) && output.stderr.is_empty(),
+        _ => false,
+    }
+}
+
+fn keyring_error(action: &str, output: &Output) -> anyhow::Error {
+    anyhow::anyhow!(
+        "Failed to {} the OS keyring: {}",
+        action,
+        String::from_utf8_lossy(&output.stderr).trim()
+    )
+}
@@ -0,0 +1,67 @@
+//! Token provider reusing the authentication of the GitHub CLI
+//!
+//! Users who already ran `gh auth login` do not need a separate token:
+//! `gh auth token` prints the token gh stores for a host, whether it keeps
+//! it in its own configuration file or in the OS keyring.
+
+use std::process::{Command, Stdio};
+
+use super::credential_store::parse_secret;
+
+/// Host gh is asked for a token of unless another host is configured
+pub const GH_DEFAULT_HOSTNAME: &str = "github.com";
+
+/// Reads the token of a host from the `gh` CLI
+pub struct GhCliTokenProvider {
+    hostname: String,
+}
+
+impl Default for GhCliTokenProvider {
+    fn default() -> Self {
+        Self::new(GH_DEFAULT_HOSTNAME)
+    }
+}
+
+impl GhCliTokenProvider {
+    /// Create a provider for the token gh stores for `hostname`
+    pub fn new(hostname: impl Into<String>) -> Self {
+        Self {
+            hostname: hostname.into(),
+        }
+    }
+
+    /// Returns the host the token is read for
+    pub fn hostname(&self) -> &str {
+        &self.hostname
+    }
+
+    /// Read the token from gh
+    ///
+    /// This is a fallback, so a missing gh installation, a host gh is not
+    /// logged in to and gh versions without `gh auth token` (before 2.17)
+    /// all result in `None` rather than an error.
+    pub fn get_token(&self) -> Option<String> {
+        let output = match Command::new("gh")
+            .args(["auth", "token", "--hostname", &self.hostname])
+            .stdin(Stdio::null())
+            .output()
+        {
+            Ok(output) => output,
+            Err(e) => {
+                tracing::debug!("GitHub CLI is not available: {}", e);
+                return None;
+            }
+        };
+
+        if output.status.success() {
+            parse_secret(&output.stdout)
+        } else {
+            tracing::debug!(
+                "GitHub CLI has no token for {}: {}",
+                self.hostname,
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+            None
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! GitHub token resolution
+//!
+//! The token is taken from the `GITHUB_EDIT_GITHUB_TOKEN` environment
+//! variable when it is set, then from `GITHUB_TOKEN` inside GitHub Actions,
+//! otherwise from the GitHub CLI when it is installed and logged in, and
+//! finally from the credential store selected in the `[auth]` section of the
+//! configuration file. A token written in the selected profile takes
+//! precedence over all of them, and the keyring entry and GitHub CLI host
+//! follow the profile.
+
+pub mod actions;
+pub mod credential_store;
+pub mod gh;
+
+pub use actions::*;
+pub use credential_store::*;
+pub use gh::*;
+
+use anyhow::Result;
+use std::collections::BTreeMap;
+
+use crate::config::{AuthConfig, CredentialStoreKind, SelectedProfile};
+
+/// Returns the keyring account the token of a profile is stored under
+pub fn keyring_account(profile_name: Option<&str>) -> String {
+    match profile_name {
+        Some(name) => format!("{}-{}", KEYRING_DEFAULT_ACCOUNT, name),
+        None => KEYRING_DEFAULT_ACCOUNT.to_string(),
+    }
+}
+
+/// Where the GitHub token in use was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenSource {
+    /// Given on the command line
+    CommandLine,
+    /// The `token` of the selected profile
+    Profile,
+    /// The `GITHUB_EDIT_GITHUB_TOKEN` environment variable
+    Env,
+    /// The `GITHUB_TOKEN` environment variable of a GitHub Actions workflow
+    GitHubActions,
+    /// The GitHub CLI
+    GhCli,
+    /// The OS keyring
+    Keyring,
+}
+
+impl std::fmt::Display for TokenSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenSource::CommandLine => write!(f, "the command line"),
+            TokenSource::Profile => write!(f, "the selected profile"),
+            TokenSource::Env => write!(f, "{}", TOKEN_ENV_VAR),
+            TokenSource::GitHubActions => {
+                write!(f, "{} (GitHub Actions)", ACTIONS_TOKEN_ENV_VAR)
+            }
+            TokenSource::GhCli => write!(f, "the GitHub CLI"),
+            TokenSource::Keyring => write!(f, "the OS keyring"),
+        }
+    }
+}
+
+/// A resolved GitHub token and where it was found
+#[derive(Clone)]
+pub struct ResolvedToken {
+    pub token: String,
+    pub source: TokenSource,
+}
+
+impl ResolvedToken {
+    pub fn new(token: String, source: TokenSource) -> Self {
+        Self { token, source }
+    }
+}
+
+/// Resolve the GitHub token for the given authentication settings and profile
+///
+/// The sources are tried in this order:
+/// 1. The `token` of the selected profile
+/// 2. The `GITHUB_EDIT_GITHUB_TOKEN` environment variable
+/// 3. The `GITHUB_TOKEN` environment variable inside GitHub Actions
+/// 4. The GitHub CLI, unless disabled
+/// 5. The OS keyring, if selected as the credential store
+///
+/// The source used is logged.
+///
+/// # Returns
+/// The token and its source, or `None` if no source provides one
+///
+/// # Errors
+/// Returns an error if the configured store cannot be read
+pub fn resolve_token(
+    config: &AuthConfig,
+    profile: Option<&SelectedProfile>,
+) -> Result<Option<ResolvedToken>> {
+    let resolved = find_token(config, profile)?;
+    match &resolved {
+        Some(resolved) => tracing::info!("Using the GitHub token from {}", resolved.source),
+        None => tracing::info!("No GitHub token found"),
+    }
+    Ok(resolved)
+}
+
+fn find_token(
+    config: &AuthConfig,
+    profile: Option<&SelectedProfile>,
+) -> Result<Option<ResolvedToken>> {
+    if let Some(token) = profile.and_then(|selected| selected.profile.token.clone()) {
+        return Ok(Some(ResolvedToken::new(token, TokenSource::Profile)));
+    }
+    if let Some(token) = EnvCredentialStore.get_token()? {
+        return Ok(Some(ResolvedToken::new(token, TokenSource::Env)));
+    }
+    if let Some(token) = actions_token() {
+        return Ok(Some(ResolvedToken::new(token, TokenSource::GitHubActions)));
+    }
+    if config.gh_cli {
+        let provider = match profile {
+            Some(selected) => GhCliTokenProvider::new(selected.profile.hostname()),
+            None => GhCliTokenProvider::default(),
+        };
+        if let Some(token) = provider.get_token() {
+            return Ok(Some(ResolvedToken::new(token, TokenSource::GhCli)));
+        }
+    }
+    match config.credential_store {
+        CredentialStoreKind::Env => Ok(None),
+        CredentialStoreKind::Keyring => {
+            let account = keyring_account(profile.map(|selected| selected.name.as_str()));
+            Ok(KeyringCredentialStore::new(account)
+                .get_token()?
+                .map(|token| ResolvedToken::new(token, TokenSource::Keyring)))
+        }
+    }
+}
+
+/// Returns the tokens by repository owner for the given settings and profile
+///
+/// A selected profile only uses its own `owner_tokens`, so tokens meant for
+/// one host are never sent to another.
+pub fn resolve_owner_tokens(
+    config: &AuthConfig,
+    profile: Option<&SelectedProfile>,
+) -> BTreeMap<String, String> {
+    match profile {
+        Some(selected) => selected.profile.owner_tokens.clone(),
+        None => config.owner_tokens.clone(),
+    }
+}
@@ -0,0 +1,678 @@
+//! GitHub Actions CLI commands and execution logic
+//!
+//! This module contains the CLI command definitions and execution logic
+//! for dispatching workflows, listing, cancelling and re-running workflow
+//! runs, downloading their logs and artifacts, and reviewing their
+//! deployments to protected environments.
+
+use super::input::resolve_item_url;
+use super::output::{OutputFormat, next_page_line};
+use super::pagination::PaginationArgs;
+use anyhow::Result;
+use clap::Subcommand;
+use github_edit::github::GitHubClient;
+use github_edit::tools::functions::actions;
+use github_edit::types::actions::{
+    Artifact, ArtifactId, Deployment, DeploymentReviewState, PendingDeployment, WorkflowJob,
+    WorkflowLogFile, WorkflowRun, WorkflowRunFilter, WorkflowRunId, WorkflowRunLogs,
+    parse_workflow_inputs, select_job_logs,
+};
+use github_edit::types::pagination::Page;
+use github_edit::types::pull_request::PullRequestNumber;
+use github_edit::types::repository::{RepositoryId, RepositoryUrl};
+use serde::Serialize;
+use std::fmt;
+use std::path::PathBuf;
+
+#[derive(Subcommand)]
+pub enum ActionsAction {
+    /// Trigger a workflow_dispatch event for a workflow
+    ///
+    /// Examples:
+    ///   github-edit-cli actions dispatch -r https://github.com/owner/repo -w ci.yml --ref main
+    ///   github-edit-cli actions dispatch -r https://github.com/owner/repo -w deploy.yml --ref v1.0.0 -i environment=staging -i dry_run=true
+    Dispatch {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Workflow file name (e.g. "ci.yml") or numeric workflow ID
+        #[arg(short, long, value_name = "WORKFLOW")]
+        workflow: String,
+        /// Branch or tag to run the workflow on
+        #[arg(long = "ref", value_name = "REF")]
+        git_ref: String,
+        /// Workflow input as key=value (repeatable)
+        #[arg(short, long = "input", value_name = "KEY=VALUE")]
+        inputs: Vec<String>,
+    },
+    /// List workflow runs, most recent first
+    ///
+    /// Examples:
+    ///   github-edit-cli actions list-runs -r https://github.com/owner/repo
+    ///   github-edit-cli actions list-runs -r https://github.com/owner/repo -w ci.yml -b main --status failure
+    ListRuns {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Workflow file name or numeric workflow ID to list runs of
+        #[arg(short, long, value_name = "WORKFLOW")]
+        workflow: Option<String>,
+        /// Branch the runs were triggered for
+        #[arg(short, long, value_name = "BRANCH")]
+        branch: Option<String>,
+        /// Event that triggered the runs (e.g. push, pull_request, workflow_dispatch)
+        #[arg(short, long, value_name = "EVENT")]
+        event: Option<String>,
+        /// Run status or conclusion (e.g. in_progress, completed, failure)
+        #[arg(short, long, value_name = "STATUS")]
+        status: Option<String>,
+        #[command(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// Cancel a queued or in-progress workflow run
+    ///
+    /// Examples:
+    ///   github-edit-cli actions cancel-run -r https://github.com/owner/repo --run-id 1234567890
+    CancelRun {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Workflow run ID
+        #[arg(long, value_name = "RUN_ID")]
+        run_id: u64,
+    },
+    /// Re-run all jobs of a completed workflow run
+    ///
+    /// Examples:
+    ///   github-edit-cli actions rerun-run -r https://github.com/owner/repo --run-id 1234567890
+    RerunRun {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Workflow run ID
+        #[arg(long, value_name = "RUN_ID")]
+        run_id: u64,
+    },
+    /// Approve a workflow run that waits for maintainer approval
+    ///
+    /// Examples:
+    ///   github-edit-cli actions approve-run -r https://github.com/owner/repo --run-id 1234567890
+    ApproveRun {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Workflow run ID
+        #[arg(long, value_name = "RUN_ID")]
+        run_id: u64,
+    },
+    /// Approve all workflow runs of a pull request that wait for maintainer approval
+    ///
+    /// Examples:
+    ///   github-edit-cli actions approve-pr-runs -r https://github.com/owner/repo -p 123
+    ApprovePrRuns {
+        /// Repository URL (HTTPS format), or the URL of the pull request itself
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Pull request number (optional when --repository-url is the pull request URL)
+        #[arg(short, long, value_name = "NUMBER")]
+        pr_number: Option<u32>,
+    },
+    /// Re-run only the failed jobs of a completed workflow run
+    ///
+    /// Examples:
+    ///   github-edit-cli actions rerun-failed-jobs -r https://github.com/owner/repo --run-id 1234567890
+    RerunFailedJobs {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Workflow run ID
+        #[arg(long, value_name = "RUN_ID")]
+        run_id: u64,
+    },
+    /// List the jobs of a workflow run with their conclusions and failed steps
+    ///
+    /// Examples:
+    ///   github-edit-cli actions list-jobs -r https://github.com/owner/repo --run-id 1234567890
+    ///   github-edit-cli actions list-jobs -r https://github.com/owner/repo --run-id 1234567890 --all-attempts
+    ListJobs {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Workflow run ID
+        #[arg(long, value_name = "RUN_ID")]
+        run_id: u64,
+        /// Include jobs of earlier run attempts
+        #[arg(long)]
+        all_attempts: bool,
+        #[command(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// List the deployments of a workflow run waiting for review on protected environments
+    ///
+    /// Examples:
+    ///   github-edit-cli actions pending-deployments -r https://github.com/owner/repo --run-id 1234567890
+    PendingDeployments {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Workflow run ID
+        #[arg(long, value_name = "RUN_ID")]
+        run_id: u64,
+    },
+    /// Approve or reject deployments of a workflow run waiting on protected environments
+    ///
+    /// Examples:
+    ///   github-edit-cli actions review-deployments -r https://github.com/owner/repo --run-id 1234567890 --environment-ids 161088068 -s approved -c "Ship it"
+    ///   github-edit-cli actions review-deployments -r https://github.com/owner/repo --run-id 1234567890 --environment-ids 161088068,161088069 -s rejected
+    ReviewDeployments {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Workflow run ID
+        #[arg(long, value_name = "RUN_ID")]
+        run_id: u64,
+        /// Comma-separated IDs of the environments to review
+        #[arg(
+            long,
+            value_name = "ENVIRONMENT_IDS",
+            value_delimiter = ',',
+            required = true
+        )]
+        environment_ids: Vec<u64>,
+        /// Review decision
+        #[arg(short, long, value_enum)]
+        state: DeploymentReviewState,
+        /// Comment recorded with the review
+        #[arg(short, long, value_name = "COMMENT", default_value = "")]
+        comment: String,
+    },
+    /// Print the logs of a workflow run, or save them as a zip archive
+    ///
+    /// Examples:
+    ///   github-edit-cli actions logs -r https://github.com/owner/repo --run-id 1234567890
+    ///   github-edit-cli actions logs -r https://github.com/owner/repo --run-id 1234567890 --tail 50
+    ///   github-edit-cli actions logs -r https://github.com/owner/repo --run-id 1234567890 --output-file logs.zip
+    Logs {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Workflow run ID
+        #[arg(long, value_name = "RUN_ID")]
+        run_id: u64,
+        /// Save the zip archive of all logs to this path instead of printing them
+        #[arg(short = 'o', long, value_name = "PATH")]
+        output_file: Option<PathBuf>,
+        /// Print only the last lines of each job log
+        #[arg(long, value_name = "LINES", conflicts_with = "output_file")]
+        tail: Option<usize>,
+    },
+    /// List artifacts of a repository or of a single workflow run
+    ///
+    /// Examples:
+    ///   github-edit-cli actions list-artifacts -r https://github.com/owner/repo
+    ///   github-edit-cli actions list-artifacts -r https://github.com/owner/repo --run-id 1234567890
+    ListArtifacts {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Workflow run ID to list the artifacts of
+        #[arg(long, value_name = "RUN_ID")]
+        run_id: Option<u64>,
+        #[command(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// Download an artifact as <artifact name>.zip
+    ///
+    /// Examples:
+    ///   github-edit-cli actions download-artifact -r https://github.com/owner/repo --artifact-id 987654321
+    ///   github-edit-cli actions download-artifact -r https://github.com/owner/repo --artifact-id 987654321 -o ./artifacts
+    DownloadArtifact {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Artifact ID
+        #[arg(long, value_name = "ARTIFACT_ID")]
+        artifact_id: u64,
+        /// Directory to save the artifact in
+        #[arg(short, long, value_name = "DIR", default_value = ".")]
+        output_dir: PathBuf,
+    },
+}
+
+pub async fn execute_actions_action(
+    github_client: &GitHubClient,
+    action: ActionsAction,
+    output: OutputFormat,
+) -> Result<()> {
+    let result = match action {
+        ActionsAction::Dispatch {
+            repository_url,
+            workflow,
+            git_ref,
+            inputs,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+            let inputs = parse_workflow_inputs(&inputs).map_err(|e| anyhow::anyhow!(e))?;
+
+            actions::dispatch_workflow(github_client, &repo_id, &workflow, &git_ref, &inputs)
+                .await?;
+
+            ActionsResult::WorkflowDispatched { workflow, git_ref }
+        }
+        ActionsAction::ListRuns {
+            repository_url,
+            workflow,
+            branch,
+            event,
+            status,
+            pagination,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+            let filter = WorkflowRunFilter {
+                workflow,
+                branch,
+                event,
+                status,
+                head_sha: None,
+            };
+
+            let runs = pagination
+                .fetch(|pagination| {
+                    actions::list_workflow_runs(github_client, &repo_id, &filter, pagination)
+                })
+                .await?;
+
+            ActionsResult::WorkflowRuns(runs)
+        }
+        ActionsAction::CancelRun {
+            repository_url,
+            run_id,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            actions::cancel_workflow_run(github_client, &repo_id, WorkflowRunId::new(run_id))
+                .await?;
+
+            ActionsResult::RunCancellationRequested { run_id }
+        }
+        ActionsAction::RerunRun {
+            repository_url,
+            run_id,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            actions::rerun_workflow_run(github_client, &repo_id, WorkflowRunId::new(run_id))
+                .await?;
+
+            ActionsResult::RunRerunRequested { run_id }
+        }
+        ActionsAction::ApproveRun {
+            repository_url,
+            run_id,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            actions::approve_workflow_run(github_client, &repo_id, WorkflowRunId::new(run_id))
+                .await?;
+
+            ActionsResult::RunApproved { run_id }
+        }
+        ActionsAction::ApprovePrRuns {
+            repository_url,
+            pr_number,
+        } => {
+            let (repo_id, pr_number) = resolve_item_url(&repository_url, pr_number)?;
+
+            let runs = actions::approve_pending_runs_for_pr(
+                github_client,
+                &repo_id,
+                PullRequestNumber::new(pr_number),
+            )
+            .await?;
+
+            ActionsResult::PullRequestRunsApproved { pr_number, runs }
+        }
+        ActionsAction::RerunFailedJobs {
+            repository_url,
+            run_id,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            actions::rerun_failed_jobs(github_client, &repo_id, WorkflowRunId::new(run_id)).await?;
+
+            ActionsResult::FailedJobsRerunRequested { run_id }
+        }
+        ActionsAction::ListJobs {
+            repository_url,
+            run_id,
+            all_attempts,
+            pagination,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let jobs = pagination
+                .fetch(|pagination| {
+                    actions::list_jobs_for_run(
+                        github_client,
+                        &repo_id,
+                        WorkflowRunId::new(run_id),
+                        all_attempts,
+                        pagination,
+                    )
+                })
+                .await?;
+
+            ActionsResult::Jobs(jobs)
+        }
+        ActionsAction::PendingDeployments {
+            repository_url,
+            run_id,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let deployments = actions::list_pending_deployments(
+                github_client,
+                &repo_id,
+                WorkflowRunId::new(run_id),
+            )
+            .await?;
+
+            ActionsResult::PendingDeployments { deployments }
+        }
+        ActionsAction::ReviewDeployments {
+            repository_url,
+            run_id,
+            environment_ids,
+            state,
+            comment,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let deployments = actions::review_pending_deployments(
+                github_client,
+                &repo_id,
+                WorkflowRunId::new(run_id),
+                &environment_ids,
+                state,
+                &comment,
+            )
+            .await?;
+
+            ActionsResult::DeploymentsReviewed {
+                run_id,
+                state,
+                deployments,
+            }
+        }
+        ActionsAction::Logs {
+            repository_url,
+            run_id,
+            output_file,
+            tail,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            match actions::get_workflow_run_logs(
+                github_client,
+                &repo_id,
+                WorkflowRunId::new(run_id),
+                output_file.as_deref(),
+            )
+            .await?
+            {
+                WorkflowRunLogs::Saved(path) => ActionsResult::LogsSaved { run_id, path },
+                WorkflowRunLogs::Extracted(files) => {
+                    let logs = select_job_logs(&files)
+                        .into_iter()
+                        .map(|file| WorkflowLogFile {
+                            name: file.name.clone(),
+                            content: match tail {
+                                Some(lines) => file.tail(lines).to_string(),
+                                None => file.content.trim_end().to_string(),
+                            },
+                        })
+                        .collect();
+                    ActionsResult::Logs { run_id, logs }
+                }
+            }
+        }
+        ActionsAction::ListArtifacts {
+            repository_url,
+            run_id,
+            pagination,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let artifacts = pagination
+                .fetch(|pagination| {
+                    actions::list_artifacts(
+                        github_client,
+                        &repo_id,
+                        run_id.map(WorkflowRunId::new),
+                        pagination,
+                    )
+                })
+                .await?;
+
+            ActionsResult::Artifacts(artifacts)
+        }
+        ActionsAction::DownloadArtifact {
+            repository_url,
+            artifact_id,
+            output_dir,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let path = actions::download_artifact(
+                github_client,
+                &repo_id,
+                ArtifactId::new(artifact_id),
+                &output_dir,
+            )
+            .await?;
+
+            ActionsResult::ArtifactSaved { artifact_id, path }
+        }
+    };
+
+    output.print(&result)
+}
+
+/// Result of a GitHub Actions command
+#[derive(Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+enum ActionsResult {
+    WorkflowDispatched {
+        workflow: String,
+        git_ref: String,
+    },
+    WorkflowRuns(Page<WorkflowRun>),
+    RunCancellationRequested {
+        run_id: u64,
+    },
+    RunRerunRequested {
+        run_id: u64,
+    },
+    RunApproved {
+        run_id: u64,
+    },
+    PullRequestRunsApproved {
+        pr_number: u32,
+        runs: Vec<WorkflowRun>,
+    },
+    FailedJobsRerunRequested {
+        run_id: u64,
+    },
+    Jobs(Page<WorkflowJob>),
+    PendingDeployments {
+        deployments: Vec<PendingDeployment>,
+    },
+    DeploymentsReviewed {
+        run_id: u64,
+        state: DeploymentReviewState,
+        deployments: Vec<Deployment>,
+    },
+    LogsSaved {
+        run_id: u64,
+        path: PathBuf,
+    },
+    Logs {
+        run_id: u64,
+        logs: Vec<WorkflowLogFile>,
+    },
+    Artifacts(Page<Artifact>),
+    ArtifactSaved {
+        artifact_id: u64,
+        path: PathBuf,
+    },
+}
+
+impl fmt::Display for ActionsResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActionsResult::WorkflowDispatched { workflow, git_ref } => {
+                writeln!(f, "Dispatched workflow {} on {}", workflow, git_ref)
+            }
+            ActionsResult::WorkflowRuns(runs) => {
+                for run in &runs.items {
+                    writeln!(
+                        f,
+                        "{}\t{}\t{}\t{}\t{}\t{}",
+                        run.id,
+                        run.name.as_deref().unwrap_or_default(),
+                        run.status.as_deref().unwrap_or_default(),
+                        run.conclusion.as_deref().unwrap_or_default(),
+                        run.head_branch.as_deref().unwrap_or_default(),
+                        run.html_url
+                    )?;
+                }
+                next_page_line(f, "runs", runs.page, runs.has_next_page)
+            }
+            ActionsResult::RunCancellationRequested { run_id } => {
+                writeln!(f, "Requested cancellation of workflow run {}", run_id)
+            }
+            ActionsResult::RunRerunRequested { run_id } => {
+                writeln!(f, "Requested re-run of workflow run {}", run_id)
+            }
+            ActionsResult::RunApproved { run_id } => {
+                writeln!(f, "Approved workflow run {}", run_id)
+            }
+            ActionsResult::PullRequestRunsApproved { pr_number, runs } => {
+                for run in runs {
+                    writeln!(
+                        f,
+                        "Approved workflow run {}\t{}",
+                        run.id,
+                        run.name.as_deref().unwrap_or_default()
+                    )?;
+                }
+                if runs.is_empty() {
+                    writeln!(
+                        f,
+                        "No workflow runs of pull request #{} are waiting for approval",
+                        pr_number
+                    )?;
+                }
+                Ok(())
+            }
+            ActionsResult::FailedJobsRerunRequested { run_id } => writeln!(
+                f,
+                "Requested re-run of the failed jobs of workflow run {}",
+                run_id
+            ),
+            ActionsResult::Jobs(jobs) => {
+                for job in &jobs.items {
+                    writeln!(
+                        f,
+                        "{}\t{}\t{}\t{}",
+                        job.id,
+                        job.name,
+                        job.status,
+                        job.conclusion
+                            .map(|conclusion| conclusion.to_string())
+                            .unwrap_or_default()
+                    )?;
+                    for step in job.failed_steps() {
+                        writeln!(f, "\tstep {} failed: {}", step.number, step.name)?;
+                    }
+                }
+                next_page_line(f, "jobs", jobs.page, jobs.has_next_page)
+            }
+            ActionsResult::PendingDeployments { deployments } => {
+                for deployment in deployments {
+                    let reviewers: Vec<&str> = deployment
+                        .reviewers
+                        .iter()
+                        .filter_map(|reviewer| reviewer.name())
+                        .collect();
+                    writeln!(
+                        f,
+                        "{}\t{}\t{}\t{}",
+                        deployment.environment.id,
+                        deployment.environment.name,
+                        reviewers.join(","),
+                        if deployment.current_user_can_approve {
+                            "can approve"
+                        } else {
+                            ""
+                        }
+                    )?;
+                }
+                Ok(())
+            }
+            ActionsResult::DeploymentsReviewed {
+                run_id,
+                state,
+                deployments,
+            } => {
+                writeln!(
+                    f,
+                    "Marked deployments of workflow run {} as {}",
+                    run_id, state
+                )?;
+                for deployment in deployments {
+                    writeln!(
+                        f,
+                        "{}\t{}\t{}",
+                        deployment.id, deployment.environment, deployment.git_ref
+                    )?;
+                }
+                Ok(())
+            }
+            ActionsResult::LogsSaved { run_id, path } => writeln!(
+                f,
+                "Saved logs of workflow run {} to {}",
+                run_id,
+                path.display()
+            ),
+            ActionsResult::Logs { logs, .. } => {
+                for file in logs {
+                    writeln!(f, "== {} ==", file.name)?;
+                    writeln!(f, "{}", file.content)?;
+                }
+                Ok(())
+            }
+            ActionsResult::Artifacts(artifacts) => {
+                for artifact in &artifacts.items {
+                    writeln!(
+                        f,
+                        "{}\t{}\t{}\t{}",
+                        artifact.id,
+                        artifact.name,
+                        artifact.size_in_bytes,
+                        if artifact.expired { "expired" } else { "" }
+                    )?;
+                }
+                next_page_line(f, "artifacts", artifacts.page, artifacts.has_next_page)
+            }
+            ActionsResult::ArtifactSaved { artifact_id, path } => {
+                writeln!(f, "Saved artifact {} to {}", artifact_id, path.display())
+            }
+        }
+    }
+}
+
+fn parse_repository_url(repository_url: String) -> Result<RepositoryId> {
+    RepositoryId::parse_url(&RepositoryUrl::new(repository_url))
+        .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))
+}
@@ -0,0 +1,144 @@
+//! Plan file CLI command and execution logic
+//!
+//! This module contains the CLI command definition and execution logic for
+//! applying the operations of a JSON or CSV plan file in bulk, e.g. when
+//! migrating issues from another tracker.
+
+use super::output::OutputFormat;
+use anyhow::Result;
+use clap::Args;
+use github_edit::github::GitHubClient;
+use github_edit::github::dry_run::is_dry_run;
+use github_edit::tools::functions::issue::DEFAULT_BULK_CONCURRENCY;
+use github_edit::tools::functions::plan;
+use github_edit::types::plan::{PlanFormat, PlanOperationResult, PlanOperationStatus, PlanReport};
+use serde::Serialize;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+#[derive(Args)]
+pub struct ApplyArgs {
+    /// Plan file listing the operations to apply
+    ///
+    /// A JSON plan is an array of operations, each naming its kind in
+    /// "operation": create_issue (repository_url, title, body, labels,
+    /// assignees, milestone), add_labels (issue_url, labels) or
+    /// set_milestone (issue_url, milestone). A CSV plan has a header row
+    /// with the same fields, comma-separated lists and empty cells for the
+    /// fields a row leaves out.
+    #[arg(short, long, value_name = "PATH")]
+    file: PathBuf,
+    /// Format of the plan file (default: csv for .csv files, json otherwise)
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    format: Option<PlanFormat>,
+    /// Maximum number of operations run at once
+    #[arg(long, value_name = "COUNT", default_value_t = DEFAULT_BULK_CONCURRENCY)]
+    concurrency: usize,
+    /// Skip the operations not started yet once one fails
+    #[arg(long)]
+    stop_on_error: bool,
+}
+
+pub async fn execute_apply(
+    github_client: &GitHubClient,
+    args: ApplyArgs,
+    output: OutputFormat,
+) -> Result<()> {
+    let format = args.format.unwrap_or_else(|| plan_format(&args.file));
+    let content = std::fs::read_to_string(&args.file)
+        .map_err(|e| anyhow::anyhow!("Failed to read plan file {}: {}", args.file.display(), e))?;
+    let operations = plan::parse_plan(&content, format)?;
+    if operations.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Plan file {} has no operations",
+            args.file.display()
+        ));
+    }
+
+    // Progress goes to stderr, keeping stdout to the report
+    let total = operations.len();
+    let mut done = 0;
+    let report = plan::apply_plan(
+        github_client,
+        operations,
+        args.concurrency,
+        args.stop_on_error,
+        |result| {
+            done += 1;
+            eprintln!("[{}/{}] {}", done, total, ResultLine(result));
+        },
+    )
+    .await;
+
+    // A dry run reports the planned requests instead
+    if is_dry_run() {
+        return Ok(());
+    }
+    let failed = report.failed;
+    output.print(&ApplyResult::Applied(report))?;
+    if failed > 0 {
+        return Err(anyhow::anyhow!(
+            "{} operation(s) of the plan failed",
+            failed
+        ));
+    }
+    Ok(())
+}
+
+/// Format of a plan file named by its extension
+fn plan_format(path: &Path) -> PlanFormat {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some(extension) if extension.eq_ignore_ascii_case("csv") => PlanFormat::Csv,
+        _ => PlanFormat::Json,
+    }
+}
+
+/// Result of the apply command
+#[derive(Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+enum ApplyResult {
+    Applied(PlanReport),
+}
+
+impl fmt::Display for ApplyResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApplyResult::Applied(report) => {
+                writeln!(
+                    f,
+                    "Applied {} operation(s): {} succeeded, {} failed, {} skipped",
+                    report.results.len(),
+                    report.succeeded,
+                    report.failed,
+                    report.skipped
+                )?;
+                for result in &report.results {
+                    writeln!(f, "{}. {}", result.row, ResultLine(result))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// One line of text for the result of an operation
+struct ResultLine<'a>(&'a PlanOperationResult);
+
+impl fmt::Display for ResultLine<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = self.0;
+        let status = match result.status {
+            PlanOperationStatus::Succeeded => "succeeded",
+            PlanOperationStatus::Failed => "failed",
+            PlanOperationStatus::Skipped => "skipped",
+        };
+        write!(f, "{} {}: {}", result.operation, result.target, status)?;
+        if let Some(url) = &result.url {
+            write!(f, " {}", url)?;
+        }
+        if let Some(message) = &result.message {
+            write!(f, " - {}", message)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+//! Audit log CLI commands and execution logic
+//!
+//! This module contains the CLI command definitions and execution logic
+//! for querying the audit log, and the recording of the CLI commands that
+//! write to GitHub.
+
+use super::output::OutputFormat;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use clap::{ArgMatches, Subcommand, parser::ValueSource};
+use github_edit::audit::{AuditLog, AuditQuery, authenticated_login, redact_parameters};
+use github_edit::config::Config;
+use github_edit::github::GitHubClient;
+use github_edit::types::audit::{AuditEntry, AuditRequest, AuditSource};
+use serde::Serialize;
+use std::fmt;
+use std::path::PathBuf;
+
+/// Client name of the audit entries of the CLI
+const CLI_CLIENT_NAME: &str = "github-edit-cli";
+
+#[derive(Subcommand)]
+pub enum AuditAction {
+    /// List the recorded operations that wrote to GitHub, oldest first
+    ///
+    /// Examples:
+    ///   github-edit-cli audit list
+    ///   github-edit-cli audit list --tool add_labels_to_issue --since 2024-06-01T00:00:00Z
+    ///   github-edit-cli audit list --failed --json
+    List {
+        /// Only operations of this MCP tool or CLI command (e.g. "issue create")
+        #[arg(long, value_name = "TOOL")]
+        tool: Option<String>,
+        /// Only operations of this GitHub login
+        #[arg(long, value_name = "LOGIN")]
+        actor: Option<String>,
+        /// Only operations at or after this time (RFC 3339, e.g. 2024-01-01T00:00:00Z)
+        #[arg(long, value_name = "TIMESTAMP")]
+        since: Option<DateTime<Utc>>,
+        /// Only failed operations
+        #[arg(long)]
+        failed: bool,
+        /// Show at most this many of the newest operations
+        #[arg(long, value_name = "COUNT", default_value_t = 50)]
+        limit: usize,
+        /// Print each operation as a JSON line, as stored in the log, whatever the --output format
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+pub async fn execute_audit_action(
+    config: &Config,
+    action: AuditAction,
+    output: OutputFormat,
+) -> Result<()> {
+    let Some(log) = AuditLog::from_config(&config.audit)? else {
+        anyhow::bail!("The audit log is disabled in the [audit] section of the configuration");
+    };
+
+    match action {
+        AuditAction::List {
+            tool,
+            actor,
+            since,
+            failed,
+            limit,
+            json,
+        } => {
+            let query = AuditQuery {
+                tool,
+                actor,
+                since,
+                failed_only: failed,
+                limit: Some(limit),
+            };
+            let entries = log.read(&query)?;
+
+            if json {
+                for entry in &entries {
+                    println!("{}", serde_json::to_string(entry)?);
+                }
+                return Ok(());
+            }
+            output.print(&AuditResult::Operations {
+                log: log.path().to_path_buf(),
+                entries,
+            })
+        }
+    }
+}
+
+/// Result of an audit log command
+#[derive(Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+enum AuditResult {
+    Operations {
+        log: PathBuf,
+        entries: Vec<AuditEntry>,
+    },
+}
+
+impl fmt::Display for AuditResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuditResult::Operations { log, entries } => {
+                if entries.is_empty() {
+                    return writeln!(f, "No operations recorded in {}", log.display());
+                }
+                for entry in entries {
+                    writeln!(
+                        f,
+                        "{}\t{}\t{}\t{}\t{}",
+                        entry.timestamp.to_rfc3339(),
+                        entry.source,
+                        entry.actor.as_deref().unwrap_or("-"),
+                        entry.tool,
+                        if entry.success { "succeeded" } else { "failed" }
+                    )?;
+                    if let Some(error) = &entry.error {
+                        writeln!(f, "  Error: {}", error)?;
+                    }
+                    for request in &entry.requests {
+                        writeln!(f, "  {}", describe_request(request))?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Returns the CLI command of the parsed arguments, e.g. `issue create`, and
+/// the arguments given to it
+pub fn command_parameters(matches: &ArgMatches) -> (String, serde_json::Value) {
+    let mut names = Vec::new();
+    let mut matches = matches;
+    while let Some((name, sub_matches)) = matches.subcommand() {
+        names.push(name);
+        matches = sub_matches;
+    }
+
+    let mut parameters = serde_json::Map::new();
+    for id in matches.ids() {
+        if matches.value_source(id.as_str()) == Some(ValueSource::DefaultValue) {
+            continue;
+        }
+        let Ok(Some(values)) = matches.try_get_raw(id.as_str()) else {
+            continue;
+        };
+        let mut values: Vec<serde_json::Value> = values
+            .map(|value| serde_json::Value::String(value.to_string_lossy().into_owned()))
+            .collect();
+        let value = if values.len() == 1 {
+            values.remove(0)
+        } else {
+            serde_json::Value::Array(values)
+        };
+        parameters.insert(id.to_string(), value);
+    }
+    (names.join(" "), serde_json::Value::Object(parameters))
+}
+
+/// Append a CLI command to the audit log if it sent a write request
+///
+/// Failing to write the log is reported as a warning, not as a failure of
+/// the command.
+pub async fn record_command(
+    log: &AuditLog,
+    github_client: &GitHubClient,
+    command: &str,
+    parameters: serde_json::Value,
+    result: &Result<()>,
+    requests: &[AuditRequest],
+) {
+    if requests.is_empty() {
+        return;
+    }
+    let entry = AuditEntry {
+        timestamp: Utc::now(),
+        source: AuditSource::Cli,
+        actor: authenticated_login(github_client).await,
+        client: Some(CLI_CLIENT_NAME.to_string()),
+        tool: command.to_string(),
+        parameters: redact_parameters(command, parameters),
+        success: result.is_ok(),
+        error: result.as_ref().err().map(|e| format!("{:#}", e)),
+        trace_id: None,
+        requests: requests.to_vec(),
+        undo: None,
+        undoes: None,
+    };
+    if let Err(e) = log.append(&entry) {
+        tracing::warn!("Failed to record {} in the audit log: {:#}", command, e);
+    }
+}
+
+fn describe_request(request: &AuditRequest) -> String {
+    let mut text = format!("{} {}", request.method, request.url);
+    if let Some(mutation) = &request.mutation {
+        text.push_str(&format!(" ({})", mutation));
+    }
+    match (&request.status, &request.error) {
+        (Some(status), _) => text.push_str(&format!(" -> {}", status)),
+        (None, Some(error)) => text.push_str(&format!(" -> {}", error)),
+        (None, None) => {}
+    }
+    text
+}
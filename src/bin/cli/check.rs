@@ -0,0 +1,198 @@
+//! Check run and commit status CLI commands and execution logic
+//!
+//! This module contains the CLI command definitions and execution logic
+//! for reading check runs and combined commit statuses and for reporting
+//! commit statuses.
+
+use super::output::{OutputFormat, next_page_line};
+use super::pagination::PaginationArgs;
+use anyhow::Result;
+use clap::Subcommand;
+use github_edit::github::GitHubClient;
+use github_edit::tools::functions::check;
+use github_edit::types::check::{CheckRun, CombinedStatus, CommitState, CommitStatus};
+use github_edit::types::pagination::Page;
+use github_edit::types::repository::{RepositoryId, RepositoryUrl};
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Subcommand)]
+pub enum CheckAction {
+    /// List check runs for a commit SHA, branch or tag
+    ///
+    /// Examples:
+    ///   github-edit-cli checks list-runs -r https://github.com/owner/repo --ref main
+    ///   github-edit-cli checks list-runs -r https://github.com/owner/repo --ref 6dcb09b5b57875f334f61aebed695e2e4193db5e -n build
+    ListRuns {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Commit SHA, branch name or tag name
+        #[arg(long = "ref", value_name = "REF")]
+        git_ref: String,
+        /// Check run name to filter by
+        #[arg(short = 'n', long, value_name = "NAME")]
+        check_name: Option<String>,
+        #[command(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// Show the combined commit status for a commit SHA, branch or tag
+    ///
+    /// Examples:
+    ///   github-edit-cli checks status -r https://github.com/owner/repo --ref main
+    Status {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Commit SHA, branch name or tag name
+        #[arg(long = "ref", value_name = "REF")]
+        git_ref: String,
+    },
+    /// Create a commit status
+    ///
+    /// Examples:
+    ///   github-edit-cli checks create-status -r https://github.com/owner/repo --sha 6dcb09b5b57875f334f61aebed695e2e4193db5e -s success -c ci/lint
+    ///   github-edit-cli checks create-status -r https://github.com/owner/repo --sha 6dcb09b5b57875f334f61aebed695e2e4193db5e -s failure -c ci/lint -d "3 warnings" --target-url https://ci.example.com/builds/42
+    CreateStatus {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Commit SHA to attach the status to
+        #[arg(long, value_name = "SHA")]
+        sha: String,
+        /// Status state
+        #[arg(short, long, value_enum)]
+        state: CommitState,
+        /// Label identifying the status (defaults to "default")
+        #[arg(short, long, value_name = "CONTEXT")]
+        context: Option<String>,
+        /// Short description of the status
+        #[arg(short, long, value_name = "DESCRIPTION")]
+        description: Option<String>,
+        /// URL with details of the status
+        #[arg(long, value_name = "URL")]
+        target_url: Option<String>,
+    },
+}
+
+pub async fn execute_check_action(
+    github_client: &GitHubClient,
+    action: CheckAction,
+    output: OutputFormat,
+) -> Result<()> {
+    let result = match action {
+        CheckAction::ListRuns {
+            repository_url,
+            git_ref,
+            check_name,
+            pagination,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let check_runs = pagination
+                .fetch(|pagination| {
+                    check::list_check_runs_for_ref(
+                        github_client,
+                        &repo_id,
+                        &git_ref,
+                        check_name.as_deref(),
+                        pagination,
+                    )
+                })
+                .await?;
+
+            CheckResult::CheckRuns(check_runs)
+        }
+        CheckAction::Status {
+            repository_url,
+            git_ref,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let combined = check::get_combined_status(github_client, &repo_id, &git_ref).await?;
+
+            CheckResult::CombinedStatus(combined)
+        }
+        CheckAction::CreateStatus {
+            repository_url,
+            sha,
+            state,
+            context,
+            description,
+            target_url,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let status = check::create_commit_status(
+                github_client,
+                &repo_id,
+                &sha,
+                state,
+                context.as_deref(),
+                description.as_deref(),
+                target_url.as_deref(),
+            )
+            .await?;
+
+            CheckResult::StatusCreated { sha, status }
+        }
+    };
+
+    output.print(&result)
+}
+
+/// Result of a check command
+#[derive(Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+enum CheckResult {
+    CheckRuns(Page<CheckRun>),
+    CombinedStatus(CombinedStatus),
+    StatusCreated { sha: String, status: CommitStatus },
+}
+
+impl fmt::Display for CheckResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckResult::CheckRuns(check_runs) => {
+                for check_run in &check_runs.items {
+                    writeln!(
+                        f,
+                        "{}\t{}\t{}\t{}",
+                        check_run.name,
+                        check_run.status,
+                        check_run
+                            .conclusion
+                            .map(|conclusion| conclusion.to_string())
+                            .unwrap_or_default(),
+                        check_run.html_url.as_deref().unwrap_or_default()
+                    )?;
+                }
+                next_page_line(f, "check runs", check_runs.page, check_runs.has_next_page)
+            }
+            CheckResult::CombinedStatus(combined) => {
+                writeln!(f, "{}\t{}", combined.sha, combined.state)?;
+                for status in &combined.statuses {
+                    writeln!(
+                        f,
+                        "{}\t{}\t{}\t{}",
+                        status.context,
+                        status.state,
+                        status.description.as_deref().unwrap_or_default(),
+                        status.target_url.as_deref().unwrap_or_default()
+                    )?;
+                }
+                Ok(())
+            }
+            CheckResult::StatusCreated { sha, status } => writeln!(
+                f,
+                "Created {} status '{}' on {} (status ID: {})",
+                status.state, status.context, sha, status.id
+            ),
+        }
+    }
+}
+
+fn parse_repository_url(repository_url: String) -> Result<RepositoryId> {
+    RepositoryId::parse_url(&RepositoryUrl::new(repository_url))
+        .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))
+}
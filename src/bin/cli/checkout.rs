@@ -0,0 +1,215 @@
+//! Checking out pull requests
+//!
+//! `pull-request checkout` reads the head branch of a pull request and plans
+//! the git commands that fetch it into a local branch tracking it. Branches
+//! of forks are fetched from a remote of the fork, which is added when the
+//! local repository has none; branches of deleted forks are fetched from the
+//! `pull/<number>/head` ref of the base repository. The commands are printed,
+//! or run in the current directory.
+
+use anyhow::{Context, Result};
+use github_edit::types::pull_request::PullRequestHead;
+use std::process::Command;
+
+/// Remotes and branches of the local git repository
+#[derive(Debug, Default)]
+pub struct LocalRepository {
+    /// Names and fetch URLs of the remotes
+    pub remotes: Vec<(String, String)>,
+    /// Names of the local branches
+    pub branches: Vec<String>,
+}
+
+impl LocalRepository {
+    /// Read the repository of the current directory, `None` outside a git repository
+    pub fn read() -> Result<Option<Self>> {
+        if git(&["rev-parse", "--git-dir"]).is_err() {
+            return Ok(None);
+        }
+
+        let remotes = git(&["remote", "-v"])?
+            .lines()
+            .filter_map(|line| {
+                let (name, rest) = line.split_once('\t')?;
+                let url = rest.strip_suffix(" (fetch)")?;
+                Some((name.to_string(), url.to_string()))
+            })
+            .collect();
+        let branches = git(&["for-each-ref", "--format=%(refname:short)", "refs/heads"])?
+            .lines()
+            .map(|branch| branch.to_string())
+            .collect();
+        Ok(Some(Self { remotes, branches }))
+    }
+
+    fn has_branch(&self, branch: &str) -> bool {
+        self.branches.iter().any(|name| name == branch)
+    }
+
+    fn remote_url(&self, name: &str) -> Option<&str> {
+        self.remotes
+            .iter()
+            .find(|(remote, _)| remote == name)
+            .map(|(_, url)| url.as_str())
+    }
+
+    /// Returns the name of the remote fetching from one of `urls`
+    fn remote_for(&self, urls: &[&str]) -> Option<&str> {
+        self.remotes
+            .iter()
+            .find(|(_, url)| {
+                urls.iter()
+                    .any(|candidate| same_repository_url(url, candidate))
+            })
+            .map(|(name, _)| name.as_str())
+    }
+}
+
+/// Plan the git commands checking out the head of pull request `number` as
+/// `branch`
+///
+/// # Arguments
+/// * `base_remote` - Remote of the base repository of the pull request
+/// * `local` - State of the local repository, to reuse its remotes and branches
+pub fn checkout_commands(
+    head: &PullRequestHead,
+    number: u32,
+    base_remote: &str,
+    branch: &str,
+    local: &LocalRepository,
+) -> Vec<Vec<String>> {
+    let command = |args: &[&str]| -> Vec<String> {
+        std::iter::once("git")
+            .chain(args.iter().copied())
+            .map(|arg| arg.to_string())
+            .collect()
+    };
+    let mut commands = Vec::new();
+
+    let remote = match (head.is_fork, head.clone_url.as_deref()) {
+        (false, _) => base_remote.to_string(),
+        (true, Some(clone_url)) => {
+            let urls: Vec<&str> = [Some(clone_url), head.ssh_url.as_deref()]
+                .into_iter()
+                .flatten()
+                .collect();
+            match local.remote_for(&urls) {
+                Some(remote) => remote.to_string(),
+                None => {
+                    let owner = head.owner().unwrap_or("fork");
+                    let remote = if local.remote_url(owner).is_some() {
+                        format!("{}-fork", owner)
+                    } else {
+                        owner.to_string()
+                    };
+                    // Fetch forks the way the base repository is fetched
+                    let uses_ssh = local.remote_url(base_remote).is_some_and(is_ssh_url);
+                    let url = match (uses_ssh, head.ssh_url.as_deref()) {
+                        (true, Some(ssh_url)) => ssh_url,
+                        _ => clone_url,
+                    };
+                    commands.push(command(&["remote", "add", &remote, url]));
+                    remote
+                }
+            }
+        }
+        (true, None) => {
+            // The fork was deleted; GitHub keeps the head in the base repository
+            let pull_ref = format!("pull/{}/head", number);
+            if local.has_branch(branch) {
+                commands.push(command(&["fetch", base_remote, &pull_ref]));
+                commands.push(command(&["switch", branch]));
+                commands.push(command(&["merge", "--ff-only", "FETCH_HEAD"]));
+            } else {
+                let refspec = format!("{}:{}", pull_ref, branch);
+                commands.push(command(&["fetch", base_remote, &refspec]));
+                commands.push(command(&["switch", branch]));
+            }
+            return commands;
+        }
+    };
+
+    let tracked = format!("{}/{}", remote, head.branch);
+    commands.push(command(&["fetch", &remote, &head.branch]));
+    if local.has_branch(branch) {
+        commands.push(command(&["switch", branch]));
+        commands.push(command(&["merge", "--ff-only", &tracked]));
+    } else {
+        commands.push(command(&["switch", "-c", branch, "--track", &tracked]));
+    }
+    commands
+}
+
+/// Run the planned commands in order, stopping at the first failure
+pub fn run_commands(commands: &[Vec<String>]) -> Result<()> {
+    for command in commands {
+        let output = Command::new(&command[0])
+            .args(&command[1..])
+            .output()
+            .with_context(|| format!("Failed to run {}", command_line(command)))?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "{} failed: {}",
+                command_line(command),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Returns the command as a line to paste in a shell
+pub fn command_line(command: &[String]) -> String {
+    command
+        .iter()
+        .map(|arg| shell_quote(arg))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn shell_quote(arg: &str) -> String {
+    let is_plain = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:@+=,%".contains(c));
+    if is_plain {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
+
+/// Run git with `args` in the current directory, returning its output
+fn git(args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn is_ssh_url(url: &str) -> bool {
+    url.starts_with("ssh://") || (url.contains('@') && !url.contains("://"))
+}
+
+/// Returns true if both URLs name the same repository, whatever the protocol
+fn same_repository_url(a: &str, b: &str) -> bool {
+    fn normalize(url: &str) -> String {
+        let url = url
+            .trim_end_matches('/')
+            .trim_end_matches(".git")
+            .to_lowercase();
+        let url = url.split_once("://").map_or(url.as_str(), |(_, rest)| rest);
+        // user@host:path and user@host/path
+        let url = url.split_once('@').map_or(url, |(_, rest)| rest);
+        url.replacen(':', "/", 1)
+    }
+    normalize(a) == normalize(b)
+}
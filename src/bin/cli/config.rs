@@ -0,0 +1,156 @@
+//! Configuration CLI commands and execution logic
+//!
+//! This module contains the CLI command definitions and execution logic for
+//! reading and changing the settings of the configuration file by dotted
+//! key, e.g. `defaults.repository` or `retry.max_retries`.
+
+use super::output::OutputFormat;
+use super::profile::parse_output_format;
+use anyhow::Result;
+use clap::Subcommand;
+use github_edit::config::Config;
+use serde::Serialize;
+use std::fmt;
+use std::path::PathBuf;
+
+#[derive(Subcommand)]
+pub enum ConfigAction {
+    /// Print a setting, or the whole configuration without a key
+    ///
+    /// Prints nothing if the setting is not set.
+    ///
+    /// Examples:
+    ///   github-edit-cli config get defaults.repository
+    ///   github-edit-cli config get profiles.work
+    ///   github-edit-cli config get
+    Get {
+        /// Dotted key of the setting
+        #[arg(value_name = "KEY")]
+        key: Option<String>,
+    },
+    /// Change a setting, creating the configuration file if needed
+    ///
+    /// Values are read as booleans or numbers where the setting takes one.
+    ///
+    /// Examples:
+    ///   github-edit-cli config set defaults.repository owner/repo
+    ///   github-edit-cli config set defaults.output json
+    ///   github-edit-cli config set retry.max_retries 5
+    ///   github-edit-cli config set auth.credential_store keyring
+    Set {
+        /// Dotted key of the setting
+        #[arg(value_name = "KEY")]
+        key: String,
+        /// New value of the setting
+        #[arg(value_name = "VALUE")]
+        value: String,
+    },
+    /// Remove a setting, restoring its default
+    ///
+    /// Examples:
+    ///   github-edit-cli config unset defaults.output
+    Unset {
+        /// Dotted key of the setting
+        #[arg(value_name = "KEY")]
+        key: String,
+    },
+    /// Print the path of the configuration file
+    ///
+    /// Examples:
+    ///   github-edit-cli config path
+    Path,
+}
+
+pub fn execute_config_action(action: ConfigAction, output: OutputFormat) -> Result<()> {
+    let result = match action {
+        ConfigAction::Get { key: None } => ConfigResult::Config {
+            config: Config::load()?,
+        },
+        ConfigAction::Get { key: Some(key) } => {
+            let value = Config::load()?.get_value(&key)?;
+            ConfigResult::Value { key, value }
+        }
+        ConfigAction::Set { key, value } => {
+            let mut config = Config::load()?;
+            config.set_value(&key, &value)?;
+            if let Some(output) = &config.defaults.output {
+                parse_output_format(output)?;
+            }
+            config.save()?;
+            let value = config.get_value(&key)?;
+            ConfigResult::Set { key, value }
+        }
+        ConfigAction::Unset { key } => {
+            let mut config = Config::load()?;
+            let removed = config.unset_value(&key)?;
+            if removed {
+                config.save()?;
+            }
+            ConfigResult::Unset { key, removed }
+        }
+        ConfigAction::Path => ConfigResult::Path {
+            path: Config::default_path()?,
+        },
+    };
+    output.print(&result)
+}
+
+/// Result of a configuration command
+#[derive(Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+enum ConfigResult {
+    Config {
+        config: Config,
+    },
+    Value {
+        key: String,
+        value: Option<toml::Value>,
+    },
+    Set {
+        key: String,
+        value: Option<toml::Value>,
+    },
+    Unset {
+        key: String,
+        removed: bool,
+    },
+    Path {
+        path: PathBuf,
+    },
+}
+
+impl fmt::Display for ConfigResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigResult::Config { config } => {
+                write!(
+                    f,
+                    "{}",
+                    toml::to_string_pretty(config).map_err(|_| fmt::Error)?
+                )
+            }
+            ConfigResult::Value { value: None, .. } => Ok(()),
+            ConfigResult::Value {
+                value: Some(toml::Value::String(value)),
+                ..
+            } => writeln!(f, "{}", value),
+            ConfigResult::Value {
+                value: Some(toml::Value::Table(table)),
+                ..
+            } => write!(f, "{}", table),
+            ConfigResult::Value {
+                value: Some(value), ..
+            } => writeln!(f, "{}", value),
+            ConfigResult::Set { key, value } => match value {
+                Some(value) => writeln!(f, "Set {} = {}", key, value),
+                None => writeln!(f, "Set {}", key),
+            },
+            ConfigResult::Unset { key, removed: true } => writeln!(f, "Removed {}", key),
+            ConfigResult::Unset {
+                key,
+                removed: false,
+            } => writeln!(f, "{} is not set", key),
+            ConfigResult::Path { path } => writeln!(f, "{}", path.display()),
+        }
+    }
+}
@@ -0,0 +1,426 @@
+//! GitHub Discussions CLI commands and execution logic
+//!
+//! This module contains the CLI command definitions and execution logic
+//! for listing discussion categories, creating and reading discussions,
+//! commenting on them, marking answers and moderating discussions.
+
+use super::input::{read_body, require_body};
+use super::output::OutputFormat;
+use anyhow::Result;
+use clap::Subcommand;
+use github_edit::github::GitHubClient;
+use github_edit::tools::functions::discussion;
+use github_edit::types::discussion::{DiscussionCategory, DiscussionNumber, LockReason};
+use github_edit::types::repository::{RepositoryId, RepositoryUrl};
+use serde::Serialize;
+use std::fmt;
+use std::path::PathBuf;
+
+#[derive(Subcommand)]
+pub enum DiscussionAction {
+    /// List the discussion categories of a repository
+    ///
+    /// Examples:
+    ///   github-edit-cli discussion categories -r https://github.com/owner/repo
+    Categories {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+    },
+    /// Get a discussion with its comments and replies as JSON
+    ///
+    /// Examples:
+    ///   github-edit-cli discussion get -r https://github.com/owner/repo -d 42
+    Get {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Discussion number
+        #[arg(short, long, value_name = "NUMBER")]
+        discussion: u32,
+    },
+    /// Create a discussion
+    ///
+    /// Examples:
+    ///   github-edit-cli discussion create -r https://github.com/owner/repo -c "Q&A" -t "How do I configure X?" -b "Details..."
+    Create {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Category name, slug or node ID
+        #[arg(short, long, value_name = "CATEGORY")]
+        category: String,
+        /// Discussion title
+        #[arg(short, long, value_name = "TITLE")]
+        title: String,
+        /// Discussion body in Markdown
+        #[arg(
+            short,
+            long,
+            value_name = "BODY",
+            required_unless_present = "body_file"
+        )]
+        body: Option<String>,
+        /// Read the discussion body from a file instead ("-" reads stdin, as does --body -)
+        #[arg(long, value_name = "PATH", conflicts_with = "body")]
+        body_file: Option<PathBuf>,
+    },
+    /// Comment on a discussion, or reply to one of its comments
+    ///
+    /// Examples:
+    ///   github-edit-cli discussion comment -r https://github.com/owner/repo -d 42 -b "Try setting X"
+    ///   github-edit-cli discussion comment -r https://github.com/owner/repo -d 42 -b "Thanks!" --reply-to DC_kwDOA...
+    Comment {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Discussion number
+        #[arg(short, long, value_name = "NUMBER")]
+        discussion: u32,
+        /// Comment body in Markdown
+        #[arg(
+            short,
+            long,
+            value_name = "BODY",
+            required_unless_present = "body_file"
+        )]
+        body: Option<String>,
+        /// Read the comment body from a file instead ("-" reads stdin, as does --body -)
+        #[arg(long, value_name = "PATH", conflicts_with = "body")]
+        body_file: Option<PathBuf>,
+        /// Node ID of the top-level comment to reply to
+        #[arg(long, value_name = "COMMENT_ID")]
+        reply_to: Option<String>,
+    },
+    /// Mark a discussion comment as the answer
+    ///
+    /// Examples:
+    ///   github-edit-cli discussion mark-answer --comment-id DC_kwDOA...
+    MarkAnswer {
+        /// Node ID of the discussion comment
+        #[arg(long, value_name = "COMMENT_ID")]
+        comment_id: String,
+    },
+    /// Lock a discussion so only collaborators can comment on it
+    ///
+    /// Examples:
+    ///   github-edit-cli discussion lock -r https://github.com/owner/repo -d 42 --reason too_heated
+    Lock {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Discussion number
+        #[arg(short, long, value_name = "NUMBER")]
+        discussion: u32,
+        /// Reason recorded for the lock
+        #[arg(long, value_enum)]
+        reason: Option<LockReason>,
+    },
+    /// Unlock a previously locked discussion
+    ///
+    /// Examples:
+    ///   github-edit-cli discussion unlock -r https://github.com/owner/repo -d 42
+    Unlock {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Discussion number
+        #[arg(short, long, value_name = "NUMBER")]
+        discussion: u32,
+    },
+    /// Update the title, body or category of a discussion
+    ///
+    /// Examples:
+    ///   github-edit-cli discussion edit -r https://github.com/owner/repo -d 42 -t "Clearer title"
+    ///   github-edit-cli discussion edit -r https://github.com/owner/repo -d 42 -c "Ideas"
+    Edit {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Discussion number
+        #[arg(short, long, value_name = "NUMBER")]
+        discussion: u32,
+        /// New title
+        #[arg(short, long, value_name = "TITLE")]
+        title: Option<String>,
+        /// New body in Markdown
+        #[arg(short, long, value_name = "BODY")]
+        body: Option<String>,
+        /// Read the new body from a file instead ("-" reads stdin, as does --body -)
+        #[arg(long, value_name = "PATH", conflicts_with = "body")]
+        body_file: Option<PathBuf>,
+        /// New category name, slug or node ID
+        #[arg(short, long, value_name = "CATEGORY")]
+        category: Option<String>,
+    },
+    /// Delete a discussion comment or reply
+    ///
+    /// Examples:
+    ///   github-edit-cli discussion delete-comment --comment-id DC_kwDOA...
+    DeleteComment {
+        /// Node ID of the discussion comment
+        #[arg(long, value_name = "COMMENT_ID")]
+        comment_id: String,
+    },
+}
+
+pub async fn execute_discussion_action(
+    github_client: &GitHubClient,
+    action: DiscussionAction,
+    output: OutputFormat,
+) -> Result<()> {
+    let result = match action {
+        DiscussionAction::Categories { repository_url } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let categories =
+                discussion::list_discussion_categories(github_client, &repo_id).await?;
+
+            DiscussionResult::Categories { categories }
+        }
+        DiscussionAction::Get {
+            repository_url,
+            discussion: discussion_number,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let result = discussion::get_discussion(
+                github_client,
+                &repo_id,
+                DiscussionNumber::new(discussion_number),
+            )
+            .await?;
+
+            return output.print_data(&result);
+        }
+        DiscussionAction::Create {
+            repository_url,
+            category,
+            title,
+            body,
+            body_file,
+        } => {
+            let body = require_body(body, body_file)?;
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let created =
+                discussion::create_discussion(github_client, &repo_id, &category, &title, &body)
+                    .await?;
+
+            DiscussionResult::Created {
+                discussion: created.number,
+                url: created.url,
+            }
+        }
+        DiscussionAction::Comment {
+            repository_url,
+            discussion: discussion_number,
+            body,
+            body_file,
+            reply_to,
+        } => {
+            let body = require_body(body, body_file)?;
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let comment = discussion::add_discussion_comment(
+                github_client,
+                &repo_id,
+                DiscussionNumber::new(discussion_number),
+                &body,
+                reply_to.as_deref(),
+            )
+            .await?;
+
+            DiscussionResult::CommentAdded {
+                comment_id: comment.id,
+                url: comment.url,
+            }
+        }
+        DiscussionAction::MarkAnswer { comment_id } => {
+            let discussion_number =
+                discussion::mark_comment_as_answer(github_client, &comment_id).await?;
+
+            DiscussionResult::AnswerMarked {
+                comment_id,
+                discussion: discussion_number,
+            }
+        }
+        DiscussionAction::Lock {
+            repository_url,
+            discussion: discussion_number,
+            reason,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let locked = discussion::lock_discussion(
+                github_client,
+                &repo_id,
+                DiscussionNumber::new(discussion_number),
+                reason,
+            )
+            .await?;
+
+            if !locked {
+                anyhow::bail!(
+                    "Lock requested, but discussion #{} is not reported as locked",
+                    discussion_number
+                );
+            }
+            DiscussionResult::Locked {
+                discussion: discussion_number,
+            }
+        }
+        DiscussionAction::Unlock {
+            repository_url,
+            discussion: discussion_number,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let locked = discussion::unlock_discussion(
+                github_client,
+                &repo_id,
+                DiscussionNumber::new(discussion_number),
+            )
+            .await?;
+
+            if locked {
+                anyhow::bail!(
+                    "Unlock requested, but discussion #{} is still reported as locked",
+                    discussion_number
+                );
+            }
+            DiscussionResult::Unlocked {
+                discussion: discussion_number,
+            }
+        }
+        DiscussionAction::Edit {
+            repository_url,
+            discussion: discussion_number,
+            title,
+            body,
+            body_file,
+            category,
+        } => {
+            let body = read_body(body, body_file)?;
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let updated = discussion::update_discussion(
+                github_client,
+                &repo_id,
+                DiscussionNumber::new(discussion_number),
+                title.as_deref(),
+                body.as_deref(),
+                category.as_deref(),
+            )
+            .await?;
+
+            DiscussionResult::Updated {
+                discussion: updated.number,
+                category: updated.category.name,
+                title: updated.title,
+            }
+        }
+        DiscussionAction::DeleteComment { comment_id } => {
+            discussion::delete_discussion_comment(github_client, &comment_id).await?;
+
+            DiscussionResult::CommentDeleted { comment_id }
+        }
+    };
+    output.print(&result)
+}
+
+/// Result of a discussion command
+#[derive(Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+enum DiscussionResult {
+    Categories {
+        categories: Vec<DiscussionCategory>,
+    },
+    Created {
+        discussion: DiscussionNumber,
+        url: String,
+    },
+    CommentAdded {
+        comment_id: String,
+        url: String,
+    },
+    AnswerMarked {
+        comment_id: String,
+        discussion: DiscussionNumber,
+    },
+    Locked {
+        discussion: u32,
+    },
+    Unlocked {
+        discussion: u32,
+    },
+    Updated {
+        discussion: DiscussionNumber,
+        category: String,
+        title: String,
+    },
+    CommentDeleted {
+        comment_id: String,
+    },
+}
+
+impl fmt::Display for DiscussionResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiscussionResult::Categories { categories } => {
+                for category in categories {
+                    writeln!(
+                        f,
+                        "{}\t{}\t{}\t{}",
+                        category.id,
+                        category.slug,
+                        category.name,
+                        if category.is_answerable {
+                            "answerable"
+                        } else {
+                            ""
+                        }
+                    )?;
+                }
+                Ok(())
+            }
+            DiscussionResult::Created { discussion, url } => {
+                writeln!(f, "Created discussion #{}: {}", discussion, url)
+            }
+            DiscussionResult::CommentAdded { comment_id, url } => {
+                writeln!(f, "Added comment {}: {}", comment_id, url)
+            }
+            DiscussionResult::AnswerMarked {
+                comment_id,
+                discussion,
+            } => writeln!(
+                f,
+                "Marked comment {} as the answer of discussion #{}",
+                comment_id, discussion
+            ),
+            DiscussionResult::Locked { discussion } => {
+                writeln!(f, "Locked discussion #{}", discussion)
+            }
+            DiscussionResult::Unlocked { discussion } => {
+                writeln!(f, "Unlocked discussion #{}", discussion)
+            }
+            DiscussionResult::Updated {
+                discussion,
+                category,
+                title,
+            } => writeln!(
+                f,
+                "Updated discussion #{} ({}): {}",
+                discussion, category, title
+            ),
+            DiscussionResult::CommentDeleted { comment_id } => {
+                writeln!(f, "Deleted discussion comment {}", comment_id)
+            }
+        }
+    }
+}
+
+fn parse_repository_url(repository_url: String) -> Result<RepositoryId> {
+    RepositoryId::parse_url(&RepositoryUrl::new(repository_url))
+        .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))
+}
@@ -0,0 +1,73 @@
+//! Dry runs of the CLI commands
+//!
+//! With the global `--dry-run` option, a command resolves its inputs with
+//! the usual read requests but sends none of its writes, like an MCP tool
+//! called with `dry_run: true`. The write requests it would have sent are
+//! printed instead, with warnings about inputs that would not do what the
+//! caller expects.
+
+use super::audit::command_parameters;
+use super::output::OutputFormat;
+use anyhow::Result;
+use clap::ArgMatches;
+use github_edit::github::GitHubClient;
+use github_edit::tools::dry_run::{dry_run_operation, format_report};
+use github_edit::types::dry_run::DryRunReport;
+use serde::Serialize;
+use std::fmt;
+use std::future::Future;
+
+/// Run a command as a dry run and print the write requests it would send
+///
+/// A command failing before any write, e.g. on an issue that does not
+/// exist, fails the dry run with its error.
+pub async fn execute_dry_run<F>(
+    github_client: &GitHubClient,
+    matches: &ArgMatches,
+    command: F,
+    output: OutputFormat,
+) -> Result<()>
+where
+    F: Future<Output = Result<()>>,
+{
+    let (name, parameters) = command_parameters(matches);
+    let arguments = dry_run_arguments(parameters);
+    let errors = |result: &Result<()>| match result {
+        Ok(()) => Vec::new(),
+        Err(e) => vec![format!("{:#}", e)],
+    };
+    match dry_run_operation(github_client, &name, &arguments, command, errors).await {
+        Ok(report) => output.print(&DryRunResult { report }),
+        Err(result) => result,
+    }
+}
+
+/// Arguments of a command in the shape of the MCP tool arguments, with the
+/// comma-separated labels split into a list
+fn dry_run_arguments(parameters: serde_json::Value) -> serde_json::Map<String, serde_json::Value> {
+    let serde_json::Value::Object(mut arguments) = parameters else {
+        return serde_json::Map::new();
+    };
+    if let Some(serde_json::Value::String(labels)) = arguments.get("labels") {
+        let labels = labels
+            .split(',')
+            .map(|label| serde_json::Value::String(label.trim().to_string()))
+            .collect();
+        arguments.insert("labels".to_string(), serde_json::Value::Array(labels));
+    }
+    arguments
+}
+
+/// Result of a command run with `--dry-run`
+#[derive(Serialize)]
+#[serde(tag = "result", rename = "dry_run")]
+struct DryRunResult {
+    #[serde(flatten)]
+    report: DryRunReport,
+}
+
+impl fmt::Display for DryRunResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", format_report(&self.report))
+    }
+}
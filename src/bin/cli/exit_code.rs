@@ -0,0 +1,51 @@
+//! Exit codes of the CLI
+//!
+//! Failed commands exit with a code naming the kind of failure, so shell
+//! scripts and CI steps can branch on it:
+//!
+//! | Code | Failure |
+//! |------|---------|
+//! | 0 | none |
+//! | 1 | any other error |
+//! | 2 | invalid command-line arguments |
+//! | 3 | authentication failure: missing, invalid or expired token |
+//! | 4 | not found |
+//! | 5 | permission denied |
+//! | 6 | rate limited |
+//! | 7 | validation error: GitHub rejected the given values |
+//! | 8 | network or GitHub server error |
+
+use github_edit::github::error::ErrorKind;
+use std::process::ExitCode;
+
+/// Any error without a more specific code
+pub const EXIT_FAILURE: u8 = 1;
+// 2 is the exit code of clap for invalid command-line arguments
+pub const EXIT_AUTH: u8 = 3;
+pub const EXIT_NOT_FOUND: u8 = 4;
+pub const EXIT_PERMISSION_DENIED: u8 = 5;
+pub const EXIT_RATE_LIMITED: u8 = 6;
+pub const EXIT_VALIDATION: u8 = 7;
+pub const EXIT_NETWORK: u8 = 8;
+
+/// Returns the exit code of a failure of the given kind
+pub fn exit_code(kind: ErrorKind) -> u8 {
+    match kind {
+        ErrorKind::Auth => EXIT_AUTH,
+        ErrorKind::NotFound => EXIT_NOT_FOUND,
+        ErrorKind::PermissionDenied => EXIT_PERMISSION_DENIED,
+        ErrorKind::RateLimited => EXIT_RATE_LIMITED,
+        ErrorKind::Validation => EXIT_VALIDATION,
+        ErrorKind::Network => EXIT_NETWORK,
+        ErrorKind::Other => EXIT_FAILURE,
+    }
+}
+
+/// Print the error of a failed command and return its exit code
+///
+/// The error is printed with its causes, as `main` returning the error
+/// would print it.
+pub fn report_error(error: &anyhow::Error) -> ExitCode {
+    eprintln!("Error: {:?}", error);
+    ExitCode::from(exit_code(ErrorKind::of(error)))
+}
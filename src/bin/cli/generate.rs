@@ -0,0 +1,41 @@
+//! Shell completion and manual page generation commands
+//!
+//! Both are generated from the same clap command the CLI parses its
+//! arguments with, so they always cover every command and option.
+
+use anyhow::{Context, Result};
+use clap::Command;
+use clap_complete::Shell;
+use std::io::Write;
+use std::path::Path;
+
+/// Print the completion script of `shell` for `command`
+pub fn execute_completions(mut command: Command, shell: Shell) -> Result<()> {
+    // clap_complete panics on write errors, so the script is written to
+    // stdout here, where a closed pipe is an ordinary error
+    let name = command.get_name().to_string();
+    let mut script = Vec::new();
+    clap_complete::generate(shell, &mut command, name, &mut script);
+    std::io::stdout()
+        .write_all(&script)
+        .context("Failed to write the completion script")
+}
+
+/// Print the manual page of `command`, or write one page per command and
+/// subcommand to `out_dir`
+pub fn execute_man(command: Command, out_dir: Option<&Path>) -> Result<()> {
+    match out_dir {
+        Some(out_dir) => {
+            std::fs::create_dir_all(out_dir)
+                .with_context(|| format!("Failed to create {}", out_dir.display()))?;
+            clap_mangen::generate_to(command, out_dir).with_context(|| {
+                format!("Failed to write the manual pages to {}", out_dir.display())
+            })?;
+            eprintln!("Wrote the manual pages to {}", out_dir.display());
+        }
+        None => clap_mangen::Man::new(command)
+            .render(&mut std::io::stdout())
+            .context("Failed to write the manual page")?,
+    }
+    Ok(())
+}
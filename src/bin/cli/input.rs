@@ -0,0 +1,53 @@
+//! Input of the CLI commands
+//!
+//! Commands taking a Markdown body accept it as `--body <BODY>` or read it
+//! from a file with `--body-file <PATH>`. Passing `-` to either option reads
+//! the body from stdin, so multi-line Markdown with code blocks can be piped
+//! in without escaping it for the shell.
+//!
+//! Commands acting on one issue or pull request take `--repository-url` and
+//! the number of the item, or the URL of the item alone as `--repository-url`.
+
+use anyhow::{Context, Result};
+use github_edit::types::item_url::resolve_item_target;
+use github_edit::types::repository::RepositoryId;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Value of `--body` and `--body-file` reading the body from stdin
+const STDIN: &str = "-";
+
+/// Body given with `--body` or `--body-file`, if any
+pub fn read_body(body: Option<String>, body_file: Option<PathBuf>) -> Result<Option<String>> {
+    match (body, body_file) {
+        (Some(body), _) if body == STDIN => read_stdin().map(Some),
+        (Some(body), _) => Ok(Some(body)),
+        (None, Some(path)) if path.as_os_str() == STDIN => read_stdin().map(Some),
+        (None, Some(path)) => read_file(&path).map(Some),
+        (None, None) => Ok(None),
+    }
+}
+
+/// Body given with `--body` or `--body-file`, which one of them must be
+pub fn require_body(body: Option<String>, body_file: Option<PathBuf>) -> Result<String> {
+    read_body(body, body_file)?.ok_or_else(|| anyhow::anyhow!("--body or --body-file is required"))
+}
+
+/// Repository and number of the issue or pull request given with
+/// `--repository-url` and its number option
+pub fn resolve_item_url(repository_url: &str, number: Option<u32>) -> Result<(RepositoryId, u32)> {
+    resolve_item_target(repository_url, number).map_err(|e| anyhow::anyhow!(e))
+}
+
+fn read_stdin() -> Result<String> {
+    let mut body = String::new();
+    std::io::stdin()
+        .read_to_string(&mut body)
+        .context("Failed to read the body from stdin")?;
+    Ok(body)
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read the body from {}", path.display()))
+}
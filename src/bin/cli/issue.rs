@@ -4,13 +4,27 @@
 //! for issue management operations including creating, commenting, editing,
 //! and state management.
 
+use super::input::{read_body, require_body, resolve_item_url};
+use super::output::OutputFormat;
+use super::pagination::PaginationArgs;
+use super::template::TemplateArgs;
+use super::watch::{self, WatchArgs};
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use clap::Subcommand;
 use github_edit::github::GitHubClient;
-use github_edit::tools::functions::issue;
-use github_edit::types::issue::{IssueCommentNumber, IssueNumber, IssueState, IssueUrl};
+use github_edit::tools::functions::{issue, notification, repository};
+use github_edit::types::issue::{
+    IssueCommentNumber, IssueNumber, IssueState, IssueStateReason, IssueUrl,
+};
 use github_edit::types::label::Label;
+use github_edit::types::project::ProjectOriginalResource;
 use github_edit::types::repository::{MilestoneNumber, RepositoryId, RepositoryUrl};
+use github_edit::types::template::TemplateKind;
+use github_edit::types::timeline::CrossReference;
+use serde::Serialize;
+use std::fmt;
+use std::path::PathBuf;
 
 #[derive(Subcommand)]
 pub enum IssueAction {
@@ -29,11 +43,46 @@ pub enum IssueAction {
         #[arg(required = true, value_name = "URL")]
         urls: Vec<String>,
     },
+    /// List issues in a repository
+    ///
+    /// Pull requests are excluded from the results.
+    ///
+    /// Examples:
+    ///   github-edit-cli issue list -r https://github.com/owner/repo
+    ///   github-edit-cli issue list -r https://github.com/owner/repo -s open -l bug,critical --assignee none
+    ///   github-edit-cli issue list -r https://github.com/owner/repo --since 2024-01-01T00:00:00Z --limit 100 --page 2
+    List {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Only list issues in this state (lists open and closed issues when omitted)
+        ///
+        /// Valid values:
+        ///   open   - Issue is active and needs attention
+        ///   closed - Issue has been resolved or dismissed
+        #[arg(short, long, value_name = "STATE")]
+        state: Option<IssueState>,
+        /// Comma-separated list of label names every issue must have
+        #[arg(short, long, value_name = "LABELS")]
+        labels: Option<String>,
+        /// Assignee username ("none" for unassigned issues, "*" for any assignee)
+        #[arg(short, long, value_name = "USER")]
+        assignee: Option<String>,
+        /// Milestone number the issues must belong to
+        #[arg(short, long, value_name = "MILESTONE_ID")]
+        milestone_number: Option<u64>,
+        /// Only issues updated at or after this time (RFC 3339, e.g. 2024-01-01T00:00:00Z)
+        #[arg(long, value_name = "TIMESTAMP")]
+        since: Option<DateTime<Utc>>,
+        #[command(flatten)]
+        pagination: PaginationArgs,
+    },
     /// Create a new issue
     ///
     /// Examples:
     ///   github-edit-cli issue create -r https://github.com/owner/repo -t "Bug: Application crashes on startup" -b "When I run the app..."
     ///   github-edit-cli issue create --repository-url https://github.com/rust-lang/rust --title "Feature Request: New async trait" --body "It would be great to have..."
+    ///   github-edit-cli issue create -r https://github.com/owner/repo -t "Bug: Login fails" --template bug --var version=1.2.0
     Create {
         /// Repository URL (HTTPS format)
         ///
@@ -60,6 +109,11 @@ pub enum IssueAction {
         ///   "I would like to propose adding a new feature that..."
         #[arg(short, long, value_name = "BODY")]
         body: Option<String>,
+        /// Read the issue body from a file instead ("-" reads stdin, as does --body -)
+        #[arg(long, value_name = "PATH", conflicts_with = "body")]
+        body_file: Option<PathBuf>,
+        #[command(flatten)]
+        template: TemplateArgs,
     },
     /// Add a comment to an existing issue
     ///
@@ -67,7 +121,7 @@ pub enum IssueAction {
     ///   github-edit-cli issue comment -r https://github.com/owner/repo -i 123 -b "I can confirm this bug"
     ///   github-edit-cli issue comment --repository-url https://github.com/rust-lang/rust --issue 98765 --body "Here's a potential fix..."
     Comment {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the issue itself
         ///
         /// Examples:
         ///   https://github.com/owner/repo
@@ -75,14 +129,14 @@ pub enum IssueAction {
         ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Issue number (numeric ID from the URL)
+        /// Issue number (numeric ID from the URL; optional when --repository-url is the issue URL)
         ///
         /// Examples:
         ///   123 (from https://github.com/owner/repo/issues/123)
         ///   98765 (from https://github.com/rust-lang/rust/issues/98765)
         ///   142857 (from https://github.com/microsoft/vscode/issues/142857)
         #[arg(short, long, value_name = "NUMBER")]
-        issue: u32,
+        issue: Option<u32>,
         /// Comment body (supports Markdown formatting)
         ///
         /// Examples:
@@ -90,8 +144,16 @@ pub enum IssueAction {
         ///   "Here's a potential fix: ```rust\nfn solution() {...}\n```"
         ///   "This is related to issue #456"
         ///   "## Analysis\nAfter investigating, I found that..."
-        #[arg(short, long, value_name = "BODY")]
-        body: String,
+        #[arg(
+            short,
+            long,
+            value_name = "BODY",
+            required_unless_present = "body_file"
+        )]
+        body: Option<String>,
+        /// Read the comment body from a file instead ("-" reads stdin, as does --body -)
+        #[arg(long, value_name = "PATH", conflicts_with = "body")]
+        body_file: Option<PathBuf>,
     },
     /// Edit the title of an existing issue
     ///
@@ -99,7 +161,7 @@ pub enum IssueAction {
     ///   github-edit-cli issue edit-title -r https://github.com/owner/repo -i 123 -t "Updated: Bug found in authentication module"
     ///   github-edit-cli issue edit-title --repository-url https://github.com/rust-lang/rust --issue 98765 --title "[WIP] Feature: New async trait implementation"
     EditTitle {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the issue itself
         ///
         /// Examples:
         ///   https://github.com/owner/repo
@@ -107,14 +169,14 @@ pub enum IssueAction {
         ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Issue number (numeric ID from the URL)
+        /// Issue number (numeric ID from the URL; optional when --repository-url is the issue URL)
         ///
         /// Examples:
         ///   123 (from https://github.com/owner/repo/issues/123)
         ///   98765 (from https://github.com/rust-lang/rust/issues/98765)
         ///   142857 (from https://github.com/microsoft/vscode/issues/142857)
         #[arg(short, long, value_name = "NUMBER")]
-        issue: u32,
+        issue: Option<u32>,
         /// New title for the issue
         ///
         /// Examples:
@@ -130,7 +192,7 @@ pub enum IssueAction {
     ///   github-edit-cli issue edit-body -r https://github.com/owner/repo -i 123 -b "Updated description with more details..."
     ///   github-edit-cli issue edit-body --repository-url https://github.com/rust-lang/rust --issue 98765 --body "## Updated Analysis\nAfter further investigation..."
     EditBody {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the issue itself
         ///
         /// Examples:
         ///   https://github.com/owner/repo
@@ -138,30 +200,39 @@ pub enum IssueAction {
         ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Issue number (numeric ID from the URL)
+        /// Issue number (numeric ID from the URL; optional when --repository-url is the issue URL)
         ///
         /// Examples:
         ///   123 (from https://github.com/owner/repo/issues/123)
         ///   98765 (from https://github.com/rust-lang/rust/issues/98765)
         ///   142857 (from https://github.com/microsoft/vscode/issues/142857)
         #[arg(short, long, value_name = "NUMBER")]
-        issue: u32,
+        issue: Option<u32>,
         /// New body content (supports Markdown formatting)
         ///
         /// Examples:
         ///   "Updated description with reproduction steps..."
         ///   "## Problem\nThe issue occurs when...\n## Solution\nWe can fix this by..."
         ///   "After further investigation, I found that the root cause is..."
-        #[arg(short, long, value_name = "BODY")]
-        body: String,
+        #[arg(
+            short,
+            long,
+            value_name = "BODY",
+            required_unless_present = "body_file"
+        )]
+        body: Option<String>,
+        /// Read the new body from a file instead ("-" reads stdin, as does --body -)
+        #[arg(long, value_name = "PATH", conflicts_with = "body")]
+        body_file: Option<PathBuf>,
     },
     /// Update the state of an issue (open/closed)
     ///
     /// Examples:
     ///   github-edit-cli issue update-state -r https://github.com/owner/repo -i 123 -s closed
     ///   github-edit-cli issue update-state --repository-url https://github.com/rust-lang/rust --issue 98765 --state open
+    ///   github-edit-cli issue update-state -r https://github.com/owner/repo -i 123 -s closed --reason not_planned
     UpdateState {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the issue itself
         ///
         /// Examples:
         ///   https://github.com/owner/repo
@@ -169,14 +240,14 @@ pub enum IssueAction {
         ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Issue number (numeric ID from the URL)
+        /// Issue number (numeric ID from the URL; optional when --repository-url is the issue URL)
         ///
         /// Examples:
         ///   123 (from https://github.com/owner/repo/issues/123)
         ///   98765 (from https://github.com/rust-lang/rust/issues/98765)
         ///   142857 (from https://github.com/microsoft/vscode/issues/142857)
         #[arg(short, long, value_name = "NUMBER")]
-        issue: u32,
+        issue: Option<u32>,
         /// New state for the issue
         ///
         /// Valid values:
@@ -184,6 +255,14 @@ pub enum IssueAction {
         ///   closed - Issue has been resolved or dismissed
         #[arg(short, long, value_name = "STATE")]
         state: IssueState,
+        /// Reason for the state change
+        ///
+        /// Valid values:
+        ///   completed   - Closed because the work is done (requires --state closed)
+        ///   not_planned - Closed because it will not be worked on (requires --state closed)
+        ///   reopened    - Reopened (requires --state open)
+        #[arg(long, value_name = "REASON")]
+        reason: Option<IssueStateReason>,
     },
     /// Edit an existing comment on an issue
     ///
@@ -191,18 +270,26 @@ pub enum IssueAction {
     ///   github-edit-cli issue edit-comment -r https://github.com/owner/repo -i 123 -c 456 -b "Updated comment text"
     ///   github-edit-cli issue edit-comment --repository-url https://github.com/rust-lang/rust --issue 98765 --comment 789 --body "Here's the corrected information..."
     EditComment {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the issue itself
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Issue number (numeric ID from the URL)
+        /// Issue number (numeric ID from the URL; optional when --repository-url is the issue URL)
         #[arg(short, long, value_name = "NUMBER")]
-        issue: u32,
+        issue: Option<u32>,
         /// Comment number to edit
         #[arg(short, long, value_name = "NUMBER")]
         comment: u32,
         /// New comment body (supports Markdown formatting)
-        #[arg(short, long, value_name = "BODY")]
-        body: String,
+        #[arg(
+            short,
+            long,
+            value_name = "BODY",
+            required_unless_present = "body_file"
+        )]
+        body: Option<String>,
+        /// Read the new comment body from a file instead ("-" reads stdin, as does --body -)
+        #[arg(long, value_name = "PATH", conflicts_with = "body")]
+        body_file: Option<PathBuf>,
     },
     /// Delete a comment from an issue
     ///
@@ -210,12 +297,12 @@ pub enum IssueAction {
     ///   github-edit-cli issue delete-comment -r https://github.com/owner/repo -i 123 -c 456
     ///   github-edit-cli issue delete-comment --repository-url https://github.com/rust-lang/rust --issue 98765 --comment 789
     DeleteComment {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the issue itself
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Issue number (numeric ID from the URL)
+        /// Issue number (numeric ID from the URL; optional when --repository-url is the issue URL)
         #[arg(short, long, value_name = "NUMBER")]
-        issue: u32,
+        issue: Option<u32>,
         /// Comment number to delete
         #[arg(short, long, value_name = "NUMBER")]
         comment: u32,
@@ -226,12 +313,12 @@ pub enum IssueAction {
     ///   github-edit-cli issue add-assignees -r https://github.com/owner/repo -i 123 -a user1,user2
     ///   github-edit-cli issue add-assignees --repository-url https://github.com/rust-lang/rust --issue 98765 --assignees john,jane
     AddAssignees {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the issue itself
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Issue number (numeric ID from the URL)
+        /// Issue number (numeric ID from the URL; optional when --repository-url is the issue URL)
         #[arg(short, long, value_name = "NUMBER")]
-        issue: u32,
+        issue: Option<u32>,
         /// Comma-separated list of assignee usernames to add
         #[arg(short, long, value_name = "USERS")]
         assignees: String,
@@ -242,12 +329,12 @@ pub enum IssueAction {
     ///   github-edit-cli issue remove-assignees -r https://github.com/owner/repo -i 123 -a user1,user2
     ///   github-edit-cli issue remove-assignees --repository-url https://github.com/rust-lang/rust --issue 98765 --assignees john,jane
     RemoveAssignees {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the issue itself
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Issue number (numeric ID from the URL)
+        /// Issue number (numeric ID from the URL; optional when --repository-url is the issue URL)
         #[arg(short, long, value_name = "NUMBER")]
-        issue: u32,
+        issue: Option<u32>,
         /// Comma-separated list of assignee usernames to remove
         #[arg(short, long, value_name = "USERS")]
         assignees: String,
@@ -258,12 +345,12 @@ pub enum IssueAction {
     ///   github-edit-cli issue remove-labels -r https://github.com/owner/repo -i 123 -l bug,enhancement
     ///   github-edit-cli issue remove-labels --repository-url https://github.com/rust-lang/rust --issue 98765 --labels critical,needs-review
     RemoveLabels {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the issue itself
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Issue number (numeric ID from the URL)
+        /// Issue number (numeric ID from the URL; optional when --repository-url is the issue URL)
         #[arg(short, long, value_name = "NUMBER")]
-        issue: u32,
+        issue: Option<u32>,
         /// Comma-separated list of label names to remove
         #[arg(short, long, value_name = "LABELS")]
         labels: String,
@@ -274,28 +361,38 @@ pub enum IssueAction {
     ///   github-edit-cli issue delete -r https://github.com/owner/repo -i 123
     ///   github-edit-cli issue delete --repository-url https://github.com/rust-lang/rust --issue 98765
     Delete {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the issue itself
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Issue number (numeric ID from the URL)
+        /// Issue number (numeric ID from the URL; optional when --repository-url is the issue URL)
         #[arg(short, long, value_name = "NUMBER")]
-        issue: u32,
+        issue: Option<u32>,
     },
     /// Set milestone for an issue
     ///
     /// Examples:
     ///   github-edit-cli issue set-milestone -r https://github.com/owner/repo -i 123 -m 1
     ///   github-edit-cli issue set-milestone --repository-url https://github.com/rust-lang/rust --issue 98765 --milestone-id 5
+    ///   github-edit-cli issue set-milestone -r https://github.com/owner/repo -i 123 --milestone-title "v1.0.0"
     SetMilestone {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the issue itself
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Issue number (numeric ID from the URL)
+        /// Issue number (numeric ID from the URL; optional when --repository-url is the issue URL)
         #[arg(short, long, value_name = "NUMBER")]
-        issue: u32,
+        issue: Option<u32>,
         /// Milestone ID (numeric ID from the milestone)
-        #[arg(short, long, value_name = "MILESTONE_ID")]
-        milestone_number: u32,
+        #[arg(
+            short,
+            long,
+            value_name = "MILESTONE_ID",
+            required_unless_present = "milestone_title",
+            conflicts_with = "milestone_title"
+        )]
+        milestone_number: Option<u32>,
+        /// Milestone title, resolved to its number
+        #[arg(long, value_name = "TITLE")]
+        milestone_title: Option<String>,
     },
     /// Remove milestone from an issue
     ///
@@ -303,30 +400,155 @@ pub enum IssueAction {
     ///   github-edit-cli issue remove-milestone -r https://github.com/owner/repo -i 123
     ///   github-edit-cli issue remove-milestone --repository-url https://github.com/rust-lang/rust --issue 98765
     RemoveMilestone {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the issue itself
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Issue number (numeric ID from the URL)
+        /// Issue number (numeric ID from the URL; optional when --repository-url is the issue URL)
         #[arg(short, long, value_name = "NUMBER")]
-        issue: u32,
+        issue: Option<u32>,
     },
+    /// Show your subscription state on an issue or pull request
+    ///
+    /// Examples:
+    ///   github-edit-cli issue subscription -r https://github.com/owner/repo -i 123
+    Subscription {
+        /// Repository URL (HTTPS format), or the URL of the issue or pull request itself
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Issue or pull request number (optional when --repository-url is its URL)
+        #[arg(short, long, value_name = "NUMBER")]
+        issue: Option<u32>,
+    },
+    /// Subscribe to all conversation on an issue or pull request
+    ///
+    /// Examples:
+    ///   github-edit-cli issue subscribe -r https://github.com/owner/repo -i 123
+    Subscribe {
+        /// Repository URL (HTTPS format), or the URL of the issue or pull request itself
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Issue or pull request number (optional when --repository-url is its URL)
+        #[arg(short, long, value_name = "NUMBER")]
+        issue: Option<u32>,
+    },
+    /// Unsubscribe from an issue or pull request
+    ///
+    /// You are still notified when participating or mentioned.
+    ///
+    /// Examples:
+    ///   github-edit-cli issue unsubscribe -r https://github.com/owner/repo -i 123
+    Unsubscribe {
+        /// Repository URL (HTTPS format), or the URL of the issue or pull request itself
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Issue or pull request number (optional when --repository-url is its URL)
+        #[arg(short, long, value_name = "NUMBER")]
+        issue: Option<u32>,
+    },
+    /// List the issues and pull requests referencing an issue or pull request
+    ///
+    /// Examples:
+    ///   github-edit-cli issue cross-references https://github.com/owner/repo/issues/123
+    ///   github-edit-cli issue cross-references https://github.com/owner/repo/pull/456
+    CrossReferences {
+        /// Issue or pull request URL
+        url: String,
+    },
+    /// Watch an issue or pull request, printing new comments, reviews and
+    /// state changes as they happen
+    ///
+    /// Polls the timeline of the item every --interval seconds with
+    /// conditional requests, which do not count against the rate limit while
+    /// nothing changes. Each event is printed as a line, or as one line of
+    /// JSON with --output json.
+    ///
+    /// Examples:
+    ///   github-edit-cli issue watch https://github.com/owner/repo/issues/123
+    ///   github-edit-cli issue watch https://github.com/owner/repo/issues/123 --interval 10 --until-closed
+    ///   github-edit-cli --output json issue watch https://github.com/owner/repo/issues/123 --timeout 3600
+    Watch {
+        /// Issue or pull request URL
+        #[arg(value_name = "URL")]
+        url: String,
+        #[command(flatten)]
+        watch: WatchArgs,
+    },
+}
+
+impl IssueAction {
+    /// Returns true if the action changes an issue, so it can be queued
+    /// while GitHub is unreachable
+    pub fn is_queueable(&self) -> bool {
+        !matches!(
+            self,
+            IssueAction::Get { .. }
+                | IssueAction::List { .. }
+                | IssueAction::Subscription { .. }
+                | IssueAction::CrossReferences { .. }
+                | IssueAction::Watch { .. }
+        )
+    }
 }
 
-pub async fn execute_issue_action(github_client: &GitHubClient, action: IssueAction) -> Result<()> {
-    match action {
+pub async fn execute_issue_action(
+    github_client: &GitHubClient,
+    action: IssueAction,
+    output: OutputFormat,
+) -> Result<()> {
+    let result = match action {
         IssueAction::Get { urls } => {
-            let issue_urls: Vec<IssueUrl> = urls.into_iter().map(|url| IssueUrl(url)).collect();
+            let issue_urls: Vec<IssueUrl> = urls.into_iter().map(IssueUrl).collect();
             let result = issue::get_issues_details(github_client, issue_urls).await?;
-            println!("{}", serde_json::to_string_pretty(&result)?);
+            return output.print_data(&result);
+        }
+        IssueAction::List {
+            repository_url,
+            state,
+            labels,
+            assignee,
+            milestone_number,
+            since,
+            pagination,
+        } => {
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let label_list: Vec<String> = labels
+                .map(|labels| labels.split(',').map(|s| s.trim().to_string()).collect())
+                .unwrap_or_default();
+            let result = pagination
+                .fetch(|pagination| {
+                    issue::list_issues(
+                        github_client,
+                        &repo_id,
+                        state,
+                        &label_list,
+                        assignee.as_deref(),
+                        milestone_number.map(MilestoneNumber::new),
+                        since,
+                        pagination,
+                    )
+                })
+                .await?;
+            return output.print_data(&result);
         }
         IssueAction::Create {
             repository_url,
             title,
             body,
+            body_file,
+            template,
         } => {
             let repo_url = RepositoryUrl::new(repository_url);
             let repo_id = RepositoryId::parse_url(&repo_url)
                 .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let body = match template
+                .render(github_client, &repo_id, TemplateKind::Issue)
+                .await?
+            {
+                Some(body) => Some(body),
+                None => read_body(body, body_file)?,
+            };
             let created_issue = issue::create_issue(
                 github_client,
                 &repo_id,
@@ -337,101 +559,105 @@ pub async fn execute_issue_action(github_client: &GitHubClient, action: IssueAct
                 None,
             )
             .await?;
-            println!("Created issue #{}", created_issue.issue_id.number);
+            IssueResult::Created {
+                issue: created_issue.issue_id.number,
+                url: created_issue.issue_id.url(),
+            }
         }
         IssueAction::Comment {
             repository_url,
             issue,
             body,
+            body_file,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let body = require_body(body, body_file)?;
+            let (repo_id, issue) = resolve_item_url(&repository_url, issue)?;
             let issue_number = IssueNumber::new(issue);
             let comment_number =
                 issue::add_comment(github_client, &repo_id, issue_number, &body).await?;
-            println!("Added comment #{}", comment_number);
+            IssueResult::CommentAdded {
+                issue,
+                comment: comment_number,
+            }
         }
         IssueAction::EditTitle {
             repository_url,
             issue,
             title,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, issue) = resolve_item_url(&repository_url, issue)?;
             let issue_number = IssueNumber::new(issue);
             issue::edit_title(github_client, &repo_id, issue_number, &title).await?;
-            println!("Updated issue #{} title", issue);
+            IssueResult::TitleUpdated { issue }
         }
         IssueAction::EditBody {
             repository_url,
             issue,
             body,
+            body_file,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let body = require_body(body, body_file)?;
+            let (repo_id, issue) = resolve_item_url(&repository_url, issue)?;
             let issue_number = IssueNumber::new(issue);
             issue::edit_body(github_client, &repo_id, issue_number, &body).await?;
-            println!("Updated issue #{} body", issue);
+            IssueResult::BodyUpdated { issue }
         }
         IssueAction::UpdateState {
             repository_url,
             issue,
             state,
+            reason,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, issue) = resolve_item_url(&repository_url, issue)?;
             let issue_number = IssueNumber::new(issue);
-            issue::update_state(github_client, &repo_id, issue_number, state).await?;
-            println!("Updated issue #{} state to {}", issue, state);
+            issue::update_state(github_client, &repo_id, issue_number, state, reason).await?;
+            IssueResult::StateUpdated {
+                issue,
+                state,
+                reason,
+            }
         }
         IssueAction::EditComment {
             repository_url,
             issue,
             comment,
             body,
+            body_file,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let body = require_body(body, body_file)?;
+            let (repo_id, issue) = resolve_item_url(&repository_url, issue)?;
             let issue_number = IssueNumber::new(issue);
             let comment_number = IssueCommentNumber::new(comment.into());
             issue::edit_comment(github_client, &repo_id, issue_number, comment_number, &body)
                 .await?;
-            println!("Updated comment #{} on issue #{}", comment, issue);
+            IssueResult::CommentUpdated { issue, comment }
         }
         IssueAction::DeleteComment {
             repository_url,
             issue,
             comment,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, issue) = resolve_item_url(&repository_url, issue)?;
             let issue_number = IssueNumber::new(issue);
             let comment_number = IssueCommentNumber::new(comment.into());
             issue::delete_comment(github_client, &repo_id, issue_number, comment_number).await?;
-            println!("Deleted comment #{} from issue #{}", comment, issue);
+            IssueResult::CommentDeleted { issue, comment }
         }
         IssueAction::AddAssignees {
             repository_url,
             issue,
             assignees,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, issue) = resolve_item_url(&repository_url, issue)?;
             let issue_number = IssueNumber::new(issue);
             let assignee_list: Vec<String> =
                 assignees.split(',').map(|s| s.trim().to_string()).collect();
             let (added, skipped) =
                 issue::add_assignees(github_client, &repo_id, issue_number, &assignee_list).await?;
-            println!("Added assignees: {:?}", added);
-            if !skipped.is_empty() {
-                println!("Skipped (already assigned): {:?}", skipped);
+            IssueResult::AssigneesAdded {
+                issue,
+                added,
+                skipped,
             }
         }
         IssueAction::RemoveAssignees {
@@ -439,18 +665,17 @@ pub async fn execute_issue_action(github_client: &GitHubClient, action: IssueAct
             issue,
             assignees,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, issue) = resolve_item_url(&repository_url, issue)?;
             let issue_number = IssueNumber::new(issue);
             let assignee_list: Vec<String> =
                 assignees.split(',').map(|s| s.trim().to_string()).collect();
             let (removed, skipped) =
                 issue::remove_assignees(github_client, &repo_id, issue_number, &assignee_list)
                     .await?;
-            println!("Removed assignees: {:?}", removed);
-            if !skipped.is_empty() {
-                println!("Skipped (not assigned): {:?}", skipped);
+            IssueResult::AssigneesRemoved {
+                issue,
+                removed,
+                skipped,
             }
         }
         IssueAction::RemoveLabels {
@@ -458,9 +683,7 @@ pub async fn execute_issue_action(github_client: &GitHubClient, action: IssueAct
             issue,
             labels,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, issue) = resolve_item_url(&repository_url, issue)?;
             let issue_number = IssueNumber::new(issue);
             let label_list: Vec<Label> = labels
                 .split(',')
@@ -468,56 +691,259 @@ pub async fn execute_issue_action(github_client: &GitHubClient, action: IssueAct
                 .collect();
             let (removed, skipped) =
                 issue::remove_labels(github_client, &repo_id, issue_number, &label_list).await?;
-            println!(
-                "Removed labels: {:?}",
-                removed.iter().map(|l| &l.name).collect::<Vec<_>>()
-            );
-            if !skipped.is_empty() {
-                println!(
-                    "Skipped (not assigned): {:?}",
-                    skipped.iter().map(|l| &l.name).collect::<Vec<_>>()
-                );
+            IssueResult::LabelsRemoved {
+                issue,
+                removed: removed.into_iter().map(|l| l.name).collect(),
+                skipped: skipped.into_iter().map(|l| l.name).collect(),
             }
         }
         IssueAction::Delete {
             repository_url,
             issue,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, issue) = resolve_item_url(&repository_url, issue)?;
             let issue_number = IssueNumber::new(issue);
             issue::delete_issue(github_client, &repo_id, issue_number).await?;
-            println!("Deleted issue #{}", issue);
+            IssueResult::Deleted { issue }
         }
         IssueAction::SetMilestone {
             repository_url,
             issue,
             milestone_number,
+            milestone_title,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, issue) = resolve_item_url(&repository_url, issue)?;
             let issue_number = IssueNumber::new(issue);
-            let milestone_number = MilestoneNumber::new(milestone_number.into());
+            let milestone_number = repository::resolve_milestone(
+                github_client,
+                &repo_id,
+                milestone_number.map(|n| MilestoneNumber::new(n.into())),
+                milestone_title.as_deref(),
+            )
+            .await?;
             issue::set_milestone(github_client, &repo_id, issue_number, milestone_number).await?;
-            println!(
-                "Set milestone {} for issue #{}",
-                milestone_number.value(),
-                issue
-            );
+            IssueResult::MilestoneSet {
+                issue,
+                milestone: milestone_number.value(),
+            }
         }
         IssueAction::RemoveMilestone {
             repository_url,
             issue,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, issue) = resolve_item_url(&repository_url, issue)?;
             let issue_number = IssueNumber::new(issue);
             issue::remove_milestone(github_client, &repo_id, issue_number).await?;
-            println!("Removed milestone from issue #{}", issue);
+            IssueResult::MilestoneRemoved { issue }
+        }
+        IssueAction::Subscription {
+            repository_url,
+            issue,
+        } => {
+            let (repo_id, issue) = resolve_item_url(&repository_url, issue)?;
+            let subscription = notification::get_issue_subscription(
+                github_client,
+                &repo_id,
+                IssueNumber::new(issue),
+            )
+            .await?;
+            return output.print_data(&subscription);
+        }
+        IssueAction::Subscribe {
+            repository_url,
+            issue,
+        } => {
+            let (repo_id, issue) = resolve_item_url(&repository_url, issue)?;
+            notification::subscribe_to_issue(github_client, &repo_id, IssueNumber::new(issue))
+                .await?;
+            IssueResult::Subscribed { issue }
+        }
+        IssueAction::Unsubscribe {
+            repository_url,
+            issue,
+        } => {
+            let (repo_id, issue) = resolve_item_url(&repository_url, issue)?;
+            notification::unsubscribe_from_issue(github_client, &repo_id, IssueNumber::new(issue))
+                .await?;
+            IssueResult::Unsubscribed { issue }
+        }
+        IssueAction::CrossReferences { url } => {
+            let item = ProjectOriginalResource::parse_url(&url).map_err(|e| anyhow::anyhow!(e))?;
+            let (repo_id, number) = item
+                .repository_and_number()
+                .ok_or_else(|| anyhow::anyhow!("Invalid issue or pull request URL: {}", url))?;
+
+            let references =
+                issue::find_cross_references(github_client, repo_id, IssueNumber::new(number))
+                    .await?;
+
+            IssueResult::CrossReferences { url, references }
+        }
+        IssueAction::Watch { url, watch } => {
+            return watch::watch(github_client, &url, &watch, output).await;
+        }
+    };
+    output.print(&result)
+}
+
+/// Result of an issue command
+#[derive(Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+enum IssueResult {
+    Created {
+        issue: u32,
+        url: String,
+    },
+    CommentAdded {
+        issue: u32,
+        comment: IssueCommentNumber,
+    },
+    TitleUpdated {
+        issue: u32,
+    },
+    BodyUpdated {
+        issue: u32,
+    },
+    StateUpdated {
+        issue: u32,
+        state: IssueState,
+        reason: Option<IssueStateReason>,
+    },
+    CommentUpdated {
+        issue: u32,
+        comment: u32,
+    },
+    CommentDeleted {
+        issue: u32,
+        comment: u32,
+    },
+    AssigneesAdded {
+        issue: u32,
+        added: Vec<String>,
+        skipped: Vec<String>,
+    },
+    AssigneesRemoved {
+        issue: u32,
+        removed: Vec<String>,
+        skipped: Vec<String>,
+    },
+    LabelsRemoved {
+        issue: u32,
+        removed: Vec<String>,
+        skipped: Vec<String>,
+    },
+    Deleted {
+        issue: u32,
+    },
+    MilestoneSet {
+        issue: u32,
+        milestone: u64,
+    },
+    MilestoneRemoved {
+        issue: u32,
+    },
+    Subscribed {
+        issue: u32,
+    },
+    Unsubscribed {
+        issue: u32,
+    },
+    CrossReferences {
+        url: String,
+        references: Vec<CrossReference>,
+    },
+}
+
+impl fmt::Display for IssueResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IssueResult::Created { issue, .. } => writeln!(f, "Created issue #{}", issue),
+            IssueResult::CommentAdded { comment, .. } => {
+                writeln!(f, "Added comment #{}", comment)
+            }
+            IssueResult::TitleUpdated { issue } => writeln!(f, "Updated issue #{} title", issue),
+            IssueResult::BodyUpdated { issue } => writeln!(f, "Updated issue #{} body", issue),
+            IssueResult::StateUpdated {
+                issue,
+                state,
+                reason: Some(reason),
+            } => writeln!(
+                f,
+                "Updated issue #{} state to {} ({})",
+                issue, state, reason
+            ),
+            IssueResult::StateUpdated {
+                issue,
+                state,
+                reason: None,
+            } => writeln!(f, "Updated issue #{} state to {}", issue, state),
+            IssueResult::CommentUpdated { issue, comment } => {
+                writeln!(f, "Updated comment #{} on issue #{}", comment, issue)
+            }
+            IssueResult::CommentDeleted { issue, comment } => {
+                writeln!(f, "Deleted comment #{} from issue #{}", comment, issue)
+            }
+            IssueResult::AssigneesAdded { added, skipped, .. } => {
+                writeln!(f, "Added assignees: {:?}", added)?;
+                if !skipped.is_empty() {
+                    writeln!(f, "Skipped (already assigned): {:?}", skipped)?;
+                }
+                Ok(())
+            }
+            IssueResult::AssigneesRemoved {
+                removed, skipped, ..
+            } => {
+                writeln!(f, "Removed assignees: {:?}", removed)?;
+                if !skipped.is_empty() {
+                    writeln!(f, "Skipped (not assigned): {:?}", skipped)?;
+                }
+                Ok(())
+            }
+            IssueResult::LabelsRemoved {
+                removed, skipped, ..
+            } => {
+                writeln!(f, "Removed labels: {:?}", removed)?;
+                if !skipped.is_empty() {
+                    writeln!(f, "Skipped (not assigned): {:?}", skipped)?;
+                }
+                Ok(())
+            }
+            IssueResult::Deleted { issue } => writeln!(f, "Deleted issue #{}", issue),
+            IssueResult::MilestoneSet { issue, milestone } => {
+                writeln!(f, "Set milestone {} for issue #{}", milestone, issue)
+            }
+            IssueResult::MilestoneRemoved { issue } => {
+                writeln!(f, "Removed milestone from issue #{}", issue)
+            }
+            IssueResult::Subscribed { issue } => writeln!(f, "Subscribed to #{}", issue),
+            IssueResult::Unsubscribed { issue } => writeln!(f, "Unsubscribed from #{}", issue),
+            IssueResult::CrossReferences { url, references } => {
+                for reference in references {
+                    writeln!(
+                        f,
+                        "{}#{}\t{}\t{}\t{}{}\t{}",
+                        reference.repository,
+                        reference.number,
+                        if reference.is_pull_request {
+                            "pr"
+                        } else {
+                            "issue"
+                        },
+                        reference.state.to_lowercase(),
+                        reference.title,
+                        if reference.will_close_target {
+                            " (closes)"
+                        } else {
+                            ""
+                        },
+                        reference.url
+                    )?;
+                }
+                if references.is_empty() {
+                    writeln!(f, "No issues or pull requests reference {}", url)?;
+                }
+                Ok(())
+            }
         }
     }
-    Ok(())
 }
@@ -0,0 +1,49 @@
+//! Logging of the CLI
+//!
+//! Logs are written to stderr, keeping stdout to the command results. The
+//! global `-q/--quiet` and `-v/--verbose` options select what is logged,
+//! and `--log-format` whether it is logged as readable lines or as JSON
+//! lines to collect with log tooling. Without either option, `RUST_LOG`
+//! applies as before.
+
+use clap::ValueEnum;
+use tracing_subscriber::EnvFilter;
+
+/// Format of the logs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    /// Readable lines, colored on a terminal
+    #[default]
+    Pretty,
+    /// One JSON object per line, with the fields of the event at the top level
+    Json,
+}
+
+/// Returns the log filter of the given verbosity
+///
+/// Quiet logs nothing, so only the error of a failed command is printed. Each
+/// `-v` raises the level of this crate's logs: warnings of retried requests
+/// and progress, then every GitHub API call with its duration and rate limit,
+/// then everything. Other crates only log their warnings.
+pub fn log_filter(quiet: bool, verbose: u8) -> EnvFilter {
+    let level = match (quiet, verbose) {
+        (true, _) => return EnvFilter::new("off"),
+        (false, 0) => return EnvFilter::from_default_env(),
+        (false, 1) => "info",
+        (false, 2) => "debug",
+        (false, _) => "trace",
+    };
+    // The directive also covers the targets of the CLI binary, github_edit_cli
+    EnvFilter::new(format!("warn,github_edit={}", level))
+}
+
+/// Initialize tracing on stderr with the given verbosity and format
+pub fn init_logging(quiet: bool, verbose: u8, format: LogFormat) {
+    let subscriber = tracing_subscriber::fmt()
+        .with_env_filter(log_filter(quiet, verbose))
+        .with_writer(std::io::stderr);
+    match format {
+        LogFormat::Pretty => subscriber.init(),
+        LogFormat::Json => subscriber.json().flatten_event(true).init(),
+    }
+}
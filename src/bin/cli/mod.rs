@@ -1,14 +1,53 @@
 //! CLI module for GitHub Edit
 //!
 //! This module contains the command-line interface definitions and execution logic
-//! organized by resource type (issues, pull requests, projects).
+//! organized by resource type (audit log, authentication, configuration, issues, pull requests, projects, offline queue, repositories, actions, checks, secrets, variables, discussions, notifications, search, templates).
 
+pub mod actions;
+pub mod apply;
+pub mod audit;
+pub mod auth;
+pub mod check;
+pub mod checkout;
+pub mod config;
+pub mod discussion;
+pub mod dry_run;
+pub mod exit_code;
+pub mod generate;
+pub mod input;
 pub mod issue;
+pub mod logging;
+pub mod notification;
+pub mod output;
+pub mod pagination;
+pub mod profile;
 pub mod project;
 pub mod pull_request;
+pub mod queue;
 pub mod repository;
+pub mod search;
+pub mod secret;
+pub mod template;
+pub mod watch;
 
+pub use actions::{ActionsAction, execute_actions_action};
+pub use apply::{ApplyArgs, execute_apply};
+pub use audit::{AuditAction, command_parameters, execute_audit_action, record_command};
+pub use auth::{AuthAction, execute_auth_action};
+pub use check::{CheckAction, execute_check_action};
+pub use config::{ConfigAction, execute_config_action};
+pub use discussion::{DiscussionAction, execute_discussion_action};
+pub use dry_run::execute_dry_run;
+pub use exit_code::report_error;
+pub use generate::{execute_completions, execute_man};
 pub use issue::{IssueAction, execute_issue_action};
+pub use logging::{LogFormat, init_logging};
+pub use notification::{NotificationAction, execute_notification_action};
+pub use output::OutputFormat;
+pub use profile::apply_config_defaults;
 pub use project::{ProjectAction, execute_project_action};
 pub use pull_request::{PullRequestAction, execute_pr_action};
+pub use queue::{QueueAction, execute_queue_action, queue_failed_command};
 pub use repository::{RepositoryAction, execute_repository_action};
+pub use search::{SearchAction, execute_search_action};
+pub use secret::{SecretAction, VariableAction, execute_secret_action, execute_variable_action};
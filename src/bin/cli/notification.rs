@@ -0,0 +1,225 @@
+//! Notification inbox CLI commands and execution logic
+//!
+//! This module contains the CLI command definitions and execution logic
+//! for triaging the authenticated user's notifications and managing
+//! thread subscriptions.
+
+use super::output::{OutputFormat, next_page_line};
+use super::pagination::PaginationArgs;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use clap::Subcommand;
+use github_edit::github::GitHubClient;
+use github_edit::tools::functions::notification;
+use github_edit::types::notification::{Notification, NotificationFilter, NotificationThreadId};
+use github_edit::types::pagination::Page;
+use github_edit::types::repository::{RepositoryId, RepositoryUrl};
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Subcommand)]
+pub enum NotificationAction {
+    /// List notifications, most recently updated first
+    ///
+    /// Only unread notifications are listed unless --include-read is given.
+    ///
+    /// Examples:
+    ///   github-edit-cli notifications list
+    ///   github-edit-cli notifications list -r https://github.com/owner/repo --participating
+    ///   github-edit-cli notifications list --include-read --since 2024-01-01T00:00:00Z
+    List {
+        /// Repository URL (HTTPS format) to restrict the notifications to
+        #[arg(short, long, value_name = "URL")]
+        repository_url: Option<String>,
+        /// Include notifications already marked as read
+        #[arg(long)]
+        include_read: bool,
+        /// Only include notifications where you are participating or mentioned
+        #[arg(long)]
+        participating: bool,
+        /// Only include notifications updated at or after this time (RFC 3339)
+        #[arg(long, value_name = "TIMESTAMP")]
+        since: Option<DateTime<Utc>>,
+        /// Only include notifications updated before this time (RFC 3339)
+        #[arg(long, value_name = "TIMESTAMP")]
+        before: Option<DateTime<Utc>>,
+        #[command(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// Mark a notification thread as read
+    ///
+    /// Examples:
+    ///   github-edit-cli notifications read --thread-id 1234567
+    Read {
+        /// Notification thread ID
+        #[arg(long, value_name = "THREAD_ID")]
+        thread_id: String,
+    },
+    /// Mark a notification thread as done
+    ///
+    /// Examples:
+    ///   github-edit-cli notifications done --thread-id 1234567
+    Done {
+        /// Notification thread ID
+        #[arg(long, value_name = "THREAD_ID")]
+        thread_id: String,
+    },
+    /// Show the subscription to a notification thread
+    ///
+    /// Examples:
+    ///   github-edit-cli notifications subscription --thread-id 1234567
+    Subscription {
+        /// Notification thread ID
+        #[arg(long, value_name = "THREAD_ID")]
+        thread_id: String,
+    },
+    /// Subscribe to a notification thread
+    ///
+    /// Examples:
+    ///   github-edit-cli notifications subscribe --thread-id 1234567
+    Subscribe {
+        /// Notification thread ID
+        #[arg(long, value_name = "THREAD_ID")]
+        thread_id: String,
+    },
+    /// Unsubscribe from a notification thread
+    ///
+    /// Examples:
+    ///   github-edit-cli notifications unsubscribe --thread-id 1234567
+    Unsubscribe {
+        /// Notification thread ID
+        #[arg(long, value_name = "THREAD_ID")]
+        thread_id: String,
+    },
+}
+
+pub async fn execute_notification_action(
+    github_client: &GitHubClient,
+    action: NotificationAction,
+    output: OutputFormat,
+) -> Result<()> {
+    let result = match action {
+        NotificationAction::List {
+            repository_url,
+            include_read,
+            participating,
+            since,
+            before,
+            pagination,
+        } => {
+            let repo_id = repository_url.map(parse_repository_url).transpose()?;
+            let filter = NotificationFilter {
+                all: include_read,
+                participating,
+                since,
+                before,
+            };
+
+            let notifications = pagination
+                .fetch(|pagination| {
+                    notification::list_notifications(
+                        github_client,
+                        repo_id.as_ref(),
+                        &filter,
+                        pagination,
+                    )
+                })
+                .await?;
+
+            NotificationResult::Notifications(notifications)
+        }
+        NotificationAction::Read { thread_id } => {
+            let thread_id = NotificationThreadId::new(thread_id);
+
+            notification::mark_notification_read(github_client, &thread_id).await?;
+
+            NotificationResult::MarkedRead { thread_id }
+        }
+        NotificationAction::Done { thread_id } => {
+            let thread_id = NotificationThreadId::new(thread_id);
+
+            notification::mark_thread_done(github_client, &thread_id).await?;
+
+            NotificationResult::MarkedDone { thread_id }
+        }
+        NotificationAction::Subscription { thread_id } => {
+            let thread_id = NotificationThreadId::new(thread_id);
+
+            let subscription =
+                notification::get_thread_subscription(github_client, &thread_id).await?;
+
+            return output.print_data(&subscription);
+        }
+        NotificationAction::Subscribe { thread_id } => {
+            let thread_id = NotificationThreadId::new(thread_id);
+
+            notification::subscribe_thread(github_client, &thread_id).await?;
+
+            NotificationResult::Subscribed { thread_id }
+        }
+        NotificationAction::Unsubscribe { thread_id } => {
+            let thread_id = NotificationThreadId::new(thread_id);
+
+            notification::unsubscribe_thread(github_client, &thread_id).await?;
+
+            NotificationResult::Unsubscribed { thread_id }
+        }
+    };
+    output.print(&result)
+}
+
+/// Result of a notification command
+#[derive(Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+enum NotificationResult {
+    Notifications(Page<Notification>),
+    MarkedRead { thread_id: NotificationThreadId },
+    MarkedDone { thread_id: NotificationThreadId },
+    Subscribed { thread_id: NotificationThreadId },
+    Unsubscribed { thread_id: NotificationThreadId },
+}
+
+impl fmt::Display for NotificationResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotificationResult::Notifications(notifications) => {
+                for item in &notifications.items {
+                    writeln!(
+                        f,
+                        "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                        item.id,
+                        if item.unread { "unread" } else { "read" },
+                        item.reason,
+                        item.repository.full_name,
+                        item.subject.subject_type,
+                        item.subject.title,
+                        item.html_url()
+                    )?;
+                }
+                next_page_line(
+                    f,
+                    "notifications",
+                    notifications.page,
+                    notifications.has_next_page,
+                )
+            }
+            NotificationResult::MarkedRead { thread_id } => {
+                writeln!(f, "Marked notification thread {} as read", thread_id)
+            }
+            NotificationResult::MarkedDone { thread_id } => {
+                writeln!(f, "Marked notification thread {} as done", thread_id)
+            }
+            NotificationResult::Subscribed { thread_id } => {
+                writeln!(f, "Subscribed to notification thread {}", thread_id)
+            }
+            NotificationResult::Unsubscribed { thread_id } => {
+                writeln!(f, "Unsubscribed from notification thread {}", thread_id)
+            }
+        }
+    }
+}
+
+fn parse_repository_url(repository_url: String) -> Result<RepositoryId> {
+    RepositoryId::parse_url(&RepositoryUrl::new(repository_url))
+        .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))
+}
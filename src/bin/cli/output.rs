@@ -0,0 +1,54 @@
+//! Output of the CLI commands
+//!
+//! Every command builds a typed result and prints it in the format selected
+//! with the global `--output` option: as text to read (the default), or as
+//! pretty-printed JSON to process with jq and other scripts. In JSON, the
+//! `result` field names the kind of result, e.g. `"created"`.
+//! Resources fetched from GitHub, such as issue details, are printed as
+//! JSON in both formats.
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt::Display;
+
+/// Format the results of the CLI commands are printed in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Text, one line per item with tab-separated columns
+    #[default]
+    Text,
+    /// Pretty-printed JSON
+    Json,
+}
+
+impl OutputFormat {
+    /// Print `result` as its text, which ends each line with a newline, or
+    /// as JSON
+    pub fn print<T: Serialize + Display>(self, result: &T) -> Result<()> {
+        match self {
+            OutputFormat::Text => print!("{}", result),
+            OutputFormat::Json => println!("{}", serde_json::to_string_pretty(result)?),
+        }
+        Ok(())
+    }
+
+    /// Print `data` fetched from GitHub as JSON, whatever the format
+    pub fn print_data<T: Serialize>(self, data: &T) -> Result<()> {
+        println!("{}", serde_json::to_string_pretty(data)?);
+        Ok(())
+    }
+}
+
+/// Text line announcing the next page of a listing, if there is one
+pub fn next_page_line(
+    f: &mut std::fmt::Formatter<'_>,
+    items: &str,
+    page: u32,
+    has_next_page: bool,
+) -> std::fmt::Result {
+    if has_next_page {
+        writeln!(f, "More {} available on page {}", items, page + 1)?;
+    }
+    Ok(())
+}
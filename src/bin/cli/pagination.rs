@@ -0,0 +1,128 @@
+//! Pagination options of the list and search commands
+//!
+//! Every listing takes the same `--limit`, `--page` and `--all` options:
+//! `--limit` caps the number of items (30 by default), `--page` selects a
+//! page of `--limit` items, and `--all` fetches every page. Limits above the
+//! page size of the GitHub API, 100, are fetched page by page.
+
+use anyhow::Result;
+use clap::Args;
+use github_edit::types::pagination::{DEFAULT_PER_PAGE, MAX_PER_PAGE, Page, Pagination};
+use github_edit::types::search::SearchResults;
+use std::future::Future;
+
+/// Pagination options shared by the list and search commands
+#[derive(Args, Debug, Clone, Default)]
+pub struct PaginationArgs {
+    /// Maximum number of items to list (default: 30, or all with --all)
+    #[arg(long, alias = "per-page", value_name = "COUNT")]
+    pub limit: Option<u32>,
+    /// Page number starting at 1, in pages of --limit items (at most 100)
+    #[arg(long, value_name = "PAGE", conflicts_with = "all")]
+    pub page: Option<u32>,
+    /// Fetch all pages
+    #[arg(long)]
+    pub all: bool,
+}
+
+/// A page of a listing, possibly with details of the whole listing
+pub trait Paged {
+    type Item;
+
+    fn page_mut(&mut self) -> &mut Page<Self::Item>;
+}
+
+impl<T> Paged for Page<T> {
+    type Item = T;
+
+    fn page_mut(&mut self) -> &mut Page<T> {
+        self
+    }
+}
+
+impl<T> Paged for SearchResults<T> {
+    type Item = T;
+
+    fn page_mut(&mut self) -> &mut Page<T> {
+        &mut self.page
+    }
+}
+
+impl PaginationArgs {
+    /// Returns the maximum number of items, `None` for all
+    fn max_items(&self) -> Option<usize> {
+        match (self.limit, self.all) {
+            (Some(limit), _) => Some(limit.max(1) as usize),
+            (None, true) => None,
+            (None, false) => Some(DEFAULT_PER_PAGE as usize),
+        }
+    }
+
+    /// Returns the page size to request
+    fn per_page(&self) -> u8 {
+        self.max_items()
+            .map_or(MAX_PER_PAGE, |limit| limit.min(MAX_PER_PAGE as usize) as u8)
+    }
+
+    /// Fetch the selected items with `list`, which fetches one page
+    ///
+    /// Pages are fetched until the limit is reached or no page is left. The
+    /// result holds the items of all fetched pages, and tells whether more
+    /// items are available after them.
+    pub async fn fetch<R, F, Fut>(&self, mut list: F) -> Result<R>
+    where
+        R: Paged,
+        F: FnMut(Pagination) -> Fut,
+        Fut: Future<Output = Result<R>>,
+    {
+        let mut pagination = Pagination::new(self.page, Some(self.per_page()));
+        let mut result = list(pagination).await?;
+        if self.page.is_some() {
+            return Ok(result);
+        }
+
+        let max_items = self.max_items();
+        loop {
+            let page = result.page_mut();
+            if let Some(max_items) = max_items {
+                if page.items.len() >= max_items {
+                    if page.items.len() > max_items {
+                        page.items.truncate(max_items);
+                        page.has_next_page = true;
+                    }
+                    return Ok(result);
+                }
+            }
+            if !page.has_next_page {
+                return Ok(result);
+            }
+
+            pagination = pagination.next();
+            let mut next = list(pagination).await?;
+            let next = next.page_mut();
+            let page = result.page_mut();
+            page.items.append(&mut next.items);
+            page.page = next.page;
+            page.has_next_page = next.has_next_page;
+        }
+    }
+
+    /// Select the items of a listing fetched at once
+    pub fn select<T>(&self, mut items: Vec<T>) -> Page<T> {
+        let per_page = self.per_page();
+        let pagination = Pagination::new(self.page, Some(per_page));
+        let start = match self.page {
+            Some(_) => (pagination.page as usize - 1).saturating_mul(per_page as usize),
+            None => 0,
+        };
+        let end = match (self.page, self.max_items()) {
+            (Some(_), _) => start.saturating_add(per_page as usize),
+            (None, Some(max_items)) => max_items,
+            (None, None) => items.len(),
+        };
+        let has_next_page = items.len() > end;
+        items.truncate(end);
+        let items = items.split_off(start.min(items.len()));
+        Page::new(items, pagination, has_next_page)
+    }
+}
@@ -0,0 +1,93 @@
+//! Configuration defaults for CLI arguments
+//!
+//! The default owner and repository of the selected profile are applied to
+//! the `--owner` and `--repository-url` arguments of every command, so they
+//! can be omitted when working in the profile's repository. Without a
+//! profile repository, `defaults.repository` of the configuration file is
+//! used, and `defaults.output` sets the default of `--output`.
+
+use super::output::OutputFormat;
+use anyhow::Result;
+use clap::{Arg, Command, ValueEnum};
+use github_edit::config::{Config, Profile};
+
+/// Apply the defaults of the configuration and of the selected profile to a
+/// command and all of its subcommands
+///
+/// # Errors
+/// Returns an error if `defaults.output` is not an output format
+pub fn apply_config_defaults(
+    command: Command,
+    config: &Config,
+    profile: Option<&Profile>,
+) -> Result<Command> {
+    // clap only takes static strings as default values; the command is built
+    // once per process, so leaking the few configured values is harmless
+    let leak = |value: String| -> &'static str { Box::leak(value.into_boxed_str()) };
+    let repository = profile
+        .and_then(|profile| profile.default_repository())
+        .or_else(|| config.defaults.repository.clone())
+        .map(leak);
+    let owner = profile.and_then(|profile| profile.owner.clone()).map(leak);
+
+    let mut command = apply_defaults(command, repository, owner);
+    if let Some(output) = &config.defaults.output {
+        let output = match parse_output_format(output)? {
+            OutputFormat::Text => "text",
+            OutputFormat::Json => "json",
+        };
+        command = command.mut_arg("output", |arg| arg.default_value(output));
+    }
+    Ok(command)
+}
+
+/// Parse an output format given in the configuration file
+///
+/// # Errors
+/// Returns an error naming the valid formats if `output` is not one of them
+pub fn parse_output_format(output: &str) -> Result<OutputFormat> {
+    OutputFormat::from_str(output, true).map_err(|_| {
+        anyhow::anyhow!(
+            "Invalid output format '{}' in defaults.output. Expected text or json",
+            output
+        )
+    })
+}
+
+fn apply_defaults(
+    command: Command,
+    repository: Option<&'static str>,
+    owner: Option<&'static str>,
+) -> Command {
+    let subcommand_names: Vec<String> = command
+        .get_subcommands()
+        .map(|subcommand| subcommand.get_name().to_string())
+        .collect();
+
+    let mut command = command.mut_args(|arg| {
+        let default = match arg.get_id().as_str() {
+            "repository_url" => repository,
+            "owner" => owner,
+            _ => None,
+        };
+        match default {
+            Some(default) => with_default(arg, default),
+            None => arg,
+        }
+    });
+    for name in subcommand_names {
+        command = command.mut_subcommand(name, |subcommand| {
+            apply_defaults(subcommand, repository, owner)
+        });
+    }
+    command
+}
+
+fn with_default(arg: Arg, default: &'static str) -> Arg {
+    // Optional arguments keep their meaning of "not given"
+    if arg.is_required_set() {
+        arg.required(false).default_value(default)
+    } else {
+        arg
+    }
+}
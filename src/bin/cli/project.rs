@@ -1,26 +1,116 @@
 //! Project-related CLI commands and execution logic
 //!
 //! This module contains the CLI command definitions and execution logic
-//! for project management operations including updating custom fields
-//! and managing project items.
+//! for project management operations including listing and updating
+//! custom fields and managing project items.
 
+use super::output::{OutputFormat, next_page_line};
+use super::pagination::PaginationArgs;
 use anyhow::Result;
 use clap::Subcommand;
 use github_edit::github::GitHubClient;
 use github_edit::tools::functions::project;
+use github_edit::types::pagination::Page;
 use github_edit::types::project::{
-    ProjectCustomFieldType, ProjectFieldId, ProjectFieldValue, ProjectItemId, ProjectNodeId,
+    Project, ProjectCustomFieldType, ProjectDetails, ProjectField, ProjectFieldId,
+    ProjectFieldValue, ProjectId, ProjectItem, ProjectItemId, ProjectNodeId, ProjectNumber,
+    ProjectType,
 };
+use github_edit::types::repository::Owner;
 use github_edit::types::{IssueNumber, PullRequestNumber, RepositoryId};
+use serde::Serialize;
+use std::fmt;
 use std::str::FromStr;
 
 #[derive(Subcommand)]
 pub enum ProjectAction {
+    /// Create a new project
+    ///
+    /// Examples:
+    ///   github-edit-cli project create --owner "octocat" --title "Roadmap" --project-type user
+    ///   github-edit-cli project create --owner "my-org" --title "Sprint Board" --project-type organization
+    Create {
+        /// User or organization that will own the project
+        #[arg(long, value_name = "OWNER")]
+        owner: String,
+        /// Project title
+        #[arg(long, value_name = "TITLE")]
+        title: String,
+        /// Project type (user or organization)
+        #[arg(long, value_name = "TYPE")]
+        project_type: ProjectType,
+    },
+    /// Show a project with its settings, fields and item count
+    ///
+    /// Examples:
+    ///   github-edit-cli project get --owner "octocat" --number 1 --project-type user
+    Get {
+        /// User or organization that owns the project
+        #[arg(long, value_name = "OWNER")]
+        owner: String,
+        /// Project number
+        #[arg(long, value_name = "NUMBER")]
+        number: u64,
+        /// Project type (user or organization)
+        #[arg(long, value_name = "TYPE")]
+        project_type: ProjectType,
+    },
+    /// List the items of a project with their field values
+    ///
+    /// Examples:
+    ///   github-edit-cli project list-items --project-node-id "PVT_kwDOBw6lbs4AAVGQ"
+    ///   github-edit-cli project list-items --project-node-id "PVT_kwDOBw6lbs4AAVGQ" --include-archived --all
+    ListItems {
+        /// Project node ID (GraphQL ID from GitHub Projects)
+        #[arg(long, value_name = "NODE_ID")]
+        project_node_id: String,
+        /// List archived items as well
+        #[arg(long)]
+        include_archived: bool,
+        #[command(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// List the fields of a project with their single select options and iterations
+    ///
+    /// Examples:
+    ///   github-edit-cli project list-fields --project-node-id "PVT_kwDOBw6lbs4AAVGQ"
+    ListFields {
+        /// Project node ID (GraphQL ID from GitHub Projects)
+        #[arg(long, value_name = "NODE_ID")]
+        project_node_id: String,
+    },
+    /// Copy a project, e.g. to instantiate a template board
+    ///
+    /// Examples:
+    ///   github-edit-cli project copy --source-project-node-id "PVT_kwDOBw6lbs4AAVGQ" --owner "my-org" --project-type organization --title "Sprint 12"
+    ///   github-edit-cli project copy --source-project-node-id "PVT_kwDOBw6lbs4AAVGQ" --owner "octocat" --project-type user --title "Roadmap" --include-draft-issues
+    Copy {
+        /// Node ID of the project to copy
+        #[arg(long, value_name = "NODE_ID")]
+        source_project_node_id: String,
+        /// User or organization that will own the copy
+        #[arg(long, value_name = "OWNER")]
+        owner: String,
+        /// Owner type of the copy (user or organization)
+        #[arg(long, value_name = "TYPE")]
+        project_type: ProjectType,
+        /// Title of the copy
+        #[arg(long, value_name = "TITLE")]
+        title: String,
+        /// Copy draft issues as well
+        #[arg(long)]
+        include_draft_issues: bool,
+    },
     /// Update a project item field value
     ///
+    /// The field may be given by name instead of ID, in which case the field
+    /// type is taken from the field definition, and the item by the URL of its
+    /// issue or pull request.
+    ///
     /// Examples:
     ///   github-edit-cli project update-field --project-node-id "PN_kwDOBw6lbs4AAVGQ" --project-item-id "PVTI_lADOBw6lbs4AAVGQzgF6sCo" --project-field-id "PVTF_lADOBw6lbs4AAVGQzgF6sCo" --field-type text --value "In Progress"
     ///   github-edit-cli project update-field --project-node-id "PN_kwDOBw6lbs4AAVGQ" --project-item-id "PVTI_lADOBw6lbs4AAVGQzgF6sCo" --project-field-id "PVTF_lADOBw6lbs4AAVGQzgF6sCo" --field-type single_select --value "High Priority"
+    ///   github-edit-cli project update-field --project-node-id "PN_kwDOBw6lbs4AAVGQ" --content-url https://github.com/owner/repo/issues/123 --field-name "Status" --value "In Progress"
     UpdateField {
         /// Project node ID (GraphQL ID from GitHub Projects)
         ///
@@ -43,8 +133,16 @@ pub enum ProjectAction {
         ///   PVTI_lADOBw6lbs4AAVGQzgF6sCo
         ///   PVTI_lADOABcDEf4AAGH1zgH7tDp
         ///   PVTI_lADOXYZ123abcDEFghI8uEm
-        #[arg(long, value_name = "ITEM_ID")]
-        project_item_id: String,
+        #[arg(
+            long,
+            value_name = "ITEM_ID",
+            required_unless_present = "content_url",
+            conflicts_with = "content_url"
+        )]
+        project_item_id: Option<String>,
+        /// Issue or pull request URL whose project item to update, instead of --project-item-id
+        #[arg(long, value_name = "URL")]
+        content_url: Option<String>,
         /// Field ID (GraphQL node ID for the specific field/column)
         ///
         /// This represents a specific field (column) in your project.
@@ -54,8 +152,16 @@ pub enum ProjectAction {
         ///   PVTF_lADOBw6lbs4AAVGQzgF6sCo (Status field)
         ///   PVTF_lADOABcDEf4AAGH1zgH7tDp (Priority field)
         ///   PVTF_lADOXYZ123abcDEFghI8uEm (Assignee field)
-        #[arg(long, value_name = "FIELD_ID")]
-        project_field_id: String,
+        #[arg(
+            long,
+            value_name = "FIELD_ID",
+            required_unless_present = "field_name",
+            conflicts_with = "field_name"
+        )]
+        project_field_id: Option<String>,
+        /// Field name, resolved to its field ID (e.g. "Status")
+        #[arg(long, value_name = "NAME")]
+        field_name: Option<String>,
         /// Field type (determines how the value is interpreted)
         ///
         /// Valid field types:
@@ -63,7 +169,9 @@ pub enum ProjectAction {
         ///   number        - Numeric fields (e.g., "5", "42", "100")
         ///   date          - Date fields in ISO format (e.g., "2024-01-15", "2024-12-31")
         ///   single_select - Single choice from predefined options (e.g., "High", "Medium", "Low")
-        ///   multi_select  - Multiple choices from predefined options (comma-separated)
+        ///   multi_select  - Multiple choices (comma-separated); replaces all issue
+        ///                   labels for a Labels field, or sets one option of a
+        ///                   single select field
         ///
         /// Examples by type:
         ///   --field-type text --value "Ready for deployment"
@@ -71,8 +179,10 @@ pub enum ProjectAction {
         ///   --field-type date --value "2024-03-15"
         ///   --field-type single_select --value "High Priority"
         ///   --field-type multi_select --value "bug,frontend,urgent"
-        #[arg(long, value_name = "TYPE")]
-        field_type: ProjectCustomFieldType,
+        ///
+        /// Optional with --field-name, which takes the type from the field.
+        #[arg(long, value_name = "TYPE", required_unless_present = "field_name")]
+        field_type: Option<ProjectCustomFieldType>,
         /// Field value (format depends on field type)
         ///
         /// Value formats by field type:
@@ -178,6 +288,7 @@ pub enum ProjectAction {
     ///
     /// Examples:
     ///   github-edit-cli project update-single-select-field --project-node-id "PN_kwDOBw6lbs4AAVGQ" --project-item-id "PVTI_lADOBw6lbs4AAVGQzgF6sCo" --project-field-id "PVTF_lADOBw6lbs4AAVGQzgF6sCo" --option-id "f75ad846"
+    ///   github-edit-cli project update-single-select-field --project-node-id "PN_kwDOBw6lbs4AAVGQ" --project-item-id "PVTI_lADOBw6lbs4AAVGQzgF6sCo" --project-field-id "PVTF_lADOBw6lbs4AAVGQzgF6sCo" --option-name "In Progress"
     UpdateSingleSelectField {
         /// Project node ID (GraphQL ID from GitHub Projects)
         #[arg(long, value_name = "NODE_ID")]
@@ -189,8 +300,16 @@ pub enum ProjectAction {
         #[arg(long, value_name = "FIELD_ID")]
         project_field_id: String,
         /// Option ID for the selected value
-        #[arg(long, value_name = "OPTION_ID")]
-        option_id: String,
+        #[arg(
+            long,
+            value_name = "OPTION_ID",
+            required_unless_present = "option_name",
+            conflicts_with = "option_name"
+        )]
+        option_id: Option<String>,
+        /// Option name for the selected value, resolved to its option ID
+        #[arg(long, value_name = "OPTION_NAME")]
+        option_name: Option<String>,
     },
     /// Add an issue to a project
     ///
@@ -214,6 +333,8 @@ pub enum ProjectAction {
     ///
     /// Examples:
     ///   github-edit-cli project add-pull-request --project-node-id "PN_kwDOBw6lbs4AAVGQ" --owner "octocat" --repo "Hello-World" --pull-request-number 456
+    ///   github-edit-cli project add-pr --project-node-id "PN_kwDOBw6lbs4AAVGQ" --owner "octocat" --repo "Hello-World" --pull-request-number 456
+    #[command(visible_alias = "add-pr")]
     AddPullRequest {
         /// Project node ID (GraphQL ID from GitHub Projects)
         #[arg(long, value_name = "NODE_ID")]
@@ -228,33 +349,239 @@ pub enum ProjectAction {
         #[arg(long, value_name = "NUMBER")]
         pull_request_number: u32,
     },
+    /// Remove an item from a project
+    ///
+    /// Examples:
+    ///   github-edit-cli project remove-item --project-node-id "PN_kwDOBw6lbs4AAVGQ" --project-item-id "PVTI_lADOBw6lbs4AAVGQzgF6sCo"
+    RemoveItem {
+        /// Project node ID (GraphQL ID from GitHub Projects)
+        #[arg(long, value_name = "NODE_ID")]
+        project_node_id: String,
+        /// Project item ID (GraphQL node ID for the specific item/row)
+        #[arg(long, value_name = "ITEM_ID")]
+        project_item_id: String,
+    },
+    /// Archive a project item
+    ///
+    /// Examples:
+    ///   github-edit-cli project archive-item --project-node-id "PN_kwDOBw6lbs4AAVGQ" --project-item-id "PVTI_lADOBw6lbs4AAVGQzgF6sCo"
+    ArchiveItem {
+        /// Project node ID (GraphQL ID from GitHub Projects)
+        #[arg(long, value_name = "NODE_ID")]
+        project_node_id: String,
+        /// Project item ID (GraphQL node ID for the specific item/row)
+        #[arg(long, value_name = "ITEM_ID")]
+        project_item_id: String,
+    },
+    /// Restore an archived project item
+    ///
+    /// Examples:
+    ///   github-edit-cli project unarchive-item --project-node-id "PN_kwDOBw6lbs4AAVGQ" --project-item-id "PVTI_lADOBw6lbs4AAVGQzgF6sCo"
+    UnarchiveItem {
+        /// Project node ID (GraphQL ID from GitHub Projects)
+        #[arg(long, value_name = "NODE_ID")]
+        project_node_id: String,
+        /// Project item ID (GraphQL node ID for the specific item/row)
+        #[arg(long, value_name = "ITEM_ID")]
+        project_item_id: String,
+    },
+    /// Move a project item within the project's manual ordering
+    ///
+    /// Examples:
+    ///   github-edit-cli project move-item --project-node-id "PN_kwDOBw6lbs4AAVGQ" --project-item-id "PVTI_lADOBw6lbs4AAVGQzgF6sCo" --after-item-id "PVTI_lADOBw6lbs4AAVGQzgF6sCp"
+    ///   github-edit-cli project move-item --project-node-id "PN_kwDOBw6lbs4AAVGQ" --project-item-id "PVTI_lADOBw6lbs4AAVGQzgF6sCo"
+    MoveItem {
+        /// Project node ID (GraphQL ID from GitHub Projects)
+        #[arg(long, value_name = "NODE_ID")]
+        project_node_id: String,
+        /// Project item ID (GraphQL node ID for the specific item/row)
+        #[arg(long, value_name = "ITEM_ID")]
+        project_item_id: String,
+        /// Item to place the moved item after; omit to move it to the top
+        #[arg(long, value_name = "ITEM_ID")]
+        after_item_id: Option<String>,
+    },
+    /// Link a repository to a project
+    ///
+    /// Examples:
+    ///   github-edit-cli project link-repository --project-node-id "PN_kwDOBw6lbs4AAVGQ" --owner "octocat" --repo "Hello-World"
+    LinkRepository {
+        /// Project node ID (GraphQL ID from GitHub Projects)
+        #[arg(long, value_name = "NODE_ID")]
+        project_node_id: String,
+        /// Repository owner
+        #[arg(long, value_name = "OWNER")]
+        owner: String,
+        /// Repository name
+        #[arg(long, value_name = "REPO")]
+        repo: String,
+    },
+    /// Unlink a repository from a project
+    ///
+    /// Examples:
+    ///   github-edit-cli project unlink-repository --project-node-id "PN_kwDOBw6lbs4AAVGQ" --owner "octocat" --repo "Hello-World"
+    UnlinkRepository {
+        /// Project node ID (GraphQL ID from GitHub Projects)
+        #[arg(long, value_name = "NODE_ID")]
+        project_node_id: String,
+        /// Repository owner
+        #[arg(long, value_name = "OWNER")]
+        owner: String,
+        /// Repository name
+        #[arg(long, value_name = "REPO")]
+        repo: String,
+    },
+    /// Link an organization team to a project
+    ///
+    /// Examples:
+    ///   github-edit-cli project link-team --project-node-id "PN_kwDOBw6lbs4AAVGQ" --organization "my-org" --team-slug "core-team"
+    LinkTeam {
+        /// Project node ID (GraphQL ID from GitHub Projects)
+        #[arg(long, value_name = "NODE_ID")]
+        project_node_id: String,
+        /// Organization that owns the team
+        #[arg(long, value_name = "ORG")]
+        organization: String,
+        /// Team slug
+        #[arg(long, value_name = "SLUG")]
+        team_slug: String,
+    },
+    /// Unlink an organization team from a project
+    ///
+    /// Examples:
+    ///   github-edit-cli project unlink-team --project-node-id "PN_kwDOBw6lbs4AAVGQ" --organization "my-org" --team-slug "core-team"
+    UnlinkTeam {
+        /// Project node ID (GraphQL ID from GitHub Projects)
+        #[arg(long, value_name = "NODE_ID")]
+        project_node_id: String,
+        /// Organization that owns the team
+        #[arg(long, value_name = "ORG")]
+        organization: String,
+        /// Team slug
+        #[arg(long, value_name = "SLUG")]
+        team_slug: String,
+    },
 }
 
 pub async fn execute_project_action(
     github_client: &GitHubClient,
     action: ProjectAction,
+    output: OutputFormat,
 ) -> Result<()> {
-    match action {
+    let result = match action {
+        ProjectAction::Create {
+            owner,
+            title,
+            project_type,
+        } => {
+            let project =
+                project::create_project(github_client, &Owner(owner), &title, project_type).await?;
+            ProjectResult::created(project)
+        }
+        ProjectAction::Get {
+            owner,
+            number,
+            project_type,
+        } => {
+            let project_id = ProjectId::new(Owner(owner), ProjectNumber(number), project_type);
+            let details = project::get_project(github_client, &project_id).await?;
+            ProjectResult::Details(Box::new(details))
+        }
+        ProjectAction::ListItems {
+            project_node_id,
+            include_archived,
+            pagination,
+        } => {
+            let items =
+                project::list_project_items(github_client, &ProjectNodeId::new(project_node_id))
+                    .await?
+                    .into_iter()
+                    .filter(|item| include_archived || !item.archived)
+                    .collect();
+            ProjectResult::Items(pagination.select(items))
+        }
+        ProjectAction::ListFields { project_node_id } => {
+            let fields =
+                project::list_project_fields(github_client, &ProjectNodeId::new(project_node_id))
+                    .await?;
+            ProjectResult::Fields { fields }
+        }
+        ProjectAction::Copy {
+            source_project_node_id,
+            owner,
+            project_type,
+            title,
+            include_draft_issues,
+        } => {
+            let project = project::copy_project(
+                github_client,
+                &ProjectNodeId::new(source_project_node_id),
+                &Owner(owner),
+                project_type,
+                &title,
+                include_draft_issues,
+            )
+            .await?;
+            ProjectResult::copied(project)
+        }
         ProjectAction::UpdateField {
             project_node_id,
             project_item_id,
+            content_url,
             project_field_id,
+            field_name,
             field_type,
             value,
         } => {
             let typed_project_node_id = ProjectNodeId::new(project_node_id);
-            let typed_project_item_id = ProjectItemId::new(project_item_id);
-            let typed_project_field_id = ProjectFieldId::new(project_field_id);
-
-            // Parse field type from string to enum
-            let field_type_enum = ProjectCustomFieldType::from_str(&field_type.as_ref())
-                .map_err(|_| anyhow::anyhow!(
-                    "Unsupported field type '{}'. Supported types: text, number, date, single_select, multi_select",
-                    field_type.as_ref()
-                ))?;
+            let typed_project_item_id = match (project_item_id, content_url) {
+                (Some(project_item_id), _) => ProjectItemId::new(project_item_id),
+                (None, Some(content_url)) => {
+                    project::find_project_item_for_content(
+                        github_client,
+                        &typed_project_node_id,
+                        &content_url,
+                    )
+                    .await?
+                }
+                (None, None) => {
+                    return Err(anyhow::anyhow!(
+                        "Either --project-item-id or --content-url is required"
+                    ));
+                }
+            };
+            let (typed_project_field_id, field_type) = match (project_field_id, field_name) {
+                (Some(project_field_id), _) => (
+                    ProjectFieldId::new(project_field_id),
+                    field_type.ok_or_else(|| anyhow::anyhow!("--field-type is required"))?,
+                ),
+                (None, Some(field_name)) => {
+                    let field = project::resolve_project_field(
+                        github_client,
+                        &typed_project_node_id,
+                        &field_name,
+                    )
+                    .await?;
+                    let field_type = match field_type {
+                        Some(field_type) => field_type,
+                        None => field.data_type.custom_field_type().ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "Field '{}' is a {} field, which can not be updated with update-field",
+                                field.name,
+                                field.data_type
+                            )
+                        })?,
+                    };
+                    (field.field_id, field_type)
+                }
+                (None, None) => {
+                    return Err(anyhow::anyhow!(
+                        "Either --project-field-id or --field-name is required"
+                    ));
+                }
+            };
 
-            // Parse field value using the ProjectFieldValue method
-            let parsed_value = ProjectFieldValue::from_string_with_type(&field_type_enum, &value)?;
+            let parsed_value = ProjectFieldValue::from_string_with_type(&field_type, &value)?;
 
             project::update_project_item_field(
                 github_client,
@@ -264,7 +591,11 @@ pub async fn execute_project_action(
                 &parsed_value,
             )
             .await?;
-            println!("Updated project item field successfully");
+            ProjectResult::ItemFieldUpdated {
+                project_item_id: typed_project_item_id,
+                project_field_id: typed_project_field_id,
+                field: "field",
+            }
         }
         ProjectAction::UpdateFieldValue {
             project_node_id,
@@ -278,7 +609,7 @@ pub async fn execute_project_action(
             let typed_project_field_id = ProjectFieldId::new(project_field_id);
 
             // Parse field type from string to enum
-            let field_type_enum = ProjectCustomFieldType::from_str(&field_type.as_ref())
+            let field_type_enum = ProjectCustomFieldType::from_str(field_type.as_ref())
                 .map_err(|_| anyhow::anyhow!(
                     "Unsupported field type '{}'. Supported types: text, number, date, single_select, multi_select",
                     field_type.as_ref()
@@ -295,7 +626,11 @@ pub async fn execute_project_action(
                 &parsed_value,
             )
             .await?;
-            println!("Updated project item field value successfully");
+            ProjectResult::ItemFieldUpdated {
+                project_item_id: typed_project_item_id,
+                project_field_id: typed_project_field_id,
+                field: "field value",
+            }
         }
         ProjectAction::UpdateTextField {
             project_node_id,
@@ -315,7 +650,11 @@ pub async fn execute_project_action(
                 &text_value,
             )
             .await?;
-            println!("Updated project item text field successfully");
+            ProjectResult::ItemFieldUpdated {
+                project_item_id: typed_project_item_id,
+                project_field_id: typed_project_field_id,
+                field: "text field",
+            }
         }
         ProjectAction::UpdateNumberField {
             project_node_id,
@@ -335,7 +674,11 @@ pub async fn execute_project_action(
                 number_value,
             )
             .await?;
-            println!("Updated project item number field successfully");
+            ProjectResult::ItemFieldUpdated {
+                project_item_id: typed_project_item_id,
+                project_field_id: typed_project_field_id,
+                field: "number field",
+            }
         }
         ProjectAction::UpdateDateField {
             project_node_id,
@@ -360,27 +703,55 @@ pub async fn execute_project_action(
                 parsed_date,
             )
             .await?;
-            println!("Updated project item date field successfully");
+            ProjectResult::ItemFieldUpdated {
+                project_item_id: typed_project_item_id,
+                project_field_id: typed_project_field_id,
+                field: "date field",
+            }
         }
         ProjectAction::UpdateSingleSelectField {
             project_node_id,
             project_item_id,
             project_field_id,
             option_id,
+            option_name,
         } => {
             let typed_project_node_id = ProjectNodeId::new(project_node_id);
             let typed_project_item_id = ProjectItemId::new(project_item_id);
             let typed_project_field_id = ProjectFieldId::new(project_field_id);
 
-            project::update_project_item_single_select_field(
-                github_client,
-                &typed_project_node_id,
-                &typed_project_item_id,
-                &typed_project_field_id,
-                &option_id,
-            )
-            .await?;
-            println!("Updated project item single select field successfully");
+            match (option_id, option_name) {
+                (Some(option_id), _) => {
+                    project::update_project_item_single_select_field(
+                        github_client,
+                        &typed_project_node_id,
+                        &typed_project_item_id,
+                        &typed_project_field_id,
+                        &option_id,
+                    )
+                    .await?;
+                }
+                (None, Some(option_name)) => {
+                    project::update_project_item_single_select_field_by_name(
+                        github_client,
+                        &typed_project_node_id,
+                        &typed_project_item_id,
+                        &typed_project_field_id,
+                        &option_name,
+                    )
+                    .await?;
+                }
+                (None, None) => {
+                    return Err(anyhow::anyhow!(
+                        "Either --option-id or --option-name is required"
+                    ));
+                }
+            }
+            ProjectResult::ItemFieldUpdated {
+                project_item_id: typed_project_item_id,
+                project_field_id: typed_project_field_id,
+                field: "single select field",
+            }
         }
         ProjectAction::AddIssue {
             project_node_id,
@@ -399,10 +770,7 @@ pub async fn execute_project_action(
                 typed_issue_number,
             )
             .await?;
-            println!(
-                "Added issue to project successfully. Project item ID: {}",
-                project_item_id.0.as_str()
-            );
+            ProjectResult::IssueAdded { project_item_id }
         }
         ProjectAction::AddPullRequest {
             project_node_id,
@@ -421,11 +789,369 @@ pub async fn execute_project_action(
                 typed_pr_number,
             )
             .await?;
-            println!(
+            ProjectResult::PullRequestAdded { project_item_id }
+        }
+        ProjectAction::RemoveItem {
+            project_node_id,
+            project_item_id,
+        } => {
+            let typed_project_node_id = ProjectNodeId::new(project_node_id);
+            let typed_project_item_id = ProjectItemId::new(project_item_id);
+
+            project::remove_item_from_project(
+                github_client,
+                &typed_project_node_id,
+                &typed_project_item_id,
+            )
+            .await?;
+            ProjectResult::ItemRemoved {
+                project_item_id: typed_project_item_id,
+            }
+        }
+        ProjectAction::ArchiveItem {
+            project_node_id,
+            project_item_id,
+        } => {
+            let typed_project_node_id = ProjectNodeId::new(project_node_id);
+            let typed_project_item_id = ProjectItemId::new(project_item_id);
+
+            project::archive_project_item(
+                github_client,
+                &typed_project_node_id,
+                &typed_project_item_id,
+            )
+            .await?;
+            ProjectResult::ItemArchived {
+                project_item_id: typed_project_item_id,
+            }
+        }
+        ProjectAction::UnarchiveItem {
+            project_node_id,
+            project_item_id,
+        } => {
+            let typed_project_node_id = ProjectNodeId::new(project_node_id);
+            let typed_project_item_id = ProjectItemId::new(project_item_id);
+
+            project::unarchive_project_item(
+                github_client,
+                &typed_project_node_id,
+                &typed_project_item_id,
+            )
+            .await?;
+            ProjectResult::ItemUnarchived {
+                project_item_id: typed_project_item_id,
+            }
+        }
+        ProjectAction::MoveItem {
+            project_node_id,
+            project_item_id,
+            after_item_id,
+        } => {
+            let typed_project_node_id = ProjectNodeId::new(project_node_id);
+            let typed_project_item_id = ProjectItemId::new(project_item_id);
+            let typed_after_item_id = after_item_id.map(ProjectItemId::new);
+
+            project::update_project_item_position(
+                github_client,
+                &typed_project_node_id,
+                &typed_project_item_id,
+                typed_after_item_id.as_ref(),
+            )
+            .await?;
+            ProjectResult::ItemMoved {
+                project_item_id: typed_project_item_id,
+            }
+        }
+        ProjectAction::LinkRepository {
+            project_node_id,
+            owner,
+            repo,
+        } => {
+            let typed_project_node_id = ProjectNodeId::new(project_node_id);
+            let repository_id = RepositoryId::new(owner, repo);
+
+            project::link_repository_to_project(
+                github_client,
+                &typed_project_node_id,
+                &repository_id,
+            )
+            .await?;
+            ProjectResult::RepositoryLinked {
+                repository: repository_id,
+            }
+        }
+        ProjectAction::UnlinkRepository {
+            project_node_id,
+            owner,
+            repo,
+        } => {
+            let typed_project_node_id = ProjectNodeId::new(project_node_id);
+            let repository_id = RepositoryId::new(owner, repo);
+
+            project::unlink_repository_from_project(
+                github_client,
+                &typed_project_node_id,
+                &repository_id,
+            )
+            .await?;
+            ProjectResult::RepositoryUnlinked {
+                repository: repository_id,
+            }
+        }
+        ProjectAction::LinkTeam {
+            project_node_id,
+            organization,
+            team_slug,
+        } => {
+            let typed_project_node_id = ProjectNodeId::new(project_node_id);
+
+            project::link_team_to_project(
+                github_client,
+                &typed_project_node_id,
+                &Owner(organization.clone()),
+                &team_slug,
+            )
+            .await?;
+            ProjectResult::TeamLinked {
+                organization,
+                team_slug,
+            }
+        }
+        ProjectAction::UnlinkTeam {
+            project_node_id,
+            organization,
+            team_slug,
+        } => {
+            let typed_project_node_id = ProjectNodeId::new(project_node_id);
+
+            project::unlink_team_from_project(
+                github_client,
+                &typed_project_node_id,
+                &Owner(organization.clone()),
+                &team_slug,
+            )
+            .await?;
+            ProjectResult::TeamUnlinked {
+                organization,
+                team_slug,
+            }
+        }
+    };
+    output.print(&result)
+}
+
+/// Result of a project command
+#[derive(Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+enum ProjectResult {
+    Created {
+        project_number: ProjectNumber,
+        project_node_id: ProjectNodeId,
+        url: String,
+    },
+    Details(Box<ProjectDetails>),
+    Items(Page<ProjectItem>),
+    Fields {
+        fields: Vec<ProjectField>,
+    },
+    Copied {
+        project_number: ProjectNumber,
+        project_node_id: ProjectNodeId,
+        url: String,
+    },
+    ItemFieldUpdated {
+        project_item_id: ProjectItemId,
+        project_field_id: ProjectFieldId,
+        /// Kind of the update, e.g. "text field"
+        #[serde(skip)]
+        field: &'static str,
+    },
+    IssueAdded {
+        project_item_id: ProjectItemId,
+    },
+    PullRequestAdded {
+        project_item_id: ProjectItemId,
+    },
+    ItemRemoved {
+        project_item_id: ProjectItemId,
+    },
+    ItemArchived {
+        project_item_id: ProjectItemId,
+    },
+    ItemUnarchived {
+        project_item_id: ProjectItemId,
+    },
+    ItemMoved {
+        project_item_id: ProjectItemId,
+    },
+    RepositoryLinked {
+        repository: RepositoryId,
+    },
+    RepositoryUnlinked {
+        repository: RepositoryId,
+    },
+    TeamLinked {
+        organization: String,
+        team_slug: String,
+    },
+    TeamUnlinked {
+        organization: String,
+        team_slug: String,
+    },
+}
+
+impl ProjectResult {
+    fn created(project: Project) -> Self {
+        ProjectResult::Created {
+            url: project.project_id.url(),
+            project_number: project.project_id.number,
+            project_node_id: project.project_node_id,
+        }
+    }
+
+    fn copied(project: Project) -> Self {
+        ProjectResult::Copied {
+            url: project.project_id.url(),
+            project_number: project.project_id.number,
+            project_node_id: project.project_node_id,
+        }
+    }
+}
+
+impl fmt::Display for ProjectResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProjectResult::Created {
+                project_number,
+                project_node_id,
+                url,
+            } => writeln!(
+                f,
+                "Created project successfully. Project number: {}, project node ID: {}, URL: {}",
+                project_number, project_node_id, url
+            ),
+            ProjectResult::Details(details) => {
+                let project = &details.project;
+                writeln!(f, "Project: {}", project.title)?;
+                writeln!(f, "Project number: {}", project.project_id.number)?;
+                writeln!(f, "Project node ID: {}", project.project_node_id)?;
+                writeln!(f, "URL: {}", project.project_id.url())?;
+                if let Some(description) = &project.description {
+                    writeln!(f, "Description: {}", description)?;
+                }
+                writeln!(f, "Visibility: {}", details.visibility)?;
+                writeln!(f, "State: {}", details.state)?;
+                writeln!(f, "Items: {}", details.item_count)?;
+                writeln!(f, "Fields:")?;
+                for field in &details.fields {
+                    writeln!(
+                        f,
+                        "  {} [{}] (field ID: {})",
+                        field.name, field.data_type, field.field_id
+                    )?;
+                }
+                Ok(())
+            }
+            ProjectResult::Items(items) => {
+                for item in &items.items {
+                    let values = item
+                        .field_values
+                        .iter()
+                        .map(|field_value| {
+                            format!(
+                                "{}: {}",
+                                field_value.field_name,
+                                format_field_value(&field_value.value)
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join("; ");
+                    if item.archived {
+                        writeln!(f, "{}\t(archived)\t{}", item.item_id, values)?;
+                    } else {
+                        writeln!(f, "{}\t{}", item.item_id, values)?;
+                    }
+                }
+                next_page_line(f, "items", items.page, items.has_next_page)
+            }
+            ProjectResult::Fields { fields } => {
+                for field in fields {
+                    writeln!(
+                        f,
+                        "{} [{}] (field ID: {})",
+                        field.name, field.data_type, field.field_id
+                    )?;
+                    for option in &field.options {
+                        writeln!(f, "  option: {} (option ID: {})", option.name, option.id)?;
+                    }
+                    if let Some(configuration) = &field.iteration_configuration {
+                        for iteration in &configuration.iterations {
+                            writeln!(
+                                f,
+                                "  iteration: {} starting {} for {} days (iteration ID: {})",
+                                iteration.title,
+                                iteration.start_date,
+                                iteration.duration,
+                                iteration.id
+                            )?;
+                        }
+                    }
+                }
+                Ok(())
+            }
+            ProjectResult::Copied {
+                project_number,
+                project_node_id,
+                url,
+            } => writeln!(
+                f,
+                "Copied project successfully. Project number: {}, project node ID: {}, URL: {}",
+                project_number, project_node_id, url
+            ),
+            ProjectResult::ItemFieldUpdated { field, .. } => {
+                writeln!(f, "Updated project item {} successfully", field)
+            }
+            ProjectResult::IssueAdded { project_item_id } => writeln!(
+                f,
+                "Added issue to project successfully. Project item ID: {}",
+                project_item_id.0
+            ),
+            ProjectResult::PullRequestAdded { project_item_id } => writeln!(
+                f,
                 "Added pull request to project successfully. Project item ID: {}",
-                project_item_id.0.as_str()
-            );
+                project_item_id.0
+            ),
+            ProjectResult::ItemRemoved { .. } => {
+                writeln!(f, "Removed item from project successfully")
+            }
+            ProjectResult::ItemArchived { .. } => {
+                writeln!(f, "Archived project item successfully")
+            }
+            ProjectResult::ItemUnarchived { .. } => {
+                writeln!(f, "Unarchived project item successfully")
+            }
+            ProjectResult::ItemMoved { .. } => writeln!(f, "Moved project item successfully"),
+            ProjectResult::RepositoryLinked { .. } => {
+                writeln!(f, "Linked repository to project successfully")
+            }
+            ProjectResult::RepositoryUnlinked { .. } => {
+                writeln!(f, "Unlinked repository from project successfully")
+            }
+            ProjectResult::TeamLinked { .. } => writeln!(f, "Linked team to project successfully"),
+            ProjectResult::TeamUnlinked { .. } => {
+                writeln!(f, "Unlinked team from project successfully")
+            }
         }
     }
-    Ok(())
+}
+
+/// Format a field value the way it is shown in the project
+fn format_field_value(value: &ProjectFieldValue) -> String {
+    match value {
+        ProjectFieldValue::Text(text) => text.clone(),
+        ProjectFieldValue::Number(number) => number.to_string(),
+        ProjectFieldValue::Date(date) => date.format("%Y-%m-%d").to_string(),
+        ProjectFieldValue::SingleSelect(name) => name.clone(),
+        ProjectFieldValue::MultiSelect(values) => values.join(", "),
+    }
 }
@@ -4,21 +4,40 @@
 //! for pull request management operations including creating, commenting,
 //! editing, and managing assignees, reviewers, labels, and milestones.
 
+use super::checkout::{self, LocalRepository};
+use super::input::{read_body, require_body, resolve_item_url};
+use super::output::OutputFormat;
+use super::template::TemplateArgs;
+use super::watch::{self, WatchArgs};
 use anyhow::Result;
 use clap::Subcommand;
 use github_edit::github::GitHubClient;
-use github_edit::tools::functions::pull_request;
+use github_edit::tools::functions::issue::{DEFAULT_COMMENT_LIMIT, MAX_COMMENT_LIMIT};
+use github_edit::tools::functions::{pull_request, repository};
+use github_edit::types::User;
 use github_edit::types::label::Label;
-use github_edit::types::pull_request::{Branch, PullRequestCommentNumber, PullRequestNumber};
+use github_edit::types::pull_request::{
+    Branch, PullRequestCommentNumber, PullRequestDetails, PullRequestId, PullRequestNumber,
+    PullRequestUrl,
+};
 use github_edit::types::repository::{MilestoneNumber, RepositoryId, RepositoryUrl};
+use github_edit::types::template::TemplateKind;
+use serde::Serialize;
+use std::fmt;
+use std::path::PathBuf;
 
 #[derive(Subcommand)]
 pub enum PullRequestAction {
     /// Get details for pull requests by URLs
     ///
+    /// Prints each pull request with its description and its oldest
+    /// discussion comments, or the pull requests as JSON with --output json.
+    ///
     /// Examples:
     ///   github-edit-cli pull-request get https://github.com/owner/repo/pull/123
     ///   github-edit-cli pull-request get https://github.com/rust-lang/rust/pull/98765 https://github.com/tokio-rs/tokio/pull/5432
+    ///   github-edit-cli pull-request get https://github.com/owner/repo/pull/123 --comment-limit 100
+    ///   github-edit-cli --output json pull-request get https://github.com/owner/repo/pull/123 --no-comments
     Get {
         /// Pull request URLs to fetch
         ///
@@ -28,12 +47,19 @@ pub enum PullRequestAction {
         ///   https://github.com/microsoft/vscode/pull/142857
         #[arg(required = true, value_name = "URL")]
         urls: Vec<String>,
+        /// Maximum number of discussion comments to include per pull request (at most 100)
+        #[arg(long, value_name = "COUNT", default_value_t = DEFAULT_COMMENT_LIMIT)]
+        comment_limit: usize,
+        /// Leave out the discussion comments
+        #[arg(long, conflicts_with = "comment_limit")]
+        no_comments: bool,
     },
     /// Create a new pull request
     ///
     /// Examples:
     ///   github-edit-cli pull-request create -r https://github.com/owner/repo -t "Fix authentication bug" --head feature-auth-fix --base main
     ///   github-edit-cli pull-request create --repository-url https://github.com/rust-lang/rust --title "Add async support to trait" --head async-trait --base master --body "This PR adds..." --draft
+    ///   github-edit-cli pull-request create -r https://github.com/owner/repo -t "Fix login" --head fix-login --base main --template feature --var issue=123
     Create {
         /// Repository URL (HTTPS format)
         ///
@@ -80,6 +106,11 @@ pub enum PullRequestAction {
         ///   "Closes #123\n\nThis implementation..."
         #[arg(short, long, value_name = "BODY")]
         body: Option<String>,
+        /// Read the pull request body from a file instead ("-" reads stdin, as does --body -)
+        #[arg(long, value_name = "PATH", conflicts_with = "body")]
+        body_file: Option<PathBuf>,
+        #[command(flatten)]
+        template: TemplateArgs,
         /// Create as draft pull request (not ready for review)
         ///
         /// Use this flag when:
@@ -95,7 +126,7 @@ pub enum PullRequestAction {
     ///   github-edit-cli pull-request comment -r https://github.com/owner/repo -p 123 -b "LGTM! Great work on this fix."
     ///   github-edit-cli pull-request comment --repository-url https://github.com/rust-lang/rust --pr 98765 --body "Could you add a test for the edge case?"
     Comment {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the pull request itself
         ///
         /// Examples:
         ///   https://github.com/owner/repo
@@ -103,14 +134,14 @@ pub enum PullRequestAction {
         ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Pull request number (numeric ID from the URL)
+        /// Pull request number (numeric ID from the URL; optional when --repository-url is the pull request URL)
         ///
         /// Examples:
         ///   123 (from https://github.com/owner/repo/pull/123)
         ///   98765 (from https://github.com/rust-lang/rust/pull/98765)
         ///   142857 (from https://github.com/microsoft/vscode/pull/142857)
         #[arg(short = 'p', long, value_name = "NUMBER")]
-        pull_request_number: u32,
+        pull_request_number: Option<u32>,
         /// Comment body (supports Markdown formatting)
         ///
         /// Examples:
@@ -118,8 +149,16 @@ pub enum PullRequestAction {
         ///   "Could you add a test for the edge case when input is null?"
         ///   "## Review Comments\n- Line 45: Consider using a more descriptive variable name"
         ///   "This looks good but please rebase on latest main"
-        #[arg(short, long, value_name = "BODY")]
-        body: String,
+        #[arg(
+            short,
+            long,
+            value_name = "BODY",
+            required_unless_present = "body_file"
+        )]
+        body: Option<String>,
+        /// Read the comment body from a file instead ("-" reads stdin, as does --body -)
+        #[arg(long, value_name = "PATH", conflicts_with = "body")]
+        body_file: Option<PathBuf>,
     },
     /// Close a pull request without merging
     ///
@@ -127,7 +166,7 @@ pub enum PullRequestAction {
     ///   github-edit-cli pull-request close -r https://github.com/owner/repo -p 123
     ///   github-edit-cli pull-request close --repository-url https://github.com/rust-lang/rust --pr 98765
     Close {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the pull request itself
         ///
         /// Examples:
         ///   https://github.com/owner/repo
@@ -135,14 +174,14 @@ pub enum PullRequestAction {
         ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Pull request number (numeric ID from the URL)
+        /// Pull request number (numeric ID from the URL; optional when --repository-url is the pull request URL)
         ///
         /// Examples:
         ///   123 (from https://github.com/owner/repo/pull/123)
         ///   98765 (from https://github.com/rust-lang/rust/pull/98765)
         ///   142857 (from https://github.com/microsoft/vscode/pull/142857)
         #[arg(short = 'p', long, value_name = "NUMBER")]
-        pull_request_number: u32,
+        pull_request_number: Option<u32>,
     },
     /// Edit the title of an existing pull request
     ///
@@ -150,7 +189,7 @@ pub enum PullRequestAction {
     ///   github-edit-cli pull-request edit-title -r https://github.com/owner/repo -p 123 -t "Updated: Fix authentication bug with OAuth flow"
     ///   github-edit-cli pull-request edit-title --repository-url https://github.com/rust-lang/rust --pr 98765 --title "[WIP] Feature: New async trait implementation"
     EditTitle {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the pull request itself
         ///
         /// Examples:
         ///   https://github.com/owner/repo
@@ -158,14 +197,14 @@ pub enum PullRequestAction {
         ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Pull request number (numeric ID from the URL)
+        /// Pull request number (numeric ID from the URL; optional when --repository-url is the pull request URL)
         ///
         /// Examples:
         ///   123 (from https://github.com/owner/repo/pull/123)
         ///   98765 (from https://github.com/rust-lang/rust/pull/98765)
         ///   142857 (from https://github.com/microsoft/vscode/pull/142857)
         #[arg(short = 'p', long, value_name = "NUMBER")]
-        pull_request_number: u32,
+        pull_request_number: Option<u32>,
         /// New title for the pull request
         ///
         /// Examples:
@@ -181,7 +220,7 @@ pub enum PullRequestAction {
     ///   github-edit-cli pull-request edit-body -r https://github.com/owner/repo -p 123 -b "Updated description with benchmark results..."
     ///   github-edit-cli pull-request edit-body --repository-url https://github.com/rust-lang/rust --pr 98765 --body "## Updated Implementation\nAfter review feedback..."
     EditBody {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the pull request itself
         ///
         /// Examples:
         ///   https://github.com/owner/repo
@@ -189,22 +228,30 @@ pub enum PullRequestAction {
         ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Pull request number (numeric ID from the URL)
+        /// Pull request number (numeric ID from the URL; optional when --repository-url is the pull request URL)
         ///
         /// Examples:
         ///   123 (from https://github.com/owner/repo/pull/123)
         ///   98765 (from https://github.com/rust-lang/rust/pull/98765)
         ///   142857 (from https://github.com/microsoft/vscode/pull/142857)
         #[arg(short = 'p', long, value_name = "NUMBER")]
-        pull_request_number: u32,
+        pull_request_number: Option<u32>,
         /// New body content (supports Markdown formatting)
         ///
         /// Examples:
         ///   "Updated implementation with performance improvements..."
         ///   "## Changes\n- Fixed memory leak\n- Added benchmarks\n## Results\nPerformance improved by 40%"
         ///   "After review feedback, I've updated the approach to..."
-        #[arg(short, long, value_name = "BODY")]
-        body: String,
+        #[arg(
+            short,
+            long,
+            value_name = "BODY",
+            required_unless_present = "body_file"
+        )]
+        body: Option<String>,
+        /// Read the new body from a file instead ("-" reads stdin, as does --body -)
+        #[arg(long, value_name = "PATH", conflicts_with = "body")]
+        body_file: Option<PathBuf>,
     },
     /// Edit an existing pull request comment
     ///
@@ -212,7 +259,7 @@ pub enum PullRequestAction {
     ///   github-edit-cli pull-request edit-comment -r https://github.com/owner/repo -p 123 -c 456 -b "Updated comment with clarification..."
     ///   github-edit-cli pull-request edit-comment --repository-url https://github.com/rust-lang/rust --pr 98765 --comment 789 --body "After thinking more about this..."
     EditComment {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the pull request itself
         ///
         /// Examples:
         ///   https://github.com/owner/repo
@@ -220,14 +267,14 @@ pub enum PullRequestAction {
         ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Pull request number (numeric ID from the URL)
+        /// Pull request number (numeric ID from the URL; optional when --repository-url is the pull request URL)
         ///
         /// Examples:
         ///   123 (from https://github.com/owner/repo/pull/123)
         ///   98765 (from https://github.com/rust-lang/rust/pull/98765)
         ///   142857 (from https://github.com/microsoft/vscode/pull/142857)
         #[arg(short = 'p', long, value_name = "NUMBER")]
-        pull_request_number: u32,
+        pull_request_number: Option<u32>,
         /// Comment number (numeric ID of the comment)
         ///
         /// Examples:
@@ -241,8 +288,16 @@ pub enum PullRequestAction {
         ///   "Updated comment with clarification..."
         ///   "After thinking more about this approach..."
         ///   "## Revised Analysis\nI've reconsidered the implementation..."
-        #[arg(short, long, value_name = "BODY")]
-        body: String,
+        #[arg(
+            short,
+            long,
+            value_name = "BODY",
+            required_unless_present = "body_file"
+        )]
+        body: Option<String>,
+        /// Read the new comment body from a file instead ("-" reads stdin, as does --body -)
+        #[arg(long, value_name = "PATH", conflicts_with = "body")]
+        body_file: Option<PathBuf>,
     },
     /// Delete a pull request comment
     ///
@@ -250,7 +305,7 @@ pub enum PullRequestAction {
     ///   github-edit-cli pull-request delete-comment -r https://github.com/owner/repo -p 123 -c 456
     ///   github-edit-cli pull-request delete-comment --repository-url https://github.com/rust-lang/rust --pr 98765 --comment 789
     DeleteComment {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the pull request itself
         ///
         /// Examples:
         ///   https://github.com/owner/repo
@@ -258,14 +313,14 @@ pub enum PullRequestAction {
         ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Pull request number (numeric ID from the URL)
+        /// Pull request number (numeric ID from the URL; optional when --repository-url is the pull request URL)
         ///
         /// Examples:
         ///   123 (from https://github.com/owner/repo/pull/123)
         ///   98765 (from https://github.com/rust-lang/rust/pull/98765)
         ///   142857 (from https://github.com/microsoft/vscode/pull/142857)
         #[arg(short = 'p', long, value_name = "NUMBER")]
-        pull_request_number: u32,
+        pull_request_number: Option<u32>,
         /// Comment number (numeric ID of the comment to delete)
         ///
         /// Examples:
@@ -280,7 +335,7 @@ pub enum PullRequestAction {
     ///   github-edit-cli pull-request add-assignees -r https://github.com/owner/repo -p 123 -a "user1,user2"
     ///   github-edit-cli pull-request add-assignees --repository-url https://github.com/rust-lang/rust --pr 98765 --assignees "maintainer1,maintainer2"
     AddAssignees {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the pull request itself
         ///
         /// Examples:
         ///   https://github.com/owner/repo
@@ -288,14 +343,14 @@ pub enum PullRequestAction {
         ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Pull request number (numeric ID from the URL)
+        /// Pull request number (numeric ID from the URL; optional when --repository-url is the pull request URL)
         ///
         /// Examples:
         ///   123 (from https://github.com/owner/repo/pull/123)
         ///   98765 (from https://github.com/rust-lang/rust/pull/98765)
         ///   142857 (from https://github.com/microsoft/vscode/pull/142857)
         #[arg(short = 'p', long, value_name = "NUMBER")]
-        pull_request_number: u32,
+        pull_request_number: Option<u32>,
         /// Comma-separated list of assignee usernames to add
         ///
         /// Examples:
@@ -311,7 +366,7 @@ pub enum PullRequestAction {
     ///   github-edit-cli pull-request remove-assignees -r https://github.com/owner/repo -p 123 -a "user1,user2"
     ///   github-edit-cli pull-request remove-assignees --repository-url https://github.com/rust-lang/rust --pr 98765 --assignees "maintainer1,maintainer2"
     RemoveAssignees {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the pull request itself
         ///
         /// Examples:
         ///   https://github.com/owner/repo
@@ -319,14 +374,14 @@ pub enum PullRequestAction {
         ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Pull request number (numeric ID from the URL)
+        /// Pull request number (numeric ID from the URL; optional when --repository-url is the pull request URL)
         ///
         /// Examples:
         ///   123 (from https://github.com/owner/repo/pull/123)
         ///   98765 (from https://github.com/rust-lang/rust/pull/98765)
         ///   142857 (from https://github.com/microsoft/vscode/pull/142857)
         #[arg(short = 'p', long, value_name = "NUMBER")]
-        pull_request_number: u32,
+        pull_request_number: Option<u32>,
         /// Comma-separated list of assignee usernames to remove
         ///
         /// Examples:
@@ -342,7 +397,7 @@ pub enum PullRequestAction {
     ///   github-edit-cli pull-request add-reviewers -r https://github.com/owner/repo -p 123 -u "reviewer1,reviewer2"
     ///   github-edit-cli pull-request add-reviewers --repository-url https://github.com/rust-lang/rust --pr 98765 --reviewers "expert1,expert2"
     AddReviewers {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the pull request itself
         ///
         /// Examples:
         ///   https://github.com/owner/repo
@@ -350,14 +405,14 @@ pub enum PullRequestAction {
         ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Pull request number (numeric ID from the URL)
+        /// Pull request number (numeric ID from the URL; optional when --repository-url is the pull request URL)
         ///
         /// Examples:
         ///   123 (from https://github.com/owner/repo/pull/123)
         ///   98765 (from https://github.com/rust-lang/rust/pull/98765)
         ///   142857 (from https://github.com/microsoft/vscode/pull/142857)
         #[arg(short = 'p', long, value_name = "NUMBER")]
-        pull_request_number: u32,
+        pull_request_number: Option<u32>,
         /// Comma-separated list of reviewer usernames
         ///
         /// Examples:
@@ -373,7 +428,7 @@ pub enum PullRequestAction {
     ///   github-edit-cli pull-request add-labels -r https://github.com/owner/repo -p 123 -l "bug,critical"
     ///   github-edit-cli pull-request add-labels --repository-url https://github.com/rust-lang/rust --pr 98765 --labels "enhancement,performance"
     AddLabels {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the pull request itself
         ///
         /// Examples:
         ///   https://github.com/owner/repo
@@ -381,14 +436,14 @@ pub enum PullRequestAction {
         ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Pull request number (numeric ID from the URL)
+        /// Pull request number (numeric ID from the URL; optional when --repository-url is the pull request URL)
         ///
         /// Examples:
         ///   123 (from https://github.com/owner/repo/pull/123)
         ///   98765 (from https://github.com/rust-lang/rust/pull/98765)
         ///   142857 (from https://github.com/microsoft/vscode/pull/142857)
         #[arg(short = 'p', long, value_name = "NUMBER")]
-        pull_request_number: u32,
+        pull_request_number: Option<u32>,
         /// Comma-separated list of label names
         ///
         /// Examples:
@@ -404,7 +459,7 @@ pub enum PullRequestAction {
     ///   github-edit-cli pull-request remove-labels -r https://github.com/owner/repo -p 123 -l "bug,critical"
     ///   github-edit-cli pull-request remove-labels --repository-url https://github.com/rust-lang/rust --pr 98765 --labels "enhancement,performance"
     RemoveLabels {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the pull request itself
         ///
         /// Examples:
         ///   https://github.com/owner/repo
@@ -412,14 +467,14 @@ pub enum PullRequestAction {
         ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Pull request number (numeric ID from the URL)
+        /// Pull request number (numeric ID from the URL; optional when --repository-url is the pull request URL)
         ///
         /// Examples:
         ///   123 (from https://github.com/owner/repo/pull/123)
         ///   98765 (from https://github.com/rust-lang/rust/pull/98765)
         ///   142857 (from https://github.com/microsoft/vscode/pull/142857)
         #[arg(short = 'p', long, value_name = "NUMBER")]
-        pull_request_number: u32,
+        pull_request_number: Option<u32>,
         /// Comma-separated list of label names to remove
         ///
         /// Examples:
@@ -434,8 +489,9 @@ pub enum PullRequestAction {
     /// Examples:
     ///   github-edit-cli pull-request add-milestone -r https://github.com/owner/repo -p 123 -m 5
     ///   github-edit-cli pull-request add-milestone --repository-url https://github.com/rust-lang/rust --pr 98765 --milestone 10
+    ///   github-edit-cli pull-request add-milestone -r https://github.com/owner/repo -p 123 --milestone-title "v1.0.0"
     AddMilestone {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the pull request itself
         ///
         /// Examples:
         ///   https://github.com/owner/repo
@@ -443,22 +499,35 @@ pub enum PullRequestAction {
         ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Pull request number (numeric ID from the URL)
+        /// Pull request number (numeric ID from the URL; optional when --repository-url is the pull request URL)
         ///
         /// Examples:
         ///   123 (from https://github.com/owner/repo/pull/123)
         ///   98765 (from https://github.com/rust-lang/rust/pull/98765)
         ///   142857 (from https://github.com/microsoft/vscode/pull/142857)
         #[arg(short = 'p', long, value_name = "NUMBER")]
-        pull_request_number: u32,
+        pull_request_number: Option<u32>,
         /// Milestone ID (numeric ID of the milestone)
         ///
         /// Examples:
         ///   5 (milestone ID)
         ///   10 (another milestone ID)
         ///   15 (yet another milestone ID)
-        #[arg(short = 'm', long, value_name = "ID")]
-        milestone: u64,
+        #[arg(
+            short = 'm',
+            long,
+            value_name = "ID",
+            required_unless_present = "milestone_title",
+            conflicts_with = "milestone_title"
+        )]
+        milestone: Option<u64>,
+        /// Milestone title, resolved to its number
+        ///
+        /// Examples:
+        ///   "v1.0.0"
+        ///   "Sprint 1"
+        #[arg(long, value_name = "TITLE")]
+        milestone_title: Option<String>,
     },
     /// Remove milestone from a pull request
     ///
@@ -466,7 +535,7 @@ pub enum PullRequestAction {
     ///   github-edit-cli pull-request remove-milestone -r https://github.com/owner/repo -p 123
     ///   github-edit-cli pull-request remove-milestone --repository-url https://github.com/rust-lang/rust --pr 98765
     RemoveMilestone {
-        /// Repository URL (HTTPS format)
+        /// Repository URL (HTTPS format), or the URL of the pull request itself
         ///
         /// Examples:
         ///   https://github.com/owner/repo
@@ -474,27 +543,119 @@ pub enum PullRequestAction {
         ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Pull request number (numeric ID from the URL)
+        /// Pull request number (numeric ID from the URL; optional when --repository-url is the pull request URL)
         ///
         /// Examples:
         ///   123 (from https://github.com/owner/repo/pull/123)
         ///   98765 (from https://github.com/rust-lang/rust/pull/98765)
         ///   142857 (from https://github.com/microsoft/vscode/pull/142857)
         #[arg(short = 'p', long, value_name = "NUMBER")]
-        pull_request_number: u32,
+        pull_request_number: Option<u32>,
+    },
+    /// Check out the head branch of a pull request into a local branch
+    ///
+    /// Prints the git commands that fetch the head branch and switch to a
+    /// local branch tracking it, or runs them in the current repository with
+    /// --execute. Branches of forks are fetched from a remote named after the
+    /// fork owner, added unless a remote of the fork exists; it uses SSH when
+    /// the base remote does. An existing local branch is fast-forwarded.
+    ///
+    /// Examples:
+    ///   github-edit-cli pull-request checkout https://github.com/owner/repo/pull/123
+    ///   github-edit-cli pull-request checkout https://github.com/owner/repo/pull/123 --execute
+    ///   github-edit-cli pull-request checkout https://github.com/owner/repo/pull/123 --branch review-123 --remote upstream --execute
+    Checkout {
+        /// Pull request URL
+        #[arg(value_name = "URL")]
+        url: String,
+        /// Name of the local branch (default: the name of the head branch)
+        #[arg(short, long, value_name = "NAME")]
+        branch: Option<String>,
+        /// Remote of the base repository
+        #[arg(long, value_name = "NAME", default_value = "origin")]
+        remote: String,
+        /// Run the git commands instead of printing them
+        #[arg(long)]
+        execute: bool,
+    },
+    /// Watch a pull request, printing new comments, reviews, commits and
+    /// state changes as they happen
+    ///
+    /// Polls the timeline of the pull request every --interval seconds with
+    /// conditional requests, which do not count against the rate limit while
+    /// nothing changes. Each event is printed as a line, or as one line of
+    /// JSON with --output json.
+    ///
+    /// Examples:
+    ///   github-edit-cli pull-request watch https://github.com/owner/repo/pull/123
+    ///   github-edit-cli pull-request watch https://github.com/owner/repo/pull/123 --interval 10 --until-closed
+    ///   github-edit-cli --output json pull-request watch https://github.com/owner/repo/pull/123 --timeout 3600
+    Watch {
+        /// Pull request URL
+        #[arg(value_name = "URL")]
+        url: String,
+        #[command(flatten)]
+        watch: WatchArgs,
     },
 }
 
+impl PullRequestAction {
+    /// Returns true if the action changes a pull request, so it can be
+    /// queued while GitHub is unreachable
+    pub fn is_queueable(&self) -> bool {
+        !matches!(
+            self,
+            PullRequestAction::Get { .. }
+                | PullRequestAction::Checkout { .. }
+                | PullRequestAction::Watch { .. }
+        )
+    }
+}
+
 pub async fn execute_pr_action(
     github_client: &GitHubClient,
     action: PullRequestAction,
+    output: OutputFormat,
 ) -> Result<()> {
-    match action {
-        PullRequestAction::Get { urls: _ } => {
-            eprintln!("Get pull request details functionality has been removed");
-            return Err(anyhow::anyhow!(
-                "Get pull request details functionality has been removed"
-            ));
+    let result = match action {
+        PullRequestAction::Get {
+            urls,
+            comment_limit,
+            no_comments,
+        } => {
+            if comment_limit > MAX_COMMENT_LIMIT {
+                return Err(anyhow::anyhow!(
+                    "Invalid comment limit {}: at most {} comments can be included",
+                    comment_limit,
+                    MAX_COMMENT_LIMIT
+                ));
+            }
+            let comment_limit = if no_comments { 0 } else { comment_limit };
+            let pull_request_ids = urls
+                .iter()
+                .map(|url| {
+                    PullRequestId::parse_url(&PullRequestUrl(url.clone())).map_err(|e| {
+                        anyhow::anyhow!("Failed to parse pull request URL {}: {}", url, e)
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let pull_requests = pull_request::get_pull_requests_with_details(
+                github_client,
+                &pull_request_ids,
+                comment_limit,
+                None,
+            )
+            .await
+            .into_iter()
+            .zip(&urls)
+            .map(|(details, url)| {
+                details.map_err(|e| anyhow::anyhow!("Failed to fetch pull request {}: {}", url, e))
+            })
+            .collect::<Result<Vec<_>>>()?;
+            PullRequestResult::Details {
+                pull_requests,
+                include_comments: !no_comments,
+            }
         }
         PullRequestAction::Create {
             repository_url,
@@ -502,11 +663,20 @@ pub async fn execute_pr_action(
             head,
             base,
             body,
+            body_file,
+            template,
             draft,
         } => {
             let repo_url = RepositoryUrl::new(repository_url);
             let repo_id = RepositoryId::parse_url(&repo_url)
                 .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let body = match template
+                .render(github_client, &repo_id, TemplateKind::PullRequest)
+                .await?
+            {
+                Some(body) => Some(body),
+                None => read_body(body, body_file)?,
+            };
             let head_branch = Branch::new(head);
             let base_branch = Branch::new(base);
             let created_pr = pull_request::create_pull_request(
@@ -519,118 +689,119 @@ pub async fn execute_pr_action(
                 Some(draft),
             )
             .await?;
-            println!(
-                "Created pull request #{}",
-                created_pr.pull_request_id.number
-            );
+            PullRequestResult::Created {
+                pull_request: created_pr.pull_request_id.number,
+                url: created_pr.pull_request_id.url(),
+            }
         }
         PullRequestAction::Comment {
             repository_url,
             pull_request_number,
             body,
+            body_file,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let body = require_body(body, body_file)?;
+            let (repo_id, pull_request_number) =
+                resolve_item_url(&repository_url, pull_request_number)?;
             let pr_number = PullRequestNumber::new(pull_request_number);
             let comment_number =
                 pull_request::add_comment(github_client, &repo_id, pr_number, &body).await?;
-            println!("Added comment #{}", comment_number);
+            PullRequestResult::CommentAdded {
+                pull_request: pull_request_number,
+                comment: comment_number,
+            }
         }
         PullRequestAction::Close {
             repository_url,
             pull_request_number,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, pull_request_number) =
+                resolve_item_url(&repository_url, pull_request_number)?;
             let pr_number = PullRequestNumber::new(pull_request_number);
             pull_request::close_pull_request(github_client, &repo_id, pr_number).await?;
-            println!("Closed pull request #{}", pull_request_number);
+            PullRequestResult::Closed {
+                pull_request: pull_request_number,
+            }
         }
         PullRequestAction::EditTitle {
             repository_url,
             pull_request_number,
             title,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, pull_request_number) =
+                resolve_item_url(&repository_url, pull_request_number)?;
             let pr_number = PullRequestNumber::new(pull_request_number);
             pull_request::edit_title(github_client, &repo_id, pr_number, &title).await?;
-            println!("Updated pull request #{} title", pull_request_number);
+            PullRequestResult::TitleUpdated {
+                pull_request: pull_request_number,
+            }
         }
         PullRequestAction::EditBody {
             repository_url,
             pull_request_number,
             body,
+            body_file,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let body = require_body(body, body_file)?;
+            let (repo_id, pull_request_number) =
+                resolve_item_url(&repository_url, pull_request_number)?;
             let pr_number = PullRequestNumber::new(pull_request_number);
             pull_request::edit_body(github_client, &repo_id, pr_number, &body).await?;
-            println!("Updated pull request #{} body", pull_request_number);
+            PullRequestResult::BodyUpdated {
+                pull_request: pull_request_number,
+            }
         }
         PullRequestAction::EditComment {
             repository_url,
             pull_request_number,
             comment_number,
             body,
+            body_file,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let body = require_body(body, body_file)?;
+            let (repo_id, pull_request_number) =
+                resolve_item_url(&repository_url, pull_request_number)?;
             let pr_number = PullRequestNumber::new(pull_request_number);
             let comment_num = PullRequestCommentNumber::new(comment_number.into());
             pull_request::edit_comment(github_client, &repo_id, pr_number, comment_num, &body)
                 .await?;
-            println!(
-                "Updated pull request #{} comment #{}",
-                pull_request_number, comment_number
-            );
+            PullRequestResult::CommentUpdated {
+                pull_request: pull_request_number,
+                comment: comment_number,
+            }
         }
         PullRequestAction::DeleteComment {
             repository_url,
             pull_request_number,
             comment_number,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, pull_request_number) =
+                resolve_item_url(&repository_url, pull_request_number)?;
             let pr_number = PullRequestNumber::new(pull_request_number);
             let comment_num = PullRequestCommentNumber::new(comment_number.into());
             pull_request::delete_comment(github_client, &repo_id, pr_number, comment_num).await?;
-            println!(
-                "Deleted pull request #{} comment #{}",
-                pull_request_number, comment_number
-            );
+            PullRequestResult::CommentDeleted {
+                pull_request: pull_request_number,
+                comment: comment_number,
+            }
         }
         PullRequestAction::AddAssignees {
             repository_url,
             pull_request_number,
             assignees,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, pull_request_number) =
+                resolve_item_url(&repository_url, pull_request_number)?;
             let pr_number = PullRequestNumber::new(pull_request_number);
             let assignee_list: Vec<String> =
                 assignees.split(',').map(|s| s.trim().to_string()).collect();
             let (added, skipped) =
                 pull_request::add_assignees(github_client, &repo_id, pr_number, &assignee_list)
                     .await?;
-            println!(
-                "Added {} assignees to pull request #{}",
-                added.len(),
-                pull_request_number
-            );
-            if !skipped.is_empty() {
-                println!(
-                    "Skipped {} assignees (already assigned): {}",
-                    skipped.len(),
-                    skipped.join(", ")
-                );
+            PullRequestResult::AssigneesAdded {
+                pull_request: pull_request_number,
+                added,
+                skipped,
             }
         }
         PullRequestAction::RemoveAssignees {
@@ -638,27 +809,25 @@ pub async fn execute_pr_action(
             pull_request_number,
             assignees,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, pull_request_number) =
+                resolve_item_url(&repository_url, pull_request_number)?;
             let pr_number = PullRequestNumber::new(pull_request_number);
             let assignee_list: Vec<String> =
                 assignees.split(',').map(|s| s.trim().to_string()).collect();
             pull_request::remove_assignees(github_client, &repo_id, pr_number, &assignee_list)
                 .await?;
-            println!(
-                "Removed assignees from pull request #{}",
-                pull_request_number
-            );
+            PullRequestResult::AssigneesRemoved {
+                pull_request: pull_request_number,
+                assignees: assignee_list,
+            }
         }
         PullRequestAction::AddReviewers {
             repository_url,
             pull_request_number,
             reviewers,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, pull_request_number) =
+                resolve_item_url(&repository_url, pull_request_number)?;
             let pr_number = PullRequestNumber::new(pull_request_number);
             let reviewer_list: Vec<String> =
                 reviewers.split(',').map(|s| s.trim().to_string()).collect();
@@ -669,17 +838,10 @@ pub async fn execute_pr_action(
                 &reviewer_list,
             )
             .await?;
-            println!(
-                "Added {} reviewers to pull request #{}",
-                added.len(),
-                pull_request_number
-            );
-            if !skipped.is_empty() {
-                println!(
-                    "Skipped {} reviewers (already requested): {}",
-                    skipped.len(),
-                    skipped.join(", ")
-                );
+            PullRequestResult::ReviewersAdded {
+                pull_request: pull_request_number,
+                added,
+                skipped,
             }
         }
         PullRequestAction::AddLabels {
@@ -687,64 +849,422 @@ pub async fn execute_pr_action(
             pull_request_number,
             labels,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, pull_request_number) =
+                resolve_item_url(&repository_url, pull_request_number)?;
             let pr_number = PullRequestNumber::new(pull_request_number);
             let label_list: Vec<Label> = labels
                 .split(',')
                 .map(|s| Label::from(s.trim().to_string()))
                 .collect();
             pull_request::add_labels(github_client, &repo_id, pr_number, &label_list).await?;
-            println!("Added labels to pull request #{}", pull_request_number);
+            PullRequestResult::LabelsAdded {
+                pull_request: pull_request_number,
+                labels: label_list.into_iter().map(|label| label.name).collect(),
+            }
         }
         PullRequestAction::RemoveLabels {
             repository_url,
             pull_request_number,
             labels,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, pull_request_number) =
+                resolve_item_url(&repository_url, pull_request_number)?;
             let pr_number = PullRequestNumber::new(pull_request_number);
             let label_list: Vec<Label> = labels
                 .split(',')
                 .map(|s| Label::from(s.trim().to_string()))
                 .collect();
             pull_request::remove_labels(github_client, &repo_id, pr_number, &label_list).await?;
-            println!("Removed labels from pull request #{}", pull_request_number);
+            PullRequestResult::LabelsRemoved {
+                pull_request: pull_request_number,
+                labels: label_list.into_iter().map(|label| label.name).collect(),
+            }
         }
         PullRequestAction::AddMilestone {
             repository_url,
             pull_request_number,
             milestone,
+            milestone_title,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, pull_request_number) =
+                resolve_item_url(&repository_url, pull_request_number)?;
             let pr_number = PullRequestNumber::new(pull_request_number);
-            let milestone_number = MilestoneNumber::new(milestone);
+            let milestone_number = repository::resolve_milestone(
+                github_client,
+                &repo_id,
+                milestone.map(MilestoneNumber::new),
+                milestone_title.as_deref(),
+            )
+            .await?;
             pull_request::add_milestone(github_client, &repo_id, pr_number, milestone_number)
                 .await?;
-            println!(
-                "Added milestone {} to pull request #{}",
-                milestone, pull_request_number
-            );
+            PullRequestResult::MilestoneAdded {
+                pull_request: pull_request_number,
+                milestone: milestone_number.value(),
+            }
         }
         PullRequestAction::RemoveMilestone {
             repository_url,
             pull_request_number,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let (repo_id, pull_request_number) =
+                resolve_item_url(&repository_url, pull_request_number)?;
             let pr_number = PullRequestNumber::new(pull_request_number);
             pull_request::remove_milestone(github_client, &repo_id, pr_number).await?;
-            println!(
-                "Removed milestone from pull request #{}",
-                pull_request_number
-            );
+            PullRequestResult::MilestoneRemoved {
+                pull_request: pull_request_number,
+            }
         }
+        PullRequestAction::Checkout {
+            url,
+            branch,
+            remote,
+            execute,
+        } => {
+            let (repo_id, pull_request_number) = resolve_item_url(&url, None)?;
+            let head = pull_request::get_head(
+                github_client,
+                &repo_id,
+                PullRequestNumber::new(pull_request_number),
+            )
+            .await?;
+            let branch = branch.unwrap_or_else(|| head.branch.clone());
+
+            let local = match LocalRepository::read()? {
+                Some(local) => local,
+                None if execute => anyhow::bail!("Not in a git repository"),
+                None => LocalRepository::default(),
+            };
+            let commands =
+                checkout::checkout_commands(&head, pull_request_number, &remote, &branch, &local);
+            if execute {
+                checkout::run_commands(&commands)?;
+            }
+
+            let commands = commands
+                .iter()
+                .map(|command| checkout::command_line(command))
+                .collect();
+            if execute {
+                PullRequestResult::CheckedOut {
+                    pull_request: pull_request_number,
+                    branch,
+                    commands,
+                }
+            } else {
+                PullRequestResult::CheckoutCommands {
+                    pull_request: pull_request_number,
+                    branch,
+                    commands,
+                }
+            }
+        }
+        PullRequestAction::Watch { url, watch } => {
+            return watch::watch(github_client, &url, &watch, output).await;
+        }
+    };
+    output.print(&result)
+}
+
+/// Result of a pull request command
+#[derive(Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+enum PullRequestResult {
+    Details {
+        pull_requests: Vec<PullRequestDetails>,
+        #[serde(skip)]
+        include_comments: bool,
+    },
+    Created {
+        pull_request: u32,
+        url: String,
+    },
+    CommentAdded {
+        pull_request: u32,
+        comment: PullRequestCommentNumber,
+    },
+    Closed {
+        pull_request: u32,
+    },
+    TitleUpdated {
+        pull_request: u32,
+    },
+    BodyUpdated {
+        pull_request: u32,
+    },
+    CommentUpdated {
+        pull_request: u32,
+        comment: u32,
+    },
+    CommentDeleted {
+        pull_request: u32,
+        comment: u32,
+    },
+    AssigneesAdded {
+        pull_request: u32,
+        added: Vec<String>,
+        skipped: Vec<String>,
+    },
+    AssigneesRemoved {
+        pull_request: u32,
+        assignees: Vec<String>,
+    },
+    ReviewersAdded {
+        pull_request: u32,
+        added: Vec<String>,
+        skipped: Vec<String>,
+    },
+    LabelsAdded {
+        pull_request: u32,
+        labels: Vec<String>,
+    },
+    LabelsRemoved {
+        pull_request: u32,
+        labels: Vec<String>,
+    },
+    MilestoneAdded {
+        pull_request: u32,
+        milestone: u64,
+    },
+    MilestoneRemoved {
+        pull_request: u32,
+    },
+    CheckoutCommands {
+        pull_request: u32,
+        branch: String,
+        commands: Vec<String>,
+    },
+    CheckedOut {
+        pull_request: u32,
+        branch: String,
+        commands: Vec<String>,
+    },
+}
+
+impl fmt::Display for PullRequestResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PullRequestResult::Details {
+                pull_requests,
+                include_comments,
+            } => {
+                for (index, details) in pull_requests.iter().enumerate() {
+                    if index > 0 {
+                        writeln!(f)?;
+                    }
+                    write_pull_request_details(f, details, *include_comments)?;
+                }
+                Ok(())
+            }
+            PullRequestResult::Created { pull_request, .. } => {
+                writeln!(f, "Created pull request #{}", pull_request)
+            }
+            PullRequestResult::CommentAdded { comment, .. } => {
+                writeln!(f, "Added comment #{}", comment)
+            }
+            PullRequestResult::Closed { pull_request } => {
+                writeln!(f, "Closed pull request #{}", pull_request)
+            }
+            PullRequestResult::TitleUpdated { pull_request } => {
+                writeln!(f, "Updated pull request #{} title", pull_request)
+            }
+            PullRequestResult::BodyUpdated { pull_request } => {
+                writeln!(f, "Updated pull request #{} body", pull_request)
+            }
+            PullRequestResult::CommentUpdated {
+                pull_request,
+                comment,
+            } => writeln!(
+                f,
+                "Updated pull request #{} comment #{}",
+                pull_request, comment
+            ),
+            PullRequestResult::CommentDeleted {
+                pull_request,
+                comment,
+            } => writeln!(
+                f,
+                "Deleted pull request #{} comment #{}",
+                pull_request, comment
+            ),
+            PullRequestResult::AssigneesAdded {
+                pull_request,
+                added,
+                skipped,
+            } => {
+                writeln!(
+                    f,
+                    "Added {} assignees to pull request #{}",
+                    added.len(),
+                    pull_request
+                )?;
+                if !skipped.is_empty() {
+                    writeln!(
+                        f,
+                        "Skipped {} assignees (already assigned): {}",
+                        skipped.len(),
+                        skipped.join(", ")
+                    )?;
+                }
+                Ok(())
+            }
+            PullRequestResult::AssigneesRemoved { pull_request, .. } => {
+                writeln!(f, "Removed assignees from pull request #{}", pull_request)
+            }
+            PullRequestResult::ReviewersAdded {
+                pull_request,
+                added,
+                skipped,
+            } => {
+                writeln!(
+                    f,
+                    "Added {} reviewers to pull request #{}",
+                    added.len(),
+                    pull_request
+                )?;
+                if !skipped.is_empty() {
+                    writeln!(
+                        f,
+                        "Skipped {} reviewers (already requested): {}",
+                        skipped.len(),
+                        skipped.join(", ")
+                    )?;
+                }
+                Ok(())
+            }
+            PullRequestResult::LabelsAdded { pull_request, .. } => {
+                writeln!(f, "Added labels to pull request #{}", pull_request)
+            }
+            PullRequestResult::LabelsRemoved { pull_request, .. } => {
+                writeln!(f, "Removed labels from pull request #{}", pull_request)
+            }
+            PullRequestResult::MilestoneAdded {
+                pull_request,
+                milestone,
+            } => writeln!(
+                f,
+                "Added milestone {} to pull request #{}",
+                milestone, pull_request
+            ),
+            PullRequestResult::MilestoneRemoved { pull_request } => {
+                writeln!(f, "Removed milestone from pull request #{}", pull_request)
+            }
+            PullRequestResult::CheckoutCommands { commands, .. } => {
+                for command in commands {
+                    writeln!(f, "{}", command)?;
+                }
+                Ok(())
+            }
+            PullRequestResult::CheckedOut {
+                pull_request,
+                branch,
+                ..
+            } => writeln!(
+                f,
+                "Switched to branch {} of pull request #{}",
+                branch, pull_request
+            ),
+        }
+    }
+}
+
+/// Text of a pull request with its description and, if included, its comments
+fn write_pull_request_details(
+    f: &mut fmt::Formatter<'_>,
+    details: &PullRequestDetails,
+    include_comments: bool,
+) -> fmt::Result {
+    let pull_request = &details.pull_request;
+    let join_users = |users: &[User]| {
+        users
+            .iter()
+            .map(|user| user.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    writeln!(
+        f,
+        "Pull request #{} [{}{}] {}",
+        pull_request.pull_request_id.number,
+        pull_request.state,
+        if pull_request.draft { ", draft" } else { "" },
+        pull_request.title
+    )?;
+    writeln!(f, "URL: {}", pull_request.pull_request_id.url())?;
+    if let Some(author) = &pull_request.author {
+        writeln!(f, "Author: {}", author)?;
+    }
+    writeln!(
+        f,
+        "Branches: {} -> {}",
+        pull_request.head_branch, pull_request.base_branch
+    )?;
+    writeln!(
+        f,
+        "Changes: {} commit(s), {} file(s), +{} -{}",
+        pull_request.commits_count,
+        pull_request.changed_files,
+        pull_request.additions,
+        pull_request.deletions
+    )?;
+    writeln!(
+        f,
+        "Created: {}, updated: {}",
+        pull_request.created_at, pull_request.updated_at
+    )?;
+    if let Some(merged_at) = pull_request.merged_at {
+        writeln!(f, "Merged: {}", merged_at)?;
+    }
+    if let Some(mergeable) = pull_request.mergeable {
+        writeln!(f, "Mergeable: {}", mergeable)?;
+    }
+    if !pull_request.assignees.is_empty() {
+        writeln!(f, "Assignees: {}", join_users(&pull_request.assignees))?;
+    }
+    if !pull_request.requested_reviewers.is_empty() {
+        writeln!(
+            f,
+            "Requested reviewers: {}",
+            join_users(&pull_request.requested_reviewers)
+        )?;
+    }
+    if !pull_request.labels.is_empty() {
+        let names: Vec<&str> = pull_request
+            .labels
+            .iter()
+            .map(|label| label.name.as_str())
+            .collect();
+        writeln!(f, "Labels: {}", names.join(", "))?;
+    }
+    if let Some(milestone_number) = pull_request.milestone_number {
+        writeln!(f, "Milestone: {}", milestone_number)?;
+    }
+    writeln!(f)?;
+    match pull_request.body.as_deref().map(str::trim_end) {
+        Some(body) if !body.trim().is_empty() => writeln!(f, "{}", body)?,
+        _ => writeln!(f, "(no description)")?,
+    }
+    if !include_comments {
+        return Ok(());
+    }
+    for comment in &pull_request.comments {
+        writeln!(f)?;
+        writeln!(
+            f,
+            "Comment #{} by {} at {}",
+            comment.comment_number,
+            comment
+                .author
+                .as_ref()
+                .map(|author| author.to_string())
+                .unwrap_or_else(|| "unknown".to_string()),
+            comment.created_at
+        )?;
+        writeln!(f, "{}", comment.body.trim_end())?;
+    }
+    if details.has_more_comments {
+        writeln!(f)?;
+        writeln!(f, "More comments available with a higher --comment-limit")?;
     }
     Ok(())
 }
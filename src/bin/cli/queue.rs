@@ -0,0 +1,415 @@
+//! Offline queue CLI commands and execution logic
+//!
+//! With `--queue-offline`, or `queue.enabled` in the configuration file, an
+//! issue or pull request command that fails because GitHub cannot be reached
+//! is appended to the offline queue instead of failing. `queue flush` replays
+//! the queued commands in order once GitHub is reachable again, stopping at
+//! the first command that fails or whose issue or pull request changed on
+//! GitHub after it was queued.
+
+use super::audit::command_parameters;
+use super::input::resolve_item_url;
+use super::output::OutputFormat;
+use anyhow::{Context, Result};
+use chrono::Utc;
+use clap::{ArgMatches, Subcommand};
+use github_edit::config::QueueConfig;
+use github_edit::github::GitHubClient;
+use github_edit::queue::OfflineQueue;
+use github_edit::tools::functions::issue;
+use github_edit::types::audit::AuditRequest;
+use github_edit::types::issue::IssueNumber;
+use github_edit::types::project::ProjectOriginalResource;
+use github_edit::types::queue::QueuedCommand;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fmt;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+#[derive(Subcommand)]
+pub enum QueueAction {
+    /// List the queued commands, oldest first
+    ///
+    /// Examples:
+    ///   github-edit-cli queue list
+    List,
+    /// Replay the queued commands in order, removing those that succeed
+    ///
+    /// Before replaying a command changing an issue or pull request, the item
+    /// is read from GitHub; if it was updated after the command was queued,
+    /// the flush stops so the change can be reviewed. The flush also stops at
+    /// the first command that fails, keeping it and the following commands
+    /// queued.
+    ///
+    /// Examples:
+    ///   github-edit-cli queue flush
+    ///   github-edit-cli queue flush --force
+    Flush {
+        /// Replay commands even if their issue or pull request changed after
+        /// they were queued
+        #[arg(long)]
+        force: bool,
+    },
+    /// Remove all queued commands without replaying them
+    ///
+    /// Examples:
+    ///   github-edit-cli queue clear
+    Clear,
+}
+
+/// Queue the command of `matches`, which failed with `error` to reach GitHub
+///
+/// Commands reading their body from stdin, and commands of which a write
+/// request already succeeded, are not queued, and fail with `error`.
+///
+/// # Arguments
+/// * `profile` - Name of the profile the command ran with
+/// * `requests` - Write requests the command sent before failing
+pub fn queue_failed_command(
+    config: &QueueConfig,
+    matches: &ArgMatches,
+    profile: Option<&str>,
+    error: anyhow::Error,
+    requests: &[AuditRequest],
+    output: OutputFormat,
+) -> Result<()> {
+    let (command, parameters) = command_parameters(matches);
+    let reads_stdin = ["body", "body_file"]
+        .iter()
+        .any(|id| parameters[id].as_str() == Some("-"));
+    if reads_stdin {
+        tracing::warn!("Not queueing {}: its body is read from stdin", command);
+        return Err(error);
+    }
+    let applied = requests.iter().any(|request| {
+        request
+            .status
+            .is_some_and(|status| (200..300).contains(&status))
+    });
+    if applied {
+        tracing::warn!(
+            "Not queueing {}: part of its changes reached GitHub",
+            command
+        );
+        return Err(error);
+    }
+
+    let queued = QueuedCommand {
+        queued_at: Utc::now(),
+        target: command_target(&command, &parameters),
+        command,
+        args: replay_args(std::env::args().skip(1)),
+        profile: profile.map(|profile| profile.to_string()),
+        cwd: std::env::current_dir().context("Failed to read the current directory")?,
+    };
+    let queue = OfflineQueue::from_config(config)?;
+    queue.push(&queued)?;
+    output.print(&QueueResult::Queued {
+        queue: queue.path().to_path_buf(),
+        command: queued,
+        error: format!("{:#}", error),
+    })
+}
+
+pub async fn execute_queue_action(
+    config: &QueueConfig,
+    action: QueueAction,
+    connect: impl Fn(Option<&str>) -> Result<GitHubClient>,
+    output: OutputFormat,
+) -> Result<()> {
+    let queue = OfflineQueue::from_config(config)?;
+
+    match action {
+        QueueAction::List => output.print(&QueueResult::Commands {
+            queue: queue.path().to_path_buf(),
+            commands: queue.read()?,
+        }),
+        QueueAction::Flush { force } => {
+            let commands = queue.read()?;
+            let mut outcomes = Vec::new();
+            let mut replayed_targets = HashSet::new();
+            let mut stopped = None;
+
+            for command in &commands {
+                if stopped.is_some() {
+                    outcomes.push(FlushOutcome::new(command, FlushStatus::Pending, None));
+                    continue;
+                }
+                // Items changed by the commands replayed before are updated
+                // by this flush, not by someone else
+                let target = command
+                    .target
+                    .as_deref()
+                    .filter(|target| !force && !replayed_targets.contains(*target));
+                if let Some(target) = target {
+                    match updated_since_queued(&connect, command, target).await {
+                        Ok(false) => {}
+                        Ok(true) => {
+                            stopped = Some(format!(
+                                "{} changed on GitHub after {} was queued; replay it with --force, or remove it with 'queue clear'",
+                                target, command.command
+                            ));
+                            outcomes.push(FlushOutcome::new(command, FlushStatus::Conflict, None));
+                            continue;
+                        }
+                        Err(e) => {
+                            stopped = Some(format!("Failed to read {}: {:#}", target, e));
+                            outcomes.push(FlushOutcome::new(command, FlushStatus::Failed, None));
+                            continue;
+                        }
+                    }
+                }
+
+                match replay(command) {
+                    Ok(output) => {
+                        if let Some(target) = &command.target {
+                            replayed_targets.insert(target.clone());
+                        }
+                        outcomes.push(FlushOutcome::new(
+                            command,
+                            FlushStatus::Replayed,
+                            Some(output),
+                        ));
+                    }
+                    Err(e) => {
+                        stopped = Some(format!("{} failed: {:#}", command.command, e));
+                        outcomes.push(FlushOutcome::new(command, FlushStatus::Failed, None));
+                    }
+                }
+            }
+
+            let replayed = outcomes
+                .iter()
+                .filter(|outcome| outcome.status == FlushStatus::Replayed)
+                .count();
+            queue.remove_oldest(Some(replayed))?;
+            output.print(&QueueResult::Flushed {
+                queue: queue.path().to_path_buf(),
+                commands: outcomes,
+            })?;
+            match stopped {
+                Some(reason) => Err(anyhow::anyhow!("Stopped flushing the queue: {}", reason)),
+                None => Ok(()),
+            }
+        }
+        QueueAction::Clear => {
+            let count = queue.read()?.len();
+            queue.remove_oldest(None)?;
+            output.print(&QueueResult::Cleared {
+                queue: queue.path().to_path_buf(),
+                count,
+            })
+        }
+    }
+}
+
+/// Returns true if the issue or pull request at `target` was updated after
+/// `command` was queued
+async fn updated_since_queued(
+    connect: &impl Fn(Option<&str>) -> Result<GitHubClient>,
+    command: &QueuedCommand,
+    target: &str,
+) -> Result<bool> {
+    let item = ProjectOriginalResource::parse_url(target).map_err(|e| anyhow::anyhow!(e))?;
+    let (repository_id, number) = item
+        .repository_and_number()
+        .ok_or_else(|| anyhow::anyhow!("Invalid issue or pull request URL: {}", target))?;
+    let github_client = connect(command.profile.as_deref())?;
+    let details =
+        issue::get_issue_details(&github_client, repository_id, IssueNumber::new(number), 0)
+            .await?;
+    Ok(details.issue.updated_at > command.queued_at)
+}
+
+/// Run a queued command in its working directory, returning its output
+fn replay(command: &QueuedCommand) -> Result<String> {
+    let program = std::env::current_exe().context("Failed to find the CLI program")?;
+    let mut process = Command::new(program);
+    // A command failing again must not be queued behind itself
+    process.arg("--no-queue");
+    match &command.profile {
+        Some(profile) => {
+            process.args(["--profile", profile]);
+        }
+        None => {
+            process.env_remove("GITHUB_EDIT_PROFILE");
+        }
+    }
+    let output = process
+        .args(&command.args)
+        .current_dir(&command.cwd)
+        .stdin(Stdio::null())
+        .output()
+        .with_context(|| format!("Failed to run {}", command.command))?;
+    if !output.status.success() {
+        anyhow::bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Returns the arguments to replay a command with, without the options
+/// selecting the profile and the queueing
+fn replay_args(mut args: impl Iterator<Item = String>) -> Vec<String> {
+    let mut replay_args = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--queue-offline" | "--no-queue" => {}
+            "--profile" => {
+                args.next();
+            }
+            _ if arg.starts_with("--profile=") => {}
+            _ => replay_args.push(arg),
+        }
+    }
+    replay_args
+}
+
+/// Returns the URL of the issue or pull request a command changes, from its
+/// repository URL and number arguments
+fn command_target(command: &str, parameters: &serde_json::Value) -> Option<String> {
+    let repository_url = parameters["repository_url"].as_str()?;
+    let (kind, number_id) = if command.starts_with("pull-request ") {
+        ("pull", "pull_request_number")
+    } else {
+        ("issues", "issue")
+    };
+    let number = parameters[number_id]
+        .as_str()
+        .and_then(|number| number.parse().ok());
+    let (repository_id, number) = resolve_item_url(repository_url, number).ok()?;
+    Some(format!("{}/{}/{}", repository_id.url(), kind, number))
+}
+
+/// Result of replaying a queued command
+#[derive(Serialize)]
+struct FlushOutcome {
+    command: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<String>,
+    status: FlushStatus,
+    /// Output of the replayed command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output: Option<String>,
+}
+
+impl FlushOutcome {
+    fn new(command: &QueuedCommand, status: FlushStatus, output: Option<String>) -> Self {
+        Self {
+            command: command.command.clone(),
+            target: command.target.clone(),
+            status,
+            output,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum FlushStatus {
+    /// Replayed and removed from the queue
+    Replayed,
+    /// Not replayed, as its item changed on GitHub after it was queued
+    Conflict,
+    /// Failed to replay, and kept in the queue
+    Failed,
+    /// Not replayed, as the flush stopped before it
+    Pending,
+}
+
+impl fmt::Display for FlushStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Replayed => "replayed",
+            Self::Conflict => "conflict",
+            Self::Failed => "failed",
+            Self::Pending => "pending",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Result of an offline queue command
+#[derive(Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+enum QueueResult {
+    Queued {
+        queue: PathBuf,
+        command: QueuedCommand,
+        error: String,
+    },
+    Commands {
+        queue: PathBuf,
+        commands: Vec<QueuedCommand>,
+    },
+    Flushed {
+        queue: PathBuf,
+        commands: Vec<FlushOutcome>,
+    },
+    Cleared {
+        queue: PathBuf,
+        count: usize,
+    },
+}
+
+impl fmt::Display for QueueResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueueResult::Queued {
+                queue,
+                command,
+                error,
+            } => {
+                writeln!(
+                    f,
+                    "GitHub is unreachable; queued {} in {}",
+                    command.command,
+                    queue.display()
+                )?;
+                writeln!(f, "  Error: {}", error)?;
+                writeln!(f, "Replay it with 'github-edit-cli queue flush'")
+            }
+            QueueResult::Commands { queue, commands } => {
+                if commands.is_empty() {
+                    return writeln!(f, "No commands queued in {}", queue.display());
+                }
+                for command in commands {
+                    writeln!(
+                        f,
+                        "{}\t{}\t{}",
+                        command.queued_at.to_rfc3339(),
+                        command.command,
+                        command.target.as_deref().unwrap_or("-")
+                    )?;
+                }
+                Ok(())
+            }
+            QueueResult::Flushed { queue, commands } => {
+                if commands.is_empty() {
+                    return writeln!(f, "No commands queued in {}", queue.display());
+                }
+                for outcome in commands {
+                    writeln!(
+                        f,
+                        "{}\t{}\t{}",
+                        outcome.status,
+                        outcome.command,
+                        outcome.target.as_deref().unwrap_or("-")
+                    )?;
+                    for line in outcome.output.iter().flat_map(|output| output.lines()) {
+                        writeln!(f, "  {}", line)?;
+                    }
+                }
+                Ok(())
+            }
+            QueueResult::Cleared { queue, count } => {
+                writeln!(
+                    f,
+                    "Removed {} queued commands from {}",
+                    count,
+                    queue.display()
+                )
+            }
+        }
+    }
+}
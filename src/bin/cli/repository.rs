@@ -1,30 +1,362 @@
 //! Repository-related CLI commands and execution logic
 //!
 //! This module contains the CLI command definitions and execution logic
-//! for repository milestone and label management operations.
+//! for repository settings, archiving, transfer, milestone, label, branch, commit, release and fork management
+//! operations, and for copying labels and milestones between repositories.
 
+use super::input::read_body;
+use super::output::{OutputFormat, next_page_line};
+use super::pagination::PaginationArgs;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use clap::Subcommand;
 use github_edit::github::GitHubClient;
 use github_edit::tools::functions::repository;
-use github_edit::types::milestone::MilestoneState;
-use github_edit::types::repository::{MilestoneNumber, RepositoryId, RepositoryUrl};
+use github_edit::types::branch::RepositoryBranch;
+use github_edit::types::commit::{Commit, CommitFilter};
+use github_edit::types::fork::ForkSyncResult;
+use github_edit::types::label::{Label, parse_label_color};
+use github_edit::types::metadata_copy::{
+    MetadataConflictStrategy, MetadataCopyOptions, MetadataCopyResult,
+};
+use github_edit::types::milestone::{Milestone, MilestoneState, parse_due_date};
+use github_edit::types::pagination::Page;
+use github_edit::types::release::{GeneratedReleaseNotes, Release, ReleaseId, ReleaseUpdate};
+use github_edit::types::repository::{
+    MilestoneNumber, RepositoryId, RepositorySettings, RepositoryUrl,
+};
+use github_edit::types::transfer::{RepositoryTransfer, RepositoryTransferStatus};
+use serde::Serialize;
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Subcommand)]
 pub enum RepositoryAction {
-    /// Create a new milestone in a repository
+    /// Show an overview of a repository
+    ///
+    /// Examples:
+    ///   github-edit-cli repository get -r https://github.com/owner/repo
+    Get {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+    },
+    /// Manage the milestones of a repository
     ///
     /// Examples:
-    ///   github-edit-cli repository create-milestone -r https://github.com/owner/repo -t "v1.0.0" -d "Initial release"
-    ///   github-edit-cli repository create-milestone --repository-url https://github.com/rust-lang/rust --title "Sprint 1" --description "First sprint tasks"
+    ///   github-edit-cli repository milestone list -r https://github.com/owner/repo -s open
+    ///   github-edit-cli repository milestone create -r https://github.com/owner/repo -t "v1.0.0" --due 2024-12-31
+    ///   github-edit-cli repository milestone close -r https://github.com/owner/repo --milestone-title "v1.0.0"
+    Milestone {
+        #[command(subcommand)]
+        action: MilestoneAction,
+    },
+    /// Create a new milestone (superseded by "repository milestone create")
+    #[command(hide = true)]
     CreateMilestone {
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        #[arg(short, long, value_name = "TITLE")]
+        title: String,
+        #[arg(short, long, value_name = "DESCRIPTION")]
+        description: Option<String>,
+        #[arg(long, value_name = "DUE_DATE", value_parser = parse_due_date)]
+        due_on: Option<DateTime<Utc>>,
+        #[arg(short, long, value_name = "STATE")]
+        state: Option<MilestoneState>,
+    },
+    /// Update an existing milestone (superseded by "repository milestone update")
+    #[command(hide = true)]
+    UpdateMilestone {
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        #[arg(short, long, value_name = "ID")]
+        milestone_number: u32,
+        #[arg(short, long, value_name = "TITLE")]
+        title: Option<String>,
+        #[arg(short, long, value_name = "DESCRIPTION")]
+        description: Option<String>,
+        #[arg(long, value_name = "DUE_DATE", value_parser = parse_due_date)]
+        due_on: Option<DateTime<Utc>>,
+        #[arg(short, long, value_name = "STATE")]
+        state: Option<MilestoneState>,
+    },
+    /// Delete a milestone (superseded by "repository milestone delete")
+    #[command(hide = true)]
+    DeleteMilestone {
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        #[arg(short, long, value_name = "ID")]
+        milestone_number: u32,
+    },
+    /// List the milestones of a repository (superseded by "repository milestone list")
+    #[command(hide = true)]
+    ListMilestones {
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        #[arg(short, long, value_name = "STATE")]
+        state: Option<MilestoneState>,
+        #[command(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// Manage the labels of a repository
+    ///
+    /// Examples:
+    ///   github-edit-cli repository label list -r https://github.com/owner/repo
+    ///   github-edit-cli repository label create -r https://github.com/owner/repo -n "bug" -c "#d73a4a"
+    ///   github-edit-cli repository label rename -r https://github.com/owner/repo -n "bug" --to "type: bug"
+    Label {
+        #[command(subcommand)]
+        action: LabelAction,
+    },
+    /// Create a new label (superseded by "repository label create")
+    #[command(hide = true)]
+    CreateLabel {
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        #[arg(short, long, value_name = "NAME")]
+        name: String,
+        #[arg(short, long, value_name = "COLOR", value_parser = parse_label_color)]
+        color: Option<String>,
+        #[arg(short, long, value_name = "DESCRIPTION")]
+        description: Option<String>,
+    },
+    /// Update an existing label (superseded by "repository label update")
+    #[command(hide = true)]
+    UpdateLabel {
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        #[arg(short, long, value_name = "OLD_NAME")]
+        old_name: String,
+        #[arg(short, long, value_name = "NEW_NAME")]
+        new_name: Option<String>,
+        #[arg(short, long, value_name = "COLOR", value_parser = parse_label_color)]
+        color: Option<String>,
+        #[arg(short, long, value_name = "DESCRIPTION")]
+        description: Option<String>,
+    },
+    /// Delete a label (superseded by "repository label delete")
+    #[command(hide = true)]
+    DeleteLabel {
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        #[arg(short, long, value_name = "NAME")]
+        name: String,
+    },
+    /// List the labels of a repository (superseded by "repository label list")
+    #[command(hide = true)]
+    ListLabels {
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        #[command(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// Branch management operations
+    ///
+    /// Examples:
+    ///   github-edit-cli repository branch list -r https://github.com/owner/repo
+    ///   github-edit-cli repository branch create -r https://github.com/owner/repo -n "feature/login" -f main
+    Branch {
+        #[command(subcommand)]
+        action: BranchAction,
+    },
+    /// Commit history operations
+    ///
+    /// Examples:
+    ///   github-edit-cli repository commit get -r https://github.com/owner/repo -s 6dcb09b
+    ///   github-edit-cli repository commit list -r https://github.com/owner/repo -b main -p src/lib.rs
+    Commit {
+        #[command(subcommand)]
+        action: CommitAction,
+    },
+    /// Release management operations
+    ///
+    /// Examples:
+    ///   github-edit-cli repository release create -r https://github.com/owner/repo -t v1.0.0 --generate-notes
+    ///   github-edit-cli repository release publish -r https://github.com/owner/repo --release-id 123456
+    Release {
+        #[command(subcommand)]
+        action: ReleaseAction,
+    },
+    /// Fork a repository to the authenticated user or an organization
+    ///
+    /// Examples:
+    ///   github-edit-cli repository fork -r https://github.com/owner/repo
+    ///   github-edit-cli repository fork -r https://github.com/owner/repo -o my-org --default-branch-only
+    Fork {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Organization to create the fork in (default: the authenticated user)
+        #[arg(short, long, value_name = "ORG")]
+        organization: Option<String>,
+        /// Copy only the default branch
+        #[arg(long)]
+        default_branch_only: bool,
+    },
+    /// Sync a branch of a fork with its upstream repository
+    ///
+    /// Examples:
+    ///   github-edit-cli repository sync-fork -r https://github.com/me/repo
+    ///   github-edit-cli repository sync-fork -r https://github.com/me/repo -b develop
+    SyncFork {
+        /// URL of the fork (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Branch to sync (default: the default branch)
+        #[arg(short, long, value_name = "BRANCH")]
+        branch: Option<String>,
+    },
+    /// Archive a repository, making it read-only
+    ///
+    /// Examples:
+    ///   github-edit-cli repository archive -r https://github.com/owner/repo
+    Archive {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+    },
+    /// Unarchive an archived repository
+    ///
+    /// Examples:
+    ///   github-edit-cli repository unarchive -r https://github.com/owner/repo
+    Unarchive {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+    },
+    /// Transfer a repository to another user or organization
+    ///
+    /// Waits for the transfer to complete unless --wait 0 is given. Transfers
+    /// to a user stay pending until the user accepts them.
+    ///
+    /// Examples:
+    ///   github-edit-cli repository transfer -r https://github.com/owner/repo --new-owner my-org
+    ///   github-edit-cli repository transfer -r https://github.com/owner/repo --new-owner my-org --new-name repo-archive --team-ids "1,2" --wait 60
+    Transfer {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// The user or organization receiving the repository
+        #[arg(long, value_name = "OWNER")]
+        new_owner: String,
+        /// New name of the repository under the new owner
+        #[arg(long, value_name = "NAME")]
+        new_name: Option<String>,
+        /// Comma-separated IDs of teams in the new organization to grant access
+        #[arg(long, value_name = "TEAM_IDS", value_delimiter = ',')]
+        team_ids: Vec<u64>,
+        /// Seconds to wait for the transfer to complete
+        #[arg(long, value_name = "SECONDS", default_value_t = 30)]
+        wait: u64,
+    },
+    /// Check whether a transferred repository is available under its new owner
+    ///
+    /// Examples:
+    ///   github-edit-cli repository transfer-status -r https://github.com/my-org/repo
+    TransferStatus {
+        /// Repository URL under the new owner (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+    },
+    /// Copy labels and milestones from one repository to another
+    ///
+    /// Copies both labels and milestones unless --labels or --milestones is given.
+    ///
+    /// Examples:
+    ///   github-edit-cli repository copy-metadata -s https://github.com/owner/template -t https://github.com/owner/repo
+    ///   github-edit-cli repository copy-metadata -s https://github.com/owner/template -t https://github.com/owner/repo --labels --on-conflict overwrite
+    CopyMetadata {
+        /// Source repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        source_repository_url: String,
+        /// Target repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        target_repository_url: String,
+        /// Copy labels
+        #[arg(long)]
+        labels: bool,
+        /// Copy milestones
+        #[arg(long)]
+        milestones: bool,
+        /// How to handle items that already exist in the target repository
+        #[arg(long, value_name = "STRATEGY", default_value = "skip")]
+        on_conflict: MetadataConflictStrategy,
+    },
+    /// Update repository settings. Only the given settings are changed
+    ///
+    /// Examples:
+    ///   github-edit-cli repository update-settings -r https://github.com/owner/repo -d "A new description" --topics "rust,cli"
+    ///   github-edit-cli repository update-settings -r https://github.com/owner/repo --allow-merge-commit false --delete-branch-on-merge true
+    UpdateSettings {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// New description
+        #[arg(short, long, value_name = "DESCRIPTION")]
+        description: Option<String>,
+        /// New homepage URL
+        #[arg(long, value_name = "URL")]
+        homepage: Option<String>,
+        /// Comma-separated topics replacing the current topics ("" removes all)
+        #[arg(long, value_name = "TOPICS")]
+        topics: Option<String>,
+        /// Enable or disable issues
+        #[arg(long, value_name = "BOOL")]
+        has_issues: Option<bool>,
+        /// Enable or disable the wiki
+        #[arg(long, value_name = "BOOL")]
+        has_wiki: Option<bool>,
+        /// Enable or disable classic projects
+        #[arg(long, value_name = "BOOL")]
+        has_projects: Option<bool>,
+        /// New default branch (must exist)
+        #[arg(long, value_name = "BRANCH")]
+        default_branch: Option<String>,
+        /// Allow or disallow squash merging
+        #[arg(long, value_name = "BOOL")]
+        allow_squash_merge: Option<bool>,
+        /// Allow or disallow merge commits
+        #[arg(long, value_name = "BOOL")]
+        allow_merge_commit: Option<bool>,
+        /// Allow or disallow rebase merging
+        #[arg(long, value_name = "BOOL")]
+        allow_rebase_merge: Option<bool>,
+        /// Delete head branches after merge
+        #[arg(long, value_name = "BOOL")]
+        delete_branch_on_merge: Option<bool>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum MilestoneAction {
+    /// List the milestones of a repository
+    ///
+    /// Examples:
+    ///   github-edit-cli repository milestone list -r https://github.com/owner/repo
+    ///   github-edit-cli repository milestone list -r https://github.com/owner/repo -s open --all
+    List {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Only list milestones in this state (default: open and closed)
+        #[arg(short, long, value_name = "STATE")]
+        state: Option<MilestoneState>,
+        #[command(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// Create a new milestone in a repository
+    ///
+    /// Examples:
+    ///   github-edit-cli repository milestone create -r https://github.com/owner/repo -t "v1.0.0" -d "Initial release"
+    ///   github-edit-cli repository milestone create -r https://github.com/owner/repo -t "Sprint 1" --due 2024-06-15
+    Create {
         /// Repository URL (HTTPS format)
         ///
         /// Examples:
         ///   https://github.com/owner/repo
         ///   https://github.com/rust-lang/rust
-        ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
         /// Milestone title
@@ -32,124 +364,129 @@ pub enum RepositoryAction {
         /// Examples:
         ///   "v1.0.0"
         ///   "Sprint 1"
-        ///   "Release 2024.1"
-        ///   "Bug fixes for Q1"
         #[arg(short, long, value_name = "TITLE")]
         title: String,
         /// Milestone description (optional)
-        ///
-        /// Examples:
-        ///   "Initial release with core features"
-        ///   "First sprint focusing on authentication"
-        ///   "Critical bug fixes for the first quarter"
         #[arg(short, long, value_name = "DESCRIPTION")]
         description: Option<String>,
-        /// Due date for the milestone (optional, ISO 8601 format)
+        /// Due date (optional), either a date or an RFC 3339 timestamp
         ///
         /// Examples:
+        ///   "2024-12-31"
         ///   "2024-12-31T23:59:59Z"
-        ///   "2024-06-15T00:00:00Z"
-        ///   "2024-03-01T09:00:00Z"
-        #[arg(long, value_name = "DUE_DATE")]
-        due_on: Option<String>,
+        #[arg(long, value_name = "DATE", value_parser = parse_due_date)]
+        due: Option<DateTime<Utc>>,
         /// Milestone state (optional, defaults to open)
-        ///
-        /// Valid values:
-        ///   open   - Milestone is active and accepting issues
-        ///   closed - Milestone is completed or closed
         #[arg(short, long, value_name = "STATE")]
         state: Option<MilestoneState>,
     },
-    /// Update an existing milestone in a repository
+    /// Update the title, description, due date or state of a milestone
     ///
     /// Examples:
-    ///   github-edit-cli repository update-milestone -r https://github.com/owner/repo -m 1 -t "v1.0.1" -d "Updated release"
-    ///   github-edit-cli repository update-milestone --repository-url https://github.com/rust-lang/rust --milestone-id 5 --title "Sprint 2"
-    UpdateMilestone {
+    ///   github-edit-cli repository milestone update -r https://github.com/owner/repo -m 1 --due 2025-01-31
+    ///   github-edit-cli repository milestone update -r https://github.com/owner/repo --milestone-title "v1.0.0" -t "v1.0.1"
+    Update {
         /// Repository URL (HTTPS format)
-        ///
-        /// Examples:
-        ///   https://github.com/owner/repo
-        ///   https://github.com/rust-lang/rust
-        ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Milestone ID to update
-        ///
-        /// Examples:
-        ///   1
-        ///   5
-        ///   42
-        #[arg(short, long, value_name = "ID")]
-        milestone_number: u32,
+        /// Milestone number
+        #[arg(
+            short,
+            long,
+            value_name = "MILESTONE_ID",
+            required_unless_present = "milestone_title",
+            conflicts_with = "milestone_title"
+        )]
+        milestone_number: Option<u32>,
+        /// Milestone title, resolved to its number
+        #[arg(long, value_name = "TITLE")]
+        milestone_title: Option<String>,
         /// New milestone title (optional)
-        ///
-        /// Examples:
-        ///   "v1.0.1"
-        ///   "Sprint 2"
-        ///   "Release 2024.2"
-        ///   "Updated bug fixes for Q1"
         #[arg(short, long, value_name = "TITLE")]
         title: Option<String>,
         /// New milestone description (optional)
-        ///
-        /// Examples:
-        ///   "Updated release with bug fixes"
-        ///   "Second sprint focusing on performance"
-        ///   "Critical bug fixes and improvements"
         #[arg(short, long, value_name = "DESCRIPTION")]
         description: Option<String>,
-        /// New due date for the milestone (optional, ISO 8601 format)
-        ///
-        /// Examples:
-        ///   "2024-12-31T23:59:59Z"
-        ///   "2024-06-15T00:00:00Z"
-        ///   "2024-03-01T09:00:00Z"
-        #[arg(long, value_name = "DUE_DATE")]
-        due_on: Option<String>,
+        /// New due date (optional), either a date or an RFC 3339 timestamp
+        #[arg(long, value_name = "DATE", value_parser = parse_due_date)]
+        due: Option<DateTime<Utc>>,
         /// New milestone state (optional)
-        ///
-        /// Valid values:
-        ///   open   - Milestone is active and accepting issues
-        ///   closed - Milestone is completed or closed
         #[arg(short, long, value_name = "STATE")]
         state: Option<MilestoneState>,
     },
-    /// Delete an existing milestone from a repository
+    /// Close a milestone
     ///
     /// Examples:
-    ///   github-edit-cli repository delete-milestone -r https://github.com/owner/repo -m 1
-    ///   github-edit-cli repository delete-milestone --repository-url https://github.com/rust-lang/rust --milestone-id 5
-    DeleteMilestone {
+    ///   github-edit-cli repository milestone close -r https://github.com/owner/repo -m 1
+    ///   github-edit-cli repository milestone close -r https://github.com/owner/repo --milestone-title "v1.0.0"
+    Close {
         /// Repository URL (HTTPS format)
-        ///
-        /// Examples:
-        ///   https://github.com/owner/repo
-        ///   https://github.com/rust-lang/rust
-        ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
-        /// Milestone ID to delete
-        ///
-        /// Examples:
-        ///   1
-        ///   5
-        ///   42
-        #[arg(short, long, value_name = "ID")]
-        milestone_number: u32,
+        /// Milestone number
+        #[arg(
+            short,
+            long,
+            value_name = "MILESTONE_ID",
+            required_unless_present = "milestone_title",
+            conflicts_with = "milestone_title"
+        )]
+        milestone_number: Option<u32>,
+        /// Milestone title, resolved to its number
+        #[arg(long, value_name = "TITLE")]
+        milestone_title: Option<String>,
+    },
+    /// Delete a milestone from a repository
+    ///
+    /// Issues and pull requests in the milestone are kept without a milestone.
+    ///
+    /// Examples:
+    ///   github-edit-cli repository milestone delete -r https://github.com/owner/repo -m 1
+    ///   github-edit-cli repository milestone delete -r https://github.com/owner/repo --milestone-title "Sprint 1"
+    Delete {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Milestone number
+        #[arg(
+            short,
+            long,
+            value_name = "MILESTONE_ID",
+            required_unless_present = "milestone_title",
+            conflicts_with = "milestone_title"
+        )]
+        milestone_number: Option<u32>,
+        /// Milestone title, resolved to its number
+        #[arg(long, value_name = "TITLE")]
+        milestone_title: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum LabelAction {
+    /// List the labels of a repository
+    ///
+    /// Examples:
+    ///   github-edit-cli repository label list -r https://github.com/owner/repo
+    ///   github-edit-cli repository label list -r https://github.com/owner/repo --page 2 --limit 100
+    List {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        #[command(flatten)]
+        pagination: PaginationArgs,
     },
     /// Create a new label in a repository
     ///
     /// Examples:
-    ///   github-edit-cli repository create-label -r https://github.com/owner/repo -n "bug" -c "ff0000" -d "Something isn't working"
-    ///   github-edit-cli repository create-label --repository-url https://github.com/rust-lang/rust --name "enhancement" --color "00ff00"
-    CreateLabel {
+    ///   github-edit-cli repository label create -r https://github.com/owner/repo -n "bug" -c "ff0000" -d "Something isn't working"
+    ///   github-edit-cli repository label create --repository-url https://github.com/rust-lang/rust --name "enhancement" --color "#00ff00"
+    Create {
         /// Repository URL (HTTPS format)
         ///
         /// Examples:
         ///   https://github.com/owner/repo
         ///   https://github.com/rust-lang/rust
-        ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
         /// Label name
@@ -158,143 +495,339 @@ pub enum RepositoryAction {
         ///   "bug"
         ///   "enhancement"
         ///   "good first issue"
-        ///   "help wanted"
         #[arg(short, long, value_name = "NAME")]
         name: String,
-        /// Label color (optional, defaults to ffffff)
+        /// Label color as six hex digits, with or without "#" (optional, defaults to ffffff)
         ///
         /// Examples:
         ///   "ff0000" (red)
-        ///   "00ff00" (green)
-        ///   "0000ff" (blue)
-        ///   "ffff00" (yellow)
-        #[arg(short, long, value_name = "COLOR")]
+        ///   "#00ff00" (green)
+        #[arg(short, long, value_name = "COLOR", value_parser = parse_label_color)]
         color: Option<String>,
         /// Label description (optional)
         ///
         /// Examples:
         ///   "Something isn't working"
-        ///   "New feature or request"
         ///   "Good for newcomers"
-        ///   "Extra attention is needed"
         #[arg(short, long, value_name = "DESCRIPTION")]
         description: Option<String>,
     },
-    /// Update an existing label in a repository
+    /// Update the name, color or description of a label
     ///
     /// Examples:
-    ///   github-edit-cli repository update-label -r https://github.com/owner/repo -o "bug" -n "critical-bug" -c "ff0000"
-    ///   github-edit-cli repository update-label --repository-url https://github.com/rust-lang/rust --old-name "enhancement" --new-name "feature"
-    UpdateLabel {
+    ///   github-edit-cli repository label update -r https://github.com/owner/repo -n "bug" -c "d73a4a"
+    ///   github-edit-cli repository label update -r https://github.com/owner/repo -n "bug" --new-name "critical-bug" -d "Breaks production"
+    Update {
         /// Repository URL (HTTPS format)
-        ///
-        /// Examples:
-        ///   https://github.com/owner/repo
-        ///   https://github.com/rust-lang/rust
-        ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
         /// Current label name
-        ///
-        /// Examples:
-        ///   "bug"
-        ///   "enhancement"
-        ///   "good first issue"
-        #[arg(short, long, value_name = "OLD_NAME")]
-        old_name: String,
+        #[arg(short, long, value_name = "NAME")]
+        name: String,
         /// New label name (optional)
-        ///
-        /// Examples:
-        ///   "critical-bug"
-        ///   "feature"
-        ///   "beginner-friendly"
-        #[arg(short, long, value_name = "NEW_NAME")]
+        #[arg(long, value_name = "NEW_NAME")]
         new_name: Option<String>,
-        /// New label color (optional)
-        ///
-        /// Examples:
-        ///   "ff0000" (red)
-        ///   "00ff00" (green)
-        ///   "0000ff" (blue)
-        ///   "ffff00" (yellow)
-        #[arg(short, long, value_name = "COLOR")]
+        /// New label color as six hex digits, with or without "#" (optional)
+        #[arg(short, long, value_name = "COLOR", value_parser = parse_label_color)]
         color: Option<String>,
         /// New label description (optional)
-        ///
-        /// Examples:
-        ///   "Critical issue requiring immediate attention"
-        ///   "New feature or enhancement request"
-        ///   "Good for newcomers to the project"
         #[arg(short, long, value_name = "DESCRIPTION")]
         description: Option<String>,
     },
-    /// Delete an existing label from a repository
+    /// Rename a label, keeping it on the issues and pull requests it is on
     ///
     /// Examples:
-    ///   github-edit-cli repository delete-label -r https://github.com/owner/repo -n "bug"
-    ///   github-edit-cli repository delete-label --repository-url https://github.com/rust-lang/rust --name "enhancement"
-    DeleteLabel {
+    ///   github-edit-cli repository label rename -r https://github.com/owner/repo -n "bug" --to "type: bug"
+    Rename {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Current label name
+        #[arg(short, long, value_name = "NAME")]
+        name: String,
+        /// New label name
+        #[arg(long, value_name = "NEW_NAME")]
+        to: String,
+    },
+    /// Delete a label from a repository
+    ///
+    /// The label is removed from all issues and pull requests it is on.
+    ///
+    /// Examples:
+    ///   github-edit-cli repository label delete -r https://github.com/owner/repo -n "bug"
+    Delete {
         /// Repository URL (HTTPS format)
-        ///
-        /// Examples:
-        ///   https://github.com/owner/repo
-        ///   https://github.com/rust-lang/rust
-        ///   https://github.com/microsoft/vscode
         #[arg(short, long, value_name = "URL")]
         repository_url: String,
         /// Label name to delete
-        ///
-        /// Examples:
-        ///   "bug"
-        ///   "enhancement"
-        ///   "good first issue"
-        ///   "help wanted"
         #[arg(short, long, value_name = "NAME")]
         name: String,
     },
 }
 
-pub async fn execute_repository_action(
-    github_client: &GitHubClient,
-    action: RepositoryAction,
-) -> Result<()> {
-    match action {
-        RepositoryAction::CreateMilestone {
-            repository_url,
-            title,
-            description,
-            due_on,
-            state,
-        } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
-
-            let due_date = if let Some(due_str) = due_on {
-                Some(
-                    due_str
-                        .parse::<DateTime<Utc>>()
-                        .map_err(|e| anyhow::anyhow!("Failed to parse due date: {}", e))?,
-                )
-            } else {
-                None
-            };
-
-            let created_milestone = repository::create_milestone(
-                github_client,
-                &repo_id,
-                &title,
-                description.as_deref(),
-                due_date,
-                state,
-            )
-            .await?;
+#[derive(Subcommand)]
+pub enum BranchAction {
+    /// List the branches of a repository
+    ///
+    /// Examples:
+    ///   github-edit-cli repository branch list -r https://github.com/owner/repo
+    ///   github-edit-cli repository branch list -r https://github.com/owner/repo --page 2 --limit 100
+    List {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        #[command(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// Create a branch from a branch name, tag or commit SHA
+    ///
+    /// Examples:
+    ///   github-edit-cli repository branch create -r https://github.com/owner/repo -n "feature/login" -f main
+    ///   github-edit-cli repository branch create -r https://github.com/owner/repo -n "hotfix/1.0.1" -f v1.0.0
+    Create {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Name of the new branch
+        #[arg(short, long, value_name = "NAME")]
+        name: String,
+        /// Branch, tag or commit SHA to branch from
+        #[arg(short, long, value_name = "REF")]
+        from_ref: String,
+    },
+    /// Delete a branch
+    ///
+    /// Examples:
+    ///   github-edit-cli repository branch delete -r https://github.com/owner/repo -n "feature/login"
+    Delete {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Name of the branch to delete
+        #[arg(short, long, value_name = "NAME")]
+        name: String,
+    },
+    /// Rename a branch
+    ///
+    /// Examples:
+    ///   github-edit-cli repository branch rename -r https://github.com/owner/repo -n "master" --new-name "main"
+    Rename {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Current branch name
+        #[arg(short, long, value_name = "NAME")]
+        name: String,
+        /// New branch name
+        #[arg(long, value_name = "NEW_NAME")]
+        new_name: String,
+    },
+}
 
-            println!(
-                "Created milestone #{} - {}",
-                created_milestone.id.value(),
-                created_milestone.title
-            );
+#[derive(Subcommand)]
+pub enum CommitAction {
+    /// Show a commit with its message, stats and changed files
+    ///
+    /// Examples:
+    ///   github-edit-cli repository commit get -r https://github.com/owner/repo -s 6dcb09b5b57875f334f61aebed695e2e4193db5e
+    ///   github-edit-cli repository commit get -r https://github.com/owner/repo -s v1.0.0 --patch
+    Get {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Commit SHA, branch or tag
+        #[arg(short, long, value_name = "SHA")]
+        sha: String,
+        /// Print the diff of each changed file
+        #[arg(long)]
+        patch: bool,
+    },
+    /// List commits, newest first
+    ///
+    /// Examples:
+    ///   github-edit-cli repository commit list -r https://github.com/owner/repo
+    ///   github-edit-cli repository commit list -r https://github.com/owner/repo -b release/1.x -p src/
+    ///   github-edit-cli repository commit list -r https://github.com/owner/repo --since 2024-01-01T00:00:00Z --until 2024-02-01T00:00:00Z
+    List {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Branch, tag or commit SHA to start listing from (default: the default branch)
+        #[arg(short, long, value_name = "BRANCH")]
+        branch: Option<String>,
+        /// Only commits touching this file or directory
+        #[arg(short, long, value_name = "PATH")]
+        path: Option<String>,
+        /// Only commits authored at or after this time (RFC 3339)
+        #[arg(long, value_name = "TIMESTAMP")]
+        since: Option<DateTime<Utc>>,
+        /// Only commits authored at or before this time (RFC 3339)
+        #[arg(long, value_name = "TIMESTAMP")]
+        until: Option<DateTime<Utc>>,
+        #[command(flatten)]
+        pagination: PaginationArgs,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ReleaseAction {
+    /// Create a release, creating the tag from the target if it does not exist
+    ///
+    /// Examples:
+    ///   github-edit-cli repository release create -r https://github.com/owner/repo -t v1.0.0 -n "Version 1.0.0" -b "First stable release"
+    ///   github-edit-cli repository release create -r https://github.com/owner/repo -t v1.1.0-rc.1 --prerelease --draft --generate-notes
+    Create {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Tag of the release
+        #[arg(short, long, value_name = "TAG")]
+        tag: String,
+        /// Release title (default: the tag)
+        #[arg(short, long, value_name = "NAME")]
+        name: Option<String>,
+        /// Release notes in Markdown
+        #[arg(short, long, value_name = "BODY", conflicts_with = "generate_notes")]
+        body: Option<String>,
+        /// Read the release notes from a file instead ("-" reads stdin, as does --body -)
+        #[arg(
+            long,
+            value_name = "PATH",
+            conflicts_with_all = ["body", "generate_notes"]
+        )]
+        body_file: Option<PathBuf>,
+        /// Use notes generated from the pull requests merged since the previous release
+        #[arg(long)]
+        generate_notes: bool,
+        /// Create the release as an unpublished draft
+        #[arg(long)]
+        draft: bool,
+        /// Mark the release as a prerelease
+        #[arg(long)]
+        prerelease: bool,
+        /// Branch or commit SHA to create the tag from (default: the default branch)
+        #[arg(long, value_name = "REF")]
+        target: Option<String>,
+    },
+    /// Update a release identified by ID or tag
+    ///
+    /// Examples:
+    ///   github-edit-cli repository release update -r https://github.com/owner/repo -t v1.0.0 -b "Updated notes"
+    ///   github-edit-cli repository release update -r https://github.com/owner/repo --release-id 123456 --prerelease false
+    Update {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Release ID (required for drafts)
+        #[arg(long, value_name = "ID", conflicts_with = "tag")]
+        release_id: Option<u64>,
+        /// Tag of a published release
+        #[arg(short, long, value_name = "TAG")]
+        tag: Option<String>,
+        /// New tag
+        #[arg(long, value_name = "TAG")]
+        new_tag: Option<String>,
+        /// New release title
+        #[arg(short, long, value_name = "NAME")]
+        name: Option<String>,
+        /// New release notes in Markdown
+        #[arg(short, long, value_name = "BODY")]
+        body: Option<String>,
+        /// Read the new release notes from a file instead ("-" reads stdin, as does --body -)
+        #[arg(long, value_name = "PATH", conflicts_with = "body")]
+        body_file: Option<PathBuf>,
+        /// Draft flag; false publishes a draft
+        #[arg(long, value_name = "BOOL")]
+        draft: Option<bool>,
+        /// Prerelease flag
+        #[arg(long, value_name = "BOOL")]
+        prerelease: Option<bool>,
+        /// Branch or commit SHA for an unpublished tag
+        #[arg(long, value_name = "REF")]
+        target: Option<String>,
+    },
+    /// Publish a draft release
+    ///
+    /// Examples:
+    ///   github-edit-cli repository release publish -r https://github.com/owner/repo --release-id 123456
+    Publish {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Release ID of the draft
+        #[arg(long, value_name = "ID")]
+        release_id: u64,
+    },
+    /// Delete a release identified by ID or tag. The tag itself is kept
+    ///
+    /// Examples:
+    ///   github-edit-cli repository release delete -r https://github.com/owner/repo -t v1.0.0
+    ///   github-edit-cli repository release delete -r https://github.com/owner/repo --release-id 123456
+    Delete {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Release ID (required for drafts)
+        #[arg(long, value_name = "ID", conflicts_with = "tag")]
+        release_id: Option<u64>,
+        /// Tag of a published release
+        #[arg(short, long, value_name = "TAG")]
+        tag: Option<String>,
+    },
+    /// Print release notes generated from the pull requests merged since the previous release
+    ///
+    /// Examples:
+    ///   github-edit-cli repository release generate-notes -r https://github.com/owner/repo -t v1.1.0
+    ///   github-edit-cli repository release generate-notes -r https://github.com/owner/repo -t v1.1.0 --previous-tag v1.0.0
+    GenerateNotes {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Tag of the release the notes are for
+        #[arg(short, long, value_name = "TAG")]
+        tag: String,
+        /// Tag to start from (default: the latest release)
+        #[arg(long, value_name = "TAG")]
+        previous_tag: Option<String>,
+        /// Branch or commit SHA if the tag does not exist yet
+        #[arg(long, value_name = "REF")]
+        target: Option<String>,
+    },
+}
+
+pub async fn execute_repository_action(
+    github_client: &GitHubClient,
+    action: RepositoryAction,
+    output: OutputFormat,
+) -> Result<()> {
+    let result = match action {
+        RepositoryAction::Get { repository_url } => {
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+
+            let repo = repository::get_repository(github_client, &repo_id).await?;
+
+            return output.print_data(&repo);
+        }
+        RepositoryAction::Milestone { action } => {
+            execute_milestone_action(github_client, action).await?
+        }
+        RepositoryAction::CreateMilestone {
+            repository_url,
+            title,
+            description,
+            due_on,
+            state,
+        } => {
+            let action = MilestoneAction::Create {
+                repository_url,
+                title,
+                description,
+                due: due_on,
+                state,
+            };
+            execute_milestone_action(github_client, action).await?
         }
         RepositoryAction::UpdateMilestone {
             repository_url,
@@ -304,63 +837,412 @@ pub async fn execute_repository_action(
             due_on,
             state,
         } => {
+            let action = MilestoneAction::Update {
+                repository_url,
+                milestone_number: Some(milestone_number),
+                milestone_title: None,
+                title,
+                description,
+                due: due_on,
+                state,
+            };
+            execute_milestone_action(github_client, action).await?
+        }
+        RepositoryAction::DeleteMilestone {
+            repository_url,
+            milestone_number,
+        } => {
+            let action = MilestoneAction::Delete {
+                repository_url,
+                milestone_number: Some(milestone_number),
+                milestone_title: None,
+            };
+            execute_milestone_action(github_client, action).await?
+        }
+        RepositoryAction::ListMilestones {
+            repository_url,
+            state,
+            pagination,
+        } => {
+            let action = MilestoneAction::List {
+                repository_url,
+                state,
+                pagination,
+            };
+            execute_milestone_action(github_client, action).await?
+        }
+        RepositoryAction::Label { action } => execute_label_action(github_client, action).await?,
+        RepositoryAction::CreateLabel {
+            repository_url,
+            name,
+            color,
+            description,
+        } => {
+            let action = LabelAction::Create {
+                repository_url,
+                name,
+                color,
+                description,
+            };
+            execute_label_action(github_client, action).await?
+        }
+        RepositoryAction::UpdateLabel {
+            repository_url,
+            old_name,
+            new_name,
+            color,
+            description,
+        } => {
+            let action = LabelAction::Update {
+                repository_url,
+                name: old_name,
+                new_name,
+                color,
+                description,
+            };
+            execute_label_action(github_client, action).await?
+        }
+        RepositoryAction::DeleteLabel {
+            repository_url,
+            name,
+        } => {
+            let action = LabelAction::Delete {
+                repository_url,
+                name,
+            };
+            execute_label_action(github_client, action).await?
+        }
+        RepositoryAction::ListLabels {
+            repository_url,
+            pagination,
+        } => {
+            let action = LabelAction::List {
+                repository_url,
+                pagination,
+            };
+            execute_label_action(github_client, action).await?
+        }
+        RepositoryAction::Branch { action } => execute_branch_action(github_client, action).await?,
+        RepositoryAction::Commit { action } => execute_commit_action(github_client, action).await?,
+        RepositoryAction::Release { action } => {
+            execute_release_action(github_client, action).await?
+        }
+        RepositoryAction::Fork {
+            repository_url,
+            organization,
+            default_branch_only,
+        } => {
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+
+            let fork = repository::fork_repository(
+                github_client,
+                &repo_id,
+                organization.as_deref(),
+                default_branch_only,
+            )
+            .await?;
+
+            RepositoryResult::Forked {
+                repository: repo_id,
+                url: fork.html_url,
+            }
+        }
+        RepositoryAction::Archive { repository_url } => {
             let repo_url = RepositoryUrl::new(repository_url);
             let repo_id = RepositoryId::parse_url(&repo_url)
                 .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
 
-            let milestone_number = MilestoneNumber::new(milestone_number.into());
+            repository::archive_repository(github_client, &repo_id).await?;
 
-            let due_date = if let Some(due_str) = due_on {
-                Some(
-                    due_str
-                        .parse::<DateTime<Utc>>()
-                        .map_err(|e| anyhow::anyhow!("Failed to parse due date: {}", e))?,
-                )
-            } else {
-                None
+            RepositoryResult::Archived {
+                repository: repo_id,
+            }
+        }
+        RepositoryAction::Unarchive { repository_url } => {
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+
+            repository::unarchive_repository(github_client, &repo_id).await?;
+
+            RepositoryResult::Unarchived {
+                repository: repo_id,
+            }
+        }
+        RepositoryAction::Transfer {
+            repository_url,
+            new_owner,
+            new_name,
+            team_ids,
+            wait,
+        } => {
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+
+            let transfer = repository::transfer_repository(
+                github_client,
+                &repo_id,
+                &new_owner,
+                new_name.as_deref(),
+                &team_ids,
+                Some(Duration::from_secs(wait)),
+            )
+            .await?;
+
+            RepositoryResult::Transferred {
+                repository: repo_id,
+                transfer,
+            }
+        }
+        RepositoryAction::TransferStatus { repository_url } => {
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+
+            let status =
+                repository::get_repository_transfer_status(github_client, &repo_id).await?;
+
+            RepositoryResult::TransferStatus {
+                repository: repo_id,
+                status,
+            }
+        }
+        RepositoryAction::CopyMetadata {
+            source_repository_url,
+            target_repository_url,
+            labels,
+            milestones,
+            on_conflict,
+        } => {
+            let source_repo_id =
+                RepositoryId::parse_url(&RepositoryUrl::new(source_repository_url))
+                    .map_err(|e| anyhow::anyhow!("Failed to parse source repository URL: {}", e))?;
+            let target_repo_id =
+                RepositoryId::parse_url(&RepositoryUrl::new(target_repository_url))
+                    .map_err(|e| anyhow::anyhow!("Failed to parse target repository URL: {}", e))?;
+
+            let copy_all = !labels && !milestones;
+            let options = MetadataCopyOptions {
+                labels: labels || copy_all,
+                milestones: milestones || copy_all,
+                on_conflict,
+            };
+            let result = repository::copy_repository_metadata(
+                github_client,
+                &source_repo_id,
+                &target_repo_id,
+                &options,
+            )
+            .await?;
+
+            let failure_count = result.failure_count();
+            output.print(&RepositoryResult::MetadataCopied(result))?;
+            if failure_count > 0 {
+                return Err(anyhow::anyhow!(
+                    "Failed to copy {} item(s) to {}",
+                    failure_count,
+                    target_repo_id
+                ));
+            }
+            return Ok(());
+        }
+        RepositoryAction::UpdateSettings {
+            repository_url,
+            description,
+            homepage,
+            topics,
+            has_issues,
+            has_wiki,
+            has_projects,
+            default_branch,
+            allow_squash_merge,
+            allow_merge_commit,
+            allow_rebase_merge,
+            delete_branch_on_merge,
+        } => {
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+
+            let settings = RepositorySettings {
+                description,
+                homepage,
+                topics: topics.map(|topics| {
+                    topics
+                        .split(',')
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect()
+                }),
+                has_issues,
+                has_wiki,
+                has_projects,
+                default_branch,
+                allow_squash_merge,
+                allow_merge_commit,
+                allow_rebase_merge,
+                delete_branch_on_merge,
             };
+            let updated =
+                repository::update_repository_settings(github_client, &repo_id, &settings).await?;
+
+            RepositoryResult::SettingsUpdated {
+                repository: repo_id,
+                settings: updated,
+            }
+        }
+        RepositoryAction::SyncFork {
+            repository_url,
+            branch,
+        } => {
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+
+            let result =
+                repository::sync_fork_with_upstream(github_client, &repo_id, branch.as_deref())
+                    .await?;
+
+            RepositoryResult::ForkSynced(result)
+        }
+    };
+    output.print(&result)
+}
 
+async fn execute_milestone_action(
+    github_client: &GitHubClient,
+    action: MilestoneAction,
+) -> Result<RepositoryResult> {
+    let result = match action {
+        MilestoneAction::List {
+            repository_url,
+            state,
+            pagination,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+            let milestones = repository::list_milestones(github_client, &repo_id, state).await?;
+            RepositoryResult::Milestones(pagination.select(milestones))
+        }
+        MilestoneAction::Create {
+            repository_url,
+            title,
+            description,
+            due,
+            state,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+            let created_milestone = repository::create_milestone(
+                github_client,
+                &repo_id,
+                &title,
+                description.as_deref(),
+                due,
+                state,
+            )
+            .await?;
+            RepositoryResult::MilestoneCreated(created_milestone)
+        }
+        MilestoneAction::Update {
+            repository_url,
+            milestone_number,
+            milestone_title,
+            title,
+            description,
+            due,
+            state,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+            let milestone_number = repository::resolve_milestone(
+                github_client,
+                &repo_id,
+                milestone_number.map(|n| MilestoneNumber::new(n.into())),
+                milestone_title.as_deref(),
+            )
+            .await?;
             let updated_milestone = repository::update_milestone(
                 github_client,
                 &repo_id,
                 &milestone_number,
                 title.as_deref(),
                 description.as_deref(),
-                due_date,
+                due,
                 state,
             )
             .await?;
-
-            println!(
-                "Updated milestone #{} - {}",
-                updated_milestone.id.value(),
-                updated_milestone.title
-            );
+            RepositoryResult::MilestoneUpdated(updated_milestone)
         }
-        RepositoryAction::DeleteMilestone {
+        MilestoneAction::Close {
             repository_url,
             milestone_number,
+            milestone_title,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
-
-            let milestone_number = MilestoneNumber::new(milestone_number.into());
-
+            let repo_id = parse_repository_url(repository_url)?;
+            let milestone_number = repository::resolve_milestone(
+                github_client,
+                &repo_id,
+                milestone_number.map(|n| MilestoneNumber::new(n.into())),
+                milestone_title.as_deref(),
+            )
+            .await?;
+            let closed_milestone = repository::update_milestone(
+                github_client,
+                &repo_id,
+                &milestone_number,
+                None,
+                None,
+                None,
+                Some(MilestoneState::Closed),
+            )
+            .await?;
+            RepositoryResult::MilestoneClosed(closed_milestone)
+        }
+        MilestoneAction::Delete {
+            repository_url,
+            milestone_number,
+            milestone_title,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+            let milestone_number = repository::resolve_milestone(
+                github_client,
+                &repo_id,
+                milestone_number.map(|n| MilestoneNumber::new(n.into())),
+                milestone_title.as_deref(),
+            )
+            .await?;
             repository::delete_milestone(github_client, &repo_id, &milestone_number).await?;
+            RepositoryResult::MilestoneDeleted {
+                milestone: milestone_number.value(),
+            }
+        }
+    };
+    Ok(result)
+}
 
-            println!("Deleted milestone #{}", milestone_number.value());
+async fn execute_label_action(
+    github_client: &GitHubClient,
+    action: LabelAction,
+) -> Result<RepositoryResult> {
+    let result = match action {
+        LabelAction::List {
+            repository_url,
+            pagination,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+            let labels = pagination
+                .fetch(|pagination| repository::list_labels(github_client, &repo_id, pagination))
+                .await?;
+            RepositoryResult::Labels(labels)
         }
-        RepositoryAction::CreateLabel {
+        LabelAction::Create {
             repository_url,
             name,
             color,
             description,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
-
+            let repo_id = parse_repository_url(repository_url)?;
             let created_label = repository::create_label(
                 github_client,
                 &repo_id,
@@ -369,52 +1251,607 @@ pub async fn execute_repository_action(
                 description.as_deref(),
             )
             .await?;
-
-            println!(
-                "Created label '{}' with color #{}",
-                created_label.name,
-                created_label.color()
-            );
+            RepositoryResult::LabelCreated(created_label)
         }
-        RepositoryAction::UpdateLabel {
+        LabelAction::Update {
             repository_url,
-            old_name,
+            name,
             new_name,
             color,
             description,
         } => {
-            let repo_url = RepositoryUrl::new(repository_url);
-            let repo_id = RepositoryId::parse_url(&repo_url)
-                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
-
+            let repo_id = parse_repository_url(repository_url)?;
             let updated_label = repository::update_label(
                 github_client,
                 &repo_id,
-                &old_name,
+                &name,
                 new_name.as_deref(),
                 color.as_deref(),
                 description.as_deref(),
             )
             .await?;
+            RepositoryResult::LabelUpdated(updated_label)
+        }
+        LabelAction::Rename {
+            repository_url,
+            name,
+            to,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+            let label =
+                repository::update_label(github_client, &repo_id, &name, Some(&to), None, None)
+                    .await?;
+            RepositoryResult::LabelRenamed { from: name, label }
+        }
+        LabelAction::Delete {
+            repository_url,
+            name,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+            repository::delete_label(github_client, &repo_id, &name).await?;
+            RepositoryResult::LabelDeleted { name }
+        }
+    };
+    Ok(result)
+}
 
-            println!(
-                "Updated label '{}' with color #{}",
-                updated_label.name,
-                updated_label.color()
-            );
+async fn execute_branch_action(
+    github_client: &GitHubClient,
+    action: BranchAction,
+) -> Result<RepositoryResult> {
+    let result = match action {
+        BranchAction::List {
+            repository_url,
+            pagination,
+        } => {
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+
+            let branches = pagination
+                .fetch(|pagination| repository::list_branches(github_client, &repo_id, pagination))
+                .await?;
+
+            RepositoryResult::Branches(branches)
         }
-        RepositoryAction::DeleteLabel {
+        BranchAction::Create {
             repository_url,
             name,
+            from_ref,
         } => {
             let repo_url = RepositoryUrl::new(repository_url);
             let repo_id = RepositoryId::parse_url(&repo_url)
                 .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
 
-            repository::delete_label(github_client, &repo_id, &name).await?;
+            let branch =
+                repository::create_branch(github_client, &repo_id, &name, &from_ref).await?;
+
+            RepositoryResult::BranchCreated(branch)
+        }
+        BranchAction::Delete {
+            repository_url,
+            name,
+        } => {
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+
+            repository::delete_branch(github_client, &repo_id, &name).await?;
 
-            println!("Deleted label '{}'", name);
+            RepositoryResult::BranchDeleted { name }
+        }
+        BranchAction::Rename {
+            repository_url,
+            name,
+            new_name,
+        } => {
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+
+            let branch =
+                repository::rename_branch(github_client, &repo_id, &name, &new_name).await?;
+
+            RepositoryResult::BranchRenamed {
+                old_name: name,
+                branch,
+            }
+        }
+    };
+    Ok(result)
+}
+
+async fn execute_commit_action(
+    github_client: &GitHubClient,
+    action: CommitAction,
+) -> Result<RepositoryResult> {
+    let result = match action {
+        CommitAction::Get {
+            repository_url,
+            sha,
+            patch,
+        } => {
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+
+            let commit = repository::get_commit(github_client, &repo_id, &sha).await?;
+
+            RepositoryResult::Commit {
+                commit: Box::new(commit),
+                patch,
+            }
+        }
+        CommitAction::List {
+            repository_url,
+            branch,
+            path,
+            since,
+            until,
+            pagination,
+        } => {
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+            let filter = CommitFilter {
+                branch,
+                path,
+                since,
+                until,
+            };
+
+            let commits = pagination
+                .fetch(|pagination| {
+                    repository::list_commits(github_client, &repo_id, &filter, pagination)
+                })
+                .await?;
+
+            RepositoryResult::Commits(commits)
+        }
+    };
+    Ok(result)
+}
+
+async fn execute_release_action(
+    github_client: &GitHubClient,
+    action: ReleaseAction,
+) -> Result<RepositoryResult> {
+    let result = match action {
+        ReleaseAction::Create {
+            repository_url,
+            tag,
+            name,
+            body,
+            body_file,
+            generate_notes,
+            draft,
+            prerelease,
+            target,
+        } => {
+            let body = read_body(body, body_file)?;
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+
+            let body = if generate_notes {
+                let notes = repository::generate_release_notes(
+                    github_client,
+                    &repo_id,
+                    &tag,
+                    None,
+                    target.as_deref(),
+                )
+                .await?;
+                Some(notes.body)
+            } else {
+                body
+            };
+
+            let release = repository::create_release(
+                github_client,
+                &repo_id,
+                &tag,
+                name.as_deref(),
+                body.as_deref(),
+                draft,
+                prerelease,
+                target.as_deref(),
+            )
+            .await?;
+
+            RepositoryResult::ReleaseCreated(release)
+        }
+        ReleaseAction::Update {
+            repository_url,
+            release_id,
+            tag,
+            new_tag,
+            name,
+            body,
+            body_file,
+            draft,
+            prerelease,
+            target,
+        } => {
+            let body = read_body(body, body_file)?;
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+
+            let release_id = repository::resolve_release_id(
+                github_client,
+                &repo_id,
+                release_id.map(ReleaseId::new),
+                tag.as_deref(),
+            )
+            .await?;
+
+            let update = ReleaseUpdate {
+                tag_name: new_tag,
+                name,
+                body,
+                draft,
+                prerelease,
+                target_commitish: target,
+            };
+            let release =
+                repository::update_release(github_client, &repo_id, release_id, &update).await?;
+
+            RepositoryResult::ReleaseUpdated(release)
+        }
+        ReleaseAction::Publish {
+            repository_url,
+            release_id,
+        } => {
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+
+            let release =
+                repository::publish_release(github_client, &repo_id, ReleaseId::new(release_id))
+                    .await?;
+
+            RepositoryResult::ReleasePublished(release)
+        }
+        ReleaseAction::Delete {
+            repository_url,
+            release_id,
+            tag,
+        } => {
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+
+            let release_id = repository::resolve_release_id(
+                github_client,
+                &repo_id,
+                release_id.map(ReleaseId::new),
+                tag.as_deref(),
+            )
+            .await?;
+            repository::delete_release(github_client, &repo_id, release_id).await?;
+
+            RepositoryResult::ReleaseDeleted { release_id }
+        }
+        ReleaseAction::GenerateNotes {
+            repository_url,
+            tag,
+            previous_tag,
+            target,
+        } => {
+            let repo_url = RepositoryUrl::new(repository_url);
+            let repo_id = RepositoryId::parse_url(&repo_url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))?;
+
+            let notes = repository::generate_release_notes(
+                github_client,
+                &repo_id,
+                &tag,
+                previous_tag.as_deref(),
+                target.as_deref(),
+            )
+            .await?;
+
+            RepositoryResult::ReleaseNotes(notes)
+        }
+    };
+    Ok(result)
+}
+
+/// Result of a repository command
+#[derive(Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+enum RepositoryResult {
+    MilestoneCreated(Milestone),
+    MilestoneUpdated(Milestone),
+    MilestoneClosed(Milestone),
+    MilestoneDeleted {
+        milestone: u64,
+    },
+    Milestones(Page<Milestone>),
+    LabelCreated(Label),
+    LabelUpdated(Label),
+    LabelRenamed {
+        from: String,
+        label: Label,
+    },
+    LabelDeleted {
+        name: String,
+    },
+    Labels(Page<Label>),
+    Forked {
+        repository: RepositoryId,
+        url: String,
+    },
+    Archived {
+        repository: RepositoryId,
+    },
+    Unarchived {
+        repository: RepositoryId,
+    },
+    Transferred {
+        repository: RepositoryId,
+        transfer: RepositoryTransfer,
+    },
+    TransferStatus {
+        repository: RepositoryId,
+        status: RepositoryTransferStatus,
+    },
+    MetadataCopied(MetadataCopyResult),
+    SettingsUpdated {
+        repository: RepositoryId,
+        settings: RepositorySettings,
+    },
+    ForkSynced(ForkSyncResult),
+    Branches(Page<RepositoryBranch>),
+    BranchCreated(RepositoryBranch),
+    BranchDeleted {
+        name: String,
+    },
+    BranchRenamed {
+        old_name: String,
+        branch: RepositoryBranch,
+    },
+    Commit {
+        commit: Box<Commit>,
+        /// Whether the text shows the patches of the changed files
+        #[serde(skip)]
+        patch: bool,
+    },
+    Commits(Page<Commit>),
+    ReleaseCreated(Release),
+    ReleaseUpdated(Release),
+    ReleasePublished(Release),
+    ReleaseDeleted {
+        release_id: ReleaseId,
+    },
+    ReleaseNotes(GeneratedReleaseNotes),
+}
+
+impl fmt::Display for RepositoryResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepositoryResult::MilestoneCreated(milestone) => writeln!(
+                f,
+                "Created milestone #{} - {}",
+                milestone.id.value(),
+                milestone.title
+            ),
+            RepositoryResult::MilestoneUpdated(milestone) => writeln!(
+                f,
+                "Updated milestone #{} - {}",
+                milestone.id.value(),
+                milestone.title
+            ),
+            RepositoryResult::MilestoneClosed(milestone) => writeln!(
+                f,
+                "Closed milestone #{} - {}",
+                milestone.id.value(),
+                milestone.title
+            ),
+            RepositoryResult::MilestoneDeleted { milestone } => {
+                writeln!(f, "Deleted milestone #{}", milestone)
+            }
+            RepositoryResult::Milestones(milestones) => {
+                for milestone in &milestones.items {
+                    writeln!(
+                        f,
+                        "{}\t{}\t{}\t{}",
+                        milestone.id.value(),
+                        milestone.state,
+                        milestone.title,
+                        milestone
+                            .due_on
+                            .map(|due_on| due_on.format("%Y-%m-%d").to_string())
+                            .unwrap_or_default()
+                    )?;
+                }
+                next_page_line(f, "milestones", milestones.page, milestones.has_next_page)
+            }
+            RepositoryResult::LabelCreated(label) => writeln!(
+                f,
+                "Created label '{}' with color #{}",
+                label.name,
+                label.color()
+            ),
+            RepositoryResult::LabelUpdated(label) => writeln!(
+                f,
+                "Updated label '{}' with color #{}",
+                label.name,
+                label.color()
+            ),
+            RepositoryResult::LabelRenamed { from, label } => {
+                writeln!(f, "Renamed label '{}' to '{}'", from, label.name)
+            }
+            RepositoryResult::LabelDeleted { name } => writeln!(f, "Deleted label '{}'", name),
+            RepositoryResult::Labels(labels) => {
+                for label in &labels.items {
+                    writeln!(
+                        f,
+                        "{}\t{}\t{}",
+                        label.name,
+                        label.color(),
+                        label.description().unwrap_or_default()
+                    )?;
+                }
+                next_page_line(f, "labels", labels.page, labels.has_next_page)
+            }
+            RepositoryResult::Forked { repository, url } => {
+                writeln!(f, "Forked {} to {}", repository, url)
+            }
+            RepositoryResult::Archived { repository } => writeln!(f, "Archived {}", repository),
+            RepositoryResult::Unarchived { repository } => {
+                writeln!(f, "Unarchived {}", repository)
+            }
+            RepositoryResult::Transferred {
+                repository,
+                transfer,
+            } => {
+                if transfer.is_completed() {
+                    writeln!(f, "Transferred {} to {}", repository, transfer.html_url)
+                } else {
+                    writeln!(
+                        f,
+                        "Transfer of {} to {} is pending",
+                        repository, transfer.html_url
+                    )
+                }
+            }
+            RepositoryResult::TransferStatus { repository, status } => {
+                writeln!(f, "{}\t{}", repository, status)
+            }
+            RepositoryResult::MetadataCopied(result) => {
+                for (kind, entries) in
+                    [("label", &result.labels), ("milestone", &result.milestones)]
+                {
+                    for entry in entries {
+                        match &entry.error {
+                            Some(error) => writeln!(
+                                f,
+                                "{}\t{}\t{}\t{}",
+                                kind, entry.action, entry.name, error
+                            )?,
+                            None => writeln!(f, "{}\t{}\t{}", kind, entry.action, entry.name)?,
+                        }
+                    }
+                }
+                Ok(())
+            }
+            RepositoryResult::SettingsUpdated {
+                repository,
+                settings,
+            } => {
+                writeln!(f, "Updated settings of {}", repository)?;
+                writeln!(
+                    f,
+                    "{}",
+                    serde_json::to_string_pretty(settings).map_err(|_| fmt::Error)?
+                )
+            }
+            RepositoryResult::ForkSynced(result) => {
+                if result.updated() {
+                    writeln!(f, "Synced fork with {}", result.base_branch)
+                } else {
+                    writeln!(f, "Fork is already up to date with {}", result.base_branch)
+                }
+            }
+            RepositoryResult::Branches(branches) => {
+                for branch in &branches.items {
+                    writeln!(
+                        f,
+                        "{}\t{}{}",
+                        branch.name,
+                        branch.commit_sha,
+                        if branch.protected { "\tprotected" } else { "" }
+                    )?;
+                }
+                next_page_line(f, "branches", branches.page, branches.has_next_page)
+            }
+            RepositoryResult::BranchCreated(branch) => writeln!(
+                f,
+                "Created branch '{}' at {}",
+                branch.name, branch.commit_sha
+            ),
+            RepositoryResult::BranchDeleted { name } => writeln!(f, "Deleted branch '{}'", name),
+            RepositoryResult::BranchRenamed { old_name, branch } => {
+                writeln!(f, "Renamed branch '{}' to '{}'", old_name, branch.name)
+            }
+            RepositoryResult::Commit { commit, patch } => {
+                writeln!(f, "commit {}", commit.sha)?;
+                if let Some(author) = &commit.commit.author {
+                    writeln!(f, "Author: {} <{}>", author.name, author.email)?;
+                    writeln!(f, "Date:   {}", author.date.to_rfc3339())?;
+                }
+                writeln!(f)?;
+                for line in commit.commit.message.lines() {
+                    writeln!(f, "    {}", line)?;
+                }
+                writeln!(f)?;
+                for file in &commit.files {
+                    writeln!(
+                        f,
+                        "{}\t+{}\t-{}\t{}",
+                        file.status, file.additions, file.deletions, file.filename
+                    )?;
+                    if *patch {
+                        if let Some(diff) = &file.patch {
+                            writeln!(f, "{}", diff)?;
+                        }
+                    }
+                }
+                if let Some(stats) = &commit.stats {
+                    writeln!(
+                        f,
+                        "{} file(s) changed, {} insertion(s), {} deletion(s)",
+                        commit.files.len(),
+                        stats.additions,
+                        stats.deletions
+                    )?;
+                }
+                Ok(())
+            }
+            RepositoryResult::Commits(commits) => {
+                for commit in &commits.items {
+                    writeln!(
+                        f,
+                        "{}\t{}\t{}\t{}",
+                        commit.short_sha(),
+                        commit
+                            .authored_at()
+                            .map(|date| date.to_rfc3339())
+                            .unwrap_or_default(),
+                        commit.author_name().unwrap_or("unknown"),
+                        commit.summary()
+                    )?;
+                }
+                next_page_line(f, "commits", commits.page, commits.has_next_page)
+            }
+            RepositoryResult::ReleaseCreated(release) => writeln!(
+                f,
+                "Created release {} (ID: {}): {}",
+                release.tag_name, release.id, release.html_url
+            ),
+            RepositoryResult::ReleaseUpdated(release) => writeln!(
+                f,
+                "Updated release {} (ID: {}): {}",
+                release.tag_name, release.id, release.html_url
+            ),
+            RepositoryResult::ReleasePublished(release) => writeln!(
+                f,
+                "Published release {} (ID: {}): {}",
+                release.tag_name, release.id, release.html_url
+            ),
+            RepositoryResult::ReleaseDeleted { release_id } => {
+                writeln!(f, "Deleted release {}", release_id)
+            }
+            RepositoryResult::ReleaseNotes(notes) => {
+                writeln!(f, "{}\n\n{}", notes.name, notes.body)
+            }
         }
     }
-    Ok(())
+}
+
+fn parse_repository_url(repository_url: String) -> Result<RepositoryId> {
+    RepositoryId::parse_url(&RepositoryUrl::new(repository_url))
+        .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))
 }
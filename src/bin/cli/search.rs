@@ -0,0 +1,298 @@
+//! Search CLI commands and execution logic
+//!
+//! This module contains the CLI command definitions and execution logic
+//! for locating issues, pull requests, repositories and users with
+//! GitHub's search query syntax, and for managing saved search aliases.
+
+use super::output::{OutputFormat, next_page_line};
+use super::pagination::PaginationArgs;
+use anyhow::Result;
+use clap::Subcommand;
+use github_edit::github::GitHubClient;
+use github_edit::tools::functions::search;
+use github_edit::types::search::{
+    IssueSearchResult, IssueSearchSort, RepositorySearchResult, RepositorySearchSort, SearchOrder,
+    SearchResults, UserSearchResult, UserSearchSort,
+};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Subcommand)]
+pub enum SearchAction {
+    /// Search issues and pull requests
+    ///
+    /// Use "is:issue" or "is:pr" in the query to restrict the result type.
+    /// Saved aliases are referenced as "@name".
+    ///
+    /// Examples:
+    ///   github-edit-cli search issues "repo:owner/repo label:bug is:open"
+    ///   github-edit-cli search issues "is:pr author:octocat created:>2024-01-01" --sort updated
+    ///   github-edit-cli search issues "repo:owner/repo is:issue no:assignee" --sort created --order asc
+    ///   github-edit-cli search issues "@triage author:octocat"
+    Issues {
+        /// Search query using GitHub's search syntax
+        query: String,
+        /// Field to sort by (default: best match)
+        #[arg(long, value_enum)]
+        sort: Option<IssueSearchSort>,
+        /// Sort order (default: desc)
+        #[arg(long, value_enum)]
+        order: Option<SearchOrder>,
+        #[command(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// Search repositories
+    ///
+    /// Examples:
+    ///   github-edit-cli search repos "github-edit in:name"
+    ///   github-edit-cli search repos "topic:mcp language:rust" --sort stars
+    Repos {
+        /// Search query using GitHub's search syntax
+        query: String,
+        /// Field to sort by (default: best match)
+        #[arg(long, value_enum)]
+        sort: Option<RepositorySearchSort>,
+        /// Sort order (default: desc)
+        #[arg(long, value_enum)]
+        order: Option<SearchOrder>,
+        #[command(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// Search users and organizations
+    ///
+    /// Examples:
+    ///   github-edit-cli search users "tacogips"
+    ///   github-edit-cli search users "type:org rust" --sort followers
+    Users {
+        /// Search query using GitHub's search syntax
+        query: String,
+        /// Field to sort by (default: best match)
+        #[arg(long, value_enum)]
+        sort: Option<UserSearchSort>,
+        /// Sort order (default: desc)
+        #[arg(long, value_enum)]
+        order: Option<SearchOrder>,
+        #[command(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// Manage saved search aliases
+    ///
+    /// Examples:
+    ///   github-edit-cli search alias add triage "is:open label:needs-triage repo:owner/repo"
+    ///   github-edit-cli search alias list
+    Alias {
+        #[command(subcommand)]
+        action: SearchAliasAction,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum SearchAliasAction {
+    /// Save a search query under an alias, replacing any query saved under it
+    ///
+    /// Examples:
+    ///   github-edit-cli search alias add triage "is:open label:needs-triage repo:owner/repo"
+    Add {
+        /// Alias name (alphanumeric characters, hyphens and underscores)
+        name: String,
+        /// Search query to save
+        query: String,
+    },
+    /// List the saved search aliases
+    ///
+    /// Examples:
+    ///   github-edit-cli search alias list
+    List,
+    /// Remove a saved search alias
+    ///
+    /// Examples:
+    ///   github-edit-cli search alias remove triage
+    Remove {
+        /// Alias name
+        name: String,
+    },
+}
+
+pub async fn execute_search_action(
+    github_client: &GitHubClient,
+    action: SearchAction,
+    output: OutputFormat,
+) -> Result<()> {
+    let result = match action {
+        SearchAction::Issues {
+            query,
+            sort,
+            order,
+            pagination,
+        } => {
+            let results = pagination
+                .fetch(|pagination| {
+                    search::search_issues_and_prs(github_client, &query, sort, order, pagination)
+                })
+                .await?;
+
+            SearchResult::Issues(results)
+        }
+        SearchAction::Repos {
+            query,
+            sort,
+            order,
+            pagination,
+        } => {
+            let results = pagination
+                .fetch(|pagination| {
+                    search::search_repositories(github_client, &query, sort, order, pagination)
+                })
+                .await?;
+
+            SearchResult::Repositories(results)
+        }
+        SearchAction::Users {
+            query,
+            sort,
+            order,
+            pagination,
+        } => {
+            let results = pagination
+                .fetch(|pagination| {
+                    search::search_users(github_client, &query, sort, order, pagination)
+                })
+                .await?;
+
+            SearchResult::Users(results)
+        }
+        SearchAction::Alias { action } => execute_search_alias_action(action)?,
+    };
+
+    output.print(&result)
+}
+
+fn execute_search_alias_action(action: SearchAliasAction) -> Result<SearchResult> {
+    let result = match action {
+        SearchAliasAction::Add { name, query } => {
+            let previous = search::add_search_alias(&name, &query)?;
+            SearchResult::AliasSaved {
+                name,
+                query,
+                previous,
+            }
+        }
+        SearchAliasAction::List => SearchResult::Aliases {
+            aliases: search::list_search_aliases()?,
+        },
+        SearchAliasAction::Remove { name } => {
+            let query = search::remove_search_alias(&name)?;
+            SearchResult::AliasRemoved { name, query }
+        }
+    };
+
+    Ok(result)
+}
+
+/// Result of a search command
+#[derive(Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+enum SearchResult {
+    Issues(SearchResults<IssueSearchResult>),
+    Repositories(SearchResults<RepositorySearchResult>),
+    Users(SearchResults<UserSearchResult>),
+    AliasSaved {
+        name: String,
+        query: String,
+        /// Query the alias had before, if it was updated
+        previous: Option<String>,
+    },
+    Aliases {
+        aliases: BTreeMap<String, String>,
+    },
+    AliasRemoved {
+        name: String,
+        query: String,
+    },
+}
+
+impl fmt::Display for SearchResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SearchResult::Issues(results) => {
+                writeln!(
+                    f,
+                    "Found {} issue(s) and pull request(s){}",
+                    results.total_count,
+                    if results.incomplete_results {
+                        " (results may be incomplete)"
+                    } else {
+                        ""
+                    }
+                )?;
+                for item in &results.page.items {
+                    writeln!(
+                        f,
+                        "{}#{}\t{}\t{}\t{}\t{}\t{}",
+                        item.repository(),
+                        item.number,
+                        if item.is_pull_request() {
+                            "pr"
+                        } else {
+                            "issue"
+                        },
+                        item.state,
+                        item.title,
+                        item.author().unwrap_or("ghost"),
+                        item.html_url
+                    )?;
+                }
+                next_page_line(f, "results", results.page.page, results.page.has_next_page)
+            }
+            SearchResult::Repositories(results) => {
+                writeln!(f, "Found {} repositories", results.total_count)?;
+                for repository in &results.page.items {
+                    writeln!(
+                        f,
+                        "{}\t{}\t{}\t{}\t{}",
+                        repository.full_name,
+                        repository.stargazers_count,
+                        repository.language.as_deref().unwrap_or("-"),
+                        repository.description.as_deref().unwrap_or(""),
+                        repository.html_url
+                    )?;
+                }
+                next_page_line(f, "results", results.page.page, results.page.has_next_page)
+            }
+            SearchResult::Users(results) => {
+                writeln!(f, "Found {} users and organizations", results.total_count)?;
+                for user in &results.page.items {
+                    writeln!(
+                        f,
+                        "{}\t{}\t{}",
+                        user.login, user.account_type, user.html_url
+                    )?;
+                }
+                next_page_line(f, "results", results.page.page, results.page.has_next_page)
+            }
+            SearchResult::AliasSaved {
+                name,
+                previous: Some(previous),
+                ..
+            } => writeln!(f, "Updated alias @{} (was: {})", name, previous),
+            SearchResult::AliasSaved {
+                name,
+                previous: None,
+                ..
+            } => writeln!(f, "Added alias @{}", name),
+            SearchResult::Aliases { aliases } => {
+                for (name, query) in aliases {
+                    writeln!(f, "@{}\t{}", name, query)?;
+                }
+                if aliases.is_empty() {
+                    writeln!(f, "No search aliases saved")?;
+                }
+                Ok(())
+            }
+            SearchResult::AliasRemoved { name, query } => {
+                writeln!(f, "Removed alias @{} ({})", name, query)
+            }
+        }
+    }
+}
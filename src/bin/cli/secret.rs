@@ -0,0 +1,270 @@
+//! GitHub Actions secret and variable CLI commands and execution logic
+//!
+//! This module contains the CLI command definitions and execution logic
+//! for rotating repository secrets and managing configuration variables.
+
+use super::output::{OutputFormat, next_page_line};
+use super::pagination::PaginationArgs;
+use anyhow::Result;
+use clap::Subcommand;
+use github_edit::github::GitHubClient;
+use github_edit::tools::functions::secret;
+use github_edit::types::pagination::Page;
+use github_edit::types::repository::{RepositoryId, RepositoryUrl};
+use github_edit::types::secret::{ActionsSecret, ActionsVariable};
+use serde::Serialize;
+use std::fmt;
+use std::io::Read;
+
+#[derive(Subcommand)]
+pub enum SecretAction {
+    /// Create or update a repository secret
+    ///
+    /// The value is read from stdin when --value is omitted, which keeps it
+    /// out of the shell history.
+    ///
+    /// Examples:
+    ///   github-edit-cli secrets set -r https://github.com/owner/repo -n DEPLOY_TOKEN --value "s3cr3t"
+    ///   cat token.txt | github-edit-cli secrets set -r https://github.com/owner/repo -n DEPLOY_TOKEN
+    Set {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Secret name
+        #[arg(short, long, value_name = "NAME")]
+        name: String,
+        /// Secret value (read from stdin if omitted)
+        #[arg(long, value_name = "VALUE")]
+        value: Option<String>,
+    },
+    /// Delete a repository secret
+    ///
+    /// Examples:
+    ///   github-edit-cli secrets delete -r https://github.com/owner/repo -n DEPLOY_TOKEN
+    Delete {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Secret name
+        #[arg(short, long, value_name = "NAME")]
+        name: String,
+    },
+    /// List the secret names of a repository
+    ///
+    /// Examples:
+    ///   github-edit-cli secrets list -r https://github.com/owner/repo
+    List {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        #[command(flatten)]
+        pagination: PaginationArgs,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum VariableAction {
+    /// List the configuration variables of a repository
+    ///
+    /// Examples:
+    ///   github-edit-cli variables list -r https://github.com/owner/repo
+    List {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        #[command(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// Show the value of a configuration variable
+    ///
+    /// Examples:
+    ///   github-edit-cli variables get -r https://github.com/owner/repo -n DEPLOY_ENV
+    Get {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Variable name
+        #[arg(short, long, value_name = "NAME")]
+        name: String,
+    },
+    /// Create a configuration variable, or update it if it already exists
+    ///
+    /// Examples:
+    ///   github-edit-cli variables set -r https://github.com/owner/repo -n DEPLOY_ENV --value staging
+    Set {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Variable name
+        #[arg(short, long, value_name = "NAME")]
+        name: String,
+        /// Variable value
+        #[arg(long, value_name = "VALUE")]
+        value: String,
+    },
+    /// Delete a configuration variable
+    ///
+    /// Examples:
+    ///   github-edit-cli variables delete -r https://github.com/owner/repo -n DEPLOY_ENV
+    Delete {
+        /// Repository URL (HTTPS format)
+        #[arg(short, long, value_name = "URL")]
+        repository_url: String,
+        /// Variable name
+        #[arg(short, long, value_name = "NAME")]
+        name: String,
+    },
+}
+
+pub async fn execute_secret_action(
+    github_client: &GitHubClient,
+    action: SecretAction,
+    output: OutputFormat,
+) -> Result<()> {
+    let result = match action {
+        SecretAction::Set {
+            repository_url,
+            name,
+            value,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+            let value = match value {
+                Some(value) => value,
+                None => {
+                    let mut value = String::new();
+                    std::io::stdin().read_to_string(&mut value)?;
+                    value.trim_end_matches(['\r', '\n']).to_string()
+                }
+            };
+
+            secret::set_repo_secret(github_client, &repo_id, &name, &value).await?;
+
+            SecretResult::SecretSet { name }
+        }
+        SecretAction::Delete {
+            repository_url,
+            name,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            secret::delete_repo_secret(github_client, &repo_id, &name).await?;
+
+            SecretResult::SecretDeleted { name }
+        }
+        SecretAction::List {
+            repository_url,
+            pagination,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let secrets = pagination
+                .fetch(|pagination| secret::list_secrets(github_client, &repo_id, pagination))
+                .await?;
+
+            SecretResult::Secrets(secrets)
+        }
+    };
+    output.print(&result)
+}
+
+pub async fn execute_variable_action(
+    github_client: &GitHubClient,
+    action: VariableAction,
+    output: OutputFormat,
+) -> Result<()> {
+    let result = match action {
+        VariableAction::List {
+            repository_url,
+            pagination,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let variables = pagination
+                .fetch(|pagination| secret::list_variables(github_client, &repo_id, pagination))
+                .await?;
+
+            SecretResult::Variables(variables)
+        }
+        VariableAction::Get {
+            repository_url,
+            name,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let variable = secret::get_variable(github_client, &repo_id, &name).await?;
+
+            SecretResult::Variable(variable)
+        }
+        VariableAction::Set {
+            repository_url,
+            name,
+            value,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            let created = secret::set_variable(github_client, &repo_id, &name, &value).await?;
+
+            SecretResult::VariableSet { name, created }
+        }
+        VariableAction::Delete {
+            repository_url,
+            name,
+        } => {
+            let repo_id = parse_repository_url(repository_url)?;
+
+            secret::delete_variable(github_client, &repo_id, &name).await?;
+
+            SecretResult::VariableDeleted { name }
+        }
+    };
+    output.print(&result)
+}
+
+/// Result of a secret or variable command
+#[derive(Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+enum SecretResult {
+    SecretSet { name: String },
+    SecretDeleted { name: String },
+    Secrets(Page<ActionsSecret>),
+    Variables(Page<ActionsVariable>),
+    Variable(ActionsVariable),
+    VariableSet { name: String, created: bool },
+    VariableDeleted { name: String },
+}
+
+impl fmt::Display for SecretResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SecretResult::SecretSet { name } => writeln!(f, "Set secret {}", name),
+            SecretResult::SecretDeleted { name } => writeln!(f, "Deleted secret {}", name),
+            SecretResult::Secrets(secrets) => {
+                for secret in &secrets.items {
+                    writeln!(f, "{}\t{}", secret.name, secret.updated_at.to_rfc3339())?;
+                }
+                next_page_line(f, "secrets", secrets.page, secrets.has_next_page)
+            }
+            SecretResult::Variables(variables) => {
+                for variable in &variables.items {
+                    writeln!(f, "{}\t{}", variable.name, variable.value)?;
+                }
+                next_page_line(f, "variables", variables.page, variables.has_next_page)
+            }
+            SecretResult::Variable(variable) => writeln!(f, "{}", variable.value),
+            SecretResult::VariableSet {
+                name,
+                created: true,
+            } => writeln!(f, "Created variable {}", name),
+            SecretResult::VariableSet {
+                name,
+                created: false,
+            } => writeln!(f, "Updated variable {}", name),
+            SecretResult::VariableDeleted { name } => writeln!(f, "Deleted variable {}", name),
+        }
+    }
+}
+
+fn parse_repository_url(repository_url: String) -> Result<RepositoryId> {
+    RepositoryId::parse_url(&RepositoryUrl::new(repository_url))
+        .map_err(|e| anyhow::anyhow!("Failed to parse repository URL: {}", e))
+}
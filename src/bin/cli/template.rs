@@ -0,0 +1,70 @@
+//! Templates of the CLI create commands
+//!
+//! `issue create` and `pull-request create` can build their body from a
+//! template with `--template <NAME>`, filling its `{{ key }}` placeholders
+//! from `--var key=value`. The template is read from a local file when
+//! `NAME` is a path, then from the template directories of the current
+//! directory, e.g. `.github/ISSUE_TEMPLATE/bug.md`, and otherwise fetched
+//! from the same directories of the target repository.
+
+use anyhow::{Context, Result};
+use clap::Args;
+use github_edit::github::GitHubClient;
+use github_edit::tools::functions::template;
+use github_edit::types::repository::RepositoryId;
+use github_edit::types::template::{TemplateKind, parse_template_vars, render_template};
+use std::path::Path;
+
+#[derive(Args)]
+pub struct TemplateArgs {
+    /// Build the body from a template (e.g. "bug" for .github/ISSUE_TEMPLATE/bug.md)
+    ///
+    /// A path to a local file is read as is. Otherwise the template is looked
+    /// up in the template directories of the current directory, then of the
+    /// repository.
+    #[arg(long, value_name = "NAME", conflicts_with_all = ["body", "body_file"])]
+    pub template: Option<String>,
+    /// Value of a {{ key }} placeholder of the template as key=value (repeatable)
+    #[arg(long = "var", value_name = "KEY=VALUE", requires = "template")]
+    pub vars: Vec<String>,
+}
+
+impl TemplateArgs {
+    /// Body rendered from the template, if one is given
+    pub async fn render(
+        self,
+        github_client: &GitHubClient,
+        repository_id: &RepositoryId,
+        kind: TemplateKind,
+    ) -> Result<Option<String>> {
+        let Some(name) = self.template else {
+            return Ok(None);
+        };
+        let vars = parse_template_vars(&self.vars).map_err(|e| anyhow::anyhow!(e))?;
+        let template = match read_local_template(kind, &name)? {
+            Some(template) => template,
+            None => template::fetch_template(github_client, repository_id, kind, &name).await?,
+        };
+        render_template(&template, &vars)
+            .map(Some)
+            .map_err(|e| anyhow::anyhow!("{} (pass them with --var key=value)", e))
+    }
+}
+
+/// Template stored in a local file, if any
+fn read_local_template(kind: TemplateKind, name: &str) -> Result<Option<String>> {
+    let named_path = Path::new(name);
+    let path = if named_path.is_file() {
+        Some(named_path.to_path_buf())
+    } else {
+        kind.template_paths(name)
+            .into_iter()
+            .map(Into::into)
+            .find(|path: &std::path::PathBuf| path.is_file())
+    };
+    path.map(|path| {
+        std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read the template {}", path.display()))
+    })
+    .transpose()
+}
@@ -0,0 +1,189 @@
+//! Watching issues and pull requests
+//!
+//! `issue watch` and `pull-request watch` poll the timeline of an item and
+//! print its new events as they happen: comments, reviews, closes, reopens,
+//! merges and the other events of the timeline. Every poll sends the ETag of
+//! the last answer, so polling an unchanged item does not count against the
+//! rate limit.
+
+use super::output::OutputFormat;
+use anyhow::Result;
+use clap::Args;
+use github_edit::github::GitHubClient;
+use github_edit::tools::functions::issue;
+use github_edit::types::conditional::Conditional;
+use github_edit::types::issue::IssueNumber;
+use github_edit::types::pagination::{MAX_PER_PAGE, Pagination};
+use github_edit::types::project::ProjectOriginalResource;
+use github_edit::types::repository::RepositoryId;
+use github_edit::types::timeline::{IssueTimelineEvent, TimelineEventKind};
+use std::time::Duration;
+
+/// Options of the watch commands
+#[derive(Args, Debug, Clone)]
+pub struct WatchArgs {
+    /// Seconds to wait between polls
+    #[arg(long, value_name = "SECONDS", default_value_t = 30, value_parser = clap::value_parser!(u64).range(1..))]
+    pub interval: u64,
+    /// Stop watching after this many seconds
+    #[arg(long, value_name = "SECONDS")]
+    pub timeout: Option<u64>,
+    /// Stop watching once the item is closed or merged
+    #[arg(long)]
+    pub until_closed: bool,
+    /// Print the events already on the timeline before the new ones
+    #[arg(long)]
+    pub include_existing: bool,
+}
+
+/// Watch the issue or pull request at `url`, printing its new timeline events
+///
+/// Runs until interrupted, or until the timeout or closing selected in `args`.
+pub async fn watch(
+    github_client: &GitHubClient,
+    url: &str,
+    args: &WatchArgs,
+    output: OutputFormat,
+) -> Result<()> {
+    let item = ProjectOriginalResource::parse_url(url).map_err(|e| anyhow::anyhow!(e))?;
+    let (repo_id, number) = item
+        .repository_and_number()
+        .ok_or_else(|| anyhow::anyhow!("Invalid issue or pull request URL: {}", url))?;
+    let watcher = TimelineWatcher::new(github_client, repo_id.clone(), IssueNumber::new(number));
+
+    let watching = watch_timeline(watcher, args, output);
+    match args.timeout {
+        Some(timeout) => tokio::time::timeout(Duration::from_secs(timeout), watching)
+            .await
+            .unwrap_or(Ok(())),
+        None => watching.await,
+    }
+}
+
+async fn watch_timeline(
+    mut watcher: TimelineWatcher<'_>,
+    args: &WatchArgs,
+    output: OutputFormat,
+) -> Result<()> {
+    let mut closed = false;
+    let existing = watcher.poll().await?;
+    for event in &existing {
+        closed = is_closed_after(closed, event);
+        if args.include_existing {
+            print_event(event, output)?;
+        }
+    }
+
+    loop {
+        if args.until_closed && closed {
+            return Ok(());
+        }
+        tokio::time::sleep(Duration::from_secs(args.interval)).await;
+        for event in watcher.poll().await? {
+            closed = is_closed_after(closed, &event);
+            print_event(&event, output)?;
+        }
+    }
+}
+
+/// Returns whether the item is closed after `event`
+fn is_closed_after(closed: bool, event: &IssueTimelineEvent) -> bool {
+    match event.kind {
+        TimelineEventKind::Closed { .. } | TimelineEventKind::Merged { .. } => true,
+        TimelineEventKind::Reopened => false,
+        _ => closed,
+    }
+}
+
+/// Print a timeline event as a text line with the body of comments and
+/// reviews indented below it, or as one line of JSON
+fn print_event(event: &IssueTimelineEvent, output: OutputFormat) -> Result<()> {
+    match output {
+        OutputFormat::Text => {
+            println!(
+                "{}\t{}\t{}",
+                event
+                    .created_at
+                    .map(|created_at| created_at.to_rfc3339())
+                    .unwrap_or_else(|| "-".to_string()),
+                event.actor.as_deref().unwrap_or("-"),
+                event.kind
+            );
+            if let TimelineEventKind::Commented { body, .. }
+            | TimelineEventKind::Reviewed { body, .. } = &event.kind
+            {
+                for line in body.lines() {
+                    println!("    {}", line);
+                }
+            }
+        }
+        OutputFormat::Json => println!("{}", serde_json::to_string(event)?),
+    }
+    Ok(())
+}
+
+/// Position of a watch on the timeline of an item
+///
+/// Only the last page of the timeline is polled, as new events are appended
+/// to it; when it fills up, the watch moves on to the following page.
+struct TimelineWatcher<'a> {
+    github_client: &'a GitHubClient,
+    repository_id: RepositoryId,
+    issue_number: IssueNumber,
+    /// Last page of the timeline
+    pagination: Pagination,
+    /// Number of events of the last page already returned
+    seen: usize,
+    /// ETag of the last page
+    etag: Option<String>,
+}
+
+impl<'a> TimelineWatcher<'a> {
+    fn new(
+        github_client: &'a GitHubClient,
+        repository_id: RepositoryId,
+        issue_number: IssueNumber,
+    ) -> Self {
+        Self {
+            github_client,
+            repository_id,
+            issue_number,
+            pagination: Pagination::new(None, Some(MAX_PER_PAGE)),
+            seen: 0,
+            etag: None,
+        }
+    }
+
+    /// Returns the events added to the timeline since the last poll, or all
+    /// events on the first poll
+    async fn poll(&mut self) -> Result<Vec<IssueTimelineEvent>> {
+        let mut events = Vec::new();
+        loop {
+            let page = match issue::get_timeline_if_changed(
+                self.github_client,
+                &self.repository_id,
+                self.issue_number,
+                self.pagination,
+                self.etag.as_deref(),
+            )
+            .await?
+            {
+                Conditional::Modified { value, etag } => {
+                    self.etag = etag;
+                    value
+                }
+                Conditional::NotModified => return Ok(events),
+            };
+
+            let count = page.items.len();
+            events.extend(page.items.into_iter().skip(self.seen));
+            if !page.has_next_page {
+                self.seen = count;
+                return Ok(events);
+            }
+            self.pagination = self.pagination.next();
+            self.seen = 0;
+            self.etag = None;
+        }
+    }
+}
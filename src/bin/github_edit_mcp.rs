@@ -1,6 +1,11 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
+use github_edit::audit::AuditLog;
+use github_edit::auth::TokenSource;
+use github_edit::github::GitHubClient;
+use github_edit::types::webhook::WebhookEvent;
 use std::net::SocketAddr;
+use tokio::sync::broadcast;
 use tracing_subscriber::{self, layer::SubscriberExt, util::SubscriberInitExt};
 
 /// Parse timezone if provided, otherwise use local timezone
@@ -76,13 +81,28 @@ enum Commands {
         #[arg(short, long)]
         debug: bool,
 
-        /// GitHub personal access token for API authentication (overrides GITHUB_EDIT_GITHUB_TOKEN environment variable)
+        /// GitHub personal access token for API authentication (overrides the profile token, GITHUB_EDIT_GITHUB_TOKEN environment variable and the credential store)
         #[arg(short = 't', long)]
         github_token: Option<String>,
 
+        /// Configuration profile to use, selecting the GitHub host, token and defaults (overrides GITHUB_EDIT_PROFILE environment variable)
+        #[arg(short = 'p', long)]
+        profile: Option<String>,
+
         /// Timezone for datetime formatting in output - supports standard timezones (e.g., "JST", "+09:00", "America/New_York", "UTC")
         #[arg(short = 'z', long)]
         timezone: Option<String>,
+        /// Maximum characters of a tool response; longer responses are cut and continued with get_response_continuation (overrides max_chars in the [response] table of the configuration file)
+        #[arg(long)]
+        max_response_chars: Option<usize>,
+
+        /// Receive GitHub webhooks and forward their events to the MCP clients; needs the webhook secret in GITHUB_EDIT_WEBHOOK_SECRET or the [webhooks] table of the configuration file
+        #[arg(long)]
+        webhooks: bool,
+
+        /// Address the webhook listener binds to (overrides address in the [webhooks] table of the configuration file; default 0.0.0.0:8090)
+        #[arg(long, requires = "webhooks")]
+        webhook_address: Option<String>,
     },
     /// Run the server with HTTP/SSE interface for web-based access and testing
     Http {
@@ -94,13 +114,28 @@ enum Commands {
         #[arg(short, long)]
         debug: bool,
 
-        /// GitHub personal access token for API authentication (overrides GITHUB_EDIT_GITHUB_TOKEN environment variable)
+        /// GitHub personal access token for API authentication (overrides the profile token, GITHUB_EDIT_GITHUB_TOKEN environment variable and the credential store)
         #[arg(short = 't', long)]
         github_token: Option<String>,
 
+        /// Configuration profile to use, selecting the GitHub host, token and defaults (overrides GITHUB_EDIT_PROFILE environment variable)
+        #[arg(short = 'p', long)]
+        profile: Option<String>,
+
         /// Timezone for datetime formatting in output - supports standard timezones (e.g., "JST", "+09:00", "America/New_York", "UTC")
         #[arg(short = 'z', long)]
         timezone: Option<String>,
+        /// Maximum characters of a tool response; longer responses are cut and continued with get_response_continuation (overrides max_chars in the [response] table of the configuration file)
+        #[arg(long)]
+        max_response_chars: Option<usize>,
+
+        /// Receive GitHub webhooks and forward their events to the MCP clients; needs the webhook secret in GITHUB_EDIT_WEBHOOK_SECRET or the [webhooks] table of the configuration file
+        #[arg(long)]
+        webhooks: bool,
+
+        /// Address the webhook listener binds to (overrides address in the [webhooks] table of the configuration file; default 0.0.0.0:8090)
+        #[arg(long, requires = "webhooks")]
+        webhook_address: Option<String>,
     },
 }
 
@@ -117,40 +152,131 @@ async fn main() -> Result<()> {
         Commands::Stdio {
             debug: _,
             github_token,
+            profile,
             timezone,
+            max_response_chars,
+            webhooks,
+            webhook_address,
         } => {
-            // Use github_token directly or get from environment
-            let github_token =
-                github_token.or_else(|| std::env::var("GITHUB_EDIT_GITHUB_TOKEN").ok());
+            let config = github_edit::config::Config::load()?;
+            let max_response_chars = config.response.max_response_chars(max_response_chars);
+
+            // Use github_token directly or get from the profile, environment or credential store
+            let github_client = create_github_client(&config, github_token, profile)?;
 
             // Parse timezone if provided, otherwise use local timezone
             let timezone = parse_timezone_or_default(timezone);
 
-            github_edit::transport::stdio::run_stdio_server(github_token, timezone).await
+            let audit_log = AuditLog::from_config(&config.audit)?;
+            let webhook_events = start_webhooks(&config, webhooks, webhook_address).await?;
+
+            github_edit::transport::stdio::run_stdio_server(
+                github_client,
+                timezone,
+                max_response_chars,
+                audit_log,
+                webhook_events,
+            )
+            .await
         }
         Commands::Http {
             address,
             debug,
             github_token,
+            profile,
             timezone,
+            max_response_chars,
+            webhooks,
+            webhook_address,
         } => {
-            // Use github_token directly or get from environment
-            let github_token =
-                github_token.or_else(|| std::env::var("GITHUB_EDIT_GITHUB_TOKEN").ok());
+            let config = github_edit::config::Config::load()?;
+            let max_response_chars = config.response.max_response_chars(max_response_chars);
+
+            // Use github_token directly or get from the profile, environment or credential store
+            let github_client = create_github_client(&config, github_token, profile)?;
 
             // Parse timezone if provided, otherwise use local timezone
             let timezone = parse_timezone_or_default(timezone);
 
-            run_http_server(address, debug, github_token, timezone).await
+            let audit_log = AuditLog::from_config(&config.audit)?;
+            let webhook_events = start_webhooks(&config, webhooks, webhook_address).await?;
+
+            run_http_server(
+                address,
+                debug,
+                github_client,
+                timezone,
+                max_response_chars,
+                audit_log,
+                webhook_events,
+            )
+            .await
         }
     }
 }
 
+/// Select the configuration profile and create the GitHub client
+///
+/// The token given on the command line is used as is; otherwise it is
+/// resolved from the profile, the environment, GitHub Actions, the credential
+/// store selected in the configuration file or the GitHub CLI. The client uses the network settings,
+/// the retry settings and the owner tokens of the configuration file.
+fn create_github_client(
+    config: &github_edit::config::Config,
+    github_token: Option<String>,
+    profile: Option<String>,
+) -> Result<GitHubClient> {
+    let selected = config.select_profile(profile.as_deref())?;
+    let github_token = match github_token {
+        Some(token) => {
+            tracing::info!("Using the GitHub token from {}", TokenSource::CommandLine);
+            Some(token)
+        }
+        None => github_edit::auth::resolve_token(&config.auth, selected.as_ref())?
+            .map(|resolved| resolved.token),
+    };
+    let api_base_url = selected
+        .as_ref()
+        .map(|selected| selected.profile.api_base_url())
+        .unwrap_or(github_edit::github::client::DEFAULT_API_BASE_URL);
+    let owner_tokens = github_edit::auth::resolve_owner_tokens(&config.auth, selected.as_ref());
+    github_edit::github::client::configure_retries(&config.retry);
+    let github_client =
+        GitHubClient::with_network_config(github_token, None, api_base_url, &config.network)?
+            .with_owner_tokens(&owner_tokens)?;
+    match github_edit::github::etag_cache::EtagCache::from_config(&config.cache)? {
+        Some(etag_cache) => github_client.with_etag_cache(etag_cache),
+        None => Ok(github_client),
+    }
+}
+
+/// Start the webhook listener if `--webhooks` is given
+///
+/// # Returns
+/// The channel the listener sends its events to, or `None` without webhooks
+async fn start_webhooks(
+    config: &github_edit::config::Config,
+    webhooks: bool,
+    webhook_address: Option<String>,
+) -> Result<Option<broadcast::Sender<WebhookEvent>>> {
+    if !webhooks {
+        return Ok(None);
+    }
+    let secret = config.webhooks.webhook_secret()?;
+    let address = config.webhooks.listen_address(webhook_address);
+    let (events, _cancellation_token) =
+        github_edit::transport::webhook::start_webhook_listener(&address, secret).await?;
+    Ok(Some(events))
+}
+
 async fn run_http_server(
     address: String,
     debug: bool,
-    github_token: Option<String>,
+    github_client: GitHubClient,
     timezone: Option<String>,
+    max_response_chars: usize,
+    audit_log: Option<AuditLog>,
+    webhook_events: Option<broadcast::Sender<WebhookEvent>>,
 ) -> Result<()> {
     // Setup tracing
     let level = if debug { "debug" } else { "info" };
@@ -172,12 +298,20 @@ async fn run_http_server(
         addr
     );
 
-    if github_token.is_some() {
-        tracing::info!("Using GitHub token from command line arguments");
+    let token_owners = github_client.token_owners();
+    if !token_owners.is_empty() {
+        tracing::info!("Using owner tokens for: {}", token_owners.join(", "));
     }
 
     // Create app and run server using the new rust-sdk implementation
-    let app = github_edit::transport::sse_server::SseServerApp::new(addr, github_token, timezone);
+    let app = github_edit::transport::sse_server::SseServerApp::new(
+        addr,
+        github_client,
+        timezone,
+        max_response_chars,
+        audit_log,
+        webhook_events,
+    );
     app.serve().await?;
 
     Ok(())
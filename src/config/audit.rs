@@ -0,0 +1,47 @@
+//! Audit log configuration
+//!
+//! The operations of the MCP server and CLI that write to GitHub are
+//! recorded in an append-only JSON Lines file, by default `audit.jsonl` next
+//! to the configuration file. The log can be moved or turned off:
+//!
+//! ```toml
+//! [audit]
+//! enabled = true
+//! path = "/var/log/github-edit/audit.jsonl"
+//! ```
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// File name of the audit log in the configuration directory
+pub const AUDIT_LOG_FILE_NAME: &str = "audit.jsonl";
+
+/// Audit log settings of the configuration file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditConfig {
+    /// Whether operations are recorded (default: true)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Path of the audit log file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+}
+
+impl AuditConfig {
+    /// Returns the path of the audit log, or `None` if auditing is disabled
+    ///
+    /// # Errors
+    /// Returns an error if no path is configured and the platform has no
+    /// configuration directory.
+    pub fn log_path(&self) -> Result<Option<PathBuf>> {
+        if self.enabled == Some(false) {
+            return Ok(None);
+        }
+        if let Some(path) = &self.path {
+            return Ok(Some(path.clone()));
+        }
+        let config_path = super::Config::default_path()?;
+        Ok(Some(config_path.with_file_name(AUDIT_LOG_FILE_NAME)))
+    }
+}
@@ -0,0 +1,64 @@
+//! Response cache configuration
+//!
+//! With the cache enabled, GET responses of the GitHub API carrying an ETag
+//! are kept, and later requests for the same URL are sent with
+//! `If-None-Match`. GitHub answers those with `304 Not Modified` while the
+//! resource is unchanged, which does not count against the rate limit, and
+//! the kept response is used instead. Responses are kept in memory, and with
+//! `persist` also on disk, by default in the `cache` directory next to the
+//! configuration file, so that later runs can revalidate them:
+//!
+//! ```toml
+//! [cache]
+//! enabled = true
+//! persist = true
+//! path = "/home/me/.cache/github-edit"
+//! ```
+//!
+//! The files hold the bodies of the responses, including those of private
+//! repositories; they can be deleted at any time.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Directory name of the persisted cache in the configuration directory
+pub const CACHE_DIR_NAME: &str = "cache";
+
+/// Response cache settings of the configuration file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheConfig {
+    /// Whether GET responses are cached and revalidated (default: false)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Whether cached responses are also kept on disk (default: false)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub persist: Option<bool>,
+    /// Directory of the persisted cache
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+}
+
+impl CacheConfig {
+    /// Returns true if GET responses are cached
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or(false)
+    }
+
+    /// Returns the directory of the persisted cache, or `None` if cached
+    /// responses are only kept in memory
+    ///
+    /// # Errors
+    /// Returns an error if no path is configured and the platform has no
+    /// configuration directory.
+    pub fn cache_dir(&self) -> Result<Option<PathBuf>> {
+        if !self.persist.unwrap_or(false) {
+            return Ok(None);
+        }
+        if let Some(path) = &self.path {
+            return Ok(Some(path.clone()));
+        }
+        let config_path = super::Config::default_path()?;
+        Ok(Some(config_path.with_file_name(CACHE_DIR_NAME)))
+    }
+}
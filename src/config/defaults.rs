@@ -0,0 +1,26 @@
+//! CLI defaults configuration
+//!
+//! Defaults of options repeated on many CLI commands, so they can be
+//! omitted:
+//!
+//! ```toml
+//! [defaults]
+//! # Used when --repository-url is not given and no profile sets a repository
+//! repository = "owner/repo"
+//! # "text" (default) or "json"
+//! output = "json"
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// CLI defaults of the configuration file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DefaultsConfig {
+    /// Default repository as `owner/repo` or URL; the repository of the
+    /// selected profile takes precedence
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+    /// Default format of the command results, `text` or `json`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+}
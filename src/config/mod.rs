@@ -0,0 +1,295 @@
+//! User configuration
+//!
+//! This module loads and saves the user configuration file, a TOML file
+//! stored at `~/.config/github-edit/config.toml` (or the platform
+//! equivalent), or in the directory named by `GITHUB_EDIT_CONFIG_DIR`. A
+//! missing file is treated as an empty configuration.
+
+pub mod audit;
+pub mod auth;
+pub mod cache;
+pub mod defaults;
+pub mod network;
+pub mod profile;
+pub mod queue;
+pub mod response;
+pub mod retry;
+pub mod search;
+pub mod webhook;
+
+pub use audit::*;
+pub use auth::*;
+pub use cache::*;
+pub use defaults::*;
+pub use network::*;
+pub use profile::*;
+pub use queue::*;
+pub use response::*;
+pub use retry::*;
+pub use search::*;
+pub use webhook::*;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Directory name of the configuration under the platform config directory
+const CONFIG_DIR_NAME: &str = "github-edit";
+
+/// File name of the configuration file
+const CONFIG_FILE_NAME: &str = "config.toml";
+
+/// Environment variable overriding the directory of the configuration file
+pub const CONFIG_DIR_ENV_VAR: &str = "GITHUB_EDIT_CONFIG_DIR";
+
+/// Contents of the configuration file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Config {
+    /// Profile used when none is selected on the command line or in the environment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_profile: Option<String>,
+    /// Defaults of CLI options
+    #[serde(default)]
+    pub defaults: DefaultsConfig,
+    /// Authentication settings
+    #[serde(default)]
+    pub auth: AuthConfig,
+    /// Search settings
+    #[serde(default)]
+    pub search: SearchConfig,
+    /// Proxy and TLS settings
+    #[serde(default)]
+    pub network: NetworkConfig,
+    /// Retry settings
+    #[serde(default)]
+    pub retry: RetryConfig,
+    /// Response cache settings
+    #[serde(default)]
+    pub cache: CacheConfig,
+    /// Tool response size settings
+    #[serde(default)]
+    pub response: ResponseConfig,
+    /// Audit log settings
+    #[serde(default)]
+    pub audit: AuditConfig,
+    /// Webhook listener settings
+    #[serde(default)]
+    pub webhooks: WebhookConfig,
+    /// Offline queue settings of the CLI
+    #[serde(default)]
+    pub queue: QueueConfig,
+    /// Named profiles
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+impl Config {
+    /// Returns the path of the configuration file
+    ///
+    /// # Errors
+    /// Returns an error if the platform has no configuration directory.
+    pub fn default_path() -> Result<PathBuf> {
+        if let Some(config_dir) = std::env::var_os(CONFIG_DIR_ENV_VAR) {
+            return Ok(PathBuf::from(config_dir).join(CONFIG_FILE_NAME));
+        }
+        let config_dir = dirs::config_dir()
+            .context("Could not determine the configuration directory of this platform")?;
+        Ok(config_dir.join(CONFIG_DIR_NAME).join(CONFIG_FILE_NAME))
+    }
+
+    /// Load the configuration from the default path
+    pub fn load() -> Result<Self> {
+        Self::load_from(&Self::default_path()?)
+    }
+
+    /// Load the configuration from `path`, or an empty one if it does not exist
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or is not valid TOML.
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read configuration {}", path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("Invalid configuration {}", path.display()))
+    }
+
+    /// Save the configuration to the default path
+    pub fn save(&self) -> Result<()> {
+        self.save_to(&Self::default_path()?)
+    }
+
+    /// Save the configuration to `path`, creating its directory if needed
+    ///
+    /// # Errors
+    /// Returns an error if the file or its directory cannot be written.
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+        }
+        let content = toml::to_string_pretty(self)?;
+        std::fs::write(path, content)
+            .with_context(|| format!("Failed to write configuration {}", path.display()))
+    }
+
+    /// Select a profile by name
+    ///
+    /// The name given explicitly (e.g. by `--profile`) is used first, then the
+    /// `GITHUB_EDIT_PROFILE` environment variable and finally
+    /// `default_profile`.
+    ///
+    /// # Returns
+    /// The selected profile, or `None` if no profile is selected
+    ///
+    /// # Errors
+    /// Returns an error if the selected profile is not defined.
+    pub fn select_profile(&self, name: Option<&str>) -> Result<Option<SelectedProfile>> {
+        let name = name
+            .map(|name| name.to_string())
+            .or_else(|| std::env::var(PROFILE_ENV_VAR).ok())
+            .filter(|name| !name.is_empty())
+            .or_else(|| self.default_profile.clone());
+        let Some(name) = name else {
+            return Ok(None);
+        };
+
+        match self.profiles.get(&name) {
+            Some(profile) => Ok(Some(SelectedProfile {
+                name,
+                profile: profile.clone(),
+            })),
+            None => anyhow::bail!(
+                "Profile '{}' is not defined. Available profiles: {}",
+                name,
+                if self.profiles.is_empty() {
+                    "none".to_string()
+                } else {
+                    self.profiles.keys().cloned().collect::<Vec<_>>().join(", ")
+                }
+            ),
+        }
+    }
+    /// Get a setting by its dotted key, e.g. `defaults.repository`
+    ///
+    /// # Returns
+    /// The value of the setting, a table for keys such as `profiles.work`, or
+    /// `None` if the setting is not set
+    pub fn get_value(&self, key: &str) -> Result<Option<toml::Value>> {
+        let mut value = toml::Value::try_from(self)?;
+        for part in split_key(key)? {
+            match value.get(part) {
+                Some(child) => value = child.clone(),
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(value))
+    }
+
+    /// Set a setting by its dotted key, e.g. `retry.max_retries`
+    ///
+    /// `value` is read as a TOML boolean or number where the setting takes
+    /// one, and as a string otherwise.
+    ///
+    /// # Errors
+    /// Returns an error if the key is not a known setting or the value has
+    /// the wrong type for it
+    pub fn set_value(&mut self, key: &str, value: &str) -> Result<()> {
+        let parts = split_key(key)?;
+        let scalar = toml::from_str::<toml::Table>(&format!("value = {}", value))
+            .ok()
+            .and_then(|table| table.get("value").cloned())
+            .filter(|value| !value.is_table() && !value.is_array());
+        let candidates = scalar
+            .into_iter()
+            .chain(std::iter::once(toml::Value::String(value.to_string())));
+
+        let mut error = None;
+        for candidate in candidates {
+            let mut root = toml::Value::try_from(&*self)?;
+            insert_value(&mut root, &parts, candidate)?;
+            match root.try_into::<Config>() {
+                Ok(config) => {
+                    if config.get_value(key)?.is_none() {
+                        anyhow::bail!("Unknown configuration key '{}'", key);
+                    }
+                    *self = config;
+                    return Ok(());
+                }
+                Err(e) => error = Some(e),
+            }
+        }
+        Err(anyhow::anyhow!(
+            "Invalid value '{}' for '{}': {}",
+            value,
+            key,
+            error.map(|e| e.to_string()).unwrap_or_default()
+        ))
+    }
+
+    /// Remove a setting by its dotted key, restoring its default
+    ///
+    /// # Returns
+    /// `true` if the setting was set
+    ///
+    /// # Errors
+    /// Returns an error if the setting can not be removed, e.g. because it
+    /// is required within its table
+    pub fn unset_value(&mut self, key: &str) -> Result<bool> {
+        let parts = split_key(key)?;
+        let mut root = toml::Value::try_from(&*self)?;
+        let (last, parents) = parts.split_last().expect("keys have at least one part");
+        let mut table = &mut root;
+        for part in parents {
+            match table.get_mut(*part) {
+                Some(child) => table = child,
+                None => return Ok(false),
+            }
+        }
+        let removed = table
+            .as_table_mut()
+            .and_then(|table| table.remove(*last))
+            .is_some();
+        if removed {
+            *self = root
+                .try_into()
+                .with_context(|| format!("Failed to remove '{}'", key))?;
+        }
+        Ok(removed)
+    }
+}
+
+/// Split a dotted configuration key into its parts
+fn split_key(key: &str) -> Result<Vec<&str>> {
+    let parts: Vec<&str> = key.split('.').collect();
+    if parts.iter().any(|part| part.is_empty()) {
+        anyhow::bail!("Invalid configuration key '{}'", key);
+    }
+    Ok(parts)
+}
+
+/// Insert `value` at `parts` below `root`, creating missing tables
+fn insert_value(root: &mut toml::Value, parts: &[&str], value: toml::Value) -> Result<()> {
+    let (last, parents) = parts.split_last().expect("keys have at least one part");
+    let mut table = root;
+    for part in parents {
+        table = table
+            .as_table_mut()
+            .context("Configuration key does not name a table")?
+            .entry(part.to_string())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+    }
+    table
+        .as_table_mut()
+        .with_context(|| {
+            format!(
+                "Configuration key '{}' does not name a table",
+                parents.join(".")
+            )
+        })?
+        .insert(last.to_string(), value);
+    Ok(())
+}
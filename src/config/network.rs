@@ -0,0 +1,61 @@
+//! Network configuration
+//!
+//! Proxy and TLS settings for corporate networks. Without a `proxy` the
+//! standard `HTTPS_PROXY`, `HTTP_PROXY`, `ALL_PROXY` and `NO_PROXY`
+//! environment variables are honored:
+//!
+//! ```toml
+//! [network]
+//! proxy = "http://proxy.example.com:3128"
+//! no_proxy = "localhost,.internal.example.com"
+//! # PEM file with additional root certificates, e.g. of a TLS inspecting proxy
+//! ca_bundle = "/etc/ssl/certs/corporate-ca.pem"
+//! # Seconds to wait for a connection, and for a whole request
+//! connect_timeout_secs = 10
+//! timeout_secs = 120
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Seconds to wait for a connection to GitHub unless configured otherwise
+pub const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 10;
+
+/// Network settings of the configuration file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkConfig {
+    /// URL of the proxy all requests are sent through, overriding the proxy
+    /// environment variables; credentials may be given as `user:password@`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    /// Comma separated hosts, domains and IP ranges reached without `proxy`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_proxy: Option<String>,
+    /// PEM file with root certificates trusted in addition to the built-in
+    /// and system ones
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_bundle: Option<PathBuf>,
+    /// Seconds to wait for a connection to be established
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connect_timeout_secs: Option<u64>,
+    /// Seconds a whole request may take, including reading the response;
+    /// requests are not limited by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+}
+
+impl NetworkConfig {
+    /// Returns how long to wait for a connection to be established
+    pub fn connect_timeout(&self) -> Duration {
+        Duration::from_secs(
+            self.connect_timeout_secs
+                .unwrap_or(DEFAULT_CONNECT_TIMEOUT_SECS),
+        )
+    }
+
+    /// Returns how long a whole request may take, if it is limited
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout_secs.map(Duration::from_secs)
+    }
+}
@@ -0,0 +1,51 @@
+//! Offline queue configuration
+//!
+//! With the queue enabled, CLI commands changing issues and pull requests
+//! that fail because GitHub cannot be reached are appended to a JSON Lines
+//! file, by default `queue.jsonl` next to the configuration file, and
+//! replayed later with `github-edit-cli queue flush`. The queue is off
+//! unless enabled here or with the `--queue-offline` option:
+//!
+//! ```toml
+//! [queue]
+//! enabled = true
+//! path = "/home/me/github-edit-queue.jsonl"
+//! ```
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// File name of the offline queue in the configuration directory
+pub const QUEUE_FILE_NAME: &str = "queue.jsonl";
+
+/// Offline queue settings of the configuration file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueueConfig {
+    /// Whether failed writes are queued without `--queue-offline` (default: false)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Path of the queue file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+}
+
+impl QueueConfig {
+    /// Returns true if failed writes are queued by default
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or(false)
+    }
+
+    /// Returns the path of the queue file
+    ///
+    /// # Errors
+    /// Returns an error if no path is configured and the platform has no
+    /// configuration directory.
+    pub fn queue_path(&self) -> Result<PathBuf> {
+        if let Some(path) = &self.path {
+            return Ok(path.clone());
+        }
+        let config_path = super::Config::default_path()?;
+        Ok(config_path.with_file_name(QUEUE_FILE_NAME))
+    }
+}
@@ -0,0 +1,39 @@
+//! Response configuration
+//!
+//! Limits the size of MCP tool responses for clients with small context
+//! windows. Longer responses are cut and end with a continuation token for
+//! the rest:
+//!
+//! ```toml
+//! [response]
+//! max_chars = 20000
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// Maximum characters of a tool response when none is configured
+pub const DEFAULT_MAX_RESPONSE_CHARS: usize = 50_000;
+
+/// Smallest accepted maximum, so every continuation makes progress
+pub const MIN_MAX_RESPONSE_CHARS: usize = 1_000;
+
+/// Response settings of the configuration file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponseConfig {
+    /// Maximum characters of the text of a tool response
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_chars: Option<usize>,
+}
+
+impl ResponseConfig {
+    /// Returns the maximum response size, preferring `override_chars` (e.g.
+    /// given on the command line) over the configured one
+    ///
+    /// Values below `MIN_MAX_RESPONSE_CHARS` are raised to it.
+    pub fn max_response_chars(&self, override_chars: Option<usize>) -> usize {
+        override_chars
+            .or(self.max_chars)
+            .unwrap_or(DEFAULT_MAX_RESPONSE_CHARS)
+            .max(MIN_MAX_RESPONSE_CHARS)
+    }
+}
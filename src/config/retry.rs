@@ -0,0 +1,44 @@
+//! Retry configuration
+//!
+//! Rate limited requests and other retryable failures are retried with
+//! exponential backoff, doubling the delay after every attempt:
+//!
+//! ```toml
+//! [retry]
+//! max_retries = 5
+//! initial_delay_ms = 500
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::github::client::DEFAULT_MAX_RETRY_COUNT;
+
+/// Delay before the first retry when none is configured
+pub const DEFAULT_INITIAL_RETRY_DELAY_MS: u64 = 100;
+
+/// Retry settings of the configuration file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetryConfig {
+    /// Number of retries after the first attempt (0 turns retries off)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_retries: Option<u32>,
+    /// Delay before the first retry in milliseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_delay_ms: Option<u64>,
+}
+
+impl RetryConfig {
+    /// Returns the number of retries after the first attempt
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries.unwrap_or(DEFAULT_MAX_RETRY_COUNT)
+    }
+
+    /// Returns the delay before the retry following `attempt` (starting at 0)
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let initial_delay = self
+            .initial_delay_ms
+            .unwrap_or(DEFAULT_INITIAL_RETRY_DELAY_MS);
+        Duration::from_millis(initial_delay.saturating_mul(1u64 << attempt.min(32)))
+    }
+}
@@ -0,0 +1,75 @@
+//! Search configuration
+//!
+//! Saved search queries are stored as aliases in the `[search.aliases]`
+//! table of the configuration file and referenced in queries as `@name`:
+//!
+//! ```toml
+//! [search.aliases]
+//! triage = "is:open label:needs-triage repo:owner/repo"
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Prefix marking an alias reference in a search query
+pub const SEARCH_ALIAS_PREFIX: char = '@';
+
+/// Search settings of the configuration file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchConfig {
+    /// Saved search queries by alias name
+    #[serde(default)]
+    pub aliases: BTreeMap<String, String>,
+}
+
+impl SearchConfig {
+    /// Expand the alias references in a search query
+    ///
+    /// Every whitespace-separated `@name` token naming an alias is replaced
+    /// by the saved query, so further qualifiers can be added around it
+    /// (e.g. `@triage author:octocat`). Other `@` tokens such as `@me` in
+    /// `review-requested:@me` are not at the start of a token and are kept.
+    /// Saved queries are not expanded again.
+    ///
+    /// # Errors
+    /// Returns an error message naming the first unknown alias.
+    pub fn expand_query(&self, query: &str) -> Result<String, String> {
+        query
+            .split_whitespace()
+            .map(|token| match token.strip_prefix(SEARCH_ALIAS_PREFIX) {
+                Some(name) if !name.is_empty() => {
+                    self.aliases.get(name).map(|q| q.as_str()).ok_or_else(|| {
+                        format!(
+                            "Unknown search alias '{}{}'. Add it with 'search alias add'",
+                            SEARCH_ALIAS_PREFIX, name
+                        )
+                    })
+                }
+                _ => Ok(token),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|tokens| tokens.join(" "))
+    }
+}
+
+/// Check that a name is valid for a search alias
+///
+/// Names may only contain alphanumeric characters, hyphens and underscores.
+///
+/// # Errors
+/// Returns an error message describing why the name is invalid.
+pub fn validate_search_alias_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Alias name must not be empty".to_string());
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(format!(
+            "Invalid alias name '{}'. Only alphanumeric characters, hyphens and underscores are allowed",
+            name
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,63 @@
+//! Webhook configuration
+//!
+//! The MCP server started with `--webhooks` receives GitHub webhook
+//! deliveries on a listener of its own. Deliveries are accepted only if they
+//! are signed with the webhook secret, taken from the
+//! `GITHUB_EDIT_WEBHOOK_SECRET` environment variable or the configuration
+//! file:
+//!
+//! ```toml
+//! [webhooks]
+//! address = "0.0.0.0:8090"
+//! secret = "xxxxxxxxxxxxxxxxxxxxxxxx"
+//! ```
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// Environment variable holding the webhook secret
+pub const WEBHOOK_SECRET_ENV_VAR: &str = "GITHUB_EDIT_WEBHOOK_SECRET";
+
+/// Address of the webhook listener when none is configured
+pub const DEFAULT_WEBHOOK_ADDRESS: &str = "0.0.0.0:8090";
+
+/// Webhook settings of the configuration file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    /// Address the webhook listener binds to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    /// Secret the webhook deliveries are signed with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+}
+
+impl WebhookConfig {
+    /// Returns the address of the webhook listener, preferring
+    /// `override_address` (e.g. given on the command line) over the
+    /// configured one
+    pub fn listen_address(&self, override_address: Option<String>) -> String {
+        override_address
+            .or_else(|| self.address.clone())
+            .unwrap_or_else(|| DEFAULT_WEBHOOK_ADDRESS.to_string())
+    }
+
+    /// Returns the webhook secret, preferring the environment variable over
+    /// the configuration file
+    ///
+    /// # Errors
+    /// Returns an error if neither sets a non-empty secret, as unsigned
+    /// deliveries are never accepted.
+    pub fn webhook_secret(&self) -> Result<String> {
+        std::env::var(WEBHOOK_SECRET_ENV_VAR)
+            .ok()
+            .or_else(|| self.secret.clone())
+            .filter(|secret| !secret.is_empty())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Webhooks need a secret: set {} or secret in the [webhooks] section of the configuration file",
+                    WEBHOOK_SECRET_ENV_VAR
+                )
+            })
+    }
+}
@@ -0,0 +1,65 @@
+//! Recording of the write requests of an operation
+//!
+//! Within `with_audit`, every request that changes something on GitHub is
+//! recorded once it has been sent: REST requests other than GET and HEAD,
+//! and GraphQL mutations, with the status of their response. Writes not sent
+//! because of a dry run are not recorded. The MCP server and CLI write the
+//! recorded requests to the audit log.
+
+use crate::github::dry_run;
+use crate::types::audit::AuditRequest;
+
+use std::cell::RefCell;
+use std::future::Future;
+
+tokio::task_local! {
+    static SENT_WRITES: RefCell<Vec<AuditRequest>>;
+}
+
+/// Run `future`, recording the write requests it sends
+///
+/// # Returns
+/// The output of `future` and the write requests it sent
+pub async fn with_audit<F: Future>(future: F) -> (F::Output, Vec<AuditRequest>) {
+    SENT_WRITES
+        .scope(RefCell::new(Vec::new()), async move {
+            let output = future.await;
+            let requests = SENT_WRITES.with(|requests| requests.take());
+            (output, requests)
+        })
+        .await
+}
+
+/// Record a sent request if it is a write and the operation is audited
+pub fn record_request(method: &str, url: &str, body: &[u8], outcome: Result<u16, &str>) {
+    let _ = SENT_WRITES.try_with(|requests| {
+        if !dry_run::is_write(method, url, body) {
+            return;
+        }
+        let (status, error) = match outcome {
+            Ok(status) => (Some(status), None),
+            Err(error) => (None, Some(error.to_string())),
+        };
+        requests.borrow_mut().push(AuditRequest {
+            method: method.to_uppercase(),
+            url: url.to_string(),
+            mutation: graphql_mutation_name(body),
+            status,
+            error,
+        });
+    });
+}
+
+/// Returns the name of the first field of a GraphQL mutation, e.g.
+/// `addProjectV2ItemById`
+fn graphql_mutation_name(body: &[u8]) -> Option<String> {
+    let body: serde_json::Value = serde_json::from_slice(body).ok()?;
+    let query = body.get("query")?.as_str()?;
+    let mutation = &query[query.find("mutation")?..];
+    let selection = mutation[mutation.find('{')? + 1..].trim_start();
+    let name: String = selection
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+    (!name.is_empty()).then_some(name)
+}
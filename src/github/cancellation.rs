@@ -0,0 +1,44 @@
+//! Cooperative cancellation of GitHub operations
+//!
+//! The MCP server runs each tool call within `with_cancellation`, scoping the
+//! cancellation token of the request to it. Once the client cancels the call,
+//! or the session ends, `retry_with_backoff` stops before sending the next
+//! request and batch loops stop starting new items, so an aborted agent run
+//! does not keep writing to GitHub. A request already sent is not aborted.
+//!
+//! Outside of a scope, e.g. in the CLI, operations are never cancelled.
+
+use std::future::Future;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+tokio::task_local! {
+    static CANCELLATION_TOKEN: CancellationToken;
+}
+
+/// Run `future` with `token` as the cancellation token of its operations
+pub async fn with_cancellation<F: Future>(token: CancellationToken, future: F) -> F::Output {
+    CANCELLATION_TOKEN.scope(token, future).await
+}
+
+/// Returns true if the operation running on this task was cancelled
+pub fn is_cancelled() -> bool {
+    CANCELLATION_TOKEN
+        .try_with(|token| token.is_cancelled())
+        .unwrap_or(false)
+}
+
+/// Sleep for `delay`, waking up early if the operation is cancelled
+///
+/// # Returns
+/// `false` if the operation was cancelled before the delay elapsed
+pub async fn sleep_unless_cancelled(delay: Duration) -> bool {
+    let Ok(token) = CANCELLATION_TOKEN.try_with(|token| token.clone()) else {
+        tokio::time::sleep(delay).await;
+        return true;
+    };
+    tokio::select! {
+        _ = token.cancelled() => false,
+        _ = tokio::time::sleep(delay) => true,
+    }
+}
@@ -1,34 +1,610 @@
-use crate::github::error::ApiRetryableError;
+use crate::config::{NetworkConfig, RetryConfig};
+use crate::github::audit;
+use crate::github::cancellation;
+use crate::github::dry_run;
+use crate::github::error::{ApiRetryableError, ErrorKind, GitHubError};
+use crate::github::etag_cache::{CachedResponse, EtagCache};
+use crate::github::graphql::{GraphQlRequest, GraphQlResponse};
+use crate::github::http_client::{
+    ReqwestService, USER_AGENT_VALUE, bearer_header, build_http_client,
+};
+use crate::github::token_router::TokenRouter;
+use crate::github::trace;
+use crate::types::conditional::Conditional;
+use crate::types::trace::TraceLevel;
 
-use anyhow::Result;
-use octocrab::Octocrab;
-use tokio::time::Duration;
-use tokio::time::sleep;
+use anyhow::{Context, Result};
+use http::{HeaderName, HeaderValue, Uri, header::USER_AGENT};
+use octocrab::service::middleware::{
+    auth_header::AuthHeaderLayer, base_uri::BaseUriLayer, extra_headers::ExtraHeadersLayer,
+};
+use octocrab::{AuthState, Octocrab, OctocrabBuilder};
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+use std::sync::{Arc, OnceLock};
+use tokio::time::{Duration, Instant};
+use tower::Layer;
 
 /// Default maximum number of retry attempts for API operations
 pub const DEFAULT_MAX_RETRY_COUNT: u32 = 15;
 
+/// Retry settings of this process, from the configuration file
+static RETRY_CONFIG: OnceLock<RetryConfig> = OnceLock::new();
+
+/// Base URL of the REST API of github.com
+pub const DEFAULT_API_BASE_URL: &str = "https://api.github.com";
+
+/// Media type requested by the direct REST requests
+const REST_ACCEPT_VALUE: &str = "application/vnd.github.v3+json";
+
+/// Base URL of release asset uploads, which octocrab authenticates like the API
+const UPLOAD_BASE_URL: &str = "https://uploads.github.com";
+
 #[derive(Clone)]
 pub struct GitHubClient {
     pub(crate) client: octocrab::Octocrab,
+    /// Client rooted at the GraphQL endpoint, which GitHub Enterprise Server
+    /// serves outside the REST API path
+    pub(crate) graphql_client: octocrab::Octocrab,
+    /// Client of the direct REST requests, which octocrab also sends its
+    /// requests with
+    pub(crate) http_client: reqwest::Client,
     pub(crate) token: Option<String>,
+    /// Tokens used instead of `token` for the requests of specific owners
+    pub(crate) token_router: Arc<TokenRouter>,
+    /// Responses the GET requests are revalidated against
+    pub(crate) etag_cache: Option<Arc<EtagCache>>,
+    /// Base URL of the REST API without a trailing slash
+    pub(crate) api_base_url: String,
 }
 
 impl GitHubClient {
-    pub fn new(token: Option<String>, _timeout: Option<Duration>) -> Result<Self> {
-        let mut builder = Octocrab::builder();
+    pub fn new(token: Option<String>, timeout: Option<Duration>) -> Result<Self> {
+        Self::with_api_base_url(token, timeout, DEFAULT_API_BASE_URL)
+    }
+
+    /// Create a client for the REST API at `api_base_url`
+    ///
+    /// Use `https://HOSTNAME/api/v3` for GitHub Enterprise Server; the GraphQL
+    /// endpoint is derived from it.
+    ///
+    /// # Errors
+    /// Returns an error if the URL is not a valid http(s) URL.
+    pub fn with_api_base_url(
+        token: Option<String>,
+        timeout: Option<Duration>,
+        api_base_url: &str,
+    ) -> Result<Self> {
+        Self::with_network_config(token, timeout, api_base_url, &NetworkConfig::default())
+    }
 
-        if let Some(ref token_str) = token {
-            builder = builder.personal_token(token_str.clone());
+    /// Create a client for the REST API at `api_base_url` using the proxy,
+    /// TLS and timeout settings of `network`
+    ///
+    /// `timeout`, if given, replaces the request timeout of `network`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The URL is not a valid http(s) URL
+    /// - The CA bundle cannot be read or the proxy URL is invalid
+    pub fn with_network_config(
+        token: Option<String>,
+        timeout: Option<Duration>,
+        api_base_url: &str,
+        network: &NetworkConfig,
+    ) -> Result<Self> {
+        let api_base_url = api_base_url.trim_end_matches('/').to_string();
+        let parsed = url::Url::parse(&api_base_url)
+            .map_err(|e| anyhow::anyhow!("Invalid API base URL '{}': {}", api_base_url, e))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            anyhow::bail!(
+                "Invalid API base URL '{}': must be an http or https URL",
+                api_base_url
+            );
         }
 
-        let client = builder.build()?;
-        Ok(GitHubClient { client, token })
+        let http_client = match timeout {
+            Some(timeout) => build_http_client(&NetworkConfig {
+                timeout_secs: Some(timeout.as_secs().max(1)),
+                ..network.clone()
+            })?,
+            None => build_http_client(network)?,
+        };
+        Self::from_parts(
+            token,
+            api_base_url,
+            http_client,
+            Arc::new(TokenRouter::default()),
+            None,
+        )
+    }
+
+    /// Use the given tokens instead of the client's token for the
+    /// repositories and organizations of their owners
+    ///
+    /// Owners are matched case-insensitively. GraphQL mutations addressing
+    /// objects by node ID use the token of the owner of those nodes, looked
+    /// up with the client's token. Requests whose owner can't be found keep
+    /// using the client's token.
+    ///
+    /// # Errors
+    /// Returns an error if a token contains characters invalid in a header.
+    pub fn with_owner_tokens(self, owner_tokens: &BTreeMap<String, String>) -> Result<Self> {
+        let token_router = TokenRouter::new(owner_tokens, &self.api_base_url);
+        Self::from_parts(
+            self.token,
+            self.api_base_url,
+            self.http_client,
+            Arc::new(token_router),
+            self.etag_cache,
+        )
+    }
+
+    /// Revalidate the GET requests of octocrab and the direct REST requests
+    /// against the responses kept in `etag_cache`
+    ///
+    /// Unchanged resources are then answered with `304 Not Modified`, which
+    /// does not count against the rate limit, and served from the cache.
+    ///
+    /// # Errors
+    /// Returns an error if a token contains characters invalid in a header.
+    pub fn with_etag_cache(self, etag_cache: EtagCache) -> Result<Self> {
+        Self::from_parts(
+            self.token,
+            self.api_base_url,
+            self.http_client,
+            self.token_router,
+            Some(Arc::new(etag_cache)),
+        )
+    }
+
+    /// Build the octocrab clients on top of the shared HTTP client
+    fn from_parts(
+        token: Option<String>,
+        api_base_url: String,
+        http_client: reqwest::Client,
+        token_router: Arc<TokenRouter>,
+        etag_cache: Option<Arc<EtagCache>>,
+    ) -> Result<Self> {
+        let auth_header = token.as_deref().map(bearer_header).transpose()?;
+        for (owner, owner_token) in token_router.iter() {
+            bearer_header(owner_token)
+                .with_context(|| format!("Invalid token for owner '{}'", owner))?;
+        }
+        let headers: Arc<Vec<(HeaderName, HeaderValue)>> = Arc::new(vec![(
+            USER_AGENT,
+            HeaderValue::from_static(USER_AGENT_VALUE),
+        )]);
+
+        // The same layers as octocrab's default client, on top of the shared
+        // HTTP client instead of octocrab's own connector
+        let build = |base_uri: &str| -> Result<Octocrab> {
+            let base_uri: Uri = base_uri.parse()?;
+            let service = ExtraHeadersLayer::new(headers.clone()).layer(ReqwestService::new(
+                http_client.clone(),
+                token_router.clone(),
+                etag_cache.clone(),
+            ));
+            let service = BaseUriLayer::new(base_uri.clone()).layer(service);
+            let service = AuthHeaderLayer::new(
+                auth_header.clone(),
+                base_uri,
+                Uri::from_static(UPLOAD_BASE_URL),
+            )
+            .layer(service);
+            Ok(OctocrabBuilder::new_empty()
+                .with_service(service)
+                .with_auth(AuthState::None)
+                .build()?)
+        };
+
+        let client = build(&api_base_url)?;
+        let graphql_client = build(graphql_base_url(&api_base_url))?;
+        Ok(GitHubClient {
+            client,
+            graphql_client,
+            http_client,
+            token,
+            token_router,
+            etag_cache,
+            api_base_url,
+        })
+    }
+
+    /// Returns the owners with a token of their own
+    pub fn token_owners(&self) -> Vec<&str> {
+        self.token_router.owners()
+    }
+
+    /// Returns the token used for the repositories of `owner`, or the
+    /// client's token without an owner
+    pub(crate) fn token_for_owner(&self, owner: Option<&str>) -> Option<&str> {
+        owner
+            .and_then(|owner| self.token_router.token_for_owner(owner))
+            .or(self.token.as_deref())
+    }
+
+    /// Returns the token for a REST request to `url`
+    ///
+    /// This is the token of the owner the URL addresses if it has one, and
+    /// the client's token otherwise.
+    pub(crate) fn token_for_url(&self, url: &str) -> Option<&str> {
+        self.token_router
+            .token_for_request(url, &[])
+            .or(self.token.as_deref())
+    }
+
+    /// Returns the base URL of the REST API
+    pub fn api_base_url(&self) -> &str {
+        &self.api_base_url
     }
 
     pub fn octocrab(&self) -> &Octocrab {
         &self.client
     }
+
+    /// Execute a GraphQL query or mutation with variables
+    ///
+    /// Sends the query together with a `variables` object so that user supplied
+    /// values never need to be interpolated into the query text.
+    ///
+    /// # Arguments
+    /// * `query` - The GraphQL query or mutation document
+    /// * `variables` - JSON object holding the variables referenced by the query
+    ///
+    /// # Returns
+    /// The `data` object of the GraphQL response, read as `T`; queries whose
+    /// data is not needed read it as `serde::de::IgnoredAny`
+    ///
+    /// # Errors
+    /// Returns the errors of `GraphQlResponse::into_data` if the response holds
+    /// GraphQL errors or data not matching `T`, and the classified transport
+    /// error if the request itself fails.
+    pub(crate) async fn execute_graphql<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+    ) -> std::result::Result<T, ApiRetryableError> {
+        self.graphql_client
+            .graphql::<GraphQlResponse>(&GraphQlRequest { query, variables })
+            .await
+            .map_err(ApiRetryableError::from_octocrab_error)?
+            .into_data()
+    }
+
+    /// Send a REST request to the GitHub API using a full URL
+    ///
+    /// Direct requests are used where octocrab's relative path handling is
+    /// unreliable or the endpoint is not covered by octocrab. Non-success
+    /// responses are classified into retryable, rate limit, and non-retryable
+    /// errors.
+    ///
+    /// # Arguments
+    /// * `method` - The HTTP method
+    /// * `url` - Full API URL below the configured base URL (e.g. `https://api.github.com/repos/owner/repo/...`)
+    /// * `body` - Optional JSON request body
+    ///
+    /// # Returns
+    /// The successful HTTP response
+    ///
+    /// # Errors
+    /// Returns `ApiRetryableError::Rejected` with the category of the status
+    /// if no token is configured or the API rejects the request, `RateLimit`
+    /// for 429 responses, and `Retryable` for server and network errors.
+    pub(crate) async fn send_rest_request(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        body: Option<&serde_json::Value>,
+    ) -> std::result::Result<reqwest::Response, ApiRetryableError> {
+        let token = self.token_for_url(url).ok_or_else(|| {
+            ApiRetryableError::Rejected(ErrorKind::Auth, "GitHub token not configured".to_string())
+        })?;
+        self.send_rest_request_with_token(method, url, body, token)
+            .await
+    }
+
+    /// Send a REST request to the GitHub API with the given token
+    ///
+    /// Like `send_rest_request`, for endpoints that do not name the owner
+    /// whose token should be used, such as `/rate_limit`.
+    pub(crate) async fn send_rest_request_with_token(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        body: Option<&serde_json::Value>,
+        token: &str,
+    ) -> std::result::Result<reqwest::Response, ApiRetryableError> {
+        self.send_request(method, url, body, token, None).await
+    }
+
+    /// Send a conditional GET request to the GitHub API
+    ///
+    /// With an ETag, GitHub answers `304 Not Modified` if the resource still
+    /// has it, without counting the request against the rate limit.
+    ///
+    /// # Returns
+    /// The successful HTTP response with the ETag of the resource, or
+    /// `Conditional::NotModified`
+    pub(crate) async fn send_conditional_request(
+        &self,
+        url: &str,
+        etag: Option<&str>,
+    ) -> std::result::Result<Conditional<reqwest::Response>, ApiRetryableError> {
+        let token = self.token_for_url(url).ok_or_else(|| {
+            ApiRetryableError::Rejected(ErrorKind::Auth, "GitHub token not configured".to_string())
+        })?;
+        let response = self
+            .send_request(reqwest::Method::GET, url, None, token, etag)
+            .await?;
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            return Ok(Conditional::NotModified);
+        }
+
+        let etag = response
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string());
+        Ok(Conditional::Modified {
+            value: response,
+            etag,
+        })
+    }
+
+    /// Send a request, accepting `304 Not Modified` as an answer to `etag`
+    async fn send_request(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        body: Option<&serde_json::Value>,
+        token: &str,
+        etag: Option<&str>,
+    ) -> std::result::Result<reqwest::Response, ApiRetryableError> {
+        if dry_run::intercept_json(method.as_str(), url, body) {
+            return Err(ApiRetryableError::NonRetryable(
+                dry_run::DRY_RUN_MESSAGE.to_string(),
+            ));
+        }
+
+        let method_name = method.to_string();
+        let authorization = format!("token {}", token);
+
+        // GET requests whose caller does not manage the ETag itself are
+        // revalidated against the cached response
+        let cache = self
+            .etag_cache
+            .as_ref()
+            .filter(|_| method == reqwest::Method::GET && etag.is_none())
+            .map(|cache| {
+                let key = EtagCache::key(
+                    url,
+                    Some(REST_ACCEPT_VALUE.as_bytes()),
+                    Some(authorization.as_bytes()),
+                );
+                let cached = cache.get(&key);
+                (cache, key, cached)
+            });
+
+        let mut request = self
+            .http_client
+            .request(method, url)
+            .header("Authorization", authorization)
+            .header("Accept", REST_ACCEPT_VALUE);
+
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+        if let Some(etag) = etag.or_else(|| {
+            cache
+                .as_ref()
+                .and_then(|(_, _, cached)| cached.as_ref())
+                .map(|cached| cached.etag.as_str())
+        }) {
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+        }
+
+        let response = send_reported(request, &method_name, url, body).await;
+        let response = response
+            .map_err(|e| ApiRetryableError::Retryable(format!("HTTP request failed: {}", e)))?;
+
+        if let Some((_, _, Some(cached))) = &cache {
+            if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+                tracing::debug!("Serving {} from the ETag cache", url);
+                return rebuild_response(200, &cached.headers, cached.body.clone().into_bytes());
+            }
+        }
+
+        let not_modified = etag.is_some() && response.status() == reqwest::StatusCode::NOT_MODIFIED;
+        if !response.status().is_success() && !not_modified {
+            let status = response.status();
+            // Fine-grained and GitHub App tokens are told which permissions
+            // the endpoint accepts when they lack them
+            let accepted_permissions = response
+                .headers()
+                .get("x-accepted-github-permissions")
+                .and_then(|value| value.to_str().ok())
+                .filter(|value| !value.is_empty())
+                .map(|value| value.to_string());
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            let mut error_msg = format!("GitHub API error {}: {}", status, error_text);
+            if let Some(permissions) = accepted_permissions {
+                if status == reqwest::StatusCode::FORBIDDEN
+                    || status == reqwest::StatusCode::NOT_FOUND
+                {
+                    error_msg.push_str(&format!(
+                        " (the token needs one of these permissions: {})",
+                        permissions
+                    ));
+                }
+            }
+            return Err(ApiRetryableError::from_status(status.as_u16(), error_msg));
+        }
+
+        match cache {
+            Some((cache, key, _)) => store_response(cache, key, response).await,
+            None => Ok(response),
+        }
+    }
+}
+
+/// Keep a successful response with an ETag in `cache`
+///
+/// The body is read to be kept, so the response is returned rebuilt from it.
+async fn store_response(
+    cache: &EtagCache,
+    key: String,
+    response: reqwest::Response,
+) -> std::result::Result<reqwest::Response, ApiRetryableError> {
+    let etag = response
+        .headers()
+        .get(reqwest::header::ETAG)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string());
+    let etag = match etag {
+        Some(etag) if response.status() == reqwest::StatusCode::OK => etag,
+        _ => return Ok(response),
+    };
+
+    let headers: Vec<(String, String)> = response
+        .headers()
+        .iter()
+        .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+        .collect();
+    let body = response
+        .bytes()
+        .await
+        .map_err(|e| ApiRetryableError::Retryable(format!("HTTP request failed: {}", e)))?;
+    if let Ok(text) = std::str::from_utf8(&body) {
+        cache.store(CachedResponse {
+            key,
+            etag,
+            headers: headers.clone(),
+            body: text.to_string(),
+        });
+    }
+    rebuild_response(200, &headers, body.to_vec())
+}
+
+/// Build a `reqwest::Response` from a status, headers and body read before
+fn rebuild_response(
+    status: u16,
+    headers: &[(String, String)],
+    body: Vec<u8>,
+) -> std::result::Result<reqwest::Response, ApiRetryableError> {
+    let mut builder = http02::Response::builder().status(status);
+    for (name, value) in headers {
+        builder = builder.header(name.as_str(), value.as_str());
+    }
+    builder
+        .body(body)
+        .map(reqwest::Response::from)
+        .map_err(|e| ApiRetryableError::NonRetryable(format!("Invalid cached response: {}", e)))
+}
+
+/// Returns the URL the `/graphql` endpoint is resolved against
+///
+/// github.com serves GraphQL at `https://api.github.com/graphql`, while
+/// GitHub Enterprise Server serves it at `/api/graphql` next to the REST API
+/// at `/api/v3`.
+pub fn graphql_base_url(api_base_url: &str) -> &str {
+    let api_base_url = api_base_url.trim_end_matches('/');
+    api_base_url.strip_suffix("/v3").unwrap_or(api_base_url)
+}
+
+/// Report a sent GitHub request to the trace and audit of the operation
+///
+/// # Arguments
+/// * `outcome` - HTTP status of the response, or the error that kept the
+///   request from being answered
+pub(crate) fn report_request(method: &str, url: &str, body: &[u8], outcome: Result<u16, &str>) {
+    trace::emit_request(method, url, outcome);
+    audit::record_request(method, url, body, outcome);
+}
+
+/// Send a request with a JSON body with reqwest, logging it and reporting it
+/// like `report_request`
+pub(crate) async fn send_reported(
+    request: reqwest::RequestBuilder,
+    method: &str,
+    url: &str,
+    body: Option<&serde_json::Value>,
+) -> reqwest::Result<reqwest::Response> {
+    let started = Instant::now();
+    let response = request.send().await;
+    log_api_call(method, url, started.elapsed(), response.as_ref());
+
+    let body = body
+        .map(|body| serde_json::to_vec(body).unwrap_or_default())
+        .unwrap_or_default();
+    match &response {
+        Ok(response) => report_request(method, url, &body, Ok(response.status().as_u16())),
+        Err(e) => report_request(
+            method,
+            url,
+            &body,
+            Err(&format!("HTTP request failed: {}", e)),
+        ),
+    }
+    response
+}
+
+/// Log a GitHub request at debug level, with its duration and the rate limit
+/// GitHub reported in the response
+///
+/// The rate limit fields are left out when the response has no rate limit
+/// headers, e.g. for failed requests.
+pub(crate) fn log_api_call(
+    method: &str,
+    url: &str,
+    elapsed: Duration,
+    response: std::result::Result<&reqwest::Response, &reqwest::Error>,
+) {
+    let duration_ms = elapsed.as_millis() as u64;
+    match response {
+        Ok(response) => {
+            let header = |name: &str| {
+                response
+                    .headers()
+                    .get(name)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.parse::<u64>().ok())
+            };
+            tracing::debug!(
+                method,
+                url,
+                status = response.status().as_u16(),
+                duration_ms,
+                rate_limit_limit = header("x-ratelimit-limit"),
+                rate_limit_remaining = header("x-ratelimit-remaining"),
+                rate_limit_reset = header("x-ratelimit-reset"),
+                "GitHub API call"
+            );
+        }
+        Err(error) => {
+            tracing::debug!(method, url, duration_ms, error = %error, "GitHub API call failed");
+        }
+    }
+}
+
+/// Returns true if the `Link` header of a paginated response points to a next page
+pub(crate) fn has_next_page(response: &reqwest::Response) -> bool {
+    response
+        .headers()
+        .get(reqwest::header::LINK)
+        .and_then(|link| link.to_str().ok())
+        .map(|link| link.split(',').any(|part| part.contains("rel=\"next\"")))
+        .unwrap_or(false)
+}
+
+/// Use `config` for the retries of all operations of this process
+///
+/// Only the first call takes effect; without one the built-in defaults apply.
+pub fn configure_retries(config: &RetryConfig) {
+    let _ = RETRY_CONFIG.set(config.clone());
 }
 
 pub(crate) async fn retry_with_backoff<F, Fut, T>(
@@ -41,9 +617,15 @@ where
     Fut: std::future::Future<Output = std::result::Result<T, ApiRetryableError>>,
 {
     let mut attempt = 0;
-    let max_retries = max_retry_count.unwrap_or(DEFAULT_MAX_RETRY_COUNT);
+    let default_retry_config = RetryConfig::default();
+    let retry_config = RETRY_CONFIG.get().unwrap_or(&default_retry_config);
+    let max_retries = max_retry_count.unwrap_or_else(|| retry_config.max_retries());
 
     loop {
+        if cancellation::is_cancelled() {
+            return Err(anyhow::anyhow!("Operation {} cancelled", operation_name));
+        }
+
         match execute_operation().await {
             Ok(result) => {
                 tracing::debug!(
@@ -51,6 +633,15 @@ where
                     operation_name,
                     attempt + 1
                 );
+                trace::emit(
+                    TraceLevel::Debug,
+                    format!(
+                        "Operation {} succeeded on attempt {}",
+                        operation_name,
+                        attempt + 1
+                    ),
+                    serde_json::json!({ "operation": operation_name, "attempt": attempt + 1 }),
+                );
                 return Ok(result);
             }
             Err(e) => {
@@ -60,20 +651,34 @@ where
                     attempt + 1,
                     e,
                 );
+                trace::emit(
+                    TraceLevel::Warning,
+                    format!(
+                        "Operation {} failed on attempt {}: {}",
+                        operation_name,
+                        attempt + 1,
+                        e
+                    ),
+                    serde_json::json!({
+                        "operation": operation_name,
+                        "attempt": attempt + 1,
+                        "error": e.to_string(),
+                    }),
+                );
 
                 // Check if this is a non-retryable error
                 match &e {
-                    ApiRetryableError::NonRetryable(_) => {
+                    ApiRetryableError::NonRetryable(_) | ApiRetryableError::Rejected(..) => {
                         tracing::debug!(
                             "Operation {} failed with non-retryable error, not retrying: {}",
                             operation_name,
                             e
                         );
-                        return Err(anyhow::anyhow!(
-                            "Operation {} failed: {}",
-                            operation_name,
-                            e
-                        ));
+                        return Err(GitHubError::new(
+                            e.kind(),
+                            format!("Operation {} failed: {}", operation_name, e),
+                        )
+                        .into());
                     }
                     ApiRetryableError::RateLimit => {
                         tracing::debug!(
@@ -90,22 +695,40 @@ where
                 }
 
                 if attempt >= max_retries {
-                    return Err(anyhow::anyhow!(
-                        "Operation {} failed after {} attempts: {}",
-                        operation_name,
-                        attempt + 1,
-                        e
-                    ));
+                    return Err(GitHubError::new(
+                        e.kind(),
+                        format!(
+                            "Operation {} failed after {} attempts: {}",
+                            operation_name,
+                            attempt + 1,
+                            e
+                        ),
+                    )
+                    .into());
                 }
 
-                let delay = Duration::from_millis(100 * (1 << attempt));
+                let delay = retry_config.delay(attempt);
                 tracing::debug!(
                     "Retrying operation {} after delay: {:?}",
                     operation_name,
                     delay
                 );
+                trace::emit(
+                    TraceLevel::Info,
+                    format!("Retrying operation {} after {:?}", operation_name, delay),
+                    serde_json::json!({
+                        "operation": operation_name,
+                        "attempt": attempt + 2,
+                        "delay_ms": delay.as_millis() as u64,
+                    }),
+                );
 
-                sleep(delay).await;
+                if !cancellation::sleep_unless_cancelled(delay).await {
+                    return Err(anyhow::anyhow!(
+                        "Operation {} cancelled while waiting to retry",
+                        operation_name
+                    ));
+                }
                 attempt += 1;
             }
         }
@@ -0,0 +1,533 @@
+use crate::github::client::{GitHubClient, has_next_page, retry_with_backoff};
+use crate::github::error::ApiRetryableError;
+use crate::types::actions::{
+    Artifact, ArtifactId, Deployment, DeploymentReviewState, PendingDeployment, WorkflowJob,
+    WorkflowRun, WorkflowRunFilter, WorkflowRunId,
+};
+use crate::types::pagination::{Page, Pagination};
+use crate::types::repository::RepositoryId;
+
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::BTreeMap;
+
+/// Response body of the workflow run list endpoints
+#[derive(Deserialize)]
+struct WorkflowRunsResponse {
+    workflow_runs: Vec<WorkflowRun>,
+}
+
+/// Response body of the workflow job list endpoint
+#[derive(Deserialize)]
+struct WorkflowJobsResponse {
+    jobs: Vec<WorkflowJob>,
+}
+
+/// Response body of the artifact list endpoints
+#[derive(Deserialize)]
+struct ArtifactsResponse {
+    artifacts: Vec<Artifact>,
+}
+
+impl GitHubClient {
+    /// Trigger a `workflow_dispatch` event for a workflow
+    ///
+    /// GitHub queues the run asynchronously and does not return it; use
+    /// `list_workflow_runs` with the `workflow_dispatch` event to find it.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `workflow` - Workflow file name (e.g. "ci.yml") or numeric workflow ID
+    /// * `git_ref` - Branch or tag to run the workflow on
+    /// * `inputs` - Input values for the workflow's `workflow_dispatch` inputs
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The workflow does not exist or has no `workflow_dispatch` trigger
+    /// - An input is not defined by the workflow or a required input is missing
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn dispatch_workflow(
+        &self,
+        repository_id: &RepositoryId,
+        workflow: &str,
+        git_ref: &str,
+        inputs: &BTreeMap<String, String>,
+    ) -> Result<()> {
+        let operation_name = "dispatch_workflow";
+
+        let mut request_body = json!({ "ref": git_ref });
+        if !inputs.is_empty() {
+            request_body["inputs"] = json!(inputs);
+        }
+        let url = self.actions_url(
+            repository_id,
+            &format!("/workflows/{}/dispatches", urlencoding::encode(workflow)),
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            self.send_rest_request(reqwest::Method::POST, &url, Some(&request_body))
+                .await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// List workflow runs of a repository, most recent first
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `filter` - Workflow, branch, event and status to restrict the runs to
+    /// * `pagination` - The page of runs to fetch
+    ///
+    /// # Returns
+    /// A page of `WorkflowRun`s
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or workflow does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_workflow_runs(
+        &self,
+        repository_id: &RepositoryId,
+        filter: &WorkflowRunFilter,
+        pagination: Pagination,
+    ) -> Result<Page<WorkflowRun>> {
+        let operation_name = "list_workflow_runs";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.list_workflow_runs_impl(repository_id, filter, pagination)
+                .await
+        })
+        .await
+    }
+
+    async fn list_workflow_runs_impl(
+        &self,
+        repository_id: &RepositoryId,
+        filter: &WorkflowRunFilter,
+        pagination: Pagination,
+    ) -> std::result::Result<Page<WorkflowRun>, ApiRetryableError> {
+        let runs_path = match &filter.workflow {
+            Some(workflow) => format!("/workflows/{}/runs", urlencoding::encode(workflow)),
+            None => "/runs".to_string(),
+        };
+        let url = format!(
+            "{}?{}",
+            self.actions_url(repository_id, &runs_path),
+            filter.to_query(pagination)
+        );
+
+        let response = self
+            .send_rest_request(reqwest::Method::GET, &url, None)
+            .await?;
+        let has_next = has_next_page(&response);
+
+        let runs: WorkflowRunsResponse = response.json().await.map_err(|e| {
+            ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+        })?;
+
+        Ok(Page::new(runs.workflow_runs, pagination, has_next))
+    }
+
+    /// Cancel a queued or in-progress workflow run
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `run_id` - The workflow run to cancel
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The run does not exist or has already completed
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn cancel_workflow_run(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: WorkflowRunId,
+    ) -> Result<()> {
+        self.post_workflow_run_action("cancel_workflow_run", repository_id, run_id, "cancel")
+            .await
+    }
+
+    /// Re-run all jobs of a workflow run
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `run_id` - The workflow run to re-run
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The run does not exist or is still in progress
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn rerun_workflow_run(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: WorkflowRunId,
+    ) -> Result<()> {
+        self.post_workflow_run_action("rerun_workflow_run", repository_id, run_id, "rerun")
+            .await
+    }
+
+    /// Approve a workflow run that waits for approval
+    ///
+    /// Runs triggered by pull requests from forks of first-time contributors
+    /// wait with the `action_required` conclusion until a maintainer approves
+    /// them.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `run_id` - The workflow run to approve
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The run does not exist or does not wait for approval
+    /// - The token lacks write access to the repository
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn approve_workflow_run(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: WorkflowRunId,
+    ) -> Result<()> {
+        self.post_workflow_run_action("approve_workflow_run", repository_id, run_id, "approve")
+            .await
+    }
+
+    /// Re-run only the failed jobs of a workflow run
+    ///
+    /// Jobs that the failed jobs depend on are re-run as well.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `run_id` - The workflow run to re-run the failed jobs of
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The run does not exist, is still in progress or has no failed jobs
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn rerun_failed_jobs(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: WorkflowRunId,
+    ) -> Result<()> {
+        self.post_workflow_run_action(
+            "rerun_failed_jobs",
+            repository_id,
+            run_id,
+            "rerun-failed-jobs",
+        )
+        .await
+    }
+
+    /// List the jobs of a workflow run
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `run_id` - The workflow run to list the jobs of
+    /// * `all_attempts` - Whether to include jobs of earlier attempts instead of only the latest
+    /// * `pagination` - The page of jobs to fetch
+    ///
+    /// # Returns
+    /// A page of `WorkflowJob`s with their steps
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The run does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_jobs_for_run(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: WorkflowRunId,
+        all_attempts: bool,
+        pagination: Pagination,
+    ) -> Result<Page<WorkflowJob>> {
+        let operation_name = "list_jobs_for_run";
+        let url = format!(
+            "{}?filter={}&per_page={}&page={}",
+            self.actions_url(repository_id, &format!("/runs/{}/jobs", run_id)),
+            if all_attempts { "all" } else { "latest" },
+            pagination.per_page,
+            pagination.page
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::GET, &url, None)
+                .await?;
+            let has_next = has_next_page(&response);
+
+            let jobs: WorkflowJobsResponse = response.json().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })?;
+
+            Ok(Page::new(jobs.jobs, pagination, has_next))
+        })
+        .await
+    }
+
+    /// List the deployments of a workflow run waiting on protected environments
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `run_id` - The workflow run
+    ///
+    /// # Returns
+    /// The `PendingDeployment`s with their environments and required reviewers
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The run does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_pending_deployments(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: WorkflowRunId,
+    ) -> Result<Vec<PendingDeployment>> {
+        let operation_name = "list_pending_deployments";
+        let url = self.actions_url(
+            repository_id,
+            &format!("/runs/{}/pending_deployments", run_id),
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::GET, &url, None)
+                .await?;
+            response
+                .json::<Vec<PendingDeployment>>()
+                .await
+                .map_err(|e| {
+                    ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+                })
+        })
+        .await
+    }
+
+    /// Approve or reject deployments of a workflow run waiting on protected environments
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `run_id` - The workflow run
+    /// * `environment_ids` - IDs of the environments to review the deployments for
+    /// * `state` - Whether to approve or reject the deployments
+    /// * `comment` - Comment recorded with the review
+    ///
+    /// # Returns
+    /// The `Deployment`s created for approved environments
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The run does not exist or has no deployment waiting on an environment
+    /// - The authenticated user is not a required reviewer of an environment
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn review_pending_deployments(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: WorkflowRunId,
+        environment_ids: &[u64],
+        state: DeploymentReviewState,
+        comment: &str,
+    ) -> Result<Vec<Deployment>> {
+        let operation_name = "review_pending_deployments";
+        let url = self.actions_url(
+            repository_id,
+            &format!("/runs/{}/pending_deployments", run_id),
+        );
+        let request_body = json!({
+            "environment_ids": environment_ids,
+            "state": state.to_string(),
+            "comment": comment,
+        });
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::POST, &url, Some(&request_body))
+                .await?;
+            response.json::<Vec<Deployment>>().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })
+        })
+        .await
+    }
+
+    /// Send a body-less POST to an action endpoint of a workflow run
+    async fn post_workflow_run_action(
+        &self,
+        operation_name: &str,
+        repository_id: &RepositoryId,
+        run_id: WorkflowRunId,
+        action: &str,
+    ) -> Result<()> {
+        let url = self.actions_url(repository_id, &format!("/runs/{}/{}", run_id, action));
+
+        retry_with_backoff(operation_name, None, || async {
+            self.send_rest_request(reqwest::Method::POST, &url, None)
+                .await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Download the log archive of a workflow run
+    ///
+    /// GitHub redirects to a short-lived download URL; the redirect is
+    /// followed without the token.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `run_id` - The workflow run to download the logs of
+    ///
+    /// # Returns
+    /// The zip archive with one log file per job and per step
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The run does not exist or its logs have expired
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn download_workflow_run_logs(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: WorkflowRunId,
+    ) -> Result<Vec<u8>> {
+        let url = self.actions_url(repository_id, &format!("/runs/{}/logs", run_id));
+        self.download_archive("download_workflow_run_logs", &url)
+            .await
+    }
+
+    /// List artifacts of a repository or of a single workflow run
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `run_id` - Optional workflow run to list the artifacts of
+    /// * `pagination` - The page of artifacts to fetch
+    ///
+    /// # Returns
+    /// A page of `Artifact`s, most recent first
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or run does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_artifacts(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: Option<WorkflowRunId>,
+        pagination: Pagination,
+    ) -> Result<Page<Artifact>> {
+        let operation_name = "list_artifacts";
+
+        let artifacts_path = match run_id {
+            Some(run_id) => format!("/runs/{}/artifacts", run_id),
+            None => "/artifacts".to_string(),
+        };
+        let url = format!(
+            "{}?per_page={}&page={}",
+            self.actions_url(repository_id, &artifacts_path),
+            pagination.per_page,
+            pagination.page
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::GET, &url, None)
+                .await?;
+            let has_next = has_next_page(&response);
+
+            let artifacts: ArtifactsResponse = response.json().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })?;
+
+            Ok(Page::new(artifacts.artifacts, pagination, has_next))
+        })
+        .await
+    }
+
+    /// Get a single artifact
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `artifact_id` - The artifact identifier
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The artifact does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_artifact(
+        &self,
+        repository_id: &RepositoryId,
+        artifact_id: ArtifactId,
+    ) -> Result<Artifact> {
+        let operation_name = "get_artifact";
+        let url = self.actions_url(repository_id, &format!("/artifacts/{}", artifact_id));
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::GET, &url, None)
+                .await?;
+            response.json::<Artifact>().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })
+        })
+        .await
+    }
+
+    /// Download an artifact as a zip archive
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `artifact_id` - The artifact to download
+    ///
+    /// # Returns
+    /// The zip archive with the files of the artifact
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The artifact does not exist or has expired
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn download_artifact(
+        &self,
+        repository_id: &RepositoryId,
+        artifact_id: ArtifactId,
+    ) -> Result<Vec<u8>> {
+        let url = self.actions_url(repository_id, &format!("/artifacts/{}/zip", artifact_id));
+        self.download_archive("download_artifact", &url).await
+    }
+
+    /// Download a zip archive from an endpoint that redirects to its download URL
+    async fn download_archive(&self, operation_name: &str, url: &str) -> Result<Vec<u8>> {
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::GET, url, None)
+                .await?;
+            let bytes = response.bytes().await.map_err(|e| {
+                ApiRetryableError::Retryable(format!("Failed to download archive: {}", e))
+            })?;
+            Ok(bytes.to_vec())
+        })
+        .await
+    }
+}
+
+impl GitHubClient {
+    pub(crate) fn actions_url(&self, repository_id: &RepositoryId, suffix: &str) -> String {
+        format!(
+            "{}/repos/{}/{}/actions{}",
+            self.api_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+            suffix
+        )
+    }
+}
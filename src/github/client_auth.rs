@@ -0,0 +1,155 @@
+use crate::github::client::{GitHubClient, retry_with_backoff};
+use crate::github::error::ApiRetryableError;
+use crate::types::auth::{
+    AccountType, AuthStatus, AuthenticatedUser, TokenKind, parse_scopes_header,
+};
+use crate::types::rate_limit::RateLimit;
+use crate::types::repository::Owner;
+
+use anyhow::Result;
+use serde::Deserialize;
+
+/// The fields of the authenticated user read from `/user`
+#[derive(Deserialize)]
+struct UserResponse {
+    login: String,
+    name: Option<String>,
+    #[serde(default)]
+    id: u64,
+    #[serde(default, rename = "type")]
+    account_type: String,
+    html_url: Option<String>,
+}
+
+/// Response of `/rate_limit`
+#[derive(Deserialize)]
+struct RateLimitResponse {
+    resources: RateLimit,
+}
+
+impl GitHubClient {
+    /// Verify the configured token and read its identity and scopes
+    ///
+    /// Calls `/user` and reads the `X-OAuth-Scopes` header, which GitHub only
+    /// returns for classic and OAuth tokens.
+    ///
+    /// # Returns
+    /// The `AuthStatus` of the token
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - No token is configured
+    /// - The token is invalid, expired or revoked
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn verify_auth(&self) -> Result<AuthStatus> {
+        let operation_name = "verify_auth";
+        let url = format!("{}/user", self.api_base_url);
+
+        retry_with_backoff(operation_name, None, || async {
+            let (user, scopes) = self.fetch_user(&url).await?;
+
+            Ok(AuthStatus {
+                login: user.login,
+                name: user.name,
+                token_kind: self
+                    .token
+                    .as_deref()
+                    .map(TokenKind::from_token)
+                    .unwrap_or(TokenKind::Unknown),
+                scopes,
+                api_base_url: self.api_base_url.clone(),
+            })
+        })
+        .await
+    }
+
+    /// Get the account the configured token acts as
+    ///
+    /// Calls `/user` and reads the `X-OAuth-Scopes` header, which GitHub only
+    /// returns for classic and OAuth tokens.
+    ///
+    /// # Returns
+    /// The login, name, account type and scopes of the authenticated account
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - No token is configured
+    /// - The token is invalid, expired or revoked, or is a GitHub App
+    ///   installation token, which does not act as an account
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_authenticated_user(&self) -> Result<AuthenticatedUser> {
+        let operation_name = "get_authenticated_user";
+        let url = format!("{}/user", self.api_base_url);
+
+        retry_with_backoff(operation_name, None, || async {
+            let (user, scopes) = self.fetch_user(&url).await?;
+
+            Ok(AuthenticatedUser {
+                login: user.login,
+                name: user.name,
+                account_type: AccountType::from_api_type(&user.account_type),
+                id: user.id,
+                html_url: user.html_url,
+                scopes,
+            })
+        })
+        .await
+    }
+
+    /// Read `/user` and the scopes of the token from the response headers
+    async fn fetch_user(
+        &self,
+        url: &str,
+    ) -> std::result::Result<(UserResponse, Option<Vec<String>>), ApiRetryableError> {
+        let response = self
+            .send_rest_request(reqwest::Method::GET, url, None)
+            .await?;
+
+        let scopes = response
+            .headers()
+            .get("x-oauth-scopes")
+            .and_then(|value| value.to_str().ok())
+            .map(parse_scopes_header);
+        let user = response.json::<UserResponse>().await.map_err(|e| {
+            ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+        })?;
+        Ok((user, scopes))
+    }
+
+    /// Get the rate limit buckets of the token
+    ///
+    /// Checking the rate limit does not count against it.
+    ///
+    /// # Arguments
+    /// * `owner` - Owner whose token from `owner_tokens` is checked; the
+    ///   client's token is checked if `None` or the owner has no token of its own
+    ///
+    /// # Returns
+    /// The core, GraphQL and search buckets of the token
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - No token is configured
+    /// - Rate limiting is disabled, as on some GitHub Enterprise Server instances
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_rate_limit(&self, owner: Option<&Owner>) -> Result<RateLimit> {
+        let operation_name = "get_rate_limit";
+        let url = format!("{}/rate_limit", self.api_base_url);
+        let token = self
+            .token_for_owner(owner.map(|owner| owner.as_str()))
+            .ok_or_else(|| anyhow::anyhow!("GitHub token not configured"))?;
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request_with_token(reqwest::Method::GET, &url, None, token)
+                .await?;
+            let rate_limit = response.json::<RateLimitResponse>().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })?;
+            Ok(rate_limit.resources)
+        })
+        .await
+    }
+}
@@ -0,0 +1,253 @@
+use crate::github::client::{GitHubClient, has_next_page, retry_with_backoff};
+use crate::github::error::ApiRetryableError;
+use crate::types::branch::RepositoryBranch;
+use crate::types::pagination::{Page, Pagination};
+use crate::types::repository::RepositoryId;
+
+use anyhow::Result;
+use serde_json::json;
+
+impl GitHubClient {
+    /// List the branches of a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `pagination` - The page of branches to fetch
+    ///
+    /// # Returns
+    /// A page of `RepositoryBranch` with the commit SHA each branch points to
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_branches(
+        &self,
+        repository_id: &RepositoryId,
+        pagination: Pagination,
+    ) -> Result<Page<RepositoryBranch>> {
+        let operation_name = "list_branches";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.list_branches_impl(repository_id, pagination).await
+        })
+        .await
+    }
+
+    async fn list_branches_impl(
+        &self,
+        repository_id: &RepositoryId,
+        pagination: Pagination,
+    ) -> std::result::Result<Page<RepositoryBranch>, ApiRetryableError> {
+        let url = format!(
+            "{}/repos/{}/{}/branches?per_page={}&page={}",
+            self.api_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+            pagination.per_page,
+            pagination.page
+        );
+
+        let response = self
+            .send_rest_request(reqwest::Method::GET, &url, None)
+            .await?;
+        let has_next = has_next_page(&response);
+
+        let entries: Vec<serde_json::Value> = response.json().await.map_err(|e| {
+            ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+        })?;
+
+        let branches = entries
+            .iter()
+            .filter_map(RepositoryBranch::from_api_value)
+            .collect();
+
+        Ok(Page::new(branches, pagination, has_next))
+    }
+
+    /// Create a branch from a branch name, tag or commit SHA
+    ///
+    /// Resolves `from_ref` to a commit SHA and creates `refs/heads/<name>`
+    /// pointing to it.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `name` - The name of the new branch (without `refs/heads/`)
+    /// * `from_ref` - The branch, tag or commit SHA to branch from
+    ///
+    /// # Returns
+    /// The created `RepositoryBranch`
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - `from_ref` can not be resolved to a commit
+    /// - A branch with the same name already exists
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn create_branch(
+        &self,
+        repository_id: &RepositoryId,
+        name: &str,
+        from_ref: &str,
+    ) -> Result<RepositoryBranch> {
+        let operation_name = "create_branch";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.create_branch_impl(repository_id, name, from_ref).await
+        })
+        .await
+    }
+
+    async fn create_branch_impl(
+        &self,
+        repository_id: &RepositoryId,
+        name: &str,
+        from_ref: &str,
+    ) -> std::result::Result<RepositoryBranch, ApiRetryableError> {
+        let owner = repository_id.owner().as_str();
+        let repo = repository_id.repo_name().as_str();
+
+        // The commits endpoint resolves branch names, tags and SHAs alike
+        let commit_url = format!(
+            "{}/repos/{}/{}/commits/{}",
+            self.api_base_url, owner, repo, from_ref
+        );
+        let commit: serde_json::Value = self
+            .send_rest_request(reqwest::Method::GET, &commit_url, None)
+            .await?
+            .json()
+            .await
+            .map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })?;
+        let sha = commit
+            .get("sha")
+            .and_then(|sha| sha.as_str())
+            .ok_or_else(|| {
+                ApiRetryableError::NonRetryable(format!(
+                    "Failed to resolve '{}' to a commit",
+                    from_ref
+                ))
+            })?
+            .to_string();
+
+        let url = format!("{}/repos/{}/{}/git/refs", self.api_base_url, owner, repo);
+        let body = json!({
+            "ref": format!("refs/heads/{}", name),
+            "sha": sha,
+        });
+        self.send_rest_request(reqwest::Method::POST, &url, Some(&body))
+            .await?;
+
+        Ok(RepositoryBranch::new(name.to_string(), sha, false))
+    }
+
+    /// Delete a branch
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `name` - The name of the branch to delete (without `refs/heads/`)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the branch was successfully deleted
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or branch does not exist or is not accessible
+    /// - The branch is protected or is the default branch
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn delete_branch(&self, repository_id: &RepositoryId, name: &str) -> Result<()> {
+        let operation_name = "delete_branch";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.delete_branch_impl(repository_id, name).await
+        })
+        .await
+    }
+
+    async fn delete_branch_impl(
+        &self,
+        repository_id: &RepositoryId,
+        name: &str,
+    ) -> std::result::Result<(), ApiRetryableError> {
+        let url = format!(
+            "{}/repos/{}/{}/git/refs/heads/{}",
+            self.api_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+            name
+        );
+
+        self.send_rest_request(reqwest::Method::DELETE, &url, None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rename a branch
+    ///
+    /// GitHub updates open pull requests and branch protection rules that
+    /// target the branch, and redirects the old name.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `name` - The current branch name
+    /// * `new_name` - The new branch name
+    ///
+    /// # Returns
+    /// The renamed `RepositoryBranch`
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or branch does not exist or is not accessible
+    /// - The user does not have permission to rename the branch
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn rename_branch(
+        &self,
+        repository_id: &RepositoryId,
+        name: &str,
+        new_name: &str,
+    ) -> Result<RepositoryBranch> {
+        let operation_name = "rename_branch";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.rename_branch_impl(repository_id, name, new_name).await
+        })
+        .await
+    }
+
+    async fn rename_branch_impl(
+        &self,
+        repository_id: &RepositoryId,
+        name: &str,
+        new_name: &str,
+    ) -> std::result::Result<RepositoryBranch, ApiRetryableError> {
+        let url = format!(
+            "{}/repos/{}/{}/branches/{}/rename",
+            self.api_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+            urlencoding::encode(name)
+        );
+        let body = json!({ "new_name": new_name });
+
+        let branch: serde_json::Value = self
+            .send_rest_request(reqwest::Method::POST, &url, Some(&body))
+            .await?
+            .json()
+            .await
+            .map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })?;
+
+        RepositoryBranch::from_api_value(&branch).ok_or_else(|| {
+            ApiRetryableError::NonRetryable(
+                "Failed to rename branch: unexpected response shape".to_string(),
+            )
+        })
+    }
+}
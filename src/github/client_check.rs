@@ -0,0 +1,181 @@
+use crate::github::client::{GitHubClient, has_next_page, retry_with_backoff};
+use crate::github::error::ApiRetryableError;
+use crate::types::check::{CheckRun, CombinedStatus, CommitState, CommitStatus};
+use crate::types::pagination::{Page, Pagination};
+use crate::types::repository::RepositoryId;
+
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::json;
+
+/// Response body of the check run list endpoint
+#[derive(Deserialize)]
+struct CheckRunsResponse {
+    check_runs: Vec<CheckRun>,
+}
+
+impl GitHubClient {
+    /// List check runs for a commit SHA, branch or tag
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `git_ref` - Commit SHA, branch name or tag name
+    /// * `check_name` - Optional check run name to filter by
+    /// * `pagination` - The page of check runs to fetch
+    ///
+    /// # Returns
+    /// A page of the latest `CheckRun`s for the ref
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or ref does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_check_runs_for_ref(
+        &self,
+        repository_id: &RepositoryId,
+        git_ref: &str,
+        check_name: Option<&str>,
+        pagination: Pagination,
+    ) -> Result<Page<CheckRun>> {
+        let operation_name = "list_check_runs_for_ref";
+
+        let mut url = format!(
+            "{}?per_page={}&page={}",
+            self.commits_url(repository_id, git_ref, "/check-runs"),
+            pagination.per_page,
+            pagination.page
+        );
+        if let Some(check_name) = check_name {
+            url.push_str(&format!("&check_name={}", urlencoding::encode(check_name)));
+        }
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::GET, &url, None)
+                .await?;
+            let has_next = has_next_page(&response);
+
+            let check_runs: CheckRunsResponse = response.json().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })?;
+
+            Ok(Page::new(check_runs.check_runs, pagination, has_next))
+        })
+        .await
+    }
+
+    /// Get the combined commit status for a commit SHA, branch or tag
+    ///
+    /// Only the first 100 statuses are included; GitHub keeps the latest
+    /// status of each context.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `git_ref` - Commit SHA, branch name or tag name
+    ///
+    /// # Returns
+    /// The `CombinedStatus` of the ref
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or ref does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_combined_status(
+        &self,
+        repository_id: &RepositoryId,
+        git_ref: &str,
+    ) -> Result<CombinedStatus> {
+        let operation_name = "get_combined_status";
+        let url = format!(
+            "{}?per_page=100",
+            self.commits_url(repository_id, git_ref, "/status")
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::GET, &url, None)
+                .await?;
+            response.json::<CombinedStatus>().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })
+        })
+        .await
+    }
+
+    /// Create a commit status
+    ///
+    /// A new status for the same context replaces the previous one in the
+    /// combined status.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `sha` - The commit SHA to attach the status to
+    /// * `state` - The state of the status
+    /// * `context` - Optional label identifying the status (defaults to "default")
+    /// * `description` - Optional short description of the status
+    /// * `target_url` - Optional URL with details of the status
+    ///
+    /// # Returns
+    /// The created `CommitStatus`
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or commit does not exist or the token lacks write access
+    /// - The commit already has 1000 statuses for the context
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn create_commit_status(
+        &self,
+        repository_id: &RepositoryId,
+        sha: &str,
+        state: CommitState,
+        context: Option<&str>,
+        description: Option<&str>,
+        target_url: Option<&str>,
+    ) -> Result<CommitStatus> {
+        let operation_name = "create_commit_status";
+
+        let mut request_body = json!({ "state": state.to_string() });
+        if let Some(context) = context {
+            request_body["context"] = json!(context);
+        }
+        if let Some(description) = description {
+            request_body["description"] = json!(description);
+        }
+        if let Some(target_url) = target_url {
+            request_body["target_url"] = json!(target_url);
+        }
+        let url = format!(
+            "{}/repos/{}/{}/statuses/{}",
+            self.api_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+            urlencoding::encode(sha)
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::POST, &url, Some(&request_body))
+                .await?;
+            response.json::<CommitStatus>().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })
+        })
+        .await
+    }
+}
+
+impl GitHubClient {
+    fn commits_url(&self, repository_id: &RepositoryId, git_ref: &str, suffix: &str) -> String {
+        format!(
+            "{}/repos/{}/{}/commits/{}{}",
+            self.api_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+            urlencoding::encode(git_ref),
+            suffix
+        )
+    }
+}
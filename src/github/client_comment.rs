@@ -0,0 +1,340 @@
+use crate::github::client::{GitHubClient, retry_with_backoff};
+use crate::github::error::ApiRetryableError;
+use crate::types::comment::{CommentPermissions, CommentTarget, MinimizeReason};
+use crate::types::repository::RepositoryId;
+
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::json;
+
+impl GitHubClient {
+    /// Hide (minimize) an issue or pull request comment
+    ///
+    /// Collapses the comment in the GitHub UI with a classifier explaining why
+    /// it was hidden. The comment content is kept and can be shown again with
+    /// `unminimize_comment`.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `target` - The comment to hide
+    /// * `reason` - The classifier recorded for the hidden comment
+    ///
+    /// # Returns
+    /// `true` if GitHub reports the comment as minimized
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or comment does not exist or is not accessible
+    /// - The user does not have permission to moderate the comment
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn minimize_comment(
+        &self,
+        repository_id: &RepositoryId,
+        target: CommentTarget,
+        reason: MinimizeReason,
+    ) -> Result<bool> {
+        let operation_name = "minimize_comment";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.minimize_comment_impl(repository_id, target, reason)
+                .await
+        })
+        .await
+    }
+
+    async fn minimize_comment_impl(
+        &self,
+        repository_id: &RepositoryId,
+        target: CommentTarget,
+        reason: MinimizeReason,
+    ) -> std::result::Result<bool, ApiRetryableError> {
+        let node_id = self.get_comment_node_id(repository_id, target).await?;
+
+        let mutation = r#"
+            mutation($subjectId: ID!, $classifier: ReportedContentClassifiers!) {
+                minimizeComment(input: {subjectId: $subjectId, classifier: $classifier}) {
+                    minimizedComment {
+                        isMinimized
+                    }
+                }
+            }
+        "#;
+
+        let data: MinimizeCommentData = self
+            .execute_graphql(
+                mutation,
+                json!({
+                    "subjectId": node_id,
+                    "classifier": reason.graphql_value(),
+                }),
+            )
+            .await
+            .map_err(|e| {
+                e.with_context(&format!(
+                    "Failed to minimize {} in {}/{}",
+                    target,
+                    repository_id.owner(),
+                    repository_id.repo_name()
+                ))
+            })?;
+
+        Ok(data
+            .minimize_comment
+            .and_then(|payload| payload.minimized_comment)
+            .is_some_and(|comment| comment.is_minimized))
+    }
+
+    /// Show (unminimize) a previously hidden issue or pull request comment
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `target` - The comment to show again
+    ///
+    /// # Returns
+    /// `true` if GitHub reports the comment as still minimized, `false` once it is visible
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or comment does not exist or is not accessible
+    /// - The user does not have permission to moderate the comment
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn unminimize_comment(
+        &self,
+        repository_id: &RepositoryId,
+        target: CommentTarget,
+    ) -> Result<bool> {
+        let operation_name = "unminimize_comment";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.unminimize_comment_impl(repository_id, target).await
+        })
+        .await
+    }
+
+    async fn unminimize_comment_impl(
+        &self,
+        repository_id: &RepositoryId,
+        target: CommentTarget,
+    ) -> std::result::Result<bool, ApiRetryableError> {
+        let node_id = self.get_comment_node_id(repository_id, target).await?;
+
+        let mutation = r#"
+            mutation($subjectId: ID!) {
+                unminimizeComment(input: {subjectId: $subjectId}) {
+                    unminimizedComment {
+                        isMinimized
+                    }
+                }
+            }
+        "#;
+
+        let data: UnminimizeCommentData = self
+            .execute_graphql(mutation, json!({ "subjectId": node_id }))
+            .await
+            .map_err(|e| {
+                e.with_context(&format!(
+                    "Failed to unminimize {} in {}/{}",
+                    target,
+                    repository_id.owner(),
+                    repository_id.repo_name()
+                ))
+            })?;
+
+        Ok(data
+            .unminimize_comment
+            .and_then(|payload| payload.unminimized_comment)
+            .is_some_and(|comment| comment.is_minimized))
+    }
+
+    /// Get the permissions of the authenticated user on a comment
+    ///
+    /// Reads `viewerCanUpdate`, `viewerCanDelete`, and `viewerCanMinimize` so
+    /// callers can check permissions before attempting an edit or delete
+    /// instead of failing with an opaque 403 response.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `target` - The comment to check
+    ///
+    /// # Returns
+    /// The `CommentPermissions` of the authenticated user
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or comment does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_comment_permissions(
+        &self,
+        repository_id: &RepositoryId,
+        target: CommentTarget,
+    ) -> Result<CommentPermissions> {
+        let operation_name = "get_comment_permissions";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.get_comment_permissions_impl(repository_id, target)
+                .await
+        })
+        .await
+    }
+
+    async fn get_comment_permissions_impl(
+        &self,
+        repository_id: &RepositoryId,
+        target: CommentTarget,
+    ) -> std::result::Result<CommentPermissions, ApiRetryableError> {
+        let node_id = self.get_comment_node_id(repository_id, target).await?;
+
+        let query = r#"
+            query($id: ID!) {
+                node(id: $id) {
+                    ... on IssueComment {
+                        author {
+                            login
+                        }
+                        viewerDidAuthor
+                        viewerCanUpdate
+                        viewerCanDelete
+                        viewerCanMinimize
+                    }
+                }
+            }
+        "#;
+
+        let data: CommentPermissionsData = self
+            .execute_graphql(query, json!({ "id": node_id }))
+            .await
+            .map_err(|e| {
+                e.with_context(&format!(
+                    "Failed to get permissions of {} in {}/{}",
+                    target,
+                    repository_id.owner(),
+                    repository_id.repo_name()
+                ))
+            })?;
+
+        let node = data
+            .node
+            .ok_or_else(|| ApiRetryableError::NonRetryable(format!("{} not found", target)))?;
+
+        Ok(CommentPermissions {
+            author: node.author.map(|author| author.login),
+            viewer_did_author: node.viewer_did_author,
+            can_update: node.viewer_can_update,
+            can_delete: node.viewer_can_delete,
+            can_minimize: node.viewer_can_minimize,
+        })
+    }
+
+    /// Delete an issue or pull request comment
+    ///
+    /// Pull request discussion comments are issue comments on the GitHub API,
+    /// so both kinds are deleted by their comment ID alone.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `target` - The comment to delete
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or comment does not exist or is not accessible
+    /// - The user does not have permission to delete the comment
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn delete_comment(
+        &self,
+        repository_id: &RepositoryId,
+        target: CommentTarget,
+    ) -> Result<()> {
+        let operation_name = "delete_comment";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.client
+                .issues(
+                    repository_id.owner().as_str(),
+                    repository_id.repo_name().as_str(),
+                )
+                .delete_comment(target.comment_id().into())
+                .await
+                .map_err(ApiRetryableError::from_octocrab_error)
+        })
+        .await
+    }
+
+    /// Resolve the GraphQL node ID of an issue or pull request comment
+    async fn get_comment_node_id(
+        &self,
+        repository_id: &RepositoryId,
+        target: CommentTarget,
+    ) -> std::result::Result<String, ApiRetryableError> {
+        let comment = self
+            .client
+            .issues(
+                repository_id.owner().as_str(),
+                repository_id.repo_name().as_str(),
+            )
+            .get_comment(target.comment_id().into())
+            .await
+            .map_err(ApiRetryableError::from_octocrab_error)?;
+
+        Ok(comment.node_id)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MinimizeCommentData {
+    minimize_comment: Option<MinimizeCommentPayload>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MinimizeCommentPayload {
+    minimized_comment: Option<MinimizedState>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UnminimizeCommentData {
+    unminimize_comment: Option<UnminimizeCommentPayload>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UnminimizeCommentPayload {
+    unminimized_comment: Option<MinimizedState>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MinimizedState {
+    #[serde(default)]
+    is_minimized: bool,
+}
+
+#[derive(Deserialize)]
+struct CommentPermissionsData {
+    node: Option<CommentPermissionsNode>,
+}
+
+/// Viewer fields of an issue comment; other node types read as all `false`
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CommentPermissionsNode {
+    author: Option<CommentAuthor>,
+    #[serde(default)]
+    viewer_did_author: bool,
+    #[serde(default)]
+    viewer_can_update: bool,
+    #[serde(default)]
+    viewer_can_delete: bool,
+    #[serde(default)]
+    viewer_can_minimize: bool,
+}
+
+#[derive(Deserialize)]
+struct CommentAuthor {
+    login: String,
+}
@@ -0,0 +1,92 @@
+use crate::github::client::{GitHubClient, has_next_page, retry_with_backoff};
+use crate::github::error::ApiRetryableError;
+use crate::types::commit::{Commit, CommitFilter};
+use crate::types::pagination::{Page, Pagination};
+use crate::types::repository::RepositoryId;
+
+use anyhow::Result;
+
+impl GitHubClient {
+    /// Get a single commit with its message, author, stats and changed files
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `git_ref` - Commit SHA, branch or tag to resolve to a commit
+    ///
+    /// # Returns
+    /// The `Commit` including its `stats` and `files`
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - `git_ref` can not be resolved to a commit
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_commit(&self, repository_id: &RepositoryId, git_ref: &str) -> Result<Commit> {
+        let operation_name = "get_commit";
+        let url = format!(
+            "{}/repos/{}/{}/commits/{}",
+            self.api_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+            urlencoding::encode(git_ref)
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::GET, &url, None)
+                .await?;
+
+            response.json::<Commit>().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })
+        })
+        .await
+    }
+
+    /// List the commits of a repository, newest first
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `filter` - Starting ref, path and time range to restrict the commits to
+    /// * `pagination` - The page of commits to fetch
+    ///
+    /// # Returns
+    /// A page of `Commit`s without stats or changed files
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist, is not accessible or is empty
+    /// - The starting ref does not exist
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_commits(
+        &self,
+        repository_id: &RepositoryId,
+        filter: &CommitFilter,
+        pagination: Pagination,
+    ) -> Result<Page<Commit>> {
+        let operation_name = "list_commits";
+        let url = format!(
+            "{}/repos/{}/{}/commits?{}",
+            self.api_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+            filter.to_query(pagination)
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::GET, &url, None)
+                .await?;
+            let has_next = has_next_page(&response);
+
+            let commits: Vec<Commit> = response.json().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })?;
+
+            Ok(Page::new(commits, pagination, has_next))
+        })
+        .await
+    }
+}
@@ -0,0 +1,233 @@
+use crate::github::client::{GitHubClient, retry_with_backoff};
+use crate::github::error::ApiRetryableError;
+use crate::types::content::{FileCommit, FileContent, encode_content};
+use crate::types::repository::RepositoryId;
+
+use anyhow::Result;
+use serde_json::json;
+
+impl GitHubClient {
+    /// Get the contents of a file
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `path` - The file path relative to the repository root
+    /// * `git_ref` - Optional branch, tag or commit SHA (defaults to the default branch)
+    ///
+    /// # Returns
+    /// The decoded `FileContent`, including the blob SHA needed for updates
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or file does not exist or is not accessible
+    /// - The path is a directory or the file is larger than 1 MB
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_file_content(
+        &self,
+        repository_id: &RepositoryId,
+        path: &str,
+        git_ref: Option<&str>,
+    ) -> Result<FileContent> {
+        let operation_name = "get_file_content";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.get_file_content_impl(repository_id, path, git_ref)
+                .await
+        })
+        .await
+    }
+
+    async fn get_file_content_impl(
+        &self,
+        repository_id: &RepositoryId,
+        path: &str,
+        git_ref: Option<&str>,
+    ) -> std::result::Result<FileContent, ApiRetryableError> {
+        let mut url = self.contents_url(repository_id, path);
+        if let Some(git_ref) = git_ref {
+            url.push_str(&format!("?ref={}", urlencoding::encode(git_ref)));
+        }
+
+        let value: serde_json::Value = self
+            .send_rest_request(reqwest::Method::GET, &url, None)
+            .await?
+            .json()
+            .await
+            .map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })?;
+
+        FileContent::from_api_value(&value).map_err(|e| {
+            ApiRetryableError::NonRetryable(format!("Failed to get file '{}': {}", path, e))
+        })
+    }
+
+    /// Create a file or update an existing one in a single commit
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `path` - The file path relative to the repository root
+    /// * `content` - The new file contents
+    /// * `message` - The commit message
+    /// * `branch` - Optional branch to commit to (defaults to the default branch)
+    /// * `sha` - Blob SHA of the file being replaced; required when updating
+    ///
+    /// # Returns
+    /// The `FileCommit` with the new blob SHA and the commit SHA
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or branch does not exist or is not accessible
+    /// - The file exists and `sha` is missing or does not match the current file
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn create_or_update_file(
+        &self,
+        repository_id: &RepositoryId,
+        path: &str,
+        content: &[u8],
+        message: &str,
+        branch: Option<&str>,
+        sha: Option<&str>,
+    ) -> Result<FileCommit> {
+        let operation_name = "create_or_update_file";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.create_or_update_file_impl(repository_id, path, content, message, branch, sha)
+                .await
+        })
+        .await
+    }
+
+    async fn create_or_update_file_impl(
+        &self,
+        repository_id: &RepositoryId,
+        path: &str,
+        content: &[u8],
+        message: &str,
+        branch: Option<&str>,
+        sha: Option<&str>,
+    ) -> std::result::Result<FileCommit, ApiRetryableError> {
+        let mut body = json!({
+            "message": message,
+            "content": encode_content(content),
+        });
+        if let Some(branch) = branch {
+            body["branch"] = json!(branch);
+        }
+        if let Some(sha) = sha {
+            body["sha"] = json!(sha);
+        }
+
+        let value: serde_json::Value = self
+            .send_rest_request(
+                reqwest::Method::PUT,
+                &self.contents_url(repository_id, path),
+                Some(&body),
+            )
+            .await?
+            .json()
+            .await
+            .map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })?;
+
+        FileCommit::from_api_value(path, &value).ok_or_else(|| {
+            ApiRetryableError::NonRetryable(
+                "Failed to write file: unexpected response shape".to_string(),
+            )
+        })
+    }
+
+    /// Delete a file in a single commit
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `path` - The file path relative to the repository root
+    /// * `message` - The commit message
+    /// * `sha` - Blob SHA of the file being deleted
+    /// * `branch` - Optional branch to commit to (defaults to the default branch)
+    ///
+    /// # Returns
+    /// The `FileCommit` of the deletion
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository, branch or file does not exist or is not accessible
+    /// - `sha` does not match the current file
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn delete_file(
+        &self,
+        repository_id: &RepositoryId,
+        path: &str,
+        message: &str,
+        sha: &str,
+        branch: Option<&str>,
+    ) -> Result<FileCommit> {
+        let operation_name = "delete_file";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.delete_file_impl(repository_id, path, message, sha, branch)
+                .await
+        })
+        .await
+    }
+
+    async fn delete_file_impl(
+        &self,
+        repository_id: &RepositoryId,
+        path: &str,
+        message: &str,
+        sha: &str,
+        branch: Option<&str>,
+    ) -> std::result::Result<FileCommit, ApiRetryableError> {
+        let mut body = json!({
+            "message": message,
+            "sha": sha,
+        });
+        if let Some(branch) = branch {
+            body["branch"] = json!(branch);
+        }
+
+        let value: serde_json::Value = self
+            .send_rest_request(
+                reqwest::Method::DELETE,
+                &self.contents_url(repository_id, path),
+                Some(&body),
+            )
+            .await?
+            .json()
+            .await
+            .map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })?;
+
+        FileCommit::from_api_value(path, &value).ok_or_else(|| {
+            ApiRetryableError::NonRetryable(
+                "Failed to delete file: unexpected response shape".to_string(),
+            )
+        })
+    }
+}
+
+impl GitHubClient {
+    /// Build the Contents API URL of a path, encoding each path segment
+    fn contents_url(&self, repository_id: &RepositoryId, path: &str) -> String {
+        let encoded_path = path
+            .trim_matches('/')
+            .split('/')
+            .map(|segment| urlencoding::encode(segment).into_owned())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        format!(
+            "{}/repos/{}/{}/contents/{}",
+            self.api_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+            encoded_path
+        )
+    }
+}
@@ -0,0 +1,733 @@
+use crate::github::client::{GitHubClient, retry_with_backoff};
+use crate::github::error::ApiRetryableError;
+use crate::github::graphql::{Connection, NodeId};
+use crate::types::discussion::{
+    Discussion, DiscussionCategory, DiscussionComment, DiscussionNumber, LockReason,
+};
+use crate::types::repository::RepositoryId;
+
+use anyhow::Result;
+use serde::Deserialize;
+use serde::de::IgnoredAny;
+use serde_json::json;
+
+/// Number of top-level comments fetched with a discussion
+const DISCUSSION_COMMENT_LIMIT: u32 = 100;
+
+/// Number of replies fetched for each discussion comment
+const DISCUSSION_REPLY_LIMIT: u32 = 50;
+
+/// Fields selected for every discussion category
+const CATEGORY_FIELDS: &str = "id name slug description emoji isAnswerable";
+
+/// Fields selected for every discussion comment and reply
+const COMMENT_FIELDS: &str = "id body url createdAt isAnswer upvoteCount author { login }";
+
+/// Fields selected for every discussion, without its comments
+fn discussion_fields() -> String {
+    format!(
+        "id number title body url createdAt locked closed isAnswered author {{ login }} category {{ {} }}",
+        CATEGORY_FIELDS
+    )
+}
+
+impl GitHubClient {
+    /// List the discussion categories of a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    ///
+    /// # Returns
+    /// The `DiscussionCategory`s of the repository
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or does not have discussions enabled
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_discussion_categories(
+        &self,
+        repository_id: &RepositoryId,
+    ) -> Result<Vec<DiscussionCategory>> {
+        let operation_name = "list_discussion_categories";
+
+        let query = format!(
+            r#"
+            query($owner: String!, $name: String!) {{
+                repository(owner: $owner, name: $name) {{
+                    hasDiscussionsEnabled
+                    discussionCategories(first: 100) {{
+                        nodes {{ {} }}
+                    }}
+                }}
+            }}
+        "#,
+            CATEGORY_FIELDS
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            let data: CategoriesData = self
+                .execute_graphql(
+                    &query,
+                    json!({
+                        "owner": repository_id.owner().as_str(),
+                        "name": repository_id.repo_name().as_str(),
+                    }),
+                )
+                .await
+                .map_err(|e| {
+                    e.with_context(&format!(
+                        "Failed to list discussion categories of {}",
+                        repository_id
+                    ))
+                })?;
+
+            let repository = data.repository.ok_or_else(|| {
+                ApiRetryableError::NonRetryable(format!("Repository {} not found", repository_id))
+            })?;
+            if repository.has_discussions_enabled == Some(false) {
+                return Err(ApiRetryableError::NonRetryable(format!(
+                    "Discussions are not enabled for {}",
+                    repository_id
+                )));
+            }
+
+            Ok(repository
+                .discussion_categories
+                .into_nodes()
+                .filter_map(|node| DiscussionCategory::from_graphql_value(&node))
+                .collect())
+        })
+        .await
+    }
+
+    /// Get a discussion with its comments and their replies
+    ///
+    /// Fetches up to the first 100 top-level comments and up to 50 replies
+    /// of each comment.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `discussion_number` - The discussion number
+    ///
+    /// # Returns
+    /// The `Discussion` with its fetched comments
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or discussion does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_discussion(
+        &self,
+        repository_id: &RepositoryId,
+        discussion_number: DiscussionNumber,
+    ) -> Result<Discussion> {
+        let operation_name = "get_discussion";
+
+        let query = format!(
+            r#"
+            query($owner: String!, $name: String!, $number: Int!) {{
+                repository(owner: $owner, name: $name) {{
+                    discussion(number: $number) {{
+                        {}
+                        comments(first: {}) {{
+                            totalCount
+                            nodes {{
+                                {}
+                                replies(first: {}) {{
+                                    nodes {{ {} }}
+                                }}
+                            }}
+                        }}
+                    }}
+                }}
+            }}
+        "#,
+            discussion_fields(),
+            DISCUSSION_COMMENT_LIMIT,
+            COMMENT_FIELDS,
+            DISCUSSION_REPLY_LIMIT,
+            COMMENT_FIELDS
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            let data: DiscussionData<serde_json::Value> = self
+                .execute_graphql(
+                    &query,
+                    json!({
+                        "owner": repository_id.owner().as_str(),
+                        "name": repository_id.repo_name().as_str(),
+                        "number": discussion_number.value(),
+                    }),
+                )
+                .await
+                .map_err(|e| {
+                    e.with_context(&format!(
+                        "Failed to get discussion #{} in {}",
+                        discussion_number, repository_id
+                    ))
+                })?;
+
+            data.repository
+                .and_then(|repository| repository.discussion)
+                .and_then(|discussion| Discussion::from_graphql_value(&discussion))
+                .ok_or_else(|| {
+                    ApiRetryableError::NonRetryable(format!(
+                        "Discussion #{} not found in {}",
+                        discussion_number, repository_id
+                    ))
+                })
+        })
+        .await
+    }
+
+    /// Create a discussion in a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `category_id` - GraphQL node ID of the discussion category
+    /// * `title` - The discussion title
+    /// * `body` - The discussion body in Markdown
+    ///
+    /// # Returns
+    /// The created `Discussion`, without comments
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or category does not exist or is not accessible
+    /// - Discussions are not enabled for the repository
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn create_discussion(
+        &self,
+        repository_id: &RepositoryId,
+        category_id: &str,
+        title: &str,
+        body: &str,
+    ) -> Result<Discussion> {
+        let operation_name = "create_discussion";
+
+        let mutation = format!(
+            r#"
+            mutation($input: CreateDiscussionInput!) {{
+                createDiscussion(input: $input) {{
+                    discussion {{ {} }}
+                }}
+            }}
+        "#,
+            discussion_fields()
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            let repository_node_id = self.get_repository_node_id(repository_id).await?;
+            let data: CreateDiscussionData = self
+                .execute_graphql(
+                    &mutation,
+                    json!({
+                        "input": {
+                            "repositoryId": repository_node_id,
+                            "categoryId": category_id,
+                            "title": title,
+                            "body": body,
+                        }
+                    }),
+                )
+                .await
+                .map_err(|e| {
+                    e.with_context(&format!("Failed to create discussion in {}", repository_id))
+                })?;
+
+            data.create_discussion
+                .and_then(|payload| payload.discussion)
+                .and_then(|discussion| Discussion::from_graphql_value(&discussion))
+                .ok_or_else(|| {
+                    ApiRetryableError::NonRetryable(
+                        "Create discussion response is missing discussion fields".to_string(),
+                    )
+                })
+        })
+        .await
+    }
+
+    /// Add a comment to a discussion, or reply to one of its comments
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `discussion_number` - The discussion number
+    /// * `body` - The comment body in Markdown
+    /// * `reply_to_id` - GraphQL node ID of the top-level comment to reply to
+    ///
+    /// # Returns
+    /// The created `DiscussionComment`
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository, discussion or replied comment does not exist
+    /// - The discussion is locked
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn add_discussion_comment(
+        &self,
+        repository_id: &RepositoryId,
+        discussion_number: DiscussionNumber,
+        body: &str,
+        reply_to_id: Option<&str>,
+    ) -> Result<DiscussionComment> {
+        let operation_name = "add_discussion_comment";
+
+        let mutation = format!(
+            r#"
+            mutation($input: AddDiscussionCommentInput!) {{
+                addDiscussionComment(input: $input) {{
+                    comment {{ {} }}
+                }}
+            }}
+        "#,
+            COMMENT_FIELDS
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            let discussion_id = self
+                .get_discussion_node_id(repository_id, discussion_number)
+                .await?;
+
+            let mut input = json!({ "discussionId": discussion_id, "body": body });
+            if let Some(reply_to_id) = reply_to_id {
+                input["replyToId"] = json!(reply_to_id);
+            }
+
+            let data: AddDiscussionCommentData = self
+                .execute_graphql(&mutation, json!({ "input": input }))
+                .await
+                .map_err(|e| {
+                    e.with_context(&format!(
+                        "Failed to comment on discussion #{} in {}",
+                        discussion_number, repository_id
+                    ))
+                })?;
+
+            data.add_discussion_comment
+                .and_then(|payload| payload.comment)
+                .and_then(|comment| DiscussionComment::from_graphql_value(&comment))
+                .ok_or_else(|| {
+                    ApiRetryableError::NonRetryable(
+                        "Add discussion comment response is missing comment fields".to_string(),
+                    )
+                })
+        })
+        .await
+    }
+
+    /// Mark a discussion comment as the answer of its discussion
+    ///
+    /// Only comments in answerable (Q&A) categories can be marked. Marking a
+    /// comment replaces any previously chosen answer.
+    ///
+    /// # Arguments
+    /// * `comment_id` - GraphQL node ID of the comment
+    ///
+    /// # Returns
+    /// The number of the answered discussion
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The comment does not exist or its category is not answerable
+    /// - The user cannot mark answers in the repository
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn mark_comment_as_answer(&self, comment_id: &str) -> Result<DiscussionNumber> {
+        let operation_name = "mark_comment_as_answer";
+
+        let mutation = r#"
+            mutation($id: ID!) {
+                markDiscussionCommentAsAnswer(input: {id: $id}) {
+                    discussion {
+                        number
+                    }
+                }
+            }
+        "#;
+
+        retry_with_backoff(operation_name, None, || async {
+            let data: MarkAnswerData = self
+                .execute_graphql(mutation, json!({ "id": comment_id }))
+                .await
+                .map_err(|e| {
+                    e.with_context(&format!("Failed to mark comment {} as answer", comment_id))
+                })?;
+
+            data.mark_discussion_comment_as_answer
+                .and_then(|payload| payload.discussion)
+                .map(|discussion| DiscussionNumber::new(discussion.number))
+                .ok_or_else(|| {
+                    ApiRetryableError::NonRetryable(
+                        "Mark as answer response is missing the discussion number".to_string(),
+                    )
+                })
+        })
+        .await
+    }
+
+    /// Lock a discussion so only collaborators can comment on it
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `discussion_number` - The discussion number
+    /// * `reason` - Optional reason recorded for the lock
+    ///
+    /// # Returns
+    /// `true` if GitHub reports the discussion as locked
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or discussion does not exist
+    /// - The user cannot moderate discussions in the repository
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn lock_discussion(
+        &self,
+        repository_id: &RepositoryId,
+        discussion_number: DiscussionNumber,
+        reason: Option<LockReason>,
+    ) -> Result<bool> {
+        let operation_name = "lock_discussion";
+
+        let mutation = r#"
+            mutation($input: LockLockableInput!) {
+                lockLockable(input: $input) {
+                    lockedRecord {
+                        locked
+                    }
+                }
+            }
+        "#;
+
+        retry_with_backoff(operation_name, None, || async {
+            let discussion_id = self
+                .get_discussion_node_id(repository_id, discussion_number)
+                .await?;
+
+            let mut input = json!({ "lockableId": discussion_id });
+            if let Some(reason) = reason {
+                input["lockReason"] = json!(reason.graphql_value());
+            }
+
+            let data: LockData = self
+                .execute_graphql(mutation, json!({ "input": input }))
+                .await
+                .map_err(|e| {
+                    e.with_context(&format!(
+                        "Failed to lock discussion #{} in {}",
+                        discussion_number, repository_id
+                    ))
+                })?;
+
+            Ok(data
+                .lock_lockable
+                .and_then(|payload| payload.locked_record)
+                .is_some_and(|record| record.locked))
+        })
+        .await
+    }
+
+    /// Unlock a previously locked discussion
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `discussion_number` - The discussion number
+    ///
+    /// # Returns
+    /// `true` if GitHub reports the discussion as still locked, `false` once it is unlocked
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or discussion does not exist
+    /// - The user cannot moderate discussions in the repository
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn unlock_discussion(
+        &self,
+        repository_id: &RepositoryId,
+        discussion_number: DiscussionNumber,
+    ) -> Result<bool> {
+        let operation_name = "unlock_discussion";
+
+        let mutation = r#"
+            mutation($id: ID!) {
+                unlockLockable(input: {lockableId: $id}) {
+                    unlockedRecord {
+                        locked
+                    }
+                }
+            }
+        "#;
+
+        retry_with_backoff(operation_name, None, || async {
+            let discussion_id = self
+                .get_discussion_node_id(repository_id, discussion_number)
+                .await?;
+
+            let data: UnlockData = self
+                .execute_graphql(mutation, json!({ "id": discussion_id }))
+                .await
+                .map_err(|e| {
+                    e.with_context(&format!(
+                        "Failed to unlock discussion #{} in {}",
+                        discussion_number, repository_id
+                    ))
+                })?;
+
+            Ok(data
+                .unlock_lockable
+                .and_then(|payload| payload.unlocked_record)
+                .is_some_and(|record| record.locked))
+        })
+        .await
+    }
+
+    /// Update the title, body or category of a discussion
+    ///
+    /// Fields passed as `None` are left unchanged.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `discussion_number` - The discussion number
+    /// * `title` - Optional new title
+    /// * `body` - Optional new body in Markdown
+    /// * `category_id` - Optional GraphQL node ID of the new category
+    ///
+    /// # Returns
+    /// The updated `Discussion`, without comments
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository, discussion or category does not exist
+    /// - The user cannot update the discussion
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn update_discussion(
+        &self,
+        repository_id: &RepositoryId,
+        discussion_number: DiscussionNumber,
+        title: Option<&str>,
+        body: Option<&str>,
+        category_id: Option<&str>,
+    ) -> Result<Discussion> {
+        let operation_name = "update_discussion";
+
+        let mutation = format!(
+            r#"
+            mutation($input: UpdateDiscussionInput!) {{
+                updateDiscussion(input: $input) {{
+                    discussion {{ {} }}
+                }}
+            }}
+        "#,
+            discussion_fields()
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            let discussion_id = self
+                .get_discussion_node_id(repository_id, discussion_number)
+                .await?;
+
+            let mut input = json!({ "discussionId": discussion_id });
+            for (key, value) in [
+                ("title", title),
+                ("body", body),
+                ("categoryId", category_id),
+            ] {
+                if let Some(value) = value {
+                    input[key] = json!(value);
+                }
+            }
+
+            let data: UpdateDiscussionData = self
+                .execute_graphql(&mutation, json!({ "input": input }))
+                .await
+                .map_err(|e| {
+                    e.with_context(&format!(
+                        "Failed to update discussion #{} in {}",
+                        discussion_number, repository_id
+                    ))
+                })?;
+
+            data.update_discussion
+                .and_then(|payload| payload.discussion)
+                .and_then(|discussion| Discussion::from_graphql_value(&discussion))
+                .ok_or_else(|| {
+                    ApiRetryableError::NonRetryable(
+                        "Update discussion response is missing discussion fields".to_string(),
+                    )
+                })
+        })
+        .await
+    }
+
+    /// Delete a discussion comment or reply
+    ///
+    /// Deleting a top-level comment also deletes its replies.
+    ///
+    /// # Arguments
+    /// * `comment_id` - GraphQL node ID of the comment
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The comment does not exist
+    /// - The user cannot delete the comment
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn delete_discussion_comment(&self, comment_id: &str) -> Result<()> {
+        let operation_name = "delete_discussion_comment";
+
+        let mutation = r#"
+            mutation($id: ID!) {
+                deleteDiscussionComment(input: {id: $id}) {
+                    comment {
+                        id
+                    }
+                }
+            }
+        "#;
+
+        retry_with_backoff(operation_name, None, || async {
+            self.execute_graphql::<IgnoredAny>(mutation, json!({ "id": comment_id }))
+                .await
+                .map_err(|e| {
+                    e.with_context(&format!(
+                        "Failed to delete discussion comment {}",
+                        comment_id
+                    ))
+                })?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Resolve the GraphQL node ID of a discussion
+    async fn get_discussion_node_id(
+        &self,
+        repository_id: &RepositoryId,
+        discussion_number: DiscussionNumber,
+    ) -> std::result::Result<String, ApiRetryableError> {
+        self.execute_graphql::<DiscussionData<NodeId>>(
+            "query($owner: String!, $name: String!, $number: Int!) { repository(owner: $owner, name: $name) { discussion(number: $number) { id } } }",
+            json!({
+                "owner": repository_id.owner().as_str(),
+                "name": repository_id.repo_name().as_str(),
+                "number": discussion_number.value(),
+            }),
+        )
+        .await
+        .map_err(|e| e.with_context(&format!(
+                "Failed to resolve discussion #{} in {}",
+                discussion_number, repository_id)))?
+        .repository
+        .and_then(|repository| repository.discussion)
+        .map(|discussion| discussion.id)
+        .ok_or_else(|| {
+            ApiRetryableError::NonRetryable(format!(
+                "Discussion #{} not found in {}",
+                discussion_number, repository_id
+            ))
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct CategoriesData {
+    repository: Option<CategoriesRepository>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CategoriesRepository {
+    has_discussions_enabled: Option<bool>,
+    discussion_categories: Connection<serde_json::Value>,
+}
+
+/// Data of a query selecting `repository.discussion` as `T`
+#[derive(Deserialize)]
+struct DiscussionData<T> {
+    repository: Option<DiscussionRepository<T>>,
+}
+
+#[derive(Deserialize)]
+struct DiscussionRepository<T> {
+    discussion: Option<T>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateDiscussionData {
+    create_discussion: Option<DiscussionPayload>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateDiscussionData {
+    update_discussion: Option<DiscussionPayload>,
+}
+
+#[derive(Deserialize)]
+struct DiscussionPayload {
+    discussion: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AddDiscussionCommentData {
+    add_discussion_comment: Option<DiscussionCommentPayload>,
+}
+
+#[derive(Deserialize)]
+struct DiscussionCommentPayload {
+    comment: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MarkAnswerData {
+    mark_discussion_comment_as_answer: Option<MarkAnswerPayload>,
+}
+
+#[derive(Deserialize)]
+struct MarkAnswerPayload {
+    discussion: Option<AnsweredDiscussion>,
+}
+
+#[derive(Deserialize)]
+struct AnsweredDiscussion {
+    number: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LockData {
+    lock_lockable: Option<LockPayload>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LockPayload {
+    locked_record: Option<LockedState>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UnlockData {
+    unlock_lockable: Option<UnlockPayload>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UnlockPayload {
+    unlocked_record: Option<LockedState>,
+}
+
+#[derive(Deserialize)]
+struct LockedState {
+    #[serde(default)]
+    locked: bool,
+}
@@ -0,0 +1,164 @@
+use crate::github::client::{GitHubClient, retry_with_backoff};
+use crate::github::error::ApiRetryableError;
+use crate::types::fork::{ForkSyncResult, RepositoryFork};
+use crate::types::repository::RepositoryId;
+
+use anyhow::Result;
+use serde_json::json;
+
+impl GitHubClient {
+    /// Fork a repository
+    ///
+    /// GitHub creates forks asynchronously, so the returned fork may take a
+    /// short while before its contents can be accessed. Forking a repository
+    /// that was already forked to the same owner returns the existing fork.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository to fork
+    /// * `organization` - Optional organization to create the fork in (defaults to the authenticated user)
+    /// * `default_branch_only` - Whether to copy only the default branch
+    ///
+    /// # Returns
+    /// The created `RepositoryFork`
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - Forking is disabled for the repository or organization
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn fork_repository(
+        &self,
+        repository_id: &RepositoryId,
+        organization: Option<&str>,
+        default_branch_only: bool,
+    ) -> Result<RepositoryFork> {
+        let operation_name = "fork_repository";
+
+        let mut request_body = json!({ "default_branch_only": default_branch_only });
+        if let Some(organization) = organization {
+            request_body["organization"] = json!(organization);
+        }
+
+        retry_with_backoff(operation_name, None, || async {
+            self.fork_repository_impl(repository_id, &request_body)
+                .await
+        })
+        .await
+    }
+
+    async fn fork_repository_impl(
+        &self,
+        repository_id: &RepositoryId,
+        request_body: &serde_json::Value,
+    ) -> std::result::Result<RepositoryFork, ApiRetryableError> {
+        let url = self.repository_url(repository_id, "/forks");
+
+        let response = self
+            .send_rest_request(reqwest::Method::POST, &url, Some(request_body))
+            .await?;
+        let value: serde_json::Value = response.json().await.map_err(|e| {
+            ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+        })?;
+
+        RepositoryFork::from_api_value(&value).ok_or_else(|| {
+            ApiRetryableError::NonRetryable(
+                "Fork response is missing repository fields".to_string(),
+            )
+        })
+    }
+
+    /// Sync a branch of a fork with its upstream repository
+    ///
+    /// Merges the upstream branch of the same name into the fork branch. The
+    /// result reports whether the branch was fast-forwarded, merged or already
+    /// up to date.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The fork to update
+    /// * `branch` - Optional branch to sync (defaults to the default branch of the fork)
+    ///
+    /// # Returns
+    /// The `ForkSyncResult` describing how the branch was updated
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist, is not accessible or is not a fork
+    /// - The branch has conflicts with the upstream branch
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn sync_fork_with_upstream(
+        &self,
+        repository_id: &RepositoryId,
+        branch: Option<&str>,
+    ) -> Result<ForkSyncResult> {
+        let operation_name = "sync_fork_with_upstream";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.sync_fork_with_upstream_impl(repository_id, branch)
+                .await
+        })
+        .await
+    }
+
+    async fn sync_fork_with_upstream_impl(
+        &self,
+        repository_id: &RepositoryId,
+        branch: Option<&str>,
+    ) -> std::result::Result<ForkSyncResult, ApiRetryableError> {
+        let branch = match branch {
+            Some(branch) => branch.to_string(),
+            None => self.get_default_branch_impl(repository_id).await?,
+        };
+
+        let url = self.repository_url(repository_id, "/merge-upstream");
+        let response = self
+            .send_rest_request(
+                reqwest::Method::POST,
+                &url,
+                Some(&json!({ "branch": branch })),
+            )
+            .await?;
+
+        response.json::<ForkSyncResult>().await.map_err(|e| {
+            ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+        })
+    }
+
+    async fn get_default_branch_impl(
+        &self,
+        repository_id: &RepositoryId,
+    ) -> std::result::Result<String, ApiRetryableError> {
+        let url = self.repository_url(repository_id, "");
+
+        let response = self
+            .send_rest_request(reqwest::Method::GET, &url, None)
+            .await?;
+        let value: serde_json::Value = response.json().await.map_err(|e| {
+            ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+        })?;
+
+        value
+            .get("default_branch")
+            .and_then(|b| b.as_str())
+            .map(|b| b.to_string())
+            .ok_or_else(|| {
+                ApiRetryableError::NonRetryable(
+                    "Repository response is missing the default branch".to_string(),
+                )
+            })
+    }
+}
+
+impl GitHubClient {
+    /// Build a URL below the REST endpoint of a repository
+    fn repository_url(&self, repository_id: &RepositoryId, suffix: &str) -> String {
+        format!(
+            "{}/repos/{}/{}{}",
+            self.api_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+            suffix
+        )
+    }
+}
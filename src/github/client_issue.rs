@@ -1,12 +1,20 @@
-use crate::github::client::{GitHubClient, retry_with_backoff};
+use crate::github::client::{GitHubClient, has_next_page, retry_with_backoff};
 use crate::github::error::ApiRetryableError;
+use crate::github::graphql::Connection;
+use crate::types::conditional::Conditional;
 use crate::types::issue::{
-    Issue, IssueComment, IssueCommentNumber, IssueId, IssueNumber, IssueState,
+    Issue, IssueComment, IssueCommentNumber, IssueId, IssueNumber, IssueState, IssueStateReason,
+    IssueType, IssueTypeId,
 };
-use crate::types::repository::{MilestoneNumber, RepositoryId};
+use crate::types::pagination::{MAX_PER_PAGE, Page, Pagination};
+use crate::types::repository::{MilestoneNumber, Owner, RepositoryId};
+use crate::types::timeline::{CrossReference, IssueTimelineEvent};
 use crate::types::{User, label::Label};
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde::de::IgnoredAny;
 
 impl GitHubClient {
     /// Get an issue by repository ID and issue number
@@ -15,7 +23,7 @@ impl GitHubClient {
     /// - Basic metadata (title, body, state)
     /// - Author and assignee information
     /// - Labels and milestone data
-    /// - Discussion comments
+    /// - All discussion comments (every page is fetched)
     /// - Creation and update timestamps
     ///
     /// # Arguments
@@ -35,11 +43,41 @@ impl GitHubClient {
         &self,
         repository_id: &RepositoryId,
         issue_number: IssueNumber,
+    ) -> Result<Issue> {
+        self.get_issue_with_comment_limit(repository_id, issue_number, None)
+            .await
+    }
+
+    /// Get an issue with at most `comment_limit` of its comments
+    ///
+    /// Same as `get_issue`, but stops fetching comment pages once the limit is
+    /// reached. Useful for issues with very long discussions.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `issue_number` - The issue number
+    /// * `comment_limit` - Maximum number of comments to load (oldest first); `None` loads all
+    ///
+    /// # Returns
+    /// A complete `Issue` struct with up to `comment_limit` comments
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - The issue number does not exist
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_issue_with_comment_limit(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+        comment_limit: Option<usize>,
     ) -> Result<Issue> {
         let operation_name = "get_issue";
 
         retry_with_backoff(operation_name, None, || async {
-            self.get_issue_impl(repository_id, issue_number).await
+            self.get_issue_impl(repository_id, issue_number, comment_limit)
+                .await
         })
         .await
     }
@@ -48,6 +86,7 @@ impl GitHubClient {
         &self,
         repository_id: &RepositoryId,
         issue_number: IssueNumber,
+        comment_limit: Option<usize>,
     ) -> std::result::Result<Issue, ApiRetryableError> {
         let owner = repository_id.owner().as_str();
         let repo = repository_id.repo_name().as_str();
@@ -61,69 +100,259 @@ impl GitHubClient {
             .map_err(ApiRetryableError::from_octocrab_error)?;
 
         // Get issue comments
-        let comments_response = self
-            .client
-            .issues(owner, repo)
-            .list_comments(number.into())
+        let comments: Vec<IssueComment> = self
+            .fetch_issue_comments(repository_id, number.into(), comment_limit)
+            .await?
+            .into_iter()
+            .map(from_octocrab_comment)
+            .collect();
+
+        Ok(from_octocrab_issue(repository_id, octocrab_issue, comments))
+    }
+
+    /// Fetch the comments of an issue or pull request across all pages
+    ///
+    /// Stops requesting further pages once `limit` comments have been loaded.
+    pub(crate) async fn fetch_issue_comments(
+        &self,
+        repository_id: &RepositoryId,
+        number: u64,
+        limit: Option<usize>,
+    ) -> std::result::Result<Vec<octocrab::models::issues::Comment>, ApiRetryableError> {
+        let issues_handler = self.client.issues(
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+        );
+
+        let mut comments = Vec::new();
+        let mut pagination = Pagination::new(None, Some(MAX_PER_PAGE));
+
+        loop {
+            let response = issues_handler
+                .list_comments(number)
+                .per_page(pagination.per_page)
+                .page(pagination.page)
+                .send()
+                .await
+                .map_err(ApiRetryableError::from_octocrab_error)?;
+
+            let has_next = response.next.is_some();
+            comments.extend(response.items);
+
+            if let Some(limit) = limit {
+                if comments.len() >= limit {
+                    comments.truncate(limit);
+                    break;
+                }
+            }
+            if !has_next {
+                break;
+            }
+            pagination = pagination.next();
+        }
+
+        Ok(comments)
+    }
+
+    /// List comments on an issue
+    ///
+    /// Retrieves one page of comments, oldest first, optionally limited to
+    /// comments updated since a given time and to a single author. GitHub has
+    /// no server-side author filter, so author filtering is applied to the
+    /// fetched page and a page may hold fewer items than requested.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `issue_number` - The issue number
+    /// * `since` - Only comments updated at or after this time
+    /// * `author` - Only comments written by this login (case-insensitive)
+    /// * `pagination` - The page of comments to fetch
+    ///
+    /// # Returns
+    /// A page of `IssueComment`
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - The issue number does not exist
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_issue_comments(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+        since: Option<DateTime<Utc>>,
+        author: Option<&str>,
+        pagination: Pagination,
+    ) -> Result<Page<IssueComment>> {
+        let operation_name = "list_issue_comments";
+
+        retry_with_backoff(operation_name, None, || async {
+            let page = self
+                .list_comments_page(
+                    repository_id,
+                    issue_number.value().into(),
+                    since,
+                    author,
+                    pagination,
+                )
+                .await?;
+            Ok(Page::new(
+                page.items.into_iter().map(from_octocrab_comment).collect(),
+                pagination,
+                page.has_next_page,
+            ))
+        })
+        .await
+    }
+
+    /// Fetch one page of issue or pull request comments with `since` and author filters
+    pub(crate) async fn list_comments_page(
+        &self,
+        repository_id: &RepositoryId,
+        number: u64,
+        since: Option<DateTime<Utc>>,
+        author: Option<&str>,
+        pagination: Pagination,
+    ) -> std::result::Result<Page<octocrab::models::issues::Comment>, ApiRetryableError> {
+        let issues_handler = self.client.issues(
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+        );
+
+        let mut builder = issues_handler
+            .list_comments(number)
+            .per_page(pagination.per_page)
+            .page(pagination.page);
+        if let Some(since) = since {
+            builder = builder.since(since);
+        }
+
+        let response = builder
             .send()
             .await
             .map_err(ApiRetryableError::from_octocrab_error)?;
 
-        let comments: Vec<IssueComment> = comments_response
+        let has_next = response.next.is_some();
+        let comments = response
             .items
             .into_iter()
-            .map(|comment| {
-                IssueComment::new(
-                    IssueCommentNumber::new(comment.id.0),
-                    comment.body.unwrap_or_default(),
-                    Some(User::new(
-                        comment.user.login,
-                        Some(comment.user.avatar_url.to_string()),
-                    )),
-                    comment.created_at,
-                    comment.updated_at.unwrap_or(comment.created_at),
-                )
+            .filter(|comment| {
+                author.is_none_or(|author| comment.user.login.eq_ignore_ascii_case(author))
             })
             .collect();
 
-        // Convert octocrab issue state to our state enum
-        let state = match octocrab_issue.state {
-            octocrab::models::IssueState::Open => IssueState::Open,
-            octocrab::models::IssueState::Closed => IssueState::Closed,
-            _ => IssueState::Closed,
+        Ok(Page::new(comments, pagination, has_next))
+    }
+
+    /// List issues in a repository
+    ///
+    /// Retrieves one page of issues matching the given filters so that issues
+    /// can be triaged without knowing their numbers in advance. Pull requests,
+    /// which the GitHub issues endpoint also returns, are excluded, so a page
+    /// may hold fewer items than requested. Comments are not fetched for
+    /// listed issues; use `get_issue` for the full discussion.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `state` - Optional state filter; `None` lists both open and closed issues
+    /// * `labels` - Label names that every returned issue must have
+    /// * `assignee` - Optional assignee login (`none` for unassigned, `*` for any assignee)
+    /// * `milestone` - Optional milestone the issues must belong to
+    /// * `since` - Only issues updated at or after this time
+    /// * `pagination` - The page of issues to fetch
+    ///
+    /// # Returns
+    /// A page of `Issue` ordered by creation time, newest first
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - The milestone does not exist
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_issues(
+        &self,
+        repository_id: &RepositoryId,
+        state: Option<IssueState>,
+        labels: &[String],
+        assignee: Option<&str>,
+        milestone: Option<MilestoneNumber>,
+        since: Option<DateTime<Utc>>,
+        pagination: Pagination,
+    ) -> Result<Page<Issue>> {
+        let operation_name = "list_issues";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.list_issues_impl(
+                repository_id,
+                state,
+                labels,
+                assignee,
+                milestone,
+                since,
+                pagination,
+            )
+            .await
+        })
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn list_issues_impl(
+        &self,
+        repository_id: &RepositoryId,
+        state: Option<IssueState>,
+        labels: &[String],
+        assignee: Option<&str>,
+        milestone: Option<MilestoneNumber>,
+        since: Option<DateTime<Utc>>,
+        pagination: Pagination,
+    ) -> std::result::Result<Page<Issue>, ApiRetryableError> {
+        let owner = repository_id.owner().as_str();
+        let repo = repository_id.repo_name().as_str();
+        let issues_handler = self.client.issues(owner, repo);
+
+        let state = match state {
+            Some(IssueState::Open) => octocrab::params::State::Open,
+            Some(IssueState::Closed) => octocrab::params::State::Closed,
+            None => octocrab::params::State::All,
         };
 
-        // Convert labels to strings
-        let labels: Vec<String> = octocrab_issue
-            .labels
-            .into_iter()
-            .map(|label| label.name)
-            .collect();
+        let mut builder = issues_handler
+            .list()
+            .state(state)
+            .per_page(pagination.per_page)
+            .page(pagination.page);
 
-        // Convert assignees to strings
-        let assignees: Vec<String> = octocrab_issue
-            .assignees
+        if !labels.is_empty() {
+            builder = builder.labels(labels);
+        }
+        if let Some(assignee) = assignee {
+            builder = builder.assignee(assignee);
+        }
+        if let Some(milestone) = milestone {
+            builder = builder.milestone(milestone.value());
+        }
+        if let Some(since) = since {
+            builder = builder.since(since);
+        }
+
+        let response = builder
+            .send()
+            .await
+            .map_err(ApiRetryableError::from_octocrab_error)?;
+
+        let has_next = response.next.is_some();
+        let issues = response
+            .items
             .into_iter()
-            .map(|user| user.login)
+            .filter(|issue| issue.pull_request.is_none())
+            .map(|issue| from_octocrab_issue(repository_id, issue, Vec::new()))
             .collect();
 
-        let issue = Issue::new(
-            IssueId::new(repository_id.clone(), number),
-            octocrab_issue.title,
-            octocrab_issue.body,
-            state,
-            octocrab_issue.user.login,
-            assignees,
-            labels,
-            octocrab_issue.created_at,
-            octocrab_issue.updated_at,
-            octocrab_issue.closed_at,
-            comments,
-            octocrab_issue.milestone.map(|m| m.id.0),
-            octocrab_issue.locked,
-        );
-
-        Ok(issue)
+        Ok(Page::new(issues, pagination, has_next))
     }
 
     /// Create a new issue in a repository
@@ -596,18 +825,21 @@ impl GitHubClient {
     ///
     /// Changes the state of an existing issue to either open or closed.
     /// This is a focused method for just changing the issue state without
-    /// affecting other properties.
+    /// affecting other properties. An optional state reason records why the
+    /// issue was closed (completed / not planned) or that it was reopened.
     ///
     /// # Arguments
     /// * `repository_id` - The repository identifier containing owner and repo name
     /// * `issue_number` - The issue number to update
     /// * `state` - The new state for the issue (open or closed)
+    /// * `state_reason` - Optional reason for the state change
     ///
     /// # Returns
     /// Returns `Ok(())` if the issue state was successfully updated
     ///
     /// # Errors
     /// Returns an error if:
+    /// - The state reason does not match the target state
     /// - The repository does not exist or is not accessible
     /// - The issue number does not exist
     /// - The user does not have permission to edit the issue
@@ -618,11 +850,12 @@ impl GitHubClient {
         repository_id: &RepositoryId,
         issue_number: IssueNumber,
         state: IssueState,
+        state_reason: Option<IssueStateReason>,
     ) -> Result<()> {
         let operation_name = "update_issue_state";
 
         retry_with_backoff(operation_name, None, || async {
-            self.update_issue_state_impl(repository_id, issue_number, state)
+            self.update_issue_state_impl(repository_id, issue_number, state, state_reason)
                 .await
         })
         .await
@@ -633,21 +866,44 @@ impl GitHubClient {
         repository_id: &RepositoryId,
         issue_number: IssueNumber,
         state: IssueState,
+        state_reason: Option<IssueStateReason>,
     ) -> std::result::Result<(), ApiRetryableError> {
         let owner = repository_id.owner().as_str();
         let repo = repository_id.repo_name().as_str();
         let number = issue_number.value();
 
+        if let Some(reason) = state_reason {
+            if !reason.is_valid_for(state) {
+                return Err(ApiRetryableError::NonRetryable(format!(
+                    "State reason '{}' cannot be used with state '{}'",
+                    reason, state
+                )));
+            }
+        }
+
         // Convert our IssueState to octocrab's IssueState
         let octocrab_state = match state {
             IssueState::Open => octocrab::models::IssueState::Open,
             IssueState::Closed => octocrab::models::IssueState::Closed,
         };
 
-        self.client
-            .issues(owner, repo)
-            .update(number.into())
-            .state(octocrab_state)
+        let issues_handler = self.client.issues(owner, repo);
+        let mut builder = issues_handler.update(number.into()).state(octocrab_state);
+
+        if let Some(reason) = state_reason {
+            let octocrab_reason = match reason {
+                IssueStateReason::Completed => {
+                    octocrab::models::issues::IssueStateReason::Completed
+                }
+                IssueStateReason::NotPlanned => {
+                    octocrab::models::issues::IssueStateReason::NotPlanned
+                }
+                IssueStateReason::Reopened => octocrab::models::issues::IssueStateReason::Reopened,
+            };
+            builder = builder.state_reason(octocrab_reason);
+        }
+
+        builder
             .send()
             .await
             .map_err(ApiRetryableError::from_octocrab_error)?;
@@ -683,6 +939,7 @@ impl GitHubClient {
     /// - The user does not have permission to edit the issue
     /// - API rate limits are exceeded (with automatic retry)
     /// - Network errors occur (with automatic retry)
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_issue(
         &self,
         repository_id: &RepositoryId,
@@ -712,6 +969,7 @@ impl GitHubClient {
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn update_issue_impl(
         &self,
         repository_id: &RepositoryId,
@@ -742,12 +1000,10 @@ impl GitHubClient {
         }
 
         // Update body if provided (including setting to None)
-        if let Some(body) = body {
-            if let Some(body_text) = body {
-                builder = builder.body(body_text);
-            }
-            // Note: octocrab doesn't support removing body (setting to None)
-            // If body is Some(None), we skip setting it
+        // Note: octocrab doesn't support removing body (setting to None)
+        // If body is Some(None), we skip setting it
+        if let Some(Some(body_text)) = body {
+            builder = builder.body(body_text);
         }
 
         // Update state if provided
@@ -770,13 +1026,10 @@ impl GitHubClient {
         }
 
         // Update milestone if provided (including removal)
-        if let Some(milestone_number) = milestone_number {
-            if let Some(milestone_number) = milestone_number {
-                builder = builder.milestone(milestone_number.value());
-            } else {
-                // To remove milestone, we need to set it to null
-                // This requires a different approach using the raw API
-            }
+        // To remove milestone (Some(None)), we would need to set it to null,
+        // which requires a different approach using the raw API
+        if let Some(Some(milestone_number)) = milestone_number {
+            builder = builder.milestone(milestone_number.value());
         }
 
         let _octocrab_issue = builder
@@ -785,7 +1038,7 @@ impl GitHubClient {
             .map_err(ApiRetryableError::from_octocrab_error)?;
 
         // Get updated issue with comments to return complete data
-        self.get_issue_impl(repository_id, issue_number).await
+        self.get_issue_impl(repository_id, issue_number, None).await
     }
 
     /// Add labels to an issue
@@ -846,6 +1099,62 @@ impl GitHubClient {
         Ok(())
     }
 
+    /// Replace all labels of an issue
+    ///
+    /// Sets the labels of an existing issue or pull request to exactly the
+    /// given labels. An empty slice removes all labels.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `issue_number` - The issue (or pull request) number to set labels on
+    /// * `labels` - Array of label names the issue should have
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the labels were successfully replaced
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - The issue number does not exist
+    /// - The user does not have permission to edit the issue
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn replace_issue_labels(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+        labels: &[Label],
+    ) -> Result<()> {
+        let operation_name = "replace_issue_labels";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.replace_issue_labels_impl(repository_id, issue_number, labels)
+                .await
+        })
+        .await
+    }
+
+    async fn replace_issue_labels_impl(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+        labels: &[Label],
+    ) -> std::result::Result<(), ApiRetryableError> {
+        let owner = repository_id.owner().as_str();
+        let repo = repository_id.repo_name().as_str();
+        let number = issue_number.value();
+
+        let label_names: Vec<String> = labels.iter().map(|l| l.name().to_string()).collect();
+
+        self.client
+            .issues(owner, repo)
+            .replace_all_labels(number.into(), &label_names)
+            .await
+            .map_err(ApiRetryableError::from_octocrab_error)?;
+
+        Ok(())
+    }
+
     /// Set milestone for an issue
     ///
     /// Sets or updates the milestone for an existing issue. This operation replaces
@@ -947,45 +1256,28 @@ impl GitHubClient {
         let number = issue_number.value();
 
         // Use GraphQL to remove milestone by setting it to null
-        let mutation = format!(
-            r#"
-            mutation {{
-                updateIssue(input: {{
-                    id: "{}"
-                    milestoneId: null
-                }}) {{
+        let issue_node_id = self.get_issue_node_id(repository_id, issue_number).await?;
+        let mutation = r#"
+            mutation($issueId: ID!) {
+                updateIssue(input: { id: $issueId, milestoneId: null }) {
                     clientMutationId
-                }}
-            }}
-            "#,
-            self.get_issue_node_id(repository_id, issue_number).await?
-        );
+                }
+            }
+        "#;
 
-        let response = self
-            .client
-            .graphql::<serde_json::Value>(&serde_json::json!({
-                "query": mutation
-            }))
-            .await
-            .map_err(ApiRetryableError::from_octocrab_error)?;
+        self.execute_graphql::<IgnoredAny>(
+            mutation,
+            serde_json::json!({ "issueId": issue_node_id }),
+        )
+        .await
+        .map_err(|e| {
+            e.with_context(&format!(
+                "Failed to remove milestone from issue {}/{}/{}",
+                owner, repo, number
+            ))
+        })?;
 
-        // Check if the mutation was successful
-        if response.get("data").is_some() && response.get("errors").is_none() {
-            Ok(())
-        } else {
-            let error_msg = response
-                .get("errors")
-                .and_then(|errors| errors.as_array())
-                .and_then(|arr| arr.first())
-                .and_then(|error| error.get("message"))
-                .and_then(|msg| msg.as_str())
-                .unwrap_or("Unknown GraphQL error");
-
-            Err(ApiRetryableError::NonRetryable(format!(
-                "Failed to remove milestone from issue {}/{}/{}: {}",
-                owner, repo, number, error_msg
-            )))
-        }
+        Ok(())
     }
 
     /// Helper function to get issue node ID for GraphQL operations
@@ -1008,6 +1300,418 @@ impl GitHubClient {
         Ok(octocrab_issue.node_id)
     }
 
+    /// Get the timeline events of an issue
+    ///
+    /// Retrieves one page of the issue timeline, which records everything that
+    /// happened to the issue: label and assignee changes, milestone changes,
+    /// renames, cross-references from other issues and pull requests, commit
+    /// references, comments, and state changes. Pull requests share the issue
+    /// timeline, so pull request numbers are accepted as well.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `issue_number` - The issue or pull request number
+    /// * `pagination` - The page of events to fetch
+    ///
+    /// # Returns
+    /// A page of `IssueTimelineEvent` in chronological order
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - The issue number does not exist
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_issue_timeline(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+        pagination: Pagination,
+    ) -> Result<Page<IssueTimelineEvent>> {
+        let operation_name = "get_issue_timeline";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.get_issue_timeline_impl(repository_id, issue_number, pagination)
+                .await
+        })
+        .await
+    }
+
+    pub(crate) async fn get_issue_timeline_impl(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+        pagination: Pagination,
+    ) -> std::result::Result<Page<IssueTimelineEvent>, ApiRetryableError> {
+        let url = self.issue_timeline_url(repository_id, issue_number, pagination);
+        let response = self
+            .send_rest_request(reqwest::Method::GET, &url, None)
+            .await?;
+        parse_timeline_page(response, pagination).await
+    }
+
+    /// Get a page of the timeline events of an issue if it changed
+    ///
+    /// Sends a conditional request with the ETag of the page fetched before,
+    /// so polling an unchanged timeline does not count against the rate limit.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `issue_number` - The issue or pull request number
+    /// * `pagination` - The page of events to fetch
+    /// * `etag` - ETag of the page fetched before, if any
+    ///
+    /// # Returns
+    /// The page with its ETag, or `Conditional::NotModified` if it still has `etag`
+    ///
+    /// # Errors
+    /// Returns the same errors as `get_issue_timeline`
+    pub async fn get_issue_timeline_if_changed(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+        pagination: Pagination,
+        etag: Option<&str>,
+    ) -> Result<Conditional<Page<IssueTimelineEvent>>> {
+        let operation_name = "get_issue_timeline_if_changed";
+
+        retry_with_backoff(operation_name, None, || async {
+            let url = self.issue_timeline_url(repository_id, issue_number, pagination);
+            match self.send_conditional_request(&url, etag).await? {
+                Conditional::Modified { value, etag } => Ok(Conditional::Modified {
+                    value: parse_timeline_page(value, pagination).await?,
+                    etag,
+                }),
+                Conditional::NotModified => Ok(Conditional::NotModified),
+            }
+        })
+        .await
+    }
+
+    fn issue_timeline_url(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+        pagination: Pagination,
+    ) -> String {
+        format!(
+            "{}/repos/{}/{}/issues/{}/timeline?per_page={}&page={}",
+            self.api_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+            issue_number.value(),
+            pagination.per_page,
+            pagination.page
+        )
+    }
+
+    /// List the issues and pull requests that reference an issue or pull request
+    ///
+    /// Reads every cross-referenced event of the item's timeline, including
+    /// references from other repositories. References from items the token
+    /// cannot read are omitted.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `issue_number` - The issue or pull request number
+    ///
+    /// # Returns
+    /// One `CrossReference` per cross-referenced event in chronological order
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - The issue or pull request does not exist
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_cross_references(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+    ) -> Result<Vec<CrossReference>> {
+        let mut references = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let operation_name = "list_cross_references";
+            let (page, next_cursor) = retry_with_backoff(operation_name, None, || async {
+                self.list_cross_references_page_impl(repository_id, issue_number, cursor.as_deref())
+                    .await
+            })
+            .await?;
+
+            references.extend(page);
+            match next_cursor {
+                Some(next_cursor) => cursor = Some(next_cursor),
+                None => break,
+            }
+        }
+
+        Ok(references)
+    }
+
+    async fn list_cross_references_page_impl(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+        cursor: Option<&str>,
+    ) -> std::result::Result<(Vec<CrossReference>, Option<String>), ApiRetryableError> {
+        let query = r#"
+            fragment CrossReference on CrossReferencedEvent {
+                actor { login }
+                referencedAt
+                isCrossRepository
+                willCloseTarget
+                source {
+                    __typename
+                    ... on Issue {
+                        number
+                        title
+                        url
+                        state
+                        repository { nameWithOwner }
+                    }
+                    ... on PullRequest {
+                        number
+                        title
+                        url
+                        state
+                        repository { nameWithOwner }
+                    }
+                }
+            }
+
+            query($owner: String!, $name: String!, $number: Int!, $cursor: String) {
+                repository(owner: $owner, name: $name) {
+                    issueOrPullRequest(number: $number) {
+                        ... on Issue {
+                            timelineItems(first: 100, after: $cursor, itemTypes: [CROSS_REFERENCED_EVENT]) {
+                                nodes { ...CrossReference }
+                                pageInfo { hasNextPage endCursor }
+                            }
+                        }
+                        ... on PullRequest {
+                            timelineItems(first: 100, after: $cursor, itemTypes: [CROSS_REFERENCED_EVENT]) {
+                                nodes { ...CrossReference }
+                                pageInfo { hasNextPage endCursor }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let data: CrossReferencesData = self
+            .execute_graphql(
+                query,
+                serde_json::json!({
+                    "owner": repository_id.owner().as_str(),
+                    "name": repository_id.repo_name().as_str(),
+                    "number": issue_number.value(),
+                    "cursor": cursor,
+                }),
+            )
+            .await
+            .map_err(|e| {
+                e.with_context(&format!(
+                    "Failed to list cross-references of #{} in {}",
+                    issue_number, repository_id
+                ))
+            })?;
+
+        let connection = data
+            .repository
+            .and_then(|repository| repository.issue_or_pull_request)
+            .map(|item| item.timeline_items)
+            .ok_or_else(|| {
+                ApiRetryableError::NonRetryable(format!(
+                    "Issue or pull request #{} not found in {}",
+                    issue_number, repository_id
+                ))
+            })?;
+
+        let next_cursor = connection.next_cursor();
+        let references = connection
+            .into_nodes()
+            .filter_map(|node| CrossReference::from_graphql_value(&node))
+            .collect();
+
+        Ok((references, next_cursor))
+    }
+
+    /// List the issue types defined by an organization
+    ///
+    /// Issue types (e.g. Bug, Feature, Task) are configured at the organization
+    /// level and shared by all repositories of the organization.
+    ///
+    /// # Arguments
+    /// * `owner` - The organization login
+    ///
+    /// # Returns
+    /// All issue types of the organization, including disabled ones
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The organization does not exist or is not accessible
+    /// - The owner is a user account (issue types are organization-only)
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_issue_types(&self, owner: &Owner) -> Result<Vec<IssueType>> {
+        let operation_name = "list_issue_types";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.list_issue_types_impl(owner).await
+        })
+        .await
+    }
+
+    async fn list_issue_types_impl(
+        &self,
+        owner: &Owner,
+    ) -> std::result::Result<Vec<IssueType>, ApiRetryableError> {
+        let query = r#"
+            query($owner: String!) {
+                organization(login: $owner) {
+                    issueTypes(first: 100) {
+                        nodes {
+                            id
+                            name
+                            description
+                            color
+                            isEnabled
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let data: IssueTypesData = self
+            .execute_graphql(query, serde_json::json!({ "owner": owner.as_str() }))
+            .await
+            .map_err(|e| e.with_context(&format!("Failed to list issue types of {}", owner)))?;
+
+        let issue_types = data
+            .organization
+            .ok_or_else(|| {
+                ApiRetryableError::NonRetryable(format!(
+                    "Organization {} not found or has no issue types",
+                    owner
+                ))
+            })?
+            .issue_types
+            .into_nodes()
+            .map(|node| IssueType {
+                id: IssueTypeId::new(node.id),
+                name: node.name,
+                description: node.description,
+                color: node.color,
+                is_enabled: node.is_enabled.unwrap_or(true),
+            })
+            .collect();
+
+        Ok(issue_types)
+    }
+
+    /// Set or clear the issue type of an issue
+    ///
+    /// Resolves the issue type by name (case-insensitive) among the issue types
+    /// of the repository owner organization and assigns it to the issue.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `issue_number` - The issue number to classify
+    /// * `issue_type` - The issue type name (e.g. "Bug"), or `None` to clear the type
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the issue type was successfully updated
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - The issue number does not exist
+    /// - No enabled issue type with the given name exists in the organization
+    /// - The user does not have permission to edit the issue
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn set_issue_type(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+        issue_type: Option<&str>,
+    ) -> Result<()> {
+        let operation_name = "set_issue_type";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.set_issue_type_impl(repository_id, issue_number, issue_type)
+                .await
+        })
+        .await
+    }
+
+    async fn set_issue_type_impl(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+        issue_type: Option<&str>,
+    ) -> std::result::Result<(), ApiRetryableError> {
+        let owner = repository_id.owner().as_str();
+        let repo = repository_id.repo_name().as_str();
+        let number = issue_number.value();
+
+        let issue_type_id = match issue_type {
+            Some(name) => {
+                let issue_types = self.list_issue_types_impl(repository_id.owner()).await?;
+                let found = IssueType::find_by_name(&issue_types, name)
+                    .filter(|issue_type| issue_type.is_enabled)
+                    .ok_or_else(|| {
+                        let available: Vec<&str> = issue_types
+                            .iter()
+                            .filter(|issue_type| issue_type.is_enabled)
+                            .map(|issue_type| issue_type.name.as_str())
+                            .collect();
+                        ApiRetryableError::NonRetryable(format!(
+                            "Issue type '{}' not found in {}. Available: {}",
+                            name,
+                            owner,
+                            available.join(", ")
+                        ))
+                    })?;
+                Some(found.id.clone())
+            }
+            None => None,
+        };
+
+        let issue_node_id = self.get_issue_node_id(repository_id, issue_number).await?;
+
+        let mutation = r#"
+            mutation($issueId: ID!, $issueTypeId: ID) {
+                updateIssueIssueType(input: {
+                    issueId: $issueId
+                    issueTypeId: $issueTypeId
+                }) {
+                    clientMutationId
+                }
+            }
+        "#;
+
+        self.execute_graphql::<IgnoredAny>(
+            mutation,
+            serde_json::json!({
+                "issueId": issue_node_id,
+                "issueTypeId": issue_type_id.as_ref().map(|id| id.value()),
+            }),
+        )
+        .await
+        .map_err(|e| {
+            e.with_context(&format!(
+                "Failed to set issue type of {}/{}/{}",
+                owner, repo, number
+            ))
+        })?;
+
+        Ok(())
+    }
+
     /// Delete an issue
     ///
     /// Deletes an existing issue from the specified repository.
@@ -1061,44 +1765,161 @@ impl GitHubClient {
         let node_id = octocrab_issue.node_id;
 
         // Use GraphQL mutation to delete the issue
-        let mutation = format!(
-            r#"
-            mutation {{
-                deleteIssue(input: {{
-                    issueId: "{}"
-                }}) {{
+        let mutation = r#"
+            mutation($issueId: ID!) {
+                deleteIssue(input: { issueId: $issueId }) {
                     clientMutationId
-                }}
-            }}
-            "#,
-            node_id
-        );
+                }
+            }
+        "#;
 
-        // Execute GraphQL mutation
-        let response = self
-            .client
-            .graphql::<serde_json::Value>(&serde_json::json!({
-                "query": mutation
-            }))
+        self.execute_graphql::<IgnoredAny>(mutation, serde_json::json!({ "issueId": node_id }))
             .await
-            .map_err(ApiRetryableError::from_octocrab_error)?;
+            .map_err(|e| {
+                e.with_context(&format!(
+                    "Failed to delete issue {}/{}/{}",
+                    owner, repo, number
+                ))
+            })?;
 
-        // Check if the mutation was successful
-        if response.get("data").is_some() && response.get("errors").is_none() {
-            Ok(())
-        } else {
-            let error_msg = response
-                .get("errors")
-                .and_then(|errors| errors.as_array())
-                .and_then(|arr| arr.first())
-                .and_then(|error| error.get("message"))
-                .and_then(|msg| msg.as_str())
-                .unwrap_or("Unknown GraphQL error");
-
-            Err(ApiRetryableError::NonRetryable(format!(
-                "Failed to delete issue {}/{}/{}: {}",
-                owner, repo, number, error_msg
-            )))
+        Ok(())
+    }
+}
+
+/// Data of the cross-references query
+#[derive(Deserialize)]
+struct CrossReferencesData {
+    repository: Option<CrossReferencesRepository>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CrossReferencesRepository {
+    issue_or_pull_request: Option<TimelineItemsNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TimelineItemsNode {
+    timeline_items: Connection<serde_json::Value>,
+}
+
+/// Data of the issue types query
+#[derive(Deserialize)]
+struct IssueTypesData {
+    organization: Option<IssueTypesOrganization>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IssueTypesOrganization {
+    issue_types: Connection<IssueTypeNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IssueTypeNode {
+    id: String,
+    name: String,
+    description: Option<String>,
+    color: Option<String>,
+    is_enabled: Option<bool>,
+}
+
+fn from_octocrab_issue(
+    repository_id: &RepositoryId,
+    octocrab_issue: octocrab::models::issues::Issue,
+    comments: Vec<IssueComment>,
+) -> Issue {
+    // Convert octocrab issue state to our state enum
+    let state = match octocrab_issue.state {
+        octocrab::models::IssueState::Open => IssueState::Open,
+        octocrab::models::IssueState::Closed => IssueState::Closed,
+        _ => IssueState::Closed,
+    };
+
+    // Convert labels to strings
+    let labels: Vec<String> = octocrab_issue
+        .labels
+        .into_iter()
+        .map(|label| label.name)
+        .collect();
+
+    // Convert assignees to strings
+    let assignees: Vec<String> = octocrab_issue
+        .assignees
+        .into_iter()
+        .map(|user| user.login)
+        .collect();
+
+    Issue::new(
+        IssueId::new(repository_id.clone(), octocrab_issue.number as u32),
+        octocrab_issue.title,
+        octocrab_issue.body,
+        state,
+        octocrab_issue.user.login,
+        assignees,
+        labels,
+        octocrab_issue.created_at,
+        octocrab_issue.updated_at,
+        octocrab_issue.closed_at,
+        comments,
+        octocrab_issue.milestone.map(|m| m.id.0),
+        octocrab_issue.locked,
+    )
+    .with_state_reason(
+        octocrab_issue
+            .state_reason
+            .and_then(from_octocrab_state_reason),
+    )
+}
+
+/// Returns the state reason of an octocrab issue, or `None` for reasons that
+/// can't be set through this crate, such as `duplicate`
+fn from_octocrab_state_reason(
+    reason: octocrab::models::issues::IssueStateReason,
+) -> Option<IssueStateReason> {
+    match reason {
+        octocrab::models::issues::IssueStateReason::Completed => Some(IssueStateReason::Completed),
+        octocrab::models::issues::IssueStateReason::NotPlanned => {
+            Some(IssueStateReason::NotPlanned)
         }
+        octocrab::models::issues::IssueStateReason::Reopened => Some(IssueStateReason::Reopened),
+        _ => None,
     }
 }
+
+fn from_octocrab_comment(comment: octocrab::models::issues::Comment) -> IssueComment {
+    IssueComment::new(
+        IssueCommentNumber::new(comment.id.0),
+        comment.body.unwrap_or_default(),
+        Some(User::new(
+            comment.user.login,
+            Some(comment.user.avatar_url.to_string()),
+        )),
+        comment.created_at,
+        comment.updated_at.unwrap_or(comment.created_at),
+    )
+}
+
+/// Parse a page of timeline events from a REST API response
+async fn parse_timeline_page(
+    response: reqwest::Response,
+    pagination: Pagination,
+) -> std::result::Result<Page<IssueTimelineEvent>, ApiRetryableError> {
+    let has_next = has_next_page(&response);
+
+    // Timeline events have many event-specific shapes; parse them from raw JSON
+    // so that unknown event types do not fail the whole request
+    let entries: Vec<serde_json::Value> = response
+        .json()
+        .await
+        .map_err(|e| ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e)))?;
+
+    let events = entries
+        .iter()
+        .map(IssueTimelineEvent::from_api_value)
+        .collect();
+
+    Ok(Page::new(events, pagination, has_next))
+}
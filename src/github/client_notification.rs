@@ -0,0 +1,430 @@
+use crate::github::client::{GitHubClient, has_next_page, retry_with_backoff};
+use crate::github::error::ApiRetryableError;
+use crate::types::issue::IssueNumber;
+use crate::types::notification::{
+    IssueSubscription, Notification, NotificationFilter, NotificationThreadId, SubscriptionState,
+    ThreadSubscription,
+};
+use crate::types::pagination::{Page, Pagination};
+use crate::types::repository::RepositoryId;
+
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::json;
+
+impl GitHubClient {
+    /// List notifications of the authenticated user, most recently updated first
+    ///
+    /// # Arguments
+    /// * `repository_id` - Optional repository to restrict the notifications to
+    /// * `filter` - Read state, participation and time range to restrict the notifications to
+    /// * `pagination` - The page of notifications to fetch
+    ///
+    /// # Returns
+    /// A page of `Notification`s
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The token cannot read notifications (classic tokens need the `notifications` scope)
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_notifications(
+        &self,
+        repository_id: Option<&RepositoryId>,
+        filter: &NotificationFilter,
+        pagination: Pagination,
+    ) -> Result<Page<Notification>> {
+        let operation_name = "list_notifications";
+
+        let base_url = match repository_id {
+            Some(repository_id) => format!(
+                "{}/repos/{}/{}/notifications",
+                self.api_base_url,
+                repository_id.owner().as_str(),
+                repository_id.repo_name().as_str()
+            ),
+            None => format!("{}/notifications", self.api_base_url),
+        };
+        let url = format!("{}?{}", base_url, filter.to_query(pagination));
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::GET, &url, None)
+                .await?;
+            let has_next = has_next_page(&response);
+
+            let notifications: Vec<Notification> = response.json().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })?;
+
+            Ok(Page::new(notifications, pagination, has_next))
+        })
+        .await
+    }
+
+    /// Mark a notification thread as read
+    ///
+    /// # Arguments
+    /// * `thread_id` - The notification thread
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The thread does not exist or the token cannot manage notifications
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn mark_notification_read(&self, thread_id: &NotificationThreadId) -> Result<()> {
+        let operation_name = "mark_notification_read";
+        let url = self.thread_url(thread_id, "");
+
+        retry_with_backoff(operation_name, None, || async {
+            self.send_rest_request(reqwest::Method::PATCH, &url, None)
+                .await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Mark a notification thread as done, removing it from the inbox
+    ///
+    /// # Arguments
+    /// * `thread_id` - The notification thread
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The thread does not exist or the token cannot manage notifications
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn mark_thread_done(&self, thread_id: &NotificationThreadId) -> Result<()> {
+        let operation_name = "mark_thread_done";
+        let url = self.thread_url(thread_id, "");
+
+        retry_with_backoff(operation_name, None, || async {
+            self.send_rest_request(reqwest::Method::DELETE, &url, None)
+                .await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Get the subscription of the authenticated user to a notification thread
+    ///
+    /// # Arguments
+    /// * `thread_id` - The notification thread
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The thread does not exist or the user is not subscribed to it
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_thread_subscription(
+        &self,
+        thread_id: &NotificationThreadId,
+    ) -> Result<ThreadSubscription> {
+        let operation_name = "get_thread_subscription";
+        let url = self.thread_url(thread_id, "/subscription");
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::GET, &url, None)
+                .await?;
+            response.json::<ThreadSubscription>().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })
+        })
+        .await
+    }
+
+    /// Subscribe to a notification thread
+    ///
+    /// Also unmutes a thread that was previously ignored.
+    ///
+    /// # Arguments
+    /// * `thread_id` - The notification thread
+    ///
+    /// # Returns
+    /// The resulting `ThreadSubscription`
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The thread does not exist or the token cannot manage notifications
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn subscribe_thread(
+        &self,
+        thread_id: &NotificationThreadId,
+    ) -> Result<ThreadSubscription> {
+        let operation_name = "subscribe_thread";
+        let url = self.thread_url(thread_id, "/subscription");
+        let request_body = json!({ "ignored": false });
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::PUT, &url, Some(&request_body))
+                .await?;
+            response.json::<ThreadSubscription>().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })
+        })
+        .await
+    }
+
+    /// Unsubscribe from a notification thread
+    ///
+    /// Notifications resume if the user is mentioned or comments on the
+    /// thread again.
+    ///
+    /// # Arguments
+    /// * `thread_id` - The notification thread
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The thread does not exist or the token cannot manage notifications
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn unsubscribe_thread(&self, thread_id: &NotificationThreadId) -> Result<()> {
+        let operation_name = "unsubscribe_thread";
+        let url = self.thread_url(thread_id, "/subscription");
+
+        retry_with_backoff(operation_name, None, || async {
+            self.send_rest_request(reqwest::Method::DELETE, &url, None)
+                .await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Get the subscription of the authenticated user to an issue or pull request
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `issue_number` - The issue or pull request number
+    ///
+    /// # Returns
+    /// The current `IssueSubscription`
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository, issue or pull request does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_issue_subscription(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+    ) -> Result<IssueSubscription> {
+        let operation_name = "get_issue_subscription";
+
+        retry_with_backoff(operation_name, None, || async {
+            let (_, subscription) = self.get_subscribable(repository_id, issue_number).await?;
+            Ok(subscription)
+        })
+        .await
+    }
+
+    /// Subscribe to all conversation on an issue or pull request
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `issue_number` - The issue or pull request number
+    ///
+    /// # Returns
+    /// The resulting `IssueSubscription`
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository, issue or pull request does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn subscribe_to_issue(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+    ) -> Result<IssueSubscription> {
+        let operation_name = "subscribe_to_issue";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.update_issue_subscription(
+                repository_id,
+                issue_number,
+                SubscriptionState::Subscribed,
+            )
+            .await
+        })
+        .await
+    }
+
+    /// Unsubscribe from an issue or pull request
+    ///
+    /// The user is still notified when participating or mentioned.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `issue_number` - The issue or pull request number
+    ///
+    /// # Returns
+    /// The resulting `IssueSubscription`
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository, issue or pull request does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn unsubscribe_from_issue(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+    ) -> Result<IssueSubscription> {
+        let operation_name = "unsubscribe_from_issue";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.update_issue_subscription(
+                repository_id,
+                issue_number,
+                SubscriptionState::Unsubscribed,
+            )
+            .await
+        })
+        .await
+    }
+
+    async fn update_issue_subscription(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+        state: SubscriptionState,
+    ) -> std::result::Result<IssueSubscription, ApiRetryableError> {
+        let (subscribable_id, _) = self.get_subscribable(repository_id, issue_number).await?;
+
+        let mutation = r#"
+            mutation($id: ID!, $state: SubscriptionState!) {
+                updateSubscription(input: {subscribableId: $id, state: $state}) {
+                    subscribable {
+                        viewerSubscription
+                        viewerCanSubscribe
+                    }
+                }
+            }
+        "#;
+
+        let data: UpdateSubscriptionData = self
+            .execute_graphql(
+                mutation,
+                json!({ "id": subscribable_id, "state": state.graphql_value() }),
+            )
+            .await
+            .map_err(|e| {
+                e.with_context(&format!(
+                    "Failed to update subscription to #{} in {}",
+                    issue_number, repository_id
+                ))
+            })?;
+
+        data.update_subscription
+            .and_then(|payload| payload.subscribable)
+            .map(|subscribable| IssueSubscription::from_graphql_value(&subscribable))
+            .ok_or_else(|| {
+                ApiRetryableError::NonRetryable(
+                    "Update subscription response is missing the subscribable".to_string(),
+                )
+            })
+    }
+
+    /// Resolve the node ID and current subscription of an issue or pull request
+    async fn get_subscribable(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+    ) -> std::result::Result<(String, IssueSubscription), ApiRetryableError> {
+        let query = r#"
+            query($owner: String!, $name: String!, $number: Int!) {
+                repository(owner: $owner, name: $name) {
+                    issueOrPullRequest(number: $number) {
+                        ... on Issue {
+                            id
+                            viewerSubscription
+                            viewerCanSubscribe
+                        }
+                        ... on PullRequest {
+                            id
+                            viewerSubscription
+                            viewerCanSubscribe
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let data: SubscribableData = self
+            .execute_graphql(
+                query,
+                json!({
+                    "owner": repository_id.owner().as_str(),
+                    "name": repository_id.repo_name().as_str(),
+                    "number": issue_number.value(),
+                }),
+            )
+            .await
+            .map_err(|e| {
+                e.with_context(&format!(
+                    "Failed to resolve #{} in {}",
+                    issue_number, repository_id
+                ))
+            })?;
+
+        let item = data
+            .repository
+            .and_then(|repository| repository.issue_or_pull_request)
+            .ok_or_else(|| {
+                ApiRetryableError::NonRetryable(format!(
+                    "Issue or pull request #{} not found in {}",
+                    issue_number, repository_id
+                ))
+            })?;
+
+        Ok((
+            item.id,
+            IssueSubscription::from_graphql_value(&item.subscription),
+        ))
+    }
+}
+
+impl GitHubClient {
+    fn thread_url(&self, thread_id: &NotificationThreadId, suffix: &str) -> String {
+        format!(
+            "{}/notifications/threads/{}{}",
+            self.api_base_url,
+            urlencoding::encode(thread_id.value()),
+            suffix
+        )
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateSubscriptionData {
+    update_subscription: Option<UpdateSubscriptionPayload>,
+}
+
+#[derive(Deserialize)]
+struct UpdateSubscriptionPayload {
+    subscribable: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct SubscribableData {
+    repository: Option<SubscribableRepository>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SubscribableRepository {
+    issue_or_pull_request: Option<SubscribableNode>,
+}
+
+/// Issue or pull request with its node ID and `Subscribable` fields
+#[derive(Deserialize)]
+struct SubscribableNode {
+    id: String,
+    #[serde(flatten)]
+    subscription: serde_json::Value,
+}
@@ -1,200 +1,1831 @@
 use crate::github::client::{GitHubClient, retry_with_backoff};
 use crate::github::error::ApiRetryableError;
-use crate::types::project::{ProjectFieldValue, ProjectId};
+use crate::github::graphql::{Connection, NodeId};
+use crate::types::issue::IssueId;
+use crate::types::project::{
+    DraftIssueId, Project, ProjectCustomFieldType, ProjectDetails, ProjectDraftIssue, ProjectField,
+    ProjectFieldValue, ProjectId, ProjectItem, ProjectNumber, ProjectOriginalResource,
+    ProjectState, ProjectType, ProjectUpdate, ProjectVisibility,
+};
+use crate::types::pull_request::PullRequestId;
+use crate::types::repository::Owner;
 use crate::types::{
     IssueNumber, ProjectFieldId, ProjectItemId, ProjectNodeId, PullRequestNumber, RepositoryId,
 };
 
-use anyhow::Result;
-use serde_json::json;
+use anyhow::Result;
+use serde::Deserialize;
+use serde::de::IgnoredAny;
+use serde_json::json;
+
+impl GitHubClient {
+    /// Create a new GitHub Project v2
+    ///
+    /// Resolves the owner's GraphQL node ID and then creates the project
+    /// with `createProjectV2`.
+    ///
+    /// # Arguments
+    /// * `owner` - The user or organization that will own the project
+    /// * `title` - The project title
+    /// * `project_type` - Whether the owner is a user or an organization
+    ///
+    /// # Returns
+    /// Returns the created `Project`, including its number and node ID
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The owner does not exist or is not of the given type
+    /// - The user does not have permission to create projects for the owner
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn create_project(
+        &self,
+        owner: &Owner,
+        title: &str,
+        project_type: ProjectType,
+    ) -> Result<Project> {
+        let operation_name = "create_project";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.create_project_impl(owner, title, project_type).await
+        })
+        .await
+    }
+
+    async fn create_project_impl(
+        &self,
+        owner: &Owner,
+        title: &str,
+        project_type: ProjectType,
+    ) -> std::result::Result<Project, ApiRetryableError> {
+        let owner_node_id = self.get_owner_node_id(owner, project_type).await?;
+
+        let mutation = format!(
+            r#"
+            mutation($ownerId: ID!, $title: String!) {{
+                createProjectV2(input: {{ ownerId: $ownerId, title: $title }}) {{
+                    projectV2 {{ {} }}
+                }}
+            }}
+            "#,
+            PROJECT_V2_FIELDS
+        );
+
+        let data: CreateProjectData = self
+            .execute_graphql(
+                &mutation,
+                json!({ "ownerId": owner_node_id, "title": title }),
+            )
+            .await
+            .map_err(|e| e.with_context("Failed to create project"))?;
+
+        let project = data
+            .create_project_v2
+            .and_then(|payload| payload.project_v2)
+            .ok_or_else(|| {
+                ApiRetryableError::NonRetryable(
+                    "Failed to create project: no project in response".to_string(),
+                )
+            })?;
+
+        project_from_graphql(&project).ok_or_else(|| {
+            ApiRetryableError::NonRetryable(
+                "Failed to create project: unexpected response shape".to_string(),
+            )
+        })
+    }
+
+    /// Copy a GitHub Project v2, e.g. to instantiate a template project
+    ///
+    /// Fields, views, workflows and insights are copied with `copyProjectV2`.
+    /// Issues and pull requests are never copied; draft issues are copied
+    /// only when `include_draft_issues` is set.
+    ///
+    /// # Arguments
+    /// * `source_project_node_id` - The node ID of the project to copy
+    /// * `owner` - The user or organization that will own the copy
+    /// * `project_type` - Whether the new owner is a user or an organization
+    /// * `title` - The title of the copy
+    /// * `include_draft_issues` - Whether draft issues are copied as well
+    ///
+    /// # Returns
+    /// Returns the new `Project`, including its number and node ID
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The source project or owner does not exist or is not accessible
+    /// - The user does not have permission to create projects for the owner
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn copy_project(
+        &self,
+        source_project_node_id: &ProjectNodeId,
+        owner: &Owner,
+        project_type: ProjectType,
+        title: &str,
+        include_draft_issues: bool,
+    ) -> Result<Project> {
+        let operation_name = "copy_project";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.copy_project_impl(
+                source_project_node_id,
+                owner,
+                project_type,
+                title,
+                include_draft_issues,
+            )
+            .await
+        })
+        .await
+    }
+
+    async fn copy_project_impl(
+        &self,
+        source_project_node_id: &ProjectNodeId,
+        owner: &Owner,
+        project_type: ProjectType,
+        title: &str,
+        include_draft_issues: bool,
+    ) -> std::result::Result<Project, ApiRetryableError> {
+        let owner_node_id = self.get_owner_node_id(owner, project_type).await?;
+
+        let mutation = format!(
+            r#"
+            mutation($projectId: ID!, $ownerId: ID!, $title: String!, $includeDraftIssues: Boolean!) {{
+                copyProjectV2(input: {{
+                    projectId: $projectId,
+                    ownerId: $ownerId,
+                    title: $title,
+                    includeDraftIssues: $includeDraftIssues
+                }}) {{
+                    projectV2 {{ {} }}
+                }}
+            }}
+            "#,
+            PROJECT_V2_FIELDS
+        );
+
+        let data: CopyProjectData = self
+            .execute_graphql(
+                &mutation,
+                json!({
+                    "projectId": source_project_node_id.value(),
+                    "ownerId": owner_node_id,
+                    "title": title,
+                    "includeDraftIssues": include_draft_issues,
+                }),
+            )
+            .await
+            .map_err(|e| e.with_context("Failed to copy project"))?;
+
+        data.copy_project_v2
+            .and_then(|payload| payload.project_v2)
+            .and_then(|project| project_from_graphql(&project))
+            .ok_or_else(|| {
+                ApiRetryableError::NonRetryable(
+                    "Failed to copy project: unexpected response shape".to_string(),
+                )
+            })
+    }
+
+    /// Get a project with its settings, field definitions and item count
+    ///
+    /// Fetches the project metadata, the first page of fields and the total
+    /// item count in a single query; remaining fields are fetched only for
+    /// projects with more than 100 fields.
+    ///
+    /// # Arguments
+    /// * `project_id` - The project identifier (owner, number and type)
+    ///
+    /// # Returns
+    /// Returns the project details, including its node ID
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The project does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_project(&self, project_id: &ProjectId) -> Result<ProjectDetails> {
+        let operation_name = "get_project";
+
+        let (mut details, has_more_fields) = retry_with_backoff(operation_name, None, || async {
+            self.get_project_impl(project_id).await
+        })
+        .await?;
+
+        if has_more_fields {
+            details.fields = self
+                .list_project_fields(&details.project.project_node_id)
+                .await?;
+        }
+
+        Ok(details)
+    }
+
+    async fn get_project_impl(
+        &self,
+        project_id: &ProjectId,
+    ) -> std::result::Result<(ProjectDetails, bool), ApiRetryableError> {
+        let owner_field = match project_id.project_type {
+            ProjectType::User => "user",
+            ProjectType::Organization => "organization",
+        };
+        let query = format!(
+            r#"
+            query($login: String!, $number: Int!) {{
+                {}(login: $login) {{
+                    projectV2(number: $number) {{
+                        {}
+                        readme
+                        public
+                        closed
+                        items(first: 1) {{
+                            totalCount
+                        }}
+                        fields(first: 100) {{
+                            nodes {{ {} }}
+                            pageInfo {{
+                                hasNextPage
+                            }}
+                        }}
+                    }}
+                }}
+            }}
+            "#,
+            owner_field, PROJECT_V2_FIELDS, PROJECT_V2_FIELD_CONFIGURATION_FIELDS
+        );
+
+        let data: OwnerData<OwnerProject<ProjectDetailsNode>> = self
+            .execute_graphql(
+                &query,
+                json!({
+                    "login": project_id.owner.as_str(),
+                    "number": project_id.number.value(),
+                }),
+            )
+            .await
+            .map_err(|e| e.with_context(&format!("Failed to get project {}", project_id)))?;
+
+        let project_node = data
+            .owner
+            .and_then(|owner| owner.project_v2)
+            .ok_or_else(|| {
+                ApiRetryableError::NonRetryable(format!("Project {} not found", project_id))
+            })?;
+
+        let project = project_from_graphql(&project_node.project).ok_or_else(|| {
+            ApiRetryableError::NonRetryable(
+                "Failed to get project: unexpected response shape".to_string(),
+            )
+        })?;
+
+        let has_more_fields = project_node
+            .fields
+            .as_ref()
+            .is_some_and(|fields| fields.page_info.has_next_page);
+        let fields = project_node
+            .fields
+            .map(|fields| {
+                fields
+                    .into_nodes()
+                    .filter_map(|node| ProjectField::from_graphql_value(&node))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let details = ProjectDetails {
+            project,
+            readme: project_node.readme.filter(|r| !r.is_empty()),
+            visibility: if project_node.public {
+                ProjectVisibility::Public
+            } else {
+                ProjectVisibility::Private
+            },
+            state: if project_node.closed {
+                ProjectState::Closed
+            } else {
+                ProjectState::Open
+            },
+            fields,
+            item_count: project_node
+                .items
+                .and_then(|items| items.total_count)
+                .unwrap_or(0),
+        };
+
+        Ok((details, has_more_fields))
+    }
+
+    /// Update the settings of a GitHub Project v2
+    ///
+    /// Only the fields set in `update` are sent to `updateProjectV2`; all
+    /// other settings are left unchanged.
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `update` - The settings to change
+    ///
+    /// # Returns
+    /// Returns the updated `Project`
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - No settings were given
+    /// - The project does not exist or is not accessible
+    /// - The user does not have permission to edit the project
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn update_project(
+        &self,
+        project_node_id: &ProjectNodeId,
+        update: &ProjectUpdate,
+    ) -> Result<Project> {
+        if update.is_empty() {
+            return Err(anyhow::anyhow!("No project settings to update"));
+        }
+
+        let operation_name = "update_project";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.update_project_impl(project_node_id, update).await
+        })
+        .await
+    }
+
+    async fn update_project_impl(
+        &self,
+        project_node_id: &ProjectNodeId,
+        update: &ProjectUpdate,
+    ) -> std::result::Result<Project, ApiRetryableError> {
+        let mutation = format!(
+            r#"
+            mutation($input: UpdateProjectV2Input!) {{
+                updateProjectV2(input: $input) {{
+                    projectV2 {{ {} }}
+                }}
+            }}
+            "#,
+            PROJECT_V2_FIELDS
+        );
+
+        let mut input = json!({ "projectId": project_node_id.value() });
+        if let Some(title) = &update.title {
+            input["title"] = json!(title);
+        }
+        if let Some(short_description) = &update.short_description {
+            input["shortDescription"] = json!(short_description);
+        }
+        if let Some(readme) = &update.readme {
+            input["readme"] = json!(readme);
+        }
+        if let Some(visibility) = update.visibility {
+            input["public"] = json!(visibility == ProjectVisibility::Public);
+        }
+        if let Some(state) = update.state {
+            input["closed"] = json!(state == ProjectState::Closed);
+        }
+
+        let data: UpdateProjectData = self
+            .execute_graphql(&mutation, json!({ "input": input }))
+            .await
+            .map_err(|e| e.with_context("Failed to update project"))?;
+
+        data.update_project_v2
+            .and_then(|payload| payload.project_v2)
+            .and_then(|project| project_from_graphql(&project))
+            .ok_or_else(|| {
+                ApiRetryableError::NonRetryable(
+                    "Failed to update project: unexpected response shape".to_string(),
+                )
+            })
+    }
+
+    /// Delete a GitHub Project v2
+    ///
+    /// Permanently deletes the project with `deleteProjectV2`. Issues and pull
+    /// requests that were items of the project are not affected.
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the project was successfully deleted
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The project does not exist or is not accessible
+    /// - The user does not have permission to delete the project
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn delete_project(&self, project_node_id: &ProjectNodeId) -> Result<()> {
+        let operation_name = "delete_project";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.delete_project_impl(project_node_id).await
+        })
+        .await
+    }
+
+    async fn delete_project_impl(
+        &self,
+        project_node_id: &ProjectNodeId,
+    ) -> std::result::Result<(), ApiRetryableError> {
+        let mutation = r#"
+            mutation($projectId: ID!) {
+                deleteProjectV2(input: { projectId: $projectId }) {
+                    projectV2 {
+                        id
+                    }
+                }
+            }
+        "#;
+
+        self.execute_graphql::<IgnoredAny>(
+            mutation,
+            json!({ "projectId": project_node_id.value() }),
+        )
+        .await
+        .map_err(|e| e.with_context("Failed to delete project"))?;
+
+        Ok(())
+    }
+
+    /// Resolve the GraphQL node ID of a user or organization
+    async fn get_owner_node_id(
+        &self,
+        owner: &Owner,
+        project_type: ProjectType,
+    ) -> std::result::Result<String, ApiRetryableError> {
+        let owner_field = match project_type {
+            ProjectType::User => "user",
+            ProjectType::Organization => "organization",
+        };
+        let query = format!(
+            "query($login: String!) {{ {}(login: $login) {{ id }} }}",
+            owner_field
+        );
+
+        let data: OwnerData<NodeId> = self
+            .execute_graphql(&query, json!({ "login": owner.as_str() }))
+            .await
+            .map_err(|e| {
+                e.with_context(&format!("Failed to resolve {} '{}'", owner_field, owner))
+            })?;
+
+        data.owner.map(|node| node.id).ok_or_else(|| {
+            ApiRetryableError::NonRetryable(format!("{} '{}' not found", project_type, owner))
+        })
+    }
+
+    /// Resolve the GraphQL node ID of a repository
+    pub(crate) async fn get_repository_node_id(
+        &self,
+        repository_id: &RepositoryId,
+    ) -> std::result::Result<String, ApiRetryableError> {
+        self.execute_graphql::<RepositoryNodeData>(
+            "query($owner: String!, $name: String!) { repository(owner: $owner, name: $name) { id } }",
+            json!({
+                "owner": repository_id.owner().as_str(),
+                "name": repository_id.repo_name().as_str(),
+            }),
+        )
+        .await
+        .map_err(|e| e.with_context(&format!(
+                "Failed to resolve repository {}",
+                repository_id)))?
+        .repository
+        .map(|repository| repository.id)
+        .ok_or_else(|| {
+            ApiRetryableError::NonRetryable(format!("Repository {} not found", repository_id))
+        })
+    }
+
+    /// Resolve the GraphQL node ID of an organization team from its slug
+    async fn get_team_node_id(
+        &self,
+        organization: &Owner,
+        team_slug: &str,
+    ) -> std::result::Result<String, ApiRetryableError> {
+        self.execute_graphql::<TeamNodeData>(
+            "query($login: String!, $slug: String!) { organization(login: $login) { team(slug: $slug) { id } } }",
+            json!({ "login": organization.as_str(), "slug": team_slug }),
+        )
+        .await
+        .map_err(|e| e.with_context(&format!(
+                "Failed to resolve team {}/{}",
+                organization, team_slug)))?
+        .organization
+        .and_then(|organization| organization.team)
+        .map(|team| team.id)
+        .ok_or_else(|| {
+            ApiRetryableError::NonRetryable(format!(
+                "Team {}/{} not found",
+                organization, team_slug
+            ))
+        })
+    }
+
+    /// Update a project item field value using GraphQL API
+    ///
+    /// This method updates various field types in GitHub Projects v2:
+    /// - Text fields: Set text content
+    /// - Number fields: Set numeric values
+    /// - Date fields: Set date values (ISO 8601 format)
+    /// - Single select fields: Set selected option ID
+    /// - Iteration fields: Set iteration values
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `item_id` - The project item ID (GraphQL node ID)
+    /// * `field_id` - The field ID (GraphQL node ID)
+    /// * `value` - The new field value
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the field was successfully updated
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The project, item, or field does not exist or is not accessible
+    /// - The field type doesn't match the provided value type
+    /// - The user does not have permission to edit the project
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn update_project_item_field_value(
+        &self,
+        project_node_id: &ProjectNodeId,
+        project_item_id: &ProjectItemId,
+        project_field_id: &ProjectFieldId,
+        value: &ProjectFieldValue,
+    ) -> Result<()> {
+        let operation_name = "update_project_item_field_value";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.update_project_item_field_value_impl(
+                project_node_id,
+                project_item_id,
+                project_field_id,
+                value,
+            )
+            .await
+        })
+        .await
+    }
+
+    async fn update_project_item_field_value_impl(
+        &self,
+        project_node_id: &ProjectNodeId,
+        project_item_id: &ProjectItemId,
+        project_field_id: &ProjectFieldId,
+        value: &ProjectFieldValue,
+    ) -> std::result::Result<(), ApiRetryableError> {
+        // Build the field value input based on field value type
+        let value = match value {
+            ProjectFieldValue::Text(text_value) => json!({ "text": text_value }),
+            ProjectFieldValue::Number(number_value) => json!({ "number": number_value }),
+            ProjectFieldValue::Date(date_value) => json!({ "date": date_value.to_rfc3339() }),
+            ProjectFieldValue::SingleSelect(select_value) => {
+                json!({ "singleSelectOptionId": select_value })
+            }
+            ProjectFieldValue::MultiSelect(_) => {
+                // updateProjectV2ItemFieldValue has no multi-select value format;
+                // ProjectService applies multi-select values based on the field type
+                return Err(ApiRetryableError::NonRetryable(
+                    "Multi-select values must be applied through ProjectService".to_string(),
+                ));
+            }
+        };
+
+        let mutation = r#"
+            mutation($projectId: ID!, $itemId: ID!, $fieldId: ID!, $value: ProjectV2FieldValue!) {
+                updateProjectV2ItemFieldValue(input: {
+                    projectId: $projectId
+                    itemId: $itemId
+                    fieldId: $fieldId
+                    value: $value
+                }) {
+                    projectV2Item {
+                        id
+                    }
+                }
+            }
+        "#;
+
+        self.execute_graphql::<IgnoredAny>(
+            mutation,
+            json!({
+                "projectId": project_node_id.value(),
+                "itemId": project_item_id.value(),
+                "fieldId": project_field_id.value(),
+                "value": value,
+            }),
+        )
+        .await
+        .map_err(|e| e.with_context("Failed to update project item field value"))?;
+
+        Ok(())
+    }
+
+    /// Link a repository to a project
+    ///
+    /// Linked projects are listed in the repository's Projects tab.
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `repository_id` - The repository to link
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the repository was successfully linked
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The project or repository does not exist or is not accessible
+    /// - The repository and project belong to different owners
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn link_repository_to_project(
+        &self,
+        project_node_id: &ProjectNodeId,
+        repository_id: &RepositoryId,
+    ) -> Result<()> {
+        let operation_name = "link_repository_to_project";
+
+        retry_with_backoff(operation_name, None, || async {
+            let repository_node_id = self.get_repository_node_id(repository_id).await?;
+            self.project_link_mutation_impl(
+                "linkProjectV2ToRepository",
+                "repositoryId",
+                project_node_id,
+                &repository_node_id,
+            )
+            .await
+        })
+        .await
+    }
+
+    /// Unlink a repository from a project
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `repository_id` - The repository to unlink
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the repository was successfully unlinked
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The project or repository does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn unlink_repository_from_project(
+        &self,
+        project_node_id: &ProjectNodeId,
+        repository_id: &RepositoryId,
+    ) -> Result<()> {
+        let operation_name = "unlink_repository_from_project";
+
+        retry_with_backoff(operation_name, None, || async {
+            let repository_node_id = self.get_repository_node_id(repository_id).await?;
+            self.project_link_mutation_impl(
+                "unlinkProjectV2FromRepository",
+                "repositoryId",
+                project_node_id,
+                &repository_node_id,
+            )
+            .await
+        })
+        .await
+    }
+
+    /// Link an organization team to a project
+    ///
+    /// Linked projects are listed in the team's Projects tab.
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `organization` - The organization that owns the team
+    /// * `team_slug` - The team slug (e.g. "core-team")
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the team was successfully linked
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The project or team does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn link_team_to_project(
+        &self,
+        project_node_id: &ProjectNodeId,
+        organization: &Owner,
+        team_slug: &str,
+    ) -> Result<()> {
+        let operation_name = "link_team_to_project";
+
+        retry_with_backoff(operation_name, None, || async {
+            let team_node_id = self.get_team_node_id(organization, team_slug).await?;
+            self.project_link_mutation_impl(
+                "linkProjectV2ToTeam",
+                "teamId",
+                project_node_id,
+                &team_node_id,
+            )
+            .await
+        })
+        .await
+    }
+
+    /// Unlink an organization team from a project
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `organization` - The organization that owns the team
+    /// * `team_slug` - The team slug (e.g. "core-team")
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the team was successfully unlinked
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The project or team does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn unlink_team_from_project(
+        &self,
+        project_node_id: &ProjectNodeId,
+        organization: &Owner,
+        team_slug: &str,
+    ) -> Result<()> {
+        let operation_name = "unlink_team_from_project";
+
+        retry_with_backoff(operation_name, None, || async {
+            let team_node_id = self.get_team_node_id(organization, team_slug).await?;
+            self.project_link_mutation_impl(
+                "unlinkProjectV2FromTeam",
+                "teamId",
+                project_node_id,
+                &team_node_id,
+            )
+            .await
+        })
+        .await
+    }
+
+    /// Run one of the project link/unlink mutations, which share the same input shape
+    async fn project_link_mutation_impl(
+        &self,
+        mutation_name: &str,
+        target_input_field: &str,
+        project_node_id: &ProjectNodeId,
+        target_node_id: &str,
+    ) -> std::result::Result<(), ApiRetryableError> {
+        let mutation = format!(
+            r#"
+            mutation($projectId: ID!, $targetId: ID!) {{
+                {}(input: {{ projectId: $projectId, {}: $targetId }}) {{
+                    clientMutationId
+                }}
+            }}
+            "#,
+            mutation_name, target_input_field
+        );
+
+        self.execute_graphql::<IgnoredAny>(
+            &mutation,
+            json!({ "projectId": project_node_id.value(), "targetId": target_node_id }),
+        )
+        .await
+        .map_err(|e| e.with_context(&format!("Failed to run {}", mutation_name)))?;
+
+        Ok(())
+    }
+
+    /// Create a custom field in a project
+    ///
+    /// Single select fields are created with the given options in order,
+    /// using the default gray color and no description.
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `name` - The field name
+    /// * `data_type` - The field type (multi-select fields can not be created)
+    /// * `options` - Option names for single select fields
+    ///
+    /// # Returns
+    /// Returns the created field with its ID and option IDs
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The data type can not be created or options are given for a non single select field
+    /// - The project does not exist or is not accessible
+    /// - A field with the same name already exists
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn create_project_field(
+        &self,
+        project_node_id: &ProjectNodeId,
+        name: &str,
+        data_type: &ProjectCustomFieldType,
+        options: &[String],
+    ) -> Result<ProjectField> {
+        let graphql_data_type = match data_type {
+            ProjectCustomFieldType::Text => "TEXT",
+            ProjectCustomFieldType::Number => "NUMBER",
+            ProjectCustomFieldType::Date => "DATE",
+            ProjectCustomFieldType::SingleSelect => "SINGLE_SELECT",
+            ProjectCustomFieldType::MultiSelect => {
+                return Err(anyhow::anyhow!(
+                    "Projects v2 does not support creating multi_select fields"
+                ));
+            }
+        };
+        let is_single_select = matches!(data_type, ProjectCustomFieldType::SingleSelect);
+        if is_single_select && options.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Single select fields require at least one option"
+            ));
+        }
+        if !is_single_select && !options.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Options can only be given for single_select fields"
+            ));
+        }
+
+        let operation_name = "create_project_field";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.create_project_field_impl(project_node_id, name, graphql_data_type, options)
+                .await
+        })
+        .await
+    }
+
+    async fn create_project_field_impl(
+        &self,
+        project_node_id: &ProjectNodeId,
+        name: &str,
+        graphql_data_type: &str,
+        options: &[String],
+    ) -> std::result::Result<ProjectField, ApiRetryableError> {
+        let mutation = format!(
+            r#"
+            mutation($input: CreateProjectV2FieldInput!) {{
+                createProjectV2Field(input: $input) {{
+                    projectV2Field {{ {} }}
+                }}
+            }}
+            "#,
+            PROJECT_V2_FIELD_CONFIGURATION_FIELDS
+        );
+
+        let mut input = json!({
+            "projectId": project_node_id.value(),
+            "name": name,
+            "dataType": graphql_data_type,
+        });
+        if !options.is_empty() {
+            input["singleSelectOptions"] = single_select_options_input(options);
+        }
+
+        let data: CreateProjectFieldData = self
+            .execute_graphql(&mutation, json!({ "input": input }))
+            .await
+            .map_err(|e| e.with_context("Failed to create project field"))?;
+
+        data.create_project_v2_field
+            .and_then(|payload| payload.project_v2_field)
+            .and_then(|field| ProjectField::from_graphql_value(&field))
+            .ok_or_else(|| {
+                ApiRetryableError::NonRetryable(
+                    "Failed to create project field: unexpected response shape".to_string(),
+                )
+            })
+    }
+
+    /// Rename a project field and/or replace its single select options
+    ///
+    /// Replacing options replaces the whole option list; items that had a
+    /// removed option selected lose their value.
+    ///
+    /// # Arguments
+    /// * `field_id` - The field ID (GraphQL node ID)
+    /// * `name` - Optional new field name
+    /// * `options` - Optional new option names for a single select field
+    ///
+    /// # Returns
+    /// Returns the updated field
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Neither a name nor options are given
+    /// - The field does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn update_project_field(
+        &self,
+        field_id: &ProjectFieldId,
+        name: Option<&str>,
+        options: Option<&[String]>,
+    ) -> Result<ProjectField> {
+        if name.is_none() && options.is_none() {
+            return Err(anyhow::anyhow!("No project field changes to update"));
+        }
+
+        let operation_name = "update_project_field";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.update_project_field_impl(field_id, name, options)
+                .await
+        })
+        .await
+    }
+
+    async fn update_project_field_impl(
+        &self,
+        field_id: &ProjectFieldId,
+        name: Option<&str>,
+        options: Option<&[String]>,
+    ) -> std::result::Result<ProjectField, ApiRetryableError> {
+        let mutation = format!(
+            r#"
+            mutation($input: UpdateProjectV2FieldInput!) {{
+                updateProjectV2Field(input: $input) {{
+                    projectV2Field {{ {} }}
+                }}
+            }}
+            "#,
+            PROJECT_V2_FIELD_CONFIGURATION_FIELDS
+        );
+
+        let mut input = json!({ "fieldId": field_id.value() });
+        if let Some(name) = name {
+            input["name"] = json!(name);
+        }
+        if let Some(options) = options {
+            input["singleSelectOptions"] = single_select_options_input(options);
+        }
+
+        let data: UpdateProjectFieldData = self
+            .execute_graphql(&mutation, json!({ "input": input }))
+            .await
+            .map_err(|e| e.with_context("Failed to update project field"))?;
+
+        data.update_project_v2_field
+            .and_then(|payload| payload.project_v2_field)
+            .and_then(|field| ProjectField::from_graphql_value(&field))
+            .ok_or_else(|| {
+                ApiRetryableError::NonRetryable(
+                    "Failed to update project field: unexpected response shape".to_string(),
+                )
+            })
+    }
+
+    /// Delete a custom field from a project
+    ///
+    /// The values of the field are removed from all items. Built-in fields
+    /// such as Title can not be deleted.
+    ///
+    /// # Arguments
+    /// * `field_id` - The field ID (GraphQL node ID)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the field was successfully deleted
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The field does not exist, is not accessible or is a built-in field
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn delete_project_field(&self, field_id: &ProjectFieldId) -> Result<()> {
+        let operation_name = "delete_project_field";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.delete_project_field_impl(field_id).await
+        })
+        .await
+    }
+
+    async fn delete_project_field_impl(
+        &self,
+        field_id: &ProjectFieldId,
+    ) -> std::result::Result<(), ApiRetryableError> {
+        let mutation = r#"
+            mutation($fieldId: ID!) {
+                deleteProjectV2Field(input: { fieldId: $fieldId }) {
+                    projectV2Field {
+                        ... on ProjectV2FieldCommon { id }
+                    }
+                }
+            }
+        "#;
+
+        self.execute_graphql::<IgnoredAny>(mutation, json!({ "fieldId": field_id.value() }))
+            .await
+            .map_err(|e| e.with_context("Failed to delete project field"))?;
+
+        Ok(())
+    }
+
+    /// List the items of a project with their field values
+    ///
+    /// Fetches all pages of items, including archived items. Up to 50 field
+    /// values are read per item.
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    ///
+    /// # Returns
+    /// Returns the project items in project order
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The project does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_project_items(
+        &self,
+        project_node_id: &ProjectNodeId,
+    ) -> Result<Vec<ProjectItem>> {
+        let mut items = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let operation_name = "list_project_items";
+            let (page, next_cursor) = retry_with_backoff(operation_name, None, || async {
+                self.list_project_items_page_impl(project_node_id, cursor.as_deref())
+                    .await
+            })
+            .await?;
+
+            items.extend(page);
+            match next_cursor {
+                Some(next_cursor) => cursor = Some(next_cursor),
+                None => break,
+            }
+        }
+
+        Ok(items)
+    }
+
+    async fn list_project_items_page_impl(
+        &self,
+        project_node_id: &ProjectNodeId,
+        cursor: Option<&str>,
+    ) -> std::result::Result<(Vec<ProjectItem>, Option<String>), ApiRetryableError> {
+        let query = r#"
+            query($projectId: ID!, $cursor: String) {
+                node(id: $projectId) {
+                    ... on ProjectV2 {
+                        items(first: 100, after: $cursor) {
+                            nodes {
+                                id
+                                isArchived
+                                fieldValues(first: 50) {
+                                    nodes {
+                                        __typename
+                                        ... on ProjectV2ItemFieldTextValue {
+                                            text
+                                            field { ... on ProjectV2FieldCommon { id name } }
+                                        }
+                                        ... on ProjectV2ItemFieldNumberValue {
+                                            number
+                                            field { ... on ProjectV2FieldCommon { id name } }
+                                        }
+                                        ... on ProjectV2ItemFieldDateValue {
+                                            date
+                                            field { ... on ProjectV2FieldCommon { id name } }
+                                        }
+                                        ... on ProjectV2ItemFieldSingleSelectValue {
+                                            name
+                                            field { ... on ProjectV2FieldCommon { id name } }
+                                        }
+                                        ... on ProjectV2ItemFieldLabelValue {
+                                            labels(first: 50) { nodes { name } }
+                                            field { ... on ProjectV2FieldCommon { id name } }
+                                        }
+                                    }
+                                }
+                            }
+                            pageInfo {
+                                hasNextPage
+                                endCursor
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let data: NodeData<ProjectItemsNode> = self
+            .execute_graphql(
+                query,
+                json!({ "projectId": project_node_id.value(), "cursor": cursor }),
+            )
+            .await
+            .map_err(|e| e.with_context("Failed to list project items"))?;
+
+        let connection = data.node.and_then(|node| node.items).ok_or_else(|| {
+            ApiRetryableError::NonRetryable(format!(
+                "Project {} not found or is not a project",
+                project_node_id
+            ))
+        })?;
+
+        let next_cursor = connection.next_cursor();
+        let items = connection
+            .into_nodes()
+            .filter_map(|node| ProjectItem::from_graphql_value(&node))
+            .collect();
+
+        Ok((items, next_cursor))
+    }
+
+    /// List the field definitions of a project
+    ///
+    /// Returns every field of the project, including built-in fields such as
+    /// Title and Assignees, with single select options and iteration
+    /// configurations. All pages of fields are fetched.
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    ///
+    /// # Returns
+    /// Returns the project fields in the order GitHub reports them
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The project does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_project_fields(
+        &self,
+        project_node_id: &ProjectNodeId,
+    ) -> Result<Vec<ProjectField>> {
+        let mut fields = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        loop {
+            let operation_name = "list_project_fields";
+            let (page, next_cursor) = retry_with_backoff(operation_name, None, || async {
+                self.list_project_fields_page_impl(project_node_id, cursor.as_deref())
+                    .await
+            })
+            .await?;
+
+            fields.extend(page);
+            match next_cursor {
+                Some(next_cursor) => cursor = Some(next_cursor),
+                None => break,
+            }
+        }
+
+        Ok(fields)
+    }
+
+    async fn list_project_fields_page_impl(
+        &self,
+        project_node_id: &ProjectNodeId,
+        cursor: Option<&str>,
+    ) -> std::result::Result<(Vec<ProjectField>, Option<String>), ApiRetryableError> {
+        let query = format!(
+            r#"
+            query($projectId: ID!, $cursor: String) {{
+                node(id: $projectId) {{
+                    ... on ProjectV2 {{
+                        fields(first: 100, after: $cursor) {{
+                            nodes {{ {} }}
+                            pageInfo {{
+                                hasNextPage
+                                endCursor
+                            }}
+                        }}
+                    }}
+                }}
+            }}
+            "#,
+            PROJECT_V2_FIELD_CONFIGURATION_FIELDS
+        );
+
+        let data: NodeData<ProjectFieldsNode> = self
+            .execute_graphql(
+                &query,
+                json!({ "projectId": project_node_id.value(), "cursor": cursor }),
+            )
+            .await
+            .map_err(|e| e.with_context("Failed to list project fields"))?;
+
+        let connection = data.node.and_then(|node| node.fields).ok_or_else(|| {
+            ApiRetryableError::NonRetryable(format!(
+                "Project {} not found or is not a project",
+                project_node_id
+            ))
+        })?;
+
+        let next_cursor = connection.next_cursor();
+        let fields = connection
+            .into_nodes()
+            .filter_map(|node| ProjectField::from_graphql_value(&node))
+            .collect();
+
+        Ok((fields, next_cursor))
+    }
+
+    /// Find the project item that represents an issue or pull request
+    ///
+    /// Looks up the project items of the issue or pull request, including
+    /// archived items, and returns the one that belongs to the given project.
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `content` - The issue or pull request
+    ///
+    /// # Returns
+    /// Returns `Ok(Some(ProjectItemId))` if the content is in the project and
+    /// `Ok(None)` if it is not
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The content is a draft issue
+    /// - The issue or pull request does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn find_project_item_for_content(
+        &self,
+        project_node_id: &ProjectNodeId,
+        content: &ProjectOriginalResource,
+    ) -> Result<Option<ProjectItemId>> {
+        let (repository_id, number) = content
+            .repository_and_number()
+            .ok_or_else(|| anyhow::anyhow!("Draft issues can not be looked up by URL"))?;
+
+        let mut cursor: Option<String> = None;
+        loop {
+            let operation_name = "find_project_item_for_content";
+            let (item_id, next_cursor) = retry_with_backoff(operation_name, None, || async {
+                self.find_project_item_for_content_page_impl(
+                    project_node_id,
+                    repository_id,
+                    number,
+                    cursor.as_deref(),
+                )
+                .await
+            })
+            .await?;
+
+            if item_id.is_some() {
+                return Ok(item_id);
+            }
+            match next_cursor {
+                Some(next_cursor) => cursor = Some(next_cursor),
+                None => return Ok(None),
+            }
+        }
+    }
+
+    async fn find_project_item_for_content_page_impl(
+        &self,
+        project_node_id: &ProjectNodeId,
+        repository_id: &RepositoryId,
+        number: u32,
+        cursor: Option<&str>,
+    ) -> std::result::Result<(Option<ProjectItemId>, Option<String>), ApiRetryableError> {
+        let query = r#"
+            query($owner: String!, $name: String!, $number: Int!, $cursor: String) {
+                repository(owner: $owner, name: $name) {
+                    issueOrPullRequest(number: $number) {
+                        ... on Issue {
+                            projectItems(first: 100, after: $cursor, includeArchived: true) {
+                                nodes { id project { id } }
+                                pageInfo { hasNextPage endCursor }
+                            }
+                        }
+                        ... on PullRequest {
+                            projectItems(first: 100, after: $cursor, includeArchived: true) {
+                                nodes { id project { id } }
+                                pageInfo { hasNextPage endCursor }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let data: ContentProjectItemsData = self
+            .execute_graphql(
+                query,
+                json!({
+                    "owner": repository_id.owner().as_str(),
+                    "name": repository_id.repo_name().as_str(),
+                    "number": number,
+                    "cursor": cursor,
+                }),
+            )
+            .await
+            .map_err(|e| e.with_context("Failed to find project item"))?;
+
+        let connection = data
+            .repository
+            .and_then(|repository| repository.issue_or_pull_request)
+            .and_then(|content| content.project_items)
+            .ok_or_else(|| {
+                ApiRetryableError::NonRetryable(format!(
+                    "Issue or pull request {}/{}#{} not found",
+                    repository_id.owner(),
+                    repository_id.repo_name(),
+                    number
+                ))
+            })?;
+
+        let next_cursor = connection.next_cursor();
+        let item_id = connection
+            .into_nodes()
+            .find(|node| {
+                node.project
+                    .as_ref()
+                    .is_some_and(|project| project.id == project_node_id.value())
+            })
+            .map(|node| ProjectItemId::new(node.id));
+
+        Ok((item_id, next_cursor))
+    }
+
+    /// Remove an item from a project
+    ///
+    /// Deletes the project item with `deleteProjectV2Item`. The underlying
+    /// issue or pull request is not affected; draft issues are deleted.
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `item_id` - The project item ID (GraphQL node ID)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the item was successfully removed
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The project or item does not exist or is not accessible
+    /// - The user does not have permission to edit the project
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn remove_item_from_project(
+        &self,
+        project_node_id: &ProjectNodeId,
+        item_id: &ProjectItemId,
+    ) -> Result<()> {
+        let operation_name = "remove_item_from_project";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.remove_item_from_project_impl(project_node_id, item_id)
+                .await
+        })
+        .await
+    }
+
+    async fn remove_item_from_project_impl(
+        &self,
+        project_node_id: &ProjectNodeId,
+        item_id: &ProjectItemId,
+    ) -> std::result::Result<(), ApiRetryableError> {
+        let mutation = r#"
+            mutation($projectId: ID!, $itemId: ID!) {
+                deleteProjectV2Item(input: { projectId: $projectId, itemId: $itemId }) {
+                    deletedItemId
+                }
+            }
+        "#;
+
+        self.execute_graphql::<IgnoredAny>(
+            mutation,
+            json!({ "projectId": project_node_id.value(), "itemId": item_id.value() }),
+        )
+        .await
+        .map_err(|e| e.with_context("Failed to remove item from project"))?;
+
+        Ok(())
+    }
+
+    /// Archive or unarchive a project item
+    ///
+    /// Archived items are hidden from project views but keep their field
+    /// values and can be restored with `archived = false`.
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `item_id` - The project item ID (GraphQL node ID)
+    /// * `archived` - `true` to archive the item, `false` to unarchive it
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the item was successfully archived or unarchived
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The project or item does not exist or is not accessible
+    /// - The user does not have permission to edit the project
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn set_project_item_archived(
+        &self,
+        project_node_id: &ProjectNodeId,
+        item_id: &ProjectItemId,
+        archived: bool,
+    ) -> Result<()> {
+        let operation_name = if archived {
+            "archive_project_item"
+        } else {
+            "unarchive_project_item"
+        };
+
+        retry_with_backoff(operation_name, None, || async {
+            self.set_project_item_archived_impl(project_node_id, item_id, archived)
+                .await
+        })
+        .await
+    }
+
+    async fn set_project_item_archived_impl(
+        &self,
+        project_node_id: &ProjectNodeId,
+        item_id: &ProjectItemId,
+        archived: bool,
+    ) -> std::result::Result<(), ApiRetryableError> {
+        let (mutation, action) = if archived {
+            (
+                r#"
+                mutation($projectId: ID!, $itemId: ID!) {
+                    archiveProjectV2Item(input: { projectId: $projectId, itemId: $itemId }) {
+                        item { id }
+                    }
+                }
+                "#,
+                "archive",
+            )
+        } else {
+            (
+                r#"
+                mutation($projectId: ID!, $itemId: ID!) {
+                    unarchiveProjectV2Item(input: { projectId: $projectId, itemId: $itemId }) {
+                        item { id }
+                    }
+                }
+                "#,
+                "unarchive",
+            )
+        };
+
+        self.execute_graphql::<IgnoredAny>(
+            mutation,
+            json!({ "projectId": project_node_id.value(), "itemId": item_id.value() }),
+        )
+        .await
+        .map_err(|e| e.with_context(&format!("Failed to {} project item", action)))?;
+
+        Ok(())
+    }
+
+    /// Move a project item within the project's manual ordering
+    ///
+    /// The ordering is used by views sorted manually (no sort field).
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `item_id` - The project item ID to move
+    /// * `after_item_id` - The item to place it after; `None` moves it to the top
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the item was successfully moved
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The project or either item does not exist or is not accessible
+    /// - The user does not have permission to edit the project
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn update_project_item_position(
+        &self,
+        project_node_id: &ProjectNodeId,
+        item_id: &ProjectItemId,
+        after_item_id: Option<&ProjectItemId>,
+    ) -> Result<()> {
+        let operation_name = "update_project_item_position";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.update_project_item_position_impl(project_node_id, item_id, after_item_id)
+                .await
+        })
+        .await
+    }
+
+    async fn update_project_item_position_impl(
+        &self,
+        project_node_id: &ProjectNodeId,
+        item_id: &ProjectItemId,
+        after_item_id: Option<&ProjectItemId>,
+    ) -> std::result::Result<(), ApiRetryableError> {
+        let mutation = r#"
+            mutation($projectId: ID!, $itemId: ID!, $afterId: ID) {
+                updateProjectV2ItemPosition(input: { projectId: $projectId, itemId: $itemId, afterId: $afterId }) {
+                    clientMutationId
+                }
+            }
+        "#;
+
+        self.execute_graphql::<IgnoredAny>(
+            mutation,
+            json!({
+                "projectId": project_node_id.value(),
+                "itemId": item_id.value(),
+                "afterId": after_item_id.map(|id| id.value()),
+            }),
+        )
+        .await
+        .map_err(|e| e.with_context("Failed to update project item position"))?;
+
+        Ok(())
+    }
+
+    /// Add a draft issue to a project
+    ///
+    /// Draft issues exist only in the project until they are converted to a
+    /// repository issue with `convert_draft_issue_to_issue`.
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `title` - The draft issue title
+    /// * `body` - Optional draft issue body
+    ///
+    /// # Returns
+    /// Returns the new project item ID and draft issue ID
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The project does not exist or is not accessible
+    /// - The user does not have permission to edit the project
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn add_draft_issue_to_project(
+        &self,
+        project_node_id: &ProjectNodeId,
+        title: &str,
+        body: Option<&str>,
+    ) -> Result<ProjectDraftIssue> {
+        let operation_name = "add_draft_issue_to_project";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.add_draft_issue_to_project_impl(project_node_id, title, body)
+                .await
+        })
+        .await
+    }
+
+    async fn add_draft_issue_to_project_impl(
+        &self,
+        project_node_id: &ProjectNodeId,
+        title: &str,
+        body: Option<&str>,
+    ) -> std::result::Result<ProjectDraftIssue, ApiRetryableError> {
+        let mutation = r#"
+            mutation($projectId: ID!, $title: String!, $body: String) {
+                addProjectV2DraftIssue(input: { projectId: $projectId, title: $title, body: $body }) {
+                    projectItem {
+                        id
+                        content {
+                            ... on DraftIssue { id }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let data: AddDraftIssueData = self
+            .execute_graphql(
+                mutation,
+                json!({ "projectId": project_node_id.value(), "title": title, "body": body }),
+            )
+            .await
+            .map_err(|e| e.with_context("Failed to add draft issue to project"))?;
+
+        data.add_project_v2_draft_issue
+            .and_then(|payload| payload.project_item)
+            .and_then(|item| {
+                Some(ProjectDraftIssue {
+                    draft_issue_id: DraftIssueId::new(item.content?.id),
+                    project_item_id: ProjectItemId::new(item.id),
+                })
+            })
+            .ok_or_else(|| {
+                ApiRetryableError::NonRetryable(
+                    "Failed to add draft issue to project: unexpected response shape".to_string(),
+                )
+            })
+    }
+
+    /// Update the title and/or body of a draft issue
+    ///
+    /// # Arguments
+    /// * `draft_issue_id` - The draft issue ID (not the project item ID)
+    /// * `title` - Optional new title
+    /// * `body` - Optional new body
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the draft issue was successfully updated
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Neither title nor body is given
+    /// - The draft issue does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn update_draft_issue(
+        &self,
+        draft_issue_id: &DraftIssueId,
+        title: Option<&str>,
+        body: Option<&str>,
+    ) -> Result<()> {
+        if title.is_none() && body.is_none() {
+            return Err(anyhow::anyhow!("No draft issue changes to update"));
+        }
+
+        let operation_name = "update_draft_issue";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.update_draft_issue_impl(draft_issue_id, title, body)
+                .await
+        })
+        .await
+    }
+
+    async fn update_draft_issue_impl(
+        &self,
+        draft_issue_id: &DraftIssueId,
+        title: Option<&str>,
+        body: Option<&str>,
+    ) -> std::result::Result<(), ApiRetryableError> {
+        let mutation = r#"
+            mutation($input: UpdateProjectV2DraftIssueInput!) {
+                updateProjectV2DraftIssue(input: $input) {
+                    draftIssue { id }
+                }
+            }
+        "#;
 
-impl GitHubClient {
-    /// Update a project item field value using GraphQL API
+        let mut input = json!({ "draftIssueId": draft_issue_id.value() });
+        if let Some(title) = title {
+            input["title"] = json!(title);
+        }
+        if let Some(body) = body {
+            input["body"] = json!(body);
+        }
+
+        self.execute_graphql::<IgnoredAny>(mutation, json!({ "input": input }))
+            .await
+            .map_err(|e| e.with_context("Failed to update draft issue"))?;
+
+        Ok(())
+    }
+
+    /// Convert a draft issue project item to a repository issue
     ///
-    /// This method updates various field types in GitHub Projects v2:
-    /// - Text fields: Set text content
-    /// - Number fields: Set numeric values
-    /// - Date fields: Set date values (ISO 8601 format)
-    /// - Single select fields: Set selected option ID
-    /// - Iteration fields: Set iteration values
+    /// The project item keeps its position and field values and now refers
+    /// to the new issue.
     ///
     /// # Arguments
-    /// * `project_node_id` - The project node identifier (GraphQL ID)
-    /// * `item_id` - The project item ID (GraphQL node ID)
-    /// * `field_id` - The field ID (GraphQL node ID)
-    /// * `value` - The new field value
+    /// * `project_item_id` - The project item ID holding the draft issue
+    /// * `repository_id` - The repository to create the issue in
     ///
     /// # Returns
-    /// Returns `Ok(())` if the field was successfully updated
+    /// Returns the identifier of the created issue
     ///
     /// # Errors
     /// Returns an error if:
-    /// - The project, item, or field does not exist or is not accessible
-    /// - The field type doesn't match the provided value type
-    /// - The user does not have permission to edit the project
+    /// - The item is not a draft issue
+    /// - The repository does not exist or is not accessible
     /// - API rate limits are exceeded (with automatic retry)
     /// - Network errors occur (with automatic retry)
-    pub async fn update_project_item_field_value(
+    pub async fn convert_draft_issue_to_issue(
         &self,
-        project_node_id: &ProjectNodeId,
         project_item_id: &ProjectItemId,
-        project_field_id: &ProjectFieldId,
-        value: &ProjectFieldValue,
-    ) -> Result<()> {
-        let operation_name = "update_project_item_field_value";
+        repository_id: &RepositoryId,
+    ) -> Result<IssueId> {
+        let operation_name = "convert_draft_issue_to_issue";
 
         retry_with_backoff(operation_name, None, || async {
-            self.update_project_item_field_value_impl(
-                project_node_id,
-                project_item_id,
-                project_field_id,
-                value,
-            )
-            .await
+            self.convert_draft_issue_to_issue_impl(project_item_id, repository_id)
+                .await
         })
         .await
     }
 
-    async fn update_project_item_field_value_impl(
+    async fn convert_draft_issue_to_issue_impl(
         &self,
-        project_node_id: &ProjectNodeId,
         project_item_id: &ProjectItemId,
-        project_field_id: &ProjectFieldId,
-        value: &ProjectFieldValue,
-    ) -> std::result::Result<(), ApiRetryableError> {
-        // Build the GraphQL mutation based on field value type
-        let mutation = match value {
-            ProjectFieldValue::Text(text_value) => {
-                format!(
-                    r#"
-                    mutation {{
-                        updateProjectV2ItemFieldValue(input: {{
-                            projectId: "{}"
-                            itemId: "{}"
-                            fieldId: "{}"
-                            value: {{
-                                text: "{}"
-                            }}
-                        }}) {{
-                            projectV2Item {{
-                                id
-                            }}
-                        }}
-                    }}
-                    "#,
-                    project_node_id.value(),
-                    project_item_id.value(),
-                    project_field_id.value(),
-                    text_value.replace('"', "\\\"") // Escape quotes in text
-                )
-            }
-            ProjectFieldValue::Number(number_value) => {
-                format!(
-                    r#"
-                    mutation {{
-                        updateProjectV2ItemFieldValue(input: {{
-                            projectId: "{}"
-                            itemId: "{}"
-                            fieldId: "{}"
-                            value: {{
-                                number: {}
-                            }}
-                        }}) {{
-                            projectV2Item {{
-                                id
-                            }}
-                        }}
-                    }}
-                    "#,
-                    project_node_id.value(),
-                    project_item_id.value(),
-                    project_field_id.value(),
-                    number_value
-                )
-            }
-            ProjectFieldValue::Date(date_value) => {
-                format!(
-                    r#"
-                    mutation {{
-                        updateProjectV2ItemFieldValue(input: {{
-                            projectId: "{}"
-                            itemId: "{}"
-                            fieldId: "{}"
-                            value: {{
-                                date: "{}"
-                            }}
-                        }}) {{
-                            projectV2Item {{
-                                id
-                            }}
-                        }}
-                    }}
-                    "#,
-                    project_node_id.value(),
-                    project_item_id.value(),
-                    project_field_id.value(),
-                    date_value.to_rfc3339()
-                )
+        repository_id: &RepositoryId,
+    ) -> std::result::Result<IssueId, ApiRetryableError> {
+        let repository_node_id = self.get_repository_node_id(repository_id).await?;
+
+        let mutation = r#"
+            mutation($itemId: ID!, $repositoryId: ID!) {
+                convertProjectV2DraftIssueItemToIssue(input: { itemId: $itemId, repositoryId: $repositoryId }) {
+                    item {
+                        content {
+                            ... on Issue { number }
+                        }
+                    }
+                }
             }
-            ProjectFieldValue::SingleSelect(select_value) => {
-                format!(
-                    r#"
-                    mutation {{
-                        updateProjectV2ItemFieldValue(input: {{
-                            projectId: "{}"
-                            itemId: "{}"
-                            fieldId: "{}"
-                            value: {{
-                                singleSelectOptionId: "{}"
-                            }}
-                        }}) {{
-                            projectV2Item {{
-                                id
-                            }}
-                        }}
-                    }}
-                    "#,
-                    project_node_id.value(),
-                    project_item_id.value(),
-                    project_field_id.value(),
-                    select_value
+        "#;
+
+        let data: ConvertDraftIssueData = self
+            .execute_graphql(
+                mutation,
+                json!({ "itemId": project_item_id.value(), "repositoryId": repository_node_id }),
+            )
+            .await
+            .map_err(|e| e.with_context("Failed to convert draft issue to issue"))?;
+
+        data.convert_project_v2_draft_issue_item_to_issue
+            .and_then(|payload| payload.item)
+            .and_then(|item| item.content)
+            .map(|issue| IssueId::new(repository_id.clone(), issue.number))
+            .ok_or_else(|| {
+                ApiRetryableError::NonRetryable(
+                    "Failed to convert draft issue to issue: unexpected response shape".to_string(),
                 )
+            })
+    }
+
+    /// Get the issue, pull request or draft issue a project item refers to
+    ///
+    /// # Arguments
+    /// * `item_id` - The project item ID (GraphQL node ID)
+    ///
+    /// # Returns
+    /// Returns the content of the project item
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The project item does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_project_item_content(
+        &self,
+        item_id: &ProjectItemId,
+    ) -> Result<ProjectOriginalResource> {
+        let operation_name = "get_project_item_content";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.get_project_item_content_impl(item_id).await
+        })
+        .await
+    }
+
+    async fn get_project_item_content_impl(
+        &self,
+        item_id: &ProjectItemId,
+    ) -> std::result::Result<ProjectOriginalResource, ApiRetryableError> {
+        let query = r#"
+            query($itemId: ID!) {
+                node(id: $itemId) {
+                    ... on ProjectV2Item {
+                        content {
+                            __typename
+                            ... on Issue {
+                                number
+                                repository { owner { login } name }
+                            }
+                            ... on PullRequest {
+                                number
+                                repository { owner { login } name }
+                            }
+                        }
+                    }
+                }
             }
-            ProjectFieldValue::MultiSelect(_) => {
-                // Multi-select is not supported by updateProjectV2ItemFieldValue
-                // Would need separate handling or different mutation
-                return Err(ApiRetryableError::NonRetryable(
-                    "Multi-select fields are not supported by updateProjectV2ItemFieldValue"
-                        .to_string(),
-                ));
-            }
-        };
+        "#;
 
-        // Execute GraphQL mutation
-        let response = self
-            .client
-            .graphql::<serde_json::Value>(&json!({
-                "query": mutation
-            }))
+        let data: NodeData<ProjectItemContentNode> = self
+            .execute_graphql(query, json!({ "itemId": item_id.value() }))
             .await
-            .map_err(ApiRetryableError::from_octocrab_error)?;
+            .map_err(|e| e.with_context("Failed to get project item content"))?;
 
-        // Check if the mutation was successful
-        if response.get("data").is_some() && response.get("errors").is_none() {
-            Ok(())
-        } else {
-            let error_msg = response
-                .get("errors")
-                .and_then(|errors| errors.as_array())
-                .and_then(|arr| arr.first())
-                .and_then(|error| error.get("message"))
-                .and_then(|msg| msg.as_str())
-                .unwrap_or("Unknown GraphQL error");
-
-            Err(ApiRetryableError::NonRetryable(format!(
-                "Failed to update project item field value: {}",
-                error_msg
-            )))
+        let content = data.node.and_then(|node| node.content).ok_or_else(|| {
+            ApiRetryableError::NonRetryable(format!("Project item {} not found", item_id))
+        })?;
+
+        match content {
+            ProjectItemContent::DraftIssue => Ok(ProjectOriginalResource::DraftIssue),
+            ProjectItemContent::Issue { number, repository } => Ok(ProjectOriginalResource::Issue(
+                IssueId::new(repository.into_repository_id(), number),
+            )),
+            ProjectItemContent::PullRequest { number, repository } => {
+                Ok(ProjectOriginalResource::PullRequest(PullRequestId::new(
+                    repository.into_repository_id(),
+                    number,
+                )))
+            }
+            ProjectItemContent::Other => Err(ApiRetryableError::NonRetryable(format!(
+                "Unsupported content in project item {}",
+                item_id
+            ))),
         }
     }
 
@@ -204,58 +1835,30 @@ impl GitHubClient {
         let number = project_id.project_number().value();
         let project_type = project_id.project_type();
 
-        let query = match project_type {
-            crate::types::project::ProjectType::User => {
-                format!(
-                    r#"
-                    query {{
-                        user(login: "{}") {{
-                            projectV2(number: {}) {{
-                                id
-                            }}
-                        }}
-                    }}
-                    "#,
-                    owner, number
-                )
-            }
-            crate::types::project::ProjectType::Organization => {
-                format!(
-                    r#"
-                    query {{
-                        organization(login: "{}") {{
-                            projectV2(number: {}) {{
-                                id
-                            }}
-                        }}
-                    }}
-                    "#,
-                    owner, number
-                )
-            }
+        let owner_field = match project_type {
+            crate::types::project::ProjectType::User => "user",
+            crate::types::project::ProjectType::Organization => "organization",
         };
+        let query = format!(
+            r#"
+            query($login: String!, $number: Int!) {{
+                {}(login: $login) {{
+                    projectV2(number: $number) {{
+                        id
+                    }}
+                }}
+            }}
+            "#,
+            owner_field
+        );
 
-        let response = self
-            .client
-            .graphql::<serde_json::Value>(&json!({
-                "query": query
-            }))
+        let data: OwnerData<OwnerProject<NodeId>> = self
+            .execute_graphql(&query, json!({ "login": owner, "number": number }))
             .await?;
 
-        // Extract project node ID from response
-        let node_id = response
-            .get("data")
-            .and_then(|data| match project_type {
-                crate::types::project::ProjectType::User => data
-                    .get("user")
-                    .and_then(|user| user.get("projectV2"))
-                    .and_then(|project| project.get("id")),
-                crate::types::project::ProjectType::Organization => data
-                    .get("organization")
-                    .and_then(|org| org.get("projectV2"))
-                    .and_then(|project| project.get("id")),
-            })
-            .and_then(|id| id.as_str())
+        let node_id = data
+            .owner
+            .and_then(|owner| owner.project_v2)
             .ok_or_else(|| {
                 anyhow::anyhow!(
                     "Failed to get project node ID for project {}/{}",
@@ -264,7 +1867,7 @@ impl GitHubClient {
                 )
             })?;
 
-        Ok(ProjectNodeId::new(node_id.to_string()))
+        Ok(ProjectNodeId::new(node_id.id))
     }
 
     /// Update a project item text field value
@@ -459,54 +2062,9 @@ impl GitHubClient {
 
         let issue_node_id = octocrab_issue.node_id;
 
-        let mutation = format!(
-            r#"
-            mutation {{
-                addProjectV2ItemById(input: {{
-                    projectId: "{}"
-                    contentId: "{}"
-                }}) {{
-                    item {{
-                        id
-                    }}
-                }}
-            }}
-            "#,
-            project_node_id.value(),
-            issue_node_id
-        );
-
-        let response = self
-            .client
-            .graphql::<serde_json::Value>(&json!({
-                "query": mutation
-            }))
+        self.add_content_to_project(project_node_id, &issue_node_id)
             .await
-            .map_err(ApiRetryableError::from_octocrab_error)?;
-
-        if let Some(data) = response.get("data") {
-            if let Some(item_id) = data
-                .get("addProjectV2ItemById")
-                .and_then(|add_result| add_result.get("item"))
-                .and_then(|item| item.get("id"))
-                .and_then(|id| id.as_str())
-            {
-                return Ok(ProjectItemId::new(item_id.to_string()));
-            }
-        }
-
-        let error_msg = response
-            .get("errors")
-            .and_then(|errors| errors.as_array())
-            .and_then(|arr| arr.first())
-            .and_then(|error| error.get("message"))
-            .and_then(|msg| msg.as_str())
-            .unwrap_or("Unknown GraphQL error");
-
-        Err(ApiRetryableError::NonRetryable(format!(
-            "Failed to add issue to project: {}",
-            error_msg
-        )))
+            .map_err(|e| e.with_context("Failed to add issue to project"))
     }
 
     async fn add_pull_request_to_project_impl(
@@ -533,53 +2091,346 @@ impl GitHubClient {
             ))
         })?;
 
-        let mutation = format!(
-            r#"
-            mutation {{
-                addProjectV2ItemById(input: {{
-                    projectId: "{}"
-                    contentId: "{}"
-                }}) {{
-                    item {{
+        self.add_content_to_project(project_node_id, &pull_request_node_id)
+            .await
+            .map_err(|e| e.with_context("Failed to add pull request to project"))
+    }
+
+    /// Add an issue or pull request to a project by its node ID, returning
+    /// the ID of the new project item
+    async fn add_content_to_project(
+        &self,
+        project_node_id: &ProjectNodeId,
+        content_node_id: &str,
+    ) -> std::result::Result<ProjectItemId, ApiRetryableError> {
+        let mutation = r#"
+            mutation($projectId: ID!, $contentId: ID!) {
+                addProjectV2ItemById(input: { projectId: $projectId, contentId: $contentId }) {
+                    item {
                         id
-                    }}
-                }}
-            }}
-            "#,
-            project_node_id.value(),
-            pull_request_node_id
-        );
+                    }
+                }
+            }
+        "#;
 
-        let response = self
-            .client
-            .graphql::<serde_json::Value>(&json!({
-                "query": mutation
-            }))
-            .await
-            .map_err(ApiRetryableError::from_octocrab_error)?;
+        let data: AddProjectItemData = self
+            .execute_graphql(
+                mutation,
+                json!({ "projectId": project_node_id.value(), "contentId": content_node_id }),
+            )
+            .await?;
 
-        if let Some(data) = response.get("data") {
-            if let Some(item_id) = data
-                .get("addProjectV2ItemById")
-                .and_then(|add_result| add_result.get("item"))
-                .and_then(|item| item.get("id"))
-                .and_then(|id| id.as_str())
-            {
-                return Ok(ProjectItemId::new(item_id.to_string()));
+        data.add_project_v2_item_by_id
+            .and_then(|payload| payload.item)
+            .map(|item| ProjectItemId::new(item.id))
+            .ok_or_else(|| ApiRetryableError::NonRetryable("No item in response".to_string()))
+    }
+}
+
+/// Build a `Project` from a GraphQL `ProjectV2` object
+/// Build `ProjectV2SingleSelectFieldOptionInput` values from option names
+fn single_select_options_input(options: &[String]) -> serde_json::Value {
+    serde_json::Value::Array(
+        options
+            .iter()
+            .map(|name| json!({ "name": name, "color": "GRAY", "description": "" }))
+            .collect(),
+    )
+}
+
+/// `ProjectV2FieldConfiguration` fields selected by queries that return a `ProjectField`
+const PROJECT_V2_FIELD_CONFIGURATION_FIELDS: &str = r#"
+    ... on ProjectV2Field {
+        id
+        name
+        dataType
+    }
+    ... on ProjectV2SingleSelectField {
+        id
+        name
+        dataType
+        options {
+            id
+            name
+            color
+            description
+        }
+    }
+    ... on ProjectV2IterationField {
+        id
+        name
+        dataType
+        configuration {
+            duration
+            startDay
+            iterations {
+                id
+                title
+                startDate
+                duration
+            }
+            completedIterations {
+                id
+                title
+                startDate
+                duration
             }
         }
+    }
+"#;
+
+/// `ProjectV2` fields selected by queries that return a `Project`
+const PROJECT_V2_FIELDS: &str = r#"
+    id
+    number
+    title
+    shortDescription
+    createdAt
+    updatedAt
+    owner {
+        __typename
+        ... on User { login }
+        ... on Organization { login }
+    }
+"#;
+
+fn project_from_graphql(project: &serde_json::Value) -> Option<Project> {
+    let owner = project.get("owner")?;
+    let project_type = match owner.get("__typename")?.as_str()? {
+        "User" => ProjectType::User,
+        "Organization" => ProjectType::Organization,
+        _ => return None,
+    };
+    let owner = Owner::new(owner.get("login")?.as_str()?.to_string());
+    let node_id = project.get("id")?.as_str()?;
+    let number = project.get("number")?.as_u64()?;
+    let title = project.get("title")?.as_str()?;
+    let description = project
+        .get("shortDescription")
+        .and_then(|d| d.as_str())
+        .filter(|d| !d.is_empty())
+        .map(|d| d.to_string());
+    let created_at = project.get("createdAt")?.as_str()?.parse().ok()?;
+    let updated_at = project.get("updatedAt")?.as_str()?.parse().ok()?;
+
+    Some(Project::new(
+        ProjectId::new(owner, ProjectNumber::new(number), project_type),
+        ProjectNodeId::new(node_id.to_string()),
+        title.to_string(),
+        description,
+        created_at,
+        updated_at,
+    ))
+}
+
+/// Data of a query selecting a `user` or `organization` as `T`
+#[derive(Deserialize)]
+struct OwnerData<T> {
+    #[serde(alias = "user", alias = "organization")]
+    owner: Option<T>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OwnerProject<T> {
+    project_v2: Option<T>,
+}
+
+/// Data of a query selecting `node` as `T`
+#[derive(Deserialize)]
+struct NodeData<T> {
+    node: Option<T>,
+}
+
+#[derive(Deserialize)]
+struct ProjectDetailsNode {
+    /// The `PROJECT_V2_FIELDS` of the project, read by `project_from_graphql`
+    #[serde(flatten)]
+    project: serde_json::Value,
+    readme: Option<String>,
+    #[serde(default)]
+    public: bool,
+    #[serde(default)]
+    closed: bool,
+    items: Option<Connection<IgnoredAny>>,
+    fields: Option<Connection<serde_json::Value>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateProjectData {
+    create_project_v2: Option<ProjectPayload>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CopyProjectData {
+    copy_project_v2: Option<ProjectPayload>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateProjectData {
+    update_project_v2: Option<ProjectPayload>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProjectPayload {
+    project_v2: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct RepositoryNodeData {
+    repository: Option<NodeId>,
+}
+
+#[derive(Deserialize)]
+struct TeamNodeData {
+    organization: Option<TeamOrganization>,
+}
+
+#[derive(Deserialize)]
+struct TeamOrganization {
+    team: Option<NodeId>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateProjectFieldData {
+    create_project_v2_field: Option<ProjectFieldPayload>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateProjectFieldData {
+    update_project_v2_field: Option<ProjectFieldPayload>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProjectFieldPayload {
+    project_v2_field: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct ProjectItemsNode {
+    items: Option<Connection<serde_json::Value>>,
+}
+
+#[derive(Deserialize)]
+struct ProjectFieldsNode {
+    fields: Option<Connection<serde_json::Value>>,
+}
+
+#[derive(Deserialize)]
+struct ContentProjectItemsData {
+    repository: Option<ContentProjectItemsRepository>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ContentProjectItemsRepository {
+    issue_or_pull_request: Option<ContentProjectItems>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ContentProjectItems {
+    project_items: Option<Connection<ContentProjectItem>>,
+}
+
+#[derive(Deserialize)]
+struct ContentProjectItem {
+    id: String,
+    project: Option<NodeId>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AddDraftIssueData {
+    add_project_v2_draft_issue: Option<AddDraftIssuePayload>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AddDraftIssuePayload {
+    project_item: Option<DraftIssueItem>,
+}
+
+#[derive(Deserialize)]
+struct DraftIssueItem {
+    id: String,
+    content: Option<NodeId>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ConvertDraftIssueData {
+    convert_project_v2_draft_issue_item_to_issue: Option<ConvertDraftIssuePayload>,
+}
+
+#[derive(Deserialize)]
+struct ConvertDraftIssuePayload {
+    item: Option<ConvertedItem>,
+}
+
+#[derive(Deserialize)]
+struct ConvertedItem {
+    content: Option<ConvertedIssue>,
+}
+
+#[derive(Deserialize)]
+struct ConvertedIssue {
+    number: u32,
+}
+
+#[derive(Deserialize)]
+struct ProjectItemContentNode {
+    content: Option<ProjectItemContent>,
+}
+
+/// Issue, pull request or draft issue a project item refers to
+#[derive(Deserialize)]
+#[serde(tag = "__typename")]
+enum ProjectItemContent {
+    DraftIssue,
+    Issue {
+        number: u32,
+        repository: ContentRepository,
+    },
+    PullRequest {
+        number: u32,
+        repository: ContentRepository,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize)]
+struct ContentRepository {
+    owner: ContentRepositoryOwner,
+    name: String,
+}
 
-        let error_msg = response
-            .get("errors")
-            .and_then(|errors| errors.as_array())
-            .and_then(|arr| arr.first())
-            .and_then(|error| error.get("message"))
-            .and_then(|msg| msg.as_str())
-            .unwrap_or("Unknown GraphQL error");
+#[derive(Deserialize)]
+struct ContentRepositoryOwner {
+    login: String,
+}
 
-        Err(ApiRetryableError::NonRetryable(format!(
-            "Failed to add pull request to project: {}",
-            error_msg
-        )))
+impl ContentRepository {
+    fn into_repository_id(self) -> RepositoryId {
+        RepositoryId::new(self.owner.login, self.name)
     }
 }
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AddProjectItemData {
+    add_project_v2_item_by_id: Option<AddProjectItemPayload>,
+}
+
+#[derive(Deserialize)]
+struct AddProjectItemPayload {
+    item: Option<NodeId>,
+}
@@ -1,13 +1,20 @@
 use crate::github::client::retry_with_backoff;
 use crate::github::error::ApiRetryableError;
+use crate::github::graphql::Connection;
+use crate::types::pagination::{Page, Pagination};
 use crate::types::pull_request::{
-    Branch, PullRequest, PullRequestComment, PullRequestCommentNumber, PullRequestNumber,
-    PullRequestState,
+    Branch, BranchProtectionSummary, MergeStateStatus, MergeableState, PullRequest,
+    PullRequestComment, PullRequestCommentNumber, PullRequestHead, PullRequestId,
+    PullRequestMergeStatus, PullRequestNumber, PullRequestState, RequiredCheckState,
+    RequiredCheckStatus, ReviewDecision,
 };
 use crate::types::repository::{MilestoneNumber, RepositoryId};
 use crate::types::{User, label::Label};
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde::de::IgnoredAny;
 
 impl crate::github::client::GitHubClient {
     /// Create a new pull request
@@ -87,12 +94,13 @@ impl crate::github::client::GitHubClient {
         let octocrab_pr = pr_builder
             .send()
             .await
-            .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+            .map_err(ApiRetryableError::from_octocrab_error)?;
 
         // Convert the created PR to our internal PullRequest type
         // by fetching it again to get complete data
         let pr_number = PullRequestNumber::new(octocrab_pr.number as u32);
-        self.get_pull_request_impl(repository_id, pr_number).await
+        self.get_pull_request_impl(repository_id, pr_number, None)
+            .await
     }
     /// Get a pull request by repository ID and pull request number
     ///
@@ -100,7 +108,7 @@ impl crate::github::client::GitHubClient {
     /// - Basic metadata (title, body, state, branches)
     /// - Author and assignee information
     /// - Labels and milestone data
-    /// - Discussion comments (general PR comments, not code review comments)
+    /// - All discussion comments (general PR comments, not code review comments)
     /// - Commit and change statistics
     ///
     /// # Arguments
@@ -120,11 +128,41 @@ impl crate::github::client::GitHubClient {
         &self,
         repository_id: &RepositoryId,
         pr_number: PullRequestNumber,
+    ) -> Result<PullRequest> {
+        self.get_pull_request_with_comment_limit(repository_id, pr_number, None)
+            .await
+    }
+
+    /// Get a pull request with at most `comment_limit` of its discussion comments
+    ///
+    /// Same as `get_pull_request`, but stops fetching comment pages once the
+    /// limit is reached. Useful for pull requests with very long discussions.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `pr_number` - The pull request number
+    /// * `comment_limit` - Maximum number of comments to load (oldest first); `None` loads all
+    ///
+    /// # Returns
+    /// A complete `PullRequest` struct with up to `comment_limit` comments
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - The pull request number does not exist
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_pull_request_with_comment_limit(
+        &self,
+        repository_id: &RepositoryId,
+        pr_number: PullRequestNumber,
+        comment_limit: Option<usize>,
     ) -> Result<PullRequest> {
         let operation_name = "get_pull_request";
 
         retry_with_backoff(operation_name, None, || async {
-            self.get_pull_request_impl(repository_id, pr_number).await
+            self.get_pull_request_impl(repository_id, pr_number, comment_limit)
+                .await
         })
         .await
     }
@@ -133,6 +171,7 @@ impl crate::github::client::GitHubClient {
         &self,
         repository_id: &RepositoryId,
         pr_number: PullRequestNumber,
+        comment_limit: Option<usize>,
     ) -> std::result::Result<PullRequest, ApiRetryableError> {
         let owner = repository_id.owner().as_str();
         let repo = repository_id.repo_name().as_str();
@@ -143,32 +182,14 @@ impl crate::github::client::GitHubClient {
             .pulls(owner, repo)
             .get(number.into())
             .await
-            .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+            .map_err(ApiRetryableError::from_octocrab_error)?;
 
         // Get PR discussion comments (issue comments API is correct for general PR discussion)
-        let comments_response = self
-            .client
-            .issues(owner, repo)
-            .list_comments(number.into())
-            .send()
-            .await
-            .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
-
-        let comments: Vec<PullRequestComment> = comments_response
-            .items
+        let comments: Vec<PullRequestComment> = self
+            .fetch_issue_comments(repository_id, number.into(), comment_limit)
+            .await?
             .into_iter()
-            .map(|comment| {
-                PullRequestComment::new(
-                    PullRequestCommentNumber::new(comment.id.0),
-                    comment.body.unwrap_or_default(),
-                    Some(User::new(
-                        comment.user.login,
-                        Some(comment.user.avatar_url.to_string()),
-                    )),
-                    comment.created_at,
-                    comment.updated_at.unwrap_or(comment.created_at),
-                )
-            })
+            .map(from_octocrab_comment)
             .collect();
 
         // Convert octocrab PR state to our state enum
@@ -241,6 +262,58 @@ impl crate::github::client::GitHubClient {
         Ok(pull_request)
     }
 
+    /// List discussion comments on a pull request
+    ///
+    /// Retrieves one page of general discussion comments (not code review
+    /// comments), oldest first, optionally limited to comments updated since a
+    /// given time and to a single author. Author filtering is applied to the
+    /// fetched page, so a page may hold fewer items than requested.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `pr_number` - The pull request number
+    /// * `since` - Only comments updated at or after this time
+    /// * `author` - Only comments written by this login (case-insensitive)
+    /// * `pagination` - The page of comments to fetch
+    ///
+    /// # Returns
+    /// A page of `PullRequestComment`
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - The pull request number does not exist
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_pull_request_comments(
+        &self,
+        repository_id: &RepositoryId,
+        pr_number: PullRequestNumber,
+        since: Option<DateTime<Utc>>,
+        author: Option<&str>,
+        pagination: Pagination,
+    ) -> Result<Page<PullRequestComment>> {
+        let operation_name = "list_pull_request_comments";
+
+        retry_with_backoff(operation_name, None, || async {
+            let page = self
+                .list_comments_page(
+                    repository_id,
+                    pr_number.value().into(),
+                    since,
+                    author,
+                    pagination,
+                )
+                .await?;
+            Ok(Page::new(
+                page.items.into_iter().map(from_octocrab_comment).collect(),
+                pagination,
+                page.has_next_page,
+            ))
+        })
+        .await
+    }
+
     /// Add a comment to a pull request
     ///
     /// Creates a new comment on the specified pull request. This adds a general
@@ -291,7 +364,7 @@ impl crate::github::client::GitHubClient {
             .issues(owner, repo)
             .create_comment(number.into(), body)
             .await
-            .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+            .map_err(ApiRetryableError::from_octocrab_error)?;
 
         Ok(PullRequestCommentNumber::new(comment.id.0))
     }
@@ -350,7 +423,7 @@ impl crate::github::client::GitHubClient {
             .issues(owner, repo)
             .update_comment(octocrab::models::CommentId(comment_id), body)
             .await
-            .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+            .map_err(ApiRetryableError::from_octocrab_error)?;
 
         Ok(())
     }
@@ -407,7 +480,7 @@ impl crate::github::client::GitHubClient {
             .issues(owner, repo)
             .delete_comment(octocrab::models::CommentId(comment_id))
             .await
-            .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+            .map_err(ApiRetryableError::from_octocrab_error)?;
 
         Ok(())
     }
@@ -460,7 +533,7 @@ impl crate::github::client::GitHubClient {
             .pulls(owner, repo)
             .get(number.into())
             .await
-            .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+            .map_err(ApiRetryableError::from_octocrab_error)?;
 
         let node_id = octocrab_pr.node_id.ok_or_else(|| {
             ApiRetryableError::NonRetryable(format!(
@@ -470,45 +543,27 @@ impl crate::github::client::GitHubClient {
         })?;
 
         // Use GraphQL mutation to close the pull request
-        let mutation = format!(
-            r#"
-            mutation {{
-                closePullRequest(input: {{
-                    pullRequestId: "{}"
-                }}) {{
+        let mutation = r#"
+            mutation($pullRequestId: ID!) {
+                closePullRequest(input: { pullRequestId: $pullRequestId }) {
                     clientMutationId
-                }}
-            }}
-            "#,
-            node_id
-        );
+                }
+            }
+        "#;
 
-        // Execute GraphQL mutation
-        let response = self
-            .client
-            .graphql::<serde_json::Value>(&serde_json::json!({
-                "query": mutation
-            }))
-            .await
-            .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+        self.execute_graphql::<IgnoredAny>(
+            mutation,
+            serde_json::json!({ "pullRequestId": node_id }),
+        )
+        .await
+        .map_err(|e| {
+            e.with_context(&format!(
+                "Failed to close pull request {}/{}/{}",
+                owner, repo, number
+            ))
+        })?;
 
-        // Check if the mutation was successful
-        if response.get("data").is_some() && response.get("errors").is_none() {
-            Ok(())
-        } else {
-            let error_msg = response
-                .get("errors")
-                .and_then(|errors| errors.as_array())
-                .and_then(|arr| arr.first())
-                .and_then(|error| error.get("message"))
-                .and_then(|msg| msg.as_str())
-                .unwrap_or("Unknown GraphQL error");
-
-            Err(ApiRetryableError::NonRetryable(format!(
-                "Failed to close pull request {}/{}/{}: {}",
-                owner, repo, number, error_msg
-            )))
-        }
+        Ok(())
     }
 
     /// Add assignees to a pull request
@@ -562,7 +617,7 @@ impl crate::github::client::GitHubClient {
             .issues(owner, repo)
             .add_assignees(number.into(), &assignee_refs)
             .await
-            .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+            .map_err(ApiRetryableError::from_octocrab_error)?;
 
         Ok(())
     }
@@ -618,7 +673,7 @@ impl crate::github::client::GitHubClient {
             .issues(owner, repo)
             .remove_assignees(number.into(), &assignee_refs)
             .await
-            .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+            .map_err(ApiRetryableError::from_octocrab_error)?;
 
         Ok(())
     }
@@ -671,7 +726,9 @@ impl crate::github::client::GitHubClient {
         let number = pr_number.value();
 
         // First, get the current pull request to see existing assignees
-        let current_pr = self.get_pull_request_impl(repository_id, pr_number).await?;
+        let current_pr = self
+            .get_pull_request_impl(repository_id, pr_number, None)
+            .await?;
 
         // Extract current assignee usernames
         let current_assignees: Vec<String> = current_pr
@@ -688,7 +745,7 @@ impl crate::github::client::GitHubClient {
                 .issues(owner, repo)
                 .remove_assignees(number.into(), &current_assignee_refs)
                 .await
-                .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+                .map_err(ApiRetryableError::from_octocrab_error)?;
         }
 
         // Add new assignees if any specified
@@ -698,7 +755,7 @@ impl crate::github::client::GitHubClient {
                 .issues(owner, repo)
                 .add_assignees(number.into(), &new_assignee_refs)
                 .await
-                .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+                .map_err(ApiRetryableError::from_octocrab_error)?;
         }
 
         Ok(())
@@ -755,7 +812,7 @@ impl crate::github::client::GitHubClient {
             .title(title)
             .send()
             .await
-            .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+            .map_err(ApiRetryableError::from_octocrab_error)?;
 
         Ok(())
     }
@@ -811,7 +868,7 @@ impl crate::github::client::GitHubClient {
             .body(body)
             .send()
             .await
-            .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+            .map_err(ApiRetryableError::from_octocrab_error)?;
 
         Ok(())
     }
@@ -868,7 +925,7 @@ impl crate::github::client::GitHubClient {
             .issues(owner, repo)
             .add_labels(number.into(), &label_names)
             .await
-            .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+            .map_err(ApiRetryableError::from_octocrab_error)?;
 
         Ok(())
     }
@@ -924,7 +981,7 @@ impl crate::github::client::GitHubClient {
                 .issues(owner, repo)
                 .remove_label(number.into(), label.name())
                 .await
-                .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+                .map_err(ApiRetryableError::from_octocrab_error)?;
         }
 
         Ok(())
@@ -978,7 +1035,9 @@ impl crate::github::client::GitHubClient {
         let number = pr_number.value();
 
         // First, get the current pull request to see existing labels
-        let current_pr = self.get_pull_request_impl(repository_id, pr_number).await?;
+        let current_pr = self
+            .get_pull_request_impl(repository_id, pr_number, None)
+            .await?;
 
         // Extract current label names
         let current_labels: Vec<String> = current_pr
@@ -994,7 +1053,7 @@ impl crate::github::client::GitHubClient {
                     .issues(owner, repo)
                     .remove_label(number.into(), label)
                     .await
-                    .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+                    .map_err(ApiRetryableError::from_octocrab_error)?;
             }
         }
 
@@ -1006,7 +1065,7 @@ impl crate::github::client::GitHubClient {
                 .issues(owner, repo)
                 .add_labels(number.into(), &label_names)
                 .await
-                .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+                .map_err(ApiRetryableError::from_octocrab_error)?;
         }
 
         Ok(())
@@ -1064,7 +1123,7 @@ impl crate::github::client::GitHubClient {
             .milestone(milestone_number.value())
             .send()
             .await
-            .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+            .map_err(ApiRetryableError::from_octocrab_error)?;
 
         Ok(())
     }
@@ -1112,45 +1171,30 @@ impl crate::github::client::GitHubClient {
         let number = pr_number.value();
 
         // Use GraphQL to remove milestone by setting it to null
-        let mutation = format!(
-            r#"
-            mutation {{
-                updatePullRequest(input: {{
-                    pullRequestId: "{}"
-                    milestoneId: null
-                }}) {{
+        let pull_request_node_id = self
+            .get_pull_request_node_id(repository_id, pr_number)
+            .await?;
+        let mutation = r#"
+            mutation($pullRequestId: ID!) {
+                updatePullRequest(input: { pullRequestId: $pullRequestId, milestoneId: null }) {
                     clientMutationId
-                }}
-            }}
-            "#,
-            self.get_pull_request_node_id(repository_id, pr_number)
-                .await?
-        );
+                }
+            }
+        "#;
 
-        let response = self
-            .client
-            .graphql::<serde_json::Value>(&serde_json::json!({
-                "query": mutation
-            }))
-            .await
-            .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+        self.execute_graphql::<IgnoredAny>(
+            mutation,
+            serde_json::json!({ "pullRequestId": pull_request_node_id }),
+        )
+        .await
+        .map_err(|e| {
+            e.with_context(&format!(
+                "Failed to remove milestone from pull request {}/{}/{}",
+                owner, repo, number
+            ))
+        })?;
 
-        if response.get("data").is_some() && response.get("errors").is_none() {
-            Ok(())
-        } else {
-            let error_msg = response
-                .get("errors")
-                .and_then(|errors| errors.as_array())
-                .and_then(|arr| arr.first())
-                .and_then(|error| error.get("message"))
-                .and_then(|msg| msg.as_str())
-                .unwrap_or("Unknown GraphQL error");
-
-            Err(ApiRetryableError::NonRetryable(format!(
-                "Failed to remove milestone from pull request {}/{}/{}: {}",
-                owner, repo, number, error_msg
-            )))
-        }
+        Ok(())
     }
 
     /// Edit (replace) milestone of a pull request
@@ -1206,7 +1250,7 @@ impl crate::github::client::GitHubClient {
                 .milestone(milestone_number.value())
                 .send()
                 .await
-                .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+                .map_err(ApiRetryableError::from_octocrab_error)?;
         } else {
             // For removing milestone, use the GraphQL approach
             return self
@@ -1217,6 +1261,338 @@ impl crate::github::client::GitHubClient {
         Ok(())
     }
 
+    /// Get the SHA of the head commit of a pull request
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `pr_number` - The pull request number
+    ///
+    /// # Returns
+    /// The full SHA of the latest commit on the head branch
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or pull request does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_pull_request_head_sha(
+        &self,
+        repository_id: &RepositoryId,
+        pr_number: PullRequestNumber,
+    ) -> Result<String> {
+        let operation_name = "get_pull_request_head_sha";
+        let url = format!(
+            "{}/repos/{}/{}/pulls/{}",
+            self.api_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+            pr_number.value()
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::GET, &url, None)
+                .await?;
+            let value: serde_json::Value = response.json().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })?;
+
+            value
+                .pointer("/head/sha")
+                .and_then(|sha| sha.as_str())
+                .map(|sha| sha.to_string())
+                .ok_or_else(|| {
+                    ApiRetryableError::NonRetryable(
+                        "Pull request response is missing the head SHA".to_string(),
+                    )
+                })
+        })
+        .await
+    }
+
+    /// Get the head branch of a pull request
+    ///
+    /// Returns the branch with the repository it lives in, which is a fork of
+    /// the base repository for pull requests from forks, so the branch can be
+    /// fetched locally.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `pr_number` - The pull request number
+    ///
+    /// # Returns
+    /// The `PullRequestHead` of the pull request
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - The pull request number does not exist
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_pull_request_head(
+        &self,
+        repository_id: &RepositoryId,
+        pr_number: PullRequestNumber,
+    ) -> Result<PullRequestHead> {
+        let operation_name = "get_pull_request_head";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.get_pull_request_head_impl(repository_id, pr_number)
+                .await
+        })
+        .await
+    }
+
+    async fn get_pull_request_head_impl(
+        &self,
+        repository_id: &RepositoryId,
+        pr_number: PullRequestNumber,
+    ) -> std::result::Result<PullRequestHead, ApiRetryableError> {
+        let owner = repository_id.owner().as_str();
+        let repo = repository_id.repo_name().as_str();
+        let number = pr_number.value();
+
+        let octocrab_pr = self
+            .client
+            .pulls(owner, repo)
+            .get(number.into())
+            .await
+            .map_err(ApiRetryableError::from_octocrab_error)?;
+
+        let head = octocrab_pr.head;
+        let head_repository = head.repo.as_ref();
+        let repository = head_repository.and_then(|repo| repo.full_name.clone());
+        let base_repository = format!("{}/{}", owner, repo);
+        let is_fork = repository
+            .as_deref()
+            .is_none_or(|name| !name.eq_ignore_ascii_case(&base_repository));
+
+        Ok(PullRequestHead {
+            branch: head.ref_field,
+            sha: head.sha,
+            repository,
+            clone_url: head_repository
+                .and_then(|repo| repo.clone_url.as_ref())
+                .map(|url| url.to_string()),
+            ssh_url: head_repository.and_then(|repo| repo.ssh_url.clone()),
+            is_fork,
+            maintainer_can_modify: octocrab_pr.maintainer_can_modify,
+        })
+    }
+
+    /// Get the merge status of a pull request
+    ///
+    /// Aggregates everything that decides whether a pull request can be merged:
+    /// mergeability, the merge state computed by GitHub, the review decision,
+    /// the results of required status checks and the branch protection rule of
+    /// the base branch. The result lists every blocker found.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `pr_number` - The pull request number
+    ///
+    /// # Returns
+    /// A `PullRequestMergeStatus` describing the merge readiness and its blockers
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - The pull request number does not exist
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_pull_request_merge_status(
+        &self,
+        repository_id: &RepositoryId,
+        pr_number: PullRequestNumber,
+    ) -> Result<PullRequestMergeStatus> {
+        let operation_name = "get_pull_request_merge_status";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.get_pull_request_merge_status_impl(repository_id, pr_number)
+                .await
+        })
+        .await
+    }
+
+    async fn get_pull_request_merge_status_impl(
+        &self,
+        repository_id: &RepositoryId,
+        pr_number: PullRequestNumber,
+    ) -> std::result::Result<PullRequestMergeStatus, ApiRetryableError> {
+        let owner = repository_id.owner().as_str();
+        let repo = repository_id.repo_name().as_str();
+        let number = pr_number.value();
+
+        let query = r#"
+            query($owner: String!, $name: String!, $number: Int!) {
+                repository(owner: $owner, name: $name) {
+                    pullRequest(number: $number) {
+                        isDraft
+                        mergeable
+                        mergeStateStatus
+                        reviewDecision
+                        baseRef {
+                            branchProtectionRule {
+                                requiresApprovingReviews
+                                requiredApprovingReviewCount
+                                requiredStatusCheckContexts
+                                requiresConversationResolution
+                                requiresLinearHistory
+                                requiresCommitSignatures
+                            }
+                        }
+                        commits(last: 1) {
+                            nodes {
+                                commit {
+                                    statusCheckRollup {
+                                        contexts(first: 100) {
+                                            nodes {
+                                                __typename
+                                                ... on CheckRun {
+                                                    name
+                                                    status
+                                                    conclusion
+                                                    isRequired(pullRequestNumber: $number)
+                                                }
+                                                ... on StatusContext {
+                                                    context
+                                                    state
+                                                    isRequired(pullRequestNumber: $number)
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let data: MergeStatusData = self
+            .execute_graphql(
+                query,
+                serde_json::json!({
+                    "owner": owner,
+                    "name": repo,
+                    "number": number,
+                }),
+            )
+            .await
+            .map_err(|e| {
+                e.with_context(&format!(
+                    "Failed to get merge status of pull request {}/{}/{}",
+                    owner, repo, number
+                ))
+            })?;
+
+        let pr = data
+            .repository
+            .and_then(|r| r.pull_request)
+            .ok_or_else(|| {
+                ApiRetryableError::NonRetryable(format!(
+                    "Pull request {}/{}/{} not found",
+                    owner, repo, number
+                ))
+            })?;
+
+        let mergeable = pr
+            .mergeable
+            .and_then(|v| v.parse::<MergeableState>().ok())
+            .unwrap_or(MergeableState::Unknown);
+        let merge_state_status = pr
+            .merge_state_status
+            .and_then(|v| v.parse::<MergeStateStatus>().ok())
+            .unwrap_or(MergeStateStatus::Unknown);
+        let review_decision = pr
+            .review_decision
+            .and_then(|v| v.parse::<ReviewDecision>().ok());
+
+        // Branch protection rules are only visible with sufficient permissions
+        let branch_protection = pr
+            .base_ref
+            .and_then(|r| r.branch_protection_rule)
+            .map(|rule| BranchProtectionSummary {
+                required_approving_review_count: rule
+                    .requires_approving_reviews
+                    .filter(|required| *required)
+                    .and(rule.required_approving_review_count),
+                required_status_check_contexts: rule
+                    .required_status_check_contexts
+                    .unwrap_or_default(),
+                requires_conversation_resolution: rule
+                    .requires_conversation_resolution
+                    .unwrap_or(false),
+                requires_linear_history: rule.requires_linear_history.unwrap_or(false),
+                requires_commit_signatures: rule.requires_commit_signatures.unwrap_or(false),
+            });
+
+        let contexts = pr
+            .commits
+            .into_nodes()
+            .next()
+            .and_then(|node| node.commit.status_check_rollup)
+            .map(|rollup| rollup.contexts.into_nodes().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let mut required_checks: Vec<RequiredCheckStatus> = contexts
+            .into_iter()
+            .filter_map(|ctx| match ctx {
+                StatusCheckContext::CheckRun {
+                    name,
+                    status,
+                    conclusion,
+                    is_required: true,
+                } => {
+                    let state = match (status.as_deref(), conclusion.as_deref()) {
+                        (Some("COMPLETED"), Some("SUCCESS" | "NEUTRAL" | "SKIPPED")) => {
+                            RequiredCheckState::Passed
+                        }
+                        (Some("COMPLETED"), _) => RequiredCheckState::Failed,
+                        _ => RequiredCheckState::Pending,
+                    };
+                    Some(RequiredCheckStatus::new(name, state))
+                }
+                StatusCheckContext::StatusContext {
+                    context,
+                    state,
+                    is_required: true,
+                } => {
+                    let state = match state.as_deref() {
+                        Some("SUCCESS") => RequiredCheckState::Passed,
+                        Some("PENDING" | "EXPECTED") => RequiredCheckState::Pending,
+                        _ => RequiredCheckState::Failed,
+                    };
+                    Some(RequiredCheckStatus::new(context, state))
+                }
+                _ => None,
+            })
+            .collect();
+
+        // Required contexts that never reported on the head commit
+        if let Some(protection) = &branch_protection {
+            for context in &protection.required_status_check_contexts {
+                if !required_checks.iter().any(|check| &check.name == context) {
+                    required_checks.push(RequiredCheckStatus::new(
+                        context.clone(),
+                        RequiredCheckState::Missing,
+                    ));
+                }
+            }
+        }
+
+        Ok(PullRequestMergeStatus::new(
+            PullRequestId::new(repository_id.clone(), number),
+            pr.is_draft,
+            mergeable,
+            merge_state_status,
+            review_decision,
+            required_checks,
+            branch_protection,
+        ))
+    }
+
     /// Helper method to get pull request node ID for GraphQL operations
     async fn get_pull_request_node_id(
         &self,
@@ -1232,7 +1608,7 @@ impl crate::github::client::GitHubClient {
             .pulls(owner, repo)
             .get(number.into())
             .await
-            .map_err(|e| ApiRetryableError::from_octocrab_error(e))?;
+            .map_err(ApiRetryableError::from_octocrab_error)?;
 
         octocrab_pr.node_id.ok_or_else(|| {
             ApiRetryableError::NonRetryable(format!(
@@ -1242,3 +1618,94 @@ impl crate::github::client::GitHubClient {
         })
     }
 }
+
+fn from_octocrab_comment(comment: octocrab::models::issues::Comment) -> PullRequestComment {
+    PullRequestComment::new(
+        PullRequestCommentNumber::new(comment.id.0),
+        comment.body.unwrap_or_default(),
+        Some(User::new(
+            comment.user.login,
+            Some(comment.user.avatar_url.to_string()),
+        )),
+        comment.created_at,
+        comment.updated_at.unwrap_or(comment.created_at),
+    )
+}
+
+/// Data of the merge status query of `get_pull_request_merge_status`
+#[derive(Deserialize)]
+struct MergeStatusData {
+    repository: Option<MergeStatusRepository>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MergeStatusRepository {
+    pull_request: Option<MergeStatusPullRequest>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MergeStatusPullRequest {
+    #[serde(default)]
+    is_draft: bool,
+    mergeable: Option<String>,
+    merge_state_status: Option<String>,
+    review_decision: Option<String>,
+    base_ref: Option<MergeStatusBaseRef>,
+    commits: Connection<MergeStatusCommitNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MergeStatusBaseRef {
+    branch_protection_rule: Option<BranchProtectionRuleNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BranchProtectionRuleNode {
+    requires_approving_reviews: Option<bool>,
+    required_approving_review_count: Option<u32>,
+    required_status_check_contexts: Option<Vec<String>>,
+    requires_conversation_resolution: Option<bool>,
+    requires_linear_history: Option<bool>,
+    requires_commit_signatures: Option<bool>,
+}
+
+#[derive(Deserialize)]
+struct MergeStatusCommitNode {
+    commit: MergeStatusCommit,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MergeStatusCommit {
+    status_check_rollup: Option<StatusCheckRollupNode>,
+}
+
+#[derive(Deserialize)]
+struct StatusCheckRollupNode {
+    contexts: Connection<StatusCheckContext>,
+}
+
+/// Check run or commit status reported on the head commit
+#[derive(Deserialize)]
+#[serde(tag = "__typename")]
+enum StatusCheckContext {
+    #[serde(rename_all = "camelCase")]
+    CheckRun {
+        name: String,
+        status: Option<String>,
+        conclusion: Option<String>,
+        #[serde(default)]
+        is_required: bool,
+    },
+    #[serde(rename_all = "camelCase")]
+    StatusContext {
+        context: String,
+        state: Option<String>,
+        #[serde(default)]
+        is_required: bool,
+    },
+}
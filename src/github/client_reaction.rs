@@ -0,0 +1,269 @@
+use crate::github::client::{GitHubClient, retry_with_backoff};
+use crate::github::error::ApiRetryableError;
+use crate::types::User;
+use crate::types::reaction::{Reaction, ReactionId, ReactionKind, ReactionTarget};
+use crate::types::repository::RepositoryId;
+
+use anyhow::Result;
+use octocrab::models::reactions::ReactionContent;
+
+/// Maximum page size supported by the GitHub reactions API
+const REACTIONS_PER_PAGE: u8 = 100;
+
+impl GitHubClient {
+    /// Add a reaction to an issue, pull request, or comment
+    ///
+    /// Adds an emoji reaction as the authenticated user. If the user already
+    /// reacted with the same kind, GitHub returns the existing reaction.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `target` - The issue, pull request, or comment to react to
+    /// * `kind` - The reaction kind to add
+    ///
+    /// # Returns
+    /// The created (or already existing) `Reaction`
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - The target issue, pull request, or comment does not exist
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn add_reaction(
+        &self,
+        repository_id: &RepositoryId,
+        target: ReactionTarget,
+        kind: ReactionKind,
+    ) -> Result<Reaction> {
+        let operation_name = "add_reaction";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.add_reaction_impl(repository_id, target, kind).await
+        })
+        .await
+    }
+
+    async fn add_reaction_impl(
+        &self,
+        repository_id: &RepositoryId,
+        target: ReactionTarget,
+        kind: ReactionKind,
+    ) -> std::result::Result<Reaction, ApiRetryableError> {
+        let owner = repository_id.owner().as_str();
+        let repo = repository_id.repo_name().as_str();
+        let issues_handler = self.client.issues(owner, repo);
+        let content = to_reaction_content(kind);
+
+        let reaction = match (target.issue_number(), target.comment_number()) {
+            (Some(number), _) => issues_handler.create_reaction(number, content).await,
+            (_, Some(comment_id)) => {
+                issues_handler
+                    .create_comment_reaction(comment_id, content)
+                    .await
+            }
+            _ => unreachable!("reaction target is either an issue or a comment"),
+        }
+        .map_err(ApiRetryableError::from_octocrab_error)?;
+
+        Ok(from_octocrab_reaction(reaction))
+    }
+
+    /// List reactions on an issue, pull request, or comment
+    ///
+    /// Fetches all pages of reactions, optionally keeping only one kind.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `target` - The issue, pull request, or comment to list reactions for
+    /// * `kind` - Optional reaction kind to filter by
+    ///
+    /// # Returns
+    /// A vector of `Reaction` in the order returned by GitHub
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - The target issue, pull request, or comment does not exist
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_reactions(
+        &self,
+        repository_id: &RepositoryId,
+        target: ReactionTarget,
+        kind: Option<ReactionKind>,
+    ) -> Result<Vec<Reaction>> {
+        let operation_name = "list_reactions";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.list_reactions_impl(repository_id, target, kind).await
+        })
+        .await
+    }
+
+    async fn list_reactions_impl(
+        &self,
+        repository_id: &RepositoryId,
+        target: ReactionTarget,
+        kind: Option<ReactionKind>,
+    ) -> std::result::Result<Vec<Reaction>, ApiRetryableError> {
+        let owner = repository_id.owner().as_str();
+        let repo = repository_id.repo_name().as_str();
+        let issues_handler = self.client.issues(owner, repo);
+
+        let mut reactions = Vec::new();
+        let mut page: u32 = 1;
+
+        loop {
+            let response = match (target.issue_number(), target.comment_number()) {
+                (Some(number), _) => {
+                    issues_handler
+                        .list_reactions(number)
+                        .per_page(REACTIONS_PER_PAGE)
+                        .page(page)
+                        .send()
+                        .await
+                }
+                (_, Some(comment_id)) => {
+                    issues_handler
+                        .list_comment_reactions(comment_id)
+                        .per_page(REACTIONS_PER_PAGE)
+                        .page(page)
+                        .send()
+                        .await
+                }
+                _ => unreachable!("reaction target is either an issue or a comment"),
+            }
+            .map_err(ApiRetryableError::from_octocrab_error)?;
+
+            let has_next = response.next.is_some();
+            reactions.extend(response.items.into_iter().map(from_octocrab_reaction));
+
+            if !has_next {
+                break;
+            }
+            page += 1;
+        }
+
+        if let Some(kind) = kind {
+            reactions.retain(|reaction| reaction.kind == kind);
+        }
+
+        Ok(reactions)
+    }
+
+    /// Remove the authenticated user's reaction from an issue, pull request, or comment
+    ///
+    /// Looks up the reaction of the given kind left by the authenticated user
+    /// and deletes it. Reactions of other users are never touched.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `target` - The issue, pull request, or comment to remove the reaction from
+    /// * `kind` - The reaction kind to remove
+    ///
+    /// # Returns
+    /// `true` if a reaction was removed, `false` if the user had not reacted with that kind
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - The target issue, pull request, or comment does not exist
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn remove_reaction(
+        &self,
+        repository_id: &RepositoryId,
+        target: ReactionTarget,
+        kind: ReactionKind,
+    ) -> Result<bool> {
+        let operation_name = "remove_reaction";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.remove_reaction_impl(repository_id, target, kind).await
+        })
+        .await
+    }
+
+    async fn remove_reaction_impl(
+        &self,
+        repository_id: &RepositoryId,
+        target: ReactionTarget,
+        kind: ReactionKind,
+    ) -> std::result::Result<bool, ApiRetryableError> {
+        let owner = repository_id.owner().as_str();
+        let repo = repository_id.repo_name().as_str();
+
+        let viewer = self
+            .client
+            .current()
+            .user()
+            .await
+            .map_err(ApiRetryableError::from_octocrab_error)?;
+
+        let reactions = self
+            .list_reactions_impl(repository_id, target, Some(kind))
+            .await?;
+        let Some(reaction_id) = reactions
+            .iter()
+            .find(|reaction| reaction.user.username == viewer.login)
+            .map(|reaction| reaction.id)
+        else {
+            return Ok(false);
+        };
+
+        let issues_handler = self.client.issues(owner, repo);
+        match (target.issue_number(), target.comment_number()) {
+            (Some(number), _) => {
+                issues_handler
+                    .delete_reaction(number, reaction_id.value())
+                    .await
+            }
+            (_, Some(comment_id)) => {
+                issues_handler
+                    .delete_comment_reaction(comment_id, reaction_id.value())
+                    .await
+            }
+            _ => unreachable!("reaction target is either an issue or a comment"),
+        }
+        .map_err(ApiRetryableError::from_octocrab_error)?;
+
+        Ok(true)
+    }
+}
+
+fn to_reaction_content(kind: ReactionKind) -> ReactionContent {
+    match kind {
+        ReactionKind::PlusOne => ReactionContent::PlusOne,
+        ReactionKind::MinusOne => ReactionContent::MinusOne,
+        ReactionKind::Laugh => ReactionContent::Laugh,
+        ReactionKind::Confused => ReactionContent::Confused,
+        ReactionKind::Heart => ReactionContent::Heart,
+        ReactionKind::Hooray => ReactionContent::Hooray,
+        ReactionKind::Rocket => ReactionContent::Rocket,
+        ReactionKind::Eyes => ReactionContent::Eyes,
+    }
+}
+
+fn from_octocrab_reaction(reaction: octocrab::models::reactions::Reaction) -> Reaction {
+    let kind = match reaction.content {
+        ReactionContent::PlusOne => ReactionKind::PlusOne,
+        ReactionContent::MinusOne => ReactionKind::MinusOne,
+        ReactionContent::Laugh => ReactionKind::Laugh,
+        ReactionContent::Confused => ReactionKind::Confused,
+        ReactionContent::Heart => ReactionKind::Heart,
+        ReactionContent::Hooray => ReactionKind::Hooray,
+        ReactionContent::Rocket => ReactionKind::Rocket,
+        ReactionContent::Eyes => ReactionKind::Eyes,
+    };
+
+    Reaction::new(
+        ReactionId::new(reaction.id.0),
+        kind,
+        User::new(
+            reaction.user.login,
+            Some(reaction.user.avatar_url.to_string()),
+        ),
+        reaction.created_at,
+    )
+}
@@ -0,0 +1,261 @@
+use crate::github::client::{GitHubClient, retry_with_backoff};
+use crate::github::error::ApiRetryableError;
+use crate::types::release::{GeneratedReleaseNotes, Release, ReleaseId, ReleaseUpdate};
+use crate::types::repository::RepositoryId;
+
+use anyhow::Result;
+use serde_json::json;
+
+impl GitHubClient {
+    /// Create a release
+    ///
+    /// The tag is created from `target_commitish` if it does not exist yet.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `tag_name` - The tag of the release (e.g. "v1.0.0")
+    /// * `name` - Optional release title (defaults to the tag name)
+    /// * `body` - Optional release notes in Markdown
+    /// * `draft` - Whether the release is created as an unpublished draft
+    /// * `prerelease` - Whether the release is marked as a prerelease
+    /// * `target_commitish` - Optional branch or commit SHA for a new tag (defaults to the default branch)
+    ///
+    /// # Returns
+    /// The created `Release`
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - A release for the tag already exists
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_release(
+        &self,
+        repository_id: &RepositoryId,
+        tag_name: &str,
+        name: Option<&str>,
+        body: Option<&str>,
+        draft: bool,
+        prerelease: bool,
+        target_commitish: Option<&str>,
+    ) -> Result<Release> {
+        let operation_name = "create_release";
+
+        let mut request_body = json!({
+            "tag_name": tag_name,
+            "draft": draft,
+            "prerelease": prerelease,
+        });
+        if let Some(name) = name {
+            request_body["name"] = json!(name);
+        }
+        if let Some(body) = body {
+            request_body["body"] = json!(body);
+        }
+        if let Some(target_commitish) = target_commitish {
+            request_body["target_commitish"] = json!(target_commitish);
+        }
+
+        retry_with_backoff(operation_name, None, || async {
+            self.send_release_request(
+                reqwest::Method::POST,
+                &self.releases_url(repository_id, ""),
+                &request_body,
+            )
+            .await
+        })
+        .await
+    }
+
+    /// Get a published release by its tag
+    ///
+    /// Draft releases have no tag yet and can only be addressed by ID.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `tag_name` - The tag of the release
+    ///
+    /// # Returns
+    /// The `Release` for the tag
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or release does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_release_by_tag(
+        &self,
+        repository_id: &RepositoryId,
+        tag_name: &str,
+    ) -> Result<Release> {
+        let operation_name = "get_release_by_tag";
+
+        retry_with_backoff(operation_name, None, || async {
+            let url = self.releases_url(
+                repository_id,
+                &format!("/tags/{}", urlencoding::encode(tag_name)),
+            );
+            let response = self
+                .send_rest_request(reqwest::Method::GET, &url, None)
+                .await?;
+            response.json::<Release>().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })
+        })
+        .await
+    }
+
+    /// Update a release
+    ///
+    /// Publishing a draft is done by setting `draft` to `false`.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `release_id` - The release identifier
+    /// * `update` - The settings to change; unset fields keep their current value
+    ///
+    /// # Returns
+    /// The updated `Release`
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - No setting is given
+    /// - The repository or release does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn update_release(
+        &self,
+        repository_id: &RepositoryId,
+        release_id: ReleaseId,
+        update: &ReleaseUpdate,
+    ) -> Result<Release> {
+        if update.is_empty() {
+            return Err(anyhow::anyhow!("No release settings to update"));
+        }
+
+        let operation_name = "update_release";
+        let request_body = update.to_request_body();
+
+        retry_with_backoff(operation_name, None, || async {
+            self.send_release_request(
+                reqwest::Method::PATCH,
+                &self.releases_url(repository_id, &format!("/{}", release_id)),
+                &request_body,
+            )
+            .await
+        })
+        .await
+    }
+
+    /// Delete a release
+    ///
+    /// The tag of the release is kept.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `release_id` - The release identifier
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the release was successfully deleted
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or release does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn delete_release(
+        &self,
+        repository_id: &RepositoryId,
+        release_id: ReleaseId,
+    ) -> Result<()> {
+        let operation_name = "delete_release";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.send_rest_request(
+                reqwest::Method::DELETE,
+                &self.releases_url(repository_id, &format!("/{}", release_id)),
+                None,
+            )
+            .await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Generate release notes from the pull requests merged since the previous release
+    ///
+    /// The notes are only generated, not saved; pass them to `create_release`
+    /// or `update_release`.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `tag_name` - The tag of the release the notes are for
+    /// * `previous_tag_name` - Optional tag to start from (defaults to the latest release)
+    /// * `target_commitish` - Optional branch or commit SHA if the tag does not exist yet
+    ///
+    /// # Returns
+    /// The generated release title and Markdown body
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn generate_release_notes(
+        &self,
+        repository_id: &RepositoryId,
+        tag_name: &str,
+        previous_tag_name: Option<&str>,
+        target_commitish: Option<&str>,
+    ) -> Result<GeneratedReleaseNotes> {
+        let operation_name = "generate_release_notes";
+
+        let mut request_body = json!({ "tag_name": tag_name });
+        if let Some(previous_tag_name) = previous_tag_name {
+            request_body["previous_tag_name"] = json!(previous_tag_name);
+        }
+        if let Some(target_commitish) = target_commitish {
+            request_body["target_commitish"] = json!(target_commitish);
+        }
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(
+                    reqwest::Method::POST,
+                    &self.releases_url(repository_id, "/generate-notes"),
+                    Some(&request_body),
+                )
+                .await?;
+            response.json::<GeneratedReleaseNotes>().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })
+        })
+        .await
+    }
+
+    async fn send_release_request(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        body: &serde_json::Value,
+    ) -> std::result::Result<Release, ApiRetryableError> {
+        let response = self.send_rest_request(method, url, Some(body)).await?;
+        response.json::<Release>().await.map_err(|e| {
+            ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+        })
+    }
+}
+
+impl GitHubClient {
+    /// Build a URL below the releases endpoint of a repository
+    fn releases_url(&self, repository_id: &RepositoryId, suffix: &str) -> String {
+        format!(
+            "{}/repos/{}/{}/releases{}",
+            self.api_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+            suffix
+        )
+    }
+}
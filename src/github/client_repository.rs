@@ -1,11 +1,14 @@
-use crate::github::client::{GitHubClient, retry_with_backoff};
+use crate::github::client::{GitHubClient, has_next_page, retry_with_backoff, send_reported};
+use crate::github::dry_run;
 use crate::github::error::ApiRetryableError;
 use crate::types::label::Label;
 use crate::types::milestone::{Milestone, MilestoneState};
-use crate::types::repository::{MilestoneNumber, RepositoryId};
+use crate::types::pagination::{Page, Pagination};
+use crate::types::repository::{MilestoneNumber, Repository, RepositoryId};
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 struct GitHubMilestoneResponse {
@@ -19,6 +22,29 @@ struct GitHubMilestoneResponse {
     due_on: Option<chrono::DateTime<chrono::Utc>>,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default)]
+    closed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl GitHubMilestoneResponse {
+    fn into_milestone(self) -> Milestone {
+        let state = match self.state.as_str() {
+            "closed" => MilestoneState::Closed,
+            _ => MilestoneState::Open,
+        };
+        Milestone::new(
+            MilestoneNumber::new(self.number as u64),
+            self.title,
+            self.description,
+            state,
+            self.open_issues,
+            self.closed_issues,
+            self.due_on,
+            self.created_at,
+            self.updated_at.unwrap_or(self.created_at),
+            self.closed_at,
+        )
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -28,7 +54,92 @@ struct GitHubLabelResponse {
     description: Option<String>,
 }
 
+impl GitHubLabelResponse {
+    fn into_label(self) -> Label {
+        Label::new_with_description(self.name, Some(self.color), self.description)
+    }
+}
+
+#[derive(Deserialize)]
+struct RepositoryData {
+    repository: Option<serde_json::Value>,
+}
+
 impl GitHubClient {
+    /// Get an overview of a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    ///
+    /// # Returns
+    /// The `Repository` with its description, default branch, visibility, topics,
+    /// open issue and pull request counts, license and the permission of the
+    /// authenticated user
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_repository(&self, repository_id: &RepositoryId) -> Result<Repository> {
+        let operation_name = "get_repository";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.get_repository_impl(repository_id).await
+        })
+        .await
+    }
+
+    async fn get_repository_impl(
+        &self,
+        repository_id: &RepositoryId,
+    ) -> std::result::Result<Repository, ApiRetryableError> {
+        let query = r#"
+            query($owner: String!, $name: String!) {
+                repository(owner: $owner, name: $name) {
+                    name
+                    owner { login }
+                    url
+                    description
+                    homepageUrl
+                    defaultBranchRef { name }
+                    visibility
+                    repositoryTopics(first: 20) {
+                        nodes { topic { name } }
+                    }
+                    issues(states: OPEN) { totalCount }
+                    pullRequests(states: OPEN) { totalCount }
+                    licenseInfo { spdxId name }
+                    viewerPermission
+                    isArchived
+                    isFork
+                    stargazerCount
+                }
+            }
+        "#;
+
+        let data: RepositoryData = self
+            .execute_graphql(
+                query,
+                json!({
+                    "owner": repository_id.owner().as_str(),
+                    "name": repository_id.repo_name().as_str(),
+                }),
+            )
+            .await
+            .map_err(|e| e.with_context(&format!("Failed to get repository {}", repository_id)))?;
+
+        let repository_value = data.repository.ok_or_else(|| {
+            ApiRetryableError::NonRetryable(format!("Repository {} not found", repository_id))
+        })?;
+
+        Repository::from_graphql_value(&repository_value).ok_or_else(|| {
+            ApiRetryableError::NonRetryable(
+                "Failed to get repository: unexpected response shape".to_string(),
+            )
+        })
+    }
+
     /// Create a new milestone in a repository
     ///
     /// Creates a new milestone in the specified repository with the provided title and optional
@@ -103,23 +214,28 @@ impl GitHubClient {
         };
         request_body["state"] = serde_json::Value::String(state_str.to_string());
 
-        let url = format!("https://api.github.com/repos/{}/{}/milestones", owner, repo);
+        let url = format!("{}/repos/{}/{}/milestones", self.api_base_url, owner, repo);
         tracing::debug!("Using URL: {}", url);
         tracing::debug!("Request body: {}", request_body);
 
-        let token = self.token.as_ref().ok_or_else(|| {
+        let token = self.token_for_url(&url).ok_or_else(|| {
             ApiRetryableError::NonRetryable("GitHub token not configured".to_string())
         })?;
 
-        let client = reqwest::Client::new();
-        let response = client
+        if dry_run::intercept_json("POST", &url, Some(&request_body)) {
+            return Err(ApiRetryableError::NonRetryable(
+                dry_run::DRY_RUN_MESSAGE.to_string(),
+            ));
+        }
+
+        let request = self
+            .http_client
             .post(&url)
             .header("Authorization", format!("token {}", token))
-            .header("User-Agent", "github-edit-cli")
             .header("Accept", "application/vnd.github.v3+json")
-            .json(&request_body)
-            .send()
-            .await
+            .json(&request_body);
+        let response = send_reported(request, "POST", &url, Some(&request_body)).await;
+        let response = response
             .map_err(|e| ApiRetryableError::Retryable(format!("HTTP request failed: {}", e)))?;
 
         if !response.status().is_success() {
@@ -129,42 +245,14 @@ impl GitHubClient {
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
             let error_msg = format!("GitHub API error {}: {}", status, error_text);
-            return Err(if status.is_server_error() {
-                ApiRetryableError::Retryable(error_msg)
-            } else if status == 429 {
-                ApiRetryableError::RateLimit
-            } else {
-                ApiRetryableError::NonRetryable(error_msg)
-            });
+            return Err(ApiRetryableError::from_status(status.as_u16(), error_msg));
         }
 
         let github_milestone: GitHubMilestoneResponse = response.json().await.map_err(|e| {
             ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
         })?;
 
-        // Convert GitHub API response to our milestone type
-        let milestone_state = match github_milestone.state.as_str() {
-            "open" => MilestoneState::Open,
-            "closed" => MilestoneState::Closed,
-            _ => MilestoneState::Open, // Default to Open for any unknown states
-        };
-
-        let milestone = Milestone::new(
-            MilestoneNumber::new(github_milestone.number as u64),
-            github_milestone.title,
-            github_milestone.description,
-            milestone_state,
-            github_milestone.open_issues,
-            github_milestone.closed_issues,
-            github_milestone.due_on,
-            github_milestone.created_at,
-            github_milestone
-                .updated_at
-                .unwrap_or(github_milestone.created_at),
-            None, // New milestone is not closed
-        );
-
-        Ok(milestone)
+        Ok(github_milestone.into_milestone())
     }
 
     /// Delete a milestone from a repository
@@ -211,24 +299,30 @@ impl GitHubClient {
         // Use direct GitHub API call instead of octacrab to avoid URI parsing bugs
         // REV: octacrab 0.44.1 fails with relative paths, full URLs work reliably
         let url = format!(
-            "https://api.github.com/repos/{}/{}/milestones/{}",
+            "{}/repos/{}/{}/milestones/{}",
+            self.api_base_url,
             owner,
             repo,
             milestone_number.value()
         );
 
-        let token = self.token.as_ref().ok_or_else(|| {
+        let token = self.token_for_url(&url).ok_or_else(|| {
             ApiRetryableError::NonRetryable("GitHub token not configured".to_string())
         })?;
 
-        let client = reqwest::Client::new();
-        let response = client
+        if dry_run::intercept_json("DELETE", &url, None) {
+            return Err(ApiRetryableError::NonRetryable(
+                dry_run::DRY_RUN_MESSAGE.to_string(),
+            ));
+        }
+
+        let request = self
+            .http_client
             .delete(&url)
             .header("Authorization", format!("token {}", token))
-            .header("User-Agent", "github-edit-cli")
-            .header("Accept", "application/vnd.github.v3+json")
-            .send()
-            .await
+            .header("Accept", "application/vnd.github.v3+json");
+        let response = send_reported(request, "DELETE", &url, None).await;
+        let response = response
             .map_err(|e| ApiRetryableError::Retryable(format!("HTTP request failed: {}", e)))?;
 
         if !response.status().is_success() {
@@ -238,13 +332,7 @@ impl GitHubClient {
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
             let error_msg = format!("GitHub API error {}: {}", status, error_text);
-            return Err(if status.is_server_error() {
-                ApiRetryableError::Retryable(error_msg)
-            } else if status == 429 {
-                ApiRetryableError::RateLimit
-            } else {
-                ApiRetryableError::NonRetryable(error_msg)
-            });
+            return Err(ApiRetryableError::from_status(status.as_u16(), error_msg));
         }
 
         Ok(())
@@ -336,7 +424,8 @@ impl GitHubClient {
         // Use direct GitHub API call instead of octacrab to avoid URI parsing bugs
         // REV: octacrab 0.44.1 fails with relative paths, full URLs work reliably
         let url = format!(
-            "https://api.github.com/repos/{}/{}/milestones/{}",
+            "{}/repos/{}/{}/milestones/{}",
+            self.api_base_url,
             owner,
             repo,
             milestone_number.value()
@@ -345,19 +434,24 @@ impl GitHubClient {
         tracing::debug!("Update milestone ID: {}", milestone_number.value());
         tracing::debug!("Request body: {}", request_body);
 
-        let token = self.token.as_ref().ok_or_else(|| {
+        let token = self.token_for_url(&url).ok_or_else(|| {
             ApiRetryableError::NonRetryable("GitHub token not configured".to_string())
         })?;
 
-        let client = reqwest::Client::new();
-        let response = client
+        if dry_run::intercept_json("PATCH", &url, Some(&request_body)) {
+            return Err(ApiRetryableError::NonRetryable(
+                dry_run::DRY_RUN_MESSAGE.to_string(),
+            ));
+        }
+
+        let request = self
+            .http_client
             .patch(&url)
             .header("Authorization", format!("token {}", token))
-            .header("User-Agent", "github-edit-cli")
             .header("Accept", "application/vnd.github.v3+json")
-            .json(&request_body)
-            .send()
-            .await
+            .json(&request_body);
+        let response = send_reported(request, "PATCH", &url, Some(&request_body)).await;
+        let response = response
             .map_err(|e| ApiRetryableError::Retryable(format!("HTTP request failed: {}", e)))?;
 
         if !response.status().is_success() {
@@ -367,13 +461,7 @@ impl GitHubClient {
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
             let error_msg = format!("GitHub API error {}: {}", status, error_text);
-            return Err(if status.is_server_error() {
-                ApiRetryableError::Retryable(error_msg)
-            } else if status == 429 {
-                ApiRetryableError::RateLimit
-            } else {
-                ApiRetryableError::NonRetryable(error_msg)
-            });
+            return Err(ApiRetryableError::from_status(status.as_u16(), error_msg));
         }
 
         let github_milestone: GitHubMilestoneResponse = response.json().await.map_err(|e| {
@@ -413,6 +501,73 @@ impl GitHubClient {
         Ok(milestone)
     }
 
+    /// List the milestones of a repository
+    ///
+    /// Fetches every page, so the result contains all matching milestones.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `state` - Optional state filter (`None` lists open and closed milestones)
+    ///
+    /// # Returns
+    /// The milestones ordered by due date
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_milestones(
+        &self,
+        repository_id: &RepositoryId,
+        state: Option<MilestoneState>,
+    ) -> Result<Vec<Milestone>> {
+        let operation_name = "list_milestones";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.list_milestones_impl(repository_id, state).await
+        })
+        .await
+    }
+
+    async fn list_milestones_impl(
+        &self,
+        repository_id: &RepositoryId,
+        state: Option<MilestoneState>,
+    ) -> std::result::Result<Vec<Milestone>, ApiRetryableError> {
+        let state_str = state.map(|s| s.to_string()).unwrap_or("all".to_string());
+
+        let mut milestones = Vec::new();
+        let mut page = 1;
+        loop {
+            let url = format!(
+                "{}/repos/{}/{}/milestones?state={}&sort=due_on&per_page=100&page={}",
+                self.api_base_url,
+                repository_id.owner().as_str(),
+                repository_id.repo_name().as_str(),
+                state_str,
+                page
+            );
+
+            let response = self
+                .send_rest_request(reqwest::Method::GET, &url, None)
+                .await?;
+            let has_next = has_next_page(&response);
+
+            let entries: Vec<GitHubMilestoneResponse> = response.json().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })?;
+            milestones.extend(entries.into_iter().map(|m| m.into_milestone()));
+
+            if !has_next {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(milestones)
+    }
+
     /// Create a new label in a repository
     ///
     /// Creates a new label in the specified repository with the provided name, optional color, and optional
@@ -470,7 +625,7 @@ impl GitHubClient {
         // Use direct GitHub API call for label operations
         // REV: octocrab doesn't provide repository label operations through issues().labels()
         // Repository labels are managed through the repos API, not issues API
-        let url = format!("https://api.github.com/repos/{}/{}/labels", owner, repo);
+        let url = format!("{}/repos/{}/{}/labels", self.api_base_url, owner, repo);
 
         let mut request_body = serde_json::json!({
             "name": name,
@@ -481,19 +636,24 @@ impl GitHubClient {
             request_body["description"] = serde_json::Value::String(description.to_string());
         }
 
-        let token = self.token.as_ref().ok_or_else(|| {
+        let token = self.token_for_url(&url).ok_or_else(|| {
             ApiRetryableError::NonRetryable("GitHub token not configured".to_string())
         })?;
 
-        let client = reqwest::Client::new();
-        let response = client
+        if dry_run::intercept_json("POST", &url, Some(&request_body)) {
+            return Err(ApiRetryableError::NonRetryable(
+                dry_run::DRY_RUN_MESSAGE.to_string(),
+            ));
+        }
+
+        let request = self
+            .http_client
             .post(&url)
             .header("Authorization", format!("token {}", token))
-            .header("User-Agent", "github-edit-cli")
             .header("Accept", "application/vnd.github.v3+json")
-            .json(&request_body)
-            .send()
-            .await
+            .json(&request_body);
+        let response = send_reported(request, "POST", &url, Some(&request_body)).await;
+        let response = response
             .map_err(|e| ApiRetryableError::Retryable(format!("HTTP request failed: {}", e)))?;
 
         if !response.status().is_success() {
@@ -503,13 +663,7 @@ impl GitHubClient {
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
             let error_msg = format!("GitHub API error {}: {}", status, error_text);
-            return Err(if status.is_server_error() {
-                ApiRetryableError::Retryable(error_msg)
-            } else if status == 429 {
-                ApiRetryableError::RateLimit
-            } else {
-                ApiRetryableError::NonRetryable(error_msg)
-            });
+            return Err(ApiRetryableError::from_status(status.as_u16(), error_msg));
         }
 
         let result = response.json::<GitHubLabelResponse>().await;
@@ -584,8 +738,11 @@ impl GitHubClient {
         // REV: octocrab doesn't provide repository label operations through issues().labels()
         // Repository labels are managed through the repos API, not issues API
         let url = format!(
-            "https://api.github.com/repos/{}/{}/labels/{}",
-            owner, repo, old_name
+            "{}/repos/{}/{}/labels/{}",
+            self.api_base_url,
+            owner,
+            repo,
+            urlencoding::encode(old_name)
         );
 
         let mut request_body = serde_json::json!({});
@@ -602,19 +759,24 @@ impl GitHubClient {
             request_body["description"] = serde_json::Value::String(description.to_string());
         }
 
-        let token = self.token.as_ref().ok_or_else(|| {
+        let token = self.token_for_url(&url).ok_or_else(|| {
             ApiRetryableError::NonRetryable("GitHub token not configured".to_string())
         })?;
 
-        let client = reqwest::Client::new();
-        let response = client
+        if dry_run::intercept_json("PATCH", &url, Some(&request_body)) {
+            return Err(ApiRetryableError::NonRetryable(
+                dry_run::DRY_RUN_MESSAGE.to_string(),
+            ));
+        }
+
+        let request = self
+            .http_client
             .patch(&url)
             .header("Authorization", format!("token {}", token))
-            .header("User-Agent", "github-edit-cli")
             .header("Accept", "application/vnd.github.v3+json")
-            .json(&request_body)
-            .send()
-            .await
+            .json(&request_body);
+        let response = send_reported(request, "PATCH", &url, Some(&request_body)).await;
+        let response = response
             .map_err(|e| ApiRetryableError::Retryable(format!("HTTP request failed: {}", e)))?;
 
         if !response.status().is_success() {
@@ -624,13 +786,7 @@ impl GitHubClient {
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
             let error_msg = format!("GitHub API error {}: {}", status, error_text);
-            return Err(if status.is_server_error() {
-                ApiRetryableError::Retryable(error_msg)
-            } else if status == 429 {
-                ApiRetryableError::RateLimit
-            } else {
-                ApiRetryableError::NonRetryable(error_msg)
-            });
+            return Err(ApiRetryableError::from_status(status.as_u16(), error_msg));
         }
 
         let result = response.json::<GitHubLabelResponse>().await;
@@ -691,22 +847,30 @@ impl GitHubClient {
         // REV: octocrab doesn't provide repository label operations through issues().labels()
         // Repository labels are managed through the repos API, not issues API
         let url = format!(
-            "https://api.github.com/repos/{}/{}/labels/{}",
-            owner, repo, label_name
+            "{}/repos/{}/{}/labels/{}",
+            self.api_base_url,
+            owner,
+            repo,
+            urlencoding::encode(label_name)
         );
 
-        let token = self.token.as_ref().ok_or_else(|| {
+        let token = self.token_for_url(&url).ok_or_else(|| {
             ApiRetryableError::NonRetryable("GitHub token not configured".to_string())
         })?;
 
-        let client = reqwest::Client::new();
-        let response = client
+        if dry_run::intercept_json("DELETE", &url, None) {
+            return Err(ApiRetryableError::NonRetryable(
+                dry_run::DRY_RUN_MESSAGE.to_string(),
+            ));
+        }
+
+        let request = self
+            .http_client
             .delete(&url)
             .header("Authorization", format!("token {}", token))
-            .header("User-Agent", "github-edit-cli")
-            .header("Accept", "application/vnd.github.v3+json")
-            .send()
-            .await
+            .header("Accept", "application/vnd.github.v3+json");
+        let response = send_reported(request, "DELETE", &url, None).await;
+        let response = response
             .map_err(|e| ApiRetryableError::Retryable(format!("HTTP request failed: {}", e)))?;
 
         if !response.status().is_success() {
@@ -716,15 +880,63 @@ impl GitHubClient {
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
             let error_msg = format!("GitHub API error {}: {}", status, error_text);
-            return Err(if status.is_server_error() {
-                ApiRetryableError::Retryable(error_msg)
-            } else if status == 429 {
-                ApiRetryableError::RateLimit
-            } else {
-                ApiRetryableError::NonRetryable(error_msg)
-            });
+            return Err(ApiRetryableError::from_status(status.as_u16(), error_msg));
         }
 
         Ok(())
     }
+
+    /// List the labels of a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `pagination` - The page of labels to fetch
+    ///
+    /// # Returns
+    /// A page of `Label` with name, color and description
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or is not accessible
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_labels(
+        &self,
+        repository_id: &RepositoryId,
+        pagination: Pagination,
+    ) -> Result<Page<Label>> {
+        let operation_name = "list_labels";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.list_labels_impl(repository_id, pagination).await
+        })
+        .await
+    }
+
+    async fn list_labels_impl(
+        &self,
+        repository_id: &RepositoryId,
+        pagination: Pagination,
+    ) -> std::result::Result<Page<Label>, ApiRetryableError> {
+        let url = format!(
+            "{}/repos/{}/{}/labels?per_page={}&page={}",
+            self.api_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str(),
+            pagination.per_page,
+            pagination.page
+        );
+
+        let response = self
+            .send_rest_request(reqwest::Method::GET, &url, None)
+            .await?;
+        let has_next = has_next_page(&response);
+
+        let entries: Vec<GitHubLabelResponse> = response.json().await.map_err(|e| {
+            ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+        })?;
+        let labels = entries.into_iter().map(|l| l.into_label()).collect();
+
+        Ok(Page::new(labels, pagination, has_next))
+    }
 }
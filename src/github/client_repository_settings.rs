@@ -0,0 +1,150 @@
+use crate::github::client::{GitHubClient, retry_with_backoff};
+use crate::github::error::ApiRetryableError;
+use crate::types::repository::{RepositoryId, RepositorySettings};
+
+use anyhow::Result;
+use serde_json::json;
+
+impl GitHubClient {
+    /// Update the settings of a repository
+    ///
+    /// Only the settings that are set are changed. Topics replace the current
+    /// topics as a whole.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `settings` - The settings to change
+    ///
+    /// # Returns
+    /// The resulting `RepositorySettings` of the repository
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - No setting is given
+    /// - The repository does not exist or the token lacks admin access
+    /// - A value is rejected (e.g. an unknown default branch or an invalid topic)
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn update_repository_settings(
+        &self,
+        repository_id: &RepositoryId,
+        settings: &RepositorySettings,
+    ) -> Result<RepositorySettings> {
+        if settings.is_empty() {
+            return Err(anyhow::anyhow!(
+                "At least one repository setting must be given"
+            ));
+        }
+
+        let operation_name = "update_repository_settings";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.update_repository_settings_impl(repository_id, settings)
+                .await
+        })
+        .await
+    }
+
+    async fn update_repository_settings_impl(
+        &self,
+        repository_id: &RepositoryId,
+        settings: &RepositorySettings,
+    ) -> std::result::Result<RepositorySettings, ApiRetryableError> {
+        let url = format!(
+            "{}/repos/{}/{}",
+            self.api_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str()
+        );
+
+        // Replace the topics first so the repository response below includes them
+        if let Some(topics) = &settings.topics {
+            self.send_rest_request(
+                reqwest::Method::PUT,
+                &format!("{}/topics", url),
+                Some(&json!({ "names": topics })),
+            )
+            .await?;
+        }
+
+        let response = if settings.has_repository_fields() {
+            self.send_rest_request(
+                reqwest::Method::PATCH,
+                &url,
+                Some(&settings.to_request_body()),
+            )
+            .await?
+        } else {
+            self.send_rest_request(reqwest::Method::GET, &url, None)
+                .await?
+        };
+
+        response.json::<RepositorySettings>().await.map_err(|e| {
+            ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+        })
+    }
+
+    /// Archive a repository
+    ///
+    /// Archived repositories are read-only: issues, pull requests, labels and
+    /// code can no longer be changed until the repository is unarchived.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or the token lacks admin access
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn archive_repository(&self, repository_id: &RepositoryId) -> Result<()> {
+        let operation_name = "archive_repository";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.set_repository_archived_impl(repository_id, true).await
+        })
+        .await
+    }
+
+    /// Unarchive a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or the token lacks admin access
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn unarchive_repository(&self, repository_id: &RepositoryId) -> Result<()> {
+        let operation_name = "unarchive_repository";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.set_repository_archived_impl(repository_id, false)
+                .await
+        })
+        .await
+    }
+
+    async fn set_repository_archived_impl(
+        &self,
+        repository_id: &RepositoryId,
+        archived: bool,
+    ) -> std::result::Result<(), ApiRetryableError> {
+        let url = format!(
+            "{}/repos/{}/{}",
+            self.api_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str()
+        );
+
+        self.send_rest_request(
+            reqwest::Method::PATCH,
+            &url,
+            Some(&json!({ "archived": archived })),
+        )
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use crate::github::client::{GitHubClient, has_next_page, retry_with_backoff};
+use crate::github::error::ApiRetryableError;
+use crate::types::pagination::{Page, Pagination};
+use crate::types::search::{
+    IssueSearchResult, IssueSearchSort, RepositorySearchResult, RepositorySearchSort, SearchOrder,
+    SearchResults, UserSearchResult, UserSearchSort, search_query_string,
+};
+
+use anyhow::Result;
+use serde::Deserialize;
+
+/// Response body shared by the search endpoints
+#[derive(Deserialize)]
+struct SearchResponse<T> {
+    total_count: u64,
+    incomplete_results: bool,
+    items: Vec<T>,
+}
+
+impl GitHubClient {
+    /// Search issues and pull requests across GitHub
+    ///
+    /// # Arguments
+    /// * `query` - Search query using GitHub's search syntax (e.g. "repo:owner/repo label:bug is:open")
+    /// * `sort` - Optional field to sort by; results are sorted by best match when omitted
+    /// * `order` - Optional sort order, descending by default
+    /// * `pagination` - The page of results to fetch
+    ///
+    /// # Returns
+    /// A page of `IssueSearchResult`s with the total number of matching items
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The query is invalid or refers to repositories the token cannot access
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn search_issues_and_prs(
+        &self,
+        query: &str,
+        sort: Option<IssueSearchSort>,
+        order: Option<SearchOrder>,
+        pagination: Pagination,
+    ) -> Result<SearchResults<IssueSearchResult>> {
+        let operation_name = "search_issues_and_prs";
+        let url = format!(
+            "{}/search/issues?{}",
+            self.api_base_url,
+            search_query_string(query, sort, order, pagination)
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            self.search_impl(&url, pagination).await
+        })
+        .await
+    }
+
+    /// Search repositories across GitHub
+    ///
+    /// # Arguments
+    /// * `query` - Search query using GitHub's search syntax (e.g. "edit in:name user:tacogips")
+    /// * `sort` - Optional field to sort by; results are sorted by best match when omitted
+    /// * `order` - Optional sort order, descending by default
+    /// * `pagination` - The page of results to fetch
+    ///
+    /// # Returns
+    /// A page of `RepositorySearchResult`s with the total number of matching repositories
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The query is invalid
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn search_repositories(
+        &self,
+        query: &str,
+        sort: Option<RepositorySearchSort>,
+        order: Option<SearchOrder>,
+        pagination: Pagination,
+    ) -> Result<SearchResults<RepositorySearchResult>> {
+        let operation_name = "search_repositories";
+        let url = format!(
+            "{}/search/repositories?{}",
+            self.api_base_url,
+            search_query_string(query, sort, order, pagination)
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            self.search_impl(&url, pagination).await
+        })
+        .await
+    }
+
+    /// Search users and organizations across GitHub
+    ///
+    /// # Arguments
+    /// * `query` - Search query using GitHub's search syntax (e.g. "tacogips type:user")
+    /// * `sort` - Optional field to sort by; results are sorted by best match when omitted
+    /// * `order` - Optional sort order, descending by default
+    /// * `pagination` - The page of results to fetch
+    ///
+    /// # Returns
+    /// A page of `UserSearchResult`s with the total number of matching accounts
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The query is invalid
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn search_users(
+        &self,
+        query: &str,
+        sort: Option<UserSearchSort>,
+        order: Option<SearchOrder>,
+        pagination: Pagination,
+    ) -> Result<SearchResults<UserSearchResult>> {
+        let operation_name = "search_users";
+        let url = format!(
+            "{}/search/users?{}",
+            self.api_base_url,
+            search_query_string(query, sort, order, pagination)
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            self.search_impl(&url, pagination).await
+        })
+        .await
+    }
+
+    async fn search_impl<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        pagination: Pagination,
+    ) -> std::result::Result<SearchResults<T>, ApiRetryableError> {
+        let response = self
+            .send_rest_request(reqwest::Method::GET, url, None)
+            .await?;
+        let has_next = has_next_page(&response);
+
+        let search_response: SearchResponse<T> = response.json().await.map_err(|e| {
+            ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+        })?;
+
+        Ok(SearchResults {
+            total_count: search_response.total_count,
+            incomplete_results: search_response.incomplete_results,
+            page: Page::new(search_response.items, pagination, has_next),
+        })
+    }
+}
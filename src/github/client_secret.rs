@@ -0,0 +1,313 @@
+use crate::github::client::{GitHubClient, has_next_page, retry_with_backoff};
+use crate::github::error::ApiRetryableError;
+use crate::types::pagination::{Page, Pagination};
+use crate::types::repository::RepositoryId;
+use crate::types::secret::{ActionsPublicKey, ActionsSecret, ActionsVariable};
+
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::json;
+
+/// Response body of the secret list endpoint
+#[derive(Deserialize)]
+struct SecretsResponse {
+    secrets: Vec<ActionsSecret>,
+}
+
+/// Response body of the variable list endpoint
+#[derive(Deserialize)]
+struct VariablesResponse {
+    variables: Vec<ActionsVariable>,
+}
+
+impl GitHubClient {
+    /// Get the public key of a repository used to encrypt secret values
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    ///
+    /// # Returns
+    /// The `ActionsPublicKey` of the repository
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or the token lacks admin access
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_repo_public_key(
+        &self,
+        repository_id: &RepositoryId,
+    ) -> Result<ActionsPublicKey> {
+        let operation_name = "get_repo_public_key";
+        let url = self.actions_url(repository_id, "/secrets/public-key");
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::GET, &url, None)
+                .await?;
+            response.json::<ActionsPublicKey>().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })
+        })
+        .await
+    }
+
+    /// Create or update a repository secret
+    ///
+    /// The value is encrypted with the repository public key before it is
+    /// sent, so it never leaves this process in plain text.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `name` - The secret name
+    /// * `value` - The plain-text secret value
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or the token lacks admin access
+    /// - The value cannot be encrypted with the repository public key
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn set_repo_secret(
+        &self,
+        repository_id: &RepositoryId,
+        name: &str,
+        value: &str,
+    ) -> Result<()> {
+        let operation_name = "set_repo_secret";
+
+        let public_key = self.get_repo_public_key(repository_id).await?;
+        let request_body = json!({
+            "encrypted_value": public_key.seal(value)?,
+            "key_id": public_key.key_id,
+        });
+        let url = self.actions_url(repository_id, &format!("/secrets/{}", name));
+
+        retry_with_backoff(operation_name, None, || async {
+            self.send_rest_request(reqwest::Method::PUT, &url, Some(&request_body))
+                .await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Delete a repository secret
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `name` - The secret name
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or secret does not exist or the token lacks admin access
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn delete_repo_secret(&self, repository_id: &RepositoryId, name: &str) -> Result<()> {
+        let operation_name = "delete_repo_secret";
+        let url = self.actions_url(repository_id, &format!("/secrets/{}", name));
+
+        retry_with_backoff(operation_name, None, || async {
+            self.send_rest_request(reqwest::Method::DELETE, &url, None)
+                .await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// List the secrets of a repository without their values
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `pagination` - The page of secrets to fetch
+    ///
+    /// # Returns
+    /// A page of `ActionsSecret`s
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or the token lacks admin access
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_secrets(
+        &self,
+        repository_id: &RepositoryId,
+        pagination: Pagination,
+    ) -> Result<Page<ActionsSecret>> {
+        let operation_name = "list_secrets";
+        let url = format!(
+            "{}?per_page={}&page={}",
+            self.actions_url(repository_id, "/secrets"),
+            pagination.per_page,
+            pagination.page
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::GET, &url, None)
+                .await?;
+            let has_next = has_next_page(&response);
+
+            let secrets: SecretsResponse = response.json().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })?;
+
+            Ok(Page::new(secrets.secrets, pagination, has_next))
+        })
+        .await
+    }
+
+    /// List the configuration variables of a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `pagination` - The page of variables to fetch
+    ///
+    /// # Returns
+    /// A page of `ActionsVariable`s with their values
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or the token lacks admin access
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn list_variables(
+        &self,
+        repository_id: &RepositoryId,
+        pagination: Pagination,
+    ) -> Result<Page<ActionsVariable>> {
+        let operation_name = "list_variables";
+        let url = format!(
+            "{}?per_page={}&page={}",
+            self.actions_url(repository_id, "/variables"),
+            pagination.per_page,
+            pagination.page
+        );
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::GET, &url, None)
+                .await?;
+            let has_next = has_next_page(&response);
+
+            let variables: VariablesResponse = response.json().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })?;
+
+            Ok(Page::new(variables.variables, pagination, has_next))
+        })
+        .await
+    }
+
+    /// Get a configuration variable of a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `name` - The variable name
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository or variable does not exist or the token lacks admin access
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_variable(
+        &self,
+        repository_id: &RepositoryId,
+        name: &str,
+    ) -> Result<ActionsVariable> {
+        let operation_name = "get_variable";
+        let url = self.actions_url(repository_id, &format!("/variables/{}", name));
+
+        retry_with_backoff(operation_name, None, || async {
+            let response = self
+                .send_rest_request(reqwest::Method::GET, &url, None)
+                .await?;
+            response.json::<ActionsVariable>().await.map_err(|e| {
+                ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+            })
+        })
+        .await
+    }
+
+    /// Create a configuration variable in a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `name` - The variable name
+    /// * `value` - The variable value
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The variable already exists or the token lacks admin access
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn create_variable(
+        &self,
+        repository_id: &RepositoryId,
+        name: &str,
+        value: &str,
+    ) -> Result<()> {
+        let operation_name = "create_variable";
+        let url = self.actions_url(repository_id, "/variables");
+        let request_body = json!({ "name": name, "value": value });
+
+        retry_with_backoff(operation_name, None, || async {
+            self.send_rest_request(reqwest::Method::POST, &url, Some(&request_body))
+                .await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Update the value of a configuration variable in a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `name` - The variable name
+    /// * `value` - The new variable value
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The variable does not exist or the token lacks admin access
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn update_variable(
+        &self,
+        repository_id: &RepositoryId,
+        name: &str,
+        value: &str,
+    ) -> Result<()> {
+        let operation_name = "update_variable";
+        let url = self.actions_url(repository_id, &format!("/variables/{}", name));
+        let request_body = json!({ "name": name, "value": value });
+
+        retry_with_backoff(operation_name, None, || async {
+            self.send_rest_request(reqwest::Method::PATCH, &url, Some(&request_body))
+                .await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Delete a configuration variable from a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `name` - The variable name
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The variable does not exist or the token lacks admin access
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn delete_variable(&self, repository_id: &RepositoryId, name: &str) -> Result<()> {
+        let operation_name = "delete_variable";
+        let url = self.actions_url(repository_id, &format!("/variables/{}", name));
+
+        retry_with_backoff(operation_name, None, || async {
+            self.send_rest_request(reqwest::Method::DELETE, &url, None)
+                .await?;
+            Ok(())
+        })
+        .await
+    }
+}
@@ -0,0 +1,192 @@
+use crate::github::client::{GitHubClient, retry_with_backoff, send_reported};
+use crate::github::error::ApiRetryableError;
+use crate::types::repository::RepositoryId;
+use crate::types::transfer::{RepositoryTransfer, RepositoryTransferStatus};
+
+use anyhow::Result;
+use serde_json::json;
+use tokio::time::{Duration, Instant, sleep};
+
+/// Interval between transfer status checks while waiting for a transfer
+const TRANSFER_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+impl GitHubClient {
+    /// Transfer a repository to another user or organization
+    ///
+    /// GitHub accepts the transfer request and moves the repository
+    /// asynchronously, so the returned transfer is pending. Transfers to a user
+    /// stay pending until the user accepts them.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository to transfer
+    /// * `new_owner` - The user or organization receiving the repository
+    /// * `new_name` - Optional new name of the repository under the new owner
+    /// * `team_ids` - IDs of teams in the new organization to grant access to the repository
+    ///
+    /// # Returns
+    /// The pending `RepositoryTransfer` describing the repository under its new owner
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The repository does not exist or the token lacks admin access
+    /// - The new owner does not exist or already has a repository with the same name
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn transfer_repository(
+        &self,
+        repository_id: &RepositoryId,
+        new_owner: &str,
+        new_name: Option<&str>,
+        team_ids: &[u64],
+    ) -> Result<RepositoryTransfer> {
+        let operation_name = "transfer_repository";
+
+        let mut request_body = json!({ "new_owner": new_owner });
+        if let Some(new_name) = new_name {
+            request_body["new_name"] = json!(new_name);
+        }
+        if !team_ids.is_empty() {
+            request_body["team_ids"] = json!(team_ids);
+        }
+
+        retry_with_backoff(operation_name, None, || async {
+            self.transfer_repository_impl(repository_id, &request_body)
+                .await
+        })
+        .await
+    }
+
+    async fn transfer_repository_impl(
+        &self,
+        repository_id: &RepositoryId,
+        request_body: &serde_json::Value,
+    ) -> std::result::Result<RepositoryTransfer, ApiRetryableError> {
+        let url = format!(
+            "{}/repos/{}/{}/transfer",
+            self.api_base_url,
+            repository_id.owner().as_str(),
+            repository_id.repo_name().as_str()
+        );
+
+        let response = self
+            .send_rest_request(reqwest::Method::POST, &url, Some(request_body))
+            .await?;
+        let value: serde_json::Value = response.json().await.map_err(|e| {
+            ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+        })?;
+
+        RepositoryTransfer::from_api_value(&value).ok_or_else(|| {
+            ApiRetryableError::NonRetryable(
+                "Transfer response is missing repository fields".to_string(),
+            )
+        })
+    }
+
+    /// Check whether a transferred repository is available under its new owner
+    ///
+    /// # Arguments
+    /// * `target_repository_id` - The repository under its new owner
+    ///
+    /// # Returns
+    /// `Completed` once the repository exists under the new owner, `Pending` otherwise
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - API rate limits are exceeded (with automatic retry)
+    /// - Network errors occur (with automatic retry)
+    pub async fn get_repository_transfer_status(
+        &self,
+        target_repository_id: &RepositoryId,
+    ) -> Result<RepositoryTransferStatus> {
+        let operation_name = "get_repository_transfer_status";
+
+        retry_with_backoff(operation_name, None, || async {
+            self.get_repository_transfer_status_impl(target_repository_id)
+                .await
+        })
+        .await
+    }
+
+    async fn get_repository_transfer_status_impl(
+        &self,
+        target_repository_id: &RepositoryId,
+    ) -> std::result::Result<RepositoryTransferStatus, ApiRetryableError> {
+        let url = format!(
+            "{}/repos/{}/{}",
+            self.api_base_url,
+            target_repository_id.owner().as_str(),
+            target_repository_id.repo_name().as_str()
+        );
+
+        let token = self.token_for_url(&url).ok_or_else(|| {
+            ApiRetryableError::NonRetryable("GitHub token not configured".to_string())
+        })?;
+
+        // REV: A 404 is an expected answer while the transfer is in progress,
+        // so the response status is inspected here instead of using send_rest_request
+        let request = self
+            .http_client
+            .get(&url)
+            .header("Authorization", format!("token {}", token))
+            .header("Accept", "application/vnd.github.v3+json");
+        let response = send_reported(request, "GET", &url, None)
+            .await
+            .map_err(|e| ApiRetryableError::Retryable(format!("HTTP request failed: {}", e)))?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(RepositoryTransferStatus::Pending);
+        }
+        if !status.is_success() {
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            let error_msg = format!("GitHub API error {}: {}", status, error_text);
+            return Err(ApiRetryableError::from_status(status.as_u16(), error_msg));
+        }
+
+        let value: serde_json::Value = response.json().await.map_err(|e| {
+            ApiRetryableError::NonRetryable(format!("Failed to parse response: {}", e))
+        })?;
+        let owner = value
+            .pointer("/owner/login")
+            .and_then(|login| login.as_str())
+            .unwrap_or_default();
+
+        if owner.eq_ignore_ascii_case(target_repository_id.owner().as_str()) {
+            Ok(RepositoryTransferStatus::Completed)
+        } else {
+            Ok(RepositoryTransferStatus::Pending)
+        }
+    }
+
+    /// Wait until a transferred repository is available under its new owner
+    ///
+    /// Checks the transfer status every few seconds until it completes or
+    /// `timeout` elapses.
+    ///
+    /// # Arguments
+    /// * `target_repository_id` - The repository under its new owner
+    /// * `timeout` - How long to wait for the transfer to complete
+    ///
+    /// # Returns
+    /// The last observed `RepositoryTransferStatus`
+    pub async fn wait_for_repository_transfer(
+        &self,
+        target_repository_id: &RepositoryId,
+        timeout: Duration,
+    ) -> Result<RepositoryTransferStatus> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let status = self
+                .get_repository_transfer_status(target_repository_id)
+                .await?;
+            if status == RepositoryTransferStatus::Completed || Instant::now() >= deadline {
+                return Ok(status);
+            }
+            sleep(TRANSFER_POLL_INTERVAL.min(deadline.saturating_duration_since(Instant::now())))
+                .await;
+        }
+    }
+}
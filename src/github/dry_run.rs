@@ -0,0 +1,106 @@
+//! Dry runs of GitHub operations
+//!
+//! Within `with_dry_run`, every request that would change something on
+//! GitHub is recorded instead of being sent: REST requests other than GET and
+//! HEAD, and GraphQL mutations. Reads still go through, so inputs such as
+//! repositories, node IDs and project field options are resolved and
+//! validated as in a real run.
+//!
+//! A recorded request fails with `DRY_RUN_MESSAGE` as a client error, which
+//! `retry_with_backoff` does not retry. Operations that go on after a failed
+//! write, such as bulk updates, record their remaining writes as well.
+
+use crate::types::dry_run::PlannedRequest;
+
+use std::cell::RefCell;
+use std::future::Future;
+
+/// Error message of the requests not sent in a dry run
+pub const DRY_RUN_MESSAGE: &str = "Dry run: request not sent";
+
+/// POST endpoints that do not change anything
+const READ_ONLY_POST_SUFFIXES: [&str; 1] = ["/releases/generate-notes"];
+
+tokio::task_local! {
+    static PLANNED_REQUESTS: RefCell<Vec<PlannedRequest>>;
+}
+
+/// Run `future` as a dry run
+///
+/// # Returns
+/// The output of `future` and the write requests it would have sent
+pub async fn with_dry_run<F: Future>(future: F) -> (F::Output, Vec<PlannedRequest>) {
+    PLANNED_REQUESTS
+        .scope(RefCell::new(Vec::new()), async move {
+            let output = future.await;
+            let planned_requests = PLANNED_REQUESTS.with(|requests| requests.take());
+            (output, planned_requests)
+        })
+        .await
+}
+
+/// Returns true if the operation running on this task is a dry run
+pub fn is_dry_run() -> bool {
+    PLANNED_REQUESTS.try_with(|_| ()).is_ok()
+}
+
+/// Record the request instead of sending it if this is a dry run and the
+/// request is a write
+///
+/// # Returns
+/// `true` if the request was recorded and must not be sent
+pub fn intercept(method: &str, url: &str, body: &[u8]) -> bool {
+    if !is_dry_run() || !is_write(method, url, body) {
+        return false;
+    }
+    let body = (!body.is_empty())
+        .then(|| serde_json::from_slice(body).ok())
+        .flatten();
+    tracing::debug!("Dry run: not sending {} {}", method, url);
+    PLANNED_REQUESTS.with(|requests| {
+        requests.borrow_mut().push(PlannedRequest {
+            method: method.to_uppercase(),
+            url: url.to_string(),
+            body,
+        })
+    });
+    true
+}
+
+/// Like `intercept`, for requests with a JSON body
+pub fn intercept_json(method: &str, url: &str, body: Option<&serde_json::Value>) -> bool {
+    let body = body
+        .map(|body| serde_json::to_vec(body).unwrap_or_default())
+        .unwrap_or_default();
+    intercept(method, url, &body)
+}
+
+/// Returns true if the request changes something on GitHub
+pub fn is_write(method: &str, url: &str, body: &[u8]) -> bool {
+    let method = method.to_uppercase();
+    if method == "GET" || method == "HEAD" {
+        return false;
+    }
+    let path = url.split('?').next().unwrap_or(url).trim_end_matches('/');
+    if method == "POST" && path.ends_with("/graphql") {
+        return is_graphql_mutation(body);
+    }
+    !(method == "POST"
+        && READ_ONLY_POST_SUFFIXES
+            .iter()
+            .any(|suffix| path.ends_with(suffix)))
+}
+
+/// Returns true if the GraphQL request body holds a mutation
+///
+/// Bodies that cannot be parsed are treated as mutations, so a dry run never
+/// sends a request it cannot classify.
+fn is_graphql_mutation(body: &[u8]) -> bool {
+    let query = serde_json::from_slice::<serde_json::Value>(body)
+        .ok()
+        .and_then(|body| body.get("query")?.as_str().map(str::to_string));
+    match query {
+        Some(query) => query.trim_start().starts_with("mutation"),
+        None => true,
+    }
+}
@@ -7,6 +7,9 @@ pub enum ApiRetryableError {
     RateLimit,
     /// Client errors that should not be retried (4xx except 429)
     NonRetryable(String),
+    /// Client errors of a response whose status code tells their category,
+    /// not retried either
+    Rejected(ErrorKind, String),
 }
 
 impl ApiRetryableError {
@@ -15,6 +18,44 @@ impl ApiRetryableError {
         Self::Retryable(message.to_string())
     }
 
+    /// Create the error of a failed response with the HTTP `status`
+    ///
+    /// Server errors are retried and 429 responses are rate limits; other
+    /// errors keep the category of their status.
+    pub fn from_status(status: u16, message: String) -> Self {
+        match status {
+            429 => Self::RateLimit,
+            500..=599 => Self::Retryable(message),
+            _ => Self::Rejected(ErrorKind::from_status(status, &message), message),
+        }
+    }
+
+    /// Prefix the message of this error with `context`, keeping its category
+    pub fn with_context(self, context: &str) -> Self {
+        match self {
+            Self::Retryable(message) => Self::Retryable(format!("{}: {}", context, message)),
+            Self::RateLimit => Self::RateLimit,
+            Self::NonRetryable(message) => Self::NonRetryable(format!("{}: {}", context, message)),
+            Self::Rejected(kind, message) => {
+                Self::Rejected(kind, format!("{}: {}", context, message))
+            }
+        }
+    }
+
+    /// Returns the category of this error
+    ///
+    /// Retryable errors are network and server failures. Client errors carry
+    /// the category of their status code, and those without one, such as
+    /// GraphQL errors, are categorized by their message.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Retryable(_) => ErrorKind::Network,
+            Self::RateLimit => ErrorKind::RateLimited,
+            Self::NonRetryable(message) => ErrorKind::from_message(message),
+            Self::Rejected(kind, _) => *kind,
+        }
+    }
+
     /// Convert octocrab error to appropriate retry category
     pub fn from_octocrab_error(error: octocrab::Error) -> Self {
         // Log the raw error for debugging
@@ -53,7 +94,7 @@ impl ApiRetryableError {
                                 status,
                                 detailed_error
                             );
-                            Self::NonRetryable(detailed_error)
+                            Self::Rejected(ErrorKind::PermissionDenied, detailed_error)
                         }
                     }
                     400..=499 => {
@@ -62,7 +103,10 @@ impl ApiRetryableError {
                             status,
                             detailed_error
                         );
-                        Self::NonRetryable(detailed_error)
+                        Self::Rejected(
+                            ErrorKind::from_status(status, &detailed_error),
+                            detailed_error,
+                        )
                     }
                     500..=599 => {
                         tracing::warn!(
@@ -94,6 +138,12 @@ impl ApiRetryableError {
                 tracing::warn!("Hyper error - will retry: {}", error_msg);
                 Self::Retryable(error_msg)
             }
+            octocrab::Error::Service { .. } => {
+                // Connection failures and timeouts of the HTTP client - retryable
+                let error_msg = format!("Connection error: {}", error);
+                tracing::warn!("Connection error - will retry: {}", error_msg);
+                Self::Retryable(error_msg)
+            }
             octocrab::Error::Json { .. } => {
                 // JSON parsing error - not retryable
                 let error_msg = format!("JSON parsing error: {}", error);
@@ -131,9 +181,141 @@ impl std::fmt::Display for ApiRetryableError {
         match self {
             Self::Retryable(msg) => write!(f, "Retryable error: {}", msg),
             Self::RateLimit => write!(f, "Rate limit error"),
-            Self::NonRetryable(msg) => write!(f, "Non-retryable error: {}", msg),
+            Self::NonRetryable(msg) | Self::Rejected(_, msg) => {
+                write!(f, "Non-retryable error: {}", msg)
+            }
         }
     }
 }
 
 impl std::error::Error for ApiRetryableError {}
+
+/// Category of a failed operation
+///
+/// Lets callers such as the CLI tell authentication, permission and lookup
+/// failures apart without matching on error messages themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    /// The token is missing, invalid or expired
+    Auth,
+    /// The repository, issue or other resource does not exist, or is hidden
+    /// from the token
+    NotFound,
+    /// The token lacks the permissions or scopes for the operation
+    PermissionDenied,
+    /// The rate limit was still exceeded after retrying
+    RateLimited,
+    /// GitHub or the input parsing rejected the given values
+    Validation,
+    /// The request failed to reach GitHub, or GitHub failed with a server error
+    Network,
+    /// Any other failure
+    Other,
+}
+
+impl ErrorKind {
+    /// Categorize a failed response by its HTTP status code
+    ///
+    /// `message` tells rate limits apart from other 403 responses.
+    pub fn from_status(status: u16, message: &str) -> Self {
+        match status {
+            401 => Self::Auth,
+            403 if message.to_lowercase().contains("rate limit") => Self::RateLimited,
+            403 => Self::PermissionDenied,
+            404 | 410 => Self::NotFound,
+            400 | 409 | 422 => Self::Validation,
+            429 => Self::RateLimited,
+            500..=599 => Self::Network,
+            _ => Self::Other,
+        }
+    }
+
+    /// Categorize an error without a status code by its message
+    ///
+    /// Recognizes the messages of GraphQL errors and of the errors raised
+    /// before any request is sent.
+    pub fn from_message(message: &str) -> Self {
+        let lower = message.to_lowercase();
+        if lower.contains("bad credentials") || lower.contains("token not configured") {
+            Self::Auth
+        } else if lower.contains("resource not accessible")
+            || lower.contains("required scopes")
+            || lower.contains("does not have the correct permissions")
+            || lower.contains("forbidden")
+        {
+            Self::PermissionDenied
+        } else if lower.contains("could not resolve to") || lower.contains("not found") {
+            Self::NotFound
+        } else if lower.contains("rate limit") {
+            Self::RateLimited
+        } else {
+            Self::Other
+        }
+    }
+
+    /// Returns the category of `error`
+    ///
+    /// The first categorized error in the chain decides: a `GitHubError`,
+    /// an `ApiRetryableError`, or an error of the HTTP client, which is a
+    /// network failure. Errors without a category are `Other`.
+    pub fn of(error: &anyhow::Error) -> Self {
+        error
+            .chain()
+            .find_map(|cause| {
+                if let Some(error) = cause.downcast_ref::<GitHubError>() {
+                    Some(error.kind)
+                } else if let Some(error) = cause.downcast_ref::<ApiRetryableError>() {
+                    Some(error.kind())
+                } else if cause.downcast_ref::<reqwest::Error>().is_some() {
+                    Some(Self::Network)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or(Self::Other)
+    }
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Auth => "authentication failure",
+            Self::NotFound => "not found",
+            Self::PermissionDenied => "permission denied",
+            Self::RateLimited => "rate limited",
+            Self::Validation => "validation error",
+            Self::Network => "network error",
+            Self::Other => "error",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Error of a failed operation, with its category
+///
+/// Displays as its message alone, so wrapping an error in it only adds the
+/// category.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitHubError {
+    pub kind: ErrorKind,
+    pub message: String,
+}
+
+impl GitHubError {
+    /// Create a new error of the given category
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for GitHubError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for GitHubError {}
@@ -0,0 +1,181 @@
+//! ETag cache of GET responses
+//!
+//! Responses carrying an ETag are kept by URL, together with the `Accept`
+//! header and a fingerprint of the token they were requested with, since
+//! GitHub varies its responses by both. A later request for the same key is
+//! sent with `If-None-Match`, and a `304 Not Modified` answer, which does not
+//! count against the rate limit, is served from the kept response.
+//!
+//! Entries live in memory and, for a cache with a directory, in one JSON
+//! file per entry so that later runs can revalidate them too. Entries that
+//! cannot be read or written are treated as missing.
+
+use crate::config::CacheConfig;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Maximum number of responses kept in memory; the oldest are dropped first
+pub const MAX_MEMORY_ENTRIES: usize = 512;
+
+/// Response kept for revalidation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedResponse {
+    /// Key the response was stored under, to detect file name collisions
+    pub key: String,
+    /// ETag to send in `If-None-Match`
+    pub etag: String,
+    /// Headers of the original response
+    pub headers: Vec<(String, String)>,
+    /// Body of the original response
+    pub body: String,
+}
+
+/// In-memory and optionally on-disk cache of GET responses with ETags
+#[derive(Debug, Default)]
+pub struct EtagCache {
+    entries: Mutex<MemoryEntries>,
+    dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryEntries {
+    responses: HashMap<String, CachedResponse>,
+    /// Keys in the order they were stored, oldest first
+    order: VecDeque<String>,
+}
+
+impl EtagCache {
+    /// Create a cache keeping responses in memory, and in files in `dir` if
+    /// given
+    pub fn new(dir: Option<PathBuf>) -> Self {
+        Self {
+            entries: Mutex::new(MemoryEntries::default()),
+            dir,
+        }
+    }
+
+    /// Create the cache configured by `config`, or `None` if it is disabled
+    ///
+    /// # Errors
+    /// Returns an error if the cache is persisted without a configured path
+    /// and the platform has no configuration directory.
+    pub fn from_config(config: &CacheConfig) -> Result<Option<Self>> {
+        if !config.is_enabled() {
+            return Ok(None);
+        }
+        Ok(Some(Self::new(config.cache_dir()?)))
+    }
+
+    /// Returns the directory of the persisted entries, if any
+    pub fn dir(&self) -> Option<&Path> {
+        self.dir.as_deref()
+    }
+
+    /// Returns the cache key of a GET request
+    ///
+    /// # Arguments
+    /// * `url` - Full URL of the request, including its query
+    /// * `accept` - `Accept` header of the request, if any
+    /// * `authorization` - `Authorization` header of the request, if any,
+    ///   which is only kept as a fingerprint
+    pub fn key(url: &str, accept: Option<&[u8]>, authorization: Option<&[u8]>) -> String {
+        format!(
+            "{} accept={} auth={:016x}",
+            url,
+            String::from_utf8_lossy(accept.unwrap_or_default()),
+            authorization.map(fnv1a).unwrap_or_default()
+        )
+    }
+
+    /// Returns the response stored under `key`, from memory or from disk
+    pub fn get(&self, key: &str) -> Option<CachedResponse> {
+        if let Some(response) = self.lock().responses.get(key) {
+            return Some(response.clone());
+        }
+
+        let path = self.entry_path(key)?;
+        let content = std::fs::read(&path).ok()?;
+        let response: CachedResponse = serde_json::from_slice(&content).ok()?;
+        if response.key != key {
+            return None;
+        }
+        self.lock().insert(response.clone());
+        Some(response)
+    }
+
+    /// Store a response under its key, replacing the one stored before
+    pub fn store(&self, response: CachedResponse) {
+        if let Some(path) = self.entry_path(&response.key) {
+            if let Err(e) = write_entry(&path, &response) {
+                tracing::debug!("Failed to write cache entry {}: {}", path.display(), e);
+            }
+        }
+        self.lock().insert(response);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MemoryEntries> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Returns the file of the entry stored under `key`
+    fn entry_path(&self, key: &str) -> Option<PathBuf> {
+        let dir = self.dir.as_ref()?;
+        Some(dir.join(format!("{:016x}.json", fnv1a(key.as_bytes()))))
+    }
+}
+
+impl MemoryEntries {
+    fn insert(&mut self, response: CachedResponse) {
+        if self
+            .responses
+            .insert(response.key.clone(), response.clone())
+            .is_none()
+        {
+            self.order.push_back(response.key);
+        }
+        while self.order.len() > MAX_MEMORY_ENTRIES {
+            if let Some(oldest) = self.order.pop_front() {
+                self.responses.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// Write an entry to `path`, readable only by the current user
+///
+/// Entries hold the bodies of responses from private repositories, so the
+/// directory is created with mode 0700 and the file with mode 0600 on Unix.
+fn write_entry(path: &Path, response: &CachedResponse) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        let mut builder = std::fs::DirBuilder::new();
+        builder.recursive(true);
+        #[cfg(unix)]
+        std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+        builder.create(dir)?;
+    }
+
+    let content = serde_json::to_vec(response).map_err(std::io::Error::other)?;
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(path)?;
+    // Files written before under a wider mode are narrowed as well
+    #[cfg(unix)]
+    file.set_permissions(std::os::unix::fs::PermissionsExt::from_mode(0o600))?;
+    file.write_all(&content)
+}
+
+/// 64-bit FNV-1a hash, stable across runs for the names of cache files
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
@@ -0,0 +1,144 @@
+//! Typed GraphQL requests and responses
+//!
+//! Queries are sent as a `GraphQlRequest` with their variables, and answers
+//! are read as a `GraphQlResponse`. `GraphQlResponse::into_data` is the one
+//! place GraphQL errors become `ApiRetryableError`s; it then reads the data
+//! into a struct mirroring the selection of the query, so a response that
+//! no longer matches the query fails with the path of the mismatch instead
+//! of being read as missing values.
+
+use super::error::ApiRetryableError;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Body of a GraphQL request
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphQlRequest<'a, V> {
+    pub query: &'a str,
+    pub variables: V,
+}
+
+/// Body of a GraphQL response, before its data is read
+#[derive(Debug, Clone, Deserialize)]
+pub struct GraphQlResponse {
+    #[serde(default)]
+    pub data: Option<serde_json::Value>,
+    #[serde(default)]
+    pub errors: Vec<GraphQlError>,
+}
+
+/// Error of a GraphQL response
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GraphQlError {
+    pub message: String,
+    /// Category of the error, e.g. `NOT_FOUND` or `RATE_LIMITED`
+    #[serde(default, rename = "type")]
+    pub error_type: Option<String>,
+    /// Path of the field that failed, of names and list indexes
+    #[serde(default)]
+    pub path: Vec<serde_json::Value>,
+}
+
+impl std::fmt::Display for GraphQlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)?;
+        if !self.path.is_empty() {
+            let path: Vec<String> = self
+                .path
+                .iter()
+                .map(|segment| match segment {
+                    serde_json::Value::String(name) => name.clone(),
+                    other => other.to_string(),
+                })
+                .collect();
+            write!(f, " (at {})", path.join("."))?;
+        }
+        Ok(())
+    }
+}
+
+impl GraphQlResponse {
+    /// Returns the data of the response read as `T`
+    ///
+    /// # Errors
+    /// Returns `ApiRetryableError::RateLimit` if GraphQL reports the rate
+    /// limit as exceeded, and `ApiRetryableError::NonRetryable` with every
+    /// error message if the response has errors, has no data, or its data
+    /// does not match `T`.
+    pub fn into_data<T: DeserializeOwned>(self) -> Result<T, ApiRetryableError> {
+        if !self.errors.is_empty() {
+            if self
+                .errors
+                .iter()
+                .any(|error| error.error_type.as_deref() == Some("RATE_LIMITED"))
+            {
+                return Err(ApiRetryableError::RateLimit);
+            }
+            let messages: Vec<String> = self.errors.iter().map(|error| error.to_string()).collect();
+            return Err(ApiRetryableError::NonRetryable(messages.join("; ")));
+        }
+
+        let data = self.data.ok_or_else(|| {
+            ApiRetryableError::NonRetryable("No data in GraphQL response".to_string())
+        })?;
+        serde_path_to_error::deserialize(data).map_err(|e| {
+            ApiRetryableError::NonRetryable(format!(
+                "Unexpected GraphQL response at {}: {}",
+                e.path(),
+                e.inner()
+            ))
+        })
+    }
+}
+
+/// Page of a GraphQL connection
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Connection<T> {
+    /// Items of the page; GitHub returns `null` for items the token cannot read
+    #[serde(default = "Vec::new")]
+    pub nodes: Vec<Option<T>>,
+    #[serde(default)]
+    pub page_info: PageInfo,
+    #[serde(default)]
+    pub total_count: Option<u64>,
+}
+
+impl<T> Connection<T> {
+    /// Returns the readable items of the page
+    pub fn into_nodes(self) -> impl Iterator<Item = T> {
+        self.nodes.into_iter().flatten()
+    }
+
+    /// Returns the cursor of the next page, if there is one
+    pub fn next_cursor(&self) -> Option<String> {
+        self.page_info.next_cursor()
+    }
+}
+
+/// Position of a page in a GraphQL connection
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageInfo {
+    #[serde(default)]
+    pub has_next_page: bool,
+    #[serde(default)]
+    pub end_cursor: Option<String>,
+}
+
+impl PageInfo {
+    /// Returns the cursor of the next page, if there is one
+    pub fn next_cursor(&self) -> Option<String> {
+        if self.has_next_page {
+            self.end_cursor.clone()
+        } else {
+            None
+        }
+    }
+}
+
+/// Object holding only a node ID, e.g. the payload of a mutation creating a node
+#[derive(Debug, Clone, Deserialize)]
+pub struct NodeId {
+    pub id: String,
+}
@@ -0,0 +1,375 @@
+//! HTTP transport shared by octocrab and the direct REST requests
+//!
+//! Every request of a `GitHubClient` is sent with one `reqwest::Client`, so
+//! the proxy, TLS and timeout settings of the configuration apply to octocrab
+//! and the direct REST requests alike, and all of them reuse its pooled
+//! connections. Octocrab is connected to it through `ReqwestService`, which
+//! also applies the tokens of a `TokenRouter` and revalidates GET requests
+//! against the responses of an `EtagCache`.
+
+use crate::config::NetworkConfig;
+use crate::github::client::{log_api_call, report_request};
+use crate::github::dry_run;
+use crate::github::etag_cache::{CachedResponse, EtagCache};
+use crate::github::token_router::{self, TokenRouter};
+
+use anyhow::{Context, Result};
+use bytes::Bytes;
+use http_body_util::{BodyExt, Full};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context as TaskContext, Poll};
+use std::time::{Duration, Instant};
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// User agent sent with every request
+pub(crate) const USER_AGENT_VALUE: &str = "github-edit-cli";
+
+/// Interval of the TCP keepalive probes of idle pooled connections
+const TCP_KEEPALIVE: Duration = Duration::from_secs(60);
+
+/// Build the HTTP client used for all requests to the GitHub API
+///
+/// Trusts the built-in web PKI roots, the system certificates (or those of
+/// `SSL_CERT_FILE`/`SSL_CERT_DIR`) and the certificates of `ca_bundle`.
+/// Requests go through `proxy` if configured, and otherwise through the
+/// proxy of the `HTTPS_PROXY`/`HTTP_PROXY`/`ALL_PROXY` environment variables.
+/// Every request carries the user agent of this crate and is bound by the
+/// configured timeouts.
+///
+/// # Errors
+/// Returns an error if:
+/// - The CA bundle cannot be read or contains no valid PEM certificate
+/// - The proxy URL is invalid
+pub fn build_http_client(network: &NetworkConfig) -> Result<reqwest::Client> {
+    let mut builder = reqwest::Client::builder()
+        .user_agent(USER_AGENT_VALUE)
+        .connect_timeout(network.connect_timeout())
+        .tcp_keepalive(TCP_KEEPALIVE);
+    if let Some(timeout) = network.timeout() {
+        builder = builder.timeout(timeout);
+    }
+
+    for certificate in native_root_certificates() {
+        builder = builder.add_root_certificate(certificate);
+    }
+
+    if let Some(path) = &network.ca_bundle {
+        let pem = std::fs::read(path)
+            .with_context(|| format!("Failed to read CA bundle {}", path.display()))?;
+        let certificates = reqwest::Certificate::from_pem_bundle(&pem)
+            .with_context(|| format!("Invalid CA bundle {}", path.display()))?;
+        if certificates.is_empty() {
+            anyhow::bail!("CA bundle {} contains no certificates", path.display());
+        }
+        for certificate in certificates {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+
+    if let Some(proxy_url) = &network.proxy {
+        let proxy = reqwest::Proxy::all(proxy_url)
+            .with_context(|| format!("Invalid proxy URL '{}'", redact_proxy_url(proxy_url)))?
+            .no_proxy(
+                network
+                    .no_proxy
+                    .as_deref()
+                    .and_then(reqwest::NoProxy::from_string),
+            );
+        builder = builder.proxy(proxy);
+    }
+
+    builder.build().context("Failed to build the HTTP client")
+}
+
+/// Load the root certificates of the system store
+///
+/// Certificates rustls cannot parse, which system stores often contain, are
+/// skipped since a single one would fail the whole client.
+fn native_root_certificates() -> Vec<reqwest::Certificate> {
+    let result = rustls_native_certs::load_native_certs();
+    for error in &result.errors {
+        tracing::debug!("Failed to load system certificates: {}", error);
+    }
+    result
+        .certs
+        .into_iter()
+        .filter(|der| rustls::RootCertStore::empty().add(der.clone()).is_ok())
+        .filter_map(|der| reqwest::Certificate::from_der(der.as_ref()).ok())
+        .collect()
+}
+
+/// Build the `Authorization` header value of a token
+///
+/// # Errors
+/// Returns an error if the token contains characters invalid in a header.
+pub fn bearer_header(token: &str) -> Result<http::HeaderValue> {
+    http::HeaderValue::from_str(&format!("Bearer {}", token))
+        .map_err(|_| anyhow::anyhow!("GitHub token contains invalid characters"))
+}
+
+/// Returns the proxy URL with its password masked for error messages
+pub fn redact_proxy_url(proxy_url: &str) -> String {
+    match url::Url::parse(proxy_url) {
+        Ok(mut parsed) if parsed.password().is_some() => {
+            let _ = parsed.set_password(Some("***"));
+            parsed.to_string()
+        }
+        _ => proxy_url.to_string(),
+    }
+}
+
+/// Tower service sending octocrab's requests with a `reqwest::Client`
+///
+/// Authenticated requests addressing an owner with a token of its own in the
+/// `TokenRouter` are sent with that token. With an `EtagCache`, GET requests
+/// for a cached response are sent with its ETag, and a `304 Not Modified`
+/// answer is replaced by the cached response.
+#[derive(Clone)]
+pub struct ReqwestService {
+    client: reqwest::Client,
+    token_router: Arc<TokenRouter>,
+    etag_cache: Option<Arc<EtagCache>>,
+}
+
+impl ReqwestService {
+    pub fn new(
+        client: reqwest::Client,
+        token_router: Arc<TokenRouter>,
+        etag_cache: Option<Arc<EtagCache>>,
+    ) -> Self {
+        Self {
+            client,
+            token_router,
+            etag_cache,
+        }
+    }
+}
+
+/// Returns the token of the owner of the nodes a GraphQL request addresses
+/// by ID
+///
+/// Owners not seen before are looked up with the request's own, default,
+/// token. A warning is logged when the owner can't be found, as the request
+/// is then sent as the default identity.
+async fn node_owner_token(
+    client: &reqwest::Client,
+    parts: &http::request::Parts,
+    token_router: &TokenRouter,
+    node_ids: &[String],
+) -> Option<String> {
+    if node_ids.is_empty() {
+        return None;
+    }
+    let unknown: Vec<&String> = node_ids
+        .iter()
+        .filter(|id| token_router.node_owner(id).is_none())
+        .collect();
+    if !unknown.is_empty() {
+        match lookup_node_owners(client, parts, &unknown).await {
+            Ok(owners) => {
+                for (id, owner) in unknown.iter().zip(owners) {
+                    if let Some(owner) = owner {
+                        token_router.set_node_owner(id, &owner);
+                    }
+                }
+            }
+            Err(e) => tracing::debug!("Looking up the owner of nodes failed: {:#}", e),
+        }
+    }
+
+    let Some(owner) = node_ids.iter().find_map(|id| token_router.node_owner(id)) else {
+        tracing::warn!(
+            "Could not find the owner of {}; sending the request with the default token",
+            node_ids.join(", ")
+        );
+        return None;
+    };
+    token_router
+        .token_for_owner(&owner)
+        .map(|token| token.to_string())
+}
+
+/// Look up the owner login of `ids` with the headers of the GraphQL request
+/// `parts`
+async fn lookup_node_owners(
+    client: &reqwest::Client,
+    parts: &http::request::Parts,
+    ids: &[&String],
+) -> Result<Vec<Option<String>>> {
+    let body = serde_json::json!({
+        "query": token_router::node_owner_query(),
+        "variables": { "ids": ids },
+    });
+    let mut request = client.post(parts.uri.to_string());
+    for (name, value) in parts.headers.iter() {
+        if name != http::header::CONTENT_LENGTH && name != http::header::CONTENT_TYPE {
+            request = request.header(name.as_str(), value.as_bytes());
+        }
+    }
+    let response = request
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(body.to_string())
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(token_router::node_owners_of_response(
+        &response.bytes().await?,
+    ))
+}
+
+impl<B> tower::Service<http::Request<B>> for ReqwestService
+where
+    B: http_body::Body<Data = Bytes> + Send + 'static,
+    B::Error: Into<BoxError>,
+{
+    type Response = http::Response<Full<Bytes>>;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut TaskContext<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let client = self.client.clone();
+        let token_router = self.token_router.clone();
+        let etag_cache = self.etag_cache.clone();
+        Box::pin(async move {
+            let (mut parts, body) = request.into_parts();
+            let body = body.collect().await.map_err(Into::into)?.to_bytes();
+
+            // Only requests octocrab authenticated, i.e. those to the API
+            // host, are given an owner's token
+            if parts.headers.contains_key(http::header::AUTHORIZATION) {
+                let uri = parts.uri.to_string();
+                let token = match token_router.token_for_request(&uri, &body) {
+                    Some(token) => Some(token.to_string()),
+                    None => {
+                        let node_ids = token_router.unrouted_node_ids(&uri, &body);
+                        node_owner_token(&client, &parts, &token_router, &node_ids).await
+                    }
+                };
+                if let Some(token) = token {
+                    parts
+                        .headers
+                        .insert(http::header::AUTHORIZATION, bearer_header(&token)?);
+                }
+            }
+
+            // Writes of a dry run are answered without reaching GitHub, with
+            // an error octocrab reports like any other client error
+            let uri = parts.uri.to_string();
+            if dry_run::intercept(parts.method.as_str(), &uri, &body) {
+                let body = serde_json::json!({
+                    "message": dry_run::DRY_RUN_MESSAGE,
+                    "documentation_url": null,
+                });
+                return Ok(http::Response::builder()
+                    .status(http::StatusCode::CONFLICT)
+                    .header(http::header::CONTENT_TYPE, "application/json")
+                    .body(Full::new(Bytes::from(body.to_string())))?);
+            }
+
+            // reqwest 0.11 is built on http 0.2, so the request and response
+            // are converted field by field
+            let method = reqwest::Method::from_bytes(parts.method.as_str().as_bytes())?;
+            let mut request = client.request(method, uri.as_str());
+            for (name, value) in parts.headers.iter() {
+                request = request.header(name.as_str(), value.as_bytes());
+            }
+
+            // Requests already carrying an ETag manage it themselves
+            let cache_key = etag_cache
+                .as_ref()
+                .filter(|_| {
+                    parts.method == http::Method::GET
+                        && !parts.headers.contains_key(http::header::IF_NONE_MATCH)
+                })
+                .map(|_| {
+                    EtagCache::key(
+                        &uri,
+                        parts
+                            .headers
+                            .get(http::header::ACCEPT)
+                            .map(|v| v.as_bytes()),
+                        parts
+                            .headers
+                            .get(http::header::AUTHORIZATION)
+                            .map(|v| v.as_bytes()),
+                    )
+                });
+            let cached = etag_cache
+                .as_ref()
+                .zip(cache_key.as_ref())
+                .and_then(|(cache, key)| cache.get(key));
+            if let Some(cached) = &cached {
+                request =
+                    request.header(http::header::IF_NONE_MATCH.as_str(), cached.etag.as_str());
+            }
+
+            let started = Instant::now();
+            let response = request.body(body.clone()).send().await;
+            log_api_call(
+                parts.method.as_str(),
+                &uri,
+                started.elapsed(),
+                response.as_ref(),
+            );
+            let response = match response {
+                Ok(response) => {
+                    let status = response.status().as_u16();
+                    report_request(parts.method.as_str(), &uri, &body, Ok(status));
+                    response
+                }
+                Err(e) => {
+                    report_request(parts.method.as_str(), &uri, &body, Err(&e.to_string()));
+                    return Err(e.into());
+                }
+            };
+
+            if let Some(cached) = cached {
+                if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+                    tracing::debug!("Serving {} from the ETag cache", uri);
+                    let mut builder = http::Response::builder().status(http::StatusCode::OK);
+                    for (name, value) in &cached.headers {
+                        builder = builder.header(name.as_str(), value.as_str());
+                    }
+                    return Ok(builder.body(Full::new(Bytes::from(cached.body)))?);
+                }
+            }
+
+            let status = response.status();
+            let etag = response
+                .headers()
+                .get(reqwest::header::ETAG)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.to_string());
+            let mut builder = http::Response::builder().status(status.as_u16());
+            let mut headers = Vec::new();
+            for (name, value) in response.headers() {
+                builder = builder.header(name.as_str(), value.as_bytes());
+                if let Ok(value) = value.to_str() {
+                    headers.push((name.as_str().to_string(), value.to_string()));
+                }
+            }
+            let body = response.bytes().await?;
+
+            if let (Some(cache), Some(key), Some(etag)) = (&etag_cache, cache_key, etag) {
+                if status == reqwest::StatusCode::OK {
+                    if let Ok(text) = std::str::from_utf8(&body) {
+                        cache.store(CachedResponse {
+                            key,
+                            etag,
+                            headers,
+                            body: text.to_string(),
+                        });
+                    }
+                }
+            }
+            Ok(builder.body(Full::new(body))?)
+        })
+    }
+}
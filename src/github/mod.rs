@@ -1,8 +1,33 @@
+pub mod audit;
+pub mod cancellation;
 pub mod client;
+pub mod client_actions;
+pub mod client_auth;
+pub mod client_branch;
+pub mod client_check;
+pub mod client_comment;
+pub mod client_commit;
+pub mod client_content;
+pub mod client_discussion;
+pub mod client_fork;
 pub mod client_issue;
+pub mod client_notification;
 pub mod client_project;
 pub mod client_pull_request;
+pub mod client_reaction;
+pub mod client_release;
 pub mod client_repository;
+pub mod client_repository_settings;
+pub mod client_search;
+pub mod client_secret;
+pub mod client_transfer;
+pub mod dry_run;
 pub mod error;
+pub mod etag_cache;
+pub mod graphql;
+pub mod http_client;
+pub mod token_router;
+pub mod trace;
+pub mod webhook;
 
 pub use client::GitHubClient;
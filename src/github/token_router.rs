@@ -0,0 +1,210 @@
+//! Token selection by repository owner
+//!
+//! A `TokenRouter` maps repository owners to tokens, so one client can act
+//! as different identities in different organizations. The owner of a
+//! request is taken from REST paths below `/repos/{owner}` and
+//! `/orgs/{org}`, and from the `owner` or `login` variable of GraphQL
+//! requests. GraphQL requests addressing objects only by node ID, such as
+//! project and discussion mutations, are routed to the owner of those nodes,
+//! which is looked up once per node with the default token of the client.
+//! Requests whose owner can't be found use the default token.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Path segments of REST endpoints followed by the owner
+const OWNER_PATH_SEGMENTS: &[&str] = &["repos", "orgs"];
+
+/// GraphQL variables holding the owner
+const OWNER_GRAPHQL_VARIABLES: &[&str] = &["owner", "login"];
+
+/// Owner of an account node, either an organization or a user
+const ACCOUNT_OWNER: &str = "owner { ... on Organization { login } ... on User { login } }";
+
+/// GraphQL query returning the owner login of the nodes of the `ids` variable
+pub fn node_owner_query() -> String {
+    format!(
+        "query($ids: [ID!]!) {{ nodes(ids: $ids) {{ \
+         ... on RepositoryNode {{ repository {{ owner {{ login }} }} }} \
+         ... on Repository {{ owner {{ login }} }} \
+         ... on ProjectV2 {{ {account} }} \
+         ... on ProjectV2Item {{ project {{ {account} }} }} \
+         ... on ProjectV2FieldCommon {{ project {{ {account} }} }} \
+         ... on Team {{ organization {{ login }} }} \
+         ... on Organization {{ login }} \
+         ... on User {{ login }} }} }}",
+        account = ACCOUNT_OWNER
+    )
+}
+
+/// Tokens by repository owner
+#[derive(Clone, Default)]
+pub struct TokenRouter {
+    /// Tokens keyed by the lowercase owner, as owners are case-insensitive
+    owner_tokens: HashMap<String, String>,
+    /// Path of the REST API base URL without a trailing slash, e.g. `/api/v3`
+    api_base_path: String,
+    /// Owners of the nodes looked up so far, keyed by node ID
+    node_owners: Arc<Mutex<HashMap<String, String>>>,
+}
+
+impl TokenRouter {
+    /// Create a router for the API at `api_base_url`
+    pub fn new<'a>(
+        owner_tokens: impl IntoIterator<Item = (&'a String, &'a String)>,
+        api_base_url: &str,
+    ) -> Self {
+        let api_base_path = url::Url::parse(api_base_url)
+            .map(|url| url.path().trim_end_matches('/').to_string())
+            .unwrap_or_default();
+        Self {
+            owner_tokens: owner_tokens
+                .into_iter()
+                .map(|(owner, token)| (owner.to_lowercase(), token.clone()))
+                .collect(),
+            api_base_path,
+            node_owners: Arc::default(),
+        }
+    }
+
+    /// Returns true if no owner has a token of its own
+    pub fn is_empty(&self) -> bool {
+        self.owner_tokens.is_empty()
+    }
+
+    /// Returns the owners with a token of their own, sorted
+    pub fn owners(&self) -> Vec<&str> {
+        let mut owners: Vec<&str> = self.iter().map(|(owner, _)| owner).collect();
+        owners.sort_unstable();
+        owners
+    }
+
+    /// Iterate over the lowercase owners and their tokens
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.owner_tokens
+            .iter()
+            .map(|(owner, token)| (owner.as_str(), token.as_str()))
+    }
+
+    /// Returns the token of `owner`, if it has one
+    pub fn token_for_owner(&self, owner: &str) -> Option<&str> {
+        self.owner_tokens
+            .get(&owner.to_lowercase())
+            .map(|token| token.as_str())
+    }
+
+    /// Returns the token for a request to `url` with the JSON `body`, if the
+    /// owner it addresses has one
+    pub fn token_for_request(&self, url: &str, body: &[u8]) -> Option<&str> {
+        if self.is_empty() {
+            return None;
+        }
+        let owner = self
+            .owner_of_url(url)
+            .or_else(|| owner_of_graphql_request(body))?;
+        self.token_for_owner(&owner)
+    }
+
+    /// Returns the owner addressed by a REST API URL
+    ///
+    /// # Examples
+    /// `https://api.github.com/repos/octocat/hello/issues` addresses `octocat`.
+    pub fn owner_of_url(&self, url: &str) -> Option<String> {
+        let parsed = url::Url::parse(url).ok()?;
+        let path = parsed.path().strip_prefix(&self.api_base_path)?;
+        let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+        let first = segments.next()?;
+        if !OWNER_PATH_SEGMENTS.contains(&first) {
+            return None;
+        }
+        segments.next().map(|owner| owner.to_string())
+    }
+
+    /// Returns the node IDs whose owner selects the token of a request to
+    /// `url` with the JSON `body`
+    ///
+    /// Empty unless owners have tokens of their own and the request is a
+    /// GraphQL request without an owner in its URL or variables.
+    pub fn unrouted_node_ids(&self, url: &str, body: &[u8]) -> Vec<String> {
+        if self.is_empty()
+            || self.owner_of_url(url).is_some()
+            || owner_of_graphql_request(body).is_some()
+        {
+            return Vec::new();
+        }
+        node_ids_of_graphql_request(body)
+    }
+
+    /// Returns the owner of the node `id`, if it was looked up before
+    pub fn node_owner(&self, id: &str) -> Option<String> {
+        self.node_owners.lock().ok()?.get(id).cloned()
+    }
+
+    /// Remember `owner` as the owner of the node `id`
+    pub fn set_node_owner(&self, id: &str, owner: &str) {
+        if let Ok(mut node_owners) = self.node_owners.lock() {
+            node_owners.insert(id.to_string(), owner.to_string());
+        }
+    }
+}
+
+/// Returns the owner variable of a GraphQL request body
+pub fn owner_of_graphql_request(body: &[u8]) -> Option<String> {
+    let request: serde_json::Value = serde_json::from_slice(body).ok()?;
+    let variables = request.get("variables")?;
+    OWNER_GRAPHQL_VARIABLES
+        .iter()
+        .find_map(|name| variables.get(*name).and_then(|value| value.as_str()))
+        .map(|owner| owner.to_string())
+}
+
+/// Returns the node IDs among the variables of a GraphQL request body
+///
+/// Node IDs are the string values of variables named `id` or ending in
+/// `Id`, also within input objects.
+pub fn node_ids_of_graphql_request(body: &[u8]) -> Vec<String> {
+    fn collect(value: &serde_json::Value, ids: &mut Vec<String>) {
+        let serde_json::Value::Object(object) = value else {
+            return;
+        };
+        for (name, value) in object {
+            match value {
+                serde_json::Value::String(id) if name == "id" || name.ends_with("Id") => {
+                    if !ids.contains(id) {
+                        ids.push(id.clone());
+                    }
+                }
+                serde_json::Value::Object(_) => collect(value, ids),
+                _ => {}
+            }
+        }
+    }
+
+    let mut ids = Vec::new();
+    if let Some(variables) = serde_json::from_slice::<serde_json::Value>(body)
+        .ok()
+        .and_then(|request| request.get("variables").cloned())
+    {
+        collect(&variables, &mut ids);
+    }
+    ids
+}
+
+/// Returns the owner login of each node of a response to [`node_owner_query`],
+/// in the order of the requested IDs
+pub fn node_owners_of_response(body: &[u8]) -> Vec<Option<String>> {
+    fn login(value: &serde_json::Value) -> Option<String> {
+        let object = value.as_object()?;
+        if let Some(login) = object.get("login").and_then(|login| login.as_str()) {
+            return Some(login.to_string());
+        }
+        object.values().find_map(login)
+    }
+
+    serde_json::from_slice::<serde_json::Value>(body)
+        .ok()
+        .and_then(|response| response.pointer("/data/nodes").cloned())
+        .and_then(|nodes| nodes.as_array().cloned())
+        .map(|nodes| nodes.iter().map(login).collect())
+        .unwrap_or_default()
+}
@@ -0,0 +1,79 @@
+//! Tracing of the GitHub operations of a tool call
+//!
+//! The MCP server runs each tool call within `with_trace`, scoping a trace ID
+//! and an event sink to it. `retry_with_backoff` reports the attempts and
+//! retries of each operation, and the request senders report each GitHub
+//! request with its status, so the server can forward them to the client as
+//! log messages correlated by the trace ID.
+//!
+//! Outside of a scope, e.g. in the CLI, events are dropped; the `tracing`
+//! log is written either way.
+
+use crate::types::trace::{TraceEvent, TraceLevel};
+
+use std::future::Future;
+use tokio::sync::mpsc::UnboundedSender;
+
+tokio::task_local! {
+    static TRACE: Trace;
+}
+
+/// Trace ID and event sink of a tool call
+struct Trace {
+    id: String,
+    sink: UnboundedSender<TraceEvent>,
+}
+
+/// Returns a new trace ID
+pub fn new_trace_id() -> String {
+    uuid::Uuid::new_v4().simple().to_string()[..16].to_string()
+}
+
+/// Run `future` traced as `trace_id`, sending its events to `sink`
+pub async fn with_trace<F: Future>(
+    trace_id: String,
+    sink: UnboundedSender<TraceEvent>,
+    future: F,
+) -> F::Output {
+    TRACE.scope(Trace { id: trace_id, sink }, future).await
+}
+
+/// Returns the trace ID of the operation running on this task, if traced
+pub fn current_trace_id() -> Option<String> {
+    TRACE.try_with(|trace| trace.id.clone()).ok()
+}
+
+/// Report an event of the operation running on this task
+pub fn emit(level: TraceLevel, message: impl Into<String>, data: serde_json::Value) {
+    let _ = TRACE.try_with(|trace| {
+        // The receiver is gone once the tool call has been answered
+        let _ = trace.sink.send(TraceEvent {
+            trace_id: trace.id.clone(),
+            level,
+            message: message.into(),
+            data,
+        });
+    });
+}
+
+/// Report a GitHub request and its HTTP status, or the error that kept it
+/// from being answered
+pub fn emit_request(method: &str, url: &str, outcome: Result<u16, &str>) {
+    let (level, message, data) = match outcome {
+        Ok(status) => (
+            if status < 400 {
+                TraceLevel::Debug
+            } else {
+                TraceLevel::Warning
+            },
+            format!("GitHub request {} {}: {}", method, url, status),
+            serde_json::json!({ "method": method, "url": url, "status": status }),
+        ),
+        Err(error) => (
+            TraceLevel::Warning,
+            format!("GitHub request {} {} failed: {}", method, url, error),
+            serde_json::json!({ "method": method, "url": url, "error": error }),
+        ),
+    };
+    emit(level, message, data);
+}
@@ -0,0 +1,220 @@
+//! Validation and conversion of GitHub webhook deliveries
+//!
+//! GitHub signs each delivery with the webhook secret: the
+//! `X-Hub-Signature-256` header holds `sha256=` followed by the hex encoded
+//! HMAC-SHA256 of the request body. Deliveries are accepted only if that
+//! signature matches, compared in constant time.
+//!
+//! Accepted deliveries are converted into `WebhookEvent`s naming the issue
+//! or pull request they are about by its resource URI, with a one line
+//! summary. Issue, issue comment, pull request, review and review comment
+//! events have summaries of their own; other events are described by their
+//! name and action.
+
+use crate::types::issue::IssueId;
+use crate::types::pull_request::PullRequestId;
+use crate::types::repository::RepositoryId;
+use crate::types::resource::ResourceUri;
+use crate::types::webhook::WebhookEvent;
+
+use anyhow::{Context, Result};
+use ring::hmac;
+use serde_json::Value;
+use std::fmt::Write;
+
+/// Header naming the event of a delivery
+pub const EVENT_HEADER: &str = "x-github-event";
+
+/// Header holding the unique ID of a delivery
+pub const DELIVERY_HEADER: &str = "x-github-delivery";
+
+/// Header holding the HMAC-SHA256 signature of a delivery
+pub const SIGNATURE_HEADER: &str = "x-hub-signature-256";
+
+/// Returns true if `signature`, the value of the `X-Hub-Signature-256`
+/// header, is the signature of `body` with `secret`
+pub fn verify_signature(secret: &str, body: &[u8], signature: &str) -> bool {
+    let Some(expected) = signature.strip_prefix("sha256=").and_then(decode_hex) else {
+        return false;
+    };
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    hmac::verify(&key, body, &expected).is_ok()
+}
+
+/// Returns the `X-Hub-Signature-256` header value signing `body` with `secret`
+pub fn sign(secret: &str, body: &[u8]) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let tag = hmac::sign(&key, body);
+    tag.as_ref()
+        .iter()
+        .fold(String::from("sha256="), |mut signature, byte| {
+            let _ = write!(signature, "{:02x}", byte);
+            signature
+        })
+}
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    if text.len() % 2 != 0 {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Convert a delivery of `event` into a webhook event
+///
+/// # Errors
+/// Returns an error if the body is not a JSON object
+pub fn parse_event(event: &str, delivery_id: Option<&str>, body: &[u8]) -> Result<WebhookEvent> {
+    let payload: Value =
+        serde_json::from_slice(body).context("Webhook payload is not valid JSON")?;
+    if !payload.is_object() {
+        anyhow::bail!("Webhook payload is not a JSON object");
+    }
+
+    let action = text(&payload, &["action"]);
+    let repository = text(&payload, &["repository", "full_name"]);
+    let sender = text(&payload, &["sender", "login"]);
+    let resource_uri = repository
+        .as_deref()
+        .and_then(|repository| resource_uri(event, &payload, repository));
+    let summary = summarize(
+        event,
+        action.as_deref(),
+        &payload,
+        resource_uri.as_ref(),
+        repository.as_deref(),
+        sender.as_deref(),
+    );
+
+    Ok(WebhookEvent {
+        event: event.to_string(),
+        action,
+        delivery_id: delivery_id.map(str::to_string),
+        repository,
+        resource_uri: resource_uri.map(|uri| uri.to_string()),
+        sender,
+        summary,
+        received_at: chrono::Utc::now(),
+    })
+}
+
+/// Returns the issue or pull request the event is about
+fn resource_uri(event: &str, payload: &Value, repository: &str) -> Option<ResourceUri> {
+    let (owner, repo) = repository.split_once('/')?;
+    let repository_id = RepositoryId::new(owner, repo);
+    let number =
+        |path: &[&str]| number(payload, path).and_then(|number| u32::try_from(number).ok());
+    match event {
+        "issues" | "issue_comment" => {
+            let number = number(&["issue", "number"])?;
+            // Comments on pull requests are delivered as issue comments
+            if payload["issue"].get("pull_request").is_some() {
+                Some(ResourceUri::PullRequest(PullRequestId::new(
+                    repository_id,
+                    number,
+                )))
+            } else {
+                Some(ResourceUri::Issue(IssueId::new(repository_id, number)))
+            }
+        }
+        "pull_request" | "pull_request_review" | "pull_request_review_comment" => {
+            let number = number(&["pull_request", "number"])?;
+            Some(ResourceUri::PullRequest(PullRequestId::new(
+                repository_id,
+                number,
+            )))
+        }
+        _ => None,
+    }
+}
+
+fn summarize(
+    event: &str,
+    action: Option<&str>,
+    payload: &Value,
+    resource_uri: Option<&ResourceUri>,
+    repository: Option<&str>,
+    sender: Option<&str>,
+) -> String {
+    let by = sender
+        .map(|sender| format!(" by {}", sender))
+        .unwrap_or_default();
+    let action_text = action.unwrap_or("delivered").replace('_', " ");
+
+    match (event, resource_uri) {
+        ("ping", _) => format!(
+            "Webhook ping{}: {}",
+            repository
+                .map(|repository| format!(" from {}", repository))
+                .unwrap_or_default(),
+            text(payload, &["zen"]).unwrap_or_default()
+        ),
+        ("issues", Some(uri)) => format!(
+            "Issue {} {}{}: {}",
+            uri.name(),
+            action_text,
+            by,
+            text(payload, &["issue", "title"]).unwrap_or_default()
+        ),
+        ("pull_request", Some(uri)) if action == Some("review_requested") => {
+            let reviewer = text(payload, &["requested_reviewer", "login"])
+                .or_else(|| text(payload, &["requested_team", "name"]))
+                .unwrap_or_else(|| "a reviewer".to_string());
+            format!(
+                "Review of pull request {} requested from {}{}: {}",
+                uri.name(),
+                reviewer,
+                by,
+                text(payload, &["pull_request", "title"]).unwrap_or_default()
+            )
+        }
+        ("pull_request", Some(uri)) => format!(
+            "Pull request {} {}{}: {}",
+            uri.name(),
+            action_text,
+            by,
+            text(payload, &["pull_request", "title"]).unwrap_or_default()
+        ),
+        ("issue_comment", Some(uri)) => format!("Comment {} on {}{}", action_text, uri.name(), by),
+        ("pull_request_review", Some(uri)) => format!(
+            "Review {} on pull request {}{}{}",
+            action_text,
+            uri.name(),
+            by,
+            text(payload, &["review", "state"])
+                .map(|state| format!(": {}", state))
+                .unwrap_or_default()
+        ),
+        ("pull_request_review_comment", Some(uri)) => format!(
+            "Review comment {} on pull request {}{}",
+            action_text,
+            uri.name(),
+            by
+        ),
+        (event, _) => format!(
+            "{} {}{}{}",
+            event,
+            action_text,
+            repository
+                .map(|repository| format!(" in {}", repository))
+                .unwrap_or_default(),
+            by
+        ),
+    }
+}
+
+fn text(payload: &Value, path: &[&str]) -> Option<String> {
+    path.iter()
+        .try_fold(payload, |value, key| value.get(key))?
+        .as_str()
+        .map(str::to_string)
+}
+
+fn number(payload: &Value, path: &[&str]) -> Option<u64> {
+    path.iter()
+        .try_fold(payload, |value, key| value.get(key))?
+        .as_u64()
+}
@@ -1,6 +1,18 @@
+/// Append-only audit log of the operations writing to GitHub
+pub mod audit;
+
+/// GitHub token resolution from the environment and credential stores
+pub mod auth;
+
+/// User configuration file, including saved search aliases
+pub mod config;
+
 /// GitHub API client implementations and utilities for fetching repository data
 pub mod github;
 
+/// Offline queue of the CLI commands that failed to reach GitHub
+pub mod queue;
+
 /// Core services for search, synchronization, and embeddings generation
 pub mod services;
 
@@ -0,0 +1,215 @@
+//! Offline queue of the CLI commands that failed to reach GitHub
+//!
+//! The queue is a JSON Lines file of `QueuedCommand` entries, oldest first.
+//! Commands are appended with a single write each, and `queue flush`
+//! removes the entries it replayed from the front. Both hold a lock file
+//! next to the queue while they change it, so commands queued by other
+//! processes during a flush are kept.
+
+use crate::config::QueueConfig;
+use crate::types::queue::QueuedCommand;
+
+use anyhow::{Context, Result};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+/// How long to wait for another process to release the queue
+const LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Interval of the attempts to take the lock
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Age after which a lock is taken to be left behind by a crashed process
+const STALE_LOCK_AGE: Duration = Duration::from_secs(60);
+
+/// JSON Lines file of queued commands
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OfflineQueue {
+    path: PathBuf,
+}
+
+impl OfflineQueue {
+    /// Create a queue stored at `path`
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Returns the queue of the configuration
+    pub fn from_config(config: &QueueConfig) -> Result<Self> {
+        Ok(Self::new(config.queue_path()?))
+    }
+
+    /// Returns the path of the queue file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append a command, creating the file and its directory if needed
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    pub fn push(&self, command: &QueuedCommand) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+        }
+        let mut line = serde_json::to_vec(command)?;
+        line.push(b'\n');
+        let _lock = QueueLock::acquire(&self.path)?;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open queue {}", self.path.display()))?;
+        file.write_all(&line)
+            .with_context(|| format!("Failed to write queue {}", self.path.display()))
+    }
+
+    /// Read the queued commands, oldest first
+    ///
+    /// A missing file has no commands. Lines that are not valid commands,
+    /// such as a line cut short by a crash, are skipped with a warning.
+    ///
+    /// # Errors
+    /// Returns an error if the file exists but cannot be read.
+    pub fn read(&self) -> Result<Vec<QueuedCommand>> {
+        Ok(self
+            .read_lines()?
+            .into_iter()
+            .filter_map(|(index, line)| match serde_json::from_str(&line) {
+                Ok(command) => Some(command),
+                Err(e) => {
+                    tracing::warn!(
+                        "Skipping invalid line {} of queue {}: {}",
+                        index + 1,
+                        self.path.display(),
+                        e
+                    );
+                    None
+                }
+            })
+            .collect())
+    }
+
+    /// Remove the `count` oldest commands, or all of them with `None`
+    ///
+    /// The queue is locked from reading to rewriting it, so commands other
+    /// processes append meanwhile are kept. Lines that are not valid
+    /// commands are kept too, unless the whole queue is cleared. The file is
+    /// removed once no line is left.
+    ///
+    /// # Errors
+    /// Returns an error if the queue cannot be locked, read or rewritten.
+    pub fn remove_oldest(&self, count: Option<usize>) -> Result<()> {
+        if !self.path.exists() {
+            return Ok(());
+        }
+        let _lock = QueueLock::acquire(&self.path)?;
+        let kept = match count {
+            Some(mut count) => self
+                .read_lines()?
+                .into_iter()
+                .filter(|(_, line)| {
+                    if count == 0 || serde_json::from_str::<QueuedCommand>(line).is_err() {
+                        return true;
+                    }
+                    count -= 1;
+                    false
+                })
+                .map(|(_, line)| line)
+                .collect::<Vec<_>>(),
+            None => Vec::new(),
+        };
+
+        if kept.is_empty() {
+            return match std::fs::remove_file(&self.path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e)
+                    .with_context(|| format!("Failed to remove queue {}", self.path.display())),
+                _ => Ok(()),
+            };
+        }
+        // Write a sibling file first, so a crash never leaves half a queue
+        let temporary = self.path.with_extension("jsonl.tmp");
+        let mut content = kept.join("\n");
+        content.push('\n');
+        std::fs::write(&temporary, content)
+            .with_context(|| format!("Failed to write queue {}", temporary.display()))?;
+        std::fs::rename(&temporary, &self.path)
+            .with_context(|| format!("Failed to write queue {}", self.path.display()))
+    }
+
+    /// Non-empty lines of the file with their index
+    fn read_lines(&self) -> Result<Vec<(usize, String)>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let file = std::fs::File::open(&self.path)
+            .with_context(|| format!("Failed to open queue {}", self.path.display()))?;
+
+        let mut lines = Vec::new();
+        for (index, line) in BufReader::new(file).lines().enumerate() {
+            let line =
+                line.with_context(|| format!("Failed to read queue {}", self.path.display()))?;
+            if !line.trim().is_empty() {
+                lines.push((index, line));
+            }
+        }
+        Ok(lines)
+    }
+}
+
+/// Lock file held while a process changes the queue, removed when dropped
+struct QueueLock {
+    path: PathBuf,
+}
+
+impl QueueLock {
+    /// Take the lock of the queue at `queue_path`, waiting for other
+    /// processes holding it
+    ///
+    /// A lock older than `STALE_LOCK_AGE` is taken over, as no process
+    /// holds it that long.
+    fn acquire(queue_path: &Path) -> Result<Self> {
+        let path = queue_path.with_extension("jsonl.lock");
+        let started = Instant::now();
+        loop {
+            match std::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+            {
+                Ok(_) => return Ok(Self { path }),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                    let stale = std::fs::metadata(&path)
+                        .and_then(|metadata| metadata.modified())
+                        .ok()
+                        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+                        .is_some_and(|age| age > STALE_LOCK_AGE);
+                    if stale {
+                        tracing::warn!("Taking over the stale queue lock {}", path.display());
+                        let _ = std::fs::remove_file(&path);
+                        continue;
+                    }
+                    if started.elapsed() > LOCK_TIMEOUT {
+                        anyhow::bail!(
+                            "Queue is locked by another process; remove {} if no other command is running",
+                            path.display()
+                        );
+                    }
+                    std::thread::sleep(LOCK_RETRY_INTERVAL);
+                }
+                Err(e) => {
+                    return Err(e)
+                        .with_context(|| format!("Failed to lock queue {}", path.display()));
+                }
+            }
+        }
+    }
+}
+
+impl Drop for QueueLock {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
@@ -0,0 +1,252 @@
+use crate::github::GitHubClient;
+use crate::types::actions::{
+    Artifact, ArtifactId, Deployment, DeploymentReviewState, PendingDeployment, WorkflowJob,
+    WorkflowRun, WorkflowRunFilter, WorkflowRunId,
+};
+use crate::types::pagination::{Page, Pagination};
+use crate::types::repository::RepositoryId;
+use anyhow::Result;
+use std::collections::BTreeMap;
+
+/// Service layer for GitHub Actions operations
+///
+/// This service provides a high-level interface for dispatching workflows,
+/// managing their runs and downloading their logs and artifacts.
+pub struct ActionsService {
+    github_client: GitHubClient,
+}
+
+impl ActionsService {
+    /// Create a new actions service instance
+    pub fn new(github_client: GitHubClient) -> Self {
+        Self { github_client }
+    }
+
+    /// Trigger a `workflow_dispatch` event for a workflow
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `workflow` - Workflow file name or numeric workflow ID
+    /// * `git_ref` - Branch or tag to run the workflow on
+    /// * `inputs` - Input values for the workflow
+    pub async fn dispatch_workflow(
+        &self,
+        repository_id: &RepositoryId,
+        workflow: &str,
+        git_ref: &str,
+        inputs: &BTreeMap<String, String>,
+    ) -> Result<()> {
+        self.github_client
+            .dispatch_workflow(repository_id, workflow, git_ref, inputs)
+            .await
+    }
+
+    /// List workflow runs of a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `filter` - Workflow, branch, event and status to restrict the runs to
+    /// * `pagination` - The page of runs to fetch
+    ///
+    /// # Returns
+    /// A page of workflow runs, most recent first
+    pub async fn list_workflow_runs(
+        &self,
+        repository_id: &RepositoryId,
+        filter: &WorkflowRunFilter,
+        pagination: Pagination,
+    ) -> Result<Page<WorkflowRun>> {
+        self.github_client
+            .list_workflow_runs(repository_id, filter, pagination)
+            .await
+    }
+
+    /// Cancel a queued or in-progress workflow run
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `run_id` - The workflow run to cancel
+    pub async fn cancel_workflow_run(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: WorkflowRunId,
+    ) -> Result<()> {
+        self.github_client
+            .cancel_workflow_run(repository_id, run_id)
+            .await
+    }
+
+    /// Re-run all jobs of a workflow run
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `run_id` - The workflow run to re-run
+    pub async fn rerun_workflow_run(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: WorkflowRunId,
+    ) -> Result<()> {
+        self.github_client
+            .rerun_workflow_run(repository_id, run_id)
+            .await
+    }
+
+    /// Approve a workflow run that waits for approval
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `run_id` - The workflow run to approve
+    pub async fn approve_workflow_run(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: WorkflowRunId,
+    ) -> Result<()> {
+        self.github_client
+            .approve_workflow_run(repository_id, run_id)
+            .await
+    }
+
+    /// Re-run only the failed jobs of a workflow run
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `run_id` - The workflow run to re-run the failed jobs of
+    pub async fn rerun_failed_jobs(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: WorkflowRunId,
+    ) -> Result<()> {
+        self.github_client
+            .rerun_failed_jobs(repository_id, run_id)
+            .await
+    }
+
+    /// List the jobs of a workflow run
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `run_id` - The workflow run to list the jobs of
+    /// * `all_attempts` - Whether to include jobs of earlier attempts
+    /// * `pagination` - The page of jobs to fetch
+    ///
+    /// # Returns
+    /// A page of jobs with their steps
+    pub async fn list_jobs_for_run(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: WorkflowRunId,
+        all_attempts: bool,
+        pagination: Pagination,
+    ) -> Result<Page<WorkflowJob>> {
+        self.github_client
+            .list_jobs_for_run(repository_id, run_id, all_attempts, pagination)
+            .await
+    }
+
+    /// Download the log archive of a workflow run
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `run_id` - The workflow run to download the logs of
+    ///
+    /// # Returns
+    /// The zip archive of the run's logs
+    pub async fn download_workflow_run_logs(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: WorkflowRunId,
+    ) -> Result<Vec<u8>> {
+        self.github_client
+            .download_workflow_run_logs(repository_id, run_id)
+            .await
+    }
+
+    /// List artifacts of a repository or of a single workflow run
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `run_id` - Optional workflow run to list the artifacts of
+    /// * `pagination` - The page of artifacts to fetch
+    ///
+    /// # Returns
+    /// A page of artifacts, most recent first
+    pub async fn list_artifacts(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: Option<WorkflowRunId>,
+        pagination: Pagination,
+    ) -> Result<Page<Artifact>> {
+        self.github_client
+            .list_artifacts(repository_id, run_id, pagination)
+            .await
+    }
+
+    /// Get a single artifact
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `artifact_id` - The artifact identifier
+    pub async fn get_artifact(
+        &self,
+        repository_id: &RepositoryId,
+        artifact_id: ArtifactId,
+    ) -> Result<Artifact> {
+        self.github_client
+            .get_artifact(repository_id, artifact_id)
+            .await
+    }
+
+    /// Download an artifact as a zip archive
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `artifact_id` - The artifact to download
+    ///
+    /// # Returns
+    /// The zip archive with the files of the artifact
+    pub async fn download_artifact(
+        &self,
+        repository_id: &RepositoryId,
+        artifact_id: ArtifactId,
+    ) -> Result<Vec<u8>> {
+        self.github_client
+            .download_artifact(repository_id, artifact_id)
+            .await
+    }
+
+    /// List the deployments of a workflow run waiting on protected environments
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `run_id` - The workflow run
+    pub async fn list_pending_deployments(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: WorkflowRunId,
+    ) -> Result<Vec<PendingDeployment>> {
+        self.github_client
+            .list_pending_deployments(repository_id, run_id)
+            .await
+    }
+
+    /// Approve or reject deployments of a workflow run waiting on protected environments
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `run_id` - The workflow run
+    /// * `environment_ids` - IDs of the environments to review the deployments for
+    /// * `state` - Whether to approve or reject the deployments
+    /// * `comment` - Comment recorded with the review
+    pub async fn review_pending_deployments(
+        &self,
+        repository_id: &RepositoryId,
+        run_id: WorkflowRunId,
+        environment_ids: &[u64],
+        state: DeploymentReviewState,
+        comment: &str,
+    ) -> Result<Vec<Deployment>> {
+        self.github_client
+            .review_pending_deployments(repository_id, run_id, environment_ids, state, comment)
+            .await
+    }
+}
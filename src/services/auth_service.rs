@@ -0,0 +1,35 @@
+use crate::github::GitHubClient;
+use crate::types::auth::{AuthStatus, AuthenticatedUser};
+use crate::types::rate_limit::RateLimit;
+use crate::types::repository::Owner;
+use anyhow::Result;
+
+/// Service layer for authentication operations
+///
+/// This service provides a high-level interface for checking the identity,
+/// permissions and rate limits of the configured token.
+pub struct AuthService {
+    github_client: GitHubClient,
+}
+
+impl AuthService {
+    /// Create a new auth service instance
+    pub fn new(github_client: GitHubClient) -> Self {
+        Self { github_client }
+    }
+
+    /// Verify the configured token and read its identity and scopes
+    pub async fn verify_auth(&self) -> Result<AuthStatus> {
+        self.github_client.verify_auth().await
+    }
+
+    /// Get the account the configured token acts as
+    pub async fn get_authenticated_user(&self) -> Result<AuthenticatedUser> {
+        self.github_client.get_authenticated_user().await
+    }
+
+    /// Get the rate limit buckets of the token of `owner`, or of the client's token
+    pub async fn get_rate_limit(&self, owner: Option<&Owner>) -> Result<RateLimit> {
+        self.github_client.get_rate_limit(owner).await
+    }
+}
@@ -0,0 +1,80 @@
+use crate::github::GitHubClient;
+use crate::types::check::{CheckRun, CombinedStatus, CommitState, CommitStatus};
+use crate::types::pagination::{Page, Pagination};
+use crate::types::repository::RepositoryId;
+use anyhow::Result;
+
+/// Service layer for check run and commit status operations
+///
+/// This service provides a high-level interface for reading and reporting
+/// the CI signals attached to commits.
+pub struct CheckService {
+    github_client: GitHubClient,
+}
+
+impl CheckService {
+    /// Create a new check service instance
+    pub fn new(github_client: GitHubClient) -> Self {
+        Self { github_client }
+    }
+
+    /// List check runs for a commit SHA, branch or tag
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `git_ref` - Commit SHA, branch name or tag name
+    /// * `check_name` - Optional check run name to filter by
+    /// * `pagination` - The page of check runs to fetch
+    ///
+    /// # Returns
+    /// A page of check runs for the ref
+    pub async fn list_check_runs_for_ref(
+        &self,
+        repository_id: &RepositoryId,
+        git_ref: &str,
+        check_name: Option<&str>,
+        pagination: Pagination,
+    ) -> Result<Page<CheckRun>> {
+        self.github_client
+            .list_check_runs_for_ref(repository_id, git_ref, check_name, pagination)
+            .await
+    }
+
+    /// Get the combined commit status for a commit SHA, branch or tag
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `git_ref` - Commit SHA, branch name or tag name
+    pub async fn get_combined_status(
+        &self,
+        repository_id: &RepositoryId,
+        git_ref: &str,
+    ) -> Result<CombinedStatus> {
+        self.github_client
+            .get_combined_status(repository_id, git_ref)
+            .await
+    }
+
+    /// Create a commit status
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `sha` - The commit SHA to attach the status to
+    /// * `state` - The state of the status
+    /// * `context` - Optional label identifying the status
+    /// * `description` - Optional short description of the status
+    /// * `target_url` - Optional URL with details of the status
+    pub async fn create_commit_status(
+        &self,
+        repository_id: &RepositoryId,
+        sha: &str,
+        state: CommitState,
+        context: Option<&str>,
+        description: Option<&str>,
+        target_url: Option<&str>,
+    ) -> Result<CommitStatus> {
+        self.github_client
+            .create_commit_status(repository_id, sha, state, context, description, target_url)
+            .await
+    }
+}
@@ -0,0 +1,90 @@
+use crate::github::GitHubClient;
+use crate::types::comment::{CommentPermissions, CommentTarget, MinimizeReason};
+use crate::types::repository::RepositoryId;
+use anyhow::Result;
+
+/// Service layer for comment moderation operations
+///
+/// This service provides a high-level interface for hiding, showing and
+/// deleting comments on issues and pull requests.
+pub struct CommentService {
+    github_client: GitHubClient,
+}
+
+impl CommentService {
+    /// Create a new comment service instance
+    pub fn new(github_client: GitHubClient) -> Self {
+        Self { github_client }
+    }
+
+    /// Get the permissions of the authenticated user on a comment
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `target` - The comment to check
+    ///
+    /// # Returns
+    /// Whether the user may edit, delete, or hide the comment
+    pub async fn get_permissions(
+        &self,
+        repository_id: &RepositoryId,
+        target: CommentTarget,
+    ) -> Result<CommentPermissions> {
+        self.github_client
+            .get_comment_permissions(repository_id, target)
+            .await
+    }
+
+    /// Hide (minimize) a comment
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `target` - The comment to hide
+    /// * `reason` - The classifier recorded for the hidden comment
+    ///
+    /// # Returns
+    /// `true` if the comment is now minimized
+    pub async fn minimize_comment(
+        &self,
+        repository_id: &RepositoryId,
+        target: CommentTarget,
+        reason: MinimizeReason,
+    ) -> Result<bool> {
+        self.github_client
+            .minimize_comment(repository_id, target, reason)
+            .await
+    }
+
+    /// Show (unminimize) a hidden comment
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `target` - The comment to show again
+    ///
+    /// # Returns
+    /// `true` if the comment is still minimized
+    pub async fn unminimize_comment(
+        &self,
+        repository_id: &RepositoryId,
+        target: CommentTarget,
+    ) -> Result<bool> {
+        self.github_client
+            .unminimize_comment(repository_id, target)
+            .await
+    }
+
+    /// Delete a comment
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `target` - The comment to delete
+    pub async fn delete_comment(
+        &self,
+        repository_id: &RepositoryId,
+        target: CommentTarget,
+    ) -> Result<()> {
+        self.github_client
+            .delete_comment(repository_id, target)
+            .await
+    }
+}
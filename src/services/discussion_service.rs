@@ -0,0 +1,158 @@
+use crate::github::GitHubClient;
+use crate::types::discussion::{
+    Discussion, DiscussionCategory, DiscussionComment, DiscussionNumber, LockReason,
+};
+use crate::types::repository::RepositoryId;
+use anyhow::Result;
+
+/// Service layer for GitHub Discussions operations
+///
+/// This service provides a high-level interface for creating discussions,
+/// commenting on them, choosing their answers and moderating them.
+pub struct DiscussionService {
+    github_client: GitHubClient,
+}
+
+impl DiscussionService {
+    /// Create a new discussion service instance
+    pub fn new(github_client: GitHubClient) -> Self {
+        Self { github_client }
+    }
+
+    /// List the discussion categories of a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    pub async fn list_discussion_categories(
+        &self,
+        repository_id: &RepositoryId,
+    ) -> Result<Vec<DiscussionCategory>> {
+        self.github_client
+            .list_discussion_categories(repository_id)
+            .await
+    }
+
+    /// Get a discussion with its comments and their replies
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `discussion_number` - The discussion number
+    pub async fn get_discussion(
+        &self,
+        repository_id: &RepositoryId,
+        discussion_number: DiscussionNumber,
+    ) -> Result<Discussion> {
+        self.github_client
+            .get_discussion(repository_id, discussion_number)
+            .await
+    }
+
+    /// Create a discussion in a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `category_id` - GraphQL node ID of the discussion category
+    /// * `title` - The discussion title
+    /// * `body` - The discussion body
+    pub async fn create_discussion(
+        &self,
+        repository_id: &RepositoryId,
+        category_id: &str,
+        title: &str,
+        body: &str,
+    ) -> Result<Discussion> {
+        self.github_client
+            .create_discussion(repository_id, category_id, title, body)
+            .await
+    }
+
+    /// Add a comment to a discussion, or reply to one of its comments
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `discussion_number` - The discussion number
+    /// * `body` - The comment body
+    /// * `reply_to_id` - GraphQL node ID of the comment to reply to
+    pub async fn add_discussion_comment(
+        &self,
+        repository_id: &RepositoryId,
+        discussion_number: DiscussionNumber,
+        body: &str,
+        reply_to_id: Option<&str>,
+    ) -> Result<DiscussionComment> {
+        self.github_client
+            .add_discussion_comment(repository_id, discussion_number, body, reply_to_id)
+            .await
+    }
+
+    /// Mark a discussion comment as the answer of its discussion
+    ///
+    /// # Arguments
+    /// * `comment_id` - GraphQL node ID of the comment
+    pub async fn mark_comment_as_answer(&self, comment_id: &str) -> Result<DiscussionNumber> {
+        self.github_client.mark_comment_as_answer(comment_id).await
+    }
+
+    /// Lock a discussion so only collaborators can comment on it
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `discussion_number` - The discussion number
+    /// * `reason` - Optional reason recorded for the lock
+    pub async fn lock_discussion(
+        &self,
+        repository_id: &RepositoryId,
+        discussion_number: DiscussionNumber,
+        reason: Option<LockReason>,
+    ) -> Result<bool> {
+        self.github_client
+            .lock_discussion(repository_id, discussion_number, reason)
+            .await
+    }
+
+    /// Unlock a previously locked discussion
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `discussion_number` - The discussion number
+    pub async fn unlock_discussion(
+        &self,
+        repository_id: &RepositoryId,
+        discussion_number: DiscussionNumber,
+    ) -> Result<bool> {
+        self.github_client
+            .unlock_discussion(repository_id, discussion_number)
+            .await
+    }
+
+    /// Update the title, body or category of a discussion
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `discussion_number` - The discussion number
+    /// * `title` - Optional new title
+    /// * `body` - Optional new body
+    /// * `category_id` - Optional GraphQL node ID of the new category
+    pub async fn update_discussion(
+        &self,
+        repository_id: &RepositoryId,
+        discussion_number: DiscussionNumber,
+        title: Option<&str>,
+        body: Option<&str>,
+        category_id: Option<&str>,
+    ) -> Result<Discussion> {
+        self.github_client
+            .update_discussion(repository_id, discussion_number, title, body, category_id)
+            .await
+    }
+
+    /// Delete a discussion comment or reply
+    ///
+    /// # Arguments
+    /// * `comment_id` - GraphQL node ID of the comment
+    pub async fn delete_discussion_comment(&self, comment_id: &str) -> Result<()> {
+        self.github_client
+            .delete_discussion_comment(comment_id)
+            .await
+    }
+}
@@ -1,8 +1,14 @@
 use crate::github::GitHubClient;
-use crate::types::issue::{Issue, IssueCommentNumber, IssueNumber, IssueState};
-use crate::types::repository::{MilestoneNumber, RepositoryId};
+use crate::types::conditional::Conditional;
+use crate::types::issue::{
+    Issue, IssueComment, IssueCommentNumber, IssueNumber, IssueState, IssueStateReason, IssueType,
+};
+use crate::types::pagination::{Page, Pagination};
+use crate::types::repository::{MilestoneNumber, Owner, RepositoryId};
+use crate::types::timeline::{CrossReference, IssueTimelineEvent};
 use crate::types::{User, label::Label};
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 
 /// Service layer for issue operations
 ///
@@ -176,20 +182,23 @@ impl IssueService {
 
     /// Update the state of an issue
     ///
-    /// Changes an issue's state between open and closed.
+    /// Changes an issue's state between open and closed, optionally
+    /// recording the reason for the change.
     ///
     /// # Arguments
     /// * `repository_id` - The repository identifier
     /// * `issue_number` - The issue number to update
     /// * `state` - The new state for the issue
+    /// * `state_reason` - Optional reason (completed, not_planned, reopened)
     pub async fn update_state(
         &self,
         repository_id: &RepositoryId,
         issue_number: IssueNumber,
         state: IssueState,
+        state_reason: Option<IssueStateReason>,
     ) -> Result<()> {
         self.github_client
-            .update_issue_state(repository_id, issue_number, state)
+            .update_issue_state(repository_id, issue_number, state, state_reason)
             .await
     }
 
@@ -210,6 +219,7 @@ impl IssueService {
     ///
     /// # Returns
     /// The updated issue with all current metadata
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_issue(
         &self,
         repository_id: &RepositoryId,
@@ -253,6 +263,155 @@ impl IssueService {
             .await
     }
 
+    /// List issues in a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `state` - Optional state filter; `None` lists all issues
+    /// * `labels` - Label names that every returned issue must have
+    /// * `assignee` - Optional assignee login
+    /// * `milestone` - Optional milestone the issues must belong to
+    /// * `since` - Only issues updated at or after this time
+    /// * `pagination` - The page of issues to fetch
+    ///
+    /// # Returns
+    /// A page of issues, excluding pull requests
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_issues(
+        &self,
+        repository_id: &RepositoryId,
+        state: Option<IssueState>,
+        labels: &[String],
+        assignee: Option<&str>,
+        milestone: Option<MilestoneNumber>,
+        since: Option<DateTime<Utc>>,
+        pagination: Pagination,
+    ) -> Result<Page<Issue>> {
+        self.github_client
+            .list_issues(
+                repository_id,
+                state,
+                labels,
+                assignee,
+                milestone,
+                since,
+                pagination,
+            )
+            .await
+    }
+
+    /// List comments on an issue
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `issue_number` - The issue number
+    /// * `since` - Only comments updated at or after this time
+    /// * `author` - Only comments written by this login
+    /// * `pagination` - The page of comments to fetch
+    ///
+    /// # Returns
+    /// A page of issue comments
+    pub async fn list_comments(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+        since: Option<DateTime<Utc>>,
+        author: Option<&str>,
+        pagination: Pagination,
+    ) -> Result<Page<IssueComment>> {
+        self.github_client
+            .list_issue_comments(repository_id, issue_number, since, author, pagination)
+            .await
+    }
+
+    /// Get the timeline events of an issue
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `issue_number` - The issue or pull request number
+    /// * `pagination` - The page of events to fetch
+    ///
+    /// # Returns
+    /// A page of timeline events in chronological order
+    pub async fn get_timeline(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+        pagination: Pagination,
+    ) -> Result<Page<IssueTimelineEvent>> {
+        self.github_client
+            .get_issue_timeline(repository_id, issue_number, pagination)
+            .await
+    }
+
+    /// Get a page of the timeline events of an issue if it changed
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `issue_number` - The issue or pull request number
+    /// * `pagination` - The page of events to fetch
+    /// * `etag` - ETag of the page fetched before, if any
+    ///
+    /// # Returns
+    /// The page with its ETag, or `Conditional::NotModified`
+    pub async fn get_timeline_if_changed(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+        pagination: Pagination,
+        etag: Option<&str>,
+    ) -> Result<Conditional<Page<IssueTimelineEvent>>> {
+        self.github_client
+            .get_issue_timeline_if_changed(repository_id, issue_number, pagination, etag)
+            .await
+    }
+
+    /// List the issues and pull requests that reference an issue or pull request
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `issue_number` - The issue or pull request number
+    ///
+    /// # Returns
+    /// One cross-reference per cross-referenced event in chronological order
+    pub async fn list_cross_references(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+    ) -> Result<Vec<CrossReference>> {
+        self.github_client
+            .list_cross_references(repository_id, issue_number)
+            .await
+    }
+
+    /// List the issue types defined by an organization
+    ///
+    /// # Arguments
+    /// * `owner` - The organization login
+    ///
+    /// # Returns
+    /// All issue types of the organization
+    pub async fn list_issue_types(&self, owner: &Owner) -> Result<Vec<IssueType>> {
+        self.github_client.list_issue_types(owner).await
+    }
+
+    /// Set or clear the issue type of an issue
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `issue_number` - The issue number to classify
+    /// * `issue_type` - The issue type name, or `None` to clear the type
+    pub async fn set_issue_type(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+        issue_type: Option<&str>,
+    ) -> Result<()> {
+        self.github_client
+            .set_issue_type(repository_id, issue_number, issue_type)
+            .await
+    }
+
     /// Add assignees to an issue
     ///
     /// Adds one or more assignees to an existing issue. Before adding,
@@ -1,4 +1,13 @@
+pub mod actions_service;
+pub mod auth_service;
+pub mod check_service;
+pub mod comment_service;
+pub mod discussion_service;
 pub mod issue_service;
+pub mod notification_service;
 pub mod project_service;
 pub mod pull_request_service;
+pub mod reaction_service;
 pub mod repository_service;
+pub mod search_service;
+pub mod secret_service;
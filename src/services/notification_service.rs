@@ -0,0 +1,132 @@
+use crate::github::GitHubClient;
+use crate::types::issue::IssueNumber;
+use crate::types::notification::{
+    IssueSubscription, Notification, NotificationFilter, NotificationThreadId, ThreadSubscription,
+};
+use crate::types::pagination::{Page, Pagination};
+use crate::types::repository::RepositoryId;
+use anyhow::Result;
+
+/// Service layer for notification inbox operations
+///
+/// This service provides a high-level interface for triaging the
+/// authenticated user's notifications and managing subscriptions to
+/// threads, issues and pull requests.
+pub struct NotificationService {
+    github_client: GitHubClient,
+}
+
+impl NotificationService {
+    /// Create a new notification service instance
+    pub fn new(github_client: GitHubClient) -> Self {
+        Self { github_client }
+    }
+
+    /// List notifications of the authenticated user
+    ///
+    /// # Arguments
+    /// * `repository_id` - Optional repository to restrict the notifications to
+    /// * `filter` - Read state, participation and time range filter
+    /// * `pagination` - The page of notifications to fetch
+    pub async fn list_notifications(
+        &self,
+        repository_id: Option<&RepositoryId>,
+        filter: &NotificationFilter,
+        pagination: Pagination,
+    ) -> Result<Page<Notification>> {
+        self.github_client
+            .list_notifications(repository_id, filter, pagination)
+            .await
+    }
+
+    /// Mark a notification thread as read
+    ///
+    /// # Arguments
+    /// * `thread_id` - The notification thread
+    pub async fn mark_notification_read(&self, thread_id: &NotificationThreadId) -> Result<()> {
+        self.github_client.mark_notification_read(thread_id).await
+    }
+
+    /// Mark a notification thread as done
+    ///
+    /// # Arguments
+    /// * `thread_id` - The notification thread
+    pub async fn mark_thread_done(&self, thread_id: &NotificationThreadId) -> Result<()> {
+        self.github_client.mark_thread_done(thread_id).await
+    }
+
+    /// Get the subscription to a notification thread
+    ///
+    /// # Arguments
+    /// * `thread_id` - The notification thread
+    pub async fn get_thread_subscription(
+        &self,
+        thread_id: &NotificationThreadId,
+    ) -> Result<ThreadSubscription> {
+        self.github_client.get_thread_subscription(thread_id).await
+    }
+
+    /// Subscribe to a notification thread
+    ///
+    /// # Arguments
+    /// * `thread_id` - The notification thread
+    pub async fn subscribe_thread(
+        &self,
+        thread_id: &NotificationThreadId,
+    ) -> Result<ThreadSubscription> {
+        self.github_client.subscribe_thread(thread_id).await
+    }
+
+    /// Unsubscribe from a notification thread
+    ///
+    /// # Arguments
+    /// * `thread_id` - The notification thread
+    pub async fn unsubscribe_thread(&self, thread_id: &NotificationThreadId) -> Result<()> {
+        self.github_client.unsubscribe_thread(thread_id).await
+    }
+
+    /// Get the subscription to an issue or pull request
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `issue_number` - The issue or pull request number
+    pub async fn get_issue_subscription(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+    ) -> Result<IssueSubscription> {
+        self.github_client
+            .get_issue_subscription(repository_id, issue_number)
+            .await
+    }
+
+    /// Subscribe to an issue or pull request
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `issue_number` - The issue or pull request number
+    pub async fn subscribe_to_issue(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+    ) -> Result<IssueSubscription> {
+        self.github_client
+            .subscribe_to_issue(repository_id, issue_number)
+            .await
+    }
+
+    /// Unsubscribe from an issue or pull request
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `issue_number` - The issue or pull request number
+    pub async fn unsubscribe_from_issue(
+        &self,
+        repository_id: &RepositoryId,
+        issue_number: IssueNumber,
+    ) -> Result<IssueSubscription> {
+        self.github_client
+            .unsubscribe_from_issue(repository_id, issue_number)
+            .await
+    }
+}
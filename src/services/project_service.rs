@@ -1,5 +1,12 @@
 use crate::github::GitHubClient;
-use crate::types::project::{ProjectFieldValue, ProjectId};
+use crate::types::issue::IssueId;
+use crate::types::label::Label;
+use crate::types::project::{
+    DraftIssueId, Project, ProjectCustomFieldType, ProjectDetails, ProjectDraftIssue, ProjectField,
+    ProjectFieldDataType, ProjectFieldValue, ProjectId, ProjectItem, ProjectOriginalResource,
+    ProjectType, ProjectUpdate,
+};
+use crate::types::repository::Owner;
 use crate::types::{
     IssueNumber, ProjectFieldId, ProjectItemId, ProjectNodeId, PullRequestNumber, RepositoryId,
 };
@@ -20,6 +27,462 @@ impl ProjectService {
         Self { github_client }
     }
 
+    /// Create a new project owned by a user or organization
+    ///
+    /// # Arguments
+    /// * `owner` - The user or organization that will own the project
+    /// * `title` - The project title
+    /// * `project_type` - Whether the owner is a user or an organization
+    ///
+    /// # Returns
+    /// Returns the created project, including its number and node ID
+    pub async fn create_project(
+        &self,
+        owner: &Owner,
+        title: &str,
+        project_type: ProjectType,
+    ) -> Result<Project> {
+        self.github_client
+            .create_project(owner, title, project_type)
+            .await
+    }
+
+    /// Copy a project, including its fields and views
+    ///
+    /// # Arguments
+    /// * `source_project_node_id` - The node ID of the project to copy
+    /// * `owner` - The user or organization that will own the copy
+    /// * `project_type` - Whether the new owner is a user or an organization
+    /// * `title` - The title of the copy
+    /// * `include_draft_issues` - Whether draft issues are copied as well
+    ///
+    /// # Returns
+    /// Returns the new project
+    pub async fn copy_project(
+        &self,
+        source_project_node_id: &ProjectNodeId,
+        owner: &Owner,
+        project_type: ProjectType,
+        title: &str,
+        include_draft_issues: bool,
+    ) -> Result<Project> {
+        self.github_client
+            .copy_project(
+                source_project_node_id,
+                owner,
+                project_type,
+                title,
+                include_draft_issues,
+            )
+            .await
+    }
+
+    /// Get a project with its settings, field definitions and item count
+    ///
+    /// # Arguments
+    /// * `project_id` - The project identifier (owner, number and type)
+    ///
+    /// # Returns
+    /// Returns the project details, including its node ID
+    pub async fn get_project(&self, project_id: &ProjectId) -> Result<ProjectDetails> {
+        self.github_client.get_project(project_id).await
+    }
+
+    /// Update the settings of a project
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `update` - The settings to change
+    ///
+    /// # Returns
+    /// Returns the updated project
+    pub async fn update_project(
+        &self,
+        project_node_id: &ProjectNodeId,
+        update: &ProjectUpdate,
+    ) -> Result<Project> {
+        self.github_client
+            .update_project(project_node_id, update)
+            .await
+    }
+
+    /// Delete a project
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the project was successfully deleted
+    pub async fn delete_project(&self, project_node_id: &ProjectNodeId) -> Result<()> {
+        self.github_client.delete_project(project_node_id).await
+    }
+
+    /// List the items of a project with their field values
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    ///
+    /// # Returns
+    /// Returns all items of the project, including archived items
+    pub async fn list_project_items(
+        &self,
+        project_node_id: &ProjectNodeId,
+    ) -> Result<Vec<ProjectItem>> {
+        self.github_client.list_project_items(project_node_id).await
+    }
+
+    /// List the field definitions of a project
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    ///
+    /// # Returns
+    /// Returns all fields with their single select options and iteration configurations
+    pub async fn list_project_fields(
+        &self,
+        project_node_id: &ProjectNodeId,
+    ) -> Result<Vec<ProjectField>> {
+        self.github_client
+            .list_project_fields(project_node_id)
+            .await
+    }
+
+    /// Link a repository to a project
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `repository_id` - The repository to link
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the repository was successfully linked
+    pub async fn link_repository_to_project(
+        &self,
+        project_node_id: &ProjectNodeId,
+        repository_id: &RepositoryId,
+    ) -> Result<()> {
+        self.github_client
+            .link_repository_to_project(project_node_id, repository_id)
+            .await
+    }
+
+    /// Unlink a repository from a project
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `repository_id` - The repository to unlink
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the repository was successfully unlinked
+    pub async fn unlink_repository_from_project(
+        &self,
+        project_node_id: &ProjectNodeId,
+        repository_id: &RepositoryId,
+    ) -> Result<()> {
+        self.github_client
+            .unlink_repository_from_project(project_node_id, repository_id)
+            .await
+    }
+
+    /// Link an organization team to a project
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `organization` - The organization that owns the team
+    /// * `team_slug` - The team slug
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the team was successfully linked
+    pub async fn link_team_to_project(
+        &self,
+        project_node_id: &ProjectNodeId,
+        organization: &Owner,
+        team_slug: &str,
+    ) -> Result<()> {
+        self.github_client
+            .link_team_to_project(project_node_id, organization, team_slug)
+            .await
+    }
+
+    /// Unlink an organization team from a project
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `organization` - The organization that owns the team
+    /// * `team_slug` - The team slug
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the team was successfully unlinked
+    pub async fn unlink_team_from_project(
+        &self,
+        project_node_id: &ProjectNodeId,
+        organization: &Owner,
+        team_slug: &str,
+    ) -> Result<()> {
+        self.github_client
+            .unlink_team_from_project(project_node_id, organization, team_slug)
+            .await
+    }
+
+    /// Create a custom field in a project
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `name` - The field name
+    /// * `data_type` - The field type
+    /// * `options` - Option names for single select fields
+    ///
+    /// # Returns
+    /// Returns the created field with its ID and option IDs
+    pub async fn create_project_field(
+        &self,
+        project_node_id: &ProjectNodeId,
+        name: &str,
+        data_type: &ProjectCustomFieldType,
+        options: &[String],
+    ) -> Result<ProjectField> {
+        self.github_client
+            .create_project_field(project_node_id, name, data_type, options)
+            .await
+    }
+
+    /// Rename a project field and/or replace its single select options
+    ///
+    /// # Arguments
+    /// * `field_id` - The field ID (GraphQL node ID)
+    /// * `name` - Optional new field name
+    /// * `options` - Optional new option names for a single select field
+    ///
+    /// # Returns
+    /// Returns the updated field
+    pub async fn update_project_field(
+        &self,
+        field_id: &ProjectFieldId,
+        name: Option<&str>,
+        options: Option<&[String]>,
+    ) -> Result<ProjectField> {
+        self.github_client
+            .update_project_field(field_id, name, options)
+            .await
+    }
+
+    /// Delete a custom field from a project
+    ///
+    /// # Arguments
+    /// * `field_id` - The field ID (GraphQL node ID)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the field was successfully deleted
+    pub async fn delete_project_field(&self, field_id: &ProjectFieldId) -> Result<()> {
+        self.github_client.delete_project_field(field_id).await
+    }
+
+    /// Move a project item within the project's manual ordering
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `item_id` - The project item ID to move
+    /// * `after_item_id` - The item to place it after; `None` moves it to the top
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the item was successfully moved
+    pub async fn update_project_item_position(
+        &self,
+        project_node_id: &ProjectNodeId,
+        item_id: &ProjectItemId,
+        after_item_id: Option<&ProjectItemId>,
+    ) -> Result<()> {
+        self.github_client
+            .update_project_item_position(project_node_id, item_id, after_item_id)
+            .await
+    }
+
+    /// Add a draft issue to a project
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `title` - The draft issue title
+    /// * `body` - Optional draft issue body
+    ///
+    /// # Returns
+    /// Returns the new project item ID and draft issue ID
+    pub async fn add_draft_issue_to_project(
+        &self,
+        project_node_id: &ProjectNodeId,
+        title: &str,
+        body: Option<&str>,
+    ) -> Result<ProjectDraftIssue> {
+        self.github_client
+            .add_draft_issue_to_project(project_node_id, title, body)
+            .await
+    }
+
+    /// Update the title and/or body of a draft issue
+    ///
+    /// # Arguments
+    /// * `draft_issue_id` - The draft issue ID (not the project item ID)
+    /// * `title` - Optional new title
+    /// * `body` - Optional new body
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the draft issue was successfully updated
+    pub async fn update_draft_issue(
+        &self,
+        draft_issue_id: &DraftIssueId,
+        title: Option<&str>,
+        body: Option<&str>,
+    ) -> Result<()> {
+        self.github_client
+            .update_draft_issue(draft_issue_id, title, body)
+            .await
+    }
+
+    /// Convert a draft issue project item to a repository issue
+    ///
+    /// # Arguments
+    /// * `project_item_id` - The project item ID holding the draft issue
+    /// * `repository_id` - The repository to create the issue in
+    ///
+    /// # Returns
+    /// Returns the identifier of the created issue
+    pub async fn convert_draft_issue_to_issue(
+        &self,
+        project_item_id: &ProjectItemId,
+        repository_id: &RepositoryId,
+    ) -> Result<IssueId> {
+        self.github_client
+            .convert_draft_issue_to_issue(project_item_id, repository_id)
+            .await
+    }
+
+    /// Find the project item that represents an issue or pull request
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `content` - The issue or pull request
+    ///
+    /// # Returns
+    /// Returns the project item ID, or `None` if the content is not in the project
+    pub async fn find_project_item_for_content(
+        &self,
+        project_node_id: &ProjectNodeId,
+        content: &ProjectOriginalResource,
+    ) -> Result<Option<ProjectItemId>> {
+        self.github_client
+            .find_project_item_for_content(project_node_id, content)
+            .await
+    }
+
+    /// Remove an item from a project
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `item_id` - The project item ID (GraphQL node ID)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the item was successfully removed
+    pub async fn remove_item_from_project(
+        &self,
+        project_node_id: &ProjectNodeId,
+        item_id: &ProjectItemId,
+    ) -> Result<()> {
+        self.github_client
+            .remove_item_from_project(project_node_id, item_id)
+            .await
+    }
+
+    /// Archive a project item
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `item_id` - The project item ID (GraphQL node ID)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the item was successfully archived
+    pub async fn archive_project_item(
+        &self,
+        project_node_id: &ProjectNodeId,
+        item_id: &ProjectItemId,
+    ) -> Result<()> {
+        self.github_client
+            .set_project_item_archived(project_node_id, item_id, true)
+            .await
+    }
+
+    /// Restore an archived project item
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `item_id` - The project item ID (GraphQL node ID)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the item was successfully unarchived
+    pub async fn unarchive_project_item(
+        &self,
+        project_node_id: &ProjectNodeId,
+        item_id: &ProjectItemId,
+    ) -> Result<()> {
+        self.github_client
+            .set_project_item_archived(project_node_id, item_id, false)
+            .await
+    }
+
+    /// Resolve a single select option name to its option ID
+    ///
+    /// Looks up the field definition of the project and matches the option by
+    /// name, falling back to a case-insensitive name match and then to the
+    /// option ID itself.
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `field_id` - The single select field ID (GraphQL node ID)
+    /// * `option` - The option name (e.g. "In Progress") or option ID
+    ///
+    /// # Returns
+    /// Returns the option ID
+    ///
+    /// # Errors
+    /// Returns an error if the field does not exist, is not a single select
+    /// field, or has no matching option
+    pub async fn resolve_single_select_option(
+        &self,
+        project_node_id: &ProjectNodeId,
+        field_id: &ProjectFieldId,
+        option: &str,
+    ) -> Result<String> {
+        let fields = self.list_project_fields(project_node_id).await?;
+        let field = fields
+            .iter()
+            .find(|field| field.field_id.value() == field_id.value())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Field {} not found in project {}",
+                    field_id,
+                    project_node_id
+                )
+            })?;
+
+        if field.data_type != ProjectFieldDataType::SingleSelect {
+            return Err(anyhow::anyhow!(
+                "Field '{}' is a {} field, not a single select field",
+                field.name,
+                field.data_type
+            ));
+        }
+
+        field
+            .find_option(option)
+            .map(|option| option.id.clone())
+            .ok_or_else(|| {
+                let available: Vec<&str> = field.options.iter().map(|o| o.name.as_str()).collect();
+                anyhow::anyhow!(
+                    "Option '{}' not found in field '{}'. Available options: {}",
+                    option,
+                    field.name,
+                    available.join(", ")
+                )
+            })
+    }
+
     /// Update a project item field value
     ///
     /// Updates a field value for a project item using the project node ID.
@@ -40,6 +503,17 @@ impl ProjectService {
         project_field_id: &ProjectFieldId,
         value: &ProjectFieldValue,
     ) -> Result<()> {
+        if let ProjectFieldValue::MultiSelect(values) = value {
+            return self
+                .update_project_item_multi_select_field(
+                    project_node_id,
+                    project_item_id,
+                    project_field_id,
+                    values,
+                )
+                .await;
+        }
+
         self.github_client
             .update_project_item_field_value(
                 project_node_id,
@@ -50,6 +524,85 @@ impl ProjectService {
             .await
     }
 
+    /// Update a project item field with a set of options
+    ///
+    /// Projects v2 has no dedicated multi-select field, so the value is
+    /// applied according to the field's data type:
+    /// - Labels fields replace the labels of the underlying issue or pull request
+    /// - Single select fields accept exactly one option, resolved by name
+    ///
+    /// # Arguments
+    /// * `project_node_id` - The project node identifier (GraphQL ID)
+    /// * `item_id` - The project item ID (GraphQL node ID)
+    /// * `field_id` - The field ID (GraphQL node ID)
+    /// * `values` - The option (or label) names to set
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the field was successfully updated
+    ///
+    /// # Errors
+    /// Returns an error if the field does not accept multiple values, or if a
+    /// Labels field is set on a draft issue
+    pub async fn update_project_item_multi_select_field(
+        &self,
+        project_node_id: &ProjectNodeId,
+        item_id: &ProjectItemId,
+        field_id: &ProjectFieldId,
+        values: &[String],
+    ) -> Result<()> {
+        let fields = self.list_project_fields(project_node_id).await?;
+        let field = fields
+            .iter()
+            .find(|field| field.field_id.value() == field_id.value())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Field {} not found in project {}",
+                    field_id,
+                    project_node_id
+                )
+            })?;
+
+        match &field.data_type {
+            ProjectFieldDataType::Labels => {
+                let content = self.github_client.get_project_item_content(item_id).await?;
+                let (repository_id, number) = content.repository_and_number().ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Draft issues can not have labels; convert the draft issue first"
+                    )
+                })?;
+                let labels: Vec<Label> = values.iter().map(|v| Label::from(v.clone())).collect();
+                self.github_client
+                    .replace_issue_labels(repository_id, IssueNumber::new(number), &labels)
+                    .await
+            }
+            ProjectFieldDataType::SingleSelect => match values {
+                [option] => {
+                    let option_id = self
+                        .resolve_single_select_option(project_node_id, field_id, option)
+                        .await?;
+                    self.github_client
+                        .update_project_item_single_select_field(
+                            project_node_id,
+                            item_id,
+                            field_id,
+                            &option_id,
+                        )
+                        .await
+                }
+                _ => Err(anyhow::anyhow!(
+                    "Field '{}' is a single select field and holds exactly one option, got {}",
+                    field.name,
+                    values.len()
+                )),
+            },
+            data_type => Err(anyhow::anyhow!(
+                "Field '{}' is a {} field and does not accept multiple values",
+                field.name,
+                data_type
+            )),
+        }
+    }
+
     /// Update a project item field using typed field value
     ///
     /// Single method that dispatches to appropriate GitHub client method based on field value type.
@@ -101,11 +654,14 @@ impl ProjectService {
                     )
                     .await
             }
-            ProjectFieldValue::MultiSelect(_) => {
-                // MultiSelect is not supported by the current GitHub client methods
-                Err(anyhow::anyhow!(
-                    "MultiSelect field updates are not yet supported"
-                ))
+            ProjectFieldValue::MultiSelect(values) => {
+                self.update_project_item_multi_select_field(
+                    project_node_id,
+                    item_id,
+                    field_id,
+                    values,
+                )
+                .await
             }
         }
     }
@@ -1,10 +1,13 @@
 use crate::github::GitHubClient;
 use crate::types::label::Label;
+use crate::types::pagination::{Page, Pagination};
 use crate::types::pull_request::{
-    Branch, PullRequest, PullRequestCommentNumber, PullRequestNumber,
+    Branch, PullRequest, PullRequestComment, PullRequestCommentNumber, PullRequestHead,
+    PullRequestMergeStatus, PullRequestNumber,
 };
 use crate::types::repository::{MilestoneNumber, RepositoryId};
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 
 /// Service layer for pull request operations
 ///
@@ -75,6 +78,104 @@ impl PullRequestService {
             .await
     }
 
+    /// Get a pull request with at most `comment_limit` of its discussion comments
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `pr_number` - The pull request number
+    /// * `comment_limit` - Maximum number of comments to load (oldest first)
+    ///
+    /// # Returns
+    /// The pull request with up to `comment_limit` comments
+    pub async fn get_pull_request_with_comment_limit(
+        &self,
+        repository_id: &RepositoryId,
+        pr_number: PullRequestNumber,
+        comment_limit: usize,
+    ) -> Result<PullRequest> {
+        self.github_client
+            .get_pull_request_with_comment_limit(repository_id, pr_number, Some(comment_limit))
+            .await
+    }
+
+    /// List discussion comments on a pull request
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `pr_number` - The pull request number
+    /// * `since` - Only comments updated at or after this time
+    /// * `author` - Only comments written by this login
+    /// * `pagination` - The page of comments to fetch
+    ///
+    /// # Returns
+    /// A page of pull request discussion comments
+    pub async fn list_comments(
+        &self,
+        repository_id: &RepositoryId,
+        pr_number: PullRequestNumber,
+        since: Option<DateTime<Utc>>,
+        author: Option<&str>,
+        pagination: Pagination,
+    ) -> Result<Page<PullRequestComment>> {
+        self.github_client
+            .list_pull_request_comments(repository_id, pr_number, since, author, pagination)
+            .await
+    }
+
+    /// Get the head branch of a pull request
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `pr_number` - The pull request number
+    ///
+    /// # Returns
+    /// The head branch with the repository it lives in
+    pub async fn get_head(
+        &self,
+        repository_id: &RepositoryId,
+        pr_number: PullRequestNumber,
+    ) -> Result<PullRequestHead> {
+        self.github_client
+            .get_pull_request_head(repository_id, pr_number)
+            .await
+    }
+
+    /// Get the merge status of a pull request
+    ///
+    /// Aggregates mergeability, merge state, review decision, required checks
+    /// and branch protection into a single list of merge blockers.
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `pr_number` - The pull request number
+    ///
+    /// # Returns
+    /// A `PullRequestMergeStatus` describing the merge readiness and its blockers
+    pub async fn get_merge_status(
+        &self,
+        repository_id: &RepositoryId,
+        pr_number: PullRequestNumber,
+    ) -> Result<PullRequestMergeStatus> {
+        self.github_client
+            .get_pull_request_merge_status(repository_id, pr_number)
+            .await
+    }
+
+    /// Get the SHA of the head commit of a pull request
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier containing owner and repo name
+    /// * `pr_number` - The pull request number
+    pub async fn get_head_sha(
+        &self,
+        repository_id: &RepositoryId,
+        pr_number: PullRequestNumber,
+    ) -> Result<String> {
+        self.github_client
+            .get_pull_request_head_sha(repository_id, pr_number)
+            .await
+    }
+
     /// Add a comment to a pull request
     ///
     /// Creates a new comment on the specified pull request. This adds a general
@@ -0,0 +1,79 @@
+use crate::github::GitHubClient;
+use crate::types::reaction::{Reaction, ReactionKind, ReactionTarget};
+use crate::types::repository::RepositoryId;
+use anyhow::Result;
+
+/// Service layer for reaction operations
+///
+/// This service provides a high-level interface for managing emoji reactions
+/// on issues, pull requests, and their comments.
+pub struct ReactionService {
+    github_client: GitHubClient,
+}
+
+impl ReactionService {
+    /// Create a new reaction service instance
+    pub fn new(github_client: GitHubClient) -> Self {
+        Self { github_client }
+    }
+
+    /// Add a reaction to an issue, pull request, or comment
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `target` - The issue, pull request, or comment to react to
+    /// * `kind` - The reaction kind to add
+    ///
+    /// # Returns
+    /// The created (or already existing) reaction
+    pub async fn add_reaction(
+        &self,
+        repository_id: &RepositoryId,
+        target: ReactionTarget,
+        kind: ReactionKind,
+    ) -> Result<Reaction> {
+        self.github_client
+            .add_reaction(repository_id, target, kind)
+            .await
+    }
+
+    /// List reactions on an issue, pull request, or comment
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `target` - The issue, pull request, or comment to list reactions for
+    /// * `kind` - Optional reaction kind to filter by
+    ///
+    /// # Returns
+    /// All reactions on the target, optionally filtered by kind
+    pub async fn list_reactions(
+        &self,
+        repository_id: &RepositoryId,
+        target: ReactionTarget,
+        kind: Option<ReactionKind>,
+    ) -> Result<Vec<Reaction>> {
+        self.github_client
+            .list_reactions(repository_id, target, kind)
+            .await
+    }
+
+    /// Remove the authenticated user's reaction from an issue, pull request, or comment
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `target` - The issue, pull request, or comment to remove the reaction from
+    /// * `kind` - The reaction kind to remove
+    ///
+    /// # Returns
+    /// `true` if a reaction was removed, `false` if there was nothing to remove
+    pub async fn remove_reaction(
+        &self,
+        repository_id: &RepositoryId,
+        target: ReactionTarget,
+        kind: ReactionKind,
+    ) -> Result<bool> {
+        self.github_client
+            .remove_reaction(repository_id, target, kind)
+            .await
+    }
+}
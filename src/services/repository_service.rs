@@ -1,8 +1,16 @@
 use crate::github::GitHubClient;
+use crate::types::branch::RepositoryBranch;
+use crate::types::commit::{Commit, CommitFilter};
+use crate::types::content::{FileCommit, FileContent};
+use crate::types::fork::{ForkSyncResult, RepositoryFork};
 use crate::types::label::Label;
 use crate::types::milestone::{Milestone, MilestoneState};
-use crate::types::repository::{MilestoneNumber, RepositoryId};
+use crate::types::pagination::{Page, Pagination};
+use crate::types::release::{GeneratedReleaseNotes, Release, ReleaseId, ReleaseUpdate};
+use crate::types::repository::{MilestoneNumber, Repository, RepositoryId, RepositorySettings};
+use crate::types::transfer::{RepositoryTransfer, RepositoryTransferStatus};
 use anyhow::Result;
+use std::time::Duration;
 
 /// Service layer for repository operations
 ///
@@ -19,6 +27,17 @@ impl RepositoryService {
         Self { github_client }
     }
 
+    /// Get an overview of a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    ///
+    /// # Returns
+    /// The repository overview
+    pub async fn get_repository(&self, repository_id: &RepositoryId) -> Result<Repository> {
+        self.github_client.get_repository(repository_id).await
+    }
+
     /// Create a new label
     ///
     /// Creates a new label in the specified repository with the provided
@@ -86,6 +105,24 @@ impl RepositoryService {
             .await
     }
 
+    /// List the labels of a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `pagination` - The page of labels to fetch
+    ///
+    /// # Returns
+    /// A page of labels
+    pub async fn list_labels(
+        &self,
+        repository_id: &RepositoryId,
+        pagination: Pagination,
+    ) -> Result<Page<Label>> {
+        self.github_client
+            .list_labels(repository_id, pagination)
+            .await
+    }
+
     /// Create a new milestone
     ///
     /// Creates a new milestone in the specified repository with the provided
@@ -167,4 +204,431 @@ impl RepositoryService {
             .delete_milestone(repository_id, milestone_number)
             .await
     }
+
+    /// List the branches of a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `pagination` - The page of branches to fetch
+    ///
+    /// # Returns
+    /// A page of branches with the commit SHA each branch points to
+    pub async fn list_branches(
+        &self,
+        repository_id: &RepositoryId,
+        pagination: Pagination,
+    ) -> Result<Page<RepositoryBranch>> {
+        self.github_client
+            .list_branches(repository_id, pagination)
+            .await
+    }
+
+    /// Create a branch
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `name` - The name of the new branch
+    /// * `from_ref` - The branch, tag or commit SHA to branch from
+    ///
+    /// # Returns
+    /// The created branch
+    pub async fn create_branch(
+        &self,
+        repository_id: &RepositoryId,
+        name: &str,
+        from_ref: &str,
+    ) -> Result<RepositoryBranch> {
+        self.github_client
+            .create_branch(repository_id, name, from_ref)
+            .await
+    }
+
+    /// Delete a branch
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `name` - The name of the branch to delete
+    pub async fn delete_branch(&self, repository_id: &RepositoryId, name: &str) -> Result<()> {
+        self.github_client.delete_branch(repository_id, name).await
+    }
+
+    /// Rename a branch
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `name` - The current branch name
+    /// * `new_name` - The new branch name
+    ///
+    /// # Returns
+    /// The renamed branch
+    pub async fn rename_branch(
+        &self,
+        repository_id: &RepositoryId,
+        name: &str,
+        new_name: &str,
+    ) -> Result<RepositoryBranch> {
+        self.github_client
+            .rename_branch(repository_id, name, new_name)
+            .await
+    }
+
+    /// Get a single commit with its stats and changed files
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `git_ref` - Commit SHA, branch or tag to resolve to a commit
+    ///
+    /// # Returns
+    /// The commit
+    pub async fn get_commit(&self, repository_id: &RepositoryId, git_ref: &str) -> Result<Commit> {
+        self.github_client.get_commit(repository_id, git_ref).await
+    }
+
+    /// List the commits of a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `filter` - Starting ref, path and time range filter
+    /// * `pagination` - The page of commits to fetch
+    ///
+    /// # Returns
+    /// A page of commits, newest first
+    pub async fn list_commits(
+        &self,
+        repository_id: &RepositoryId,
+        filter: &CommitFilter,
+        pagination: Pagination,
+    ) -> Result<Page<Commit>> {
+        self.github_client
+            .list_commits(repository_id, filter, pagination)
+            .await
+    }
+
+    /// Get the contents of a file
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `path` - The file path relative to the repository root
+    /// * `git_ref` - Optional branch, tag or commit SHA
+    ///
+    /// # Returns
+    /// The decoded file contents and blob SHA
+    pub async fn get_file_content(
+        &self,
+        repository_id: &RepositoryId,
+        path: &str,
+        git_ref: Option<&str>,
+    ) -> Result<FileContent> {
+        self.github_client
+            .get_file_content(repository_id, path, git_ref)
+            .await
+    }
+
+    /// Create a file or update an existing one
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `path` - The file path relative to the repository root
+    /// * `content` - The new file contents
+    /// * `message` - The commit message
+    /// * `branch` - Optional branch to commit to
+    /// * `sha` - Blob SHA of the file being replaced; required when updating
+    ///
+    /// # Returns
+    /// The commit that wrote the file
+    pub async fn create_or_update_file(
+        &self,
+        repository_id: &RepositoryId,
+        path: &str,
+        content: &[u8],
+        message: &str,
+        branch: Option<&str>,
+        sha: Option<&str>,
+    ) -> Result<FileCommit> {
+        self.github_client
+            .create_or_update_file(repository_id, path, content, message, branch, sha)
+            .await
+    }
+
+    /// Delete a file
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `path` - The file path relative to the repository root
+    /// * `message` - The commit message
+    /// * `sha` - Blob SHA of the file being deleted
+    /// * `branch` - Optional branch to commit to
+    ///
+    /// # Returns
+    /// The commit that deleted the file
+    pub async fn delete_file(
+        &self,
+        repository_id: &RepositoryId,
+        path: &str,
+        message: &str,
+        sha: &str,
+        branch: Option<&str>,
+    ) -> Result<FileCommit> {
+        self.github_client
+            .delete_file(repository_id, path, message, sha, branch)
+            .await
+    }
+
+    /// Create a release
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `tag_name` - The tag of the release
+    /// * `name` - Optional release title
+    /// * `body` - Optional release notes in Markdown
+    /// * `draft` - Whether the release is created as an unpublished draft
+    /// * `prerelease` - Whether the release is marked as a prerelease
+    /// * `target_commitish` - Optional branch or commit SHA for a new tag
+    ///
+    /// # Returns
+    /// The created release
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_release(
+        &self,
+        repository_id: &RepositoryId,
+        tag_name: &str,
+        name: Option<&str>,
+        body: Option<&str>,
+        draft: bool,
+        prerelease: bool,
+        target_commitish: Option<&str>,
+    ) -> Result<Release> {
+        self.github_client
+            .create_release(
+                repository_id,
+                tag_name,
+                name,
+                body,
+                draft,
+                prerelease,
+                target_commitish,
+            )
+            .await
+    }
+
+    /// Get a published release by its tag
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `tag_name` - The tag of the release
+    ///
+    /// # Returns
+    /// The release for the tag
+    pub async fn get_release_by_tag(
+        &self,
+        repository_id: &RepositoryId,
+        tag_name: &str,
+    ) -> Result<Release> {
+        self.github_client
+            .get_release_by_tag(repository_id, tag_name)
+            .await
+    }
+
+    /// Update a release
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `release_id` - The release identifier
+    /// * `update` - The settings to change
+    ///
+    /// # Returns
+    /// The updated release
+    pub async fn update_release(
+        &self,
+        repository_id: &RepositoryId,
+        release_id: ReleaseId,
+        update: &ReleaseUpdate,
+    ) -> Result<Release> {
+        self.github_client
+            .update_release(repository_id, release_id, update)
+            .await
+    }
+
+    /// Delete a release
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `release_id` - The release identifier
+    pub async fn delete_release(
+        &self,
+        repository_id: &RepositoryId,
+        release_id: ReleaseId,
+    ) -> Result<()> {
+        self.github_client
+            .delete_release(repository_id, release_id)
+            .await
+    }
+
+    /// Generate release notes from merged pull requests
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `tag_name` - The tag of the release the notes are for
+    /// * `previous_tag_name` - Optional tag to start from
+    /// * `target_commitish` - Optional branch or commit SHA if the tag does not exist yet
+    ///
+    /// # Returns
+    /// The generated release title and body
+    pub async fn generate_release_notes(
+        &self,
+        repository_id: &RepositoryId,
+        tag_name: &str,
+        previous_tag_name: Option<&str>,
+        target_commitish: Option<&str>,
+    ) -> Result<GeneratedReleaseNotes> {
+        self.github_client
+            .generate_release_notes(repository_id, tag_name, previous_tag_name, target_commitish)
+            .await
+    }
+
+    /// Fork a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository to fork
+    /// * `organization` - Optional organization to create the fork in
+    /// * `default_branch_only` - Whether to copy only the default branch
+    ///
+    /// # Returns
+    /// The created fork
+    pub async fn fork_repository(
+        &self,
+        repository_id: &RepositoryId,
+        organization: Option<&str>,
+        default_branch_only: bool,
+    ) -> Result<RepositoryFork> {
+        self.github_client
+            .fork_repository(repository_id, organization, default_branch_only)
+            .await
+    }
+
+    /// Sync a branch of a fork with its upstream repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The fork to update
+    /// * `branch` - Optional branch to sync (defaults to the default branch)
+    ///
+    /// # Returns
+    /// How the branch was updated
+    pub async fn sync_fork_with_upstream(
+        &self,
+        repository_id: &RepositoryId,
+        branch: Option<&str>,
+    ) -> Result<ForkSyncResult> {
+        self.github_client
+            .sync_fork_with_upstream(repository_id, branch)
+            .await
+    }
+
+    /// Update the settings of a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `settings` - The settings to change
+    ///
+    /// # Returns
+    /// The resulting settings of the repository
+    pub async fn update_repository_settings(
+        &self,
+        repository_id: &RepositoryId,
+        settings: &RepositorySettings,
+    ) -> Result<RepositorySettings> {
+        self.github_client
+            .update_repository_settings(repository_id, settings)
+            .await
+    }
+
+    /// Archive a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    pub async fn archive_repository(&self, repository_id: &RepositoryId) -> Result<()> {
+        self.github_client.archive_repository(repository_id).await
+    }
+
+    /// Unarchive a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    pub async fn unarchive_repository(&self, repository_id: &RepositoryId) -> Result<()> {
+        self.github_client.unarchive_repository(repository_id).await
+    }
+
+    /// Transfer a repository to another user or organization
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository to transfer
+    /// * `new_owner` - The user or organization receiving the repository
+    /// * `new_name` - Optional new name of the repository
+    /// * `team_ids` - Teams of the new organization to grant access
+    ///
+    /// # Returns
+    /// The pending transfer
+    pub async fn transfer_repository(
+        &self,
+        repository_id: &RepositoryId,
+        new_owner: &str,
+        new_name: Option<&str>,
+        team_ids: &[u64],
+    ) -> Result<RepositoryTransfer> {
+        self.github_client
+            .transfer_repository(repository_id, new_owner, new_name, team_ids)
+            .await
+    }
+
+    /// Get the status of a repository transfer
+    ///
+    /// # Arguments
+    /// * `target_repository_id` - The repository under its new owner
+    ///
+    /// # Returns
+    /// Whether the transfer has completed
+    pub async fn get_repository_transfer_status(
+        &self,
+        target_repository_id: &RepositoryId,
+    ) -> Result<RepositoryTransferStatus> {
+        self.github_client
+            .get_repository_transfer_status(target_repository_id)
+            .await
+    }
+
+    /// Wait until a repository transfer completes or the timeout elapses
+    ///
+    /// # Arguments
+    /// * `target_repository_id` - The repository under its new owner
+    /// * `timeout` - How long to wait
+    ///
+    /// # Returns
+    /// The last observed transfer status
+    pub async fn wait_for_repository_transfer(
+        &self,
+        target_repository_id: &RepositoryId,
+        timeout: Duration,
+    ) -> Result<RepositoryTransferStatus> {
+        self.github_client
+            .wait_for_repository_transfer(target_repository_id, timeout)
+            .await
+    }
+
+    /// List the milestones of a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `state` - Optional state filter (`None` lists all milestones)
+    ///
+    /// # Returns
+    /// All matching milestones
+    pub async fn list_milestones(
+        &self,
+        repository_id: &RepositoryId,
+        state: Option<MilestoneState>,
+    ) -> Result<Vec<Milestone>> {
+        self.github_client
+            .list_milestones(repository_id, state)
+            .await
+    }
 }
@@ -0,0 +1,79 @@
+use crate::github::GitHubClient;
+use crate::types::pagination::Pagination;
+use crate::types::search::{
+    IssueSearchResult, IssueSearchSort, RepositorySearchResult, RepositorySearchSort, SearchOrder,
+    SearchResults, UserSearchResult, UserSearchSort,
+};
+use anyhow::Result;
+
+/// Service layer for search operations
+///
+/// This service provides a high-level interface for locating issues, pull
+/// requests, repositories and users with GitHub's search query syntax.
+pub struct SearchService {
+    github_client: GitHubClient,
+}
+
+impl SearchService {
+    /// Create a new search service instance
+    pub fn new(github_client: GitHubClient) -> Self {
+        Self { github_client }
+    }
+
+    /// Search issues and pull requests
+    ///
+    /// # Arguments
+    /// * `query` - Search query using GitHub's search syntax
+    /// * `sort` - Optional field to sort by
+    /// * `order` - Optional sort order
+    /// * `pagination` - The page of results to fetch
+    pub async fn search_issues_and_prs(
+        &self,
+        query: &str,
+        sort: Option<IssueSearchSort>,
+        order: Option<SearchOrder>,
+        pagination: Pagination,
+    ) -> Result<SearchResults<IssueSearchResult>> {
+        self.github_client
+            .search_issues_and_prs(query, sort, order, pagination)
+            .await
+    }
+
+    /// Search repositories
+    ///
+    /// # Arguments
+    /// * `query` - Search query using GitHub's search syntax
+    /// * `sort` - Optional field to sort by
+    /// * `order` - Optional sort order
+    /// * `pagination` - The page of results to fetch
+    pub async fn search_repositories(
+        &self,
+        query: &str,
+        sort: Option<RepositorySearchSort>,
+        order: Option<SearchOrder>,
+        pagination: Pagination,
+    ) -> Result<SearchResults<RepositorySearchResult>> {
+        self.github_client
+            .search_repositories(query, sort, order, pagination)
+            .await
+    }
+
+    /// Search users and organizations
+    ///
+    /// # Arguments
+    /// * `query` - Search query using GitHub's search syntax
+    /// * `sort` - Optional field to sort by
+    /// * `order` - Optional sort order
+    /// * `pagination` - The page of results to fetch
+    pub async fn search_users(
+        &self,
+        query: &str,
+        sort: Option<UserSearchSort>,
+        order: Option<SearchOrder>,
+        pagination: Pagination,
+    ) -> Result<SearchResults<UserSearchResult>> {
+        self.github_client
+            .search_users(query, sort, order, pagination)
+            .await
+    }
+}
@@ -0,0 +1,136 @@
+use crate::github::GitHubClient;
+use crate::types::pagination::{Page, Pagination};
+use crate::types::repository::RepositoryId;
+use crate::types::secret::{ActionsSecret, ActionsVariable};
+use anyhow::Result;
+
+/// Service layer for GitHub Actions secrets and variables
+///
+/// This service provides a high-level interface for rotating repository
+/// secrets and managing configuration variables.
+pub struct SecretService {
+    github_client: GitHubClient,
+}
+
+impl SecretService {
+    /// Create a new secret service instance
+    pub fn new(github_client: GitHubClient) -> Self {
+        Self { github_client }
+    }
+
+    /// Create or update a repository secret
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `name` - The secret name
+    /// * `value` - The plain-text secret value, encrypted before it is sent
+    pub async fn set_repo_secret(
+        &self,
+        repository_id: &RepositoryId,
+        name: &str,
+        value: &str,
+    ) -> Result<()> {
+        self.github_client
+            .set_repo_secret(repository_id, name, value)
+            .await
+    }
+
+    /// Delete a repository secret
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `name` - The secret name
+    pub async fn delete_repo_secret(&self, repository_id: &RepositoryId, name: &str) -> Result<()> {
+        self.github_client
+            .delete_repo_secret(repository_id, name)
+            .await
+    }
+
+    /// List the secrets of a repository without their values
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `pagination` - The page of secrets to fetch
+    pub async fn list_secrets(
+        &self,
+        repository_id: &RepositoryId,
+        pagination: Pagination,
+    ) -> Result<Page<ActionsSecret>> {
+        self.github_client
+            .list_secrets(repository_id, pagination)
+            .await
+    }
+
+    /// List the configuration variables of a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `pagination` - The page of variables to fetch
+    pub async fn list_variables(
+        &self,
+        repository_id: &RepositoryId,
+        pagination: Pagination,
+    ) -> Result<Page<ActionsVariable>> {
+        self.github_client
+            .list_variables(repository_id, pagination)
+            .await
+    }
+
+    /// Get a configuration variable of a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `name` - The variable name
+    pub async fn get_variable(
+        &self,
+        repository_id: &RepositoryId,
+        name: &str,
+    ) -> Result<ActionsVariable> {
+        self.github_client.get_variable(repository_id, name).await
+    }
+
+    /// Create a configuration variable in a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `name` - The variable name
+    /// * `value` - The variable value
+    pub async fn create_variable(
+        &self,
+        repository_id: &RepositoryId,
+        name: &str,
+        value: &str,
+    ) -> Result<()> {
+        self.github_client
+            .create_variable(repository_id, name, value)
+            .await
+    }
+
+    /// Update the value of a configuration variable in a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `name` - The variable name
+    /// * `value` - The new variable value
+    pub async fn update_variable(
+        &self,
+        repository_id: &RepositoryId,
+        name: &str,
+        value: &str,
+    ) -> Result<()> {
+        self.github_client
+            .update_variable(repository_id, name, value)
+            .await
+    }
+
+    /// Delete a configuration variable from a repository
+    ///
+    /// # Arguments
+    /// * `repository_id` - The repository identifier
+    /// * `name` - The variable name
+    pub async fn delete_variable(&self, repository_id: &RepositoryId, name: &str) -> Result<()> {
+        self.github_client
+            .delete_variable(repository_id, name)
+            .await
+    }
+}
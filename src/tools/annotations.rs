@@ -0,0 +1,253 @@
+//! Behavior annotations of the MCP tools
+//!
+//! Every tool is classified here by its effect on GitHub, and the
+//! `readOnlyHint`, `destructiveHint`, `idempotentHint` and `openWorldHint`
+//! annotations of the MCP specification are derived from that effect, so
+//! clients can apply their own confirmation policies. The registry is also
+//! the source of the dry run classification: only tools that are not read
+//! only accept `dry_run`.
+//!
+//! Tool annotations were added to the protocol after the 2024-11-05 version
+//! this server speaks, and the `Tool` of rmcp 0.1 has no field for them, so
+//! `tools/list` cannot carry them yet. `tool_annotations` returns them in the
+//! shape of the specification for when it can.
+
+use serde::{Deserialize, Serialize};
+
+/// Effect of a tool on GitHub
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolEffect {
+    /// Reads without changing anything
+    ReadOnly,
+    /// Creates something new on every call, such as an issue or comment
+    Create,
+    /// Sets state that a repeated call leaves unchanged, such as a title,
+    /// labels or a field value; what it changes can be restored
+    Update,
+    /// Deletes or irrecoverably overwrites data, such as an issue or the
+    /// value of a secret
+    Destructive,
+    /// Runs other tools whose effects are not known in advance
+    Composite,
+}
+
+/// Behavior hints of a tool, as defined by the MCP specification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolAnnotations {
+    /// The tool does not modify its environment
+    pub read_only_hint: bool,
+    /// The tool may delete or overwrite data; only meaningful if not read only
+    pub destructive_hint: bool,
+    /// Repeated calls with the same arguments have no additional effect;
+    /// only meaningful if not read only
+    pub idempotent_hint: bool,
+    /// The tool interacts with an open world of external entities, here GitHub
+    pub open_world_hint: bool,
+}
+
+impl ToolEffect {
+    /// Returns the annotations of a tool with this effect
+    pub fn annotations(self) -> ToolAnnotations {
+        let (read_only_hint, destructive_hint, idempotent_hint) = match self {
+            Self::ReadOnly => (true, false, true),
+            Self::Create => (false, false, false),
+            Self::Update => (false, false, true),
+            Self::Destructive => (false, true, true),
+            Self::Composite => (false, true, false),
+        };
+        ToolAnnotations {
+            read_only_hint,
+            destructive_hint,
+            idempotent_hint,
+            open_world_hint: true,
+        }
+    }
+}
+
+/// Returns the effect of a tool, or `None` for unknown tools
+pub fn tool_effect(name: &str) -> Option<ToolEffect> {
+    let effect = match name {
+        // Projects
+        "get_project"
+        | "list_project_fields"
+        | "get_project_node_id"
+        | "find_project_item_for_content"
+        | "resolve_single_select_option" => ToolEffect::ReadOnly,
+        "create_project"
+        | "copy_project"
+        | "create_project_field"
+        | "add_draft_issue_to_project" => ToolEffect::Create,
+        "update_project"
+        | "update_project_field"
+        | "update_project_item_field"
+        | "update_project_item_text_field"
+        | "update_project_item_number_field"
+        | "update_project_item_date_field"
+        | "update_project_item_single_select_field"
+        | "bulk_update_project_field"
+        | "update_project_item_position"
+        | "archive_project_item"
+        | "unarchive_project_item"
+        | "update_draft_issue"
+        | "convert_draft_issue_to_issue"
+        | "add_issue_to_project"
+        | "add_pull_request_to_project"
+        | "link_repository_to_project"
+        | "unlink_repository_from_project"
+        | "link_team_to_project"
+        | "unlink_team_from_project" => ToolEffect::Update,
+        "delete_project" | "delete_project_field" | "remove_item_from_project" => {
+            ToolEffect::Destructive
+        }
+
+        // Pull requests
+        "get_pull_request"
+        | "get_pull_requests"
+        | "get_pull_request_merge_status"
+        | "list_pull_request_comments" => ToolEffect::ReadOnly,
+        "create_pull_request" | "add_comment_to_pull_request" => ToolEffect::Create,
+        "edit_comment_on_pull_request"
+        | "close_pull_request"
+        | "edit_pull_request_title"
+        | "edit_pull_request_body"
+        | "add_assignees_to_pull_request"
+        | "remove_assignees_from_pull_request"
+        | "add_requested_reviewers_to_pull_request"
+        | "add_labels_to_pull_request"
+        | "remove_labels_from_pull_request"
+        | "add_milestone_to_pull_request"
+        | "remove_milestone_from_pull_request" => ToolEffect::Update,
+
+        // Comments and reactions
+        "can_edit_comment" | "list_reactions" => ToolEffect::ReadOnly,
+        "minimize_comment" | "unminimize_comment" | "add_reaction" | "remove_reaction" => {
+            ToolEffect::Update
+        }
+
+        // Deletions guarded by confirmation tokens
+        "delete_issue" | "delete_label" | "delete_milestone" | "delete_comment" => {
+            ToolEffect::Destructive
+        }
+
+        // Issues
+        "get_issue"
+        | "get_issues"
+        | "list_issues"
+        | "list_issue_comments"
+        | "get_issue_timeline"
+        | "find_cross_references"
+        | "list_issue_types" => ToolEffect::ReadOnly,
+        "create_issue" | "add_comment_to_issue" | "mark_issue_duplicate" | "link_issues" => {
+            ToolEffect::Create
+        }
+        "edit_comment_on_issue"
+        | "edit_issue_title"
+        | "edit_issue_body"
+        | "update_issue_state"
+        | "add_assignees_to_issue"
+        | "remove_assignees_from_issue"
+        | "add_labels_to_issue"
+        | "remove_labels_from_issue"
+        | "add_milestone_to_issue"
+        | "remove_milestone_from_issue"
+        | "set_issue_type"
+        | "bulk_update_issues" => ToolEffect::Update,
+
+        // Repositories
+        "get_repository"
+        | "list_milestones"
+        | "list_labels"
+        | "list_branches"
+        | "generate_release_notes"
+        | "get_commit"
+        | "list_commits"
+        | "get_file_content"
+        | "get_repository_transfer_status" => ToolEffect::ReadOnly,
+        "create_milestone" | "create_label" | "create_branch" | "create_release" => {
+            ToolEffect::Create
+        }
+        "update_label"
+        | "rename_branch"
+        | "update_release"
+        | "publish_release"
+        | "create_or_update_file"
+        | "fork_repository"
+        | "sync_fork_with_upstream"
+        | "update_repository_settings"
+        | "archive_repository"
+        | "unarchive_repository"
+        | "copy_repository_metadata" => ToolEffect::Update,
+        "delete_release" | "delete_file" | "transfer_repository" => ToolEffect::Destructive,
+
+        // GitHub Actions and checks
+        "list_workflow_runs"
+        | "list_jobs_for_run"
+        | "list_pending_deployments"
+        | "get_workflow_run_logs"
+        | "list_artifacts"
+        | "download_artifact"
+        | "list_check_runs_for_ref"
+        | "get_combined_status" => ToolEffect::ReadOnly,
+        "dispatch_workflow"
+        | "rerun_workflow_run"
+        | "rerun_failed_jobs"
+        | "create_commit_status" => ToolEffect::Create,
+        "cancel_workflow_run"
+        | "approve_workflow_run"
+        | "approve_pending_runs_for_pr"
+        | "review_pending_deployments" => ToolEffect::Update,
+
+        // Secrets and variables
+        "list_secrets" | "list_variables" | "get_variable" => ToolEffect::ReadOnly,
+        "set_variable" => ToolEffect::Update,
+        "set_repo_secret" | "delete_repo_secret" | "delete_variable" => ToolEffect::Destructive,
+
+        // Discussions
+        "list_discussion_categories" | "get_discussion" => ToolEffect::ReadOnly,
+        "create_discussion" | "add_discussion_comment" => ToolEffect::Create,
+        "mark_comment_as_answer"
+        | "lock_discussion"
+        | "unlock_discussion"
+        | "update_discussion" => ToolEffect::Update,
+        "delete_discussion_comment" => ToolEffect::Destructive,
+
+        // Notifications and subscriptions
+        "list_notifications" | "get_issue_subscription" => ToolEffect::ReadOnly,
+        "mark_notification_read"
+        | "mark_thread_done"
+        | "subscribe_thread"
+        | "unsubscribe_thread"
+        | "subscribe_to_issue"
+        | "unsubscribe_from_issue" => ToolEffect::Update,
+
+        // Search and authentication
+        "list_search_aliases"
+        | "search_issues_and_prs"
+        | "search_repositories"
+        | "search_users"
+        | "auth_status"
+        | "get_authenticated_user"
+        | "get_rate_limit" => ToolEffect::ReadOnly,
+
+        // Tools of the server itself
+        "get_response_continuation" | "set_default_repository" => ToolEffect::ReadOnly,
+        "execute_batch" | "undo_last_operation" => ToolEffect::Composite,
+
+        _ => return None,
+    };
+    Some(effect)
+}
+
+/// Returns the annotations of a tool, or `None` for unknown tools
+pub fn tool_annotations(name: &str) -> Option<ToolAnnotations> {
+    let mut annotations = tool_effect(name)?.annotations();
+    // Tools of the server itself do not reach GitHub; setting the session
+    // default repository changes no GitHub data, so it counts as read only
+    if name == crate::tools::truncation::GET_RESPONSE_CONTINUATION_TOOL
+        || name == crate::tools::session::SET_DEFAULT_REPOSITORY_TOOL
+    {
+        annotations.open_world_hint = false;
+    }
+    Some(annotations)
+}
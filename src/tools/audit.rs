@@ -0,0 +1,234 @@
+//! Audit log of the MCP tool calls
+//!
+//! Tool calls that send write requests to GitHub are appended to the audit
+//! log with the login of the token's account, the name of the MCP client,
+//! the redacted arguments, the outcome, the trace ID of the call, the write
+//! requests sent and the call reversing it, if any. Failing to write the log
+//! is logged, but does not fail the call.
+
+use crate::audit::{AuditLog, AuditQuery, redact_parameters};
+use crate::github::GitHubClient;
+use crate::github::{audit, trace};
+use crate::tools::dry_run::DRY_RUN_ARGUMENT;
+use crate::tools::tool_definition::success_result;
+use crate::tools::undo::{self, UNDO_LAST_OPERATION_TOOL};
+use crate::types::audit::{AuditEntry, AuditRequest, AuditSource, UndoOperation, UndoRequest};
+
+use rmcp::{Error as McpError, model::*};
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+
+/// Writes the tool calls of the server to the audit log
+#[derive(Clone)]
+pub struct ToolCallAuditor {
+    log: AuditLog,
+    /// Login of the token's account, read once when first needed
+    actor: Arc<OnceCell<Option<String>>>,
+}
+
+impl ToolCallAuditor {
+    /// Create an auditor appending to `log`
+    pub fn new(log: AuditLog) -> Self {
+        Self {
+            log,
+            actor: Arc::default(),
+        }
+    }
+
+    /// Run the call of `tool`, appending it to the audit log if it sent a
+    /// write request
+    ///
+    /// `undo`, the call reversing this one prepared by `undo::prepare_undo`,
+    /// is recorded only if the call succeeds.
+    pub async fn audit_call<F>(
+        &self,
+        github_client: &GitHubClient,
+        tool: &str,
+        arguments: Option<&JsonObject>,
+        client_name: Option<String>,
+        undo: Option<UndoOperation>,
+        call: F,
+    ) -> Result<CallToolResult, McpError>
+    where
+        F: Future<Output = Result<CallToolResult, McpError>>,
+    {
+        let (result, requests) = audit::with_audit(call).await;
+        if requests.is_empty() {
+            return result;
+        }
+
+        let parameters = serde_json::Value::Object(arguments.cloned().unwrap_or_default());
+        let mut entry = self
+            .entry(
+                github_client,
+                tool,
+                parameters,
+                client_name,
+                &result,
+                requests,
+            )
+            .await;
+        if entry.success {
+            entry.undo = undo;
+        }
+        self.append(&entry);
+        result
+    }
+
+    /// Handle an `undo_last_operation` call
+    ///
+    /// Finds the operation to undo in the audit log and passes its reversing
+    /// call to `dispatch`. The undo is recorded as an operation of its own,
+    /// naming the trace ID it reversed, so the operation is not undone twice.
+    /// An operation without a reversing call is reported as an error result
+    /// with the reason. A dry run passes `dry_run` to the reversing call and
+    /// records nothing.
+    pub async fn undo_last_operation<F, Fut>(
+        &self,
+        github_client: &GitHubClient,
+        arguments: Option<JsonObject>,
+        client_name: Option<String>,
+        is_dry_run: bool,
+        dispatch: F,
+    ) -> Result<CallToolResult, McpError>
+    where
+        F: FnOnce(CallToolRequestParam) -> Fut,
+        Fut: Future<Output = Result<CallToolResult, McpError>>,
+    {
+        let request: UndoRequest =
+            serde_json::from_value(serde_json::Value::Object(arguments.unwrap_or_default()))
+                .map_err(|e| {
+                    McpError::invalid_params(
+                        format!("Invalid {} arguments: {}", UNDO_LAST_OPERATION_TOOL, e),
+                        None,
+                    )
+                })?;
+        let query = AuditQuery {
+            actor: self.actor(github_client).await,
+            ..AuditQuery::default()
+        };
+        let entries = self.log.read(&query).map_err(|e| {
+            McpError::internal_error(format!("Failed to read the audit log: {:#}", e), None)
+        })?;
+        let operation = undo::find_operation(&entries, request.trace_id.as_deref())
+            .map_err(|e| McpError::invalid_params(e, None))?;
+        let trace_id = operation.trace_id.clone().unwrap_or_default();
+
+        let Some(inverse) = operation.undo.clone() else {
+            return Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Cannot undo {} (trace ID {}): {}",
+                    operation.tool,
+                    trace_id,
+                    undo::irreversible_reason(operation)
+                ))],
+                is_error: Some(true),
+            });
+        };
+        let undone_tool = operation.tool.clone();
+        if is_dry_run {
+            let mut arguments = inverse.arguments.as_object().cloned().unwrap_or_default();
+            arguments.insert(DRY_RUN_ARGUMENT.to_string(), serde_json::Value::Bool(true));
+            let mut result = dispatch(CallToolRequestParam {
+                name: inverse.tool.clone().into(),
+                arguments: Some(arguments),
+            })
+            .await?;
+            result.content.insert(
+                0,
+                Content::text(format!(
+                    "Would undo {} (trace ID {}) with {}",
+                    undone_tool, trace_id, inverse.tool
+                )),
+            );
+            return Ok(result);
+        }
+        let (result, requests) = audit::with_audit(dispatch(CallToolRequestParam {
+            name: inverse.tool.clone().into(),
+            arguments: inverse.arguments.as_object().cloned(),
+        }))
+        .await;
+
+        let parameters = serde_json::json!({ "trace_id": trace_id });
+        let mut entry = self
+            .entry(
+                github_client,
+                UNDO_LAST_OPERATION_TOOL,
+                parameters,
+                client_name,
+                &result,
+                requests,
+            )
+            .await;
+        entry.undoes = Some(trace_id.clone());
+        self.append(&entry);
+
+        let result = result?;
+        if result.is_error == Some(true) {
+            return Ok(result);
+        }
+        Ok(success_result(
+            format!(
+                "Undid {} (trace ID {}) with {}",
+                undone_tool, trace_id, inverse.tool
+            ),
+            &serde_json::json!({
+                "undone_tool": undone_tool,
+                "undone_trace_id": trace_id,
+                "tool": inverse.tool,
+                "arguments": inverse.arguments,
+            }),
+        ))
+    }
+
+    async fn entry(
+        &self,
+        github_client: &GitHubClient,
+        tool: &str,
+        parameters: serde_json::Value,
+        client_name: Option<String>,
+        result: &Result<CallToolResult, McpError>,
+        requests: Vec<AuditRequest>,
+    ) -> AuditEntry {
+        let error = match result {
+            Ok(result) if result.is_error == Some(true) => Some(
+                result
+                    .content
+                    .iter()
+                    .find_map(|content| content.as_text())
+                    .map(|text| text.text.clone())
+                    .unwrap_or_default(),
+            ),
+            Ok(_) => None,
+            Err(e) => Some(e.message.to_string()),
+        };
+        AuditEntry {
+            timestamp: chrono::Utc::now(),
+            source: AuditSource::Mcp,
+            actor: self.actor(github_client).await,
+            client: client_name,
+            tool: tool.to_string(),
+            parameters: redact_parameters(tool, parameters),
+            success: error.is_none(),
+            error,
+            trace_id: trace::current_trace_id(),
+            requests,
+            undo: None,
+            undoes: None,
+        }
+    }
+
+    fn append(&self, entry: &AuditEntry) {
+        if let Err(e) = self.log.append(entry) {
+            tracing::warn!("Failed to record {} in the audit log: {:#}", entry.tool, e);
+        }
+    }
+
+    async fn actor(&self, github_client: &GitHubClient) -> Option<String> {
+        self.actor
+            .get_or_init(|| crate::audit::authenticated_login(github_client))
+            .await
+            .clone()
+    }
+}
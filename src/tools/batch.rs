@@ -0,0 +1,222 @@
+//! `execute_batch` MCP tool
+//!
+//! Runs an ordered list of tool calls in one round trip. Each operation is
+//! dispatched like a call of its own, so it honors `dry_run` and the
+//! confirmation tokens of the deletion tools. By default the batch stops at
+//! the first failed operation and skips the rest; with `continue_on_error`
+//! every operation runs. The result reports the outcome of each operation.
+
+use crate::github::cancellation;
+use crate::tools::dry_run::{DRY_RUN_ARGUMENT, is_read_only_tool};
+use crate::tools::tool_definition::success_result;
+use crate::types::batch::{
+    BatchOperation, BatchOperationResult, BatchOperationStatus, BatchReport, BatchRequest,
+};
+
+use rmcp::handler::server::tool::cached_schema_for_type;
+use rmcp::{Error as McpError, model::*};
+use std::future::Future;
+
+/// Name of the batch tool
+pub const EXECUTE_BATCH_TOOL: &str = "execute_batch";
+
+/// Maximum number of operations of one batch
+pub const MAX_BATCH_OPERATIONS: usize = 50;
+
+/// Returns the batch tool as listed to clients
+pub fn execute_batch_tool() -> Tool {
+    Tool::new(
+        EXECUTE_BATCH_TOOL,
+        format!(
+            "Run up to {} tool calls in order in one request, e.g. several edits of an issue. Each operation names a tool and its params. The batch stops at the first failed operation and skips the rest unless continue_on_error is true. Returns the outcome of every operation",
+            MAX_BATCH_OPERATIONS
+        ),
+        cached_schema_for_type::<BatchRequest>(),
+    )
+}
+
+/// Parse the arguments of a batch call
+///
+/// # Errors
+/// Returns an error if the arguments do not match `BatchRequest`, or the
+/// batch is empty or has more than `MAX_BATCH_OPERATIONS` operations
+pub fn parse_batch_request(arguments: Option<JsonObject>) -> Result<BatchRequest, McpError> {
+    let arguments = serde_json::Value::Object(arguments.unwrap_or_default());
+    let request: BatchRequest = serde_json::from_value(arguments).map_err(|e| {
+        McpError::invalid_params(
+            format!("Invalid {} arguments: {}", EXECUTE_BATCH_TOOL, e),
+            None,
+        )
+    })?;
+    if request.operations.is_empty() {
+        return Err(McpError::invalid_params(
+            "The batch has no operations".to_string(),
+            None,
+        ));
+    }
+    if request.operations.len() > MAX_BATCH_OPERATIONS {
+        return Err(McpError::invalid_params(
+            format!(
+                "The batch has {} operations; at most {} are allowed",
+                request.operations.len(),
+                MAX_BATCH_OPERATIONS
+            ),
+            None,
+        ));
+    }
+    Ok(request)
+}
+
+/// Run the operations of a batch and report their outcome
+///
+/// # Arguments
+/// * `request` - The operations and error handling of the batch
+/// * `dry_run` - Run every mutating operation as a dry run
+/// * `call` - Dispatches one tool call
+///
+/// # Returns
+/// The consolidated report, flagged as an error if an operation failed
+pub async fn execute_batch<F, Fut>(request: BatchRequest, dry_run: bool, call: F) -> CallToolResult
+where
+    F: Fn(CallToolRequestParam) -> Fut,
+    Fut: Future<Output = Result<CallToolResult, McpError>>,
+{
+    let mut results = Vec::with_capacity(request.operations.len());
+    let mut skip_reason: Option<String> = None;
+
+    for (index, operation) in request.operations.into_iter().enumerate() {
+        if skip_reason.is_none() && cancellation::is_cancelled() {
+            skip_reason = Some("Cancelled before the operation started".to_string());
+        }
+        if let Some(reason) = &skip_reason {
+            results.push(BatchOperationResult {
+                index,
+                tool: operation.tool,
+                status: BatchOperationStatus::Skipped,
+                message: Some(reason.clone()),
+                data: None,
+            });
+            continue;
+        }
+
+        let result = run_operation(index, operation, dry_run, &call).await;
+        if result.status == BatchOperationStatus::Failed && !request.continue_on_error {
+            skip_reason = Some(format!("Skipped after operation {} failed", index));
+        }
+        results.push(result);
+    }
+
+    let count = |status: BatchOperationStatus| {
+        results
+            .iter()
+            .filter(|result| result.status == status)
+            .count()
+    };
+    let report = BatchReport {
+        succeeded: count(BatchOperationStatus::Succeeded),
+        failed: count(BatchOperationStatus::Failed),
+        skipped: count(BatchOperationStatus::Skipped),
+        results,
+    };
+
+    let mut result = success_result(format_report(&report), &report);
+    if report.failed > 0 {
+        result.is_error = Some(true);
+    }
+    result
+}
+
+async fn run_operation<F, Fut>(
+    index: usize,
+    operation: BatchOperation,
+    dry_run: bool,
+    call: &F,
+) -> BatchOperationResult
+where
+    F: Fn(CallToolRequestParam) -> Fut,
+    Fut: Future<Output = Result<CallToolResult, McpError>>,
+{
+    let BatchOperation { tool, mut params } = operation;
+    if tool == EXECUTE_BATCH_TOOL {
+        return BatchOperationResult {
+            index,
+            tool,
+            status: BatchOperationStatus::Failed,
+            message: Some("Batches cannot be nested".to_string()),
+            data: None,
+        };
+    }
+    if dry_run && !is_read_only_tool(&tool) {
+        params.insert(DRY_RUN_ARGUMENT.to_string(), serde_json::Value::Bool(true));
+    }
+
+    let request = CallToolRequestParam {
+        name: tool.clone().into(),
+        arguments: Some(params),
+    };
+    match call(request).await {
+        Ok(result) if result.is_error == Some(true) => BatchOperationResult {
+            index,
+            tool,
+            status: BatchOperationStatus::Failed,
+            message: Some(texts(&result).join("\n")),
+            data: None,
+        },
+        Ok(result) => {
+            let texts = texts(&result);
+            BatchOperationResult {
+                index,
+                tool,
+                status: BatchOperationStatus::Succeeded,
+                data: texts
+                    .get(1)
+                    .and_then(|json| serde_json::from_str(json).ok()),
+                message: texts.into_iter().next(),
+            }
+        }
+        Err(e) => BatchOperationResult {
+            index,
+            tool,
+            status: BatchOperationStatus::Failed,
+            message: Some(e.message.to_string()),
+            data: None,
+        },
+    }
+}
+
+fn format_report(report: &BatchReport) -> String {
+    let mut text = format!(
+        "Batch of {} operation(s): {} succeeded, {} failed, {} skipped",
+        report.results.len(),
+        report.succeeded,
+        report.failed,
+        report.skipped
+    );
+    for result in &report.results {
+        let status = match result.status {
+            BatchOperationStatus::Succeeded => "succeeded",
+            BatchOperationStatus::Failed => "failed",
+            BatchOperationStatus::Skipped => "skipped",
+        };
+        text.push_str(&format!("\n{}. {}: {}", result.index, result.tool, status));
+        if let Some(line) = result
+            .message
+            .as_deref()
+            .and_then(|message| message.lines().next())
+        {
+            text.push_str(&format!(" - {}", line));
+        }
+    }
+    text
+}
+
+fn texts(result: &CallToolResult) -> Vec<String> {
+    result
+        .content
+        .iter()
+        .filter_map(|content| match &content.raw {
+            RawContent::Text(text) => Some(text.text.clone()),
+            _ => None,
+        })
+        .collect()
+}
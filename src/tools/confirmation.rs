@@ -0,0 +1,88 @@
+//! Confirmation tokens of the deletion tools
+//!
+//! The first call of a deletion tool issues a token bound to the exact
+//! operation; the second call must pass it back within
+//! `CONFIRMATION_TOKEN_TTL`. Tokens are single use and only accepted for the
+//! operation they were issued for, so an agent cannot reuse the token of one
+//! issue to delete another.
+
+use crate::github::dry_run::is_dry_run;
+use crate::types::confirmation::DestructiveOperation;
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// How long a confirmation token is accepted after it was issued
+pub const CONFIRMATION_TOKEN_TTL: chrono::Duration = chrono::Duration::minutes(5);
+
+/// Confirmation tokens issued to the client of one session
+#[derive(Clone, Default)]
+pub struct ConfirmationTokens {
+    pending: Arc<Mutex<HashMap<String, PendingConfirmation>>>,
+}
+
+struct PendingConfirmation {
+    operation: DestructiveOperation,
+    expires_at: DateTime<Utc>,
+}
+
+impl ConfirmationTokens {
+    /// Issue a token confirming `operation`
+    ///
+    /// # Returns
+    /// The token and when it expires
+    pub fn issue(&self, operation: DestructiveOperation) -> (String, DateTime<Utc>) {
+        let token = uuid::Uuid::new_v4().simple().to_string();
+        let expires_at = Utc::now() + CONFIRMATION_TOKEN_TTL;
+        let mut pending = self.lock();
+        pending.retain(|_, confirmation| confirmation.expires_at > Utc::now());
+        pending.insert(
+            token.clone(),
+            PendingConfirmation {
+                operation,
+                expires_at,
+            },
+        );
+        (token, expires_at)
+    }
+
+    /// Check a token passed to perform `operation` and consume it
+    ///
+    /// In a dry run the token is checked but kept, so it can still confirm
+    /// the real deletion.
+    ///
+    /// # Errors
+    /// Returns an error if the token is unknown, expired, already used, or
+    /// was issued for another operation
+    pub fn redeem(&self, token: &str, operation: &DestructiveOperation) -> Result<(), String> {
+        let mut pending = self.lock();
+        let Some(confirmation) = pending.get(token) else {
+            return Err(format!(
+                "Unknown or already used confirmation token. Call {} without confirmation_token to get a new one",
+                operation.tool_name()
+            ));
+        };
+        if confirmation.expires_at <= Utc::now() {
+            pending.remove(token);
+            return Err(format!(
+                "Confirmation token expired. Call {} without confirmation_token to get a new one",
+                operation.tool_name()
+            ));
+        }
+        if &confirmation.operation != operation {
+            return Err(format!(
+                "Confirmation token was issued to {}, not to {}",
+                confirmation.operation, operation
+            ));
+        }
+        if !is_dry_run() {
+            pending.remove(token);
+        }
+        Ok(())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, PendingConfirmation>> {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
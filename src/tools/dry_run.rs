@@ -0,0 +1,258 @@
+//! `dry_run` argument of the mutating MCP tools
+//!
+//! Every tool that changes something on GitHub accepts `dry_run: true`. The
+//! tool then runs within `github::dry_run::with_dry_run`: its inputs are
+//! resolved with real read requests, while its writes are recorded instead
+//! of sent. The result lists the write requests the tool would have sent,
+//! with warnings about inputs that would not do what the caller expects,
+//! such as labels GitHub would create on the fly.
+
+use crate::github::GitHubClient;
+use crate::github::dry_run::{DRY_RUN_MESSAGE, with_dry_run};
+use crate::tools::annotations::{ToolEffect, tool_effect};
+use crate::tools::tool_definition::success_result;
+use crate::types::dry_run::{DryRunReport, PlannedRequest};
+use crate::types::pagination::{MAX_PER_PAGE, Pagination};
+use crate::types::repository::{RepositoryId, RepositoryUrl};
+
+use rmcp::{Error as McpError, model::*};
+use std::collections::BTreeSet;
+use std::future::Future;
+use std::sync::Arc;
+
+/// Name of the argument enabling a dry run
+pub const DRY_RUN_ARGUMENT: &str = "dry_run";
+
+/// Maximum number of label pages read to validate label names
+const MAX_LABEL_PAGES: u32 = 10;
+
+/// Returns true if the tool never changes anything on GitHub
+///
+/// Tools are classified by the annotation registry; unknown tools are not
+/// read only.
+pub fn is_read_only_tool(name: &str) -> bool {
+    tool_effect(name) == Some(ToolEffect::ReadOnly)
+}
+
+/// Add the `dry_run` argument to the input schema of a mutating tool
+pub fn with_dry_run_argument(mut tool: Tool) -> Tool {
+    if is_read_only_tool(&tool.name) {
+        return tool;
+    }
+    let mut schema = tool.input_schema.as_ref().clone();
+    let properties = schema
+        .entry("properties")
+        .or_insert_with(|| serde_json::Value::Object(JsonObject::new()));
+    if let Some(properties) = properties.as_object_mut() {
+        properties.insert(
+            DRY_RUN_ARGUMENT.to_string(),
+            serde_json::json!({
+                "type": "boolean",
+                "description": "Validate the inputs and return the write requests the tool would send, without sending them",
+            }),
+        );
+    }
+    tool.input_schema = Arc::new(schema);
+    tool
+}
+
+/// Remove the `dry_run` argument from a call to a mutating tool
+///
+/// # Returns
+/// `true` if the call asks for a dry run
+///
+/// # Errors
+/// Returns an error if `dry_run` is not a boolean
+pub fn take_dry_run_argument(request: &mut CallToolRequestParam) -> Result<bool, McpError> {
+    if is_read_only_tool(&request.name) {
+        return Ok(false);
+    }
+    let Some(value) = request
+        .arguments
+        .as_mut()
+        .and_then(|arguments| arguments.remove(DRY_RUN_ARGUMENT))
+    else {
+        return Ok(false);
+    };
+    match value {
+        serde_json::Value::Bool(dry_run) => Ok(dry_run),
+        serde_json::Value::Null => Ok(false),
+        other => Err(McpError::invalid_params(
+            format!("'{}' must be a boolean, got {}", DRY_RUN_ARGUMENT, other),
+            None,
+        )),
+    }
+}
+
+/// Run a tool call as a dry run and report the requests it would send
+///
+/// # Arguments
+/// * `github_client` - Client used to validate the arguments
+/// * `request` - The tool call, without its `dry_run` argument
+/// * `call` - The tool call to run
+pub async fn dry_run_tool<F>(
+    github_client: &GitHubClient,
+    request: &CallToolRequestParam,
+    call: F,
+) -> Result<CallToolResult, McpError>
+where
+    F: Future<Output = Result<CallToolResult, McpError>>,
+{
+    let arguments = request.arguments.clone().unwrap_or_default();
+    let errors = |result: &Result<CallToolResult, McpError>| match result {
+        Ok(result) if result.is_error == Some(true) => texts(result),
+        Ok(_) => Vec::new(),
+        Err(e) => vec![e.message.to_string()],
+    };
+    match dry_run_operation(github_client, &request.name, &arguments, call, errors).await {
+        Ok(report) => Ok(success_result(format_report(&report), &report)),
+        Err(result) => result,
+    }
+}
+
+/// Run an operation as a dry run and report the write requests it would send
+///
+/// Shared by the MCP tools and the CLI commands. `arguments` are checked as
+/// the tool arguments are: the repository named by `repository_url` must be
+/// accessible, and the `labels` must exist in it.
+///
+/// # Arguments
+/// * `github_client` - Client used to validate the arguments
+/// * `operation` - Name of the tool or command
+/// * `arguments` - Arguments of the operation
+/// * `call` - The operation to run
+/// * `errors` - Returns the error messages of the output of the operation
+///
+/// # Returns
+/// The report of the dry run, or the output of the operation if it failed
+/// before sending any write, e.g. on an input that does not resolve
+pub async fn dry_run_operation<F, E>(
+    github_client: &GitHubClient,
+    operation: &str,
+    arguments: &JsonObject,
+    call: F,
+    errors: E,
+) -> Result<DryRunReport, F::Output>
+where
+    F: Future,
+    E: FnOnce(&F::Output) -> Vec<String>,
+{
+    let mut warnings = validate_arguments(github_client, arguments).await;
+
+    let (output, planned_requests) = with_dry_run(call).await;
+    let errors = errors(&output);
+    if planned_requests.is_empty() && !errors.is_empty() {
+        return Err(output);
+    }
+    warnings.extend(
+        errors
+            .into_iter()
+            .filter(|error| !error.contains(DRY_RUN_MESSAGE)),
+    );
+
+    Ok(DryRunReport {
+        tool: operation.to_string(),
+        planned_requests,
+        warnings,
+    })
+}
+
+/// Check that the repository and labels named by the arguments exist
+async fn validate_arguments(github_client: &GitHubClient, arguments: &JsonObject) -> Vec<String> {
+    let Some(repository_url) = arguments
+        .get("repository_url")
+        .and_then(|value| value.as_str())
+    else {
+        return Vec::new();
+    };
+    let repository_id = match RepositoryId::parse_url(&RepositoryUrl(repository_url.to_string())) {
+        Ok(repository_id) => repository_id,
+        // The tool reports invalid URLs itself
+        Err(_) => return Vec::new(),
+    };
+    if let Err(e) = github_client.get_repository(&repository_id).await {
+        return vec![format!(
+            "Repository {} is not accessible: {}",
+            repository_id.url(),
+            e
+        )];
+    }
+
+    let labels: Vec<&str> = arguments
+        .get("labels")
+        .and_then(|value| value.as_array())
+        .map(|labels| labels.iter().filter_map(|label| label.as_str()).collect())
+        .unwrap_or_default();
+    if labels.is_empty() {
+        return Vec::new();
+    }
+    match existing_labels(github_client, &repository_id).await {
+        Ok(existing) => labels
+            .into_iter()
+            .filter(|label| !existing.contains(&label.to_lowercase()))
+            .map(|label| {
+                format!(
+                    "Label '{}' does not exist in {} and would be created by GitHub",
+                    label,
+                    repository_id.url()
+                )
+            })
+            .collect(),
+        Err(e) => vec![format!("Failed to list labels to validate them: {}", e)],
+    }
+}
+
+/// Returns the lowercased names of the labels of a repository
+async fn existing_labels(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+) -> anyhow::Result<BTreeSet<String>> {
+    let mut names = BTreeSet::new();
+    for page in 1..=MAX_LABEL_PAGES {
+        let labels = github_client
+            .list_labels(
+                repository_id,
+                Pagination::new(Some(page), Some(MAX_PER_PAGE)),
+            )
+            .await?;
+        names.extend(labels.items.iter().map(|label| label.name.to_lowercase()));
+        if !labels.has_next_page {
+            break;
+        }
+    }
+    Ok(names)
+}
+
+/// Render a dry run report as text
+pub fn format_report(report: &DryRunReport) -> String {
+    let mut text = if report.planned_requests.is_empty() {
+        format!("Dry run of {}: no changes needed", report.tool)
+    } else {
+        format!(
+            "Dry run of {}: would send {} request(s):",
+            report.tool,
+            report.planned_requests.len()
+        )
+    };
+    for PlannedRequest { method, url, body } in &report.planned_requests {
+        text.push_str(&format!("\n- {} {}", method, url));
+        if let Some(body) = body {
+            text.push_str(&format!(" {}", body));
+        }
+    }
+    for warning in &report.warnings {
+        text.push_str(&format!("\nWarning: {}", warning));
+    }
+    text
+}
+
+fn texts(result: &CallToolResult) -> Vec<String> {
+    result
+        .content
+        .iter()
+        .filter_map(|content| match &content.raw {
+            RawContent::Text(text) => Some(text.text.clone()),
+            _ => None,
+        })
+        .collect()
+}
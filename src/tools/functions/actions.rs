@@ -0,0 +1,383 @@
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::github::GitHubClient;
+use crate::services::actions_service::ActionsService;
+use crate::services::pull_request_service::PullRequestService;
+use crate::types::actions::{
+    Artifact, ArtifactId, Deployment, DeploymentReviewState, PendingDeployment, WorkflowJob,
+    WorkflowRun, WorkflowRunFilter, WorkflowRunId, WorkflowRunLogs, extract_log_files,
+};
+use crate::types::pagination::{MAX_PER_PAGE, Page, Pagination};
+use crate::types::pull_request::PullRequestNumber;
+use crate::types::repository::RepositoryId;
+
+/// Trigger a `workflow_dispatch` event for a workflow
+///
+/// The run is queued asynchronously; list the workflow's runs with the
+/// `workflow_dispatch` event to follow it.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `workflow` - Workflow file name (e.g. "ci.yml") or numeric workflow ID
+/// * `git_ref` - Branch or tag to run the workflow on
+/// * `inputs` - Input values for the workflow
+pub async fn dispatch_workflow(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    workflow: &str,
+    git_ref: &str,
+    inputs: &BTreeMap<String, String>,
+) -> Result<()> {
+    let actions_service = ActionsService::new(github_client.clone());
+    actions_service
+        .dispatch_workflow(repository_id, workflow, git_ref, inputs)
+        .await
+}
+
+/// List workflow runs of a repository
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `filter` - Workflow, branch, event and status to restrict the runs to
+/// * `pagination` - The page of runs to fetch
+///
+/// # Returns
+/// A page of workflow runs, most recent first
+pub async fn list_workflow_runs(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    filter: &WorkflowRunFilter,
+    pagination: Pagination,
+) -> Result<Page<WorkflowRun>> {
+    let actions_service = ActionsService::new(github_client.clone());
+    actions_service
+        .list_workflow_runs(repository_id, filter, pagination)
+        .await
+}
+
+/// Cancel a queued or in-progress workflow run
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `run_id` - The workflow run to cancel
+pub async fn cancel_workflow_run(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    run_id: WorkflowRunId,
+) -> Result<()> {
+    let actions_service = ActionsService::new(github_client.clone());
+    actions_service
+        .cancel_workflow_run(repository_id, run_id)
+        .await
+}
+
+/// Re-run all jobs of a completed workflow run
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `run_id` - The workflow run to re-run
+pub async fn rerun_workflow_run(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    run_id: WorkflowRunId,
+) -> Result<()> {
+    let actions_service = ActionsService::new(github_client.clone());
+    actions_service
+        .rerun_workflow_run(repository_id, run_id)
+        .await
+}
+
+/// Approve a workflow run that waits for approval
+///
+/// Runs triggered by pull requests from forks of first-time contributors
+/// wait until a maintainer approves them.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `run_id` - The workflow run to approve
+pub async fn approve_workflow_run(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    run_id: WorkflowRunId,
+) -> Result<()> {
+    let actions_service = ActionsService::new(github_client.clone());
+    actions_service
+        .approve_workflow_run(repository_id, run_id)
+        .await
+}
+
+/// Approve every workflow run of a pull request that waits for approval
+///
+/// Finds the runs for the pull request's head commit with the
+/// `action_required` status and approves each of them.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `pr_number` - The pull request number
+///
+/// # Returns
+/// The approved workflow runs; empty if no run was waiting for approval
+///
+/// # Errors
+/// Returns an error if a run cannot be approved. Runs approved before the
+/// failure stay approved.
+pub async fn approve_pending_runs_for_pr(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    pr_number: PullRequestNumber,
+) -> Result<Vec<WorkflowRun>> {
+    let pull_request_service = PullRequestService::new(github_client.clone());
+    let head_sha = pull_request_service
+        .get_head_sha(repository_id, pr_number)
+        .await?;
+
+    let actions_service = ActionsService::new(github_client.clone());
+    let filter = WorkflowRunFilter {
+        status: Some("action_required".to_string()),
+        head_sha: Some(head_sha),
+        ..Default::default()
+    };
+
+    let mut pending_runs = Vec::new();
+    let mut pagination = Pagination::new(None, Some(MAX_PER_PAGE));
+    loop {
+        let page = actions_service
+            .list_workflow_runs(repository_id, &filter, pagination)
+            .await?;
+        pending_runs.extend(page.items);
+        if !page.has_next_page {
+            break;
+        }
+        pagination = pagination.next();
+    }
+
+    for (approved, run) in pending_runs.iter().enumerate() {
+        actions_service
+            .approve_workflow_run(repository_id, run.id)
+            .await
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "Failed to approve workflow run {} after approving {} run(s): {}",
+                    run.id,
+                    approved,
+                    e
+                )
+            })?;
+    }
+
+    Ok(pending_runs)
+}
+
+/// Re-run only the failed jobs of a workflow run
+///
+/// Cheaper than re-running the whole run when a failure is flaky. Jobs that
+/// the failed jobs depend on are re-run as well.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `run_id` - The workflow run to re-run the failed jobs of
+pub async fn rerun_failed_jobs(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    run_id: WorkflowRunId,
+) -> Result<()> {
+    let actions_service = ActionsService::new(github_client.clone());
+    actions_service
+        .rerun_failed_jobs(repository_id, run_id)
+        .await
+}
+
+/// List the jobs of a workflow run
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `run_id` - The workflow run to list the jobs of
+/// * `all_attempts` - Whether to include jobs of earlier attempts instead of only the latest
+/// * `pagination` - The page of jobs to fetch
+///
+/// # Returns
+/// A page of jobs with their steps and conclusions
+pub async fn list_jobs_for_run(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    run_id: WorkflowRunId,
+    all_attempts: bool,
+    pagination: Pagination,
+) -> Result<Page<WorkflowJob>> {
+    let actions_service = ActionsService::new(github_client.clone());
+    actions_service
+        .list_jobs_for_run(repository_id, run_id, all_attempts, pagination)
+        .await
+}
+
+/// Get the logs of a workflow run
+///
+/// Without `save_to` the log files are extracted from the downloaded archive
+/// and returned as text. With `save_to` the archive is written to that path
+/// unchanged, which suits runs with large logs.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `run_id` - The workflow run to get the logs of
+/// * `save_to` - Optional path to save the zip archive to
+///
+/// # Returns
+/// The extracted log files, or the path of the saved archive
+pub async fn get_workflow_run_logs(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    run_id: WorkflowRunId,
+    save_to: Option<&Path>,
+) -> Result<WorkflowRunLogs> {
+    let actions_service = ActionsService::new(github_client.clone());
+    let archive = actions_service
+        .download_workflow_run_logs(repository_id, run_id)
+        .await?;
+
+    match save_to {
+        Some(path) => {
+            tokio::fs::write(path, &archive)
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to save logs to {}: {}", path.display(), e))?;
+            Ok(WorkflowRunLogs::Saved(path.to_path_buf()))
+        }
+        None => Ok(WorkflowRunLogs::Extracted(extract_log_files(&archive)?)),
+    }
+}
+
+/// List artifacts of a repository or of a single workflow run
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `run_id` - Optional workflow run to list the artifacts of
+/// * `pagination` - The page of artifacts to fetch
+///
+/// # Returns
+/// A page of artifacts, most recent first
+pub async fn list_artifacts(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    run_id: Option<WorkflowRunId>,
+    pagination: Pagination,
+) -> Result<Page<Artifact>> {
+    let actions_service = ActionsService::new(github_client.clone());
+    actions_service
+        .list_artifacts(repository_id, run_id, pagination)
+        .await
+}
+
+/// Download an artifact into a directory
+///
+/// The artifact is saved as `<artifact name>.zip` in `output_dir`, which is
+/// created if missing.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `artifact_id` - The artifact to download
+/// * `output_dir` - Directory to save the archive in
+///
+/// # Returns
+/// The path of the saved archive
+///
+/// # Errors
+/// Returns an error if the artifact has expired or cannot be written.
+pub async fn download_artifact(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    artifact_id: ArtifactId,
+    output_dir: &Path,
+) -> Result<PathBuf> {
+    let actions_service = ActionsService::new(github_client.clone());
+    let artifact = actions_service
+        .get_artifact(repository_id, artifact_id)
+        .await?;
+    if artifact.expired {
+        return Err(anyhow::anyhow!(
+            "Artifact {} ({}) has expired",
+            artifact.name,
+            artifact.id
+        ));
+    }
+
+    let archive = actions_service
+        .download_artifact(repository_id, artifact_id)
+        .await?;
+
+    let path = output_dir.join(format!("{}.zip", artifact.name));
+    tokio::fs::create_dir_all(output_dir)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to create {}: {}", output_dir.display(), e))?;
+    tokio::fs::write(&path, &archive)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to save artifact to {}: {}", path.display(), e))?;
+
+    Ok(path)
+}
+
+/// List the deployments of a workflow run waiting on protected environments
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `run_id` - The workflow run
+///
+/// # Returns
+/// The pending deployments with their environments and required reviewers
+pub async fn list_pending_deployments(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    run_id: WorkflowRunId,
+) -> Result<Vec<PendingDeployment>> {
+    let actions_service = ActionsService::new(github_client.clone());
+    actions_service
+        .list_pending_deployments(repository_id, run_id)
+        .await
+}
+
+/// Approve or reject deployments of a workflow run waiting on protected environments
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `run_id` - The workflow run
+/// * `environment_ids` - IDs of the environments to review the deployments for
+/// * `state` - Whether to approve or reject the deployments
+/// * `comment` - Comment recorded with the review
+///
+/// # Returns
+/// The deployments created for approved environments
+///
+/// # Errors
+/// Returns an error if no environment ID is given.
+pub async fn review_pending_deployments(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    run_id: WorkflowRunId,
+    environment_ids: &[u64],
+    state: DeploymentReviewState,
+    comment: &str,
+) -> Result<Vec<Deployment>> {
+    if environment_ids.is_empty() {
+        return Err(anyhow::anyhow!(
+            "At least one environment ID is required to review deployments"
+        ));
+    }
+
+    let actions_service = ActionsService::new(github_client.clone());
+    actions_service
+        .review_pending_deployments(repository_id, run_id, environment_ids, state, comment)
+        .await
+}
@@ -0,0 +1,179 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+use crate::github::GitHubClient;
+use crate::services::auth_service::AuthService;
+use crate::types::auth::{AccountType, AuthStatus, AuthenticatedUser};
+use crate::types::rate_limit::{RateLimit, RateLimitBucket};
+use crate::types::repository::Owner;
+
+/// Verify the configured token and report its identity and scopes
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+///
+/// # Returns
+/// The `AuthStatus` of the token; `AuthStatus::unmet_requirements` lists the
+/// tool groups the token lacks scopes for
+///
+/// # Errors
+/// Returns an error if no token is configured or the token is rejected
+pub async fn verify_auth(github_client: &GitHubClient) -> Result<AuthStatus> {
+    let auth_service = AuthService::new(github_client.clone());
+    auth_service.verify_auth().await
+}
+
+/// Describe an auth status as lines of text
+///
+/// Lists the identity and scopes of the token followed by the tool groups it
+/// lacks scopes for, shared by the MCP tool and the CLI.
+pub fn describe_auth_status(status: &AuthStatus) -> Vec<String> {
+    let mut lines = vec![
+        format!(
+            "Authenticated as {}{} against {}",
+            status.login,
+            status
+                .name
+                .as_deref()
+                .map(|name| format!(" ({})", name))
+                .unwrap_or_default(),
+            status.api_base_url
+        ),
+        format!("Token kind: {}", status.token_kind),
+    ];
+
+    match &status.scopes {
+        Some(scopes) => {
+            lines.push(format!(
+                "Scopes: {}",
+                if scopes.is_empty() {
+                    "none".to_string()
+                } else {
+                    scopes.join(", ")
+                }
+            ));
+            let unmet = status.unmet_requirements();
+            if unmet.is_empty() {
+                lines.push("All tools are usable with these scopes".to_string());
+            } else {
+                lines.push("Tools that will not work with these scopes:".to_string());
+                lines.extend(unmet.iter().map(|requirement| {
+                    format!(
+                        "- {} ({}): needs {}",
+                        requirement.area,
+                        requirement.tools,
+                        requirement.scopes.join(" or ")
+                    )
+                }));
+            }
+            if status.is_public_repo_only() {
+                lines.push(
+                    "Only public repositories are accessible: the token has public_repo but not repo"
+                        .to_string(),
+                );
+            }
+        }
+        None => lines.push(
+            "Scopes: not reported for this kind of token. Its permissions are checked per request, and failing requests name the permissions they need"
+                .to_string(),
+        ),
+    }
+
+    lines
+}
+
+/// Get the account the configured token acts as
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+///
+/// # Returns
+/// The login, name, account type and scopes of the authenticated account
+///
+/// # Errors
+/// Returns an error if no token is configured or the token is rejected
+pub async fn get_authenticated_user(github_client: &GitHubClient) -> Result<AuthenticatedUser> {
+    let auth_service = AuthService::new(github_client.clone());
+    auth_service.get_authenticated_user().await
+}
+
+/// Describe the authenticated account as lines of text
+///
+/// Shared by the MCP tool and the CLI.
+pub fn describe_authenticated_user(user: &AuthenticatedUser) -> Vec<String> {
+    let mut lines = vec![format!(
+        "Authenticated as {}{} ({} account)",
+        user.login,
+        user.name
+            .as_deref()
+            .map(|name| format!(" ({})", name))
+            .unwrap_or_default(),
+        user.account_type
+    )];
+    match user.account_type {
+        AccountType::Bot => lines.push(format!(
+            "Mention as @{}; comments and edits appear as the bot, and bots cannot be assigned to issues",
+            user.login
+        )),
+        _ => lines.push(format!(
+            "Mention as @{}; assign issues to yourself with the login '{}'",
+            user.login, user.login
+        )),
+    }
+    if let Some(html_url) = &user.html_url {
+        lines.push(format!("Profile: {}", html_url));
+    }
+    lines.push(match &user.scopes {
+        Some(scopes) if scopes.is_empty() => "Scopes: none".to_string(),
+        Some(scopes) => format!("Scopes: {}", scopes.join(", ")),
+        None => "Scopes: not reported for this kind of token".to_string(),
+    });
+    lines
+}
+
+/// Get the rate limit buckets of a token
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `owner` - Owner whose token from `owner_tokens` is checked; the client's
+///   token is checked if `None`
+///
+/// # Returns
+/// The core, GraphQL and search buckets of the token
+///
+/// # Errors
+/// Returns an error if no token is configured or rate limiting is disabled
+pub async fn get_rate_limit(
+    github_client: &GitHubClient,
+    owner: Option<Owner>,
+) -> Result<RateLimit> {
+    let auth_service = AuthService::new(github_client.clone());
+    auth_service.get_rate_limit(owner.as_ref()).await
+}
+
+/// Describe rate limit buckets as lines of text at `now`
+///
+/// Shared by the MCP tool and the CLI.
+pub fn describe_rate_limit(rate_limit: &RateLimit, now: DateTime<Utc>) -> Vec<String> {
+    let describe = |name: &str, bucket: &RateLimitBucket| {
+        format!(
+            "{}: {} of {} remaining ({} used), resets at {} (in {} min){}",
+            name,
+            bucket.remaining,
+            bucket.limit,
+            bucket.used,
+            bucket.reset.format("%Y-%m-%dT%H:%M:%SZ"),
+            bucket.minutes_until_reset(now),
+            if bucket.is_exhausted() {
+                " - exhausted"
+            } else {
+                ""
+            }
+        )
+    };
+    vec![
+        describe("Core (REST)", &rate_limit.core),
+        describe("GraphQL (points)", &rate_limit.graphql),
+        describe("Search", &rate_limit.search),
+    ]
+}
@@ -0,0 +1,81 @@
+use anyhow::Result;
+
+use crate::github::GitHubClient;
+use crate::services::check_service::CheckService;
+use crate::types::check::{CheckRun, CombinedStatus, CommitState, CommitStatus};
+use crate::types::pagination::{Page, Pagination};
+use crate::types::repository::RepositoryId;
+
+/// List check runs for a commit SHA, branch or tag
+///
+/// Check runs are created by GitHub Apps, including GitHub Actions jobs.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `git_ref` - Commit SHA, branch name or tag name
+/// * `check_name` - Optional check run name to filter by
+/// * `pagination` - The page of check runs to fetch
+///
+/// # Returns
+/// A page of check runs for the ref
+pub async fn list_check_runs_for_ref(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    git_ref: &str,
+    check_name: Option<&str>,
+    pagination: Pagination,
+) -> Result<Page<CheckRun>> {
+    let check_service = CheckService::new(github_client.clone());
+    check_service
+        .list_check_runs_for_ref(repository_id, git_ref, check_name, pagination)
+        .await
+}
+
+/// Get the combined commit status for a commit SHA, branch or tag
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `git_ref` - Commit SHA, branch name or tag name
+///
+/// # Returns
+/// The combined state and the latest status of each context
+pub async fn get_combined_status(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    git_ref: &str,
+) -> Result<CombinedStatus> {
+    let check_service = CheckService::new(github_client.clone());
+    check_service
+        .get_combined_status(repository_id, git_ref)
+        .await
+}
+
+/// Create a commit status
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `sha` - The commit SHA to attach the status to
+/// * `state` - The state of the status
+/// * `context` - Optional label identifying the status (defaults to "default")
+/// * `description` - Optional short description of the status
+/// * `target_url` - Optional URL with details of the status
+///
+/// # Returns
+/// The created commit status
+pub async fn create_commit_status(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    sha: &str,
+    state: CommitState,
+    context: Option<&str>,
+    description: Option<&str>,
+    target_url: Option<&str>,
+) -> Result<CommitStatus> {
+    let check_service = CheckService::new(github_client.clone());
+    check_service
+        .create_commit_status(repository_id, sha, state, context, description, target_url)
+        .await
+}
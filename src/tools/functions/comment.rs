@@ -0,0 +1,91 @@
+use anyhow::Result;
+
+use crate::github::GitHubClient;
+use crate::services::comment_service::CommentService;
+use crate::types::comment::{CommentPermissions, CommentTarget, MinimizeReason};
+use crate::types::repository::RepositoryId;
+
+/// Check what the authenticated user may do with a comment
+///
+/// Use before editing, deleting, or hiding a comment to report a clear
+/// "not permitted" result instead of an opaque 403 from the API.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `target` - The comment to check
+///
+/// # Returns
+/// The comment author and whether the user may edit, delete, or hide the comment
+pub async fn can_edit_comment(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    target: CommentTarget,
+) -> Result<CommentPermissions> {
+    let comment_service = CommentService::new(github_client.clone());
+    comment_service.get_permissions(repository_id, target).await
+}
+
+/// Hide (minimize) an issue or pull request comment
+///
+/// Collapses the comment with a classifier such as spam, abuse, outdated, or
+/// resolved, so moderation workflows can clean up threads without deleting
+/// content.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `target` - The comment to hide
+/// * `reason` - The classifier recorded for the hidden comment
+///
+/// # Returns
+/// `true` if the comment is now minimized
+pub async fn minimize_comment(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    target: CommentTarget,
+    reason: MinimizeReason,
+) -> Result<bool> {
+    let comment_service = CommentService::new(github_client.clone());
+    comment_service
+        .minimize_comment(repository_id, target, reason)
+        .await
+}
+
+/// Show (unminimize) a hidden issue or pull request comment
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `target` - The comment to show again
+///
+/// # Returns
+/// `true` if the comment is still minimized
+pub async fn unminimize_comment(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    target: CommentTarget,
+) -> Result<bool> {
+    let comment_service = CommentService::new(github_client.clone());
+    comment_service
+        .unminimize_comment(repository_id, target)
+        .await
+}
+
+/// Delete an issue or pull request comment
+///
+/// Permanently removes the comment. Use `minimize_comment` to hide a comment
+/// while keeping its content.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `target` - The comment to delete
+pub async fn delete_comment(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    target: CommentTarget,
+) -> Result<()> {
+    let comment_service = CommentService::new(github_client.clone());
+    comment_service.delete_comment(repository_id, target).await
+}
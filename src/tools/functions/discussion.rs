@@ -0,0 +1,238 @@
+use anyhow::Result;
+
+use crate::github::GitHubClient;
+use crate::services::discussion_service::DiscussionService;
+use crate::types::discussion::{
+    Discussion, DiscussionCategory, DiscussionComment, DiscussionNumber, LockReason,
+};
+use crate::types::repository::RepositoryId;
+
+/// List the discussion categories of a repository
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+pub async fn list_discussion_categories(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+) -> Result<Vec<DiscussionCategory>> {
+    let discussion_service = DiscussionService::new(github_client.clone());
+    discussion_service
+        .list_discussion_categories(repository_id)
+        .await
+}
+
+/// Get a discussion with its comments and their replies
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `discussion_number` - The discussion number
+pub async fn get_discussion(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    discussion_number: DiscussionNumber,
+) -> Result<Discussion> {
+    let discussion_service = DiscussionService::new(github_client.clone());
+    discussion_service
+        .get_discussion(repository_id, discussion_number)
+        .await
+}
+
+/// Create a discussion in a repository
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `category` - Name, slug or node ID of the discussion category
+/// * `title` - The discussion title
+/// * `body` - The discussion body in Markdown
+///
+/// # Errors
+/// Returns an error listing the available categories if `category` does
+/// not match any of them.
+pub async fn create_discussion(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    category: &str,
+    title: &str,
+    body: &str,
+) -> Result<Discussion> {
+    let discussion_service = DiscussionService::new(github_client.clone());
+    let category_id = resolve_category_id(&discussion_service, repository_id, category).await?;
+
+    discussion_service
+        .create_discussion(repository_id, &category_id, title, body)
+        .await
+}
+
+/// Add a comment to a discussion, or reply to one of its comments
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `discussion_number` - The discussion number
+/// * `body` - The comment body in Markdown
+/// * `reply_to_id` - Node ID of the top-level comment to reply to
+pub async fn add_discussion_comment(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    discussion_number: DiscussionNumber,
+    body: &str,
+    reply_to_id: Option<&str>,
+) -> Result<DiscussionComment> {
+    let discussion_service = DiscussionService::new(github_client.clone());
+    discussion_service
+        .add_discussion_comment(repository_id, discussion_number, body, reply_to_id)
+        .await
+}
+
+/// Mark a discussion comment as the answer of its discussion
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `comment_id` - Node ID of the comment (e.g. "DC_kwDOA...")
+///
+/// # Returns
+/// The number of the answered discussion
+pub async fn mark_comment_as_answer(
+    github_client: &GitHubClient,
+    comment_id: &str,
+) -> Result<DiscussionNumber> {
+    let discussion_service = DiscussionService::new(github_client.clone());
+    discussion_service.mark_comment_as_answer(comment_id).await
+}
+
+/// Lock a discussion so only collaborators can comment on it
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `discussion_number` - The discussion number
+/// * `reason` - Optional reason recorded for the lock
+///
+/// # Returns
+/// `true` if the discussion is locked
+pub async fn lock_discussion(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    discussion_number: DiscussionNumber,
+    reason: Option<LockReason>,
+) -> Result<bool> {
+    let discussion_service = DiscussionService::new(github_client.clone());
+    discussion_service
+        .lock_discussion(repository_id, discussion_number, reason)
+        .await
+}
+
+/// Unlock a previously locked discussion
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `discussion_number` - The discussion number
+///
+/// # Returns
+/// `true` if the discussion is still locked, `false` once it is unlocked
+pub async fn unlock_discussion(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    discussion_number: DiscussionNumber,
+) -> Result<bool> {
+    let discussion_service = DiscussionService::new(github_client.clone());
+    discussion_service
+        .unlock_discussion(repository_id, discussion_number)
+        .await
+}
+
+/// Update the title, body or category of a discussion
+///
+/// Fields passed as `None` are left unchanged.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `discussion_number` - The discussion number
+/// * `title` - Optional new title
+/// * `body` - Optional new body in Markdown
+/// * `category` - Optional name, slug or node ID of the new category
+///
+/// # Errors
+/// Returns an error if no field is given or `category` does not match any
+/// category of the repository.
+pub async fn update_discussion(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    discussion_number: DiscussionNumber,
+    title: Option<&str>,
+    body: Option<&str>,
+    category: Option<&str>,
+) -> Result<Discussion> {
+    if title.is_none() && body.is_none() && category.is_none() {
+        return Err(anyhow::anyhow!(
+            "At least one of title, body or category must be given"
+        ));
+    }
+
+    let discussion_service = DiscussionService::new(github_client.clone());
+    let category_id = match category {
+        Some(category) => {
+            Some(resolve_category_id(&discussion_service, repository_id, category).await?)
+        }
+        None => None,
+    };
+
+    discussion_service
+        .update_discussion(
+            repository_id,
+            discussion_number,
+            title,
+            body,
+            category_id.as_deref(),
+        )
+        .await
+}
+
+/// Delete a discussion comment or reply
+///
+/// Deleting a top-level comment also deletes its replies.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `comment_id` - Node ID of the comment (e.g. "DC_kwDOA...")
+pub async fn delete_discussion_comment(
+    github_client: &GitHubClient,
+    comment_id: &str,
+) -> Result<()> {
+    let discussion_service = DiscussionService::new(github_client.clone());
+    discussion_service
+        .delete_discussion_comment(comment_id)
+        .await
+}
+
+/// Resolve a category name, slug or node ID to the category node ID
+async fn resolve_category_id(
+    discussion_service: &DiscussionService,
+    repository_id: &RepositoryId,
+    category: &str,
+) -> Result<String> {
+    let categories = discussion_service
+        .list_discussion_categories(repository_id)
+        .await?;
+    categories
+        .iter()
+        .find(|candidate| candidate.matches(category))
+        .map(|candidate| candidate.id.clone())
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Discussion category '{}' not found in {}. Available categories: {}",
+                category,
+                repository_id,
+                categories
+                    .iter()
+                    .map(|candidate| candidate.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })
+}
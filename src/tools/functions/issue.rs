@@ -1,16 +1,99 @@
 use anyhow::Result;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
 use std::collections::BTreeMap;
 
 use crate::github::GitHubClient;
+use crate::github::cancellation;
 use crate::services::issue_service::IssueService;
-use crate::types::issue::{Issue, IssueCommentNumber, IssueId, IssueNumber, IssueState, IssueUrl};
-use crate::types::repository::{MilestoneNumber, RepositoryId};
+use crate::types::conditional::Conditional;
+use crate::types::issue::{
+    BulkIssueOperations, BulkIssueUpdateResult, DuplicateIssueResult, Issue, IssueComment,
+    IssueCommentNumber, IssueDetails, IssueId, IssueLinkResult, IssueNumber, IssueState,
+    IssueStateReason, IssueType, IssueUrl,
+};
+use crate::types::pagination::{MAX_PER_PAGE, Page, Pagination};
+use crate::types::repository::{MilestoneNumber, Owner, RepositoryId};
+use crate::types::timeline::{CrossReference, IssueTimelineEvent, dedup_cross_references};
 use crate::types::{User, label::Label};
 
+/// Label applied by `mark_issue_duplicate` when no label is specified
+pub const DEFAULT_DUPLICATE_LABEL: &str = "duplicate";
+
+/// Number of issues updated at the same time by `bulk_update_issues` by default
+pub const DEFAULT_BULK_CONCURRENCY: usize = 4;
+
+/// Failure reported for the items a cancelled batch did not start
+pub const CANCELLED_BEFORE_UPDATE: &str = "Cancelled before the update started";
+
+/// Number of issues or pull requests read at the same time by
+/// `get_issues_with_details` and `get_pull_requests_with_details` by default
+pub const DEFAULT_DETAILS_CONCURRENCY: usize = 4;
+
+/// Failure reported for the items a cancelled read did not start
+pub const CANCELLED_BEFORE_READ: &str = "Cancelled before it was read";
+
+/// Number of comments returned with an issue or pull request by default
+pub const DEFAULT_COMMENT_LIMIT: usize = 30;
+
+/// Maximum number of comments returned with an issue or pull request
+pub const MAX_COMMENT_LIMIT: usize = 100;
+
+/// Maximum number of URLs read by one `get_issues` or `get_pull_requests` call
+pub const MAX_DETAILS_URLS: usize = 20;
+
+/// Number of times `link_issues` checks the timelines for the cross-reference events
+const LINK_VERIFICATION_ATTEMPTS: u32 = 3;
+
+/// Delay between cross-reference checks, as GitHub records the events asynchronously
+const LINK_VERIFICATION_DELAY: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Get an issue with its comments
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `issue_number` - The issue number to fetch
+pub async fn get_issue(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    issue_number: IssueNumber,
+) -> Result<Issue> {
+    github_client.get_issue(repository_id, issue_number).await
+}
+
+/// Get an issue with at most `comment_limit` of its comments, oldest first
+///
+/// One comment more than the limit is fetched to tell whether the issue has
+/// further comments.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `issue_number` - The issue number to fetch
+/// * `comment_limit` - Maximum number of comments to return
+pub async fn get_issue_details(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    issue_number: IssueNumber,
+    comment_limit: usize,
+) -> Result<IssueDetails> {
+    let mut issue = github_client
+        .get_issue_with_comment_limit(repository_id, issue_number, Some(comment_limit + 1))
+        .await?;
+    let has_more_comments = issue.comments.len() > comment_limit;
+    issue.comments.truncate(comment_limit);
+    Ok(IssueDetails {
+        issue,
+        has_more_comments,
+    })
+}
+
 /// Get details for multiple issues from their URLs
 ///
-/// This function parses issue URLs, groups them by repository,
-/// and fetches the corresponding issues using the IssueService.
+/// This function parses issue URLs, fetches the issues with at most
+/// `DEFAULT_DETAILS_CONCURRENCY` requests in flight, and groups them by
+/// repository.
 ///
 /// # Arguments
 /// * `github_client` - The GitHub client instance
@@ -18,54 +101,109 @@ use crate::types::{User, label::Label};
 ///
 /// # Returns
 /// A BTreeMap grouping issues by repository ID
+///
+/// # Errors
+/// Returns the first failure in the order of `issue_urls` if any URL cannot
+/// be parsed or any issue cannot be fetched
 pub async fn get_issues_details(
     github_client: &GitHubClient,
     issue_urls: Vec<IssueUrl>,
 ) -> Result<BTreeMap<RepositoryId, Vec<Issue>>> {
-    // Convert URLs to IssueIds and group by repository
-    let mut issue_ids_by_repo: BTreeMap<RepositoryId, Vec<IssueNumber>> = BTreeMap::new();
-
-    for url in issue_urls {
-        match IssueId::parse_url(&url) {
-            Ok(issue_id) => {
-                let issue_number = IssueNumber::new(issue_id.number);
-                issue_ids_by_repo
-                    .entry(issue_id.git_repository)
-                    .or_default()
-                    .push(issue_number);
-            }
-            Err(e) => {
-                return Err(anyhow::anyhow!("Failed to parse issue URL {}: {}", url, e));
-            }
-        }
-    }
+    let issue_ids = issue_urls
+        .iter()
+        .map(|url| {
+            IssueId::parse_url(url)
+                .map_err(|e| anyhow::anyhow!("Failed to parse issue URL {}: {}", url, e))
+        })
+        .collect::<Result<Vec<_>>>()?;
 
-    // Fetch issues directly using GitHub client
-    let mut result: BTreeMap<RepositoryId, Vec<Issue>> = BTreeMap::new();
+    let issues = read_concurrently(&issue_ids, None, |issue_id| {
+        github_client.get_issue(&issue_id.git_repository, IssueNumber::new(issue_id.number))
+    })
+    .await;
 
-    for (repository_id, issue_numbers) in issue_ids_by_repo {
-        let mut issues = Vec::new();
-
-        for issue_number in issue_numbers {
-            match github_client.get_issue(&repository_id, issue_number).await {
-                Ok(issue) => issues.push(issue),
-                Err(e) => {
-                    return Err(anyhow::anyhow!(
-                        "Failed to fetch issue {} from repository {}: {}",
-                        issue_number,
-                        repository_id,
-                        e
-                    ));
-                }
-            }
-        }
-
-        result.insert(repository_id, issues);
+    let mut result: BTreeMap<RepositoryId, Vec<Issue>> = BTreeMap::new();
+    for (issue_id, issue) in issue_ids.into_iter().zip(issues) {
+        let issue = issue.map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to fetch issue {} from repository {}: {}",
+                issue_id.number,
+                issue_id.git_repository,
+                e
+            )
+        })?;
+        result
+            .entry(issue_id.git_repository)
+            .or_default()
+            .push(issue);
     }
 
     Ok(result)
 }
 
+/// Get several issues, each with at most `comment_limit` of its comments
+///
+/// Reads the issues with at most `concurrency` requests in flight at once.
+/// A failure on one issue never aborts the others; once the operation is
+/// cancelled, the issues not read yet fail with `CANCELLED_BEFORE_READ`.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `issue_ids` - The issues to fetch, possibly from different repositories
+/// * `comment_limit` - Maximum number of comments to return per issue
+/// * `concurrency` - Maximum number of issues read at once (defaults to `DEFAULT_DETAILS_CONCURRENCY`)
+///
+/// # Returns
+/// One result per issue, in the order of `issue_ids`
+pub async fn get_issues_with_details(
+    github_client: &GitHubClient,
+    issue_ids: &[IssueId],
+    comment_limit: usize,
+    concurrency: Option<usize>,
+) -> Vec<Result<IssueDetails>> {
+    read_concurrently(issue_ids, concurrency, |issue_id| {
+        get_issue_details(
+            github_client,
+            &issue_id.git_repository,
+            IssueNumber::new(issue_id.number),
+            comment_limit,
+        )
+    })
+    .await
+}
+
+/// Read every item of `ids` with at most `concurrency` reads in flight
+///
+/// Reads complete in any order, as they are polled through a
+/// `FuturesUnordered`, but the results are returned in the order of `ids`.
+/// Once the operation is cancelled, the reads not started yet fail with
+/// `CANCELLED_BEFORE_READ`.
+pub(crate) async fn read_concurrently<'a, I, T, F, Fut>(
+    ids: &'a [I],
+    concurrency: Option<usize>,
+    read: F,
+) -> Vec<Result<T>>
+where
+    F: Fn(&'a I) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let concurrency = concurrency.unwrap_or(DEFAULT_DETAILS_CONCURRENCY).max(1);
+    let read = &read;
+
+    let mut results: Vec<(usize, Result<T>)> = stream::iter(0..ids.len())
+        .map(|index| async move {
+            if cancellation::is_cancelled() {
+                return (index, Err(anyhow::anyhow!(CANCELLED_BEFORE_READ)));
+            }
+            (index, read(&ids[index]).await)
+        })
+        .buffer_unordered(concurrency)
+        .collect()
+        .await;
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
 /// Create a new issue
 ///
 /// Creates a new issue in the specified repository with the provided
@@ -216,22 +354,25 @@ pub async fn edit_body(
 
 /// Update the state of an issue
 ///
-/// Changes an issue's state between open and closed.
+/// Changes an issue's state between open and closed, optionally recording
+/// why the issue was closed or reopened.
 ///
 /// # Arguments
 /// * `github_client` - The GitHub client instance
 /// * `repository_id` - The repository identifier
 /// * `issue_number` - The issue number to update
 /// * `state` - The new state for the issue
+/// * `state_reason` - Optional reason (completed, not_planned, reopened)
 pub async fn update_state(
     github_client: &GitHubClient,
     repository_id: &RepositoryId,
     issue_number: IssueNumber,
     state: IssueState,
+    state_reason: Option<IssueStateReason>,
 ) -> Result<()> {
     let issue_service = IssueService::new(github_client.clone());
     issue_service
-        .update_state(repository_id, issue_number, state)
+        .update_state(repository_id, issue_number, state, state_reason)
         .await
 }
 
@@ -255,6 +396,194 @@ pub async fn delete_issue(
         .await
 }
 
+/// List issues in a repository
+///
+/// Lists one page of issues matching the filters so issues can be triaged
+/// without knowing their numbers in advance. Pull requests are excluded.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `state` - Optional state filter; `None` lists all issues
+/// * `labels` - Label names that every returned issue must have
+/// * `assignee` - Optional assignee login (`none` for unassigned, `*` for any)
+/// * `milestone` - Optional milestone the issues must belong to
+/// * `since` - Only issues updated at or after this time
+/// * `pagination` - The page of issues to fetch
+///
+/// # Returns
+/// A page of issues without their comments
+#[allow(clippy::too_many_arguments)]
+pub async fn list_issues(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    state: Option<IssueState>,
+    labels: &[String],
+    assignee: Option<&str>,
+    milestone: Option<MilestoneNumber>,
+    since: Option<DateTime<Utc>>,
+    pagination: Pagination,
+) -> Result<Page<Issue>> {
+    let issue_service = IssueService::new(github_client.clone());
+    issue_service
+        .list_issues(
+            repository_id,
+            state,
+            labels,
+            assignee,
+            milestone,
+            since,
+            pagination,
+        )
+        .await
+}
+
+/// List comments on an issue
+///
+/// Fetches one page of comments, oldest first, so long discussions can be
+/// read incrementally. Use `since` to fetch only comments updated after the
+/// last read.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `issue_number` - The issue number
+/// * `since` - Only comments updated at or after this time
+/// * `author` - Only comments written by this login (case-insensitive)
+/// * `pagination` - The page of comments to fetch
+///
+/// # Returns
+/// A page of issue comments
+pub async fn list_comments(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    issue_number: IssueNumber,
+    since: Option<DateTime<Utc>>,
+    author: Option<&str>,
+    pagination: Pagination,
+) -> Result<Page<IssueComment>> {
+    let issue_service = IssueService::new(github_client.clone());
+    issue_service
+        .list_comments(repository_id, issue_number, since, author, pagination)
+        .await
+}
+
+/// Get the timeline events of an issue
+///
+/// Returns labels, assignments, milestone changes, cross-references,
+/// commit references, comments, and state changes so the full history of an
+/// issue can be reconstructed.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `issue_number` - The issue or pull request number
+/// * `pagination` - The page of events to fetch
+///
+/// # Returns
+/// A page of timeline events in chronological order
+pub async fn get_timeline(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    issue_number: IssueNumber,
+    pagination: Pagination,
+) -> Result<Page<IssueTimelineEvent>> {
+    let issue_service = IssueService::new(github_client.clone());
+    issue_service
+        .get_timeline(repository_id, issue_number, pagination)
+        .await
+}
+
+/// Get a page of the timeline events of an issue if it changed
+///
+/// Polls the timeline with a conditional request: an unchanged page is
+/// answered without counting against the rate limit.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `issue_number` - The issue or pull request number
+/// * `pagination` - The page of events to fetch
+/// * `etag` - ETag of the page fetched before, if any
+///
+/// # Returns
+/// The page with its ETag, or `Conditional::NotModified` if it still has `etag`
+pub async fn get_timeline_if_changed(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    issue_number: IssueNumber,
+    pagination: Pagination,
+    etag: Option<&str>,
+) -> Result<Conditional<Page<IssueTimelineEvent>>> {
+    let issue_service = IssueService::new(github_client.clone());
+    issue_service
+        .get_timeline_if_changed(repository_id, issue_number, pagination, etag)
+        .await
+}
+
+/// Find the issues and pull requests that reference an issue or pull request
+///
+/// Use this to build a dependency map of related items across repositories.
+/// An item referencing the target several times is listed once, at its first
+/// reference.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `issue_number` - The issue or pull request number
+///
+/// # Returns
+/// The referencing items in the order they first referenced the target
+pub async fn find_cross_references(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    issue_number: IssueNumber,
+) -> Result<Vec<CrossReference>> {
+    let issue_service = IssueService::new(github_client.clone());
+    let references = issue_service
+        .list_cross_references(repository_id, issue_number)
+        .await?;
+    Ok(dedup_cross_references(references))
+}
+
+/// List the issue types defined by an organization
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `owner` - The organization login
+///
+/// # Returns
+/// All issue types of the organization
+pub async fn list_issue_types(
+    github_client: &GitHubClient,
+    owner: &Owner,
+) -> Result<Vec<IssueType>> {
+    let issue_service = IssueService::new(github_client.clone());
+    issue_service.list_issue_types(owner).await
+}
+
+/// Set or clear the issue type of an issue
+///
+/// Classifies an issue as Bug, Feature, Task or any other issue type
+/// defined by the repository owner organization.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `issue_number` - The issue number to classify
+/// * `issue_type` - The issue type name, or `None` to clear the type
+pub async fn set_issue_type(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    issue_number: IssueNumber,
+    issue_type: Option<&str>,
+) -> Result<()> {
+    let issue_service = IssueService::new(github_client.clone());
+    issue_service
+        .set_issue_type(repository_id, issue_number, issue_type)
+        .await
+}
+
 /// Add assignees to an issue
 ///
 /// Adds one or more assignees to an existing issue. Before adding,
@@ -492,3 +821,332 @@ pub async fn remove_milestone(
         .remove_milestone(repository_id, issue_number)
         .await
 }
+
+/// Mark an issue as a duplicate of another issue
+///
+/// Performs the usual duplicate triage in one call:
+/// 1. Comments `Duplicate of #N` on the issue, which GitHub recognizes as a
+///    duplicate marker
+/// 2. Applies the duplicate label (`duplicate` unless another label is given)
+/// 3. Closes the issue with the `not_planned` state reason
+///
+/// Every step is attempted even if an earlier step fails. Failures are
+/// collected in the returned result instead of aborting the whole operation.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `issue_number` - The issue number to mark as a duplicate
+/// * `duplicate_of` - The canonical issue number in the same repository
+/// * `duplicate_label` - Optional label to apply instead of the default `duplicate`
+///
+/// # Returns
+/// A `DuplicateIssueResult` describing which steps succeeded and which failed
+///
+/// # Errors
+/// Returns an error only if the issue is marked as a duplicate of itself
+pub async fn mark_issue_duplicate(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    issue_number: IssueNumber,
+    duplicate_of: IssueNumber,
+    duplicate_label: Option<&str>,
+) -> Result<DuplicateIssueResult> {
+    if issue_number == duplicate_of {
+        return Err(anyhow::anyhow!(
+            "Issue #{} cannot be a duplicate of itself",
+            issue_number
+        ));
+    }
+
+    let mut result = DuplicateIssueResult::new(
+        IssueId::new(repository_id.clone(), issue_number.value()),
+        duplicate_of,
+    );
+
+    let comment_body = format!("Duplicate of #{}", duplicate_of);
+    match add_comment(github_client, repository_id, issue_number, &comment_body).await {
+        Ok(comment_number) => result.comment_number = Some(comment_number),
+        Err(e) => result
+            .failures
+            .push(format!("Failed to add duplicate comment: {}", e)),
+    }
+
+    let label_name = duplicate_label.unwrap_or(DEFAULT_DUPLICATE_LABEL);
+    match add_labels(
+        github_client,
+        repository_id,
+        issue_number,
+        &[Label::from(label_name.to_string())],
+    )
+    .await
+    {
+        Ok(_) => result.applied_label = Some(label_name.to_string()),
+        Err(e) => result
+            .failures
+            .push(format!("Failed to apply label '{}': {}", label_name, e)),
+    }
+
+    match update_state(
+        github_client,
+        repository_id,
+        issue_number,
+        IssueState::Closed,
+        Some(IssueStateReason::NotPlanned),
+    )
+    .await
+    {
+        Ok(_) => result.closed = true,
+        Err(e) => result
+            .failures
+            .push(format!("Failed to close issue as not planned: {}", e)),
+    }
+
+    Ok(result)
+}
+
+/// Apply the same set of operations to multiple issues
+///
+/// Updates the issues with at most `concurrency` issues in flight at once to
+/// stay within GitHub's secondary rate limits. A failure on one issue or one
+/// operation never aborts the others; every failure is reported in the result
+/// of the affected issue. Once the operation is cancelled, the issues not
+/// started yet are reported as cancelled.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `issue_numbers` - The issues to update
+/// * `operations` - The operations applied to every issue
+/// * `concurrency` - Maximum number of issues updated at once (defaults to `DEFAULT_BULK_CONCURRENCY`)
+///
+/// # Returns
+/// One `BulkIssueUpdateResult` per issue, in the order of `issue_numbers`
+///
+/// # Errors
+/// Returns an error only if no operation is requested, or if a close reason
+/// is given that is not valid for closing
+pub async fn bulk_update_issues(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    issue_numbers: &[IssueNumber],
+    operations: &BulkIssueOperations,
+    concurrency: Option<usize>,
+) -> Result<Vec<BulkIssueUpdateResult>> {
+    if operations.is_empty() {
+        return Err(anyhow::anyhow!("No bulk update operation specified"));
+    }
+    if let Some(reason) = operations.close_reason {
+        if !operations.close || !reason.is_valid_for(IssueState::Closed) {
+            return Err(anyhow::anyhow!(
+                "State reason '{}' is not valid for closing issues",
+                reason
+            ));
+        }
+    }
+
+    let concurrency = concurrency.unwrap_or(DEFAULT_BULK_CONCURRENCY).max(1);
+
+    let results = stream::iter(issue_numbers.iter().copied())
+        .map(|issue_number| {
+            update_single_issue(github_client, repository_id, issue_number, operations)
+        })
+        .buffered(concurrency)
+        .collect()
+        .await;
+
+    Ok(results)
+}
+
+async fn update_single_issue(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    issue_number: IssueNumber,
+    operations: &BulkIssueOperations,
+) -> BulkIssueUpdateResult {
+    let mut result = BulkIssueUpdateResult::new(issue_number);
+
+    if cancellation::is_cancelled() {
+        result.failures.push(CANCELLED_BEFORE_UPDATE.to_string());
+        return result;
+    }
+
+    if !operations.add_labels.is_empty() {
+        if let Err(e) = add_labels(
+            github_client,
+            repository_id,
+            issue_number,
+            &operations.add_labels,
+        )
+        .await
+        {
+            result.failures.push(format!("Failed to add labels: {}", e));
+        }
+    }
+
+    if let Some(milestone) = operations.milestone {
+        if let Err(e) = set_milestone(github_client, repository_id, issue_number, milestone).await {
+            result
+                .failures
+                .push(format!("Failed to set milestone: {}", e));
+        }
+    }
+
+    if !operations.assignees.is_empty() {
+        if let Err(e) = add_assignees(
+            github_client,
+            repository_id,
+            issue_number,
+            &operations.assignees,
+        )
+        .await
+        {
+            result
+                .failures
+                .push(format!("Failed to add assignees: {}", e));
+        }
+    }
+
+    if operations.close {
+        if let Err(e) = update_state(
+            github_client,
+            repository_id,
+            issue_number,
+            IssueState::Closed,
+            operations.close_reason,
+        )
+        .await
+        {
+            result
+                .failures
+                .push(format!("Failed to close issue: {}", e));
+        }
+    }
+
+    result
+}
+
+/// Link two issues or pull requests with reference comments
+///
+/// Posts a `Relates to <reference>` comment on both items so each one shows a
+/// backlink to the other, then reads both timelines to confirm GitHub recorded
+/// the cross-reference events. The items may live in different repositories.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `source` - The issue or pull request to link from
+/// * `target` - The issue or pull request to link to
+///
+/// # Returns
+/// An `IssueLinkResult` whose `state()` tells whether the link was verified
+///
+/// # Errors
+/// Returns an error only if an issue is linked to itself
+pub async fn link_issues(
+    github_client: &GitHubClient,
+    source: &IssueId,
+    target: &IssueId,
+) -> Result<IssueLinkResult> {
+    if source == target {
+        return Err(anyhow::anyhow!(
+            "Issue {} cannot be linked to itself",
+            source
+        ));
+    }
+
+    let mut result = IssueLinkResult::new(source.clone(), target.clone());
+
+    let source_body = format!(
+        "Relates to {}",
+        target.reference_from(&source.git_repository)
+    );
+    match add_comment(
+        github_client,
+        &source.git_repository,
+        IssueNumber::new(source.number),
+        &source_body,
+    )
+    .await
+    {
+        Ok(comment_number) => result.source_comment = Some(comment_number),
+        Err(e) => result
+            .failures
+            .push(format!("Failed to comment on {}: {}", source, e)),
+    }
+
+    let target_body = format!(
+        "Relates to {}",
+        source.reference_from(&target.git_repository)
+    );
+    match add_comment(
+        github_client,
+        &target.git_repository,
+        IssueNumber::new(target.number),
+        &target_body,
+    )
+    .await
+    {
+        Ok(comment_number) => result.target_comment = Some(comment_number),
+        Err(e) => result
+            .failures
+            .push(format!("Failed to comment on {}: {}", target, e)),
+    }
+
+    for attempt in 0..LINK_VERIFICATION_ATTEMPTS {
+        if attempt > 0 {
+            tokio::time::sleep(LINK_VERIFICATION_DELAY).await;
+        }
+
+        if result.source_comment.is_some() && !result.target_cross_referenced {
+            match has_cross_reference(github_client, target, source).await {
+                Ok(found) => result.target_cross_referenced = found,
+                Err(e) => tracing::warn!("Failed to read timeline of {}: {}", target, e),
+            }
+        }
+        if result.target_comment.is_some() && !result.source_cross_referenced {
+            match has_cross_reference(github_client, source, target).await {
+                Ok(found) => result.source_cross_referenced = found,
+                Err(e) => tracing::warn!("Failed to read timeline of {}: {}", source, e),
+            }
+        }
+
+        let done = (result.source_comment.is_none() || result.target_cross_referenced)
+            && (result.target_comment.is_none() || result.source_cross_referenced);
+        if done {
+            break;
+        }
+    }
+
+    Ok(result)
+}
+
+/// Returns true if the timeline of `issue` contains a cross-reference from `from`
+async fn has_cross_reference(
+    github_client: &GitHubClient,
+    issue: &IssueId,
+    from: &IssueId,
+) -> Result<bool> {
+    let mut pagination = Pagination::new(None, Some(MAX_PER_PAGE));
+
+    loop {
+        let timeline = get_timeline(
+            github_client,
+            &issue.git_repository,
+            IssueNumber::new(issue.number),
+            pagination,
+        )
+        .await?;
+
+        if timeline
+            .items
+            .iter()
+            .any(|event| event.is_cross_reference_from(from))
+        {
+            return Ok(true);
+        }
+        if !timeline.has_next_page {
+            return Ok(false);
+        }
+        pagination = pagination.next();
+    }
+}
@@ -1,6 +1,18 @@
 //! Tool function implementations organized by functionality
 
+pub mod actions;
+pub mod auth;
+pub mod check;
+pub mod comment;
+pub mod discussion;
 pub mod issue;
+pub mod notification;
+pub mod plan;
 pub mod project;
 pub mod pull_request;
+pub mod reaction;
 pub mod repository;
+pub mod resource;
+pub mod search;
+pub mod secret;
+pub mod template;
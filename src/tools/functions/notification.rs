@@ -0,0 +1,149 @@
+use anyhow::Result;
+
+use crate::github::GitHubClient;
+use crate::services::notification_service::NotificationService;
+use crate::types::issue::IssueNumber;
+use crate::types::notification::{
+    IssueSubscription, Notification, NotificationFilter, NotificationThreadId, ThreadSubscription,
+};
+use crate::types::pagination::{Page, Pagination};
+use crate::types::repository::RepositoryId;
+
+/// List notifications of the authenticated user, most recently updated first
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - Optional repository to restrict the notifications to
+/// * `filter` - Read state, participation and time range to restrict the notifications to
+/// * `pagination` - The page of notifications to fetch
+pub async fn list_notifications(
+    github_client: &GitHubClient,
+    repository_id: Option<&RepositoryId>,
+    filter: &NotificationFilter,
+    pagination: Pagination,
+) -> Result<Page<Notification>> {
+    let notification_service = NotificationService::new(github_client.clone());
+    notification_service
+        .list_notifications(repository_id, filter, pagination)
+        .await
+}
+
+/// Mark a notification thread as read
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `thread_id` - The notification thread
+pub async fn mark_notification_read(
+    github_client: &GitHubClient,
+    thread_id: &NotificationThreadId,
+) -> Result<()> {
+    let notification_service = NotificationService::new(github_client.clone());
+    notification_service.mark_notification_read(thread_id).await
+}
+
+/// Mark a notification thread as done, removing it from the inbox
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `thread_id` - The notification thread
+pub async fn mark_thread_done(
+    github_client: &GitHubClient,
+    thread_id: &NotificationThreadId,
+) -> Result<()> {
+    let notification_service = NotificationService::new(github_client.clone());
+    notification_service.mark_thread_done(thread_id).await
+}
+
+/// Get the subscription of the authenticated user to a notification thread
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `thread_id` - The notification thread
+pub async fn get_thread_subscription(
+    github_client: &GitHubClient,
+    thread_id: &NotificationThreadId,
+) -> Result<ThreadSubscription> {
+    let notification_service = NotificationService::new(github_client.clone());
+    notification_service
+        .get_thread_subscription(thread_id)
+        .await
+}
+
+/// Subscribe to a notification thread
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `thread_id` - The notification thread
+pub async fn subscribe_thread(
+    github_client: &GitHubClient,
+    thread_id: &NotificationThreadId,
+) -> Result<ThreadSubscription> {
+    let notification_service = NotificationService::new(github_client.clone());
+    notification_service.subscribe_thread(thread_id).await
+}
+
+/// Unsubscribe from a notification thread
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `thread_id` - The notification thread
+pub async fn unsubscribe_thread(
+    github_client: &GitHubClient,
+    thread_id: &NotificationThreadId,
+) -> Result<()> {
+    let notification_service = NotificationService::new(github_client.clone());
+    notification_service.unsubscribe_thread(thread_id).await
+}
+
+/// Get the subscription of the authenticated user to an issue or pull request
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `issue_number` - The issue or pull request number
+pub async fn get_issue_subscription(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    issue_number: IssueNumber,
+) -> Result<IssueSubscription> {
+    let notification_service = NotificationService::new(github_client.clone());
+    notification_service
+        .get_issue_subscription(repository_id, issue_number)
+        .await
+}
+
+/// Subscribe to all conversation on an issue or pull request
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `issue_number` - The issue or pull request number
+pub async fn subscribe_to_issue(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    issue_number: IssueNumber,
+) -> Result<IssueSubscription> {
+    let notification_service = NotificationService::new(github_client.clone());
+    notification_service
+        .subscribe_to_issue(repository_id, issue_number)
+        .await
+}
+
+/// Unsubscribe from an issue or pull request
+///
+/// Notifications are still sent when participating or mentioned.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `issue_number` - The issue or pull request number
+pub async fn unsubscribe_from_issue(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    issue_number: IssueNumber,
+) -> Result<IssueSubscription> {
+    let notification_service = NotificationService::new(github_client.clone());
+    notification_service
+        .unsubscribe_from_issue(repository_id, issue_number)
+        .await
+}
@@ -0,0 +1,234 @@
+use anyhow::Result;
+use futures::stream::{self, StreamExt};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::github::GitHubClient;
+use crate::tools::functions::issue;
+use crate::types::issue::{IssueId, IssueNumber, IssueUrl};
+use crate::types::label::Label;
+use crate::types::plan::{
+    PlanFormat, PlanOperation, PlanOperationResult, PlanOperationStatus, PlanReport,
+};
+use crate::types::repository::{MilestoneNumber, RepositoryId, RepositoryUrl};
+use crate::types::user::User;
+
+/// Columns of a CSV plan holding comma-separated lists
+const CSV_LIST_COLUMNS: [&str; 2] = ["labels", "assignees"];
+
+/// Columns of a CSV plan holding numbers
+const CSV_NUMBER_COLUMNS: [&str; 1] = ["milestone"];
+
+/// Parse the operations of a plan file
+///
+/// A JSON plan is an array of operations, each naming its kind in
+/// `operation`. A CSV plan has a header row naming the same fields; empty
+/// cells are left out, and the `labels` and `assignees` cells are
+/// comma-separated lists.
+///
+/// # Arguments
+/// * `content` - Content of the plan file
+/// * `format` - Format of the plan file
+///
+/// # Errors
+/// Returns an error naming the row of the first operation that is not valid
+pub fn parse_plan(content: &str, format: PlanFormat) -> Result<Vec<PlanOperation>> {
+    match format {
+        PlanFormat::Json => {
+            let rows: Vec<serde_json::Value> = serde_json::from_str(content)
+                .map_err(|e| anyhow::anyhow!("Invalid JSON plan: {}", e))?;
+            rows.into_iter()
+                .enumerate()
+                .map(|(index, row)| parse_operation(index + 1, row))
+                .collect()
+        }
+        PlanFormat::Csv => {
+            let mut reader = csv::Reader::from_reader(content.as_bytes());
+            let headers = reader
+                .headers()
+                .map_err(|e| anyhow::anyhow!("Invalid CSV plan: {}", e))?
+                .clone();
+            reader
+                .records()
+                .enumerate()
+                .map(|(index, record)| {
+                    let record = record.map_err(|e| {
+                        anyhow::anyhow!("Invalid CSV plan row {}: {}", index + 1, e)
+                    })?;
+                    let row = csv_row(&headers, &record)
+                        .map_err(|e| anyhow::anyhow!("Invalid plan row {}: {}", index + 1, e))?;
+                    parse_operation(index + 1, row)
+                })
+                .collect()
+        }
+    }
+}
+
+fn parse_operation(row: usize, value: serde_json::Value) -> Result<PlanOperation> {
+    serde_json::from_value(value).map_err(|e| anyhow::anyhow!("Invalid plan row {}: {}", row, e))
+}
+
+/// JSON object of a CSV row, in the shape of a JSON plan operation
+fn csv_row(headers: &csv::StringRecord, record: &csv::StringRecord) -> Result<serde_json::Value> {
+    let mut row = serde_json::Map::new();
+    for (header, cell) in headers.iter().zip(record.iter()) {
+        let (header, cell) = (header.trim(), cell.trim());
+        if cell.is_empty() {
+            continue;
+        }
+        let value = if CSV_LIST_COLUMNS.contains(&header) {
+            cell.split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| serde_json::Value::String(item.to_string()))
+                .collect()
+        } else if CSV_NUMBER_COLUMNS.contains(&header) {
+            let number: u64 = cell
+                .parse()
+                .map_err(|_| anyhow::anyhow!("'{}' is not a valid {}", cell, header))?;
+            serde_json::Value::from(number)
+        } else {
+            serde_json::Value::String(cell.to_string())
+        };
+        row.insert(header.to_string(), value);
+    }
+    Ok(serde_json::Value::Object(row))
+}
+
+/// Apply the operations of a plan
+///
+/// Runs at most `concurrency` operations at once to stay within GitHub's
+/// secondary rate limits. A failed operation never aborts the others unless
+/// `stop_on_error` is set, in which case the operations not started yet are
+/// skipped.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `operations` - The operations of the plan
+/// * `concurrency` - Maximum number of operations run at once
+/// * `stop_on_error` - Skip the operations not started yet once one fails
+/// * `on_result` - Called with each result as the operations complete, in
+///   the order of the plan, e.g. to report progress
+///
+/// # Returns
+/// The report of the plan, with one result per operation in plan order
+pub async fn apply_plan<F>(
+    github_client: &GitHubClient,
+    operations: Vec<PlanOperation>,
+    concurrency: usize,
+    stop_on_error: bool,
+    mut on_result: F,
+) -> PlanReport
+where
+    F: FnMut(&PlanOperationResult),
+{
+    let failed = AtomicBool::new(false);
+    let failed = &failed;
+
+    let results: Vec<PlanOperationResult> = stream::iter(operations.into_iter().enumerate())
+        .map(|(index, operation)| async move {
+            let mut result = PlanOperationResult {
+                row: index + 1,
+                operation: operation.name().to_string(),
+                target: operation.target().to_string(),
+                status: PlanOperationStatus::Succeeded,
+                url: None,
+                message: None,
+            };
+            if stop_on_error && failed.load(Ordering::SeqCst) {
+                result.status = PlanOperationStatus::Skipped;
+                result.message = Some("Skipped after an earlier operation failed".to_string());
+                return result;
+            }
+            match apply_operation(github_client, &operation).await {
+                Ok(url) => result.url = url,
+                Err(e) => {
+                    failed.store(true, Ordering::SeqCst);
+                    result.status = PlanOperationStatus::Failed;
+                    result.message = Some(e.to_string());
+                }
+            }
+            result
+        })
+        .buffered(concurrency.max(1))
+        .inspect(|result| on_result(result))
+        .collect()
+        .await;
+
+    let count = |status: PlanOperationStatus| {
+        results
+            .iter()
+            .filter(|result| result.status == status)
+            .count()
+    };
+    PlanReport {
+        succeeded: count(PlanOperationStatus::Succeeded),
+        failed: count(PlanOperationStatus::Failed),
+        skipped: count(PlanOperationStatus::Skipped),
+        results,
+    }
+}
+
+/// Apply one operation
+///
+/// # Returns
+/// The URL of the created issue, if the operation creates one
+async fn apply_operation(
+    github_client: &GitHubClient,
+    operation: &PlanOperation,
+) -> Result<Option<String>> {
+    match operation {
+        PlanOperation::CreateIssue {
+            repository_url,
+            title,
+            body,
+            labels,
+            assignees,
+            milestone,
+        } => {
+            let repository_id = RepositoryId::parse_url(&RepositoryUrl(repository_url.clone()))
+                .map_err(|e| anyhow::anyhow!("Invalid repository URL: {}", e))?;
+            let labels: Vec<Label> = labels.iter().cloned().map(Label::from).collect();
+            let assignees: Vec<User> = assignees
+                .iter()
+                .map(|assignee| User::new(assignee.clone(), None))
+                .collect();
+            let issue = issue::create_issue(
+                github_client,
+                &repository_id,
+                title,
+                body.as_deref(),
+                (!assignees.is_empty()).then_some(assignees.as_slice()),
+                (!labels.is_empty()).then_some(labels.as_slice()),
+                milestone.map(MilestoneNumber::new),
+            )
+            .await?;
+            Ok(Some(issue.issue_id.url()))
+        }
+        PlanOperation::AddLabels { issue_url, labels } => {
+            let (repository_id, issue_number) = parse_issue_url(issue_url)?;
+            let labels: Vec<Label> = labels.iter().cloned().map(Label::from).collect();
+            issue::add_labels(github_client, &repository_id, issue_number, &labels).await?;
+            Ok(None)
+        }
+        PlanOperation::SetMilestone {
+            issue_url,
+            milestone,
+        } => {
+            let (repository_id, issue_number) = parse_issue_url(issue_url)?;
+            issue::set_milestone(
+                github_client,
+                &repository_id,
+                issue_number,
+                MilestoneNumber::new(*milestone),
+            )
+            .await?;
+            Ok(None)
+        }
+    }
+}
+
+fn parse_issue_url(issue_url: &str) -> Result<(RepositoryId, IssueNumber)> {
+    let issue_id = IssueId::parse_url(&IssueUrl(issue_url.to_string()))
+        .map_err(|e| anyhow::anyhow!("Invalid issue URL: {}", e))?;
+    Ok((issue_id.git_repository, IssueNumber::new(issue_id.number)))
+}
@@ -1,16 +1,579 @@
 use anyhow::Result;
+use futures::stream::{self, StreamExt};
 
 use crate::github::GitHubClient;
+use crate::github::cancellation;
 use crate::services::project_service::ProjectService;
-use crate::types::project::{ProjectFieldValue, ProjectId};
+use crate::tools::functions::issue::CANCELLED_BEFORE_UPDATE;
+use crate::types::issue::IssueId;
+use crate::types::project::{
+    BulkProjectItemUpdateResult, DraftIssueId, Project, ProjectCustomFieldType, ProjectDetails,
+    ProjectDraftIssue, ProjectField, ProjectFieldValue, ProjectId, ProjectItem, ProjectItemFilter,
+    ProjectOriginalResource, ProjectType, ProjectUpdate, find_project_field,
+};
+use crate::types::repository::Owner;
 use crate::types::{
     IssueNumber, ProjectFieldId, ProjectItemId, ProjectNodeId, PullRequestNumber, RepositoryId,
 };
 
+/// Number of project items updated at the same time by `bulk_update_project_field` by default
+pub const DEFAULT_BULK_PROJECT_CONCURRENCY: usize = 4;
+
+/// Create a new GitHub Project v2
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `owner` - The user or organization that will own the project
+/// * `title` - The project title
+/// * `project_type` - Whether the owner is a user or an organization
+///
+/// # Returns
+/// Returns the created project, including its number and node ID
+pub async fn create_project(
+    github_client: &GitHubClient,
+    owner: &Owner,
+    title: &str,
+    project_type: ProjectType,
+) -> Result<Project> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .create_project(owner, title, project_type)
+        .await
+}
+
+/// Copy a project as a template
+///
+/// Fields, views and workflows are copied. Issues and pull requests are not
+/// copied; draft issues are copied only when `include_draft_issues` is set.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `source_project_node_id` - The node ID of the project to copy
+/// * `owner` - The user or organization that will own the copy
+/// * `project_type` - Whether the new owner is a user or an organization
+/// * `title` - The title of the copy
+/// * `include_draft_issues` - Whether draft issues are copied as well
+///
+/// # Returns
+/// Returns the new project, including its number and node ID
+pub async fn copy_project(
+    github_client: &GitHubClient,
+    source_project_node_id: &ProjectNodeId,
+    owner: &Owner,
+    project_type: ProjectType,
+    title: &str,
+    include_draft_issues: bool,
+) -> Result<Project> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .copy_project(
+            source_project_node_id,
+            owner,
+            project_type,
+            title,
+            include_draft_issues,
+        )
+        .await
+}
+
+/// Get a project with its settings, field definitions and item count
+///
+/// Use this as a read entry point before editing a project: the result
+/// contains the project node ID and the field IDs needed by other functions.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_id` - The project identifier (owner, number and type)
+///
+/// # Returns
+/// Returns the project details, including its node ID
+pub async fn get_project(
+    github_client: &GitHubClient,
+    project_id: &ProjectId,
+) -> Result<ProjectDetails> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service.get_project(project_id).await
+}
+
+/// Update the settings of a project
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+/// * `update` - The settings to change; unset fields keep their current value
+///
+/// # Returns
+/// Returns the updated project
+pub async fn update_project(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+    update: &ProjectUpdate,
+) -> Result<Project> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .update_project(project_node_id, update)
+        .await
+}
+
+/// Delete a project
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+///
+/// # Returns
+/// Returns `Ok(())` if the project was successfully deleted
+pub async fn delete_project(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+) -> Result<()> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service.delete_project(project_node_id).await
+}
+
+/// List the field definitions of a project
+///
+/// Use this to discover field IDs, single select option IDs and iteration IDs
+/// before updating project item fields.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+///
+/// # Returns
+/// Returns all fields with their single select options and iteration configurations
+pub async fn list_project_fields(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+) -> Result<Vec<ProjectField>> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service.list_project_fields(project_node_id).await
+}
+
+/// Resolve a project field from its name
+///
+/// An exact name match wins; otherwise a unique case-insensitive match is
+/// used. Raw field IDs are accepted too.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+/// * `name_or_id` - The field name (e.g. "Status") or field ID
+///
+/// # Returns
+/// The field definition, including its data type and single select options
+///
+/// # Errors
+/// Returns an error listing the field names if no single field matches
+pub async fn resolve_project_field(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+    name_or_id: &str,
+) -> Result<ProjectField> {
+    let fields = list_project_fields(github_client, project_node_id).await?;
+    find_project_field(&fields, name_or_id)
+        .cloned()
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "No single field named '{}' found in project {}. Fields: {}",
+                name_or_id,
+                project_node_id,
+                fields
+                    .iter()
+                    .map(|field| field.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })
+}
+
+/// List the items of a project with their field values
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+///
+/// # Returns
+/// Returns all items of the project in project order, including archived items
+pub async fn list_project_items(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+) -> Result<Vec<ProjectItem>> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service.list_project_items(project_node_id).await
+}
+
+/// Link a repository to a project
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+/// * `repository_id` - The repository to link
+///
+/// # Returns
+/// Returns `Ok(())` if the repository was successfully linked
+pub async fn link_repository_to_project(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+    repository_id: &RepositoryId,
+) -> Result<()> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .link_repository_to_project(project_node_id, repository_id)
+        .await
+}
+
+/// Unlink a repository from a project
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+/// * `repository_id` - The repository to unlink
+///
+/// # Returns
+/// Returns `Ok(())` if the repository was successfully unlinked
+pub async fn unlink_repository_from_project(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+    repository_id: &RepositoryId,
+) -> Result<()> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .unlink_repository_from_project(project_node_id, repository_id)
+        .await
+}
+
+/// Link an organization team to a project
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+/// * `organization` - The organization that owns the team
+/// * `team_slug` - The team slug (e.g. "core-team")
+///
+/// # Returns
+/// Returns `Ok(())` if the team was successfully linked
+pub async fn link_team_to_project(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+    organization: &Owner,
+    team_slug: &str,
+) -> Result<()> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .link_team_to_project(project_node_id, organization, team_slug)
+        .await
+}
+
+/// Unlink an organization team from a project
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+/// * `organization` - The organization that owns the team
+/// * `team_slug` - The team slug (e.g. "core-team")
+///
+/// # Returns
+/// Returns `Ok(())` if the team was successfully unlinked
+pub async fn unlink_team_from_project(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+    organization: &Owner,
+    team_slug: &str,
+) -> Result<()> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .unlink_team_from_project(project_node_id, organization, team_slug)
+        .await
+}
+
+/// Create a custom field in a project
+///
+/// Single select fields require at least one option; options are created in
+/// the given order. Multi-select fields can not be created in Projects v2.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+/// * `name` - The field name
+/// * `data_type` - The field type
+/// * `options` - Option names for single select fields
+///
+/// # Returns
+/// Returns the created field with its ID and option IDs
+pub async fn create_project_field(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+    name: &str,
+    data_type: &ProjectCustomFieldType,
+    options: &[String],
+) -> Result<ProjectField> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .create_project_field(project_node_id, name, data_type, options)
+        .await
+}
+
+/// Rename a project field and/or replace its single select options
+///
+/// Replacing options replaces the whole option list; items that had a
+/// removed option selected lose their value.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `field_id` - The field ID (GraphQL node ID)
+/// * `name` - Optional new field name
+/// * `options` - Optional new option names for a single select field
+///
+/// # Returns
+/// Returns the updated field
+pub async fn update_project_field(
+    github_client: &GitHubClient,
+    field_id: &ProjectFieldId,
+    name: Option<&str>,
+    options: Option<&[String]>,
+) -> Result<ProjectField> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .update_project_field(field_id, name, options)
+        .await
+}
+
+/// Delete a custom field from a project
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `field_id` - The field ID (GraphQL node ID)
+///
+/// # Returns
+/// Returns `Ok(())` if the field was successfully deleted
+pub async fn delete_project_field(
+    github_client: &GitHubClient,
+    field_id: &ProjectFieldId,
+) -> Result<()> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service.delete_project_field(field_id).await
+}
+
+/// Remove an item from a project
+///
+/// The underlying issue or pull request is not affected; draft issues are deleted.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+/// * `project_item_id` - The project item ID (GraphQL node ID)
+///
+/// # Returns
+/// Returns `Ok(())` if the item was successfully removed
+pub async fn remove_item_from_project(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+    project_item_id: &ProjectItemId,
+) -> Result<()> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .remove_item_from_project(project_node_id, project_item_id)
+        .await
+}
+
+/// Archive a project item
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+/// * `project_item_id` - The project item ID (GraphQL node ID)
+///
+/// # Returns
+/// Returns `Ok(())` if the item was successfully archived
+pub async fn archive_project_item(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+    project_item_id: &ProjectItemId,
+) -> Result<()> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .archive_project_item(project_node_id, project_item_id)
+        .await
+}
+
+/// Restore an archived project item
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+/// * `project_item_id` - The project item ID (GraphQL node ID)
+///
+/// # Returns
+/// Returns `Ok(())` if the item was successfully unarchived
+pub async fn unarchive_project_item(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+    project_item_id: &ProjectItemId,
+) -> Result<()> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .unarchive_project_item(project_node_id, project_item_id)
+        .await
+}
+
+/// Move a project item within the project's manual ordering
+///
+/// Use this to prioritize a backlog: items are shown in this order by views
+/// that are not sorted by a field.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+/// * `project_item_id` - The project item ID to move
+/// * `after_item_id` - The item to place it after; `None` moves it to the top
+///
+/// # Returns
+/// Returns `Ok(())` if the item was successfully moved
+pub async fn update_project_item_position(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+    project_item_id: &ProjectItemId,
+    after_item_id: Option<&ProjectItemId>,
+) -> Result<()> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .update_project_item_position(project_node_id, project_item_id, after_item_id)
+        .await
+}
+
+/// Add a draft issue to a project
+///
+/// Draft issues let planning items be created before a repository issue exists.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+/// * `title` - The draft issue title
+/// * `body` - Optional draft issue body
+///
+/// # Returns
+/// Returns the new project item ID and draft issue ID
+pub async fn add_draft_issue_to_project(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+    title: &str,
+    body: Option<&str>,
+) -> Result<ProjectDraftIssue> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .add_draft_issue_to_project(project_node_id, title, body)
+        .await
+}
+
+/// Update the title and/or body of a draft issue
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `draft_issue_id` - The draft issue ID (not the project item ID)
+/// * `title` - Optional new title
+/// * `body` - Optional new body
+///
+/// # Returns
+/// Returns `Ok(())` if the draft issue was successfully updated
+pub async fn update_draft_issue(
+    github_client: &GitHubClient,
+    draft_issue_id: &DraftIssueId,
+    title: Option<&str>,
+    body: Option<&str>,
+) -> Result<()> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .update_draft_issue(draft_issue_id, title, body)
+        .await
+}
+
+/// Convert a draft issue project item to a repository issue
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_item_id` - The project item ID holding the draft issue
+/// * `repository_id` - The repository to create the issue in
+///
+/// # Returns
+/// Returns the identifier of the created issue
+pub async fn convert_draft_issue_to_issue(
+    github_client: &GitHubClient,
+    project_item_id: &ProjectItemId,
+    repository_id: &RepositoryId,
+) -> Result<IssueId> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .convert_draft_issue_to_issue(project_item_id, repository_id)
+        .await
+}
+
+/// Find the project item ID of an issue or pull request by URL
+///
+/// Maps an issue or pull request URL to the item that represents it in the
+/// project, so that item updates can be addressed by "project + issue URL"
+/// instead of GraphQL node IDs.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+/// * `content_url` - The issue or pull request URL
+///
+/// # Returns
+/// Returns the project item ID
+///
+/// # Errors
+/// Returns an error if the URL is invalid or the content is not in the project
+pub async fn find_project_item_for_content(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+    content_url: &str,
+) -> Result<ProjectItemId> {
+    let content = ProjectOriginalResource::parse_url(content_url).map_err(anyhow::Error::msg)?;
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .find_project_item_for_content(project_node_id, &content)
+        .await?
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "{} is not an item of project {}",
+                content_url,
+                project_node_id
+            )
+        })
+}
+
+/// Resolve a single select option name to its option ID
+///
+/// Accepts the human-readable option name (e.g. "In Progress") and looks up
+/// the option ID from the field definition. Raw option IDs are accepted too.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+/// * `project_field_id` - The single select field ID (GraphQL node ID)
+/// * `option` - The option name or option ID
+///
+/// # Returns
+/// Returns the option ID
+pub async fn resolve_single_select_option(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+    project_field_id: &ProjectFieldId,
+    option: &str,
+) -> Result<String> {
+    let project_service = ProjectService::new(github_client.clone());
+    project_service
+        .resolve_single_select_option(project_node_id, project_field_id, option)
+        .await
+}
+
 /// Update a project item field using typed field value
 ///
 /// Single method that dispatches to appropriate GitHub client method based on field value type.
 /// Updates a field value for a project item using the project node ID.
+/// Single select values may be given by option name; they are resolved to
+/// option IDs before updating.
 ///
 /// # Arguments
 /// * `github_client` - The GitHub client instance
@@ -29,8 +592,17 @@ pub async fn update_project_item_field(
     value: &ProjectFieldValue,
 ) -> Result<()> {
     let project_service = ProjectService::new(github_client.clone());
+    let value = match value {
+        ProjectFieldValue::SingleSelect(option) => {
+            let option_id = project_service
+                .resolve_single_select_option(project_node_id, project_field_id, option)
+                .await?;
+            ProjectFieldValue::SingleSelect(option_id)
+        }
+        other => other.clone(),
+    };
     project_service
-        .update_project_item_field(project_node_id, project_item_id, project_field_id, value)
+        .update_project_item_field(project_node_id, project_item_id, project_field_id, &value)
         .await
 }
 
@@ -211,6 +783,42 @@ pub async fn update_project_item_single_select_field(
         .await
 }
 
+/// Update a project item single select field by option name
+///
+/// Resolves the option name (e.g. "In Progress") to its option ID from the
+/// field definition and then performs the update.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+/// * `project_item_id` - The project item ID (GraphQL node ID)
+/// * `project_field_id` - The field ID (GraphQL node ID)
+/// * `option_name` - The name of the option to select
+///
+/// # Returns
+/// Returns the resolved option ID if the field was successfully updated
+pub async fn update_project_item_single_select_field_by_name(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+    project_item_id: &ProjectItemId,
+    project_field_id: &ProjectFieldId,
+    option_name: &str,
+) -> Result<String> {
+    let project_service = ProjectService::new(github_client.clone());
+    let option_id = project_service
+        .resolve_single_select_option(project_node_id, project_field_id, option_name)
+        .await?;
+    project_service
+        .update_project_item_single_select_field(
+            project_node_id,
+            project_item_id,
+            project_field_id,
+            &option_id,
+        )
+        .await?;
+    Ok(option_id)
+}
+
 /// Add an issue to a project
 ///
 /// Adds an existing issue to a GitHub Project v2 using the GraphQL API.
@@ -258,3 +866,83 @@ pub async fn add_pull_request_to_project(
         .add_pull_request_to_project(project_node_id, repository_id, pull_request_number)
         .await
 }
+
+/// Update one field on many project items
+///
+/// Selects the items with `filter` and applies the same value to each of
+/// them with bounded concurrency. A single select value is resolved to its
+/// option ID once before the items are updated. A failure on one item does
+/// not stop the others. Once the operation is cancelled, the items not
+/// started yet are reported as cancelled.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `project_node_id` - The project node identifier (GraphQL ID)
+/// * `project_field_id` - The field ID (GraphQL node ID) to update
+/// * `value` - The value to set on every selected item
+/// * `filter` - Explicit item IDs, or a field value the items must currently have
+/// * `concurrency` - Maximum number of items updated at once (defaults to `DEFAULT_BULK_PROJECT_CONCURRENCY`)
+///
+/// # Returns
+/// One `BulkProjectItemUpdateResult` per selected item
+///
+/// # Errors
+/// Returns an error only if the items or the single select option can not be resolved
+pub async fn bulk_update_project_field(
+    github_client: &GitHubClient,
+    project_node_id: &ProjectNodeId,
+    project_field_id: &ProjectFieldId,
+    value: &ProjectFieldValue,
+    filter: &ProjectItemFilter,
+    concurrency: Option<usize>,
+) -> Result<Vec<BulkProjectItemUpdateResult>> {
+    let project_service = ProjectService::new(github_client.clone());
+
+    let item_ids: Vec<ProjectItemId> = match filter {
+        ProjectItemFilter::ItemIds(item_ids) => item_ids.clone(),
+        ProjectItemFilter::FieldEquals { .. } => project_service
+            .list_project_items(project_node_id)
+            .await?
+            .into_iter()
+            .filter(|item| !item.archived && filter.matches(item))
+            .map(|item| item.item_id)
+            .collect(),
+    };
+
+    let value = match value {
+        ProjectFieldValue::SingleSelect(option) => {
+            let option_id = project_service
+                .resolve_single_select_option(project_node_id, project_field_id, option)
+                .await?;
+            ProjectFieldValue::SingleSelect(option_id)
+        }
+        other => other.clone(),
+    };
+
+    let concurrency = concurrency
+        .unwrap_or(DEFAULT_BULK_PROJECT_CONCURRENCY)
+        .max(1);
+    let project_service = &project_service;
+    let value = &value;
+
+    let results = stream::iter(item_ids)
+        .map(|item_id| async move {
+            if cancellation::is_cancelled() {
+                return BulkProjectItemUpdateResult {
+                    item_id,
+                    failure: Some(CANCELLED_BEFORE_UPDATE.to_string()),
+                };
+            }
+            let failure = project_service
+                .update_project_item_field(project_node_id, &item_id, project_field_id, value)
+                .await
+                .err()
+                .map(|e| e.to_string());
+            BulkProjectItemUpdateResult { item_id, failure }
+        })
+        .buffered(concurrency)
+        .collect()
+        .await;
+
+    Ok(results)
+}
@@ -1,11 +1,15 @@
 use crate::github::GitHubClient;
 use crate::services::pull_request_service::PullRequestService;
+use crate::tools::functions::issue::read_concurrently;
 use crate::types::label::Label;
+use crate::types::pagination::{Page, Pagination};
 use crate::types::pull_request::{
-    Branch, PullRequest, PullRequestCommentNumber, PullRequestNumber,
+    Branch, PullRequest, PullRequestComment, PullRequestCommentNumber, PullRequestDetails,
+    PullRequestHead, PullRequestId, PullRequestMergeStatus, PullRequestNumber,
 };
 use crate::types::repository::{MilestoneNumber, RepositoryId};
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 
 /// Create a new pull request
 ///
@@ -38,6 +42,141 @@ pub async fn create_pull_request(
         .await
 }
 
+/// Get a pull request with at most `comment_limit` of its discussion
+/// comments, oldest first
+///
+/// One comment more than the limit is fetched to tell whether the pull
+/// request has further comments.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `pr_number` - The pull request number to fetch
+/// * `comment_limit` - Maximum number of comments to return
+pub async fn get_pull_request_details(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    pr_number: PullRequestNumber,
+    comment_limit: usize,
+) -> Result<PullRequestDetails> {
+    let pr_service = PullRequestService::new(github_client.clone());
+    let mut pull_request = pr_service
+        .get_pull_request_with_comment_limit(repository_id, pr_number, comment_limit + 1)
+        .await?;
+    let has_more_comments = pull_request.comments.len() > comment_limit;
+    pull_request.comments.truncate(comment_limit);
+    Ok(PullRequestDetails {
+        pull_request,
+        has_more_comments,
+    })
+}
+
+/// Get several pull requests, each with at most `comment_limit` of its
+/// discussion comments
+///
+/// Reads the pull requests with at most `concurrency` requests in flight at
+/// once. A failure on one pull request never aborts the others; once the
+/// operation is cancelled, the pull requests not read yet fail with
+/// `CANCELLED_BEFORE_READ`.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `pull_request_ids` - The pull requests to fetch, possibly from different repositories
+/// * `comment_limit` - Maximum number of comments to return per pull request
+/// * `concurrency` - Maximum number of pull requests read at once (defaults to `DEFAULT_DETAILS_CONCURRENCY`)
+///
+/// # Returns
+/// One result per pull request, in the order of `pull_request_ids`
+pub async fn get_pull_requests_with_details(
+    github_client: &GitHubClient,
+    pull_request_ids: &[PullRequestId],
+    comment_limit: usize,
+    concurrency: Option<usize>,
+) -> Vec<Result<PullRequestDetails>> {
+    read_concurrently(pull_request_ids, concurrency, |pull_request_id| {
+        get_pull_request_details(
+            github_client,
+            &pull_request_id.git_repository,
+            PullRequestNumber::new(pull_request_id.number),
+            comment_limit,
+        )
+    })
+    .await
+}
+
+/// Get the head branch of a pull request
+///
+/// Returns the branch with the repository it lives in, a fork for pull
+/// requests from forks, and the URLs to fetch it from.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `pr_number` - The pull request number
+///
+/// # Returns
+/// The head branch of the pull request
+pub async fn get_head(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    pr_number: PullRequestNumber,
+) -> Result<PullRequestHead> {
+    let pr_service = PullRequestService::new(github_client.clone());
+    pr_service.get_head(repository_id, pr_number).await
+}
+
+/// Get the merge status of a pull request
+///
+/// Reports whether the pull request can be merged and lists every blocker:
+/// draft state, conflicts, outdated head branch, missing reviews and failing,
+/// pending or missing required checks.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `pr_number` - The pull request number to inspect
+///
+/// # Returns
+/// The aggregated merge status of the pull request
+pub async fn get_merge_status(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    pr_number: PullRequestNumber,
+) -> Result<PullRequestMergeStatus> {
+    let pr_service = PullRequestService::new(github_client.clone());
+    pr_service.get_merge_status(repository_id, pr_number).await
+}
+
+/// List discussion comments on a pull request
+///
+/// Fetches one page of general discussion comments (not code review
+/// comments), oldest first. Use `since` to fetch only comments updated after
+/// the last read.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `pr_number` - The pull request number
+/// * `since` - Only comments updated at or after this time
+/// * `author` - Only comments written by this login (case-insensitive)
+/// * `pagination` - The page of comments to fetch
+///
+/// # Returns
+/// A page of pull request discussion comments
+pub async fn list_comments(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    pr_number: PullRequestNumber,
+    since: Option<DateTime<Utc>>,
+    author: Option<&str>,
+    pagination: Pagination,
+) -> Result<Page<PullRequestComment>> {
+    let pr_service = PullRequestService::new(github_client.clone());
+    pr_service
+        .list_comments(repository_id, pr_number, since, author, pagination)
+        .await
+}
+
 /// Add a comment to a pull request
 ///
 /// Creates a new comment on the specified pull request.
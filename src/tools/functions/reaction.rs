@@ -0,0 +1,75 @@
+use anyhow::Result;
+
+use crate::github::GitHubClient;
+use crate::services::reaction_service::ReactionService;
+use crate::types::reaction::{Reaction, ReactionKind, ReactionTarget};
+use crate::types::repository::RepositoryId;
+
+/// Add a reaction to an issue, pull request, or comment
+///
+/// Adds an emoji reaction as the authenticated user. Useful for acknowledging
+/// a comment without posting a new one.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `target` - The issue, pull request, or comment to react to
+/// * `kind` - The reaction kind to add
+///
+/// # Returns
+/// The created (or already existing) reaction
+pub async fn add_reaction(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    target: ReactionTarget,
+    kind: ReactionKind,
+) -> Result<Reaction> {
+    let reaction_service = ReactionService::new(github_client.clone());
+    reaction_service
+        .add_reaction(repository_id, target, kind)
+        .await
+}
+
+/// List reactions on an issue, pull request, or comment
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `target` - The issue, pull request, or comment to list reactions for
+/// * `kind` - Optional reaction kind to filter by
+///
+/// # Returns
+/// All reactions on the target, optionally filtered by kind
+pub async fn list_reactions(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    target: ReactionTarget,
+    kind: Option<ReactionKind>,
+) -> Result<Vec<Reaction>> {
+    let reaction_service = ReactionService::new(github_client.clone());
+    reaction_service
+        .list_reactions(repository_id, target, kind)
+        .await
+}
+
+/// Remove the authenticated user's reaction from an issue, pull request, or comment
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `target` - The issue, pull request, or comment to remove the reaction from
+/// * `kind` - The reaction kind to remove
+///
+/// # Returns
+/// `true` if a reaction was removed, `false` if there was nothing to remove
+pub async fn remove_reaction(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    target: ReactionTarget,
+    kind: ReactionKind,
+) -> Result<bool> {
+    let reaction_service = ReactionService::new(github_client.clone());
+    reaction_service
+        .remove_reaction(repository_id, target, kind)
+        .await
+}
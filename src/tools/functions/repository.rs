@@ -1,11 +1,42 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use std::time::Duration;
 
 use crate::github::GitHubClient;
+use crate::github::error::{ErrorKind, GitHubError};
 use crate::services::repository_service::RepositoryService;
+use crate::types::branch::RepositoryBranch;
+use crate::types::commit::{Commit, CommitFilter};
+use crate::types::content::{FileCommit, FileContent};
+use crate::types::fork::{ForkSyncResult, RepositoryFork};
 use crate::types::label::Label;
-use crate::types::milestone::{Milestone, MilestoneState};
-use crate::types::repository::{MilestoneNumber, RepositoryId};
+use crate::types::metadata_copy::{
+    MetadataConflictStrategy, MetadataCopyAction, MetadataCopyEntry, MetadataCopyOptions,
+    MetadataCopyResult,
+};
+use crate::types::milestone::{Milestone, MilestoneState, find_milestone_by_title};
+use crate::types::pagination::{MAX_PER_PAGE, Page, Pagination};
+use crate::types::release::{GeneratedReleaseNotes, Release, ReleaseId, ReleaseUpdate};
+use crate::types::repository::{MilestoneNumber, Repository, RepositoryId, RepositorySettings};
+use crate::types::transfer::{RepositoryTransfer, RepositoryTransferStatus};
+
+/// Get an overview of a repository
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+///
+/// # Returns
+/// The repository with its description, default branch, visibility, topics,
+/// open issue and pull request counts, license and the permission of the
+/// authenticated user
+pub async fn get_repository(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+) -> Result<Repository> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service.get_repository(repository_id).await
+}
 
 /// Create a new label in a repository
 ///
@@ -87,6 +118,29 @@ pub async fn delete_label(
         .await
 }
 
+/// List the labels of a repository
+///
+/// Use this to check which labels exist before adding labels to issues or
+/// pull requests.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `pagination` - The page of labels to fetch
+///
+/// # Returns
+/// A page of labels with name, color and description
+pub async fn list_labels(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    pagination: Pagination,
+) -> Result<Page<Label>> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .list_labels(repository_id, pagination)
+        .await
+}
+
 /// Create a new milestone in a repository
 ///
 /// Creates a new milestone in the specified repository with the provided
@@ -175,3 +229,823 @@ pub async fn delete_milestone(
         .delete_milestone(repository_id, milestone_number)
         .await
 }
+
+/// List the branches of a repository
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `pagination` - The page of branches to fetch
+///
+/// # Returns
+/// A page of branches with the commit SHA each branch points to
+pub async fn list_branches(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    pagination: Pagination,
+) -> Result<Page<RepositoryBranch>> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .list_branches(repository_id, pagination)
+        .await
+}
+
+/// Create a branch from a branch name, tag or commit SHA
+///
+/// Use this before creating a pull request from a new branch.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `name` - The name of the new branch
+/// * `from_ref` - The branch, tag or commit SHA to branch from
+///
+/// # Returns
+/// The created branch
+pub async fn create_branch(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    name: &str,
+    from_ref: &str,
+) -> Result<RepositoryBranch> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .create_branch(repository_id, name, from_ref)
+        .await
+}
+
+/// Delete a branch from a repository
+///
+/// Deletes the branch ref from the repository. This action cannot be undone.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `name` - The name of the branch to delete
+///
+/// # Returns
+/// Success or error result
+pub async fn delete_branch(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    name: &str,
+) -> Result<()> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service.delete_branch(repository_id, name).await
+}
+
+/// Rename a branch
+///
+/// Open pull requests and branch protection rules targeting the branch are
+/// updated by GitHub.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `name` - The current branch name
+/// * `new_name` - The new branch name
+///
+/// # Returns
+/// The renamed branch
+pub async fn rename_branch(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    name: &str,
+    new_name: &str,
+) -> Result<RepositoryBranch> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .rename_branch(repository_id, name, new_name)
+        .await
+}
+
+/// Get a single commit with its message, author, stats and changed files
+///
+/// Use this to reference a specific commit in review comments or changelogs.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `git_ref` - Commit SHA, branch or tag to resolve to a commit
+///
+/// # Returns
+/// The commit including its stats and changed files
+pub async fn get_commit(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    git_ref: &str,
+) -> Result<Commit> {
+    let git_ref = git_ref.trim();
+    if git_ref.is_empty() {
+        anyhow::bail!("Commit SHA or ref must not be empty");
+    }
+
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service.get_commit(repository_id, git_ref).await
+}
+
+/// List the commits of a repository, newest first
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `filter` - Starting ref, path and time range to restrict the commits to
+/// * `pagination` - The page of commits to fetch
+///
+/// # Returns
+/// A page of commits without stats or changed files
+pub async fn list_commits(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    filter: &CommitFilter,
+    pagination: Pagination,
+) -> Result<Page<Commit>> {
+    if let (Some(since), Some(until)) = (filter.since, filter.until) {
+        if since > until {
+            anyhow::bail!("'since' must not be later than 'until'");
+        }
+    }
+
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .list_commits(repository_id, filter, pagination)
+        .await
+}
+
+/// Get the contents of a file in a repository
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `path` - The file path relative to the repository root
+/// * `git_ref` - Optional branch, tag or commit SHA (defaults to the default branch)
+///
+/// # Returns
+/// The decoded file contents and the blob SHA needed to update or delete the file
+pub async fn get_file_content(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    path: &str,
+    git_ref: Option<&str>,
+) -> Result<FileContent> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .get_file_content(repository_id, path, git_ref)
+        .await
+}
+
+/// Create a file or update an existing one in a single commit
+///
+/// Updating requires the blob SHA of the current file, as returned by
+/// `get_file_content`, so concurrent edits are rejected instead of lost.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `path` - The file path relative to the repository root
+/// * `content` - The new file contents
+/// * `message` - The commit message
+/// * `branch` - Optional branch to commit to (defaults to the default branch)
+/// * `sha` - Blob SHA of the file being replaced; required when updating
+///
+/// # Returns
+/// The commit that wrote the file
+pub async fn create_or_update_file(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    path: &str,
+    content: &[u8],
+    message: &str,
+    branch: Option<&str>,
+    sha: Option<&str>,
+) -> Result<FileCommit> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .create_or_update_file(repository_id, path, content, message, branch, sha)
+        .await
+}
+
+/// Delete a file in a single commit
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `path` - The file path relative to the repository root
+/// * `message` - The commit message
+/// * `sha` - Blob SHA of the file being deleted
+/// * `branch` - Optional branch to commit to (defaults to the default branch)
+///
+/// # Returns
+/// The commit that deleted the file
+pub async fn delete_file(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    path: &str,
+    message: &str,
+    sha: &str,
+    branch: Option<&str>,
+) -> Result<FileCommit> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .delete_file(repository_id, path, message, sha, branch)
+        .await
+}
+
+/// Create a release
+///
+/// The tag is created from `target_commitish` if it does not exist yet.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `tag_name` - The tag of the release (e.g. "v1.0.0")
+/// * `name` - Optional release title (defaults to the tag name)
+/// * `body` - Optional release notes in Markdown
+/// * `draft` - Whether the release is created as an unpublished draft
+/// * `prerelease` - Whether the release is marked as a prerelease
+/// * `target_commitish` - Optional branch or commit SHA for a new tag
+///
+/// # Returns
+/// The created release
+#[allow(clippy::too_many_arguments)]
+pub async fn create_release(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    tag_name: &str,
+    name: Option<&str>,
+    body: Option<&str>,
+    draft: bool,
+    prerelease: bool,
+    target_commitish: Option<&str>,
+) -> Result<Release> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .create_release(
+            repository_id,
+            tag_name,
+            name,
+            body,
+            draft,
+            prerelease,
+            target_commitish,
+        )
+        .await
+}
+
+/// Get a published release by its tag
+///
+/// Draft releases have no tag yet and can only be addressed by ID.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `tag_name` - The tag of the release
+///
+/// # Returns
+/// The release for the tag
+pub async fn get_release_by_tag(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    tag_name: &str,
+) -> Result<Release> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .get_release_by_tag(repository_id, tag_name)
+        .await
+}
+
+/// Update a release
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `release_id` - The release identifier
+/// * `update` - The settings to change; unset fields keep their current value
+///
+/// # Returns
+/// The updated release
+pub async fn update_release(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    release_id: ReleaseId,
+    update: &ReleaseUpdate,
+) -> Result<Release> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .update_release(repository_id, release_id, update)
+        .await
+}
+
+/// Publish a draft release
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `release_id` - The release identifier of the draft
+///
+/// # Returns
+/// The published release
+pub async fn publish_release(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    release_id: ReleaseId,
+) -> Result<Release> {
+    let update = ReleaseUpdate {
+        draft: Some(false),
+        ..Default::default()
+    };
+    update_release(github_client, repository_id, release_id, &update).await
+}
+
+/// Delete a release
+///
+/// The tag of the release is kept. This action cannot be undone.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `release_id` - The release identifier
+///
+/// # Returns
+/// Success or error result
+pub async fn delete_release(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    release_id: ReleaseId,
+) -> Result<()> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .delete_release(repository_id, release_id)
+        .await
+}
+
+/// Generate release notes from the pull requests merged since the previous release
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `tag_name` - The tag of the release the notes are for
+/// * `previous_tag_name` - Optional tag to start from (defaults to the latest release)
+/// * `target_commitish` - Optional branch or commit SHA if the tag does not exist yet
+///
+/// # Returns
+/// The generated release title and Markdown body
+pub async fn generate_release_notes(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    tag_name: &str,
+    previous_tag_name: Option<&str>,
+    target_commitish: Option<&str>,
+) -> Result<GeneratedReleaseNotes> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .generate_release_notes(repository_id, tag_name, previous_tag_name, target_commitish)
+        .await
+}
+
+/// List the milestones of a repository
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `state` - Optional state filter (`None` lists open and closed milestones)
+///
+/// # Returns
+/// All matching milestones ordered by due date
+pub async fn list_milestones(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    state: Option<MilestoneState>,
+) -> Result<Vec<Milestone>> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .list_milestones(repository_id, state)
+        .await
+}
+
+/// Resolve a milestone number from the milestone title
+///
+/// Open and closed milestones are searched. An exact title match wins;
+/// otherwise a unique case-insensitive match is used.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `title` - The milestone title
+///
+/// # Returns
+/// The number of the matching milestone
+///
+/// # Errors
+/// Returns an error if no milestone or more than one milestone matches the title
+pub async fn resolve_milestone_by_title(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    title: &str,
+) -> Result<MilestoneNumber> {
+    let milestones = list_milestones(github_client, repository_id, None).await?;
+    find_milestone_by_title(&milestones, title)
+        .map(|milestone| milestone.id)
+        .ok_or_else(|| {
+            GitHubError::new(
+                ErrorKind::NotFound,
+                format!(
+                    "No single milestone titled '{}' found in {}",
+                    title, repository_id
+                ),
+            )
+            .into()
+        })
+}
+
+/// Resolve the milestone number from either a number or a title
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `milestone_number` - The milestone number, if known
+/// * `title` - The milestone title
+///
+/// # Returns
+/// The milestone number
+///
+/// # Errors
+/// Returns an error unless exactly one of `milestone_number` and `title` is
+/// given, or if the title does not identify a milestone
+pub async fn resolve_milestone(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    milestone_number: Option<MilestoneNumber>,
+    title: Option<&str>,
+) -> Result<MilestoneNumber> {
+    match (milestone_number, title) {
+        (Some(milestone_number), None) => Ok(milestone_number),
+        (None, Some(title)) => {
+            resolve_milestone_by_title(github_client, repository_id, title).await
+        }
+        _ => Err(anyhow::anyhow!(
+            "Exactly one of milestone_number and milestone_title must be given"
+        )),
+    }
+}
+
+/// Resolve the release identifier from either an ID or a tag
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `release_id` - The release identifier, if known
+/// * `tag_name` - The tag of a published release
+///
+/// # Returns
+/// The release identifier
+///
+/// # Errors
+/// Returns an error unless exactly one of `release_id` and `tag_name` is given
+pub async fn resolve_release_id(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    release_id: Option<ReleaseId>,
+    tag_name: Option<&str>,
+) -> Result<ReleaseId> {
+    match (release_id, tag_name) {
+        (Some(release_id), None) => Ok(release_id),
+        (None, Some(tag_name)) => Ok(get_release_by_tag(github_client, repository_id, tag_name)
+            .await?
+            .id),
+        _ => Err(anyhow::anyhow!(
+            "Exactly one of release_id and tag must be given"
+        )),
+    }
+}
+
+/// Fork a repository
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository to fork
+/// * `organization` - Optional organization to create the fork in (defaults to the authenticated user)
+/// * `default_branch_only` - Whether to copy only the default branch
+///
+/// # Returns
+/// The created fork. Its contents may take a short while to become available.
+pub async fn fork_repository(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    organization: Option<&str>,
+    default_branch_only: bool,
+) -> Result<RepositoryFork> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .fork_repository(repository_id, organization, default_branch_only)
+        .await
+}
+
+/// Sync a branch of a fork with its upstream repository
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The fork to update
+/// * `branch` - Optional branch to sync (defaults to the default branch of the fork)
+///
+/// # Returns
+/// Whether the branch was fast-forwarded, merged or already up to date
+pub async fn sync_fork_with_upstream(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    branch: Option<&str>,
+) -> Result<ForkSyncResult> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .sync_fork_with_upstream(repository_id, branch)
+        .await
+}
+
+/// Update the settings of a repository
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `settings` - The settings to change; unset fields keep their current value
+///
+/// # Returns
+/// The resulting settings of the repository
+pub async fn update_repository_settings(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    settings: &RepositorySettings,
+) -> Result<RepositorySettings> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .update_repository_settings(repository_id, settings)
+        .await
+}
+
+/// Archive a repository
+///
+/// The repository becomes read-only until it is unarchived.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+///
+/// # Returns
+/// Success or error result
+pub async fn archive_repository(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+) -> Result<()> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service.archive_repository(repository_id).await
+}
+
+/// Unarchive a repository
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+///
+/// # Returns
+/// Success or error result
+pub async fn unarchive_repository(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+) -> Result<()> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service.unarchive_repository(repository_id).await
+}
+
+/// Transfer a repository to another user or organization
+///
+/// After the transfer request is accepted, waits up to `wait` for the
+/// repository to become available under the new owner. Transfers to a user
+/// stay pending until the user accepts them.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository to transfer
+/// * `new_owner` - The user or organization receiving the repository
+/// * `new_name` - Optional new name of the repository
+/// * `team_ids` - Teams of the new organization to grant access
+/// * `wait` - How long to wait for the transfer to complete (`None` returns immediately)
+///
+/// # Returns
+/// The transfer with its last observed status
+pub async fn transfer_repository(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    new_owner: &str,
+    new_name: Option<&str>,
+    team_ids: &[u64],
+    wait: Option<Duration>,
+) -> Result<RepositoryTransfer> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    let mut transfer = repository_service
+        .transfer_repository(repository_id, new_owner, new_name, team_ids)
+        .await?;
+
+    if let Some(wait) = wait {
+        transfer.status = repository_service
+            .wait_for_repository_transfer(&transfer.repository_id, wait)
+            .await?;
+    }
+
+    Ok(transfer)
+}
+
+/// Get the status of a repository transfer
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `target_repository_id` - The repository under its new owner
+///
+/// # Returns
+/// `Completed` once the repository is available under the new owner
+pub async fn get_repository_transfer_status(
+    github_client: &GitHubClient,
+    target_repository_id: &RepositoryId,
+) -> Result<RepositoryTransferStatus> {
+    let repository_service = RepositoryService::new(github_client.clone());
+    repository_service
+        .get_repository_transfer_status(target_repository_id)
+        .await
+}
+
+/// Copy labels and milestones from one repository to another
+///
+/// Items missing from the target repository are created. Items that already
+/// exist are handled according to `options.on_conflict`. A failure on one
+/// item never aborts the others; every failure is reported in the result.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `source_repository_id` - The repository to read labels and milestones from
+/// * `target_repository_id` - The repository to create them in
+/// * `options` - Which metadata to copy and how to handle collisions
+///
+/// # Returns
+/// The outcome for every label and milestone of the source repository
+///
+/// # Errors
+/// Returns an error if neither labels nor milestones are selected, if source
+/// and target are the same repository, or if the labels or milestones of
+/// either repository can not be listed
+pub async fn copy_repository_metadata(
+    github_client: &GitHubClient,
+    source_repository_id: &RepositoryId,
+    target_repository_id: &RepositoryId,
+    options: &MetadataCopyOptions,
+) -> Result<MetadataCopyResult> {
+    if !options.labels && !options.milestones {
+        return Err(anyhow::anyhow!(
+            "Nothing to copy: select labels, milestones or both"
+        ));
+    }
+    if source_repository_id == target_repository_id {
+        return Err(anyhow::anyhow!("Source and target repository must differ"));
+    }
+
+    let mut result = MetadataCopyResult::default();
+
+    if options.labels {
+        let source_labels = list_all_labels(github_client, source_repository_id).await?;
+        let target_labels = list_all_labels(github_client, target_repository_id).await?;
+
+        for label in source_labels {
+            let existing = target_labels
+                .iter()
+                .find(|target| target.name.eq_ignore_ascii_case(&label.name));
+            let entry = copy_label(
+                github_client,
+                target_repository_id,
+                &label,
+                existing,
+                options.on_conflict,
+            )
+            .await;
+            result.labels.push(entry);
+        }
+    }
+
+    if options.milestones {
+        let source_milestones = list_milestones(github_client, source_repository_id, None).await?;
+        let target_milestones = list_milestones(github_client, target_repository_id, None).await?;
+
+        for milestone in source_milestones {
+            let existing = target_milestones
+                .iter()
+                .find(|target| target.title == milestone.title);
+            let entry = copy_milestone(
+                github_client,
+                target_repository_id,
+                &milestone,
+                existing,
+                options.on_conflict,
+            )
+            .await;
+            result.milestones.push(entry);
+        }
+    }
+
+    Ok(result)
+}
+
+async fn list_all_labels(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+) -> Result<Vec<Label>> {
+    let mut labels = Vec::new();
+    let mut pagination = Pagination::new(None, Some(MAX_PER_PAGE));
+    loop {
+        let page = list_labels(github_client, repository_id, pagination).await?;
+        labels.extend(page.items);
+        if !page.has_next_page {
+            return Ok(labels);
+        }
+        pagination = pagination.next();
+    }
+}
+
+async fn copy_label(
+    github_client: &GitHubClient,
+    target_repository_id: &RepositoryId,
+    label: &Label,
+    existing: Option<&Label>,
+    on_conflict: MetadataConflictStrategy,
+) -> MetadataCopyEntry {
+    let action = on_conflict.action_for(existing.is_some());
+    let outcome = match (action, existing) {
+        (MetadataCopyAction::Created, _) => create_label(
+            github_client,
+            target_repository_id,
+            &label.name,
+            label.color.as_deref(),
+            label.description.as_deref(),
+        )
+        .await
+        .map(|_| ()),
+        (MetadataCopyAction::Updated, Some(existing)) => update_label(
+            github_client,
+            target_repository_id,
+            &existing.name,
+            Some(&label.name),
+            label.color.as_deref(),
+            label.description.as_deref(),
+        )
+        .await
+        .map(|_| ()),
+        (MetadataCopyAction::Failed, _) => Err(anyhow::anyhow!(
+            "Label already exists in {}",
+            target_repository_id
+        )),
+        _ => Ok(()),
+    };
+
+    match outcome {
+        Ok(()) => MetadataCopyEntry::new(label.name.clone(), action),
+        Err(e) => MetadataCopyEntry::failed(label.name.clone(), e.to_string()),
+    }
+}
+
+async fn copy_milestone(
+    github_client: &GitHubClient,
+    target_repository_id: &RepositoryId,
+    milestone: &Milestone,
+    existing: Option<&Milestone>,
+    on_conflict: MetadataConflictStrategy,
+) -> MetadataCopyEntry {
+    let action = on_conflict.action_for(existing.is_some());
+    let outcome = match (action, existing) {
+        (MetadataCopyAction::Created, _) => create_milestone(
+            github_client,
+            target_repository_id,
+            &milestone.title,
+            milestone.description.as_deref(),
+            milestone.due_on,
+            Some(milestone.state),
+        )
+        .await
+        .map(|_| ()),
+        (MetadataCopyAction::Updated, Some(existing)) => update_milestone(
+            github_client,
+            target_repository_id,
+            &existing.id,
+            None,
+            milestone.description.as_deref(),
+            milestone.due_on,
+            Some(milestone.state),
+        )
+        .await
+        .map(|_| ()),
+        (MetadataCopyAction::Failed, _) => Err(anyhow::anyhow!(
+            "Milestone already exists in {}",
+            target_repository_id
+        )),
+        _ => Ok(()),
+    };
+
+    match outcome {
+        Ok(()) => MetadataCopyEntry::new(milestone.title.clone(), action),
+        Err(e) => MetadataCopyEntry::failed(milestone.title.clone(), e.to_string()),
+    }
+}
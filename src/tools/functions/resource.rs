@@ -0,0 +1,48 @@
+use anyhow::Result;
+
+use crate::github::GitHubClient;
+use crate::services::pull_request_service::PullRequestService;
+use crate::types::issue::IssueNumber;
+use crate::types::pull_request::PullRequestNumber;
+use crate::types::resource::{ResourceSnapshot, ResourceUri};
+
+/// Read the issue or pull request addressed by a resource URI
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `uri` - The issue or pull request resource
+///
+/// # Returns
+/// The issue or pull request serialized as JSON, with its title and last
+/// update time
+pub async fn read_resource(
+    github_client: &GitHubClient,
+    uri: &ResourceUri,
+) -> Result<ResourceSnapshot> {
+    match uri {
+        ResourceUri::Issue(issue_id) => {
+            let issue = github_client
+                .get_issue(&issue_id.git_repository, IssueNumber::new(issue_id.number))
+                .await?;
+            Ok(ResourceSnapshot {
+                title: issue.title.clone(),
+                updated_at: issue.updated_at,
+                text: serde_json::to_string_pretty(&issue)?,
+            })
+        }
+        ResourceUri::PullRequest(pull_request_id) => {
+            let pr_service = PullRequestService::new(github_client.clone());
+            let pull_request = pr_service
+                .get_pull_request(
+                    &pull_request_id.git_repository,
+                    PullRequestNumber::new(pull_request_id.number),
+                )
+                .await?;
+            Ok(ResourceSnapshot {
+                title: pull_request.title.clone(),
+                updated_at: pull_request.updated_at,
+                text: serde_json::to_string_pretty(&pull_request)?,
+            })
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use anyhow::Result;
+use std::collections::BTreeMap;
+
+use crate::config::{Config, validate_search_alias_name};
+use crate::github::GitHubClient;
+use crate::services::search_service::SearchService;
+use crate::types::pagination::Pagination;
+use crate::types::search::{
+    IssueSearchResult, IssueSearchSort, RepositorySearchResult, RepositorySearchSort, SearchOrder,
+    SearchResults, UserSearchResult, UserSearchSort,
+};
+
+/// Search issues and pull requests across GitHub
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `query` - Search query using GitHub's search syntax (e.g. "repo:owner/repo label:bug is:open");
+///   `@name` references to saved aliases are expanded
+/// * `sort` - Optional field to sort by; results are sorted by best match when omitted
+/// * `order` - Optional sort order, descending by default
+/// * `pagination` - The page of results to fetch
+///
+/// # Errors
+/// Returns an error if the query is empty, uses an unknown alias or the search fails
+pub async fn search_issues_and_prs(
+    github_client: &GitHubClient,
+    query: &str,
+    sort: Option<IssueSearchSort>,
+    order: Option<SearchOrder>,
+    pagination: Pagination,
+) -> Result<SearchResults<IssueSearchResult>> {
+    let query = prepare_query(query)?;
+
+    let search_service = SearchService::new(github_client.clone());
+    search_service
+        .search_issues_and_prs(&query, sort, order, pagination)
+        .await
+}
+
+/// Search repositories across GitHub
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `query` - Search query using GitHub's search syntax (e.g. "edit in:name user:tacogips");
+///   `@name` references to saved aliases are expanded
+/// * `sort` - Optional field to sort by; results are sorted by best match when omitted
+/// * `order` - Optional sort order, descending by default
+/// * `pagination` - The page of results to fetch
+///
+/// # Errors
+/// Returns an error if the query is empty, uses an unknown alias or the search fails
+pub async fn search_repositories(
+    github_client: &GitHubClient,
+    query: &str,
+    sort: Option<RepositorySearchSort>,
+    order: Option<SearchOrder>,
+    pagination: Pagination,
+) -> Result<SearchResults<RepositorySearchResult>> {
+    let query = prepare_query(query)?;
+
+    let search_service = SearchService::new(github_client.clone());
+    search_service
+        .search_repositories(&query, sort, order, pagination)
+        .await
+}
+
+/// Search users and organizations across GitHub
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `query` - Search query using GitHub's search syntax (e.g. "tacogips type:user");
+///   `@name` references to saved aliases are expanded
+/// * `sort` - Optional field to sort by; results are sorted by best match when omitted
+/// * `order` - Optional sort order, descending by default
+/// * `pagination` - The page of results to fetch
+///
+/// # Errors
+/// Returns an error if the query is empty, uses an unknown alias or the search fails
+pub async fn search_users(
+    github_client: &GitHubClient,
+    query: &str,
+    sort: Option<UserSearchSort>,
+    order: Option<SearchOrder>,
+    pagination: Pagination,
+) -> Result<SearchResults<UserSearchResult>> {
+    let query = prepare_query(query)?;
+
+    let search_service = SearchService::new(github_client.clone());
+    search_service
+        .search_users(&query, sort, order, pagination)
+        .await
+}
+
+/// List the saved search aliases by name
+///
+/// # Errors
+/// Returns an error if the configuration file cannot be read
+pub fn list_search_aliases() -> Result<BTreeMap<String, String>> {
+    Ok(Config::load()?.search.aliases)
+}
+
+/// Save a search query under an alias, replacing any query saved under it
+///
+/// # Arguments
+/// * `name` - The alias name, referenced in queries as `@name`
+/// * `query` - The search query to save
+///
+/// # Returns
+/// The query previously saved under the alias, if any
+///
+/// # Errors
+/// Returns an error if the name is invalid, the query is empty or the
+/// configuration file cannot be written
+pub fn add_search_alias(name: &str, query: &str) -> Result<Option<String>> {
+    validate_search_alias_name(name).map_err(|e| anyhow::anyhow!(e))?;
+    let query = query.trim();
+    if query.is_empty() {
+        anyhow::bail!("Search query must not be empty");
+    }
+
+    let mut config = Config::load()?;
+    let previous = config
+        .search
+        .aliases
+        .insert(name.to_string(), query.to_string());
+    config.save()?;
+    Ok(previous)
+}
+
+/// Remove a saved search alias
+///
+/// # Arguments
+/// * `name` - The alias name
+///
+/// # Returns
+/// The query that was saved under the alias
+///
+/// # Errors
+/// Returns an error if the alias does not exist or the configuration file
+/// cannot be written
+pub fn remove_search_alias(name: &str) -> Result<String> {
+    let mut config = Config::load()?;
+    let query = config
+        .search
+        .aliases
+        .remove(name)
+        .ok_or_else(|| anyhow::anyhow!("Search alias '{}' does not exist", name))?;
+    config.save()?;
+    Ok(query)
+}
+
+/// Expand saved aliases in a query and check that it is not empty
+fn prepare_query(query: &str) -> Result<String> {
+    let query = query.trim();
+    if query.is_empty() {
+        anyhow::bail!("Search query must not be empty");
+    }
+    Config::load()?
+        .search
+        .expand_query(query)
+        .map_err(|e| anyhow::anyhow!(e))
+}
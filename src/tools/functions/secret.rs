@@ -0,0 +1,171 @@
+use anyhow::Result;
+
+use crate::github::GitHubClient;
+use crate::services::secret_service::SecretService;
+use crate::types::pagination::{MAX_PER_PAGE, Page, Pagination};
+use crate::types::repository::RepositoryId;
+use crate::types::secret::{ActionsSecret, ActionsVariable, validate_actions_name};
+
+/// Create or update a repository secret
+///
+/// The value is encrypted with the repository public key before it is sent.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `name` - The secret name
+/// * `value` - The plain-text secret value
+///
+/// # Errors
+/// Returns an error if the name is not a valid secret name or the secret
+/// cannot be stored.
+pub async fn set_repo_secret(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    name: &str,
+    value: &str,
+) -> Result<()> {
+    validate_actions_name(name).map_err(|e| anyhow::anyhow!(e))?;
+
+    let secret_service = SecretService::new(github_client.clone());
+    secret_service
+        .set_repo_secret(repository_id, name, value)
+        .await
+}
+
+/// Delete a repository secret
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `name` - The secret name
+pub async fn delete_repo_secret(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    name: &str,
+) -> Result<()> {
+    let secret_service = SecretService::new(github_client.clone());
+    secret_service.delete_repo_secret(repository_id, name).await
+}
+
+/// List the secrets of a repository
+///
+/// Secret values cannot be read back, so only names and timestamps are
+/// returned.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `pagination` - The page of secrets to fetch
+pub async fn list_secrets(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    pagination: Pagination,
+) -> Result<Page<ActionsSecret>> {
+    let secret_service = SecretService::new(github_client.clone());
+    secret_service.list_secrets(repository_id, pagination).await
+}
+
+/// List the configuration variables of a repository
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `pagination` - The page of variables to fetch
+pub async fn list_variables(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    pagination: Pagination,
+) -> Result<Page<ActionsVariable>> {
+    let secret_service = SecretService::new(github_client.clone());
+    secret_service
+        .list_variables(repository_id, pagination)
+        .await
+}
+
+/// Get a configuration variable of a repository
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `name` - The variable name
+pub async fn get_variable(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    name: &str,
+) -> Result<ActionsVariable> {
+    let secret_service = SecretService::new(github_client.clone());
+    secret_service.get_variable(repository_id, name).await
+}
+
+/// Create a configuration variable, or update it if it already exists
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `name` - The variable name
+/// * `value` - The variable value
+///
+/// # Returns
+/// `true` if the variable was created, `false` if an existing variable was updated
+///
+/// # Errors
+/// Returns an error if the name is not a valid variable name or the
+/// variable cannot be stored.
+pub async fn set_variable(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    name: &str,
+    value: &str,
+) -> Result<bool> {
+    validate_actions_name(name).map_err(|e| anyhow::anyhow!(e))?;
+
+    let secret_service = SecretService::new(github_client.clone());
+
+    // Variable names are case-insensitive and stored in uppercase
+    let mut exists = false;
+    let mut pagination = Pagination::new(None, Some(MAX_PER_PAGE));
+    loop {
+        let page = secret_service
+            .list_variables(repository_id, pagination)
+            .await?;
+        if page
+            .items
+            .iter()
+            .any(|variable| variable.name.eq_ignore_ascii_case(name))
+        {
+            exists = true;
+            break;
+        }
+        if !page.has_next_page {
+            break;
+        }
+        pagination = pagination.next();
+    }
+
+    if exists {
+        secret_service
+            .update_variable(repository_id, name, value)
+            .await?;
+    } else {
+        secret_service
+            .create_variable(repository_id, name, value)
+            .await?;
+    }
+    Ok(!exists)
+}
+
+/// Delete a configuration variable from a repository
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `name` - The variable name
+pub async fn delete_variable(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    name: &str,
+) -> Result<()> {
+    let secret_service = SecretService::new(github_client.clone());
+    secret_service.delete_variable(repository_id, name).await
+}
@@ -0,0 +1,46 @@
+use anyhow::Result;
+
+use crate::github::GitHubClient;
+use crate::tools::functions::repository;
+use crate::types::repository::RepositoryId;
+use crate::types::template::TemplateKind;
+
+/// Fetch a template from the default branch of a repository
+///
+/// Looks the template up in the template directories of its kind, e.g.
+/// `.github/ISSUE_TEMPLATE/bug.md` for the `bug` issue template.
+///
+/// # Arguments
+/// * `github_client` - The GitHub client instance
+/// * `repository_id` - The repository identifier
+/// * `kind` - Whether the template creates an issue or a pull request
+/// * `name` - Template name, with or without its `.md` extension
+///
+/// # Returns
+/// The raw template, including any front matter
+///
+/// # Errors
+/// Returns an error listing the paths looked up if none of them holds the
+/// template
+pub async fn fetch_template(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    kind: TemplateKind,
+    name: &str,
+) -> Result<String> {
+    let paths = kind.template_paths(name);
+    for path in &paths {
+        if let Ok(file) =
+            repository::get_file_content(github_client, repository_id, path, None).await
+        {
+            return String::from_utf8(file.content)
+                .map_err(|_| anyhow::anyhow!("Template {} is not valid UTF-8", path));
+        }
+    }
+    Err(anyhow::anyhow!(
+        "Template '{}' not found in {} (looked up {})",
+        name,
+        repository_id.url(),
+        paths.join(", ")
+    ))
+}
@@ -0,0 +1,128 @@
+//! Issue and pull request URLs in place of repository URLs
+//!
+//! Tools acting on one issue or pull request take `repository_url` and the
+//! number of the item. The URL of the item itself may be passed as
+//! `repository_url` instead, omitting the number: it is filled into the
+//! arguments before the call is dispatched, so the operations of
+//! `execute_batch` accept item URLs as well.
+
+use crate::tools::session::REPOSITORY_URL_ARGUMENT;
+use crate::types::item_url::ItemUrl;
+
+use rmcp::{Error as McpError, model::*};
+use std::sync::Arc;
+
+/// Names of the arguments holding the number of an issue or pull request
+pub const ITEM_NUMBER_ARGUMENTS: [&str; 3] = ["issue_number", "pr_number", "pull_request_number"];
+
+/// Returns the item number argument the tool requires along with
+/// `repository_url`, if any
+pub fn required_item_number_argument(tool: &Tool) -> Option<&'static str> {
+    let required = tool.input_schema.get("required")?.as_array()?;
+    let requires = |argument: &str| required.iter().any(|name| name.as_str() == Some(argument));
+    if !requires(REPOSITORY_URL_ARGUMENT) {
+        return None;
+    }
+    ITEM_NUMBER_ARGUMENTS
+        .into_iter()
+        .find(|argument| requires(argument))
+}
+
+/// Fill the number of the item into a call of `tool` passing the URL of an
+/// issue or pull request as `repository_url`
+///
+/// The URL is replaced with the URL of the repository of the item.
+///
+/// # Errors
+/// Returns an error if the call also passes a number differing from the
+/// number in the URL, or omits the number without passing an item URL
+pub fn fill_item_number(tool: &Tool, request: &mut CallToolRequestParam) -> Result<(), McpError> {
+    let Some(number_argument) = required_item_number_argument(tool) else {
+        return Ok(());
+    };
+    let arguments = request.arguments.get_or_insert_with(JsonObject::new);
+    let number = arguments
+        .get(number_argument)
+        .filter(|value| !value.is_null())
+        .cloned();
+    let item = arguments
+        .get(REPOSITORY_URL_ARGUMENT)
+        .and_then(|value| value.as_str())
+        .and_then(ItemUrl::parse);
+
+    match (item, number) {
+        (Some(item), Some(number)) if number.as_u64() != Some(u64::from(item.number)) => {
+            Err(McpError::invalid_params(
+                format!(
+                    "{} {} does not match the URL passed as {}",
+                    number_argument, number, REPOSITORY_URL_ARGUMENT
+                ),
+                None,
+            ))
+        }
+        (Some(item), _) => {
+            arguments.insert(
+                REPOSITORY_URL_ARGUMENT.to_string(),
+                serde_json::Value::String(item.repository_id.url()),
+            );
+            arguments.insert(number_argument.to_string(), item.number.into());
+            Ok(())
+        }
+        (None, Some(_)) => Ok(()),
+        (None, None) => Err(McpError::invalid_params(
+            format!(
+                "{} requires {}: pass it, or pass the URL of the issue or pull request as {}",
+                request.name, number_argument, REPOSITORY_URL_ARGUMENT
+            ),
+            None,
+        )),
+    }
+}
+
+/// Make the item number optional in the input schema of a tool that
+/// requires it along with `repository_url`, as it is taken from an item URL
+/// passed as `repository_url`
+pub fn with_item_url_argument(mut tool: Tool) -> Tool {
+    let Some(number_argument) = required_item_number_argument(&tool) else {
+        return tool;
+    };
+    let mut schema = tool.input_schema.as_ref().clone();
+    if let Some(required) = schema
+        .get_mut("required")
+        .and_then(|required| required.as_array_mut())
+    {
+        required.retain(|name| name.as_str() != Some(number_argument));
+    }
+    if let Some(properties) = schema
+        .get_mut("properties")
+        .and_then(|properties| properties.as_object_mut())
+    {
+        for (argument, suffix) in [
+            (
+                REPOSITORY_URL_ARGUMENT,
+                "Or the URL of the issue or pull request itself (e.g., 'https://github.com/owner/repo/issues/123')".to_string(),
+            ),
+            (
+                number_argument,
+                format!(
+                    "Optional when {} is the URL of the issue or pull request",
+                    REPOSITORY_URL_ARGUMENT
+                ),
+            ),
+        ] {
+            if let Some(property) = properties
+                .get_mut(argument)
+                .and_then(|property| property.as_object_mut())
+            {
+                let text = property
+                    .get("description")
+                    .and_then(|text| text.as_str())
+                    .unwrap_or(argument);
+                let text = format!("{}. {}", text, suffix);
+                property.insert("description".to_string(), serde_json::Value::String(text));
+            }
+        }
+    }
+    tool.input_schema = Arc::new(schema);
+    tool
+}
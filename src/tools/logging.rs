@@ -0,0 +1,213 @@
+//! MCP logging of the tool calls
+//!
+//! Each tool call runs within a trace of its own. The call, the GitHub
+//! requests and retries of its operations and its result are sent to the
+//! client as `notifications/message` log messages of the `github-edit`
+//! logger, each with the trace ID of the call in its data. Error results and
+//! errors name the trace ID as well, so a failure an agent reports can be
+//! matched to its log messages and to the server log.
+//!
+//! Messages below the level set with `logging/setLevel` are not sent. Until
+//! the client sets one the level is `info`, which leaves out the individual
+//! GitHub requests. Only the names of the tool arguments are logged, never
+//! their values, as those may be secrets.
+
+use crate::github::trace;
+use crate::types::trace::{TraceEvent, TraceLevel};
+
+use rmcp::{Error as McpError, model::*};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+use tracing::Instrument;
+
+/// Name of the logger of the log messages
+pub const LOGGER_NAME: &str = "github-edit";
+
+/// Minimum level of the log messages sent before the client sets one
+pub const DEFAULT_LOG_LEVEL: LoggingLevel = LoggingLevel::Info;
+
+/// Sends the log messages of the tool calls of one session
+#[derive(Clone)]
+pub struct ToolCallLogger {
+    level: Arc<Mutex<LoggingLevel>>,
+}
+
+impl Default for ToolCallLogger {
+    fn default() -> Self {
+        Self {
+            level: Arc::new(Mutex::new(DEFAULT_LOG_LEVEL)),
+        }
+    }
+}
+
+impl ToolCallLogger {
+    /// Set the minimum level of the log messages sent to the client
+    pub fn set_level(&self, level: LoggingLevel) {
+        *self.level.lock().unwrap_or_else(|e| e.into_inner()) = level;
+    }
+
+    /// Returns the minimum level of the log messages sent to the client
+    pub fn level(&self) -> LoggingLevel {
+        self.level.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Returns true if log messages of `level` are sent to the client
+    pub fn is_enabled(&self, level: &LoggingLevel) -> bool {
+        level_rank(level) >= level_rank(&self.level())
+    }
+
+    /// Run the call of `tool` within a new trace
+    ///
+    /// The events of the call at or above the minimum level are passed to
+    /// `notify` as log messages, in order, before this returns. An error
+    /// result or error of the call names the trace ID.
+    pub async fn trace_call<F, N, NF>(
+        &self,
+        tool: &str,
+        arguments: Option<&JsonObject>,
+        notify: N,
+        call: F,
+    ) -> Result<CallToolResult, McpError>
+    where
+        F: Future<Output = Result<CallToolResult, McpError>>,
+        N: Fn(LoggingMessageNotificationParam) -> NF + Send + 'static,
+        NF: Future<Output = ()> + Send,
+    {
+        let trace_id = trace::new_trace_id();
+        let (sink, mut events) = mpsc::unbounded_channel::<TraceEvent>();
+        let min_level = level_rank(&self.level());
+        let tool_name = tool.to_string();
+        let forwarder = tokio::spawn(async move {
+            while let Some(event) = events.recv().await {
+                let level = logging_level(event.level);
+                if level_rank(&level) < min_level {
+                    continue;
+                }
+                notify(LoggingMessageNotificationParam {
+                    level,
+                    logger: Some(LOGGER_NAME.to_string()),
+                    data: serde_json::json!({
+                        "trace_id": event.trace_id,
+                        "tool": tool_name,
+                        "message": event.message,
+                        "details": event.data,
+                    }),
+                })
+                .await;
+            }
+        });
+
+        let argument_names: Vec<&String> = arguments
+            .map(|arguments| arguments.keys().collect())
+            .unwrap_or_default();
+        let span = tracing::info_span!("tool_call", tool = %tool, trace_id = %trace_id);
+        let result = trace::with_trace(
+            trace_id.clone(),
+            sink,
+            async {
+                tracing::debug!("Received {} call", tool);
+                trace::emit(
+                    TraceLevel::Info,
+                    format!("Received {} call", tool),
+                    serde_json::json!({ "arguments": argument_names }),
+                );
+                let result = call.await;
+                match &result {
+                    Ok(result) if result.is_error != Some(true) => trace::emit(
+                        TraceLevel::Info,
+                        format!("{} succeeded", tool),
+                        serde_json::json!({}),
+                    ),
+                    Ok(result) => {
+                        let error = first_text(result).unwrap_or_default();
+                        tracing::warn!("{} failed: {}", tool, error);
+                        trace::emit(
+                            TraceLevel::Error,
+                            format!("{} failed: {}", tool, error),
+                            serde_json::json!({}),
+                        )
+                    }
+                    Err(e) => {
+                        tracing::warn!("{} failed: {}", tool, e.message);
+                        trace::emit(
+                            TraceLevel::Error,
+                            format!("{} failed: {}", tool, e.message),
+                            serde_json::json!({ "code": e.code.0 }),
+                        )
+                    }
+                }
+                result
+            }
+            .instrument(span),
+        )
+        .await;
+
+        // The sink was dropped with the trace, so the forwarder ends once it
+        // has sent the remaining events
+        if let Err(e) = forwarder.await {
+            tracing::warn!("Failed to send the log messages of {}: {}", tool, e);
+        }
+        name_trace_id(&trace_id, result)
+    }
+}
+
+/// Append the trace ID to the error message of an error result or error
+pub fn name_trace_id(
+    trace_id: &str,
+    result: Result<CallToolResult, McpError>,
+) -> Result<CallToolResult, McpError> {
+    let suffix = format!(" (trace ID: {})", trace_id);
+    match result {
+        Ok(mut result) if result.is_error == Some(true) => {
+            if let Some(text) =
+                result
+                    .content
+                    .iter_mut()
+                    .find_map(|content| match &mut content.raw {
+                        RawContent::Text(text) => Some(text),
+                        _ => None,
+                    })
+            {
+                text.text.push_str(&suffix);
+            }
+            Ok(result)
+        }
+        Err(mut e) => {
+            e.message = format!("{}{}", e.message, suffix).into();
+            Err(e)
+        }
+        result => result,
+    }
+}
+
+fn first_text(result: &CallToolResult) -> Option<String> {
+    result
+        .content
+        .iter()
+        .find_map(|content| content.as_text())
+        .map(|text| text.text.clone())
+}
+
+fn logging_level(level: TraceLevel) -> LoggingLevel {
+    match level {
+        TraceLevel::Debug => LoggingLevel::Debug,
+        TraceLevel::Info => LoggingLevel::Info,
+        TraceLevel::Warning => LoggingLevel::Warning,
+        TraceLevel::Error => LoggingLevel::Error,
+    }
+}
+
+/// Returns the position of `level` in the severity order of the levels
+fn level_rank(level: &LoggingLevel) -> u8 {
+    match level {
+        LoggingLevel::Debug => 0,
+        LoggingLevel::Info => 1,
+        LoggingLevel::Notice => 2,
+        LoggingLevel::Warning => 3,
+        LoggingLevel::Error => 4,
+        LoggingLevel::Critical => 5,
+        LoggingLevel::Alert => 6,
+        LoggingLevel::Emergency => 7,
+    }
+}
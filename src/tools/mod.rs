@@ -12,31 +12,171 @@
 
 pub mod tool_definition;
 use crate::github::GitHubClient;
+use crate::github::cancellation;
 use crate::types::issue::{IssueCommentNumber, IssueNumber};
 use crate::types::pull_request::PullRequestCommentNumber;
+use crate::types::repository::{MilestoneNumber, RepositorySettings};
 
+use rmcp::handler::server::tool::ToolCallContext;
+use rmcp::service::{Peer, RequestContext, RoleServer};
 use rmcp::{Error as McpError, ServerHandler, model::*, tool};
+use std::collections::BTreeMap;
 
+pub mod annotations;
+pub mod audit;
+pub mod batch;
+pub mod confirmation;
+pub mod dry_run;
 pub mod error;
 pub mod functions;
+pub mod item_url;
+pub mod logging;
+pub mod prompts;
+pub mod resources;
+pub mod session;
+pub mod truncation;
+pub mod undo;
+pub mod webhooks;
 
 /// The main MCP tools service for GitHub repository exploration
 #[derive(Clone)]
 pub struct GitEditTools {
     github_client: GitHubClient,
+    subscriptions: resources::ResourceSubscriptions,
+    confirmations: confirmation::ConfirmationTokens,
+    responses: truncation::ResponseLimiter,
+    logger: logging::ToolCallLogger,
+    session: session::SessionDefaults,
+    auditor: Option<audit::ToolCallAuditor>,
+    webhook_events: Option<tokio::sync::broadcast::Sender<crate::types::webhook::WebhookEvent>>,
 }
 
 impl GitEditTools {
     /// Create a new GitInsightTools instance
     pub fn new(github_client: GitHubClient) -> Self {
-        Self { github_client }
+        Self {
+            github_client,
+            subscriptions: resources::ResourceSubscriptions::default(),
+            confirmations: confirmation::ConfirmationTokens::default(),
+            responses: truncation::ResponseLimiter::default(),
+            logger: logging::ToolCallLogger::default(),
+            session: session::SessionDefaults::default(),
+            auditor: None,
+            webhook_events: None,
+        }
+    }
+
+    /// Cut tool responses after `max_chars` characters, returning the rest
+    /// through `get_response_continuation`
+    pub fn with_max_response_chars(mut self, max_chars: usize) -> Self {
+        self.responses = truncation::ResponseLimiter::new(max_chars);
+        self
     }
 
     /// Initializes the GitInsightTools instance
+    ///
+    /// Verifies the configured token in the background and logs the tools it
+    /// lacks scopes for. Verification failures are logged only, so a slow or
+    /// unreachable API does not delay the server start.
     pub async fn init(&self) -> Result<(), anyhow::Error> {
-        // Basic initialization without services
+        if self.github_client.token.is_none() {
+            tracing::warn!("No GitHub token configured; most tools will fail");
+            return Ok(());
+        }
+
+        let github_client = self.github_client.clone();
+        tokio::spawn(async move {
+            match functions::auth::verify_auth(&github_client).await {
+                Ok(status) => {
+                    tracing::info!(
+                        "Authenticated as {} ({} token)",
+                        status.login,
+                        status.token_kind
+                    );
+                    for requirement in status.unmet_requirements() {
+                        tracing::warn!(
+                            "{} will not work: the token needs {}",
+                            requirement.tools,
+                            requirement.scopes.join(" or ")
+                        );
+                    }
+                }
+                Err(e) => tracing::warn!("Failed to verify the GitHub token: {}", e),
+            }
+        });
         Ok(())
     }
+
+    /// Append the tool calls that write to GitHub to `log`
+    pub fn with_audit_log(mut self, log: crate::audit::AuditLog) -> Self {
+        self.auditor = Some(audit::ToolCallAuditor::new(log));
+        self
+    }
+
+    /// Forward the events of the webhook listener sending to `events` to the
+    /// client of the session
+    pub fn with_webhook_events(
+        mut self,
+        events: tokio::sync::broadcast::Sender<crate::types::webhook::WebhookEvent>,
+    ) -> Self {
+        self.webhook_events = Some(events);
+        self
+    }
+
+    /// Returns the tools listed to clients
+    ///
+    /// Every listed tool is classified in the annotation registry, which
+    /// decides which of them accept `dry_run`. `repository_url` is optional
+    /// in every listed tool, defaulting to the session default repository,
+    /// and may be the URL of the issue or pull request a tool acts on.
+    pub fn listed_tools() -> Vec<Tool> {
+        Self::tool_box()
+            .list()
+            .into_iter()
+            .chain([
+                batch::execute_batch_tool(),
+                undo::undo_last_operation_tool(),
+            ])
+            .map(dry_run::with_dry_run_argument)
+            .map(item_url::with_item_url_argument)
+            .map(session::with_default_repository_argument)
+            .chain([truncation::get_response_continuation_tool()])
+            .collect()
+    }
+
+    /// Dispatch a call to one of the tools of the tool box, filling in the
+    /// number of the item if `repository_url` is the URL of an issue or pull
+    /// request, and the default repository if the call omits `repository_url`
+    async fn call_single_tool(
+        &self,
+        mut request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        if let Some(item) = Self::tool_box().map.get(request.name.as_ref()) {
+            item_url::fill_item_number(&item.attr, &mut request)?;
+            self.session
+                .fill_default_repository(&item.attr, &mut request)?;
+        }
+        if dry_run::take_dry_run_argument(&mut request)? {
+            let tool_call_context = ToolCallContext::new(self, request.clone(), context);
+            return dry_run::dry_run_tool(
+                &self.github_client,
+                &request,
+                Self::tool_box().call(tool_call_context),
+            )
+            .await;
+        }
+
+        let tool_call_context = ToolCallContext::new(self, request, context);
+        Self::tool_box().call(tool_call_context).await
+    }
+}
+
+/// Convert the issue number parameter `name`, rejecting numbers out of range
+fn issue_number_param(name: &str, number: u64) -> Result<IssueNumber, McpError> {
+    u32::try_from(number)
+        .map(IssueNumber::new)
+        .map_err(|_| McpError::invalid_params(format!("{} {} is out of range", name, number), None))
 }
 
 // Tool implementations are now split across multiple files in tool_definition/
@@ -48,7 +188,7 @@ impl GitEditTools {
 
     // Project tools - defined in tool_definition/project_impl.rs
     #[tool(
-        description = "Update a project item field using string parameters. Supports text, number, date, single_select, and multi_select field types."
+        description = "Update a project item field using string parameters. Supports text, number, date, single_select, and multi_select field types. Single select values are option names or IDs. For a Labels field, multi_select values replace all labels of the item's issue or pull request: labels not in the value are removed, and draft issues are rejected. For a single select field, multi_select selects exactly one option."
     )]
     async fn update_project_item_field(
         &self,
@@ -56,8 +196,15 @@ impl GitEditTools {
         #[schemars(description = "The project node identifier (GraphQL ID)")]
         project_node_id: String,
         #[tool(param)]
-        #[schemars(description = "The project item ID (GraphQL node ID)")]
-        project_item_id: String,
+        #[schemars(
+            description = "The project item ID (GraphQL node ID). Mutually exclusive with content_url"
+        )]
+        project_item_id: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Issue or pull request URL whose project item to update, instead of project_item_id (e.g., 'https://github.com/owner/repo/issues/123')"
+        )]
+        content_url: Option<String>,
         #[tool(param)]
         #[schemars(description = "The field ID (GraphQL node ID)")]
         project_field_id: String,
@@ -76,6 +223,7 @@ impl GitEditTools {
             &self.github_client,
             project_node_id,
             project_item_id,
+            content_url,
             project_field_id,
             field_type,
             value,
@@ -83,30 +231,10 @@ impl GitEditTools {
         .await
     }
 
-    #[tool(description = "Get project node ID from project identifier")]
-    async fn get_project_node_id(
-        &self,
-        #[tool(param)]
-        #[schemars(description = "Project owner username or organization name")]
-        project_owner: String,
-        #[tool(param)]
-        #[schemars(description = "Project number")]
-        project_number: u64,
-        #[tool(param)]
-        #[schemars(description = "Project type (user or organization)")]
-        project_type: String,
-    ) -> Result<CallToolResult, McpError> {
-        tool_definition::ProjectTools::get_project_node_id(
-            &self.github_client,
-            project_owner,
-            project_number,
-            project_type,
-        )
-        .await
-    }
-
-    #[tool(description = "Update a project item text field")]
-    async fn update_project_item_text_field(
+    #[tool(
+        description = "Remove an item from a project. The underlying issue or pull request is not affected; draft issues are deleted."
+    )]
+    async fn remove_item_from_project(
         &self,
         #[tool(param)]
         #[schemars(description = "The project node identifier (GraphQL ID)")]
@@ -114,25 +242,19 @@ impl GitEditTools {
         #[tool(param)]
         #[schemars(description = "The project item ID (GraphQL node ID)")]
         project_item_id: String,
-        #[tool(param)]
-        #[schemars(description = "The field ID (GraphQL node ID)")]
-        project_field_id: String,
-        #[tool(param)]
-        #[schemars(description = "The text value to set")]
-        text_value: String,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::ProjectTools::update_project_item_text_field(
+        tool_definition::ProjectTools::remove_item_from_project(
             &self.github_client,
             project_node_id,
             project_item_id,
-            project_field_id,
-            text_value,
         )
         .await
     }
 
-    #[tool(description = "Update a project item number field")]
-    async fn update_project_item_number_field(
+    #[tool(
+        description = "Archive a project item. Archived items are hidden from project views and can be restored."
+    )]
+    async fn archive_project_item(
         &self,
         #[tool(param)]
         #[schemars(description = "The project node identifier (GraphQL ID)")]
@@ -140,25 +262,17 @@ impl GitEditTools {
         #[tool(param)]
         #[schemars(description = "The project item ID (GraphQL node ID)")]
         project_item_id: String,
-        #[tool(param)]
-        #[schemars(description = "The field ID (GraphQL node ID)")]
-        project_field_id: String,
-        #[tool(param)]
-        #[schemars(description = "The number value to set")]
-        number_value: f64,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::ProjectTools::update_project_item_number_field(
+        tool_definition::ProjectTools::archive_project_item(
             &self.github_client,
             project_node_id,
             project_item_id,
-            project_field_id,
-            number_value,
         )
         .await
     }
 
-    #[tool(description = "Update a project item date field")]
-    async fn update_project_item_date_field(
+    #[tool(description = "Restore an archived project item")]
+    async fn unarchive_project_item(
         &self,
         #[tool(param)]
         #[schemars(description = "The project node identifier (GraphQL ID)")]
@@ -166,237 +280,3188 @@ impl GitEditTools {
         #[tool(param)]
         #[schemars(description = "The project item ID (GraphQL node ID)")]
         project_item_id: String,
-        #[tool(param)]
-        #[schemars(description = "The field ID (GraphQL node ID)")]
-        project_field_id: String,
-        #[tool(param)]
-        #[schemars(
-            description = "The date value in ISO 8601 format (e.g., '2024-01-15T10:30:00Z')"
-        )]
-        date_value: String,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::ProjectTools::update_project_item_date_field(
+        tool_definition::ProjectTools::unarchive_project_item(
             &self.github_client,
             project_node_id,
             project_item_id,
-            project_field_id,
-            date_value,
         )
         .await
     }
 
-    #[tool(description = "Update a project item single select field")]
-    async fn update_project_item_single_select_field(
+    #[tool(
+        description = "Move a project item within the project's manual ordering, e.g. to prioritize a backlog. Omit after_item_id to move the item to the top"
+    )]
+    async fn update_project_item_position(
         &self,
         #[tool(param)]
         #[schemars(description = "The project node identifier (GraphQL ID)")]
         project_node_id: String,
         #[tool(param)]
-        #[schemars(description = "The project item ID (GraphQL node ID)")]
+        #[schemars(description = "The project item ID (GraphQL node ID) to move")]
         project_item_id: String,
         #[tool(param)]
-        #[schemars(description = "The field ID (GraphQL node ID)")]
-        project_field_id: String,
-        #[tool(param)]
-        #[schemars(description = "The option ID to select (GraphQL node ID)")]
-        option_id: String,
+        #[schemars(
+            description = "Optional project item ID to place the item after. When omitted the item is moved to the top"
+        )]
+        after_item_id: Option<String>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::ProjectTools::update_project_item_single_select_field(
+        tool_definition::ProjectTools::update_project_item_position(
             &self.github_client,
             project_node_id,
             project_item_id,
-            project_field_id,
-            option_id,
-        )
-        .await
-    }
-
-    #[tool(description = "Add an issue to a project")]
-    async fn add_issue_to_project(
-        &self,
-        #[tool(param)]
-        #[schemars(description = "The project node identifier (GraphQL ID)")]
-        project_node_id: String,
-        #[tool(param)]
-        #[schemars(description = "Repository owner username or organization name")]
-        repository_owner: String,
-        #[tool(param)]
-        #[schemars(description = "Repository name")]
-        repository_name: String,
-        #[tool(param)]
-        #[schemars(description = "Issue number to add to the project")]
-        issue_number: u64,
-    ) -> Result<CallToolResult, McpError> {
-        tool_definition::ProjectTools::add_issue_to_project(
-            &self.github_client,
-            project_node_id,
-            repository_owner,
-            repository_name,
-            IssueNumber::new(issue_number.try_into().unwrap()),
+            after_item_id,
         )
         .await
     }
 
-    #[tool(description = "Add a pull request to a project")]
-    async fn add_pull_request_to_project(
+    #[tool(
+        description = "Add a draft issue to a project. Returns both the project item ID and the draft issue ID."
+    )]
+    async fn add_draft_issue_to_project(
         &self,
         #[tool(param)]
         #[schemars(description = "The project node identifier (GraphQL ID)")]
         project_node_id: String,
         #[tool(param)]
-        #[schemars(description = "Repository owner username or organization name")]
-        repository_owner: String,
-        #[tool(param)]
-        #[schemars(description = "Repository name")]
-        repository_name: String,
+        #[schemars(description = "Draft issue title")]
+        title: String,
         #[tool(param)]
-        #[schemars(description = "Pull request number to add to the project")]
-        pull_request_number: u64,
+        #[schemars(description = "Optional draft issue body")]
+        body: Option<String>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::ProjectTools::add_pull_request_to_project(
+        tool_definition::ProjectTools::add_draft_issue_to_project(
             &self.github_client,
             project_node_id,
-            repository_owner,
-            repository_name,
-            pull_request_number,
+            title,
+            body,
         )
         .await
     }
 
-    #[tool(description = "Create a new pull request")]
-    async fn create_pull_request(
+    #[tool(description = "Update the title and/or body of a draft issue")]
+    async fn update_draft_issue(
         &self,
         #[tool(param)]
         #[schemars(
-            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+            description = "The draft issue ID (GraphQL ID starting with 'DI_', not the project item ID)"
         )]
-        repository_url: String,
-        #[tool(param)]
-        #[schemars(description = "Pull request title")]
-        title: String,
+        draft_issue_id: String,
         #[tool(param)]
-        #[schemars(description = "Head branch name containing the changes")]
-        head_branch: String,
-        #[tool(param)]
-        #[schemars(description = "Base branch name to merge into")]
-        base_branch: String,
+        #[schemars(description = "Optional new title")]
+        title: Option<String>,
         #[tool(param)]
-        #[schemars(description = "Optional pull request body content")]
+        #[schemars(description = "Optional new body")]
         body: Option<String>,
-        #[tool(param)]
-        #[schemars(description = "Whether to create as draft (default: false)")]
-        draft: Option<bool>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::PullRequestTools::create_pull_request(
+        tool_definition::ProjectTools::update_draft_issue(
             &self.github_client,
-            repository_url,
+            draft_issue_id,
             title,
-            head_branch,
-            base_branch,
             body,
-            draft,
         )
         .await
     }
 
-    #[tool(description = "Add a comment to a pull request")]
-    async fn add_comment_to_pull_request(
+    #[tool(
+        description = "Convert a draft issue project item to an issue in a repository. The project item keeps its field values."
+    )]
+    async fn convert_draft_issue_to_issue(
         &self,
         #[tool(param)]
+        #[schemars(description = "The project item ID holding the draft issue (GraphQL node ID)")]
+        project_item_id: String,
+        #[tool(param)]
         #[schemars(
-            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+            description = "Repository URL to create the issue in (e.g., 'https://github.com/owner/repo', 'owner/repo')"
         )]
         repository_url: String,
-        #[tool(param)]
-        #[schemars(description = "Pull request number")]
-        pr_number: u64,
-        #[tool(param)]
-        #[schemars(description = "Comment content")]
-        body: String,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::PullRequestTools::add_comment_to_pull_request(
+        tool_definition::ProjectTools::convert_draft_issue_to_issue(
             &self.github_client,
+            project_item_id,
             repository_url,
-            pr_number,
-            body,
         )
         .await
     }
 
-    #[tool(description = "Edit an existing pull request comment")]
-    async fn edit_comment_on_pull_request(
+    #[tool(
+        description = "Update one field on many project items at once, selected either by item IDs or by a current field value (e.g. Status = 'Todo'). Returns per-item results."
+    )]
+    #[allow(clippy::too_many_arguments)]
+    async fn bulk_update_project_field(
         &self,
         #[tool(param)]
+        #[schemars(description = "The project node identifier (GraphQL ID)")]
+        project_node_id: String,
+        #[tool(param)]
+        #[schemars(description = "The field ID (GraphQL node ID) to update")]
+        project_field_id: String,
+        #[tool(param)]
         #[schemars(
-            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+            description = "The field type (text, number, date, single_select, multi_select)"
         )]
-        repository_url: String,
+        field_type: String,
         #[tool(param)]
-        #[schemars(description = "Pull request number")]
-        pr_number: u64,
+        #[schemars(
+            description = "The value to set on every selected item (single select values are option names or IDs)"
+        )]
+        value: String,
         #[tool(param)]
-        #[schemars(description = "Comment number to edit")]
-        comment_number: u64,
+        #[schemars(
+            description = "Project item IDs to update. Mutually exclusive with filter_field_name/filter_value"
+        )]
+        item_ids: Option<Vec<String>>,
         #[tool(param)]
-        #[schemars(description = "New comment content")]
-        body: String,
+        #[schemars(
+            description = "Name of the field to select items by (e.g. 'Status'). Archived items are never selected"
+        )]
+        filter_field_name: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Value the filter field must currently have (e.g. 'Todo')")]
+        filter_value: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Maximum number of items updated at once (default: 4)")]
+        concurrency: Option<usize>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::PullRequestTools::edit_comment_on_pull_request(
+        tool_definition::ProjectTools::bulk_update_project_field(
             &self.github_client,
-            repository_url,
-            pr_number,
-            PullRequestCommentNumber::new(comment_number),
-            body,
+            project_node_id,
+            project_field_id,
+            field_type,
+            value,
+            item_ids,
+            filter_field_name,
+            filter_value,
+            concurrency,
         )
         .await
     }
 
-    #[tool(description = "Close a pull request")]
-    async fn close_pull_request(
+    #[tool(description = "Find the project item ID of an issue or pull request by its URL")]
+    async fn find_project_item_for_content(
         &self,
         #[tool(param)]
+        #[schemars(description = "The project node identifier (GraphQL ID)")]
+        project_node_id: String,
+        #[tool(param)]
         #[schemars(
-            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+            description = "Issue or pull request URL (e.g., 'https://github.com/owner/repo/issues/123', 'https://github.com/owner/repo/pull/456')"
         )]
-        repository_url: String,
-        #[tool(param)]
-        #[schemars(description = "Pull request number to close")]
-        pr_number: u64,
+        content_url: String,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::PullRequestTools::close_pull_request(
+        tool_definition::ProjectTools::find_project_item_for_content(
             &self.github_client,
-            repository_url,
-            pr_number,
+            project_node_id,
+            content_url,
         )
         .await
     }
 
-    #[tool(description = "Edit the title of a pull request")]
-    async fn edit_pull_request_title(
+    #[tool(description = "Create a new GitHub Project (v2) owned by a user or organization")]
+    async fn create_project(
         &self,
         #[tool(param)]
-        #[schemars(
-            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
-        )]
-        repository_url: String,
-        #[tool(param)]
-        #[schemars(description = "Pull request number")]
-        pr_number: u64,
+        #[schemars(description = "Project owner username or organization name")]
+        owner: String,
         #[tool(param)]
-        #[schemars(description = "New title content")]
+        #[schemars(description = "Project title")]
         title: String,
+        #[tool(param)]
+        #[schemars(description = "Project type (user or organization)")]
+        project_type: String,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::PullRequestTools::edit_pull_request_title(
+        tool_definition::ProjectTools::create_project(
             &self.github_client,
-            repository_url,
-            pr_number,
+            owner,
             title,
+            project_type,
         )
         .await
     }
 
-    #[tool(description = "Edit the body of a pull request")]
-    async fn edit_pull_request_body(
+    #[tool(
+        description = "Get a project by owner and number: title, description, node ID, visibility, state, item count and a summary of its fields with field IDs and single select options. Use this before editing a project"
+    )]
+    async fn get_project(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "Project owner username or organization name")]
+        project_owner: String,
+        #[tool(param)]
+        #[schemars(description = "Project number")]
+        project_number: u64,
+        #[tool(param)]
+        #[schemars(description = "Project type (user or organization)")]
+        project_type: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::get_project(
+            &self.github_client,
+            project_owner,
+            project_number,
+            project_type,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Copy a project, e.g. a template board, including its fields, views and workflows. Issues and pull requests are not copied"
+    )]
+    async fn copy_project(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The node identifier (GraphQL ID) of the project to copy")]
+        source_project_node_id: String,
+        #[tool(param)]
+        #[schemars(description = "Owner username or organization name of the new project")]
+        owner: String,
+        #[tool(param)]
+        #[schemars(description = "Owner type of the new project (user or organization)")]
+        project_type: String,
+        #[tool(param)]
+        #[schemars(description = "Title of the new project")]
+        title: String,
+        #[tool(param)]
+        #[schemars(description = "Whether draft issues are copied as well (default: false)")]
+        include_draft_issues: Option<bool>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::copy_project(
+            &self.github_client,
+            source_project_node_id,
+            owner,
+            project_type,
+            title,
+            include_draft_issues,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Update project settings: title, short description, README, visibility (public/private) and state (open/closed). Only the given settings are changed."
+    )]
+    #[allow(clippy::too_many_arguments)]
+    async fn update_project(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The project node identifier (GraphQL ID)")]
+        project_node_id: String,
+        #[tool(param)]
+        #[schemars(description = "Optional new project title")]
+        title: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional new short description")]
+        short_description: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional new README content (Markdown)")]
+        readme: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional visibility: 'public' or 'private'")]
+        visibility: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional state: 'open' or 'closed'")]
+        state: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::update_project(
+            &self.github_client,
+            project_node_id,
+            title,
+            short_description,
+            readme,
+            visibility,
+            state,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Permanently delete a project. Issues and pull requests in the project are not affected."
+    )]
+    async fn delete_project(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The project node identifier (GraphQL ID)")]
+        project_node_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::delete_project(&self.github_client, project_node_id).await
+    }
+
+    #[tool(
+        description = "List the fields of a project with their IDs, data types, single select options (with option IDs) and iteration configurations"
+    )]
+    async fn list_project_fields(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The project node identifier (GraphQL ID)")]
+        project_node_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::list_project_fields(&self.github_client, project_node_id)
+            .await
+    }
+
+    #[tool(
+        description = "Link a repository to a project so the project is listed in the repository's Projects tab"
+    )]
+    async fn link_repository_to_project(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The project node identifier (GraphQL ID)")]
+        project_node_id: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g. 'https://github.com/owner/repo' or 'owner/repo')"
+        )]
+        repository_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::link_repository_to_project(
+            &self.github_client,
+            project_node_id,
+            repository_url,
+        )
+        .await
+    }
+
+    #[tool(description = "Unlink a repository from a project")]
+    async fn unlink_repository_from_project(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The project node identifier (GraphQL ID)")]
+        project_node_id: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g. 'https://github.com/owner/repo' or 'owner/repo')"
+        )]
+        repository_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::unlink_repository_from_project(
+            &self.github_client,
+            project_node_id,
+            repository_url,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Link an organization team to a project so the project is listed in the team's Projects tab"
+    )]
+    async fn link_team_to_project(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The project node identifier (GraphQL ID)")]
+        project_node_id: String,
+        #[tool(param)]
+        #[schemars(description = "The organization that owns the team")]
+        organization: String,
+        #[tool(param)]
+        #[schemars(description = "The team slug (e.g. 'core-team')")]
+        team_slug: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::link_team_to_project(
+            &self.github_client,
+            project_node_id,
+            organization,
+            team_slug,
+        )
+        .await
+    }
+
+    #[tool(description = "Unlink an organization team from a project")]
+    async fn unlink_team_from_project(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The project node identifier (GraphQL ID)")]
+        project_node_id: String,
+        #[tool(param)]
+        #[schemars(description = "The organization that owns the team")]
+        organization: String,
+        #[tool(param)]
+        #[schemars(description = "The team slug (e.g. 'core-team')")]
+        team_slug: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::unlink_team_from_project(
+            &self.github_client,
+            project_node_id,
+            organization,
+            team_slug,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Create a custom field in a project. Supported data types: text, number, date, single_select (requires options)"
+    )]
+    async fn create_project_field(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The project node identifier (GraphQL ID)")]
+        project_node_id: String,
+        #[tool(param)]
+        #[schemars(description = "The field name")]
+        name: String,
+        #[tool(param)]
+        #[schemars(
+            description = "The field data type: 'text', 'number', 'date' or 'single_select'"
+        )]
+        data_type: String,
+        #[tool(param)]
+        #[schemars(description = "Option names for a single_select field, in display order")]
+        options: Option<Vec<String>>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::create_project_field(
+            &self.github_client,
+            project_node_id,
+            name,
+            data_type,
+            options,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Rename a project field and/or replace the options of a single select field. Items that had a removed option selected lose their value"
+    )]
+    async fn update_project_field(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The field ID (GraphQL node ID)")]
+        project_field_id: String,
+        #[tool(param)]
+        #[schemars(description = "Optional new field name")]
+        name: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional complete list of option names for a single_select field"
+        )]
+        options: Option<Vec<String>>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::update_project_field(
+            &self.github_client,
+            project_field_id,
+            name,
+            options,
+        )
+        .await
+    }
+
+    #[tool(description = "Delete a custom field and all of its values from a project")]
+    async fn delete_project_field(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The field ID (GraphQL node ID)")]
+        project_field_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::delete_project_field(&self.github_client, project_field_id)
+            .await
+    }
+
+    #[tool(description = "Get project node ID from project identifier")]
+    async fn get_project_node_id(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "Project owner username or organization name")]
+        project_owner: String,
+        #[tool(param)]
+        #[schemars(description = "Project number")]
+        project_number: u64,
+        #[tool(param)]
+        #[schemars(description = "Project type (user or organization)")]
+        project_type: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::get_project_node_id(
+            &self.github_client,
+            project_owner,
+            project_number,
+            project_type,
+        )
+        .await
+    }
+
+    #[tool(description = "Update a project item text field")]
+    async fn update_project_item_text_field(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The project node identifier (GraphQL ID)")]
+        project_node_id: String,
+        #[tool(param)]
+        #[schemars(description = "The project item ID (GraphQL node ID)")]
+        project_item_id: String,
+        #[tool(param)]
+        #[schemars(description = "The field ID (GraphQL node ID)")]
+        project_field_id: String,
+        #[tool(param)]
+        #[schemars(description = "The text value to set")]
+        text_value: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::update_project_item_text_field(
+            &self.github_client,
+            project_node_id,
+            project_item_id,
+            project_field_id,
+            text_value,
+        )
+        .await
+    }
+
+    #[tool(description = "Update a project item number field")]
+    async fn update_project_item_number_field(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The project node identifier (GraphQL ID)")]
+        project_node_id: String,
+        #[tool(param)]
+        #[schemars(description = "The project item ID (GraphQL node ID)")]
+        project_item_id: String,
+        #[tool(param)]
+        #[schemars(description = "The field ID (GraphQL node ID)")]
+        project_field_id: String,
+        #[tool(param)]
+        #[schemars(description = "The number value to set")]
+        number_value: f64,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::update_project_item_number_field(
+            &self.github_client,
+            project_node_id,
+            project_item_id,
+            project_field_id,
+            number_value,
+        )
+        .await
+    }
+
+    #[tool(description = "Update a project item date field")]
+    async fn update_project_item_date_field(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The project node identifier (GraphQL ID)")]
+        project_node_id: String,
+        #[tool(param)]
+        #[schemars(description = "The project item ID (GraphQL node ID)")]
+        project_item_id: String,
+        #[tool(param)]
+        #[schemars(description = "The field ID (GraphQL node ID)")]
+        project_field_id: String,
+        #[tool(param)]
+        #[schemars(
+            description = "The date value in ISO 8601 format (e.g., '2024-01-15T10:30:00Z')"
+        )]
+        date_value: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::update_project_item_date_field(
+            &self.github_client,
+            project_node_id,
+            project_item_id,
+            project_field_id,
+            date_value,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Update a project item single select field. Select the option either by option ID or by option name (e.g. 'In Progress')."
+    )]
+    async fn update_project_item_single_select_field(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The project node identifier (GraphQL ID)")]
+        project_node_id: String,
+        #[tool(param)]
+        #[schemars(description = "The project item ID (GraphQL node ID)")]
+        project_item_id: String,
+        #[tool(param)]
+        #[schemars(description = "The field ID (GraphQL node ID)")]
+        project_field_id: String,
+        #[tool(param)]
+        #[schemars(
+            description = "The option ID to select (GraphQL node ID). Mutually exclusive with option_name"
+        )]
+        option_id: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "The option name to select (e.g. 'In Progress'), resolved to its option ID. Mutually exclusive with option_id"
+        )]
+        option_name: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::update_project_item_single_select_field(
+            &self.github_client,
+            project_node_id,
+            project_item_id,
+            project_field_id,
+            option_id,
+            option_name,
+        )
+        .await
+    }
+
+    #[tool(description = "Resolve a single select option name to its option ID")]
+    async fn resolve_single_select_option(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The project node identifier (GraphQL ID)")]
+        project_node_id: String,
+        #[tool(param)]
+        #[schemars(description = "The single select field ID (GraphQL node ID)")]
+        project_field_id: String,
+        #[tool(param)]
+        #[schemars(description = "The option name (e.g. 'In Progress')")]
+        option_name: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::resolve_single_select_option(
+            &self.github_client,
+            project_node_id,
+            project_field_id,
+            option_name,
+        )
+        .await
+    }
+
+    #[tool(description = "Add an issue to a project")]
+    async fn add_issue_to_project(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The project node identifier (GraphQL ID)")]
+        project_node_id: String,
+        #[tool(param)]
+        #[schemars(description = "Repository owner username or organization name")]
+        repository_owner: String,
+        #[tool(param)]
+        #[schemars(description = "Repository name")]
+        repository_name: String,
+        #[tool(param)]
+        #[schemars(description = "Issue number to add to the project")]
+        issue_number: u64,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::add_issue_to_project(
+            &self.github_client,
+            project_node_id,
+            repository_owner,
+            repository_name,
+            IssueNumber::new(issue_number.try_into().unwrap()),
+        )
+        .await
+    }
+
+    #[tool(description = "Add a pull request to a project")]
+    async fn add_pull_request_to_project(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "The project node identifier (GraphQL ID)")]
+        project_node_id: String,
+        #[tool(param)]
+        #[schemars(description = "Repository owner username or organization name")]
+        repository_owner: String,
+        #[tool(param)]
+        #[schemars(description = "Repository name")]
+        repository_name: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request number to add to the project")]
+        pull_request_number: u64,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ProjectTools::add_pull_request_to_project(
+            &self.github_client,
+            project_node_id,
+            repository_owner,
+            repository_name,
+            pull_request_number,
+        )
+        .await
+    }
+
+    #[tool(description = "Create a new pull request")]
+    async fn create_pull_request(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request title")]
+        title: String,
+        #[tool(param)]
+        #[schemars(description = "Head branch name containing the changes")]
+        head_branch: String,
+        #[tool(param)]
+        #[schemars(description = "Base branch name to merge into")]
+        base_branch: String,
+        #[tool(param)]
+        #[schemars(description = "Optional pull request body content")]
+        body: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Whether to create as draft (default: false)")]
+        draft: Option<bool>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::PullRequestTools::create_pull_request(
+            &self.github_client,
+            repository_url,
+            title,
+            head_branch,
+            base_branch,
+            body,
+            draft,
+        )
+        .await
+    }
+
+    #[tool(description = "Add a comment to a pull request")]
+    async fn add_comment_to_pull_request(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request number")]
+        pr_number: u64,
+        #[tool(param)]
+        #[schemars(description = "Comment content")]
+        body: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::PullRequestTools::add_comment_to_pull_request(
+            &self.github_client,
+            repository_url,
+            pr_number,
+            body,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "List discussion comments on a pull request one page at a time, optionally filtered by update time and author"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    async fn list_pull_request_comments(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request number")]
+        pr_number: u64,
+        #[tool(param)]
+        #[schemars(
+            description = "Only comments updated at or after this RFC 3339 timestamp (e.g., '2024-01-01T00:00:00Z')"
+        )]
+        since: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Only comments written by this user login")]
+        author: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Page number starting at 1 (default: 1)")]
+        page: Option<u32>,
+        #[tool(param)]
+        #[schemars(description = "Comments per page, 1-100 (default: 30)")]
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::PullRequestTools::list_pull_request_comments(
+            &self.github_client,
+            repository_url,
+            pr_number,
+            since,
+            author,
+            page,
+            per_page,
+        )
+        .await
+    }
+
+    #[tool(description = "Edit an existing pull request comment")]
+    async fn edit_comment_on_pull_request(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request number")]
+        pr_number: u64,
+        #[tool(param)]
+        #[schemars(description = "Comment number to edit")]
+        comment_number: u64,
+        #[tool(param)]
+        #[schemars(description = "New comment content")]
+        body: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::PullRequestTools::edit_comment_on_pull_request(
+            &self.github_client,
+            repository_url,
+            pr_number,
+            PullRequestCommentNumber::new(comment_number),
+            body,
+        )
+        .await
+    }
+
+    #[tool(description = "Close a pull request")]
+    async fn close_pull_request(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request number to close")]
+        pr_number: u64,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::PullRequestTools::close_pull_request(
+            &self.github_client,
+            repository_url,
+            pr_number,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get a pull request with its body, branches, change statistics, labels, reviewers and its first discussion comments. Read it before editing"
+    )]
+    async fn get_pull_request(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request number to get")]
+        pr_number: u64,
+        #[tool(param)]
+        #[schemars(
+            description = "Maximum number of discussion comments to return, oldest first (default: 30, max: 100, 0 for none)"
+        )]
+        comment_limit: Option<u32>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::PullRequestTools::get_pull_request(
+            &self.github_client,
+            repository_url,
+            pr_number,
+            comment_limit,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get up to 20 pull requests by URL, possibly from different repositories, each with its first discussion comments. Pull requests that cannot be read are reported without failing the others"
+    )]
+    async fn get_pull_requests(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Pull request URLs (e.g., ['https://github.com/owner/repo/pull/123'])"
+        )]
+        pull_request_urls: Vec<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Maximum number of discussion comments to return per pull request, oldest first (default: 30, max: 100, 0 for none)"
+        )]
+        comment_limit: Option<u32>,
+        #[tool(param)]
+        #[schemars(description = "Maximum number of pull requests read at once (default: 4)")]
+        concurrency: Option<usize>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::PullRequestTools::get_pull_requests(
+            &self.github_client,
+            pull_request_urls,
+            comment_limit,
+            concurrency,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get the merge status of a pull request: mergeability, merge state, review decision, required checks, and every blocker preventing the merge"
+    )]
+    async fn get_pull_request_merge_status(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request number to inspect")]
+        pr_number: u64,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::PullRequestTools::get_pull_request_merge_status(
+            &self.github_client,
+            repository_url,
+            pr_number,
+        )
+        .await
+    }
+
+    #[tool(description = "Edit the title of a pull request")]
+    async fn edit_pull_request_title(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request number")]
+        pr_number: u64,
+        #[tool(param)]
+        #[schemars(description = "New title content")]
+        title: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::PullRequestTools::edit_pull_request_title(
+            &self.github_client,
+            repository_url,
+            pr_number,
+            title,
+        )
+        .await
+    }
+
+    #[tool(description = "Edit the body of a pull request")]
+    async fn edit_pull_request_body(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request number")]
+        pr_number: u64,
+        #[tool(param)]
+        #[schemars(description = "New body content")]
+        body: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::PullRequestTools::edit_pull_request_body(
+            &self.github_client,
+            repository_url,
+            pr_number,
+            body,
+        )
+        .await
+    }
+
+    #[tool(description = "Add assignees to a pull request")]
+    async fn add_assignees_to_pull_request(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request number")]
+        pr_number: u64,
+        #[tool(param)]
+        #[schemars(description = "List of usernames to add as assignees")]
+        new_assignees: Vec<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::PullRequestTools::add_assignees_to_pull_request(
+            &self.github_client,
+            repository_url,
+            pr_number,
+            new_assignees,
+        )
+        .await
+    }
+
+    #[tool(description = "Remove assignees from a pull request")]
+    async fn remove_assignees_from_pull_request(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request number")]
+        pr_number: u64,
+        #[tool(param)]
+        #[schemars(description = "List of usernames to remove from assignees")]
+        assignees: Vec<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::PullRequestTools::remove_assignees_from_pull_request(
+            &self.github_client,
+            repository_url,
+            pr_number,
+            assignees,
+        )
+        .await
+    }
+
+    #[tool(description = "Add requested reviewers to a pull request")]
+    async fn add_requested_reviewers_to_pull_request(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request number")]
+        pr_number: u64,
+        #[tool(param)]
+        #[schemars(description = "List of usernames to request as reviewers")]
+        new_reviewers: Vec<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::PullRequestTools::add_requested_reviewers_to_pull_request(
+            &self.github_client,
+            repository_url,
+            pr_number,
+            new_reviewers,
+        )
+        .await
+    }
+
+    #[tool(description = "Add labels to a pull request")]
+    async fn add_labels_to_pull_request(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request number")]
+        pr_number: u64,
+        #[tool(param)]
+        #[schemars(description = "List of label names to add")]
+        labels: Vec<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::PullRequestTools::add_labels_to_pull_request(
+            &self.github_client,
+            repository_url,
+            pr_number,
+            labels,
+        )
+        .await
+    }
+
+    #[tool(description = "Remove labels from a pull request")]
+    async fn remove_labels_from_pull_request(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request number")]
+        pr_number: u64,
+        #[tool(param)]
+        #[schemars(description = "List of label names to remove")]
+        labels: Vec<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::PullRequestTools::remove_labels_from_pull_request(
+            &self.github_client,
+            repository_url,
+            pr_number,
+            labels,
+        )
+        .await
+    }
+
+    #[tool(description = "Add milestone to a pull request")]
+    async fn add_milestone_to_pull_request(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request number")]
+        pr_number: u64,
+        #[tool(param)]
+        #[schemars(
+            description = "Milestone number to assign. Mutually exclusive with milestone_title"
+        )]
+        milestone_number: Option<u64>,
+        #[tool(param)]
+        #[schemars(
+            description = "Milestone title to assign, resolved to its number. Mutually exclusive with milestone_number"
+        )]
+        milestone_title: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::PullRequestTools::add_milestone_to_pull_request(
+            &self.github_client,
+            repository_url,
+            pr_number,
+            milestone_number,
+            milestone_title,
+        )
+        .await
+    }
+
+    #[tool(description = "Remove milestone from a pull request")]
+    async fn remove_milestone_from_pull_request(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request number")]
+        pr_number: u64,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::PullRequestTools::remove_milestone_from_pull_request(
+            &self.github_client,
+            repository_url,
+            pr_number,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Check whether the authenticated user can edit, delete, or hide an issue or pull request comment, and who wrote it"
+    )]
+    async fn can_edit_comment(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Comment type (issue_comment, pull_request_comment)")]
+        target_type: String,
+        #[tool(param)]
+        #[schemars(description = "Comment ID")]
+        comment_id: u64,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::CommentTools::can_edit_comment(
+            &self.github_client,
+            repository_url,
+            target_type,
+            comment_id,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Hide (minimize) an issue or pull request comment with a classifier, keeping its content"
+    )]
+    async fn minimize_comment(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Comment type (issue_comment, pull_request_comment)")]
+        target_type: String,
+        #[tool(param)]
+        #[schemars(description = "Comment ID")]
+        comment_id: u64,
+        #[tool(param)]
+        #[schemars(
+            description = "Reason for hiding (spam, abuse, off_topic, outdated, duplicate, resolved)"
+        )]
+        reason: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::CommentTools::minimize_comment(
+            &self.github_client,
+            repository_url,
+            target_type,
+            comment_id,
+            reason,
+        )
+        .await
+    }
+
+    #[tool(description = "Show (unminimize) a previously hidden issue or pull request comment")]
+    async fn unminimize_comment(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Comment type (issue_comment, pull_request_comment)")]
+        target_type: String,
+        #[tool(param)]
+        #[schemars(description = "Comment ID")]
+        comment_id: u64,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::CommentTools::unminimize_comment(
+            &self.github_client,
+            repository_url,
+            target_type,
+            comment_id,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Permanently delete an issue and its comments (requires admin permissions). Takes two calls: the first returns what would be deleted and a confirmation token, the second passes the token to delete"
+    )]
+    async fn delete_issue(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue number")]
+        issue_number: u64,
+        #[tool(param)]
+        #[schemars(
+            description = "Token returned by the first call. Omit it to get a description of what would be deleted and a token; pass it to delete"
+        )]
+        confirmation_token: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::DeletionTools::delete_issue(
+            &self.github_client,
+            &self.confirmations,
+            repository_url,
+            IssueNumber::new(issue_number.try_into().unwrap()),
+            confirmation_token,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Delete a label from a repository, removing it from every issue and pull request. Takes two calls: the first returns what would be deleted and a confirmation token, the second passes the token to delete"
+    )]
+    async fn delete_label(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Label name")]
+        name: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Token returned by the first call. Omit it to get a description of what would be deleted and a token; pass it to delete"
+        )]
+        confirmation_token: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::DeletionTools::delete_label(
+            &self.github_client,
+            &self.confirmations,
+            repository_url,
+            name,
+            confirmation_token,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Delete a milestone; its issues and pull requests are kept without a milestone. Takes two calls: the first returns what would be deleted and a confirmation token, the second passes the token to delete"
+    )]
+    async fn delete_milestone(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Milestone number")]
+        milestone_number: u64,
+        #[tool(param)]
+        #[schemars(
+            description = "Token returned by the first call. Omit it to get a description of what would be deleted and a token; pass it to delete"
+        )]
+        confirmation_token: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::DeletionTools::delete_milestone(
+            &self.github_client,
+            &self.confirmations,
+            repository_url,
+            MilestoneNumber::new(milestone_number),
+            confirmation_token,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Permanently delete an issue or pull request comment. Takes two calls: the first returns what would be deleted and a confirmation token, the second passes the token to delete"
+    )]
+    async fn delete_comment(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Comment type (issue_comment, pull_request_comment)")]
+        target_type: String,
+        #[tool(param)]
+        #[schemars(description = "Comment ID")]
+        comment_id: u64,
+        #[tool(param)]
+        #[schemars(
+            description = "Token returned by the first call. Omit it to get a description of what would be deleted and a token; pass it to delete"
+        )]
+        confirmation_token: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::DeletionTools::delete_comment(
+            &self.github_client,
+            &self.confirmations,
+            repository_url,
+            target_type,
+            comment_id,
+            confirmation_token,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Add an emoji reaction to an issue, pull request, or comment. Use it to acknowledge a comment without posting a new one."
+    )]
+    async fn add_reaction(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Target type (issue, pull_request, issue_comment, pull_request_comment)"
+        )]
+        target_type: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Issue or pull request number, or comment ID for comment targets"
+        )]
+        number: u64,
+        #[tool(param)]
+        #[schemars(
+            description = "Reaction kind (+1, -1, laugh, confused, heart, hooray, rocket, eyes)"
+        )]
+        reaction: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ReactionTools::add_reaction(
+            &self.github_client,
+            repository_url,
+            target_type,
+            number,
+            reaction,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Remove the authenticated user's emoji reaction from an issue, pull request, or comment"
+    )]
+    async fn remove_reaction(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Target type (issue, pull_request, issue_comment, pull_request_comment)"
+        )]
+        target_type: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Issue or pull request number, or comment ID for comment targets"
+        )]
+        number: u64,
+        #[tool(param)]
+        #[schemars(
+            description = "Reaction kind (+1, -1, laugh, confused, heart, hooray, rocket, eyes)"
+        )]
+        reaction: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ReactionTools::remove_reaction(
+            &self.github_client,
+            repository_url,
+            target_type,
+            number,
+            reaction,
+        )
+        .await
+    }
+
+    #[tool(description = "List emoji reactions on an issue, pull request, or comment")]
+    async fn list_reactions(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Target type (issue, pull_request, issue_comment, pull_request_comment)"
+        )]
+        target_type: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Issue or pull request number, or comment ID for comment targets"
+        )]
+        number: u64,
+        #[tool(param)]
+        #[schemars(description = "Optional reaction kind to filter by")]
+        reaction: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ReactionTools::list_reactions(
+            &self.github_client,
+            repository_url,
+            target_type,
+            number,
+            reaction,
+        )
+        .await
+    }
+
+    #[tool(description = "Create a new issue")]
+    async fn create_issue(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue title")]
+        title: String,
+        #[tool(param)]
+        #[schemars(description = "Optional issue body content")]
+        body: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional list of assignee usernames")]
+        assignees: Option<Vec<String>>,
+        #[tool(param)]
+        #[schemars(description = "Optional list of label names")]
+        labels: Option<Vec<String>>,
+        #[tool(param)]
+        #[schemars(description = "Optional milestone ID")]
+        milestone_number: Option<u64>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::create_issue(
+            &self.github_client,
+            repository_url,
+            title,
+            body,
+            assignees,
+            labels,
+            milestone_number,
+        )
+        .await
+    }
+
+    #[tool(description = "Add a comment to an issue")]
+    async fn add_comment_to_issue(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue number")]
+        issue_number: u64,
+        #[tool(param)]
+        #[schemars(description = "Comment content")]
+        body: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::add_comment_to_issue(
+            &self.github_client,
+            repository_url,
+            IssueNumber::new(issue_number.try_into().unwrap()),
+            body,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "List comments on an issue one page at a time, optionally filtered by update time and author"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    async fn list_issue_comments(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue number")]
+        issue_number: u64,
+        #[tool(param)]
+        #[schemars(
+            description = "Only comments updated at or after this RFC 3339 timestamp (e.g., '2024-01-01T00:00:00Z')"
+        )]
+        since: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Only comments written by this user login")]
+        author: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Page number starting at 1 (default: 1)")]
+        page: Option<u32>,
+        #[tool(param)]
+        #[schemars(description = "Comments per page, 1-100 (default: 30)")]
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::list_issue_comments(
+            &self.github_client,
+            repository_url,
+            IssueNumber::new(issue_number.try_into().unwrap()),
+            since,
+            author,
+            page,
+            per_page,
+        )
+        .await
+    }
+
+    #[tool(description = "Edit an existing issue comment")]
+    async fn edit_comment_on_issue(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue number")]
+        issue_number: u64,
+        #[tool(param)]
+        #[schemars(description = "Comment number to edit")]
+        comment_number: u64,
+        #[tool(param)]
+        #[schemars(description = "New comment content")]
+        body: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::edit_comment_on_issue(
+            &self.github_client,
+            repository_url,
+            IssueNumber::new(issue_number.try_into().unwrap()),
+            IssueCommentNumber::new(comment_number),
+            body,
+        )
+        .await
+    }
+
+    #[tool(description = "Edit the title of an issue")]
+    async fn edit_issue_title(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue number")]
+        issue_number: u64,
+        #[tool(param)]
+        #[schemars(description = "New title content")]
+        title: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::edit_issue_title(
+            &self.github_client,
+            repository_url,
+            IssueNumber::new(issue_number.try_into().unwrap()),
+            title,
+        )
+        .await
+    }
+
+    #[tool(description = "Edit the body of an issue")]
+    async fn edit_issue_body(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue number")]
+        issue_number: u64,
+        #[tool(param)]
+        #[schemars(description = "New body content")]
+        body: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::edit_issue_body(
+            &self.github_client,
+            repository_url,
+            IssueNumber::new(issue_number.try_into().unwrap()),
+            body,
+        )
+        .await
+    }
+
+    #[tool(description = "Update the state of an issue")]
+    async fn update_issue_state(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue number")]
+        issue_number: u64,
+        #[tool(param)]
+        #[schemars(description = "New state (open or closed)")]
+        state: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional state reason: 'completed' or 'not_planned' when closing, 'reopened' when opening"
+        )]
+        state_reason: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::update_issue_state(
+            &self.github_client,
+            repository_url,
+            IssueNumber::new(issue_number.try_into().unwrap()),
+            state,
+            state_reason,
+        )
+        .await
+    }
+
+    #[tool(description = "Add assignees to an issue")]
+    async fn add_assignees_to_issue(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue number")]
+        issue_number: u64,
+        #[tool(param)]
+        #[schemars(description = "List of usernames to add as assignees")]
+        new_assignees: Vec<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::add_assignees_to_issue(
+            &self.github_client,
+            repository_url,
+            IssueNumber::new(issue_number.try_into().unwrap()),
+            new_assignees,
+        )
+        .await
+    }
+
+    #[tool(description = "Remove assignees from an issue")]
+    async fn remove_assignees_from_issue(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue number")]
+        issue_number: u64,
+        #[tool(param)]
+        #[schemars(description = "List of usernames to remove from assignees")]
+        assignees: Vec<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::remove_assignees_from_issue(
+            &self.github_client,
+            repository_url,
+            IssueNumber::new(issue_number.try_into().unwrap()),
+            assignees,
+        )
+        .await
+    }
+
+    #[tool(description = "Add labels to an issue")]
+    async fn add_labels_to_issue(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue number")]
+        issue_number: u64,
+        #[tool(param)]
+        #[schemars(description = "List of label names to add")]
+        labels: Vec<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::add_labels_to_issue(
+            &self.github_client,
+            repository_url,
+            IssueNumber::new(issue_number.try_into().unwrap()),
+            labels,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get an issue with its body, labels, assignees and its first comments. Read it before editing"
+    )]
+    async fn get_issue(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue number to get")]
+        issue_number: u64,
+        #[tool(param)]
+        #[schemars(
+            description = "Maximum number of comments to return, oldest first (default: 30, max: 100, 0 for none)"
+        )]
+        comment_limit: Option<u32>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::get_issue(
+            &self.github_client,
+            repository_url,
+            IssueNumber::new(issue_number.try_into().unwrap()),
+            comment_limit,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get up to 20 issues by URL, possibly from different repositories, each with its first comments. Issues that cannot be read are reported without failing the others"
+    )]
+    async fn get_issues(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Issue URLs (e.g., ['https://github.com/owner/repo/issues/123'])"
+        )]
+        issue_urls: Vec<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Maximum number of comments to return per issue, oldest first (default: 30, max: 100, 0 for none)"
+        )]
+        comment_limit: Option<u32>,
+        #[tool(param)]
+        #[schemars(description = "Maximum number of issues read at once (default: 4)")]
+        concurrency: Option<usize>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::get_issues(
+            &self.github_client,
+            issue_urls,
+            comment_limit,
+            concurrency,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "List issues in a repository filtered by state, labels, assignee, milestone, and last update time. Pull requests are excluded"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    async fn list_issues(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue state filter: 'open', 'closed', or 'all' (default: all)")]
+        state: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Label names that every returned issue must have")]
+        labels: Option<Vec<String>>,
+        #[tool(param)]
+        #[schemars(
+            description = "Assignee login; 'none' for unassigned issues, '*' for any assignee"
+        )]
+        assignee: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Milestone number the issues must belong to")]
+        milestone_number: Option<u64>,
+        #[tool(param)]
+        #[schemars(
+            description = "Only issues updated at or after this RFC 3339 timestamp (e.g., '2024-01-01T00:00:00Z')"
+        )]
+        since: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Page number starting at 1 (default: 1)")]
+        page: Option<u32>,
+        #[tool(param)]
+        #[schemars(description = "Issues per page, 1-100 (default: 30)")]
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::list_issues(
+            &self.github_client,
+            repository_url,
+            state,
+            labels,
+            assignee,
+            milestone_number,
+            since,
+            page,
+            per_page,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get the timeline of an issue or pull request: label, assignee, and milestone changes, renames, cross-references, commit references, comments, and state changes"
+    )]
+    async fn get_issue_timeline(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue or pull request number")]
+        issue_number: u64,
+        #[tool(param)]
+        #[schemars(description = "Page number starting at 1 (default: 1)")]
+        page: Option<u32>,
+        #[tool(param)]
+        #[schemars(description = "Events per page, 1-100 (default: 30)")]
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::get_issue_timeline(
+            &self.github_client,
+            repository_url,
+            IssueNumber::new(issue_number.try_into().unwrap()),
+            page,
+            per_page,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Find all issues and pull requests, in any repository, that reference a given issue or pull request. Reports the state of each referencing item and whether merging it will close the target, to help build dependency maps between items."
+    )]
+    async fn find_cross_references(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Issue or pull request URL (e.g., 'https://github.com/owner/repo/issues/123', 'https://github.com/owner/repo/pull/456')"
+        )]
+        issue_or_pr_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::find_cross_references(&self.github_client, issue_or_pr_url)
+            .await
+    }
+
+    #[tool(
+        description = "List the issue types (e.g. Bug, Feature, Task) defined by an organization"
+    )]
+    async fn list_issue_types(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "Organization login that owns the issue types")]
+        owner: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::list_issue_types(&self.github_client, owner).await
+    }
+
+    #[tool(
+        description = "Set the issue type of an issue by name (e.g. Bug, Feature, Task). Omit issue_type to clear it."
+    )]
+    async fn set_issue_type(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue number")]
+        issue_number: u64,
+        #[tool(param)]
+        #[schemars(
+            description = "Issue type name defined by the organization (case-insensitive). Omit to clear the issue type"
+        )]
+        issue_type: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::set_issue_type(
+            &self.github_client,
+            repository_url,
+            IssueNumber::new(issue_number.try_into().unwrap()),
+            issue_type,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Mark an issue as a duplicate: comments 'Duplicate of #N', applies a duplicate label, and closes the issue as not planned. Reports partial failures."
+    )]
+    async fn mark_issue_duplicate(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue number to mark as a duplicate")]
+        issue_number: u64,
+        #[tool(param)]
+        #[schemars(description = "Number of the canonical issue in the same repository")]
+        duplicate_of: u64,
+        #[tool(param)]
+        #[schemars(description = "Optional label to apply (defaults to 'duplicate')")]
+        duplicate_label: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::mark_issue_duplicate(
+            &self.github_client,
+            repository_url,
+            issue_number_param("issue_number", issue_number)?,
+            issue_number_param("duplicate_of", duplicate_of)?,
+            duplicate_label,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Apply the same operations (add labels, set milestone, assign users, close) to multiple issues with bounded concurrency. Reports per-issue success or failure."
+    )]
+    #[allow(clippy::too_many_arguments)]
+    async fn bulk_update_issues(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue numbers to update")]
+        issue_numbers: Vec<u64>,
+        #[tool(param)]
+        #[schemars(description = "Label names to add to every issue")]
+        add_labels: Option<Vec<String>>,
+        #[tool(param)]
+        #[schemars(description = "Milestone number to set on every issue")]
+        milestone_number: Option<u64>,
+        #[tool(param)]
+        #[schemars(description = "Usernames to assign to every issue")]
+        assignees: Option<Vec<String>>,
+        #[tool(param)]
+        #[schemars(description = "Close every issue after applying the other operations")]
+        close: Option<bool>,
+        #[tool(param)]
+        #[schemars(
+            description = "Reason for closing: 'completed' or 'not_planned' (implies close)"
+        )]
+        close_reason: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Maximum number of issues updated at once (default: 4)")]
+        concurrency: Option<usize>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::bulk_update_issues(
+            &self.github_client,
+            repository_url,
+            issue_numbers
+                .into_iter()
+                .map(|number| issue_number_param("issue_numbers", number))
+                .collect::<Result<_, _>>()?,
+            add_labels,
+            milestone_number,
+            assignees,
+            close,
+            close_reason,
+            concurrency,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Link two issues or pull requests: posts a 'Relates to' reference comment on both and verifies the cross-reference events on their timelines"
+    )]
+    async fn link_issues(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL of the source issue (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Source issue or pull request number")]
+        issue_number: u64,
+        #[tool(param)]
+        #[schemars(description = "Target issue or pull request number")]
+        target_issue_number: u64,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL of the target issue (defaults to the source repository)"
+        )]
+        target_repository_url: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::link_issues(
+            &self.github_client,
+            repository_url,
+            issue_number_param("issue_number", issue_number)?,
+            issue_number_param("target_issue_number", target_issue_number)?,
+            target_repository_url,
+        )
+        .await
+    }
+
+    #[tool(description = "Add milestone to an issue")]
+    async fn add_milestone_to_issue(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue number")]
+        issue_number: u64,
+        #[tool(param)]
+        #[schemars(
+            description = "Milestone number to assign. Mutually exclusive with milestone_title"
+        )]
+        milestone_number: Option<u64>,
+        #[tool(param)]
+        #[schemars(
+            description = "Milestone title to assign, resolved to its number. Mutually exclusive with milestone_number"
+        )]
+        milestone_title: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::add_milestone_to_issue(
+            &self.github_client,
+            repository_url,
+            IssueNumber::new(issue_number.try_into().unwrap()),
+            milestone_number,
+            milestone_title,
+        )
+        .await
+    }
+
+    #[tool(description = "Remove labels from an issue")]
+    async fn remove_labels_from_issue(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue number")]
+        issue_number: u64,
+        #[tool(param)]
+        #[schemars(description = "List of label names to remove")]
+        labels: Vec<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::remove_labels_from_issue(
+            &self.github_client,
+            repository_url,
+            IssueNumber::new(issue_number.try_into().unwrap()),
+            labels,
+        )
+        .await
+    }
+
+    #[tool(description = "Remove milestone from an issue")]
+    async fn remove_milestone_from_issue(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Issue number")]
+        issue_number: u64,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::IssueTools::remove_milestone_from_issue(
+            &self.github_client,
+            repository_url,
+            IssueNumber::new(issue_number.try_into().unwrap()),
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get an overview of a repository: description, default branch, visibility, topics, open issue and pull request counts, license, and the permission of the authenticated user"
+    )]
+    async fn get_repository(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::get_repository(&self.github_client, repository_url).await
+    }
+
+    #[tool(description = "Create a new milestone in a repository")]
+    async fn create_milestone(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Milestone title")]
+        title: String,
+        #[tool(param)]
+        #[schemars(description = "Optional milestone description")]
+        description: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional due date in ISO 8601 format (e.g., '2024-01-15T10:30:00Z')"
+        )]
+        due_on: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional state (open or closed)")]
+        state: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::create_milestone(
+            &self.github_client,
+            repository_url,
+            title,
+            description,
+            due_on,
+            state,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "List the milestones of a repository with their numbers, states, progress and due dates"
+    )]
+    async fn list_milestones(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Optional state filter: open, closed or all (default: all)")]
+        state: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::list_milestones(
+            &self.github_client,
+            repository_url,
+            state,
+        )
+        .await
+    }
+
+    #[tool(description = "Create a new label in a repository")]
+    async fn create_label(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Label name")]
+        name: String,
+        #[tool(param)]
+        #[schemars(description = "Optional label color (6 character hex code without #)")]
+        color: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional label description")]
+        description: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::create_label(
+            &self.github_client,
+            repository_url,
+            name,
+            color,
+            description,
+        )
+        .await
+    }
+
+    #[tool(description = "Update an existing label in a repository")]
+    async fn update_label(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Current label name")]
+        old_name: String,
+        #[tool(param)]
+        #[schemars(description = "Optional new label name")]
+        new_name: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional new label color (6 character hex code without #)")]
+        color: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional new label description")]
+        description: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::update_label(
+            &self.github_client,
+            repository_url,
+            old_name,
+            new_name,
+            color,
+            description,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "List the labels of a repository with their colors and descriptions. Use this to check which labels exist before adding labels to issues or pull requests"
+    )]
+    async fn list_labels(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Page number starting at 1 (default: 1)")]
+        page: Option<u32>,
+        #[tool(param)]
+        #[schemars(description = "Labels per page, 1-100 (default: 30)")]
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::list_labels(
+            &self.github_client,
+            repository_url,
+            page,
+            per_page,
+        )
+        .await
+    }
+
+    #[tool(description = "List the branches of a repository with the commit each points to")]
+    async fn list_branches(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Page number starting at 1 (default: 1)")]
+        page: Option<u32>,
+        #[tool(param)]
+        #[schemars(description = "Branches per page, 1-100 (default: 30)")]
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::list_branches(
+            &self.github_client,
+            repository_url,
+            page,
+            per_page,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Create a branch from a branch name, tag or commit SHA, e.g. before creating a pull request"
+    )]
+    async fn create_branch(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Name of the new branch (e.g. 'feature/login')")]
+        name: String,
+        #[tool(param)]
+        #[schemars(description = "Branch, tag or commit SHA to branch from (e.g. 'main')")]
+        from_ref: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::create_branch(
+            &self.github_client,
+            repository_url,
+            name,
+            from_ref,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Rename a branch. Open pull requests and branch protection rules are updated by GitHub"
+    )]
+    async fn rename_branch(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Current branch name")]
+        name: String,
+        #[tool(param)]
+        #[schemars(description = "New branch name")]
+        new_name: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::rename_branch(
+            &self.github_client,
+            repository_url,
+            name,
+            new_name,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Create a release. The tag is created from target_commitish if it does not exist yet"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    async fn create_release(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Tag of the release (e.g. 'v1.0.0')")]
+        tag: String,
+        #[tool(param)]
+        #[schemars(description = "Optional release title (default: the tag)")]
+        name: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional release notes in Markdown. Use generate_release_notes to draft them"
+        )]
+        body: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Create the release as an unpublished draft (default: false)")]
+        draft: Option<bool>,
+        #[tool(param)]
+        #[schemars(description = "Mark the release as a prerelease (default: false)")]
+        prerelease: Option<bool>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional branch or commit SHA to create the tag from (default: the default branch)"
+        )]
+        target_commitish: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::create_release(
+            &self.github_client,
+            repository_url,
+            tag,
+            name,
+            body,
+            draft,
+            prerelease,
+            target_commitish,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Update a release identified by release_id or tag. Only the given settings are changed"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    async fn update_release(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Release ID. Required for drafts; mutually exclusive with tag")]
+        release_id: Option<u64>,
+        #[tool(param)]
+        #[schemars(description = "Tag of a published release. Mutually exclusive with release_id")]
+        tag: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional new tag")]
+        new_tag: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional new release title")]
+        name: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional new release notes in Markdown")]
+        body: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional draft flag; false publishes a draft")]
+        draft: Option<bool>,
+        #[tool(param)]
+        #[schemars(description = "Optional prerelease flag")]
+        prerelease: Option<bool>,
+        #[tool(param)]
+        #[schemars(description = "Optional branch or commit SHA for an unpublished tag")]
+        target_commitish: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::update_release(
+            &self.github_client,
+            repository_url,
+            release_id,
+            tag,
+            new_tag,
+            name,
+            body,
+            draft,
+            prerelease,
+            target_commitish,
+        )
+        .await
+    }
+
+    #[tool(description = "Publish a draft release")]
+    async fn publish_release(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Release ID of the draft")]
+        release_id: u64,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::publish_release(
+            &self.github_client,
+            repository_url,
+            release_id,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Delete a release identified by release_id or tag. The tag itself is kept"
+    )]
+    async fn delete_release(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Release ID. Required for drafts; mutually exclusive with tag")]
+        release_id: Option<u64>,
+        #[tool(param)]
+        #[schemars(description = "Tag of a published release. Mutually exclusive with release_id")]
+        tag: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::delete_release(
+            &self.github_client,
+            repository_url,
+            release_id,
+            tag,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Generate release notes from the pull requests merged since the previous release. The notes are returned, not saved"
+    )]
+    async fn generate_release_notes(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Tag of the release the notes are for")]
+        tag: String,
+        #[tool(param)]
+        #[schemars(description = "Optional tag to start from (default: the latest release)")]
+        previous_tag: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional branch or commit SHA if the tag does not exist yet")]
+        target_commitish: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::generate_release_notes(
+            &self.github_client,
+            repository_url,
+            tag,
+            previous_tag,
+            target_commitish,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get a commit by SHA, branch or tag with its full message, author, parents, line stats and changed files. Use this to reference specific commits in review comments and changelogs."
+    )]
+    async fn get_commit(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Commit SHA (full or abbreviated), branch or tag")]
+        sha: String,
+        #[tool(param)]
+        #[schemars(description = "Include the unified diff of each changed file (default: false)")]
+        include_patch: Option<bool>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::get_commit(
+            &self.github_client,
+            repository_url,
+            sha,
+            include_patch,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "List the commits of a repository, newest first, optionally starting from a branch and restricted to a file path or time range"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    async fn list_commits(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Branch, tag or commit SHA to start listing from (default: the default branch)"
+        )]
+        branch: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Only commits touching this file or directory path")]
+        path: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Only commits authored at or after this RFC 3339 timestamp (e.g., '2024-01-01T00:00:00Z')"
+        )]
+        since: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Only commits authored at or before this RFC 3339 timestamp")]
+        until: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Page number starting at 1 (default: 1)")]
+        page: Option<u32>,
+        #[tool(param)]
+        #[schemars(description = "Commits per page, 1-100 (default: 30)")]
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::list_commits(
+            &self.github_client,
+            repository_url,
+            branch,
+            path,
+            since,
+            until,
+            page,
+            per_page,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Get the contents of a file in a repository together with its blob SHA, which is required to update or delete the file"
+    )]
+    async fn get_file_content(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "File path relative to the repository root (e.g. 'src/main.rs')")]
+        path: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional branch, tag or commit SHA (default: the default branch)"
+        )]
+        git_ref: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::get_file_content(
+            &self.github_client,
+            repository_url,
+            path,
+            git_ref,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Create a file or replace the contents of an existing file in a single commit. Pass the current blob SHA from get_file_content when updating"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    async fn create_or_update_file(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "File path relative to the repository root (e.g. 'src/main.rs')")]
+        path: String,
+        #[tool(param)]
+        #[schemars(description = "The complete new file contents (UTF-8 text)")]
+        content: String,
+        #[tool(param)]
+        #[schemars(description = "Commit message")]
+        message: String,
+        #[tool(param)]
+        #[schemars(description = "Optional branch to commit to (default: the default branch)")]
+        branch: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Blob SHA of the file being replaced. Required when updating an existing file, omit when creating"
+        )]
+        sha: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::create_or_update_file(
+            &self.github_client,
+            repository_url,
+            path,
+            content,
+            message,
+            branch,
+            sha,
+        )
+        .await
+    }
+
+    #[tool(description = "Delete a file in a single commit")]
+    async fn delete_file(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "File path relative to the repository root")]
+        path: String,
+        #[tool(param)]
+        #[schemars(description = "Commit message")]
+        message: String,
+        #[tool(param)]
+        #[schemars(description = "Blob SHA of the file being deleted, from get_file_content")]
+        sha: String,
+        #[tool(param)]
+        #[schemars(description = "Optional branch to commit to (default: the default branch)")]
+        branch: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::delete_file(
+            &self.github_client,
+            repository_url,
+            path,
+            message,
+            sha,
+            branch,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Fork a repository to the authenticated user or an organization. Use sync_fork_with_upstream to keep the fork up to date"
+    )]
+    async fn fork_repository(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional organization to create the fork in (default: the authenticated user)"
+        )]
+        organization: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Copy only the default branch (default: false)")]
+        default_branch_only: Option<bool>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::fork_repository(
+            &self.github_client,
+            repository_url,
+            organization,
+            default_branch_only,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Sync a branch of a fork with the same branch of its upstream repository. Reports whether the branch was fast-forwarded, merged or already up to date"
+    )]
+    async fn sync_fork_with_upstream(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "URL of the fork (e.g., 'https://github.com/me/repo', 'me/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Optional branch to sync (default: the default branch)")]
+        branch: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::sync_fork_with_upstream(
+            &self.github_client,
+            repository_url,
+            branch,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Update repository settings. Only the given settings are changed; at least one is required. Requires admin access"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    async fn update_repository_settings(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Optional new description")]
+        description: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional new homepage URL")]
+        homepage: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional topics. Replaces all current topics; an empty list removes them"
+        )]
+        topics: Option<Vec<String>>,
+        #[tool(param)]
+        #[schemars(description = "Optional flag to enable issues")]
+        has_issues: Option<bool>,
+        #[tool(param)]
+        #[schemars(description = "Optional flag to enable the wiki")]
+        has_wiki: Option<bool>,
+        #[tool(param)]
+        #[schemars(description = "Optional flag to enable classic projects")]
+        has_projects: Option<bool>,
+        #[tool(param)]
+        #[schemars(description = "Optional new default branch. The branch must exist")]
+        default_branch: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional flag to allow squash merging")]
+        allow_squash_merge: Option<bool>,
+        #[tool(param)]
+        #[schemars(description = "Optional flag to allow merge commits")]
+        allow_merge_commit: Option<bool>,
+        #[tool(param)]
+        #[schemars(description = "Optional flag to allow rebase merging")]
+        allow_rebase_merge: Option<bool>,
+        #[tool(param)]
+        #[schemars(description = "Optional flag to delete head branches after merge")]
+        delete_branch_on_merge: Option<bool>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::update_repository_settings(
+            &self.github_client,
+            repository_url,
+            RepositorySettings {
+                description,
+                homepage,
+                topics,
+                has_issues,
+                has_wiki,
+                has_projects,
+                default_branch,
+                allow_squash_merge,
+                allow_merge_commit,
+                allow_rebase_merge,
+                delete_branch_on_merge,
+            },
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Archive a repository, making it read-only. Requires admin access and confirm set to true"
+    )]
+    async fn archive_repository(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Must be true to archive the repository. Confirm with the user before setting it"
+        )]
+        confirm: bool,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::archive_repository(
+            &self.github_client,
+            repository_url,
+            confirm,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Unarchive an archived repository so it can be changed again. Requires admin access"
+    )]
+    async fn unarchive_repository(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::unarchive_repository(&self.github_client, repository_url)
+            .await
+    }
+
+    #[tool(
+        description = "Transfer a repository to another user or organization. The transfer completes asynchronously; the tool waits briefly and reports whether it completed or is still pending. Requires admin access and confirm set to true"
+    )]
+    async fn transfer_repository(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "The user or organization receiving the repository")]
+        new_owner: String,
+        #[tool(param)]
+        #[schemars(description = "Optional new name of the repository under the new owner")]
+        new_name: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional IDs of teams in the new organization to grant access to the repository"
+        )]
+        team_ids: Option<Vec<u64>>,
+        #[tool(param)]
+        #[schemars(
+            description = "Seconds to wait for the transfer to complete (default: 10, 0 to return immediately)"
+        )]
+        wait_seconds: Option<u64>,
+        #[tool(param)]
+        #[schemars(
+            description = "Must be true to transfer the repository. Confirm with the user before setting it"
+        )]
+        confirm: bool,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::transfer_repository(
+            &self.github_client,
+            repository_url,
+            new_owner,
+            new_name,
+            team_ids,
+            wait_seconds,
+            confirm,
+        )
+        .await
+    }
+
+    #[tool(description = "Check whether a transferred repository is available under its new owner")]
+    async fn get_repository_transfer_status(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL under the new owner (e.g., 'https://github.com/new-owner/repo')"
+        )]
+        target_repository_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::get_repository_transfer_status(
+            &self.github_client,
+            target_repository_url,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Copy labels and milestones from a source repository to a target repository. Items missing from the target are created; existing items (labels matched case-insensitively by name, milestones by title) are skipped, overwritten or reported as failures"
+    )]
+    async fn copy_repository_metadata(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Source repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        source_repository_url: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Target repository URL (e.g., 'https://github.com/owner/other-repo', 'owner/other-repo')"
+        )]
+        target_repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Whether to copy labels (default: true)")]
+        labels: Option<bool>,
+        #[tool(param)]
+        #[schemars(description = "Whether to copy milestones (default: true)")]
+        milestones: Option<bool>,
+        #[tool(param)]
+        #[schemars(
+            description = "How to handle items that already exist in the target: skip, overwrite or fail (default: skip)"
+        )]
+        on_conflict: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::RepositoryTools::copy_repository_metadata(
+            &self.github_client,
+            source_repository_url,
+            target_repository_url,
+            labels,
+            milestones,
+            on_conflict,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Trigger a workflow_dispatch event to run a GitHub Actions workflow on a branch or tag. The run is queued asynchronously; use list_workflow_runs with event 'workflow_dispatch' to follow it"
+    )]
+    async fn dispatch_workflow(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Workflow file name (e.g., 'ci.yml') or numeric workflow ID")]
+        workflow: String,
+        #[tool(param)]
+        #[schemars(description = "Branch or tag to run the workflow on (e.g., 'main')")]
+        git_ref: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional input values for the workflow's workflow_dispatch inputs, as string key/value pairs"
+        )]
+        inputs: Option<BTreeMap<String, String>>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ActionsTools::dispatch_workflow(
+            &self.github_client,
+            repository_url,
+            workflow,
+            git_ref,
+            inputs,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "List GitHub Actions workflow runs of a repository, most recent first, with their status, conclusion and run ID"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    async fn list_workflow_runs(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional workflow file name (e.g., 'ci.yml') or numeric workflow ID to list runs of"
+        )]
+        workflow: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional branch the runs were triggered for")]
+        branch: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional event that triggered the runs (e.g., 'push', 'pull_request', 'workflow_dispatch')"
+        )]
+        event: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional run status or conclusion (e.g., 'queued', 'in_progress', 'completed', 'success', 'failure')"
+        )]
+        status: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Page number starting at 1 (default: 1)")]
+        page: Option<u32>,
+        #[tool(param)]
+        #[schemars(description = "Runs per page, 1-100 (default: 30)")]
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ActionsTools::list_workflow_runs(
+            &self.github_client,
+            repository_url,
+            workflow,
+            branch,
+            event,
+            status,
+            page,
+            per_page,
+        )
+        .await
+    }
+
+    #[tool(description = "Cancel a queued or in-progress GitHub Actions workflow run")]
+    async fn cancel_workflow_run(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Workflow run ID")]
+        run_id: u64,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ActionsTools::cancel_workflow_run(
+            &self.github_client,
+            repository_url,
+            run_id,
+        )
+        .await
+    }
+
+    #[tool(description = "Re-run all jobs of a completed GitHub Actions workflow run")]
+    async fn rerun_workflow_run(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Workflow run ID")]
+        run_id: u64,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ActionsTools::rerun_workflow_run(
+            &self.github_client,
+            repository_url,
+            run_id,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Approve a GitHub Actions workflow run that waits for maintainer approval, e.g. a run triggered by a pull request from a first-time contributor's fork. Review the pull request's changes before approving"
+    )]
+    async fn approve_workflow_run(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Workflow run ID")]
+        run_id: u64,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ActionsTools::approve_workflow_run(
+            &self.github_client,
+            repository_url,
+            run_id,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Approve all GitHub Actions workflow runs of a pull request's head commit that wait for maintainer approval. Review the pull request's changes before approving"
+    )]
+    async fn approve_pending_runs_for_pr(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Pull request number")]
+        pr_number: u32,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ActionsTools::approve_pending_runs_for_pr(
+            &self.github_client,
+            repository_url,
+            pr_number,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Re-run only the failed jobs of a completed GitHub Actions workflow run (and the jobs they depend on), e.g. to retry flaky CI without re-running the whole workflow"
+    )]
+    async fn rerun_failed_jobs(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Workflow run ID")]
+        run_id: u64,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ActionsTools::rerun_failed_jobs(
+            &self.github_client,
+            repository_url,
+            run_id,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "List the jobs of a GitHub Actions workflow run with their status, conclusion and failed steps"
+    )]
+    async fn list_jobs_for_run(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Workflow run ID")]
+        run_id: u64,
+        #[tool(param)]
+        #[schemars(
+            description = "Whether to include jobs of earlier run attempts (default: false, latest attempt only)"
+        )]
+        all_attempts: Option<bool>,
+        #[tool(param)]
+        #[schemars(description = "Page number starting at 1 (default: 1)")]
+        page: Option<u32>,
+        #[tool(param)]
+        #[schemars(description = "Jobs per page, 1-100 (default: 30)")]
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ActionsTools::list_jobs_for_run(
+            &self.github_client,
+            repository_url,
+            run_id,
+            all_attempts,
+            page,
+            per_page,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "List the deployments of a GitHub Actions workflow run that wait for review on protected environments, with the environment IDs, required reviewers and whether you can approve"
+    )]
+    async fn list_pending_deployments(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Workflow run ID")]
+        run_id: u64,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::ActionsTools::list_pending_deployments(
+            &self.github_client,
+            repository_url,
+            run_id,
+        )
+        .await
+    }
+
+    #[tool(
+        description = "Approve or reject deployments of a GitHub Actions workflow run that wait for review on protected environments. Requires being a required reviewer of the environments"
+    )]
+    async fn review_pending_deployments(
         &self,
         #[tool(param)]
         #[schemars(
@@ -404,23 +3469,35 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Pull request number")]
-        pr_number: u64,
+        #[schemars(description = "Workflow run ID")]
+        run_id: u64,
         #[tool(param)]
-        #[schemars(description = "New body content")]
-        body: String,
+        #[schemars(
+            description = "IDs of the environments to review, as listed by list_pending_deployments"
+        )]
+        environment_ids: Vec<u64>,
+        #[tool(param)]
+        #[schemars(description = "Review decision: approved or rejected")]
+        state: String,
+        #[tool(param)]
+        #[schemars(description = "Optional comment recorded with the review")]
+        comment: Option<String>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::PullRequestTools::edit_pull_request_body(
+        tool_definition::ActionsTools::review_pending_deployments(
             &self.github_client,
             repository_url,
-            pr_number,
-            body,
+            run_id,
+            environment_ids,
+            state,
+            comment,
         )
         .await
     }
 
-    #[tool(description = "Add assignees to a pull request")]
-    async fn add_assignees_to_pull_request(
+    #[tool(
+        description = "Get the logs of a GitHub Actions workflow run to diagnose failing CI. Returns the last lines of each job log, or saves the full zip archive to save_path"
+    )]
+    async fn get_workflow_run_logs(
         &self,
         #[tool(param)]
         #[schemars(
@@ -428,23 +3505,31 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Pull request number")]
-        pr_number: u64,
+        #[schemars(description = "Workflow run ID")]
+        run_id: u64,
         #[tool(param)]
-        #[schemars(description = "List of usernames to add as assignees")]
-        new_assignees: Vec<String>,
+        #[schemars(
+            description = "Optional file path to save the zip archive of all logs to instead of returning them as text"
+        )]
+        save_path: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Number of trailing lines to return per job log (default: 100)")]
+        tail_lines: Option<usize>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::PullRequestTools::add_assignees_to_pull_request(
+        tool_definition::ActionsTools::get_workflow_run_logs(
             &self.github_client,
             repository_url,
-            pr_number,
-            new_assignees,
+            run_id,
+            save_path,
+            tail_lines,
         )
         .await
     }
 
-    #[tool(description = "Remove assignees from a pull request")]
-    async fn remove_assignees_from_pull_request(
+    #[tool(
+        description = "List the artifacts uploaded by GitHub Actions workflow runs of a repository, or of a single run"
+    )]
+    async fn list_artifacts(
         &self,
         #[tool(param)]
         #[schemars(
@@ -452,23 +3537,29 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Pull request number")]
-        pr_number: u64,
+        #[schemars(description = "Optional workflow run ID to list the artifacts of")]
+        run_id: Option<u64>,
         #[tool(param)]
-        #[schemars(description = "List of usernames to remove from assignees")]
-        assignees: Vec<String>,
+        #[schemars(description = "Page number starting at 1 (default: 1)")]
+        page: Option<u32>,
+        #[tool(param)]
+        #[schemars(description = "Artifacts per page, 1-100 (default: 30)")]
+        per_page: Option<u8>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::PullRequestTools::remove_assignees_from_pull_request(
+        tool_definition::ActionsTools::list_artifacts(
             &self.github_client,
             repository_url,
-            pr_number,
-            assignees,
+            run_id,
+            page,
+            per_page,
         )
         .await
     }
 
-    #[tool(description = "Add requested reviewers to a pull request")]
-    async fn add_requested_reviewers_to_pull_request(
+    #[tool(
+        description = "Download a GitHub Actions artifact as '<artifact name>.zip' into a local directory and return the saved path"
+    )]
+    async fn download_artifact(
         &self,
         #[tool(param)]
         #[schemars(
@@ -476,23 +3567,27 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Pull request number")]
-        pr_number: u64,
+        #[schemars(description = "Artifact ID")]
+        artifact_id: u64,
         #[tool(param)]
-        #[schemars(description = "List of usernames to request as reviewers")]
-        new_reviewers: Vec<String>,
+        #[schemars(
+            description = "Optional directory to save the artifact in (default: the system temporary directory)"
+        )]
+        output_dir: Option<String>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::PullRequestTools::add_requested_reviewers_to_pull_request(
+        tool_definition::ActionsTools::download_artifact(
             &self.github_client,
             repository_url,
-            pr_number,
-            new_reviewers,
+            artifact_id,
+            output_dir,
         )
         .await
     }
 
-    #[tool(description = "Add labels to a pull request")]
-    async fn add_labels_to_pull_request(
+    #[tool(
+        description = "List check runs (e.g., GitHub Actions jobs and other GitHub App checks) for a commit SHA, branch or tag, such as the head of a pull request"
+    )]
+    async fn list_check_runs_for_ref(
         &self,
         #[tool(param)]
         #[schemars(
@@ -500,23 +3595,33 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Pull request number")]
-        pr_number: u64,
+        #[schemars(description = "Commit SHA, branch name or tag name")]
+        git_ref: String,
         #[tool(param)]
-        #[schemars(description = "List of label names to add")]
-        labels: Vec<String>,
+        #[schemars(description = "Optional check run name to filter by")]
+        check_name: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Page number starting at 1 (default: 1)")]
+        page: Option<u32>,
+        #[tool(param)]
+        #[schemars(description = "Check runs per page, 1-100 (default: 30)")]
+        per_page: Option<u8>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::PullRequestTools::add_labels_to_pull_request(
+        tool_definition::CheckTools::list_check_runs_for_ref(
             &self.github_client,
             repository_url,
-            pr_number,
-            labels,
+            git_ref,
+            check_name,
+            page,
+            per_page,
         )
         .await
     }
 
-    #[tool(description = "Remove labels from a pull request")]
-    async fn remove_labels_from_pull_request(
+    #[tool(
+        description = "Get the combined commit status (error, failure, pending or success) and the latest status of each context for a commit SHA, branch or tag. Check runs are not included; use list_check_runs_for_ref for them"
+    )]
+    async fn get_combined_status(
         &self,
         #[tool(param)]
         #[schemars(
@@ -524,23 +3629,21 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Pull request number")]
-        pr_number: u64,
-        #[tool(param)]
-        #[schemars(description = "List of label names to remove")]
-        labels: Vec<String>,
+        #[schemars(description = "Commit SHA, branch name or tag name")]
+        git_ref: String,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::PullRequestTools::remove_labels_from_pull_request(
+        tool_definition::CheckTools::get_combined_status(
             &self.github_client,
             repository_url,
-            pr_number,
-            labels,
+            git_ref,
         )
         .await
     }
 
-    #[tool(description = "Add milestone to a pull request")]
-    async fn add_milestone_to_pull_request(
+    #[tool(
+        description = "Create a commit status on a commit SHA. A new status for the same context replaces the previous one"
+    )]
+    async fn create_commit_status(
         &self,
         #[tool(param)]
         #[schemars(
@@ -548,23 +3651,39 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Pull request number")]
-        pr_number: u64,
+        #[schemars(description = "Full commit SHA to attach the status to")]
+        sha: String,
         #[tool(param)]
-        #[schemars(description = "Milestone ID to assign")]
-        milestone_number: u64,
+        #[schemars(description = "Status state: error, failure, pending or success")]
+        state: String,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional label identifying the status, e.g. 'ci/lint' (default: 'default')"
+        )]
+        context: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional short description of the status")]
+        description: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional URL with details of the status")]
+        target_url: Option<String>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::PullRequestTools::add_milestone_to_pull_request(
+        tool_definition::CheckTools::create_commit_status(
             &self.github_client,
             repository_url,
-            pr_number,
-            milestone_number,
+            sha,
+            state,
+            context,
+            description,
+            target_url,
         )
         .await
     }
 
-    #[tool(description = "Remove milestone from a pull request")]
-    async fn remove_milestone_from_pull_request(
+    #[tool(
+        description = "Create or update a GitHub Actions secret of a repository. The value is encrypted with the repository public key before it is sent"
+    )]
+    async fn set_repo_secret(
         &self,
         #[tool(param)]
         #[schemars(
@@ -572,19 +3691,25 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Pull request number")]
-        pr_number: u64,
+        #[schemars(
+            description = "Secret name (alphanumeric characters and underscores, must not start with a digit or GITHUB_)"
+        )]
+        name: String,
+        #[tool(param)]
+        #[schemars(description = "Plain-text secret value")]
+        value: String,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::PullRequestTools::remove_milestone_from_pull_request(
+        tool_definition::SecretTools::set_repo_secret(
             &self.github_client,
             repository_url,
-            pr_number,
+            name,
+            value,
         )
         .await
     }
 
-    #[tool(description = "Create a new issue")]
-    async fn create_issue(
+    #[tool(description = "Delete a GitHub Actions secret of a repository")]
+    async fn delete_repo_secret(
         &self,
         #[tool(param)]
         #[schemars(
@@ -592,35 +3717,43 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Issue title")]
-        title: String,
-        #[tool(param)]
-        #[schemars(description = "Optional issue body content")]
-        body: Option<String>,
+        #[schemars(description = "Secret name")]
+        name: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::SecretTools::delete_repo_secret(&self.github_client, repository_url, name)
+            .await
+    }
+
+    #[tool(
+        description = "List the GitHub Actions secrets of a repository. Only names and timestamps are returned; secret values cannot be read back"
+    )]
+    async fn list_secrets(
+        &self,
         #[tool(param)]
-        #[schemars(description = "Optional list of assignee usernames")]
-        assignees: Option<Vec<String>>,
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Optional list of label names")]
-        labels: Option<Vec<String>>,
+        #[schemars(description = "Page number starting at 1 (default: 1)")]
+        page: Option<u32>,
         #[tool(param)]
-        #[schemars(description = "Optional milestone ID")]
-        milestone_number: Option<u64>,
+        #[schemars(description = "Secrets per page, 1-100 (default: 30)")]
+        per_page: Option<u8>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::IssueTools::create_issue(
+        tool_definition::SecretTools::list_secrets(
             &self.github_client,
             repository_url,
-            title,
-            body,
-            assignees,
-            labels,
-            milestone_number,
+            page,
+            per_page,
         )
         .await
     }
 
-    #[tool(description = "Add a comment to an issue")]
-    async fn add_comment_to_issue(
+    #[tool(
+        description = "List the GitHub Actions configuration variables of a repository with their values"
+    )]
+    async fn list_variables(
         &self,
         #[tool(param)]
         #[schemars(
@@ -628,23 +3761,23 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Issue number")]
-        issue_number: u64,
+        #[schemars(description = "Page number starting at 1 (default: 1)")]
+        page: Option<u32>,
         #[tool(param)]
-        #[schemars(description = "Comment content")]
-        body: String,
+        #[schemars(description = "Variables per page, 1-100 (default: 30)")]
+        per_page: Option<u8>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::IssueTools::add_comment_to_issue(
+        tool_definition::SecretTools::list_variables(
             &self.github_client,
             repository_url,
-            IssueNumber::new(issue_number.try_into().unwrap()),
-            body,
+            page,
+            per_page,
         )
         .await
     }
 
-    #[tool(description = "Edit an existing issue comment")]
-    async fn edit_comment_on_issue(
+    #[tool(description = "Get a GitHub Actions configuration variable of a repository")]
+    async fn get_variable(
         &self,
         #[tool(param)]
         #[schemars(
@@ -652,27 +3785,73 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Issue number")]
-        issue_number: u64,
+        #[schemars(description = "Variable name")]
+        name: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::SecretTools::get_variable(&self.github_client, repository_url, name).await
+    }
+
+    #[tool(
+        description = "Create a GitHub Actions configuration variable of a repository, or update its value if it already exists"
+    )]
+    async fn set_variable(
+        &self,
         #[tool(param)]
-        #[schemars(description = "Comment number to edit")]
-        comment_number: u64,
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
         #[tool(param)]
-        #[schemars(description = "New comment content")]
-        body: String,
+        #[schemars(
+            description = "Variable name (alphanumeric characters and underscores, must not start with a digit or GITHUB_)"
+        )]
+        name: String,
+        #[tool(param)]
+        #[schemars(description = "Variable value")]
+        value: String,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::IssueTools::edit_comment_on_issue(
+        tool_definition::SecretTools::set_variable(&self.github_client, repository_url, name, value)
+            .await
+    }
+
+    #[tool(description = "Delete a GitHub Actions configuration variable of a repository")]
+    async fn delete_variable(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Variable name")]
+        name: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::SecretTools::delete_variable(&self.github_client, repository_url, name)
+            .await
+    }
+
+    #[tool(
+        description = "List the discussion categories of a repository with their names, slugs, node IDs and whether they are answerable (Q&A)"
+    )]
+    async fn list_discussion_categories(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::DiscussionTools::list_discussion_categories(
             &self.github_client,
             repository_url,
-            IssueNumber::new(issue_number.try_into().unwrap()),
-            IssueCommentNumber::new(comment_number),
-            body,
         )
         .await
     }
 
-    #[tool(description = "Edit the title of an issue")]
-    async fn edit_issue_title(
+    #[tool(
+        description = "Get a discussion with its body, category, state and comments including replies. Comment node IDs are shown for replying and marking answers"
+    )]
+    async fn get_discussion(
         &self,
         #[tool(param)]
         #[schemars(
@@ -680,23 +3859,49 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Issue number")]
-        issue_number: u64,
+        #[schemars(description = "Discussion number")]
+        discussion_number: u32,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::DiscussionTools::get_discussion(
+            &self.github_client,
+            repository_url,
+            discussion_number,
+        )
+        .await
+    }
+
+    #[tool(description = "Create a discussion in a repository")]
+    async fn create_discussion(
+        &self,
         #[tool(param)]
-        #[schemars(description = "New title content")]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository_url: String,
+        #[tool(param)]
+        #[schemars(description = "Category name, slug or node ID (e.g., 'Q&A', 'general')")]
+        category: String,
+        #[tool(param)]
+        #[schemars(description = "Discussion title")]
         title: String,
+        #[tool(param)]
+        #[schemars(description = "Discussion body in Markdown")]
+        body: String,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::IssueTools::edit_issue_title(
+        tool_definition::DiscussionTools::create_discussion(
             &self.github_client,
             repository_url,
-            IssueNumber::new(issue_number.try_into().unwrap()),
+            category,
             title,
+            body,
         )
         .await
     }
 
-    #[tool(description = "Edit the body of an issue")]
-    async fn edit_issue_body(
+    #[tool(
+        description = "Add a comment to a discussion, or reply to one of its top-level comments"
+    )]
+    async fn add_discussion_comment(
         &self,
         #[tool(param)]
         #[schemars(
@@ -704,23 +3909,42 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Issue number")]
-        issue_number: u64,
+        #[schemars(description = "Discussion number")]
+        discussion_number: u32,
         #[tool(param)]
-        #[schemars(description = "New body content")]
+        #[schemars(description = "Comment body in Markdown")]
         body: String,
+        #[tool(param)]
+        #[schemars(description = "Optional node ID of the top-level comment to reply to")]
+        reply_to_id: Option<String>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::IssueTools::edit_issue_body(
+        tool_definition::DiscussionTools::add_discussion_comment(
             &self.github_client,
             repository_url,
-            IssueNumber::new(issue_number.try_into().unwrap()),
+            discussion_number,
             body,
+            reply_to_id,
         )
         .await
     }
 
-    #[tool(description = "Update the state of an issue")]
-    async fn update_issue_state(
+    #[tool(
+        description = "Mark a discussion comment as the answer of its discussion. Only works in answerable (Q&A) categories and replaces any previous answer"
+    )]
+    async fn mark_comment_as_answer(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "Node ID of the discussion comment (e.g., 'DC_kwDOA...')")]
+        comment_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::DiscussionTools::mark_comment_as_answer(&self.github_client, comment_id)
+            .await
+    }
+
+    #[tool(
+        description = "Lock a discussion so only collaborators can comment on it, optionally recording a reason (off_topic, resolved, spam, too_heated)"
+    )]
+    async fn lock_discussion(
         &self,
         #[tool(param)]
         #[schemars(
@@ -728,23 +3952,23 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Issue number")]
-        issue_number: u64,
+        #[schemars(description = "Discussion number")]
+        discussion_number: u32,
         #[tool(param)]
-        #[schemars(description = "New state (open or closed)")]
-        state: String,
+        #[schemars(description = "Optional lock reason: off_topic, resolved, spam or too_heated")]
+        reason: Option<String>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::IssueTools::update_issue_state(
+        tool_definition::DiscussionTools::lock_discussion(
             &self.github_client,
             repository_url,
-            IssueNumber::new(issue_number.try_into().unwrap()),
-            state,
+            discussion_number,
+            reason,
         )
         .await
     }
 
-    #[tool(description = "Add assignees to an issue")]
-    async fn add_assignees_to_issue(
+    #[tool(description = "Unlock a previously locked discussion")]
+    async fn unlock_discussion(
         &self,
         #[tool(param)]
         #[schemars(
@@ -752,23 +3976,21 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Issue number")]
-        issue_number: u64,
-        #[tool(param)]
-        #[schemars(description = "List of usernames to add as assignees")]
-        new_assignees: Vec<String>,
+        #[schemars(description = "Discussion number")]
+        discussion_number: u32,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::IssueTools::add_assignees_to_issue(
+        tool_definition::DiscussionTools::unlock_discussion(
             &self.github_client,
             repository_url,
-            IssueNumber::new(issue_number.try_into().unwrap()),
-            new_assignees,
+            discussion_number,
         )
         .await
     }
 
-    #[tool(description = "Remove assignees from an issue")]
-    async fn remove_assignees_from_issue(
+    #[tool(
+        description = "Update the title, body or category of a discussion. Fields that are not given are left unchanged"
+    )]
+    async fn update_discussion(
         &self,
         #[tool(param)]
         #[schemars(
@@ -776,47 +3998,140 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Issue number")]
-        issue_number: u64,
+        #[schemars(description = "Discussion number")]
+        discussion_number: u32,
         #[tool(param)]
-        #[schemars(description = "List of usernames to remove from assignees")]
-        assignees: Vec<String>,
+        #[schemars(description = "Optional new title")]
+        title: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional new body in Markdown")]
+        body: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Optional new category name, slug or node ID")]
+        category: Option<String>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::IssueTools::remove_assignees_from_issue(
+        tool_definition::DiscussionTools::update_discussion(
             &self.github_client,
             repository_url,
-            IssueNumber::new(issue_number.try_into().unwrap()),
-            assignees,
+            discussion_number,
+            title,
+            body,
+            category,
         )
         .await
     }
 
-    #[tool(description = "Add labels to an issue")]
-    async fn add_labels_to_issue(
+    #[tool(
+        description = "Delete a discussion comment or reply. Deleting a top-level comment also deletes its replies"
+    )]
+    async fn delete_discussion_comment(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "Node ID of the discussion comment (e.g., 'DC_kwDOA...')")]
+        comment_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::DiscussionTools::delete_discussion_comment(&self.github_client, comment_id)
+            .await
+    }
+
+    #[tool(
+        description = "List notifications of the authenticated user, most recently updated first. By default only unread notifications are listed. Each line starts with the thread ID used by the other notification tools"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    async fn list_notifications(
         &self,
         #[tool(param)]
         #[schemars(
-            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+            description = "Optional repository URL to restrict the notifications to (e.g., 'https://github.com/owner/repo', 'owner/repo')"
         )]
-        repository_url: String,
+        repository_url: Option<String>,
         #[tool(param)]
-        #[schemars(description = "Issue number")]
-        issue_number: u64,
+        #[schemars(description = "Include notifications already marked as read (default: false)")]
+        all: Option<bool>,
         #[tool(param)]
-        #[schemars(description = "List of label names to add")]
-        labels: Vec<String>,
+        #[schemars(
+            description = "Only include notifications where you are directly participating or mentioned (default: false)"
+        )]
+        participating: Option<bool>,
+        #[tool(param)]
+        #[schemars(
+            description = "Only include notifications updated at or after this RFC 3339 timestamp (e.g., '2024-01-01T00:00:00Z')"
+        )]
+        since: Option<String>,
+        #[tool(param)]
+        #[schemars(
+            description = "Only include notifications updated before this RFC 3339 timestamp"
+        )]
+        before: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Page number starting at 1 (default: 1)")]
+        page: Option<u32>,
+        #[tool(param)]
+        #[schemars(description = "Notifications per page, 1-100 (default: 30)")]
+        per_page: Option<u8>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::IssueTools::add_labels_to_issue(
+        tool_definition::NotificationTools::list_notifications(
             &self.github_client,
             repository_url,
-            IssueNumber::new(issue_number.try_into().unwrap()),
-            labels,
+            all,
+            participating,
+            since,
+            before,
+            page,
+            per_page,
         )
         .await
     }
 
-    #[tool(description = "Add milestone to an issue")]
-    async fn add_milestone_to_issue(
+    #[tool(description = "Mark a notification thread as read")]
+    async fn mark_notification_read(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "Notification thread ID")]
+        thread_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::NotificationTools::mark_notification_read(&self.github_client, thread_id)
+            .await
+    }
+
+    #[tool(description = "Mark a notification thread as done, removing it from the inbox")]
+    async fn mark_thread_done(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "Notification thread ID")]
+        thread_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::NotificationTools::mark_thread_done(&self.github_client, thread_id).await
+    }
+
+    #[tool(
+        description = "Subscribe to a notification thread to receive its updates. Also unmutes an ignored thread"
+    )]
+    async fn subscribe_thread(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "Notification thread ID")]
+        thread_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::NotificationTools::subscribe_thread(&self.github_client, thread_id).await
+    }
+
+    #[tool(
+        description = "Unsubscribe from a notification thread. Notifications resume when you are mentioned or comment again"
+    )]
+    async fn unsubscribe_thread(
+        &self,
+        #[tool(param)]
+        #[schemars(description = "Notification thread ID")]
+        thread_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::NotificationTools::unsubscribe_thread(&self.github_client, thread_id).await
+    }
+
+    #[tool(
+        description = "Get your subscription state on an issue or pull request: subscribed (all conversation), unsubscribed (only when participating or mentioned) or ignored"
+    )]
+    async fn get_issue_subscription(
         &self,
         #[tool(param)]
         #[schemars(
@@ -824,23 +4139,19 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Issue number")]
-        issue_number: u64,
-        #[tool(param)]
-        #[schemars(description = "Milestone number to assign")]
-        milestone_number: u64,
+        #[schemars(description = "Issue or pull request number")]
+        issue_number: u32,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::IssueTools::add_milestone_to_issue(
+        tool_definition::NotificationTools::get_issue_subscription(
             &self.github_client,
             repository_url,
-            IssueNumber::new(issue_number.try_into().unwrap()),
-            milestone_number,
+            issue_number,
         )
         .await
     }
 
-    #[tool(description = "Remove labels from an issue")]
-    async fn remove_labels_from_issue(
+    #[tool(description = "Subscribe to all conversation on an issue or pull request")]
+    async fn subscribe_to_issue(
         &self,
         #[tool(param)]
         #[schemars(
@@ -848,23 +4159,21 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Issue number")]
-        issue_number: u64,
-        #[tool(param)]
-        #[schemars(description = "List of label names to remove")]
-        labels: Vec<String>,
+        #[schemars(description = "Issue or pull request number")]
+        issue_number: u32,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::IssueTools::remove_labels_from_issue(
+        tool_definition::NotificationTools::subscribe_to_issue(
             &self.github_client,
             repository_url,
-            IssueNumber::new(issue_number.try_into().unwrap()),
-            labels,
+            issue_number,
         )
         .await
     }
 
-    #[tool(description = "Remove milestone from an issue")]
-    async fn remove_milestone_from_issue(
+    #[tool(
+        description = "Unsubscribe from an issue or pull request. You are still notified when participating or mentioned"
+    )]
+    async fn unsubscribe_from_issue(
         &self,
         #[tool(param)]
         #[schemars(
@@ -872,114 +4181,310 @@ impl GitEditTools {
         )]
         repository_url: String,
         #[tool(param)]
-        #[schemars(description = "Issue number")]
-        issue_number: u64,
+        #[schemars(description = "Issue or pull request number")]
+        issue_number: u32,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::IssueTools::remove_milestone_from_issue(
+        tool_definition::NotificationTools::unsubscribe_from_issue(
             &self.github_client,
             repository_url,
-            IssueNumber::new(issue_number.try_into().unwrap()),
+            issue_number,
         )
         .await
     }
 
-    #[tool(description = "Create a new milestone in a repository")]
-    async fn create_milestone(
+    #[tool(
+        description = "List the saved search aliases and their queries. An alias is referenced in the query of any search tool as '@name' and expanded to its saved query, so recurring queries need not be retyped."
+    )]
+    async fn list_search_aliases(&self) -> Result<CallToolResult, McpError> {
+        tool_definition::SearchTools::list_search_aliases().await
+    }
+
+    #[tool(
+        description = "Search issues and pull requests across GitHub using the search query syntax (e.g., 'repo:owner/repo label:bug is:open created:>2024-01-01'). Use 'is:issue' or 'is:pr' to restrict the result type. Saved queries can be referenced as '@alias' (see list_search_aliases). Returns the repository, number, state, title, author, labels and URL of each match."
+    )]
+    async fn search_issues_and_prs(
         &self,
         #[tool(param)]
         #[schemars(
-            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+            description = "Search query (e.g., 'repo:owner/repo label:bug is:open', 'is:pr author:octocat review:required', '@triage author:octocat')"
         )]
-        repository_url: String,
-        #[tool(param)]
-        #[schemars(description = "Milestone title")]
-        title: String,
-        #[tool(param)]
-        #[schemars(description = "Optional milestone description")]
-        description: Option<String>,
+        query: String,
         #[tool(param)]
         #[schemars(
-            description = "Optional due date in ISO 8601 format (e.g., '2024-01-15T10:30:00Z')"
+            description = "Sort field: 'comments', 'reactions', 'interactions', 'created' or 'updated' (default: best match)"
         )]
-        due_on: Option<String>,
+        sort: Option<String>,
         #[tool(param)]
-        #[schemars(description = "Optional state (open or closed)")]
-        state: Option<String>,
+        #[schemars(description = "Sort order: 'asc' or 'desc' (default: 'desc')")]
+        order: Option<String>,
+        #[tool(param)]
+        #[schemars(description = "Page number starting at 1 (default: 1)")]
+        page: Option<u32>,
+        #[tool(param)]
+        #[schemars(description = "Results per page, 1-100 (default: 30)")]
+        per_page: Option<u8>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::RepositoryTools::create_milestone(
+        tool_definition::SearchTools::search_issues_and_prs(
             &self.github_client,
-            repository_url,
-            title,
-            description,
-            due_on,
-            state,
+            query,
+            sort,
+            order,
+            page,
+            per_page,
         )
         .await
     }
 
-    #[tool(description = "Create a new label in a repository")]
-    async fn create_label(
+    #[tool(
+        description = "Search repositories across GitHub using the search query syntax (e.g., 'edit in:name user:tacogips', 'topic:mcp language:rust stars:>100'). Useful when the owner or name of a repository is only approximately known. Returns the full name, stars, forks, open issues, language and URL of each match."
+    )]
+    async fn search_repositories(
         &self,
         #[tool(param)]
         #[schemars(
-            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+            description = "Search query (e.g., 'github-edit in:name', 'org:rust-lang async', 'topic:cli language:rust')"
         )]
-        repository_url: String,
+        query: String,
         #[tool(param)]
-        #[schemars(description = "Label name")]
-        name: String,
+        #[schemars(
+            description = "Sort field: 'stars', 'forks', 'help-wanted-issues' or 'updated' (default: best match)"
+        )]
+        sort: Option<String>,
         #[tool(param)]
-        #[schemars(description = "Optional label color (6 character hex code without #)")]
-        color: Option<String>,
+        #[schemars(description = "Sort order: 'asc' or 'desc' (default: 'desc')")]
+        order: Option<String>,
         #[tool(param)]
-        #[schemars(description = "Optional label description")]
-        description: Option<String>,
+        #[schemars(description = "Page number starting at 1 (default: 1)")]
+        page: Option<u32>,
+        #[tool(param)]
+        #[schemars(description = "Results per page, 1-100 (default: 30)")]
+        per_page: Option<u8>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::RepositoryTools::create_label(
+        tool_definition::SearchTools::search_repositories(
             &self.github_client,
-            repository_url,
-            name,
-            color,
-            description,
+            query,
+            sort,
+            order,
+            page,
+            per_page,
         )
         .await
     }
 
-    #[tool(description = "Update an existing label in a repository")]
-    async fn update_label(
+    #[tool(
+        description = "Search users and organizations across GitHub using the search query syntax (e.g., 'tacogips', 'fullname:\"Jane Doe\"', 'type:org location:Tokyo'). Returns the login, account type and profile URL of each match."
+    )]
+    async fn search_users(
         &self,
         #[tool(param)]
         #[schemars(
-            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+            description = "Search query (e.g., 'octocat in:login', 'type:org rust', 'followers:>1000 language:go')"
         )]
-        repository_url: String,
+        query: String,
         #[tool(param)]
-        #[schemars(description = "Current label name")]
-        old_name: String,
+        #[schemars(
+            description = "Sort field: 'followers', 'repositories' or 'joined' (default: best match)"
+        )]
+        sort: Option<String>,
         #[tool(param)]
-        #[schemars(description = "Optional new label name")]
-        new_name: Option<String>,
+        #[schemars(description = "Sort order: 'asc' or 'desc' (default: 'desc')")]
+        order: Option<String>,
         #[tool(param)]
-        #[schemars(description = "Optional new label color (6 character hex code without #)")]
-        color: Option<String>,
+        #[schemars(description = "Page number starting at 1 (default: 1)")]
+        page: Option<u32>,
         #[tool(param)]
-        #[schemars(description = "Optional new label description")]
-        description: Option<String>,
+        #[schemars(description = "Results per page, 1-100 (default: 30)")]
+        per_page: Option<u8>,
     ) -> Result<CallToolResult, McpError> {
-        tool_definition::RepositoryTools::update_label(
+        tool_definition::SearchTools::search_users(
             &self.github_client,
-            repository_url,
-            old_name,
-            new_name,
-            color,
-            description,
+            query,
+            sort,
+            order,
+            page,
+            per_page,
         )
         .await
     }
+
+    #[tool(
+        description = "Check the configured GitHub token: the authenticated user, the token kind and its OAuth scopes, and which groups of tools will not work because a scope is missing (e.g. project tools without the 'project' scope). Fine-grained tokens do not report scopes; their failing requests name the permissions they need."
+    )]
+    async fn auth_status(&self) -> Result<CallToolResult, McpError> {
+        tool_definition::AuthTools::auth_status(&self.github_client).await
+    }
+
+    #[tool(
+        description = "Get the account the configured GitHub token acts as: its login, name, type (user, bot or organization) and granted OAuth scopes. Use the login to mention yourself in comments and to assign issues to yourself rather than to another account."
+    )]
+    async fn get_authenticated_user(&self) -> Result<CallToolResult, McpError> {
+        tool_definition::AuthTools::get_authenticated_user(&self.github_client).await
+    }
+
+    #[tool(
+        description = "Get the remaining requests of the configured GitHub token in its core (REST), GraphQL (points) and search rate limit buckets, and when each resets. Use it to plan batch workloads. Checking does not count against the limit."
+    )]
+    async fn get_rate_limit(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Optional repository owner whose token from owner_tokens is checked (default: the default token)"
+        )]
+        owner: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        tool_definition::AuthTools::get_rate_limit(&self.github_client, owner).await
+    }
+
+    #[tool(
+        description = "Set the default repository of this session. Tools taking a repository_url use it when repository_url is omitted, until another default is set. Returns the previous default."
+    )]
+    async fn set_default_repository(
+        &self,
+        #[tool(param)]
+        #[schemars(
+            description = "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')"
+        )]
+        repository: String,
+    ) -> Result<CallToolResult, McpError> {
+        self.session.set_default_repository_tool(repository)
+    }
 }
 
-#[tool(tool_box)]
 impl ServerHandler for GitEditTools {
+    async fn list_tools(
+        &self,
+        _request: PaginatedRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, McpError> {
+        Ok(ListToolsResult {
+            tools: Self::listed_tools(),
+            next_cursor: None,
+        })
+    }
+
+    /// Run the tool within the cancellation scope of the request, so a
+    /// cancelled call stops before sending further requests to GitHub
+    ///
+    /// Each call is traced: its events are sent to the client as log
+    /// messages, and its errors name its trace ID. Calls that write to
+    /// GitHub are appended to the audit log, if one is configured, with the
+    /// call reversing them, which `undo_last_operation` runs.
+    ///
+    /// Mutating tools called with `dry_run: true` report the write requests
+    /// they would send instead of sending them. `execute_batch` dispatches
+    /// each of its operations like a call of its own. Responses longer than
+    /// the maximum are cut and continued by `get_response_continuation`.
+    async fn call_tool(
+        &self,
+        mut request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        if request.name == truncation::GET_RESPONSE_CONTINUATION_TOOL {
+            return self.responses.continue_response(request.arguments);
+        }
+
+        // Item URLs are resolved before the call is recorded, so the audit
+        // log and the undo see the number of the item
+        if let Some(item) = Self::tool_box().map.get(request.name.as_ref()) {
+            item_url::fill_item_number(&item.attr, &mut request)?;
+        }
+
+        let tool = request.name.to_string();
+        let arguments = request.arguments.clone();
+        let cancellation_token = context.ct.clone();
+        let peer = context.peer.clone();
+        let client_name = peer.peer_info().client_info.name.clone();
+        let notify = move |message| {
+            let peer = peer.clone();
+            async move {
+                if let Err(e) = peer.notify_logging_message(message).await {
+                    tracing::debug!("Failed to send log message: {}", e);
+                }
+            }
+        };
+        let undo_client_name = client_name.clone();
+        let call = cancellation::with_cancellation(cancellation_token, async move {
+            if request.name == undo::UNDO_LAST_OPERATION_TOOL {
+                let mut request = request;
+                let is_dry_run = dry_run::take_dry_run_argument(&mut request)?;
+                let Some(auditor) = &self.auditor else {
+                    return Err(McpError::invalid_request(
+                        format!(
+                            "{} needs the audit log, which is disabled in the [audit] section of the configuration",
+                            undo::UNDO_LAST_OPERATION_TOOL
+                        ),
+                        None,
+                    ));
+                };
+                return auditor
+                    .undo_last_operation(
+                        &self.github_client,
+                        request.arguments,
+                        Some(undo_client_name),
+                        is_dry_run,
+                        |operation| self.call_single_tool(operation, context.clone()),
+                    )
+                    .await;
+            }
+            if request.name == batch::EXECUTE_BATCH_TOOL {
+                let mut request = request;
+                let is_dry_run = dry_run::take_dry_run_argument(&mut request)?;
+                let batch_request = batch::parse_batch_request(request.arguments)?;
+                return Ok(
+                    batch::execute_batch(batch_request, is_dry_run, |operation| {
+                        self.call_single_tool(operation, context.clone())
+                    })
+                    .await,
+                );
+            }
+            self.call_single_tool(request, context).await
+        });
+        let call = async {
+            match &self.auditor {
+                // The undo records its own audit entry
+                Some(auditor) if tool != undo::UNDO_LAST_OPERATION_TOOL => {
+                    let undo = undo::prepare_undo(
+                        &self.github_client,
+                        &tool,
+                        arguments.as_ref(),
+                        self.session.default_repository(),
+                    )
+                    .await;
+                    auditor
+                        .audit_call(
+                            &self.github_client,
+                            &tool,
+                            arguments.as_ref(),
+                            Some(client_name),
+                            undo,
+                            call,
+                        )
+                        .await
+                }
+                Some(_) => call.await,
+                None => call.await,
+            }
+        };
+        let result = self
+            .logger
+            .trace_call(&tool, arguments.as_ref(), notify, call)
+            .await?;
+        Ok(self.responses.limit(&tool, result))
+    }
+
+    /// Start forwarding webhook events to the client once the session is
+    /// served, if the server receives webhooks
+    fn set_peer(&mut self, peer: Peer<RoleServer>) {
+        if let Some(events) = &self.webhook_events {
+            tokio::spawn(webhooks::forward_webhook_events(
+                events.subscribe(),
+                peer,
+                self.logger.clone(),
+                self.subscriptions.clone(),
+            ));
+        }
+    }
+
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
             protocol_version: ProtocolVersion::V_2024_11_05,
@@ -987,10 +4492,96 @@ impl ServerHandler for GitEditTools {
                 name: "github-edit".into(),
                 version: "0.1.3".into(),
             },
-            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            capabilities: ServerCapabilities::builder()
+                .enable_logging()
+                .enable_prompts()
+                .enable_resources()
+                .enable_resources_subscribe()
+                .enable_tools()
+                .build(),
             instructions: Some(
-                "GitHub Edit MCP Server - Updates GitHub project item fields. Requires GITHUB_EDIT_GITHUB_TOKEN environment variable.".into(),
+                "GitHub Edit MCP Server - Updates GitHub project item fields. Requires GITHUB_EDIT_GITHUB_TOKEN environment variable. Issues and pull requests can be read as resources at github-edit://owner/repo/issues/N and github-edit://owner/repo/pulls/N. Prompts guide issue triage, pull request reviews and release notes. Deleting issues, labels, milestones and comments takes two calls, the second passing the confirmation_token returned by the first. Mutating tools accept dry_run: true to validate their inputs and return the requests they would send without sending them. execute_batch runs several tool calls in one request. Responses longer than the configured maximum end with a truncation marker; pass its continuation_token to get_response_continuation for the rest. Tool calls are logged as notifications/message with a trace ID that error messages name; set the level with logging/setLevel. After set_default_repository, tools may omit repository_url to use that repository. undo_last_operation reverses the newest label, state, title or body change recorded in the audit log. With webhooks enabled, GitHub events such as opened issues and requested reviews arrive as notifications/message from the github-edit.webhooks logger.".into(),
             ),
         }
     }
+
+    async fn set_level(
+        &self,
+        request: SetLevelRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        self.logger.set_level(request.level);
+        Ok(())
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: PaginatedRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListPromptsResult, McpError> {
+        Ok(ListPromptsResult {
+            prompts: prompts::list_prompts(),
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(
+        &self,
+        request: GetPromptRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<GetPromptResult, McpError> {
+        prompts::get_prompt(request)
+    }
+
+    async fn list_resources(
+        &self,
+        _request: PaginatedRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, McpError> {
+        Ok(ListResourcesResult {
+            resources: self.subscriptions.resources(),
+            next_cursor: None,
+        })
+    }
+
+    async fn list_resource_templates(
+        &self,
+        _request: PaginatedRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, McpError> {
+        Ok(ListResourceTemplatesResult {
+            resource_templates: resources::resource_templates(),
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        let uri = resources::parse_resource_uri(&request.uri)?;
+        resources::read_resource(&self.github_client, &uri).await
+    }
+
+    async fn subscribe(
+        &self,
+        request: SubscribeRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        let uri = resources::parse_resource_uri(&request.uri)?;
+        self.subscriptions
+            .subscribe(&self.github_client, uri, context.peer)
+            .await
+    }
+
+    async fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        let uri = resources::parse_resource_uri(&request.uri)?;
+        self.subscriptions.unsubscribe(&uri);
+        Ok(())
+    }
 }
@@ -0,0 +1,276 @@
+//! MCP prompts for common workflows
+//!
+//! Each prompt expands into a user message walking the model through the
+//! tool calls of one workflow, with the parameters already filled in from the
+//! prompt arguments. Prompts are rendered without calling GitHub; the model
+//! fetches the data itself through the resources and tools they name.
+
+use crate::types::issue::{IssueId, IssueUrl};
+use crate::types::pull_request::{PullRequestId, PullRequestUrl};
+use crate::types::repository::{RepositoryId, RepositoryUrl};
+use crate::types::resource::ResourceUri;
+
+use rmcp::{Error as McpError, model::*};
+
+/// Workflows offered as MCP prompts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkflowPrompt {
+    /// Label, type, assign and deduplicate a new issue
+    TriageIssue,
+    /// Review a pull request and post the findings as a comment
+    ReviewPullRequest,
+    /// Draft release notes from the issues and pull requests of a milestone
+    WriteReleaseNotes,
+}
+
+impl WorkflowPrompt {
+    /// All prompts, in the order they are listed
+    pub const ALL: [WorkflowPrompt; 3] = [
+        WorkflowPrompt::TriageIssue,
+        WorkflowPrompt::ReviewPullRequest,
+        WorkflowPrompt::WriteReleaseNotes,
+    ];
+
+    /// Returns the prompt with the given name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|prompt| prompt.name() == name)
+    }
+
+    /// Returns the name clients request the prompt by
+    pub fn name(&self) -> &'static str {
+        match self {
+            WorkflowPrompt::TriageIssue => "triage_issue",
+            WorkflowPrompt::ReviewPullRequest => "review_pull_request",
+            WorkflowPrompt::WriteReleaseNotes => "write_release_notes",
+        }
+    }
+
+    /// Returns the description shown to users picking a prompt
+    pub fn description(&self) -> &'static str {
+        match self {
+            WorkflowPrompt::TriageIssue => {
+                "Triage an issue: check for duplicates, then set labels, issue type, assignees and milestone"
+            }
+            WorkflowPrompt::ReviewPullRequest => {
+                "Review a pull request: check merge blockers and CI, read the discussion and post a review comment"
+            }
+            WorkflowPrompt::WriteReleaseNotes => {
+                "Write release notes from the closed issues and merged pull requests of a milestone"
+            }
+        }
+    }
+
+    /// Returns the arguments of the prompt as (name, description, required)
+    fn argument_specs(&self) -> &'static [(&'static str, &'static str, bool)] {
+        match self {
+            WorkflowPrompt::TriageIssue => &[(
+                "issue_url",
+                "Issue URL (e.g., 'https://github.com/owner/repo/issues/123')",
+                true,
+            )],
+            WorkflowPrompt::ReviewPullRequest => &[
+                (
+                    "pull_request_url",
+                    "Pull request URL (e.g., 'https://github.com/owner/repo/pull/45')",
+                    true,
+                ),
+                (
+                    "focus",
+                    "Optional aspects to focus the review on (e.g., 'error handling, tests')",
+                    false,
+                ),
+            ],
+            WorkflowPrompt::WriteReleaseNotes => &[
+                (
+                    "repository_url",
+                    "Repository URL (e.g., 'https://github.com/owner/repo', 'owner/repo')",
+                    true,
+                ),
+                ("milestone", "Milestone title (e.g., 'v1.2.0')", true),
+                (
+                    "tag_name",
+                    "Optional tag of the release, used to let GitHub generate a changelog to start from",
+                    false,
+                ),
+            ],
+        }
+    }
+
+    /// Returns the prompt as listed to clients
+    pub fn prompt(&self) -> Prompt {
+        let arguments = self
+            .argument_specs()
+            .iter()
+            .map(|(name, description, required)| PromptArgument {
+                name: name.to_string(),
+                description: Some(description.to_string()),
+                required: Some(*required),
+            })
+            .collect();
+        Prompt::new(self.name(), Some(self.description()), Some(arguments))
+    }
+
+    /// Render the prompt with the arguments sent by the client
+    ///
+    /// # Errors
+    /// Returns an error if a required argument is missing or a URL is invalid
+    pub fn render(&self, arguments: &JsonObject) -> Result<GetPromptResult, String> {
+        for (name, _, required) in self.argument_specs() {
+            if *required && argument(arguments, name).is_none() {
+                return Err(format!(
+                    "Missing required argument '{}' of prompt {}",
+                    name,
+                    self.name()
+                ));
+            }
+        }
+
+        let text = match self {
+            WorkflowPrompt::TriageIssue => {
+                let issue_url = argument(arguments, "issue_url").unwrap_or_default();
+                let issue_id = IssueId::parse_url(&IssueUrl(issue_url.to_string()))?;
+                triage_issue_text(&issue_id)
+            }
+            WorkflowPrompt::ReviewPullRequest => {
+                let pull_request_url = argument(arguments, "pull_request_url").unwrap_or_default();
+                let pull_request_id =
+                    PullRequestId::parse_url(&PullRequestUrl(pull_request_url.to_string()))?;
+                review_pull_request_text(&pull_request_id, argument(arguments, "focus"))
+            }
+            WorkflowPrompt::WriteReleaseNotes => {
+                let repository_url = argument(arguments, "repository_url").unwrap_or_default();
+                let repository_id =
+                    RepositoryId::parse_url(&RepositoryUrl(repository_url.to_string()))?;
+                write_release_notes_text(
+                    &repository_id,
+                    argument(arguments, "milestone").unwrap_or_default(),
+                    argument(arguments, "tag_name"),
+                )
+            }
+        };
+
+        Ok(GetPromptResult {
+            description: Some(self.description().to_string()),
+            messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+        })
+    }
+}
+
+/// Returns the prompts offered by the server
+pub fn list_prompts() -> Vec<Prompt> {
+    WorkflowPrompt::ALL
+        .iter()
+        .map(|prompt| prompt.prompt())
+        .collect()
+}
+
+/// Render the prompt requested by the client
+pub fn get_prompt(request: GetPromptRequestParam) -> Result<GetPromptResult, McpError> {
+    let prompt = WorkflowPrompt::from_name(&request.name).ok_or_else(|| {
+        McpError::invalid_params(format!("Unknown prompt: {}", request.name), None)
+    })?;
+    prompt
+        .render(&request.arguments.unwrap_or_default())
+        .map_err(|e| McpError::invalid_params(e, None))
+}
+
+/// Returns a non-empty string argument
+fn argument<'a>(arguments: &'a JsonObject, name: &str) -> Option<&'a str> {
+    arguments
+        .get(name)
+        .and_then(|value| value.as_str())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+fn triage_issue_text(issue_id: &IssueId) -> String {
+    let repository_id = &issue_id.git_repository;
+    let repository_url = repository_id.url();
+    let issue_url = issue_id.url();
+    let resource_uri = ResourceUri::Issue(issue_id.clone());
+    format!(
+        "Triage the GitHub issue {issue_url}.
+
+1. Read the issue from the resource `{resource_uri}`.
+2. Look for duplicates with `search_issues_and_prs`, using a query such as \
+`repo:{owner}/{repo} is:issue <key terms of the issue>`. If the issue duplicates \
+another one, call `mark_issue_duplicate` and stop.
+3. Call `list_labels` with repository_url `{repository_url}` and pick the labels that \
+fit, then apply them with `add_labels_to_issue`. Do not invent new labels.
+4. Call `list_issue_types`; if the organization defines issue types, set the matching \
+one with `set_issue_type`.
+5. If the issue names an owner or a code area with an obvious maintainer, assign them \
+with `add_assignees_to_issue`.
+6. If the issue belongs to an open milestone from `list_milestones`, set it with \
+`add_milestone_to_issue`.
+7. Post a short comment with `add_comment_to_issue` only if information needed to act \
+on the issue is missing, asking for it.
+
+Finish with a summary of the changes you made and why.",
+        owner = repository_id.owner(),
+        repo = repository_id.repo_name(),
+    )
+}
+
+fn review_pull_request_text(pull_request_id: &PullRequestId, focus: Option<&str>) -> String {
+    let repository_url = pull_request_id.git_repository.url();
+    let pull_request_url = pull_request_id.url();
+    let number = pull_request_id.number;
+    let resource_uri = ResourceUri::PullRequest(pull_request_id.clone());
+    let focus = focus
+        .map(|focus| format!("\nFocus the review on: {}.\n", focus))
+        .unwrap_or_default();
+    format!(
+        "Review the GitHub pull request {pull_request_url}.
+{focus}
+1. Read the pull request, including its description and discussion, from the resource \
+`{resource_uri}`.
+2. Call `get_pull_request_merge_status` with repository_url `{repository_url}` and \
+pr_number {number} to list merge blockers such as conflicts, missing reviews and \
+failing checks.
+3. For failing checks, call `list_check_runs_for_ref` with the head branch of the pull \
+request and, for workflow runs, `get_workflow_run_logs` to find the cause.
+4. Read the changed files with `get_file_content` on the head branch where the \
+description alone does not explain the change.
+5. Call `list_pull_request_comments` to avoid repeating points already raised.
+6. Post one review comment with `add_comment_to_pull_request` covering correctness, \
+tests, and anything blocking the merge, most important first.
+
+Do not close, merge or edit the pull request.",
+    )
+}
+
+fn write_release_notes_text(
+    repository_id: &RepositoryId,
+    milestone: &str,
+    tag_name: Option<&str>,
+) -> String {
+    let repository_url = repository_id.url();
+    let changelog = tag_name
+        .map(|tag_name| {
+            format!(
+                "\n4. Call `generate_release_notes` with tag_name `{}` to get GitHub's changelog \
+and the contributor list, and reconcile it with the items above.",
+                tag_name
+            )
+        })
+        .unwrap_or_default();
+    format!(
+        "Write release notes for the milestone \"{milestone}\" of {repository_url}.
+
+1. Call `list_milestones` with repository_url `{repository_url}` and find the number \
+of the milestone titled \"{milestone}\".
+2. Call `list_issues` with that milestone_number and state `closed`, paging until all \
+issues are listed.
+3. Call `search_issues_and_prs` with the query \
+`repo:{owner}/{repo} is:pr is:merged milestone:\"{milestone}\"` to list the merged pull \
+requests.{changelog}
+
+Group the changes under Features, Bug Fixes and Other Changes, one line per change with \
+its issue or pull request reference, and call out breaking changes first. Leave out \
+items closed as not planned or as duplicates. Return the notes as Markdown; do not \
+create or publish a release unless asked.",
+        owner = repository_id.owner(),
+        repo = repository_id.repo_name(),
+    )
+}
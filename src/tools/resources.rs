@@ -0,0 +1,217 @@
+//! MCP resources for issues and pull requests
+//!
+//! Clients read issues and pull requests through their
+//! `github-edit://owner/repo/issues/N` and `github-edit://owner/repo/pulls/N`
+//! URIs. Subscribed resources are polled, and the client is sent a
+//! `notifications/resources/updated` notification whenever their
+//! `updated_at` changes. With webhooks, events about a subscribed resource
+//! notify the client right away as well.
+
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::resource::{
+    ISSUE_RESOURCE_URI_TEMPLATE, PULL_REQUEST_RESOURCE_URI_TEMPLATE, RESOURCE_MIME_TYPE,
+    ResourceUri,
+};
+
+use rmcp::service::{Peer, RoleServer};
+use rmcp::{Error as McpError, model::*};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// Interval between checks of subscribed resources for updates
+pub const SUBSCRIPTION_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Returns the URI templates of the issue and pull request resources
+pub fn resource_templates() -> Vec<ResourceTemplate> {
+    vec![
+        RawResourceTemplate {
+            uri_template: ISSUE_RESOURCE_URI_TEMPLATE.to_string(),
+            name: "GitHub issue".to_string(),
+            description: Some(
+                "An issue with its comments, labels and assignees, as JSON".to_string(),
+            ),
+            mime_type: Some(RESOURCE_MIME_TYPE.to_string()),
+        }
+        .no_annotation(),
+        RawResourceTemplate {
+            uri_template: PULL_REQUEST_RESOURCE_URI_TEMPLATE.to_string(),
+            name: "GitHub pull request".to_string(),
+            description: Some(
+                "A pull request with its comments, branches and change statistics, as JSON"
+                    .to_string(),
+            ),
+            mime_type: Some(RESOURCE_MIME_TYPE.to_string()),
+        }
+        .no_annotation(),
+    ]
+}
+
+/// Parse a resource URI sent by the client
+pub fn parse_resource_uri(uri: &str) -> Result<ResourceUri, McpError> {
+    ResourceUri::parse(uri).map_err(|e| McpError::invalid_params(e, None))
+}
+
+/// Read an issue or pull request resource
+pub async fn read_resource(
+    github_client: &GitHubClient,
+    uri: &ResourceUri,
+) -> Result<ReadResourceResult, McpError> {
+    let snapshot = functions::resource::read_resource(github_client, uri)
+        .await
+        .map_err(|e| {
+            McpError::internal_error(format!("Failed to read resource {}: {}", uri, e), None)
+        })?;
+    Ok(ReadResourceResult {
+        contents: vec![ResourceContents::TextResourceContents {
+            uri: uri.to_string(),
+            mime_type: Some(RESOURCE_MIME_TYPE.to_string()),
+            text: snapshot.text,
+        }],
+    })
+}
+
+/// Resources subscribed to by the client of one session
+///
+/// Each subscription is polled by a task of its own. The tasks are aborted
+/// on unsubscribe and when the session ends.
+#[derive(Clone, Default)]
+pub struct ResourceSubscriptions {
+    inner: Arc<SubscriptionTasks>,
+}
+
+#[derive(Default)]
+struct SubscriptionTasks {
+    tasks: Mutex<HashMap<ResourceUri, Subscription>>,
+}
+
+struct Subscription {
+    title: String,
+    task: JoinHandle<()>,
+}
+
+impl Drop for SubscriptionTasks {
+    fn drop(&mut self) {
+        let tasks = self.tasks.get_mut().unwrap_or_else(|e| e.into_inner());
+        for (_, subscription) in tasks.drain() {
+            subscription.task.abort();
+        }
+    }
+}
+
+impl ResourceSubscriptions {
+    /// Subscribe to updates of a resource
+    ///
+    /// The resource is read once so subscriptions to missing or inaccessible
+    /// issues and pull requests fail right away. Subscribing again to the
+    /// same resource has no effect.
+    pub async fn subscribe(
+        &self,
+        github_client: &GitHubClient,
+        uri: ResourceUri,
+        peer: Peer<RoleServer>,
+    ) -> Result<(), McpError> {
+        if self.lock().contains_key(&uri) {
+            return Ok(());
+        }
+
+        let snapshot = functions::resource::read_resource(github_client, &uri)
+            .await
+            .map_err(|e| {
+                McpError::invalid_params(format!("Failed to subscribe to {}: {}", uri, e), None)
+            })?;
+
+        let task = tokio::spawn(poll_resource(
+            github_client.clone(),
+            uri.clone(),
+            snapshot.updated_at,
+            peer,
+        ));
+        let previous = self.lock().insert(
+            uri,
+            Subscription {
+                title: snapshot.title,
+                task,
+            },
+        );
+        if let Some(previous) = previous {
+            previous.task.abort();
+        }
+        Ok(())
+    }
+
+    /// Stop watching a resource
+    pub fn unsubscribe(&self, uri: &ResourceUri) {
+        if let Some(subscription) = self.lock().remove(uri) {
+            subscription.task.abort();
+        }
+    }
+
+    /// Returns true if the client subscribed to the resource
+    pub fn is_subscribed(&self, uri: &ResourceUri) -> bool {
+        self.lock().contains_key(uri)
+    }
+
+    /// Returns the subscribed resources, sorted by URI
+    pub fn resources(&self) -> Vec<Resource> {
+        let mut resources: Vec<Resource> = self
+            .lock()
+            .iter()
+            .map(|(uri, subscription)| {
+                RawResource {
+                    uri: uri.to_string(),
+                    name: format!("{}: {}", uri.name(), subscription.title),
+                    description: None,
+                    mime_type: Some(RESOURCE_MIME_TYPE.to_string()),
+                    size: None,
+                }
+                .no_annotation()
+            })
+            .collect();
+        resources.sort_by(|a, b| a.raw.uri.cmp(&b.raw.uri));
+        resources
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<ResourceUri, Subscription>> {
+        self.inner.tasks.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Poll a subscribed resource and notify the client of its updates
+///
+/// Stops once the client can no longer be notified. Read failures are logged
+/// and retried on the next poll.
+async fn poll_resource(
+    github_client: GitHubClient,
+    uri: ResourceUri,
+    mut updated_at: chrono::DateTime<chrono::Utc>,
+    peer: Peer<RoleServer>,
+) {
+    let mut interval = tokio::time::interval(SUBSCRIPTION_POLL_INTERVAL);
+    // The first tick completes immediately
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        let snapshot = match functions::resource::read_resource(&github_client, &uri).await {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                tracing::warn!("Failed to poll subscribed resource {}: {}", uri, e);
+                continue;
+            }
+        };
+        if snapshot.updated_at == updated_at {
+            continue;
+        }
+        updated_at = snapshot.updated_at;
+
+        let notification = ResourceUpdatedNotificationParam {
+            uri: uri.to_string(),
+        };
+        if let Err(e) = peer.notify_resource_updated(notification).await {
+            tracing::debug!("Stopped watching {}: {}", uri, e);
+            return;
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Session defaults of the MCP tools
+//!
+//! `set_default_repository` stores a repository for the session. Tools that
+//! require a `repository_url` may then omit it: the default is filled into
+//! the arguments before the call is dispatched, so the operations of
+//! `execute_batch` use it as well. The default lasts as long as the session;
+//! the stdio server and each SSE session have their own.
+
+use crate::tools::tool_definition::success_result;
+use crate::types::repository::{RepositoryId, RepositoryUrl};
+
+use rmcp::{Error as McpError, model::*};
+use std::sync::{Arc, Mutex};
+
+/// Name of the tool setting the default repository
+pub const SET_DEFAULT_REPOSITORY_TOOL: &str = "set_default_repository";
+
+/// Name of the argument the default repository is filled into
+pub const REPOSITORY_URL_ARGUMENT: &str = "repository_url";
+
+/// Defaults of the tool calls of one session
+#[derive(Clone, Default)]
+pub struct SessionDefaults {
+    repository: Arc<Mutex<Option<RepositoryId>>>,
+}
+
+impl SessionDefaults {
+    /// Returns the default repository of the session, if set
+    pub fn default_repository(&self) -> Option<RepositoryId> {
+        self.repository
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Set the default repository of the session
+    ///
+    /// # Returns
+    /// The previous default repository, if any
+    pub fn set_default_repository(&self, repository: RepositoryId) -> Option<RepositoryId> {
+        self.repository
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .replace(repository)
+    }
+
+    /// Handle a `set_default_repository` call
+    ///
+    /// The argument is named `repository` rather than `repository_url`, so
+    /// the default is never filled into it.
+    pub fn set_default_repository_tool(
+        &self,
+        repository: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repository = RepositoryId::parse_url(&RepositoryUrl(repository)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+        })?;
+        let previous = self.set_default_repository(repository.clone());
+        let text = match &previous {
+            Some(previous) if *previous != repository => format!(
+                "Default repository set to {} (was {})",
+                repository, previous
+            ),
+            _ => format!("Default repository set to {}", repository),
+        };
+        Ok(success_result(
+            text,
+            &serde_json::json!({
+                "default_repository": repository.url(),
+                "previous_default_repository": previous.map(|previous| previous.url()),
+            }),
+        ))
+    }
+
+    /// Fill the default repository into a call of `tool` that omits
+    /// `repository_url`
+    ///
+    /// # Errors
+    /// Returns an error if the tool requires a repository URL, the call
+    /// omits it and no default repository is set
+    pub fn fill_default_repository(
+        &self,
+        tool: &Tool,
+        request: &mut CallToolRequestParam,
+    ) -> Result<(), McpError> {
+        if !requires_repository_url(tool) {
+            return Ok(());
+        }
+        let arguments = request.arguments.get_or_insert_with(JsonObject::new);
+        if arguments
+            .get(REPOSITORY_URL_ARGUMENT)
+            .is_some_and(|value| !value.is_null())
+        {
+            return Ok(());
+        }
+        let Some(repository) = self.default_repository() else {
+            return Err(McpError::invalid_params(
+                format!(
+                    "{} requires {}: pass it, or call {} first",
+                    request.name, REPOSITORY_URL_ARGUMENT, SET_DEFAULT_REPOSITORY_TOOL
+                ),
+                None,
+            ));
+        };
+        arguments.insert(
+            REPOSITORY_URL_ARGUMENT.to_string(),
+            serde_json::Value::String(repository.url()),
+        );
+        Ok(())
+    }
+}
+
+/// Returns true if the input schema of the tool requires `repository_url`
+pub fn requires_repository_url(tool: &Tool) -> bool {
+    tool.input_schema
+        .get("required")
+        .and_then(|required| required.as_array())
+        .is_some_and(|required| {
+            required
+                .iter()
+                .any(|name| name.as_str() == Some(REPOSITORY_URL_ARGUMENT))
+        })
+}
+
+/// Make `repository_url` optional in the input schema of a tool that
+/// requires it, as the default repository is used when it is omitted
+pub fn with_default_repository_argument(mut tool: Tool) -> Tool {
+    if !requires_repository_url(&tool) {
+        return tool;
+    }
+    let mut schema = tool.input_schema.as_ref().clone();
+    if let Some(required) = schema
+        .get_mut("required")
+        .and_then(|required| required.as_array_mut())
+    {
+        required.retain(|name| name.as_str() != Some(REPOSITORY_URL_ARGUMENT));
+    }
+    if let Some(description) = schema
+        .get_mut("properties")
+        .and_then(|properties| properties.get_mut(REPOSITORY_URL_ARGUMENT))
+        .and_then(|property| property.as_object_mut())
+    {
+        let text = description
+            .get("description")
+            .and_then(|text| text.as_str())
+            .unwrap_or("Repository URL");
+        let text = format!(
+            "{}. Defaults to the repository set with {}",
+            text, SET_DEFAULT_REPOSITORY_TOOL
+        );
+        description.insert("description".to_string(), serde_json::Value::String(text));
+    }
+    tool.input_schema = Arc::new(schema);
+    tool
+}
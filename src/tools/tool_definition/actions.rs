@@ -0,0 +1,614 @@
+//! GitHub Actions related tool definitions
+//!
+//! This module contains MCP tool implementations for dispatching workflows,
+//! listing, cancelling and re-running workflow runs, reading their logs
+//! and artifacts, and reviewing their deployments to protected environments.
+
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::actions::{
+    ArtifactId, DeploymentReviewState, WorkflowRunFilter, WorkflowRunId, WorkflowRunLogs,
+    select_job_logs,
+};
+use crate::types::pagination::Pagination;
+use crate::types::pull_request::PullRequestNumber;
+use crate::types::repository::{RepositoryId, RepositoryUrl};
+
+use crate::tools::tool_definition::success_result;
+use rmcp::{Error as McpError, model::*};
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Number of trailing lines shown per job log when no other count is given
+const DEFAULT_LOG_TAIL_LINES: usize = 100;
+
+/// GitHub Actions tools implementation
+pub struct ActionsTools;
+
+impl ActionsTools {
+    pub async fn dispatch_workflow(
+        github_client: &GitHubClient,
+        repository_url: String,
+        workflow: String,
+        git_ref: String,
+        inputs: Option<BTreeMap<String, String>>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+        let inputs = inputs.unwrap_or_default();
+
+        match functions::actions::dispatch_workflow(
+            github_client,
+            &repo_id,
+            &workflow,
+            &git_ref,
+            &inputs,
+        )
+        .await
+        {
+            Ok(()) => Ok(success_result(
+                format!(
+                    "Dispatched workflow {} on {}. The run is queued; list workflow runs with event workflow_dispatch to follow it",
+                    workflow, git_ref
+                ),
+                &json!({"workflow": workflow, "ref": git_ref, "dispatched": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to dispatch workflow: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_workflow_runs(
+        github_client: &GitHubClient,
+        repository_url: String,
+        workflow: Option<String>,
+        branch: Option<String>,
+        event: Option<String>,
+        status: Option<String>,
+        page: Option<u32>,
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url.clone())?;
+        let filter = WorkflowRunFilter {
+            workflow,
+            branch,
+            event,
+            status,
+            head_sha: None,
+        };
+
+        match functions::actions::list_workflow_runs(
+            github_client,
+            &repo_id,
+            &filter,
+            Pagination::new(page, per_page),
+        )
+        .await
+        {
+            Ok(runs) => {
+                let mut lines = vec![format!(
+                    "Workflow runs of {} (page {}, {} run(s)):",
+                    repository_url,
+                    runs.page,
+                    runs.items.len()
+                )];
+                lines.extend(runs.items.iter().map(|run| {
+                    format!(
+                        "- {} {} #{} [{}{}] {} on {} ({}) {}",
+                        run.id,
+                        run.name.as_deref().unwrap_or("(unnamed)"),
+                        run.run_number,
+                        run.status.as_deref().unwrap_or("unknown"),
+                        run.conclusion
+                            .as_deref()
+                            .map(|conclusion| format!(": {}", conclusion))
+                            .unwrap_or_default(),
+                        run.event,
+                        run.head_branch.as_deref().unwrap_or("-"),
+                        run.head_sha.chars().take(7).collect::<String>(),
+                        run.html_url
+                    )
+                }));
+                if runs.has_next_page {
+                    lines.push(format!("More runs available on page {}", runs.page + 1));
+                }
+                Ok(success_result(lines.join("\n"), &runs))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to list workflow runs: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn cancel_workflow_run(
+        github_client: &GitHubClient,
+        repository_url: String,
+        run_id: u64,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::actions::cancel_workflow_run(
+            github_client,
+            &repo_id,
+            WorkflowRunId::new(run_id),
+        )
+        .await
+        {
+            Ok(()) => Ok(success_result(
+                format!("Requested cancellation of workflow run {}", run_id),
+                &json!({"run_id": run_id, "cancellation_requested": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to cancel workflow run: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn rerun_workflow_run(
+        github_client: &GitHubClient,
+        repository_url: String,
+        run_id: u64,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::actions::rerun_workflow_run(
+            github_client,
+            &repo_id,
+            WorkflowRunId::new(run_id),
+        )
+        .await
+        {
+            Ok(()) => Ok(success_result(
+                format!("Requested re-run of workflow run {}", run_id),
+                &json!({"run_id": run_id, "rerun_requested": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to re-run workflow run: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn approve_workflow_run(
+        github_client: &GitHubClient,
+        repository_url: String,
+        run_id: u64,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::actions::approve_workflow_run(
+            github_client,
+            &repo_id,
+            WorkflowRunId::new(run_id),
+        )
+        .await
+        {
+            Ok(()) => Ok(success_result(
+                format!("Approved workflow run {}", run_id),
+                &json!({"run_id": run_id, "approved": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to approve workflow run: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn approve_pending_runs_for_pr(
+        github_client: &GitHubClient,
+        repository_url: String,
+        pr_number: u32,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::actions::approve_pending_runs_for_pr(
+            github_client,
+            &repo_id,
+            PullRequestNumber::new(pr_number),
+        )
+        .await
+        {
+            Ok(runs) if runs.is_empty() => Ok(success_result(
+                format!(
+                    "No workflow runs of pull request #{} are waiting for approval",
+                    pr_number
+                ),
+                &runs,
+            )),
+            Ok(runs) => {
+                let mut lines = vec![format!(
+                    "Approved {} workflow run(s) of pull request #{}:",
+                    runs.len(),
+                    pr_number
+                )];
+                lines.extend(runs.iter().map(|run| {
+                    format!(
+                        "- {} {} {}",
+                        run.id,
+                        run.name.as_deref().unwrap_or("(unnamed)"),
+                        run.html_url
+                    )
+                }));
+                Ok(success_result(lines.join("\n"), &runs))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to approve pending workflow runs: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn rerun_failed_jobs(
+        github_client: &GitHubClient,
+        repository_url: String,
+        run_id: u64,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::actions::rerun_failed_jobs(
+            github_client,
+            &repo_id,
+            WorkflowRunId::new(run_id),
+        )
+        .await
+        {
+            Ok(()) => Ok(success_result(
+                format!(
+                    "Requested re-run of the failed jobs of workflow run {}",
+                    run_id
+                ),
+                &json!({"run_id": run_id, "rerun_requested": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to re-run failed jobs: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn list_jobs_for_run(
+        github_client: &GitHubClient,
+        repository_url: String,
+        run_id: u64,
+        all_attempts: Option<bool>,
+        page: Option<u32>,
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::actions::list_jobs_for_run(
+            github_client,
+            &repo_id,
+            WorkflowRunId::new(run_id),
+            all_attempts.unwrap_or(false),
+            Pagination::new(page, per_page),
+        )
+        .await
+        {
+            Ok(jobs) => {
+                let mut lines = vec![format!(
+                    "Jobs of workflow run {} (page {}, {} job(s)):",
+                    run_id,
+                    jobs.page,
+                    jobs.items.len()
+                )];
+                for job in &jobs.items {
+                    lines.push(format!(
+                        "- {} {} [{}{}]{}",
+                        job.id,
+                        job.name,
+                        job.status,
+                        job.conclusion
+                            .map(|conclusion| format!(": {}", conclusion))
+                            .unwrap_or_default(),
+                        job.html_url
+                            .as_deref()
+                            .map(|url| format!(" {}", url))
+                            .unwrap_or_default()
+                    ));
+                    lines.extend(job.failed_steps().into_iter().map(|step| {
+                        format!(
+                            "  - step {} {} failed{}",
+                            step.number,
+                            step.name,
+                            step.conclusion
+                                .map(|conclusion| format!(" ({})", conclusion))
+                                .unwrap_or_default()
+                        )
+                    }));
+                }
+                if jobs.has_next_page {
+                    lines.push(format!("More jobs available on page {}", jobs.page + 1));
+                }
+                Ok(success_result(lines.join("\n"), &jobs))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to list jobs: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn get_workflow_run_logs(
+        github_client: &GitHubClient,
+        repository_url: String,
+        run_id: u64,
+        save_path: Option<String>,
+        tail_lines: Option<usize>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+        let save_path = save_path.map(PathBuf::from);
+        let tail_lines = tail_lines.unwrap_or(DEFAULT_LOG_TAIL_LINES);
+
+        match functions::actions::get_workflow_run_logs(
+            github_client,
+            &repo_id,
+            WorkflowRunId::new(run_id),
+            save_path.as_deref(),
+        )
+        .await
+        {
+            Ok(WorkflowRunLogs::Saved(path)) => Ok(success_result(
+                format!(
+                    "Saved logs of workflow run {} to {}",
+                    run_id,
+                    path.display()
+                ),
+                &json!({"run_id": run_id, "saved_to": path}),
+            )),
+            Ok(WorkflowRunLogs::Extracted(files)) => {
+                let sections: Vec<String> = select_job_logs(&files)
+                    .into_iter()
+                    .map(|file| format!("== {} ==\n{}", file.name, file.tail(tail_lines)))
+                    .collect();
+                Ok(success_result(
+                    format!(
+                        "Logs of workflow run {} (last {} line(s) per file):\n\n{}",
+                        run_id,
+                        tail_lines,
+                        sections.join("\n\n")
+                    ),
+                    &json!({"run_id": run_id, "tail_lines": tail_lines, "files": files.iter().map(|file| file.name.as_str()).collect::<Vec<_>>()}),
+                ))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to get workflow run logs: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn list_artifacts(
+        github_client: &GitHubClient,
+        repository_url: String,
+        run_id: Option<u64>,
+        page: Option<u32>,
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url.clone())?;
+
+        match functions::actions::list_artifacts(
+            github_client,
+            &repo_id,
+            run_id.map(WorkflowRunId::new),
+            Pagination::new(page, per_page),
+        )
+        .await
+        {
+            Ok(artifacts) => {
+                let mut lines = vec![format!(
+                    "Artifacts of {} (page {}, {} artifact(s)):",
+                    repository_url,
+                    artifacts.page,
+                    artifacts.items.len()
+                )];
+                lines.extend(artifacts.items.iter().map(|artifact| {
+                    format!(
+                        "- {} {} ({} bytes){}",
+                        artifact.id,
+                        artifact.name,
+                        artifact.size_in_bytes,
+                        if artifact.expired { " [expired]" } else { "" }
+                    )
+                }));
+                if artifacts.has_next_page {
+                    lines.push(format!(
+                        "More artifacts available on page {}",
+                        artifacts.page + 1
+                    ));
+                }
+                Ok(success_result(lines.join("\n"), &artifacts))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to list artifacts: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn download_artifact(
+        github_client: &GitHubClient,
+        repository_url: String,
+        artifact_id: u64,
+        output_dir: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+        let output_dir = output_dir
+            .map(PathBuf::from)
+            .unwrap_or_else(std::env::temp_dir);
+
+        match functions::actions::download_artifact(
+            github_client,
+            &repo_id,
+            ArtifactId::new(artifact_id),
+            &output_dir,
+        )
+        .await
+        {
+            Ok(path) => Ok(success_result(
+                format!("Saved artifact {} to {}", artifact_id, path.display()),
+                &path,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to download artifact: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn list_pending_deployments(
+        github_client: &GitHubClient,
+        repository_url: String,
+        run_id: u64,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::actions::list_pending_deployments(
+            github_client,
+            &repo_id,
+            WorkflowRunId::new(run_id),
+        )
+        .await
+        {
+            Ok(deployments) if deployments.is_empty() => Ok(success_result(
+                format!(
+                    "No deployments of workflow run {} are waiting for review",
+                    run_id
+                ),
+                &deployments,
+            )),
+            Ok(deployments) => {
+                let mut lines = vec![format!(
+                    "Deployments of workflow run {} waiting for review:",
+                    run_id
+                )];
+                lines.extend(deployments.iter().map(|deployment| {
+                    let reviewers: Vec<&str> = deployment
+                        .reviewers
+                        .iter()
+                        .filter_map(|reviewer| reviewer.name())
+                        .collect();
+                    format!(
+                        "- environment {} (ID: {}), reviewers: {}, you {} approve{}",
+                        deployment.environment.name,
+                        deployment.environment.id,
+                        if reviewers.is_empty() {
+                            "none".to_string()
+                        } else {
+                            reviewers.join(", ")
+                        },
+                        if deployment.current_user_can_approve {
+                            "can"
+                        } else {
+                            "cannot"
+                        },
+                        if deployment.wait_timer > 0 {
+                            format!(", wait timer {} minute(s)", deployment.wait_timer)
+                        } else {
+                            String::new()
+                        }
+                    )
+                }));
+                Ok(success_result(lines.join("\n"), &deployments))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to list pending deployments: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn review_pending_deployments(
+        github_client: &GitHubClient,
+        repository_url: String,
+        run_id: u64,
+        environment_ids: Vec<u64>,
+        state: String,
+        comment: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+        let state = state.parse::<DeploymentReviewState>().map_err(|_| {
+            McpError::invalid_request(
+                format!(
+                    "Invalid state '{}'. Must be one of: approved, rejected",
+                    state
+                ),
+                None,
+            )
+        })?;
+        if environment_ids.is_empty() {
+            return Err(McpError::invalid_request(
+                "At least one environment ID is required",
+                None,
+            ));
+        }
+
+        match functions::actions::review_pending_deployments(
+            github_client,
+            &repo_id,
+            WorkflowRunId::new(run_id),
+            &environment_ids,
+            state,
+            comment.as_deref().unwrap_or_default(),
+        )
+        .await
+        {
+            Ok(deployments) => {
+                let mut lines = vec![format!(
+                    "Marked deployments of workflow run {} as {} for {} environment(s)",
+                    run_id,
+                    state,
+                    environment_ids.len()
+                )];
+                lines.extend(deployments.iter().map(|deployment| {
+                    format!(
+                        "- deployment {} to {} at {}",
+                        deployment.id, deployment.environment, deployment.git_ref
+                    )
+                }));
+                Ok(success_result(lines.join("\n"), &deployments))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to review pending deployments: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+}
+
+fn parse_repository_url(repository_url: String) -> Result<RepositoryId, McpError> {
+    RepositoryId::parse_url(&RepositoryUrl(repository_url))
+        .map_err(|e| McpError::invalid_request(format!("Invalid repository URL: {}", e), None))
+}
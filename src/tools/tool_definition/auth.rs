@@ -0,0 +1,66 @@
+//! Authentication tool definitions
+//!
+//! This module contains MCP tool implementations for checking the identity,
+//! permissions and rate limits of the configured token.
+
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::repository::Owner;
+
+use crate::tools::tool_definition::success_result;
+use rmcp::{Error as McpError, model::*};
+
+/// Authentication tools implementation
+pub struct AuthTools;
+
+impl AuthTools {
+    pub async fn auth_status(github_client: &GitHubClient) -> Result<CallToolResult, McpError> {
+        match functions::auth::verify_auth(github_client).await {
+            Ok(status) => Ok(success_result(
+                functions::auth::describe_auth_status(&status).join("\n"),
+                &status,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to verify authentication: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn get_authenticated_user(
+        github_client: &GitHubClient,
+    ) -> Result<CallToolResult, McpError> {
+        match functions::auth::get_authenticated_user(github_client).await {
+            Ok(user) => Ok(success_result(
+                functions::auth::describe_authenticated_user(&user).join("\n"),
+                &user,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to get the authenticated user: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn get_rate_limit(
+        github_client: &GitHubClient,
+        owner: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        match functions::auth::get_rate_limit(github_client, owner.map(Owner::new)).await {
+            Ok(rate_limit) => Ok(success_result(
+                functions::auth::describe_rate_limit(&rate_limit, chrono::Utc::now()).join("\n"),
+                &rate_limit,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to get rate limit: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Check run and commit status tool definitions
+//!
+//! This module contains MCP tool implementations for reading check runs and
+//! combined commit statuses and for reporting commit statuses.
+
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::check::CommitState;
+use crate::types::pagination::Pagination;
+use crate::types::repository::{RepositoryId, RepositoryUrl};
+
+use crate::tools::tool_definition::success_result;
+use rmcp::{Error as McpError, model::*};
+
+/// Check run and commit status tools implementation
+pub struct CheckTools;
+
+impl CheckTools {
+    pub async fn list_check_runs_for_ref(
+        github_client: &GitHubClient,
+        repository_url: String,
+        git_ref: String,
+        check_name: Option<String>,
+        page: Option<u32>,
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::check::list_check_runs_for_ref(
+            github_client,
+            &repo_id,
+            &git_ref,
+            check_name.as_deref(),
+            Pagination::new(page, per_page),
+        )
+        .await
+        {
+            Ok(check_runs) => {
+                let mut lines = vec![format!(
+                    "Check runs for {} (page {}, {} check run(s)):",
+                    git_ref,
+                    check_runs.page,
+                    check_runs.items.len()
+                )];
+                lines.extend(check_runs.items.iter().map(|check_run| {
+                    format!(
+                        "- {} [{}{}]{}",
+                        check_run.name,
+                        check_run.status,
+                        check_run
+                            .conclusion
+                            .map(|conclusion| format!(": {}", conclusion))
+                            .unwrap_or_default(),
+                        check_run
+                            .html_url
+                            .as_deref()
+                            .map(|url| format!(" {}", url))
+                            .unwrap_or_default()
+                    )
+                }));
+                let failing = check_runs
+                    .items
+                    .iter()
+                    .filter(|check_run| check_run.is_failing())
+                    .count();
+                if failing > 0 {
+                    lines.push(format!("{} check run(s) failing", failing));
+                }
+                if check_runs.has_next_page {
+                    lines.push(format!(
+                        "More check runs available on page {}",
+                        check_runs.page + 1
+                    ));
+                }
+                Ok(success_result(lines.join("\n"), &check_runs))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to list check runs: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn get_combined_status(
+        github_client: &GitHubClient,
+        repository_url: String,
+        git_ref: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::check::get_combined_status(github_client, &repo_id, &git_ref).await {
+            Ok(combined) => {
+                let mut lines = vec![format!(
+                    "Combined status of {} ({}): {} ({} status(es))",
+                    git_ref, combined.sha, combined.state, combined.total_count
+                )];
+                lines.extend(combined.statuses.iter().map(|status| {
+                    format!(
+                        "- {} [{}]{}{}",
+                        status.context,
+                        status.state,
+                        status
+                            .description
+                            .as_deref()
+                            .map(|description| format!(": {}", description))
+                            .unwrap_or_default(),
+                        status
+                            .target_url
+                            .as_deref()
+                            .map(|url| format!(" {}", url))
+                            .unwrap_or_default()
+                    )
+                }));
+                Ok(success_result(lines.join("\n"), &combined))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to get combined status: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn create_commit_status(
+        github_client: &GitHubClient,
+        repository_url: String,
+        sha: String,
+        state: String,
+        context: Option<String>,
+        description: Option<String>,
+        target_url: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+        let state = state.parse::<CommitState>().map_err(|_| {
+            McpError::invalid_request(
+                format!(
+                    "Invalid state '{}'. Must be one of: error, failure, pending, success",
+                    state
+                ),
+                None,
+            )
+        })?;
+
+        match functions::check::create_commit_status(
+            github_client,
+            &repo_id,
+            &sha,
+            state,
+            context.as_deref(),
+            description.as_deref(),
+            target_url.as_deref(),
+        )
+        .await
+        {
+            Ok(status) => Ok(success_result(
+                format!(
+                    "Created {} status '{}' on {} (status ID: {})",
+                    status.state, status.context, sha, status.id
+                ),
+                &status,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to create commit status: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+}
+
+fn parse_repository_url(repository_url: String) -> Result<RepositoryId, McpError> {
+    RepositoryId::parse_url(&RepositoryUrl(repository_url))
+        .map_err(|e| McpError::invalid_request(format!("Invalid repository URL: {}", e), None))
+}
@@ -0,0 +1,167 @@
+//! Comment moderation tool definitions for GitHub repository operations
+//!
+//! This module contains MCP tool implementations for hiding and showing
+//! comments on issues and pull requests.
+
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::comment::{CommentAction, CommentTarget, CommentTargetType, MinimizeReason};
+use crate::types::repository::{RepositoryId, RepositoryUrl};
+
+use crate::tools::tool_definition::success_result;
+use rmcp::{Error as McpError, model::*};
+use serde_json::json;
+
+/// Comment moderation tools implementation
+pub struct CommentTools;
+
+impl CommentTools {
+    pub async fn can_edit_comment(
+        github_client: &GitHubClient,
+        repository_url: String,
+        target_type: String,
+        comment_id: u64,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+        let target = parse_target(&target_type, comment_id)?;
+
+        match functions::comment::can_edit_comment(github_client, &repo_id, target).await {
+            Ok(permissions) => {
+                let yes_no = |allowed: bool| if allowed { "yes" } else { "no" };
+                let lines = [
+                    format!(
+                        "Permissions on {} (author: {}{}):",
+                        target,
+                        permissions.author.as_deref().unwrap_or("unknown"),
+                        if permissions.viewer_did_author {
+                            ", written by you"
+                        } else {
+                            ""
+                        }
+                    ),
+                    format!("- edit: {}", yes_no(permissions.can_update)),
+                    format!("- delete: {}", yes_no(permissions.can_delete)),
+                    format!("- hide: {}", yes_no(permissions.can_minimize)),
+                ];
+                Ok(success_result(lines.join("\n"), &permissions))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to check comment permissions: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn minimize_comment(
+        github_client: &GitHubClient,
+        repository_url: String,
+        target_type: String,
+        comment_id: u64,
+        reason: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+        let target = parse_target(&target_type, comment_id)?;
+        let reason = reason.parse::<MinimizeReason>().map_err(|_| {
+            McpError::invalid_request(
+                format!(
+                    "Invalid reason '{}'. Must be one of: spam, abuse, off_topic, outdated, duplicate, resolved",
+                    reason
+                ),
+                None,
+            )
+        })?;
+        if let Some(denied) =
+            check_comment_permission(github_client, &repo_id, target, CommentAction::Minimize).await
+        {
+            return Ok(denied);
+        }
+
+        match functions::comment::minimize_comment(github_client, &repo_id, target, reason).await {
+            Ok(_) => Ok(success_result(
+                format!("Hid {} as {}", target, reason),
+                &json!({"target": target.to_string(), "reason": reason, "minimized": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to hide comment: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn unminimize_comment(
+        github_client: &GitHubClient,
+        repository_url: String,
+        target_type: String,
+        comment_id: u64,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+        let target = parse_target(&target_type, comment_id)?;
+        if let Some(denied) =
+            check_comment_permission(github_client, &repo_id, target, CommentAction::Minimize).await
+        {
+            return Ok(denied);
+        }
+
+        match functions::comment::unminimize_comment(github_client, &repo_id, target).await {
+            Ok(_) => Ok(success_result(
+                format!("Unhid {}", target),
+                &json!({"target": target.to_string(), "minimized": false}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to unhide comment: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+}
+
+fn parse_target(target_type: &str, comment_id: u64) -> Result<CommentTarget, McpError> {
+    let target_type = target_type.parse::<CommentTargetType>().map_err(|_| {
+        McpError::invalid_request(
+            format!(
+                "Invalid target type '{}'. Must be one of: issue_comment, pull_request_comment",
+                target_type
+            ),
+            None,
+        )
+    })?;
+    Ok(CommentTarget::new(target_type, comment_id))
+}
+
+/// Check that the authenticated user may perform `action` on a comment
+///
+/// Returns a "not permitted" tool result if the permission is missing. If the
+/// permissions cannot be read, the check is skipped and the operation itself
+/// reports the failure.
+pub(crate) async fn check_comment_permission(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    target: CommentTarget,
+    action: CommentAction,
+) -> Option<CallToolResult> {
+    match functions::comment::can_edit_comment(github_client, repository_id, target).await {
+        Ok(permissions) if !permissions.allows(action) => Some(CallToolResult {
+            content: vec![Content::text(format!(
+                "Not permitted: the authenticated user cannot {} {} (author: {})",
+                action,
+                target,
+                permissions.author.as_deref().unwrap_or("unknown")
+            ))],
+            is_error: Some(true),
+        }),
+        Ok(_) => None,
+        Err(e) => {
+            tracing::warn!("Skipping permission check for {}: {}", target, e);
+            None
+        }
+    }
+}
@@ -0,0 +1,286 @@
+//! Deletion tool definitions guarded by confirmation tokens
+//!
+//! Each tool takes two calls. Called without `confirmation_token`, it reads
+//! what would be destroyed, describes it, and returns a token; nothing is
+//! deleted. Called again with the same arguments and that token, it deletes.
+
+use crate::github::GitHubClient;
+use crate::tools::confirmation::ConfirmationTokens;
+use crate::tools::functions;
+use crate::tools::tool_definition::success_result;
+use crate::types::comment::{CommentTarget, CommentTargetType};
+use crate::types::confirmation::{ConfirmationRequest, DestructiveOperation};
+use crate::types::issue::IssueNumber;
+use crate::types::label::Label;
+use crate::types::pagination::{MAX_PER_PAGE, Pagination};
+use crate::types::repository::{MilestoneNumber, RepositoryId, RepositoryUrl};
+
+use rmcp::{Error as McpError, model::*};
+use serde_json::json;
+
+/// Deletion tool implementations
+pub struct DeletionTools;
+
+impl DeletionTools {
+    /// Delete an issue and its comments
+    pub async fn delete_issue(
+        github_client: &GitHubClient,
+        confirmations: &ConfirmationTokens,
+        repository_url: String,
+        issue_number: IssueNumber,
+        confirmation_token: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+        let operation = DestructiveOperation::DeleteIssue(repo_id.clone(), issue_number);
+
+        let Some(token) = confirmation_token else {
+            return Ok(
+                match functions::issue::get_issue(github_client, &repo_id, issue_number).await {
+                    Ok(issue) => request_confirmation(
+                        confirmations,
+                        operation,
+                        format!(
+                            "Issue #{} \"{}\" ({}, opened by {}) and its {} comment(s) will be permanently deleted",
+                            issue_number,
+                            issue.title,
+                            issue.state,
+                            issue.author,
+                            issue.comments.len()
+                        ),
+                    ),
+                    Err(e) => error_result(format!("Failed to get issue: {}", e)),
+                },
+            );
+        };
+        if let Err(e) = confirmations.redeem(&token, &operation) {
+            return Ok(error_result(e));
+        }
+
+        match functions::issue::delete_issue(github_client, &repo_id, issue_number).await {
+            Ok(_) => Ok(success_result(
+                format!("Deleted issue #{} in {}", issue_number, repo_id),
+                &json!({"issue_number": issue_number, "deleted": true}),
+            )),
+            Err(e) => Ok(error_result(format!("Failed to delete issue: {}", e))),
+        }
+    }
+
+    /// Delete a label from a repository
+    pub async fn delete_label(
+        github_client: &GitHubClient,
+        confirmations: &ConfirmationTokens,
+        repository_url: String,
+        name: String,
+        confirmation_token: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+        let operation = DestructiveOperation::DeleteLabel(repo_id.clone(), name.clone());
+
+        let Some(token) = confirmation_token else {
+            return Ok(match find_label(github_client, &repo_id, &name).await {
+                Ok(Some(label)) => request_confirmation(
+                    confirmations,
+                    operation,
+                    format!(
+                        "Label '{}' (color #{}) will be deleted and removed from every issue and pull request that has it",
+                        label.name,
+                        label.color()
+                    ),
+                ),
+                Ok(None) => error_result(format!("Label '{}' not found in {}", name, repo_id)),
+                Err(e) => error_result(format!("Failed to list labels: {}", e)),
+            });
+        };
+        if let Err(e) = confirmations.redeem(&token, &operation) {
+            return Ok(error_result(e));
+        }
+
+        match functions::repository::delete_label(github_client, &repo_id, &name).await {
+            Ok(_) => Ok(success_result(
+                format!("Deleted label '{}' in {}", name, repo_id),
+                &json!({"name": name, "deleted": true}),
+            )),
+            Err(e) => Ok(error_result(format!("Failed to delete label: {}", e))),
+        }
+    }
+
+    /// Delete a milestone from a repository
+    pub async fn delete_milestone(
+        github_client: &GitHubClient,
+        confirmations: &ConfirmationTokens,
+        repository_url: String,
+        milestone_number: MilestoneNumber,
+        confirmation_token: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+        let operation = DestructiveOperation::DeleteMilestone(repo_id.clone(), milestone_number);
+
+        let Some(token) = confirmation_token else {
+            let milestones =
+                functions::repository::list_milestones(github_client, &repo_id, None).await;
+            return Ok(match milestones {
+                Ok(milestones) => match milestones.iter().find(|m| m.id == milestone_number) {
+                    Some(milestone) => request_confirmation(
+                        confirmations,
+                        operation,
+                        format!(
+                            "Milestone {} \"{}\" ({}, {} open and {} closed issue(s)) will be deleted; its issues and pull requests are kept without a milestone",
+                            milestone_number.value(),
+                            milestone.title,
+                            milestone.state,
+                            milestone.open_issues,
+                            milestone.closed_issues
+                        ),
+                    ),
+                    None => error_result(format!(
+                        "Milestone {} not found in {}",
+                        milestone_number.value(),
+                        repo_id
+                    )),
+                },
+                Err(e) => error_result(format!("Failed to list milestones: {}", e)),
+            });
+        };
+        if let Err(e) = confirmations.redeem(&token, &operation) {
+            return Ok(error_result(e));
+        }
+
+        match functions::repository::delete_milestone(github_client, &repo_id, &milestone_number)
+            .await
+        {
+            Ok(_) => Ok(success_result(
+                format!(
+                    "Deleted milestone {} in {}",
+                    milestone_number.value(),
+                    repo_id
+                ),
+                &json!({"milestone_number": milestone_number, "deleted": true}),
+            )),
+            Err(e) => Ok(error_result(format!("Failed to delete milestone: {}", e))),
+        }
+    }
+
+    /// Delete an issue or pull request comment
+    pub async fn delete_comment(
+        github_client: &GitHubClient,
+        confirmations: &ConfirmationTokens,
+        repository_url: String,
+        target_type: String,
+        comment_id: u64,
+        confirmation_token: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+        let target_type = target_type.parse::<CommentTargetType>().map_err(|_| {
+            McpError::invalid_request(
+                format!(
+                    "Invalid target type '{}'. Must be one of: issue_comment, pull_request_comment",
+                    target_type
+                ),
+                None,
+            )
+        })?;
+        let target = CommentTarget::new(target_type, comment_id);
+        let operation = DestructiveOperation::DeleteComment(repo_id.clone(), target);
+
+        let Some(token) = confirmation_token else {
+            return Ok(
+                match functions::comment::can_edit_comment(github_client, &repo_id, target).await {
+                    Ok(permissions) if !permissions.can_delete => error_result(format!(
+                        "Not permitted: the authenticated user cannot delete {} (author: {})",
+                        target,
+                        permissions.author.as_deref().unwrap_or("unknown")
+                    )),
+                    Ok(permissions) => request_confirmation(
+                        confirmations,
+                        operation,
+                        format!(
+                            "The {} written by {} will be permanently deleted",
+                            target,
+                            permissions.author.as_deref().unwrap_or("unknown")
+                        ),
+                    ),
+                    Err(e) => error_result(format!("Failed to get comment: {}", e)),
+                },
+            );
+        };
+        if let Err(e) = confirmations.redeem(&token, &operation) {
+            return Ok(error_result(e));
+        }
+
+        match functions::comment::delete_comment(github_client, &repo_id, target).await {
+            Ok(_) => Ok(success_result(
+                format!("Deleted {} in {}", target, repo_id),
+                &json!({"target": target.to_string(), "deleted": true}),
+            )),
+            Err(e) => Ok(error_result(format!("Failed to delete comment: {}", e))),
+        }
+    }
+}
+
+fn parse_repository_url(repository_url: String) -> Result<RepositoryId, McpError> {
+    RepositoryId::parse_url(&RepositoryUrl(repository_url))
+        .map_err(|e| McpError::invalid_request(format!("Invalid repository URL: {}", e), None))
+}
+
+/// Issue a confirmation token and describe what the deletion would destroy
+fn request_confirmation(
+    confirmations: &ConfirmationTokens,
+    operation: DestructiveOperation,
+    description: String,
+) -> CallToolResult {
+    let tool_name = operation.tool_name();
+    let operation_text = operation.to_string();
+    let (confirmation_token, expires_at) = confirmations.issue(operation);
+    let text = format!(
+        "Nothing was deleted yet. {}. This cannot be undone.\nTo {}, call {} again with the same arguments and confirmation_token \"{}\" before {}.",
+        description,
+        operation_text,
+        tool_name,
+        confirmation_token,
+        expires_at.to_rfc3339()
+    );
+    success_result(
+        text,
+        &ConfirmationRequest {
+            confirmation_token,
+            operation: operation_text,
+            description,
+            expires_at,
+        },
+    )
+}
+
+/// Find a label by name, ignoring case like GitHub does
+async fn find_label(
+    github_client: &GitHubClient,
+    repository_id: &RepositoryId,
+    name: &str,
+) -> anyhow::Result<Option<Label>> {
+    let mut page = 1;
+    loop {
+        let labels = functions::repository::list_labels(
+            github_client,
+            repository_id,
+            Pagination::new(Some(page), Some(MAX_PER_PAGE)),
+        )
+        .await?;
+        if let Some(label) = labels
+            .items
+            .into_iter()
+            .find(|label| label.name.eq_ignore_ascii_case(name))
+        {
+            return Ok(Some(label));
+        }
+        if !labels.has_next_page {
+            return Ok(None);
+        }
+        page += 1;
+    }
+}
+
+fn error_result(message: String) -> CallToolResult {
+    CallToolResult {
+        content: vec![Content::text(message)],
+        is_error: Some(true),
+    }
+}
@@ -0,0 +1,387 @@
+//! GitHub Discussions tool definitions
+//!
+//! This module contains MCP tool implementations for listing discussion
+//! categories, creating and reading discussions, commenting on them,
+//! marking answers and moderating discussions.
+
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::discussion::{Discussion, DiscussionComment, DiscussionNumber, LockReason};
+use crate::types::repository::{RepositoryId, RepositoryUrl};
+
+use crate::tools::tool_definition::success_result;
+use rmcp::{Error as McpError, model::*};
+use serde_json::json;
+
+/// GitHub Discussions tools implementation
+pub struct DiscussionTools;
+
+impl DiscussionTools {
+    pub async fn list_discussion_categories(
+        github_client: &GitHubClient,
+        repository_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url.clone())?;
+
+        match functions::discussion::list_discussion_categories(github_client, &repo_id).await {
+            Ok(categories) => {
+                let mut lines = vec![format!(
+                    "Discussion categories of {} ({} categories):",
+                    repository_url,
+                    categories.len()
+                )];
+                lines.extend(categories.iter().map(|category| {
+                    format!(
+                        "- {} (slug: {}, id: {}){}{}",
+                        category.name,
+                        category.slug,
+                        category.id,
+                        if category.is_answerable {
+                            " [answerable]"
+                        } else {
+                            ""
+                        },
+                        category
+                            .description
+                            .as_deref()
+                            .map(|description| format!(": {}", description))
+                            .unwrap_or_default()
+                    )
+                }));
+                Ok(success_result(lines.join("\n"), &categories))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to list discussion categories: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn get_discussion(
+        github_client: &GitHubClient,
+        repository_url: String,
+        discussion_number: u32,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::discussion::get_discussion(
+            github_client,
+            &repo_id,
+            DiscussionNumber::new(discussion_number),
+        )
+        .await
+        {
+            Ok(discussion) => Ok(success_result(format_discussion(&discussion), &discussion)),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to get discussion: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn create_discussion(
+        github_client: &GitHubClient,
+        repository_url: String,
+        category: String,
+        title: String,
+        body: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::discussion::create_discussion(
+            github_client,
+            &repo_id,
+            &category,
+            &title,
+            &body,
+        )
+        .await
+        {
+            Ok(discussion) => Ok(success_result(
+                format!(
+                    "Created discussion #{} in {}: {}",
+                    discussion.number, discussion.category.name, discussion.url
+                ),
+                &discussion,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to create discussion: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn add_discussion_comment(
+        github_client: &GitHubClient,
+        repository_url: String,
+        discussion_number: u32,
+        body: String,
+        reply_to_id: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::discussion::add_discussion_comment(
+            github_client,
+            &repo_id,
+            DiscussionNumber::new(discussion_number),
+            &body,
+            reply_to_id.as_deref(),
+        )
+        .await
+        {
+            Ok(comment) => Ok(success_result(
+                format!(
+                    "Added comment {} to discussion #{}: {}",
+                    comment.id, discussion_number, comment.url
+                ),
+                &comment,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to add discussion comment: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn mark_comment_as_answer(
+        github_client: &GitHubClient,
+        comment_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        if comment_id.trim().is_empty() {
+            return Err(McpError::invalid_request(
+                "comment_id must not be empty".to_string(),
+                None,
+            ));
+        }
+
+        match functions::discussion::mark_comment_as_answer(github_client, &comment_id).await {
+            Ok(discussion_number) => Ok(success_result(
+                format!(
+                    "Marked comment {} as the answer of discussion #{}",
+                    comment_id, discussion_number
+                ),
+                &discussion_number,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to mark comment as answer: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn lock_discussion(
+        github_client: &GitHubClient,
+        repository_url: String,
+        discussion_number: u32,
+        reason: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+        let reason = reason
+            .map(|reason| {
+                reason.parse::<LockReason>().map_err(|_| {
+                    McpError::invalid_request(
+                        format!(
+                            "Invalid reason '{}'. Must be one of: off_topic, resolved, spam, too_heated",
+                            reason
+                        ),
+                        None,
+                    )
+                })
+            })
+            .transpose()?;
+
+        match functions::discussion::lock_discussion(
+            github_client,
+            &repo_id,
+            DiscussionNumber::new(discussion_number),
+            reason,
+        )
+        .await
+        {
+            Ok(locked) => Ok(CallToolResult {
+                content: vec![Content::text(if locked {
+                    format!("Locked discussion #{}", discussion_number)
+                } else {
+                    format!(
+                        "Lock requested, but discussion #{} is not reported as locked",
+                        discussion_number
+                    )
+                })],
+                is_error: Some(!locked),
+            }),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to lock discussion: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn unlock_discussion(
+        github_client: &GitHubClient,
+        repository_url: String,
+        discussion_number: u32,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::discussion::unlock_discussion(
+            github_client,
+            &repo_id,
+            DiscussionNumber::new(discussion_number),
+        )
+        .await
+        {
+            Ok(locked) => Ok(CallToolResult {
+                content: vec![Content::text(if locked {
+                    format!(
+                        "Unlock requested, but discussion #{} is still reported as locked",
+                        discussion_number
+                    )
+                } else {
+                    format!("Unlocked discussion #{}", discussion_number)
+                })],
+                is_error: Some(locked),
+            }),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to unlock discussion: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn update_discussion(
+        github_client: &GitHubClient,
+        repository_url: String,
+        discussion_number: u32,
+        title: Option<String>,
+        body: Option<String>,
+        category: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+        if title.is_none() && body.is_none() && category.is_none() {
+            return Err(McpError::invalid_request(
+                "At least one of title, body or category must be given".to_string(),
+                None,
+            ));
+        }
+
+        match functions::discussion::update_discussion(
+            github_client,
+            &repo_id,
+            DiscussionNumber::new(discussion_number),
+            title.as_deref(),
+            body.as_deref(),
+            category.as_deref(),
+        )
+        .await
+        {
+            Ok(discussion) => Ok(success_result(
+                format!(
+                    "Updated discussion #{} ({}): {}",
+                    discussion.number, discussion.category.name, discussion.title
+                ),
+                &discussion,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to update discussion: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn delete_discussion_comment(
+        github_client: &GitHubClient,
+        comment_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        if comment_id.trim().is_empty() {
+            return Err(McpError::invalid_request(
+                "comment_id must not be empty".to_string(),
+                None,
+            ));
+        }
+
+        match functions::discussion::delete_discussion_comment(github_client, &comment_id).await {
+            Ok(()) => Ok(success_result(
+                format!("Deleted discussion comment {}", comment_id),
+                &json!({"comment_id": comment_id, "deleted": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to delete discussion comment: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+}
+
+fn format_discussion(discussion: &Discussion) -> String {
+    let mut state = Vec::new();
+    if discussion.closed {
+        state.push("closed");
+    }
+    if discussion.locked {
+        state.push("locked");
+    }
+    if discussion.is_answered {
+        state.push("answered");
+    }
+
+    let mut lines = vec![
+        format!("#{} {}", discussion.number, discussion.title),
+        format!(
+            "Category: {} | Author: {} | Created: {}{}",
+            discussion.category.name,
+            discussion.author.as_deref().unwrap_or("ghost"),
+            discussion.created_at.to_rfc3339(),
+            if state.is_empty() {
+                String::new()
+            } else {
+                format!(" | {}", state.join(", "))
+            }
+        ),
+        format!("URL: {}", discussion.url),
+        String::new(),
+        discussion.body.clone(),
+        String::new(),
+        format!(
+            "Comments ({} of {}):",
+            discussion.comments.len(),
+            discussion.comment_count
+        ),
+    ];
+    for comment in &discussion.comments {
+        lines.push(format_comment(comment, ""));
+        for reply in &comment.replies {
+            lines.push(format_comment(reply, "  "));
+        }
+    }
+    lines.join("\n")
+}
+
+fn format_comment(comment: &DiscussionComment, indent: &str) -> String {
+    format!(
+        "{}- [{}] {} ({}, {} upvote(s)){}: {}",
+        indent,
+        comment.id,
+        comment.author.as_deref().unwrap_or("ghost"),
+        comment.created_at.to_rfc3339(),
+        comment.upvote_count,
+        if comment.is_answer { " [answer]" } else { "" },
+        comment.body
+    )
+}
+
+fn parse_repository_url(repository_url: String) -> Result<RepositoryId, McpError> {
+    RepositoryId::parse_url(&RepositoryUrl(repository_url))
+        .map_err(|e| McpError::invalid_request(format!("Invalid repository URL: {}", e), None))
+}
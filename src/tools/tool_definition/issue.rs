@@ -7,12 +7,24 @@
 
 use crate::github::GitHubClient;
 use crate::tools::functions;
+use crate::tools::functions::issue::MAX_DETAILS_URLS;
+use crate::tools::tool_definition::comment::check_comment_permission;
+use crate::tools::tool_definition::{parse_comment_limit, parse_since};
 use crate::types::User;
-use crate::types::issue::{IssueCommentNumber, IssueNumber, IssueState};
+use crate::types::comment::{CommentAction, CommentTarget};
+use crate::types::issue::{
+    BulkIssueOperations, IssueCommentNumber, IssueDetails, IssueId, IssueLinkState, IssueNumber,
+    IssueState, IssueStateReason, IssueUrl,
+};
 use crate::types::label::Label;
-use crate::types::repository::{MilestoneNumber, RepositoryId, RepositoryUrl};
+use crate::types::pagination::Pagination;
+use crate::types::project::ProjectOriginalResource;
+use crate::types::repository::{MilestoneNumber, Owner, RepositoryId, RepositoryUrl};
 
+use crate::tools::tool_definition::success_result;
+use chrono::{DateTime, Utc};
 use rmcp::{Error as McpError, model::*};
+use serde_json::json;
 
 /// Issue management tools implementation
 pub struct IssueTools;
@@ -37,7 +49,7 @@ impl IssueTools {
                 .collect()
         });
         let label_objects: Option<Vec<Label>> =
-            labels.map(|l| l.into_iter().map(|name| Label::from(name)).collect());
+            labels.map(|l| l.into_iter().map(Label::from).collect());
         let milestone: Option<MilestoneNumber> = milestone_number.map(MilestoneNumber::new);
 
         match functions::issue::create_issue(
@@ -51,13 +63,13 @@ impl IssueTools {
         )
         .await
         {
-            Ok(issue) => Ok(CallToolResult {
-                content: vec![Content::text(format!(
+            Ok(issue) => Ok(success_result(
+                format!(
                     "Issue created successfully: #{}\nTitle: {}\nState: {:?}",
                     issue.issue_id.number, issue.title, issue.state
-                ))],
-                is_error: Some(false),
-            }),
+                ),
+                &issue,
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to create issue: {}", e))],
                 is_error: Some(true),
@@ -77,13 +89,10 @@ impl IssueTools {
         let issue_num = issue_number;
 
         match functions::issue::add_comment(github_client, &repo_id, issue_num, &body).await {
-            Ok(comment_number) => Ok(CallToolResult {
-                content: vec![Content::text(format!(
-                    "Comment added successfully: #{}",
-                    comment_number
-                ))],
-                is_error: Some(false),
-            }),
+            Ok(comment_number) => Ok(success_result(
+                format!("Comment added successfully: #{}", comment_number),
+                &comment_number,
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to add comment: {}", e))],
                 is_error: Some(true),
@@ -104,13 +113,24 @@ impl IssueTools {
         let issue_num = issue_number;
         let comment_num = comment_number;
 
+        if let Some(denied) = check_comment_permission(
+            github_client,
+            &repo_id,
+            CommentTarget::IssueComment(comment_num),
+            CommentAction::Edit,
+        )
+        .await
+        {
+            return Ok(denied);
+        }
+
         match functions::issue::edit_comment(github_client, &repo_id, issue_num, comment_num, &body)
             .await
         {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text("Comment edited successfully".to_string())],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Comment edited successfully".to_string(),
+                &json!({"issue_number": issue_num, "comment_number": comment_num}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to edit comment: {}", e))],
                 is_error: Some(true),
@@ -130,10 +150,10 @@ impl IssueTools {
         let issue_num = issue_number;
 
         match functions::issue::edit_title(github_client, &repo_id, issue_num, &title).await {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text("Issue title edited successfully".to_string())],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Issue title edited successfully".to_string(),
+                &json!({"issue_number": issue_num, "title": title}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to edit title: {}", e))],
                 is_error: Some(true),
@@ -153,10 +173,10 @@ impl IssueTools {
         let issue_num = issue_number;
 
         match functions::issue::edit_body(github_client, &repo_id, issue_num, &body).await {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text("Issue body edited successfully".to_string())],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Issue body edited successfully".to_string(),
+                &json!({"issue_number": issue_num}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to edit body: {}", e))],
                 is_error: Some(true),
@@ -169,6 +189,7 @@ impl IssueTools {
         repository_url: String,
         issue_number: IssueNumber,
         state: String,
+        state_reason: Option<String>,
     ) -> Result<CallToolResult, McpError> {
         let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
             McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
@@ -186,15 +207,44 @@ impl IssueTools {
                 });
             }
         };
+        let issue_state_reason = match state_reason {
+            Some(reason) => match reason.to_lowercase().parse::<IssueStateReason>() {
+                Ok(reason) if reason.is_valid_for(issue_state) => Some(reason),
+                Ok(reason) => {
+                    return Ok(CallToolResult {
+                        content: vec![Content::text(format!(
+                            "State reason '{}' cannot be used with state '{}'",
+                            reason, issue_state
+                        ))],
+                        is_error: Some(true),
+                    });
+                }
+                Err(_) => {
+                    return Ok(CallToolResult {
+                        content: vec![Content::text(
+                            "State reason must be 'completed', 'not_planned' or 'reopened'"
+                                .to_string(),
+                        )],
+                        is_error: Some(true),
+                    });
+                }
+            },
+            None => None,
+        };
 
-        match functions::issue::update_state(github_client, &repo_id, issue_num, issue_state).await
+        match functions::issue::update_state(
+            github_client,
+            &repo_id,
+            issue_num,
+            issue_state,
+            issue_state_reason,
+        )
+        .await
         {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text(
-                    "Issue state updated successfully".to_string(),
-                )],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Issue state updated successfully".to_string(),
+                &json!({"issue_number": issue_num, "state": state}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to update state: {}", e))],
                 is_error: Some(true),
@@ -227,14 +277,14 @@ impl IssueTools {
                         skipped.join(", ")
                     ));
                 }
-                Ok(CallToolResult {
-                    content: vec![Content::text(if result.is_empty() {
+                Ok(success_result(
+                    if result.is_empty() {
                         "No changes made to assignees".to_string()
                     } else {
                         result.join("; ")
-                    })],
-                    is_error: Some(false),
-                })
+                    },
+                    &json!({"added": added, "skipped": skipped}),
+                ))
             }
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to add assignees: {}", e))],
@@ -265,14 +315,14 @@ impl IssueTools {
                 if !skipped.is_empty() {
                     result.push(format!("Skipped (not assigned): {}", skipped.join(", ")));
                 }
-                Ok(CallToolResult {
-                    content: vec![Content::text(if result.is_empty() {
+                Ok(success_result(
+                    if result.is_empty() {
                         "No changes made to assignees".to_string()
                     } else {
                         result.join("; ")
-                    })],
-                    is_error: Some(false),
-                })
+                    },
+                    &json!({"removed": removed, "skipped": skipped}),
+                ))
             }
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to remove assignees: {}", e))],
@@ -291,7 +341,7 @@ impl IssueTools {
             McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
         })?;
         let issue_num = issue_number;
-        let label_objects: Vec<Label> = labels.into_iter().map(|name| Label::from(name)).collect();
+        let label_objects: Vec<Label> = labels.into_iter().map(Label::from).collect();
 
         match functions::issue::remove_labels(github_client, &repo_id, issue_num, &label_objects)
             .await
@@ -318,14 +368,14 @@ impl IssueTools {
                             .join(", ")
                     ));
                 }
-                Ok(CallToolResult {
-                    content: vec![Content::text(if result.is_empty() {
+                Ok(success_result(
+                    if result.is_empty() {
                         "No changes made to labels".to_string()
                     } else {
                         result.join("; ")
-                    })],
-                    is_error: Some(false),
-                })
+                    },
+                    &json!({"removed": removed, "skipped": skipped}),
+                ))
             }
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to remove labels: {}", e))],
@@ -344,7 +394,7 @@ impl IssueTools {
             McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
         })?;
         let issue_num = issue_number;
-        let label_objects: Vec<Label> = labels.into_iter().map(|name| Label::from(name)).collect();
+        let label_objects: Vec<Label> = labels.into_iter().map(Label::from).collect();
 
         match functions::issue::add_labels(github_client, &repo_id, issue_num, &label_objects).await
         {
@@ -370,14 +420,14 @@ impl IssueTools {
                             .join(", ")
                     ));
                 }
-                Ok(CallToolResult {
-                    content: vec![Content::text(if result.is_empty() {
+                Ok(success_result(
+                    if result.is_empty() {
                         "No changes made to labels".to_string()
                     } else {
                         result.join("; ")
-                    })],
-                    is_error: Some(false),
-                })
+                    },
+                    &json!({"added": added_labels, "skipped": skipped_labels}),
+                ))
             }
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to add labels: {}", e))],
@@ -386,23 +436,664 @@ impl IssueTools {
         }
     }
 
+    /// Get an issue with its body and up to `comment_limit` comments
+    pub async fn get_issue(
+        github_client: &GitHubClient,
+        repository_url: String,
+        issue_number: IssueNumber,
+        comment_limit: Option<u32>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+        let comment_limit = parse_comment_limit(comment_limit)?;
+
+        match functions::issue::get_issue_details(
+            github_client,
+            &repo_id,
+            issue_number,
+            comment_limit,
+        )
+        .await
+        {
+            Ok(details) => Ok(success_result(format_issue_details(&details), &details)),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to get issue: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Get several issues by URL, each with up to `comment_limit` comments
+    ///
+    /// Issues that cannot be read are reported as failures without failing
+    /// the others. At most `concurrency` issues are read at once.
+    pub async fn get_issues(
+        github_client: &GitHubClient,
+        issue_urls: Vec<String>,
+        comment_limit: Option<u32>,
+        concurrency: Option<usize>,
+    ) -> Result<CallToolResult, McpError> {
+        if issue_urls.is_empty() || issue_urls.len() > MAX_DETAILS_URLS {
+            return Err(McpError::invalid_request(
+                format!(
+                    "Pass between 1 and {} issue URLs, got {}",
+                    MAX_DETAILS_URLS,
+                    issue_urls.len()
+                ),
+                None,
+            ));
+        }
+        let issue_ids = issue_urls
+            .into_iter()
+            .map(|url| {
+                IssueId::parse_url(&IssueUrl(url)).map_err(|e| {
+                    McpError::invalid_request(format!("Invalid issue URL: {}", e), None)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let comment_limit = parse_comment_limit(comment_limit)?;
+
+        let mut issues = Vec::new();
+        let mut failures = Vec::new();
+        let results = functions::issue::get_issues_with_details(
+            github_client,
+            &issue_ids,
+            comment_limit,
+            concurrency,
+        )
+        .await;
+        for (issue_id, result) in issue_ids.iter().zip(results) {
+            match result {
+                Ok(details) => issues.push(details),
+                Err(e) => failures.push(json!({"url": issue_id.url(), "error": e.to_string()})),
+            }
+        }
+
+        let mut sections: Vec<String> = issues.iter().map(format_issue_details).collect();
+        for failure in &failures {
+            sections.push(format!(
+                "Failed to get issue {}: {}",
+                failure["url"].as_str().unwrap_or_default(),
+                failure["error"].as_str().unwrap_or_default()
+            ));
+        }
+        let mut result = success_result(
+            sections.join("\n\n"),
+            &json!({"issues": issues, "failures": failures}),
+        );
+        if issues.is_empty() {
+            result.is_error = Some(true);
+        }
+        Ok(result)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_issues(
+        github_client: &GitHubClient,
+        repository_url: String,
+        state: Option<String>,
+        labels: Option<Vec<String>>,
+        assignee: Option<String>,
+        milestone_number: Option<u64>,
+        since: Option<String>,
+        page: Option<u32>,
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+
+        let state = match state.as_deref() {
+            None | Some("all") => None,
+            Some(value) => Some(value.parse::<IssueState>().map_err(|_| {
+                McpError::invalid_request(
+                    format!("Invalid state '{}'. Valid values: open, closed, all", value),
+                    None,
+                )
+            })?),
+        };
+        let since = parse_since(since)?;
+        let labels = labels.unwrap_or_default();
+        let pagination = Pagination::new(page, per_page);
+
+        match functions::issue::list_issues(
+            github_client,
+            &repo_id,
+            state,
+            &labels,
+            assignee.as_deref(),
+            milestone_number.map(MilestoneNumber::new),
+            since,
+            pagination,
+        )
+        .await
+        {
+            Ok(issues) => {
+                let mut lines = vec![format!(
+                    "Found {} issue(s) (page {}):",
+                    issues.items.len(),
+                    issues.page
+                )];
+                lines.extend(issues.items.iter().map(|issue| {
+                    let mut line = format!(
+                        "- #{} [{}] {}",
+                        issue.issue_id.number, issue.state, issue.title
+                    );
+                    if !issue.labels.is_empty() {
+                        line.push_str(&format!(" (labels: {})", issue.labels.join(", ")));
+                    }
+                    if !issue.assignees.is_empty() {
+                        line.push_str(&format!(" (assignees: {})", issue.assignees.join(", ")));
+                    }
+                    line
+                }));
+                if issues.has_next_page {
+                    lines.push(format!("More issues available on page {}", issues.page + 1));
+                }
+                Ok(success_result(lines.join("\n"), &issues))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to list issues: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn list_issue_comments(
+        github_client: &GitHubClient,
+        repository_url: String,
+        issue_number: IssueNumber,
+        since: Option<String>,
+        author: Option<String>,
+        page: Option<u32>,
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+        let since = parse_since(since)?;
+        let pagination = Pagination::new(page, per_page);
+
+        match functions::issue::list_comments(
+            github_client,
+            &repo_id,
+            issue_number,
+            since,
+            author.as_deref(),
+            pagination,
+        )
+        .await
+        {
+            Ok(comments) => {
+                let mut lines = vec![format!(
+                    "Comments on issue #{} (page {}, {} comment(s)):",
+                    issue_number,
+                    comments.page,
+                    comments.items.len()
+                )];
+                for comment in &comments.items {
+                    lines.push(format!(
+                        "- #{} by {} at {}:\n{}",
+                        comment.comment_number,
+                        comment
+                            .author
+                            .as_ref()
+                            .map(|user| user.username.as_str())
+                            .unwrap_or("unknown"),
+                        comment.created_at.to_rfc3339(),
+                        comment.body
+                    ));
+                }
+                if comments.has_next_page {
+                    lines.push(format!(
+                        "More comments available on page {}",
+                        comments.page + 1
+                    ));
+                }
+                Ok(success_result(lines.join("\n"), &comments))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to list issue comments: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn get_issue_timeline(
+        github_client: &GitHubClient,
+        repository_url: String,
+        issue_number: IssueNumber,
+        page: Option<u32>,
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+        let pagination = Pagination::new(page, per_page);
+
+        match functions::issue::get_timeline(github_client, &repo_id, issue_number, pagination)
+            .await
+        {
+            Ok(timeline) => {
+                let mut lines = vec![format!(
+                    "Timeline of issue #{} (page {}, {} event(s)):",
+                    issue_number,
+                    timeline.page,
+                    timeline.items.len()
+                )];
+                lines.extend(timeline.items.iter().map(|event| {
+                    format!(
+                        "- {} {} {}",
+                        event
+                            .created_at
+                            .map(|t| t.to_rfc3339())
+                            .unwrap_or_else(|| "-".to_string()),
+                        event.actor.as_deref().unwrap_or("unknown"),
+                        event.kind
+                    )
+                }));
+                if timeline.has_next_page {
+                    lines.push(format!(
+                        "More events available on page {}",
+                        timeline.page + 1
+                    ));
+                }
+                Ok(success_result(lines.join("\n"), &timeline))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to get issue timeline: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn find_cross_references(
+        github_client: &GitHubClient,
+        issue_or_pr_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        let item = ProjectOriginalResource::parse_url(&issue_or_pr_url)
+            .map_err(|e| McpError::invalid_request(e, None))?;
+        let Some((repo_id, number)) = item.repository_and_number() else {
+            return Err(McpError::invalid_request(
+                format!("Invalid issue or pull request URL: {}", issue_or_pr_url),
+                None,
+            ));
+        };
+
+        match functions::issue::find_cross_references(
+            github_client,
+            repo_id,
+            IssueNumber::new(number),
+        )
+        .await
+        {
+            Ok(references) => {
+                if references.is_empty() {
+                    return Ok(success_result(
+                        format!("No issues or pull requests reference {}", issue_or_pr_url),
+                        &references,
+                    ));
+                }
+
+                let mut lines = vec![format!(
+                    "{} item(s) reference {}:",
+                    references.len(),
+                    issue_or_pr_url
+                )];
+                lines.extend(references.iter().map(|reference| {
+                    format!(
+                        "- {}#{} [{} {}] {}{} {}",
+                        reference.repository,
+                        reference.number,
+                        reference.state.to_lowercase(),
+                        if reference.is_pull_request {
+                            "pull request"
+                        } else {
+                            "issue"
+                        },
+                        reference.title,
+                        if reference.will_close_target {
+                            " (closes this item when merged)"
+                        } else {
+                            ""
+                        },
+                        reference.url
+                    )
+                }));
+                Ok(success_result(lines.join("\n"), &references))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to find cross-references: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn list_issue_types(
+        github_client: &GitHubClient,
+        owner: String,
+    ) -> Result<CallToolResult, McpError> {
+        let owner = Owner::new(owner);
+
+        match functions::issue::list_issue_types(github_client, &owner).await {
+            Ok(issue_types) => {
+                let text = if issue_types.is_empty() {
+                    format!("No issue types defined for {}", owner)
+                } else {
+                    issue_types
+                        .iter()
+                        .map(|issue_type| {
+                            let mut line = format!("{} (ID: {})", issue_type.name, issue_type.id);
+                            if let Some(description) = &issue_type.description {
+                                line.push_str(&format!(" - {}", description));
+                            }
+                            if !issue_type.is_enabled {
+                                line.push_str(" [disabled]");
+                            }
+                            line
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                };
+                Ok(success_result(text, &issue_types))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to list issue types: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn set_issue_type(
+        github_client: &GitHubClient,
+        repository_url: String,
+        issue_number: IssueNumber,
+        issue_type: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+
+        match functions::issue::set_issue_type(
+            github_client,
+            &repo_id,
+            issue_number,
+            issue_type.as_deref(),
+        )
+        .await
+        {
+            Ok(_) => Ok(success_result(
+                match &issue_type {
+                    Some(issue_type) => format!("Issue type set to {}", issue_type),
+                    None => "Issue type cleared".to_string(),
+                },
+                &json!({"issue_number": issue_number, "issue_type": issue_type}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to set issue type: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn mark_issue_duplicate(
+        github_client: &GitHubClient,
+        repository_url: String,
+        issue_number: IssueNumber,
+        duplicate_of: IssueNumber,
+        duplicate_label: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+
+        match functions::issue::mark_issue_duplicate(
+            github_client,
+            &repo_id,
+            issue_number,
+            duplicate_of,
+            duplicate_label.as_deref(),
+        )
+        .await
+        {
+            Ok(result) => {
+                let mut lines = vec![];
+                if let Some(comment_number) = result.comment_number {
+                    lines.push(format!(
+                        "Added comment #{}: Duplicate of #{}",
+                        comment_number, result.duplicate_of
+                    ));
+                }
+                if let Some(label) = &result.applied_label {
+                    lines.push(format!("Applied label: {}", label));
+                }
+                if result.closed {
+                    lines.push("Closed issue as not planned".to_string());
+                }
+                lines.extend(result.failures.iter().cloned());
+
+                let summary = if result.is_complete() {
+                    format!(
+                        "Issue #{} marked as duplicate of #{}",
+                        issue_number, result.duplicate_of
+                    )
+                } else {
+                    format!(
+                        "Issue #{} was only partially marked as duplicate of #{}",
+                        issue_number, result.duplicate_of
+                    )
+                };
+                lines.insert(0, summary);
+
+                Ok(CallToolResult {
+                    content: vec![Content::text(lines.join("\n"))],
+                    is_error: Some(!result.is_complete()),
+                })
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to mark issue as duplicate: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn bulk_update_issues(
+        github_client: &GitHubClient,
+        repository_url: String,
+        issue_numbers: Vec<IssueNumber>,
+        add_labels: Option<Vec<String>>,
+        milestone_number: Option<u64>,
+        assignees: Option<Vec<String>>,
+        close: Option<bool>,
+        close_reason: Option<String>,
+        concurrency: Option<usize>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+
+        let close_reason = close_reason
+            .map(|reason| {
+                reason.parse::<IssueStateReason>().map_err(|_| {
+                    McpError::invalid_request(
+                        format!(
+                            "Invalid close reason '{}'. Valid values: completed, not_planned",
+                            reason
+                        ),
+                        None,
+                    )
+                })
+            })
+            .transpose()?;
+
+        let operations = BulkIssueOperations {
+            add_labels: add_labels
+                .unwrap_or_default()
+                .into_iter()
+                .map(Label::from)
+                .collect(),
+            milestone: milestone_number.map(MilestoneNumber::new),
+            assignees: assignees.unwrap_or_default(),
+            close: close.unwrap_or(false) || close_reason.is_some(),
+            close_reason,
+        };
+
+        match functions::issue::bulk_update_issues(
+            github_client,
+            &repo_id,
+            &issue_numbers,
+            &operations,
+            concurrency,
+        )
+        .await
+        {
+            Ok(results) => {
+                let failed = results.iter().filter(|r| !r.is_success()).count();
+                let mut lines = vec![format!(
+                    "Updated {} of {} issue(s)",
+                    results.len() - failed,
+                    results.len()
+                )];
+                for result in &results {
+                    if result.is_success() {
+                        lines.push(format!("- #{}: ok", result.issue_number));
+                    } else {
+                        lines.push(format!(
+                            "- #{}: {}",
+                            result.issue_number,
+                            result.failures.join("; ")
+                        ));
+                    }
+                }
+
+                Ok(CallToolResult {
+                    content: vec![Content::text(lines.join("\n"))],
+                    is_error: Some(failed > 0),
+                })
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to update issues: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn link_issues(
+        github_client: &GitHubClient,
+        repository_url: String,
+        issue_number: IssueNumber,
+        target_issue_number: IssueNumber,
+        target_repository_url: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+        let target_repo_id = match target_repository_url {
+            Some(url) => RepositoryId::parse_url(&RepositoryUrl(url)).map_err(|e| {
+                McpError::invalid_request(format!("Invalid target repository ID: {}", e), None)
+            })?,
+            None => repo_id.clone(),
+        };
+
+        let source = IssueId::new(repo_id, issue_number.value());
+        let target = IssueId::new(target_repo_id, target_issue_number.value());
+
+        match functions::issue::link_issues(github_client, &source, &target).await {
+            Ok(result) => {
+                let state = result.state();
+                let mut lines = vec![format!(
+                    "Link between {} and {}: {}",
+                    result.source, result.target, state
+                )];
+                if let Some(comment_number) = result.source_comment {
+                    lines.push(format!(
+                        "Added comment #{} on {}",
+                        comment_number, result.source
+                    ));
+                }
+                if let Some(comment_number) = result.target_comment {
+                    lines.push(format!(
+                        "Added comment #{} on {}",
+                        comment_number, result.target
+                    ));
+                }
+                lines.push(format!(
+                    "Cross-reference on target timeline: {}",
+                    if result.target_cross_referenced {
+                        "found"
+                    } else {
+                        "not found"
+                    }
+                ));
+                lines.push(format!(
+                    "Cross-reference on source timeline: {}",
+                    if result.source_cross_referenced {
+                        "found"
+                    } else {
+                        "not found"
+                    }
+                ));
+                lines.extend(result.failures.iter().cloned());
+
+                Ok(CallToolResult {
+                    content: vec![Content::text(lines.join("\n"))],
+                    is_error: Some(state == IssueLinkState::Failed),
+                })
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to link issues: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
     pub async fn add_milestone_to_issue(
         github_client: &GitHubClient,
         repository_url: String,
         issue_number: IssueNumber,
-        milestone_number: u64,
+        milestone_number: Option<u64>,
+        milestone_title: Option<String>,
     ) -> Result<CallToolResult, McpError> {
         let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
             McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
         })?;
         let issue_num = issue_number;
-        let milestone = MilestoneNumber::new(milestone_number);
+        let milestone = match functions::repository::resolve_milestone(
+            github_client,
+            &repo_id,
+            milestone_number.map(MilestoneNumber::new),
+            milestone_title.as_deref(),
+        )
+        .await
+        {
+            Ok(milestone) => milestone,
+            Err(e) => {
+                return Ok(CallToolResult {
+                    content: vec![Content::text(format!("Failed to find milestone: {}", e))],
+                    is_error: Some(true),
+                });
+            }
+        };
 
         match functions::issue::set_milestone(github_client, &repo_id, issue_num, milestone).await {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text("Milestone added successfully".to_string())],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Milestone added successfully".to_string(),
+                &json!({"issue_number": issue_num, "milestone_number": milestone_number}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to add milestone: {}", e))],
                 is_error: Some(true),
@@ -421,10 +1112,10 @@ impl IssueTools {
         let issue_num = issue_number;
 
         match functions::issue::remove_milestone(github_client, &repo_id, issue_num).await {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text("Milestone removed successfully".to_string())],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Milestone removed successfully".to_string(),
+                &json!({"issue_number": issue_num, "milestone_number": null}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to remove milestone: {}", e))],
                 is_error: Some(true),
@@ -432,3 +1123,87 @@ impl IssueTools {
         }
     }
 }
+
+/// Render an issue with its comments for the text summary of a read tool
+fn format_issue_details(details: &IssueDetails) -> String {
+    let issue = &details.issue;
+    let mut lines = vec![
+        format!(
+            "Issue #{} [{}] {}",
+            issue.issue_id.number, issue.state, issue.title
+        ),
+        format!("URL: {}", issue.issue_id.url()),
+        format!("Author: {}", issue.author),
+        format!(
+            "Created: {}, updated: {}",
+            issue.created_at, issue.updated_at
+        ),
+    ];
+    if !issue.assignees.is_empty() {
+        lines.push(format!("Assignees: {}", issue.assignees.join(", ")));
+    }
+    if !issue.labels.is_empty() {
+        lines.push(format!("Labels: {}", issue.labels.join(", ")));
+    }
+    if let Some(milestone_number) = issue.milestone_number {
+        lines.push(format!("Milestone: {}", milestone_number));
+    }
+    if issue.locked {
+        lines.push("Locked: yes".to_string());
+    }
+    lines.push(String::new());
+    lines.push(
+        issue
+            .body
+            .clone()
+            .filter(|body| !body.trim().is_empty())
+            .unwrap_or_else(|| "(no description)".to_string()),
+    );
+    push_comments(
+        &mut lines,
+        issue.comments.iter().map(|comment| {
+            (
+                comment.comment_number.value(),
+                comment.author.as_ref(),
+                comment.created_at,
+                comment.body.as_str(),
+            )
+        }),
+        details.has_more_comments,
+        "list_issue_comments",
+    );
+    lines.join("\n")
+}
+
+/// Append the comments of an issue or pull request to the text summary
+pub(crate) fn push_comments<'a>(
+    lines: &mut Vec<String>,
+    comments: impl ExactSizeIterator<Item = (u64, Option<&'a User>, DateTime<Utc>, &'a str)>,
+    has_more_comments: bool,
+    list_tool: &str,
+) {
+    let count = comments.len();
+    if count == 0 && !has_more_comments {
+        return;
+    }
+    lines.push(String::new());
+    if has_more_comments {
+        lines.push(format!(
+            "Comments (first {}; use {} for the rest):",
+            count, list_tool
+        ));
+    } else {
+        lines.push(format!("Comments ({}):", count));
+    }
+    for (id, author, created_at, body) in comments {
+        lines.push(format!(
+            "--- Comment {} by {} at {}",
+            id,
+            author
+                .map(|author| author.to_string())
+                .unwrap_or_else(|| "unknown".to_string()),
+            created_at
+        ));
+        lines.push(body.to_string());
+    }
+}
@@ -1,20 +1,112 @@
 //! Tool definition modules for GitHub repository operations
 //!
 //! This module contains the separated tool definitions organized by functionality:
+//! - `actions`: GitHub Actions workflow run tools
+//! - `auth`: Token identity and permission tools
+//! - `check`: Check run and commit status tools
+//! - `comment`: Comment moderation tools
+//! - `deletion`: Deletion tools guarded by confirmation tokens
+//! - `discussion`: GitHub Discussions tools
 //! - `issue`: Issue management tools
+//! - `notification`: Notification inbox tools
 //! - `project`: Project management tools  
 //! - `pull_request`: Pull request management tools
+//! - `reaction`: Reaction management tools
+//! - `search`: Issue, pull request, repository and user search tools
+//! - `secret`: GitHub Actions secret and variable tools
 //!
 //! The GitEditTools implementation is now split across multiple files conceptually,
 //! but the actual tool implementations are consolidated in the main mod.rs file
 //! to satisfy the #[tool(tool_box)] macro requirements.
 
+pub mod actions;
+pub mod auth;
+pub mod check;
+pub mod comment;
+pub mod deletion;
+pub mod discussion;
 pub mod issue;
+pub mod notification;
 pub mod project;
 pub mod pull_request;
+pub mod reaction;
 pub mod repository;
+pub mod search;
+pub mod secret;
 
+pub use actions::ActionsTools;
+pub use auth::AuthTools;
+pub use check::CheckTools;
+pub use comment::CommentTools;
+pub use deletion::DeletionTools;
+pub use discussion::DiscussionTools;
 pub use issue::IssueTools;
+pub use notification::NotificationTools;
 pub use project::ProjectTools;
 pub use pull_request::PullRequestTools;
+pub use reaction::ReactionTools;
 pub use repository::RepositoryTools;
+pub use search::SearchTools;
+pub use secret::SecretTools;
+
+use crate::tools::functions::issue::{DEFAULT_COMMENT_LIMIT, MAX_COMMENT_LIMIT};
+
+use chrono::{DateTime, Utc};
+use rmcp::{Error as McpError, model::*};
+use serde::Serialize;
+
+/// Build the result of a successful tool call
+///
+/// The result holds two content items: the text summary for humans, followed
+/// by `data` serialized as JSON for agents to parse. The JSON mirrors the
+/// types in `crate::types` returned by the tool functions.
+pub(crate) fn success_result<T: Serialize>(text: impl Into<String>, data: &T) -> CallToolResult {
+    let mut content = vec![Content::text(text)];
+    match Content::json(data) {
+        Ok(json) => content.push(json),
+        Err(e) => tracing::warn!("Failed to serialize the structured tool result: {}", e),
+    }
+    CallToolResult {
+        content,
+        is_error: Some(false),
+    }
+}
+
+/// Parse an optional RFC 3339 `since` timestamp given as a tool parameter
+pub(crate) fn parse_since(since: Option<String>) -> Result<Option<DateTime<Utc>>, McpError> {
+    parse_timestamp("since", since)
+}
+
+/// Parse an optional RFC 3339 timestamp given as the tool parameter `parameter`
+pub(crate) fn parse_timestamp(
+    parameter: &str,
+    value: Option<String>,
+) -> Result<Option<DateTime<Utc>>, McpError> {
+    value
+        .map(|value| {
+            DateTime::parse_from_rfc3339(&value)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|e| {
+                    McpError::invalid_request(
+                        format!("Invalid {} timestamp '{}': {}", parameter, value, e),
+                        None,
+                    )
+                })
+        })
+        .transpose()
+}
+
+/// Parse the optional `comment_limit` tool parameter of the read tools
+pub(crate) fn parse_comment_limit(comment_limit: Option<u32>) -> Result<usize, McpError> {
+    match comment_limit {
+        None => Ok(DEFAULT_COMMENT_LIMIT),
+        Some(limit) if limit as usize <= MAX_COMMENT_LIMIT => Ok(limit as usize),
+        Some(limit) => Err(McpError::invalid_request(
+            format!(
+                "Invalid comment_limit {}: at most {} comments can be returned",
+                limit, MAX_COMMENT_LIMIT
+            ),
+            None,
+        )),
+    }
+}
@@ -0,0 +1,287 @@
+//! Notification inbox tool definitions
+//!
+//! This module contains MCP tool implementations for triaging the
+//! authenticated user's notifications and managing subscriptions to
+//! threads, issues and pull requests.
+
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::tools::tool_definition::parse_timestamp;
+use crate::types::issue::IssueNumber;
+use crate::types::notification::{IssueSubscription, NotificationFilter, NotificationThreadId};
+use crate::types::pagination::Pagination;
+use crate::types::repository::{RepositoryId, RepositoryUrl};
+
+use crate::tools::tool_definition::success_result;
+use rmcp::{Error as McpError, model::*};
+use serde_json::json;
+
+/// Notification inbox tools implementation
+pub struct NotificationTools;
+
+impl NotificationTools {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_notifications(
+        github_client: &GitHubClient,
+        repository_url: Option<String>,
+        all: Option<bool>,
+        participating: Option<bool>,
+        since: Option<String>,
+        before: Option<String>,
+        page: Option<u32>,
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = repository_url.map(parse_repository_url).transpose()?;
+        let filter = NotificationFilter {
+            all: all.unwrap_or(false),
+            participating: participating.unwrap_or(false),
+            since: parse_timestamp("since", since)?,
+            before: parse_timestamp("before", before)?,
+        };
+
+        match functions::notification::list_notifications(
+            github_client,
+            repo_id.as_ref(),
+            &filter,
+            Pagination::new(page, per_page),
+        )
+        .await
+        {
+            Ok(notifications) => {
+                let mut lines = vec![format!(
+                    "Notifications (page {}, {} notification(s)):",
+                    notifications.page,
+                    notifications.items.len()
+                )];
+                lines.extend(notifications.items.iter().map(|notification| {
+                    format!(
+                        "- {} [{}{}] {} {}: {} ({}) {}",
+                        notification.id,
+                        notification.reason,
+                        if notification.unread { ", unread" } else { "" },
+                        notification.repository.full_name,
+                        notification.subject.subject_type,
+                        notification.subject.title,
+                        notification.updated_at.to_rfc3339(),
+                        notification.html_url()
+                    )
+                }));
+                if notifications.has_next_page {
+                    lines.push(format!(
+                        "More notifications available on page {}",
+                        notifications.page + 1
+                    ));
+                }
+                Ok(success_result(lines.join("\n"), &notifications))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to list notifications: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn mark_notification_read(
+        github_client: &GitHubClient,
+        thread_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        let thread_id = parse_thread_id(thread_id)?;
+
+        match functions::notification::mark_notification_read(github_client, &thread_id).await {
+            Ok(()) => Ok(success_result(
+                format!("Marked notification thread {} as read", thread_id),
+                &json!({"thread_id": thread_id, "read": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to mark notification as read: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn mark_thread_done(
+        github_client: &GitHubClient,
+        thread_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        let thread_id = parse_thread_id(thread_id)?;
+
+        match functions::notification::mark_thread_done(github_client, &thread_id).await {
+            Ok(()) => Ok(success_result(
+                format!("Marked notification thread {} as done", thread_id),
+                &json!({"thread_id": thread_id, "done": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to mark thread as done: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn subscribe_thread(
+        github_client: &GitHubClient,
+        thread_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        let thread_id = parse_thread_id(thread_id)?;
+
+        match functions::notification::subscribe_thread(github_client, &thread_id).await {
+            Ok(subscription) => Ok(success_result(
+                format!(
+                    "Subscribed to notification thread {} (subscribed: {}, ignored: {})",
+                    thread_id, subscription.subscribed, subscription.ignored
+                ),
+                &subscription,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to subscribe to thread: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn unsubscribe_thread(
+        github_client: &GitHubClient,
+        thread_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        let thread_id = parse_thread_id(thread_id)?;
+
+        match functions::notification::unsubscribe_thread(github_client, &thread_id).await {
+            Ok(()) => Ok(success_result(
+                format!("Unsubscribed from notification thread {}", thread_id),
+                &json!({"thread_id": thread_id, "subscribed": false}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to unsubscribe from thread: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn get_issue_subscription(
+        github_client: &GitHubClient,
+        repository_url: String,
+        issue_number: u32,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::notification::get_issue_subscription(
+            github_client,
+            &repo_id,
+            IssueNumber::new(issue_number),
+        )
+        .await
+        {
+            Ok(subscription) => Ok(success_result(
+                format!(
+                    "#{}: {}",
+                    issue_number,
+                    describe_subscription(&subscription)
+                ),
+                &subscription,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to get subscription: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn subscribe_to_issue(
+        github_client: &GitHubClient,
+        repository_url: String,
+        issue_number: u32,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::notification::subscribe_to_issue(
+            github_client,
+            &repo_id,
+            IssueNumber::new(issue_number),
+        )
+        .await
+        {
+            Ok(subscription) => Ok(success_result(
+                format!(
+                    "Subscribed to #{}: {}",
+                    issue_number,
+                    describe_subscription(&subscription)
+                ),
+                &subscription,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to subscribe: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn unsubscribe_from_issue(
+        github_client: &GitHubClient,
+        repository_url: String,
+        issue_number: u32,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::notification::unsubscribe_from_issue(
+            github_client,
+            &repo_id,
+            IssueNumber::new(issue_number),
+        )
+        .await
+        {
+            Ok(subscription) => Ok(success_result(
+                format!(
+                    "Unsubscribed from #{}: {}",
+                    issue_number,
+                    describe_subscription(&subscription)
+                ),
+                &subscription,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to unsubscribe: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+}
+
+fn describe_subscription(subscription: &IssueSubscription) -> String {
+    let state = match subscription.state {
+        Some(state) => state.to_string(),
+        None => "unknown".to_string(),
+    };
+    if subscription.can_subscribe {
+        format!("subscription state {}", state)
+    } else {
+        format!("subscription state {} (you cannot change it)", state)
+    }
+}
+
+fn parse_repository_url(repository_url: String) -> Result<RepositoryId, McpError> {
+    RepositoryId::parse_url(&RepositoryUrl(repository_url))
+        .map_err(|e| McpError::invalid_request(format!("Invalid repository URL: {}", e), None))
+}
+
+fn parse_thread_id(thread_id: String) -> Result<NotificationThreadId, McpError> {
+    if thread_id.is_empty() || !thread_id.chars().all(|c| c.is_ascii_digit()) {
+        return Err(McpError::invalid_request(
+            format!("Invalid notification thread ID '{}'", thread_id),
+            None,
+        ));
+    }
+    Ok(NotificationThreadId::new(thread_id))
+}
@@ -2,35 +2,973 @@
 //!
 //! This module contains MCP tool implementations for managing GitHub projects,
 //! including project item field updates and project management operations.
-//!
-//! Note: This module does not contain any delete operations for safety reasons.
 
-use crate::github::GitHubClient;
-use crate::tools::functions;
-use crate::types::issue::IssueNumber;
-use crate::types::project::{
-    ProjectCustomFieldType, ProjectFieldId, ProjectFieldValue, ProjectItemId, ProjectNodeId,
-};
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::issue::IssueNumber;
+use crate::types::project::{
+    DraftIssueId, ProjectCustomFieldType, ProjectField, ProjectFieldId, ProjectFieldValue,
+    ProjectItemFilter, ProjectItemId, ProjectNodeId,
+};
+use crate::types::repository::{Owner, RepositoryId, RepositoryUrl};
+
+use crate::tools::tool_definition::success_result;
+use rmcp::{Error as McpError, model::*};
+use serde_json::json;
+use std::str::FromStr;
+
+/// Project management tools implementation
+pub struct ProjectTools;
+
+impl ProjectTools {
+    pub async fn create_project(
+        github_client: &GitHubClient,
+        owner: String,
+        title: String,
+        project_type: String,
+    ) -> Result<CallToolResult, McpError> {
+        use crate::types::project::ProjectType;
+
+        let project_type_enum = match ProjectType::from_str(&project_type) {
+            Ok(project_type) => project_type,
+            Err(_) => {
+                return Ok(CallToolResult {
+                    content: vec![Content::text(format!(
+                        "Unsupported project type '{}'. Supported types: user, organization",
+                        project_type
+                    ))],
+                    is_error: Some(true),
+                });
+            }
+        };
+
+        match functions::project::create_project(
+            github_client,
+            &Owner(owner),
+            &title,
+            project_type_enum,
+        )
+        .await
+        {
+            Ok(project) => Ok(success_result(
+                format!(
+                    "Project created successfully. Project number: {}, project node ID: {}, URL: {}",
+                    project.project_id.number,
+                    project.project_node_id,
+                    project.project_id.url()
+                ),
+                &project,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to create project: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn get_project(
+        github_client: &GitHubClient,
+        project_owner: String,
+        project_number: u64,
+        project_type: String,
+    ) -> Result<CallToolResult, McpError> {
+        use crate::types::project::{ProjectId, ProjectNumber, ProjectType};
+
+        let project_type_enum = match ProjectType::from_str(&project_type) {
+            Ok(project_type) => project_type,
+            Err(_) => {
+                return Ok(CallToolResult {
+                    content: vec![Content::text(format!(
+                        "Unsupported project type '{}'. Supported types: user, organization",
+                        project_type
+                    ))],
+                    is_error: Some(true),
+                });
+            }
+        };
+
+        let project_id = ProjectId::new(
+            Owner(project_owner),
+            ProjectNumber(project_number),
+            project_type_enum,
+        );
+
+        match functions::project::get_project(github_client, &project_id).await {
+            Ok(details) => {
+                let project = &details.project;
+                let mut lines = vec![
+                    format!("Project: {}", project.title),
+                    format!("Project number: {}", project.project_id.number),
+                    format!("Project node ID: {}", project.project_node_id),
+                    format!("URL: {}", project.project_id.url()),
+                    format!(
+                        "Description: {}",
+                        project.description.as_deref().unwrap_or("(none)")
+                    ),
+                    format!("Visibility: {}", details.visibility),
+                    format!("State: {}", details.state),
+                    format!("Items: {}", details.item_count),
+                    format!("Fields ({}):", details.fields.len()),
+                ];
+                for field in &details.fields {
+                    lines.push(format!(
+                        "- {} [{}] (field ID: {})",
+                        field.name, field.data_type, field.field_id
+                    ));
+                    if !field.options.is_empty() {
+                        let option_names: Vec<&str> = field
+                            .options
+                            .iter()
+                            .map(|option| option.name.as_str())
+                            .collect();
+                        lines.push(format!("    options: {}", option_names.join(", ")));
+                    }
+                }
+                Ok(success_result(lines.join("\n"), &details))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to get project: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn copy_project(
+        github_client: &GitHubClient,
+        source_project_node_id: String,
+        owner: String,
+        project_type: String,
+        title: String,
+        include_draft_issues: Option<bool>,
+    ) -> Result<CallToolResult, McpError> {
+        use crate::types::project::ProjectType;
+
+        let project_type_enum = match ProjectType::from_str(&project_type) {
+            Ok(project_type) => project_type,
+            Err(_) => {
+                return Ok(CallToolResult {
+                    content: vec![Content::text(format!(
+                        "Unsupported project type '{}'. Supported types: user, organization",
+                        project_type
+                    ))],
+                    is_error: Some(true),
+                });
+            }
+        };
+
+        match functions::project::copy_project(
+            github_client,
+            &ProjectNodeId::new(source_project_node_id),
+            &Owner(owner),
+            project_type_enum,
+            &title,
+            include_draft_issues.unwrap_or(false),
+        )
+        .await
+        {
+            Ok(project) => Ok(success_result(
+                format!(
+                    "Project copied successfully. Project number: {}, project node ID: {}, URL: {}",
+                    project.project_id.number,
+                    project.project_node_id,
+                    project.project_id.url()
+                ),
+                &project,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to copy project: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn update_project(
+        github_client: &GitHubClient,
+        project_node_id: String,
+        title: Option<String>,
+        short_description: Option<String>,
+        readme: Option<String>,
+        visibility: Option<String>,
+        state: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        use crate::types::project::{ProjectState, ProjectUpdate, ProjectVisibility};
+
+        let visibility = match visibility
+            .map(|v| ProjectVisibility::from_str(&v).map_err(|_| v))
+            .transpose()
+        {
+            Ok(visibility) => visibility,
+            Err(v) => {
+                return Ok(CallToolResult {
+                    content: vec![Content::text(format!(
+                        "Unsupported visibility '{}'. Supported values: public, private",
+                        v
+                    ))],
+                    is_error: Some(true),
+                });
+            }
+        };
+
+        let state = match state
+            .map(|s| ProjectState::from_str(&s).map_err(|_| s))
+            .transpose()
+        {
+            Ok(state) => state,
+            Err(s) => {
+                return Ok(CallToolResult {
+                    content: vec![Content::text(format!(
+                        "Unsupported state '{}'. Supported values: open, closed",
+                        s
+                    ))],
+                    is_error: Some(true),
+                });
+            }
+        };
+
+        let update = ProjectUpdate {
+            title,
+            short_description,
+            readme,
+            visibility,
+            state,
+        };
+
+        if update.is_empty() {
+            return Ok(CallToolResult {
+                content: vec![Content::text(
+                    "No project settings to update. Specify at least one of title, short_description, readme, visibility or state".to_string(),
+                )],
+                is_error: Some(true),
+            });
+        }
+
+        match functions::project::update_project(
+            github_client,
+            &ProjectNodeId::new(project_node_id),
+            &update,
+        )
+        .await
+        {
+            Ok(project) => Ok(success_result(
+                format!(
+                    "Project updated successfully. Project number: {}, title: {}, URL: {}",
+                    project.project_id.number,
+                    project.title,
+                    project.project_id.url()
+                ),
+                &project,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to update project: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn delete_project(
+        github_client: &GitHubClient,
+        project_node_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        match functions::project::delete_project(
+            github_client,
+            &ProjectNodeId::new(project_node_id.clone()),
+        )
+        .await
+        {
+            Ok(_) => Ok(success_result(
+                format!("Project {} deleted successfully", project_node_id),
+                &json!({"project_node_id": project_node_id, "deleted": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to delete project: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn list_project_fields(
+        github_client: &GitHubClient,
+        project_node_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        match functions::project::list_project_fields(
+            github_client,
+            &ProjectNodeId::new(project_node_id),
+        )
+        .await
+        {
+            Ok(fields) => {
+                let mut lines = vec![format!("Found {} project fields", fields.len())];
+                for field in &fields {
+                    lines.push(format!(
+                        "- {} [{}] (field ID: {})",
+                        field.name, field.data_type, field.field_id
+                    ));
+                    for option in &field.options {
+                        lines.push(format!(
+                            "    option: {} (option ID: {})",
+                            option.name, option.id
+                        ));
+                    }
+                    if let Some(configuration) = &field.iteration_configuration {
+                        lines.push(format!(
+                            "    duration: {} days, start day: {}",
+                            configuration.duration, configuration.start_day
+                        ));
+                        for iteration in &configuration.iterations {
+                            lines.push(format!(
+                                "    iteration: {} starting {} for {} days (iteration ID: {})",
+                                iteration.title,
+                                iteration.start_date,
+                                iteration.duration,
+                                iteration.id
+                            ));
+                        }
+                        for iteration in &configuration.completed_iterations {
+                            lines.push(format!(
+                                "    completed iteration: {} starting {} for {} days (iteration ID: {})",
+                                iteration.title,
+                                iteration.start_date,
+                                iteration.duration,
+                                iteration.id
+                            ));
+                        }
+                    }
+                }
+                Ok(success_result(lines.join("\n"), &fields))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to list project fields: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn link_repository_to_project(
+        github_client: &GitHubClient,
+        project_node_id: String,
+        repository_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repository_id =
+            RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+                McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+            })?;
+
+        match functions::project::link_repository_to_project(
+            github_client,
+            &ProjectNodeId::new(project_node_id.clone()),
+            &repository_id,
+        )
+        .await
+        {
+            Ok(_) => Ok(success_result(
+                format!(
+                    "Repository {} linked to project {} successfully",
+                    repository_id, project_node_id
+                ),
+                &json!({"project_node_id": project_node_id, "repository": repository_id, "linked": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to link repository: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn unlink_repository_from_project(
+        github_client: &GitHubClient,
+        project_node_id: String,
+        repository_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repository_id =
+            RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+                McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+            })?;
+
+        match functions::project::unlink_repository_from_project(
+            github_client,
+            &ProjectNodeId::new(project_node_id.clone()),
+            &repository_id,
+        )
+        .await
+        {
+            Ok(_) => Ok(success_result(
+                format!(
+                    "Repository {} unlinked from project {} successfully",
+                    repository_id, project_node_id
+                ),
+                &json!({"project_node_id": project_node_id, "repository": repository_id, "linked": false}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to unlink repository: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn link_team_to_project(
+        github_client: &GitHubClient,
+        project_node_id: String,
+        organization: String,
+        team_slug: String,
+    ) -> Result<CallToolResult, McpError> {
+        match functions::project::link_team_to_project(
+            github_client,
+            &ProjectNodeId::new(project_node_id.clone()),
+            &Owner::new(organization.clone()),
+            &team_slug,
+        )
+        .await
+        {
+            Ok(_) => Ok(success_result(
+                format!(
+                    "Team {}/{} linked to project {} successfully",
+                    organization, team_slug, project_node_id
+                ),
+                &json!({"project_node_id": project_node_id, "organization": organization, "team_slug": team_slug, "linked": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to link team: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn unlink_team_from_project(
+        github_client: &GitHubClient,
+        project_node_id: String,
+        organization: String,
+        team_slug: String,
+    ) -> Result<CallToolResult, McpError> {
+        match functions::project::unlink_team_from_project(
+            github_client,
+            &ProjectNodeId::new(project_node_id.clone()),
+            &Owner::new(organization.clone()),
+            &team_slug,
+        )
+        .await
+        {
+            Ok(_) => Ok(success_result(
+                format!(
+                    "Team {}/{} unlinked from project {} successfully",
+                    organization, team_slug, project_node_id
+                ),
+                &json!({"project_node_id": project_node_id, "organization": organization, "team_slug": team_slug, "linked": false}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to unlink team: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn create_project_field(
+        github_client: &GitHubClient,
+        project_node_id: String,
+        name: String,
+        data_type: String,
+        options: Option<Vec<String>>,
+    ) -> Result<CallToolResult, McpError> {
+        let data_type_enum = match ProjectCustomFieldType::from_str(&data_type) {
+            Ok(dt) => dt,
+            Err(_) => {
+                return Ok(CallToolResult {
+                    content: vec![Content::text(format!(
+                        "Unsupported data type '{}'. Supported types: text, number, date, single_select",
+                        data_type
+                    ))],
+                    is_error: Some(true),
+                });
+            }
+        };
+
+        match functions::project::create_project_field(
+            github_client,
+            &ProjectNodeId::new(project_node_id),
+            &name,
+            &data_type_enum,
+            &options.unwrap_or_default(),
+        )
+        .await
+        {
+            Ok(field) => Ok(success_result(
+                format_project_field_change("Created", &field),
+                &field,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to create project field: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn update_project_field(
+        github_client: &GitHubClient,
+        project_field_id: String,
+        name: Option<String>,
+        options: Option<Vec<String>>,
+    ) -> Result<CallToolResult, McpError> {
+        match functions::project::update_project_field(
+            github_client,
+            &ProjectFieldId::new(project_field_id),
+            name.as_deref(),
+            options.as_deref(),
+        )
+        .await
+        {
+            Ok(field) => Ok(success_result(
+                format_project_field_change("Updated", &field),
+                &field,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to update project field: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn delete_project_field(
+        github_client: &GitHubClient,
+        project_field_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        match functions::project::delete_project_field(
+            github_client,
+            &ProjectFieldId::new(project_field_id.clone()),
+        )
+        .await
+        {
+            Ok(_) => Ok(success_result(
+                format!("Project field {} deleted successfully", project_field_id),
+                &json!({"project_field_id": project_field_id, "deleted": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to delete project field: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn resolve_single_select_option(
+        github_client: &GitHubClient,
+        project_node_id: String,
+        project_field_id: String,
+        option_name: String,
+    ) -> Result<CallToolResult, McpError> {
+        match functions::project::resolve_single_select_option(
+            github_client,
+            &ProjectNodeId::new(project_node_id),
+            &ProjectFieldId::new(project_field_id),
+            &option_name,
+        )
+        .await
+        {
+            Ok(option_id) => Ok(success_result(
+                format!("Option '{}' has option ID: {}", option_name, option_id),
+                &option_id,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to resolve single select option: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn remove_item_from_project(
+        github_client: &GitHubClient,
+        project_node_id: String,
+        project_item_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        match functions::project::remove_item_from_project(
+            github_client,
+            &ProjectNodeId::new(project_node_id),
+            &ProjectItemId::new(project_item_id.clone()),
+        )
+        .await
+        {
+            Ok(_) => Ok(success_result(
+                format!(
+                    "Project item {} removed from project successfully",
+                    project_item_id
+                ),
+                &json!({"project_item_id": project_item_id, "removed": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to remove item from project: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
 
-use rmcp::{Error as McpError, model::*};
-use std::str::FromStr;
+    pub async fn archive_project_item(
+        github_client: &GitHubClient,
+        project_node_id: String,
+        project_item_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        match functions::project::archive_project_item(
+            github_client,
+            &ProjectNodeId::new(project_node_id),
+            &ProjectItemId::new(project_item_id.clone()),
+        )
+        .await
+        {
+            Ok(_) => Ok(success_result(
+                format!("Project item {} archived successfully", project_item_id),
+                &json!({"project_item_id": project_item_id, "archived": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to archive project item: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
 
-/// Project management tools implementation
-pub struct ProjectTools;
+    pub async fn unarchive_project_item(
+        github_client: &GitHubClient,
+        project_node_id: String,
+        project_item_id: String,
+    ) -> Result<CallToolResult, McpError> {
+        match functions::project::unarchive_project_item(
+            github_client,
+            &ProjectNodeId::new(project_node_id),
+            &ProjectItemId::new(project_item_id.clone()),
+        )
+        .await
+        {
+            Ok(_) => Ok(success_result(
+                format!("Project item {} unarchived successfully", project_item_id),
+                &json!({"project_item_id": project_item_id, "archived": false}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to unarchive project item: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
 
-impl ProjectTools {
-    pub async fn update_project_item_field(
+    pub async fn update_project_item_position(
+        github_client: &GitHubClient,
+        project_node_id: String,
+        project_item_id: String,
+        after_item_id: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let after_item_id = after_item_id.map(ProjectItemId::new);
+
+        match functions::project::update_project_item_position(
+            github_client,
+            &ProjectNodeId::new(project_node_id),
+            &ProjectItemId::new(project_item_id.clone()),
+            after_item_id.as_ref(),
+        )
+        .await
+        {
+            Ok(_) => {
+                let position = match &after_item_id {
+                    Some(after_item_id) => format!("after {}", after_item_id),
+                    None => "to the top".to_string(),
+                };
+                Ok(success_result(
+                    format!(
+                        "Project item {} moved {} successfully",
+                        project_item_id, position
+                    ),
+                    &json!({"project_item_id": project_item_id, "after_item_id": after_item_id}),
+                ))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to update project item position: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn add_draft_issue_to_project(
         github_client: &GitHubClient,
         project_node_id: String,
+        title: String,
+        body: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        match functions::project::add_draft_issue_to_project(
+            github_client,
+            &ProjectNodeId::new(project_node_id),
+            &title,
+            body.as_deref(),
+        )
+        .await
+        {
+            Ok(draft_issue) => Ok(success_result(
+                format!(
+                    "Draft issue added to project successfully. Project item ID: {}, draft issue ID: {}",
+                    draft_issue.project_item_id, draft_issue.draft_issue_id
+                ),
+                &draft_issue,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to add draft issue to project: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn update_draft_issue(
+        github_client: &GitHubClient,
+        draft_issue_id: String,
+        title: Option<String>,
+        body: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        if title.is_none() && body.is_none() {
+            return Ok(CallToolResult {
+                content: vec![Content::text(
+                    "No draft issue changes to update. Specify title and/or body".to_string(),
+                )],
+                is_error: Some(true),
+            });
+        }
+
+        match functions::project::update_draft_issue(
+            github_client,
+            &DraftIssueId::new(draft_issue_id.clone()),
+            title.as_deref(),
+            body.as_deref(),
+        )
+        .await
+        {
+            Ok(_) => Ok(success_result(
+                format!("Draft issue {} updated successfully", draft_issue_id),
+                &json!({"draft_issue_id": draft_issue_id, "updated": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to update draft issue: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn convert_draft_issue_to_issue(
+        github_client: &GitHubClient,
         project_item_id: String,
+        repository_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+
+        match functions::project::convert_draft_issue_to_issue(
+            github_client,
+            &ProjectItemId::new(project_item_id),
+            &repo_id,
+        )
+        .await
+        {
+            Ok(issue_id) => Ok(success_result(
+                format!(
+                    "Draft issue converted to issue #{}: {}",
+                    issue_id.number,
+                    issue_id.url()
+                ),
+                &issue_id,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to convert draft issue to issue: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn bulk_update_project_field(
+        github_client: &GitHubClient,
+        project_node_id: String,
+        project_field_id: String,
+        field_type: String,
+        value: String,
+        item_ids: Option<Vec<String>>,
+        filter_field_name: Option<String>,
+        filter_value: Option<String>,
+        concurrency: Option<usize>,
+    ) -> Result<CallToolResult, McpError> {
+        let filter = match (item_ids, filter_field_name, filter_value) {
+            (Some(item_ids), None, None) => {
+                ProjectItemFilter::ItemIds(item_ids.into_iter().map(ProjectItemId::new).collect())
+            }
+            (None, Some(field_name), Some(value)) => {
+                ProjectItemFilter::FieldEquals { field_name, value }
+            }
+            _ => {
+                return Ok(CallToolResult {
+                    content: vec![Content::text(
+                        "Specify either item_ids, or both filter_field_name and filter_value"
+                            .to_string(),
+                    )],
+                    is_error: Some(true),
+                });
+            }
+        };
+
+        let field_type_enum = match ProjectCustomFieldType::from_str(&field_type) {
+            Ok(ft) => ft,
+            Err(_) => {
+                return Ok(CallToolResult {
+                    content: vec![Content::text(format!(
+                        "Unsupported field type '{}'. Supported types: text, number, date, single_select, multi_select",
+                        field_type
+                    ))],
+                    is_error: Some(true),
+                });
+            }
+        };
+
+        let parsed_value = match ProjectFieldValue::from_string_with_type(&field_type_enum, &value)
+        {
+            Ok(pv) => pv,
+            Err(e) => {
+                return Ok(CallToolResult {
+                    content: vec![Content::text(format!("Failed to parse field value: {}", e))],
+                    is_error: Some(true),
+                });
+            }
+        };
+
+        match functions::project::bulk_update_project_field(
+            github_client,
+            &ProjectNodeId::new(project_node_id),
+            &ProjectFieldId::new(project_field_id),
+            &parsed_value,
+            &filter,
+            concurrency,
+        )
+        .await
+        {
+            Ok(results) => {
+                let failed = results.iter().filter(|r| !r.is_success()).count();
+                let mut lines = vec![format!(
+                    "Updated {} of {} project item(s)",
+                    results.len() - failed,
+                    results.len()
+                )];
+                for result in &results {
+                    match &result.failure {
+                        None => lines.push(format!("- {}: ok", result.item_id)),
+                        Some(failure) => lines.push(format!("- {}: {}", result.item_id, failure)),
+                    }
+                }
+
+                Ok(CallToolResult {
+                    content: vec![Content::text(lines.join("\n"))],
+                    is_error: Some(failed > 0),
+                })
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to update project items: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn find_project_item_for_content(
+        github_client: &GitHubClient,
+        project_node_id: String,
+        content_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        match functions::project::find_project_item_for_content(
+            github_client,
+            &ProjectNodeId::new(project_node_id),
+            &content_url,
+        )
+        .await
+        {
+            Ok(project_item_id) => Ok(success_result(
+                format!("Project item ID for {}: {}", content_url, project_item_id),
+                &project_item_id,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to find project item: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn update_project_item_field(
+        github_client: &GitHubClient,
+        project_node_id: String,
+        project_item_id: Option<String>,
+        content_url: Option<String>,
         project_field_id: String,
         field_type: String,
         value: String,
     ) -> Result<CallToolResult, McpError> {
         let typed_project_node_id = ProjectNodeId::new(project_node_id.clone());
-        let typed_project_item_id = ProjectItemId::new(project_item_id.clone());
         let typed_project_field_id = ProjectFieldId::new(project_field_id.clone());
 
+        let typed_project_item_id = match (project_item_id, content_url) {
+            (Some(project_item_id), None) => ProjectItemId::new(project_item_id),
+            (None, Some(content_url)) => {
+                match functions::project::find_project_item_for_content(
+                    github_client,
+                    &typed_project_node_id,
+                    &content_url,
+                )
+                .await
+                {
+                    Ok(project_item_id) => project_item_id,
+                    Err(e) => {
+                        return Ok(CallToolResult {
+                            content: vec![Content::text(format!(
+                                "Failed to find project item: {}",
+                                e
+                            ))],
+                            is_error: Some(true),
+                        });
+                    }
+                }
+            }
+            _ => {
+                return Ok(CallToolResult {
+                    content: vec![Content::text(
+                        "Specify exactly one of project_item_id or content_url".to_string(),
+                    )],
+                    is_error: Some(true),
+                });
+            }
+        };
+
         let field_type_enum = match ProjectCustomFieldType::from_str(&field_type) {
             Ok(ft) => ft,
             Err(_) => {
@@ -64,12 +1002,10 @@ impl ProjectTools {
         )
         .await
         {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text(
-                    "Project item field updated successfully".to_string(),
-                )],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Project item field updated successfully".to_string(),
+                &json!({"project_item_id": typed_project_item_id, "project_field_id": typed_project_field_id, "updated": true}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!(
                     "Failed to update project item field: {}",
@@ -87,7 +1023,6 @@ impl ProjectTools {
         project_type: String,
     ) -> Result<CallToolResult, McpError> {
         use crate::types::project::{ProjectId, ProjectNumber, ProjectType};
-        use crate::types::repository::Owner;
 
         let project_type_enum = match project_type.as_str() {
             "user" => ProjectType::User,
@@ -110,13 +1045,10 @@ impl ProjectTools {
         );
 
         match functions::project::get_project_node_id(github_client, &project_id).await {
-            Ok(node_id) => Ok(CallToolResult {
-                content: vec![Content::text(format!(
-                    "Project node ID: {}",
-                    node_id.value()
-                ))],
-                is_error: Some(false),
-            }),
+            Ok(node_id) => Ok(success_result(
+                format!("Project node ID: {}", node_id.value()),
+                &node_id,
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!(
                     "Failed to get project node ID: {}",
@@ -147,12 +1079,10 @@ impl ProjectTools {
         )
         .await
         {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text(
-                    "Project item text field updated successfully".to_string(),
-                )],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Project item text field updated successfully".to_string(),
+                &json!({"project_item_id": typed_project_item_id, "project_field_id": typed_project_field_id, "text": text_value}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!(
                     "Failed to update project item text field: {}",
@@ -183,12 +1113,10 @@ impl ProjectTools {
         )
         .await
         {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text(
-                    "Project item number field updated successfully".to_string(),
-                )],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Project item number field updated successfully".to_string(),
+                &json!({"project_item_id": typed_project_item_id, "project_field_id": typed_project_field_id, "number": number_value}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!(
                     "Failed to update project item number field: {}",
@@ -232,12 +1160,10 @@ impl ProjectTools {
         )
         .await
         {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text(
-                    "Project item date field updated successfully".to_string(),
-                )],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Project item date field updated successfully".to_string(),
+                &json!({"project_item_id": typed_project_item_id, "project_field_id": typed_project_field_id, "date": date_value}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!(
                     "Failed to update project item date field: {}",
@@ -253,12 +1179,51 @@ impl ProjectTools {
         project_node_id: String,
         project_item_id: String,
         project_field_id: String,
-        option_id: String,
+        option_id: Option<String>,
+        option_name: Option<String>,
     ) -> Result<CallToolResult, McpError> {
         let typed_project_node_id = ProjectNodeId::new(project_node_id);
         let typed_project_item_id = ProjectItemId::new(project_item_id);
         let typed_project_field_id = ProjectFieldId::new(project_field_id);
 
+        let option_id = match (option_id, option_name) {
+            (Some(option_id), None) => option_id,
+            (None, Some(option_name)) => {
+                return match functions::project::update_project_item_single_select_field_by_name(
+                    github_client,
+                    &typed_project_node_id,
+                    &typed_project_item_id,
+                    &typed_project_field_id,
+                    &option_name,
+                )
+                .await
+                {
+                    Ok(option_id) => Ok(success_result(
+                        format!(
+                            "Project item single select field updated successfully to '{}' (option ID: {})",
+                            option_name, option_id
+                        ),
+                        &option_id,
+                    )),
+                    Err(e) => Ok(CallToolResult {
+                        content: vec![Content::text(format!(
+                            "Failed to update project item single select field: {}",
+                            e
+                        ))],
+                        is_error: Some(true),
+                    }),
+                };
+            }
+            _ => {
+                return Ok(CallToolResult {
+                    content: vec![Content::text(
+                        "Specify exactly one of option_id or option_name".to_string(),
+                    )],
+                    is_error: Some(true),
+                });
+            }
+        };
+
         match functions::project::update_project_item_single_select_field(
             github_client,
             &typed_project_node_id,
@@ -268,12 +1233,10 @@ impl ProjectTools {
         )
         .await
         {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text(
-                    "Project item single select field updated successfully".to_string(),
-                )],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Project item single select field updated successfully".to_string(),
+                &json!({"project_item_id": typed_project_item_id, "project_field_id": typed_project_field_id, "option_id": option_id}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!(
                     "Failed to update project item single select field: {}",
@@ -305,13 +1268,13 @@ impl ProjectTools {
         )
         .await
         {
-            Ok(project_item_id) => Ok(CallToolResult {
-                content: vec![Content::text(format!(
+            Ok(project_item_id) => Ok(success_result(
+                format!(
                     "Issue added to project successfully. Project item ID: {}",
                     project_item_id.value()
-                ))],
-                is_error: Some(false),
-            }),
+                ),
+                &project_item_id,
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!(
                     "Failed to add issue to project: {}",
@@ -349,13 +1312,13 @@ impl ProjectTools {
         )
         .await
         {
-            Ok(project_item_id) => Ok(CallToolResult {
-                content: vec![Content::text(format!(
+            Ok(project_item_id) => Ok(success_result(
+                format!(
                     "Pull request added to project successfully. Project item ID: {}",
                     project_item_id.value()
-                ))],
-                is_error: Some(false),
-            }),
+                ),
+                &project_item_id,
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!(
                     "Failed to add pull request to project: {}",
@@ -366,3 +1329,18 @@ impl ProjectTools {
         }
     }
 }
+
+/// Describe a created or updated field with its ID and option IDs
+fn format_project_field_change(action: &str, field: &ProjectField) -> String {
+    let mut lines = vec![format!(
+        "{} project field {} [{}] (field ID: {})",
+        action, field.name, field.data_type, field.field_id
+    )];
+    for option in &field.options {
+        lines.push(format!(
+            "    option: {} (option ID: {})",
+            option.name, option.id
+        ));
+    }
+    lines.join("\n")
+}
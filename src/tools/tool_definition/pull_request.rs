@@ -7,11 +7,22 @@
 
 use crate::github::GitHubClient;
 use crate::tools::functions;
+use crate::tools::functions::issue::MAX_DETAILS_URLS;
+use crate::tools::tool_definition::comment::check_comment_permission;
+use crate::tools::tool_definition::issue::push_comments;
+use crate::tools::tool_definition::{parse_comment_limit, parse_since};
+use crate::types::comment::{CommentAction, CommentTarget};
 use crate::types::label::Label;
-use crate::types::pull_request::{Branch, PullRequestCommentNumber, PullRequestNumber};
+use crate::types::pagination::Pagination;
+use crate::types::pull_request::{
+    Branch, PullRequestCommentNumber, PullRequestDetails, PullRequestId, PullRequestNumber,
+    PullRequestUrl,
+};
 use crate::types::repository::{MilestoneNumber, RepositoryId, RepositoryUrl};
 
+use crate::tools::tool_definition::success_result;
 use rmcp::{Error as McpError, model::*};
+use serde_json::json;
 
 /// Pull request management tools implementation
 pub struct PullRequestTools;
@@ -43,13 +54,13 @@ impl PullRequestTools {
         )
         .await
         {
-            Ok(pr) => Ok(CallToolResult {
-                content: vec![Content::text(format!(
+            Ok(pr) => Ok(success_result(
+                format!(
                     "Pull request created successfully: #{}\nTitle: {}\nStatus: {:?}",
                     pr.pull_request_id.number, pr.title, pr.state
-                ))],
-                is_error: Some(false),
-            }),
+                ),
+                &pr,
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!(
                     "Failed to create pull request: {}",
@@ -72,13 +83,10 @@ impl PullRequestTools {
         let pr_num = PullRequestNumber::new(pr_number as u32);
 
         match functions::pull_request::add_comment(github_client, &repo_id, pr_num, &body).await {
-            Ok(comment_number) => Ok(CallToolResult {
-                content: vec![Content::text(format!(
-                    "Comment added successfully: #{}",
-                    comment_number
-                ))],
-                is_error: Some(false),
-            }),
+            Ok(comment_number) => Ok(success_result(
+                format!("Comment added successfully: #{}", comment_number),
+                &comment_number,
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to add comment: {}", e))],
                 is_error: Some(true),
@@ -86,6 +94,70 @@ impl PullRequestTools {
         }
     }
 
+    pub async fn list_pull_request_comments(
+        github_client: &GitHubClient,
+        repository_url: String,
+        pr_number: u64,
+        since: Option<String>,
+        author: Option<String>,
+        page: Option<u32>,
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+        let pr_number = PullRequestNumber::new(pr_number as u32);
+        let since = parse_since(since)?;
+        let pagination = Pagination::new(page, per_page);
+
+        match functions::pull_request::list_comments(
+            github_client,
+            &repo_id,
+            pr_number,
+            since,
+            author.as_deref(),
+            pagination,
+        )
+        .await
+        {
+            Ok(comments) => {
+                let mut lines = vec![format!(
+                    "Comments on pull request #{} (page {}, {} comment(s)):",
+                    pr_number,
+                    comments.page,
+                    comments.items.len()
+                )];
+                for comment in &comments.items {
+                    lines.push(format!(
+                        "- #{} by {} at {}:\n{}",
+                        comment.comment_number,
+                        comment
+                            .author
+                            .as_ref()
+                            .map(|user| user.username.as_str())
+                            .unwrap_or("unknown"),
+                        comment.created_at.to_rfc3339(),
+                        comment.body
+                    ));
+                }
+                if comments.has_next_page {
+                    lines.push(format!(
+                        "More comments available on page {}",
+                        comments.page + 1
+                    ));
+                }
+                Ok(success_result(lines.join("\n"), &comments))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to list pull request comments: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
     pub async fn edit_comment_on_pull_request(
         github_client: &GitHubClient,
         repository_url: String,
@@ -99,6 +171,17 @@ impl PullRequestTools {
         let pr_num = PullRequestNumber::new(pr_number as u32);
         let comment_num = comment_number;
 
+        if let Some(denied) = check_comment_permission(
+            github_client,
+            &repo_id,
+            CommentTarget::PullRequestComment(comment_num),
+            CommentAction::Edit,
+        )
+        .await
+        {
+            return Ok(denied);
+        }
+
         match functions::pull_request::edit_comment(
             github_client,
             &repo_id,
@@ -108,10 +191,10 @@ impl PullRequestTools {
         )
         .await
         {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text("Comment edited successfully".to_string())],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Comment edited successfully".to_string(),
+                &json!({"pr_number": pr_number, "comment_number": comment_number}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to edit comment: {}", e))],
                 is_error: Some(true),
@@ -130,12 +213,10 @@ impl PullRequestTools {
         let pr_num = PullRequestNumber::new(pr_number as u32);
 
         match functions::pull_request::close_pull_request(github_client, &repo_id, pr_num).await {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text(
-                    "Pull request closed successfully".to_string(),
-                )],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Pull request closed successfully".to_string(),
+                &json!({"pr_number": pr_number, "state": "closed"}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!(
                     "Failed to close pull request: {}",
@@ -146,6 +227,162 @@ impl PullRequestTools {
         }
     }
 
+    /// Get a pull request with its body and up to `comment_limit`
+    /// discussion comments
+    pub async fn get_pull_request(
+        github_client: &GitHubClient,
+        repository_url: String,
+        pr_number: u64,
+        comment_limit: Option<u32>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+        let pr_num = PullRequestNumber::new(pr_number as u32);
+        let comment_limit = parse_comment_limit(comment_limit)?;
+
+        match functions::pull_request::get_pull_request_details(
+            github_client,
+            &repo_id,
+            pr_num,
+            comment_limit,
+        )
+        .await
+        {
+            Ok(details) => Ok(success_result(
+                format_pull_request_details(&details),
+                &details,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to get pull request: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Get several pull requests by URL, each with up to `comment_limit`
+    /// discussion comments
+    ///
+    /// Pull requests that cannot be read are reported as failures without
+    /// failing the others. At most `concurrency` pull requests are read at
+    /// once.
+    pub async fn get_pull_requests(
+        github_client: &GitHubClient,
+        pull_request_urls: Vec<String>,
+        comment_limit: Option<u32>,
+        concurrency: Option<usize>,
+    ) -> Result<CallToolResult, McpError> {
+        if pull_request_urls.is_empty() || pull_request_urls.len() > MAX_DETAILS_URLS {
+            return Err(McpError::invalid_request(
+                format!(
+                    "Pass between 1 and {} pull request URLs, got {}",
+                    MAX_DETAILS_URLS,
+                    pull_request_urls.len()
+                ),
+                None,
+            ));
+        }
+        let pull_request_ids = pull_request_urls
+            .into_iter()
+            .map(|url| {
+                PullRequestId::parse_url(&PullRequestUrl(url)).map_err(|e| {
+                    McpError::invalid_request(format!("Invalid pull request URL: {}", e), None)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let comment_limit = parse_comment_limit(comment_limit)?;
+
+        let mut pull_requests = Vec::new();
+        let mut failures = Vec::new();
+        let results = functions::pull_request::get_pull_requests_with_details(
+            github_client,
+            &pull_request_ids,
+            comment_limit,
+            concurrency,
+        )
+        .await;
+        for (pull_request_id, result) in pull_request_ids.iter().zip(results) {
+            match result {
+                Ok(details) => pull_requests.push(details),
+                Err(e) => {
+                    failures.push(json!({"url": pull_request_id.url(), "error": e.to_string()}))
+                }
+            }
+        }
+
+        let mut sections: Vec<String> = pull_requests
+            .iter()
+            .map(format_pull_request_details)
+            .collect();
+        for failure in &failures {
+            sections.push(format!(
+                "Failed to get pull request {}: {}",
+                failure["url"].as_str().unwrap_or_default(),
+                failure["error"].as_str().unwrap_or_default()
+            ));
+        }
+        let mut result = success_result(
+            sections.join("\n\n"),
+            &json!({"pull_requests": pull_requests, "failures": failures}),
+        );
+        if pull_requests.is_empty() {
+            result.is_error = Some(true);
+        }
+        Ok(result)
+    }
+
+    pub async fn get_pull_request_merge_status(
+        github_client: &GitHubClient,
+        repository_url: String,
+        pr_number: u64,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+        let pr_num = PullRequestNumber::new(pr_number as u32);
+
+        match functions::pull_request::get_merge_status(github_client, &repo_id, pr_num).await {
+            Ok(status) => {
+                let mut lines = vec![
+                    format!("Pull request #{}", status.pull_request_id.number),
+                    format!("Mergeable: {}", status.is_mergeable()),
+                    format!("Mergeable state: {}", status.mergeable),
+                    format!("Merge state status: {}", status.merge_state_status),
+                    format!(
+                        "Review decision: {}",
+                        status
+                            .review_decision
+                            .map(|d| d.to_string())
+                            .unwrap_or_else(|| "none".to_string())
+                    ),
+                ];
+                if !status.required_checks.is_empty() {
+                    lines.push("Required checks:".to_string());
+                    for check in &status.required_checks {
+                        lines.push(format!("  - {}: {}", check.name, check.state));
+                    }
+                }
+                if status.blockers.is_empty() {
+                    lines.push("Blockers: none".to_string());
+                } else {
+                    lines.push("Blockers:".to_string());
+                    for blocker in &status.blockers {
+                        lines.push(format!("  - {}", blocker));
+                    }
+                }
+
+                Ok(success_result(lines.join("\n"), &status))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to get pull request merge status: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
     pub async fn edit_pull_request_title(
         github_client: &GitHubClient,
         repository_url: String,
@@ -158,12 +395,10 @@ impl PullRequestTools {
         let pr_num = PullRequestNumber::new(pr_number as u32);
 
         match functions::pull_request::edit_title(github_client, &repo_id, pr_num, &title).await {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text(
-                    "Pull request title edited successfully".to_string(),
-                )],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Pull request title edited successfully".to_string(),
+                &json!({"pr_number": pr_number, "title": title}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to edit title: {}", e))],
                 is_error: Some(true),
@@ -183,12 +418,10 @@ impl PullRequestTools {
         let pr_num = PullRequestNumber::new(pr_number as u32);
 
         match functions::pull_request::edit_body(github_client, &repo_id, pr_num, &body).await {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text(
-                    "Pull request body edited successfully".to_string(),
-                )],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Pull request body edited successfully".to_string(),
+                &json!({"pr_number": pr_number}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to edit body: {}", e))],
                 is_error: Some(true),
@@ -226,14 +459,14 @@ impl PullRequestTools {
                         skipped.join(", ")
                     ));
                 }
-                Ok(CallToolResult {
-                    content: vec![Content::text(if result.is_empty() {
+                Ok(success_result(
+                    if result.is_empty() {
                         "No changes made to assignees".to_string()
                     } else {
                         result.join("; ")
-                    })],
-                    is_error: Some(false),
-                })
+                    },
+                    &json!({"added": added, "skipped": skipped}),
+                ))
             }
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to add assignees: {}", e))],
@@ -256,10 +489,10 @@ impl PullRequestTools {
         match functions::pull_request::remove_assignees(github_client, &repo_id, pr_num, &assignees)
             .await
         {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text("Assignees removed successfully".to_string())],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Assignees removed successfully".to_string(),
+                &json!({"pr_number": pr_number, "removed": assignees}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to remove assignees: {}", e))],
                 is_error: Some(true),
@@ -297,14 +530,14 @@ impl PullRequestTools {
                         skipped.join(", ")
                     ));
                 }
-                Ok(CallToolResult {
-                    content: vec![Content::text(if result.is_empty() {
+                Ok(success_result(
+                    if result.is_empty() {
                         "No changes made to reviewers".to_string()
                     } else {
                         result.join("; ")
-                    })],
-                    is_error: Some(false),
-                })
+                    },
+                    &json!({"added": added, "skipped": skipped}),
+                ))
             }
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to add reviewers: {}", e))],
@@ -323,15 +556,15 @@ impl PullRequestTools {
             McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
         })?;
         let pr_num = PullRequestNumber::new(pr_number as u32);
-        let label_objects: Vec<Label> = labels.into_iter().map(|name| Label::from(name)).collect();
+        let label_objects: Vec<Label> = labels.into_iter().map(Label::from).collect();
 
         match functions::pull_request::add_labels(github_client, &repo_id, pr_num, &label_objects)
             .await
         {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text("Labels added successfully".to_string())],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Labels added successfully".to_string(),
+                &json!({"pr_number": pr_number, "added": label_objects}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to add labels: {}", e))],
                 is_error: Some(true),
@@ -349,7 +582,7 @@ impl PullRequestTools {
             McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
         })?;
         let pr_num = PullRequestNumber::new(pr_number as u32);
-        let label_objects: Vec<Label> = labels.into_iter().map(|name| Label::from(name)).collect();
+        let label_objects: Vec<Label> = labels.into_iter().map(Label::from).collect();
 
         match functions::pull_request::remove_labels(
             github_client,
@@ -359,10 +592,10 @@ impl PullRequestTools {
         )
         .await
         {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text("Labels removed successfully".to_string())],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Labels removed successfully".to_string(),
+                &json!({"pr_number": pr_number, "removed": label_objects}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to remove labels: {}", e))],
                 is_error: Some(true),
@@ -374,21 +607,37 @@ impl PullRequestTools {
         github_client: &GitHubClient,
         repository_url: String,
         pr_number: u64,
-        milestone_number: u64,
+        milestone_number: Option<u64>,
+        milestone_title: Option<String>,
     ) -> Result<CallToolResult, McpError> {
         let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
             McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
         })?;
         let pr_num = PullRequestNumber::new(pr_number as u32);
-        let milestone = MilestoneNumber::new(milestone_number);
+        let milestone = match functions::repository::resolve_milestone(
+            github_client,
+            &repo_id,
+            milestone_number.map(MilestoneNumber::new),
+            milestone_title.as_deref(),
+        )
+        .await
+        {
+            Ok(milestone) => milestone,
+            Err(e) => {
+                return Ok(CallToolResult {
+                    content: vec![Content::text(format!("Failed to find milestone: {}", e))],
+                    is_error: Some(true),
+                });
+            }
+        };
 
         match functions::pull_request::add_milestone(github_client, &repo_id, pr_num, milestone)
             .await
         {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text("Milestone added successfully".to_string())],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Milestone added successfully".to_string(),
+                &json!({"pr_number": pr_number, "milestone_number": milestone_number}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to add milestone: {}", e))],
                 is_error: Some(true),
@@ -407,10 +656,10 @@ impl PullRequestTools {
         let pr_num = PullRequestNumber::new(pr_number as u32);
 
         match functions::pull_request::remove_milestone(github_client, &repo_id, pr_num).await {
-            Ok(_) => Ok(CallToolResult {
-                content: vec![Content::text("Milestone removed successfully".to_string())],
-                is_error: Some(false),
-            }),
+            Ok(_) => Ok(success_result(
+                "Milestone removed successfully".to_string(),
+                &json!({"pr_number": pr_number, "milestone_number": null}),
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to remove milestone: {}", e))],
                 is_error: Some(true),
@@ -418,3 +667,102 @@ impl PullRequestTools {
         }
     }
 }
+
+/// Render a pull request with its comments for the text summary of a read tool
+fn format_pull_request_details(details: &PullRequestDetails) -> String {
+    let pull_request = &details.pull_request;
+    let join_users = |users: &[crate::types::User]| {
+        users
+            .iter()
+            .map(|user| user.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let mut lines = vec![
+        format!(
+            "Pull request #{} [{}{}] {}",
+            pull_request.pull_request_id.number,
+            pull_request.state,
+            if pull_request.draft { ", draft" } else { "" },
+            pull_request.title
+        ),
+        format!("URL: {}", pull_request.pull_request_id.url()),
+        format!(
+            "Author: {}",
+            pull_request
+                .author
+                .as_ref()
+                .map(|author| author.to_string())
+                .unwrap_or_else(|| "unknown".to_string())
+        ),
+        format!(
+            "Branches: {} -> {}",
+            pull_request.head_branch, pull_request.base_branch
+        ),
+        format!(
+            "Changes: {} commit(s), {} file(s), +{} -{}",
+            pull_request.commits_count,
+            pull_request.changed_files,
+            pull_request.additions,
+            pull_request.deletions
+        ),
+        format!(
+            "Created: {}, updated: {}",
+            pull_request.created_at, pull_request.updated_at
+        ),
+    ];
+    if let Some(merged_at) = pull_request.merged_at {
+        lines.push(format!("Merged: {}", merged_at));
+    }
+    if let Some(mergeable) = pull_request.mergeable {
+        lines.push(format!("Mergeable: {}", mergeable));
+    }
+    if !pull_request.assignees.is_empty() {
+        lines.push(format!(
+            "Assignees: {}",
+            join_users(&pull_request.assignees)
+        ));
+    }
+    if !pull_request.requested_reviewers.is_empty() {
+        lines.push(format!(
+            "Requested reviewers: {}",
+            join_users(&pull_request.requested_reviewers)
+        ));
+    }
+    if !pull_request.labels.is_empty() {
+        lines.push(format!(
+            "Labels: {}",
+            pull_request
+                .labels
+                .iter()
+                .map(|label| label.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    if let Some(milestone_number) = pull_request.milestone_number {
+        lines.push(format!("Milestone: {}", milestone_number));
+    }
+    lines.push(String::new());
+    lines.push(
+        pull_request
+            .body
+            .clone()
+            .filter(|body| !body.trim().is_empty())
+            .unwrap_or_else(|| "(no description)".to_string()),
+    );
+    push_comments(
+        &mut lines,
+        pull_request.comments.iter().map(|comment| {
+            (
+                comment.comment_number.value(),
+                comment.author.as_ref(),
+                comment.created_at,
+                comment.body.as_str(),
+            )
+        }),
+        details.has_more_comments,
+        "list_pull_request_comments",
+    );
+    lines.join("\n")
+}
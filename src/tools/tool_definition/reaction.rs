@@ -0,0 +1,142 @@
+//! Reaction related tool definitions for GitHub repository operations
+//!
+//! This module contains MCP tool implementations for adding, removing, and
+//! listing emoji reactions on issues, pull requests, and their comments.
+
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::reaction::{ReactionKind, ReactionTarget, ReactionTargetType};
+use crate::types::repository::{RepositoryId, RepositoryUrl};
+
+use crate::tools::tool_definition::success_result;
+use rmcp::{Error as McpError, model::*};
+use serde_json::json;
+
+/// Reaction management tools implementation
+pub struct ReactionTools;
+
+impl ReactionTools {
+    pub async fn add_reaction(
+        github_client: &GitHubClient,
+        repository_url: String,
+        target_type: String,
+        number: u64,
+        reaction: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+        let target = parse_target(&target_type, number)?;
+        let kind = parse_kind(&reaction)?;
+
+        match functions::reaction::add_reaction(github_client, &repo_id, target, kind).await {
+            Ok(reaction) => Ok(success_result(
+                format!(
+                    "Added reaction {} to {} (reaction ID: {})",
+                    reaction.kind, target, reaction.id
+                ),
+                &reaction,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to add reaction: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn remove_reaction(
+        github_client: &GitHubClient,
+        repository_url: String,
+        target_type: String,
+        number: u64,
+        reaction: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+        let target = parse_target(&target_type, number)?;
+        let kind = parse_kind(&reaction)?;
+
+        match functions::reaction::remove_reaction(github_client, &repo_id, target, kind).await {
+            Ok(true) => Ok(success_result(
+                format!("Removed reaction {} from {}", kind, target),
+                &json!({"removed": true}),
+            )),
+            Ok(false) => Ok(success_result(
+                format!(
+                    "No {} reaction by the authenticated user on {}",
+                    kind, target
+                ),
+                &json!({"removed": false}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to remove reaction: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn list_reactions(
+        github_client: &GitHubClient,
+        repository_url: String,
+        target_type: String,
+        number: u64,
+        reaction: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository ID: {}", e), None)
+        })?;
+        let target = parse_target(&target_type, number)?;
+        let kind = reaction.as_deref().map(parse_kind).transpose()?;
+
+        match functions::reaction::list_reactions(github_client, &repo_id, target, kind).await {
+            Ok(reactions) => {
+                let text = if reactions.is_empty() {
+                    format!("No reactions on {}", target)
+                } else {
+                    let mut lines = vec![format!("{} reaction(s) on {}:", reactions.len(), target)];
+                    lines.extend(reactions.iter().map(|reaction| {
+                        format!(
+                            "- {} by {} at {} (ID: {})",
+                            reaction.kind,
+                            reaction.user.username,
+                            reaction.created_at.to_rfc3339(),
+                            reaction.id
+                        )
+                    }));
+                    lines.join("\n")
+                };
+                Ok(success_result(text, &reactions))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to list reactions: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+}
+
+fn parse_target(target_type: &str, number: u64) -> Result<ReactionTarget, McpError> {
+    let target_type = target_type.parse::<ReactionTargetType>().map_err(|_| {
+        McpError::invalid_request(
+            format!(
+                "Invalid target type '{}'. Must be one of: issue, pull_request, issue_comment, pull_request_comment",
+                target_type
+            ),
+            None,
+        )
+    })?;
+    Ok(ReactionTarget::new(target_type, number))
+}
+
+fn parse_kind(reaction: &str) -> Result<ReactionKind, McpError> {
+    reaction.parse::<ReactionKind>().map_err(|_| {
+        McpError::invalid_request(
+            format!(
+                "Invalid reaction '{}'. Must be one of: +1, -1, laugh, confused, heart, hooray, rocket, eyes",
+                reaction
+            ),
+            None,
+        )
+    })
+}
@@ -3,20 +3,92 @@
 //! This module contains MCP tool implementations for managing GitHub repositories,
 //! including milestone creation and repository management operations.
 //!
-//! Note: This module does not contain any delete operations for safety reasons.
+//! Note: Branches can not be deleted through these tools. Labels and
+//! milestones are deleted through the confirmed tools of `deletion`.
 
+use crate::tools::tool_definition::success_result;
 use chrono::{DateTime, Utc};
 use rmcp::{Error as McpError, model::*};
+use serde_json::json;
+use std::time::Duration;
 
 use crate::github::GitHubClient;
 use crate::tools::functions::repository;
+use crate::tools::tool_definition::parse_timestamp;
+use crate::types::commit::CommitFilter;
+use crate::types::fork::{ForkSyncMergeType, ForkSyncResult};
+use crate::types::metadata_copy::{
+    MetadataConflictStrategy, MetadataCopyEntry, MetadataCopyOptions,
+};
 use crate::types::milestone::MilestoneState;
-use crate::types::repository::{RepositoryId, RepositoryUrl};
+use crate::types::pagination::Pagination;
+use crate::types::release::{Release, ReleaseId, ReleaseUpdate};
+use crate::types::repository::{RepositoryId, RepositorySettings, RepositoryUrl};
+use crate::types::transfer::RepositoryTransferStatus;
+
+/// Seconds `transfer_repository` waits for a transfer to complete by default
+const DEFAULT_TRANSFER_WAIT_SECONDS: u64 = 10;
 
 /// Repository-related tool implementations
 pub struct RepositoryTools;
 
 impl RepositoryTools {
+    /// Get an overview of a repository
+    pub async fn get_repository(
+        github_client: &GitHubClient,
+        repository_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+        })?;
+
+        match repository::get_repository(github_client, &repo_id).await {
+            Ok(repo) => {
+                let mut lines = vec![format!(
+                    "{} ({}{}{})",
+                    repo.html_url,
+                    repo.visibility,
+                    if repo.archived { ", archived" } else { "" },
+                    if repo.fork { ", fork" } else { "" }
+                )];
+                if let Some(description) = &repo.description {
+                    lines.push(format!("Description: {}", description));
+                }
+                if let Some(homepage) = &repo.homepage {
+                    lines.push(format!("Homepage: {}", homepage));
+                }
+                lines.push(format!(
+                    "Default branch: {}",
+                    repo.default_branch
+                        .as_deref()
+                        .unwrap_or("(empty repository)")
+                ));
+                if !repo.topics.is_empty() {
+                    lines.push(format!("Topics: {}", repo.topics.join(", ")));
+                }
+                lines.push(format!(
+                    "Open issues: {}, open pull requests: {}, stars: {}",
+                    repo.open_issue_count, repo.open_pull_request_count, repo.stargazer_count
+                ));
+                lines.push(format!(
+                    "License: {}",
+                    repo.license.as_deref().unwrap_or("none")
+                ));
+                lines.push(format!(
+                    "Your permission: {}",
+                    repo.viewer_permission
+                        .map(|permission| permission.to_string())
+                        .unwrap_or_else(|| "unknown".to_string())
+                ));
+                Ok(success_result(lines.join("\n"), &repo))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to get repository: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
     /// Create a new label in a repository
     pub async fn create_label(
         github_client: &GitHubClient,
@@ -39,15 +111,15 @@ impl RepositoryTools {
         )
         .await
         {
-            Ok(label) => Ok(CallToolResult {
-                content: vec![Content::text(format!(
+            Ok(label) => Ok(success_result(
+                format!(
                     "Created label '{}' with color '{}' in repository {}",
                     label.name,
                     label.color(),
                     repository_url
-                ))],
-                is_error: Some(false),
-            }),
+                ),
+                &label,
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to create label: {}", e))],
                 is_error: Some(true),
@@ -79,15 +151,15 @@ impl RepositoryTools {
         )
         .await
         {
-            Ok(label) => Ok(CallToolResult {
-                content: vec![Content::text(format!(
+            Ok(label) => Ok(success_result(
+                format!(
                     "Updated label '{}' with color '{}' in repository {}",
                     label.name,
                     label.color(),
                     repository_url
-                ))],
-                is_error: Some(false),
-            }),
+                ),
+                &label,
+            )),
             Err(e) => Ok(CallToolResult {
                 content: vec![Content::text(format!("Failed to update label: {}", e))],
                 is_error: Some(true),
@@ -95,6 +167,50 @@ impl RepositoryTools {
         }
     }
 
+    /// List the labels of a repository
+    pub async fn list_labels(
+        github_client: &GitHubClient,
+        repository_url: String,
+        page: Option<u32>,
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id =
+            RepositoryId::parse_url(&RepositoryUrl(repository_url.clone())).map_err(|e| {
+                McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+            })?;
+        let pagination = Pagination::new(page, per_page);
+
+        match repository::list_labels(github_client, &repo_id, pagination).await {
+            Ok(labels) => {
+                let mut lines = vec![format!(
+                    "Labels of {} (page {}, {} label(s)):",
+                    repository_url,
+                    labels.page,
+                    labels.items.len()
+                )];
+                lines.extend(labels.items.iter().map(|label| {
+                    format!(
+                        "- {} (#{}){}",
+                        label.name,
+                        label.color(),
+                        label
+                            .description()
+                            .map(|description| format!(": {}", description))
+                            .unwrap_or_default()
+                    )
+                }));
+                if labels.has_next_page {
+                    lines.push(format!("More labels available on page {}", labels.page + 1));
+                }
+                Ok(success_result(lines.join("\n"), &labels))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to list labels: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
     /// Create a new milestone in a repository
     pub async fn create_milestone(
         github_client: &GitHubClient,
@@ -146,17 +262,949 @@ impl RepositoryTools {
         )
         .await
         {
-            Ok(milestone) => Ok(CallToolResult {
-                content: vec![Content::text(format!(
+            Ok(milestone) => Ok(success_result(
+                format!(
                     "Created milestone '{}' with ID {} in repository {}",
                     milestone.title, milestone.id.0, repository_url
+                ),
+                &milestone,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to create milestone: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// List the milestones of a repository
+    pub async fn list_milestones(
+        github_client: &GitHubClient,
+        repository_url: String,
+        state: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id =
+            RepositoryId::parse_url(&RepositoryUrl(repository_url.clone())).map_err(|e| {
+                McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+            })?;
+
+        let milestone_state = match state.as_deref().map(str::to_lowercase).as_deref() {
+            None | Some("all") => None,
+            Some("open") => Some(MilestoneState::Open),
+            Some("closed") => Some(MilestoneState::Closed),
+            Some(_) => {
+                return Err(McpError::invalid_request(
+                    "State must be 'open', 'closed' or 'all'".to_string(),
+                    None,
+                ));
+            }
+        };
+
+        match repository::list_milestones(github_client, &repo_id, milestone_state).await {
+            Ok(milestones) => {
+                let mut lines = vec![format!(
+                    "Milestones of {} ({} milestone(s)):",
+                    repository_url,
+                    milestones.len()
+                )];
+                lines.extend(milestones.iter().map(|milestone| {
+                    format!(
+                        "- #{} {} [{}] {}/{} issue(s) closed{}",
+                        milestone.id.value(),
+                        milestone.title,
+                        milestone.state,
+                        milestone.closed_issues,
+                        milestone.total_issues(),
+                        milestone
+                            .due_on
+                            .map(|due_on| format!(", due {}", due_on.format("%Y-%m-%d")))
+                            .unwrap_or_default()
+                    )
+                }));
+                Ok(success_result(lines.join("\n"), &milestones))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to list milestones: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// List the branches of a repository
+    pub async fn list_branches(
+        github_client: &GitHubClient,
+        repository_url: String,
+        page: Option<u32>,
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id =
+            RepositoryId::parse_url(&RepositoryUrl(repository_url.clone())).map_err(|e| {
+                McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+            })?;
+        let pagination = Pagination::new(page, per_page);
+
+        match repository::list_branches(github_client, &repo_id, pagination).await {
+            Ok(branches) => {
+                let mut lines = vec![format!(
+                    "Branches of {} (page {}, {} branch(es)):",
+                    repository_url,
+                    branches.page,
+                    branches.items.len()
+                )];
+                lines.extend(branches.items.iter().map(|branch| {
+                    format!(
+                        "- {} ({}){}",
+                        branch.name,
+                        branch.commit_sha,
+                        if branch.protected { " [protected]" } else { "" }
+                    )
+                }));
+                if branches.has_next_page {
+                    lines.push(format!(
+                        "More branches available on page {}",
+                        branches.page + 1
+                    ));
+                }
+                Ok(success_result(lines.join("\n"), &branches))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to list branches: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Create a branch from a branch name, tag or commit SHA
+    pub async fn create_branch(
+        github_client: &GitHubClient,
+        repository_url: String,
+        name: String,
+        from_ref: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id =
+            RepositoryId::parse_url(&RepositoryUrl(repository_url.clone())).map_err(|e| {
+                McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+            })?;
+
+        match repository::create_branch(github_client, &repo_id, &name, &from_ref).await {
+            Ok(branch) => Ok(success_result(
+                format!(
+                    "Created branch '{}' at {} in repository {}",
+                    branch.name, branch.commit_sha, repository_url
+                ),
+                &branch,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to create branch: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Rename a branch
+    pub async fn rename_branch(
+        github_client: &GitHubClient,
+        repository_url: String,
+        name: String,
+        new_name: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id =
+            RepositoryId::parse_url(&RepositoryUrl(repository_url.clone())).map_err(|e| {
+                McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+            })?;
+
+        match repository::rename_branch(github_client, &repo_id, &name, &new_name).await {
+            Ok(branch) => Ok(success_result(
+                format!(
+                    "Renamed branch '{}' to '{}' in repository {}",
+                    name, branch.name, repository_url
+                ),
+                &branch,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to rename branch: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Get a single commit with its message, author, stats and changed files
+    pub async fn get_commit(
+        github_client: &GitHubClient,
+        repository_url: String,
+        sha: String,
+        include_patch: Option<bool>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id =
+            RepositoryId::parse_url(&RepositoryUrl(repository_url.clone())).map_err(|e| {
+                McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+            })?;
+
+        match repository::get_commit(github_client, &repo_id, &sha).await {
+            Ok(commit) => {
+                let mut lines = vec![
+                    format!("Commit {} in {}", commit.sha, repository_url),
+                    format!("URL: {}", commit.html_url),
+                    format!(
+                        "Author: {}{}",
+                        commit.author_name().unwrap_or("unknown"),
+                        commit
+                            .authored_at()
+                            .map(|date| format!(" ({})", date.to_rfc3339()))
+                            .unwrap_or_default()
+                    ),
+                    format!(
+                        "Parents: {}",
+                        commit
+                            .parents
+                            .iter()
+                            .map(|parent| parent.sha.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                ];
+                if let Some(stats) = commit.stats {
+                    lines.push(format!(
+                        "Stats: +{} -{} in {} file(s)",
+                        stats.additions,
+                        stats.deletions,
+                        commit.files.len()
+                    ));
+                }
+                lines.push(String::new());
+                lines.push(commit.commit.message.clone());
+                if !commit.files.is_empty() {
+                    lines.push(String::new());
+                    lines.push("Files:".to_string());
+                    for file in &commit.files {
+                        lines.push(format!(
+                            "- {} [{}] +{} -{}{}",
+                            file.filename,
+                            file.status,
+                            file.additions,
+                            file.deletions,
+                            file.previous_filename
+                                .as_deref()
+                                .map(|previous| format!(" (from {})", previous))
+                                .unwrap_or_default()
+                        ));
+                        if include_patch.unwrap_or(false) {
+                            if let Some(patch) = &file.patch {
+                                lines.push(patch.clone());
+                            }
+                        }
+                    }
+                }
+                Ok(success_result(lines.join("\n"), &commit))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to get commit: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// List the commits of a repository, newest first
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_commits(
+        github_client: &GitHubClient,
+        repository_url: String,
+        branch: Option<String>,
+        path: Option<String>,
+        since: Option<String>,
+        until: Option<String>,
+        page: Option<u32>,
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id =
+            RepositoryId::parse_url(&RepositoryUrl(repository_url.clone())).map_err(|e| {
+                McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+            })?;
+        let filter = CommitFilter {
+            branch,
+            path,
+            since: parse_timestamp("since", since)?,
+            until: parse_timestamp("until", until)?,
+        };
+
+        match repository::list_commits(
+            github_client,
+            &repo_id,
+            &filter,
+            Pagination::new(page, per_page),
+        )
+        .await
+        {
+            Ok(commits) => {
+                let mut lines = vec![format!(
+                    "Commits of {} (page {}, {} commit(s)):",
+                    repository_url,
+                    commits.page,
+                    commits.items.len()
+                )];
+                lines.extend(commits.items.iter().map(|commit| {
+                    format!(
+                        "- {} {} ({}, {})",
+                        commit.short_sha(),
+                        commit.summary(),
+                        commit.author_name().unwrap_or("unknown"),
+                        commit
+                            .authored_at()
+                            .map(|date| date.to_rfc3339())
+                            .unwrap_or_default()
+                    )
+                }));
+                if commits.has_next_page {
+                    lines.push(format!(
+                        "More commits available on page {}",
+                        commits.page + 1
+                    ));
+                }
+                Ok(success_result(lines.join("\n"), &commits))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to list commits: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Get the contents of a file in a repository
+    pub async fn get_file_content(
+        github_client: &GitHubClient,
+        repository_url: String,
+        path: String,
+        git_ref: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+        })?;
+
+        match repository::get_file_content(github_client, &repo_id, &path, git_ref.as_deref()).await
+        {
+            Ok(file) => {
+                let header = format!(
+                    "File: {} (sha: {}, {} bytes)",
+                    file.path, file.sha, file.size
+                );
+                let body = match file.text() {
+                    Some(text) => text.to_string(),
+                    None => format!(
+                        "Binary content (base64):\n{}",
+                        crate::types::content::encode_content(&file.content)
+                    ),
+                };
+                Ok(success_result(format!("{}\n\n{}", header, body), &file))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to get file content: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Create a file or update an existing one in a single commit
+    pub async fn create_or_update_file(
+        github_client: &GitHubClient,
+        repository_url: String,
+        path: String,
+        content: String,
+        message: String,
+        branch: Option<String>,
+        sha: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+        })?;
+
+        match repository::create_or_update_file(
+            github_client,
+            &repo_id,
+            &path,
+            content.as_bytes(),
+            &message,
+            branch.as_deref(),
+            sha.as_deref(),
+        )
+        .await
+        {
+            Ok(commit) => Ok(success_result(
+                format!(
+                    "{} '{}' in commit {} (new file sha: {})",
+                    if sha.is_some() { "Updated" } else { "Created" },
+                    commit.path,
+                    commit.commit_sha,
+                    commit.content_sha.as_deref().unwrap_or("unknown")
+                ),
+                &commit,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to write file: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Delete a file in a single commit
+    pub async fn delete_file(
+        github_client: &GitHubClient,
+        repository_url: String,
+        path: String,
+        message: String,
+        sha: String,
+        branch: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+        })?;
+
+        match repository::delete_file(
+            github_client,
+            &repo_id,
+            &path,
+            &message,
+            &sha,
+            branch.as_deref(),
+        )
+        .await
+        {
+            Ok(commit) => Ok(success_result(
+                format!("Deleted '{}' in commit {}", commit.path, commit.commit_sha),
+                &commit,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to delete file: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Create a release
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_release(
+        github_client: &GitHubClient,
+        repository_url: String,
+        tag: String,
+        name: Option<String>,
+        body: Option<String>,
+        draft: Option<bool>,
+        prerelease: Option<bool>,
+        target_commitish: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+        })?;
+
+        match repository::create_release(
+            github_client,
+            &repo_id,
+            &tag,
+            name.as_deref(),
+            body.as_deref(),
+            draft.unwrap_or(false),
+            prerelease.unwrap_or(false),
+            target_commitish.as_deref(),
+        )
+        .await
+        {
+            Ok(release) => Ok(success_result(
+                format!("Created {}", describe_release(&release)),
+                &release,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to create release: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Update a release identified by ID or tag
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_release(
+        github_client: &GitHubClient,
+        repository_url: String,
+        release_id: Option<u64>,
+        tag: Option<String>,
+        new_tag: Option<String>,
+        name: Option<String>,
+        body: Option<String>,
+        draft: Option<bool>,
+        prerelease: Option<bool>,
+        target_commitish: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+        })?;
+        let release_id = match repository::resolve_release_id(
+            github_client,
+            &repo_id,
+            release_id.map(ReleaseId::new),
+            tag.as_deref(),
+        )
+        .await
+        {
+            Ok(release_id) => release_id,
+            Err(e) => {
+                return Ok(CallToolResult {
+                    content: vec![Content::text(format!("Failed to find release: {}", e))],
+                    is_error: Some(true),
+                });
+            }
+        };
+
+        let update = ReleaseUpdate {
+            tag_name: new_tag,
+            name,
+            body,
+            draft,
+            prerelease,
+            target_commitish,
+        };
+
+        match repository::update_release(github_client, &repo_id, release_id, &update).await {
+            Ok(release) => Ok(success_result(
+                format!("Updated {}", describe_release(&release)),
+                &release,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to update release: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Publish a draft release
+    pub async fn publish_release(
+        github_client: &GitHubClient,
+        repository_url: String,
+        release_id: u64,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+        })?;
+
+        match repository::publish_release(github_client, &repo_id, ReleaseId::new(release_id)).await
+        {
+            Ok(release) => Ok(success_result(
+                format!("Published {}", describe_release(&release)),
+                &release,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to publish release: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Delete a release identified by ID or tag
+    pub async fn delete_release(
+        github_client: &GitHubClient,
+        repository_url: String,
+        release_id: Option<u64>,
+        tag: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+        })?;
+        let release_id = match repository::resolve_release_id(
+            github_client,
+            &repo_id,
+            release_id.map(ReleaseId::new),
+            tag.as_deref(),
+        )
+        .await
+        {
+            Ok(release_id) => release_id,
+            Err(e) => {
+                return Ok(CallToolResult {
+                    content: vec![Content::text(format!("Failed to find release: {}", e))],
+                    is_error: Some(true),
+                });
+            }
+        };
+
+        match repository::delete_release(github_client, &repo_id, release_id).await {
+            Ok(_) => Ok(success_result(
+                format!("Deleted release {} (the tag was kept)", release_id),
+                &json!({"release_id": release_id, "deleted": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to delete release: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Generate release notes from merged pull requests
+    pub async fn generate_release_notes(
+        github_client: &GitHubClient,
+        repository_url: String,
+        tag: String,
+        previous_tag: Option<String>,
+        target_commitish: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+        })?;
+
+        match repository::generate_release_notes(
+            github_client,
+            &repo_id,
+            &tag,
+            previous_tag.as_deref(),
+            target_commitish.as_deref(),
+        )
+        .await
+        {
+            Ok(notes) => Ok(success_result(
+                format!("{}\n\n{}", notes.name, notes.body),
+                &notes,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to generate release notes: {}",
+                    e
                 ))],
-                is_error: Some(false),
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Fork a repository
+    pub async fn fork_repository(
+        github_client: &GitHubClient,
+        repository_url: String,
+        organization: Option<String>,
+        default_branch_only: Option<bool>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+        })?;
+
+        match repository::fork_repository(
+            github_client,
+            &repo_id,
+            organization.as_deref(),
+            default_branch_only.unwrap_or(false),
+        )
+        .await
+        {
+            Ok(fork) => Ok(success_result(
+                format!(
+                    "Forked {} to {} (default branch: {}). The fork may take a short while to become available.",
+                    repo_id, fork.html_url, fork.default_branch
+                ),
+                &fork,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to fork repository: {}", e))],
+                is_error: Some(true),
             }),
+        }
+    }
+
+    /// Sync a branch of a fork with its upstream repository
+    pub async fn sync_fork_with_upstream(
+        github_client: &GitHubClient,
+        repository_url: String,
+        branch: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+        })?;
+
+        match repository::sync_fork_with_upstream(github_client, &repo_id, branch.as_deref()).await
+        {
+            Ok(result) => Ok(success_result(describe_fork_sync(&result), &result)),
             Err(e) => Ok(CallToolResult {
-                content: vec![Content::text(format!("Failed to create milestone: {}", e))],
+                content: vec![Content::text(format!("Failed to sync fork: {}", e))],
                 is_error: Some(true),
             }),
         }
     }
+
+    /// Update the settings of a repository
+    pub async fn update_repository_settings(
+        github_client: &GitHubClient,
+        repository_url: String,
+        settings: RepositorySettings,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+        })?;
+
+        match repository::update_repository_settings(github_client, &repo_id, &settings).await {
+            Ok(updated) => Ok(success_result(
+                format!(
+                    "Updated settings of {}\n{}",
+                    repo_id,
+                    serde_json::to_string_pretty(&updated).unwrap_or_default()
+                ),
+                &updated,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to update repository settings: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Archive a repository once the caller has confirmed it
+    pub async fn archive_repository(
+        github_client: &GitHubClient,
+        repository_url: String,
+        confirm: bool,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+        })?;
+
+        if !confirm {
+            return Err(McpError::invalid_request(
+                format!(
+                    "Archiving makes {} read-only. Set confirm to true to archive it",
+                    repo_id
+                ),
+                None,
+            ));
+        }
+
+        match repository::archive_repository(github_client, &repo_id).await {
+            Ok(()) => Ok(success_result(
+                format!("Archived {}", repo_id),
+                &json!({"repository": repo_id, "archived": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to archive repository: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Unarchive a repository
+    pub async fn unarchive_repository(
+        github_client: &GitHubClient,
+        repository_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+        })?;
+
+        match repository::unarchive_repository(github_client, &repo_id).await {
+            Ok(()) => Ok(success_result(
+                format!("Unarchived {}", repo_id),
+                &json!({"repository": repo_id, "archived": false}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to unarchive repository: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Transfer a repository to a new owner once the caller has confirmed it
+    #[allow(clippy::too_many_arguments)]
+    pub async fn transfer_repository(
+        github_client: &GitHubClient,
+        repository_url: String,
+        new_owner: String,
+        new_name: Option<String>,
+        team_ids: Option<Vec<u64>>,
+        wait_seconds: Option<u64>,
+        confirm: bool,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = RepositoryId::parse_url(&RepositoryUrl(repository_url)).map_err(|e| {
+            McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+        })?;
+
+        if !confirm {
+            return Err(McpError::invalid_request(
+                format!(
+                    "Transferring moves {} to {}. Set confirm to true to transfer it",
+                    repo_id, new_owner
+                ),
+                None,
+            ));
+        }
+
+        let wait = Duration::from_secs(wait_seconds.unwrap_or(DEFAULT_TRANSFER_WAIT_SECONDS));
+        match repository::transfer_repository(
+            github_client,
+            &repo_id,
+            &new_owner,
+            new_name.as_deref(),
+            &team_ids.unwrap_or_default(),
+            Some(wait),
+        )
+        .await
+        {
+            Ok(transfer) => Ok(success_result(
+                if transfer.is_completed() {
+                    format!("Transferred {} to {}", repo_id, transfer.html_url)
+                } else {
+                    format!(
+                        "Transfer of {} to {} is pending. Check it later with get_repository_transfer_status; transfers to a user wait until the user accepts them",
+                        repo_id, transfer.html_url
+                    )
+                },
+                &transfer,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to transfer repository: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Get the status of a repository transfer
+    pub async fn get_repository_transfer_status(
+        github_client: &GitHubClient,
+        target_repository_url: String,
+    ) -> Result<CallToolResult, McpError> {
+        let target_repo_id = RepositoryId::parse_url(&RepositoryUrl(target_repository_url))
+            .map_err(|e| {
+                McpError::invalid_request(format!("Invalid repository URL: {}", e), None)
+            })?;
+
+        match repository::get_repository_transfer_status(github_client, &target_repo_id).await {
+            Ok(RepositoryTransferStatus::Completed) => Ok(success_result(
+                format!("Transfer completed: {} is available", target_repo_id),
+                &json!({"repository": target_repo_id, "status": "completed"}),
+            )),
+            Ok(RepositoryTransferStatus::Pending) => Ok(success_result(
+                format!("Transfer pending: {} is not available yet", target_repo_id),
+                &json!({"repository": target_repo_id, "status": "pending"}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to get repository transfer status: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    /// Copy labels and milestones from one repository to another
+    pub async fn copy_repository_metadata(
+        github_client: &GitHubClient,
+        source_repository_url: String,
+        target_repository_url: String,
+        labels: Option<bool>,
+        milestones: Option<bool>,
+        on_conflict: Option<String>,
+    ) -> Result<CallToolResult, McpError> {
+        let source_repo_id = RepositoryId::parse_url(&RepositoryUrl(source_repository_url))
+            .map_err(|e| {
+                McpError::invalid_request(format!("Invalid source repository URL: {}", e), None)
+            })?;
+        let target_repo_id = RepositoryId::parse_url(&RepositoryUrl(target_repository_url))
+            .map_err(|e| {
+                McpError::invalid_request(format!("Invalid target repository URL: {}", e), None)
+            })?;
+
+        let on_conflict = match on_conflict {
+            Some(strategy) => strategy.parse::<MetadataConflictStrategy>().map_err(|_| {
+                McpError::invalid_request(
+                    format!(
+                        "Invalid conflict strategy '{}'. Valid values: skip, overwrite, fail",
+                        strategy
+                    ),
+                    None,
+                )
+            })?,
+            None => MetadataConflictStrategy::default(),
+        };
+        let options = MetadataCopyOptions {
+            labels: labels.unwrap_or(true),
+            milestones: milestones.unwrap_or(true),
+            on_conflict,
+        };
+
+        match repository::copy_repository_metadata(
+            github_client,
+            &source_repo_id,
+            &target_repo_id,
+            &options,
+        )
+        .await
+        {
+            Ok(result) => {
+                let mut lines = vec![format!(
+                    "Copied metadata from {} to {} ({} failure(s))",
+                    source_repo_id,
+                    target_repo_id,
+                    result.failure_count()
+                )];
+                if options.labels {
+                    lines.push(format!("Labels ({}):", result.labels.len()));
+                    lines.extend(result.labels.iter().map(describe_copy_entry));
+                }
+                if options.milestones {
+                    lines.push(format!("Milestones ({}):", result.milestones.len()));
+                    lines.extend(result.milestones.iter().map(describe_copy_entry));
+                }
+                Ok(CallToolResult {
+                    content: vec![Content::text(lines.join("\n"))],
+                    is_error: Some(!result.is_success()),
+                })
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to copy repository metadata: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+}
+
+/// Describe the outcome of copying a label or milestone
+fn describe_copy_entry(entry: &MetadataCopyEntry) -> String {
+    match &entry.error {
+        Some(error) => format!("- {}: {} ({})", entry.name, entry.action, error),
+        None => format!("- {}: {}", entry.name, entry.action),
+    }
+}
+
+/// Describe a release with its ID, tag, status and URL
+fn describe_release(release: &Release) -> String {
+    let status = if release.draft {
+        "draft"
+    } else if release.prerelease {
+        "prerelease"
+    } else {
+        "published"
+    };
+    format!(
+        "release '{}' (ID: {}, tag: {}, {}): {}",
+        release.name.as_deref().unwrap_or(&release.tag_name),
+        release.id,
+        release.tag_name,
+        status,
+        release.html_url
+    )
+}
+
+/// Describe the outcome of a fork sync
+fn describe_fork_sync(result: &ForkSyncResult) -> String {
+    match result.merge_type {
+        ForkSyncMergeType::FastForward => {
+            format!("Fast-forwarded the fork to {}", result.base_branch)
+        }
+        ForkSyncMergeType::Merge => format!("Merged {} into the fork", result.base_branch),
+        ForkSyncMergeType::None => format!("Already up to date: {}", result.message),
+    }
 }
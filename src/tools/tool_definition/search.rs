@@ -0,0 +1,293 @@
+//! Search tool definitions
+//!
+//! This module contains MCP tool implementations for locating issues, pull
+//! requests, repositories and users with GitHub's search query syntax.
+
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::pagination::Pagination;
+use crate::types::search::{
+    IssueSearchSort, MAX_SEARCH_RESULTS, RepositorySearchSort, SearchOrder, SearchResults,
+    UserSearchSort,
+};
+
+use crate::tools::tool_definition::success_result;
+use rmcp::{Error as McpError, model::*};
+
+/// Search tools implementation
+pub struct SearchTools;
+
+impl SearchTools {
+    pub async fn list_search_aliases() -> Result<CallToolResult, McpError> {
+        match functions::search::list_search_aliases() {
+            Ok(aliases) if aliases.is_empty() => Ok(success_result(
+                "No search aliases are saved. Add one with 'github-edit-cli search alias add'",
+                &aliases,
+            )),
+            Ok(aliases) => {
+                let mut lines = vec![format!("Search aliases ({}):", aliases.len())];
+                lines.extend(
+                    aliases
+                        .iter()
+                        .map(|(name, query)| format!("- @{}: {}", name, query)),
+                );
+                Ok(success_result(lines.join("\n"), &aliases))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to list search aliases: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn search_issues_and_prs(
+        github_client: &GitHubClient,
+        query: String,
+        sort: Option<String>,
+        order: Option<String>,
+        page: Option<u32>,
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        let sort = sort
+            .map(|sort| {
+                sort.parse::<IssueSearchSort>().map_err(|_| {
+                    McpError::invalid_request(
+                        format!(
+                            "Invalid sort '{}'. Must be one of: comments, reactions, interactions, created, updated",
+                            sort
+                        ),
+                        None,
+                    )
+                })
+            })
+            .transpose()?;
+        let order = parse_order(order)?;
+
+        match functions::search::search_issues_and_prs(
+            github_client,
+            &query,
+            sort,
+            order,
+            Pagination::new(page, per_page),
+        )
+        .await
+        {
+            Ok(results) => {
+                let mut lines = vec![summary_line("issues and pull requests", &results)];
+                lines.extend(results.page.items.iter().map(|item| {
+                    let labels = item.label_names();
+                    format!(
+                        "- {}#{} [{} {}] {} by {}{} ({} comment(s), updated {}) {}",
+                        item.repository(),
+                        item.number,
+                        item.state,
+                        if item.is_pull_request() {
+                            "pull request"
+                        } else {
+                            "issue"
+                        },
+                        item.title,
+                        item.author().unwrap_or("ghost"),
+                        if labels.is_empty() {
+                            String::new()
+                        } else {
+                            format!(", labels: {}", labels.join(", "))
+                        },
+                        item.comments,
+                        item.updated_at.to_rfc3339(),
+                        item.html_url
+                    )
+                }));
+                lines.extend(paging_notes(&results));
+                Ok(success_result(lines.join("\n"), &results))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to search issues and pull requests: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn search_repositories(
+        github_client: &GitHubClient,
+        query: String,
+        sort: Option<String>,
+        order: Option<String>,
+        page: Option<u32>,
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        let sort = sort
+            .map(|sort| {
+                sort.parse::<RepositorySearchSort>().map_err(|_| {
+                    McpError::invalid_request(
+                        format!(
+                            "Invalid sort '{}'. Must be one of: stars, forks, help-wanted-issues, updated",
+                            sort
+                        ),
+                        None,
+                    )
+                })
+            })
+            .transpose()?;
+        let order = parse_order(order)?;
+
+        match functions::search::search_repositories(
+            github_client,
+            &query,
+            sort,
+            order,
+            Pagination::new(page, per_page),
+        )
+        .await
+        {
+            Ok(results) => {
+                let mut lines = vec![summary_line("repositories", &results)];
+                lines.extend(results.page.items.iter().map(|repository| {
+                    let mut flags = Vec::new();
+                    if repository.private {
+                        flags.push("private");
+                    }
+                    if repository.fork {
+                        flags.push("fork");
+                    }
+                    if repository.archived {
+                        flags.push("archived");
+                    }
+                    format!(
+                        "- {}{} ({} star(s), {} fork(s), {} open issue(s){}) {}{}",
+                        repository.full_name,
+                        if flags.is_empty() {
+                            String::new()
+                        } else {
+                            format!(" [{}]", flags.join(", "))
+                        },
+                        repository.stargazers_count,
+                        repository.forks_count,
+                        repository.open_issues_count,
+                        repository
+                            .language
+                            .as_deref()
+                            .map(|language| format!(", {}", language))
+                            .unwrap_or_default(),
+                        repository.html_url,
+                        repository
+                            .description
+                            .as_deref()
+                            .filter(|description| !description.is_empty())
+                            .map(|description| format!("\n  {}", description))
+                            .unwrap_or_default()
+                    )
+                }));
+                lines.extend(paging_notes(&results));
+                Ok(success_result(lines.join("\n"), &results))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!(
+                    "Failed to search repositories: {}",
+                    e
+                ))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn search_users(
+        github_client: &GitHubClient,
+        query: String,
+        sort: Option<String>,
+        order: Option<String>,
+        page: Option<u32>,
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        let sort = sort
+            .map(|sort| {
+                sort.parse::<UserSearchSort>().map_err(|_| {
+                    McpError::invalid_request(
+                        format!(
+                            "Invalid sort '{}'. Must be one of: followers, repositories, joined",
+                            sort
+                        ),
+                        None,
+                    )
+                })
+            })
+            .transpose()?;
+        let order = parse_order(order)?;
+
+        match functions::search::search_users(
+            github_client,
+            &query,
+            sort,
+            order,
+            Pagination::new(page, per_page),
+        )
+        .await
+        {
+            Ok(results) => {
+                let mut lines = vec![summary_line("users and organizations", &results)];
+                lines.extend(results.page.items.iter().map(|user| {
+                    format!("- {} [{}] {}", user.login, user.account_type, user.html_url)
+                }));
+                lines.extend(paging_notes(&results));
+                Ok(success_result(lines.join("\n"), &results))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to search users: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+}
+
+/// Describe the number of matches and the returned page of a search
+fn summary_line<T>(kind: &str, results: &SearchResults<T>) -> String {
+    format!(
+        "Found {} {}{} (page {}, {} result(s)):",
+        results.total_count,
+        kind,
+        if results.incomplete_results {
+            ", results may be incomplete"
+        } else {
+            ""
+        },
+        results.page.page,
+        results.page.items.len()
+    )
+}
+
+/// Notes on further pages and the result limit of the search API
+fn paging_notes<T>(results: &SearchResults<T>) -> Vec<String> {
+    let mut notes = Vec::new();
+    if results.page.has_next_page {
+        notes.push(format!(
+            "More results available on page {}",
+            results.page.page + 1
+        ));
+    }
+    if results.total_count > MAX_SEARCH_RESULTS {
+        notes.push(format!(
+            "Only the first {} results can be paged through; narrow the query to see the rest",
+            MAX_SEARCH_RESULTS
+        ));
+    }
+    notes
+}
+
+fn parse_order(order: Option<String>) -> Result<Option<SearchOrder>, McpError> {
+    order
+        .map(|order| {
+            order.parse::<SearchOrder>().map_err(|_| {
+                McpError::invalid_request(
+                    format!("Invalid order '{}'. Must be one of: asc, desc", order),
+                    None,
+                )
+            })
+        })
+        .transpose()
+}
@@ -0,0 +1,218 @@
+//! GitHub Actions secret and variable tool definitions
+//!
+//! This module contains MCP tool implementations for rotating repository
+//! secrets and managing configuration variables used by workflows.
+
+use crate::github::GitHubClient;
+use crate::tools::functions;
+use crate::types::pagination::Pagination;
+use crate::types::repository::{RepositoryId, RepositoryUrl};
+use crate::types::secret::validate_actions_name;
+
+use crate::tools::tool_definition::success_result;
+use rmcp::{Error as McpError, model::*};
+use serde_json::json;
+
+/// GitHub Actions secret and variable tools implementation
+pub struct SecretTools;
+
+impl SecretTools {
+    pub async fn set_repo_secret(
+        github_client: &GitHubClient,
+        repository_url: String,
+        name: String,
+        value: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+        validate_name(&name)?;
+
+        match functions::secret::set_repo_secret(github_client, &repo_id, &name, &value).await {
+            Ok(()) => Ok(success_result(
+                format!("Set secret {}", name),
+                &json!({"name": name, "set": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to set secret: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn delete_repo_secret(
+        github_client: &GitHubClient,
+        repository_url: String,
+        name: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::secret::delete_repo_secret(github_client, &repo_id, &name).await {
+            Ok(()) => Ok(success_result(
+                format!("Deleted secret {}", name),
+                &json!({"name": name, "deleted": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to delete secret: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn list_secrets(
+        github_client: &GitHubClient,
+        repository_url: String,
+        page: Option<u32>,
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url.clone())?;
+
+        match functions::secret::list_secrets(
+            github_client,
+            &repo_id,
+            Pagination::new(page, per_page),
+        )
+        .await
+        {
+            Ok(secrets) => {
+                let mut lines = vec![format!(
+                    "Secrets of {} (page {}, {} secret(s)):",
+                    repository_url,
+                    secrets.page,
+                    secrets.items.len()
+                )];
+                lines.extend(secrets.items.iter().map(|secret| {
+                    format!(
+                        "- {} (updated {})",
+                        secret.name,
+                        secret.updated_at.to_rfc3339()
+                    )
+                }));
+                if secrets.has_next_page {
+                    lines.push(format!(
+                        "More secrets available on page {}",
+                        secrets.page + 1
+                    ));
+                }
+                Ok(success_result(lines.join("\n"), &secrets))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to list secrets: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn list_variables(
+        github_client: &GitHubClient,
+        repository_url: String,
+        page: Option<u32>,
+        per_page: Option<u8>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url.clone())?;
+
+        match functions::secret::list_variables(
+            github_client,
+            &repo_id,
+            Pagination::new(page, per_page),
+        )
+        .await
+        {
+            Ok(variables) => {
+                let mut lines = vec![format!(
+                    "Variables of {} (page {}, {} variable(s)):",
+                    repository_url,
+                    variables.page,
+                    variables.items.len()
+                )];
+                lines.extend(
+                    variables
+                        .items
+                        .iter()
+                        .map(|variable| format!("- {}={}", variable.name, variable.value)),
+                );
+                if variables.has_next_page {
+                    lines.push(format!(
+                        "More variables available on page {}",
+                        variables.page + 1
+                    ));
+                }
+                Ok(success_result(lines.join("\n"), &variables))
+            }
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to list variables: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn get_variable(
+        github_client: &GitHubClient,
+        repository_url: String,
+        name: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::secret::get_variable(github_client, &repo_id, &name).await {
+            Ok(variable) => Ok(success_result(
+                format!("{}={}", variable.name, variable.value),
+                &variable,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to get variable: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn set_variable(
+        github_client: &GitHubClient,
+        repository_url: String,
+        name: String,
+        value: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+        validate_name(&name)?;
+
+        match functions::secret::set_variable(github_client, &repo_id, &name, &value).await {
+            Ok(created) => Ok(success_result(
+                format!(
+                    "{} variable {}",
+                    if created { "Created" } else { "Updated" },
+                    name
+                ),
+                &created,
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to set variable: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+
+    pub async fn delete_variable(
+        github_client: &GitHubClient,
+        repository_url: String,
+        name: String,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_id = parse_repository_url(repository_url)?;
+
+        match functions::secret::delete_variable(github_client, &repo_id, &name).await {
+            Ok(()) => Ok(success_result(
+                format!("Deleted variable {}", name),
+                &json!({"name": name, "deleted": true}),
+            )),
+            Err(e) => Ok(CallToolResult {
+                content: vec![Content::text(format!("Failed to delete variable: {}", e))],
+                is_error: Some(true),
+            }),
+        }
+    }
+}
+
+fn parse_repository_url(repository_url: String) -> Result<RepositoryId, McpError> {
+    RepositoryId::parse_url(&RepositoryUrl(repository_url))
+        .map_err(|e| McpError::invalid_request(format!("Invalid repository URL: {}", e), None))
+}
+
+fn validate_name(name: &str) -> Result<(), McpError> {
+    validate_actions_name(name).map_err(|e| McpError::invalid_request(e, None))
+}
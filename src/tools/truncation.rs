@@ -0,0 +1,248 @@
+//! Response size limits of the MCP tools
+//!
+//! Tool responses whose text is longer than the configured maximum are cut,
+//! preferably at a line break, and end with a truncation marker naming a
+//! continuation token. `get_response_continuation` returns the next part of
+//! the response for that token, cut the same way and with a new token while
+//! more remains. Tokens are single use and expire after
+//! `CONTINUATION_TOKEN_TTL`.
+
+use crate::config::DEFAULT_MAX_RESPONSE_CHARS;
+use crate::types::truncation::ContinuationRequest;
+
+use chrono::{DateTime, Utc};
+use rmcp::handler::server::tool::cached_schema_for_type;
+use rmcp::{Error as McpError, model::*};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Name of the continuation tool
+pub const GET_RESPONSE_CONTINUATION_TOOL: &str = "get_response_continuation";
+
+/// How long the rest of a truncated response is kept
+pub const CONTINUATION_TOKEN_TTL: chrono::Duration = chrono::Duration::minutes(10);
+
+/// Number of truncated responses kept per session; the oldest is dropped
+/// when another one is truncated
+const MAX_PENDING_CONTINUATIONS: usize = 20;
+
+/// Returns the continuation tool as listed to clients
+pub fn get_response_continuation_tool() -> Tool {
+    Tool::new(
+        GET_RESPONSE_CONTINUATION_TOOL,
+        "Get the next part of a truncated tool response. Pass the continuation_token from the truncation marker at the end of the previous part",
+        cached_schema_for_type::<ContinuationRequest>(),
+    )
+}
+
+/// Limits the size of the tool responses of one session
+#[derive(Clone)]
+pub struct ResponseLimiter {
+    max_chars: usize,
+    pending: Arc<Mutex<HashMap<String, PendingContinuation>>>,
+}
+
+/// Rest of a truncated response
+struct PendingContinuation {
+    tool: String,
+    parts: Vec<String>,
+    is_error: Option<bool>,
+    /// Characters of the response returned before `parts`
+    offset: usize,
+    /// Characters of the whole response
+    total: usize,
+    expires_at: DateTime<Utc>,
+}
+
+impl Default for ResponseLimiter {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_RESPONSE_CHARS)
+    }
+}
+
+impl ResponseLimiter {
+    /// Create a limiter cutting responses after `max_chars` characters
+    pub fn new(max_chars: usize) -> Self {
+        Self {
+            max_chars: max_chars.max(1),
+            pending: Arc::default(),
+        }
+    }
+
+    /// Returns the maximum characters of a response
+    pub fn max_chars(&self) -> usize {
+        self.max_chars
+    }
+
+    /// Cut the result of `tool` to the maximum size
+    ///
+    /// Results within the limit are returned unchanged. Otherwise the text
+    /// content is cut, the rest is kept under a new continuation token and a
+    /// truncation marker naming the token is appended as the last content.
+    /// Non-text content is kept as is and does not count towards the limit.
+    pub fn limit(&self, tool: &str, result: CallToolResult) -> CallToolResult {
+        let total: usize = result
+            .content
+            .iter()
+            .filter_map(|content| content.as_text())
+            .map(|text| text.text.chars().count())
+            .sum();
+        if total <= self.max_chars {
+            return result;
+        }
+        self.cut(tool, result.content, result.is_error, 0, total)
+    }
+
+    /// Return the next part of a truncated response
+    ///
+    /// # Errors
+    /// Returns an error if the arguments have no continuation token, or the
+    /// token is unknown, already used or expired
+    pub fn continue_response(
+        &self,
+        arguments: Option<JsonObject>,
+    ) -> Result<CallToolResult, McpError> {
+        let arguments = serde_json::Value::Object(arguments.unwrap_or_default());
+        let request: ContinuationRequest = serde_json::from_value(arguments).map_err(|e| {
+            McpError::invalid_params(
+                format!(
+                    "Invalid {} arguments: {}",
+                    GET_RESPONSE_CONTINUATION_TOOL, e
+                ),
+                None,
+            )
+        })?;
+
+        let continuation = {
+            let mut pending = self.lock();
+            pending.retain(|_, continuation| continuation.expires_at > Utc::now());
+            pending.remove(&request.continuation_token)
+        };
+        let Some(continuation) = continuation else {
+            return Err(McpError::invalid_params(
+                "Unknown, already used or expired continuation token. Call the tool again to get a new one".to_string(),
+                None,
+            ));
+        };
+
+        let content = continuation
+            .parts
+            .into_iter()
+            .map(Content::text)
+            .collect::<Vec<_>>();
+        let remaining = continuation.total - continuation.offset;
+        if remaining <= self.max_chars {
+            return Ok(CallToolResult {
+                content,
+                is_error: continuation.is_error,
+            });
+        }
+        Ok(self.cut(
+            &continuation.tool,
+            content,
+            continuation.is_error,
+            continuation.offset,
+            continuation.total,
+        ))
+    }
+
+    /// Keep the first `max_chars` characters of the text of `content` and
+    /// store the rest under a continuation token
+    fn cut(
+        &self,
+        tool: &str,
+        content: Vec<Content>,
+        is_error: Option<bool>,
+        offset: usize,
+        total: usize,
+    ) -> CallToolResult {
+        let mut budget = self.max_chars;
+        let mut shown = Vec::with_capacity(content.len() + 1);
+        let mut parts = Vec::new();
+
+        for content in content {
+            let Some(text) = content.as_text().map(|text| text.text.clone()) else {
+                shown.push(content);
+                continue;
+            };
+            if !parts.is_empty() {
+                parts.push(text);
+                continue;
+            }
+            let chars = text.chars().count();
+            if chars <= budget {
+                budget -= chars;
+                shown.push(content);
+                continue;
+            }
+            let (head, tail) = split_text(&text, budget);
+            budget -= head.chars().count();
+            if !head.is_empty() {
+                shown.push(Content::text(head));
+            }
+            parts.push(tail.to_string());
+        }
+
+        let end = offset + self.max_chars - budget;
+        let (token, expires_at) = self.store(PendingContinuation {
+            tool: tool.to_string(),
+            parts,
+            is_error,
+            offset: end,
+            total,
+            expires_at: Utc::now() + CONTINUATION_TOKEN_TTL,
+        });
+        shown.push(Content::text(format!(
+            "[Truncated {} response: characters {}-{} of {} shown. Call {} with continuation_token \"{}\" before {} to get the rest]",
+            tool,
+            offset + 1,
+            end,
+            total,
+            GET_RESPONSE_CONTINUATION_TOOL,
+            token,
+            expires_at.to_rfc3339()
+        )));
+        CallToolResult {
+            content: shown,
+            is_error,
+        }
+    }
+
+    fn store(&self, continuation: PendingContinuation) -> (String, DateTime<Utc>) {
+        let token = uuid::Uuid::new_v4().simple().to_string();
+        let expires_at = continuation.expires_at;
+        let mut pending = self.lock();
+        pending.retain(|_, continuation| continuation.expires_at > Utc::now());
+        while pending.len() >= MAX_PENDING_CONTINUATIONS {
+            let Some(oldest) = pending
+                .iter()
+                .min_by_key(|(_, continuation)| continuation.expires_at)
+                .map(|(token, _)| token.clone())
+            else {
+                break;
+            };
+            pending.remove(&oldest);
+        }
+        pending.insert(token.clone(), continuation);
+        (token, expires_at)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, PendingContinuation>> {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Split `text` after at most `max_chars` characters, at the last line break
+/// if one is in the second half of the kept text
+fn split_text(text: &str, max_chars: usize) -> (&str, &str) {
+    let end = text
+        .char_indices()
+        .nth(max_chars)
+        .map(|(index, _)| index)
+        .unwrap_or(text.len());
+    let end = match text[..end].rfind('\n') {
+        Some(line_end) if line_end + 1 >= end / 2 && line_end + 1 < end => line_end + 1,
+        _ => end,
+    };
+    text.split_at(end)
+}
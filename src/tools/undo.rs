@@ -0,0 +1,344 @@
+//! Undo of audited MCP tool calls
+//!
+//! Before a reversible tool call runs, the state it changes is read from
+//! GitHub and the call restoring that state is stored with its audit entry:
+//! the previous title or body of an issue or pull request, the previous
+//! state of an issue with its state reason, and the labels actually added or removed. The
+//! `undo_last_operation` tool runs the stored call of the newest operation
+//! of the token's account that succeeded and is not undone yet, or of the
+//! operation with a given trace ID. Operations without a stored call, such
+//! as creations and deletions, are reported as irreversible with the reason.
+
+use crate::github::GitHubClient;
+use crate::tools::annotations::{ToolEffect, tool_effect};
+use crate::tools::session::REPOSITORY_URL_ARGUMENT;
+use crate::types::audit::{AuditEntry, AuditSource, UndoOperation, UndoRequest};
+use crate::types::issue::{IssueNumber, IssueState, IssueStateReason};
+use crate::types::pull_request::{PullRequestNumber, PullRequestState};
+use crate::types::repository::{RepositoryId, RepositoryUrl};
+
+use anyhow::Result;
+use rmcp::handler::server::tool::cached_schema_for_type;
+use rmcp::model::*;
+use std::collections::HashSet;
+
+/// Name of the undo tool
+pub const UNDO_LAST_OPERATION_TOOL: &str = "undo_last_operation";
+
+/// Tools whose calls store the call reversing them
+pub const REVERSIBLE_TOOLS: [&str; 9] = [
+    "edit_issue_title",
+    "edit_issue_body",
+    "update_issue_state",
+    "add_labels_to_issue",
+    "remove_labels_from_issue",
+    "edit_pull_request_title",
+    "edit_pull_request_body",
+    "add_labels_to_pull_request",
+    "remove_labels_from_pull_request",
+];
+
+/// Returns the undo tool as listed to clients
+pub fn undo_last_operation_tool() -> Tool {
+    Tool::new(
+        UNDO_LAST_OPERATION_TOOL,
+        "Undo the newest operation of the token's account recorded in the audit log, or the operation with the given trace ID: re-add removed labels, remove added labels, reopen or close an issue again, or restore the previous title or body. Creations, deletions and other operations that cannot be reversed are reported with the reason",
+        cached_schema_for_type::<UndoRequest>(),
+    )
+}
+
+/// State of an issue or pull request before a reversible call
+struct Snapshot {
+    title: String,
+    body: Option<String>,
+    open: bool,
+    /// Why the issue was closed, for closed issues
+    state_reason: Option<IssueStateReason>,
+    labels: Vec<String>,
+}
+
+/// Returns the call reversing the call of `tool`, read from the current
+/// state on GitHub, or `None` if the call is not reversible
+///
+/// Calls omitting `repository_url` use `default_repository`. Dry runs and
+/// calls that would change nothing have no reversing call. Failing to read
+/// the state is logged, and the call is then recorded without one.
+pub async fn prepare_undo(
+    github_client: &GitHubClient,
+    tool: &str,
+    arguments: Option<&JsonObject>,
+    default_repository: Option<RepositoryId>,
+) -> Option<UndoOperation> {
+    let arguments = arguments?;
+    if !REVERSIBLE_TOOLS.contains(&tool)
+        || arguments.get("dry_run").and_then(|value| value.as_bool()) == Some(true)
+    {
+        return None;
+    }
+    let repository = match arguments
+        .get(REPOSITORY_URL_ARGUMENT)
+        .and_then(|value| value.as_str())
+    {
+        Some(url) => RepositoryId::parse_url(&RepositoryUrl(url.to_string())).ok()?,
+        None => default_repository?,
+    };
+
+    match read_snapshot(github_client, tool, arguments, &repository).await {
+        Ok(Some((number_argument, snapshot))) => {
+            let (inverse_tool, mut inverse_arguments) = inverse_call(tool, arguments, &snapshot)?;
+            inverse_arguments.insert(
+                REPOSITORY_URL_ARGUMENT.to_string(),
+                serde_json::Value::String(repository.url()),
+            );
+            inverse_arguments.insert(
+                number_argument.to_string(),
+                arguments.get(number_argument).cloned()?,
+            );
+            Some(UndoOperation {
+                tool: inverse_tool.to_string(),
+                arguments: serde_json::Value::Object(inverse_arguments),
+            })
+        }
+        Ok(None) => None,
+        Err(e) => {
+            tracing::warn!("Failed to read the state before {} for undo: {:#}", tool, e);
+            None
+        }
+    }
+}
+
+async fn read_snapshot(
+    github_client: &GitHubClient,
+    tool: &str,
+    arguments: &JsonObject,
+    repository: &RepositoryId,
+) -> Result<Option<(&'static str, Snapshot)>> {
+    if !tool.contains("pull_request") {
+        let Some(number) = number_argument(arguments, "issue_number") else {
+            return Ok(None);
+        };
+        let issue = github_client
+            .get_issue_with_comment_limit(repository, IssueNumber::new(number), Some(0))
+            .await?;
+        return Ok(Some((
+            "issue_number",
+            Snapshot {
+                title: issue.title,
+                body: issue.body,
+                open: issue.state == IssueState::Open,
+                state_reason: issue.state_reason,
+                labels: issue.labels,
+            },
+        )));
+    }
+
+    let Some(number) = number_argument(arguments, "pr_number") else {
+        return Ok(None);
+    };
+    let pull_request = github_client
+        .get_pull_request_with_comment_limit(repository, PullRequestNumber::new(number), Some(0))
+        .await?;
+    Ok(Some((
+        "pr_number",
+        Snapshot {
+            title: pull_request.title,
+            body: pull_request.body,
+            open: pull_request.state == PullRequestState::Open,
+            state_reason: None,
+            labels: pull_request
+                .labels
+                .into_iter()
+                .map(|label| label.name)
+                .collect(),
+        },
+    )))
+}
+
+/// Returns the tool and arguments, other than the repository and number,
+/// restoring `snapshot` after the call of `tool`
+fn inverse_call<'a>(
+    tool: &'a str,
+    arguments: &JsonObject,
+    snapshot: &Snapshot,
+) -> Option<(&'a str, JsonObject)> {
+    let mut inverse = JsonObject::new();
+    let inverse_tool = match tool {
+        "edit_issue_title" | "edit_pull_request_title" => {
+            inverse.insert("title".to_string(), snapshot.title.clone().into());
+            tool
+        }
+        "edit_issue_body" | "edit_pull_request_body" => {
+            let body = snapshot.body.clone().unwrap_or_default();
+            inverse.insert("body".to_string(), body.into());
+            tool
+        }
+        "update_issue_state" => {
+            let requested_open = arguments
+                .get("state")
+                .and_then(|state| state.as_str())?
+                .eq_ignore_ascii_case("open");
+            if requested_open == snapshot.open {
+                return None;
+            }
+            if snapshot.open {
+                inverse.insert("state".to_string(), "open".into());
+                inverse.insert("state_reason".to_string(), "reopened".into());
+            } else {
+                // Closed again for the reason it was closed with before
+                inverse.insert("state".to_string(), "closed".into());
+                if let Some(reason) = snapshot
+                    .state_reason
+                    .filter(|reason| reason.is_valid_for(IssueState::Closed))
+                {
+                    inverse.insert("state_reason".to_string(), reason.to_string().into());
+                }
+            }
+            tool
+        }
+        "add_labels_to_issue" | "add_labels_to_pull_request" => {
+            // Only the labels the call added are removed again
+            let added: Vec<String> = label_argument(arguments)
+                .into_iter()
+                .filter(|label| !contains_label(&snapshot.labels, label))
+                .collect();
+            if added.is_empty() {
+                return None;
+            }
+            inverse.insert("labels".to_string(), added.into());
+            if tool == "add_labels_to_issue" {
+                "remove_labels_from_issue"
+            } else {
+                "remove_labels_from_pull_request"
+            }
+        }
+        "remove_labels_from_issue" | "remove_labels_from_pull_request" => {
+            // Only the labels the item had are added again, named as before
+            let requested = label_argument(arguments);
+            let removed: Vec<String> = snapshot
+                .labels
+                .iter()
+                .filter(|label| contains_label(&requested, label))
+                .cloned()
+                .collect();
+            if removed.is_empty() {
+                return None;
+            }
+            inverse.insert("labels".to_string(), removed.into());
+            if tool == "remove_labels_from_issue" {
+                "add_labels_to_issue"
+            } else {
+                "add_labels_to_pull_request"
+            }
+        }
+        _ => return None,
+    };
+    Some((inverse_tool, inverse))
+}
+
+fn number_argument(arguments: &JsonObject, name: &str) -> Option<u32> {
+    arguments
+        .get(name)
+        .and_then(|number| number.as_u64())
+        .and_then(|number| u32::try_from(number).ok())
+}
+
+fn label_argument(arguments: &JsonObject) -> Vec<String> {
+    arguments
+        .get("labels")
+        .and_then(|labels| labels.as_array())
+        .map(|labels| {
+            labels
+                .iter()
+                .filter_map(|label| label.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Label names are case-insensitive on GitHub
+fn contains_label(labels: &[String], label: &str) -> bool {
+    labels.iter().any(|name| name.eq_ignore_ascii_case(label))
+}
+
+/// Returns the operation `undo_last_operation` reverses among `entries`,
+/// the audit entries of the token's account, oldest first
+///
+/// Only MCP tool calls can be undone, as only they record a trace ID. Without
+/// `trace_id` the newest operation that succeeded and is not undone yet is
+/// chosen, whether it is reversible or not.
+///
+/// # Errors
+/// Returns an error message if there is no such operation, or the operation
+/// with `trace_id` failed or is already undone
+pub fn find_operation<'a>(
+    entries: &'a [AuditEntry],
+    trace_id: Option<&str>,
+) -> std::result::Result<&'a AuditEntry, String> {
+    let undone: HashSet<&str> = entries
+        .iter()
+        .filter(|entry| entry.success)
+        .filter_map(|entry| entry.undoes.as_deref())
+        .collect();
+    let mut operations = entries.iter().filter(|entry| {
+        entry.source == AuditSource::Mcp && entry.trace_id.is_some() && entry.undoes.is_none()
+    });
+    let is_undone = |entry: &AuditEntry| {
+        entry
+            .trace_id
+            .as_deref()
+            .is_some_and(|id| undone.contains(id))
+    };
+
+    let Some(trace_id) = trace_id else {
+        return operations
+            .rev()
+            .find(|entry| entry.success && !is_undone(entry))
+            .ok_or_else(|| "No operation left to undo in the audit log".to_string());
+    };
+    let entry = operations
+        .find(|entry| entry.trace_id.as_deref() == Some(trace_id))
+        .ok_or_else(|| format!("No operation with trace ID {} in the audit log", trace_id))?;
+    if !entry.success {
+        return Err(format!(
+            "{} (trace ID {}) failed, so there is no change to undo",
+            entry.tool, trace_id
+        ));
+    }
+    if is_undone(entry) {
+        return Err(format!(
+            "{} (trace ID {}) is already undone",
+            entry.tool, trace_id
+        ));
+    }
+    Ok(entry)
+}
+
+/// Returns why the operation of `entry`, which has no reversing call, cannot
+/// be undone
+pub fn irreversible_reason(entry: &AuditEntry) -> String {
+    let tool = entry.tool.as_str();
+    match (tool, tool_effect(tool)) {
+        ("close_pull_request", _) => {
+            "there is no tool to reopen a pull request; reopen it on GitHub".to_string()
+        }
+        (_, Some(ToolEffect::Create)) => format!(
+            "{} created something new, which undo does not delete; delete it if it is not wanted",
+            tool
+        ),
+        (_, Some(ToolEffect::Destructive)) => {
+            format!("{} deleted or overwrote data that cannot be restored", tool)
+        }
+        (_, Some(ToolEffect::Composite)) => format!(
+            "{} ran several operations; reverse their changes one by one",
+            tool
+        ),
+        _ if REVERSIBLE_TOOLS.contains(&tool) => format!(
+            "the state before {} was not recorded, or the call changed nothing",
+            tool
+        ),
+        _ => format!(
+            "{} is not reversible; only title, body, issue state and label changes of issues and pull requests are",
+            tool
+        ),
+    }
+}
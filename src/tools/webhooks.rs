@@ -0,0 +1,93 @@
+//! Webhook events of the MCP sessions
+//!
+//! When the server receives webhooks, each session forwards the events to
+//! its client as they arrive, so agents can react instead of polling. Every
+//! event is sent as an `info` log message of the `github-edit.webhooks`
+//! logger with the event as its data, subject to the level set with
+//! `logging/setLevel`. An event about an issue or pull request the client
+//! subscribed to as a resource also sends
+//! `notifications/resources/updated` right away, without waiting for the
+//! next poll.
+
+use crate::tools::logging::ToolCallLogger;
+use crate::tools::resources::ResourceSubscriptions;
+use crate::types::resource::ResourceUri;
+use crate::types::webhook::WebhookEvent;
+
+use rmcp::model::*;
+use rmcp::service::{Peer, RoleServer};
+use tokio::sync::broadcast;
+
+/// Name of the logger of the webhook event messages
+pub const WEBHOOK_LOGGER_NAME: &str = "github-edit.webhooks";
+
+/// Notifications sent to a client for one webhook event
+#[derive(Debug, Clone, Default)]
+pub struct WebhookNotifications {
+    pub message: Option<LoggingMessageNotificationParam>,
+    pub resource_updated: Option<ResourceUpdatedNotificationParam>,
+}
+
+/// Returns the notifications of `event` for the session of `logger` and
+/// `subscriptions`
+pub fn webhook_notifications(
+    event: &WebhookEvent,
+    logger: &ToolCallLogger,
+    subscriptions: &ResourceSubscriptions,
+) -> WebhookNotifications {
+    let message = logger
+        .is_enabled(&LoggingLevel::Info)
+        .then(|| LoggingMessageNotificationParam {
+            level: LoggingLevel::Info,
+            logger: Some(WEBHOOK_LOGGER_NAME.to_string()),
+            data: serde_json::to_value(event).unwrap_or_default(),
+        });
+    let resource_updated = event
+        .resource_uri
+        .as_deref()
+        .and_then(|uri| ResourceUri::parse(uri).ok())
+        .filter(|uri| subscriptions.is_subscribed(uri))
+        .map(|uri| ResourceUpdatedNotificationParam {
+            uri: uri.to_string(),
+        });
+    WebhookNotifications {
+        message,
+        resource_updated,
+    }
+}
+
+/// Send the webhook events of `events` to the client of `peer`
+///
+/// Stops once the client can no longer be notified or the listener stops. A
+/// session too slow to keep up skips the events it missed.
+pub async fn forward_webhook_events(
+    mut events: broadcast::Receiver<WebhookEvent>,
+    peer: Peer<RoleServer>,
+    logger: ToolCallLogger,
+    subscriptions: ResourceSubscriptions,
+) {
+    loop {
+        let event = match events.recv().await {
+            Ok(event) => event,
+            Err(broadcast::error::RecvError::Lagged(missed)) => {
+                tracing::warn!("Skipped {} webhook events of a slow session", missed);
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => return,
+        };
+
+        let notifications = webhook_notifications(&event, &logger, &subscriptions);
+        if let Some(message) = notifications.message {
+            if let Err(e) = peer.notify_logging_message(message).await {
+                tracing::debug!("Stopped forwarding webhook events: {}", e);
+                return;
+            }
+        }
+        if let Some(updated) = notifications.resource_updated {
+            if let Err(e) = peer.notify_resource_updated(updated).await {
+                tracing::debug!("Stopped forwarding webhook events: {}", e);
+                return;
+            }
+        }
+    }
+}
@@ -8,3 +8,6 @@ pub mod sse_server;
 
 /// Standard I/O transport for subprocess-based MCP communication
 pub mod stdio;
+
+/// HTTP listener receiving GitHub webhook deliveries
+pub mod webhook;
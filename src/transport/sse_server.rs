@@ -1,12 +1,18 @@
-use crate::{github::GitHubClient, tools::GitEditTools};
+use crate::{
+    audit::AuditLog, github::GitHubClient, tools::GitEditTools, types::webhook::WebhookEvent,
+};
 use anyhow::Result;
 use rmcp::transport::sse_server::SseServer;
 use std::net::SocketAddr;
+use tokio::sync::broadcast;
 
 pub struct SseServerApp {
     bind_addr: SocketAddr,
-    github_token: Option<String>,
+    github_client: GitHubClient,
     timezone: Option<String>,
+    max_response_chars: usize,
+    audit_log: Option<AuditLog>,
+    webhook_events: Option<broadcast::Sender<WebhookEvent>>,
 }
 
 impl SseServerApp {
@@ -15,20 +21,30 @@ impl SseServerApp {
     /// # Arguments
     ///
     /// * `bind_addr` - The socket address to bind the server to
-    /// * `github_token` - Optional GitHub personal access token for API authentication
+    /// * `github_client` - GitHub client configured with the token, API base URL,
+    ///   network settings and owner tokens, shared by all sessions
+    /// * `max_response_chars` - Maximum characters of a tool response
+    /// * `audit_log` - Audit log of the tool calls writing to GitHub, if enabled
+    /// * `webhook_events` - Events of the webhook listener forwarded to every session, if enabled
     ///
     /// # Returns
     ///
     /// Returns a new SseServerApp instance.
     pub fn new(
         bind_addr: SocketAddr,
-        github_token: Option<String>,
+        github_client: GitHubClient,
         timezone: Option<String>,
+        max_response_chars: usize,
+        audit_log: Option<AuditLog>,
+        webhook_events: Option<broadcast::Sender<WebhookEvent>>,
     ) -> Self {
         Self {
             bind_addr,
-            github_token,
+            github_client,
             timezone,
+            max_response_chars,
+            audit_log,
+            webhook_events,
         }
     }
 
@@ -48,17 +64,26 @@ impl SseServerApp {
     pub async fn serve(self) -> Result<()> {
         // Initialize the service before starting the server
         tracing::info!("Initializing GitInsight service before starting SSE server...");
-        let github_client = GitHubClient::new(self.github_token.clone(), None)?;
-        let init_service = GitEditTools::new(github_client);
+        let github_client = self.github_client;
+        let init_service = GitEditTools::new(github_client.clone());
         init_service.init().await?;
         tracing::info!("GitInsight service initialization complete");
 
         let sse_server = SseServer::serve(self.bind_addr).await?;
-        let github_token = self.github_token.clone();
         let _timezone = self.timezone.clone();
+        let max_response_chars = self.max_response_chars;
+        let audit_log = self.audit_log;
+        let webhook_events = self.webhook_events;
         let cancellation_token = sse_server.with_service(move || {
-            let github_client = GitHubClient::new(github_token.clone(), None).unwrap();
-            GitEditTools::new(github_client)
+            let mut service = GitEditTools::new(github_client.clone())
+                .with_max_response_chars(max_response_chars);
+            if let Some(log) = &audit_log {
+                service = service.with_audit_log(log.clone());
+            }
+            if let Some(events) = &webhook_events {
+                service = service.with_webhook_events(events.clone());
+            }
+            service
         });
 
         // Wait for Ctrl+C signal to gracefully shutdown
@@ -1,8 +1,11 @@
+use crate::audit::AuditLog;
 use crate::github::GitHubClient;
 use crate::tools::GitEditTools;
+use crate::types::webhook::WebhookEvent;
 use anyhow::Result;
 use rmcp::ServiceExt;
 use rmcp::transport::stdio;
+use tokio::sync::broadcast;
 
 /// Runs the MCP server in STDIN/STDOUT mode.
 ///
@@ -10,20 +13,30 @@ use rmcp::transport::stdio;
 /// communicating through standard input/output streams.
 ///
 /// # Arguments
-/// * `github_token` - Optional GitHub personal access token for API authentication
+/// * `github_client` - GitHub client configured with the token, API base URL,
+///   network settings and owner tokens
 /// * `_timezone` - Optional timezone for displaying dates (unused after GraphQL removal)
+/// * `max_response_chars` - Maximum characters of a tool response
+/// * `audit_log` - Audit log of the tool calls writing to GitHub, if enabled
+/// * `webhook_events` - Events of the webhook listener forwarded to the client, if enabled
 ///
 /// # Returns
 /// * `Result<()>` - Success when server shuts down cleanly, or error
 pub async fn run_stdio_server(
-    github_token: Option<String>,
+    github_client: GitHubClient,
     _timezone: Option<String>,
+    max_response_chars: usize,
+    audit_log: Option<AuditLog>,
+    webhook_events: Option<broadcast::Sender<WebhookEvent>>,
 ) -> Result<()> {
-    // Create GitHub client
-    let github_client = GitHubClient::new(github_token, None)?;
-
     // Create an instance of our GitHub code tools wrapper with the provided token
-    let service = GitEditTools::new(github_client);
+    let mut service = GitEditTools::new(github_client).with_max_response_chars(max_response_chars);
+    if let Some(log) = audit_log {
+        service = service.with_audit_log(log);
+    }
+    if let Some(events) = webhook_events {
+        service = service.with_webhook_events(events);
+    }
 
     // Initialize the service
     service.init().await?;
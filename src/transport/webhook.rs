@@ -0,0 +1,152 @@
+use crate::github::webhook::{
+    DELIVERY_HEADER, EVENT_HEADER, SIGNATURE_HEADER, parse_event, verify_signature,
+};
+use crate::types::webhook::WebhookEvent;
+use anyhow::{Context, Result};
+use axum::Router;
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::post;
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+use tokio_util::sync::CancellationToken;
+
+/// Path GitHub delivers webhooks to
+pub const WEBHOOK_PATH: &str = "/webhooks";
+
+/// Events buffered per MCP session; a session lagging further behind misses
+/// the oldest ones
+pub const WEBHOOK_EVENT_CAPACITY: usize = 256;
+
+/// Creates the channel the webhook listener sends its events to
+///
+/// Every MCP session subscribes to the returned sender.
+pub fn webhook_channel() -> broadcast::Sender<WebhookEvent> {
+    broadcast::channel(WEBHOOK_EVENT_CAPACITY).0
+}
+
+#[derive(Clone)]
+struct ListenerState {
+    secret: String,
+    events: broadcast::Sender<WebhookEvent>,
+}
+
+/// Binds a webhook listener to `address` and serves it in the background.
+///
+/// # Returns
+///
+/// Returns the channel of the received events and the token stopping the
+/// listener.
+///
+/// # Errors
+///
+/// Returns an error if the address is invalid or cannot be bound.
+pub async fn start_webhook_listener(
+    address: &str,
+    secret: String,
+) -> Result<(broadcast::Sender<WebhookEvent>, CancellationToken)> {
+    let listener = TcpListener::bind(address)
+        .await
+        .with_context(|| format!("Failed to bind the webhook listener to {}", address))?;
+    let events = webhook_channel();
+    let cancellation_token = CancellationToken::new();
+    let webhook_listener = WebhookListener::new(secret, events.clone());
+    let listener_token = cancellation_token.clone();
+    tokio::spawn(async move {
+        if let Err(e) = webhook_listener.serve(listener, listener_token).await {
+            tracing::error!("Webhook listener failed: {:#}", e);
+        }
+    });
+    Ok((events, cancellation_token))
+}
+
+pub struct WebhookListener {
+    secret: String,
+    events: broadcast::Sender<WebhookEvent>,
+}
+
+impl WebhookListener {
+    /// Creates a webhook listener.
+    ///
+    /// # Arguments
+    ///
+    /// * `secret` - Webhook secret the signatures of the deliveries are checked with
+    /// * `events` - Channel the accepted deliveries are sent to as events
+    ///
+    /// # Returns
+    ///
+    /// Returns a new WebhookListener instance.
+    pub fn new(secret: String, events: broadcast::Sender<WebhookEvent>) -> Self {
+        Self { secret, events }
+    }
+
+    /// Receives webhook deliveries at `WEBHOOK_PATH` until `cancellation_token`
+    /// is cancelled.
+    ///
+    /// Deliveries with a valid signature are answered with 202 Accepted and
+    /// sent to the connected MCP sessions; deliveries without one are
+    /// rejected with 401 Unauthorized, and malformed ones with 400 Bad Request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server fails while serving.
+    pub async fn serve(
+        self,
+        listener: TcpListener,
+        cancellation_token: CancellationToken,
+    ) -> Result<()> {
+        let state = ListenerState {
+            secret: self.secret,
+            events: self.events,
+        };
+        let router = Router::new()
+            .route(WEBHOOK_PATH, post(receive_delivery))
+            .with_state(state);
+        if let Ok(address) = listener.local_addr() {
+            tracing::info!(
+                "Receiving GitHub webhooks at http://{}{}",
+                address,
+                WEBHOOK_PATH
+            );
+        }
+        axum::serve(listener, router)
+            .with_graceful_shutdown(cancellation_token.cancelled_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+async fn receive_delivery(
+    State(state): State<ListenerState>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> (StatusCode, String) {
+    let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+
+    let Some(signature) = header(SIGNATURE_HEADER) else {
+        return (
+            StatusCode::UNAUTHORIZED,
+            format!("Missing {} header", SIGNATURE_HEADER),
+        );
+    };
+    if !verify_signature(&state.secret, &body, signature) {
+        tracing::warn!("Rejected a webhook delivery with an invalid signature");
+        return (StatusCode::UNAUTHORIZED, "Invalid signature".to_string());
+    }
+    let Some(event) = header(EVENT_HEADER) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!("Missing {} header", EVENT_HEADER),
+        );
+    };
+
+    let event = match parse_event(event, header(DELIVERY_HEADER), &body) {
+        Ok(event) => event,
+        Err(e) => return (StatusCode::BAD_REQUEST, format!("{:#}", e)),
+    };
+    tracing::info!("Received webhook: {}", event.summary);
+    // Without connected sessions there is nobody to notify
+    let _ = state.events.send(event);
+    (StatusCode::ACCEPTED, "Accepted".to_string())
+}
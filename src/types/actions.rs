@@ -0,0 +1,359 @@
+//! GitHub Actions domain types
+//!
+//! This module contains the types for GitHub Actions workflow runs and the
+//! filters and inputs used when listing or dispatching them, as well as
+//! their artifacts, logs and deployments waiting for review.
+
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::PathBuf;
+use strum::{Display, EnumString};
+
+use crate::types::check::{CheckConclusion, CheckRunStatus};
+use crate::types::pagination::Pagination;
+
+/// Numeric identifier of a workflow run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WorkflowRunId(pub u64);
+
+impl WorkflowRunId {
+    /// Create a new workflow run identifier
+    pub fn new(id: u64) -> Self {
+        Self(id)
+    }
+
+    /// Get the inner value
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl std::fmt::Display for WorkflowRunId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A run of a GitHub Actions workflow
+///
+/// Field names follow the REST API so runs deserialize directly from API
+/// responses. `status` is one of `queued`, `in_progress`, `completed`,
+/// `waiting`, `requested` or `pending`; `conclusion` is set once the run
+/// has completed (e.g. `success`, `failure`, `cancelled`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowRun {
+    pub id: WorkflowRunId,
+    pub name: Option<String>,
+    pub head_branch: Option<String>,
+    pub head_sha: String,
+    pub event: String,
+    pub status: Option<String>,
+    pub conclusion: Option<String>,
+    pub run_number: u64,
+    pub run_attempt: Option<u64>,
+    pub html_url: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WorkflowRun {
+    /// Returns true if the run has finished, regardless of its conclusion
+    pub fn is_completed(&self) -> bool {
+        self.status.as_deref() == Some("completed")
+    }
+}
+
+/// A job of a workflow run
+///
+/// Jobs are check runs, so they share the status and conclusion of check
+/// runs. Field names follow the REST API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowJob {
+    pub id: u64,
+    pub run_id: WorkflowRunId,
+    pub run_attempt: Option<u64>,
+    pub name: String,
+    pub status: CheckRunStatus,
+    pub conclusion: Option<CheckConclusion>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub html_url: Option<String>,
+    #[serde(default)]
+    pub steps: Vec<WorkflowJobStep>,
+}
+
+impl WorkflowJob {
+    /// Returns true if the job completed with a failing conclusion
+    pub fn is_failing(&self) -> bool {
+        self.conclusion
+            .is_some_and(|conclusion| conclusion.is_failure())
+    }
+
+    /// Returns the steps that completed with a failing conclusion
+    pub fn failed_steps(&self) -> Vec<&WorkflowJobStep> {
+        self.steps
+            .iter()
+            .filter(|step| {
+                step.conclusion
+                    .is_some_and(|conclusion| conclusion.is_failure())
+            })
+            .collect()
+    }
+}
+
+/// A step of a workflow job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowJobStep {
+    pub number: u64,
+    pub name: String,
+    pub status: CheckRunStatus,
+    pub conclusion: Option<CheckConclusion>,
+}
+
+/// Filter for listing workflow runs
+///
+/// Fields left as `None` do not restrict the listed runs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowRunFilter {
+    /// Workflow file name (e.g. "ci.yml") or numeric workflow ID
+    pub workflow: Option<String>,
+    /// Branch the runs were triggered for
+    pub branch: Option<String>,
+    /// Event that triggered the runs (e.g. "push", "workflow_dispatch")
+    pub event: Option<String>,
+    /// Run status or conclusion (e.g. "in_progress", "failure")
+    pub status: Option<String>,
+    /// Commit SHA the runs were triggered for
+    pub head_sha: Option<String>,
+}
+
+impl WorkflowRunFilter {
+    /// Build the query string for listing runs with this filter
+    pub fn to_query(&self, pagination: Pagination) -> String {
+        let mut params = Vec::new();
+        for (key, value) in [
+            ("branch", &self.branch),
+            ("event", &self.event),
+            ("status", &self.status),
+            ("head_sha", &self.head_sha),
+        ] {
+            if let Some(value) = value {
+                params.push(format!("{}={}", key, urlencoding::encode(value)));
+            }
+        }
+        params.push(format!("per_page={}", pagination.per_page));
+        params.push(format!("page={}", pagination.page));
+        params.join("&")
+    }
+}
+
+/// Parse `key=value` pairs into workflow dispatch inputs
+///
+/// # Errors
+/// Returns an error message for a pair without `=` or with an empty key.
+pub fn parse_workflow_inputs(pairs: &[String]) -> Result<BTreeMap<String, String>, String> {
+    pairs
+        .iter()
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() => {
+                Ok((key.trim().to_string(), value.to_string()))
+            }
+            _ => Err(format!(
+                "Invalid workflow input '{}'. Expected key=value",
+                pair
+            )),
+        })
+        .collect()
+}
+
+/// Numeric identifier of a workflow artifact
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ArtifactId(pub u64);
+
+impl ArtifactId {
+    /// Create a new artifact identifier
+    pub fn new(id: u64) -> Self {
+        Self(id)
+    }
+
+    /// Get the inner value
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl std::fmt::Display for ArtifactId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A file uploaded by a workflow run
+///
+/// Field names follow the REST API so artifacts deserialize directly from
+/// API responses. Expired artifacts are still listed but can no longer be
+/// downloaded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Artifact {
+    pub id: ArtifactId,
+    pub name: String,
+    pub size_in_bytes: u64,
+    pub expired: bool,
+    pub created_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// A single log file of a workflow run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowLogFile {
+    /// Path of the file inside the log archive (e.g. "build/3_Run tests.txt")
+    pub name: String,
+    pub content: String,
+}
+
+impl WorkflowLogFile {
+    /// Returns true if the file holds the full log of a job
+    ///
+    /// Log archives contain one top-level file per job and a directory per
+    /// job with the log of each step.
+    pub fn is_job_log(&self) -> bool {
+        !self.name.contains('/')
+    }
+
+    /// Returns the last `lines` lines of the log
+    pub fn tail(&self, lines: usize) -> &str {
+        if lines == 0 {
+            return "";
+        }
+        let content = self.content.trim_end_matches('\n');
+        match content.rmatch_indices('\n').nth(lines - 1) {
+            Some((index, _)) => &content[index + 1..],
+            None => content,
+        }
+    }
+}
+
+/// Select the files holding full job logs
+///
+/// Job logs repeat the step logs, so the step logs are only returned when
+/// the archive has no job logs.
+pub fn select_job_logs(files: &[WorkflowLogFile]) -> Vec<&WorkflowLogFile> {
+    let has_job_logs = files.iter().any(|file| file.is_job_log());
+    files
+        .iter()
+        .filter(|file| !has_job_logs || file.is_job_log())
+        .collect()
+}
+
+/// Logs of a workflow run, either extracted or saved as a zip archive
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorkflowRunLogs {
+    /// The log files extracted from the archive, sorted by name
+    Extracted(Vec<WorkflowLogFile>),
+    /// Path of the saved log archive
+    Saved(PathBuf),
+}
+
+/// Extract the log files from a workflow run log archive
+///
+/// Directory entries are skipped and invalid UTF-8 is replaced.
+///
+/// # Errors
+/// Returns an error if the archive is not a valid zip file.
+pub fn extract_log_files(archive: &[u8]) -> anyhow::Result<Vec<WorkflowLogFile>> {
+    let mut zip = zip::ZipArchive::new(std::io::Cursor::new(archive))?;
+    let mut files = Vec::new();
+    for index in 0..zip.len() {
+        let mut entry = zip.by_index(index)?;
+        if entry.is_dir() {
+            continue;
+        }
+        let mut content = Vec::new();
+        entry.read_to_end(&mut content)?;
+        files.push(WorkflowLogFile {
+            name: entry.name().to_string(),
+            content: String::from_utf8_lossy(&content).into_owned(),
+        });
+    }
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(files)
+}
+
+/// A deployment environment of a repository
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeploymentEnvironment {
+    pub id: u64,
+    pub name: String,
+    pub html_url: Option<String>,
+}
+
+/// The user or team account of a required deployment reviewer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeploymentReviewerAccount {
+    /// Login of a user reviewer
+    pub login: Option<String>,
+    /// Slug of a team reviewer
+    pub slug: Option<String>,
+}
+
+/// A user or team allowed to review a deployment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeploymentReviewer {
+    /// "User" or "Team"
+    #[serde(rename = "type")]
+    pub reviewer_type: String,
+    pub reviewer: DeploymentReviewerAccount,
+}
+
+impl DeploymentReviewer {
+    /// Returns the user login or team slug of the reviewer
+    pub fn name(&self) -> Option<&str> {
+        self.reviewer
+            .login
+            .as_deref()
+            .or(self.reviewer.slug.as_deref())
+    }
+}
+
+/// A deployment of a workflow run waiting on a protected environment
+///
+/// Field names follow the REST API so pending deployments deserialize
+/// directly from API responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingDeployment {
+    pub environment: DeploymentEnvironment,
+    /// Minutes to wait before the deployment may proceed
+    pub wait_timer: u64,
+    pub wait_timer_started_at: Option<DateTime<Utc>>,
+    /// Whether the authenticated user is a required reviewer of the environment
+    pub current_user_can_approve: bool,
+    #[serde(default)]
+    pub reviewers: Vec<DeploymentReviewer>,
+}
+
+/// Decision on deployments waiting on protected environments
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum, Display, EnumString,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum DeploymentReviewState {
+    #[value(name = "approved")]
+    Approved,
+    #[value(name = "rejected")]
+    Rejected,
+}
+
+/// A deployment created when pending deployments are reviewed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Deployment {
+    pub id: u64,
+    pub environment: String,
+    pub sha: String,
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    pub created_at: DateTime<Utc>,
+}
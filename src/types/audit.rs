@@ -0,0 +1,100 @@
+//! Audit log types
+//!
+//! Every operation of the MCP server or CLI that sends a write request to
+//! GitHub is recorded as one entry of the audit log, with who ran it, its
+//! parameters, its outcome and the write requests it sent. Reversible MCP
+//! tool calls also record the call that reverses them, which
+//! `undo_last_operation` runs.
+
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Interface an audited operation was run through
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditSource {
+    Mcp,
+    Cli,
+}
+
+impl std::fmt::Display for AuditSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Mcp => write!(f, "mcp"),
+            Self::Cli => write!(f, "cli"),
+        }
+    }
+}
+
+/// Write request sent to GitHub by an audited operation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditRequest {
+    /// HTTP method, e.g. `POST`
+    pub method: String,
+    /// Full API URL of the request
+    pub url: String,
+    /// Name of the GraphQL mutation, for requests to the GraphQL endpoint
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mutation: Option<String>,
+    /// HTTP status of the response, if one was received
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// Error that kept the request from being answered
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Entry of the audit log
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// When the operation finished
+    pub timestamp: DateTime<Utc>,
+    pub source: AuditSource,
+    /// Login of the GitHub account the token acts as, if it could be read
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+    /// Name of the MCP client, e.g. `claude-ai`, or `github-edit-cli`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client: Option<String>,
+    /// MCP tool name, or the CLI command such as `issue create`
+    pub tool: String,
+    /// Parameters of the operation, with secrets redacted and long values cut
+    pub parameters: serde_json::Value,
+    /// Whether the operation succeeded
+    pub success: bool,
+    /// Error message of a failed operation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Trace ID of the MCP tool call
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_id: Option<String>,
+    /// Write requests the operation sent, in order
+    pub requests: Vec<AuditRequest>,
+    /// Tool call restoring the state before the operation, if it is
+    /// reversible
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub undo: Option<UndoOperation>,
+    /// Trace ID of the operation this `undo_last_operation` call reversed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub undoes: Option<String>,
+}
+
+/// MCP tool call reversing an audited operation
+///
+/// The arguments hold the values recorded before the operation, such as a
+/// previous title or the labels removed, so they are never redacted or cut.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UndoOperation {
+    pub tool: String,
+    pub arguments: serde_json::Value,
+}
+
+/// Arguments of the `undo_last_operation` tool
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct UndoRequest {
+    /// Trace ID of the operation to undo (default: the newest operation of
+    /// the token's account that succeeded and is not undone yet)
+    #[serde(default)]
+    pub trace_id: Option<String>,
+}
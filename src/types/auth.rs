@@ -0,0 +1,236 @@
+//! Authentication status types
+//!
+//! This module describes the identity and OAuth scopes of the configured
+//! token, and which groups of tools need which classic token scopes, so the
+//! tools a token cannot use can be reported before they fail.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use strum::Display;
+
+/// Kind of a GitHub token, recognized by its prefix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum TokenKind {
+    /// Personal access token (classic), prefixed `ghp_`
+    Classic,
+    /// Fine-grained personal access token, prefixed `github_pat_`
+    FineGrained,
+    /// OAuth app token, e.g. from `gh auth login`, prefixed `gho_`
+    #[strum(serialize = "oauth")]
+    #[serde(rename = "oauth")]
+    OAuth,
+    /// GitHub App installation or user token, prefixed `ghs_` or `ghu_`
+    GitHubApp,
+    /// Token of an unrecognized format
+    Unknown,
+}
+
+impl TokenKind {
+    /// Determine the kind of a token from its prefix
+    pub fn from_token(token: &str) -> Self {
+        if token.starts_with("github_pat_") {
+            Self::FineGrained
+        } else if token.starts_with("ghp_") {
+            Self::Classic
+        } else if token.starts_with("gho_") {
+            Self::OAuth
+        } else if token.starts_with("ghs_") || token.starts_with("ghu_") {
+            Self::GitHubApp
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+/// Classic token scopes needed by a group of tools
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScopeRequirement {
+    /// Name of the group of tools
+    pub area: &'static str,
+    /// The tools of the group
+    pub tools: &'static str,
+    /// Scopes of which any one is sufficient
+    pub scopes: &'static [&'static str],
+}
+
+/// Classic token scopes needed by each group of tools
+///
+/// `public_repo` is accepted where it suffices for public repositories.
+pub const SCOPE_REQUIREMENTS: &[ScopeRequirement] = &[
+    ScopeRequirement {
+        area: "Issues, pull requests and repositories",
+        tools: "issue, pull request, comment, reaction, label, milestone, branch, release, file, commit, fork and repository settings tools",
+        scopes: &["repo", "public_repo"],
+    },
+    ScopeRequirement {
+        area: "Projects",
+        tools: "project and project item tools",
+        scopes: &["project"],
+    },
+    ScopeRequirement {
+        area: "Organization teams",
+        tools: "link_team_to_project, unlink_team_from_project",
+        scopes: &["read:org"],
+    },
+    ScopeRequirement {
+        area: "GitHub Actions",
+        tools: "workflow dispatch, run, job, deployment, log and artifact tools",
+        scopes: &["repo", "public_repo"],
+    },
+    ScopeRequirement {
+        area: "Workflow files",
+        tools: "create_or_update_file and delete_file on .github/workflows",
+        scopes: &["workflow"],
+    },
+    ScopeRequirement {
+        area: "Commit statuses",
+        tools: "create_commit_status",
+        scopes: &["repo:status", "public_repo"],
+    },
+    ScopeRequirement {
+        area: "Secrets and variables",
+        tools: "secret and variable tools",
+        scopes: &["repo"],
+    },
+    ScopeRequirement {
+        area: "Discussions",
+        tools: "discussion tools",
+        scopes: &["repo", "public_repo", "write:discussion"],
+    },
+    ScopeRequirement {
+        area: "Notifications",
+        tools: "notification and subscription tools",
+        scopes: &["notifications", "repo"],
+    },
+];
+
+/// Scopes implied by a granted scope
+const IMPLIED_SCOPES: &[(&str, &[&str])] = &[
+    (
+        "repo",
+        &[
+            "public_repo",
+            "repo:status",
+            "repo_deployment",
+            "repo:invite",
+            "security_events",
+        ],
+    ),
+    ("admin:org", &["write:org", "read:org"]),
+    ("write:org", &["read:org"]),
+    ("project", &["read:project"]),
+    ("write:discussion", &["read:discussion"]),
+    ("admin:repo_hook", &["write:repo_hook", "read:repo_hook"]),
+    ("write:repo_hook", &["read:repo_hook"]),
+    ("user", &["read:user", "user:email", "user:follow"]),
+];
+
+/// Parse the comma separated scopes of an `X-OAuth-Scopes` header
+pub fn parse_scopes_header(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|scope| scope.trim())
+        .filter(|scope| !scope.is_empty())
+        .map(|scope| scope.to_string())
+        .collect()
+}
+
+/// Expand granted scopes with the scopes they imply
+pub fn expand_scopes(scopes: &[String]) -> BTreeSet<String> {
+    let mut expanded: BTreeSet<String> = scopes.iter().cloned().collect();
+    for scope in scopes {
+        if let Some((_, implied)) = IMPLIED_SCOPES.iter().find(|(name, _)| name == scope) {
+            expanded.extend(implied.iter().map(|implied| implied.to_string()));
+        }
+    }
+    expanded
+}
+
+/// Type of a GitHub account
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum AccountType {
+    User,
+    /// Bot account of a GitHub App, with a login ending in `[bot]`
+    Bot,
+    Organization,
+}
+
+impl AccountType {
+    /// Parse the `type` of an account as returned by the REST API
+    ///
+    /// Unrecognized types are treated as users.
+    pub fn from_api_type(value: &str) -> Self {
+        match value {
+            "Bot" => Self::Bot,
+            "Organization" => Self::Organization,
+            _ => Self::User,
+        }
+    }
+}
+
+/// Account the configured token acts as
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthenticatedUser {
+    /// Login to mention and assign, e.g. `octocat` or `my-app[bot]`
+    pub login: String,
+    /// Display name of the account
+    pub name: Option<String>,
+    pub account_type: AccountType,
+    /// Numeric ID of the account
+    pub id: u64,
+    /// Profile URL of the account
+    pub html_url: Option<String>,
+    /// Granted OAuth scopes; `None` for tokens without scopes, such as
+    /// fine-grained and GitHub App tokens
+    pub scopes: Option<Vec<String>>,
+}
+
+/// Identity and permissions of the configured token
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthStatus {
+    /// Login of the authenticated user or app
+    pub login: String,
+    /// Display name of the authenticated user
+    pub name: Option<String>,
+    /// Kind of the token
+    pub token_kind: TokenKind,
+    /// Granted OAuth scopes; `None` for tokens without scopes, such as
+    /// fine-grained and GitHub App tokens, whose permissions cannot be listed
+    pub scopes: Option<Vec<String>>,
+    /// Base URL of the REST API the token was verified against
+    pub api_base_url: String,
+}
+
+impl AuthStatus {
+    /// Returns the tool groups the token lacks the scopes for
+    ///
+    /// Empty when the token has no scopes to check.
+    pub fn unmet_requirements(&self) -> Vec<&'static ScopeRequirement> {
+        let Some(scopes) = &self.scopes else {
+            return Vec::new();
+        };
+        let granted = expand_scopes(scopes);
+        SCOPE_REQUIREMENTS
+            .iter()
+            .filter(|requirement| {
+                !requirement
+                    .scopes
+                    .iter()
+                    .any(|scope| granted.contains(*scope))
+            })
+            .collect()
+    }
+
+    /// Returns true if only public repositories can be written to because
+    /// `public_repo` is granted without `repo`
+    pub fn is_public_repo_only(&self) -> bool {
+        self.scopes.as_ref().is_some_and(|scopes| {
+            let granted = expand_scopes(scopes);
+            granted.contains("public_repo") && !granted.contains("repo")
+        })
+    }
+}
@@ -0,0 +1,67 @@
+//! Batch operation types
+//!
+//! The `execute_batch` MCP tool runs an ordered list of tool calls in one
+//! round trip and reports the outcome of each of them.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// One tool call of a batch
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct BatchOperation {
+    /// Name of the tool to call, e.g. `add_labels_to_issue`
+    pub tool: String,
+    /// Arguments of the tool call, as they would be passed to the tool
+    #[serde(default)]
+    pub params: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Arguments of the `execute_batch` tool
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct BatchRequest {
+    /// Tool calls to run, in order
+    pub operations: Vec<BatchOperation>,
+    /// Keep running the remaining operations after one fails. By default
+    /// the batch stops at the first failure and skips the rest
+    #[serde(default)]
+    pub continue_on_error: bool,
+}
+
+/// Outcome of one operation of a batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchOperationStatus {
+    /// The tool call succeeded
+    Succeeded,
+    /// The tool call failed or was rejected
+    Failed,
+    /// The operation was not run because an earlier one failed or the batch
+    /// was cancelled
+    Skipped,
+}
+
+/// Result of one operation of a batch
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchOperationResult {
+    /// Position of the operation in the batch, starting at 0
+    pub index: usize,
+    /// Name of the tool called
+    pub tool: String,
+    pub status: BatchOperationStatus,
+    /// Text summary or error message of the tool call
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Structured result of a successful tool call
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+}
+
+/// Consolidated report of a batch
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchReport {
+    pub succeeded: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    /// Results of all operations, in the order they were given
+    pub results: Vec<BatchOperationResult>,
+}
@@ -0,0 +1,41 @@
+//! Repository branch types
+//!
+//! This module provides the representation of repository branches returned by
+//! branch management operations.
+
+use serde::{Deserialize, Serialize};
+
+/// A branch of a repository and the commit it points to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryBranch {
+    pub name: String,
+    pub commit_sha: String,
+    pub protected: bool,
+}
+
+impl RepositoryBranch {
+    /// Create a new branch
+    pub fn new(name: String, commit_sha: String, protected: bool) -> Self {
+        Self {
+            name,
+            commit_sha,
+            protected,
+        }
+    }
+
+    /// Parse a branch from a REST API branch object
+    ///
+    /// Accepts both the short form returned by the branch list endpoint and the
+    /// full form returned by the single branch endpoints. Returns `None` if the
+    /// value lacks a name or commit SHA.
+    pub fn from_api_value(value: &serde_json::Value) -> Option<Self> {
+        Some(Self {
+            name: value.get("name")?.as_str()?.to_string(),
+            commit_sha: value.pointer("/commit/sha")?.as_str()?.to_string(),
+            protected: value
+                .get("protected")
+                .and_then(|p| p.as_bool())
+                .unwrap_or(false),
+        })
+    }
+}
@@ -0,0 +1,131 @@
+//! Check run and commit status domain types
+//!
+//! This module contains the types for the two kinds of CI signals GitHub
+//! attaches to commits: check runs created by GitHub Apps (including GitHub
+//! Actions) and commit statuses created through the statuses API.
+
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+/// Progress of a check run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CheckRunStatus {
+    Queued,
+    InProgress,
+    Completed,
+    Waiting,
+    Requested,
+    Pending,
+}
+
+/// Final result of a completed check run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CheckConclusion {
+    Success,
+    Failure,
+    Neutral,
+    Cancelled,
+    Skipped,
+    TimedOut,
+    ActionRequired,
+    Stale,
+    StartupFailure,
+}
+
+impl CheckConclusion {
+    /// Returns true if the conclusion blocks a pull request from passing CI
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Self::Failure
+                | Self::Cancelled
+                | Self::TimedOut
+                | Self::ActionRequired
+                | Self::StartupFailure
+        )
+    }
+}
+
+/// A check run on a commit
+///
+/// Field names follow the REST API so check runs deserialize directly from
+/// API responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckRun {
+    pub id: u64,
+    pub name: String,
+    pub head_sha: String,
+    pub status: CheckRunStatus,
+    pub conclusion: Option<CheckConclusion>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub html_url: Option<String>,
+    pub details_url: Option<String>,
+}
+
+impl CheckRun {
+    /// Returns true if the check run completed with a failing conclusion
+    pub fn is_failing(&self) -> bool {
+        self.conclusion
+            .is_some_and(|conclusion| conclusion.is_failure())
+    }
+}
+
+/// State of a commit status, also used for the combined state of a commit
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum, Display, EnumString,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum CommitState {
+    #[value(name = "error")]
+    Error,
+    #[value(name = "failure")]
+    Failure,
+    #[value(name = "pending")]
+    Pending,
+    #[value(name = "success")]
+    Success,
+}
+
+/// A status reported for a commit by an external service
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitStatus {
+    pub id: u64,
+    pub state: CommitState,
+    /// Label distinguishing this status from statuses of other services
+    pub context: String,
+    pub description: Option<String>,
+    pub target_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The latest status of each context on a commit, combined into one state
+///
+/// The combined state is `failure` if any context failed or errored,
+/// `pending` if any context is pending or none exists, and `success`
+/// otherwise. Check runs are not part of the combined status.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombinedStatus {
+    pub state: CommitState,
+    pub sha: String,
+    pub total_count: u64,
+    pub statuses: Vec<CommitStatus>,
+}
+
+impl CombinedStatus {
+    /// Returns the statuses that failed or errored
+    pub fn failing_statuses(&self) -> Vec<&CommitStatus> {
+        self.statuses
+            .iter()
+            .filter(|status| matches!(status.state, CommitState::Error | CommitState::Failure))
+            .collect()
+    }
+}
@@ -0,0 +1,129 @@
+//! Comment moderation types for issues and pull requests
+//!
+//! This module provides the comment targets shared by issues and pull
+//! requests and the classifiers GitHub accepts when hiding a comment.
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+use crate::types::issue::IssueCommentNumber;
+use crate::types::pull_request::PullRequestCommentNumber;
+
+/// Reason recorded when a comment is hidden (minimized).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumString, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+pub enum MinimizeReason {
+    /// Unsolicited advertising or other spam
+    Spam,
+    /// Abusive or harassing content
+    Abuse,
+    /// Not related to the discussion
+    OffTopic,
+    /// No longer accurate or relevant
+    Outdated,
+    /// Repeats an earlier comment
+    Duplicate,
+    /// The discussed matter has been resolved
+    Resolved,
+}
+
+impl MinimizeReason {
+    /// Returns the `ReportedContentClassifiers` value used by the GitHub GraphQL API
+    pub fn graphql_value(&self) -> &'static str {
+        match self {
+            Self::Spam => "SPAM",
+            Self::Abuse => "ABUSE",
+            Self::OffTopic => "OFF_TOPIC",
+            Self::Outdated => "OUTDATED",
+            Self::Duplicate => "DUPLICATE",
+            Self::Resolved => "RESOLVED",
+        }
+    }
+}
+
+/// Kind of comment a moderation operation applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum CommentTargetType {
+    /// A comment on an issue
+    IssueComment,
+    /// A discussion comment on a pull request
+    PullRequestComment,
+}
+
+/// Comment a moderation operation applies to.
+///
+/// Pull request discussion comments are issue comments on the GitHub API, so
+/// both kinds are addressed by their comment ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CommentTarget {
+    IssueComment(IssueCommentNumber),
+    PullRequestComment(PullRequestCommentNumber),
+}
+
+impl CommentTarget {
+    /// Build a target from its type and comment ID
+    pub fn new(target_type: CommentTargetType, comment_id: u64) -> Self {
+        match target_type {
+            CommentTargetType::IssueComment => {
+                Self::IssueComment(IssueCommentNumber::new(comment_id))
+            }
+            CommentTargetType::PullRequestComment => {
+                Self::PullRequestComment(PullRequestCommentNumber::new(comment_id))
+            }
+        }
+    }
+
+    /// Returns the comment ID
+    pub fn comment_id(&self) -> u64 {
+        match self {
+            Self::IssueComment(number) => number.value(),
+            Self::PullRequestComment(number) => number.value(),
+        }
+    }
+}
+
+impl std::fmt::Display for CommentTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IssueComment(number) => write!(f, "issue comment {}", number),
+            Self::PullRequestComment(number) => write!(f, "pull request comment {}", number),
+        }
+    }
+}
+
+/// Action on a comment that requires a permission
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum CommentAction {
+    /// Edit the comment body
+    Edit,
+    /// Delete the comment
+    Delete,
+    /// Hide or show the comment
+    Minimize,
+}
+
+/// Permissions of the authenticated user on a comment
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommentPermissions {
+    /// Login of the comment author, if the account still exists
+    pub author: Option<String>,
+    /// Whether the authenticated user wrote the comment
+    pub viewer_did_author: bool,
+    pub can_update: bool,
+    pub can_delete: bool,
+    pub can_minimize: bool,
+}
+
+impl CommentPermissions {
+    /// Returns true if the authenticated user may perform the action
+    pub fn allows(&self, action: CommentAction) -> bool {
+        match action {
+            CommentAction::Edit => self.can_update,
+            CommentAction::Delete => self.can_delete,
+            CommentAction::Minimize => self.can_minimize,
+        }
+    }
+}
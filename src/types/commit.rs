@@ -0,0 +1,156 @@
+//! Repository commit types
+//!
+//! This module provides the representation of commits returned by the
+//! commit listing and detail operations, and the filter used when listing
+//! the history of a repository.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::pagination::Pagination;
+
+/// Name, email and date recorded in a commit for its author or committer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GitActor {
+    pub name: String,
+    pub email: String,
+    pub date: DateTime<Utc>,
+}
+
+/// The git data of a commit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitData {
+    pub message: String,
+    pub author: Option<GitActor>,
+    pub committer: Option<GitActor>,
+}
+
+/// The GitHub account linked to a commit author or committer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitAccount {
+    pub login: String,
+}
+
+/// A parent of a commit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitParent {
+    pub sha: String,
+}
+
+/// Line changes of a commit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitStats {
+    pub additions: u64,
+    pub deletions: u64,
+    pub total: u64,
+}
+
+/// A file changed by a commit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitFile {
+    pub filename: String,
+    /// "added", "removed", "modified", "renamed", "copied", "changed" or "unchanged"
+    pub status: String,
+    pub additions: u64,
+    pub deletions: u64,
+    pub changes: u64,
+    /// Path of the file before it was renamed
+    pub previous_filename: Option<String>,
+    /// Unified diff of the file; omitted for binary and very large files
+    pub patch: Option<String>,
+}
+
+/// A commit of a repository
+///
+/// Field names follow the REST API so commits deserialize directly from API
+/// responses. `stats` and `files` are only returned when a single commit is
+/// fetched; the API returns at most 300 changed files.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Commit {
+    pub sha: String,
+    pub html_url: String,
+    pub commit: CommitData,
+    /// GitHub account of the author, if the author email is linked to one
+    pub author: Option<CommitAccount>,
+    #[serde(default)]
+    pub parents: Vec<CommitParent>,
+    pub stats: Option<CommitStats>,
+    #[serde(default)]
+    pub files: Vec<CommitFile>,
+}
+
+impl Commit {
+    /// Returns the abbreviated 7 character SHA
+    pub fn short_sha(&self) -> &str {
+        self.sha.get(..7).unwrap_or(&self.sha)
+    }
+
+    /// Returns the first line of the commit message
+    pub fn summary(&self) -> &str {
+        self.commit.message.lines().next().unwrap_or_default()
+    }
+
+    /// Returns the GitHub login of the author, or the git author name if the
+    /// author is not linked to an account
+    pub fn author_name(&self) -> Option<&str> {
+        self.author
+            .as_ref()
+            .map(|account| account.login.as_str())
+            .or_else(|| {
+                self.commit
+                    .author
+                    .as_ref()
+                    .map(|author| author.name.as_str())
+            })
+    }
+
+    /// Returns the date the commit was authored
+    pub fn authored_at(&self) -> Option<DateTime<Utc>> {
+        self.commit.author.as_ref().map(|author| author.date)
+    }
+
+    /// Returns true if the commit merges two or more parents
+    pub fn is_merge(&self) -> bool {
+        self.parents.len() > 1
+    }
+}
+
+/// Filter for listing the commits of a repository
+///
+/// Fields left as `None` do not restrict the listed commits.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitFilter {
+    /// Branch, tag or commit SHA to start listing from (default: the default branch)
+    pub branch: Option<String>,
+    /// Only commits touching this file or directory
+    pub path: Option<String>,
+    /// Only commits authored at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Only commits authored at or before this time
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl CommitFilter {
+    /// Build the query string for listing commits with this filter
+    pub fn to_query(&self, pagination: Pagination) -> String {
+        let mut params = Vec::new();
+        if let Some(branch) = &self.branch {
+            params.push(format!("sha={}", urlencoding::encode(branch)));
+        }
+        if let Some(path) = &self.path {
+            params.push(format!("path={}", urlencoding::encode(path)));
+        }
+        for (key, value) in [("since", &self.since), ("until", &self.until)] {
+            if let Some(value) = value {
+                params.push(format!(
+                    "{}={}",
+                    key,
+                    urlencoding::encode(&value.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+                ));
+            }
+        }
+        params.push(format!("per_page={}", pagination.per_page));
+        params.push(format!("page={}", pagination.page));
+        params.join("&")
+    }
+}
@@ -0,0 +1,35 @@
+//! Conditional request types
+//!
+//! This module provides the result of a conditional GET request, which
+//! GitHub answers with `304 Not Modified` when the resource still has the
+//! ETag given in `If-None-Match`. Such answers do not count against the
+//! rate limit, which makes them the way to poll for changes.
+
+use serde::{Deserialize, Serialize};
+
+/// Result of a conditional request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum Conditional<T> {
+    /// The resource changed, or no ETag was given
+    Modified {
+        value: T,
+        /// ETag of the returned resource, to send with the next request
+        etag: Option<String>,
+    },
+    /// The resource still has the given ETag
+    NotModified,
+}
+
+impl<T> Conditional<T> {
+    /// Apply `f` to the value of a modified resource
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Conditional<U> {
+        match self {
+            Conditional::Modified { value, etag } => Conditional::Modified {
+                value: f(value),
+                etag,
+            },
+            Conditional::NotModified => Conditional::NotModified,
+        }
+    }
+}
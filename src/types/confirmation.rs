@@ -0,0 +1,68 @@
+//! Confirmation types for destructive operations
+//!
+//! Deleting an issue, label, milestone or comment through the MCP tools
+//! takes two calls: the first describes what would be destroyed and returns
+//! a confirmation token, and only a second call passing that token deletes.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::comment::CommentTarget;
+use crate::types::issue::IssueNumber;
+use crate::types::repository::{MilestoneNumber, RepositoryId};
+
+/// Deletion guarded by a confirmation token
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DestructiveOperation {
+    DeleteIssue(RepositoryId, IssueNumber),
+    DeleteLabel(RepositoryId, String),
+    DeleteMilestone(RepositoryId, MilestoneNumber),
+    DeleteComment(RepositoryId, CommentTarget),
+}
+
+impl DestructiveOperation {
+    /// Returns the name of the tool performing the operation
+    pub fn tool_name(&self) -> &'static str {
+        match self {
+            Self::DeleteIssue(..) => "delete_issue",
+            Self::DeleteLabel(..) => "delete_label",
+            Self::DeleteMilestone(..) => "delete_milestone",
+            Self::DeleteComment(..) => "delete_comment",
+        }
+    }
+}
+
+impl std::fmt::Display for DestructiveOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DeleteIssue(repository_id, issue_number) => {
+                write!(f, "delete issue #{} in {}", issue_number, repository_id)
+            }
+            Self::DeleteLabel(repository_id, name) => {
+                write!(f, "delete label '{}' in {}", name, repository_id)
+            }
+            Self::DeleteMilestone(repository_id, milestone_number) => write!(
+                f,
+                "delete milestone {} in {}",
+                milestone_number.value(),
+                repository_id
+            ),
+            Self::DeleteComment(repository_id, target) => {
+                write!(f, "delete {} in {}", target, repository_id)
+            }
+        }
+    }
+}
+
+/// Result of the first call of a deletion, before anything is deleted
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfirmationRequest {
+    /// Token to pass as `confirmation_token` to perform the deletion
+    pub confirmation_token: String,
+    /// The operation the token confirms, e.g. `delete issue #42 in owner/repo`
+    pub operation: String,
+    /// What the deletion would destroy
+    pub description: String,
+    /// When the token stops being accepted
+    pub expires_at: DateTime<Utc>,
+}
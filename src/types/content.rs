@@ -0,0 +1,106 @@
+//! Repository file content types
+//!
+//! This module provides the file representations used by the Contents API
+//! operations: decoded file contents and the commits created by file edits.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde::{Deserialize, Serialize};
+
+/// A file of a repository with its decoded contents
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileContent {
+    pub path: String,
+    /// Blob SHA, required to update or delete the file
+    pub sha: String,
+    pub size: u64,
+    pub content: Vec<u8>,
+}
+
+impl FileContent {
+    /// Parse a file from a Contents API response
+    ///
+    /// # Errors
+    /// Returns an error message if the response describes a directory, a
+    /// file too large to be returned inline, or contains invalid base64.
+    pub fn from_api_value(value: &serde_json::Value) -> Result<Self, String> {
+        if value.is_array() {
+            return Err("Path is a directory, not a file".to_string());
+        }
+        let kind = value.get("type").and_then(|t| t.as_str()).unwrap_or("");
+        if kind != "file" {
+            return Err(format!("Path is a {}, not a file", kind));
+        }
+
+        let str_of = |key: &str| {
+            value
+                .get(key)
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| format!("Missing '{}' in file response", key))
+        };
+
+        let encoding = value.get("encoding").and_then(|e| e.as_str()).unwrap_or("");
+        if encoding != "base64" {
+            return Err(
+                "File is too large to be returned by the Contents API (over 1 MB)".to_string(),
+            );
+        }
+
+        Ok(Self {
+            path: str_of("path")?.to_string(),
+            sha: str_of("sha")?.to_string(),
+            size: value.get("size").and_then(|s| s.as_u64()).unwrap_or(0),
+            content: decode_content(str_of("content")?)?,
+        })
+    }
+
+    /// Returns the contents as text if they are valid UTF-8
+    pub fn text(&self) -> Option<&str> {
+        std::str::from_utf8(&self.content).ok()
+    }
+}
+
+/// Commit created by adding, updating or deleting a file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileCommit {
+    pub path: String,
+    /// Blob SHA of the new file contents; `None` after a deletion
+    pub content_sha: Option<String>,
+    pub commit_sha: String,
+    pub commit_url: Option<String>,
+}
+
+impl FileCommit {
+    /// Parse the result of a Contents API write from its response
+    ///
+    /// Returns `None` if the response has no commit SHA.
+    pub fn from_api_value(path: &str, value: &serde_json::Value) -> Option<Self> {
+        Some(Self {
+            path: path.to_string(),
+            content_sha: value
+                .pointer("/content/sha")
+                .and_then(|sha| sha.as_str())
+                .map(|sha| sha.to_string()),
+            commit_sha: value.pointer("/commit/sha")?.as_str()?.to_string(),
+            commit_url: value
+                .pointer("/commit/html_url")
+                .and_then(|url| url.as_str())
+                .map(|url| url.to_string()),
+        })
+    }
+}
+
+/// Encode file contents for the Contents API
+pub fn encode_content(content: &[u8]) -> String {
+    STANDARD.encode(content)
+}
+
+/// Decode file contents returned by the Contents API
+///
+/// The API wraps base64 content in lines, so whitespace is ignored.
+pub fn decode_content(encoded: &str) -> Result<Vec<u8>, String> {
+    let compact: String = encoded.split_whitespace().collect();
+    STANDARD
+        .decode(compact)
+        .map_err(|e| format!("Invalid base64 file content: {}", e))
+}
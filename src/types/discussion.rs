@@ -0,0 +1,238 @@
+//! GitHub Discussions domain types
+//!
+//! This module contains the types for repository discussions, their
+//! categories and their threaded comments. Discussions are only available
+//! through the GraphQL API, so the types are parsed from GraphQL objects.
+
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+/// Wrapper type for discussion numbers providing type safety
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DiscussionNumber(pub u32);
+
+impl DiscussionNumber {
+    /// Create a new discussion number
+    pub fn new(number: u32) -> Self {
+        Self(number)
+    }
+
+    /// Get the inner value
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+}
+
+impl std::fmt::Display for DiscussionNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A discussion category of a repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiscussionCategory {
+    /// GraphQL node ID of the category
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    pub description: Option<String>,
+    pub emoji: Option<String>,
+    /// Whether comments in this category can be marked as the answer (Q&A)
+    pub is_answerable: bool,
+}
+
+impl DiscussionCategory {
+    /// Parse a category from a GraphQL `DiscussionCategory` object
+    ///
+    /// Returns `None` if the value lacks the ID, name or slug.
+    pub fn from_graphql_value(value: &serde_json::Value) -> Option<Self> {
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            name: value.get("name")?.as_str()?.to_string(),
+            slug: value.get("slug")?.as_str()?.to_string(),
+            description: optional_string(value, "description"),
+            emoji: optional_string(value, "emoji"),
+            is_answerable: value
+                .get("isAnswerable")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+        })
+    }
+
+    /// Returns true if the category is identified by `key`
+    ///
+    /// A category matches its node ID or slug exactly, or its name ignoring
+    /// case.
+    pub fn matches(&self, key: &str) -> bool {
+        self.id == key || self.slug == key || self.name.eq_ignore_ascii_case(key)
+    }
+}
+
+/// A comment on a discussion, or a reply to such a comment
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiscussionComment {
+    /// GraphQL node ID of the comment
+    pub id: String,
+    /// Login of the author; `None` for deleted accounts
+    pub author: Option<String>,
+    pub body: String,
+    pub url: String,
+    pub created_at: DateTime<Utc>,
+    /// Whether the comment is marked as the answer of the discussion
+    pub is_answer: bool,
+    pub upvote_count: u64,
+    /// Replies to the comment; replies themselves have no replies
+    pub replies: Vec<DiscussionComment>,
+}
+
+impl DiscussionComment {
+    /// Parse a comment from a GraphQL `DiscussionComment` object
+    ///
+    /// Returns `None` if the value lacks the ID, body, URL or creation time.
+    pub fn from_graphql_value(value: &serde_json::Value) -> Option<Self> {
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            author: author_login(value),
+            body: value.get("body")?.as_str()?.to_string(),
+            url: value.get("url")?.as_str()?.to_string(),
+            created_at: value.get("createdAt")?.as_str()?.parse().ok()?,
+            is_answer: value
+                .get("isAnswer")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            upvote_count: value
+                .get("upvoteCount")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0),
+            replies: nodes(value, "/replies/nodes")
+                .filter_map(Self::from_graphql_value)
+                .collect(),
+        })
+    }
+}
+
+/// A discussion with its category and comments
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Discussion {
+    /// GraphQL node ID of the discussion
+    pub id: String,
+    pub number: DiscussionNumber,
+    pub title: String,
+    pub body: String,
+    pub url: String,
+    /// Login of the author; `None` for deleted accounts
+    pub author: Option<String>,
+    pub category: DiscussionCategory,
+    pub created_at: DateTime<Utc>,
+    pub locked: bool,
+    pub closed: bool,
+    /// Whether a comment is marked as the answer
+    pub is_answered: bool,
+    /// Total number of top-level comments, including those not fetched
+    pub comment_count: u64,
+    /// The fetched top-level comments, oldest first
+    pub comments: Vec<DiscussionComment>,
+}
+
+impl Discussion {
+    /// Parse a discussion from a GraphQL `Discussion` object
+    ///
+    /// Comments are read from `comments.nodes` when they were requested.
+    /// Returns `None` if the value lacks the ID, number, title, body, URL,
+    /// category or creation time.
+    pub fn from_graphql_value(value: &serde_json::Value) -> Option<Self> {
+        let flag = |name: &str| value.get(name).and_then(|v| v.as_bool()).unwrap_or(false);
+        let comments: Vec<DiscussionComment> = nodes(value, "/comments/nodes")
+            .filter_map(DiscussionComment::from_graphql_value)
+            .collect();
+
+        Some(Self {
+            id: value.get("id")?.as_str()?.to_string(),
+            number: DiscussionNumber::new(u32::try_from(value.get("number")?.as_u64()?).ok()?),
+            title: value.get("title")?.as_str()?.to_string(),
+            body: value.get("body")?.as_str()?.to_string(),
+            url: value.get("url")?.as_str()?.to_string(),
+            author: author_login(value),
+            category: DiscussionCategory::from_graphql_value(value.get("category")?)?,
+            created_at: value.get("createdAt")?.as_str()?.parse().ok()?,
+            locked: flag("locked"),
+            closed: flag("closed"),
+            is_answered: flag("isAnswered"),
+            comment_count: value
+                .pointer("/comments/totalCount")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(comments.len() as u64),
+            comments,
+        })
+    }
+
+    /// Returns the comment or reply marked as the answer, if any
+    pub fn answer(&self) -> Option<&DiscussionComment> {
+        self.comments
+            .iter()
+            .flat_map(|comment| std::iter::once(comment).chain(comment.replies.iter()))
+            .find(|comment| comment.is_answer)
+    }
+}
+
+/// Reason recorded when a discussion is locked
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ValueEnum, Display, EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+pub enum LockReason {
+    /// The conversation was off-topic
+    #[value(name = "off_topic")]
+    OffTopic,
+    /// The conversation was resolved
+    #[value(name = "resolved")]
+    Resolved,
+    /// The conversation was spam
+    #[value(name = "spam")]
+    Spam,
+    /// The conversation was too heated
+    #[value(name = "too_heated")]
+    TooHeated,
+}
+
+impl LockReason {
+    /// Returns the `LockReason` value used by the GitHub GraphQL API
+    pub fn graphql_value(&self) -> &'static str {
+        match self {
+            Self::OffTopic => "OFF_TOPIC",
+            Self::Resolved => "RESOLVED",
+            Self::Spam => "SPAM",
+            Self::TooHeated => "TOO_HEATED",
+        }
+    }
+}
+
+fn optional_string(value: &serde_json::Value, name: &str) -> Option<String> {
+    value
+        .get(name)
+        .and_then(|v| v.as_str())
+        .filter(|v| !v.is_empty())
+        .map(|v| v.to_string())
+}
+
+fn author_login(value: &serde_json::Value) -> Option<String> {
+    value
+        .pointer("/author/login")
+        .and_then(|v| v.as_str())
+        .map(|v| v.to_string())
+}
+
+fn nodes<'a>(
+    value: &'a serde_json::Value,
+    pointer: &str,
+) -> impl Iterator<Item = &'a serde_json::Value> {
+    value
+        .pointer(pointer)
+        .and_then(|nodes| nodes.as_array())
+        .into_iter()
+        .flatten()
+}
@@ -0,0 +1,35 @@
+//! Dry run types
+//!
+//! A tool called with `dry_run: true` resolves its inputs with the usual
+//! read requests but sends none of its writes. The writes it would have sent
+//! are reported as planned requests instead.
+
+use serde::{Deserialize, Serialize};
+
+/// Write request a dry run would have sent to the GitHub API
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlannedRequest {
+    /// HTTP method, e.g. `POST`
+    pub method: String,
+    /// Full API URL of the request
+    pub url: String,
+    /// JSON body of the request, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<serde_json::Value>,
+}
+
+/// Outcome of a tool called with `dry_run: true`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DryRunReport {
+    /// Name of the tool
+    pub tool: String,
+    /// Write requests the tool would have sent, in order
+    ///
+    /// A write whose response the tool needs to continue, such as the ID of
+    /// a created item, ends the dry run: the writes depending on it are not
+    /// listed.
+    pub planned_requests: Vec<PlannedRequest>,
+    /// Problems found while validating the inputs, such as labels the
+    /// repository does not define
+    pub warnings: Vec<String>,
+}
@@ -0,0 +1,66 @@
+//! Repository fork types
+//!
+//! This module provides the representation of forks created by fork
+//! operations and the outcome of syncing a fork with its upstream repository.
+
+use crate::types::repository::RepositoryId;
+use serde::{Deserialize, Serialize};
+
+/// A fork of a repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryFork {
+    pub repository_id: RepositoryId,
+    pub html_url: String,
+    pub default_branch: String,
+    /// The repository the fork was created from
+    pub parent: Option<RepositoryId>,
+}
+
+impl RepositoryFork {
+    /// Parse a fork from a REST API repository object
+    ///
+    /// Returns `None` if the value lacks the owner, name or default branch.
+    pub fn from_api_value(value: &serde_json::Value) -> Option<Self> {
+        Some(Self {
+            repository_id: repository_id_from_api_value(value)?,
+            html_url: value.get("html_url")?.as_str()?.to_string(),
+            default_branch: value.get("default_branch")?.as_str()?.to_string(),
+            parent: value.get("parent").and_then(repository_id_from_api_value),
+        })
+    }
+}
+
+/// How a fork branch was brought up to date with its upstream branch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ForkSyncMergeType {
+    /// The fork branch was behind and moved forward to the upstream branch
+    FastForward,
+    /// The fork branch had its own commits and a merge commit was created
+    Merge,
+    /// The fork branch was already up to date
+    None,
+}
+
+/// Outcome of syncing a fork branch with its upstream repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForkSyncResult {
+    pub message: String,
+    pub merge_type: ForkSyncMergeType,
+    /// The upstream branch that was merged, as `owner:branch`
+    pub base_branch: String,
+}
+
+impl ForkSyncResult {
+    /// Returns true if the sync changed the fork branch
+    pub fn updated(&self) -> bool {
+        self.merge_type != ForkSyncMergeType::None
+    }
+}
+
+fn repository_id_from_api_value(value: &serde_json::Value) -> Option<RepositoryId> {
+    Some(RepositoryId::new(
+        value.pointer("/owner/login")?.as_str()?,
+        value.get("name")?.as_str()?,
+    ))
+}
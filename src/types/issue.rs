@@ -12,6 +12,8 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 
+use crate::types::label::Label;
+use crate::types::repository::MilestoneNumber;
 use crate::types::{User, repository::RepositoryId};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -84,6 +86,37 @@ pub enum IssueState {
     Closed,
 }
 
+/// Reason attached to an issue state change.
+///
+/// GitHub records why an issue was closed or reopened. `Completed` and
+/// `NotPlanned` apply when closing, `Reopened` applies when reopening.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumString, Display, ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+#[value(rename_all = "snake_case")]
+pub enum IssueStateReason {
+    /// Issue was closed because the work is done
+    Completed,
+    /// Issue was closed because it will not be worked on
+    NotPlanned,
+    /// Issue was reopened
+    Reopened,
+}
+
+impl IssueStateReason {
+    /// Returns true if this reason can be applied together with the given state
+    pub fn is_valid_for(&self, state: IssueState) -> bool {
+        match self {
+            IssueStateReason::Completed | IssueStateReason::NotPlanned => {
+                state == IssueState::Closed
+            }
+            IssueStateReason::Reopened => state == IssueState::Open,
+        }
+    }
+}
+
 /// Strong-typed issue identifier with URL parsing capabilities.
 ///
 /// This struct encapsulates all issue identification logic and URL parsing
@@ -132,6 +165,22 @@ impl IssueId {
 
         Err(format!("Invalid issue URL format: {}", input_str))
     }
+
+    /// Returns the reference to this issue as written from another repository
+    ///
+    /// Yields `#123` within the same repository and `owner/repo#123` otherwise.
+    pub fn reference_from(&self, repository_id: &RepositoryId) -> String {
+        if &self.git_repository == repository_id {
+            format!("#{}", self.number)
+        } else {
+            format!(
+                "{}/{}#{}",
+                self.git_repository.owner(),
+                self.git_repository.repo_name(),
+                self.number
+            )
+        }
+    }
 }
 
 impl std::fmt::Display for IssueId {
@@ -159,6 +208,9 @@ pub struct Issue {
     pub comments: Vec<IssueComment>,
     pub milestone_number: Option<u64>,
     pub locked: bool,
+    /// Reason of the last state change, e.g. why a closed issue was closed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_reason: Option<IssueStateReason>,
 }
 
 impl Issue {
@@ -193,6 +245,205 @@ impl Issue {
             comments,
             milestone_number,
             locked,
+            state_reason: None,
+        }
+    }
+
+    /// Set the reason of the last state change
+    pub fn with_state_reason(mut self, state_reason: Option<IssueStateReason>) -> Self {
+        self.state_reason = state_reason;
+        self
+    }
+}
+
+/// Issue read with at most a given number of its comments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueDetails {
+    #[serde(flatten)]
+    pub issue: Issue,
+    /// Whether the issue has comments beyond those in `comments`
+    pub has_more_comments: bool,
+}
+
+/// GraphQL node ID of an organization-level issue type
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct IssueTypeId(pub String);
+
+impl IssueTypeId {
+    /// Create a new issue type ID
+    pub fn new<S: Into<String>>(id: S) -> Self {
+        Self(id.into())
+    }
+
+    /// Get the inner value
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for IssueTypeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Organization-level issue type (e.g. Bug, Feature, Task)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueType {
+    pub id: IssueTypeId,
+    pub name: String,
+    pub description: Option<String>,
+    pub color: Option<String>,
+    pub is_enabled: bool,
+}
+
+impl IssueType {
+    /// Find an issue type by name (case-insensitive)
+    pub fn find_by_name<'a>(issue_types: &'a [IssueType], name: &str) -> Option<&'a IssueType> {
+        issue_types
+            .iter()
+            .find(|issue_type| issue_type.name.eq_ignore_ascii_case(name))
+    }
+}
+
+/// Outcome of marking an issue as a duplicate of another issue.
+///
+/// Marking a duplicate consists of several independent steps (comment, label,
+/// close). Each step is attempted even if an earlier one failed, and every
+/// failure is recorded so callers can report partial success.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateIssueResult {
+    pub issue_id: IssueId,
+    pub duplicate_of: IssueNumber,
+    pub comment_number: Option<IssueCommentNumber>,
+    pub applied_label: Option<String>,
+    pub closed: bool,
+    pub failures: Vec<String>,
+}
+
+impl DuplicateIssueResult {
+    /// Create a result with no steps completed yet
+    pub fn new(issue_id: IssueId, duplicate_of: IssueNumber) -> Self {
+        Self {
+            issue_id,
+            duplicate_of,
+            comment_number: None,
+            applied_label: None,
+            closed: false,
+            failures: Vec::new(),
+        }
+    }
+
+    /// Returns true if every step succeeded
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Operations applied to every issue by a bulk update
+///
+/// Operations run in a fixed order per issue: labels, milestone, assignees,
+/// and finally closing, so an issue is only closed after its metadata is set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BulkIssueOperations {
+    /// Labels to add (labels already on the issue are skipped)
+    pub add_labels: Vec<Label>,
+    /// Milestone to set
+    pub milestone: Option<MilestoneNumber>,
+    /// Usernames to assign (users already assigned are skipped)
+    pub assignees: Vec<String>,
+    /// Whether to close the issue
+    pub close: bool,
+    /// Reason recorded when closing; only valid together with `close`
+    pub close_reason: Option<IssueStateReason>,
+}
+
+impl BulkIssueOperations {
+    /// Returns true if no operation is requested
+    pub fn is_empty(&self) -> bool {
+        self.add_labels.is_empty()
+            && self.milestone.is_none()
+            && self.assignees.is_empty()
+            && !self.close
+    }
+}
+
+/// Outcome of a bulk update for a single issue
+///
+/// Every requested operation is attempted even if an earlier one failed, and
+/// each failure is recorded so callers can report partial success.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkIssueUpdateResult {
+    pub issue_number: IssueNumber,
+    pub failures: Vec<String>,
+}
+
+impl BulkIssueUpdateResult {
+    /// Create a result with no failures recorded
+    pub fn new(issue_number: IssueNumber) -> Self {
+        Self {
+            issue_number,
+            failures: Vec::new(),
+        }
+    }
+
+    /// Returns true if every operation succeeded
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Verification state of a link between two issues
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum IssueLinkState {
+    /// Both reference comments were posted and both timelines show the cross-reference
+    Verified,
+    /// Reference comments were posted but a cross-reference event is not visible yet
+    Unverified,
+    /// Posting a reference comment failed
+    Failed,
+}
+
+/// Outcome of linking two issues with reference comments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueLinkResult {
+    pub source: IssueId,
+    pub target: IssueId,
+    /// Comment on the source issue referencing the target
+    pub source_comment: Option<IssueCommentNumber>,
+    /// Comment on the target issue referencing the source
+    pub target_comment: Option<IssueCommentNumber>,
+    /// Whether the target timeline shows a cross-reference from the source
+    pub target_cross_referenced: bool,
+    /// Whether the source timeline shows a cross-reference from the target
+    pub source_cross_referenced: bool,
+    pub failures: Vec<String>,
+}
+
+impl IssueLinkResult {
+    /// Create a result with no steps completed yet
+    pub fn new(source: IssueId, target: IssueId) -> Self {
+        Self {
+            source,
+            target,
+            source_comment: None,
+            target_comment: None,
+            target_cross_referenced: false,
+            source_cross_referenced: false,
+            failures: Vec::new(),
+        }
+    }
+
+    /// Returns the verification state of the link
+    pub fn state(&self) -> IssueLinkState {
+        if self.source_comment.is_none() || self.target_comment.is_none() {
+            IssueLinkState::Failed
+        } else if self.source_cross_referenced && self.target_cross_referenced {
+            IssueLinkState::Verified
+        } else {
+            IssueLinkState::Unverified
         }
     }
 }
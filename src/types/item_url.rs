@@ -0,0 +1,74 @@
+//! Issue and pull request URL types
+//!
+//! Commands acting on one issue or pull request take the repository URL and
+//! the number of the item. The URL of the item itself, e.g.
+//! `https://github.com/owner/repo/issues/123`, can be given in place of the
+//! repository URL instead, and the number is then taken from it.
+
+use crate::types::repository::{RepositoryId, RepositoryUrl};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static ITEM_URL_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:https?://)?github\.com/([^/]+)/([^/]+)/(?:issues|pull)/(\d+)(?:[/?#].*)?$")
+        .expect("Failed to compile item URL regex")
+});
+
+/// Issue or pull request named by its URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemUrl {
+    pub repository_id: RepositoryId,
+    pub number: u32,
+}
+
+impl ItemUrl {
+    /// Parse the URL of an issue or pull request
+    ///
+    /// Accepts `https://github.com/owner/repo/issues/123` and
+    /// `https://github.com/owner/repo/pull/123`, including URLs of a comment
+    /// or tab of the item such as `.../pull/123/files`. GitHub numbers issues
+    /// and pull requests in one sequence, so either form names the item.
+    ///
+    /// # Returns
+    /// `None` if the input is not the URL of an issue or pull request
+    pub fn parse(input: &str) -> Option<Self> {
+        let captures = ITEM_URL_REGEX.captures(input.trim())?;
+        let number = captures.get(3)?.as_str().parse().ok()?;
+        Some(Self {
+            repository_id: RepositoryId::new(captures.get(1)?.as_str(), captures.get(2)?.as_str()),
+            number,
+        })
+    }
+}
+
+/// Resolve the repository and number of an issue or pull request
+///
+/// `repository_url` is either the URL of the repository, with the number
+/// given separately, or the URL of the item, in which case `number` may be
+/// omitted.
+///
+/// # Errors
+/// Returns an error message if no number is given and `repository_url` is
+/// not the URL of an item, if the given number differs from the number in
+/// the URL, or if the repository URL is invalid
+pub fn resolve_item_target(
+    repository_url: &str,
+    number: Option<u32>,
+) -> Result<(RepositoryId, u32), String> {
+    match (ItemUrl::parse(repository_url), number) {
+        (Some(item), Some(number)) if item.number != number => Err(format!(
+            "Number {} does not match the URL {}",
+            number, repository_url
+        )),
+        (Some(item), _) => Ok((item.repository_id, item.number)),
+        (None, Some(number)) => {
+            let repository_id =
+                RepositoryId::parse_url(&RepositoryUrl(repository_url.to_string()))?;
+            Ok((repository_id, number))
+        }
+        (None, None) => Err(format!(
+            "No issue or pull request number given and {} is not the URL of an issue or pull request",
+            repository_url
+        )),
+    }
+}
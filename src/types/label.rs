@@ -47,6 +47,24 @@ impl Label {
     }
 }
 
+/// Parse a label color given as a hex RGB color
+///
+/// Accepts `ff0000` as well as `#FF0000` and returns the color the way GitHub
+/// stores it: six lowercase hex digits without the `#`.
+///
+/// # Errors
+/// Returns an error message if the color is not six hex digits
+pub fn parse_label_color(color: &str) -> Result<String, String> {
+    let hex = color.trim().trim_start_matches('#');
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!(
+            "Invalid label color '{}'. Expected six hex digits such as ff0000",
+            color
+        ));
+    }
+    Ok(hex.to_ascii_lowercase())
+}
+
 impl From<String> for Label {
     fn from(name: String) -> Self {
         Label::new(name, None)
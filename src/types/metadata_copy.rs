@@ -0,0 +1,142 @@
+//! Repository metadata copy types
+//!
+//! This module provides the options and results of copying labels and
+//! milestones from one repository to another.
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+/// How to handle a label or milestone that already exists in the target repository
+///
+/// Labels collide when their names match case-insensitively, as GitHub
+/// treats label names. Milestones collide when their titles match.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    ValueEnum,
+    Display,
+    EnumString,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum MetadataConflictStrategy {
+    /// Keep the existing item in the target repository unchanged
+    #[default]
+    #[value(name = "skip")]
+    Skip,
+    /// Overwrite the existing item with the values of the source item
+    #[value(name = "overwrite")]
+    Overwrite,
+    /// Report the existing item as a failure
+    #[value(name = "fail")]
+    Fail,
+}
+
+impl MetadataConflictStrategy {
+    /// Returns the action to take for an item, given whether it already exists
+    /// in the target repository
+    pub fn action_for(&self, exists_in_target: bool) -> MetadataCopyAction {
+        match (exists_in_target, self) {
+            (false, _) => MetadataCopyAction::Created,
+            (true, Self::Skip) => MetadataCopyAction::Skipped,
+            (true, Self::Overwrite) => MetadataCopyAction::Updated,
+            (true, Self::Fail) => MetadataCopyAction::Failed,
+        }
+    }
+}
+
+/// Selection of the metadata to copy between repositories
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataCopyOptions {
+    /// Whether to copy labels
+    pub labels: bool,
+    /// Whether to copy milestones
+    pub milestones: bool,
+    /// How to handle items that already exist in the target repository
+    pub on_conflict: MetadataConflictStrategy,
+}
+
+impl Default for MetadataCopyOptions {
+    fn default() -> Self {
+        Self {
+            labels: true,
+            milestones: true,
+            on_conflict: MetadataConflictStrategy::default(),
+        }
+    }
+}
+
+/// What happened to a single copied label or milestone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum MetadataCopyAction {
+    /// The item did not exist in the target repository and was created
+    Created,
+    /// The existing item in the target repository was overwritten
+    Updated,
+    /// The existing item in the target repository was kept
+    Skipped,
+    /// The item could not be copied
+    Failed,
+}
+
+/// Outcome of copying a single label or milestone
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataCopyEntry {
+    /// The label name or milestone title
+    pub name: String,
+    pub action: MetadataCopyAction,
+    /// The reason the item could not be copied
+    pub error: Option<String>,
+}
+
+impl MetadataCopyEntry {
+    /// Create an entry for an item copied without error
+    pub fn new(name: String, action: MetadataCopyAction) -> Self {
+        Self {
+            name,
+            action,
+            error: None,
+        }
+    }
+
+    /// Create an entry for an item that could not be copied
+    pub fn failed(name: String, error: String) -> Self {
+        Self {
+            name,
+            action: MetadataCopyAction::Failed,
+            error: Some(error),
+        }
+    }
+}
+
+/// Outcome of copying labels and milestones between repositories
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataCopyResult {
+    pub labels: Vec<MetadataCopyEntry>,
+    pub milestones: Vec<MetadataCopyEntry>,
+}
+
+impl MetadataCopyResult {
+    /// Returns the number of labels and milestones that could not be copied
+    pub fn failure_count(&self) -> usize {
+        self.labels
+            .iter()
+            .chain(&self.milestones)
+            .filter(|entry| entry.action == MetadataCopyAction::Failed)
+            .count()
+    }
+
+    /// Returns true if every label and milestone was copied or skipped
+    pub fn is_success(&self) -> bool {
+        self.failure_count() == 0
+    }
+}
@@ -3,7 +3,7 @@
 //! This module contains the Milestone domain types for GitHub milestones.
 
 use crate::types::repository::MilestoneNumber;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
@@ -120,3 +120,50 @@ impl Milestone {
         }
     }
 }
+
+/// Parse a milestone due date
+///
+/// Accepts a date such as `2024-12-31`, which is due at midnight UTC, or an
+/// RFC 3339 timestamp such as `2024-12-31T23:59:59Z`.
+///
+/// # Errors
+/// Returns an error message if the value is neither a date nor a timestamp
+pub fn parse_due_date(due: &str) -> Result<DateTime<Utc>, String> {
+    let due = due.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(due, "%Y-%m-%d") {
+        return Ok(date.and_time(NaiveTime::MIN).and_utc());
+    }
+    DateTime::parse_from_rfc3339(due)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|_| {
+            format!(
+                "Invalid due date '{}'. Expected a date such as 2024-12-31 or a timestamp such as 2024-12-31T23:59:59Z",
+                due
+            )
+        })
+}
+
+/// Find a milestone by its title
+///
+/// An exact title match wins. Otherwise the title is matched case-insensitively,
+/// which must identify a single milestone.
+///
+/// # Returns
+/// The matching milestone, or `None` if no milestone or more than one
+/// case-insensitive match has the title
+pub fn find_milestone_by_title<'a>(
+    milestones: &'a [Milestone],
+    title: &str,
+) -> Option<&'a Milestone> {
+    if let Some(milestone) = milestones.iter().find(|m| m.title == title) {
+        return Some(milestone);
+    }
+
+    let mut matches = milestones
+        .iter()
+        .filter(|m| m.title.eq_ignore_ascii_case(title));
+    match (matches.next(), matches.next()) {
+        (Some(milestone), None) => Some(milestone),
+        _ => None,
+    }
+}
@@ -4,18 +4,82 @@
 //! following domain-driven design principles. All types are strongly-typed and
 //! provide comprehensive validation and conversion capabilities.
 
+pub mod actions;
+pub mod audit;
+pub mod auth;
+pub mod batch;
+pub mod branch;
+pub mod check;
+pub mod comment;
+pub mod commit;
+pub mod conditional;
+pub mod confirmation;
+pub mod content;
+pub mod discussion;
+pub mod dry_run;
+pub mod fork;
 pub mod issue;
+pub mod item_url;
 pub mod label;
+pub mod metadata_copy;
 pub mod milestone;
+pub mod notification;
+pub mod pagination;
+pub mod plan;
 pub mod project;
 pub mod pull_request;
+pub mod queue;
+pub mod rate_limit;
+pub mod reaction;
+pub mod release;
 pub mod repository;
+pub mod resource;
+pub mod search;
+pub mod secret;
+pub mod template;
+pub mod timeline;
+pub mod trace;
+pub mod transfer;
+pub mod truncation;
 pub mod user;
+pub mod webhook;
 
+pub use actions::*;
+pub use audit::*;
+pub use auth::*;
+pub use batch::*;
+pub use branch::*;
+pub use check::*;
+pub use comment::*;
+pub use commit::*;
+pub use conditional::*;
+pub use confirmation::*;
+pub use content::*;
+pub use discussion::*;
+pub use dry_run::*;
+pub use fork::*;
 pub use issue::*;
+pub use item_url::*;
 pub use label::*;
+pub use metadata_copy::*;
 pub use milestone::*;
+pub use notification::*;
+pub use pagination::*;
+pub use plan::*;
 pub use project::*;
 pub use pull_request::*;
+pub use queue::*;
+pub use rate_limit::*;
+pub use reaction::*;
+pub use release::*;
 pub use repository::*;
+pub use resource::*;
+pub use search::*;
+pub use secret::*;
+pub use template::*;
+pub use timeline::*;
+pub use trace::*;
+pub use transfer::*;
+pub use truncation::*;
 pub use user::*;
+pub use webhook::*;
@@ -0,0 +1,217 @@
+//! Notification inbox domain types
+//!
+//! This module contains the types for the authenticated user's notification
+//! threads, the filters used when listing them, thread subscriptions and
+//! subscriptions to issues and pull requests.
+
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+use crate::types::pagination::Pagination;
+
+/// Identifier of a notification thread
+///
+/// The API returns thread IDs as strings.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct NotificationThreadId(pub String);
+
+impl NotificationThreadId {
+    /// Create a new notification thread identifier
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+
+    /// Get the inner value
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for NotificationThreadId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The issue, pull request, release or other item a notification is about
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationSubject {
+    pub title: String,
+    /// API URL of the subject; `None` for subjects without one (e.g. discussions)
+    pub url: Option<String>,
+    pub latest_comment_url: Option<String>,
+    /// Kind of subject, e.g. "Issue", "PullRequest", "Release", "CheckSuite"
+    #[serde(rename = "type")]
+    pub subject_type: String,
+}
+
+/// Repository a notification belongs to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationRepository {
+    /// "owner/repo"
+    pub full_name: String,
+    pub html_url: String,
+}
+
+/// A notification thread of the authenticated user
+///
+/// Field names follow the REST API so notifications deserialize directly
+/// from API responses. `reason` explains why the user was notified, e.g.
+/// `mention`, `review_requested`, `assign` or `subscribed`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub id: NotificationThreadId,
+    pub repository: NotificationRepository,
+    pub subject: NotificationSubject,
+    pub reason: String,
+    pub unread: bool,
+    pub updated_at: DateTime<Utc>,
+    pub last_read_at: Option<DateTime<Utc>>,
+}
+
+impl Notification {
+    /// Returns the web URL of the notification subject
+    ///
+    /// Converts the API URL of issues, pull requests and commits to the
+    /// matching github.com URL. Falls back to the repository URL when the
+    /// subject has no API URL or it cannot be converted.
+    pub fn html_url(&self) -> String {
+        if !matches!(
+            self.subject.subject_type.as_str(),
+            "Issue" | "PullRequest" | "Commit"
+        ) {
+            return self.repository.html_url.clone();
+        }
+
+        self.subject
+            .url
+            .as_deref()
+            .and_then(|url| url.strip_prefix("https://api.github.com/repos/"))
+            .map(|path| {
+                format!(
+                    "https://github.com/{}",
+                    path.replacen("/pulls/", "/pull/", 1)
+                        .replacen("/commits/", "/commit/", 1)
+                )
+            })
+            .unwrap_or_else(|| self.repository.html_url.clone())
+    }
+}
+
+/// Filter for listing notifications
+///
+/// By default only unread notifications are listed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationFilter {
+    /// Include notifications already marked as read
+    pub all: bool,
+    /// Only include notifications where the user is directly participating or mentioned
+    pub participating: bool,
+    /// Only include notifications updated at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Only include notifications updated before this time
+    pub before: Option<DateTime<Utc>>,
+}
+
+impl NotificationFilter {
+    /// Build the query string for listing notifications with this filter
+    pub fn to_query(&self, pagination: Pagination) -> String {
+        let mut params = Vec::new();
+        if self.all {
+            params.push("all=true".to_string());
+        }
+        if self.participating {
+            params.push("participating=true".to_string());
+        }
+        for (key, value) in [("since", &self.since), ("before", &self.before)] {
+            if let Some(value) = value {
+                params.push(format!(
+                    "{}={}",
+                    key,
+                    urlencoding::encode(&value.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+                ));
+            }
+        }
+        params.push(format!("per_page={}", pagination.per_page));
+        params.push(format!("page={}", pagination.page));
+        params.join("&")
+    }
+}
+
+/// Subscription of the authenticated user to a notification thread
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadSubscription {
+    /// Whether the user receives notifications for the thread
+    pub subscribed: bool,
+    /// Whether all notifications of the thread are muted
+    pub ignored: bool,
+    pub reason: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// Subscription state of the authenticated user on an issue or pull request
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ValueEnum, Display, EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+pub enum SubscriptionState {
+    /// Notified of all conversation
+    #[value(name = "subscribed")]
+    Subscribed,
+    /// Only notified when participating or mentioned
+    #[value(name = "unsubscribed")]
+    Unsubscribed,
+    /// Never notified
+    #[value(name = "ignored")]
+    Ignored,
+}
+
+impl SubscriptionState {
+    /// Returns the `SubscriptionState` value used by the GitHub GraphQL API
+    pub fn graphql_value(&self) -> &'static str {
+        match self {
+            Self::Subscribed => "SUBSCRIBED",
+            Self::Unsubscribed => "UNSUBSCRIBED",
+            Self::Ignored => "IGNORED",
+        }
+    }
+
+    /// Parse a `SubscriptionState` value returned by the GitHub GraphQL API
+    pub fn from_graphql(value: &str) -> Option<Self> {
+        match value {
+            "SUBSCRIBED" => Some(Self::Subscribed),
+            "UNSUBSCRIBED" => Some(Self::Unsubscribed),
+            "IGNORED" => Some(Self::Ignored),
+            _ => None,
+        }
+    }
+}
+
+/// Subscription of the authenticated user to an issue or pull request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssueSubscription {
+    /// Current state; `None` if GitHub does not report one
+    pub state: Option<SubscriptionState>,
+    /// Whether the user is allowed to change the subscription
+    pub can_subscribe: bool,
+}
+
+impl IssueSubscription {
+    /// Parse a subscription from a GraphQL `Subscribable` object
+    pub fn from_graphql_value(value: &serde_json::Value) -> Self {
+        Self {
+            state: value
+                .get("viewerSubscription")
+                .and_then(|v| v.as_str())
+                .and_then(SubscriptionState::from_graphql),
+            can_subscribe: value
+                .get("viewerCanSubscribe")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Pagination types for list operations
+//!
+//! This module provides the page selection passed to list operations and the
+//! page of results they return.
+
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of items per page supported by the GitHub API
+pub const MAX_PER_PAGE: u8 = 100;
+
+/// Default number of items per page used by the GitHub API
+pub const DEFAULT_PER_PAGE: u8 = 30;
+
+/// Page selection for list operations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pagination {
+    pub page: u32,
+    pub per_page: u8,
+}
+
+impl Pagination {
+    /// Create a new page selection
+    ///
+    /// The page number starts at 1 and the page size is clamped to `1..=100`.
+    pub fn new(page: Option<u32>, per_page: Option<u8>) -> Self {
+        Self {
+            page: page.unwrap_or(1).max(1),
+            per_page: per_page.unwrap_or(DEFAULT_PER_PAGE).clamp(1, MAX_PER_PAGE),
+        }
+    }
+
+    /// Returns the selection for the following page
+    pub fn next(&self) -> Self {
+        Self {
+            page: self.page + 1,
+            per_page: self.per_page,
+        }
+    }
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self::new(None, None)
+    }
+}
+
+/// A single page of results from a list operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub page: u32,
+    pub per_page: u8,
+    pub has_next_page: bool,
+}
+
+impl<T> Page<T> {
+    /// Create a new page of results
+    pub fn new(items: Vec<T>, pagination: Pagination, has_next_page: bool) -> Self {
+        Self {
+            items,
+            page: pagination.page,
+            per_page: pagination.per_page,
+            has_next_page,
+        }
+    }
+}
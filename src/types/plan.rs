@@ -0,0 +1,117 @@
+//! Plan file types
+//!
+//! A plan file lists operations to apply in bulk, e.g. when migrating issues
+//! from another tracker: issues to create, labels to add and milestones to
+//! set. `github-edit-cli apply` runs the operations and reports the outcome
+//! of each of them.
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+/// One operation of a plan file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "operation", rename_all = "snake_case", deny_unknown_fields)]
+pub enum PlanOperation {
+    /// Create an issue
+    CreateIssue {
+        /// Repository URL, e.g. `https://github.com/owner/repo`
+        repository_url: String,
+        title: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        body: Option<String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        labels: Vec<String>,
+        /// Usernames to assign
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        assignees: Vec<String>,
+        /// Milestone number
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        milestone: Option<u64>,
+    },
+    /// Add labels to an issue (labels already on the issue are skipped)
+    AddLabels {
+        /// Issue URL, e.g. `https://github.com/owner/repo/issues/123`
+        issue_url: String,
+        labels: Vec<String>,
+    },
+    /// Set the milestone of an issue
+    SetMilestone {
+        /// Issue URL, e.g. `https://github.com/owner/repo/issues/123`
+        issue_url: String,
+        /// Milestone number
+        milestone: u64,
+    },
+}
+
+impl PlanOperation {
+    /// Name of the operation, as given in the `operation` field
+    pub fn name(&self) -> &'static str {
+        match self {
+            PlanOperation::CreateIssue { .. } => "create_issue",
+            PlanOperation::AddLabels { .. } => "add_labels",
+            PlanOperation::SetMilestone { .. } => "set_milestone",
+        }
+    }
+
+    /// Repository or issue the operation applies to
+    pub fn target(&self) -> &str {
+        match self {
+            PlanOperation::CreateIssue { repository_url, .. } => repository_url,
+            PlanOperation::AddLabels { issue_url, .. }
+            | PlanOperation::SetMilestone { issue_url, .. } => issue_url,
+        }
+    }
+}
+
+/// Format of a plan file
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum, Display, EnumString,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum PlanFormat {
+    /// JSON array of operations, each naming its kind in `operation`
+    Json,
+    /// CSV with a header row; the `operation` column names the kind of each
+    /// row and list columns such as `labels` are comma-separated
+    Csv,
+}
+
+/// Outcome of one operation of a plan
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanOperationStatus {
+    Succeeded,
+    Failed,
+    /// Not run because an earlier operation failed with `--stop-on-error`
+    Skipped,
+}
+
+/// Result of one operation of a plan
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanOperationResult {
+    /// Position of the operation in the plan, starting at 1
+    pub row: usize,
+    /// Name of the operation, e.g. `create_issue`
+    pub operation: String,
+    /// Repository or issue the operation applies to
+    pub target: String,
+    pub status: PlanOperationStatus,
+    /// URL of the created issue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Error of a failed operation, or why it was skipped
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Consolidated report of an applied plan
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanReport {
+    pub succeeded: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    /// Results of all operations, in the order of the plan
+    pub results: Vec<PlanOperationResult>,
+}
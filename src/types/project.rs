@@ -5,7 +5,7 @@
 //! URL parsing logic is contained within this module.
 
 use anyhow;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use clap::ValueEnum;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -16,7 +16,11 @@ use crate::types::label::Label;
 use crate::types::user::User;
 use serde::{Deserialize, Serialize};
 
-use crate::types::{issue::IssueId, pull_request::PullRequestId, repository::Owner};
+use crate::types::{
+    issue::{IssueId, IssueUrl},
+    pull_request::{PullRequestId, PullRequestUrl},
+    repository::{Owner, RepositoryId},
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ProjectUrl(pub String);
@@ -221,6 +225,29 @@ pub enum ProjectVisibility {
     Private,
 }
 
+/// Settings to change on an existing project
+///
+/// Fields left as `None` are not sent and keep their current value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectUpdate {
+    pub title: Option<String>,
+    pub short_description: Option<String>,
+    pub readme: Option<String>,
+    pub visibility: Option<ProjectVisibility>,
+    pub state: Option<ProjectState>,
+}
+
+impl ProjectUpdate {
+    /// Returns true if no setting would be changed
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none()
+            && self.short_description.is_none()
+            && self.readme.is_none()
+            && self.visibility.is_none()
+            && self.state.is_none()
+    }
+}
+
 impl Project {
     /// Create new project with basic metadata
     #[allow(clippy::too_many_arguments)]
@@ -272,6 +299,36 @@ pub enum ProjectOriginalResource {
     DraftIssue,
 }
 
+impl ProjectOriginalResource {
+    /// Parse a GitHub issue or pull request URL
+    ///
+    /// Supports `https://github.com/owner/repo/issues/123` and
+    /// `https://github.com/owner/repo/pull/456`.
+    pub fn parse_url(url: &str) -> Result<Self, String> {
+        if let Ok(issue_id) = IssueId::parse_url(&IssueUrl(url.to_string())) {
+            return Ok(Self::Issue(issue_id));
+        }
+        if let Ok(pull_request_id) = PullRequestId::parse_url(&PullRequestUrl(url.to_string())) {
+            return Ok(Self::PullRequest(pull_request_id));
+        }
+        Err(format!(
+            "Invalid issue or pull request URL format: {}",
+            url.trim_end_matches('/')
+        ))
+    }
+
+    /// Returns the repository and number of the issue or pull request
+    ///
+    /// Draft issues have neither and yield `None`.
+    pub fn repository_and_number(&self) -> Option<(&RepositoryId, u32)> {
+        match self {
+            Self::Issue(issue_id) => Some((&issue_id.git_repository, issue_id.number)),
+            Self::PullRequest(pr_id) => Some((&pr_id.git_repository, pr_id.number)),
+            Self::DraftIssue => None,
+        }
+    }
+}
+
 /// Custom field definition for a project
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectCustomField {
@@ -308,6 +365,132 @@ pub struct ProjectCustomFieldValue {
     pub value: ProjectFieldValue,
 }
 
+/// Item of a project with its field values, as read from the project
+///
+/// Single select values hold the option name and Labels fields are read as
+/// `MultiSelect` values of label names.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectItem {
+    pub item_id: ProjectItemId,
+    pub archived: bool,
+    pub field_values: Vec<ProjectCustomFieldValue>,
+}
+
+impl ProjectItem {
+    /// Parse an item from a GraphQL `ProjectV2Item` node
+    ///
+    /// Field values of unsupported types (e.g. iterations) are skipped.
+    pub fn from_graphql_value(value: &serde_json::Value) -> Option<Self> {
+        let field_values = value
+            .pointer("/fieldValues/nodes")
+            .and_then(|nodes| nodes.as_array())
+            .map(|nodes| {
+                nodes
+                    .iter()
+                    .filter_map(|node| {
+                        let field_value = match node.get("__typename")?.as_str()? {
+                            "ProjectV2ItemFieldTextValue" => {
+                                ProjectFieldValue::Text(node.get("text")?.as_str()?.to_string())
+                            }
+                            "ProjectV2ItemFieldNumberValue" => {
+                                ProjectFieldValue::Number(node.get("number")?.as_f64()?)
+                            }
+                            "ProjectV2ItemFieldDateValue" => {
+                                let date: NaiveDate = node.get("date")?.as_str()?.parse().ok()?;
+                                ProjectFieldValue::Date(date.and_hms_opt(0, 0, 0)?.and_utc())
+                            }
+                            "ProjectV2ItemFieldSingleSelectValue" => {
+                                ProjectFieldValue::SingleSelect(
+                                    node.get("name")?.as_str()?.to_string(),
+                                )
+                            }
+                            "ProjectV2ItemFieldLabelValue" => ProjectFieldValue::MultiSelect(
+                                node.pointer("/labels/nodes")?
+                                    .as_array()?
+                                    .iter()
+                                    .filter_map(|label| label.get("name")?.as_str())
+                                    .map(|name| name.to_string())
+                                    .collect(),
+                            ),
+                            _ => return None,
+                        };
+                        Some(ProjectCustomFieldValue {
+                            field_id: node.pointer("/field/id")?.as_str()?.to_string(),
+                            field_name: node.pointer("/field/name")?.as_str()?.to_string(),
+                            value: field_value,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(Self {
+            item_id: ProjectItemId::new(value.get("id")?.as_str()?.to_string()),
+            archived: value
+                .get("isArchived")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            field_values,
+        })
+    }
+
+    /// Get the value of a field by its name, ignoring case
+    pub fn field_value(&self, field_name: &str) -> Option<&ProjectFieldValue> {
+        self.field_values
+            .iter()
+            .find(|field_value| field_value.field_name.eq_ignore_ascii_case(field_name))
+            .map(|field_value| &field_value.value)
+    }
+}
+
+/// Selects the project items a bulk update applies to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ProjectItemFilter {
+    /// Explicit list of project items
+    ItemIds(Vec<ProjectItemId>),
+    /// Items whose field currently has the given value (e.g. Status = "Todo")
+    FieldEquals { field_name: String, value: String },
+}
+
+impl ProjectItemFilter {
+    /// Returns true if the item is selected by this filter
+    ///
+    /// Text and single select values compare case-insensitively, numbers
+    /// numerically, dates by calendar day (`YYYY-MM-DD`), and multi-select
+    /// values match if any of them equals the expected value.
+    pub fn matches(&self, item: &ProjectItem) -> bool {
+        match self {
+            Self::ItemIds(item_ids) => item_ids.iter().any(|id| id.value() == item.item_id.value()),
+            Self::FieldEquals { field_name, value } => match item.field_value(field_name) {
+                Some(ProjectFieldValue::Text(text)) => text.eq_ignore_ascii_case(value),
+                Some(ProjectFieldValue::SingleSelect(name)) => name.eq_ignore_ascii_case(value),
+                Some(ProjectFieldValue::Number(number)) => value
+                    .parse::<f64>()
+                    .is_ok_and(|expected| expected == *number),
+                Some(ProjectFieldValue::Date(date)) => date.date_naive().to_string() == *value,
+                Some(ProjectFieldValue::MultiSelect(values)) => {
+                    values.iter().any(|v| v.eq_ignore_ascii_case(value))
+                }
+                None => false,
+            },
+        }
+    }
+}
+
+/// Outcome of updating one item in a bulk project field update
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkProjectItemUpdateResult {
+    pub item_id: ProjectItemId,
+    pub failure: Option<String>,
+}
+
+impl BulkProjectItemUpdateResult {
+    /// Returns true if the item was updated
+    pub fn is_success(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
 /// Actual value of a custom field
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ProjectFieldValue {
@@ -451,6 +634,37 @@ impl std::fmt::Display for ProjectItemId {
     }
 }
 
+/// Draft issue node ID (GraphQL ID of a `DraftIssue`, distinct from its project item ID)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DraftIssueId(pub String);
+
+impl DraftIssueId {
+    /// Create new draft issue ID
+    pub fn new(id: String) -> Self {
+        Self(id)
+    }
+
+    /// Get the string value
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for DraftIssueId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Draft issue created in a project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectDraftIssue {
+    /// The project item that holds the draft issue
+    pub project_item_id: ProjectItemId,
+    /// The draft issue itself, used to edit its title and body
+    pub draft_issue_id: DraftIssueId,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectFieldId(pub String);
 
@@ -486,3 +700,244 @@ impl ProjectFieldName {
         &self.0
     }
 }
+
+/// Data type of a project field as reported by the GraphQL API
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectFieldDataType {
+    Text,
+    Number,
+    Date,
+    SingleSelect,
+    Iteration,
+    Title,
+    Assignees,
+    Labels,
+    LinkedPullRequests,
+    Milestone,
+    Repository,
+    Reviewers,
+    /// Data type not known to this crate, kept by its lowercased GraphQL name
+    Other(String),
+}
+
+impl ProjectFieldDataType {
+    /// Parse a GraphQL `ProjectV2FieldType` value such as `SINGLE_SELECT`
+    pub fn from_graphql(value: &str) -> Self {
+        match value {
+            "TEXT" => Self::Text,
+            "NUMBER" => Self::Number,
+            "DATE" => Self::Date,
+            "SINGLE_SELECT" => Self::SingleSelect,
+            "ITERATION" => Self::Iteration,
+            "TITLE" => Self::Title,
+            "ASSIGNEES" => Self::Assignees,
+            "LABELS" => Self::Labels,
+            "LINKED_PULL_REQUESTS" => Self::LinkedPullRequests,
+            "MILESTONE" => Self::Milestone,
+            "REPOSITORY" => Self::Repository,
+            "REVIEWERS" => Self::Reviewers,
+            other => Self::Other(other.to_lowercase()),
+        }
+    }
+
+    /// Field type used to parse values set on a field of this data type
+    ///
+    /// Labels fields take `MultiSelect` values. Returns `None` for data types
+    /// that can not be set through a field value, such as iterations.
+    pub fn custom_field_type(&self) -> Option<ProjectCustomFieldType> {
+        match self {
+            Self::Text => Some(ProjectCustomFieldType::Text),
+            Self::Number => Some(ProjectCustomFieldType::Number),
+            Self::Date => Some(ProjectCustomFieldType::Date),
+            Self::SingleSelect => Some(ProjectCustomFieldType::SingleSelect),
+            Self::Labels => Some(ProjectCustomFieldType::MultiSelect),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ProjectFieldDataType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Text => "text",
+            Self::Number => "number",
+            Self::Date => "date",
+            Self::SingleSelect => "single_select",
+            Self::Iteration => "iteration",
+            Self::Title => "title",
+            Self::Assignees => "assignees",
+            Self::Labels => "labels",
+            Self::LinkedPullRequests => "linked_pull_requests",
+            Self::Milestone => "milestone",
+            Self::Repository => "repository",
+            Self::Reviewers => "reviewers",
+            Self::Other(name) => name,
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Option of a single select project field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectSingleSelectOption {
+    pub id: String,
+    pub name: String,
+    pub color: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Iteration of an iteration project field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectIteration {
+    pub id: String,
+    pub title: String,
+    pub start_date: NaiveDate,
+    /// Duration in days
+    pub duration: u64,
+}
+
+/// Configuration of an iteration project field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectIterationConfiguration {
+    /// Default iteration duration in days
+    pub duration: u64,
+    /// Day of the week iterations start on (1 = Monday, 7 = Sunday)
+    pub start_day: u64,
+    pub iterations: Vec<ProjectIteration>,
+    pub completed_iterations: Vec<ProjectIteration>,
+}
+
+/// Field definition of a project
+///
+/// `options` is only populated for single select fields and
+/// `iteration_configuration` only for iteration fields.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectField {
+    pub field_id: ProjectFieldId,
+    pub name: String,
+    pub data_type: ProjectFieldDataType,
+    pub options: Vec<ProjectSingleSelectOption>,
+    pub iteration_configuration: Option<ProjectIterationConfiguration>,
+}
+
+impl ProjectField {
+    /// Parse a field from a GraphQL `ProjectV2FieldConfiguration` node
+    ///
+    /// Returns `None` if the node lacks an ID, name or data type.
+    pub fn from_graphql_value(value: &serde_json::Value) -> Option<Self> {
+        let str_of = |value: &serde_json::Value, key: &str| {
+            value
+                .get(key)
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+        };
+
+        let options = value
+            .get("options")
+            .and_then(|options| options.as_array())
+            .map(|options| {
+                options
+                    .iter()
+                    .filter_map(|option| {
+                        Some(ProjectSingleSelectOption {
+                            id: str_of(option, "id")?,
+                            name: str_of(option, "name")?,
+                            color: str_of(option, "color"),
+                            description: str_of(option, "description").filter(|d| !d.is_empty()),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let parse_iterations = |value: Option<&serde_json::Value>| -> Vec<ProjectIteration> {
+            value
+                .and_then(|iterations| iterations.as_array())
+                .map(|iterations| {
+                    iterations
+                        .iter()
+                        .filter_map(|iteration| {
+                            Some(ProjectIteration {
+                                id: str_of(iteration, "id")?,
+                                title: str_of(iteration, "title")?,
+                                start_date: str_of(iteration, "startDate")?.parse().ok()?,
+                                duration: iteration.get("duration")?.as_u64()?,
+                            })
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        let iteration_configuration = value.get("configuration").and_then(|configuration| {
+            Some(ProjectIterationConfiguration {
+                duration: configuration.get("duration")?.as_u64()?,
+                start_day: configuration.get("startDay")?.as_u64()?,
+                iterations: parse_iterations(configuration.get("iterations")),
+                completed_iterations: parse_iterations(configuration.get("completedIterations")),
+            })
+        });
+
+        Some(Self {
+            field_id: ProjectFieldId::new(str_of(value, "id")?),
+            name: str_of(value, "name")?,
+            data_type: ProjectFieldDataType::from_graphql(value.get("dataType")?.as_str()?),
+            options,
+            iteration_configuration,
+        })
+    }
+
+    /// Find a single select option by name or option ID
+    ///
+    /// An exact name match wins over a case-insensitive one, and names win
+    /// over option IDs, so raw option IDs keep working as input.
+    pub fn find_option(&self, name_or_id: &str) -> Option<&ProjectSingleSelectOption> {
+        self.options
+            .iter()
+            .find(|option| option.name == name_or_id)
+            .or_else(|| {
+                self.options
+                    .iter()
+                    .find(|option| option.name.eq_ignore_ascii_case(name_or_id))
+            })
+            .or_else(|| self.options.iter().find(|option| option.id == name_or_id))
+    }
+}
+
+/// Find a project field by name or field ID
+///
+/// An exact name match wins. Otherwise the name is matched case-insensitively,
+/// which must identify a single field, and raw field IDs are accepted last.
+pub fn find_project_field<'a>(
+    fields: &'a [ProjectField],
+    name_or_id: &str,
+) -> Option<&'a ProjectField> {
+    if let Some(field) = fields.iter().find(|field| field.name == name_or_id) {
+        return Some(field);
+    }
+
+    let mut matches = fields
+        .iter()
+        .filter(|field| field.name.eq_ignore_ascii_case(name_or_id));
+    match (matches.next(), matches.next()) {
+        (Some(field), None) => Some(field),
+        (Some(_), Some(_)) => None,
+        (None, _) => fields
+            .iter()
+            .find(|field| field.field_id.value() == name_or_id),
+    }
+}
+
+/// Project metadata together with its field definitions and item count
+///
+/// Returned by `get_project` as a read entry point before editing a project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectDetails {
+    pub project: Project,
+    pub readme: Option<String>,
+    pub visibility: ProjectVisibility,
+    pub state: ProjectState,
+    pub fields: Vec<ProjectField>,
+    pub item_count: u64,
+}
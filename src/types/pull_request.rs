@@ -181,6 +181,43 @@ pub struct PullRequest {
     pub mergeable: Option<bool>,
 }
 
+/// Pull request read with at most a given number of its discussion comments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestDetails {
+    #[serde(flatten)]
+    pub pull_request: PullRequest,
+    /// Whether the pull request has comments beyond those in `comments`
+    pub has_more_comments: bool,
+}
+
+/// Branch a pull request merges its changes from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PullRequestHead {
+    /// Name of the head branch
+    pub branch: String,
+    /// Commit the head branch points to
+    pub sha: String,
+    /// Full name (`owner/repo`) of the repository of the head branch, `None`
+    /// if the repository was deleted
+    pub repository: Option<String>,
+    pub clone_url: Option<String>,
+    pub ssh_url: Option<String>,
+    /// Whether the head branch is in another repository than the base branch
+    pub is_fork: bool,
+    /// Whether maintainers of the base repository can push to the head branch
+    pub maintainer_can_modify: bool,
+}
+
+impl PullRequestHead {
+    /// Returns the owner of the repository of the head branch
+    pub fn owner(&self) -> Option<&str> {
+        self.repository
+            .as_deref()
+            .and_then(|repository| repository.split_once('/'))
+            .map(|(owner, _)| owner)
+    }
+}
+
 /// A comment ID specific to pull request comments
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct GitPullRequestCommentId {
@@ -236,3 +273,217 @@ impl PullRequestComment {
         }
     }
 }
+
+/// Whether GitHub can merge a pull request without conflicts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumString, Display)]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+pub enum MergeableState {
+    /// The pull request can be merged without conflicts
+    Mergeable,
+    /// The pull request has merge conflicts with the base branch
+    Conflicting,
+    /// GitHub has not finished computing mergeability yet
+    Unknown,
+}
+
+/// Detailed merge state of a pull request as computed by GitHub.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumString, Display)]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+pub enum MergeStateStatus {
+    /// The head branch is out of date with the base branch
+    Behind,
+    /// Merging is blocked by branch protection requirements
+    Blocked,
+    /// The pull request is mergeable and all requirements are met
+    Clean,
+    /// The merge commit cannot be cleanly created
+    Dirty,
+    /// The pull request is a draft
+    Draft,
+    /// Mergeable with passing commit status and pre-receive hooks
+    HasHooks,
+    /// The state cannot currently be determined
+    Unknown,
+    /// Mergeable with non-passing commit status
+    Unstable,
+}
+
+/// Review decision of a pull request based on required reviews.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumString, Display)]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+pub enum ReviewDecision {
+    /// The pull request has received an approving review
+    Approved,
+    /// Changes have been requested on the pull request
+    ChangesRequested,
+    /// A review is required before the pull request can be merged
+    ReviewRequired,
+}
+
+/// Outcome of a required status check or check run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum RequiredCheckState {
+    /// The check completed successfully (or was neutral/skipped)
+    Passed,
+    /// The check completed with a failing conclusion
+    Failed,
+    /// The check is queued or still running
+    Pending,
+    /// The check is required by branch protection but has not been reported
+    Missing,
+}
+
+/// A status check that is required for merging a pull request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RequiredCheckStatus {
+    pub name: String,
+    pub state: RequiredCheckState,
+}
+
+impl RequiredCheckStatus {
+    /// Create new required check status
+    pub fn new<S: Into<String>>(name: S, state: RequiredCheckState) -> Self {
+        Self {
+            name: name.into(),
+            state,
+        }
+    }
+}
+
+/// Branch protection requirements applied to the base branch of a pull request.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BranchProtectionSummary {
+    pub required_approving_review_count: Option<u32>,
+    pub required_status_check_contexts: Vec<String>,
+    pub requires_conversation_resolution: bool,
+    pub requires_linear_history: bool,
+    pub requires_commit_signatures: bool,
+}
+
+/// A single reason why a pull request cannot be merged right now.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MergeBlocker {
+    /// The pull request is still a draft
+    Draft,
+    /// The pull request has merge conflicts
+    MergeConflicts,
+    /// The head branch must be updated with the base branch
+    BehindBase,
+    /// A reviewer requested changes
+    ChangesRequested,
+    /// An approving review is still required
+    ReviewRequired,
+    /// A required check failed
+    FailingCheck(String),
+    /// A required check has not finished yet
+    PendingCheck(String),
+    /// A required check has not been reported at all
+    MissingCheck(String),
+    /// Branch protection blocks the merge for a reason not covered above
+    /// (e.g. unresolved conversations or unsigned commits)
+    BranchProtection,
+}
+
+impl std::fmt::Display for MergeBlocker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MergeBlocker::Draft => write!(f, "Pull request is a draft"),
+            MergeBlocker::MergeConflicts => write!(f, "Pull request has merge conflicts"),
+            MergeBlocker::BehindBase => write!(f, "Head branch is behind the base branch"),
+            MergeBlocker::ChangesRequested => write!(f, "Changes have been requested"),
+            MergeBlocker::ReviewRequired => write!(f, "An approving review is required"),
+            MergeBlocker::FailingCheck(name) => write!(f, "Required check '{}' failed", name),
+            MergeBlocker::PendingCheck(name) => {
+                write!(f, "Required check '{}' is pending", name)
+            }
+            MergeBlocker::MissingCheck(name) => {
+                write!(f, "Required check '{}' has not been reported", name)
+            }
+            MergeBlocker::BranchProtection => {
+                write!(f, "Merging is blocked by branch protection rules")
+            }
+        }
+    }
+}
+
+/// Aggregated answer to "can this pull request be merged, and if not, why?".
+///
+/// Combines mergeability, the merge state computed by GitHub, the review
+/// decision, required check results and branch protection requirements into
+/// a single list of blockers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PullRequestMergeStatus {
+    pub pull_request_id: PullRequestId,
+    pub is_draft: bool,
+    pub mergeable: MergeableState,
+    pub merge_state_status: MergeStateStatus,
+    pub review_decision: Option<ReviewDecision>,
+    pub required_checks: Vec<RequiredCheckStatus>,
+    pub branch_protection: Option<BranchProtectionSummary>,
+    pub blockers: Vec<MergeBlocker>,
+}
+
+impl PullRequestMergeStatus {
+    /// Create a new merge status, deriving the list of blockers from the inputs
+    pub fn new(
+        pull_request_id: PullRequestId,
+        is_draft: bool,
+        mergeable: MergeableState,
+        merge_state_status: MergeStateStatus,
+        review_decision: Option<ReviewDecision>,
+        required_checks: Vec<RequiredCheckStatus>,
+        branch_protection: Option<BranchProtectionSummary>,
+    ) -> Self {
+        let mut blockers = Vec::new();
+
+        if is_draft {
+            blockers.push(MergeBlocker::Draft);
+        }
+        if mergeable == MergeableState::Conflicting || merge_state_status == MergeStateStatus::Dirty
+        {
+            blockers.push(MergeBlocker::MergeConflicts);
+        }
+        if merge_state_status == MergeStateStatus::Behind {
+            blockers.push(MergeBlocker::BehindBase);
+        }
+        match review_decision {
+            Some(ReviewDecision::ChangesRequested) => blockers.push(MergeBlocker::ChangesRequested),
+            Some(ReviewDecision::ReviewRequired) => blockers.push(MergeBlocker::ReviewRequired),
+            _ => {}
+        }
+        for check in &required_checks {
+            match check.state {
+                RequiredCheckState::Passed => {}
+                RequiredCheckState::Failed => {
+                    blockers.push(MergeBlocker::FailingCheck(check.name.clone()))
+                }
+                RequiredCheckState::Pending => {
+                    blockers.push(MergeBlocker::PendingCheck(check.name.clone()))
+                }
+                RequiredCheckState::Missing => {
+                    blockers.push(MergeBlocker::MissingCheck(check.name.clone()))
+                }
+            }
+        }
+        if merge_state_status == MergeStateStatus::Blocked && blockers.is_empty() {
+            blockers.push(MergeBlocker::BranchProtection);
+        }
+
+        Self {
+            pull_request_id,
+            is_draft,
+            mergeable,
+            merge_state_status,
+            review_decision,
+            required_checks,
+            branch_protection,
+            blockers,
+        }
+    }
+
+    /// Returns true if nothing is currently known to block the merge
+    pub fn is_mergeable(&self) -> bool {
+        self.blockers.is_empty() && self.mergeable == MergeableState::Mergeable
+    }
+}
@@ -0,0 +1,31 @@
+//! Offline queue types
+//!
+//! A CLI command changing an issue or pull request that fails because GitHub
+//! cannot be reached is stored as one entry of the offline queue, with its
+//! arguments and where it was run, and replayed by `queue flush`.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Command of the offline queue
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueuedCommand {
+    /// When the command failed and was queued
+    pub queued_at: DateTime<Utc>,
+    /// CLI command, such as `issue comment`
+    pub command: String,
+    /// Arguments of the command line after the program name, without the
+    /// profile and queue options
+    pub args: Vec<String>,
+    /// Configuration profile the command ran with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// Working directory of the command, where relative paths such as body
+    /// files are resolved on replay
+    pub cwd: PathBuf,
+    /// URL of the issue or pull request the command changes; commands
+    /// creating one have none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+}
@@ -0,0 +1,46 @@
+//! Rate limit types
+//!
+//! This module describes the rate limit buckets of a token as returned by
+//! `/rate_limit`, so batch workloads can be planned around the remaining
+//! requests.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// One rate limit bucket of the token
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitBucket {
+    /// Requests allowed per window
+    pub limit: u32,
+    /// Requests made in the current window
+    pub used: u32,
+    /// Requests left in the current window
+    pub remaining: u32,
+    /// When the current window ends and `remaining` is reset to `limit`
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub reset: DateTime<Utc>,
+}
+
+impl RateLimitBucket {
+    /// Returns true if no requests are left in the current window
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+
+    /// Returns the whole minutes until the bucket resets, rounded up
+    pub fn minutes_until_reset(&self, now: DateTime<Utc>) -> i64 {
+        let seconds = (self.reset - now).num_seconds().max(0);
+        (seconds + 59) / 60
+    }
+}
+
+/// The rate limit buckets used by the tools
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimit {
+    /// REST API requests other than searches
+    pub core: RateLimitBucket,
+    /// GraphQL API points
+    pub graphql: RateLimitBucket,
+    /// Search API requests
+    pub search: RateLimitBucket,
+}
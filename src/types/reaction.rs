@@ -0,0 +1,167 @@
+//! Reaction types for issues, pull requests, and comments
+//!
+//! This module provides the emoji reaction kinds supported by GitHub and the
+//! targets reactions can be attached to.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+use crate::types::issue::{IssueCommentNumber, IssueNumber};
+use crate::types::pull_request::{PullRequestCommentNumber, PullRequestNumber};
+use crate::types::user::User;
+
+/// Emoji reaction kinds supported by GitHub.
+///
+/// Parses both the GitHub API values (`+1`, `-1`, `laugh`, ...) and
+/// readable aliases (`thumbs_up`, `thumbs_down`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumString, Display)]
+#[strum(ascii_case_insensitive)]
+pub enum ReactionKind {
+    /// 👍
+    #[serde(rename = "+1")]
+    #[strum(to_string = "+1", serialize = "thumbs_up")]
+    PlusOne,
+    /// 👎
+    #[serde(rename = "-1")]
+    #[strum(to_string = "-1", serialize = "thumbs_down")]
+    MinusOne,
+    /// 😄
+    #[serde(rename = "laugh")]
+    #[strum(to_string = "laugh")]
+    Laugh,
+    /// 😕
+    #[serde(rename = "confused")]
+    #[strum(to_string = "confused")]
+    Confused,
+    /// ❤️
+    #[serde(rename = "heart")]
+    #[strum(to_string = "heart")]
+    Heart,
+    /// 🎉
+    #[serde(rename = "hooray")]
+    #[strum(to_string = "hooray")]
+    Hooray,
+    /// 🚀
+    #[serde(rename = "rocket")]
+    #[strum(to_string = "rocket")]
+    Rocket,
+    /// 👀
+    #[serde(rename = "eyes")]
+    #[strum(to_string = "eyes")]
+    Eyes,
+}
+
+/// Wrapper type for reaction IDs providing type safety
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ReactionId(pub u64);
+
+impl ReactionId {
+    /// Create a new reaction ID
+    pub fn new(id: u64) -> Self {
+        Self(id)
+    }
+
+    /// Get the inner value
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl std::fmt::Display for ReactionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Kind of resource a reaction is attached to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum ReactionTargetType {
+    /// An issue
+    Issue,
+    /// A pull request
+    PullRequest,
+    /// A comment on an issue
+    IssueComment,
+    /// A discussion comment on a pull request
+    PullRequestComment,
+}
+
+/// Resource a reaction is attached to.
+///
+/// Pull requests share the issue reactions endpoint, and pull request
+/// discussion comments share the issue comment reactions endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReactionTarget {
+    Issue(IssueNumber),
+    PullRequest(PullRequestNumber),
+    IssueComment(IssueCommentNumber),
+    PullRequestComment(PullRequestCommentNumber),
+}
+
+impl ReactionTarget {
+    /// Build a target from its type and the issue, pull request, or comment number
+    pub fn new(target_type: ReactionTargetType, number: u64) -> Self {
+        match target_type {
+            ReactionTargetType::Issue => Self::Issue(IssueNumber::new(number as u32)),
+            ReactionTargetType::PullRequest => {
+                Self::PullRequest(PullRequestNumber::new(number as u32))
+            }
+            ReactionTargetType::IssueComment => Self::IssueComment(IssueCommentNumber::new(number)),
+            ReactionTargetType::PullRequestComment => {
+                Self::PullRequestComment(PullRequestCommentNumber::new(number))
+            }
+        }
+    }
+
+    /// Returns the issue number if the target is an issue or pull request
+    pub fn issue_number(&self) -> Option<u64> {
+        match self {
+            Self::Issue(number) => Some(number.value() as u64),
+            Self::PullRequest(number) => Some(number.value() as u64),
+            _ => None,
+        }
+    }
+
+    /// Returns the comment ID if the target is an issue or pull request comment
+    pub fn comment_number(&self) -> Option<u64> {
+        match self {
+            Self::IssueComment(number) => Some(number.value()),
+            Self::PullRequestComment(number) => Some(number.value()),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ReactionTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Issue(number) => write!(f, "issue #{}", number),
+            Self::PullRequest(number) => write!(f, "pull request #{}", number),
+            Self::IssueComment(number) => write!(f, "issue comment {}", number),
+            Self::PullRequestComment(number) => write!(f, "pull request comment {}", number),
+        }
+    }
+}
+
+/// A reaction left by a user on an issue, pull request, or comment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reaction {
+    pub id: ReactionId,
+    pub kind: ReactionKind,
+    pub user: User,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Reaction {
+    /// Create new reaction
+    pub fn new(id: ReactionId, kind: ReactionKind, user: User, created_at: DateTime<Utc>) -> Self {
+        Self {
+            id,
+            kind,
+            user,
+            created_at,
+        }
+    }
+}
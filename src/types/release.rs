@@ -0,0 +1,106 @@
+//! Release domain types
+//!
+//! This module contains the types for GitHub releases and the release
+//! settings used when creating or editing them.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Numeric identifier of a release
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ReleaseId(pub u64);
+
+impl ReleaseId {
+    /// Create a new release identifier
+    pub fn new(id: u64) -> Self {
+        Self(id)
+    }
+
+    /// Get the inner value
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl std::fmt::Display for ReleaseId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A release of a repository
+///
+/// Field names follow the REST API so releases deserialize directly from
+/// API responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Release {
+    pub id: ReleaseId,
+    pub tag_name: String,
+    pub name: Option<String>,
+    pub body: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub target_commitish: String,
+    pub html_url: String,
+    pub created_at: DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+/// Settings to change on an existing release
+///
+/// Fields left as `None` are not sent and keep their current value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReleaseUpdate {
+    pub tag_name: Option<String>,
+    pub name: Option<String>,
+    pub body: Option<String>,
+    pub draft: Option<bool>,
+    pub prerelease: Option<bool>,
+    pub target_commitish: Option<String>,
+}
+
+impl ReleaseUpdate {
+    /// Returns true if no setting would be changed
+    pub fn is_empty(&self) -> bool {
+        self.tag_name.is_none()
+            && self.name.is_none()
+            && self.body.is_none()
+            && self.draft.is_none()
+            && self.prerelease.is_none()
+            && self.target_commitish.is_none()
+    }
+
+    /// Build the REST API request body containing only the set fields
+    pub fn to_request_body(&self) -> serde_json::Value {
+        let mut body = serde_json::Map::new();
+        if let Some(tag_name) = &self.tag_name {
+            body.insert("tag_name".to_string(), tag_name.clone().into());
+        }
+        if let Some(name) = &self.name {
+            body.insert("name".to_string(), name.clone().into());
+        }
+        if let Some(release_body) = &self.body {
+            body.insert("body".to_string(), release_body.clone().into());
+        }
+        if let Some(draft) = self.draft {
+            body.insert("draft".to_string(), draft.into());
+        }
+        if let Some(prerelease) = self.prerelease {
+            body.insert("prerelease".to_string(), prerelease.into());
+        }
+        if let Some(target_commitish) = &self.target_commitish {
+            body.insert(
+                "target_commitish".to_string(),
+                target_commitish.clone().into(),
+            );
+        }
+        serde_json::Value::Object(body)
+    }
+}
+
+/// Release notes generated by GitHub from merged pull requests
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedReleaseNotes {
+    pub name: String,
+    pub body: String,
+}
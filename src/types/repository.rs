@@ -10,6 +10,7 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
 
 /// Repository URL wrapper for type safety
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
@@ -305,3 +306,173 @@ impl GitRepository {
         self.git_repository_id.clone()
     }
 }
+
+/// Visibility of a repository
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum RepositoryVisibility {
+    Public,
+    Private,
+    /// Visible to members of the enterprise owning the repository
+    Internal,
+}
+
+/// Permission of the authenticated user on a repository
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum RepositoryPermission {
+    Admin,
+    Maintain,
+    Write,
+    Triage,
+    Read,
+}
+
+impl RepositoryPermission {
+    /// Returns true if the permission allows pushing and editing issues and pull requests
+    pub fn can_write(&self) -> bool {
+        matches!(self, Self::Admin | Self::Maintain | Self::Write)
+    }
+}
+
+/// Overview of a repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Repository {
+    pub repository_id: RepositoryId,
+    pub html_url: String,
+    pub description: Option<String>,
+    pub homepage: Option<String>,
+    /// `None` for empty repositories
+    pub default_branch: Option<String>,
+    pub visibility: RepositoryVisibility,
+    pub topics: Vec<String>,
+    pub open_issue_count: u64,
+    pub open_pull_request_count: u64,
+    /// SPDX identifier of the license, or its name if it has no identifier
+    pub license: Option<String>,
+    /// Permission of the authenticated user, if it can be determined
+    pub viewer_permission: Option<RepositoryPermission>,
+    pub archived: bool,
+    pub fork: bool,
+    pub stargazer_count: u64,
+}
+
+impl Repository {
+    /// Parse a repository from a GraphQL `Repository` object
+    ///
+    /// Returns `None` if the value lacks the owner, name, URL or visibility.
+    pub fn from_graphql_value(value: &serde_json::Value) -> Option<Self> {
+        let optional_string = |pointer: &str| {
+            value
+                .pointer(pointer)
+                .and_then(|v| v.as_str())
+                .filter(|v| !v.is_empty())
+                .map(|v| v.to_string())
+        };
+        let count = |pointer: &str| value.pointer(pointer).and_then(|v| v.as_u64()).unwrap_or(0);
+
+        Some(Self {
+            repository_id: RepositoryId::new(
+                value.pointer("/owner/login")?.as_str()?,
+                value.get("name")?.as_str()?,
+            ),
+            html_url: value.get("url")?.as_str()?.to_string(),
+            description: optional_string("/description"),
+            homepage: optional_string("/homepageUrl"),
+            default_branch: optional_string("/defaultBranchRef/name"),
+            visibility: value.get("visibility")?.as_str()?.parse().ok()?,
+            topics: value
+                .pointer("/repositoryTopics/nodes")
+                .and_then(|nodes| nodes.as_array())
+                .map(|nodes| {
+                    nodes
+                        .iter()
+                        .filter_map(|node| node.pointer("/topic/name")?.as_str())
+                        .map(|name| name.to_string())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            open_issue_count: count("/issues/totalCount"),
+            open_pull_request_count: count("/pullRequests/totalCount"),
+            license: optional_string("/licenseInfo/spdxId")
+                .filter(|spdx_id| spdx_id != "NOASSERTION")
+                .or_else(|| optional_string("/licenseInfo/name")),
+            viewer_permission: value
+                .get("viewerPermission")
+                .and_then(|v| v.as_str())
+                .and_then(|v| v.parse().ok()),
+            archived: value
+                .get("isArchived")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            fork: value
+                .get("isFork")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            stargazer_count: count("/stargazerCount"),
+        })
+    }
+}
+
+/// Editable settings of a repository
+///
+/// Used both to describe the changes to apply and to report the resulting
+/// settings. Fields left as `None` are not sent and keep their current value.
+/// Field names follow the REST API so settings deserialize directly from
+/// repository responses.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositorySettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    /// Topics replace the current topics as a whole
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topics: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_issues: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_wiki: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_projects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_squash_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_merge_commit: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_rebase_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_branch_on_merge: Option<bool>,
+}
+
+impl RepositorySettings {
+    /// Returns true if no setting would be changed
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns true if any setting other than the topics would be changed
+    ///
+    /// Topics are updated through a separate endpoint.
+    pub fn has_repository_fields(&self) -> bool {
+        Self {
+            topics: None,
+            ..self.clone()
+        } != Self::default()
+    }
+
+    /// Build the repository update request body containing only the set fields
+    ///
+    /// Topics are excluded; they are replaced through the topics endpoint.
+    pub fn to_request_body(&self) -> serde_json::Value {
+        let mut body = serde_json::to_value(self).unwrap_or_default();
+        if let Some(body) = body.as_object_mut() {
+            body.remove("topics");
+        }
+        body
+    }
+}
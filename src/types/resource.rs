@@ -0,0 +1,137 @@
+//! MCP resource types
+//!
+//! Issues and pull requests are exposed as MCP resources addressed by
+//! `github-edit://owner/repo/issues/123` and `github-edit://owner/repo/pulls/123`,
+//! so clients can read them, and get notified of their updates, without
+//! calling tools.
+
+use chrono::{DateTime, Utc};
+
+use crate::types::issue::IssueId;
+use crate::types::pull_request::PullRequestId;
+use crate::types::repository::RepositoryId;
+
+/// URI scheme of the resources served by github-edit
+pub const RESOURCE_URI_SCHEME: &str = "github-edit";
+
+/// URI template of issue resources
+pub const ISSUE_RESOURCE_URI_TEMPLATE: &str = "github-edit://{owner}/{repo}/issues/{number}";
+
+/// URI template of pull request resources
+pub const PULL_REQUEST_RESOURCE_URI_TEMPLATE: &str = "github-edit://{owner}/{repo}/pulls/{number}";
+
+/// MIME type of the resource contents
+pub const RESOURCE_MIME_TYPE: &str = "application/json";
+
+/// Issue or pull request addressed by a resource URI
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ResourceUri {
+    /// `github-edit://owner/repo/issues/123`
+    Issue(IssueId),
+    /// `github-edit://owner/repo/pulls/123`
+    PullRequest(PullRequestId),
+}
+
+impl ResourceUri {
+    /// Parse a resource URI
+    ///
+    /// # Examples
+    /// - `github-edit://octocat/hello/issues/42` - issue #42
+    /// - `github-edit://octocat/hello/pulls/7` - pull request #7
+    pub fn parse(uri: &str) -> Result<Self, String> {
+        let path = uri
+            .strip_prefix(RESOURCE_URI_SCHEME)
+            .and_then(|rest| rest.strip_prefix("://"))
+            .ok_or_else(|| {
+                format!(
+                    "Invalid resource URI '{}': expected the {}:// scheme",
+                    uri, RESOURCE_URI_SCHEME
+                )
+            })?;
+
+        let segments: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+        let [owner, repo, kind, number] = segments.as_slice() else {
+            return Err(format!(
+                "Invalid resource URI '{}': expected {}://owner/repo/issues/N or {}://owner/repo/pulls/N",
+                uri, RESOURCE_URI_SCHEME, RESOURCE_URI_SCHEME
+            ));
+        };
+        if owner.is_empty() || repo.is_empty() {
+            return Err(format!(
+                "Invalid resource URI '{}': owner and repository are required",
+                uri
+            ));
+        }
+        let number = number
+            .parse::<u32>()
+            .map_err(|e| format!("Invalid resource URI '{}': bad number: {}", uri, e))?;
+
+        let repository_id = RepositoryId::new(*owner, *repo);
+        match *kind {
+            "issues" => Ok(Self::Issue(IssueId::new(repository_id, number))),
+            "pulls" => Ok(Self::PullRequest(PullRequestId::new(repository_id, number))),
+            other => Err(format!(
+                "Invalid resource URI '{}': unknown resource kind '{}' (expected issues or pulls)",
+                uri, other
+            )),
+        }
+    }
+
+    /// Returns the repository of the issue or pull request
+    pub fn repository_id(&self) -> &RepositoryId {
+        match self {
+            Self::Issue(issue_id) => &issue_id.git_repository,
+            Self::PullRequest(pull_request_id) => &pull_request_id.git_repository,
+        }
+    }
+
+    /// Returns the issue or pull request number
+    pub fn number(&self) -> u32 {
+        match self {
+            Self::Issue(issue_id) => issue_id.number,
+            Self::PullRequest(pull_request_id) => pull_request_id.number,
+        }
+    }
+
+    /// Returns the display name of the resource, e.g. `octocat/hello#42`
+    pub fn name(&self) -> String {
+        let repository_id = self.repository_id();
+        format!(
+            "{}/{}#{}",
+            repository_id.owner(),
+            repository_id.repo_name(),
+            self.number()
+        )
+    }
+}
+
+impl std::fmt::Display for ResourceUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            Self::Issue(_) => "issues",
+            Self::PullRequest(_) => "pulls",
+        };
+        let repository_id = self.repository_id();
+        write!(
+            f,
+            "{}://{}/{}/{}/{}",
+            RESOURCE_URI_SCHEME,
+            repository_id.owner(),
+            repository_id.repo_name(),
+            kind,
+            self.number()
+        )
+    }
+}
+
+/// Contents of an issue or pull request resource
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceSnapshot {
+    /// Title of the issue or pull request
+    pub title: String,
+    /// When the issue or pull request was last updated, used to detect
+    /// changes of subscribed resources
+    pub updated_at: DateTime<Utc>,
+    /// The issue or pull request serialized as JSON
+    pub text: String,
+}
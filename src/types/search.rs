@@ -0,0 +1,227 @@
+//! Search domain types
+//!
+//! This module contains the types for searching issues, pull requests,
+//! repositories and users with the GitHub search query syntax (e.g.
+//! `repo:owner/repo label:bug is:open`), the sort options of each search
+//! and the results it returns.
+
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+use crate::types::pagination::{Page, Pagination};
+
+/// Maximum number of results the search API returns for a single query
+///
+/// Results beyond the first 1000 cannot be paged to, so queries matching
+/// more items should be narrowed down instead.
+pub const MAX_SEARCH_RESULTS: u64 = 1000;
+
+/// Order of search results
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum, Display, EnumString,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum SearchOrder {
+    #[value(name = "asc")]
+    Asc,
+    #[value(name = "desc")]
+    Desc,
+}
+
+/// Field to sort issue and pull request search results by
+///
+/// Results are sorted by best match when no sort field is given.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum, Display, EnumString,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum IssueSearchSort {
+    #[value(name = "comments")]
+    Comments,
+    #[value(name = "reactions")]
+    Reactions,
+    #[value(name = "interactions")]
+    Interactions,
+    #[value(name = "created")]
+    Created,
+    #[value(name = "updated")]
+    Updated,
+}
+
+/// Field to sort repository search results by
+///
+/// Results are sorted by best match when no sort field is given.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum, Display, EnumString,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case", ascii_case_insensitive)]
+pub enum RepositorySearchSort {
+    #[value(name = "stars")]
+    Stars,
+    #[value(name = "forks")]
+    Forks,
+    #[value(name = "help-wanted-issues")]
+    HelpWantedIssues,
+    #[value(name = "updated")]
+    Updated,
+}
+
+/// Field to sort user search results by
+///
+/// Results are sorted by best match when no sort field is given.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum, Display, EnumString,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum UserSearchSort {
+    #[value(name = "followers")]
+    Followers,
+    #[value(name = "repositories")]
+    Repositories,
+    #[value(name = "joined")]
+    Joined,
+}
+
+/// Build the query string for a search request
+///
+/// # Arguments
+/// * `query` - The search query (e.g. "repo:owner/repo is:open")
+/// * `sort` - Optional field to sort by; best match is used when omitted
+/// * `order` - Optional sort order; ignored by GitHub unless `sort` is given
+/// * `pagination` - The page of results to fetch
+pub fn search_query_string<S: std::fmt::Display>(
+    query: &str,
+    sort: Option<S>,
+    order: Option<SearchOrder>,
+    pagination: Pagination,
+) -> String {
+    let mut params = vec![format!("q={}", urlencoding::encode(query))];
+    if let Some(sort) = sort {
+        params.push(format!("sort={}", sort));
+    }
+    if let Some(order) = order {
+        params.push(format!("order={}", order));
+    }
+    params.push(format!("per_page={}", pagination.per_page));
+    params.push(format!("page={}", pagination.page));
+    params.join("&")
+}
+
+/// A page of search results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResults<T> {
+    /// Number of items matching the query across all pages
+    pub total_count: u64,
+    /// Whether the search timed out before all matching items were found
+    pub incomplete_results: bool,
+    pub page: Page<T>,
+}
+
+/// The author of an issue or pull request search result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResultUser {
+    pub login: String,
+}
+
+/// A label of an issue or pull request search result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResultLabel {
+    pub name: String,
+}
+
+/// An issue or pull request matching a search query
+///
+/// Field names follow the REST API so results deserialize directly from API
+/// responses. Pull requests are returned as issues carrying a
+/// `pull_request` object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueSearchResult {
+    pub number: u32,
+    pub title: String,
+    /// "open" or "closed"
+    pub state: String,
+    pub html_url: String,
+    /// API URL of the repository, e.g. "https://api.github.com/repos/owner/repo"
+    pub repository_url: String,
+    pub user: Option<SearchResultUser>,
+    #[serde(default)]
+    pub labels: Vec<SearchResultLabel>,
+    pub comments: u64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub pull_request: Option<serde_json::Value>,
+}
+
+impl IssueSearchResult {
+    /// Returns true if the result is a pull request
+    pub fn is_pull_request(&self) -> bool {
+        self.pull_request.is_some()
+    }
+
+    /// Returns the "owner/repo" name of the repository the result belongs to
+    pub fn repository(&self) -> &str {
+        self.repository_url
+            .strip_prefix("https://api.github.com/repos/")
+            .unwrap_or(&self.repository_url)
+    }
+
+    /// Returns the login of the author, if the account still exists
+    pub fn author(&self) -> Option<&str> {
+        self.user.as_ref().map(|user| user.login.as_str())
+    }
+
+    /// Returns the names of the labels
+    pub fn label_names(&self) -> Vec<&str> {
+        self.labels
+            .iter()
+            .map(|label| label.name.as_str())
+            .collect()
+    }
+}
+
+/// A repository matching a search query
+///
+/// Field names follow the REST API so results deserialize directly from API
+/// responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositorySearchResult {
+    /// "owner/repo" name of the repository
+    pub full_name: String,
+    pub html_url: String,
+    pub description: Option<String>,
+    pub private: bool,
+    pub fork: bool,
+    #[serde(default)]
+    pub archived: bool,
+    pub language: Option<String>,
+    pub stargazers_count: u64,
+    pub forks_count: u64,
+    pub open_issues_count: u64,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A user or organization matching a search query
+///
+/// Field names follow the REST API so results deserialize directly from API
+/// responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserSearchResult {
+    pub login: String,
+    pub html_url: String,
+    /// "User" or "Organization"
+    #[serde(rename = "type")]
+    pub account_type: String,
+}
+
+impl UserSearchResult {
+    /// Returns true if the account is an organization
+    pub fn is_organization(&self) -> bool {
+        self.account_type == "Organization"
+    }
+}
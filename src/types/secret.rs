@@ -0,0 +1,95 @@
+//! GitHub Actions secret and variable types
+//!
+//! This module contains the types for repository secrets and configuration
+//! variables used by GitHub Actions, and the sealed-box encryption GitHub
+//! requires for secret values.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Public key of a repository used to encrypt secret values
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionsPublicKey {
+    /// Identifier of the key, sent along with encrypted values
+    pub key_id: String,
+    /// Base64-encoded Curve25519 public key
+    pub key: String,
+}
+
+impl ActionsPublicKey {
+    /// Encrypt a secret value for this key
+    ///
+    /// GitHub expects the value encrypted as a libsodium sealed box and
+    /// encoded as base64.
+    ///
+    /// # Errors
+    /// Returns an error if the key is not a base64-encoded 32-byte key.
+    pub fn seal(&self, value: &str) -> anyhow::Result<String> {
+        let key_bytes: [u8; crypto_box::KEY_SIZE] = BASE64
+            .decode(&self.key)
+            .map_err(|e| anyhow::anyhow!("Invalid public key encoding: {}", e))?
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Invalid public key length"))?;
+        let public_key = crypto_box::PublicKey::from(key_bytes);
+
+        let sealed = public_key
+            .seal(&mut crypto_box::aead::OsRng, value.as_bytes())
+            .map_err(|e| anyhow::anyhow!("Failed to encrypt secret value: {}", e))?;
+        Ok(BASE64.encode(sealed))
+    }
+}
+
+/// A repository secret
+///
+/// Secret values cannot be read back; only their names and timestamps are
+/// returned by the API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionsSecret {
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A repository configuration variable
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionsVariable {
+    pub name: String,
+    pub value: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Check that a name is valid for a secret or variable
+///
+/// Names may only contain alphanumeric characters and underscores, must not
+/// start with a digit and must not start with the reserved `GITHUB_` prefix.
+/// GitHub stores names in uppercase and compares them case-insensitively.
+///
+/// # Errors
+/// Returns an error message describing why the name is invalid.
+pub fn validate_actions_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Name must not be empty".to_string());
+    }
+    if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(format!(
+            "Invalid name '{}'. Only alphanumeric characters and underscores are allowed",
+            name
+        ));
+    }
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(format!(
+            "Invalid name '{}'. Must not start with a digit",
+            name
+        ));
+    }
+    if name.to_ascii_uppercase().starts_with("GITHUB_") {
+        return Err(format!(
+            "Invalid name '{}'. The GITHUB_ prefix is reserved",
+            name
+        ));
+    }
+    Ok(())
+}
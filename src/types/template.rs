@@ -0,0 +1,144 @@
+//! Issue and pull request template types
+//!
+//! Templates are Markdown files such as `.github/ISSUE_TEMPLATE/bug.md`,
+//! optionally starting with YAML front matter. `{{ key }}` placeholders in a
+//! template are filled from `key=value` variables to build the body of a new
+//! issue or pull request.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Opening delimiter of a template placeholder
+const PLACEHOLDER_OPEN: &str = "{{";
+
+/// Closing delimiter of a template placeholder
+const PLACEHOLDER_CLOSE: &str = "}}";
+
+/// Delimiter line of YAML front matter
+const FRONT_MATTER_DELIMITER: &str = "---";
+
+/// Kind of item a template creates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateKind {
+    Issue,
+    PullRequest,
+}
+
+impl TemplateKind {
+    /// Directories holding the templates of this kind, in lookup order
+    ///
+    /// Pull requests fall back to the issue templates, so repositories
+    /// keeping all their templates in `.github/ISSUE_TEMPLATE` work too.
+    pub fn directories(&self) -> &'static [&'static str] {
+        match self {
+            TemplateKind::Issue => &[".github/ISSUE_TEMPLATE"],
+            TemplateKind::PullRequest => {
+                &[".github/PULL_REQUEST_TEMPLATE", ".github/ISSUE_TEMPLATE"]
+            }
+        }
+    }
+
+    /// Paths relative to the repository root a template may be stored at,
+    /// in lookup order
+    ///
+    /// `name` may be given with or without its `.md` extension.
+    pub fn template_paths(&self, name: &str) -> Vec<String> {
+        let file_name = if name.to_ascii_lowercase().ends_with(".md") {
+            name.to_string()
+        } else {
+            format!("{}.md", name)
+        };
+        self.directories()
+            .iter()
+            .map(|directory| format!("{}/{}", directory, file_name))
+            .collect()
+    }
+}
+
+/// Parse `key=value` pairs into template variables
+///
+/// # Errors
+/// Returns an error message for a pair without `=` or with an empty key.
+pub fn parse_template_vars(pairs: &[String]) -> Result<BTreeMap<String, String>, String> {
+    pairs
+        .iter()
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() => {
+                Ok((key.trim().to_string(), value.to_string()))
+            }
+            _ => Err(format!(
+                "Invalid template variable '{}'. Expected key=value",
+                pair
+            )),
+        })
+        .collect()
+}
+
+/// Render a template into a body
+///
+/// Drops the YAML front matter of the template, if any, and replaces each
+/// `{{ key }}` placeholder with the value of `key`. Text between braces that
+/// is not a variable name, such as `{{ }}`, is kept as is.
+///
+/// # Errors
+/// Returns an error message naming the placeholders without a variable, so
+/// no body is created with unfilled placeholders.
+pub fn render_template(template: &str, vars: &BTreeMap<String, String>) -> Result<String, String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut missing = BTreeSet::new();
+    let mut rest = strip_front_matter(template);
+
+    while let Some(start) = rest.find(PLACEHOLDER_OPEN) {
+        let after_open = &rest[start + PLACEHOLDER_OPEN.len()..];
+        let Some(end) = after_open.find(PLACEHOLDER_CLOSE) else {
+            break;
+        };
+        let key = after_open[..end].trim();
+        rendered.push_str(&rest[..start]);
+        if is_variable_name(key) {
+            match vars.get(key) {
+                Some(value) => rendered.push_str(value),
+                None => {
+                    missing.insert(key.to_string());
+                }
+            }
+            rest = &after_open[end + PLACEHOLDER_CLOSE.len()..];
+        } else {
+            rendered.push_str(PLACEHOLDER_OPEN);
+            rest = after_open;
+        }
+    }
+    rendered.push_str(rest);
+
+    if !missing.is_empty() {
+        return Err(format!(
+            "Template variables not given: {}",
+            missing.into_iter().collect::<Vec<_>>().join(", ")
+        ));
+    }
+    Ok(rendered)
+}
+
+/// Template without its YAML front matter
+fn strip_front_matter(template: &str) -> &str {
+    let mut lines = template.split_inclusive('\n');
+    match lines.next() {
+        Some(first) if first.trim_end() == FRONT_MATTER_DELIMITER => {}
+        _ => return template,
+    }
+    let mut offset = template.find('\n').map_or(template.len(), |i| i + 1);
+    for line in lines {
+        offset += line.len();
+        if line.trim_end() == FRONT_MATTER_DELIMITER {
+            return template[offset..].trim_start_matches(['\r', '\n']);
+        }
+    }
+    // An unterminated front matter is not front matter
+    template
+}
+
+fn is_variable_name(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
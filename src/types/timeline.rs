@@ -0,0 +1,329 @@
+//! Issue timeline types
+//!
+//! This module provides typed representations of the events recorded on the
+//! timeline of an issue or pull request, so the history of an item can be
+//! reconstructed beyond its comments, and of the cross-references linking
+//! items to each other.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::issue::IssueId;
+
+/// Issue or pull request that referenced the timeline owner
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrossReferenceSource {
+    /// Full name of the repository (`owner/repo`) of the referencing item
+    pub repository: Option<String>,
+    pub number: u64,
+    pub title: String,
+    pub url: String,
+    pub is_pull_request: bool,
+}
+
+/// Issue or pull request referencing another item, from a cross-referenced event
+///
+/// Parsed from the GraphQL `CrossReferencedEvent` timeline item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrossReference {
+    /// Full name of the repository (`owner/repo`) of the referencing item
+    pub repository: String,
+    pub number: u64,
+    pub title: String,
+    pub url: String,
+    pub is_pull_request: bool,
+    /// State of the referencing item: `OPEN`, `CLOSED` or `MERGED`
+    pub state: String,
+    /// Whether the referencing item is in another repository
+    pub is_cross_repository: bool,
+    /// Whether merging the referencing pull request will close the referenced item
+    pub will_close_target: bool,
+    /// Login of the user who made the reference
+    pub actor: Option<String>,
+    pub referenced_at: Option<DateTime<Utc>>,
+}
+
+impl CrossReference {
+    /// Parse a cross-reference from a GraphQL `CrossReferencedEvent` node
+    ///
+    /// Returns `None` if the source of the event is not readable by the
+    /// token or lacks a number or URL.
+    pub fn from_graphql_value(value: &serde_json::Value) -> Option<Self> {
+        let source = value.get("source")?;
+        let str_at = |value: &serde_json::Value, pointer: &str| {
+            value
+                .pointer(pointer)
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+        };
+
+        Some(Self {
+            repository: str_at(source, "/repository/nameWithOwner")?,
+            number: source.get("number")?.as_u64()?,
+            title: str_at(source, "/title").unwrap_or_default(),
+            url: str_at(source, "/url")?,
+            is_pull_request: source.get("__typename").and_then(|v| v.as_str())
+                == Some("PullRequest"),
+            state: str_at(source, "/state").unwrap_or_default(),
+            is_cross_repository: value
+                .get("isCrossRepository")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            will_close_target: value
+                .get("willCloseTarget")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            actor: str_at(value, "/actor/login"),
+            referenced_at: str_at(value, "/referencedAt")
+                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+                .map(|dt| dt.with_timezone(&Utc)),
+        })
+    }
+}
+
+/// Keep the first cross-reference from each referencing item
+///
+/// An item referencing the same issue several times yields one event per
+/// reference.
+pub fn dedup_cross_references(references: Vec<CrossReference>) -> Vec<CrossReference> {
+    let mut seen = std::collections::HashSet::new();
+    references
+        .into_iter()
+        .filter(|reference| seen.insert(reference.url.clone()))
+        .collect()
+}
+
+/// Kind-specific data of a timeline event
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TimelineEventKind {
+    /// A label was added
+    Labeled { label: String },
+    /// A label was removed
+    Unlabeled { label: String },
+    /// A user was assigned
+    Assigned { assignee: String },
+    /// A user was unassigned
+    Unassigned { assignee: String },
+    /// A milestone was set
+    Milestoned { milestone: String },
+    /// A milestone was removed
+    Demilestoned { milestone: String },
+    /// The title was changed
+    Renamed { from: String, to: String },
+    /// The item was referenced from another issue or pull request
+    CrossReferenced { source: CrossReferenceSource },
+    /// The item was referenced from a commit message
+    Referenced { commit_id: Option<String> },
+    /// A commit was pushed to the pull request
+    Committed { sha: String, message: String },
+    /// A comment was posted
+    Commented { comment_id: u64, body: String },
+    /// A review of the pull request was submitted
+    ///
+    /// `state` is `approved`, `changes_requested`, `commented` or `dismissed`.
+    Reviewed {
+        review_id: u64,
+        state: String,
+        body: String,
+    },
+    /// The item was closed, optionally by a commit
+    Closed { commit_id: Option<String> },
+    /// The pull request was merged
+    Merged { commit_id: Option<String> },
+    /// The item was reopened
+    Reopened,
+    /// Any other event type, kept by name
+    Other { name: String },
+}
+
+/// A single event on the timeline of an issue or pull request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssueTimelineEvent {
+    pub kind: TimelineEventKind,
+    pub actor: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl IssueTimelineEvent {
+    /// Create new timeline event
+    pub fn new(
+        kind: TimelineEventKind,
+        actor: Option<String>,
+        created_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            kind,
+            actor,
+            created_at,
+        }
+    }
+
+    /// Parse a timeline event from a GitHub REST API timeline entry
+    ///
+    /// Unknown or incomplete events are kept as `TimelineEventKind::Other`
+    /// so that no entry of the timeline is silently dropped.
+    pub fn from_api_value(value: &serde_json::Value) -> Self {
+        let str_at = |pointer: &str| {
+            value
+                .pointer(pointer)
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+        };
+        let event = str_at("/event").unwrap_or_else(|| "unknown".to_string());
+
+        let kind = match event.as_str() {
+            "labeled" => str_at("/label/name").map(|label| TimelineEventKind::Labeled { label }),
+            "unlabeled" => {
+                str_at("/label/name").map(|label| TimelineEventKind::Unlabeled { label })
+            }
+            "assigned" => {
+                str_at("/assignee/login").map(|assignee| TimelineEventKind::Assigned { assignee })
+            }
+            "unassigned" => {
+                str_at("/assignee/login").map(|assignee| TimelineEventKind::Unassigned { assignee })
+            }
+            "milestoned" => str_at("/milestone/title")
+                .map(|milestone| TimelineEventKind::Milestoned { milestone }),
+            "demilestoned" => str_at("/milestone/title")
+                .map(|milestone| TimelineEventKind::Demilestoned { milestone }),
+            "renamed" => str_at("/rename/from")
+                .zip(str_at("/rename/to"))
+                .map(|(from, to)| TimelineEventKind::Renamed { from, to }),
+            "cross-referenced" => value.pointer("/source/issue").and_then(|issue| {
+                Some(TimelineEventKind::CrossReferenced {
+                    source: CrossReferenceSource {
+                        repository: issue
+                            .pointer("/repository/full_name")
+                            .and_then(|v| v.as_str())
+                            .map(|s| s.to_string()),
+                        number: issue.get("number")?.as_u64()?,
+                        title: issue
+                            .get("title")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or_default()
+                            .to_string(),
+                        url: issue.get("html_url")?.as_str()?.to_string(),
+                        is_pull_request: issue.get("pull_request").is_some(),
+                    },
+                })
+            }),
+            "referenced" => Some(TimelineEventKind::Referenced {
+                commit_id: str_at("/commit_id"),
+            }),
+            "committed" => str_at("/sha").map(|sha| TimelineEventKind::Committed {
+                sha,
+                message: str_at("/message").unwrap_or_default(),
+            }),
+            "commented" => value.get("id").and_then(|v| v.as_u64()).map(|comment_id| {
+                TimelineEventKind::Commented {
+                    comment_id,
+                    body: str_at("/body").unwrap_or_default(),
+                }
+            }),
+            "reviewed" => value.get("id").and_then(|v| v.as_u64()).map(|review_id| {
+                TimelineEventKind::Reviewed {
+                    review_id,
+                    state: str_at("/state").unwrap_or_default().to_lowercase(),
+                    body: str_at("/body").unwrap_or_default(),
+                }
+            }),
+            "closed" => Some(TimelineEventKind::Closed {
+                commit_id: str_at("/commit_id"),
+            }),
+            "merged" => Some(TimelineEventKind::Merged {
+                commit_id: str_at("/commit_id"),
+            }),
+            "reopened" => Some(TimelineEventKind::Reopened),
+            _ => None,
+        }
+        .unwrap_or(TimelineEventKind::Other {
+            name: event.clone(),
+        });
+
+        // Comments and reviews carry their author in `user`, commits in `author`,
+        // other events in `actor`
+        let actor = str_at("/actor/login")
+            .or_else(|| str_at("/user/login"))
+            .or_else(|| str_at("/author/name"));
+        let created_at = str_at("/created_at")
+            .or_else(|| str_at("/submitted_at"))
+            .or_else(|| str_at("/author/date"))
+            .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+            .map(|dt| dt.with_timezone(&Utc));
+
+        Self::new(kind, actor, created_at)
+    }
+
+    /// Returns true if this event is a cross-reference from the given issue or pull request
+    pub fn is_cross_reference_from(&self, issue_id: &IssueId) -> bool {
+        match &self.kind {
+            TimelineEventKind::CrossReferenced { source } => {
+                let repository = format!(
+                    "{}/{}",
+                    issue_id.git_repository.owner(),
+                    issue_id.git_repository.repo_name()
+                );
+                source.number == issue_id.number as u64
+                    && source
+                        .repository
+                        .as_deref()
+                        .is_none_or(|name| name.eq_ignore_ascii_case(&repository))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for TimelineEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Labeled { label } => write!(f, "labeled '{}'", label),
+            Self::Unlabeled { label } => write!(f, "unlabeled '{}'", label),
+            Self::Assigned { assignee } => write!(f, "assigned {}", assignee),
+            Self::Unassigned { assignee } => write!(f, "unassigned {}", assignee),
+            Self::Milestoned { milestone } => write!(f, "added to milestone '{}'", milestone),
+            Self::Demilestoned { milestone } => {
+                write!(f, "removed from milestone '{}'", milestone)
+            }
+            Self::Renamed { from, to } => write!(f, "renamed from '{}' to '{}'", from, to),
+            Self::CrossReferenced { source } => write!(
+                f,
+                "cross-referenced by {} #{} ({})",
+                if source.is_pull_request {
+                    "pull request"
+                } else {
+                    "issue"
+                },
+                source.number,
+                source.url
+            ),
+            Self::Referenced { commit_id } => match commit_id {
+                Some(commit_id) => write!(f, "referenced from commit {}", commit_id),
+                None => write!(f, "referenced from a commit"),
+            },
+            Self::Committed { sha, message } => {
+                write!(
+                    f,
+                    "committed {}: {}",
+                    sha,
+                    message.lines().next().unwrap_or("")
+                )
+            }
+            Self::Commented { comment_id, .. } => write!(f, "commented (comment {})", comment_id),
+            Self::Reviewed {
+                review_id, state, ..
+            } => write!(f, "reviewed: {} (review {})", state, review_id),
+            Self::Closed { commit_id } => match commit_id {
+                Some(commit_id) => write!(f, "closed by commit {}", commit_id),
+                None => write!(f, "closed"),
+            },
+            Self::Merged { commit_id } => match commit_id {
+                Some(commit_id) => write!(f, "merged as commit {}", commit_id),
+                None => write!(f, "merged"),
+            },
+            Self::Reopened => write!(f, "reopened"),
+            Self::Other { name } => write!(f, "{}", name),
+        }
+    }
+}
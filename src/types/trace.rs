@@ -0,0 +1,31 @@
+//! Trace types
+//!
+//! Each MCP tool call is given a trace ID. The events of the call, such as
+//! the GitHub requests it sends and the retries of failed requests, are
+//! reported with that ID, which is also named in the error messages of the
+//! call.
+
+use serde::{Deserialize, Serialize};
+
+/// Severity of a trace event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TraceLevel {
+    Debug,
+    Info,
+    Warning,
+    Error,
+}
+
+/// Event of a traced tool call
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceEvent {
+    /// Trace ID of the tool call
+    pub trace_id: String,
+    pub level: TraceLevel,
+    /// Human readable description of the event
+    pub message: String,
+    /// Structured details of the event, e.g. the method, URL and status of a
+    /// GitHub request
+    pub data: serde_json::Value,
+}
@@ -0,0 +1,51 @@
+//! Repository transfer types
+//!
+//! This module provides the representation of repository transfers, which
+//! GitHub completes asynchronously after the transfer request is accepted.
+
+use crate::types::repository::RepositoryId;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+/// State of a repository transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum RepositoryTransferStatus {
+    /// The repository is not yet available under the new owner. Transfers to
+    /// a user stay pending until the user accepts the transfer.
+    Pending,
+    /// The repository is available under the new owner
+    Completed,
+}
+
+/// A repository transfer to a new owner
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepositoryTransfer {
+    /// The repository under its new owner
+    pub repository_id: RepositoryId,
+    pub html_url: String,
+    pub status: RepositoryTransferStatus,
+}
+
+impl RepositoryTransfer {
+    /// Parse a pending transfer from the REST API response of a transfer request
+    ///
+    /// The response describes the repository under its new owner. Returns
+    /// `None` if the value lacks the owner, name or URL.
+    pub fn from_api_value(value: &serde_json::Value) -> Option<Self> {
+        Some(Self {
+            repository_id: RepositoryId::new(
+                value.pointer("/owner/login")?.as_str()?,
+                value.get("name")?.as_str()?,
+            ),
+            html_url: value.get("html_url")?.as_str()?.to_string(),
+            status: RepositoryTransferStatus::Pending,
+        })
+    }
+
+    /// Returns true if the repository is available under the new owner
+    pub fn is_completed(&self) -> bool {
+        self.status == RepositoryTransferStatus::Completed
+    }
+}
@@ -0,0 +1,15 @@
+//! Response truncation types
+//!
+//! Tool responses longer than the configured maximum are cut and end with a
+//! marker naming a continuation token; the `get_response_continuation` MCP
+//! tool returns the rest of the response for that token.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Arguments of the `get_response_continuation` tool
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ContinuationRequest {
+    /// Continuation token from the truncation marker of the previous response
+    pub continuation_token: String,
+}
@@ -0,0 +1,34 @@
+//! Webhook event types
+//!
+//! GitHub webhook deliveries received by the webhook listener are converted
+//! into `WebhookEvent`s and forwarded to the connected MCP clients.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// GitHub webhook delivery converted for MCP clients
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookEvent {
+    /// Event name from the `X-GitHub-Event` header, e.g. `issues`
+    pub event: String,
+    /// Action of the event, e.g. `opened` or `review_requested`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// Delivery ID from the `X-GitHub-Delivery` header
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delivery_id: Option<String>,
+    /// Repository of the event, e.g. `octocat/hello`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+    /// Resource URI of the issue or pull request the event is about, e.g.
+    /// `github-edit://octocat/hello/issues/42`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_uri: Option<String>,
+    /// Login of the user that triggered the event
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<String>,
+    /// One line description, e.g. `Issue octocat/hello#42 opened by hubot: Crash on save`
+    pub summary: String,
+    /// When the delivery was received
+    pub received_at: DateTime<Utc>,
+}
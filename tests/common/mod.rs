@@ -1,5 +1,9 @@
+#![allow(dead_code)]
+
 use github_edit::github::client::GitHubClient;
 use std::env;
+use std::path::Path;
+use std::process::{Command, Output};
 
 /// Create a GitHub client using the test token from environment variable
 pub fn create_test_github_client() -> GitHubClient {
@@ -8,3 +12,38 @@ pub fn create_test_github_client() -> GitHubClient {
 
     GitHubClient::new(Some(token), None).expect("Failed to create GitHub client")
 }
+
+/// Configuration with a default profile sending its requests to `api_base_url`
+pub fn profile_config(api_base_url: &str) -> String {
+    format!(
+        "default_profile = \"test\"\n\n[profiles.test]\napi_base_url = \"{}\"\ntoken = \"ghp_test\"\n",
+        api_base_url
+    )
+}
+
+/// Command running the CLI in `config_dir`, with its configuration there
+///
+/// `config` is written to `config.toml` when given. Variables of the
+/// environment that select a token, profile or log output are removed, so
+/// tests only see what they set up.
+pub fn cli_command(config_dir: &Path, config: Option<&str>) -> Command {
+    if let Some(config) = config {
+        std::fs::write(config_dir.join("config.toml"), config).unwrap();
+    }
+    let mut command = Command::new(env!("CARGO_BIN_EXE_github-edit-cli"));
+    command
+        .current_dir(config_dir)
+        .env("GITHUB_EDIT_CONFIG_DIR", config_dir)
+        .env_remove("GITHUB_EDIT_GITHUB_TOKEN")
+        .env_remove("GITHUB_EDIT_PROFILE")
+        .env_remove("GITHUB_ACTIONS")
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("RUST_LOG")
+        .env_remove("RUST_BACKTRACE");
+    command
+}
+
+/// Run the CLI with `args`, see [`cli_command`]
+pub fn cli(config_dir: &Path, config: Option<&str>, args: &[&str]) -> Output {
+    cli_command(config_dir, config).args(args).output().unwrap()
+}
@@ -0,0 +1,72 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use github_edit::types::secret::{
+    ActionsPublicKey, ActionsSecret, ActionsVariable, validate_actions_name,
+};
+use serde_json::json;
+
+/// Sealed values can only be opened with the repository's secret key
+#[test]
+fn test_public_key_seal_round_trip() {
+    let secret_key = crypto_box::SecretKey::generate(&mut crypto_box::aead::OsRng);
+    let public_key = ActionsPublicKey {
+        key_id: "568250167242549743".to_string(),
+        key: BASE64.encode(secret_key.public_key().as_bytes()),
+    };
+
+    let sealed = BASE64.decode(public_key.seal("s3cr3t").unwrap()).unwrap();
+
+    assert_eq!(secret_key.unseal(&sealed).unwrap(), b"s3cr3t");
+}
+
+/// Keys that are not base64-encoded 32-byte keys are rejected
+#[test]
+fn test_public_key_seal_invalid_key() {
+    let not_base64 = ActionsPublicKey {
+        key_id: "1".to_string(),
+        key: "not base64!".to_string(),
+    };
+    assert!(not_base64.seal("value").is_err());
+
+    let too_short = ActionsPublicKey {
+        key_id: "1".to_string(),
+        key: BASE64.encode([0u8; 16]),
+    };
+    assert!(too_short.seal("value").is_err());
+}
+
+/// Secret and variable names follow GitHub's naming rules
+#[test]
+fn test_validate_actions_name() {
+    assert!(validate_actions_name("DEPLOY_TOKEN").is_ok());
+    assert!(validate_actions_name("_private2").is_ok());
+
+    assert!(validate_actions_name("").is_err());
+    assert!(validate_actions_name("DEPLOY-TOKEN").is_err());
+    assert!(validate_actions_name("MY TOKEN").is_err());
+    assert!(validate_actions_name("2FA_CODE").is_err());
+    assert!(validate_actions_name("GITHUB_TOKEN").is_err());
+    assert!(validate_actions_name("github_sha").is_err());
+}
+
+/// Secrets and variables deserialize from the list endpoints
+#[test]
+fn test_secret_and_variable_deserialize() {
+    let secret: ActionsSecret = serde_json::from_value(json!({
+        "name": "GH_TOKEN",
+        "created_at": "2019-08-10T14:59:22Z",
+        "updated_at": "2020-01-10T14:59:22Z"
+    }))
+    .unwrap();
+    assert_eq!(secret.name, "GH_TOKEN");
+
+    let variable: ActionsVariable = serde_json::from_value(json!({
+        "name": "USERNAME",
+        "value": "octocat",
+        "created_at": "2019-08-10T14:59:22Z",
+        "updated_at": "2020-01-10T14:59:22Z"
+    }))
+    .unwrap();
+    assert_eq!(variable.name, "USERNAME");
+    assert_eq!(variable.value, "octocat");
+}
@@ -0,0 +1,242 @@
+use github_edit::audit::{AuditLog, AuditQuery, MAX_PARAMETER_CHARS, REDACTED, redact_parameters};
+use github_edit::config::AuditConfig;
+use github_edit::github::GitHubClient;
+use github_edit::github::audit::with_audit;
+use github_edit::tools::audit::ToolCallAuditor;
+use github_edit::types::audit::{AuditEntry, AuditSource};
+use github_edit::types::repository::RepositoryId;
+use rmcp::model::{CallToolResult, Content, JsonObject};
+use serde_json::json;
+use std::path::PathBuf;
+
+fn entry(tool: &str, actor: &str, success: bool, timestamp: &str) -> AuditEntry {
+    AuditEntry {
+        timestamp: timestamp.parse().unwrap(),
+        source: AuditSource::Cli,
+        actor: Some(actor.to_string()),
+        client: None,
+        tool: tool.to_string(),
+        parameters: json!({}),
+        success,
+        error: (!success).then(|| "GitHub API error 403".to_string()),
+        trace_id: None,
+        requests: Vec::new(),
+        undo: None,
+        undoes: None,
+    }
+}
+
+async fn label_server() -> mockito::ServerGuard {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("POST", "/repos/octocat/hello/labels")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"name": "bug", "color": "d73a4a", "description": null}"#)
+        .create_async()
+        .await;
+    server
+        .mock("GET", "/user")
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"login": "octocat", "id": 1, "name": null, "type": "User", "html_url": "https://github.com/octocat"}"#)
+        .create_async()
+        .await;
+    server
+}
+
+/// Entries are appended as lines and read back filtered, newest last
+#[test]
+fn test_audit_log_append_and_query() {
+    let dir = tempfile::tempdir().unwrap();
+    let log = AuditLog::new(dir.path().join("logs").join("audit.jsonl"));
+    assert!(log.read(&AuditQuery::default()).unwrap().is_empty());
+
+    log.append(&entry(
+        "issue create",
+        "octocat",
+        true,
+        "2024-01-01T00:00:00Z",
+    ))
+    .unwrap();
+    log.append(&entry(
+        "create_label",
+        "hubot",
+        false,
+        "2024-02-01T00:00:00Z",
+    ))
+    .unwrap();
+    std::fs::OpenOptions::new()
+        .append(true)
+        .open(log.path())
+        .and_then(|mut file| std::io::Write::write_all(&mut file, b"{\"truncated\n"))
+        .unwrap();
+    log.append(&entry(
+        "create_label",
+        "octocat",
+        true,
+        "2024-03-01T00:00:00Z",
+    ))
+    .unwrap();
+
+    assert_eq!(log.read(&AuditQuery::default()).unwrap().len(), 3);
+    let labels = log
+        .read(&AuditQuery {
+            tool: Some("create_label".to_string()),
+            ..AuditQuery::default()
+        })
+        .unwrap();
+    assert_eq!(labels.len(), 2);
+    let failed = log
+        .read(&AuditQuery {
+            failed_only: true,
+            ..AuditQuery::default()
+        })
+        .unwrap();
+    assert_eq!(failed[0].actor.as_deref(), Some("hubot"));
+    let recent = log
+        .read(&AuditQuery {
+            actor: Some("octocat".to_string()),
+            since: Some("2024-01-15T00:00:00Z".parse().unwrap()),
+            ..AuditQuery::default()
+        })
+        .unwrap();
+    assert_eq!(recent.len(), 1);
+    let newest = log
+        .read(&AuditQuery {
+            limit: Some(1),
+            ..AuditQuery::default()
+        })
+        .unwrap();
+    assert_eq!(
+        newest[0].timestamp.to_rfc3339(),
+        "2024-03-01T00:00:00+00:00"
+    );
+}
+
+/// Tokens and secret values are redacted, also within batch operations,
+/// and long values are cut
+#[test]
+fn test_redact_parameters() {
+    let secret = redact_parameters(
+        "set_repo_secret",
+        json!({ "repository_url": "octocat/hello", "name": "DEPLOY", "value": "s3cr3t" }),
+    );
+    assert_eq!(secret["value"], REDACTED);
+    assert_eq!(secret["name"], "DEPLOY");
+
+    let variable = redact_parameters("set_variable", json!({ "value": "staging" }));
+    assert_eq!(variable["value"], "staging");
+
+    let batch = redact_parameters(
+        "execute_batch",
+        json!({ "operations": [
+            { "tool": "set_repo_secret", "params": { "value": "s3cr3t" } },
+            { "tool": "auth login", "params": { "token": "ghp_x" } }
+        ] }),
+    );
+    assert_eq!(batch["operations"][0]["params"]["value"], REDACTED);
+    assert_eq!(batch["operations"][1]["params"]["token"], REDACTED);
+
+    let long = redact_parameters(
+        "create_or_update_file",
+        json!({ "content": "a".repeat(2000) }),
+    );
+    let content = long["content"].as_str().unwrap();
+    assert!(content.starts_with(&"a".repeat(MAX_PARAMETER_CHARS)));
+    assert!(content.ends_with("... (2000 characters)"));
+}
+
+/// Only write requests are recorded
+#[tokio::test]
+async fn test_with_audit_records_writes() {
+    let server = label_server().await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+    let repository = RepositoryId::new("octocat", "hello");
+
+    let (label, requests) = with_audit(async {
+        client.get_authenticated_user().await.unwrap();
+        client
+            .create_label(&repository, "bug", Some("d73a4a"), None)
+            .await
+    })
+    .await;
+
+    assert_eq!(label.unwrap().name, "bug");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert!(requests[0].url.ends_with("/repos/octocat/hello/labels"));
+    assert_eq!(requests[0].status, Some(201));
+}
+
+/// Tool calls that write are appended with their actor and redacted
+/// arguments; calls that only read are not
+#[tokio::test]
+async fn test_tool_call_auditor() {
+    let server = label_server().await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let log = AuditLog::new(dir.path().join("audit.jsonl"));
+    let auditor = ToolCallAuditor::new(log.clone());
+    let arguments: JsonObject = serde_json::from_value(json!({
+        "repository_url": "octocat/hello",
+        "name": "bug",
+        "token": "ghp_secret"
+    }))
+    .unwrap();
+
+    let result = auditor
+        .audit_call(
+            &client,
+            "create_label",
+            Some(&arguments),
+            Some("test-client".to_string()),
+            None,
+            async {
+                client
+                    .create_label(&RepositoryId::new("octocat", "hello"), "bug", None, None)
+                    .await
+                    .unwrap();
+                Ok(CallToolResult::success(vec![Content::text(
+                    "Created label bug",
+                )]))
+            },
+        )
+        .await
+        .unwrap();
+    assert_ne!(result.is_error, Some(true));
+
+    auditor
+        .audit_call(&client, "list_labels", None, None, None, async {
+            Ok(CallToolResult::success(vec![Content::text("No labels")]))
+        })
+        .await
+        .unwrap();
+
+    let entries = log.read(&AuditQuery::default()).unwrap();
+    assert_eq!(entries.len(), 1);
+    let entry = &entries[0];
+    assert_eq!(entry.source, AuditSource::Mcp);
+    assert_eq!(entry.actor.as_deref(), Some("octocat"));
+    assert_eq!(entry.client.as_deref(), Some("test-client"));
+    assert!(entry.success);
+    assert_eq!(entry.parameters["token"], REDACTED);
+    assert_eq!(entry.requests.len(), 1);
+}
+
+/// The log is next to the configuration file unless moved or disabled
+#[test]
+fn test_audit_config_log_path() {
+    let disabled = AuditConfig {
+        enabled: Some(false),
+        path: Some(PathBuf::from("/tmp/audit.jsonl")),
+    };
+    assert_eq!(disabled.log_path().unwrap(), None);
+
+    let config: AuditConfig = toml::from_str("path = \"/var/log/audit.jsonl\"\n").unwrap();
+    assert_eq!(
+        config.log_path().unwrap(),
+        Some(PathBuf::from("/var/log/audit.jsonl"))
+    );
+}
@@ -0,0 +1,203 @@
+use chrono::{TimeZone, Utc};
+use github_edit::github::GitHubClient;
+use github_edit::tools::functions::auth::{
+    describe_auth_status, describe_authenticated_user, describe_rate_limit, get_authenticated_user,
+};
+use github_edit::types::auth::{
+    AccountType, AuthStatus, TokenKind, expand_scopes, parse_scopes_header,
+};
+use github_edit::types::rate_limit::RateLimit;
+
+fn status(scopes: Option<&str>) -> AuthStatus {
+    AuthStatus {
+        login: "octocat".to_string(),
+        name: Some("The Octocat".to_string()),
+        token_kind: TokenKind::Classic,
+        scopes: scopes.map(parse_scopes_header),
+        api_base_url: "https://api.github.com".to_string(),
+    }
+}
+
+fn unmet_areas(status: &AuthStatus) -> Vec<&'static str> {
+    status
+        .unmet_requirements()
+        .iter()
+        .map(|requirement| requirement.area)
+        .collect()
+}
+
+/// Token kinds are recognized by their prefix
+#[test]
+fn test_token_kind_from_token() {
+    assert_eq!(TokenKind::from_token("ghp_abc"), TokenKind::Classic);
+    assert_eq!(
+        TokenKind::from_token("github_pat_11ABC_def"),
+        TokenKind::FineGrained
+    );
+    assert_eq!(TokenKind::from_token("gho_abc"), TokenKind::OAuth);
+    assert_eq!(TokenKind::from_token("ghs_abc"), TokenKind::GitHubApp);
+    assert_eq!(TokenKind::from_token("0123abcd"), TokenKind::Unknown);
+    assert_eq!(TokenKind::FineGrained.to_string(), "fine-grained");
+    assert_eq!(TokenKind::OAuth.to_string(), "oauth");
+}
+
+/// The scopes header is a comma separated list
+#[test]
+fn test_parse_scopes_header() {
+    assert_eq!(
+        parse_scopes_header("repo, read:org,project"),
+        vec!["repo", "read:org", "project"]
+    );
+    assert!(parse_scopes_header("").is_empty());
+}
+
+/// Broader scopes grant the scopes they include
+#[test]
+fn test_expand_scopes() {
+    let expanded = expand_scopes(&parse_scopes_header("repo, admin:org"));
+
+    assert!(expanded.contains("public_repo"));
+    assert!(expanded.contains("repo:status"));
+    assert!(expanded.contains("read:org"));
+    assert!(!expanded.contains("project"));
+}
+
+/// Tool groups whose scopes are missing are reported
+#[test]
+fn test_unmet_requirements() {
+    assert_eq!(
+        unmet_areas(&status(Some("repo"))),
+        vec!["Projects", "Organization teams", "Workflow files"]
+    );
+    assert!(unmet_areas(&status(Some("repo, project, read:org, workflow"))).is_empty());
+
+    let public_only = status(Some("public_repo, project"));
+    assert!(public_only.is_public_repo_only());
+    assert!(unmet_areas(&public_only).contains(&"Secrets and variables"));
+    assert!(unmet_areas(&public_only).contains(&"Notifications"));
+
+    // Tokens without scopes cannot be checked up front
+    assert!(status(None).unmet_requirements().is_empty());
+}
+
+/// The description names the user, the scopes and the unusable tools
+#[test]
+fn test_describe_auth_status() {
+    let lines = describe_auth_status(&status(Some("repo")));
+
+    assert_eq!(
+        lines[0],
+        "Authenticated as octocat (The Octocat) against https://api.github.com"
+    );
+    assert!(lines.contains(&"Scopes: repo".to_string()));
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.starts_with("- Projects") && line.ends_with("needs project"))
+    );
+
+    let lines = describe_auth_status(&status(None));
+    assert!(lines.iter().any(|line| line.contains("not reported")));
+}
+
+/// The authenticated account is read from `/user` with the scopes header
+#[tokio::test]
+async fn test_get_authenticated_user() {
+    let mut server = mockito::Server::new_async().await;
+    let _user_mock = server
+        .mock("GET", "/user")
+        .with_header("content-type", "application/json")
+        .with_header("x-oauth-scopes", "repo, project")
+        .with_body(
+            r#"{"login": "octocat", "id": 583231, "name": "The Octocat", "type": "User", "html_url": "https://github.com/octocat"}"#,
+        )
+        .create_async()
+        .await;
+
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+    let user = get_authenticated_user(&client).await.unwrap();
+
+    assert_eq!(user.login, "octocat");
+    assert_eq!(user.account_type, AccountType::User);
+    assert_eq!(user.id, 583231);
+    assert_eq!(
+        user.scopes,
+        Some(vec!["repo".to_string(), "project".to_string()])
+    );
+
+    let lines = describe_authenticated_user(&user);
+    assert_eq!(
+        lines[0],
+        "Authenticated as octocat (The Octocat) (user account)"
+    );
+    assert!(lines.contains(&"Scopes: repo, project".to_string()));
+}
+
+/// Bot accounts are recognized, and tokens without scopes say so
+#[test]
+fn test_describe_bot_account() {
+    let user = github_edit::types::auth::AuthenticatedUser {
+        login: "deploy-app[bot]".to_string(),
+        name: None,
+        account_type: AccountType::from_api_type("Bot"),
+        id: 1,
+        html_url: None,
+        scopes: None,
+    };
+
+    let lines = describe_authenticated_user(&user);
+    assert_eq!(lines[0], "Authenticated as deploy-app[bot] (bot account)");
+    assert!(lines[1].contains("cannot be assigned"));
+    assert!(lines.iter().any(|line| line.contains("not reported")));
+    assert_eq!(
+        AccountType::from_api_type("Organization"),
+        AccountType::Organization
+    );
+}
+
+const RATE_LIMIT_RESPONSE: &str = r#"{
+    "core": {"limit": 5000, "used": 10, "remaining": 4990, "reset": 1760000600},
+    "search": {"limit": 30, "used": 30, "remaining": 0, "reset": 1760000060},
+    "graphql": {"limit": 5000, "used": 0, "remaining": 5000, "reset": 1760003600},
+    "integration_manifest": {"limit": 5000, "used": 0, "remaining": 5000, "reset": 1760003600}
+}"#;
+
+/// The buckets of `/rate_limit` are parsed with their epoch reset times
+#[test]
+fn test_parse_rate_limit() {
+    let rate_limit: RateLimit = serde_json::from_str(RATE_LIMIT_RESPONSE).unwrap();
+
+    assert_eq!(rate_limit.core.remaining, 4990);
+    assert_eq!(rate_limit.core.used, 10);
+    assert_eq!(
+        rate_limit.core.reset,
+        Utc.timestamp_opt(1760000600, 0).unwrap()
+    );
+    assert_eq!(rate_limit.graphql.limit, 5000);
+    assert!(rate_limit.search.is_exhausted());
+    assert!(!rate_limit.core.is_exhausted());
+}
+
+/// Reset times are reported in whole minutes, rounded up
+#[test]
+fn test_describe_rate_limit() {
+    let rate_limit: RateLimit = serde_json::from_str(RATE_LIMIT_RESPONSE).unwrap();
+    let now = Utc.timestamp_opt(1760000000, 0).unwrap();
+
+    assert_eq!(rate_limit.search.minutes_until_reset(now), 1);
+    assert_eq!(
+        rate_limit
+            .core
+            .minutes_until_reset(Utc.timestamp_opt(1760001000, 0).unwrap()),
+        0
+    );
+
+    let lines = describe_rate_limit(&rate_limit, now);
+    assert_eq!(
+        lines[0],
+        "Core (REST): 4990 of 5000 remaining (10 used), resets at 2025-10-09T09:03:20Z (in 10 min)"
+    );
+    assert!(lines[1].starts_with("GraphQL (points): 5000 of 5000 remaining"));
+    assert!(lines[2].ends_with("(in 1 min) - exhausted"));
+}
@@ -0,0 +1,161 @@
+use github_edit::tools::batch::{
+    EXECUTE_BATCH_TOOL, MAX_BATCH_OPERATIONS, execute_batch, execute_batch_tool,
+    parse_batch_request,
+};
+use github_edit::types::batch::{BatchOperationStatus, BatchReport};
+use rmcp::Error as McpError;
+use rmcp::model::{CallToolRequestParam, CallToolResult, Content, JsonObject, RawContent};
+use std::sync::Mutex;
+
+fn arguments(value: serde_json::Value) -> Option<JsonObject> {
+    serde_json::from_value(value).unwrap()
+}
+
+fn report(result: &CallToolResult) -> BatchReport {
+    let RawContent::Text(json) = &result.content[1].raw else {
+        panic!("expected a JSON report");
+    };
+    serde_json::from_str(&json.text).unwrap()
+}
+
+/// Stub dispatcher: `fail_*` tools fail, `unknown_*` tools do not exist,
+/// others succeed and echo their arguments
+async fn dispatch(request: CallToolRequestParam) -> Result<CallToolResult, McpError> {
+    if request.name.starts_with("unknown") {
+        return Err(McpError::invalid_params("tool not found", None));
+    }
+    if request.name.starts_with("fail") {
+        return Ok(CallToolResult::error(vec![Content::text("Failed to edit")]));
+    }
+    Ok(CallToolResult::success(vec![
+        Content::text(format!("Ran {}", request.name)),
+        Content::json(request.arguments.unwrap_or_default()).unwrap(),
+    ]))
+}
+
+/// Batch arguments are validated before anything runs
+#[test]
+fn test_parse_batch_request() {
+    let request = parse_batch_request(arguments(serde_json::json!({
+        "operations": [
+            {"tool": "edit_issue_title", "params": {"issue_number": 1, "title": "New"}},
+            {"tool": "update_issue_state"}
+        ]
+    })))
+    .unwrap();
+    assert_eq!(request.operations.len(), 2);
+    assert!(!request.continue_on_error);
+    assert!(request.operations[1].params.is_empty());
+
+    assert!(parse_batch_request(arguments(serde_json::json!({"operations": []}))).is_err());
+    assert!(parse_batch_request(arguments(serde_json::json!({"operations": "x"}))).is_err());
+    assert!(parse_batch_request(None).is_err());
+
+    let operations: Vec<_> = (0..=MAX_BATCH_OPERATIONS)
+        .map(|_| serde_json::json!({"tool": "edit_issue_title"}))
+        .collect();
+    let error =
+        parse_batch_request(arguments(serde_json::json!({"operations": operations}))).unwrap_err();
+    assert!(error.message.contains("at most"), "{}", error.message);
+}
+
+/// The listed tool describes its arguments
+#[test]
+fn test_execute_batch_tool_schema() {
+    let tool = execute_batch_tool();
+    assert_eq!(tool.name, EXECUTE_BATCH_TOOL);
+    let properties = tool.input_schema["properties"].as_object().unwrap();
+    assert!(properties.contains_key("operations"));
+    assert!(properties.contains_key("continue_on_error"));
+}
+
+/// By default the batch stops at the first failure and skips the rest
+#[tokio::test]
+async fn test_batch_stops_on_error() {
+    let request = parse_batch_request(arguments(serde_json::json!({
+        "operations": [
+            {"tool": "edit_issue_title", "params": {"title": "New"}},
+            {"tool": "fail_edit"},
+            {"tool": "edit_issue_body"}
+        ]
+    })))
+    .unwrap();
+    let result = execute_batch(request, false, dispatch).await;
+
+    assert_eq!(result.is_error, Some(true));
+    let report = report(&result);
+    assert_eq!((report.succeeded, report.failed, report.skipped), (1, 1, 1));
+    let statuses: Vec<_> = report.results.iter().map(|r| r.status).collect();
+    assert_eq!(
+        statuses,
+        [
+            BatchOperationStatus::Succeeded,
+            BatchOperationStatus::Failed,
+            BatchOperationStatus::Skipped
+        ]
+    );
+    assert_eq!(
+        report.results[0].message.as_deref(),
+        Some("Ran edit_issue_title")
+    );
+    assert_eq!(
+        report.results[0].data,
+        Some(serde_json::json!({"title": "New"}))
+    );
+    assert_eq!(report.results[1].message.as_deref(), Some("Failed to edit"));
+}
+
+/// With continue_on_error every operation runs, and unknown or nested
+/// tools fail without stopping the batch
+#[tokio::test]
+async fn test_batch_continues_on_error() {
+    let request = parse_batch_request(arguments(serde_json::json!({
+        "operations": [
+            {"tool": "unknown_tool"},
+            {"tool": EXECUTE_BATCH_TOOL, "params": {"operations": []}},
+            {"tool": "edit_issue_body"}
+        ],
+        "continue_on_error": true
+    })))
+    .unwrap();
+    let result = execute_batch(request, false, dispatch).await;
+
+    let report = report(&result);
+    assert_eq!((report.succeeded, report.failed, report.skipped), (1, 2, 0));
+    assert_eq!(report.results[0].message.as_deref(), Some("tool not found"));
+    assert_eq!(
+        report.results[1].message.as_deref(),
+        Some("Batches cannot be nested")
+    );
+}
+
+/// A dry run of the batch is a dry run of each mutating operation
+#[tokio::test]
+async fn test_batch_dry_run() {
+    let request = parse_batch_request(arguments(serde_json::json!({
+        "operations": [
+            {"tool": "edit_issue_title", "params": {"title": "New"}},
+            {"tool": "list_labels"}
+        ]
+    })))
+    .unwrap();
+    let calls = Mutex::new(Vec::new());
+    let result = execute_batch(request, true, |request: CallToolRequestParam| {
+        calls.lock().unwrap().push(request.clone());
+        dispatch(request)
+    })
+    .await;
+
+    assert_ne!(result.is_error, Some(true));
+    let calls = calls.into_inner().unwrap();
+    let dry_run = |index: usize| {
+        calls[index]
+            .arguments
+            .as_ref()
+            .unwrap()
+            .get("dry_run")
+            .cloned()
+    };
+    assert_eq!(dry_run(0), Some(serde_json::Value::Bool(true)));
+    assert_eq!(dry_run(1), None);
+}
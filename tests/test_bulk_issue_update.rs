@@ -0,0 +1,30 @@
+use github_edit::types::issue::{BulkIssueOperations, BulkIssueUpdateResult, IssueNumber};
+use github_edit::types::label::Label;
+
+/// A bulk update without any operation is detected as empty
+#[test]
+fn test_bulk_issue_operations_is_empty() {
+    assert!(BulkIssueOperations::default().is_empty());
+
+    let operations = BulkIssueOperations {
+        add_labels: vec![Label::from("bug".to_string())],
+        ..Default::default()
+    };
+    assert!(!operations.is_empty());
+
+    let operations = BulkIssueOperations {
+        close: true,
+        ..Default::default()
+    };
+    assert!(!operations.is_empty());
+}
+
+/// A per-issue result succeeds only when no failure was recorded
+#[test]
+fn test_bulk_issue_update_result_success() {
+    let mut result = BulkIssueUpdateResult::new(IssueNumber::new(1));
+    assert!(result.is_success());
+
+    result.failures.push("Failed to add labels".to_string());
+    assert!(!result.is_success());
+}
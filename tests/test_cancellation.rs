@@ -0,0 +1,127 @@
+use github_edit::github::GitHubClient;
+use github_edit::github::cancellation::{is_cancelled, sleep_unless_cancelled, with_cancellation};
+use github_edit::tools::functions::issue::{CANCELLED_BEFORE_UPDATE, bulk_update_issues};
+use github_edit::types::issue::{BulkIssueOperations, IssueNumber};
+use github_edit::types::repository::RepositoryId;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+
+fn client(server: &mockito::Server) -> GitHubClient {
+    GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap()
+}
+
+fn cancelled_token() -> CancellationToken {
+    let token = CancellationToken::new();
+    token.cancel();
+    token
+}
+
+/// Operations outside of a cancellation scope are never cancelled
+#[tokio::test]
+async fn test_no_cancellation_outside_scope() {
+    assert!(!is_cancelled());
+    assert!(sleep_unless_cancelled(Duration::from_millis(1)).await);
+
+    let token = CancellationToken::new();
+    assert!(!with_cancellation(token, async { is_cancelled() }).await);
+}
+
+/// A cancelled scope wakes up sleeping operations right away
+#[tokio::test]
+async fn test_sleep_unless_cancelled() {
+    let token = CancellationToken::new();
+    let canceller = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        canceller.cancel();
+    });
+
+    let started = Instant::now();
+    let completed = with_cancellation(token, sleep_unless_cancelled(Duration::from_secs(60))).await;
+
+    assert!(!completed);
+    assert!(started.elapsed() < Duration::from_secs(10));
+}
+
+/// No request is sent once the operation is cancelled
+#[tokio::test]
+async fn test_cancelled_operation_sends_no_request() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/rate_limit")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = client(&server);
+    let error = with_cancellation(cancelled_token(), client.get_rate_limit(None))
+        .await
+        .unwrap_err();
+
+    assert!(error.to_string().contains("cancelled"), "{}", error);
+    mock.assert_async().await;
+}
+
+/// Cancelling while waiting to retry stops the retries
+#[tokio::test]
+async fn test_cancellation_stops_retries() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/rate_limit")
+        .with_status(502)
+        .expect_at_least(1)
+        .create_async()
+        .await;
+
+    let token = CancellationToken::new();
+    let canceller = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        canceller.cancel();
+    });
+
+    let client = client(&server);
+    let started = Instant::now();
+    let error = with_cancellation(token, client.get_rate_limit(None))
+        .await
+        .unwrap_err();
+
+    assert!(error.to_string().contains("cancelled"), "{}", error);
+    assert!(started.elapsed() < Duration::from_secs(10));
+}
+
+/// A cancelled bulk update reports every issue it did not start
+#[tokio::test]
+async fn test_cancelled_bulk_update_skips_issues() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = client(&server);
+    let issue_numbers = [IssueNumber::new(1), IssueNumber::new(2)];
+    let operations = BulkIssueOperations {
+        close: true,
+        ..BulkIssueOperations::default()
+    };
+    let results = with_cancellation(
+        cancelled_token(),
+        bulk_update_issues(
+            &client,
+            &RepositoryId::new("octocat", "hello"),
+            &issue_numbers,
+            &operations,
+            None,
+        ),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 2);
+    for result in results {
+        assert_eq!(result.failures, vec![CANCELLED_BEFORE_UPDATE.to_string()]);
+    }
+    mock.assert_async().await;
+}
@@ -0,0 +1,95 @@
+use github_edit::types::check::{
+    CheckConclusion, CheckRun, CheckRunStatus, CombinedStatus, CommitState,
+};
+use serde_json::json;
+
+/// Check runs deserialize from the REST API representation
+#[test]
+fn test_check_run_deserialize() {
+    let check_run: CheckRun = serde_json::from_value(json!({
+        "id": 4,
+        "head_sha": "ce587453ced02b1526dfb4cb910479d431683101",
+        "name": "mighty_readme",
+        "status": "completed",
+        "conclusion": "timed_out",
+        "started_at": "2018-05-04T01:14:52Z",
+        "completed_at": "2018-05-04T01:14:52Z",
+        "html_url": "https://github.com/github/hello-world/runs/4",
+        "details_url": "https://example.com",
+        "app": {"slug": "octoapp"}
+    }))
+    .unwrap();
+
+    assert_eq!(check_run.status, CheckRunStatus::Completed);
+    assert_eq!(check_run.conclusion, Some(CheckConclusion::TimedOut));
+    assert!(check_run.is_failing());
+}
+
+/// Queued check runs have no conclusion and are not failing
+#[test]
+fn test_check_run_without_conclusion() {
+    let check_run: CheckRun = serde_json::from_value(json!({
+        "id": 5,
+        "head_sha": "ce587453ced02b1526dfb4cb910479d431683101",
+        "name": "build",
+        "status": "in_progress",
+        "conclusion": null,
+        "started_at": null,
+        "completed_at": null,
+        "html_url": null,
+        "details_url": null
+    }))
+    .unwrap();
+
+    assert_eq!(check_run.status, CheckRunStatus::InProgress);
+    assert!(!check_run.is_failing());
+    assert!(!CheckConclusion::Skipped.is_failure());
+    assert!(CheckConclusion::StartupFailure.is_failure());
+}
+
+/// Combined statuses list the failing contexts
+#[test]
+fn test_combined_status_failing_statuses() {
+    let combined: CombinedStatus = serde_json::from_value(json!({
+        "state": "failure",
+        "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+        "total_count": 2,
+        "statuses": [
+            {
+                "id": 1,
+                "state": "success",
+                "context": "ci/build",
+                "description": "Build passed",
+                "target_url": "https://ci.example.com/1",
+                "created_at": "2012-07-20T01:19:13Z",
+                "updated_at": "2012-07-20T01:19:13Z"
+            },
+            {
+                "id": 2,
+                "state": "error",
+                "context": "ci/lint",
+                "description": null,
+                "target_url": null,
+                "created_at": "2012-08-20T01:19:13Z",
+                "updated_at": "2012-08-20T01:19:13Z"
+            }
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(combined.state, CommitState::Failure);
+    let failing = combined.failing_statuses();
+    assert_eq!(failing.len(), 1);
+    assert_eq!(failing[0].context, "ci/lint");
+}
+
+/// Commit states parse case-insensitively and display in lowercase
+#[test]
+fn test_commit_state_parse() {
+    assert_eq!(
+        "SUCCESS".parse::<CommitState>().unwrap(),
+        CommitState::Success
+    );
+    assert_eq!(CommitState::Pending.to_string(), "pending");
+    assert!("passed".parse::<CommitState>().is_err());
+}
@@ -0,0 +1,196 @@
+use serde_json::json;
+use std::path::Path;
+use std::process::Command;
+
+mod common;
+
+use common::{cli_command, profile_config};
+
+/// Run git in `dir`, returning its output
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .current_dir(dir)
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "git {:?}: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap().trim().to_string()
+}
+
+/// Pull request 5 of octocat/hello from `head_repository`
+async fn mock_pull_request(
+    server: &mut mockito::Server,
+    head_repository: &str,
+    clone_url: &str,
+) -> mockito::Mock {
+    let repository = |full_name: &str, clone_url: &str| {
+        json!({
+            "id": 1,
+            "name": "hello",
+            "full_name": full_name,
+            "url": format!("https://api.github.com/repos/{}", full_name),
+            "clone_url": clone_url,
+            "ssh_url": format!("git@github.com:{}.git", full_name),
+        })
+    };
+    server
+        .mock("GET", "/repos/octocat/hello/pulls/5")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "url": "https://api.github.com/repos/octocat/hello/pulls/5",
+                "id": 5005,
+                "number": 5,
+                "maintainer_can_modify": true,
+                "head": {
+                    "ref": "feature",
+                    "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                    "repo": repository(head_repository, clone_url),
+                },
+                "base": {
+                    "ref": "main",
+                    "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                    "repo": repository("octocat/hello", "https://github.com/octocat/hello.git"),
+                },
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await
+}
+
+/// Branches of forks are fetched from a new remote of the fork, over SSH
+/// like the base repository
+#[tokio::test]
+async fn test_cli_pull_request_checkout_fork_commands() {
+    let mut server = mockito::Server::new_async().await;
+    let _pr_mock = mock_pull_request(
+        &mut server,
+        "forker/hello",
+        "https://github.com/forker/hello.git",
+    )
+    .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let work = dir.path().join("work");
+    std::fs::create_dir(&work).unwrap();
+    git(&work, &["init", "-q"]);
+    git(
+        &work,
+        &[
+            "remote",
+            "add",
+            "origin",
+            "git@github.com:octocat/hello.git",
+        ],
+    );
+
+    let output = cli_command(dir.path(), Some(&profile_config(&server.url())))
+        .current_dir(&work)
+        .args([
+            "pull-request",
+            "checkout",
+            "https://github.com/octocat/hello/pull/5",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "git remote add forker git@github.com:forker/hello.git\n\
+         git fetch forker feature\n\
+         git switch -c feature --track forker/feature\n"
+    );
+    // Nothing is run without --execute
+    assert_eq!(git(&work, &["remote"]), "origin");
+}
+
+/// With --execute, the branch of the fork is fetched and checked out, and an
+/// existing local branch is fast-forwarded on the next checkout
+#[tokio::test]
+async fn test_cli_pull_request_checkout_execute() {
+    let dir = tempfile::tempdir().unwrap();
+    let fork = dir.path().join("fork");
+    std::fs::create_dir(&fork).unwrap();
+    git(&fork, &["init", "-q", "-b", "main"]);
+    git(&fork, &["commit", "-q", "--allow-empty", "-m", "Initial"]);
+    git(dir.path(), &["clone", "-q", "fork", "work"]);
+    git(&fork, &["switch", "-q", "-c", "feature"]);
+    git(
+        &fork,
+        &["commit", "-q", "--allow-empty", "-m", "Add feature"],
+    );
+
+    let mut server = mockito::Server::new_async().await;
+    let fork_url = format!("file://{}", fork.display());
+    let _pr_mock = mock_pull_request(&mut server, "forker/hello", &fork_url).await;
+
+    let work = dir.path().join("work");
+    // The clone of the fork is named origin, so it is the remote of the fork
+    let output = cli_command(dir.path(), Some(&profile_config(&server.url())))
+        .current_dir(&work)
+        .args([
+            "pull-request",
+            "checkout",
+            "https://github.com/octocat/hello/pull/5",
+            "--branch",
+            "review-5",
+            "--remote",
+            "upstream",
+            "--execute",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Switched to branch review-5 of pull request #5\n"
+    );
+    assert_eq!(git(&work, &["branch", "--show-current"]), "review-5");
+    assert_eq!(git(&work, &["log", "-1", "--format=%s"]), "Add feature");
+
+    git(
+        &fork,
+        &["commit", "-q", "--allow-empty", "-m", "Fix review"],
+    );
+    let output = cli_command(dir.path(), Some(&profile_config(&server.url())))
+        .current_dir(&work)
+        .args([
+            "--output",
+            "json",
+            "pull-request",
+            "checkout",
+            "https://github.com/octocat/hello/pull/5",
+            "--branch",
+            "review-5",
+            "--execute",
+        ])
+        .output()
+        .unwrap();
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["result"], "checked_out");
+    assert_eq!(
+        result["commands"],
+        json!([
+            "git fetch origin feature",
+            "git switch review-5",
+            "git merge --ff-only origin/feature"
+        ])
+    );
+    assert_eq!(git(&work, &["log", "-1", "--format=%s"]), "Fix review");
+}
@@ -0,0 +1,88 @@
+use serde_json::Value;
+
+mod common;
+
+use common::{cli, profile_config};
+
+/// `--dry-run` prints the write requests of a command without sending them
+#[tokio::test]
+async fn test_dry_run_command() {
+    let mut server = mockito::Server::new_async().await;
+    let repository_mock = server
+        .mock("POST", "/graphql")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"message": "Not Found", "documentation_url": null}"#)
+        .expect_at_least(1)
+        .create_async()
+        .await;
+    let write_mock = server
+        .mock("POST", "/repos/octocat/hello/issues/1/comments")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let output = cli(
+        dir.path(),
+        Some(&profile_config(&server.url())),
+        &[
+            "--dry-run",
+            "--output",
+            "json",
+            "issue",
+            "comment",
+            "-r",
+            "https://github.com/octocat/hello",
+            "-i",
+            "1",
+            "-b",
+            "Looks good",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let result: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["result"], "dry_run");
+    assert_eq!(result["tool"], "issue comment");
+    assert_eq!(result["planned_requests"][0]["method"], "POST");
+    assert!(
+        result["planned_requests"][0]["url"]
+            .as_str()
+            .unwrap()
+            .ends_with("/repos/octocat/hello/issues/1/comments")
+    );
+    assert_eq!(result["planned_requests"][0]["body"]["body"], "Looks good");
+    assert!(
+        result["warnings"][0]
+            .as_str()
+            .unwrap()
+            .contains("not accessible")
+    );
+    assert!(!dir.path().join("audit.jsonl").exists());
+
+    repository_mock.assert_async().await;
+    write_mock.assert_async().await;
+}
+
+/// Storing or removing the token locally has no dry run
+#[tokio::test]
+async fn test_dry_run_local_command() {
+    let server = mockito::Server::new_async().await;
+    let dir = tempfile::tempdir().unwrap();
+    let output = cli(
+        dir.path(),
+        Some(&profile_config(&server.url())),
+        &["--dry-run", "auth", "logout"],
+    );
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("--dry-run only applies to commands sending requests to GitHub")
+    );
+}
@@ -0,0 +1,165 @@
+use anyhow::Context;
+use github_edit::github::error::{ApiRetryableError, ErrorKind, GitHubError};
+
+mod common;
+
+use common::{cli, profile_config};
+
+/// Failed responses are categorized by their status code alone
+#[test]
+fn test_error_kind_from_status() {
+    let cases = [
+        (401, "Bad credentials", ErrorKind::Auth),
+        (403, "Must have admin rights", ErrorKind::PermissionDenied),
+        (403, "API rate limit exceeded", ErrorKind::RateLimited),
+        (404, "Not Found", ErrorKind::NotFound),
+        (410, "Issues are disabled", ErrorKind::NotFound),
+        (422, "Validation Failed", ErrorKind::Validation),
+        (429, "Too Many Requests", ErrorKind::RateLimited),
+        (502, "Bad Gateway", ErrorKind::Network),
+        (418, "I'm a teapot", ErrorKind::Other),
+    ];
+
+    for (status, message, kind) in cases {
+        assert_eq!(ErrorKind::from_status(status, message), kind, "{}", status);
+    }
+}
+
+/// The category of a status is carried with the error, whatever its
+/// message says
+#[test]
+fn test_api_error_carries_status_kind() {
+    let error = ApiRetryableError::from_status(404, "Label 'not found' exists".to_string());
+    assert_eq!(error.kind(), ErrorKind::NotFound);
+    let error = ApiRetryableError::from_status(422, "Resource not found".to_string())
+        .with_context("Failed to create label");
+    assert_eq!(error.kind(), ErrorKind::Validation);
+    assert_eq!(
+        error.to_string(),
+        "Non-retryable error: Failed to create label: Resource not found"
+    );
+
+    assert_eq!(
+        ApiRetryableError::from_status(429, String::new()),
+        ApiRetryableError::RateLimit
+    );
+    assert!(matches!(
+        ApiRetryableError::from_status(503, String::new()),
+        ApiRetryableError::Retryable(_)
+    ));
+}
+
+/// Errors without a status code are categorized by their message
+#[test]
+fn test_error_kind_from_message() {
+    let cases = [
+        (
+            "Could not resolve to a Repository with the name 'octocat/missing'.",
+            ErrorKind::NotFound,
+        ),
+        (
+            "Resource not accessible by integration",
+            ErrorKind::PermissionDenied,
+        ),
+        ("GitHub token not configured", ErrorKind::Auth),
+        ("Failed to parse response: EOF", ErrorKind::Other),
+    ];
+
+    for (message, kind) in cases {
+        assert_eq!(ErrorKind::from_message(message), kind, "{}", message);
+    }
+}
+
+/// The first categorized error in the chain decides the kind, whatever
+/// context is added on top
+#[test]
+fn test_error_kind_of_error_chain() {
+    let error = anyhow::Error::new(GitHubError::new(ErrorKind::NotFound, "Issue 1 not found"))
+        .context("Failed to update issue");
+    assert_eq!(ErrorKind::of(&error), ErrorKind::NotFound);
+    assert_eq!(error.root_cause().to_string(), "Issue 1 not found");
+
+    let error = Err::<(), _>(ApiRetryableError::RateLimit)
+        .context("Failed to list issues")
+        .unwrap_err();
+    assert_eq!(ErrorKind::of(&error), ErrorKind::RateLimited);
+
+    assert_eq!(
+        ErrorKind::of(&anyhow::anyhow!("Invalid plan file")),
+        ErrorKind::Other
+    );
+}
+
+/// Failed API requests exit with the code of their status
+#[tokio::test]
+async fn test_cli_exit_code_of_api_errors() {
+    let mut server = mockito::Server::new_async().await;
+    let _missing_mock = server
+        .mock("DELETE", "/repos/octocat/hello/labels/missing")
+        .with_status(404)
+        .with_body(r#"{"message": "Not Found"}"#)
+        .create_async()
+        .await;
+    let _protected_mock = server
+        .mock("DELETE", "/repos/octocat/hello/labels/protected")
+        .with_status(403)
+        .with_body(r#"{"message": "Must have admin rights to Repository."}"#)
+        .create_async()
+        .await;
+    let _invalid_mock = server
+        .mock("DELETE", "/repos/octocat/hello/labels/invalid")
+        .with_status(422)
+        .with_body(r#"{"message": "Validation Failed"}"#)
+        .create_async()
+        .await;
+    let _user_mock = server
+        .mock("GET", "/user")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"login": "octocat", "id": 1}"#)
+        .create_async()
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    for (label, code) in [("missing", 4), ("protected", 5), ("invalid", 7)] {
+        let output = cli(
+            dir.path(),
+            Some(&profile_config(&server.url())),
+            &[
+                "repository",
+                "label",
+                "delete",
+                "-r",
+                "https://github.com/octocat/hello",
+                "-n",
+                label,
+            ],
+        );
+        assert_eq!(
+            output.status.code(),
+            Some(code),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert!(String::from_utf8_lossy(&output.stderr).starts_with("Error: "));
+    }
+}
+
+/// Commands exit with the authentication code without a token, and with
+/// clap's usage code for invalid arguments
+#[test]
+fn test_cli_exit_code_without_token() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = "[auth]\ngh_cli = false\n";
+
+    let output = cli(
+        dir.path(),
+        Some(config),
+        &["issue", "get", "https://github.com/octocat/hello/issues/1"],
+    );
+    assert_eq!(output.status.code(), Some(3));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("GITHUB_EDIT_GITHUB_TOKEN"));
+
+    let output = cli(dir.path(), Some(config), &["issue", "no-such-command"]);
+    assert_eq!(output.status.code(), Some(2));
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use common::cli;
+
+/// Completion scripts cover the nested subcommands and their options
+#[test]
+fn test_completions() {
+    let dir = tempfile::tempdir().unwrap();
+    for shell in ["bash", "zsh", "fish", "powershell", "elvish"] {
+        let output = cli(dir.path(), None, &["completions", shell]);
+        assert!(output.status.success(), "{}", shell);
+        let script = String::from_utf8(output.stdout).unwrap();
+        assert!(script.contains("pull-request"), "{}", shell);
+        assert!(script.contains("body-file"), "{}", shell);
+    }
+
+    let output = cli(dir.path(), None, &["completions", "tcsh"]);
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+}
+
+/// `man` prints the page of the CLI, or writes a page per command to a directory
+#[test]
+fn test_man() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = cli(dir.path(), None, &["man"]);
+    assert!(output.status.success());
+    let page = String::from_utf8(output.stdout).unwrap();
+    assert!(page.contains(".TH github-edit-cli 1"));
+    assert!(page.contains("pull\\-request"));
+
+    let out_dir = dir.path().join("man1");
+    let output = cli(
+        dir.path(),
+        None,
+        &["man", "--out-dir", out_dir.to_str().unwrap()],
+    );
+    assert!(output.status.success());
+    for page in [
+        "github-edit-cli.1",
+        "github-edit-cli-issue.1",
+        "github-edit-cli-issue-comment.1",
+        "github-edit-cli-repository-release-create.1",
+    ] {
+        assert!(out_dir.join(page).is_file(), "{}", page);
+    }
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use common::{cli, profile_config};
+
+/// Commands fail before any request, so nothing listens here
+const UNUSED_API_URL: &str = "http://127.0.0.1:9";
+
+const COMMENT: [&str; 6] = [
+    "issue",
+    "comment",
+    "-r",
+    "https://github.com/owner/repo",
+    "-i",
+    "1",
+];
+
+/// A required body is given with `--body` or `--body-file`, never both
+#[test]
+fn test_body_or_body_file_required() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = cli(dir.path(), Some(&profile_config(UNUSED_API_URL)), &COMMENT);
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("required arguments were not provided")
+    );
+
+    let output = cli(
+        dir.path(),
+        Some(&profile_config(UNUSED_API_URL)),
+        &[&COMMENT[..], &["-b", "Body", "--body-file", "body.md"]].concat(),
+    );
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("cannot be used with")
+    );
+}
+
+/// A body file that cannot be read fails the command before any request
+#[test]
+fn test_missing_body_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("missing.md");
+
+    let output = cli(
+        dir.path(),
+        Some(&profile_config(UNUSED_API_URL)),
+        &[&COMMENT[..], &["--body-file", path.to_str().unwrap()]].concat(),
+    );
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains(&format!("Failed to read the body from {}", path.display()))
+    );
+}
@@ -0,0 +1,234 @@
+use github_edit::types::label::parse_label_color;
+use serde_json::Value;
+
+mod common;
+
+use common::{cli, profile_config};
+
+#[test]
+fn test_parse_label_color() {
+    assert_eq!(parse_label_color("ff0000").unwrap(), "ff0000");
+    assert_eq!(parse_label_color("#D73A4A").unwrap(), "d73a4a");
+
+    for invalid in ["", "#fff", "ff00000", "gg0000", "red"] {
+        let error = parse_label_color(invalid).unwrap_err();
+        assert!(error.starts_with("Invalid label color"), "{}", error);
+    }
+}
+
+/// `repository label rename` changes only the name of the label, whose
+/// current name is encoded in the URL
+#[tokio::test]
+async fn test_cli_label_rename() {
+    let mut server = mockito::Server::new_async().await;
+    let rename_mock = server
+        .mock("PATCH", "/repos/octocat/hello/labels/good%20first%20issue")
+        .match_body(mockito::Matcher::Json(
+            serde_json::json!({ "name": "beginner" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"name": "beginner", "color": "7057ff", "description": "Good for newcomers"}"#,
+        )
+        .create_async()
+        .await;
+    // The audit log records the account of the token
+    let _user_mock = server
+        .mock("GET", "/user")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"login": "octocat", "id": 1}"#)
+        .create_async()
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let output = cli(
+        dir.path(),
+        Some(&profile_config(&server.url())),
+        &[
+            "--output",
+            "json",
+            "repository",
+            "label",
+            "rename",
+            "-r",
+            "https://github.com/octocat/hello",
+            "-n",
+            "good first issue",
+            "--to",
+            "beginner",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let result: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["result"], "label_renamed");
+    assert_eq!(result["from"], "good first issue");
+    assert_eq!(result["label"]["name"], "beginner");
+    assert_eq!(result["label"]["color"], "7057ff");
+
+    rename_mock.assert_async().await;
+}
+
+/// Invalid colors are rejected before any request is sent
+#[tokio::test]
+async fn test_cli_label_invalid_color() {
+    let server = mockito::Server::new_async().await;
+    let dir = tempfile::tempdir().unwrap();
+    let output = cli(
+        dir.path(),
+        Some(&profile_config(&server.url())),
+        &[
+            "repository",
+            "label",
+            "create",
+            "-r",
+            "https://github.com/octocat/hello",
+            "-n",
+            "bug",
+            "-c",
+            "red",
+        ],
+    );
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("Invalid label color 'red'")
+    );
+}
+
+fn labels_json(names: std::ops::Range<u32>) -> String {
+    let labels: Vec<Value> = names
+        .map(|n| {
+            serde_json::json!({
+                "id": n,
+                "name": format!("label-{}", n),
+                "color": "ededed",
+                "description": null,
+                "default": false
+            })
+        })
+        .collect();
+    Value::Array(labels).to_string()
+}
+
+/// Mock the two pages of 100 and 20 labels of `octocat/hello`
+async fn mock_label_pages(server: &mut mockito::Server) -> (mockito::Mock, mockito::Mock) {
+    let next_link = format!(
+        "<{}/repos/octocat/hello/labels?per_page=100&page=2>; rel=\"next\"",
+        server.url()
+    );
+    let first_page = server
+        .mock("GET", "/repos/octocat/hello/labels")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("per_page".into(), "100".into()),
+            mockito::Matcher::UrlEncoded("page".into(), "1".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("link", &next_link)
+        .with_body(labels_json(0..100))
+        .create_async()
+        .await;
+    let second_page = server
+        .mock("GET", "/repos/octocat/hello/labels")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("per_page".into(), "100".into()),
+            mockito::Matcher::UrlEncoded("page".into(), "2".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(labels_json(100..120))
+        .create_async()
+        .await;
+    (first_page, second_page)
+}
+
+/// `--all` fetches pages until the last one
+#[tokio::test]
+async fn test_cli_label_list_all() {
+    let mut server = mockito::Server::new_async().await;
+    let (first_page, second_page) = mock_label_pages(&mut server).await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let output = cli(
+        dir.path(),
+        Some(&profile_config(&server.url())),
+        &[
+            "--output",
+            "json",
+            "repository",
+            "label",
+            "list",
+            "-r",
+            "https://github.com/octocat/hello",
+            "--all",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let result: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["items"].as_array().unwrap().len(), 120);
+    assert_eq!(result["items"][119]["name"], "label-119");
+    assert_eq!(result["has_next_page"], false);
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+}
+
+/// Limits above the page size are fetched page by page and cut at the limit
+#[tokio::test]
+async fn test_cli_label_list_limit() {
+    let mut server = mockito::Server::new_async().await;
+    let (_first_page, _second_page) = mock_label_pages(&mut server).await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let output = cli(
+        dir.path(),
+        Some(&profile_config(&server.url())),
+        &[
+            "repository",
+            "label",
+            "list",
+            "-r",
+            "https://github.com/octocat/hello",
+            "--limit",
+            "110",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 111);
+    assert!(lines[109].starts_with("label-109\t"));
+    assert_eq!(lines[110], "More labels available on page 3");
+
+    // --page and --all select pages differently
+    let output = cli(
+        dir.path(),
+        Some(&profile_config(&server.url())),
+        &[
+            "repository",
+            "label",
+            "list",
+            "-r",
+            "https://github.com/octocat/hello",
+            "--page",
+            "2",
+            "--all",
+        ],
+    );
+    assert_eq!(output.status.code(), Some(2));
+}
@@ -0,0 +1,195 @@
+use chrono::{TimeZone, Utc};
+use github_edit::types::milestone::parse_due_date;
+use serde_json::Value;
+
+mod common;
+
+use common::{cli, profile_config};
+
+fn milestone_json(number: u32, title: &str, state: &str) -> Value {
+    serde_json::json!({
+        "id": 1000 + number,
+        "number": number,
+        "title": title,
+        "description": null,
+        "state": state,
+        "open_issues": 0,
+        "closed_issues": 3,
+        "due_on": null,
+        "created_at": "2024-01-01T00:00:00Z",
+        "updated_at": "2024-01-02T00:00:00Z",
+        "closed_at": null
+    })
+}
+
+#[test]
+fn test_parse_due_date() {
+    assert_eq!(
+        parse_due_date("2024-12-31").unwrap(),
+        Utc.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap()
+    );
+    assert_eq!(
+        parse_due_date("2024-12-31T23:59:59Z").unwrap(),
+        Utc.with_ymd_and_hms(2024, 12, 31, 23, 59, 59).unwrap()
+    );
+    assert_eq!(
+        parse_due_date("2025-01-01T09:00:00+09:00").unwrap(),
+        Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()
+    );
+
+    for invalid in ["", "2024-13-01", "31/12/2024", "tomorrow"] {
+        let error = parse_due_date(invalid).unwrap_err();
+        assert!(error.starts_with("Invalid due date"), "{}", error);
+    }
+}
+
+/// `repository milestone close` looks the milestone up by title and only
+/// changes its state
+#[tokio::test]
+async fn test_cli_milestone_close_by_title() {
+    let mut server = mockito::Server::new_async().await;
+    let _list_mock = server
+        .mock("GET", "/repos/octocat/hello/milestones")
+        .match_query(mockito::Matcher::UrlEncoded("state".into(), "all".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                milestone_json(1, "v1.0.0", "open"),
+                milestone_json(2, "v1.1.0", "open")
+            ])
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let close_mock = server
+        .mock("PATCH", "/repos/octocat/hello/milestones/2")
+        .match_body(mockito::Matcher::Json(
+            serde_json::json!({ "state": "closed" }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(milestone_json(2, "v1.1.0", "closed").to_string())
+        .create_async()
+        .await;
+    // The audit log records the account of the token
+    let _user_mock = server
+        .mock("GET", "/user")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"login": "octocat", "id": 1}"#)
+        .create_async()
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let output = cli(
+        dir.path(),
+        Some(&profile_config(&server.url())),
+        &[
+            "--output",
+            "json",
+            "repository",
+            "milestone",
+            "close",
+            "-r",
+            "https://github.com/octocat/hello",
+            "--milestone-title",
+            "V1.1.0",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let result: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["result"], "milestone_closed");
+    assert_eq!(result["id"], 2);
+    assert_eq!(result["state"], "Closed");
+
+    close_mock.assert_async().await;
+}
+
+/// Invalid due dates are rejected before any request is sent
+#[tokio::test]
+async fn test_cli_milestone_invalid_due_date() {
+    let server = mockito::Server::new_async().await;
+    let dir = tempfile::tempdir().unwrap();
+    let output = cli(
+        dir.path(),
+        Some(&profile_config(&server.url())),
+        &[
+            "repository",
+            "milestone",
+            "create",
+            "-r",
+            "https://github.com/octocat/hello",
+            "-t",
+            "v2.0.0",
+            "--due",
+            "next week",
+        ],
+    );
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("Invalid due date 'next week'")
+    );
+}
+
+/// Milestones are fetched at once and paged with the same options as the
+/// other listings
+#[tokio::test]
+async fn test_cli_milestone_list_page() {
+    let mut server = mockito::Server::new_async().await;
+    let _list_mock = server
+        .mock("GET", "/repos/octocat/hello/milestones")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                milestone_json(1, "v1.0.0", "closed"),
+                milestone_json(2, "v1.1.0", "open"),
+                milestone_json(3, "v1.2.0", "open")
+            ])
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let list = |args: &[&str]| {
+        let mut all_args = vec![
+            "--output",
+            "json",
+            "repository",
+            "milestone",
+            "list",
+            "-r",
+            "https://github.com/octocat/hello",
+        ];
+        all_args.extend_from_slice(args);
+        let output = cli(dir.path(), Some(&profile_config(&server.url())), &all_args);
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        serde_json::from_slice::<Value>(&output.stdout).unwrap()
+    };
+
+    let result = list(&["--limit", "2", "--page", "2"]);
+    assert_eq!(result["result"], "milestones");
+    assert_eq!(result["items"].as_array().unwrap().len(), 1);
+    assert_eq!(result["items"][0]["title"], "v1.2.0");
+    assert_eq!(result["has_next_page"], false);
+
+    let result = list(&["--limit", "2"]);
+    assert_eq!(result["items"].as_array().unwrap().len(), 2);
+    assert_eq!(result["has_next_page"], true);
+
+    let result = list(&["--all"]);
+    assert_eq!(result["items"].as_array().unwrap().len(), 3);
+}
@@ -0,0 +1,83 @@
+use github_edit::audit::AuditLog;
+use github_edit::types::audit::{AuditEntry, AuditSource};
+use serde_json::{Value, json};
+use std::path::Path;
+
+mod common;
+
+use common::cli;
+
+fn record(config_dir: &Path) {
+    AuditLog::new(config_dir.join("audit.jsonl"))
+        .append(&AuditEntry {
+            timestamp: "2024-01-01T00:00:00Z".parse().unwrap(),
+            source: AuditSource::Cli,
+            actor: Some("octocat".to_string()),
+            client: None,
+            tool: "issue create".to_string(),
+            parameters: json!({ "title": "Bug" }),
+            success: false,
+            error: Some("GitHub API error 403".to_string()),
+            trace_id: None,
+            requests: Vec::new(),
+            undo: None,
+            undoes: None,
+        })
+        .unwrap();
+}
+
+/// Results are printed as text by default
+#[test]
+fn test_text_output() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = cli(dir.path(), None, &["audit", "list"]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        format!(
+            "No operations recorded in {}\n",
+            dir.path().join("audit.jsonl").display()
+        )
+    );
+
+    record(dir.path());
+    let output = cli(dir.path(), None, &["audit", "list"]);
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "2024-01-01T00:00:00+00:00\tcli\toctocat\tissue create\tfailed\n  Error: GitHub API error 403\n"
+    );
+}
+
+/// `--output json` prints the result as one JSON document naming its kind,
+/// given before or after the command
+#[test]
+fn test_json_output() {
+    let dir = tempfile::tempdir().unwrap();
+    record(dir.path());
+
+    for args in [
+        ["--output", "json", "audit", "list"],
+        ["audit", "list", "--output", "json"],
+    ] {
+        let output = cli(dir.path(), None, &args);
+        assert!(output.status.success());
+        let result: Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(result["result"], "operations");
+        assert_eq!(result["entries"][0]["tool"], "issue create");
+        assert_eq!(result["entries"][0]["success"], false);
+    }
+}
+
+/// Unknown formats are rejected
+#[test]
+fn test_unknown_output_format() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = cli(dir.path(), None, &["--output", "yaml", "audit", "list"]);
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("possible values: text, json")
+    );
+}
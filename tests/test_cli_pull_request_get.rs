@@ -0,0 +1,201 @@
+use mockito::Matcher;
+use serde_json::{Value, json};
+
+mod common;
+
+use common::{cli, profile_config};
+
+fn user_json(login: &str) -> Value {
+    let url = format!("https://api.github.com/users/{}", login);
+    json!({
+        "login": login,
+        "id": 1,
+        "node_id": "MDQ6VXNlcjE=",
+        "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+        "gravatar_id": "",
+        "url": url,
+        "html_url": format!("https://github.com/{}", login),
+        "followers_url": format!("{}/followers", url),
+        "following_url": format!("{}/following", url),
+        "gists_url": format!("{}/gists", url),
+        "starred_url": format!("{}/starred", url),
+        "subscriptions_url": format!("{}/subscriptions", url),
+        "organizations_url": format!("{}/orgs", url),
+        "repos_url": format!("{}/repos", url),
+        "events_url": format!("{}/events", url),
+        "received_events_url": format!("{}/received_events", url),
+        "type": "User",
+        "site_admin": false
+    })
+}
+
+fn comment_json(id: u64, number: u64) -> Value {
+    json!({
+        "id": id,
+        "node_id": "IC_kwDOA",
+        "url": format!("https://api.github.com/repos/octocat/hello/issues/comments/{}", id),
+        "html_url": format!("https://github.com/octocat/hello/pull/{}#issuecomment-{}", number, id),
+        "body": format!("Comment {}", id),
+        "author_association": "CONTRIBUTOR",
+        "user": user_json("hubot"),
+        "created_at": "2024-01-02T04:04:05Z",
+        "updated_at": "2024-01-02T04:04:05Z"
+    })
+}
+
+/// Mock pull request `number` of octocat/hello with its discussion
+/// `comments`
+async fn mock_pull_request(
+    server: &mut mockito::ServerGuard,
+    number: u64,
+    comments: Vec<Value>,
+) -> (mockito::Mock, mockito::Mock) {
+    let pull_request = server
+        .mock("GET", format!("/repos/octocat/hello/pulls/{}", number).as_str())
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "url": format!("https://api.github.com/repos/octocat/hello/pulls/{}", number),
+                "id": 5000 + number,
+                "number": number,
+                "state": "open",
+                "title": format!("Pull request {}", number),
+                "body": format!("Description of {}", number),
+                "user": user_json("octocat"),
+                "head": { "ref": format!("feature-{}", number), "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e" },
+                "base": { "ref": "main", "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e" },
+                "created_at": "2024-01-02T03:04:05Z",
+                "updated_at": "2024-01-03T03:04:05Z"
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let comments = server
+        .mock(
+            "GET",
+            format!("/repos/octocat/hello/issues/{}/comments", number).as_str(),
+        )
+        .match_query(Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body(Value::Array(comments).to_string())
+        .create_async()
+        .await;
+    (pull_request, comments)
+}
+
+/// Several pull requests are printed in the order given, each cut to the
+/// comment limit
+#[tokio::test]
+async fn test_cli_pull_request_get_multiple_urls() {
+    let mut server = mockito::Server::new_async().await;
+    let _first = mock_pull_request(
+        &mut server,
+        5,
+        vec![comment_json(11, 5), comment_json(12, 5)],
+    )
+    .await;
+    let _second = mock_pull_request(&mut server, 6, vec![]).await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let output = cli(
+        dir.path(),
+        Some(&profile_config(&server.url())),
+        &[
+            "pull-request",
+            "get",
+            "https://github.com/octocat/hello/pull/5",
+            "https://github.com/octocat/hello/pull/6",
+            "--comment-limit",
+            "1",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let first = stdout
+        .find("Pull request #5 [open] Pull request 5")
+        .unwrap();
+    let second = stdout
+        .find("Pull request #6 [open] Pull request 6")
+        .unwrap();
+    assert!(first < second, "{}", stdout);
+    assert!(stdout.contains("Comment #11 by hubot"), "{}", stdout);
+    assert!(!stdout.contains("Comment #12"), "{}", stdout);
+    assert_eq!(
+        stdout
+            .matches("More comments available with a higher --comment-limit")
+            .count(),
+        1,
+        "{}",
+        stdout
+    );
+}
+
+/// `--no-comments` leaves the comments out of the JSON output
+#[tokio::test]
+async fn test_cli_pull_request_get_json_without_comments() {
+    let mut server = mockito::Server::new_async().await;
+    let _pull_request = mock_pull_request(&mut server, 5, vec![comment_json(11, 5)]).await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let output = cli(
+        dir.path(),
+        Some(&profile_config(&server.url())),
+        &[
+            "--output",
+            "json",
+            "pull-request",
+            "get",
+            "https://github.com/octocat/hello/pull/5",
+            "--no-comments",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let result: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["result"], "details");
+    let pull_requests = result["pull_requests"].as_array().unwrap();
+    assert_eq!(pull_requests.len(), 1);
+    assert_eq!(pull_requests[0]["title"], "Pull request 5");
+    assert_eq!(pull_requests[0]["comments"], json!([]));
+    assert_eq!(pull_requests[0]["has_more_comments"], true);
+}
+
+/// Comment limits above the maximum are rejected before any request
+#[tokio::test]
+async fn test_cli_pull_request_get_comment_limit_above_maximum() {
+    let mut server = mockito::Server::new_async().await;
+    let pull_request = server
+        .mock("GET", Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let output = cli(
+        dir.path(),
+        Some(&profile_config(&server.url())),
+        &[
+            "pull-request",
+            "get",
+            "https://github.com/octocat/hello/pull/5",
+            "--comment-limit",
+            "101",
+        ],
+    );
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("Invalid comment limit 101: at most 100 comments can be included")
+    );
+    pull_request.assert_async().await;
+}
@@ -0,0 +1,350 @@
+use github_edit::queue::OfflineQueue;
+use github_edit::types::queue::QueuedCommand;
+use mockito::Matcher;
+use serde_json::{Value, json};
+use std::path::Path;
+
+mod common;
+
+use common::{cli, profile_config};
+
+/// Configuration with a profile sending its requests to `api_base_url`,
+/// without retries
+fn queue_config(api_base_url: &str) -> String {
+    format!(
+        "{}\n[retry]\nmax_retries = 0\n",
+        profile_config(api_base_url)
+    )
+}
+
+/// Base URL of a port nothing listens on
+fn unreachable_url() -> String {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    format!("http://{}", listener.local_addr().unwrap())
+}
+
+fn user_json(login: &str) -> Value {
+    let url = format!("https://api.github.com/users/{}", login);
+    json!({
+        "login": login,
+        "id": 1,
+        "node_id": "MDQ6VXNlcjE=",
+        "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+        "gravatar_id": "",
+        "url": url,
+        "html_url": format!("https://github.com/{}", login),
+        "followers_url": format!("{}/followers", url),
+        "following_url": format!("{}/following", url),
+        "gists_url": format!("{}/gists", url),
+        "starred_url": format!("{}/starred", url),
+        "subscriptions_url": format!("{}/subscriptions", url),
+        "organizations_url": format!("{}/orgs", url),
+        "repos_url": format!("{}/repos", url),
+        "events_url": format!("{}/events", url),
+        "received_events_url": format!("{}/received_events", url),
+        "type": "User",
+        "site_admin": false
+    })
+}
+
+/// Issue 7 of octocat/hello, last updated at `updated_at`
+fn issue_json(updated_at: &str) -> Value {
+    let url = "https://api.github.com/repos/octocat/hello/issues/7";
+    json!({
+        "id": 1007,
+        "node_id": "I_kwDOA",
+        "url": url,
+        "repository_url": "https://api.github.com/repos/octocat/hello",
+        "labels_url": format!("{}/labels{{/name}}", url),
+        "comments_url": format!("{}/comments", url),
+        "events_url": format!("{}/events", url),
+        "html_url": "https://github.com/octocat/hello/issues/7",
+        "number": 7,
+        "state": "open",
+        "title": "Crash when saving drafts",
+        "body": "Steps to reproduce",
+        "user": user_json("octocat"),
+        "labels": [],
+        "assignees": [],
+        "author_association": "OWNER",
+        "locked": false,
+        "comments": 0,
+        "created_at": "2024-01-02T03:04:05Z",
+        "updated_at": updated_at
+    })
+}
+
+async fn mock_issue(server: &mut mockito::Server, updated_at: &str) -> Vec<mockito::Mock> {
+    vec![
+        server
+            .mock("GET", "/repos/octocat/hello/issues/7")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(issue_json(updated_at).to_string())
+            .create_async()
+            .await,
+        server
+            .mock("GET", "/repos/octocat/hello/issues/7/comments")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("[]")
+            .create_async()
+            .await,
+    ]
+}
+
+fn queued_commands(config_dir: &Path) -> Vec<Value> {
+    std::fs::read_to_string(config_dir.join("queue.jsonl"))
+        .unwrap_or_default()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+/// A comment failing to reach GitHub is queued, and posted by the flush
+#[tokio::test]
+async fn test_cli_queue_offline_comment_and_flush() {
+    let dir = tempfile::tempdir().unwrap();
+    let args = [
+        "--queue-offline",
+        "issue",
+        "comment",
+        "-r",
+        "https://github.com/octocat/hello",
+        "-i",
+        "7",
+        "-b",
+        "Fixed in the next release",
+    ];
+
+    // Without the option the command fails with the network exit code
+    let output = cli(
+        dir.path(),
+        Some(&queue_config(&unreachable_url())),
+        &args[1..],
+    );
+    assert_eq!(output.status.code(), Some(8));
+    assert!(queued_commands(dir.path()).is_empty());
+
+    let output = cli(dir.path(), Some(&queue_config(&unreachable_url())), &args);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        String::from_utf8(output.stdout)
+            .unwrap()
+            .starts_with("GitHub is unreachable; queued issue comment in ")
+    );
+    let queued = queued_commands(dir.path());
+    assert_eq!(queued.len(), 1);
+    assert_eq!(queued[0]["command"], "issue comment");
+    assert_eq!(queued[0]["profile"], "test");
+    assert_eq!(
+        queued[0]["target"],
+        "https://github.com/octocat/hello/issues/7"
+    );
+    assert_eq!(queued[0]["args"], json!(args[1..]));
+
+    let mut server = mockito::Server::new_async().await;
+    let _issue_mocks = mock_issue(&mut server, "2024-01-03T03:04:05Z").await;
+    let comment_mock = server
+        .mock("POST", "/repos/octocat/hello/issues/7/comments")
+        .match_body(Matcher::PartialJson(
+            json!({"body": "Fixed in the next release"}),
+        ))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": 11,
+                "node_id": "IC_kwDOA",
+                "url": "https://api.github.com/repos/octocat/hello/issues/comments/11",
+                "html_url": "https://github.com/octocat/hello/issues/7#issuecomment-11",
+                "body": "Fixed in the next release",
+                "author_association": "OWNER",
+                "user": user_json("octocat"),
+                "created_at": "2024-01-04T03:04:05Z",
+                "updated_at": "2024-01-04T03:04:05Z"
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let output = cli(
+        dir.path(),
+        Some(&queue_config(&server.url())),
+        &["--output", "json", "queue", "flush"],
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let result: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["result"], "flushed");
+    assert_eq!(result["commands"][0]["status"], "replayed");
+    comment_mock.assert_async().await;
+    assert!(!dir.path().join("queue.jsonl").exists());
+}
+
+/// The flush stops before a command whose issue changed after it was
+/// queued, and replays it with --force
+#[tokio::test]
+async fn test_cli_queue_flush_conflict() {
+    let dir = tempfile::tempdir().unwrap();
+    for title in ["First title", "Second title"] {
+        let output = cli(
+            dir.path(),
+            Some(&queue_config(&unreachable_url())),
+            &[
+                "--queue-offline",
+                "issue",
+                "edit-title",
+                "-r",
+                "https://github.com/octocat/hello/issues/7",
+                "-t",
+                title,
+            ],
+        );
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    assert_eq!(queued_commands(dir.path()).len(), 2);
+
+    let mut server = mockito::Server::new_async().await;
+    let _issue_mocks = mock_issue(&mut server, "2999-01-01T00:00:00Z").await;
+    let edit_mock = server
+        .mock("PATCH", "/repos/octocat/hello/issues/7")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_json("2999-01-01T00:00:00Z").to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let output = cli(
+        dir.path(),
+        Some(&queue_config(&server.url())),
+        &["queue", "flush"],
+    );
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "conflict\tissue edit-title\thttps://github.com/octocat/hello/issues/7\n\
+         pending\tissue edit-title\thttps://github.com/octocat/hello/issues/7\n"
+    );
+    assert!(
+        String::from_utf8_lossy(&output.stderr)
+            .contains("changed on GitHub after issue edit-title was queued")
+    );
+    assert_eq!(queued_commands(dir.path()).len(), 2);
+
+    let output = cli(
+        dir.path(),
+        Some(&queue_config(&server.url())),
+        &["--output", "json", "queue", "flush", "--force"],
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let result: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["commands"][0]["status"], "replayed");
+    assert_eq!(result["commands"][1]["status"], "replayed");
+    edit_mock.assert_async().await;
+    assert!(queued_commands(dir.path()).is_empty());
+}
+
+fn queued_command(target: &str) -> QueuedCommand {
+    QueuedCommand {
+        queued_at: chrono::Utc::now(),
+        command: "issue comment".to_string(),
+        args: vec!["issue".to_string(), "comment".to_string()],
+        profile: None,
+        cwd: std::path::PathBuf::from("/"),
+        target: Some(target.to_string()),
+    }
+}
+
+/// Removing replayed commands keeps the lines that are not valid commands
+#[test]
+fn test_queue_remove_oldest_keeps_invalid_lines() {
+    let dir = tempfile::tempdir().unwrap();
+    let queue = OfflineQueue::new(dir.path().join("queue.jsonl"));
+    queue.push(&queued_command("first")).unwrap();
+    let mut file = std::fs::OpenOptions::new()
+        .append(true)
+        .open(queue.path())
+        .unwrap();
+    std::io::Write::write_all(&mut file, b"{\"queued_at\": \"cut short\n").unwrap();
+    queue.push(&queued_command("second")).unwrap();
+    queue.push(&queued_command("third")).unwrap();
+
+    queue.remove_oldest(Some(2)).unwrap();
+    let content = std::fs::read_to_string(queue.path()).unwrap();
+    let lines: Vec<&str> = content.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0], "{\"queued_at\": \"cut short");
+    let targets: Vec<Option<String>> = queue
+        .read()
+        .unwrap()
+        .into_iter()
+        .map(|command| command.target)
+        .collect();
+    assert_eq!(targets, vec![Some("third".to_string())]);
+
+    queue.remove_oldest(None).unwrap();
+    assert!(!queue.path().exists());
+}
+
+/// Commands queued by other processes while replayed ones are removed are
+/// kept
+#[test]
+fn test_queue_remove_oldest_keeps_concurrent_pushes() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("queue.jsonl");
+    let queue = OfflineQueue::new(&path);
+    for index in 0..20 {
+        queue
+            .push(&queued_command(&format!("old-{}", index)))
+            .unwrap();
+    }
+
+    let pushers: Vec<_> = (0..4)
+        .map(|thread| {
+            let queue = OfflineQueue::new(&path);
+            std::thread::spawn(move || {
+                for index in 0..10 {
+                    queue
+                        .push(&queued_command(&format!("new-{}-{}", thread, index)))
+                        .unwrap();
+                }
+            })
+        })
+        .collect();
+    for _ in 0..20 {
+        queue.remove_oldest(Some(1)).unwrap();
+    }
+    for pusher in pushers {
+        pusher.join().unwrap();
+    }
+
+    let targets: Vec<String> = queue
+        .read()
+        .unwrap()
+        .into_iter()
+        .filter_map(|command| command.target)
+        .collect();
+    assert_eq!(targets.len(), 40);
+    assert!(targets.iter().all(|target| target.starts_with("new-")));
+    assert!(!dir.path().join("queue.jsonl.lock").exists());
+}
@@ -0,0 +1,121 @@
+use mockito::Matcher;
+use serde_json::Value;
+
+mod common;
+
+use common::{cli, profile_config};
+
+const COMMENT: &str = r#"{"event": "commented", "id": 1, "user": {"login": "octocat"}, "body": "Starting on this", "created_at": "2024-01-02T03:04:05Z"}"#;
+const REVIEW: &str = r#"{"event": "reviewed", "id": 80, "user": {"login": "reviewer"}, "body": "Please add a test\nThanks", "state": "CHANGES_REQUESTED", "submitted_at": "2024-01-02T04:00:00Z"}"#;
+const CLOSED: &str =
+    r#"{"event": "closed", "actor": {"login": "octocat"}, "created_at": "2024-01-02T05:00:00Z"}"#;
+
+/// New events are printed once, and unchanged timelines are polled with
+/// the ETag of the last answer
+#[tokio::test]
+async fn test_cli_issue_watch_prints_new_events() {
+    let mut server = mockito::Server::new_async().await;
+    let path = "/repos/octocat/hello/issues/7/timeline";
+    let first_mock = server
+        .mock("GET", path)
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("etag", "\"a\"")
+        .with_body(format!("[{}]", COMMENT))
+        .expect(1)
+        .create_async()
+        .await;
+    let changed_mock = server
+        .mock("GET", path)
+        .match_query(Matcher::Any)
+        .match_header("if-none-match", "\"a\"")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("etag", "\"b\"")
+        .with_body(format!("[{}, {}]", COMMENT, REVIEW))
+        .expect(1)
+        .create_async()
+        .await;
+    let unchanged_mock = server
+        .mock("GET", path)
+        .match_query(Matcher::Any)
+        .match_header("if-none-match", "\"b\"")
+        .with_status(304)
+        .expect_at_least(1)
+        .create_async()
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let output = cli(
+        dir.path(),
+        Some(&profile_config(&server.url())),
+        &[
+            "issue",
+            "watch",
+            "https://github.com/octocat/hello/issues/7",
+            "--interval",
+            "1",
+            "--timeout",
+            "3",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "2024-01-02T04:00:00+00:00\treviewer\treviewed: changes_requested (review 80)\n    Please add a test\n    Thanks\n"
+    );
+    first_mock.assert_async().await;
+    changed_mock.assert_async().await;
+    unchanged_mock.assert_async().await;
+}
+
+/// With --until-closed, watching a closed pull request stops at once, after
+/// printing its existing events as JSON lines with --include-existing
+#[tokio::test]
+async fn test_cli_pull_request_watch_until_closed() {
+    let mut server = mockito::Server::new_async().await;
+    let _timeline_mock = server
+        .mock("GET", "/repos/octocat/hello/issues/8/timeline")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!("[{}, {}, {}]", COMMENT, REVIEW, CLOSED))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let output = cli(
+        dir.path(),
+        Some(&profile_config(&server.url())),
+        &[
+            "--output",
+            "json",
+            "pull-request",
+            "watch",
+            "https://github.com/octocat/hello/pull/8",
+            "--until-closed",
+            "--include-existing",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let events: Vec<Value> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[1]["kind"]["event"], "reviewed");
+    assert_eq!(events[1]["kind"]["state"], "changes_requested");
+    assert_eq!(events[2]["kind"]["event"], "closed");
+    assert_eq!(events[2]["actor"], "octocat");
+}
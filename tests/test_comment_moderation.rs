@@ -0,0 +1,48 @@
+use github_edit::types::comment::{
+    CommentAction, CommentPermissions, CommentTarget, CommentTargetType, MinimizeReason,
+};
+
+/// Minimize reasons parse from snake_case and map to GraphQL classifiers
+#[test]
+fn test_minimize_reason_parsing() {
+    for (value, reason, classifier) in [
+        ("spam", MinimizeReason::Spam, "SPAM"),
+        ("abuse", MinimizeReason::Abuse, "ABUSE"),
+        ("off_topic", MinimizeReason::OffTopic, "OFF_TOPIC"),
+        ("outdated", MinimizeReason::Outdated, "OUTDATED"),
+        ("duplicate", MinimizeReason::Duplicate, "DUPLICATE"),
+        ("resolved", MinimizeReason::Resolved, "RESOLVED"),
+    ] {
+        assert_eq!(value.parse::<MinimizeReason>().unwrap(), reason);
+        assert_eq!(reason.to_string(), value);
+        assert_eq!(reason.graphql_value(), classifier);
+    }
+    assert!("Outdated".parse::<MinimizeReason>().is_ok());
+    assert!("hidden".parse::<MinimizeReason>().is_err());
+}
+
+/// Issue and pull request comment targets are both addressed by comment ID
+#[test]
+fn test_comment_target() {
+    let target = CommentTarget::new(
+        "pull_request_comment".parse::<CommentTargetType>().unwrap(),
+        42,
+    );
+    assert_eq!(target.comment_id(), 42);
+    assert_eq!(target.to_string(), "pull request comment 42");
+}
+
+/// Permissions map each comment action to the matching viewer capability
+#[test]
+fn test_comment_permissions_allows() {
+    let permissions = CommentPermissions {
+        author: Some("octocat".to_string()),
+        viewer_did_author: false,
+        can_update: false,
+        can_delete: true,
+        can_minimize: true,
+    };
+    assert!(!permissions.allows(CommentAction::Edit));
+    assert!(permissions.allows(CommentAction::Delete));
+    assert!(permissions.allows(CommentAction::Minimize));
+}
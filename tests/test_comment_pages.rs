@@ -0,0 +1,263 @@
+//! Paging, limits and filters of issue and pull request comments
+
+use chrono::{TimeZone, Utc};
+use github_edit::github::GitHubClient;
+use github_edit::types::issue::IssueNumber;
+use github_edit::types::pagination::Pagination;
+use github_edit::types::pull_request::PullRequestNumber;
+use github_edit::types::repository::RepositoryId;
+use mockito::Matcher;
+use serde_json::{Value, json};
+
+fn user_json(login: &str) -> Value {
+    let url = format!("https://api.github.com/users/{}", login);
+    json!({
+        "login": login,
+        "id": 1,
+        "node_id": "MDQ6VXNlcjE=",
+        "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+        "gravatar_id": "",
+        "url": url,
+        "html_url": format!("https://github.com/{}", login),
+        "followers_url": format!("{}/followers", url),
+        "following_url": format!("{}/following", url),
+        "gists_url": format!("{}/gists", url),
+        "starred_url": format!("{}/starred", url),
+        "subscriptions_url": format!("{}/subscriptions", url),
+        "organizations_url": format!("{}/orgs", url),
+        "repos_url": format!("{}/repos", url),
+        "events_url": format!("{}/events", url),
+        "received_events_url": format!("{}/received_events", url),
+        "type": "User",
+        "site_admin": false
+    })
+}
+
+fn issue_json() -> Value {
+    let url = "https://api.github.com/repos/octocat/hello/issues/42";
+    json!({
+        "id": 1042,
+        "node_id": "I_kwDOA",
+        "url": url,
+        "repository_url": "https://api.github.com/repos/octocat/hello",
+        "labels_url": format!("{}/labels{{/name}}", url),
+        "comments_url": format!("{}/comments", url),
+        "events_url": format!("{}/events", url),
+        "html_url": "https://github.com/octocat/hello/issues/42",
+        "number": 42,
+        "state": "open",
+        "title": "Crash when saving drafts",
+        "body": "Steps to reproduce",
+        "user": user_json("octocat"),
+        "labels": [],
+        "assignees": [],
+        "author_association": "OWNER",
+        "locked": false,
+        "comments": 5,
+        "created_at": "2024-01-02T03:04:05Z",
+        "updated_at": "2024-01-03T03:04:05Z"
+    })
+}
+
+fn comment_json(id: u64, author: &str) -> Value {
+    json!({
+        "id": id,
+        "node_id": "IC_kwDOA",
+        "url": format!("https://api.github.com/repos/octocat/hello/issues/comments/{}", id),
+        "html_url": format!("https://github.com/octocat/hello/issues/42#issuecomment-{}", id),
+        "body": format!("Comment {}", id),
+        "author_association": "CONTRIBUTOR",
+        "user": user_json(author),
+        "created_at": "2024-01-02T04:04:05Z",
+        "updated_at": "2024-01-02T04:04:05Z"
+    })
+}
+
+fn client(server: &mockito::ServerGuard) -> GitHubClient {
+    GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap()
+}
+
+/// Mock page `page` of the comments of item 42, linking to the next page
+/// unless it is the last one
+async fn mock_comment_page(
+    server: &mut mockito::ServerGuard,
+    page: u32,
+    per_page: u8,
+    comments: Vec<Value>,
+    last: bool,
+) -> mockito::Mock {
+    let mut mock = server
+        .mock("GET", "/repos/octocat/hello/issues/42/comments")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("page".to_string(), page.to_string()),
+            Matcher::UrlEncoded("per_page".to_string(), per_page.to_string()),
+        ]))
+        .with_header("content-type", "application/json")
+        .with_body(Value::Array(comments).to_string());
+    if !last {
+        mock = mock.with_header(
+            "link",
+            &format!(
+                "<{}/repos/octocat/hello/issues/42/comments?per_page={}&page={}>; rel=\"next\"",
+                server.url(),
+                per_page,
+                page + 1
+            ),
+        );
+    }
+    mock.create_async().await
+}
+
+async fn mock_issue(server: &mut mockito::ServerGuard) -> mockito::Mock {
+    server
+        .mock("GET", "/repos/octocat/hello/issues/42")
+        .with_header("content-type", "application/json")
+        .with_body(issue_json().to_string())
+        .create_async()
+        .await
+}
+
+/// All pages are fetched without a limit, and the pages after the limit is
+/// reached are never requested
+#[tokio::test]
+async fn test_issue_comments_follow_pages_up_to_limit() {
+    let mut server = mockito::Server::new_async().await;
+    let _issue = mock_issue(&mut server).await;
+    let first = mock_comment_page(
+        &mut server,
+        1,
+        100,
+        vec![comment_json(1, "hubot"), comment_json(2, "hubot")],
+        false,
+    )
+    .await
+    .expect(2);
+    let second = mock_comment_page(
+        &mut server,
+        2,
+        100,
+        vec![comment_json(3, "hubot"), comment_json(4, "hubot")],
+        false,
+    )
+    .await
+    .expect(1);
+    let third = mock_comment_page(&mut server, 3, 100, vec![comment_json(5, "hubot")], true)
+        .await
+        .expect(1);
+    let client = client(&server);
+    let repository_id = RepositoryId::new("octocat", "hello");
+
+    let issue = client
+        .get_issue_with_comment_limit(&repository_id, IssueNumber::new(42), None)
+        .await
+        .unwrap();
+    let ids: Vec<u64> = issue
+        .comments
+        .iter()
+        .map(|comment| comment.comment_number.value())
+        .collect();
+    assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+
+    // The limit is reached within the first page, which is cut to it
+    let issue = client
+        .get_issue_with_comment_limit(&repository_id, IssueNumber::new(42), Some(1))
+        .await
+        .unwrap();
+    assert_eq!(issue.comments.len(), 1);
+    assert_eq!(issue.comments[0].body, "Comment 1");
+
+    first.assert_async().await;
+    second.assert_async().await;
+    third.assert_async().await;
+}
+
+/// One page of issue comments is listed with the `since` parameter and
+/// filtered by author regardless of case
+#[tokio::test]
+async fn test_list_issue_comments_since_and_author() {
+    let mut server = mockito::Server::new_async().await;
+    let page = server
+        .mock("GET", "/repos/octocat/hello/issues/42/comments")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("page".to_string(), "2".to_string()),
+            Matcher::UrlEncoded("per_page".to_string(), "3".to_string()),
+            Matcher::UrlEncoded("since".to_string(), "2024-01-02T00:00:00Z".to_string()),
+        ]))
+        .with_header("content-type", "application/json")
+        .with_header(
+            "link",
+            &format!(
+                "<{}/repos/octocat/hello/issues/42/comments?per_page=3&page=3>; rel=\"next\"",
+                server.url()
+            ),
+        )
+        .with_body(
+            json!([
+                comment_json(1, "Hubot"),
+                comment_json(2, "octocat"),
+                comment_json(3, "hubot")
+            ])
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let comments = client(&server)
+        .list_issue_comments(
+            &RepositoryId::new("octocat", "hello"),
+            IssueNumber::new(42),
+            Some(Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap()),
+            Some("HUBOT"),
+            Pagination::new(Some(2), Some(3)),
+        )
+        .await
+        .unwrap();
+
+    let ids: Vec<u64> = comments
+        .items
+        .iter()
+        .map(|comment| comment.comment_number.value())
+        .collect();
+    assert_eq!(ids, vec![1, 3]);
+    assert_eq!(comments.page, 2);
+    assert!(comments.has_next_page);
+    page.assert_async().await;
+}
+
+/// Pull request comments are the comments of the issue of the same number,
+/// and the last page has no next page
+#[tokio::test]
+async fn test_list_pull_request_comments_last_page() {
+    let mut server = mockito::Server::new_async().await;
+    let page = mock_comment_page(
+        &mut server,
+        1,
+        30,
+        vec![comment_json(7, "octocat"), comment_json(8, "hubot")],
+        true,
+    )
+    .await;
+
+    let comments = client(&server)
+        .list_pull_request_comments(
+            &RepositoryId::new("octocat", "hello"),
+            PullRequestNumber::new(42),
+            None,
+            Some("Octocat"),
+            Pagination::new(None, Some(30)),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(comments.items.len(), 1);
+    assert_eq!(comments.items[0].body, "Comment 7");
+    assert_eq!(
+        comments.items[0]
+            .author
+            .as_ref()
+            .map(|author| author.as_str()),
+        Some("octocat")
+    );
+    assert!(!comments.has_next_page);
+    page.assert_async().await;
+}
@@ -0,0 +1,401 @@
+use github_edit::auth::{
+    ACTIONS_ENV_VAR, ACTIONS_TOKEN_ENV_VAR, TokenSource, actions_token, keyring_account,
+    parse_secret, resolve_owner_tokens,
+};
+use github_edit::config::{
+    AuthConfig, Config, CredentialStoreKind, DEFAULT_MAX_RESPONSE_CHARS, MIN_MAX_RESPONSE_CHARS,
+    Profile, RetryConfig, SearchConfig, validate_search_alias_name,
+};
+use github_edit::github::client::{DEFAULT_MAX_RETRY_COUNT, graphql_base_url};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+fn search_config() -> SearchConfig {
+    SearchConfig {
+        aliases: BTreeMap::from([
+            (
+                "triage".to_string(),
+                "is:open label:needs-triage repo:owner/repo".to_string(),
+            ),
+            ("mine".to_string(), "author:@me".to_string()),
+        ]),
+    }
+}
+
+/// Alias references are replaced by their saved queries
+#[test]
+fn test_expand_query() {
+    let config = search_config();
+
+    assert_eq!(
+        config.expand_query("@triage  no:assignee").unwrap(),
+        "is:open label:needs-triage repo:owner/repo no:assignee"
+    );
+    assert_eq!(
+        config.expand_query("is:pr @mine").unwrap(),
+        "is:pr author:@me"
+    );
+}
+
+/// `@` inside qualifiers and lone `@` tokens are not alias references
+#[test]
+fn test_expand_query_keeps_other_tokens() {
+    let config = search_config();
+
+    assert_eq!(
+        config.expand_query("is:pr review-requested:@me @").unwrap(),
+        "is:pr review-requested:@me @"
+    );
+}
+
+/// Unknown aliases are reported instead of being searched as text
+#[test]
+fn test_expand_query_unknown_alias() {
+    let error = search_config()
+        .expand_query("@unknown is:open")
+        .unwrap_err();
+
+    assert!(error.contains("@unknown"));
+}
+
+/// Alias names are restricted to a safe character set
+#[test]
+fn test_validate_search_alias_name() {
+    assert!(validate_search_alias_name("needs-triage_2").is_ok());
+    assert!(validate_search_alias_name("").is_err());
+    assert!(validate_search_alias_name("my alias").is_err());
+    assert!(validate_search_alias_name("@triage").is_err());
+}
+
+/// The configuration round-trips through its TOML file
+#[test]
+fn test_config_save_and_load() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("github-edit").join("config.toml");
+
+    assert_eq!(Config::load_from(&path).unwrap(), Config::default());
+
+    let config = Config {
+        search: search_config(),
+        ..Config::default()
+    };
+    config.save_to(&path).unwrap();
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.contains("[search.aliases]"));
+    assert_eq!(Config::load_from(&path).unwrap(), config);
+}
+
+/// Files without a search table load with no aliases
+#[test]
+fn test_config_load_without_search() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    std::fs::write(&path, "").unwrap();
+
+    assert!(Config::load_from(&path).unwrap().search.aliases.is_empty());
+}
+
+/// The credential store defaults to the environment variable
+#[test]
+fn test_auth_config_credential_store() {
+    let config: Config = toml::from_str("").unwrap();
+    assert_eq!(config.auth.credential_store, CredentialStoreKind::Env);
+
+    let config: Config = toml::from_str("[auth]\ncredential_store = \"keyring\"\n").unwrap();
+    assert_eq!(
+        config.auth,
+        AuthConfig {
+            credential_store: CredentialStoreKind::Keyring,
+            gh_cli: true,
+            owner_tokens: BTreeMap::new(),
+        }
+    );
+
+    assert_eq!(
+        "Keyring".parse::<CredentialStoreKind>().unwrap(),
+        CredentialStoreKind::Keyring
+    );
+    assert!(toml::from_str::<Config>("[auth]\ncredential_store = \"file\"\n").is_err());
+}
+
+/// The GitHub CLI fallback is enabled unless turned off
+#[test]
+fn test_auth_config_gh_cli() {
+    assert!(AuthConfig::default().gh_cli);
+
+    let config: Config = toml::from_str("[auth]\ngh_cli = false\n").unwrap();
+    assert!(!config.auth.gh_cli);
+    assert_eq!(config.auth.credential_store, CredentialStoreKind::Env);
+}
+
+/// The command line overrides the configured response size, which
+/// overrides the default; tiny limits are raised to the minimum
+#[test]
+fn test_response_config_max_chars() {
+    let config: Config = toml::from_str("").unwrap();
+    assert_eq!(
+        config.response.max_response_chars(None),
+        DEFAULT_MAX_RESPONSE_CHARS
+    );
+
+    let config: Config = toml::from_str("[response]\nmax_chars = 20000\n").unwrap();
+    assert_eq!(config.response.max_response_chars(None), 20_000);
+    assert_eq!(config.response.max_response_chars(Some(8_000)), 8_000);
+    assert_eq!(
+        config.response.max_response_chars(Some(10)),
+        MIN_MAX_RESPONSE_CHARS
+    );
+}
+
+/// Stored tokens are trimmed and blank values count as missing
+#[test]
+fn test_parse_secret() {
+    assert_eq!(
+        parse_secret(b"ghp_abc123\n"),
+        Some("ghp_abc123".to_string())
+    );
+    assert_eq!(parse_secret(b"  \r\n"), None);
+    assert_eq!(parse_secret(b""), None);
+}
+
+const PROFILES: &str = r#"
+default_profile = "work"
+
+[profiles.work]
+api_base_url = "https://github.example.com/api/v3/"
+owner = "platform"
+repo = "infrastructure"
+
+[profiles.personal]
+token = "ghp_personal"
+owner = "octocat"
+"#;
+
+/// Profiles are selected explicitly or through `default_profile`
+#[test]
+fn test_select_profile() {
+    let config: Config = toml::from_str(PROFILES).unwrap();
+
+    let selected = config.select_profile(Some("personal")).unwrap().unwrap();
+    assert_eq!(selected.name, "personal");
+    assert_eq!(selected.profile.token.as_deref(), Some("ghp_personal"));
+
+    let selected = config.select_profile(None).unwrap().unwrap();
+    assert_eq!(selected.name, "work");
+
+    let error = config.select_profile(Some("unknown")).unwrap_err();
+    assert!(error.to_string().contains("personal, work"));
+
+    assert!(Config::default().select_profile(None).unwrap().is_none());
+}
+
+/// Profiles derive their API host and default repository from their settings
+#[test]
+fn test_profile_settings() {
+    let config: Config = toml::from_str(PROFILES).unwrap();
+
+    let work = &config.profiles["work"];
+    assert_eq!(work.hostname(), "github.example.com");
+    assert_eq!(
+        work.default_repository().as_deref(),
+        Some("platform/infrastructure")
+    );
+
+    let personal = &config.profiles["personal"];
+    assert_eq!(personal.api_base_url(), "https://api.github.com");
+    assert_eq!(personal.hostname(), "github.com");
+    assert_eq!(personal.default_repository(), None);
+
+    assert_eq!(Profile::default().hostname(), "github.com");
+}
+
+/// GitHub Enterprise Server serves GraphQL next to the REST API
+#[test]
+fn test_graphql_base_url() {
+    assert_eq!(
+        graphql_base_url("https://api.github.com"),
+        "https://api.github.com"
+    );
+    assert_eq!(
+        graphql_base_url("https://github.example.com/api/v3/"),
+        "https://github.example.com/api"
+    );
+}
+
+/// Each profile keeps its own keyring entry
+#[test]
+fn test_keyring_account() {
+    assert_eq!(keyring_account(None), "github-token");
+    assert_eq!(keyring_account(Some("work")), "github-token-work");
+}
+
+/// Owner tokens of a selected profile replace those of `[auth]`
+#[test]
+fn test_resolve_owner_tokens() {
+    let config: Config = toml::from_str(
+        r#"
+[auth.owner_tokens]
+acme = "ghp_acme"
+
+[profiles.work]
+api_base_url = "https://github.example.com/api/v3"
+
+[profiles.work.owner_tokens]
+platform = "ghp_platform"
+
+[profiles.personal]
+"#,
+    )
+    .unwrap();
+
+    let tokens = resolve_owner_tokens(&config.auth, None);
+    assert_eq!(tokens.get("acme").map(String::as_str), Some("ghp_acme"));
+
+    let work = config.select_profile(Some("work")).unwrap();
+    let tokens = resolve_owner_tokens(&config.auth, work.as_ref());
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(
+        tokens.get("platform").map(String::as_str),
+        Some("ghp_platform")
+    );
+
+    let personal = config.select_profile(Some("personal")).unwrap();
+    assert!(resolve_owner_tokens(&config.auth, personal.as_ref()).is_empty());
+}
+
+/// `GITHUB_TOKEN` is only used inside GitHub Actions
+#[test]
+fn test_actions_token() {
+    let previous_actions = std::env::var_os(ACTIONS_ENV_VAR);
+    let previous_token = std::env::var_os(ACTIONS_TOKEN_ENV_VAR);
+
+    // SAFETY: no other test of this binary reads these variables
+    unsafe {
+        std::env::set_var(ACTIONS_TOKEN_ENV_VAR, "ghs_workflow\n");
+        std::env::remove_var(ACTIONS_ENV_VAR);
+    }
+    assert_eq!(actions_token(), None);
+
+    unsafe { std::env::set_var(ACTIONS_ENV_VAR, "true") };
+    assert_eq!(actions_token(), Some("ghs_workflow".to_string()));
+
+    unsafe {
+        match previous_actions {
+            Some(value) => std::env::set_var(ACTIONS_ENV_VAR, value),
+            None => std::env::remove_var(ACTIONS_ENV_VAR),
+        }
+        match previous_token {
+            Some(value) => std::env::set_var(ACTIONS_TOKEN_ENV_VAR, value),
+            None => std::env::remove_var(ACTIONS_TOKEN_ENV_VAR),
+        }
+    }
+}
+
+/// Token sources name the variable or store the token came from
+#[test]
+fn test_token_source_display() {
+    assert_eq!(TokenSource::Env.to_string(), "GITHUB_EDIT_GITHUB_TOKEN");
+    assert_eq!(
+        TokenSource::GitHubActions.to_string(),
+        "GITHUB_TOKEN (GitHub Actions)"
+    );
+    assert_eq!(TokenSource::GhCli.to_string(), "the GitHub CLI");
+}
+
+/// Settings are changed and read by dotted key, with scalars typed by the
+/// setting they are stored in
+#[test]
+fn test_config_set_and_get_value() {
+    let mut config = Config::default();
+
+    config
+        .set_value("defaults.repository", "octocat/hello")
+        .unwrap();
+    config.set_value("retry.max_retries", "5").unwrap();
+    config.set_value("auth.gh_cli", "false").unwrap();
+
+    assert_eq!(config.defaults.repository.as_deref(), Some("octocat/hello"));
+    assert_eq!(config.retry.max_retries, Some(5));
+    assert_eq!(
+        config.get_value("retry.max_retries").unwrap(),
+        Some(toml::Value::Integer(5))
+    );
+    assert_eq!(
+        config.get_value("defaults.repository").unwrap(),
+        Some(toml::Value::String("octocat/hello".to_string()))
+    );
+    assert_eq!(config.get_value("defaults.output").unwrap(), None);
+
+    assert!(config.unset_value("retry.max_retries").unwrap());
+    assert!(!config.unset_value("retry.max_retries").unwrap());
+    assert_eq!(config.retry.max_retries, None);
+}
+
+/// Unknown keys and values of the wrong type are rejected without changing
+/// the configuration
+#[test]
+fn test_config_set_value_invalid() {
+    let mut config = Config::default();
+
+    let error = config
+        .set_value("defaults.repo", "octocat/hello")
+        .unwrap_err();
+    assert!(error.to_string().contains("Unknown configuration key"));
+    let error = config.set_value("retry.max_retries", "many");
+    assert!(error.is_err());
+    assert_eq!(config, Config::default());
+}
+
+/// Without retry settings the built-in defaults apply, and the delay doubles
+/// with every attempt
+#[test]
+fn test_retry_config_delay() {
+    let config = RetryConfig::default();
+    assert_eq!(config.max_retries(), DEFAULT_MAX_RETRY_COUNT);
+    assert_eq!(config.delay(0), Duration::from_millis(100));
+
+    let config = RetryConfig {
+        max_retries: Some(2),
+        initial_delay_ms: Some(500),
+    };
+    assert_eq!(config.max_retries(), 2);
+    assert_eq!(config.delay(0), Duration::from_millis(500));
+    assert_eq!(config.delay(3), Duration::from_millis(4000));
+    assert!(config.delay(u32::MAX) > Duration::from_secs(1));
+}
+
+/// `config set` writes the configuration file read by later commands, and
+/// rejects output formats the CLI does not know
+#[test]
+fn test_cli_config_set_and_get() {
+    let dir = tempfile::tempdir().unwrap();
+    let cli = |args: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_github-edit-cli"))
+            .env("GITHUB_EDIT_CONFIG_DIR", dir.path())
+            .env_remove("GITHUB_EDIT_PROFILE")
+            .args(args)
+            .output()
+            .unwrap()
+    };
+
+    let output = cli(&["config", "set", "defaults.output", "json"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // The configured output format applies without --output
+    let output = cli(&["config", "get", "defaults.output"]);
+    assert!(output.status.success());
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["result"], "value");
+    assert_eq!(result["value"], "json");
+
+    let output = cli(&["config", "set", "defaults.output", "yaml"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid output format 'yaml'"));
+    let config = std::fs::read_to_string(dir.path().join("config.toml")).unwrap();
+    assert!(config.contains("output = \"json\""));
+}
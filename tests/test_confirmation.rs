@@ -0,0 +1,178 @@
+use github_edit::github::GitHubClient;
+use github_edit::github::dry_run::with_dry_run;
+use github_edit::tools::confirmation::ConfirmationTokens;
+use github_edit::tools::tool_definition::DeletionTools;
+use github_edit::types::comment::CommentTarget;
+use github_edit::types::confirmation::{ConfirmationRequest, DestructiveOperation};
+use github_edit::types::issue::{IssueCommentNumber, IssueNumber};
+use github_edit::types::repository::{MilestoneNumber, RepositoryId};
+use rmcp::model::{CallToolResult, RawContent};
+
+fn repository_id() -> RepositoryId {
+    RepositoryId::new("octocat", "hello")
+}
+
+fn texts(result: &CallToolResult) -> Vec<String> {
+    result
+        .content
+        .iter()
+        .map(|content| match &content.raw {
+            RawContent::Text(text) => text.text.clone(),
+            other => panic!("unexpected content: {:?}", other),
+        })
+        .collect()
+}
+
+/// Operations name what they destroy and the tool performing them
+#[test]
+fn test_destructive_operation_display() {
+    let cases = [
+        (
+            DestructiveOperation::DeleteIssue(repository_id(), IssueNumber::new(42)),
+            "delete_issue",
+            "delete issue #42 in https://github.com/octocat/hello",
+        ),
+        (
+            DestructiveOperation::DeleteLabel(repository_id(), "bug".to_string()),
+            "delete_label",
+            "delete label 'bug' in https://github.com/octocat/hello",
+        ),
+        (
+            DestructiveOperation::DeleteMilestone(repository_id(), MilestoneNumber::new(3)),
+            "delete_milestone",
+            "delete milestone 3 in https://github.com/octocat/hello",
+        ),
+        (
+            DestructiveOperation::DeleteComment(
+                repository_id(),
+                CommentTarget::IssueComment(IssueCommentNumber::new(7)),
+            ),
+            "delete_comment",
+            "delete issue comment 7 in https://github.com/octocat/hello",
+        ),
+    ];
+    for (operation, tool_name, text) in cases {
+        assert_eq!(operation.tool_name(), tool_name);
+        assert_eq!(operation.to_string(), text);
+    }
+}
+
+/// Tokens confirm only the operation they were issued for, once
+#[test]
+fn test_tokens_are_single_use_and_bound_to_operation() {
+    let confirmations = ConfirmationTokens::default();
+    let issue_42 = DestructiveOperation::DeleteIssue(repository_id(), IssueNumber::new(42));
+    let issue_43 = DestructiveOperation::DeleteIssue(repository_id(), IssueNumber::new(43));
+
+    let (token, expires_at) = confirmations.issue(issue_42.clone());
+    assert!(expires_at > chrono::Utc::now());
+
+    let error = confirmations.redeem(&token, &issue_43).unwrap_err();
+    assert!(error.contains("issued to delete issue #42"), "{}", error);
+
+    assert!(confirmations.redeem(&token, &issue_42).is_ok());
+    let error = confirmations.redeem(&token, &issue_42).unwrap_err();
+    assert!(error.contains("already used"), "{}", error);
+
+    assert!(confirmations.redeem("made-up", &issue_42).is_err());
+}
+
+/// Checking a token in a dry run leaves it usable for the real deletion
+#[tokio::test]
+async fn test_dry_run_keeps_token() {
+    let confirmations = ConfirmationTokens::default();
+    let operation = DestructiveOperation::DeleteLabel(repository_id(), "bug".to_string());
+    let (token, _) = confirmations.issue(operation.clone());
+
+    let (result, _) = with_dry_run(async { confirmations.redeem(&token, &operation) }).await;
+    assert!(result.is_ok());
+    assert!(confirmations.redeem(&token, &operation).is_ok());
+}
+
+/// The first call describes the label and deletes nothing; the second call
+/// with the token deletes it; the token cannot be used again
+#[tokio::test]
+async fn test_delete_label_two_step_flow() {
+    let mut server = mockito::Server::new_async().await;
+    let _labels_mock = server
+        .mock("GET", "/repos/octocat/hello/labels")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body(r#"[{"name": "bug", "color": "d73a4a", "description": "Something is broken"}]"#)
+        .create_async()
+        .await;
+    let delete_mock = server
+        .mock("DELETE", "/repos/octocat/hello/labels/bug")
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+    let confirmations = ConfirmationTokens::default();
+    let delete = |token: Option<String>| {
+        DeletionTools::delete_label(
+            &client,
+            &confirmations,
+            "octocat/hello".to_string(),
+            "bug".to_string(),
+            token,
+        )
+    };
+
+    let first = delete(None).await.unwrap();
+    assert_ne!(first.is_error, Some(true));
+    let first_texts = texts(&first);
+    assert!(
+        first_texts[0].starts_with("Nothing was deleted yet. Label 'bug' (color #d73a4a)"),
+        "{}",
+        first_texts[0]
+    );
+    let request: ConfirmationRequest = serde_json::from_str(&first_texts[1]).unwrap();
+    assert_eq!(
+        request.operation,
+        "delete label 'bug' in https://github.com/octocat/hello"
+    );
+
+    let wrong = delete(Some("made-up".to_string())).await.unwrap();
+    assert_eq!(wrong.is_error, Some(true));
+
+    let second = delete(Some(request.confirmation_token.clone()))
+        .await
+        .unwrap();
+    assert_ne!(second.is_error, Some(true), "{:?}", texts(&second));
+
+    let third = delete(Some(request.confirmation_token)).await.unwrap();
+    assert_eq!(third.is_error, Some(true));
+
+    delete_mock.assert_async().await;
+}
+
+/// Labels that do not exist are reported without issuing a token
+#[tokio::test]
+async fn test_delete_missing_label_issues_no_token() {
+    let mut server = mockito::Server::new_async().await;
+    let _labels_mock = server
+        .mock("GET", "/repos/octocat/hello/labels")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+    let result = DeletionTools::delete_label(
+        &client,
+        &ConfirmationTokens::default(),
+        "octocat/hello".to_string(),
+        "bug".to_string(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.is_error, Some(true));
+    assert!(texts(&result)[0].contains("not found"));
+}
@@ -0,0 +1,65 @@
+use github_edit::types::timeline::{CrossReference, dedup_cross_references};
+use serde_json::json;
+
+fn cross_referenced_event(typename: &str, number: u64, repository: &str) -> serde_json::Value {
+    json!({
+        "actor": { "login": "octocat" },
+        "referencedAt": "2024-03-01T12:00:00Z",
+        "isCrossRepository": repository != "owner/repo",
+        "willCloseTarget": typename == "PullRequest",
+        "source": {
+            "__typename": typename,
+            "number": number,
+            "title": "Referencing item",
+            "url": format!("https://github.com/{}/{}/{}", repository,
+                if typename == "PullRequest" { "pull" } else { "issues" }, number),
+            "state": "OPEN",
+            "repository": { "nameWithOwner": repository }
+        }
+    })
+}
+
+/// Cross-referenced events are parsed with their source item
+#[test]
+fn test_cross_reference_from_graphql_value() {
+    let reference =
+        CrossReference::from_graphql_value(&cross_referenced_event("PullRequest", 7, "other/lib"))
+            .unwrap();
+
+    assert_eq!(reference.repository, "other/lib");
+    assert_eq!(reference.number, 7);
+    assert!(reference.is_pull_request);
+    assert!(reference.is_cross_repository);
+    assert!(reference.will_close_target);
+    assert_eq!(reference.state, "OPEN");
+    assert_eq!(reference.actor.as_deref(), Some("octocat"));
+    assert!(reference.referenced_at.is_some());
+}
+
+/// Events whose source is not readable are skipped
+#[test]
+fn test_cross_reference_without_source() {
+    assert!(CrossReference::from_graphql_value(&json!({})).is_none());
+    assert!(CrossReference::from_graphql_value(&json!({ "source": {} })).is_none());
+}
+
+/// Repeated references from the same item are listed once
+#[test]
+fn test_dedup_cross_references() {
+    let references = [
+        cross_referenced_event("Issue", 1, "owner/repo"),
+        cross_referenced_event("PullRequest", 2, "owner/repo"),
+        cross_referenced_event("Issue", 1, "owner/repo"),
+        cross_referenced_event("Issue", 1, "other/lib"),
+    ]
+    .iter()
+    .filter_map(CrossReference::from_graphql_value)
+    .collect();
+
+    let numbers: Vec<_> = dedup_cross_references(references)
+        .iter()
+        .map(|reference| format!("{}#{}", reference.repository, reference.number))
+        .collect();
+
+    assert_eq!(numbers, vec!["owner/repo#1", "owner/repo#2", "other/lib#1"]);
+}
@@ -0,0 +1,157 @@
+use github_edit::types::discussion::{
+    Discussion, DiscussionCategory, DiscussionComment, LockReason,
+};
+use serde_json::json;
+
+fn category_value() -> serde_json::Value {
+    json!({
+        "id": "DIC_kwDOAbc123",
+        "name": "Q&A",
+        "slug": "q-a",
+        "description": "Ask the community for help",
+        "emoji": ":pray:",
+        "isAnswerable": true
+    })
+}
+
+/// Categories parse from GraphQL and match by node ID, slug or name
+#[test]
+fn test_discussion_category_matches() {
+    let category = DiscussionCategory::from_graphql_value(&category_value()).unwrap();
+
+    assert!(category.is_answerable);
+    assert_eq!(
+        category.description.as_deref(),
+        Some("Ask the community for help")
+    );
+    assert!(category.matches("DIC_kwDOAbc123"));
+    assert!(category.matches("q-a"));
+    assert!(category.matches("q&a"));
+    assert!(!category.matches("general"));
+}
+
+/// Comments parse with their nested replies
+#[test]
+fn test_discussion_comment_with_replies() {
+    let comment = DiscussionComment::from_graphql_value(&json!({
+        "id": "DC_kwDOA1",
+        "body": "Try setting X",
+        "url": "https://github.com/owner/repo/discussions/42#discussioncomment-1",
+        "createdAt": "2024-03-01T12:00:00Z",
+        "isAnswer": false,
+        "upvoteCount": 3,
+        "author": {"login": "octocat"},
+        "replies": {
+            "nodes": [
+                {
+                    "id": "DC_kwDOA2",
+                    "body": "Thanks!",
+                    "url": "https://github.com/owner/repo/discussions/42#discussioncomment-2",
+                    "createdAt": "2024-03-01T13:00:00Z",
+                    "isAnswer": false,
+                    "upvoteCount": 0,
+                    "author": null
+                }
+            ]
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(comment.author.as_deref(), Some("octocat"));
+    assert_eq!(comment.upvote_count, 3);
+    assert_eq!(comment.replies.len(), 1);
+    assert_eq!(comment.replies[0].author, None);
+    assert!(comment.replies[0].replies.is_empty());
+}
+
+/// Discussions parse with their category and find answers among replies
+#[test]
+fn test_discussion_from_graphql_value() {
+    let discussion = Discussion::from_graphql_value(&json!({
+        "id": "D_kwDOA42",
+        "number": 42,
+        "title": "How do I configure retries?",
+        "body": "Details...",
+        "url": "https://github.com/owner/repo/discussions/42",
+        "createdAt": "2024-03-01T11:00:00Z",
+        "locked": false,
+        "closed": false,
+        "isAnswered": true,
+        "author": {"login": "hubot"},
+        "category": category_value(),
+        "comments": {
+            "totalCount": 7,
+            "nodes": [
+                {
+                    "id": "DC_kwDOA1",
+                    "body": "Which version?",
+                    "url": "https://github.com/owner/repo/discussions/42#discussioncomment-1",
+                    "createdAt": "2024-03-01T12:00:00Z",
+                    "isAnswer": false,
+                    "upvoteCount": 0,
+                    "author": {"login": "octocat"},
+                    "replies": {
+                        "nodes": [
+                            {
+                                "id": "DC_kwDOA2",
+                                "body": "Set max_retry_count",
+                                "url": "https://github.com/owner/repo/discussions/42#discussioncomment-2",
+                                "createdAt": "2024-03-01T13:00:00Z",
+                                "isAnswer": true,
+                                "upvoteCount": 5,
+                                "author": {"login": "octocat"}
+                            }
+                        ]
+                    }
+                }
+            ]
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(discussion.number.value(), 42);
+    assert_eq!(discussion.category.slug, "q-a");
+    assert!(discussion.is_answered);
+    assert_eq!(discussion.comment_count, 7);
+    assert_eq!(discussion.comments.len(), 1);
+    assert_eq!(
+        discussion.answer().map(|c| c.id.as_str()),
+        Some("DC_kwDOA2")
+    );
+}
+
+/// Discussions created without requesting comments have no comments
+#[test]
+fn test_discussion_without_comments() {
+    let discussion = Discussion::from_graphql_value(&json!({
+        "id": "D_kwDOA43",
+        "number": 43,
+        "title": "Announcement",
+        "body": "",
+        "url": "https://github.com/owner/repo/discussions/43",
+        "createdAt": "2024-03-02T11:00:00Z",
+        "author": {"login": "hubot"},
+        "category": category_value()
+    }))
+    .unwrap();
+
+    assert_eq!(discussion.comment_count, 0);
+    assert!(discussion.answer().is_none());
+    assert!(!discussion.locked);
+
+    assert!(Discussion::from_graphql_value(&json!({"id": "D_kwDOA44"})).is_none());
+}
+
+/// Lock reasons parse from snake_case and map to the GraphQL enum
+#[test]
+fn test_lock_reason() {
+    assert_eq!(
+        "too_heated".parse::<LockReason>().unwrap(),
+        LockReason::TooHeated
+    );
+    assert_eq!("Spam".parse::<LockReason>().unwrap(), LockReason::Spam);
+    assert!("heated".parse::<LockReason>().is_err());
+
+    assert_eq!(LockReason::OffTopic.graphql_value(), "OFF_TOPIC");
+    assert_eq!(LockReason::Resolved.to_string(), "resolved");
+}
@@ -0,0 +1,222 @@
+use github_edit::github::GitHubClient;
+use github_edit::github::dry_run::{is_dry_run, is_write, with_dry_run};
+use github_edit::tools::dry_run::{
+    DRY_RUN_ARGUMENT, dry_run_tool, is_read_only_tool, take_dry_run_argument, with_dry_run_argument,
+};
+use github_edit::tools::tool_definition::RepositoryTools;
+use github_edit::types::dry_run::DryRunReport;
+use github_edit::types::repository::RepositoryId;
+use rmcp::model::{CallToolRequestParam, JsonObject, RawContent, Tool};
+use std::sync::Arc;
+
+fn client(server: &mockito::Server) -> GitHubClient {
+    GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap()
+}
+
+fn tool(name: &'static str) -> Tool {
+    let schema = serde_json::json!({
+        "type": "object",
+        "properties": {"repository_url": {"type": "string"}},
+    });
+    let schema: JsonObject = serde_json::from_value(schema).unwrap();
+    Tool::new(name, "", Arc::new(schema))
+}
+
+fn request(name: &'static str, arguments: serde_json::Value) -> CallToolRequestParam {
+    CallToolRequestParam {
+        name: name.into(),
+        arguments: serde_json::from_value(arguments).unwrap(),
+    }
+}
+
+/// Reads, including GraphQL queries, are sent in a dry run; everything else
+/// is a write
+#[test]
+fn test_write_classification() {
+    let api = "https://api.github.com";
+    assert!(!is_write("GET", &format!("{}/repos/o/r/labels", api), b""));
+    assert!(!is_write("HEAD", &format!("{}/repos/o/r", api), b""));
+    assert!(is_write(
+        "POST",
+        &format!("{}/repos/o/r/labels", api),
+        b"{}"
+    ));
+    assert!(is_write(
+        "PATCH",
+        &format!("{}/repos/o/r/labels/bug", api),
+        b"{}"
+    ));
+    assert!(is_write(
+        "DELETE",
+        &format!("{}/repos/o/r/labels/bug", api),
+        b""
+    ));
+    assert!(!is_write(
+        "POST",
+        &format!("{}/repos/o/r/releases/generate-notes", api),
+        b"{}"
+    ));
+
+    let graphql = format!("{}/graphql", api);
+    assert!(!is_write(
+        "POST",
+        &graphql,
+        br#"{"query": "\n  query { viewer { login } }"}"#
+    ));
+    assert!(is_write(
+        "POST",
+        &graphql,
+        br#"{"query": "mutation { deleteIssue(input: {}) { clientMutationId } }"}"#
+    ));
+    assert!(is_write("POST", &graphql, b"not json"));
+}
+
+/// Only mutating tools accept the dry_run argument
+#[test]
+fn test_dry_run_argument_of_mutating_tools() {
+    assert!(is_read_only_tool("list_labels"));
+    assert!(is_read_only_tool("get_rate_limit"));
+    assert!(is_read_only_tool("resolve_single_select_option"));
+    assert!(!is_read_only_tool("create_label"));
+
+    let mutating = with_dry_run_argument(tool("create_label"));
+    assert_eq!(
+        mutating.input_schema["properties"][DRY_RUN_ARGUMENT]["type"],
+        "boolean"
+    );
+    let read_only = with_dry_run_argument(tool("list_labels"));
+    assert!(
+        read_only.input_schema["properties"]
+            .get(DRY_RUN_ARGUMENT)
+            .is_none()
+    );
+
+    let mut call = request(
+        "create_label",
+        serde_json::json!({"repository_url": "o/r", "dry_run": true}),
+    );
+    assert!(take_dry_run_argument(&mut call).unwrap());
+    assert!(!call.arguments.unwrap().contains_key(DRY_RUN_ARGUMENT));
+
+    let mut call = request("create_label", serde_json::json!({"dry_run": "yes"}));
+    assert!(take_dry_run_argument(&mut call).is_err());
+
+    let mut call = request("list_labels", serde_json::json!({"dry_run": true}));
+    assert!(!take_dry_run_argument(&mut call).unwrap());
+}
+
+/// Direct REST writes are recorded instead of sent
+#[tokio::test]
+async fn test_dry_run_records_rest_write() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/repos/octocat/hello/labels")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = client(&server);
+    assert!(!is_dry_run());
+    let (result, planned_requests) = with_dry_run(client.create_label(
+        &RepositoryId::new("octocat", "hello"),
+        "triage",
+        Some("ededed"),
+        None,
+    ))
+    .await;
+
+    assert!(result.is_err());
+    assert_eq!(planned_requests.len(), 1);
+    assert_eq!(planned_requests[0].method, "POST");
+    assert_eq!(
+        planned_requests[0].url,
+        format!("{}/repos/octocat/hello/labels", server.url())
+    );
+    assert_eq!(
+        planned_requests[0].body,
+        Some(serde_json::json!({"name": "triage", "color": "ededed"}))
+    );
+    mock.assert_async().await;
+}
+
+/// GraphQL mutations sent through octocrab are recorded instead of sent
+#[tokio::test]
+async fn test_dry_run_records_graphql_mutation() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/graphql")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = client(&server);
+    let (result, planned_requests) =
+        with_dry_run(client.delete_discussion_comment("DC_kwDOtest")).await;
+
+    assert!(result.is_err());
+    assert_eq!(planned_requests.len(), 1);
+    let body = planned_requests[0].body.as_ref().unwrap();
+    assert!(body["query"].as_str().unwrap().contains("mutation"));
+    mock.assert_async().await;
+}
+
+/// A dry run of a tool reports its planned requests and the problems found
+/// validating its arguments
+#[tokio::test]
+async fn test_dry_run_tool_report() {
+    let mut server = mockito::Server::new_async().await;
+    let repository_mock = server
+        .mock("POST", "/graphql")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"message": "Not Found", "documentation_url": null}"#)
+        .expect_at_least(1)
+        .create_async()
+        .await;
+    let write_mock = server
+        .mock("POST", "/repos/octocat/hello/labels")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = client(&server);
+    let call = request(
+        "create_label",
+        serde_json::json!({"repository_url": "octocat/hello", "name": "triage"}),
+    );
+    let result = dry_run_tool(
+        &client,
+        &call,
+        RepositoryTools::create_label(
+            &client,
+            "octocat/hello".to_string(),
+            "triage".to_string(),
+            None,
+            None,
+        ),
+    )
+    .await
+    .unwrap();
+
+    assert_ne!(result.is_error, Some(true));
+    let RawContent::Text(text) = &result.content[0].raw else {
+        panic!("expected a text summary");
+    };
+    assert!(
+        text.text
+            .starts_with("Dry run of create_label: would send 1 request(s)"),
+        "{}",
+        text.text
+    );
+    let RawContent::Text(json) = &result.content[1].raw else {
+        panic!("expected a JSON report");
+    };
+    let report: DryRunReport = serde_json::from_str(&json.text).unwrap();
+    assert_eq!(report.tool, "create_label");
+    assert_eq!(report.planned_requests.len(), 1);
+    assert_eq!(report.warnings.len(), 1, "{:?}", report.warnings);
+    assert!(report.warnings[0].contains("not accessible"));
+
+    repository_mock.assert_async().await;
+    write_mock.assert_async().await;
+}
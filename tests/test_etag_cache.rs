@@ -0,0 +1,199 @@
+use github_edit::config::{CACHE_DIR_NAME, CacheConfig};
+use github_edit::github::GitHubClient;
+use github_edit::github::etag_cache::EtagCache;
+use github_edit::types::pagination::Pagination;
+use github_edit::types::repository::RepositoryId;
+use mockito::Matcher;
+use std::path::PathBuf;
+
+const ETAG: &str = "\"0a1b2c\"";
+
+/// Server answering `/user` with an ETag, and with `304 Not Modified` to
+/// requests revalidating it
+async fn user_server() -> (mockito::ServerGuard, mockito::Mock, mockito::Mock) {
+    let mut server = mockito::Server::new_async().await;
+    let modified = server
+        .mock("GET", "/user")
+        .match_header("if-none-match", Matcher::Missing)
+        .with_header("content-type", "application/json")
+        .with_header("etag", ETAG)
+        .with_body(r#"{"login": "octocat"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let not_modified = server
+        .mock("GET", "/user")
+        .match_header("if-none-match", ETAG)
+        .with_status(304)
+        .expect(1)
+        .create_async()
+        .await;
+    (server, modified, not_modified)
+}
+
+fn client(server: &mockito::ServerGuard, token: &str, etag_cache: EtagCache) -> GitHubClient {
+    GitHubClient::with_api_base_url(Some(token.to_string()), None, &server.url())
+        .unwrap()
+        .with_etag_cache(etag_cache)
+        .unwrap()
+}
+
+async fn login(client: &GitHubClient) -> String {
+    let user: serde_json::Value = client.octocrab().get("/user", None::<&()>).await.unwrap();
+    user["login"].as_str().unwrap().to_string()
+}
+
+/// The cache is off by default and only persisted on request
+#[test]
+fn test_cache_config() {
+    let config = CacheConfig::default();
+    assert!(!config.is_enabled());
+    assert!(EtagCache::from_config(&config).unwrap().is_none());
+
+    let config: CacheConfig = toml::from_str("enabled = true").unwrap();
+    let etag_cache = EtagCache::from_config(&config).unwrap().unwrap();
+    assert!(etag_cache.dir().is_none());
+
+    let config: CacheConfig = toml::from_str(
+        r#"
+        enabled = true
+        persist = true
+        path = "/tmp/github-edit-cache"
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        config.cache_dir().unwrap(),
+        Some(PathBuf::from("/tmp/github-edit-cache"))
+    );
+
+    let config = CacheConfig {
+        persist: Some(true),
+        ..CacheConfig::default()
+    };
+    assert!(
+        config
+            .cache_dir()
+            .unwrap()
+            .unwrap()
+            .ends_with(CACHE_DIR_NAME)
+    );
+}
+
+/// A repeated request is revalidated with the ETag and an unchanged
+/// resource is served from the cache
+#[tokio::test]
+async fn test_unchanged_response_served_from_cache() {
+    let (server, modified, not_modified) = user_server().await;
+    let client = client(&server, "ghp_test", EtagCache::new(None));
+
+    assert_eq!(login(&client).await, "octocat");
+    assert_eq!(login(&client).await, "octocat");
+
+    modified.assert_async().await;
+    not_modified.assert_async().await;
+}
+
+/// Persisted responses are revalidated by later clients
+#[tokio::test]
+async fn test_persisted_cache_is_shared_across_clients() {
+    let (server, modified, not_modified) = user_server().await;
+    let dir = tempfile::tempdir().unwrap();
+
+    let first = client(&server, "ghp_test", EtagCache::new(Some(dir.path().into())));
+    assert_eq!(login(&first).await, "octocat");
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+
+    let second = client(&server, "ghp_test", EtagCache::new(Some(dir.path().into())));
+    assert_eq!(login(&second).await, "octocat");
+
+    modified.assert_async().await;
+    not_modified.assert_async().await;
+}
+
+/// Responses fetched with one token are never served to another
+#[tokio::test]
+async fn test_cache_is_keyed_by_token() {
+    let (server, modified, not_modified) = user_server().await;
+    let dir = tempfile::tempdir().unwrap();
+
+    let first = client(
+        &server,
+        "ghp_first",
+        EtagCache::new(Some(dir.path().into())),
+    );
+    assert_eq!(login(&first).await, "octocat");
+    modified.assert_async().await;
+
+    let second = client(
+        &server,
+        "ghp_second",
+        EtagCache::new(Some(dir.path().into())),
+    );
+    assert_eq!(login(&second).await, "octocat");
+
+    assert!(!not_modified.matched_async().await);
+}
+
+/// Direct REST requests, such as listing labels, are revalidated too
+#[tokio::test]
+async fn test_direct_rest_requests_are_cached() {
+    let mut server = mockito::Server::new_async().await;
+    let modified = server
+        .mock("GET", "/repos/octocat/hello-world/labels")
+        .match_query(Matcher::Any)
+        .match_header("if-none-match", Matcher::Missing)
+        .with_header("content-type", "application/json")
+        .with_header("etag", ETAG)
+        .with_body(r#"[{"name": "bug", "color": "d73a4a", "description": null}]"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let not_modified = server
+        .mock("GET", "/repos/octocat/hello-world/labels")
+        .match_query(Matcher::Any)
+        .match_header("if-none-match", ETAG)
+        .with_status(304)
+        .expect(1)
+        .create_async()
+        .await;
+    let client = client(&server, "ghp_test", EtagCache::new(None));
+    let repository_id = RepositoryId::new("octocat", "hello-world");
+
+    for _ in 0..2 {
+        let labels = client
+            .list_labels(&repository_id, Pagination::default())
+            .await
+            .unwrap();
+        assert_eq!(labels.items.len(), 1);
+        assert_eq!(labels.items[0].name(), "bug");
+    }
+
+    modified.assert_async().await;
+    not_modified.assert_async().await;
+}
+
+/// Persisted entries are only readable by the current user
+#[cfg(unix)]
+#[tokio::test]
+async fn test_persisted_entries_are_private() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let (server, _modified, _not_modified) = user_server().await;
+    let dir = tempfile::tempdir().unwrap();
+    let cache_dir = dir.path().join("cache");
+
+    let client = client(&server, "ghp_test", EtagCache::new(Some(cache_dir.clone())));
+    assert_eq!(login(&client).await, "octocat");
+
+    let mode =
+        |path: &std::path::Path| std::fs::metadata(path).unwrap().permissions().mode() & 0o777;
+    assert_eq!(mode(&cache_dir), 0o700);
+    let entry = std::fs::read_dir(&cache_dir)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path();
+    assert_eq!(mode(&entry), 0o600);
+}
@@ -0,0 +1,66 @@
+use github_edit::types::content::{FileCommit, FileContent, decode_content, encode_content};
+use serde_json::json;
+
+/// Line-wrapped base64 content is decoded into the file bytes
+#[test]
+fn test_file_content_from_api_value() {
+    let file = FileContent::from_api_value(&json!({
+        "type": "file",
+        "encoding": "base64",
+        "size": 12,
+        "name": "README.md",
+        "path": "docs/README.md",
+        "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+        "content": "SGVsbG8s\nIHdvcmxk\nIQ==\n"
+    }))
+    .unwrap();
+
+    assert_eq!(file.path, "docs/README.md");
+    assert_eq!(file.sha, "3d21ec53a331a6f037a91c368710b99387d012c1");
+    assert_eq!(file.text(), Some("Hello, world!"));
+}
+
+/// Directories and files too large for the Contents API are rejected
+#[test]
+fn test_file_content_rejects_directories_and_large_files() {
+    assert!(FileContent::from_api_value(&json!([{"type": "file", "path": "a"}])).is_err());
+    assert!(
+        FileContent::from_api_value(&json!({
+            "type": "file",
+            "encoding": "none",
+            "path": "big.bin",
+            "sha": "abc",
+            "content": ""
+        }))
+        .is_err()
+    );
+}
+
+/// Encoding round-trips binary content
+#[test]
+fn test_content_encoding_round_trip() {
+    let bytes = vec![0u8, 159, 146, 150, 255];
+    assert_eq!(decode_content(&encode_content(&bytes)).unwrap(), bytes);
+}
+
+/// Write results carry the commit SHA and, unless deleted, the new blob SHA
+#[test]
+fn test_file_commit_from_api_value() {
+    let updated = FileCommit::from_api_value(
+        "a.txt",
+        &json!({
+            "content": {"sha": "blob1"},
+            "commit": {"sha": "commit1", "html_url": "https://github.com/o/r/commit/commit1"}
+        }),
+    )
+    .unwrap();
+    assert_eq!(updated.content_sha.as_deref(), Some("blob1"));
+    assert_eq!(updated.commit_sha, "commit1");
+
+    let deleted = FileCommit::from_api_value(
+        "a.txt",
+        &json!({"content": null, "commit": {"sha": "commit2"}}),
+    )
+    .unwrap();
+    assert!(deleted.content_sha.is_none());
+}
@@ -0,0 +1,208 @@
+use github_edit::github::GitHubClient;
+use github_edit::github::error::{ApiRetryableError, ErrorKind};
+use github_edit::github::graphql::{Connection, GraphQlResponse, NodeId};
+use github_edit::types::project::{
+    ProjectId, ProjectItemId, ProjectNumber, ProjectOriginalResource, ProjectType,
+};
+use github_edit::types::repository::Owner;
+use serde_json::json;
+
+fn response(body: serde_json::Value) -> GraphQlResponse {
+    serde_json::from_value(body).unwrap()
+}
+
+#[test]
+fn test_errors_are_joined_with_their_paths() {
+    let error = response(json!({
+        "data": null,
+        "errors": [
+            {
+                "type": "NOT_FOUND",
+                "path": ["repository", "issue"],
+                "message": "Could not resolve to an Issue with the number of 9."
+            },
+            { "message": "Something else went wrong" }
+        ]
+    }))
+    .into_data::<serde_json::Value>()
+    .unwrap_err();
+
+    assert_eq!(
+        error,
+        ApiRetryableError::NonRetryable(
+            "Could not resolve to an Issue with the number of 9. (at repository.issue); Something else went wrong"
+                .to_string()
+        )
+    );
+}
+
+#[test]
+fn test_rate_limited_error_is_retryable() {
+    let error = response(json!({
+        "errors": [{ "type": "RATE_LIMITED", "message": "API rate limit exceeded" }]
+    }))
+    .into_data::<serde_json::Value>()
+    .unwrap_err();
+
+    assert_eq!(error, ApiRetryableError::RateLimit);
+}
+
+#[test]
+fn test_missing_data() {
+    let error = response(json!({})).into_data::<NodeId>().unwrap_err();
+    assert_eq!(
+        error,
+        ApiRetryableError::NonRetryable("No data in GraphQL response".to_string())
+    );
+}
+
+#[test]
+fn test_connection_skips_unreadable_nodes() {
+    let connection: Connection<NodeId> = response(json!({
+        "data": {
+            "nodes": [{ "id": "I_1" }, null, { "id": "I_2" }],
+            "pageInfo": { "hasNextPage": true, "endCursor": "Y3Vyc29y" },
+            "totalCount": 3
+        }
+    }))
+    .into_data()
+    .unwrap();
+
+    assert_eq!(connection.next_cursor().as_deref(), Some("Y3Vyc29y"));
+    assert_eq!(connection.total_count, Some(3));
+    let ids: Vec<String> = connection.into_nodes().map(|node| node.id).collect();
+    assert_eq!(ids, vec!["I_1", "I_2"]);
+}
+
+/// A response that no longer matches the query fails with the path of the
+/// mismatch instead of being read as a missing project
+#[tokio::test]
+async fn test_schema_mismatch_names_the_path() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("POST", "/graphql")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"user": {"projectV2": {"id": 3}}}}"#)
+        .create_async()
+        .await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+
+    let project_id = ProjectId::new(
+        Owner::new("octocat".to_string()),
+        ProjectNumber::new(1),
+        ProjectType::User,
+    );
+    let error = client.get_project_node_id(&project_id).await.unwrap_err();
+    assert!(
+        format!("{:#}", error).contains("Unexpected GraphQL response at user.projectV2.id"),
+        "{:#}",
+        error
+    );
+}
+
+#[tokio::test]
+async fn test_project_item_content_by_typename() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("POST", "/graphql")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"node": {"content": {
+                "__typename": "PullRequest",
+                "number": 42,
+                "repository": {"owner": {"login": "octocat"}, "name": "hello-world"}
+            }}}}"#,
+        )
+        .create_async()
+        .await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+
+    let content = client
+        .get_project_item_content(&ProjectItemId::new("PVTI_1".to_string()))
+        .await
+        .unwrap();
+    match content {
+        ProjectOriginalResource::PullRequest(pull_request_id) => {
+            assert_eq!(pull_request_id.number, 42);
+            assert_eq!(pull_request_id.git_repository.owner().as_str(), "octocat");
+        }
+        other => panic!("unexpected content: {:?}", other),
+    }
+}
+
+/// A rate limited GraphQL call keeps its category through the context of
+/// the call site, so it is retried instead of failing at once
+#[tokio::test]
+async fn test_rate_limited_call_is_retried() {
+    let mut server = mockito::Server::new_async().await;
+    let rate_limited = server
+        .mock("POST", "/graphql")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"errors": [{"type": "RATE_LIMITED", "message": "API rate limit exceeded"}]}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let succeeded = server
+        .mock("POST", "/graphql")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"organization": {"issueTypes": {"nodes": [
+                {"id": "IT_1", "name": "Bug", "description": null, "color": "RED", "isEnabled": true}
+            ]}}}}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+
+    let issue_types = client
+        .list_issue_types(&Owner::new("octocat".to_string()))
+        .await
+        .unwrap();
+
+    assert_eq!(issue_types.len(), 1);
+    assert_eq!(issue_types[0].name, "Bug");
+    rate_limited.assert_async().await;
+    succeeded.assert_async().await;
+}
+
+/// Errors that are not retryable fail at once, with the context of the call
+/// site and their category
+#[tokio::test]
+async fn test_graphql_error_keeps_category_and_context() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/graphql")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"errors": [{"type": "NOT_FOUND", "path": ["organization"], "message": "Could not resolve to an Organization with the login of 'octocat'."}]}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+
+    let error = client
+        .list_issue_types(&Owner::new("octocat".to_string()))
+        .await
+        .unwrap_err();
+
+    assert_eq!(ErrorKind::of(&error), ErrorKind::NotFound);
+    assert!(
+        format!("{:#}", error).contains("Failed to list issue types of octocat"),
+        "{:#}",
+        error
+    );
+    mock.assert_async().await;
+}
@@ -0,0 +1,106 @@
+use github_edit::github::GitHubClient;
+use github_edit::types::project::{
+    ProjectFieldId, ProjectFieldValue, ProjectId, ProjectItemId, ProjectNodeId, ProjectNumber,
+    ProjectType,
+};
+use github_edit::types::repository::Owner;
+use mockito::Matcher;
+use serde_json::json;
+
+/// Values are sent as GraphQL variables, so quotes and line breaks in them
+/// cannot break or change the query
+#[tokio::test]
+async fn test_field_value_sent_as_variable() {
+    let mut server = mockito::Server::new_async().await;
+    let text = "He said \"ship it\"\n}) { injected }";
+    let mock = server
+        .mock("POST", "/graphql")
+        .match_body(Matcher::PartialJson(json!({
+            "variables": {
+                "projectId": "PVT_1",
+                "itemId": "PVTI_1",
+                "fieldId": "PVTF_1",
+                "value": { "text": text },
+            }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"data": {"updateProjectV2ItemFieldValue": {"projectV2Item": {"id": "PVTI_1"}}}}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+
+    client
+        .update_project_item_field_value(
+            &ProjectNodeId::new("PVT_1".to_string()),
+            &ProjectItemId::new("PVTI_1".to_string()),
+            &ProjectFieldId::new("PVTF_1".to_string()),
+            &ProjectFieldValue::Text(text.to_string()),
+        )
+        .await
+        .unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_project_node_id_query_variables() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/graphql")
+        .match_body(Matcher::PartialJson(json!({
+            "variables": { "login": "acme-corp", "number": 3 }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"organization": {"projectV2": {"id": "PVT_3"}}}}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+
+    let project_id = ProjectId::new(
+        Owner::new("acme-corp".to_string()),
+        ProjectNumber::new(3),
+        ProjectType::Organization,
+    );
+    let node_id = client.get_project_node_id(&project_id).await.unwrap();
+    assert_eq!(node_id.value(), "PVT_3");
+    mock.assert_async().await;
+}
+
+/// GraphQL errors of migrated mutations keep their message
+#[tokio::test]
+async fn test_graphql_error_message() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("POST", "/graphql")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": null, "errors": [{"message": "Could not resolve to a node with the global id of 'PVT_1'"}]}"#)
+        .create_async()
+        .await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+
+    let error = client
+        .update_project_item_field_value(
+            &ProjectNodeId::new("PVT_1".to_string()),
+            &ProjectItemId::new("PVTI_1".to_string()),
+            &ProjectFieldId::new("PVTF_1".to_string()),
+            &ProjectFieldValue::Number(3.0),
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        format!("{:#}", error).contains(
+            "Failed to update project item field value: Could not resolve to a node with the global id of 'PVT_1'"
+        ),
+        "{:#}",
+        error
+    );
+}
@@ -0,0 +1,24 @@
+use github_edit::types::issue::{IssueState, IssueStateReason};
+
+/// State reasons parse from and print as GitHub's snake_case values
+#[test]
+fn test_issue_state_reason_round_trip() {
+    for (value, reason) in [
+        ("completed", IssueStateReason::Completed),
+        ("not_planned", IssueStateReason::NotPlanned),
+        ("reopened", IssueStateReason::Reopened),
+    ] {
+        assert_eq!(value.parse::<IssueStateReason>().unwrap(), reason);
+        assert_eq!(reason.to_string(), value);
+    }
+}
+
+/// Closing reasons are only valid for closed issues and reopened only for open issues
+#[test]
+fn test_issue_state_reason_validity() {
+    assert!(IssueStateReason::Completed.is_valid_for(IssueState::Closed));
+    assert!(IssueStateReason::NotPlanned.is_valid_for(IssueState::Closed));
+    assert!(!IssueStateReason::NotPlanned.is_valid_for(IssueState::Open));
+    assert!(IssueStateReason::Reopened.is_valid_for(IssueState::Open));
+    assert!(!IssueStateReason::Reopened.is_valid_for(IssueState::Closed));
+}
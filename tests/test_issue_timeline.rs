@@ -0,0 +1,168 @@
+use github_edit::types::issue::{IssueCommentNumber, IssueId, IssueLinkResult, IssueLinkState};
+use github_edit::types::pagination::Pagination;
+use github_edit::types::repository::RepositoryId;
+use github_edit::types::timeline::{IssueTimelineEvent, TimelineEventKind};
+use serde_json::json;
+
+/// Known timeline entries are parsed into typed events with their actor and time
+#[test]
+fn test_timeline_event_from_api_value() {
+    let labeled = IssueTimelineEvent::from_api_value(&json!({
+        "event": "labeled",
+        "actor": {"login": "octocat"},
+        "label": {"name": "bug", "color": "d73a4a"},
+        "created_at": "2024-01-02T03:04:05Z"
+    }));
+    assert_eq!(
+        labeled.kind,
+        TimelineEventKind::Labeled {
+            label: "bug".to_string()
+        }
+    );
+    assert_eq!(labeled.actor.as_deref(), Some("octocat"));
+    assert!(labeled.created_at.is_some());
+
+    let cross_referenced = IssueTimelineEvent::from_api_value(&json!({
+        "event": "cross-referenced",
+        "actor": {"login": "octocat"},
+        "created_at": "2024-01-02T03:04:05Z",
+        "source": {
+            "type": "issue",
+            "issue": {
+                "number": 42,
+                "title": "Fix the bug",
+                "html_url": "https://github.com/owner/repo/pull/42",
+                "pull_request": {},
+                "repository": {"full_name": "owner/repo"}
+            }
+        }
+    }));
+    match cross_referenced.kind {
+        TimelineEventKind::CrossReferenced { source } => {
+            assert_eq!(source.number, 42);
+            assert!(source.is_pull_request);
+            assert_eq!(source.repository.as_deref(), Some("owner/repo"));
+        }
+        other => panic!("unexpected event kind: {:?}", other),
+    }
+}
+
+/// Reviews carry their author in `user` and their time in `submitted_at`
+#[test]
+fn test_timeline_event_review_and_merge() {
+    let reviewed = IssueTimelineEvent::from_api_value(&json!({
+        "event": "reviewed",
+        "id": 80,
+        "user": {"login": "reviewer"},
+        "body": "Looks good",
+        "state": "APPROVED",
+        "submitted_at": "2024-01-02T03:04:05Z"
+    }));
+    assert_eq!(
+        reviewed.kind,
+        TimelineEventKind::Reviewed {
+            review_id: 80,
+            state: "approved".to_string(),
+            body: "Looks good".to_string()
+        }
+    );
+    assert_eq!(reviewed.actor.as_deref(), Some("reviewer"));
+    assert!(reviewed.created_at.is_some());
+    assert_eq!(reviewed.kind.to_string(), "reviewed: approved (review 80)");
+
+    let merged = IssueTimelineEvent::from_api_value(&json!({
+        "event": "merged",
+        "actor": {"login": "octocat"},
+        "commit_id": "6dcb09b",
+        "created_at": "2024-01-02T03:04:05Z"
+    }));
+    assert_eq!(
+        merged.kind,
+        TimelineEventKind::Merged {
+            commit_id: Some("6dcb09b".to_string())
+        }
+    );
+}
+
+/// Unknown events are kept by name instead of being dropped
+#[test]
+fn test_timeline_event_unknown_kept_as_other() {
+    let event = IssueTimelineEvent::from_api_value(&json!({
+        "event": "locked",
+        "actor": {"login": "octocat"},
+        "created_at": "2024-01-02T03:04:05Z"
+    }));
+    assert_eq!(
+        event.kind,
+        TimelineEventKind::Other {
+            name: "locked".to_string()
+        }
+    );
+}
+
+/// Page numbers start at 1 and page sizes are clamped to the API limit
+#[test]
+fn test_pagination_clamping() {
+    let default = Pagination::default();
+    assert_eq!((default.page, default.per_page), (1, 30));
+
+    let clamped = Pagination::new(Some(0), Some(250));
+    assert_eq!((clamped.page, clamped.per_page), (1, 100));
+    assert_eq!(clamped.next().page, 2);
+}
+
+/// Cross-reference events match the referencing issue by repository and number
+#[test]
+fn test_timeline_event_is_cross_reference_from() {
+    let event = IssueTimelineEvent::from_api_value(&json!({
+        "event": "cross-referenced",
+        "created_at": "2024-01-02T03:04:05Z",
+        "source": {
+            "type": "issue",
+            "issue": {
+                "number": 7,
+                "title": "Related work",
+                "html_url": "https://github.com/Owner/Repo/issues/7",
+                "repository": {"full_name": "Owner/Repo"}
+            }
+        }
+    }));
+
+    let repository_id = RepositoryId::new("owner", "repo");
+    assert!(event.is_cross_reference_from(&IssueId::new(repository_id.clone(), 7)));
+    assert!(!event.is_cross_reference_from(&IssueId::new(repository_id, 8)));
+    assert!(!event.is_cross_reference_from(&IssueId::new(RepositoryId::new("owner", "other"), 7)));
+}
+
+/// References are short within a repository and qualified across repositories
+#[test]
+fn test_issue_reference_from() {
+    let issue_id = IssueId::new(RepositoryId::new("owner", "repo"), 12);
+    assert_eq!(
+        issue_id.reference_from(&RepositoryId::new("owner", "repo")),
+        "#12"
+    );
+    assert_eq!(
+        issue_id.reference_from(&RepositoryId::new("owner", "other")),
+        "owner/repo#12"
+    );
+}
+
+/// Link state requires both comments and both cross-references to be verified
+#[test]
+fn test_issue_link_state() {
+    let repository_id = RepositoryId::new("owner", "repo");
+    let mut result = IssueLinkResult::new(
+        IssueId::new(repository_id.clone(), 1),
+        IssueId::new(repository_id, 2),
+    );
+    assert_eq!(result.state(), IssueLinkState::Failed);
+
+    result.source_comment = Some(IssueCommentNumber::new(10));
+    result.target_comment = Some(IssueCommentNumber::new(11));
+    result.target_cross_referenced = true;
+    assert_eq!(result.state(), IssueLinkState::Unverified);
+
+    result.source_cross_referenced = true;
+    assert_eq!(result.state(), IssueLinkState::Verified);
+}
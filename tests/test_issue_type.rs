@@ -0,0 +1,162 @@
+use github_edit::github::GitHubClient;
+use github_edit::github::error::ErrorKind;
+use github_edit::types::issue::IssueNumber;
+use github_edit::types::repository::RepositoryId;
+use mockito::Matcher;
+use serde_json::{Value, json};
+
+fn user_json(login: &str) -> Value {
+    let url = format!("https://api.github.com/users/{}", login);
+    json!({
+        "login": login,
+        "id": 1,
+        "node_id": "MDQ6VXNlcjE=",
+        "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+        "gravatar_id": "",
+        "url": url,
+        "html_url": format!("https://github.com/{}", login),
+        "followers_url": format!("{}/followers", url),
+        "following_url": format!("{}/following", url),
+        "gists_url": format!("{}/gists", url),
+        "starred_url": format!("{}/starred", url),
+        "subscriptions_url": format!("{}/subscriptions", url),
+        "organizations_url": format!("{}/orgs", url),
+        "repos_url": format!("{}/repos", url),
+        "events_url": format!("{}/events", url),
+        "received_events_url": format!("{}/received_events", url),
+        "type": "User",
+        "site_admin": false
+    })
+}
+
+fn issue_json() -> Value {
+    let url = "https://api.github.com/repos/octo-org/app/issues/7";
+    json!({
+        "id": 1007,
+        "node_id": "I_kwDOA7",
+        "url": url,
+        "repository_url": "https://api.github.com/repos/octo-org/app",
+        "labels_url": format!("{}/labels{{/name}}", url),
+        "comments_url": format!("{}/comments", url),
+        "events_url": format!("{}/events", url),
+        "html_url": "https://github.com/octo-org/app/issues/7",
+        "number": 7,
+        "state": "open",
+        "title": "Crash when saving drafts",
+        "body": null,
+        "user": user_json("octocat"),
+        "labels": [],
+        "assignees": [],
+        "author_association": "OWNER",
+        "locked": false,
+        "comments": 0,
+        "created_at": "2024-01-02T03:04:05Z",
+        "updated_at": "2024-01-03T03:04:05Z"
+    })
+}
+
+/// Server with issue 7 of octo-org/app and the issue types of octo-org,
+/// one of them disabled
+async fn issue_type_server() -> mockito::ServerGuard {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("GET", "/repos/octo-org/app/issues/7")
+        .with_header("content-type", "application/json")
+        .with_body(issue_json().to_string())
+        .create_async()
+        .await;
+    server
+        .mock("POST", "/graphql")
+        .match_body(Matcher::Regex("issueTypes".to_string()))
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "data": { "organization": { "issueTypes": { "nodes": [
+                { "id": "IT_bug", "name": "Bug", "description": null, "color": "RED", "isEnabled": true },
+                { "id": "IT_feature", "name": "Feature", "description": null, "color": "BLUE", "isEnabled": true },
+                { "id": "IT_chore", "name": "Chore", "description": null, "color": "GRAY", "isEnabled": false }
+            ] } } } })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    server
+}
+
+async fn mock_update(server: &mut mockito::ServerGuard, issue_type_id: Value) -> mockito::Mock {
+    server
+        .mock("POST", "/graphql")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex("updateIssueIssueType".to_string()),
+            Matcher::PartialJson(json!({
+                "variables": { "issueId": "I_kwDOA7", "issueTypeId": issue_type_id }
+            })),
+        ]))
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"updateIssueIssueType": {"clientMutationId": null}}}"#)
+        .create_async()
+        .await
+}
+
+fn client(server: &mockito::ServerGuard) -> GitHubClient {
+    GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap()
+}
+
+/// The type is resolved by name regardless of case and set on the issue's
+/// node, and `None` clears it
+#[tokio::test]
+async fn test_set_issue_type() {
+    let mut server = issue_type_server().await;
+    let set = mock_update(&mut server, json!("IT_bug")).await;
+    let clear = mock_update(&mut server, Value::Null).await;
+    let client = client(&server);
+    let repository_id = RepositoryId::new("octo-org", "app");
+
+    client
+        .set_issue_type(&repository_id, IssueNumber::new(7), Some("bug"))
+        .await
+        .unwrap();
+    client
+        .set_issue_type(&repository_id, IssueNumber::new(7), None)
+        .await
+        .unwrap();
+
+    set.assert_async().await;
+    clear.assert_async().await;
+}
+
+/// Unknown and disabled types are rejected with the enabled types, without
+/// changing the issue
+#[tokio::test]
+async fn test_set_issue_type_unknown() {
+    let mut server = issue_type_server().await;
+    let update = server
+        .mock("POST", "/graphql")
+        .match_body(Matcher::Regex("updateIssueIssueType".to_string()))
+        .expect(0)
+        .create_async()
+        .await;
+    let client = client(&server);
+
+    for name in ["Epic", "Chore"] {
+        let error = client
+            .set_issue_type(
+                &RepositoryId::new("octo-org", "app"),
+                IssueNumber::new(7),
+                Some(name),
+            )
+            .await
+            .unwrap_err();
+        let message = format!("{:#}", error);
+        assert!(
+            message.contains(&format!(
+                "Issue type '{}' not found in octo-org. Available: Bug, Feature",
+                name
+            )),
+            "{}",
+            message
+        );
+        assert_eq!(ErrorKind::of(&error), ErrorKind::NotFound);
+    }
+
+    update.assert_async().await;
+}
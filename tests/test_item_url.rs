@@ -0,0 +1,217 @@
+use github_edit::tools::GitEditTools;
+use github_edit::tools::item_url::{fill_item_number, required_item_number_argument};
+use github_edit::types::item_url::{ItemUrl, resolve_item_target};
+use github_edit::types::repository::RepositoryId;
+use rmcp::model::{CallToolRequestParam, JsonObject, Tool};
+use serde_json::{Value, json};
+use std::process::Command;
+use std::sync::Arc;
+
+fn pull_request_tool() -> Tool {
+    let schema: JsonObject = serde_json::from_value(json!({
+        "type": "object",
+        "properties": {
+            "repository_url": { "type": "string" },
+            "pr_number": { "type": "integer" }
+        },
+        "required": ["repository_url", "pr_number"]
+    }))
+    .unwrap();
+    Tool::new(
+        "close_pull_request",
+        "Close a pull request",
+        Arc::new(schema),
+    )
+}
+
+fn request(arguments: Value) -> CallToolRequestParam {
+    CallToolRequestParam {
+        name: "close_pull_request".into(),
+        arguments: serde_json::from_value(arguments).unwrap(),
+    }
+}
+
+#[test]
+fn test_parse_item_url() {
+    let item = ItemUrl::parse("https://github.com/octocat/hello/issues/123").unwrap();
+    assert_eq!(item.repository_id, RepositoryId::new("octocat", "hello"));
+    assert_eq!(item.number, 123);
+
+    let item = ItemUrl::parse("https://github.com/octocat/hello/pull/7/files").unwrap();
+    assert_eq!(item.number, 7);
+    let item = ItemUrl::parse("https://github.com/octocat/hello/issues/9#issuecomment-1").unwrap();
+    assert_eq!(item.number, 9);
+
+    assert!(ItemUrl::parse("https://github.com/octocat/hello").is_none());
+    assert!(ItemUrl::parse("https://github.com/octocat/hello/issues").is_none());
+    assert!(ItemUrl::parse("https://github.com/octocat/hello/discussions/3").is_none());
+}
+
+/// The number is taken from an item URL, or given with a repository URL
+#[test]
+fn test_resolve_item_target() {
+    let hello = RepositoryId::new("octocat", "hello");
+    assert_eq!(
+        resolve_item_target("https://github.com/octocat/hello/issues/123", None).unwrap(),
+        (hello.clone(), 123)
+    );
+    assert_eq!(
+        resolve_item_target("https://github.com/octocat/hello/pull/123", Some(123)).unwrap(),
+        (hello.clone(), 123)
+    );
+    assert_eq!(
+        resolve_item_target("https://github.com/octocat/hello", Some(5)).unwrap(),
+        (hello, 5)
+    );
+
+    let error =
+        resolve_item_target("https://github.com/octocat/hello/issues/123", Some(5)).unwrap_err();
+    assert_eq!(
+        error,
+        "Number 5 does not match the URL https://github.com/octocat/hello/issues/123"
+    );
+    let error = resolve_item_target("https://github.com/octocat/hello", None).unwrap_err();
+    assert!(error.starts_with("No issue or pull request number given"));
+}
+
+/// An item URL passed as repository_url fills in the number of the item
+#[test]
+fn test_fill_item_number() {
+    let tool = pull_request_tool();
+
+    let mut from_url = request(json!({
+        "repository_url": "https://github.com/octocat/hello/pull/42"
+    }));
+    fill_item_number(&tool, &mut from_url).unwrap();
+    let arguments = from_url.arguments.unwrap();
+    assert_eq!(
+        arguments["repository_url"],
+        "https://github.com/octocat/hello"
+    );
+    assert_eq!(arguments["pr_number"], 42);
+
+    let mut given = request(json!({
+        "repository_url": "octocat/hello",
+        "pr_number": 3
+    }));
+    fill_item_number(&tool, &mut given).unwrap();
+    assert_eq!(given.arguments.unwrap()["repository_url"], "octocat/hello");
+
+    let mut mismatched = request(json!({
+        "repository_url": "https://github.com/octocat/hello/pull/42",
+        "pr_number": 3
+    }));
+    let error = fill_item_number(&tool, &mut mismatched).unwrap_err();
+    assert!(
+        error.message.contains("does not match"),
+        "{}",
+        error.message
+    );
+
+    let mut missing = request(json!({ "repository_url": "octocat/hello" }));
+    let error = fill_item_number(&tool, &mut missing).unwrap_err();
+    assert!(
+        error.message.contains("requires pr_number"),
+        "{}",
+        error.message
+    );
+}
+
+/// The listed tools acting on one issue or pull request take its URL alone
+#[test]
+fn test_listed_tools_make_item_number_optional() {
+    let tools = GitEditTools::listed_tools();
+    let tool = tools
+        .iter()
+        .find(|tool| tool.name == "add_comment_to_issue")
+        .unwrap();
+    let required = tool.input_schema["required"].as_array().unwrap();
+    assert!(!required.iter().any(|name| name == "issue_number"));
+    assert!(
+        tool.input_schema["properties"]["issue_number"]["description"]
+            .as_str()
+            .unwrap()
+            .contains("Optional when repository_url is the URL of the issue or pull request")
+    );
+}
+
+/// The item number of an MCP tool is found among its required arguments
+#[test]
+fn test_required_item_number_argument() {
+    assert_eq!(
+        required_item_number_argument(&pull_request_tool()),
+        Some("pr_number")
+    );
+}
+
+/// CLI commands take the URL of the issue in place of the repository URL
+/// and the number
+#[tokio::test]
+async fn test_cli_issue_url() {
+    let mut server = mockito::Server::new_async().await;
+    let _repository_mock = server
+        .mock("POST", "/graphql")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"message": "Not Found", "documentation_url": null}"#)
+        .create_async()
+        .await;
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("config.toml"),
+        format!(
+            "default_profile = \"test\"\n\n[profiles.test]\napi_base_url = \"{}\"\ntoken = \"ghp_test\"\n",
+            server.url()
+        ),
+    )
+    .unwrap();
+    let cli = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_github-edit-cli"))
+            .env("GITHUB_EDIT_CONFIG_DIR", dir.path())
+            .env_remove("GITHUB_EDIT_GITHUB_TOKEN")
+            .env_remove("GITHUB_EDIT_PROFILE")
+            .env_remove("RUST_LOG")
+            .args(args)
+            .output()
+            .unwrap()
+    };
+
+    let output = cli(&[
+        "--dry-run",
+        "--output",
+        "json",
+        "issue",
+        "comment",
+        "-r",
+        "https://github.com/octocat/hello/issues/7",
+        "-b",
+        "Looks good",
+    ]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let result: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(
+        result["planned_requests"][0]["url"]
+            .as_str()
+            .unwrap()
+            .ends_with("/repos/octocat/hello/issues/7/comments")
+    );
+
+    let output = cli(&[
+        "issue",
+        "comment",
+        "-r",
+        "https://github.com/octocat/hello",
+        "-b",
+        "Looks good",
+    ]);
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("No issue or pull request number given")
+    );
+}
@@ -0,0 +1,179 @@
+use chrono::{TimeZone, Utc};
+use github_edit::github::GitHubClient;
+use github_edit::types::issue::IssueState;
+use github_edit::types::pagination::Pagination;
+use github_edit::types::repository::{MilestoneNumber, RepositoryId};
+use mockito::Matcher;
+use serde_json::{Value, json};
+
+fn user_json(login: &str) -> Value {
+    let url = format!("https://api.github.com/users/{}", login);
+    json!({
+        "login": login,
+        "id": 1,
+        "node_id": "MDQ6VXNlcjE=",
+        "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+        "gravatar_id": "",
+        "url": url,
+        "html_url": format!("https://github.com/{}", login),
+        "followers_url": format!("{}/followers", url),
+        "following_url": format!("{}/following", url),
+        "gists_url": format!("{}/gists", url),
+        "starred_url": format!("{}/starred", url),
+        "subscriptions_url": format!("{}/subscriptions", url),
+        "organizations_url": format!("{}/orgs", url),
+        "repos_url": format!("{}/repos", url),
+        "events_url": format!("{}/events", url),
+        "received_events_url": format!("{}/received_events", url),
+        "type": "User",
+        "site_admin": false
+    })
+}
+
+fn issue_json(number: u64) -> Value {
+    let url = format!(
+        "https://api.github.com/repos/octocat/hello/issues/{}",
+        number
+    );
+    json!({
+        "id": 1000 + number,
+        "node_id": "I_kwDOA",
+        "url": url,
+        "repository_url": "https://api.github.com/repos/octocat/hello",
+        "labels_url": format!("{}/labels{{/name}}", url),
+        "comments_url": format!("{}/comments", url),
+        "events_url": format!("{}/events", url),
+        "html_url": format!("https://github.com/octocat/hello/issues/{}", number),
+        "number": number,
+        "state": "closed",
+        "title": format!("Issue {}", number),
+        "body": null,
+        "user": user_json("octocat"),
+        "labels": [],
+        "assignees": [user_json("octocat")],
+        "author_association": "OWNER",
+        "locked": false,
+        "comments": 0,
+        "created_at": "2024-01-02T03:04:05Z",
+        "updated_at": "2024-01-03T03:04:05Z",
+        "closed_at": "2024-01-03T03:04:05Z"
+    })
+}
+
+/// Issue `number` that is a pull request, as the issues endpoint returns it
+fn pull_request_json(number: u64) -> Value {
+    let mut issue = issue_json(number);
+    let url = format!(
+        "https://api.github.com/repos/octocat/hello/pulls/{}",
+        number
+    );
+    issue["pull_request"] = json!({
+        "url": url,
+        "html_url": format!("https://github.com/octocat/hello/pull/{}", number),
+        "diff_url": format!("https://github.com/octocat/hello/pull/{}.diff", number),
+        "patch_url": format!("https://github.com/octocat/hello/pull/{}.patch", number)
+    });
+    issue
+}
+
+fn client(server: &mockito::ServerGuard) -> GitHubClient {
+    GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap()
+}
+
+fn query(params: &[(&str, &str)]) -> Matcher {
+    Matcher::AllOf(
+        params
+            .iter()
+            .map(|(name, value)| Matcher::UrlEncoded(name.to_string(), value.to_string()))
+            .collect(),
+    )
+}
+
+/// Every filter is sent as its query parameter, and pull requests are left
+/// out of the page
+#[tokio::test]
+async fn test_list_issues_filters_and_excludes_pull_requests() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/repos/octocat/hello/issues")
+        .match_query(query(&[
+            ("state", "closed"),
+            ("labels", "bug,docs"),
+            ("assignee", "octocat"),
+            ("milestone", "3"),
+            ("since", "2024-01-02T00:00:00Z"),
+            ("per_page", "50"),
+            ("page", "2"),
+        ]))
+        .with_header("content-type", "application/json")
+        .with_body(json!([issue_json(7), pull_request_json(8), issue_json(9)]).to_string())
+        .create_async()
+        .await;
+
+    let page = client(&server)
+        .list_issues(
+            &RepositoryId::new("octocat", "hello"),
+            Some(IssueState::Closed),
+            &["bug".to_string(), "docs".to_string()],
+            Some("octocat"),
+            Some(MilestoneNumber::new(3)),
+            Some(Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap()),
+            Pagination::new(Some(2), Some(50)),
+        )
+        .await
+        .unwrap();
+
+    let numbers: Vec<u32> = page
+        .items
+        .iter()
+        .map(|issue| issue.issue_id.number)
+        .collect();
+    assert_eq!(numbers, vec![7, 9]);
+    assert!(
+        page.items
+            .iter()
+            .all(|issue| issue.state == IssueState::Closed)
+    );
+    assert!(!page.has_next_page);
+    mock.assert_async().await;
+}
+
+/// Without a state both open and closed issues are listed, and no other
+/// filter is sent
+#[tokio::test]
+async fn test_list_issues_without_filters() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/repos/octocat/hello/issues")
+        .match_query(Matcher::Regex(
+            "^(state=all|per_page=30|page=1)(&(state=all|per_page=30|page=1)){2}$".to_string(),
+        ))
+        .with_header("content-type", "application/json")
+        .with_header(
+            "link",
+            &format!(
+                "<{}/repos/octocat/hello/issues?state=all&per_page=30&page=2>; rel=\"next\"",
+                server.url()
+            ),
+        )
+        .with_body(json!([issue_json(7)]).to_string())
+        .create_async()
+        .await;
+
+    let page = client(&server)
+        .list_issues(
+            &RepositoryId::new("octocat", "hello"),
+            None,
+            &[],
+            None,
+            None,
+            None,
+            Pagination::new(None, Some(30)),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(page.items.len(), 1);
+    assert!(page.has_next_page);
+    mock.assert_async().await;
+}
@@ -0,0 +1,82 @@
+use github_edit::github::GitHubClient;
+use github_edit::types::pagination::Pagination;
+use github_edit::types::repository::RepositoryId;
+use mockito::Matcher;
+use serde_json::{Value, json};
+
+fn labels_json(names: std::ops::Range<u32>) -> String {
+    let labels: Vec<Value> = names
+        .map(|n| {
+            json!({
+                "id": n,
+                "name": format!("label-{}", n),
+                "color": "ededed",
+                "description": null,
+                "default": false
+            })
+        })
+        .collect();
+    Value::Array(labels).to_string()
+}
+
+/// Mock page `page` of the labels of octocat/hello, linking to the next page
+/// unless it is the last one
+async fn mock_label_page(
+    server: &mut mockito::ServerGuard,
+    page: u32,
+    names: std::ops::Range<u32>,
+    last: bool,
+) -> mockito::Mock {
+    let mut mock = server
+        .mock("GET", "/repos/octocat/hello/labels")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("per_page".to_string(), "2".to_string()),
+            Matcher::UrlEncoded("page".to_string(), page.to_string()),
+        ]))
+        .with_header("content-type", "application/json")
+        .with_body(labels_json(names));
+    if !last {
+        mock = mock.with_header(
+            "link",
+            &format!(
+                "<{}/repos/octocat/hello/labels?per_page=2&page={}>; rel=\"next\"",
+                server.url(),
+                page + 1
+            ),
+        );
+    }
+    mock.create_async().await
+}
+
+/// Each page is requested on its own, and only pages with a next link have
+/// a next page
+#[tokio::test]
+async fn test_list_labels_pages() {
+    let mut server = mockito::Server::new_async().await;
+    let first = mock_label_page(&mut server, 1, 0..2, false).await;
+    let second = mock_label_page(&mut server, 2, 2..3, true).await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+    let repository_id = RepositoryId::new("octocat", "hello");
+
+    let page = client
+        .list_labels(&repository_id, Pagination::new(None, Some(2)))
+        .await
+        .unwrap();
+    let names: Vec<&str> = page.items.iter().map(|label| label.name()).collect();
+    assert_eq!(names, vec!["label-0", "label-1"]);
+    assert_eq!(page.page, 1);
+    assert!(page.has_next_page);
+
+    let page = client
+        .list_labels(&repository_id, Pagination::new(Some(2), Some(2)))
+        .await
+        .unwrap();
+    let names: Vec<&str> = page.items.iter().map(|label| label.name()).collect();
+    assert_eq!(names, vec!["label-2"]);
+    assert_eq!(page.page, 2);
+    assert!(!page.has_next_page);
+
+    first.assert_async().await;
+    second.assert_async().await;
+}
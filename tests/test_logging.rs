@@ -0,0 +1,217 @@
+use github_edit::github::GitHubClient;
+use github_edit::tools::logging::{LOGGER_NAME, ToolCallLogger, name_trace_id};
+use github_edit::tools::tool_definition::IssueTools;
+use github_edit::types::issue::IssueNumber;
+use rmcp::Error as McpError;
+use rmcp::model::{CallToolResult, Content, LoggingLevel, LoggingMessageNotificationParam};
+use std::sync::{Arc, Mutex};
+
+type Messages = Arc<Mutex<Vec<LoggingMessageNotificationParam>>>;
+
+async fn traced_get_issue(
+    logger: &ToolCallLogger,
+    server_url: &str,
+) -> (
+    Result<CallToolResult, McpError>,
+    Vec<LoggingMessageNotificationParam>,
+) {
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, server_url).unwrap();
+    let messages = Messages::default();
+    let sent = messages.clone();
+    let result = logger
+        .trace_call(
+            "get_issue",
+            None,
+            move |message| {
+                sent.lock().unwrap().push(message);
+                async {}
+            },
+            IssueTools::get_issue(
+                &client,
+                "octocat/hello".to_string(),
+                IssueNumber::new(404),
+                None,
+            ),
+        )
+        .await;
+    let messages = messages.lock().unwrap().clone();
+    (result, messages)
+}
+
+async fn not_found_server() -> mockito::ServerGuard {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("GET", "/repos/octocat/hello/issues/404")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"message": "Not Found", "documentation_url": null}"#)
+        .create_async()
+        .await;
+    server
+}
+
+/// The call, its GitHub requests and its result are logged with one trace
+/// ID, which the error message names
+#[tokio::test]
+async fn test_tool_call_is_traced() {
+    let server = not_found_server().await;
+    let logger = ToolCallLogger::default();
+    logger.set_level(LoggingLevel::Debug);
+
+    let (result, messages) = traced_get_issue(&logger, &server.url()).await;
+
+    let trace_id = messages[0].data["trace_id"].as_str().unwrap().to_string();
+    assert!(
+        messages
+            .iter()
+            .all(|message| message.data["trace_id"] == trace_id.as_str()
+                && message.logger.as_deref() == Some(LOGGER_NAME))
+    );
+    assert_eq!(messages[0].data["message"], "Received get_issue call");
+    assert!(
+        messages
+            .iter()
+            .any(|message| message.data["details"]["status"] == 404
+                && message.data["details"]["method"] == "GET"),
+        "{:?}",
+        messages
+    );
+    let last = messages.last().unwrap();
+    assert_eq!(last.level, LoggingLevel::Error);
+    assert!(
+        last.data["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("get_issue failed:")
+    );
+
+    let error = match result {
+        Ok(result) => {
+            assert_eq!(result.is_error, Some(true));
+            result.content[0].as_text().unwrap().text.clone()
+        }
+        Err(e) => e.message.to_string(),
+    };
+    assert!(
+        error.ends_with(&format!("(trace ID: {})", trace_id)),
+        "{}",
+        error
+    );
+}
+
+/// Messages below the level are not sent; the default level leaves out the
+/// GitHub requests
+#[tokio::test]
+async fn test_log_level_filters_messages() {
+    let server = not_found_server().await;
+    let logger = ToolCallLogger::default();
+    assert_eq!(logger.level(), LoggingLevel::Info);
+
+    let (_, messages) = traced_get_issue(&logger, &server.url()).await;
+    assert!(!messages.is_empty());
+    assert!(
+        messages
+            .iter()
+            .all(|message| message.level != LoggingLevel::Debug)
+    );
+
+    logger.set_level(LoggingLevel::Error);
+    let (_, messages) = traced_get_issue(&logger, &server.url()).await;
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].level, LoggingLevel::Error);
+}
+
+/// Successful results are unchanged, errors name the trace ID
+#[test]
+fn test_name_trace_id() {
+    let result = CallToolResult::success(vec![Content::text("Issue #1")]);
+    assert_eq!(name_trace_id("abc", Ok(result.clone())).unwrap(), result);
+
+    let error = name_trace_id("abc", Err(McpError::invalid_request("Bad input", None)));
+    assert_eq!(error.unwrap_err().message, "Bad input (trace ID: abc)");
+}
+
+/// Run the CLI against `server`, deleting the label `bug`
+fn cli_delete_label(server: &mockito::Server, log_args: &[&str]) -> std::process::Output {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("config.toml"),
+        format!(
+            "default_profile = \"test\"\n\n[profiles.test]\napi_base_url = \"{}\"\ntoken = \"ghp_test\"\n\n[audit]\nenabled = false\n",
+            server.url()
+        ),
+    )
+    .unwrap();
+    std::process::Command::new(env!("CARGO_BIN_EXE_github-edit-cli"))
+        .env("GITHUB_EDIT_CONFIG_DIR", dir.path())
+        .env_remove("GITHUB_EDIT_GITHUB_TOKEN")
+        .env_remove("GITHUB_EDIT_PROFILE")
+        .env("RUST_LOG", "debug")
+        .args(log_args)
+        .args([
+            "repository",
+            "label",
+            "delete",
+            "-r",
+            "https://github.com/octocat/hello",
+            "-n",
+            "bug",
+        ])
+        .output()
+        .unwrap()
+}
+
+/// `-vv` logs every GitHub API call with its duration and the rate limit of
+/// the response, as JSON lines with `--log-format json`
+#[tokio::test]
+async fn test_cli_verbose_json_logs_api_calls() {
+    let mut server = mockito::Server::new_async().await;
+    let _delete_mock = server
+        .mock("DELETE", "/repos/octocat/hello/labels/bug")
+        .with_status(204)
+        .with_header("x-ratelimit-limit", "5000")
+        .with_header("x-ratelimit-remaining", "4999")
+        .with_header("x-ratelimit-reset", "1700000000")
+        .create_async()
+        .await;
+
+    let output = cli_delete_label(&server, &["-vv", "--log-format", "json"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let call = stderr
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .find(|event| event["message"] == "GitHub API call")
+        .unwrap_or_else(|| panic!("no API call logged: {}", stderr));
+    assert_eq!(call["level"], "DEBUG");
+    assert_eq!(call["method"], "DELETE");
+    assert_eq!(call["status"], 204);
+    assert!(call["duration_ms"].is_u64());
+    assert_eq!(call["rate_limit_limit"], 5000);
+    assert_eq!(call["rate_limit_remaining"], 4999);
+    assert_eq!(call["rate_limit_reset"], 1700000000u64);
+}
+
+/// `--quiet` logs nothing, even with `RUST_LOG` set
+#[tokio::test]
+async fn test_cli_quiet_logs_nothing() {
+    let mut server = mockito::Server::new_async().await;
+    let _delete_mock = server
+        .mock("DELETE", "/repos/octocat/hello/labels/bug")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let output = cli_delete_label(&server, &["--quiet"]);
+    assert!(output.status.success());
+    assert!(
+        output.stderr.is_empty(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,191 @@
+use github_edit::github::GitHubClient;
+use github_edit::tools::functions::issue::mark_issue_duplicate;
+use github_edit::tools::tool_definition::IssueTools;
+use github_edit::types::issue::IssueNumber;
+use github_edit::types::repository::RepositoryId;
+use mockito::Matcher;
+use rmcp::model::RawContent;
+use serde_json::{Value, json};
+
+fn user_json(login: &str) -> Value {
+    let url = format!("https://api.github.com/users/{}", login);
+    json!({
+        "login": login,
+        "id": 1,
+        "node_id": "MDQ6VXNlcjE=",
+        "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+        "gravatar_id": "",
+        "url": url,
+        "html_url": format!("https://github.com/{}", login),
+        "followers_url": format!("{}/followers", url),
+        "following_url": format!("{}/following", url),
+        "gists_url": format!("{}/gists", url),
+        "starred_url": format!("{}/starred", url),
+        "subscriptions_url": format!("{}/subscriptions", url),
+        "organizations_url": format!("{}/orgs", url),
+        "repos_url": format!("{}/repos", url),
+        "events_url": format!("{}/events", url),
+        "received_events_url": format!("{}/received_events", url),
+        "type": "User",
+        "site_admin": false
+    })
+}
+
+/// Issue 7 of octocat/hello, without labels
+fn issue_json(state: &str, state_reason: Option<&str>) -> Value {
+    let url = "https://api.github.com/repos/octocat/hello/issues/7";
+    json!({
+        "id": 1007,
+        "node_id": "I_kwDOA",
+        "url": url,
+        "repository_url": "https://api.github.com/repos/octocat/hello",
+        "labels_url": format!("{}/labels{{/name}}", url),
+        "comments_url": format!("{}/comments", url),
+        "events_url": format!("{}/events", url),
+        "html_url": "https://github.com/octocat/hello/issues/7",
+        "number": 7,
+        "state": state,
+        "state_reason": state_reason,
+        "title": "Crash when saving drafts",
+        "body": "Steps to reproduce",
+        "user": user_json("octocat"),
+        "labels": [],
+        "assignees": [],
+        "author_association": "OWNER",
+        "locked": false,
+        "comments": 1,
+        "created_at": "2024-01-02T03:04:05Z",
+        "updated_at": "2024-01-03T03:04:05Z"
+    })
+}
+
+/// Server where commenting and closing issue 7 succeed, but labeling it is
+/// rejected
+async fn label_failure_server() -> (mockito::ServerGuard, Vec<mockito::Mock>) {
+    let mut server = mockito::Server::new_async().await;
+    let mocks = vec![
+        server
+            .mock("GET", "/repos/octocat/hello/issues/7")
+            .with_header("content-type", "application/json")
+            .with_body(issue_json("open", None).to_string())
+            .create_async()
+            .await,
+        server
+            .mock("GET", "/repos/octocat/hello/issues/7/comments")
+            .match_query(Matcher::Any)
+            .with_header("content-type", "application/json")
+            .with_body("[]")
+            .create_async()
+            .await,
+        server
+            .mock("POST", "/repos/octocat/hello/issues/7/comments")
+            .match_body(Matcher::Json(json!({ "body": "Duplicate of #3" })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "id": 11,
+                    "node_id": "IC_kwDOA",
+                    "url": "https://api.github.com/repos/octocat/hello/issues/comments/11",
+                    "html_url": "https://github.com/octocat/hello/issues/7#issuecomment-11",
+                    "body": "Duplicate of #3",
+                    "author_association": "OWNER",
+                    "user": user_json("octocat"),
+                    "created_at": "2024-01-03T03:04:05Z",
+                    "updated_at": "2024-01-03T03:04:05Z"
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await,
+        server
+            .mock("POST", "/repos/octocat/hello/issues/7/labels")
+            .with_status(422)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"message": "Validation Failed", "documentation_url": null}"#)
+            .expect(1)
+            .create_async()
+            .await,
+        server
+            .mock("PATCH", "/repos/octocat/hello/issues/7")
+            .match_body(Matcher::PartialJson(
+                json!({ "state": "closed", "state_reason": "not_planned" }),
+            ))
+            .with_header("content-type", "application/json")
+            .with_body(issue_json("closed", Some("not_planned")).to_string())
+            .expect(1)
+            .create_async()
+            .await,
+    ];
+    (server, mocks)
+}
+
+/// A failing label step is reported while the comment and close steps
+/// still run
+#[tokio::test]
+async fn test_mark_issue_duplicate_reports_partial_failure() {
+    let (server, mocks) = label_failure_server().await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+
+    let result = mark_issue_duplicate(
+        &client,
+        &RepositoryId::new("octocat", "hello"),
+        IssueNumber::new(7),
+        IssueNumber::new(3),
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.comment_number.map(|number| number.value()), Some(11));
+    assert_eq!(result.applied_label, None);
+    assert!(result.closed);
+    assert_eq!(result.failures.len(), 1);
+    assert!(
+        result.failures[0].starts_with("Failed to apply label 'duplicate'"),
+        "{:?}",
+        result.failures
+    );
+    assert!(!result.is_complete());
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+}
+
+/// The tool reports a partial failure as an error, listing the steps that
+/// succeeded
+#[tokio::test]
+async fn test_mark_issue_duplicate_tool_is_error_on_partial_failure() {
+    let (server, _mocks) = label_failure_server().await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+
+    let result = IssueTools::mark_issue_duplicate(
+        &client,
+        "octocat/hello".to_string(),
+        IssueNumber::new(7),
+        IssueNumber::new(3),
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.is_error, Some(true));
+    let RawContent::Text(text) = &result.content[0].raw else {
+        panic!("unexpected content: {:?}", result.content);
+    };
+    let lines: Vec<&str> = text.text.lines().collect();
+    assert_eq!(
+        lines[0],
+        "Issue #7 was only partially marked as duplicate of #3"
+    );
+    assert!(lines.contains(&"Added comment #11: Duplicate of #3"));
+    assert!(lines.contains(&"Closed issue as not planned"));
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.starts_with("Failed to apply label 'duplicate'"))
+    );
+}
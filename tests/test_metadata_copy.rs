@@ -0,0 +1,60 @@
+use github_edit::types::metadata_copy::{
+    MetadataConflictStrategy, MetadataCopyAction, MetadataCopyEntry, MetadataCopyResult,
+};
+
+/// Missing items are always created; existing items follow the strategy
+#[test]
+fn test_conflict_strategy_action() {
+    for strategy in [
+        MetadataConflictStrategy::Skip,
+        MetadataConflictStrategy::Overwrite,
+        MetadataConflictStrategy::Fail,
+    ] {
+        assert_eq!(strategy.action_for(false), MetadataCopyAction::Created);
+    }
+
+    assert_eq!(
+        MetadataConflictStrategy::Skip.action_for(true),
+        MetadataCopyAction::Skipped
+    );
+    assert_eq!(
+        MetadataConflictStrategy::Overwrite.action_for(true),
+        MetadataCopyAction::Updated
+    );
+    assert_eq!(
+        MetadataConflictStrategy::Fail.action_for(true),
+        MetadataCopyAction::Failed
+    );
+
+    assert_eq!(
+        "overwrite".parse::<MetadataConflictStrategy>().unwrap(),
+        MetadataConflictStrategy::Overwrite
+    );
+    assert!("replace".parse::<MetadataConflictStrategy>().is_err());
+}
+
+/// Failures in labels and milestones are both counted
+#[test]
+fn test_metadata_copy_result_failures() {
+    let mut result = MetadataCopyResult::default();
+    result.labels.push(MetadataCopyEntry::new(
+        "bug".to_string(),
+        MetadataCopyAction::Created,
+    ));
+    result.milestones.push(MetadataCopyEntry::new(
+        "v1.0.0".to_string(),
+        MetadataCopyAction::Skipped,
+    ));
+    assert!(result.is_success());
+
+    result.labels.push(MetadataCopyEntry::failed(
+        "enhancement".to_string(),
+        "Validation failed".to_string(),
+    ));
+    result.milestones.push(MetadataCopyEntry::failed(
+        "v2.0.0".to_string(),
+        "Milestone already exists".to_string(),
+    ));
+    assert!(!result.is_success());
+    assert_eq!(result.failure_count(), 2);
+}
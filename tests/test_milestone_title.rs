@@ -0,0 +1,44 @@
+use chrono::Utc;
+use github_edit::types::milestone::{Milestone, MilestoneState, find_milestone_by_title};
+use github_edit::types::repository::MilestoneNumber;
+
+fn milestone(number: u64, title: &str) -> Milestone {
+    Milestone::new(
+        MilestoneNumber::new(number),
+        title.to_string(),
+        None,
+        MilestoneState::Open,
+        0,
+        0,
+        None,
+        Utc::now(),
+        Utc::now(),
+        None,
+    )
+}
+
+/// An exact title wins over case-insensitive matches, which must be unique
+#[test]
+fn test_find_milestone_by_title() {
+    let milestones = vec![
+        milestone(1, "v1.0.0"),
+        milestone(2, "Sprint 1"),
+        milestone(3, "sprint 1"),
+        milestone(4, "Backlog"),
+    ];
+
+    assert_eq!(
+        find_milestone_by_title(&milestones, "v1.0.0").map(|m| m.id),
+        Some(MilestoneNumber::new(1))
+    );
+    assert_eq!(
+        find_milestone_by_title(&milestones, "sprint 1").map(|m| m.id),
+        Some(MilestoneNumber::new(3))
+    );
+    assert_eq!(
+        find_milestone_by_title(&milestones, "BACKLOG").map(|m| m.id),
+        Some(MilestoneNumber::new(4))
+    );
+    assert!(find_milestone_by_title(&milestones, "SPRINT 1").is_none());
+    assert!(find_milestone_by_title(&milestones, "v2.0.0").is_none());
+}
@@ -0,0 +1,147 @@
+use chrono::{TimeZone, Utc};
+use github_edit::types::notification::{
+    IssueSubscription, Notification, NotificationFilter, SubscriptionState, ThreadSubscription,
+};
+use github_edit::types::pagination::Pagination;
+use serde_json::json;
+
+fn notification_value(subject_type: &str, url: Option<&str>) -> serde_json::Value {
+    json!({
+        "id": "1234567",
+        "repository": {
+            "id": 1296269,
+            "full_name": "octocat/Hello-World",
+            "html_url": "https://github.com/octocat/Hello-World"
+        },
+        "subject": {
+            "title": "Greetings",
+            "url": url,
+            "latest_comment_url": null,
+            "type": subject_type
+        },
+        "reason": "mention",
+        "unread": true,
+        "updated_at": "2024-03-01T12:00:00Z",
+        "last_read_at": null,
+        "url": "https://api.github.com/notifications/threads/1234567"
+    })
+}
+
+/// Notifications deserialize with their string thread ID
+#[test]
+fn test_notification_deserialize() {
+    let notification: Notification = serde_json::from_value(notification_value(
+        "Issue",
+        Some("https://api.github.com/repos/octocat/Hello-World/issues/123"),
+    ))
+    .unwrap();
+
+    assert_eq!(notification.id.value(), "1234567");
+    assert_eq!(notification.reason, "mention");
+    assert!(notification.unread);
+    assert_eq!(notification.last_read_at, None);
+}
+
+/// Subject API URLs are converted to web URLs
+#[test]
+fn test_notification_html_url() {
+    let html_url = |subject_type: &str, url: Option<&str>| {
+        serde_json::from_value::<Notification>(notification_value(subject_type, url))
+            .unwrap()
+            .html_url()
+    };
+
+    assert_eq!(
+        html_url(
+            "Issue",
+            Some("https://api.github.com/repos/octocat/Hello-World/issues/123")
+        ),
+        "https://github.com/octocat/Hello-World/issues/123"
+    );
+    assert_eq!(
+        html_url(
+            "PullRequest",
+            Some("https://api.github.com/repos/octocat/Hello-World/pulls/7")
+        ),
+        "https://github.com/octocat/Hello-World/pull/7"
+    );
+    assert_eq!(
+        html_url(
+            "Release",
+            Some("https://api.github.com/repos/octocat/Hello-World/releases/1")
+        ),
+        "https://github.com/octocat/Hello-World"
+    );
+    assert_eq!(
+        html_url("Discussion", None),
+        "https://github.com/octocat/Hello-World"
+    );
+}
+
+/// Filters only add the query parameters that are set
+#[test]
+fn test_notification_filter_query() {
+    let pagination = Pagination::new(Some(2), Some(50));
+    assert_eq!(
+        NotificationFilter::default().to_query(pagination),
+        "per_page=50&page=2"
+    );
+
+    let filter = NotificationFilter {
+        all: true,
+        participating: true,
+        since: Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+        before: None,
+    };
+    assert_eq!(
+        filter.to_query(pagination),
+        "all=true&participating=true&since=2024-01-01T00%3A00%3A00Z&per_page=50&page=2"
+    );
+}
+
+/// Thread subscriptions deserialize from the subscription endpoint
+#[test]
+fn test_thread_subscription_deserialize() {
+    let subscription: ThreadSubscription = serde_json::from_value(json!({
+        "subscribed": true,
+        "ignored": false,
+        "reason": null,
+        "created_at": "2012-10-06T21:34:12Z",
+        "url": "https://api.github.com/notifications/threads/1/subscription",
+        "thread_url": "https://api.github.com/notifications/threads/1"
+    }))
+    .unwrap();
+
+    assert!(subscription.subscribed);
+    assert!(!subscription.ignored);
+}
+
+/// Issue subscriptions parse the GraphQL subscription state
+#[test]
+fn test_issue_subscription_from_graphql() {
+    let subscription = IssueSubscription::from_graphql_value(&json!({
+        "id": "I_kwDOA123",
+        "viewerSubscription": "UNSUBSCRIBED",
+        "viewerCanSubscribe": true
+    }));
+    assert_eq!(subscription.state, Some(SubscriptionState::Unsubscribed));
+    assert!(subscription.can_subscribe);
+
+    let unknown = IssueSubscription::from_graphql_value(&json!({
+        "viewerSubscription": null,
+        "viewerCanSubscribe": false
+    }));
+    assert_eq!(unknown.state, None);
+    assert!(!unknown.can_subscribe);
+
+    for state in [
+        SubscriptionState::Subscribed,
+        SubscriptionState::Unsubscribed,
+        SubscriptionState::Ignored,
+    ] {
+        assert_eq!(
+            SubscriptionState::from_graphql(state.graphql_value()),
+            Some(state)
+        );
+    }
+}
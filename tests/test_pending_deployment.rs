@@ -0,0 +1,66 @@
+use github_edit::types::actions::{Deployment, DeploymentReviewState, PendingDeployment};
+use serde_json::json;
+
+/// Pending deployments deserialize with their environment and reviewers
+#[test]
+fn test_pending_deployment_deserialize() {
+    let deployments: Vec<PendingDeployment> = serde_json::from_value(json!([
+        {
+            "environment": {
+                "id": 161088068,
+                "node_id": "MDExOkVudmlyb25tZW50MTYxMDg4MDY4",
+                "name": "staging",
+                "url": "https://api.github.com/repos/github/hello-world/environments/staging",
+                "html_url": "https://github.com/github/hello-world/deployments/activity_log?environments_filter=staging"
+            },
+            "wait_timer": 30,
+            "wait_timer_started_at": "2020-11-23T22:00:40Z",
+            "current_user_can_approve": true,
+            "reviewers": [
+                {"type": "User", "reviewer": {"login": "octocat", "id": 1}},
+                {"type": "Team", "reviewer": {"id": 1, "name": "Justice League", "slug": "justice-league"}}
+            ]
+        }
+    ]))
+    .unwrap();
+
+    let deployment = &deployments[0];
+    assert_eq!(deployment.environment.id, 161088068);
+    assert_eq!(deployment.environment.name, "staging");
+    assert!(deployment.current_user_can_approve);
+
+    let reviewers: Vec<&str> = deployment
+        .reviewers
+        .iter()
+        .filter_map(|reviewer| reviewer.name())
+        .collect();
+    assert_eq!(reviewers, vec!["octocat", "justice-league"]);
+}
+
+/// Deployments created by a review keep the ref under a Rust-friendly name
+#[test]
+fn test_deployment_deserialize() {
+    let deployment: Deployment = serde_json::from_value(json!({
+        "id": 1,
+        "sha": "a84d88e7554fc1fa21bcbc4efae3c782a70d2b9d",
+        "ref": "topic-branch",
+        "task": "deploy",
+        "environment": "production",
+        "created_at": "2012-07-20T01:19:13Z"
+    }))
+    .unwrap();
+
+    assert_eq!(deployment.git_ref, "topic-branch");
+    assert_eq!(deployment.environment, "production");
+}
+
+/// Review states parse case-insensitively and display in lowercase
+#[test]
+fn test_deployment_review_state_parse() {
+    assert_eq!(
+        "Approved".parse::<DeploymentReviewState>().unwrap(),
+        DeploymentReviewState::Approved
+    );
+    assert_eq!(DeploymentReviewState::Rejected.to_string(), "rejected");
+    assert!("approve".parse::<DeploymentReviewState>().is_err());
+}
@@ -0,0 +1,146 @@
+use github_edit::github::GitHubClient;
+use github_edit::tools::functions::plan::{apply_plan, parse_plan};
+use github_edit::types::plan::{PlanFormat, PlanOperation, PlanOperationStatus};
+
+/// JSON plans name the kind of each operation in `operation`
+#[test]
+fn test_parse_json_plan() {
+    let operations = parse_plan(
+        r#"[
+            {"operation": "create_issue", "repository_url": "https://github.com/owner/repo",
+             "title": "Migrated", "labels": ["bug"], "milestone": 2},
+            {"operation": "add_labels", "issue_url": "https://github.com/owner/repo/issues/1",
+             "labels": ["triage", "p1"]},
+            {"operation": "set_milestone", "issue_url": "https://github.com/owner/repo/issues/1",
+             "milestone": 3}
+        ]"#,
+        PlanFormat::Json,
+    )
+    .unwrap();
+
+    assert_eq!(
+        operations,
+        vec![
+            PlanOperation::CreateIssue {
+                repository_url: "https://github.com/owner/repo".to_string(),
+                title: "Migrated".to_string(),
+                body: None,
+                labels: vec!["bug".to_string()],
+                assignees: Vec::new(),
+                milestone: Some(2),
+            },
+            PlanOperation::AddLabels {
+                issue_url: "https://github.com/owner/repo/issues/1".to_string(),
+                labels: vec!["triage".to_string(), "p1".to_string()],
+            },
+            PlanOperation::SetMilestone {
+                issue_url: "https://github.com/owner/repo/issues/1".to_string(),
+                milestone: 3,
+            },
+        ]
+    );
+}
+
+/// CSV rows leave out their empty cells and split the list cells on commas
+#[test]
+fn test_parse_csv_plan() {
+    let operations = parse_plan(
+        "operation,repository_url,issue_url,title,body,labels,milestone\n\
+         create_issue,https://github.com/owner/repo,,Migrated,\"Line 1\nLine 2\",\"bug, p1\",\n\
+         set_milestone,,https://github.com/owner/repo/issues/1,,,,3\n",
+        PlanFormat::Csv,
+    )
+    .unwrap();
+
+    assert_eq!(
+        operations,
+        vec![
+            PlanOperation::CreateIssue {
+                repository_url: "https://github.com/owner/repo".to_string(),
+                title: "Migrated".to_string(),
+                body: Some("Line 1\nLine 2".to_string()),
+                labels: vec!["bug".to_string(), "p1".to_string()],
+                assignees: Vec::new(),
+                milestone: None,
+            },
+            PlanOperation::SetMilestone {
+                issue_url: "https://github.com/owner/repo/issues/1".to_string(),
+                milestone: 3,
+            },
+        ]
+    );
+}
+
+/// Invalid operations are reported with their row
+#[test]
+fn test_parse_invalid_plan() {
+    let error = parse_plan(
+        r#"[
+            {"operation": "set_milestone", "issue_url": "https://github.com/owner/repo/issues/1", "milestone": 3},
+            {"operation": "close_issue", "issue_url": "https://github.com/owner/repo/issues/1"}
+        ]"#,
+        PlanFormat::Json,
+    )
+    .unwrap_err();
+    assert!(
+        error.to_string().starts_with("Invalid plan row 2"),
+        "{}",
+        error
+    );
+
+    let error = parse_plan(
+        r#"[{"operation": "add_labels", "issue_url": "https://github.com/owner/repo/issues/1", "labels": [], "title": "x"}]"#,
+        PlanFormat::Json,
+    )
+    .unwrap_err();
+    assert!(error.to_string().contains("unknown field"), "{}", error);
+
+    let error = parse_plan(
+        "operation,issue_url,milestone\nset_milestone,https://github.com/owner/repo/issues/1,v1\n",
+        PlanFormat::Csv,
+    )
+    .unwrap_err();
+    assert!(
+        error.to_string().contains("'v1' is not a valid milestone"),
+        "{}",
+        error
+    );
+}
+
+/// Failed operations are reported per row; with `stop_on_error` the
+/// operations not started yet are skipped
+#[tokio::test]
+async fn test_apply_plan_failures() {
+    let client = GitHubClient::new(Some("ghp_test".to_string()), None).unwrap();
+    let operations = vec![
+        PlanOperation::SetMilestone {
+            issue_url: "https://example.com/not-an-issue".to_string(),
+            milestone: 1,
+        },
+        PlanOperation::AddLabels {
+            issue_url: "https://example.com/not-an-issue-either".to_string(),
+            labels: vec!["bug".to_string()],
+        },
+    ];
+
+    let mut progress = Vec::new();
+    let report = apply_plan(&client, operations.clone(), 1, false, |result| {
+        progress.push(result.row)
+    })
+    .await;
+    assert_eq!(progress, vec![1, 2]);
+    assert_eq!((report.succeeded, report.failed, report.skipped), (0, 2, 0));
+    assert_eq!(report.results[0].operation, "set_milestone");
+    assert!(
+        report.results[0]
+            .message
+            .as_deref()
+            .unwrap()
+            .starts_with("Invalid issue URL")
+    );
+
+    let report = apply_plan(&client, operations, 1, true, |_| {}).await;
+    assert_eq!((report.succeeded, report.failed, report.skipped), (0, 1, 1));
+    assert_eq!(report.results[1].row, 2);
+    assert_eq!(report.results[1].status, PlanOperationStatus::Skipped);
+}
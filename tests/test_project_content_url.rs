@@ -0,0 +1,33 @@
+use github_edit::types::project::ProjectOriginalResource;
+use github_edit::types::repository::RepositoryId;
+
+/// Issue and pull request URLs are mapped to their repository and number
+#[test]
+fn test_project_content_parse_url() {
+    let issue =
+        ProjectOriginalResource::parse_url("https://github.com/octocat/Hello-World/issues/123/")
+            .unwrap();
+    assert!(matches!(issue, ProjectOriginalResource::Issue(_)));
+    let (repository_id, number) = issue.repository_and_number().unwrap();
+    assert_eq!(repository_id, &RepositoryId::new("octocat", "Hello-World"));
+    assert_eq!(number, 123);
+
+    let pull_request =
+        ProjectOriginalResource::parse_url("github.com/octocat/Hello-World/pull/456").unwrap();
+    assert!(matches!(
+        pull_request,
+        ProjectOriginalResource::PullRequest(_)
+    ));
+    assert_eq!(pull_request.repository_and_number().unwrap().1, 456);
+}
+
+/// Other URLs are rejected and draft issues have no repository
+#[test]
+fn test_project_content_parse_url_invalid() {
+    assert!(ProjectOriginalResource::parse_url("https://github.com/octocat/Hello-World").is_err());
+    assert!(
+        ProjectOriginalResource::DraftIssue
+            .repository_and_number()
+            .is_none()
+    );
+}
@@ -0,0 +1,142 @@
+use github_edit::types::project::{
+    ProjectCustomFieldType, ProjectField, ProjectFieldDataType, find_project_field,
+};
+use serde_json::json;
+
+/// Single select fields carry their options with IDs
+#[test]
+fn test_project_field_single_select_options() {
+    let field = ProjectField::from_graphql_value(&json!({
+        "id": "PVTSSF_status",
+        "name": "Status",
+        "dataType": "SINGLE_SELECT",
+        "options": [
+            {"id": "f75ad846", "name": "Todo", "color": "GRAY", "description": ""},
+            {"id": "47fc9ee4", "name": "In Progress", "color": "YELLOW", "description": "Being worked on"}
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(field.field_id.value(), "PVTSSF_status");
+    assert_eq!(field.data_type, ProjectFieldDataType::SingleSelect);
+    assert_eq!(field.options.len(), 2);
+    assert_eq!(field.options[1].id, "47fc9ee4");
+    assert_eq!(field.options[0].description, None);
+    assert!(field.iteration_configuration.is_none());
+}
+
+/// Iteration fields carry their configuration with active and completed iterations
+#[test]
+fn test_project_field_iteration_configuration() {
+    let field = ProjectField::from_graphql_value(&json!({
+        "id": "PVTIF_sprint",
+        "name": "Sprint",
+        "dataType": "ITERATION",
+        "configuration": {
+            "duration": 14,
+            "startDay": 1,
+            "iterations": [
+                {"id": "it2", "title": "Sprint 2", "startDate": "2024-01-15", "duration": 14}
+            ],
+            "completedIterations": [
+                {"id": "it1", "title": "Sprint 1", "startDate": "2024-01-01", "duration": 14}
+            ]
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(field.data_type, ProjectFieldDataType::Iteration);
+    let configuration = field.iteration_configuration.unwrap();
+    assert_eq!(configuration.duration, 14);
+    assert_eq!(configuration.iterations[0].id, "it2");
+    assert_eq!(
+        configuration.completed_iterations[0].start_date.to_string(),
+        "2024-01-01"
+    );
+}
+
+/// Unknown data types are kept and nodes without an ID are skipped
+#[test]
+fn test_project_field_unknown_and_incomplete() {
+    let field = ProjectField::from_graphql_value(&json!({
+        "id": "PVTF_parent",
+        "name": "Parent issue",
+        "dataType": "PARENT_ISSUE"
+    }))
+    .unwrap();
+    assert_eq!(
+        field.data_type,
+        ProjectFieldDataType::Other("parent_issue".to_string())
+    );
+    assert_eq!(field.data_type.to_string(), "parent_issue");
+
+    assert!(ProjectField::from_graphql_value(&json!({})).is_none());
+}
+
+/// Options are found by exact name, then case-insensitive name, then option ID
+#[test]
+fn test_project_field_find_option() {
+    let field = ProjectField::from_graphql_value(&json!({
+        "id": "PVTSSF_status",
+        "name": "Status",
+        "dataType": "SINGLE_SELECT",
+        "options": [
+            {"id": "opt1", "name": "done"},
+            {"id": "opt2", "name": "Done"},
+            {"id": "opt3", "name": "In Progress"}
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(field.find_option("Done").unwrap().id, "opt2");
+    assert_eq!(field.find_option("in progress").unwrap().id, "opt3");
+    assert_eq!(field.find_option("opt3").unwrap().name, "In Progress");
+    assert!(field.find_option("Blocked").is_none());
+}
+
+/// Fields are found by exact name, then unique case-insensitive name, then field ID
+#[test]
+fn test_find_project_field() {
+    let fields: Vec<ProjectField> = [
+        ("PVTSSF_status", "Status", "SINGLE_SELECT"),
+        ("PVTF_priority", "Priority", "TEXT"),
+        ("PVTF_points", "points", "NUMBER"),
+        ("PVTF_points2", "Points", "NUMBER"),
+    ]
+    .iter()
+    .map(|(id, name, data_type)| {
+        ProjectField::from_graphql_value(&json!({
+            "id": id,
+            "name": name,
+            "dataType": data_type
+        }))
+        .unwrap()
+    })
+    .collect();
+
+    let find = |name: &str| find_project_field(&fields, name).map(|f| f.field_id.value());
+    assert_eq!(find("status"), Some("PVTSSF_status"));
+    assert_eq!(find("Points"), Some("PVTF_points2"));
+    assert_eq!(find("POINTS"), None);
+    assert_eq!(find("PVTF_priority"), Some("PVTF_priority"));
+    assert_eq!(find("Estimate"), None);
+}
+
+/// Labels fields take multi select values and iterations can not be set by value
+#[test]
+fn test_project_field_custom_field_type() {
+    assert!(matches!(
+        ProjectFieldDataType::SingleSelect.custom_field_type(),
+        Some(ProjectCustomFieldType::SingleSelect)
+    ));
+    assert!(matches!(
+        ProjectFieldDataType::Labels.custom_field_type(),
+        Some(ProjectCustomFieldType::MultiSelect)
+    ));
+    assert!(
+        ProjectFieldDataType::Iteration
+            .custom_field_type()
+            .is_none()
+    );
+    assert!(ProjectFieldDataType::Title.custom_field_type().is_none());
+}
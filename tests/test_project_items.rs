@@ -0,0 +1,83 @@
+use github_edit::types::ProjectItemId;
+use github_edit::types::project::{ProjectFieldValue, ProjectItem, ProjectItemFilter};
+use serde_json::json;
+
+fn sample_item() -> ProjectItem {
+    ProjectItem::from_graphql_value(&json!({
+        "id": "PVTI_1",
+        "isArchived": false,
+        "fieldValues": {
+            "nodes": [
+                {
+                    "__typename": "ProjectV2ItemFieldSingleSelectValue",
+                    "name": "Todo",
+                    "field": {"id": "PVTSSF_status", "name": "Status"}
+                },
+                {
+                    "__typename": "ProjectV2ItemFieldNumberValue",
+                    "number": 3.0,
+                    "field": {"id": "PVTF_points", "name": "Points"}
+                },
+                {
+                    "__typename": "ProjectV2ItemFieldDateValue",
+                    "date": "2024-03-15",
+                    "field": {"id": "PVTF_due", "name": "Due"}
+                },
+                {
+                    "__typename": "ProjectV2ItemFieldLabelValue",
+                    "labels": {"nodes": [{"name": "bug"}, {"name": "ui"}]},
+                    "field": {"id": "PVTF_labels", "name": "Labels"}
+                },
+                {
+                    "__typename": "ProjectV2ItemFieldIterationValue",
+                    "title": "Sprint 1",
+                    "field": {"id": "PVTIF_sprint", "name": "Sprint"}
+                }
+            ]
+        }
+    }))
+    .unwrap()
+}
+
+/// Supported field values are parsed and unsupported ones are skipped
+#[test]
+fn test_project_item_from_graphql_value() {
+    let item = sample_item();
+    assert_eq!(item.item_id.value(), "PVTI_1");
+    assert!(!item.archived);
+    assert_eq!(item.field_values.len(), 4);
+    assert!(matches!(
+        item.field_value("status"),
+        Some(ProjectFieldValue::SingleSelect(name)) if name == "Todo"
+    ));
+    assert!(item.field_value("Sprint").is_none());
+}
+
+/// Field filters compare each value type in its natural form
+#[test]
+fn test_project_item_filter_field_equals() {
+    let item = sample_item();
+    let field_equals = |field_name: &str, value: &str| ProjectItemFilter::FieldEquals {
+        field_name: field_name.to_string(),
+        value: value.to_string(),
+    };
+
+    assert!(field_equals("Status", "todo").matches(&item));
+    assert!(!field_equals("Status", "Done").matches(&item));
+    assert!(field_equals("Points", "3").matches(&item));
+    assert!(field_equals("Due", "2024-03-15").matches(&item));
+    assert!(field_equals("Labels", "ui").matches(&item));
+    assert!(!field_equals("Missing", "Todo").matches(&item));
+}
+
+/// Item ID filters select only the listed items
+#[test]
+fn test_project_item_filter_item_ids() {
+    let item = sample_item();
+    assert!(
+        ProjectItemFilter::ItemIds(vec![ProjectItemId::new("PVTI_1".to_string())]).matches(&item)
+    );
+    assert!(
+        !ProjectItemFilter::ItemIds(vec![ProjectItemId::new("PVTI_2".to_string())]).matches(&item)
+    );
+}
@@ -0,0 +1,177 @@
+//! Multi-select values of project item fields, dispatched by the field type
+
+use github_edit::github::GitHubClient;
+use github_edit::services::project_service::ProjectService;
+use github_edit::types::project::{ProjectFieldId, ProjectItemId, ProjectNodeId};
+use mockito::Matcher;
+use serde_json::{Value, json};
+
+fn service(server: &mockito::ServerGuard) -> ProjectService {
+    ProjectService::new(
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap(),
+    )
+}
+
+/// Answer the GraphQL requests whose body matches the regex `operation`
+/// with `body`
+async fn graphql(server: &mut mockito::ServerGuard, operation: &str, body: Value) -> mockito::Mock {
+    server
+        .mock("POST", "/graphql")
+        .match_body(Matcher::Regex(operation.to_string()))
+        .with_header("content-type", "application/json")
+        .with_body(body.to_string())
+        .create_async()
+        .await
+}
+
+/// Answer the field listing of the project with a Labels, a single select
+/// and a text field
+async fn mock_fields(server: &mut mockito::ServerGuard) -> mockito::Mock {
+    graphql(
+        server,
+        r"fields\(first",
+        json!({ "data": { "node": { "fields": {
+            "nodes": [
+                { "id": "PVTF_labels", "name": "Labels", "dataType": "LABELS" },
+                {
+                    "id": "PVTSSF_status",
+                    "name": "Status",
+                    "dataType": "SINGLE_SELECT",
+                    "options": [
+                        { "id": "f75ad846", "name": "Todo", "color": "GRAY", "description": "" },
+                        { "id": "98236657", "name": "Done", "color": "GREEN", "description": "" }
+                    ]
+                },
+                { "id": "PVTF_notes", "name": "Notes", "dataType": "TEXT" }
+            ],
+            "pageInfo": { "hasNextPage": false, "endCursor": null }
+        } } } }),
+    )
+    .await
+}
+
+/// Answer the content lookup of a project item with `content`
+async fn mock_content(server: &mut mockito::ServerGuard, content: Value) -> mockito::Mock {
+    graphql(
+        server,
+        r"content \{",
+        json!({ "data": { "node": { "content": content } } }),
+    )
+    .await
+}
+
+async fn update(service: &ProjectService, field_id: &str, values: &[&str]) -> anyhow::Result<()> {
+    let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+    service
+        .update_project_item_multi_select_field(
+            &ProjectNodeId::new("PVT_1".to_string()),
+            &ProjectItemId::new("PVTI_1".to_string()),
+            &ProjectFieldId::new(field_id.to_string()),
+            &values,
+        )
+        .await
+}
+
+/// A Labels field replaces all labels of the item's issue
+#[tokio::test]
+async fn test_multi_select_labels_replace_issue_labels() {
+    let mut server = mockito::Server::new_async().await;
+    let _fields = mock_fields(&mut server).await;
+    let _content = mock_content(
+        &mut server,
+        json!({
+            "__typename": "Issue",
+            "number": 7,
+            "repository": { "owner": { "login": "octocat" }, "name": "hello" }
+        }),
+    )
+    .await;
+    let labels = server
+        .mock("PUT", "/repos/octocat/hello/issues/7/labels")
+        .match_body(Matcher::Json(json!({ "labels": ["bug", "docs"] })))
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    update(&service(&server), "PVTF_labels", &["bug", "docs"])
+        .await
+        .unwrap();
+
+    labels.assert_async().await;
+}
+
+/// Draft issues have no labels to replace
+#[tokio::test]
+async fn test_multi_select_labels_on_draft_issue() {
+    let mut server = mockito::Server::new_async().await;
+    let _fields = mock_fields(&mut server).await;
+    let _content = mock_content(&mut server, json!({ "__typename": "DraftIssue" })).await;
+
+    let error = update(&service(&server), "PVTF_labels", &["bug"])
+        .await
+        .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("Draft issues can not have labels"),
+        "{:#}",
+        error
+    );
+}
+
+/// A single select field takes exactly one option, resolved by name
+#[tokio::test]
+async fn test_multi_select_single_select_field() {
+    let mut server = mockito::Server::new_async().await;
+    let _fields = mock_fields(&mut server).await;
+    let mutation = graphql(
+        &mut server,
+        r#"updateProjectV2ItemFieldValue[\s\S]*"singleSelectOptionId":"98236657""#,
+        json!({ "data": { "updateProjectV2ItemFieldValue": {
+            "projectV2Item": { "id": "PVTI_1" }
+        } } }),
+    )
+    .await;
+    let service = service(&server);
+
+    update(&service, "PVTSSF_status", &["done"]).await.unwrap();
+    mutation.assert_async().await;
+
+    let error = update(&service, "PVTSSF_status", &["Todo", "Done"])
+        .await
+        .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("holds exactly one option, got 2"),
+        "{:#}",
+        error
+    );
+}
+
+/// Other fields and unknown fields do not accept multiple values
+#[tokio::test]
+async fn test_multi_select_other_fields() {
+    let mut server = mockito::Server::new_async().await;
+    let _fields = mock_fields(&mut server).await;
+    let service = service(&server);
+
+    let error = update(&service, "PVTF_notes", &["a", "b"])
+        .await
+        .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("Field 'Notes' is a text field and does not accept multiple values"),
+        "{:#}",
+        error
+    );
+
+    let error = update(&service, "PVTF_missing", &["a"]).await.unwrap_err();
+    assert!(
+        error.to_string().contains("Field PVTF_missing not found"),
+        "{:#}",
+        error
+    );
+}
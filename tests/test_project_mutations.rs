@@ -0,0 +1,750 @@
+//! Payloads of the project GraphQL operations, answered by a mock server
+//!
+//! Each operation is checked for the result it reads from a successful
+//! response and for the category its errors keep through the context of the
+//! call site.
+
+use github_edit::github::GitHubClient;
+use github_edit::github::error::ErrorKind;
+use github_edit::types::project::{
+    DraftIssueId, ProjectCustomFieldType, ProjectFieldDataType, ProjectFieldId, ProjectId,
+    ProjectItemId, ProjectNodeId, ProjectNumber, ProjectState, ProjectType, ProjectVisibility,
+};
+use github_edit::types::repository::{Owner, RepositoryId};
+use mockito::Matcher;
+use serde_json::{Value, json};
+
+fn client(server: &mockito::ServerGuard) -> GitHubClient {
+    GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap()
+}
+
+/// Answer the GraphQL requests whose body matches the regex `operation`
+/// with `body`
+async fn graphql(server: &mut mockito::ServerGuard, operation: &str, body: Value) -> mockito::Mock {
+    server
+        .mock("POST", "/graphql")
+        .match_body(Matcher::Regex(operation.to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body.to_string())
+        .expect(1)
+        .create_async()
+        .await
+}
+
+/// GraphQL error response of the given type
+fn error(error_type: &str, message: &str) -> Value {
+    json!({ "errors": [{ "type": error_type, "message": message }] })
+}
+
+fn not_found() -> Value {
+    error(
+        "NOT_FOUND",
+        "Could not resolve to a node with the global id of 'PVT_missing'",
+    )
+}
+
+fn forbidden() -> Value {
+    error("FORBIDDEN", "Resource not accessible by integration")
+}
+
+fn project_node(number: u64, title: &str) -> Value {
+    json!({
+        "id": format!("PVT_{}", number),
+        "number": number,
+        "title": title,
+        "shortDescription": "",
+        "createdAt": "2024-01-01T00:00:00Z",
+        "updatedAt": "2024-01-02T00:00:00Z",
+        "owner": { "__typename": "Organization", "login": "octo-org" }
+    })
+}
+
+fn status_field() -> Value {
+    json!({
+        "id": "PVTSSF_status",
+        "name": "Status",
+        "dataType": "SINGLE_SELECT",
+        "options": [
+            { "id": "f75ad846", "name": "Todo", "color": "GRAY", "description": "" },
+            { "id": "98236657", "name": "Done", "color": "GREEN", "description": "" }
+        ]
+    })
+}
+
+/// Check the category of `error` and the context of its call site
+fn assert_error(error: anyhow::Error, kind: ErrorKind, context: &str) {
+    assert_eq!(ErrorKind::of(&error), kind, "{:#}", error);
+    assert!(format!("{:#}", error).contains(context), "{:#}", error);
+}
+
+fn project_node_id() -> ProjectNodeId {
+    ProjectNodeId::new("PVT_1".to_string())
+}
+
+fn item_id(id: &str) -> ProjectItemId {
+    ProjectItemId::new(id.to_string())
+}
+
+#[tokio::test]
+async fn test_create_project() {
+    let mut server = mockito::Server::new_async().await;
+    let owner = graphql(
+        &mut server,
+        r"organization\(login",
+        json!({ "data": { "organization": { "id": "O_1" } } }),
+    )
+    .await;
+    let create = graphql(
+        &mut server,
+        r"createProjectV2\(",
+        json!({ "data": { "createProjectV2": { "projectV2": project_node(7, "Roadmap") } } }),
+    )
+    .await;
+
+    let project = client(&server)
+        .create_project(
+            &Owner::new("octo-org".to_string()),
+            "Roadmap",
+            ProjectType::Organization,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(project.project_id.number.value(), 7);
+    assert_eq!(project.project_id.project_type, ProjectType::Organization);
+    assert_eq!(project.project_node_id.value(), "PVT_7");
+    assert_eq!(project.title, "Roadmap");
+    assert_eq!(project.description, None);
+    owner.assert_async().await;
+    create.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_project_errors() {
+    let mut server = mockito::Server::new_async().await;
+    let _owner = graphql(
+        &mut server,
+        r"organization\(login",
+        json!({ "data": { "organization": { "id": "O_1" } } }),
+    )
+    .await;
+    let _create = graphql(&mut server, r"createProjectV2\(", forbidden()).await;
+
+    let error = client(&server)
+        .create_project(
+            &Owner::new("octo-org".to_string()),
+            "Roadmap",
+            ProjectType::Organization,
+        )
+        .await
+        .unwrap_err();
+    assert_error(
+        error,
+        ErrorKind::PermissionDenied,
+        "Failed to create project",
+    );
+
+    let mut server = mockito::Server::new_async().await;
+    let _owner = graphql(
+        &mut server,
+        r"user\(login",
+        json!({ "data": { "user": null } }),
+    )
+    .await;
+    let error = client(&server)
+        .create_project(
+            &Owner::new("ghost".to_string()),
+            "Roadmap",
+            ProjectType::User,
+        )
+        .await
+        .unwrap_err();
+    assert_error(error, ErrorKind::NotFound, "'ghost' not found");
+}
+
+#[tokio::test]
+async fn test_remove_and_archive_project_items() {
+    let mut server = mockito::Server::new_async().await;
+    let remove = graphql(
+        &mut server,
+        "deleteProjectV2Item",
+        json!({ "data": { "deleteProjectV2Item": { "deletedItemId": "PVTI_1" } } }),
+    )
+    .await;
+    let archive = graphql(
+        &mut server,
+        r"\barchiveProjectV2Item",
+        json!({ "data": { "archiveProjectV2Item": { "item": { "id": "PVTI_2" } } } }),
+    )
+    .await;
+    let unarchive = graphql(
+        &mut server,
+        "unarchiveProjectV2Item",
+        json!({ "data": { "unarchiveProjectV2Item": { "item": { "id": "PVTI_2" } } } }),
+    )
+    .await;
+    let client = client(&server);
+
+    client
+        .remove_item_from_project(&project_node_id(), &item_id("PVTI_1"))
+        .await
+        .unwrap();
+    client
+        .set_project_item_archived(&project_node_id(), &item_id("PVTI_2"), true)
+        .await
+        .unwrap();
+    client
+        .set_project_item_archived(&project_node_id(), &item_id("PVTI_2"), false)
+        .await
+        .unwrap();
+
+    remove.assert_async().await;
+    archive.assert_async().await;
+    unarchive.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_remove_and_archive_project_item_errors() {
+    let mut server = mockito::Server::new_async().await;
+    let _remove = graphql(&mut server, "deleteProjectV2Item", not_found()).await;
+    let _unarchive = graphql(&mut server, "unarchiveProjectV2Item", forbidden()).await;
+    let client = client(&server);
+
+    let error = client
+        .remove_item_from_project(&project_node_id(), &item_id("PVTI_1"))
+        .await
+        .unwrap_err();
+    assert_error(
+        error,
+        ErrorKind::NotFound,
+        "Failed to remove item from project",
+    );
+
+    let error = client
+        .set_project_item_archived(&project_node_id(), &item_id("PVTI_2"), false)
+        .await
+        .unwrap_err();
+    assert_error(
+        error,
+        ErrorKind::PermissionDenied,
+        "Failed to unarchive project item",
+    );
+}
+
+#[tokio::test]
+async fn test_draft_issues() {
+    let mut server = mockito::Server::new_async().await;
+    let add = graphql(
+        &mut server,
+        "addProjectV2DraftIssue",
+        json!({ "data": { "addProjectV2DraftIssue": {
+            "projectItem": { "id": "PVTI_3", "content": { "id": "DI_1" } }
+        } } }),
+    )
+    .await;
+    let update = graphql(
+        &mut server,
+        "updateProjectV2DraftIssue",
+        json!({ "data": { "updateProjectV2DraftIssue": { "draftIssue": { "id": "DI_1" } } } }),
+    )
+    .await;
+    let repository = graphql(
+        &mut server,
+        r"repository\(owner",
+        json!({ "data": { "repository": { "id": "R_1" } } }),
+    )
+    .await;
+    let convert = graphql(
+        &mut server,
+        "convertProjectV2DraftIssueItemToIssue",
+        json!({ "data": { "convertProjectV2DraftIssueItemToIssue": {
+            "item": { "content": { "number": 12 } }
+        } } }),
+    )
+    .await;
+    let client = client(&server);
+
+    let draft = client
+        .add_draft_issue_to_project(&project_node_id(), "Plan", Some("Details"))
+        .await
+        .unwrap();
+    assert_eq!(draft.project_item_id.value(), "PVTI_3");
+    assert_eq!(draft.draft_issue_id.value(), "DI_1");
+
+    client
+        .update_draft_issue(&draft.draft_issue_id, Some("Plan v2"), None)
+        .await
+        .unwrap();
+
+    let repository_id = RepositoryId::new("octocat", "hello");
+    let issue_id = client
+        .convert_draft_issue_to_issue(&draft.project_item_id, &repository_id)
+        .await
+        .unwrap();
+    assert_eq!(issue_id.number, 12);
+    assert_eq!(issue_id.git_repository, repository_id);
+
+    add.assert_async().await;
+    update.assert_async().await;
+    repository.assert_async().await;
+    convert.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_draft_issue_errors() {
+    let mut server = mockito::Server::new_async().await;
+    let _add = graphql(
+        &mut server,
+        "addProjectV2DraftIssue",
+        json!({ "data": { "addProjectV2DraftIssue": { "projectItem": null } } }),
+    )
+    .await;
+    let _update = graphql(&mut server, "updateProjectV2DraftIssue", not_found()).await;
+    let _repository = graphql(
+        &mut server,
+        r"repository\(owner",
+        json!({ "data": { "repository": { "id": "R_1" } } }),
+    )
+    .await;
+    let _convert = graphql(
+        &mut server,
+        "convertProjectV2DraftIssueItemToIssue",
+        forbidden(),
+    )
+    .await;
+    let client = client(&server);
+
+    let error = client
+        .add_draft_issue_to_project(&project_node_id(), "Plan", None)
+        .await
+        .unwrap_err();
+    assert_error(
+        error,
+        ErrorKind::Other,
+        "Failed to add draft issue to project: unexpected response shape",
+    );
+
+    let error = client
+        .update_draft_issue(&DraftIssueId::new("DI_1".to_string()), None, Some("Body"))
+        .await
+        .unwrap_err();
+    assert_error(error, ErrorKind::NotFound, "Failed to update draft issue");
+
+    let error = client
+        .convert_draft_issue_to_issue(&item_id("PVTI_3"), &RepositoryId::new("octocat", "hello"))
+        .await
+        .unwrap_err();
+    assert_error(
+        error,
+        ErrorKind::PermissionDenied,
+        "Failed to convert draft issue to issue",
+    );
+}
+
+#[tokio::test]
+async fn test_project_fields() {
+    let mut server = mockito::Server::new_async().await;
+    let create = server
+        .mock("POST", "/graphql")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex("createProjectV2Field".to_string()),
+            Matcher::PartialJson(json!({ "variables": { "input": {
+                "projectId": "PVT_1",
+                "name": "Status",
+                "dataType": "SINGLE_SELECT"
+            } } })),
+        ]))
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "data": { "createProjectV2Field": { "projectV2Field": status_field() } } })
+                .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let update = graphql(
+        &mut server,
+        "updateProjectV2Field",
+        json!({ "data": { "updateProjectV2Field": { "projectV2Field": {
+            "id": "PVTSSF_status",
+            "name": "State",
+            "dataType": "SINGLE_SELECT",
+            "options": []
+        } } } }),
+    )
+    .await;
+    let delete = graphql(
+        &mut server,
+        "deleteProjectV2Field",
+        json!({ "data": { "deleteProjectV2Field": { "projectV2Field": { "id": "PVTSSF_status" } } } }),
+    )
+    .await;
+    let client = client(&server);
+
+    let field = client
+        .create_project_field(
+            &project_node_id(),
+            "Status",
+            &ProjectCustomFieldType::SingleSelect,
+            &["Todo".to_string(), "Done".to_string()],
+        )
+        .await
+        .unwrap();
+    assert_eq!(field.field_id.value(), "PVTSSF_status");
+    assert_eq!(field.data_type, ProjectFieldDataType::SingleSelect);
+    assert_eq!(field.options.len(), 2);
+
+    let field = client
+        .update_project_field(&field.field_id, Some("State"), None)
+        .await
+        .unwrap();
+    assert_eq!(field.name, "State");
+
+    client.delete_project_field(&field.field_id).await.unwrap();
+
+    create.assert_async().await;
+    update.assert_async().await;
+    delete.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_project_field_errors() {
+    let mut server = mockito::Server::new_async().await;
+    let _create = graphql(&mut server, "createProjectV2Field", forbidden()).await;
+    let _update = graphql(
+        &mut server,
+        "updateProjectV2Field",
+        json!({ "data": { "updateProjectV2Field": { "projectV2Field": { "id": "PVTF_1" } } } }),
+    )
+    .await;
+    let _delete = graphql(&mut server, "deleteProjectV2Field", not_found()).await;
+    let client = client(&server);
+    let field_id = ProjectFieldId::new("PVTF_1".to_string());
+
+    let error = client
+        .create_project_field(
+            &project_node_id(),
+            "Notes",
+            &ProjectCustomFieldType::Text,
+            &[],
+        )
+        .await
+        .unwrap_err();
+    assert_error(
+        error,
+        ErrorKind::PermissionDenied,
+        "Failed to create project field",
+    );
+
+    let error = client
+        .update_project_field(&field_id, Some("Notes"), None)
+        .await
+        .unwrap_err();
+    assert_error(
+        error,
+        ErrorKind::Other,
+        "Failed to update project field: unexpected response shape",
+    );
+
+    let error = client.delete_project_field(&field_id).await.unwrap_err();
+    assert_error(error, ErrorKind::NotFound, "Failed to delete project field");
+}
+
+#[tokio::test]
+async fn test_link_repository_and_team() {
+    let mut server = mockito::Server::new_async().await;
+    let repository = graphql(
+        &mut server,
+        r"repository\(owner",
+        json!({ "data": { "repository": { "id": "R_1" } } }),
+    )
+    .await;
+    let link = server
+        .mock("POST", "/graphql")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex("linkProjectV2ToRepository".to_string()),
+            Matcher::PartialJson(
+                json!({ "variables": { "projectId": "PVT_1", "targetId": "R_1" } }),
+            ),
+        ]))
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"linkProjectV2ToRepository": {"clientMutationId": null}}}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let team = graphql(
+        &mut server,
+        r"team\(slug",
+        json!({ "data": { "organization": { "team": { "id": "T_1" } } } }),
+    )
+    .await;
+    let unlink = server
+        .mock("POST", "/graphql")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex("unlinkProjectV2FromTeam".to_string()),
+            Matcher::PartialJson(
+                json!({ "variables": { "projectId": "PVT_1", "targetId": "T_1" } }),
+            ),
+        ]))
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"unlinkProjectV2FromTeam": {"clientMutationId": null}}}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let client = client(&server);
+
+    client
+        .link_repository_to_project(&project_node_id(), &RepositoryId::new("octocat", "hello"))
+        .await
+        .unwrap();
+    client
+        .unlink_team_from_project(
+            &project_node_id(),
+            &Owner::new("octo-org".to_string()),
+            "core",
+        )
+        .await
+        .unwrap();
+
+    repository.assert_async().await;
+    link.assert_async().await;
+    team.assert_async().await;
+    unlink.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_link_repository_and_team_errors() {
+    let mut server = mockito::Server::new_async().await;
+    let _repository = graphql(
+        &mut server,
+        r"repository\(owner",
+        json!({ "data": { "repository": { "id": "R_1" } } }),
+    )
+    .await;
+    let _unlink = graphql(&mut server, "unlinkProjectV2FromRepository", forbidden()).await;
+    let _team = graphql(
+        &mut server,
+        r"team\(slug",
+        json!({ "data": { "organization": { "team": null } } }),
+    )
+    .await;
+    let client = client(&server);
+
+    let error = client
+        .unlink_repository_from_project(&project_node_id(), &RepositoryId::new("octocat", "hello"))
+        .await
+        .unwrap_err();
+    assert_error(
+        error,
+        ErrorKind::PermissionDenied,
+        "Failed to run unlinkProjectV2FromRepository",
+    );
+
+    let error = client
+        .link_team_to_project(
+            &project_node_id(),
+            &Owner::new("octo-org".to_string()),
+            "missing",
+        )
+        .await
+        .unwrap_err();
+    assert_error(
+        error,
+        ErrorKind::NotFound,
+        "Team octo-org/missing not found",
+    );
+}
+
+#[tokio::test]
+async fn test_copy_project() {
+    let mut server = mockito::Server::new_async().await;
+    let owner = graphql(
+        &mut server,
+        r"organization\(login",
+        json!({ "data": { "organization": { "id": "O_1" } } }),
+    )
+    .await;
+    let copy = server
+        .mock("POST", "/graphql")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex("copyProjectV2".to_string()),
+            Matcher::PartialJson(json!({ "variables": {
+                "projectId": "PVT_1",
+                "ownerId": "O_1",
+                "title": "Sprint board",
+                "includeDraftIssues": true
+            } })),
+        ]))
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "data": { "copyProjectV2": { "projectV2": project_node(8, "Sprint board") } } })
+                .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let project = client(&server)
+        .copy_project(
+            &project_node_id(),
+            &Owner::new("octo-org".to_string()),
+            ProjectType::Organization,
+            "Sprint board",
+            true,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(project.project_id.number.value(), 8);
+    assert_eq!(project.project_node_id.value(), "PVT_8");
+    assert_eq!(project.title, "Sprint board");
+    owner.assert_async().await;
+    copy.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_copy_project_errors() {
+    let mut server = mockito::Server::new_async().await;
+    let _owner = graphql(
+        &mut server,
+        r"organization\(login",
+        json!({ "data": { "organization": { "id": "O_1" } } }),
+    )
+    .await;
+    let _copy = graphql(&mut server, "copyProjectV2", not_found()).await;
+
+    let error = client(&server)
+        .copy_project(
+            &project_node_id(),
+            &Owner::new("octo-org".to_string()),
+            ProjectType::Organization,
+            "Sprint board",
+            false,
+        )
+        .await
+        .unwrap_err();
+    assert_error(error, ErrorKind::NotFound, "Failed to copy project");
+}
+
+#[tokio::test]
+async fn test_get_project() {
+    let mut server = mockito::Server::new_async().await;
+    let mut project = project_node(1, "Roadmap");
+    project["readme"] = json!("# Roadmap");
+    project["public"] = json!(true);
+    project["closed"] = json!(false);
+    project["items"] = json!({ "totalCount": 42 });
+    project["fields"] = json!({
+        "nodes": [status_field()],
+        "pageInfo": { "hasNextPage": false }
+    });
+    let get = graphql(
+        &mut server,
+        r"projectV2\(number",
+        json!({ "data": { "organization": { "projectV2": project } } }),
+    )
+    .await;
+
+    let project_id = ProjectId::new(
+        Owner::new("octo-org".to_string()),
+        ProjectNumber::new(1),
+        ProjectType::Organization,
+    );
+    let details = client(&server).get_project(&project_id).await.unwrap();
+
+    assert_eq!(details.project.title, "Roadmap");
+    assert_eq!(details.readme.as_deref(), Some("# Roadmap"));
+    assert_eq!(details.visibility, ProjectVisibility::Public);
+    assert_eq!(details.state, ProjectState::Open);
+    assert_eq!(details.item_count, 42);
+    assert_eq!(details.fields.len(), 1);
+    assert_eq!(details.fields[0].name, "Status");
+    get.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_project_errors() {
+    let project_id = ProjectId::new(
+        Owner::new("octo-org".to_string()),
+        ProjectNumber::new(9),
+        ProjectType::Organization,
+    );
+
+    let mut server = mockito::Server::new_async().await;
+    let _get = graphql(
+        &mut server,
+        r"projectV2\(number",
+        json!({ "data": { "organization": { "projectV2": null } } }),
+    )
+    .await;
+    let error = client(&server).get_project(&project_id).await.unwrap_err();
+    assert_error(error, ErrorKind::NotFound, "not found");
+
+    let mut server = mockito::Server::new_async().await;
+    let _get = graphql(&mut server, r"projectV2\(number", forbidden()).await;
+    let error = client(&server).get_project(&project_id).await.unwrap_err();
+    assert_error(error, ErrorKind::PermissionDenied, "Failed to get project");
+}
+
+#[tokio::test]
+async fn test_update_project_item_position() {
+    let mut server = mockito::Server::new_async().await;
+    let to_top = server
+        .mock("POST", "/graphql")
+        .match_body(Matcher::PartialJson(json!({ "variables": {
+            "projectId": "PVT_1",
+            "itemId": "PVTI_2",
+            "afterId": null
+        } })))
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"updateProjectV2ItemPosition": {"clientMutationId": null}}}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let after = server
+        .mock("POST", "/graphql")
+        .match_body(Matcher::PartialJson(json!({ "variables": {
+            "projectId": "PVT_1",
+            "itemId": "PVTI_2",
+            "afterId": "PVTI_1"
+        } })))
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"updateProjectV2ItemPosition": {"clientMutationId": null}}}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let client = client(&server);
+
+    client
+        .update_project_item_position(&project_node_id(), &item_id("PVTI_2"), None)
+        .await
+        .unwrap();
+    client
+        .update_project_item_position(
+            &project_node_id(),
+            &item_id("PVTI_2"),
+            Some(&item_id("PVTI_1")),
+        )
+        .await
+        .unwrap();
+
+    to_top.assert_async().await;
+    after.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_update_project_item_position_errors() {
+    let mut server = mockito::Server::new_async().await;
+    let _move = graphql(&mut server, "updateProjectV2ItemPosition", not_found()).await;
+
+    let error = client(&server)
+        .update_project_item_position(&project_node_id(), &item_id("PVTI_2"), None)
+        .await
+        .unwrap_err();
+    assert_error(
+        error,
+        ErrorKind::NotFound,
+        "Failed to update project item position",
+    );
+}
@@ -0,0 +1,32 @@
+use github_edit::types::project::{ProjectState, ProjectUpdate, ProjectVisibility};
+use std::str::FromStr;
+
+/// An update with no settings is empty and is rejected before calling the API
+#[test]
+fn test_project_update_is_empty() {
+    assert!(ProjectUpdate::default().is_empty());
+
+    let update = ProjectUpdate {
+        state: Some(ProjectState::Closed),
+        ..Default::default()
+    };
+    assert!(!update.is_empty());
+}
+
+/// Visibility and state accept the lowercase names used by the tools
+#[test]
+fn test_project_visibility_and_state_from_str() {
+    assert_eq!(
+        ProjectVisibility::from_str("public").unwrap(),
+        ProjectVisibility::Public
+    );
+    assert_eq!(
+        ProjectVisibility::from_str("private").unwrap(),
+        ProjectVisibility::Private
+    );
+    assert_eq!(
+        ProjectState::from_str("closed").unwrap(),
+        ProjectState::Closed
+    );
+    assert!(ProjectState::from_str("archived").is_err());
+}
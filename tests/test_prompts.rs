@@ -0,0 +1,158 @@
+use github_edit::github::GitHubClient;
+use github_edit::tools::GitEditTools;
+use github_edit::tools::prompts::{WorkflowPrompt, get_prompt, list_prompts};
+use rmcp::ServerHandler;
+use rmcp::model::{GetPromptRequestParam, JsonObject, PromptMessageContent};
+
+fn arguments(pairs: &[(&str, &str)]) -> Option<JsonObject> {
+    Some(
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), serde_json::Value::from(*value)))
+            .collect(),
+    )
+}
+
+fn render(name: &str, pairs: &[(&str, &str)]) -> Result<String, String> {
+    let result = get_prompt(GetPromptRequestParam {
+        name: name.to_string(),
+        arguments: arguments(pairs),
+    })
+    .map_err(|e| e.message.to_string())?;
+    assert_eq!(result.messages.len(), 1);
+    match &result.messages[0].content {
+        PromptMessageContent::Text { text } => Ok(text.clone()),
+        other => panic!("unexpected content: {:?}", other),
+    }
+}
+
+/// Every prompt is listed with its arguments and found by its name
+#[test]
+fn test_list_prompts() {
+    let prompts = list_prompts();
+    let names: Vec<&str> = prompts.iter().map(|prompt| prompt.name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec!["triage_issue", "review_pull_request", "write_release_notes"]
+    );
+
+    for prompt in WorkflowPrompt::ALL {
+        assert_eq!(WorkflowPrompt::from_name(prompt.name()), Some(prompt));
+    }
+    assert_eq!(WorkflowPrompt::from_name("unknown"), None);
+
+    let release_notes = &prompts[2];
+    let required: Vec<(&str, Option<bool>)> = release_notes
+        .arguments
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|argument| (argument.name.as_str(), argument.required))
+        .collect();
+    assert_eq!(
+        required,
+        vec![
+            ("repository_url", Some(true)),
+            ("milestone", Some(true)),
+            ("tag_name", Some(false)),
+        ]
+    );
+}
+
+/// The triage prompt points at the issue resource and the triage tools
+#[test]
+fn test_triage_issue_prompt() {
+    let text = render(
+        "triage_issue",
+        &[("issue_url", "https://github.com/octocat/hello/issues/42")],
+    )
+    .unwrap();
+
+    assert!(text.contains("github-edit://octocat/hello/issues/42"));
+    assert!(text.contains("repo:octocat/hello is:issue"));
+    assert!(text.contains("`add_labels_to_issue`"));
+    assert!(text.contains("`mark_issue_duplicate`"));
+}
+
+/// The review prompt fills in the pull request and the optional focus
+#[test]
+fn test_review_pull_request_prompt() {
+    let text = render(
+        "review_pull_request",
+        &[(
+            "pull_request_url",
+            "https://github.com/octocat/hello/pull/7",
+        )],
+    )
+    .unwrap();
+    assert!(text.contains("github-edit://octocat/hello/pulls/7"));
+    assert!(text.contains("pr_number 7"));
+    assert!(!text.contains("Focus the review on"));
+
+    let text = render(
+        "review_pull_request",
+        &[
+            (
+                "pull_request_url",
+                "https://github.com/octocat/hello/pull/7",
+            ),
+            ("focus", "error handling"),
+        ],
+    )
+    .unwrap();
+    assert!(text.contains("Focus the review on: error handling."));
+}
+
+/// The release notes prompt searches the milestone and uses the tag if given
+#[test]
+fn test_write_release_notes_prompt() {
+    let text = render(
+        "write_release_notes",
+        &[("repository_url", "octocat/hello"), ("milestone", "v1.2.0")],
+    )
+    .unwrap();
+    assert!(text.contains("milestone:\"v1.2.0\""));
+    assert!(!text.contains("`generate_release_notes`"));
+
+    let text = render(
+        "write_release_notes",
+        &[
+            ("repository_url", "octocat/hello"),
+            ("milestone", "v1.2.0"),
+            ("tag_name", "v1.2.0"),
+        ],
+    )
+    .unwrap();
+    assert!(text.contains("`generate_release_notes` with tag_name `v1.2.0`"));
+}
+
+/// Unknown prompts, missing arguments and invalid URLs are rejected
+#[test]
+fn test_get_prompt_errors() {
+    let error = render("unknown", &[]).unwrap_err();
+    assert!(error.contains("Unknown prompt"));
+
+    let error = render(
+        "write_release_notes",
+        &[("repository_url", "octocat/hello")],
+    )
+    .unwrap_err();
+    assert!(error.contains("Missing required argument 'milestone'"));
+
+    let error = render("triage_issue", &[("issue_url", " ")]).unwrap_err();
+    assert!(error.contains("Missing required argument 'issue_url'"));
+
+    let error = render("triage_issue", &[("issue_url", "not a url")]).unwrap_err();
+    assert!(error.contains("Invalid issue URL"));
+}
+
+/// The server advertises prompts next to its tools and resources
+#[tokio::test]
+async fn test_server_capabilities_include_prompts() {
+    let tools = GitEditTools::new(GitHubClient::new(None, None).unwrap());
+    let capabilities = tools.get_info().capabilities;
+
+    assert!(capabilities.prompts.is_some());
+    assert!(capabilities.resources.is_some());
+    assert!(capabilities.tools.is_some());
+}
@@ -0,0 +1,90 @@
+use github_edit::types::pull_request::{
+    MergeBlocker, MergeStateStatus, MergeableState, PullRequestId, PullRequestMergeStatus,
+    RequiredCheckState, RequiredCheckStatus, ReviewDecision,
+};
+use github_edit::types::repository::RepositoryId;
+
+fn pull_request_id() -> PullRequestId {
+    PullRequestId::new(RepositoryId::new("tacogips", "gitcodes-mcp-test-1"), 1)
+}
+
+/// A clean, approved pull request with passing checks has no blockers
+#[test]
+fn test_merge_status_clean() {
+    let status = PullRequestMergeStatus::new(
+        pull_request_id(),
+        false,
+        MergeableState::Mergeable,
+        MergeStateStatus::Clean,
+        Some(ReviewDecision::Approved),
+        vec![RequiredCheckStatus::new("ci", RequiredCheckState::Passed)],
+        None,
+    );
+
+    assert!(status.blockers.is_empty());
+    assert!(status.is_mergeable());
+}
+
+/// Every failing requirement is reported as a separate blocker
+#[test]
+fn test_merge_status_collects_blockers() {
+    let status = PullRequestMergeStatus::new(
+        pull_request_id(),
+        true,
+        MergeableState::Conflicting,
+        MergeStateStatus::Dirty,
+        Some(ReviewDecision::ChangesRequested),
+        vec![
+            RequiredCheckStatus::new("build", RequiredCheckState::Failed),
+            RequiredCheckStatus::new("lint", RequiredCheckState::Pending),
+            RequiredCheckStatus::new("deploy", RequiredCheckState::Missing),
+        ],
+        None,
+    );
+
+    assert_eq!(
+        status.blockers,
+        vec![
+            MergeBlocker::Draft,
+            MergeBlocker::MergeConflicts,
+            MergeBlocker::ChangesRequested,
+            MergeBlocker::FailingCheck("build".to_string()),
+            MergeBlocker::PendingCheck("lint".to_string()),
+            MergeBlocker::MissingCheck("deploy".to_string()),
+        ]
+    );
+    assert!(!status.is_mergeable());
+}
+
+/// A blocked merge state without any other explanation falls back to branch protection
+#[test]
+fn test_merge_status_blocked_by_branch_protection() {
+    let status = PullRequestMergeStatus::new(
+        pull_request_id(),
+        false,
+        MergeableState::Mergeable,
+        MergeStateStatus::Blocked,
+        Some(ReviewDecision::Approved),
+        vec![],
+        None,
+    );
+
+    assert_eq!(status.blockers, vec![MergeBlocker::BranchProtection]);
+}
+
+/// GraphQL enum values parse into the typed merge state enums
+#[test]
+fn test_merge_state_parsing() {
+    assert_eq!(
+        "HAS_HOOKS".parse::<MergeStateStatus>().unwrap(),
+        MergeStateStatus::HasHooks
+    );
+    assert_eq!(
+        "REVIEW_REQUIRED".parse::<ReviewDecision>().unwrap(),
+        ReviewDecision::ReviewRequired
+    );
+    assert_eq!(
+        "CONFLICTING".parse::<MergeableState>().unwrap(),
+        MergeableState::Conflicting
+    );
+}
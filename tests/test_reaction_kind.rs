@@ -0,0 +1,41 @@
+use github_edit::types::reaction::{ReactionKind, ReactionTarget, ReactionTargetType};
+
+/// Reaction kinds parse from GitHub API values and readable aliases
+#[test]
+fn test_reaction_kind_parsing() {
+    assert_eq!("+1".parse::<ReactionKind>().unwrap(), ReactionKind::PlusOne);
+    assert_eq!(
+        "thumbs_up".parse::<ReactionKind>().unwrap(),
+        ReactionKind::PlusOne
+    );
+    assert_eq!(
+        "-1".parse::<ReactionKind>().unwrap(),
+        ReactionKind::MinusOne
+    );
+    assert_eq!(
+        "Heart".parse::<ReactionKind>().unwrap(),
+        ReactionKind::Heart
+    );
+    assert!("thumbsup!".parse::<ReactionKind>().is_err());
+
+    assert_eq!(ReactionKind::PlusOne.to_string(), "+1");
+    assert_eq!(ReactionKind::Eyes.to_string(), "eyes");
+}
+
+/// Reaction targets route issues and pull requests to the issue endpoint and
+/// comments to the comment endpoint
+#[test]
+fn test_reaction_target_routing() {
+    let pr = ReactionTarget::new("pull_request".parse::<ReactionTargetType>().unwrap(), 42);
+    assert_eq!(pr.issue_number(), Some(42));
+    assert_eq!(pr.comment_number(), None);
+
+    let comment = ReactionTarget::new(
+        "pull_request_comment"
+            .parse::<ReactionTargetType>()
+            .unwrap(),
+        1234567890,
+    );
+    assert_eq!(comment.issue_number(), None);
+    assert_eq!(comment.comment_number(), Some(1234567890));
+}
@@ -0,0 +1,284 @@
+use github_edit::github::GitHubClient;
+use github_edit::tools::functions::issue::get_issues_with_details;
+use github_edit::tools::tool_definition::{IssueTools, PullRequestTools};
+use github_edit::types::issue::{IssueId, IssueNumber, IssueUrl};
+use rmcp::model::{CallToolResult, RawContent};
+use serde_json::{Value, json};
+
+fn texts(result: &CallToolResult) -> Vec<String> {
+    result
+        .content
+        .iter()
+        .map(|content| match &content.raw {
+            RawContent::Text(text) => text.text.clone(),
+            other => panic!("unexpected content: {:?}", other),
+        })
+        .collect()
+}
+
+fn user_json(login: &str) -> Value {
+    let url = format!("https://api.github.com/users/{}", login);
+    json!({
+        "login": login,
+        "id": 1,
+        "node_id": "MDQ6VXNlcjE=",
+        "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+        "gravatar_id": "",
+        "url": url,
+        "html_url": format!("https://github.com/{}", login),
+        "followers_url": format!("{}/followers", url),
+        "following_url": format!("{}/following", url),
+        "gists_url": format!("{}/gists", url),
+        "starred_url": format!("{}/starred", url),
+        "subscriptions_url": format!("{}/subscriptions", url),
+        "organizations_url": format!("{}/orgs", url),
+        "repos_url": format!("{}/repos", url),
+        "events_url": format!("{}/events", url),
+        "received_events_url": format!("{}/received_events", url),
+        "type": "User",
+        "site_admin": false,
+        "name": null,
+        "patch_url": null
+    })
+}
+
+fn issue_json(number: u64, comments: u32) -> Value {
+    let url = format!(
+        "https://api.github.com/repos/octocat/hello/issues/{}",
+        number
+    );
+    json!({
+        "id": 1000 + number,
+        "node_id": "I_kwDOA",
+        "url": url,
+        "repository_url": "https://api.github.com/repos/octocat/hello",
+        "labels_url": format!("{}/labels{{/name}}", url),
+        "comments_url": format!("{}/comments", url),
+        "events_url": format!("{}/events", url),
+        "html_url": format!("https://github.com/octocat/hello/issues/{}", number),
+        "number": number,
+        "state": "open",
+        "state_reason": null,
+        "title": "Crash when saving drafts",
+        "body": "Steps to reproduce",
+        "user": user_json("octocat"),
+        "labels": [{
+            "id": 1,
+            "node_id": "LA_kwDOA",
+            "url": "https://api.github.com/repos/octocat/hello/labels/bug",
+            "name": "bug",
+            "color": "d73a4a",
+            "default": true,
+            "description": null
+        }],
+        "assignees": [],
+        "author_association": "OWNER",
+        "locked": false,
+        "comments": comments,
+        "created_at": "2024-01-02T03:04:05Z",
+        "updated_at": "2024-01-03T03:04:05Z"
+    })
+}
+
+fn comment_json(id: u64, body: &str) -> Value {
+    json!({
+        "id": id,
+        "node_id": "IC_kwDOA",
+        "url": format!("https://api.github.com/repos/octocat/hello/issues/comments/{}", id),
+        "html_url": format!("https://github.com/octocat/hello/issues/42#issuecomment-{}", id),
+        "body": body,
+        "author_association": "CONTRIBUTOR",
+        "user": user_json("hubot"),
+        "created_at": "2024-01-02T04:04:05Z",
+        "updated_at": "2024-01-02T04:04:05Z"
+    })
+}
+
+async fn issue_server() -> mockito::ServerGuard {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("GET", "/repos/octocat/hello/issues/42")
+        .with_header("content-type", "application/json")
+        .with_body(issue_json(42, 3).to_string())
+        .create_async()
+        .await;
+    server
+        .mock("GET", "/repos/octocat/hello/issues/42/comments")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                comment_json(1, "First"),
+                comment_json(2, "Second"),
+                comment_json(3, "Third")
+            ])
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    server
+        .mock("GET", "/repos/octocat/hello/issues/404")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"message": "Not Found", "documentation_url": null}"#)
+        .create_async()
+        .await;
+    server
+}
+
+/// The issue is returned with at most comment_limit comments, and the text
+/// points to list_issue_comments for the rest
+#[tokio::test]
+async fn test_get_issue_limits_comments() {
+    let server = issue_server().await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+
+    let result = IssueTools::get_issue(
+        &client,
+        "octocat/hello".to_string(),
+        IssueNumber::new(42),
+        Some(2),
+    )
+    .await
+    .unwrap();
+
+    assert_ne!(result.is_error, Some(true));
+    let texts = texts(&result);
+    assert!(
+        texts[0].starts_with("Issue #42 [open] Crash when saving drafts"),
+        "{}",
+        texts[0]
+    );
+    assert!(texts[0].contains("Labels: bug"));
+    assert!(texts[0].contains("Steps to reproduce"));
+    assert!(texts[0].contains("Comments (first 2; use list_issue_comments for the rest):"));
+    assert!(texts[0].contains("Second"));
+    assert!(!texts[0].contains("Third"));
+
+    let details: Value = serde_json::from_str(&texts[1]).unwrap();
+    assert_eq!(details["title"], "Crash when saving drafts");
+    assert_eq!(details["comments"].as_array().unwrap().len(), 2);
+    assert_eq!(details["has_more_comments"], true);
+}
+
+/// Issues that cannot be read are reported without failing the others
+#[tokio::test]
+async fn test_get_issues_reports_failures() {
+    let server = issue_server().await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+
+    let result = IssueTools::get_issues(
+        &client,
+        vec![
+            "https://github.com/octocat/hello/issues/42".to_string(),
+            "https://github.com/octocat/hello/issues/404".to_string(),
+        ],
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert_ne!(result.is_error, Some(true));
+    let texts = texts(&result);
+    assert!(texts[0].contains("Comments (3):"), "{}", texts[0]);
+    assert!(texts[0].contains("Failed to get issue https://github.com/octocat/hello/issues/404"));
+
+    let data: Value = serde_json::from_str(&texts[1]).unwrap();
+    assert_eq!(data["issues"].as_array().unwrap().len(), 1);
+    assert_eq!(data["issues"][0]["has_more_comments"], false);
+    assert_eq!(
+        data["failures"][0]["url"],
+        "https://github.com/octocat/hello/issues/404"
+    );
+}
+
+/// Issues read concurrently are returned in the order they were requested,
+/// each with its own result
+#[tokio::test]
+async fn test_get_issues_with_details_keeps_order() {
+    let server = issue_server().await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+
+    let issue_ids: Vec<IssueId> = [404, 42, 404, 42]
+        .iter()
+        .map(|number| {
+            IssueId::parse_url(&IssueUrl(format!(
+                "https://github.com/octocat/hello/issues/{}",
+                number
+            )))
+            .unwrap()
+        })
+        .collect();
+    let results = get_issues_with_details(&client, &issue_ids, 1, Some(3)).await;
+
+    assert_eq!(results.len(), 4);
+    assert!(results[0].is_err());
+    assert!(results[2].is_err());
+    for details in [&results[1], &results[3]] {
+        let details = details.as_ref().unwrap();
+        assert_eq!(details.issue.issue_id.number, 42);
+        assert_eq!(details.issue.comments.len(), 1);
+        assert!(details.has_more_comments);
+    }
+}
+
+/// Invalid URLs, URL counts and comment limits are rejected before any request
+#[tokio::test]
+async fn test_read_tools_validate_arguments() {
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, "http://127.0.0.1:9")
+            .unwrap();
+
+    assert!(
+        IssueTools::get_issue(
+            &client,
+            "octocat/hello".to_string(),
+            IssueNumber::new(1),
+            Some(101)
+        )
+        .await
+        .is_err()
+    );
+    assert!(
+        IssueTools::get_issues(&client, vec![], None, None)
+            .await
+            .is_err()
+    );
+    assert!(
+        IssueTools::get_issues(
+            &client,
+            vec!["https://github.com/octocat/hello/pull/1".to_string()],
+            None,
+            None
+        )
+        .await
+        .is_err()
+    );
+    let too_many = (1..=21)
+        .map(|n| format!("https://github.com/octocat/hello/pull/{}", n))
+        .collect();
+    assert!(
+        PullRequestTools::get_pull_requests(&client, too_many, None, None)
+            .await
+            .is_err()
+    );
+    assert!(
+        PullRequestTools::get_pull_requests(
+            &client,
+            vec!["https://github.com/octocat/hello/issues/1".to_string()],
+            None,
+            None
+        )
+        .await
+        .is_err()
+    );
+    assert!(
+        PullRequestTools::get_pull_request(&client, "octocat/hello".to_string(), 1, Some(500))
+            .await
+            .is_err()
+    );
+}
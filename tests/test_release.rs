@@ -0,0 +1,46 @@
+use github_edit::types::release::{Release, ReleaseId, ReleaseUpdate};
+use serde_json::json;
+
+/// Draft releases deserialize without a publication date
+#[test]
+fn test_release_from_api_response() {
+    let release: Release = serde_json::from_value(json!({
+        "id": 123456,
+        "tag_name": "v1.0.0",
+        "name": null,
+        "body": "First stable release",
+        "draft": true,
+        "prerelease": false,
+        "target_commitish": "main",
+        "html_url": "https://github.com/owner/repo/releases/tag/untagged-abc",
+        "created_at": "2024-01-15T10:30:00Z",
+        "published_at": null,
+        "author": {"login": "octocat"},
+        "assets": []
+    }))
+    .unwrap();
+
+    assert_eq!(release.id, ReleaseId::new(123456));
+    assert_eq!(release.tag_name, "v1.0.0");
+    assert_eq!(release.name, None);
+    assert!(release.draft);
+    assert!(release.published_at.is_none());
+}
+
+/// Only the settings that are set are sent
+#[test]
+fn test_release_update_request_body() {
+    assert!(ReleaseUpdate::default().is_empty());
+    assert_eq!(ReleaseUpdate::default().to_request_body(), json!({}));
+
+    let update = ReleaseUpdate {
+        body: Some("Updated notes".to_string()),
+        draft: Some(false),
+        ..Default::default()
+    };
+    assert!(!update.is_empty());
+    assert_eq!(
+        update.to_request_body(),
+        json!({"body": "Updated notes", "draft": false})
+    );
+}
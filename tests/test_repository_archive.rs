@@ -0,0 +1,79 @@
+use github_edit::github::GitHubClient;
+use github_edit::tools::tool_definition::RepositoryTools;
+use github_edit::types::repository::RepositoryId;
+use mockito::Matcher;
+use serde_json::json;
+
+fn client(server: &mockito::ServerGuard) -> GitHubClient {
+    GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap()
+}
+
+/// Mock the PATCH of octocat/hello that sets `archived` to `archived`
+async fn mock_archived(server: &mut mockito::ServerGuard, archived: bool) -> mockito::Mock {
+    server
+        .mock("PATCH", "/repos/octocat/hello")
+        .match_body(Matcher::Json(json!({ "archived": archived })))
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "name": "hello", "archived": archived }).to_string())
+        .create_async()
+        .await
+}
+
+/// Archiving and unarchiving only change the `archived` flag
+#[tokio::test]
+async fn test_archive_and_unarchive_repository() {
+    let mut server = mockito::Server::new_async().await;
+    let archive = mock_archived(&mut server, true).await;
+    let unarchive = mock_archived(&mut server, false).await;
+    let client = client(&server);
+    let repository_id = RepositoryId::new("octocat", "hello");
+
+    client.archive_repository(&repository_id).await.unwrap();
+    client.unarchive_repository(&repository_id).await.unwrap();
+
+    archive.assert_async().await;
+    unarchive.assert_async().await;
+}
+
+/// The tool refuses to archive without confirmation and sends nothing
+#[tokio::test]
+async fn test_archive_repository_tool_requires_confirmation() {
+    let mut server = mockito::Server::new_async().await;
+    let archive = server
+        .mock("PATCH", "/repos/octocat/hello")
+        .expect(0)
+        .create_async()
+        .await;
+    let client = client(&server);
+
+    let error = RepositoryTools::archive_repository(&client, "octocat/hello".to_string(), false)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.message,
+        "Archiving makes https://github.com/octocat/hello read-only. Set confirm to true to archive it"
+    );
+    archive.assert_async().await;
+}
+
+/// With confirmation the tool archives the repository, and unarchiving
+/// needs none
+#[tokio::test]
+async fn test_archive_repository_tool_confirmed() {
+    let mut server = mockito::Server::new_async().await;
+    let archive = mock_archived(&mut server, true).await;
+    let unarchive = mock_archived(&mut server, false).await;
+    let client = client(&server);
+
+    let result = RepositoryTools::archive_repository(&client, "octocat/hello".to_string(), true)
+        .await
+        .unwrap();
+    assert_eq!(result.is_error, Some(false));
+    let result = RepositoryTools::unarchive_repository(&client, "octocat/hello".to_string())
+        .await
+        .unwrap();
+    assert_eq!(result.is_error, Some(false));
+
+    archive.assert_async().await;
+    unarchive.assert_async().await;
+}
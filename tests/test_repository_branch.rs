@@ -0,0 +1,28 @@
+use github_edit::types::branch::RepositoryBranch;
+use serde_json::json;
+
+/// Branches are parsed from both the list and the single branch response shapes
+#[test]
+fn test_repository_branch_from_api_value() {
+    let listed = RepositoryBranch::from_api_value(&json!({
+        "name": "main",
+        "commit": {"sha": "abc123", "url": "https://api.github.com/repos/o/r/commits/abc123"},
+        "protected": true
+    }))
+    .unwrap();
+    assert_eq!(
+        listed,
+        RepositoryBranch::new("main".to_string(), "abc123".to_string(), true)
+    );
+
+    let renamed = RepositoryBranch::from_api_value(&json!({
+        "name": "trunk",
+        "commit": {"sha": "def456", "commit": {"message": "Initial commit"}},
+        "_links": {}
+    }))
+    .unwrap();
+    assert_eq!(renamed.name, "trunk");
+    assert!(!renamed.protected);
+
+    assert!(RepositoryBranch::from_api_value(&json!({"name": "no-commit"})).is_none());
+}
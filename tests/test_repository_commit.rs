@@ -0,0 +1,103 @@
+use chrono::{TimeZone, Utc};
+use github_edit::types::commit::{Commit, CommitFilter};
+use github_edit::types::pagination::Pagination;
+use serde_json::json;
+
+fn commit_value() -> serde_json::Value {
+    json!({
+        "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+        "html_url": "https://github.com/octocat/Hello-World/commit/6dcb09b5b57875f334f61aebed695e2e4193db5e",
+        "commit": {
+            "message": "Fix all the bugs\n\nCloses #42",
+            "author": {
+                "name": "Monalisa Octocat",
+                "email": "support@github.com",
+                "date": "2024-01-15T16:00:49Z"
+            },
+            "committer": {
+                "name": "GitHub",
+                "email": "noreply@github.com",
+                "date": "2024-01-15T16:00:49Z"
+            }
+        },
+        "author": { "login": "octocat" },
+        "parents": [{ "sha": "7638417db6d59f3c431d3e1f261cc637155684cd" }],
+        "stats": { "additions": 104, "deletions": 4, "total": 108 },
+        "files": [
+            {
+                "filename": "src/lib.rs",
+                "status": "modified",
+                "additions": 10,
+                "deletions": 2,
+                "changes": 12,
+                "patch": "@@ -1,2 +1,10 @@"
+            },
+            {
+                "filename": "src/new.rs",
+                "status": "renamed",
+                "additions": 0,
+                "deletions": 0,
+                "changes": 0,
+                "previous_filename": "src/old.rs"
+            }
+        ]
+    })
+}
+
+/// Single commits deserialize with their stats and changed files
+#[test]
+fn test_commit_deserialize() {
+    let commit: Commit = serde_json::from_value(commit_value()).unwrap();
+
+    assert_eq!(commit.short_sha(), "6dcb09b");
+    assert_eq!(commit.summary(), "Fix all the bugs");
+    assert_eq!(commit.author_name(), Some("octocat"));
+    assert_eq!(
+        commit.authored_at(),
+        Some(Utc.with_ymd_and_hms(2024, 1, 15, 16, 0, 49).unwrap())
+    );
+    assert!(!commit.is_merge());
+    assert_eq!(commit.stats.unwrap().total, 108);
+    assert_eq!(commit.files.len(), 2);
+    assert_eq!(commit.files[0].patch.as_deref(), Some("@@ -1,2 +1,10 @@"));
+    assert_eq!(
+        commit.files[1].previous_filename.as_deref(),
+        Some("src/old.rs")
+    );
+}
+
+/// Listed commits have no stats or files, and fall back to the git author name
+#[test]
+fn test_listed_commit_without_account() {
+    let mut value = commit_value();
+    let object = value.as_object_mut().unwrap();
+    object.remove("stats");
+    object.remove("files");
+    object.insert("author".to_string(), serde_json::Value::Null);
+
+    let commit: Commit = serde_json::from_value(value).unwrap();
+
+    assert!(commit.stats.is_none());
+    assert!(commit.files.is_empty());
+    assert_eq!(commit.author_name(), Some("Monalisa Octocat"));
+}
+
+/// The commit filter encodes refs, paths and timestamps
+#[test]
+fn test_commit_filter_query() {
+    let filter = CommitFilter {
+        branch: Some("release/1.x".to_string()),
+        path: Some("src/lib.rs".to_string()),
+        since: Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+        until: None,
+    };
+
+    assert_eq!(
+        filter.to_query(Pagination::new(Some(2), Some(50))),
+        "sha=release%2F1.x&path=src%2Flib.rs&since=2024-01-01T00%3A00%3A00Z&per_page=50&page=2"
+    );
+    assert_eq!(
+        CommitFilter::default().to_query(Pagination::default()),
+        "per_page=30&page=1"
+    );
+}
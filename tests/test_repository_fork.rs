@@ -0,0 +1,49 @@
+use github_edit::types::fork::{ForkSyncMergeType, ForkSyncResult, RepositoryFork};
+use github_edit::types::repository::RepositoryId;
+use serde_json::json;
+
+/// Forks are parsed with their upstream repository
+#[test]
+fn test_repository_fork_from_api_value() {
+    let fork = RepositoryFork::from_api_value(&json!({
+        "name": "repo",
+        "full_name": "me/repo",
+        "owner": {"login": "me"},
+        "html_url": "https://github.com/me/repo",
+        "default_branch": "main",
+        "fork": true,
+        "parent": {
+            "name": "repo",
+            "full_name": "owner/repo",
+            "owner": {"login": "owner"}
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(fork.repository_id, RepositoryId::new("me", "repo"));
+    assert_eq!(fork.default_branch, "main");
+    assert_eq!(fork.parent, Some(RepositoryId::new("owner", "repo")));
+
+    assert!(RepositoryFork::from_api_value(&json!({"name": "repo"})).is_none());
+}
+
+/// Merge upstream responses report whether the branch changed
+#[test]
+fn test_fork_sync_result_merge_type() {
+    let synced: ForkSyncResult = serde_json::from_value(json!({
+        "message": "Successfully fetched and fast-forwarded from upstream owner:main.",
+        "merge_type": "fast-forward",
+        "base_branch": "owner:main"
+    }))
+    .unwrap();
+    assert_eq!(synced.merge_type, ForkSyncMergeType::FastForward);
+    assert!(synced.updated());
+
+    let up_to_date: ForkSyncResult = serde_json::from_value(json!({
+        "message": "This branch is not behind the upstream owner:main.",
+        "merge_type": "none",
+        "base_branch": "owner:main"
+    }))
+    .unwrap();
+    assert!(!up_to_date.updated());
+}
@@ -0,0 +1,71 @@
+use github_edit::types::repository::{
+    Repository, RepositoryId, RepositoryPermission, RepositoryVisibility,
+};
+use serde_json::json;
+
+/// A repository overview is parsed from the GraphQL repository object
+#[test]
+fn test_repository_from_graphql_value() {
+    let repository = Repository::from_graphql_value(&json!({
+        "name": "repo",
+        "owner": {"login": "owner"},
+        "url": "https://github.com/owner/repo",
+        "description": "Command line tools",
+        "homepageUrl": "",
+        "defaultBranchRef": {"name": "main"},
+        "visibility": "PUBLIC",
+        "repositoryTopics": {"nodes": [{"topic": {"name": "rust"}}, {"topic": {"name": "cli"}}]},
+        "issues": {"totalCount": 12},
+        "pullRequests": {"totalCount": 3},
+        "licenseInfo": {"spdxId": "MIT", "name": "MIT License"},
+        "viewerPermission": "MAINTAIN",
+        "isArchived": false,
+        "isFork": true,
+        "stargazerCount": 42
+    }))
+    .unwrap();
+
+    assert_eq!(repository.repository_id, RepositoryId::new("owner", "repo"));
+    assert_eq!(
+        repository.description.as_deref(),
+        Some("Command line tools")
+    );
+    assert_eq!(repository.homepage, None);
+    assert_eq!(repository.default_branch.as_deref(), Some("main"));
+    assert_eq!(repository.visibility, RepositoryVisibility::Public);
+    assert_eq!(repository.topics, vec!["rust", "cli"]);
+    assert_eq!(repository.open_issue_count, 12);
+    assert_eq!(repository.open_pull_request_count, 3);
+    assert_eq!(repository.license.as_deref(), Some("MIT"));
+    assert_eq!(
+        repository.viewer_permission,
+        Some(RepositoryPermission::Maintain)
+    );
+    assert!(repository.viewer_permission.unwrap().can_write());
+    assert!(repository.fork);
+}
+
+/// Empty repositories, unrecognized licenses and unknown permissions are tolerated
+#[test]
+fn test_repository_from_graphql_value_minimal() {
+    let repository = Repository::from_graphql_value(&json!({
+        "name": "empty",
+        "owner": {"login": "owner"},
+        "url": "https://github.com/owner/empty",
+        "defaultBranchRef": null,
+        "visibility": "INTERNAL",
+        "licenseInfo": {"spdxId": "NOASSERTION", "name": "Other"},
+        "viewerPermission": null
+    }))
+    .unwrap();
+
+    assert_eq!(repository.default_branch, None);
+    assert_eq!(repository.visibility, RepositoryVisibility::Internal);
+    assert_eq!(repository.license.as_deref(), Some("Other"));
+    assert_eq!(repository.viewer_permission, None);
+    assert!(repository.topics.is_empty());
+
+    assert!(
+        Repository::from_graphql_value(&json!({"name": "repo", "visibility": "PUBLIC"})).is_none()
+    );
+}
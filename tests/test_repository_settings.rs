@@ -0,0 +1,57 @@
+use github_edit::types::repository::RepositorySettings;
+use serde_json::json;
+
+/// Only set fields are sent, and topics go through their own endpoint
+#[test]
+fn test_repository_settings_request_body() {
+    assert!(RepositorySettings::default().is_empty());
+
+    let topics_only = RepositorySettings {
+        topics: Some(vec!["rust".to_string()]),
+        ..Default::default()
+    };
+    assert!(!topics_only.is_empty());
+    assert!(!topics_only.has_repository_fields());
+    assert_eq!(topics_only.to_request_body(), json!({}));
+
+    let settings = RepositorySettings {
+        description: Some("Command line tools".to_string()),
+        topics: Some(vec![]),
+        allow_merge_commit: Some(false),
+        ..Default::default()
+    };
+    assert!(settings.has_repository_fields());
+    assert_eq!(
+        settings.to_request_body(),
+        json!({"description": "Command line tools", "allow_merge_commit": false})
+    );
+}
+
+/// Settings are read from a full repository response
+#[test]
+fn test_repository_settings_from_api_response() {
+    let settings: RepositorySettings = serde_json::from_value(json!({
+        "id": 1,
+        "name": "repo",
+        "description": null,
+        "homepage": "https://example.com",
+        "topics": ["rust", "cli"],
+        "has_issues": true,
+        "has_wiki": false,
+        "has_projects": true,
+        "default_branch": "main",
+        "allow_squash_merge": true,
+        "allow_merge_commit": false,
+        "allow_rebase_merge": true,
+        "delete_branch_on_merge": true
+    }))
+    .unwrap();
+
+    assert_eq!(settings.description, None);
+    assert_eq!(
+        settings.topics,
+        Some(vec!["rust".to_string(), "cli".to_string()])
+    );
+    assert_eq!(settings.default_branch.as_deref(), Some("main"));
+    assert_eq!(settings.allow_merge_commit, Some(false));
+}
@@ -0,0 +1,25 @@
+use github_edit::types::repository::RepositoryId;
+use github_edit::types::transfer::{RepositoryTransfer, RepositoryTransferStatus};
+use serde_json::json;
+
+/// A transfer response describes the repository under its new owner
+#[test]
+fn test_repository_transfer_from_api_value() {
+    let transfer = RepositoryTransfer::from_api_value(&json!({
+        "id": 1,
+        "name": "repo-archive",
+        "full_name": "my-org/repo-archive",
+        "owner": {"login": "my-org"},
+        "html_url": "https://github.com/my-org/repo-archive"
+    }))
+    .unwrap();
+
+    assert_eq!(
+        transfer.repository_id,
+        RepositoryId::new("my-org", "repo-archive")
+    );
+    assert_eq!(transfer.status, RepositoryTransferStatus::Pending);
+    assert!(!transfer.is_completed());
+
+    assert!(RepositoryTransfer::from_api_value(&json!({"name": "repo"})).is_none());
+}
@@ -0,0 +1,84 @@
+use github_edit::github::GitHubClient;
+use github_edit::tools::GitEditTools;
+use github_edit::tools::resources::{ResourceSubscriptions, resource_templates};
+use github_edit::types::issue::IssueId;
+use github_edit::types::pull_request::PullRequestId;
+use github_edit::types::repository::RepositoryId;
+use github_edit::types::resource::{
+    ISSUE_RESOURCE_URI_TEMPLATE, PULL_REQUEST_RESOURCE_URI_TEMPLATE, ResourceUri,
+};
+use rmcp::ServerHandler;
+
+/// Issue and pull request URIs are parsed and formatted back unchanged
+#[test]
+fn test_parse_resource_uri() {
+    let repository_id = RepositoryId::new("octocat", "hello");
+
+    let issue = ResourceUri::parse("github-edit://octocat/hello/issues/42").unwrap();
+    assert_eq!(
+        issue,
+        ResourceUri::Issue(IssueId::new(repository_id.clone(), 42))
+    );
+    assert_eq!(issue.to_string(), "github-edit://octocat/hello/issues/42");
+    assert_eq!(issue.name(), "octocat/hello#42");
+
+    let pull_request = ResourceUri::parse("github-edit://octocat/hello/pulls/7/").unwrap();
+    assert_eq!(
+        pull_request,
+        ResourceUri::PullRequest(PullRequestId::new(repository_id.clone(), 7))
+    );
+    assert_eq!(
+        pull_request.to_string(),
+        "github-edit://octocat/hello/pulls/7"
+    );
+    assert_eq!(pull_request.repository_id(), &repository_id);
+    assert_eq!(pull_request.number(), 7);
+}
+
+/// Malformed URIs are rejected with the reason
+#[test]
+fn test_parse_resource_uri_errors() {
+    let cases = [
+        ("https://github.com/octocat/hello/issues/42", "scheme"),
+        ("github-edit://octocat/hello/issues", "expected"),
+        ("github-edit://octocat/hello/issues/42/comments", "expected"),
+        ("github-edit:///hello/issues/42", "owner and repository"),
+        ("github-edit://octocat/hello/issues/abc", "bad number"),
+        (
+            "github-edit://octocat/hello/discussions/42",
+            "unknown resource kind",
+        ),
+    ];
+    for (uri, reason) in cases {
+        let error = ResourceUri::parse(uri).unwrap_err();
+        assert!(error.contains(reason), "{}: {}", uri, error);
+    }
+}
+
+/// The templates advertise the issue and pull request URIs
+#[test]
+fn test_resource_templates() {
+    let templates: Vec<String> = resource_templates()
+        .into_iter()
+        .map(|template| template.raw.uri_template)
+        .collect();
+    assert_eq!(
+        templates,
+        vec![
+            ISSUE_RESOURCE_URI_TEMPLATE,
+            PULL_REQUEST_RESOURCE_URI_TEMPLATE
+        ]
+    );
+    assert!(ResourceSubscriptions::default().resources().is_empty());
+}
+
+/// The server advertises resources with subscriptions next to its tools
+#[tokio::test]
+async fn test_server_capabilities_include_resources() {
+    let tools = GitEditTools::new(GitHubClient::new(None, None).unwrap());
+    let capabilities = tools.get_info().capabilities;
+
+    let resources = capabilities.resources.unwrap();
+    assert_eq!(resources.subscribe, Some(true));
+    assert!(capabilities.tools.is_some());
+}
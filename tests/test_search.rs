@@ -0,0 +1,132 @@
+use github_edit::types::pagination::Pagination;
+use github_edit::types::search::{
+    IssueSearchResult, IssueSearchSort, RepositorySearchResult, RepositorySearchSort, SearchOrder,
+    UserSearchResult, UserSearchSort, search_query_string,
+};
+use serde_json::json;
+
+fn search_item_value(pull_request: bool) -> serde_json::Value {
+    let mut value = json!({
+        "number": 1347,
+        "title": "Found a bug",
+        "state": "open",
+        "html_url": "https://github.com/octocat/Hello-World/issues/1347",
+        "repository_url": "https://api.github.com/repos/octocat/Hello-World",
+        "user": { "login": "octocat" },
+        "labels": [{ "name": "bug" }, { "name": "good first issue" }],
+        "comments": 3,
+        "created_at": "2024-01-05T10:00:00Z",
+        "updated_at": "2024-02-01T08:30:00Z",
+        "score": 1.0
+    });
+    if pull_request {
+        value["pull_request"] = json!({
+            "url": "https://api.github.com/repos/octocat/Hello-World/pulls/1347"
+        });
+    }
+    value
+}
+
+/// Issue search results deserialize from the REST API item format
+#[test]
+fn test_issue_search_result_deserialize() {
+    let item: IssueSearchResult = serde_json::from_value(search_item_value(false)).unwrap();
+
+    assert_eq!(item.number, 1347);
+    assert_eq!(item.repository(), "octocat/Hello-World");
+    assert_eq!(item.author(), Some("octocat"));
+    assert_eq!(item.label_names(), vec!["bug", "good first issue"]);
+    assert!(!item.is_pull_request());
+}
+
+/// Pull requests are recognized by their `pull_request` object
+#[test]
+fn test_issue_search_result_pull_request() {
+    let item: IssueSearchResult = serde_json::from_value(search_item_value(true)).unwrap();
+
+    assert!(item.is_pull_request());
+}
+
+/// The query is URL-encoded and sort options are only sent when given
+#[test]
+fn test_search_query_string() {
+    assert_eq!(
+        search_query_string(
+            "repo:owner/repo label:bug is:open created:>2024-01-01",
+            Some(IssueSearchSort::Updated),
+            Some(SearchOrder::Asc),
+            Pagination::new(Some(2), Some(50)),
+        ),
+        "q=repo%3Aowner%2Frepo%20label%3Abug%20is%3Aopen%20created%3A%3E2024-01-01&sort=updated&order=asc&per_page=50&page=2"
+    );
+    assert_eq!(
+        search_query_string::<IssueSearchSort>("is:pr", None, None, Pagination::default()),
+        "q=is%3Apr&per_page=30&page=1"
+    );
+}
+
+/// Sort and order values parse case-insensitively
+#[test]
+fn test_search_sort_and_order_parse() {
+    assert_eq!(
+        "Interactions".parse::<IssueSearchSort>().unwrap(),
+        IssueSearchSort::Interactions
+    );
+    assert_eq!("DESC".parse::<SearchOrder>().unwrap(), SearchOrder::Desc);
+    assert!("best-match".parse::<IssueSearchSort>().is_err());
+}
+
+/// Repository search results deserialize from the REST API item format
+#[test]
+fn test_repository_search_result_deserialize() {
+    let repository: RepositorySearchResult = serde_json::from_value(json!({
+        "id": 1296269,
+        "full_name": "octocat/Hello-World",
+        "html_url": "https://github.com/octocat/Hello-World",
+        "description": null,
+        "private": false,
+        "fork": true,
+        "language": "Rust",
+        "stargazers_count": 80,
+        "forks_count": 9,
+        "open_issues_count": 2,
+        "updated_at": "2024-02-01T08:30:00Z"
+    }))
+    .unwrap();
+
+    assert_eq!(repository.full_name, "octocat/Hello-World");
+    assert!(repository.fork);
+    assert!(!repository.archived);
+    assert_eq!(repository.description, None);
+}
+
+/// User search results distinguish users from organizations
+#[test]
+fn test_user_search_result_deserialize() {
+    let user: UserSearchResult = serde_json::from_value(json!({
+        "login": "github",
+        "id": 9919,
+        "html_url": "https://github.com/github",
+        "type": "Organization"
+    }))
+    .unwrap();
+
+    assert_eq!(user.login, "github");
+    assert!(user.is_organization());
+}
+
+/// Repository sort values use the hyphenated API names
+#[test]
+fn test_repository_search_sort_names() {
+    assert_eq!(
+        RepositorySearchSort::HelpWantedIssues.to_string(),
+        "help-wanted-issues"
+    );
+    assert_eq!(
+        "help-wanted-issues"
+            .parse::<RepositorySearchSort>()
+            .unwrap(),
+        RepositorySearchSort::HelpWantedIssues
+    );
+    assert_eq!(UserSearchSort::Joined.to_string(), "joined");
+}
@@ -0,0 +1,121 @@
+use github_edit::tools::GitEditTools;
+use github_edit::tools::session::{
+    SET_DEFAULT_REPOSITORY_TOOL, SessionDefaults, requires_repository_url,
+};
+use rmcp::model::{CallToolRequestParam, JsonObject, Tool};
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+fn issue_tool(required: Value) -> Tool {
+    let schema: JsonObject = serde_json::from_value(json!({
+        "type": "object",
+        "properties": {
+            "repository_url": { "type": "string" },
+            "issue_number": { "type": "integer" }
+        },
+        "required": required
+    }))
+    .unwrap();
+    Tool::new("get_issue", "Get an issue", Arc::new(schema))
+}
+
+fn request(arguments: Value) -> CallToolRequestParam {
+    CallToolRequestParam {
+        name: "get_issue".into(),
+        arguments: serde_json::from_value(arguments).unwrap(),
+    }
+}
+
+/// The default repository is filled into calls omitting repository_url,
+/// and calls cannot omit it before a default is set
+#[test]
+fn test_default_repository_is_filled_in() {
+    let session = SessionDefaults::default();
+    let tool = issue_tool(json!(["repository_url", "issue_number"]));
+
+    let mut omitted = request(json!({ "issue_number": 1 }));
+    let error = session
+        .fill_default_repository(&tool, &mut omitted)
+        .unwrap_err();
+    assert!(error.message.contains(SET_DEFAULT_REPOSITORY_TOOL));
+
+    let result = session
+        .set_default_repository_tool("octocat/hello".to_string())
+        .unwrap();
+    assert_ne!(result.is_error, Some(true));
+
+    session
+        .fill_default_repository(&tool, &mut omitted)
+        .unwrap();
+    assert_eq!(
+        omitted.arguments.unwrap()["repository_url"],
+        "https://github.com/octocat/hello"
+    );
+
+    let mut given = request(json!({
+        "repository_url": "octocat/other",
+        "issue_number": 1
+    }));
+    session.fill_default_repository(&tool, &mut given).unwrap();
+    assert_eq!(given.arguments.unwrap()["repository_url"], "octocat/other");
+
+    let mut optional = request(json!({ "issue_number": 1 }));
+    session
+        .fill_default_repository(&issue_tool(json!(["issue_number"])), &mut optional)
+        .unwrap();
+    assert!(!optional.arguments.unwrap().contains_key("repository_url"));
+}
+
+/// Setting a default reports the previous one, and invalid repositories
+/// are rejected
+#[test]
+fn test_set_default_repository() {
+    let session = SessionDefaults::default();
+    session
+        .set_default_repository_tool("https://github.com/octocat/hello".to_string())
+        .unwrap();
+    let result = session
+        .set_default_repository_tool("octocat/world".to_string())
+        .unwrap();
+    let data: Value = serde_json::from_str(&result.content[1].as_text().unwrap().text).unwrap();
+    assert_eq!(
+        data["default_repository"],
+        "https://github.com/octocat/world"
+    );
+    assert_eq!(
+        data["previous_default_repository"],
+        "https://github.com/octocat/hello"
+    );
+
+    assert!(
+        session
+            .set_default_repository_tool("not a repository".to_string())
+            .is_err()
+    );
+    assert_eq!(
+        session.default_repository().unwrap().url(),
+        "https://github.com/octocat/world"
+    );
+}
+
+/// repository_url is optional in every listed tool
+#[test]
+fn test_listed_tools_make_repository_url_optional() {
+    let tools = GitEditTools::listed_tools();
+    assert!(tools.iter().all(|tool| !requires_repository_url(tool)));
+
+    let get_issue = tools.iter().find(|tool| tool.name == "get_issue").unwrap();
+    let description = get_issue.input_schema["properties"]["repository_url"]["description"]
+        .as_str()
+        .unwrap();
+    assert!(
+        description.contains(SET_DEFAULT_REPOSITORY_TOOL),
+        "{}",
+        description
+    );
+    assert!(
+        tools
+            .iter()
+            .any(|tool| tool.name == SET_DEFAULT_REPOSITORY_TOOL)
+    );
+}
@@ -0,0 +1,69 @@
+use github_edit::github::GitHubClient;
+use github_edit::tools::tool_definition::AuthTools;
+use github_edit::types::rate_limit::RateLimit;
+use rmcp::model::{CallToolResult, RawContent};
+
+const RATE_LIMIT_RESPONSE: &str = r#"{
+    "resources": {
+        "core": {"limit": 5000, "used": 10, "remaining": 4990, "reset": 1760000600},
+        "search": {"limit": 30, "used": 30, "remaining": 0, "reset": 1760000060},
+        "graphql": {"limit": 5000, "used": 0, "remaining": 5000, "reset": 1760003600}
+    }
+}"#;
+
+fn texts(result: &CallToolResult) -> Vec<String> {
+    result
+        .content
+        .iter()
+        .map(|content| match &content.raw {
+            RawContent::Text(text) => text.text.clone(),
+            other => panic!("unexpected content: {:?}", other),
+        })
+        .collect()
+}
+
+/// Successful tool results carry the text summary followed by the typed
+/// result as JSON
+#[tokio::test]
+async fn test_success_result_includes_json() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/rate_limit")
+        .with_header("content-type", "application/json")
+        .with_body(RATE_LIMIT_RESPONSE)
+        .create_async()
+        .await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+
+    let result = AuthTools::get_rate_limit(&client, None).await.unwrap();
+
+    assert_eq!(result.is_error, Some(false));
+    let texts = texts(&result);
+    assert_eq!(texts.len(), 2);
+    assert!(texts[0].contains("4990"), "{}", texts[0]);
+    let rate_limit: RateLimit = serde_json::from_str(&texts[1]).unwrap();
+    assert_eq!(rate_limit.core.remaining, 4990);
+    assert!(rate_limit.search.is_exhausted());
+}
+
+/// Failed tool results carry the error message only
+#[tokio::test]
+async fn test_error_result_is_text_only() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/rate_limit")
+        .with_status(401)
+        .with_body(r#"{"message": "Bad credentials"}"#)
+        .create_async()
+        .await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+
+    let result = AuthTools::get_rate_limit(&client, None).await.unwrap();
+
+    assert_eq!(result.is_error, Some(true));
+    let texts = texts(&result);
+    assert_eq!(texts.len(), 1);
+    assert!(texts[0].contains("Bad credentials"), "{}", texts[0]);
+}
@@ -0,0 +1,141 @@
+use github_edit::types::template::{TemplateKind, parse_template_vars, render_template};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::process::Command;
+
+fn vars(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+    pairs
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+/// Placeholders are filled and the front matter is dropped
+#[test]
+fn test_render_template() {
+    let template = "---\nname: Bug report\nabout: Report a bug\nlabels: bug\n---\n\n\
+                    ## Version\n{{version}}\n\n## Steps\n{{ steps }}\n\n`{{ }}` stays\n";
+    let rendered = render_template(
+        template,
+        &vars(&[
+            ("version", "1.2.0"),
+            ("steps", "1. Log in"),
+            ("unused", "x"),
+        ]),
+    )
+    .unwrap();
+    assert_eq!(
+        rendered,
+        "## Version\n1.2.0\n\n## Steps\n1. Log in\n\n`{{ }}` stays\n"
+    );
+
+    // An unterminated front matter is kept as part of the body
+    assert_eq!(
+        render_template("---\nnot closed {{a}}", &vars(&[("a", "b")])).unwrap(),
+        "---\nnot closed b"
+    );
+}
+
+/// Placeholders without a variable are all reported
+#[test]
+fn test_render_template_missing_vars() {
+    let error = render_template("{{ version }} {{steps}} {{version}}", &vars(&[])).unwrap_err();
+    assert_eq!(error, "Template variables not given: steps, version");
+}
+
+#[test]
+fn test_parse_template_vars() {
+    let parsed =
+        parse_template_vars(&["version=1.2.0".to_string(), "query=a=b".to_string()]).unwrap();
+    assert_eq!(parsed, vars(&[("version", "1.2.0"), ("query", "a=b")]));
+
+    let error = parse_template_vars(&["version".to_string()]).unwrap_err();
+    assert_eq!(
+        error,
+        "Invalid template variable 'version'. Expected key=value"
+    );
+}
+
+/// Pull requests fall back to the issue templates
+#[test]
+fn test_template_paths() {
+    assert_eq!(
+        TemplateKind::Issue.template_paths("bug"),
+        vec![".github/ISSUE_TEMPLATE/bug.md"]
+    );
+    assert_eq!(
+        TemplateKind::PullRequest.template_paths("feature.md"),
+        vec![
+            ".github/PULL_REQUEST_TEMPLATE/feature.md",
+            ".github/ISSUE_TEMPLATE/feature.md"
+        ]
+    );
+}
+
+/// `issue create --template` reads the template from the template directory
+/// of the current directory
+#[tokio::test]
+async fn test_cli_issue_create_from_local_template() {
+    let server = mockito::Server::new_async().await;
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("config.toml"),
+        format!(
+            "default_profile = \"test\"\n\n[profiles.test]\napi_base_url = \"{}\"\ntoken = \"ghp_test\"\n",
+            server.url()
+        ),
+    )
+    .unwrap();
+    let template_dir = dir.path().join(".github/ISSUE_TEMPLATE");
+    std::fs::create_dir_all(&template_dir).unwrap();
+    std::fs::write(
+        template_dir.join("bug.md"),
+        "---\nname: Bug report\n---\nVersion: {{ version }}\n",
+    )
+    .unwrap();
+
+    let cli = |args: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_github-edit-cli"))
+            .current_dir(dir.path())
+            .env("GITHUB_EDIT_CONFIG_DIR", dir.path())
+            .env_remove("GITHUB_EDIT_GITHUB_TOKEN")
+            .env_remove("GITHUB_EDIT_PROFILE")
+            .env_remove("RUST_LOG")
+            .args(args)
+            .output()
+            .unwrap()
+    };
+    let create = [
+        "--dry-run",
+        "--output",
+        "json",
+        "issue",
+        "create",
+        "-r",
+        "https://github.com/octocat/hello",
+        "-t",
+        "Login fails",
+        "--template",
+        "bug",
+    ];
+
+    let output = cli(&[&create[..], &["--var", "version=1.2.0"]].concat());
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let result: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        result["planned_requests"][0]["body"]["body"],
+        "Version: 1.2.0\n"
+    );
+
+    let output = cli(&create);
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("Template variables not given: version")
+    );
+}
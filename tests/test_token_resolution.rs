@@ -0,0 +1,59 @@
+//! Order of the token sources, with stub `gh` and `secret-tool` commands
+//!
+//! These tests change `PATH` and the token variables, so they live in their
+//! own test binary and run as a single test.
+#![cfg(target_os = "linux")]
+
+use github_edit::auth::{ACTIONS_ENV_VAR, TOKEN_ENV_VAR, TokenSource, resolve_token};
+use github_edit::config::{AuthConfig, CredentialStoreKind};
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+
+fn write_script(dir: &Path, name: &str, body: &str) {
+    let path = dir.join(name);
+    std::fs::write(&path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+fn source(config: &AuthConfig) -> Option<TokenSource> {
+    resolve_token(config, None)
+        .unwrap()
+        .map(|resolved| resolved.source)
+}
+
+/// The GitHub CLI is asked before the keyring, which is used when gh is
+/// disabled or has no token
+#[test]
+fn test_gh_cli_before_keyring() {
+    let bin = tempfile::tempdir().unwrap();
+    write_script(bin.path(), "gh", "echo gho_from_gh");
+    write_script(bin.path(), "secret-tool", "echo ghp_from_keyring");
+
+    // SAFETY: this is the only test of this binary
+    unsafe {
+        std::env::set_var("PATH", bin.path());
+        std::env::remove_var(TOKEN_ENV_VAR);
+        std::env::remove_var(ACTIONS_ENV_VAR);
+    }
+
+    let mut config = AuthConfig {
+        credential_store: CredentialStoreKind::Keyring,
+        ..AuthConfig::default()
+    };
+    let resolved = resolve_token(&config, None).unwrap().unwrap();
+    assert_eq!(resolved.source, TokenSource::GhCli);
+    assert_eq!(resolved.token, "gho_from_gh");
+
+    config.gh_cli = false;
+    let resolved = resolve_token(&config, None).unwrap().unwrap();
+    assert_eq!(resolved.source, TokenSource::Keyring);
+    assert_eq!(resolved.token, "ghp_from_keyring");
+
+    // gh without a token for the host falls through to the keyring
+    config.gh_cli = true;
+    write_script(bin.path(), "gh", "exit 1");
+    assert_eq!(source(&config), Some(TokenSource::Keyring));
+
+    config.credential_store = CredentialStoreKind::Env;
+    assert_eq!(source(&config), None);
+}
@@ -0,0 +1,54 @@
+use github_edit::tools::GitEditTools;
+use github_edit::tools::annotations::{ToolEffect, tool_annotations, tool_effect};
+use github_edit::tools::dry_run::DRY_RUN_ARGUMENT;
+
+/// Every listed tool is classified, and only the tools that are not read
+/// only accept dry_run
+#[test]
+fn test_every_listed_tool_is_annotated() {
+    let tools = GitEditTools::listed_tools();
+    assert!(tools.len() > 100);
+    for tool in tools {
+        let annotations = tool_annotations(&tool.name)
+            .unwrap_or_else(|| panic!("{} has no annotations", tool.name));
+        let accepts_dry_run = tool
+            .input_schema
+            .get("properties")
+            .and_then(|properties| properties.as_object())
+            .is_some_and(|properties| properties.contains_key(DRY_RUN_ARGUMENT));
+        assert_eq!(
+            accepts_dry_run, !annotations.read_only_hint,
+            "{} dry_run argument",
+            tool.name
+        );
+    }
+}
+
+/// Annotations follow the effect of the tool and use the names of the
+/// specification
+#[test]
+fn test_tool_annotation_hints() {
+    let delete = tool_annotations("delete_issue").unwrap();
+    assert!(!delete.read_only_hint);
+    assert!(delete.destructive_hint);
+
+    let get = tool_annotations("get_issue").unwrap();
+    assert!(get.read_only_hint);
+    assert!(get.open_world_hint);
+
+    let create = tool_annotations("create_issue").unwrap();
+    assert!(!create.destructive_hint);
+    assert!(!create.idempotent_hint);
+
+    assert_eq!(tool_effect("edit_issue_title"), Some(ToolEffect::Update));
+    assert!(
+        !tool_annotations("get_response_continuation")
+            .unwrap()
+            .open_world_hint
+    );
+    assert!(tool_annotations("no_such_tool").is_none());
+
+    let json = serde_json::to_value(create).unwrap();
+    assert_eq!(json["readOnlyHint"], false);
+    assert_eq!(json["idempotentHint"], false);
+}
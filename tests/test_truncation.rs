@@ -0,0 +1,117 @@
+use github_edit::tools::truncation::{
+    GET_RESPONSE_CONTINUATION_TOOL, ResponseLimiter, get_response_continuation_tool,
+};
+use rmcp::model::{CallToolResult, Content, JsonObject};
+
+fn texts(result: &CallToolResult) -> Vec<String> {
+    result
+        .content
+        .iter()
+        .map(|content| content.as_text().unwrap().text.clone())
+        .collect()
+}
+
+/// Returns the continuation token named by the truncation marker
+fn continuation_token(result: &CallToolResult) -> Option<String> {
+    let marker = texts(result).pop()?;
+    let rest = marker
+        .strip_prefix("[Truncated ")?
+        .split("continuation_token \"")
+        .nth(1)?;
+    Some(rest.split('"').next()?.to_string())
+}
+
+fn arguments(token: &str) -> Option<JsonObject> {
+    serde_json::from_value(serde_json::json!({ "continuation_token": token })).unwrap()
+}
+
+/// Responses within the limit are returned unchanged
+#[test]
+fn test_small_response_is_unchanged() {
+    let limiter = ResponseLimiter::new(100);
+    let result = CallToolResult::success(vec![Content::text("Issue #1"), Content::text("{}")]);
+    assert_eq!(limiter.limit("get_issue", result.clone()), result);
+}
+
+/// Long responses are cut at a line break and end with a marker; following
+/// the continuation tokens returns the whole response
+#[test]
+fn test_truncated_response_continues() {
+    let limiter = ResponseLimiter::new(100);
+    let lines: Vec<String> = (0..30).map(|i| format!("line {:02}", i)).collect();
+    let json = serde_json::to_string_pretty(&lines).unwrap();
+    let result = CallToolResult::success(vec![
+        Content::text("Issue #1 with 30 lines"),
+        Content::text(json.clone()),
+    ]);
+
+    let mut part = limiter.limit("get_issue", result);
+    let first = texts(&part);
+    assert_eq!(first[0], "Issue #1 with 30 lines");
+    assert!(first[1].ends_with('\n'), "{}", first[1]);
+    let marker = first.last().unwrap();
+    assert!(
+        marker.starts_with("[Truncated get_issue response: characters 1-"),
+        "{}",
+        marker
+    );
+    assert!(marker.contains(GET_RESPONSE_CONTINUATION_TOOL));
+
+    let mut collected = first[1].clone();
+    let mut parts = 1;
+    while let Some(token) = continuation_token(&part) {
+        part = limiter.continue_response(arguments(&token)).unwrap();
+        assert_ne!(part.is_error, Some(true));
+        let mut part_texts = texts(&part);
+        if continuation_token(&part).is_some() {
+            part_texts.pop();
+        }
+        let part_chars: usize = part_texts.iter().map(|text| text.chars().count()).sum();
+        assert!(part_chars <= limiter.max_chars());
+        collected.push_str(&part_texts.concat());
+        parts += 1;
+    }
+    assert_eq!(collected, json);
+    assert!(parts > 2);
+}
+
+/// Tokens are single use, and unknown tokens are rejected
+#[test]
+fn test_continuation_token_is_single_use() {
+    let limiter = ResponseLimiter::new(10);
+    let result = CallToolResult::error(vec![Content::text("Failed: ".repeat(3))]);
+    let part = limiter.limit("update_issue_state", result);
+    assert_eq!(part.is_error, Some(true));
+    let token = continuation_token(&part).unwrap();
+
+    let rest = limiter.continue_response(arguments(&token)).unwrap();
+    assert_eq!(rest.is_error, Some(true));
+    assert!(continuation_token(&rest).is_some());
+    assert!(limiter.continue_response(arguments(&token)).is_err());
+    assert!(limiter.continue_response(arguments("made-up")).is_err());
+    assert!(limiter.continue_response(None).is_err());
+}
+
+/// Text is never split inside a multi-byte character
+#[test]
+fn test_truncation_keeps_characters_whole() {
+    let limiter = ResponseLimiter::new(5);
+    let part = limiter.limit(
+        "get_issue",
+        CallToolResult::success(vec![Content::text("日本語のイシュー本文")]),
+    );
+    assert_eq!(texts(&part)[0], "日本語のイ");
+}
+
+/// The listed tool describes its argument
+#[test]
+fn test_continuation_tool_schema() {
+    let tool = get_response_continuation_tool();
+    assert_eq!(tool.name, GET_RESPONSE_CONTINUATION_TOOL);
+    assert!(
+        tool.input_schema["properties"]
+            .as_object()
+            .unwrap()
+            .contains_key("continuation_token")
+    );
+}
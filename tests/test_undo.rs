@@ -0,0 +1,377 @@
+use github_edit::audit::{AuditLog, AuditQuery};
+use github_edit::github::GitHubClient;
+use github_edit::tools::audit::ToolCallAuditor;
+use github_edit::tools::undo::{
+    UNDO_LAST_OPERATION_TOOL, find_operation, irreversible_reason, prepare_undo,
+};
+use github_edit::types::audit::{AuditEntry, AuditSource, UndoOperation};
+use github_edit::types::repository::RepositoryId;
+use rmcp::model::{CallToolRequestParam, CallToolResult, Content, JsonObject};
+use serde_json::{Value, json};
+use std::sync::{Arc, Mutex};
+
+fn user_json(login: &str) -> Value {
+    let url = format!("https://api.github.com/users/{}", login);
+    json!({
+        "login": login,
+        "id": 1,
+        "node_id": "MDQ6VXNlcjE=",
+        "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+        "gravatar_id": "",
+        "url": url,
+        "html_url": format!("https://github.com/{}", login),
+        "followers_url": format!("{}/followers", url),
+        "following_url": format!("{}/following", url),
+        "gists_url": format!("{}/gists", url),
+        "starred_url": format!("{}/starred", url),
+        "subscriptions_url": format!("{}/subscriptions", url),
+        "organizations_url": format!("{}/orgs", url),
+        "repos_url": format!("{}/repos", url),
+        "events_url": format!("{}/events", url),
+        "received_events_url": format!("{}/received_events", url),
+        "type": "User",
+        "site_admin": false,
+        "name": null,
+        "patch_url": null
+    })
+}
+
+fn issue_json(number: u64) -> Value {
+    let url = format!(
+        "https://api.github.com/repos/octocat/hello/issues/{}",
+        number
+    );
+    json!({
+        "id": 1000 + number,
+        "node_id": "I_kwDOA",
+        "url": url,
+        "repository_url": "https://api.github.com/repos/octocat/hello",
+        "labels_url": format!("{}/labels{{/name}}", url),
+        "comments_url": format!("{}/comments", url),
+        "events_url": format!("{}/events", url),
+        "html_url": format!("https://github.com/octocat/hello/issues/{}", number),
+        "number": number,
+        "state": "open",
+        "state_reason": null,
+        "title": "Crash when saving drafts",
+        "body": "Steps to reproduce",
+        "user": user_json("octocat"),
+        "labels": [{
+            "id": 1,
+            "node_id": "LA_kwDOA",
+            "url": "https://api.github.com/repos/octocat/hello/labels/bug",
+            "name": "bug",
+            "color": "d73a4a",
+            "default": true,
+            "description": null
+        }],
+        "assignees": [],
+        "author_association": "OWNER",
+        "locked": false,
+        "comments": 0,
+        "created_at": "2024-01-02T03:04:05Z",
+        "updated_at": "2024-01-03T03:04:05Z"
+    })
+}
+
+async fn issue_server() -> mockito::ServerGuard {
+    let mut server = mockito::Server::new_async().await;
+    server
+        .mock("GET", "/repos/octocat/hello/issues/7")
+        .with_header("content-type", "application/json")
+        .with_body(issue_json(7).to_string())
+        .create_async()
+        .await;
+    server
+        .mock("GET", "/repos/octocat/hello/issues/7/comments")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .create_async()
+        .await;
+    let mut closed = issue_json(8);
+    closed["state"] = json!("closed");
+    closed["state_reason"] = json!("not_planned");
+    closed["closed_at"] = json!("2024-01-03T03:04:05Z");
+    server
+        .mock("GET", "/repos/octocat/hello/issues/8")
+        .with_header("content-type", "application/json")
+        .with_body(closed.to_string())
+        .create_async()
+        .await;
+    server
+        .mock("GET", "/repos/octocat/hello/issues/8/comments")
+        .match_query(mockito::Matcher::Any)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .create_async()
+        .await;
+    server
+        .mock("GET", "/user")
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"login": "octocat", "id": 1, "name": null, "type": "User", "html_url": "https://github.com/octocat"}"#)
+        .create_async()
+        .await;
+    server
+}
+
+fn arguments(value: Value) -> JsonObject {
+    value.as_object().unwrap().clone()
+}
+
+fn entry(tool: &str, trace_id: &str, success: bool, undo: Option<UndoOperation>) -> AuditEntry {
+    AuditEntry {
+        timestamp: chrono::Utc::now(),
+        source: AuditSource::Mcp,
+        actor: Some("octocat".to_string()),
+        client: Some("test-client".to_string()),
+        tool: tool.to_string(),
+        parameters: json!({}),
+        success,
+        error: None,
+        trace_id: Some(trace_id.to_string()),
+        requests: Vec::new(),
+        undo,
+        undoes: None,
+    }
+}
+
+fn title_undo() -> UndoOperation {
+    UndoOperation {
+        tool: "edit_issue_title".to_string(),
+        arguments: json!({
+            "repository_url": "https://github.com/octocat/hello",
+            "issue_number": 7,
+            "title": "Crash when saving drafts"
+        }),
+    }
+}
+
+/// The reversing calls restore the state read before the call, covering
+/// only the labels the call actually changes
+#[tokio::test]
+async fn test_prepare_undo_reads_previous_state() {
+    let server = issue_server().await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+
+    let removed = prepare_undo(
+        &client,
+        "remove_labels_from_issue",
+        Some(&arguments(json!({
+            "repository_url": "octocat/hello",
+            "issue_number": 7,
+            "labels": ["BUG", "wontfix"]
+        }))),
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(removed.tool, "add_labels_to_issue");
+    assert_eq!(
+        removed.arguments,
+        json!({
+            "repository_url": "https://github.com/octocat/hello",
+            "issue_number": 7,
+            "labels": ["bug"]
+        })
+    );
+
+    let added = prepare_undo(
+        &client,
+        "add_labels_to_issue",
+        Some(&arguments(
+            json!({ "issue_number": 7, "labels": ["bug", "docs"] }),
+        )),
+        Some(RepositoryId::new("octocat", "hello")),
+    )
+    .await
+    .unwrap();
+    assert_eq!(added.tool, "remove_labels_from_issue");
+    assert_eq!(added.arguments["labels"], json!(["docs"]));
+
+    let title = prepare_undo(
+        &client,
+        "edit_issue_title",
+        Some(&arguments(json!({
+            "repository_url": "octocat/hello",
+            "issue_number": 7,
+            "title": "Crash on save"
+        }))),
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(title.arguments["title"], "Crash when saving drafts");
+
+    let closed = prepare_undo(
+        &client,
+        "update_issue_state",
+        Some(&arguments(json!({
+            "repository_url": "octocat/hello",
+            "issue_number": 7,
+            "state": "closed"
+        }))),
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(closed.arguments["state"], "open");
+    assert_eq!(closed.arguments["state_reason"], "reopened");
+
+    // Reopened issues are closed again for the reason they were closed with
+    let reopened = prepare_undo(
+        &client,
+        "update_issue_state",
+        Some(&arguments(json!({
+            "repository_url": "octocat/hello",
+            "issue_number": 8,
+            "state": "open"
+        }))),
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(reopened.arguments["state"], "closed");
+    assert_eq!(reopened.arguments["state_reason"], "not_planned");
+
+    // Calls that change nothing, dry runs and other tools are not reversible
+    for (tool, call) in [
+        (
+            "update_issue_state",
+            json!({ "repository_url": "octocat/hello", "issue_number": 7, "state": "open" }),
+        ),
+        (
+            "add_labels_to_issue",
+            json!({ "repository_url": "octocat/hello", "issue_number": 7, "labels": ["bug"] }),
+        ),
+        (
+            "edit_issue_title",
+            json!({ "repository_url": "octocat/hello", "issue_number": 7, "title": "x", "dry_run": true }),
+        ),
+        (
+            "create_issue",
+            json!({ "repository_url": "octocat/hello", "title": "x" }),
+        ),
+    ] {
+        assert!(
+            prepare_undo(&client, tool, Some(&arguments(call)), None)
+                .await
+                .is_none(),
+            "{} should not be reversible",
+            tool
+        );
+    }
+}
+
+/// The newest successful operation that is not undone yet is chosen
+#[test]
+fn test_find_operation() {
+    let mut undo = entry(UNDO_LAST_OPERATION_TOOL, "t4", true, None);
+    undo.undoes = Some("t3".to_string());
+    let entries = vec![
+        entry("edit_issue_title", "t1", true, Some(title_undo())),
+        entry("create_issue", "t2", true, None),
+        entry("edit_issue_body", "t3", true, None),
+        undo,
+        entry("add_labels_to_issue", "t5", false, None),
+    ];
+
+    assert_eq!(find_operation(&entries, None).unwrap().tool, "create_issue");
+    assert_eq!(
+        find_operation(&entries, Some("t1")).unwrap().tool,
+        "edit_issue_title"
+    );
+    assert!(
+        find_operation(&entries, Some("t3"))
+            .unwrap_err()
+            .contains("already undone")
+    );
+    assert!(
+        find_operation(&entries, Some("t5"))
+            .unwrap_err()
+            .contains("failed")
+    );
+    assert!(find_operation(&entries, Some("t9")).is_err());
+    assert!(find_operation(&entries[3..], None).is_err());
+
+    assert!(irreversible_reason(&entries[1]).contains("created something new"));
+    assert!(
+        irreversible_reason(&entry("delete_issue", "t6", true, None))
+            .contains("cannot be restored")
+    );
+}
+
+/// The undo runs the recorded reversing call once, a dry run records
+/// nothing, and operations without a reversing call are reported as
+/// irreversible
+#[tokio::test]
+async fn test_undo_last_operation() {
+    let server = issue_server().await;
+    let client =
+        GitHubClient::with_api_base_url(Some("ghp_test".to_string()), None, &server.url()).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let log = AuditLog::new(dir.path().join("audit.jsonl"));
+    log.append(&entry("edit_issue_title", "t1", true, Some(title_undo())))
+        .unwrap();
+    log.append(&entry("create_issue", "t2", true, None))
+        .unwrap();
+    let auditor = ToolCallAuditor::new(log.clone());
+    let dispatched: Arc<Mutex<Vec<CallToolRequestParam>>> = Arc::default();
+    let dispatch = |request: CallToolRequestParam| {
+        let dispatched = dispatched.clone();
+        async move {
+            dispatched.lock().unwrap().push(request);
+            Ok(CallToolResult::success(vec![Content::text("Updated")]))
+        }
+    };
+
+    let irreversible = auditor
+        .undo_last_operation(&client, None, None, false, dispatch)
+        .await
+        .unwrap();
+    assert_eq!(irreversible.is_error, Some(true));
+    let text = &irreversible.content[0].as_text().unwrap().text;
+    assert!(text.starts_with("Cannot undo create_issue (trace ID t2)"));
+    assert!(dispatched.lock().unwrap().is_empty());
+
+    let args = arguments(json!({ "trace_id": "t1" }));
+    let planned = auditor
+        .undo_last_operation(&client, Some(args.clone()), None, true, dispatch)
+        .await
+        .unwrap();
+    let text = &planned.content[0].as_text().unwrap().text;
+    assert_eq!(
+        text,
+        "Would undo edit_issue_title (trace ID t1) with edit_issue_title"
+    );
+    assert_eq!(
+        dispatched.lock().unwrap().remove(0).arguments.unwrap()["dry_run"],
+        true
+    );
+    assert_eq!(log.read(&AuditQuery::default()).unwrap().len(), 2);
+
+    let undone = auditor
+        .undo_last_operation(&client, Some(args.clone()), None, false, dispatch)
+        .await
+        .unwrap();
+    assert_eq!(undone.is_error, Some(false));
+    {
+        let dispatched = dispatched.lock().unwrap();
+        assert_eq!(dispatched.len(), 1);
+        assert_eq!(dispatched[0].name, "edit_issue_title");
+        assert_eq!(
+            dispatched[0].arguments.as_ref().unwrap()["title"],
+            "Crash when saving drafts"
+        );
+    }
+
+    let entries = log.read(&AuditQuery::default()).unwrap();
+    assert_eq!(entries[2].tool, UNDO_LAST_OPERATION_TOOL);
+    assert_eq!(entries[2].undoes.as_deref(), Some("t1"));
+    let again = auditor
+        .undo_last_operation(&client, Some(args), None, false, dispatch)
+        .await
+        .unwrap_err();
+    assert!(again.message.contains("already undone"));
+}
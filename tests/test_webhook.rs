@@ -0,0 +1,194 @@
+use github_edit::config::{DEFAULT_WEBHOOK_ADDRESS, WebhookConfig};
+use github_edit::github::webhook::{parse_event, sign, verify_signature};
+use github_edit::tools::logging::ToolCallLogger;
+use github_edit::tools::resources::ResourceSubscriptions;
+use github_edit::tools::webhooks::{WEBHOOK_LOGGER_NAME, webhook_notifications};
+use github_edit::transport::webhook::{WEBHOOK_PATH, WebhookListener, webhook_channel};
+use rmcp::model::LoggingLevel;
+use serde_json::json;
+use tokio_util::sync::CancellationToken;
+
+const SECRET: &str = "It's a Secret to Everybody";
+
+fn issue_opened() -> Vec<u8> {
+    json!({
+        "action": "opened",
+        "issue": { "number": 42, "title": "Crash on save" },
+        "repository": { "full_name": "octocat/hello" },
+        "sender": { "login": "hubot" }
+    })
+    .to_string()
+    .into_bytes()
+}
+
+/// Signatures are HMAC-SHA256 of the body with the secret, as GitHub sends
+/// them
+#[test]
+fn test_verify_signature() {
+    // Example of the GitHub documentation on validating webhook deliveries
+    let signature = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+    assert_eq!(sign(SECRET, b"Hello, World!"), signature);
+    assert!(verify_signature(SECRET, b"Hello, World!", signature));
+
+    assert!(!verify_signature(
+        "other secret",
+        b"Hello, World!",
+        signature
+    ));
+    assert!(!verify_signature(SECRET, b"Hello, World?", signature));
+    assert!(!verify_signature(
+        SECRET,
+        b"Hello, World!",
+        signature.trim_start_matches("sha256=")
+    ));
+    assert!(!verify_signature(SECRET, b"Hello, World!", "sha256=zz"));
+}
+
+/// Events name their issue or pull request by resource URI and are
+/// summarized in one line
+#[test]
+fn test_parse_event() {
+    let opened = parse_event("issues", Some("d-1"), &issue_opened()).unwrap();
+    assert_eq!(opened.action.as_deref(), Some("opened"));
+    assert_eq!(opened.delivery_id.as_deref(), Some("d-1"));
+    assert_eq!(opened.repository.as_deref(), Some("octocat/hello"));
+    assert_eq!(
+        opened.resource_uri.as_deref(),
+        Some("github-edit://octocat/hello/issues/42")
+    );
+    assert_eq!(
+        opened.summary,
+        "Issue octocat/hello#42 opened by hubot: Crash on save"
+    );
+
+    let review_requested = json!({
+        "action": "review_requested",
+        "pull_request": { "number": 7, "title": "Fix crash" },
+        "requested_reviewer": { "login": "monalisa" },
+        "repository": { "full_name": "octocat/hello" },
+        "sender": { "login": "hubot" }
+    });
+    let event = parse_event(
+        "pull_request",
+        None,
+        review_requested.to_string().as_bytes(),
+    )
+    .unwrap();
+    assert_eq!(
+        event.resource_uri.as_deref(),
+        Some("github-edit://octocat/hello/pulls/7")
+    );
+    assert_eq!(
+        event.summary,
+        "Review of pull request octocat/hello#7 requested from monalisa by hubot: Fix crash"
+    );
+
+    // Comments on pull requests arrive as issue comments
+    let comment = json!({
+        "action": "created",
+        "issue": { "number": 7, "pull_request": {} },
+        "repository": { "full_name": "octocat/hello" },
+        "sender": { "login": "hubot" }
+    });
+    let event = parse_event("issue_comment", None, comment.to_string().as_bytes()).unwrap();
+    assert_eq!(
+        event.resource_uri.as_deref(),
+        Some("github-edit://octocat/hello/pulls/7")
+    );
+
+    let push = json!({ "ref": "refs/heads/main", "repository": { "full_name": "octocat/hello" } });
+    let event = parse_event("push", None, push.to_string().as_bytes()).unwrap();
+    assert_eq!(event.resource_uri, None);
+    assert_eq!(event.summary, "push delivered in octocat/hello");
+
+    assert!(parse_event("issues", None, b"not json").is_err());
+}
+
+/// Signed deliveries are accepted and sent to the sessions; unsigned and
+/// malformed ones are rejected
+#[tokio::test]
+async fn test_webhook_listener() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}{}", listener.local_addr().unwrap(), WEBHOOK_PATH);
+    let events = webhook_channel();
+    let mut received = events.subscribe();
+    let cancellation_token = CancellationToken::new();
+    let server = tokio::spawn(
+        WebhookListener::new(SECRET.to_string(), events)
+            .serve(listener, cancellation_token.clone()),
+    );
+
+    let client = reqwest::Client::new();
+    let body = issue_opened();
+    let deliver = |signature: String, event: Option<&str>| {
+        let mut request = client
+            .post(&url)
+            .header("x-hub-signature-256", signature)
+            .header("x-github-delivery", "d-1")
+            .body(body.clone());
+        if let Some(event) = event {
+            request = request.header("x-github-event", event);
+        }
+        request.send()
+    };
+
+    let accepted = deliver(sign(SECRET, &body), Some("issues")).await.unwrap();
+    assert_eq!(accepted.status(), 202);
+    let event = received.recv().await.unwrap();
+    assert_eq!(event.event, "issues");
+    assert_eq!(event.delivery_id.as_deref(), Some("d-1"));
+
+    let forged = deliver(sign("guess", &body), Some("issues")).await.unwrap();
+    assert_eq!(forged.status(), 401);
+    let unnamed = deliver(sign(SECRET, &body), None).await.unwrap();
+    assert_eq!(unnamed.status(), 400);
+    assert!(received.try_recv().is_err());
+
+    cancellation_token.cancel();
+    server.await.unwrap().unwrap();
+}
+
+/// Events are sent as info log messages of the webhook logger, unless the
+/// client raised the level
+#[test]
+fn test_webhook_notifications() {
+    let event = parse_event("issues", None, &issue_opened()).unwrap();
+    let logger = ToolCallLogger::default();
+    let subscriptions = ResourceSubscriptions::default();
+
+    let notifications = webhook_notifications(&event, &logger, &subscriptions);
+    let message = notifications.message.unwrap();
+    assert_eq!(message.logger.as_deref(), Some(WEBHOOK_LOGGER_NAME));
+    assert_eq!(message.data["summary"], event.summary);
+    assert_eq!(
+        message.data["resource_uri"],
+        "github-edit://octocat/hello/issues/42"
+    );
+    // The issue is not subscribed to
+    assert!(notifications.resource_updated.is_none());
+
+    logger.set_level(LoggingLevel::Warning);
+    assert!(
+        webhook_notifications(&event, &logger, &subscriptions)
+            .message
+            .is_none()
+    );
+}
+
+/// The listener address defaults, and the secret is required
+#[test]
+fn test_webhook_config() {
+    let config = WebhookConfig::default();
+    assert_eq!(config.listen_address(None), DEFAULT_WEBHOOK_ADDRESS);
+    assert_eq!(
+        config.listen_address(Some("127.0.0.1:9000".to_string())),
+        "127.0.0.1:9000"
+    );
+
+    let config = WebhookConfig {
+        address: Some("127.0.0.1:8091".to_string()),
+        secret: Some(SECRET.to_string()),
+    };
+    assert_eq!(config.listen_address(None), "127.0.0.1:8091");
+    assert_eq!(config.webhook_secret().unwrap(), SECRET);
+}
@@ -0,0 +1,184 @@
+use github_edit::types::actions::{
+    Artifact, ArtifactId, WorkflowJob, WorkflowLogFile, WorkflowRun, WorkflowRunFilter,
+    WorkflowRunId, extract_log_files, parse_workflow_inputs, select_job_logs,
+};
+use github_edit::types::check::CheckRunStatus;
+use github_edit::types::pagination::Pagination;
+use serde_json::json;
+use std::io::Write;
+
+/// Workflow runs deserialize from the REST API representation
+#[test]
+fn test_workflow_run_deserialize() {
+    let run: WorkflowRun = serde_json::from_value(json!({
+        "id": 30433642,
+        "name": "Build",
+        "head_branch": "master",
+        "head_sha": "acb5820ced9479c074f688cc328bf03f341a511d",
+        "event": "push",
+        "status": "completed",
+        "conclusion": "failure",
+        "run_number": 562,
+        "run_attempt": 1,
+        "html_url": "https://github.com/octo-org/octo-repo/actions/runs/30433642",
+        "created_at": "2020-01-22T19:33:08Z",
+        "updated_at": "2020-01-22T19:33:08Z",
+        "workflow_id": 159038
+    }))
+    .unwrap();
+
+    assert_eq!(run.id, WorkflowRunId::new(30433642));
+    assert_eq!(run.conclusion.as_deref(), Some("failure"));
+    assert!(run.is_completed());
+}
+
+/// Only the set filter fields are added to the query, followed by the page selection
+#[test]
+fn test_workflow_run_filter_query() {
+    let filter = WorkflowRunFilter {
+        workflow: Some("ci.yml".to_string()),
+        branch: Some("feature/login".to_string()),
+        event: None,
+        status: Some("in_progress".to_string()),
+        head_sha: None,
+    };
+
+    assert_eq!(
+        filter.to_query(Pagination::new(Some(2), Some(10))),
+        "branch=feature%2Flogin&status=in_progress&per_page=10&page=2"
+    );
+    let filter = WorkflowRunFilter {
+        status: Some("action_required".to_string()),
+        head_sha: Some("6dcb09b5b57875f334f61aebed695e2e4193db5e".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        filter.to_query(Pagination::new(None, Some(100))),
+        "status=action_required&head_sha=6dcb09b5b57875f334f61aebed695e2e4193db5e&per_page=100&page=1"
+    );
+    assert_eq!(
+        WorkflowRunFilter::default().to_query(Pagination::default()),
+        "per_page=30&page=1"
+    );
+}
+
+/// Workflow inputs are given as key=value pairs; values may contain '='
+#[test]
+fn test_parse_workflow_inputs() {
+    let inputs = parse_workflow_inputs(&[
+        "environment=staging".to_string(),
+        "query=a=b".to_string(),
+        "empty=".to_string(),
+    ])
+    .unwrap();
+
+    assert_eq!(inputs["environment"], "staging");
+    assert_eq!(inputs["query"], "a=b");
+    assert_eq!(inputs["empty"], "");
+
+    assert!(parse_workflow_inputs(&["missing-separator".to_string()]).is_err());
+    assert!(parse_workflow_inputs(&["=value".to_string()]).is_err());
+}
+
+/// Artifacts deserialize from the REST API representation
+#[test]
+fn test_artifact_deserialize() {
+    let artifact: Artifact = serde_json::from_value(json!({
+        "id": 11,
+        "node_id": "MDg6QXJ0aWZhY3QxMQ==",
+        "name": "coverage-report",
+        "size_in_bytes": 556,
+        "archive_download_url": "https://api.github.com/repos/octo-org/octo-docs/actions/artifacts/11/zip",
+        "expired": false,
+        "created_at": "2020-01-10T14:59:22Z",
+        "expires_at": null
+    }))
+    .unwrap();
+
+    assert_eq!(artifact.id, ArtifactId::new(11));
+    assert_eq!(artifact.name, "coverage-report");
+    assert!(!artifact.expired);
+    assert!(artifact.expires_at.is_none());
+}
+
+/// Log archives are extracted into files sorted by name, skipping directories
+#[test]
+fn test_extract_log_files() {
+    let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default();
+    writer.add_directory("build/", options).unwrap();
+    writer.start_file("build/1_Run tests.txt", options).unwrap();
+    writer.write_all(b"running 2 tests\n").unwrap();
+    writer.start_file("0_build.txt", options).unwrap();
+    writer.write_all(b"setup\nrunning 2 tests\n").unwrap();
+    let archive = writer.finish().unwrap().into_inner();
+
+    let files = extract_log_files(&archive).unwrap();
+    let names: Vec<&str> = files.iter().map(|file| file.name.as_str()).collect();
+    assert_eq!(names, vec!["0_build.txt", "build/1_Run tests.txt"]);
+    assert_eq!(files[0].content, "setup\nrunning 2 tests\n");
+
+    let job_logs = select_job_logs(&files);
+    assert_eq!(job_logs.len(), 1);
+    assert_eq!(job_logs[0].name, "0_build.txt");
+
+    assert!(extract_log_files(b"not a zip").is_err());
+}
+
+/// Step logs are selected when the archive has no job logs
+#[test]
+fn test_select_job_logs_without_job_logs() {
+    let files = vec![WorkflowLogFile {
+        name: "build/1_Run tests.txt".to_string(),
+        content: String::new(),
+    }];
+
+    assert_eq!(select_job_logs(&files).len(), 1);
+}
+
+/// The tail of a log ignores the trailing newline
+#[test]
+fn test_workflow_log_file_tail() {
+    let file = WorkflowLogFile {
+        name: "0_build.txt".to_string(),
+        content: "one\ntwo\nthree\n".to_string(),
+    };
+
+    assert_eq!(file.tail(2), "two\nthree");
+    assert_eq!(file.tail(3), "one\ntwo\nthree");
+    assert_eq!(file.tail(10), "one\ntwo\nthree");
+    assert_eq!(file.tail(0), "");
+}
+
+/// Jobs deserialize with their steps and report the failed steps
+#[test]
+fn test_workflow_job_failed_steps() {
+    let job: WorkflowJob = serde_json::from_value(json!({
+        "id": 399444496,
+        "run_id": 29679449,
+        "run_attempt": 1,
+        "name": "build",
+        "status": "completed",
+        "conclusion": "failure",
+        "started_at": "2020-01-20T17:42:40Z",
+        "completed_at": "2020-01-20T17:44:39Z",
+        "html_url": "https://github.com/octo-org/octo-repo/runs/399444496",
+        "steps": [
+            {"name": "Set up job", "status": "completed", "conclusion": "success", "number": 1},
+            {"name": "Run tests", "status": "completed", "conclusion": "failure", "number": 2},
+            {"name": "Upload", "status": "completed", "conclusion": "skipped", "number": 3}
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(job.run_id, WorkflowRunId::new(29679449));
+    assert_eq!(job.status, CheckRunStatus::Completed);
+    assert!(job.is_failing());
+
+    let failed: Vec<&str> = job
+        .failed_steps()
+        .into_iter()
+        .map(|step| step.name.as_str())
+        .collect();
+    assert_eq!(failed, vec!["Run tests"]);
+}